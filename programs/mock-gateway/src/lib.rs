@@ -0,0 +1,323 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke;
+use anchor_lang::system_program::{transfer, Transfer};
+
+declare_id!("EPUCUXKkyLuV9YSQqHdgQDE8U98eeGfxHNH8zWyxM2Bm");
+
+// A minimal stand-in for the ZetaChain gateway. It keeps deposits and the last
+// outbound call in its PDA, and its `execute*` instructions play the gateway's
+// part of the inbound, revert and abort flows. Because they run as top-level
+// instructions of this program, a connected program configured with this
+// program id as its gateway accepts the callbacks.
+
+/// Seed of the gateway PDA, as on the real gateway
+pub const GATEWAY_SEED: &[u8] = b"meta";
+
+/// Seed of the PDA a connected program receives reverted and aborted deposits on
+pub const CONNECTED_SEED: &[u8] = b"connected";
+
+/// Largest outbound message the mock records
+pub const MAX_MESSAGE_LEN: usize = 1024;
+
+/// Largest revert message the mock records
+pub const MAX_REVERT_MESSAGE_LEN: usize = 256;
+
+#[program]
+pub mod mock_gateway {
+    use super::*;
+
+    /// Create the gateway PDA; the signer becomes the authority allowed to execute
+    pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
+        let gateway = &mut ctx.accounts.pda;
+        gateway.authority = ctx.accounts.authority.key();
+        gateway.deposit_count = 0;
+        gateway.total_deposited = 0;
+        gateway.last_deposit = None;
+        gateway.bump = ctx.bumps.pda;
+
+        Ok(())
+    }
+
+    /// Take the deposit and record the call, with the real gateway's arguments
+    pub fn deposit_and_call(
+        ctx: Context<DepositAndCall>,
+        amount: u64,
+        receiver: [u8; 20],
+        destination_chain_id: u64,
+        message: Vec<u8>,
+        revert_options: Option<RevertOptions>,
+    ) -> Result<()> {
+        require!(message.len() <= MAX_MESSAGE_LEN, MockGatewayError::MessageTooLong);
+
+        if amount > 0 {
+            let cpi_accounts = Transfer {
+                from: ctx.accounts.signer.to_account_info(),
+                to: ctx.accounts.pda.to_account_info(),
+            };
+            let cpi_program = ctx.accounts.system_program.to_account_info();
+            transfer(CpiContext::new(cpi_program, cpi_accounts), amount)?;
+        }
+
+        let revert_options = revert_options.unwrap_or_default();
+        require!(
+            revert_options.revert_message.len() <= MAX_REVERT_MESSAGE_LEN,
+            MockGatewayError::MessageTooLong
+        );
+
+        let gateway = &mut ctx.accounts.pda;
+        gateway.deposit_count = gateway
+            .deposit_count
+            .checked_add(1)
+            .ok_or(MockGatewayError::Overflow)?;
+        gateway.total_deposited = gateway
+            .total_deposited
+            .checked_add(amount)
+            .ok_or(MockGatewayError::Overflow)?;
+        gateway.last_deposit = Some(DepositRecord {
+            sender: ctx.accounts.signer.key(),
+            amount,
+            receiver,
+            destination_chain_id,
+            message,
+            revert_address: revert_options.revert_address,
+            abort_address: revert_options.abort_address,
+            call_on_revert: revert_options.call_on_revert,
+            revert_message: revert_options.revert_message,
+        });
+
+        msg!(
+            "Mock gateway deposit_and_call: sender={}, amount={}, destination_chain={}",
+            ctx.accounts.signer.key(),
+            amount,
+            destination_chain_id
+        );
+
+        Ok(())
+    }
+
+    /// Deliver an inbound message by calling `on_call` on the destination program.
+    /// The remaining accounts are passed through as the callback's accounts.
+    pub fn execute<'info>(
+        ctx: Context<'_, '_, 'info, 'info, Execute<'info>>,
+        amount: u64,
+        sender: [u8; 20],
+        data: Vec<u8>,
+        bump: u8,
+    ) -> Result<()> {
+        let mut args = Vec::new();
+        (amount, sender, data, bump).serialize(&mut args)?;
+
+        invoke_callback(&ctx, "on_call", args)
+    }
+
+    /// Revert an outbound call: return `amount` to the destination program's
+    /// connected PDA, then call its `on_revert` with the deposit's revert
+    /// message as `data`
+    pub fn execute_revert<'info>(
+        ctx: Context<'_, '_, 'info, 'info, Execute<'info>>,
+        amount: u64,
+        sender: Pubkey,
+        data: Vec<u8>,
+    ) -> Result<()> {
+        refund_connected(&ctx, amount)?;
+
+        let mut args = Vec::new();
+        (amount, sender, data).serialize(&mut args)?;
+
+        invoke_callback(&ctx, "on_revert", args)
+    }
+
+    /// Abort an outbound call that could not be reverted: return `amount` to
+    /// the connected PDA, then call the destination program's `on_abort`
+    pub fn execute_abort<'info>(
+        ctx: Context<'_, '_, 'info, 'info, Execute<'info>>,
+        amount: u64,
+        sender: Pubkey,
+        data: Vec<u8>,
+    ) -> Result<()> {
+        refund_connected(&ctx, amount)?;
+
+        let mut args = Vec::new();
+        (amount, sender, data).serialize(&mut args)?;
+
+        invoke_callback(&ctx, "on_abort", args)
+    }
+}
+
+/// Call the Anchor instruction `name` on the destination program
+fn invoke_callback<'info>(
+    ctx: &Context<'_, '_, 'info, 'info, Execute<'info>>,
+    name: &str,
+    args: Vec<u8>,
+) -> Result<()> {
+    let mut data = hash(format!("global:{}", name).as_bytes()).to_bytes()[..8].to_vec();
+    data.extend_from_slice(&args);
+
+    let accounts = ctx
+        .remaining_accounts
+        .iter()
+        .map(|account| AccountMeta {
+            pubkey: account.key(),
+            is_signer: account.is_signer,
+            is_writable: account.is_writable,
+        })
+        .collect();
+    let ix = Instruction {
+        program_id: ctx.accounts.destination_program.key(),
+        accounts,
+        data,
+    };
+
+    let mut account_infos = ctx.remaining_accounts.to_vec();
+    account_infos.push(ctx.accounts.destination_program.to_account_info());
+    invoke(&ix, &account_infos)?;
+
+    Ok(())
+}
+
+/// Move `amount` of the held deposits to the destination program's connected PDA
+fn refund_connected(ctx: &Context<Execute>, amount: u64) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    let (connected, _) =
+        Pubkey::find_program_address(&[CONNECTED_SEED], &ctx.accounts.destination_program.key());
+    let connected_account = ctx
+        .remaining_accounts
+        .iter()
+        .find(|account| account.key() == connected)
+        .ok_or(MockGatewayError::MissingConnectedAccount)?;
+
+    let gateway = ctx.accounts.pda.to_account_info();
+    let rent_exempt = Rent::get()?.minimum_balance(gateway.data_len());
+    require!(
+        gateway.lamports().saturating_sub(rent_exempt) >= amount,
+        MockGatewayError::InsufficientDeposits
+    );
+
+    gateway.sub_lamports(amount)?;
+    connected_account.add_lamports(amount)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct Initialize<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = Gateway::INIT_SPACE,
+        seeds = [GATEWAY_SEED],
+        bump,
+    )]
+    pub pda: Account<'info, Gateway>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Same accounts, in the same order, as the real gateway's `deposit_and_call`
+#[derive(Accounts)]
+pub struct DepositAndCall<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(mut, seeds = [GATEWAY_SEED], bump = pda.bump)]
+    pub pda: Account<'info, Gateway>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Execute<'info> {
+    #[account(
+        mut,
+        seeds = [GATEWAY_SEED],
+        bump = pda.bump,
+        has_one = authority @ MockGatewayError::Unauthorized,
+    )]
+    pub pda: Account<'info, Gateway>,
+
+    pub authority: Signer<'info>,
+
+    /// CHECK: Program the callback is delivered to
+    #[account(executable)]
+    pub destination_program: UncheckedAccount<'info>,
+}
+
+#[account]
+pub struct Gateway {
+    pub authority: Pubkey,
+    pub deposit_count: u64,
+    pub total_deposited: u64,
+    pub last_deposit: Option<DepositRecord>,
+    pub bump: u8,
+}
+
+impl Space for Gateway {
+    const INIT_SPACE: usize = 8 + // discriminator
+        32 + // authority
+        8 + // deposit_count
+        8 + // total_deposited
+        1 + DepositRecord::INIT_SPACE + // last_deposit
+        1; // bump
+}
+
+/// The last outbound call the gateway received
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DepositRecord {
+    pub sender: Pubkey,
+    pub amount: u64,
+    pub receiver: [u8; 20],
+    pub destination_chain_id: u64,
+    pub message: Vec<u8>,
+    pub revert_address: Pubkey,
+    pub abort_address: Pubkey,
+    pub call_on_revert: bool,
+    pub revert_message: Vec<u8>,
+}
+
+impl Space for DepositRecord {
+    const INIT_SPACE: usize = 32 + // sender
+        8 + // amount
+        20 + // receiver
+        8 + // destination_chain_id
+        4 + MAX_MESSAGE_LEN + // message
+        32 + // revert_address
+        32 + // abort_address
+        1 + // call_on_revert
+        4 + MAX_REVERT_MESSAGE_LEN; // revert_message
+}
+
+/// Same layout as the real gateway's `RevertOptions`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, Debug)]
+pub struct RevertOptions {
+    pub revert_address: Pubkey,
+    pub abort_address: Pubkey,
+    pub call_on_revert: bool,
+    pub revert_message: Vec<u8>,
+    pub on_revert_gas_limit: u64,
+}
+
+#[error_code]
+pub enum MockGatewayError {
+    #[msg("Only the gateway authority may execute callbacks")]
+    Unauthorized,
+
+    #[msg("Message is longer than the mock gateway records")]
+    MessageTooLong,
+
+    #[msg("The gateway does not hold enough deposits for this refund")]
+    InsufficientDeposits,
+
+    #[msg("The destination program's connected PDA was not passed")]
+    MissingConnectedAccount,
+
+    #[msg("Counter overflow")]
+    Overflow,
+}
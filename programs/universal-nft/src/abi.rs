@@ -0,0 +1,337 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::error::ErrorCode;
+use crate::message::{MessageHeader, NftMessage};
+use crate::state::{ChainConfig, UniversalNft};
+
+/// Size of one Solidity ABI word
+const WORD: usize = 32;
+
+/// Number of head words in the Universal NFT tuple
+const HEAD_WORDS: usize = 6;
+
+/// Universal NFT payload in Solidity ABI layout, version 2 of the tuple:
+/// `abi.encode(address receiver, uint256 tokenId, string uri, uint256 gasAmount, bytes sender, uint256 nonce)`
+///
+/// `receiver` is kept as the full 32-byte word: EVM addresses are left-padded
+/// with zeroes, while a Solana recipient uses the whole word for its pubkey.
+/// Version 1 is the five-field tuple without `nonce`. The gateway hands
+/// `on_call` no nonce of its own, and the replay guard needs one to tell two
+/// identical transfers apart, so connected contracts must send version 2;
+/// `decode` rejects version 1 rather than read its uri length as the nonce.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AbiNftMessage {
+    pub receiver: [u8; 32],
+    pub token_id: [u8; 32],
+    pub uri: String,
+    pub gas_amount: u64,
+    pub sender: Vec<u8>,
+    pub nonce: u64,
+}
+
+impl AbiNftMessage {
+    /// Serialize using the standard Solidity ABI tuple encoding
+    pub fn encode(&self) -> Vec<u8> {
+        let uri_offset = HEAD_WORDS * WORD;
+        let sender_offset = uri_offset + dynamic_len(self.uri.len());
+
+        let mut out = Vec::with_capacity(sender_offset + dynamic_len(self.sender.len()));
+        out.extend_from_slice(&self.receiver);
+        out.extend_from_slice(&self.token_id);
+        out.extend_from_slice(&u64_word(uri_offset as u64));
+        out.extend_from_slice(&u64_word(self.gas_amount));
+        out.extend_from_slice(&u64_word(sender_offset as u64));
+        out.extend_from_slice(&u64_word(self.nonce));
+        write_dynamic(&mut out, self.uri.as_bytes());
+        write_dynamic(&mut out, &self.sender);
+        out
+    }
+
+    /// Parse a payload produced by `abi.encode` on an EVM or ZEVM contract
+    pub fn decode(data: &[u8]) -> Result<Self> {
+        require!(data.len() >= HEAD_WORDS * WORD, ErrorCode::InvalidMessage);
+
+        let receiver = read_word(data, 0)?;
+        let token_id = read_word(data, WORD)?;
+        let uri_offset = read_usize(data, 2 * WORD)?;
+        let gas_amount = read_u64(data, 3 * WORD)?;
+        let sender_offset = read_usize(data, 4 * WORD)?;
+        let nonce = read_u64(data, 5 * WORD)?;
+        // A version 1 tuple points its first dynamic field at the sixth word
+        require!(
+            uri_offset >= HEAD_WORDS * WORD && sender_offset >= HEAD_WORDS * WORD,
+            ErrorCode::InvalidMessage
+        );
+
+        let uri = String::from_utf8(read_dynamic(data, uri_offset, MAX_URI_LEN)?.to_vec())
+            .map_err(|_| error!(ErrorCode::InvalidMetadataUri))?;
+        let sender = read_dynamic(data, sender_offset, MAX_RECIPIENT_LEN)?.to_vec();
+
+        Ok(AbiNftMessage {
+            receiver,
+            token_id,
+            uri,
+            gas_amount,
+            sender,
+            nonce,
+        })
+    }
+
+    /// Build the outbound ABI payload for a Solana-side `NftMessage` about `mint`
+    ///
+    /// The ABI layout has no room for the origin triple. A token of the
+    /// destination's own collection goes home under its original token id;
+    /// any other NFT travels under its Solana mint address, which
+    /// `into_nft_message` recognises when it comes back.
+    pub fn from_nft_message(
+        message: &NftMessage,
+        sender: &Pubkey,
+        mint: &Pubkey,
+        destination: &ChainConfig,
+    ) -> Result<Self> {
+        require!(message.recipient.len() == 20, ErrorCode::InvalidDestinationAddress);
+        let mut receiver = [0u8; 32];
+        receiver[12..].copy_from_slice(&message.recipient);
+
+        let (original_chain, original_contract) = abi_origin(destination);
+        let token_id = if message.original_chain == original_chain
+            && message.original_contract == original_contract
+        {
+            parse_token_id(&message.original_token_id)?
+        } else {
+            mint.to_bytes()
+        };
+
+        Ok(AbiNftMessage {
+            receiver,
+            token_id,
+            uri: message.uri.clone(),
+            gas_amount: 0,
+            sender: sender.to_bytes().to_vec(),
+            nonce: message.header.nonce,
+        })
+    }
+
+    /// Convert an inbound ABI payload into the program's message type
+    ///
+    /// The ABI layout carries no header, so the source chain is the registry
+    /// entry the gateway call was validated against, and the message is a
+    /// transfer. `solana_nft` is the record of the mint the call names: when
+    /// the token id is that mint's address, the NFT is one that left Solana
+    /// over ABI and keeps its recorded origin. Any other token belongs to the
+    /// source chain's collection.
+    pub fn into_nft_message(
+        self,
+        source: &ChainConfig,
+        solana_nft: Option<&UniversalNft>,
+    ) -> Result<NftMessage> {
+        let (original_chain, original_contract, original_token_id) = match solana_nft
+            .filter(|nft| nft.mint.to_bytes() == self.token_id)
+        {
+            Some(nft) => (
+                nft.original_chain.clone(),
+                nft.original_contract.clone(),
+                nft.original_token_id.clone(),
+            ),
+            None => {
+                let (original_chain, original_contract) = abi_origin(source);
+                (original_chain, original_contract, format_token_id(&self.token_id)?)
+            }
+        };
+
+        let message = NftMessage {
+            header: MessageHeader {
+                version: MESSAGE_VERSION,
+                message_type: MSG_TYPE_TRANSFER,
+                nonce: self.nonce,
+                source_chain_id: source.chain_id,
+                destination_chain_id: SOLANA_CHAIN_ID,
+            },
+            original_chain,
+            original_contract,
+            original_token_id,
+            uri: self.uri,
+            name: String::new(),
+            symbol: String::new(),
+            recipient: self.receiver.to_vec(),
+        };
+        message.validate()?;
+        Ok(message)
+    }
+}
+
+/// Origin chain and contract of the tokens of an ABI chain's collection
+fn abi_origin(chain: &ChainConfig) -> (String, String) {
+    (chain.name.clone(), hex::encode(&chain.connected_contract))
+}
+
+/// Parse a decimal (up to `u64::MAX`) or `0x`-prefixed hex token id into a
+/// big-endian uint256; the inverse of `format_token_id`
+pub fn parse_token_id(token_id: &str) -> Result<[u8; 32]> {
+    let mut word = [0u8; 32];
+    if let Some(hex_digits) = token_id.strip_prefix("0x") {
+        require!(
+            !hex_digits.is_empty() && hex_digits.len() <= 2 * WORD,
+            ErrorCode::InvalidOriginalChain
+        );
+        let padded = format!("{:0>64}", hex_digits);
+        hex::decode_to_slice(padded, &mut word).map_err(|_| error!(ErrorCode::InvalidOriginalChain))?;
+    } else {
+        let value: u64 = token_id.parse().map_err(|_| error!(ErrorCode::InvalidOriginalChain))?;
+        word[24..].copy_from_slice(&value.to_be_bytes());
+    }
+    Ok(word)
+}
+
+/// Format a uint256 token id the way `UniversalNft.original_token_id` stores it:
+/// decimal when it fits in a `u64`, otherwise minimal `0x`-prefixed hex
+pub fn format_token_id(token_id: &[u8; 32]) -> Result<String> {
+    if token_id[..24].iter().all(|b| *b == 0) {
+        let mut low = [0u8; 8];
+        low.copy_from_slice(&token_id[24..]);
+        return Ok(u64::from_be_bytes(low).to_string());
+    }
+    let encoded = hex::encode(token_id);
+    let formatted = format!("0x{}", encoded.trim_start_matches('0'));
+    require!(formatted.len() <= MAX_TOKEN_ID_LEN, ErrorCode::InvalidOriginalChain);
+    Ok(formatted)
+}
+
+fn u64_word(value: u64) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    word[24..].copy_from_slice(&value.to_be_bytes());
+    word
+}
+
+fn dynamic_len(len: usize) -> usize {
+    WORD + len.div_ceil(WORD) * WORD
+}
+
+fn write_dynamic(out: &mut Vec<u8>, bytes: &[u8]) {
+    out.extend_from_slice(&u64_word(bytes.len() as u64));
+    out.extend_from_slice(bytes);
+    let padding = dynamic_len(bytes.len()) - WORD - bytes.len();
+    out.resize(out.len() + padding, 0);
+}
+
+fn read_word(data: &[u8], offset: usize) -> Result<[u8; 32]> {
+    let end = offset.checked_add(WORD).ok_or(ErrorCode::InvalidMessage)?;
+    let slice = data.get(offset..end).ok_or(ErrorCode::InvalidMessage)?;
+    let mut word = [0u8; 32];
+    word.copy_from_slice(slice);
+    Ok(word)
+}
+
+fn read_u64(data: &[u8], offset: usize) -> Result<u64> {
+    let word = read_word(data, offset)?;
+    require!(word[..24].iter().all(|b| *b == 0), ErrorCode::InvalidMessage);
+    let mut low = [0u8; 8];
+    low.copy_from_slice(&word[24..]);
+    Ok(u64::from_be_bytes(low))
+}
+
+fn read_usize(data: &[u8], offset: usize) -> Result<usize> {
+    usize::try_from(read_u64(data, offset)?).map_err(|_| error!(ErrorCode::InvalidMessage))
+}
+
+fn read_dynamic(data: &[u8], offset: usize, max_len: usize) -> Result<&[u8]> {
+    let len = read_usize(data, offset)?;
+    require!(len <= max_len, ErrorCode::InvalidMessage);
+    let start = offset + WORD; // read_usize succeeded, so this cannot overflow
+    data.get(start..start + len).ok_or(error!(ErrorCode::InvalidMessage))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> AbiNftMessage {
+        let mut receiver = [0u8; 32];
+        receiver[12..].copy_from_slice(&[0xab; 20]);
+        AbiNftMessage {
+            receiver,
+            token_id: parse_token_id("42").unwrap(),
+            uri: "https://zetachain.com/metadata/1.json".to_string(),
+            gas_amount: 0,
+            sender: vec![0x11; 20],
+            nonce: 7,
+        }
+    }
+
+    #[test]
+    fn round_trips() {
+        let message = sample();
+        assert_eq!(AbiNftMessage::decode(&message.encode()).unwrap(), message);
+    }
+
+    #[test]
+    fn matches_solidity_layout() {
+        let encoded = sample().encode();
+        let uri = b"https://zetachain.com/metadata/1.json";
+
+        // Head: receiver, tokenId, uri offset, gasAmount, sender offset, nonce
+        assert_eq!(&encoded[12..32], &[0xab; 20]);
+        assert_eq!(encoded[63], 42);
+        assert_eq!(encoded[95], 0xc0);
+        assert_eq!(&encoded[96..128], &[0u8; 32]);
+        assert_eq!(&encoded[128..158], &[0u8; 30]);
+        assert_eq!(&encoded[158..160], &[0x01, 0x20]);
+        assert_eq!(&encoded[160..191], &[0u8; 31]);
+        assert_eq!(encoded[191], 7);
+
+        // Tail: uri length + padded bytes, then sender length + padded bytes
+        assert_eq!(encoded[223], uri.len() as u8);
+        assert_eq!(&encoded[224..224 + uri.len()], uri);
+        assert_eq!(encoded[319], 20);
+        assert_eq!(&encoded[320..340], &[0x11; 20]);
+        assert_eq!(encoded.len(), 352);
+    }
+
+    #[test]
+    fn rejects_version_1_tuple() {
+        // abi.encode(receiver, tokenId, uri, gasAmount, sender) without the nonce
+        let message = sample();
+        let uri_offset = 5 * WORD;
+        let sender_offset = uri_offset + dynamic_len(message.uri.len());
+        let mut encoded = Vec::new();
+        encoded.extend_from_slice(&message.receiver);
+        encoded.extend_from_slice(&message.token_id);
+        encoded.extend_from_slice(&u64_word(uri_offset as u64));
+        encoded.extend_from_slice(&u64_word(message.gas_amount));
+        encoded.extend_from_slice(&u64_word(sender_offset as u64));
+        write_dynamic(&mut encoded, message.uri.as_bytes());
+        write_dynamic(&mut encoded, &message.sender);
+        assert!(AbiNftMessage::decode(&encoded).is_err());
+    }
+
+    #[test]
+    fn rejects_out_of_bounds_offsets() {
+        let mut encoded = sample().encode();
+        encoded[94] = 0xff;
+        assert!(AbiNftMessage::decode(&encoded).is_err());
+
+        let mut encoded = sample().encode();
+        encoded[64..96].copy_from_slice(&[0xff; 32]);
+        assert!(AbiNftMessage::decode(&encoded).is_err());
+        assert!(AbiNftMessage::decode(&encoded[..100]).is_err());
+    }
+
+    #[test]
+    fn formats_token_ids() {
+        assert_eq!(format_token_id(&parse_token_id("42").unwrap()).unwrap(), "42");
+        let large = parse_token_id("0x0100000000000000000000").unwrap();
+        assert_eq!(format_token_id(&large).unwrap(), "0x100000000000000000000");
+        assert!(format_token_id(&[0xff; 32]).is_err());
+        assert!(parse_token_id("not-a-number").is_err());
+
+        // Decimal ids stop where format_token_id switches to hex
+        let max = u64::MAX.to_string();
+        assert_eq!(format_token_id(&parse_token_id(&max).unwrap()).unwrap(), max);
+        assert!(parse_token_id("18446744073709551616").is_err());
+        assert_eq!(
+            format_token_id(&parse_token_id("0x10000000000000000").unwrap()).unwrap(),
+            "0x10000000000000000"
+        );
+    }
+}
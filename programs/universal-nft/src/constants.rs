@@ -11,19 +11,36 @@ pub const MAX_COMPUTE_UNITS: u32 = 400_000;
 pub const MIN_RENT_EXEMPTION: u64 = 2_039_280; // ~0.002 SOL
 
 /// ZetaChain specific constants
+/// Destination chains are registered on-chain as `ChainConfig` accounts
 pub const ZETACHAIN_CHAIN_ID: u64 = 7000;
-pub const ETHEREUM_CHAIN_ID: u64 = 1;
-pub const BSC_CHAIN_ID: u64 = 56;
-pub const POLYGON_CHAIN_ID: u64 = 137;
 pub const SOLANA_CHAIN_ID: u64 = 900; // Solana testnet chain ID
 
+/// Cross-chain message layout version
+pub const MESSAGE_VERSION: u8 = 1;
+
 /// Cross-chain message types
 pub const MSG_TYPE_MINT: u8 = 1;
 pub const MSG_TYPE_TRANSFER: u8 = 2;
 pub const MSG_TYPE_BURN: u8 = 3;
 pub const MSG_TYPE_LOCK: u8 = 4;
 pub const MSG_TYPE_UNLOCK: u8 = 5;
+pub const MSG_TYPE_UPDATE_METADATA: u8 = 6;
+
+/// Field length limits, matching the `UniversalNft` account layout
+pub const MAX_CHAIN_LEN: usize = 32;
+pub const MAX_CONTRACT_LEN: usize = 64;
+pub const MAX_TOKEN_ID_LEN: usize = 32;
+pub const MAX_URI_LEN: usize = 200;
+pub const MAX_NAME_LEN: usize = 32;
+pub const MAX_SYMBOL_LEN: usize = 10;
+pub const MAX_RECIPIENT_LEN: usize = 32;
 
 /// Default fees (in lamports)
 pub const DEFAULT_CROSS_CHAIN_FEE: u64 = 10_000; // 0.00001 SOL
 pub const DEFAULT_MINT_FEE: u64 = 5_000; // 0.000005 SOL
+
+/// Default time (in seconds) before an unconfirmed transfer can be cancelled
+pub const DEFAULT_TRANSFER_TIMEOUT: i64 = 86_400; // 24 hours
+
+/// Time (in seconds) a finished transfer record is kept before it can be closed
+pub const TRANSFER_RETENTION_PERIOD: i64 = 7 * 86_400; // 7 days
@@ -17,6 +17,7 @@ pub enum ErrorCode {
     #[msg("Invalid metadata URI")]
     InvalidMetadataUri,
     
+    /// Reserved: no longer returned, kept so later error codes stay stable
     #[msg("Cross-chain transfer already exists")]
     TransferAlreadyExists,
     
@@ -29,6 +30,7 @@ pub enum ErrorCode {
     #[msg("ZetaChain gateway not configured")]
     GatewayNotConfigured,
     
+    /// Reserved: no longer returned, kept so later error codes stay stable
     #[msg("Compute budget exceeded")]
     ComputeBudgetExceeded,
     
@@ -43,4 +45,74 @@ pub enum ErrorCode {
     
     #[msg("Transfer confirmation timeout")]
     TransferTimeout,
+    
+    #[msg("No authority transfer is pending")]
+    NoPendingAuthority,
+    
+    #[msg("Invalid authority")]
+    InvalidAuthority,
+    
+    #[msg("Program is paused")]
+    ProgramPaused,
+    
+    #[msg("Invalid chain configuration")]
+    InvalidChainConfig,
+    
+    #[msg("Sender is not the registered Universal NFT contract")]
+    UnknownSender,
+    
+    #[msg("Cross-chain message already processed")]
+    MessageAlreadyProcessed,
+    
+    #[msg("Malformed cross-chain message")]
+    InvalidMessage,
+    
+    #[msg("Unsupported cross-chain message version")]
+    UnsupportedMessageVersion,
+    
+    #[msg("Unsupported cross-chain message type")]
+    UnsupportedMessageType,
+    
+    #[msg("Universal NFT already exists for this mint")]
+    NftAlreadyExists,
+    
+    #[msg("Universal NFT not found for this mint")]
+    NftNotFound,
+    
+    #[msg("NFT is not locked")]
+    NftNotLocked,
+    
+    #[msg("Required account missing for this message type")]
+    MissingAccount,
+    
+    #[msg("Collection already created")]
+    CollectionAlreadyExists,
+    
+    #[msg("Invalid or missing collection")]
+    InvalidCollection,
+    
+    #[msg("Origin index does not match the NFT origin")]
+    InvalidOriginIndex,
+    
+    #[msg("Transfer timeout must be positive")]
+    InvalidTransferTimeout,
+    
+    /// Reserved: no longer returned, kept so later error codes stay stable
+    #[msg("Transfer has not timed out yet")]
+    TransferNotExpired,
+    
+    #[msg("Transfer is still within its retention period")]
+    RetentionPeriodActive,
+    
+    #[msg("Nonce counter overflow")]
+    NonceOverflow,
+    
+    #[msg("Transfer no longer holds the NFT's lock")]
+    StaleTransfer,
+    
+    #[msg("Transfer was sent through the gateway; only a revert or abort can settle it")]
+    TransferSentToGateway,
+    
+    #[msg("Metadata account is not the Metaplex metadata of this mint")]
+    InvalidMetadataAccount,
 }
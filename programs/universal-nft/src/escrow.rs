@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{transfer, Transfer};
+
+use crate::state::GLOBAL_CONFIG_SEED;
+
+// NFTs that are locked for, or away on, another chain sit in the associated
+// token account of the global config PDA, so the token program rather than
+// `UniversalNft.is_locked` enforces the lock.
+
+/// Move the owner's NFT into the program escrow
+pub fn lock_in_escrow<'info>(
+    token_program: AccountInfo<'info>,
+    owner_token_account: AccountInfo<'info>,
+    escrow_token_account: AccountInfo<'info>,
+    owner: AccountInfo<'info>,
+) -> Result<()> {
+    let cpi_accounts = Transfer {
+        from: owner_token_account,
+        to: escrow_token_account,
+        authority: owner,
+    };
+    transfer(CpiContext::new(token_program, cpi_accounts), 1)
+}
+
+/// Release the escrowed NFT to `destination`, signed by the global config PDA
+pub fn release_from_escrow<'info>(
+    token_program: AccountInfo<'info>,
+    escrow_token_account: AccountInfo<'info>,
+    destination: AccountInfo<'info>,
+    global_config: AccountInfo<'info>,
+    global_config_bump: u8,
+) -> Result<()> {
+    let seeds = &[
+        GLOBAL_CONFIG_SEED,
+        &[global_config_bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    let cpi_accounts = Transfer {
+        from: escrow_token_account,
+        to: destination,
+        authority: global_config,
+    };
+    transfer(CpiContext::new_with_signer(token_program, cpi_accounts, signer), 1)
+}
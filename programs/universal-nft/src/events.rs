@@ -0,0 +1,209 @@
+use anchor_lang::prelude::*;
+
+/// Emitted once when the program's global configuration is created
+#[event]
+pub struct ProgramInitialized {
+    pub authority: Pubkey,
+    pub zetachain_gateway: Pubkey,
+    pub collection_authority: Pubkey,
+    pub fee_recipient: Pubkey,
+    pub cross_chain_fee: u64,
+    pub transfer_timeout: i64,
+}
+
+/// Emitted when the authority updates the global configuration
+#[event]
+pub struct ConfigUpdated {
+    pub authority: Pubkey,
+    pub old_zetachain_gateway: Pubkey,
+    pub new_zetachain_gateway: Pubkey,
+    pub old_collection_authority: Pubkey,
+    pub new_collection_authority: Pubkey,
+    pub old_fee_recipient: Pubkey,
+    pub new_fee_recipient: Pubkey,
+    pub old_cross_chain_fee: u64,
+    pub new_cross_chain_fee: u64,
+    pub old_guardian: Pubkey,
+    pub new_guardian: Pubkey,
+    pub old_transfer_timeout: i64,
+    pub new_transfer_timeout: i64,
+    pub updated_at: i64,
+}
+
+/// Emitted when the authority proposes a new authority
+#[event]
+pub struct AuthorityProposed {
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+}
+
+/// Emitted when a pending authority proposal is withdrawn
+#[event]
+pub struct AuthorityProposalCancelled {
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+}
+
+/// Emitted when the pending authority accepts ownership of the config
+#[event]
+pub struct AuthorityTransferred {
+    pub old_authority: Pubkey,
+    pub new_authority: Pubkey,
+}
+
+/// Emitted when the authority or guardian changes the pause switches
+#[event]
+pub struct PauseUpdated {
+    pub updated_by: Pubkey,
+    pub mint_paused: bool,
+    pub outbound_paused: bool,
+    pub inbound_paused: bool,
+    pub updated_at: i64,
+}
+
+/// Emitted when the authority registers a connected chain
+#[event]
+pub struct ChainRegistered {
+    pub chain_id: u64,
+    pub name: String,
+    pub connected_contract: Vec<u8>,
+    pub cross_chain_fee: u64,
+}
+
+/// Emitted when the authority changes a registered chain
+#[event]
+pub struct ChainUpdated {
+    pub chain_id: u64,
+    pub enabled: bool,
+    pub name: String,
+    pub connected_contract: Vec<u8>,
+    pub cross_chain_fee: u64,
+}
+
+/// Emitted when the collection authority mints a new Universal NFT on Solana
+#[event]
+pub struct NftMinted {
+    pub mint: Pubkey,
+    pub recipient: Pubkey,
+    pub original_chain: String,
+    pub original_contract: String,
+    pub original_token_id: String,
+    pub metadata_uri: String,
+}
+
+/// Emitted when an outbound transfer is created, carrying its derived id
+#[event]
+pub struct TransferInitiated {
+    pub transfer_id: [u8; 32],
+    pub nft_mint: Pubkey,
+    pub owner: Pubkey,
+    /// Owner's transfer nonce the id is derived from
+    pub nonce: u64,
+    /// Header nonce of the gateway message; `None` when no message was sent
+    pub message_nonce: Option<u64>,
+    pub destination_chain_id: u64,
+    pub destination_recipient: String,
+    pub fee: u64,
+    pub initiated_at: i64,
+}
+
+/// Emitted when the gateway confirms an outbound transfer
+#[event]
+pub struct TransferConfirmed {
+    pub transfer_id: [u8; 32],
+    pub nft_mint: Pubkey,
+    pub destination_chain: String,
+    pub destination_recipient: String,
+}
+
+/// Emitted when an outbound transfer is completed, by the gateway or by a
+/// burn acknowledgement from the destination chain
+#[event]
+pub struct TransferCompleted {
+    pub transfer_id: [u8; 32],
+    pub nft_mint: Pubkey,
+    pub destination_chain: String,
+    pub destination_recipient: String,
+    pub completed_at: i64,
+}
+
+/// Emitted when the gateway reverts a transfer and the NFT returns to its owner
+#[event]
+pub struct TransferReverted {
+    pub transfer_id: [u8; 32],
+    pub nft_mint: Pubkey,
+    pub source_owner: Pubkey,
+    pub refund_address: Pubkey,
+    pub refunded: u64,
+}
+
+/// Emitted when the gateway aborts a transfer; the NFT waits in escrow
+#[event]
+pub struct TransferAborted {
+    pub transfer_id: [u8; 32],
+    pub nft_mint: Pubkey,
+    pub abort_address: Pubkey,
+    pub refunded: u64,
+}
+
+/// Emitted when the owner or abort address takes an aborted NFT out of escrow
+#[event]
+pub struct AbortedNftReclaimed {
+    pub transfer_id: [u8; 32],
+    pub nft_mint: Pubkey,
+    pub claimant: Pubkey,
+}
+
+/// Emitted when the owner cancels a transfer that timed out
+#[event]
+pub struct TransferCancelled {
+    pub transfer_id: [u8; 32],
+    pub nft_mint: Pubkey,
+    pub owner: Pubkey,
+}
+
+/// Emitted when a finished transfer record is closed and its rent returned
+#[event]
+pub struct TransferClosed {
+    pub transfer_id: [u8; 32],
+    pub nft_mint: Pubkey,
+    pub payer: Pubkey,
+}
+
+/// Emitted when an NFT arrives on Solana for the first time and is minted
+#[event]
+pub struct NftReceived {
+    pub mint: Pubkey,
+    pub source_chain_id: u64,
+    pub sender: [u8; 20],
+    pub recipient: Pubkey,
+    pub original_chain: String,
+    pub original_contract: String,
+    pub original_token_id: String,
+}
+
+/// Emitted when an inbound message releases an escrowed NFT to its recipient
+#[event]
+pub struct NftReleased {
+    pub mint: Pubkey,
+    pub source_chain_id: u64,
+    pub recipient: Pubkey,
+    pub original_token_id: String,
+}
+
+/// Emitted when the source chain updates an NFT's metadata URI
+#[event]
+pub struct NftMetadataUpdated {
+    pub mint: Pubkey,
+    pub source_chain_id: u64,
+    pub metadata_uri: String,
+}
+
+/// Emitted when the authority creates the Universal NFT collection
+#[event]
+pub struct CollectionCreated {
+    pub authority: Pubkey,
+    pub collection_mint: Pubkey,
+    pub name: String,
+    pub uri: String,
+}
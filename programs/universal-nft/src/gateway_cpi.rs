@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+
+// Outbound calls into the ZetaChain gateway. With the `demo-gateway` feature
+// the program records the transfer and escrows the NFT as usual but never
+// calls a gateway program, so it runs on a local validator without one.
+
+#[cfg(not(feature = "demo-gateway"))]
+pub use gateway::RevertOptions;
+
+/// Same layout as the gateway's `RevertOptions`, so both builds share one IDL
+#[cfg(feature = "demo-gateway")]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, Debug)]
+pub struct RevertOptions {
+    pub revert_address: Pubkey,
+    pub abort_address: Pubkey,
+    pub call_on_revert: bool,
+    pub revert_message: Vec<u8>,
+    pub on_revert_gas_limit: u64,
+}
+
+/// Accounts for the gateway's `deposit_and_call`
+pub struct GatewayDepositAccounts<'info> {
+    pub gateway_program: AccountInfo<'info>,
+    pub signer: AccountInfo<'info>,
+    pub gateway_pda: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+}
+
+/// Deposit `amount` with the gateway and have it call the destination chain
+#[cfg(not(feature = "demo-gateway"))]
+pub fn deposit_and_call(
+    accounts: GatewayDepositAccounts<'_>,
+    amount: u64,
+    receiver: [u8; 20],
+    destination_chain_id: u64,
+    message: Vec<u8>,
+    revert_options: Option<RevertOptions>,
+) -> Result<()> {
+    let cpi_accounts = gateway::cpi::accounts::DepositAndCall {
+        signer: accounts.signer,
+        pda: accounts.gateway_pda,
+        system_program: accounts.system_program,
+    };
+    let cpi_ctx = CpiContext::new(accounts.gateway_program, cpi_accounts);
+
+    gateway::cpi::deposit_and_call(
+        cpi_ctx,
+        amount,
+        receiver,
+        destination_chain_id,
+        message,
+        revert_options,
+    )
+}
+
+/// Demo build: log the call the gateway would have received
+#[cfg(feature = "demo-gateway")]
+pub fn deposit_and_call(
+    accounts: GatewayDepositAccounts<'_>,
+    amount: u64,
+    receiver: [u8; 20],
+    destination_chain_id: u64,
+    message: Vec<u8>,
+    _revert_options: Option<RevertOptions>,
+) -> Result<()> {
+    msg!(
+        "demo-gateway: deposit_and_call not forwarded: gateway={}, amount={}, destination_chain={}, receiver={}, message_len={}",
+        accounts.gateway_program.key(),
+        amount,
+        destination_chain_id,
+        hex::encode(receiver),
+        message.len()
+    );
+
+    Ok(())
+}
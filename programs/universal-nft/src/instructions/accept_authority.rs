@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::error::ErrorCode;
+use crate::events::AuthorityTransferred;
+
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [GLOBAL_CONFIG_SEED],
+        bump = global_config.bump,
+        constraint = global_config.pending_authority.is_some() @ ErrorCode::NoPendingAuthority,
+        constraint = global_config.pending_authority == Some(new_authority.key()) @ ErrorCode::Unauthorized,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// The proposed authority, proving control of the key
+    pub new_authority: Signer<'info>,
+}
+
+/// Accept a pending authority proposal
+pub fn handler(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let global_config = &mut ctx.accounts.global_config;
+    let old_authority = global_config.authority;
+
+    global_config.authority = ctx.accounts.new_authority.key();
+    global_config.pending_authority = None;
+
+    emit!(AuthorityTransferred {
+        old_authority,
+        new_authority: global_config.authority,
+    });
+
+    msg!(
+        "Authority transferred: old_authority={}, new_authority={}",
+        old_authority,
+        global_config.authority
+    );
+
+    Ok(())
+}
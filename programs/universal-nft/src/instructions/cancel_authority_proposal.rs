@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::error::ErrorCode;
+use crate::events::AuthorityProposalCancelled;
+
+#[derive(Accounts)]
+pub struct CancelAuthorityProposal<'info> {
+    #[account(
+        mut,
+        seeds = [GLOBAL_CONFIG_SEED],
+        bump = global_config.bump,
+        has_one = authority @ ErrorCode::Unauthorized,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Withdraw a pending authority proposal
+pub fn handler(ctx: Context<CancelAuthorityProposal>) -> Result<()> {
+    let global_config = &mut ctx.accounts.global_config;
+
+    let pending_authority = global_config
+        .pending_authority
+        .take()
+        .ok_or(ErrorCode::NoPendingAuthority)?;
+
+    emit!(AuthorityProposalCancelled {
+        authority: global_config.authority,
+        pending_authority,
+    });
+
+    msg!(
+        "Authority transfer cancelled: authority={}, pending_authority={}",
+        global_config.authority,
+        pending_authority
+    );
+
+    Ok(())
+}
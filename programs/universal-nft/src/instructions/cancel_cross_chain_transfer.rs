@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::state::*;
+use crate::error::ErrorCode;
+use crate::escrow::release_from_escrow;
+use crate::events::TransferCancelled;
+
+#[derive(Accounts)]
+#[instruction(transfer_id: [u8; 32])]
+pub struct CancelCrossChainTransfer<'info> {
+    #[account(
+        seeds = [GLOBAL_CONFIG_SEED],
+        bump = global_config.bump,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            UNIVERSAL_NFT_SEED,
+            nft_mint.key().as_ref(),
+        ],
+        bump = universal_nft.bump,
+        constraint = universal_nft.is_locked @ ErrorCode::NftNotLocked,
+    )]
+    pub universal_nft: Account<'info, UniversalNft>,
+
+    #[account(
+        mut,
+        seeds = [
+            CROSS_CHAIN_TRANSFER_SEED,
+            transfer_id.as_ref(),
+        ],
+        bump = cross_chain_transfer.bump,
+        constraint = cross_chain_transfer.nft_mint == nft_mint.key() @ ErrorCode::InvalidOriginalChain,
+        constraint = cross_chain_transfer.source_owner == owner.key() @ ErrorCode::Unauthorized,
+        constraint = universal_nft.active_transfer == Some(transfer_id) @ ErrorCode::StaleTransfer,
+        constraint = cross_chain_transfer.status == TransferStatus::Initiated @ ErrorCode::InvalidTransferStatus,
+        constraint = !cross_chain_transfer.via_gateway @ ErrorCode::TransferSentToGateway,
+    )]
+    pub cross_chain_transfer: Account<'info, CrossChainTransfer>,
+
+    pub nft_mint: Account<'info, Mint>,
+
+    /// Program-owned escrow holding the NFT since the transfer was initiated
+    #[account(
+        mut,
+        associated_token::mint = nft_mint,
+        associated_token::authority = global_config,
+    )]
+    pub escrow_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = nft_mint,
+        associated_token::authority = owner,
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Cancel a transfer the gateway has not confirmed yet, whether or not the
+/// configured timeout has passed. Once cancelled the transfer is no longer
+/// `Initiated`, so `confirm_cross_chain_transfer` refuses it.
+/// Transfers sent with `deposit_and_call` are never confirmed, so they are
+/// settled by the gateway's revert or abort instead.
+pub fn handler(
+    ctx: Context<CancelCrossChainTransfer>,
+    transfer_id: [u8; 32],
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    // Return the NFT from escrow to its owner
+    release_from_escrow(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.escrow_token_account.to_account_info(),
+        ctx.accounts.owner_token_account.to_account_info(),
+        ctx.accounts.global_config.to_account_info(),
+        ctx.accounts.global_config.bump,
+    )?;
+
+    let universal_nft = &mut ctx.accounts.universal_nft;
+    universal_nft.owner = ctx.accounts.owner.key();
+    universal_nft.is_locked = false;
+    universal_nft.lock_destination_chain = String::new();
+    universal_nft.lock_recipient = String::new();
+    universal_nft.active_transfer = None;
+    universal_nft.updated_at = clock.unix_timestamp;
+
+    let cross_chain_transfer = &mut ctx.accounts.cross_chain_transfer;
+    cross_chain_transfer.status = TransferStatus::Cancelled;
+    cross_chain_transfer.completed_at = Some(clock.unix_timestamp);
+
+    emit!(TransferCancelled {
+        transfer_id,
+        nft_mint: ctx.accounts.nft_mint.key(),
+        owner: ctx.accounts.owner.key(),
+    });
+
+    msg!(
+        "CrossChainTransferCancelled: transfer_id={}, mint={}, owner={}",
+        hex::encode(transfer_id),
+        ctx.accounts.nft_mint.key(),
+        ctx.accounts.owner.key()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::error::ErrorCode;
+use crate::constants::*;
+use crate::events::TransferClosed;
+
+#[derive(Accounts)]
+#[instruction(transfer_id: [u8; 32])]
+pub struct CloseTransfer<'info> {
+    #[account(
+        mut,
+        close = payer,
+        seeds = [
+            CROSS_CHAIN_TRANSFER_SEED,
+            transfer_id.as_ref(),
+        ],
+        bump = cross_chain_transfer.bump,
+        has_one = payer @ ErrorCode::Unauthorized,
+        constraint = cross_chain_transfer.is_finalized() @ ErrorCode::InvalidTransferStatus,
+    )]
+    pub cross_chain_transfer: Account<'info, CrossChainTransfer>,
+
+    /// The transferred NFT; a transfer still holding its lock must stay open
+    #[account(
+        seeds = [
+            UNIVERSAL_NFT_SEED,
+            cross_chain_transfer.nft_mint.as_ref(),
+        ],
+        bump = universal_nft.bump,
+        constraint = universal_nft.active_transfer != Some(transfer_id) @ ErrorCode::NftLocked,
+    )]
+    pub universal_nft: Account<'info, UniversalNft>,
+
+    /// The account that paid the transfer's rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+}
+
+/// Close a finished transfer once the retention period has passed
+pub fn handler(
+    ctx: Context<CloseTransfer>,
+    transfer_id: [u8; 32],
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let cross_chain_transfer = &ctx.accounts.cross_chain_transfer;
+
+    let completed_at = cross_chain_transfer
+        .completed_at
+        .ok_or(ErrorCode::InvalidTransferStatus)?;
+    require!(
+        clock.unix_timestamp >= completed_at.saturating_add(TRANSFER_RETENTION_PERIOD),
+        ErrorCode::RetentionPeriodActive
+    );
+
+    emit!(TransferClosed {
+        transfer_id,
+        nft_mint: cross_chain_transfer.nft_mint,
+        payer: ctx.accounts.payer.key(),
+    });
+
+    msg!(
+        "CrossChainTransferClosed: transfer_id={}, mint={}, payer={}",
+        hex::encode(transfer_id),
+        cross_chain_transfer.nft_mint,
+        ctx.accounts.payer.key()
+    );
+
+    Ok(())
+}
@@ -1,12 +1,13 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{burn, Burn, Token, TokenAccount, Mint};
+use anchor_spl::token::Mint;
 
 use crate::state::*;
 use crate::error::ErrorCode;
 use crate::constants::*;
+use crate::events::TransferCompleted;
 
 #[derive(Accounts)]
-#[instruction(transfer_id: String)]
+#[instruction(transfer_id: [u8; 32])]
 pub struct CompleteCrossChainTransfer<'info> {
     #[account(
         seeds = [GLOBAL_CONFIG_SEED],
@@ -31,68 +32,50 @@ pub struct CompleteCrossChainTransfer<'info> {
         mut,
         seeds = [
             CROSS_CHAIN_TRANSFER_SEED,
-            transfer_id.as_bytes(),
+            transfer_id.as_ref(),
         ],
         bump = cross_chain_transfer.bump,
         constraint = cross_chain_transfer.nft_mint == nft_mint.key() @ ErrorCode::InvalidOriginalChain,
         constraint = cross_chain_transfer.status == TransferStatus::Confirmed @ ErrorCode::InvalidTransferStatus,
+        constraint = universal_nft.active_transfer == Some(transfer_id) @ ErrorCode::StaleTransfer,
     )]
     pub cross_chain_transfer: Account<'info, CrossChainTransfer>,
     
-    #[account(mut)]
     pub nft_mint: Account<'info, Mint>,
     
-    #[account(
-        mut,
-        constraint = owner_token_account.mint == nft_mint.key() @ ErrorCode::InvalidOriginalChain,
-        constraint = owner_token_account.owner == cross_chain_transfer.source_owner @ ErrorCode::Unauthorized,
-        constraint = owner_token_account.amount == 1 @ ErrorCode::Unauthorized,
-    )]
-    pub owner_token_account: Account<'info, TokenAccount>,
-    
-    /// CHECK: Must be the collection authority to burn NFTs
-    #[account(
-        constraint = collection_authority.key() == global_config.collection_authority @ ErrorCode::Unauthorized
-    )]
-    pub collection_authority: Signer<'info>,
-    
     /// CHECK: This is the ZetaChain gateway - validated by global config
     pub zetachain_gateway: Signer<'info>,
-    
-    pub token_program: Program<'info, Token>,
 }
 
 pub fn handler(
     ctx: Context<CompleteCrossChainTransfer>,
-    transfer_id: String,
+    transfer_id: [u8; 32],
 ) -> Result<()> {
     let universal_nft = &mut ctx.accounts.universal_nft;
     let cross_chain_transfer = &mut ctx.accounts.cross_chain_transfer;
     let clock = Clock::get()?;
     
-    // Burn the NFT token to complete the cross-chain transfer
-    let cpi_accounts = Burn {
-        mint: ctx.accounts.nft_mint.to_account_info(),
-        from: ctx.accounts.owner_token_account.to_account_info(),
-        authority: ctx.accounts.collection_authority.to_account_info(),
-    };
-    
-    let cpi_program = ctx.accounts.token_program.to_account_info();
-    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-    
-    burn(cpi_ctx, 1)?;
-    
-    // Update transfer status
+    // Update transfer status. The NFT stays in the program escrow, and locked,
+    // while it lives on the destination chain; it is released when it returns
     cross_chain_transfer.status = TransferStatus::Completed;
     cross_chain_transfer.completed_at = Some(clock.unix_timestamp);
     
-    // Update NFT state
-    universal_nft.is_locked = false;
+    // The finished transfer no longer holds the lock, so its account can be
+    // closed without the NFT's return depending on it
+    universal_nft.active_transfer = None;
     universal_nft.updated_at = clock.unix_timestamp;
     
+    emit!(TransferCompleted {
+        transfer_id,
+        nft_mint: ctx.accounts.nft_mint.key(),
+        destination_chain: cross_chain_transfer.destination_chain.clone(),
+        destination_recipient: cross_chain_transfer.destination_recipient.clone(),
+        completed_at: clock.unix_timestamp,
+    });
+    
     msg!(
         "CrossChainTransferCompleted: transfer_id={}, mint={}, destination_chain={}, recipient={}",
-        transfer_id,
+        hex::encode(transfer_id),
         ctx.accounts.nft_mint.key(),
         cross_chain_transfer.destination_chain,
         cross_chain_transfer.destination_recipient
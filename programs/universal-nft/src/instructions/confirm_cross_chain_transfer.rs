@@ -3,9 +3,10 @@ use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::error::ErrorCode;
 use crate::constants::*;
+use crate::events::TransferConfirmed;
 
 #[derive(Accounts)]
-#[instruction(transfer_id: String)]
+#[instruction(transfer_id: [u8; 32])]
 pub struct ConfirmCrossChainTransfer<'info> {
     #[account(
         seeds = [GLOBAL_CONFIG_SEED],
@@ -18,7 +19,7 @@ pub struct ConfirmCrossChainTransfer<'info> {
         mut,
         seeds = [
             CROSS_CHAIN_TRANSFER_SEED,
-            transfer_id.as_bytes(),
+            transfer_id.as_ref(),
         ],
         bump = cross_chain_transfer.bump,
         constraint = cross_chain_transfer.status == TransferStatus::Initiated @ ErrorCode::InvalidTransferStatus,
@@ -31,16 +32,30 @@ pub struct ConfirmCrossChainTransfer<'info> {
 
 pub fn handler(
     ctx: Context<ConfirmCrossChainTransfer>,
-    transfer_id: String,
+    transfer_id: [u8; 32],
 ) -> Result<()> {
     let cross_chain_transfer = &mut ctx.accounts.cross_chain_transfer;
+    let clock = Clock::get()?;
+    
+    // A transfer left unconfirmed past the timeout is stale; the owner cancels it instead
+    require!(
+        !cross_chain_transfer.is_expired(ctx.accounts.global_config.transfer_timeout, clock.unix_timestamp),
+        ErrorCode::TransferTimeout
+    );
     
     // Update transfer status to confirmed
     cross_chain_transfer.status = TransferStatus::Confirmed;
     
+    emit!(TransferConfirmed {
+        transfer_id,
+        nft_mint: cross_chain_transfer.nft_mint,
+        destination_chain: cross_chain_transfer.destination_chain.clone(),
+        destination_recipient: cross_chain_transfer.destination_recipient.clone(),
+    });
+    
     msg!(
         "CrossChainTransferConfirmed: transfer_id={}, mint={}, destination_chain={}, recipient={}",
-        transfer_id,
+        hex::encode(transfer_id),
         cross_chain_transfer.nft_mint,
         cross_chain_transfer.destination_chain,
         cross_chain_transfer.destination_recipient
@@ -0,0 +1,132 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    metadata::Metadata,
+    token::{mint_to, Mint, MintTo, Token, TokenAccount},
+};
+
+use crate::state::*;
+use crate::error::ErrorCode;
+use crate::constants::*;
+use crate::events::CollectionCreated;
+use crate::metaplex::NftMetadataAccounts;
+
+#[derive(Accounts)]
+pub struct CreateCollection<'info> {
+    #[account(
+        mut,
+        seeds = [GLOBAL_CONFIG_SEED],
+        bump = global_config.bump,
+        has_one = authority @ ErrorCode::Unauthorized,
+        constraint = global_config.collection_mint == Pubkey::default() @ ErrorCode::CollectionAlreadyExists,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        mint::decimals = 0,
+        mint::authority = mint_authority,
+        mint::freeze_authority = mint_authority,
+    )]
+    pub collection_mint: Account<'info, Mint>,
+
+    /// CHECK: Program PDA holding the mint authority of every Universal NFT
+    #[account(seeds = [MINT_AUTHORITY_SEED], bump)]
+    pub mint_authority: UncheckedAccount<'info>,
+
+    /// The collection NFT is held by the global config PDA
+    #[account(
+        init,
+        payer = authority,
+        associated_token::mint = collection_mint,
+        associated_token::authority = global_config,
+    )]
+    pub collection_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Metaplex metadata PDA, validated and created by the token metadata program
+    #[account(mut)]
+    pub collection_metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex master edition PDA, validated and created by the token metadata program
+    #[account(mut)]
+    pub collection_master_edition: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_metadata_program: Program<'info, Metadata>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Mint the collection NFT that every Universal NFT is verified against
+pub fn handler(
+    ctx: Context<CreateCollection>,
+    name: String,
+    symbol: String,
+    uri: String,
+) -> Result<()> {
+    require!(!name.is_empty() && name.len() <= MAX_NAME_LEN, ErrorCode::InvalidMetadataUri);
+    require!(!symbol.is_empty() && symbol.len() <= MAX_SYMBOL_LEN, ErrorCode::InvalidMetadataUri);
+    require!(!uri.is_empty() && uri.len() <= MAX_URI_LEN, ErrorCode::InvalidMetadataUri);
+
+    // The mint authority PDA mints; the global config PDA is update and
+    // collection authority
+    let seeds = &[
+        GLOBAL_CONFIG_SEED,
+        &[ctx.accounts.global_config.bump],
+    ];
+    let mint_authority_seeds = &[
+        MINT_AUTHORITY_SEED,
+        &[ctx.bumps.mint_authority],
+    ];
+    let signer = &[&seeds[..], &mint_authority_seeds[..]];
+
+    let nft_metadata = NftMetadataAccounts {
+        token_metadata_program: ctx.accounts.token_metadata_program.to_account_info(),
+        metadata: ctx.accounts.collection_metadata.to_account_info(),
+        master_edition: ctx.accounts.collection_master_edition.to_account_info(),
+        mint: ctx.accounts.collection_mint.to_account_info(),
+        mint_authority: ctx.accounts.mint_authority.to_account_info(),
+        update_authority: ctx.accounts.global_config.to_account_info(),
+        payer: ctx.accounts.authority.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        rent: ctx.accounts.rent.to_account_info(),
+    };
+    nft_metadata.create_collection_metadata(name.clone(), symbol, uri.clone(), signer)?;
+
+    let cpi_accounts = MintTo {
+        mint: ctx.accounts.collection_mint.to_account_info(),
+        to: ctx.accounts.collection_token_account.to_account_info(),
+        authority: ctx.accounts.mint_authority.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer,
+    );
+    mint_to(cpi_ctx, 1)?;
+
+    nft_metadata.create_master_edition(signer)?;
+
+    let global_config = &mut ctx.accounts.global_config;
+    global_config.collection_mint = ctx.accounts.collection_mint.key();
+
+    emit!(CollectionCreated {
+        authority: ctx.accounts.authority.key(),
+        collection_mint: global_config.collection_mint,
+        name,
+        uri,
+    });
+
+    msg!(
+        "Universal NFT collection created: mint={}",
+        global_config.collection_mint
+    );
+
+    Ok(())
+}
@@ -1,15 +1,24 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{burn, Burn, Token, TokenAccount, Mint};
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{Mint, Token, TokenAccount};
 
 use crate::state::*;
 use crate::error::ErrorCode;
 use crate::constants::*;
+use crate::message::{MessageHeader, NftMessage};
+use crate::abi::AbiNftMessage;
+use crate::escrow::lock_in_escrow;
+use crate::events::TransferInitiated;
+use crate::gateway_cpi::{self, GatewayDepositAccounts, RevertOptions};
 
 #[derive(Accounts)]
+#[instruction(destination_chain_id: u64)]
 pub struct DepositAndCall<'info> {
     #[account(
+        mut,
         seeds = [GLOBAL_CONFIG_SEED],
         bump = global_config.bump,
+        constraint = !global_config.outbound_paused @ ErrorCode::ProgramPaused,
     )]
     pub global_config: Account<'info, GlobalConfig>,
     
@@ -26,18 +35,41 @@ pub struct DepositAndCall<'info> {
     )]
     pub universal_nft: Account<'info, UniversalNft>,
     
+    /// Owner's transfer counter; its current value derives the transfer id
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = TransferNonce::INIT_SPACE,
+        seeds = [
+            TRANSFER_NONCE_SEED,
+            owner.key().as_ref(),
+        ],
+        bump
+    )]
+    pub transfer_nonce: Account<'info, TransferNonce>,
+    
     #[account(
         init,
         payer = payer,
         space = CrossChainTransfer::INIT_SPACE,
         seeds = [
             CROSS_CHAIN_TRANSFER_SEED,
-            transfer_id.as_bytes(),
+            &CrossChainTransfer::transfer_id(&nft_mint.key(), &owner.key(), transfer_nonce.nonce),
         ],
         bump
     )]
     pub cross_chain_transfer: Account<'info, CrossChainTransfer>,
     
+    /// CHECK: Registry entry for the destination chain - loaded in the handler
+    #[account(
+        seeds = [
+            CHAIN_CONFIG_SEED,
+            &destination_chain_id.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub chain_config: UncheckedAccount<'info>,
+    
     #[account(mut)]
     pub nft_mint: Account<'info, Mint>,
     
@@ -49,6 +81,15 @@ pub struct DepositAndCall<'info> {
     )]
     pub owner_token_account: Account<'info, TokenAccount>,
     
+    /// Program-owned escrow holding the NFT while it is on another chain
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = nft_mint,
+        associated_token::authority = global_config,
+    )]
+    pub escrow_token_account: Account<'info, TokenAccount>,
+    
     #[account(mut)]
     pub owner: Signer<'info>,
     
@@ -59,96 +100,160 @@ pub struct DepositAndCall<'info> {
     #[account(mut)]
     pub gateway_pda: UncheckedAccount<'info>,
     
-    /// CHECK: Only used for CPI to gateway
+    /// CHECK: Only used for CPI to gateway, must be the configured gateway
+    #[account(address = global_config.zetachain_gateway @ ErrorCode::GatewayNotConfigured)]
     pub gateway_program: UncheckedAccount<'info>,
     
     pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
 
-/// Initiate cross-chain NFT transfer by burning local NFT and calling gateway
+/// Initiate cross-chain NFT transfer by escrowing the local NFT and calling gateway
 pub fn handler(
     ctx: Context<DepositAndCall>,
-    transfer_id: String,
     destination_chain_id: u64,
     destination_recipient: [u8; 20], // Ethereum-style address
-    revert_options: Option<gateway::RevertOptions>,
-    bump: u8,
+    revert_options: Option<RevertOptions>,
 ) -> Result<()> {
-    let global_config = &ctx.accounts.global_config;
+    // Claim the next transfer id from the owner's counter
+    let transfer_nonce = &mut ctx.accounts.transfer_nonce;
+    transfer_nonce.owner = ctx.accounts.owner.key();
+    transfer_nonce.bump = ctx.bumps.transfer_nonce;
+    let nonce = transfer_nonce.nonce;
+    let transfer_id = transfer_nonce.next_transfer_id(&ctx.accounts.nft_mint.key())?;
+    
     let universal_nft = &mut ctx.accounts.universal_nft;
     let cross_chain_transfer = &mut ctx.accounts.cross_chain_transfer;
     let clock = Clock::get()?;
     
-    // Validate destination chain
+    // Only registered and enabled EVM chains can be targeted
+    let chain_config = ChainConfig::load_enabled(&ctx.accounts.chain_config, destination_chain_id)?;
     require!(
-        destination_chain_id > 0 && destination_chain_id != SOLANA_CHAIN_ID,
-        ErrorCode::InvalidChainId
+        chain_config.address_format == AddressFormat::Evm,
+        ErrorCode::InvalidDestinationAddress
     );
     
     // Lock the NFT for cross-chain transfer
     universal_nft.is_locked = true;
     universal_nft.lock_destination_chain = destination_chain_id.to_string();
     universal_nft.lock_recipient = hex::encode(destination_recipient);
+    universal_nft.active_transfer = Some(transfer_id);
     universal_nft.updated_at = clock.unix_timestamp;
     
     // Initialize cross-chain transfer state
-    cross_chain_transfer.transfer_id = transfer_id.clone();
+    cross_chain_transfer.transfer_id = transfer_id;
     cross_chain_transfer.nft_mint = ctx.accounts.nft_mint.key();
     cross_chain_transfer.source_owner = ctx.accounts.owner.key();
+    // Reverted deposits are refunded to the revert address and aborted ones go
+    // to the abort address, each falling back to the owner
+    cross_chain_transfer.refund_address = revert_options
+        .as_ref()
+        .map(|options| options.revert_address)
+        .filter(|address| *address != Pubkey::default())
+        .unwrap_or_else(|| ctx.accounts.owner.key());
+    cross_chain_transfer.abort_address = revert_options
+        .as_ref()
+        .map(|options| options.abort_address)
+        .filter(|address| *address != Pubkey::default())
+        .unwrap_or_else(|| ctx.accounts.owner.key());
+    cross_chain_transfer.payer = ctx.accounts.payer.key();
     cross_chain_transfer.destination_chain = destination_chain_id.to_string();
     cross_chain_transfer.destination_recipient = hex::encode(destination_recipient);
+    cross_chain_transfer.via_gateway = true;
     cross_chain_transfer.status = TransferStatus::Initiated;
     cross_chain_transfer.initiated_at = clock.unix_timestamp;
     cross_chain_transfer.completed_at = None;
-    cross_chain_transfer.bump = bump;
-    
-    // Burn the NFT since it's moving to another chain
-    let cpi_accounts = Burn {
-        mint: ctx.accounts.nft_mint.to_account_info(),
-        from: ctx.accounts.owner_token_account.to_account_info(),
-        authority: ctx.accounts.owner.to_account_info(),
-    };
+    cross_chain_transfer.bump = ctx.bumps.cross_chain_transfer;
     
-    let cpi_program = ctx.accounts.token_program.to_account_info();
-    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-    
-    burn(cpi_ctx, 1)?;
+    // Escrow the NFT rather than burning it: the master edition owns the mint
+    // authority, so the same mint can only be restored from escrow on return
+    lock_in_escrow(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.owner_token_account.to_account_info(),
+        ctx.accounts.escrow_token_account.to_account_info(),
+        ctx.accounts.owner.to_account_info(),
+    )?;
     
     // Prepare cross-chain message data
-    let message_data = format!(
-        "chain:{},token_id:{},uri:{},name:{},symbol:{}",
-        universal_nft.original_chain,
-        universal_nft.original_token_id,
-        universal_nft.metadata_uri,
-        "UniversalNFT", // Default name
-        "UNFT" // Default symbol
-    );
+    let global_config = &mut ctx.accounts.global_config;
+    let message_nonce = global_config.outbound_nonce;
+    global_config.outbound_nonce = message_nonce.checked_add(1).ok_or(ErrorCode::NonceOverflow)?;
     
-    // Call ZetaChain gateway to initiate cross-chain transfer
-    let gateway_program = ctx.accounts.gateway_program.to_account_info();
+    let message = NftMessage {
+        header: MessageHeader {
+            version: MESSAGE_VERSION,
+            message_type: MSG_TYPE_TRANSFER,
+            nonce: message_nonce,
+            source_chain_id: SOLANA_CHAIN_ID,
+            destination_chain_id,
+        },
+        original_chain: universal_nft.original_chain.clone(),
+        original_contract: universal_nft.original_contract.clone(),
+        original_token_id: universal_nft.original_token_id.clone(),
+        uri: universal_nft.metadata_uri.clone(),
+        name: "UniversalNFT".to_string(), // Default name
+        symbol: "UNFT".to_string(), // Default symbol
+        recipient: destination_recipient.to_vec(),
+    };
+    let message_data = match chain_config.message_encoding {
+        MessageEncoding::Borsh => message.encode()?,
+        MessageEncoding::Abi => {
+            AbiNftMessage::from_nft_message(
+                &message,
+                &ctx.accounts.owner.key(),
+                &ctx.accounts.nft_mint.key(),
+                &chain_config,
+            )?
+            .encode()
+        }
+    };
     
-    let cpi_accounts = gateway::cpi::accounts::DepositAndCall {
+    // Call ZetaChain gateway to initiate cross-chain transfer
+    let gateway_accounts = GatewayDepositAccounts {
+        gateway_program: ctx.accounts.gateway_program.to_account_info(),
         signer: ctx.accounts.payer.to_account_info(),
-        pda: ctx.accounts.gateway_pda.to_account_info(),
+        gateway_pda: ctx.accounts.gateway_pda.to_account_info(),
         system_program: ctx.accounts.system_program.to_account_info(),
     };
     
-    let cpi_program = gateway_program;
-    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+    // The gateway hands the revert message back to on_revert and on_abort as
+    // `data`, so it carries the transfer id in place of a caller-chosen message
+    let gateway_revert_options = RevertOptions {
+        revert_address: cross_chain_transfer.refund_address,
+        abort_address: cross_chain_transfer.abort_address,
+        call_on_revert: true,
+        revert_message: transfer_id.to_vec(),
+        on_revert_gas_limit: revert_options
+            .as_ref()
+            .map(|options| options.on_revert_gas_limit)
+            .unwrap_or_default(),
+    };
     
-    gateway::cpi::deposit_and_call(
-        cpi_ctx,
-        global_config.cross_chain_fee,
+    gateway_cpi::deposit_and_call(
+        gateway_accounts,
+        chain_config.cross_chain_fee,
         destination_recipient,
         destination_chain_id,
-        message_data.as_bytes().to_vec(),
-        revert_options,
+        message_data,
+        Some(gateway_revert_options),
     )?;
     
+    emit!(TransferInitiated {
+        transfer_id,
+        nft_mint: ctx.accounts.nft_mint.key(),
+        owner: ctx.accounts.owner.key(),
+        nonce,
+        message_nonce: Some(message_nonce),
+        destination_chain_id,
+        destination_recipient: hex::encode(destination_recipient),
+        fee: chain_config.cross_chain_fee,
+        initiated_at: clock.unix_timestamp,
+    });
+    
     msg!(
         "Cross-chain NFT transfer initiated via gateway: transfer_id={}, mint={}, destination_chain={}, recipient={:?}",
-        transfer_id,
+        hex::encode(transfer_id),
         ctx.accounts.nft_mint.key(),
         destination_chain_id,
         destination_recipient
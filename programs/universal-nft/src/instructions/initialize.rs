@@ -4,6 +4,7 @@ use anchor_lang::system_program;
 use crate::state::*;
 use crate::error::ErrorCode;
 use crate::constants::*;
+use crate::events::ProgramInitialized;
 
 #[derive(Accounts)]
 #[instruction(bump: u8)]
@@ -48,10 +49,27 @@ pub fn handler(
     global_config.authority = ctx.accounts.authority.key();
     global_config.zetachain_gateway = ctx.accounts.zetachain_gateway.key();
     global_config.collection_authority = ctx.accounts.collection_authority.key();
+    global_config.collection_mint = Pubkey::default();
     global_config.fee_recipient = ctx.accounts.fee_recipient.key();
     global_config.cross_chain_fee = cross_chain_fee.unwrap_or(DEFAULT_CROSS_CHAIN_FEE);
+    global_config.outbound_nonce = 0;
+    global_config.transfer_timeout = DEFAULT_TRANSFER_TIMEOUT;
+    global_config.pending_authority = None;
+    global_config.guardian = ctx.accounts.authority.key();
+    global_config.mint_paused = false;
+    global_config.outbound_paused = false;
+    global_config.inbound_paused = false;
     global_config.bump = bump;
     
+    emit!(ProgramInitialized {
+        authority: global_config.authority,
+        zetachain_gateway: global_config.zetachain_gateway,
+        collection_authority: global_config.collection_authority,
+        fee_recipient: global_config.fee_recipient,
+        cross_chain_fee: global_config.cross_chain_fee,
+        transfer_timeout: global_config.transfer_timeout,
+    });
+    
     msg!(
         "Universal NFT program initialized with authority: {}, gateway: {}",
         global_config.authority,
@@ -1,16 +1,20 @@
 use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
 use anchor_spl::token::{Token, TokenAccount, Mint};
 
 use crate::state::*;
 use crate::error::ErrorCode;
 use crate::constants::*;
+use crate::escrow::lock_in_escrow;
+use crate::events::TransferInitiated;
 
 #[derive(Accounts)]
-#[instruction(transfer_id: String, destination_chain: String, destination_recipient: String)]
+#[instruction(destination_chain_id: u64)]
 pub struct InitiateCrossChainTransfer<'info> {
     #[account(
         seeds = [GLOBAL_CONFIG_SEED],
         bump = global_config.bump,
+        constraint = !global_config.outbound_paused @ ErrorCode::ProgramPaused,
     )]
     pub global_config: Account<'info, GlobalConfig>,
     
@@ -27,18 +31,41 @@ pub struct InitiateCrossChainTransfer<'info> {
     )]
     pub universal_nft: Account<'info, UniversalNft>,
     
+    /// Owner's transfer counter; its current value derives the transfer id
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = TransferNonce::INIT_SPACE,
+        seeds = [
+            TRANSFER_NONCE_SEED,
+            owner.key().as_ref(),
+        ],
+        bump
+    )]
+    pub transfer_nonce: Account<'info, TransferNonce>,
+    
     #[account(
         init,
         payer = payer,
         space = CrossChainTransfer::INIT_SPACE,
         seeds = [
             CROSS_CHAIN_TRANSFER_SEED,
-            transfer_id.as_bytes(),
+            &CrossChainTransfer::transfer_id(&nft_mint.key(), &owner.key(), transfer_nonce.nonce),
         ],
         bump
     )]
     pub cross_chain_transfer: Account<'info, CrossChainTransfer>,
     
+    /// CHECK: Registry entry for the destination chain - loaded in the handler
+    #[account(
+        seeds = [
+            CHAIN_CONFIG_SEED,
+            &destination_chain_id.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub chain_config: UncheckedAccount<'info>,
+    
     pub nft_mint: Account<'info, Mint>,
     
     #[account(
@@ -49,6 +76,15 @@ pub struct InitiateCrossChainTransfer<'info> {
     )]
     pub owner_token_account: Account<'info, TokenAccount>,
     
+    /// Program-owned escrow holding the NFT while it is locked
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = nft_mint,
+        associated_token::authority = global_config,
+    )]
+    pub escrow_token_account: Account<'info, TokenAccount>,
+    
     #[account(mut)]
     pub owner: Signer<'info>,
     
@@ -58,27 +94,43 @@ pub struct InitiateCrossChainTransfer<'info> {
     /// CHECK: This will be validated by ZetaChain gateway
     pub zetachain_gateway: AccountInfo<'info>,
     
+    /// CHECK: Receives the cross-chain fee
+    #[account(mut, address = global_config.fee_recipient @ ErrorCode::Unauthorized)]
+    pub fee_recipient: AccountInfo<'info>,
+    
     pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
 
 pub fn handler(
     ctx: Context<InitiateCrossChainTransfer>,
-    transfer_id: String,
-    destination_chain: String,
+    destination_chain_id: u64,
     destination_recipient: String,
-    bump: u8,
 ) -> Result<()> {
+    // Claim the next transfer id from the owner's counter
+    let transfer_nonce = &mut ctx.accounts.transfer_nonce;
+    transfer_nonce.owner = ctx.accounts.owner.key();
+    transfer_nonce.bump = ctx.bumps.transfer_nonce;
+    let nonce = transfer_nonce.nonce;
+    let transfer_id = transfer_nonce.next_transfer_id(&ctx.accounts.nft_mint.key())?;
+    
     let global_config = &ctx.accounts.global_config;
     let universal_nft = &mut ctx.accounts.universal_nft;
     let cross_chain_transfer = &mut ctx.accounts.cross_chain_transfer;
     let clock = Clock::get()?;
     
     // Validate input parameters
-    require!(!transfer_id.is_empty() && transfer_id.len() <= 32, ErrorCode::InvalidDestinationAddress);
-    require!(!destination_chain.is_empty() && destination_chain.len() <= 32, ErrorCode::InvalidChainId);
     require!(!destination_recipient.is_empty() && destination_recipient.len() <= 64, ErrorCode::InvalidDestinationAddress);
     
+    // Only registered and enabled chains can be targeted
+    let chain_config = ChainConfig::load_enabled(&ctx.accounts.chain_config, destination_chain_id)?;
+    require!(
+        chain_config.address_format.is_valid_recipient(&destination_recipient),
+        ErrorCode::InvalidDestinationAddress
+    );
+    let destination_chain = destination_chain_id.to_string();
+    
     // Validate ZetaChain gateway
     require!(
         ctx.accounts.zetachain_gateway.key() == global_config.zetachain_gateway,
@@ -87,48 +139,72 @@ pub fn handler(
     
     // Check if payer has enough funds for cross-chain fee
     require!(
-        ctx.accounts.payer.lamports() >= global_config.cross_chain_fee,
+        ctx.accounts.payer.lamports() >= chain_config.cross_chain_fee,
         ErrorCode::InsufficientFunds
     );
     
-    // Lock the NFT for cross-chain transfer
+    // Lock the NFT for cross-chain transfer; the token itself moves into escrow
+    // so it cannot be sold or moved while the transfer is in flight
+    lock_in_escrow(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.owner_token_account.to_account_info(),
+        ctx.accounts.escrow_token_account.to_account_info(),
+        ctx.accounts.owner.to_account_info(),
+    )?;
     universal_nft.is_locked = true;
     universal_nft.lock_destination_chain = destination_chain.clone();
     universal_nft.lock_recipient = destination_recipient.clone();
+    universal_nft.active_transfer = Some(transfer_id);
     universal_nft.updated_at = clock.unix_timestamp;
     
     // Initialize cross-chain transfer state
-    cross_chain_transfer.transfer_id = transfer_id.clone();
+    cross_chain_transfer.transfer_id = transfer_id;
     cross_chain_transfer.nft_mint = ctx.accounts.nft_mint.key();
     cross_chain_transfer.source_owner = ctx.accounts.owner.key();
+    cross_chain_transfer.refund_address = ctx.accounts.owner.key();
+    cross_chain_transfer.abort_address = ctx.accounts.owner.key();
+    cross_chain_transfer.payer = ctx.accounts.payer.key();
     cross_chain_transfer.destination_chain = destination_chain.clone();
     cross_chain_transfer.destination_recipient = destination_recipient.clone();
+    cross_chain_transfer.via_gateway = false;
     cross_chain_transfer.status = TransferStatus::Initiated;
     cross_chain_transfer.initiated_at = clock.unix_timestamp;
     cross_chain_transfer.completed_at = None;
-    cross_chain_transfer.bump = bump;
+    cross_chain_transfer.bump = ctx.bumps.cross_chain_transfer;
     
     // Transfer fee to fee recipient
     let ix = anchor_lang::solana_program::system_instruction::transfer(
         &ctx.accounts.payer.key(),
         &global_config.fee_recipient,
-        global_config.cross_chain_fee,
+        chain_config.cross_chain_fee,
     );
     
     anchor_lang::solana_program::program::invoke(
         &ix,
         &[
             ctx.accounts.payer.to_account_info(),
-            ctx.accounts.zetachain_gateway.to_account_info(),
+            ctx.accounts.fee_recipient.to_account_info(),
             ctx.accounts.system_program.to_account_info(),
         ],
     )?;
     
+    emit!(TransferInitiated {
+        transfer_id,
+        nft_mint: ctx.accounts.nft_mint.key(),
+        owner: ctx.accounts.owner.key(),
+        nonce,
+        message_nonce: None,
+        destination_chain_id,
+        destination_recipient: destination_recipient.clone(),
+        fee: chain_config.cross_chain_fee,
+        initiated_at: clock.unix_timestamp,
+    });
+    
     // Emit cross-chain message to ZetaChain
     // Note: In a real implementation, this would interact with ZetaChain's messaging protocol
     msg!(
         "CrossChainTransferInitiated: transfer_id={}, mint={}, destination_chain={}, recipient={}, original_chain={}, original_token_id={}",
-        transfer_id,
+        hex::encode(transfer_id),
         ctx.accounts.nft_mint.key(),
         destination_chain,
         destination_recipient,
@@ -1,20 +1,32 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
+    metadata::Metadata,
     token::{mint_to, Mint, MintTo, Token, TokenAccount},
 };
 
 use crate::state::*;
 use crate::error::ErrorCode;
 use crate::constants::*;
+use crate::events::NftMinted;
+use crate::metaplex::{CollectionAccounts, NftMetadataAccounts};
 
 #[derive(Accounts)]
-#[instruction(bump: u8, original_chain: String, original_contract: String, original_token_id: String)]
+#[instruction(
+    bump: u8,
+    name: String,
+    symbol: String,
+    uri: String,
+    original_chain: String,
+    original_contract: String,
+    original_token_id: String,
+)]
 pub struct MintNft<'info> {
     #[account(
         seeds = [GLOBAL_CONFIG_SEED],
         bump = global_config.bump,
-        constraint = global_config.collection_authority == collection_authority.key() @ ErrorCode::Unauthorized
+        constraint = global_config.collection_authority == collection_authority.key() @ ErrorCode::Unauthorized,
+        constraint = !global_config.mint_paused @ ErrorCode::ProgramPaused,
     )]
     pub global_config: Account<'info, GlobalConfig>,
     
@@ -30,12 +42,25 @@ pub struct MintNft<'info> {
     )]
     pub universal_nft: Account<'info, UniversalNft>,
     
+    /// One Solana mint per origin: fails if the token was already minted
+    #[account(
+        init,
+        payer = payer,
+        space = OriginIndex::INIT_SPACE,
+        seeds = [
+            ORIGIN_INDEX_SEED,
+            &OriginIndex::origin_hash(&original_chain, &original_contract, &original_token_id),
+        ],
+        bump
+    )]
+    pub origin_index: Account<'info, OriginIndex>,
+    
     #[account(
         init,
         payer = payer,
         mint::decimals = 0,
-        mint::authority = collection_authority,
-        mint::freeze_authority = collection_authority,
+        mint::authority = mint_authority,
+        mint::freeze_authority = mint_authority,
     )]
     pub mint: Account<'info, Mint>,
     
@@ -47,7 +72,27 @@ pub struct MintNft<'info> {
     )]
     pub token_account: Account<'info, TokenAccount>,
     
-    // Metadata will be added in future iterations
+    /// CHECK: Program PDA holding the mint authority of every Universal NFT
+    #[account(seeds = [MINT_AUTHORITY_SEED], bump)]
+    pub mint_authority: UncheckedAccount<'info>,
+    
+    /// CHECK: Metaplex metadata PDA, validated and created by the token metadata program
+    #[account(mut)]
+    pub metadata: UncheckedAccount<'info>,
+    
+    /// CHECK: Metaplex master edition PDA, validated and created by the token metadata program
+    #[account(mut)]
+    pub master_edition: UncheckedAccount<'info>,
+    
+    #[account(address = global_config.collection_mint @ ErrorCode::InvalidCollection)]
+    pub collection_mint: Account<'info, Mint>,
+    
+    /// CHECK: Collection metadata PDA, validated by the token metadata program
+    #[account(mut)]
+    pub collection_metadata: UncheckedAccount<'info>,
+    
+    /// CHECK: Collection master edition PDA, validated by the token metadata program
+    pub collection_master_edition: UncheckedAccount<'info>,
     
     #[account(mut)]
     pub payer: Signer<'info>,
@@ -56,12 +101,14 @@ pub struct MintNft<'info> {
     /// CHECK: This can be any valid Solana address
     pub recipient: AccountInfo<'info>,
     
-    /// Collection authority (must match global config)
+    /// Collection authority (must match global config); authorizes the mint
     pub collection_authority: Signer<'info>,
     
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_metadata_program: Program<'info, Metadata>,
     pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
 }
 
 pub fn handler(
@@ -74,19 +121,24 @@ pub fn handler(
     original_contract: String,
     original_token_id: String,
 ) -> Result<()> {
-    let global_config = &ctx.accounts.global_config;
     let mint = &ctx.accounts.mint;
     let universal_nft = &mut ctx.accounts.universal_nft;
     let clock = Clock::get()?;
     
     // Validate input parameters
-    require!(!name.is_empty() && name.len() <= 32, ErrorCode::InvalidMetadataUri);
-    require!(!symbol.is_empty() && symbol.len() <= 10, ErrorCode::InvalidMetadataUri);
-    require!(!uri.is_empty() && uri.len() <= 200, ErrorCode::InvalidMetadataUri);
+    require!(!name.is_empty() && name.len() <= MAX_NAME_LEN, ErrorCode::InvalidMetadataUri);
+    require!(!symbol.is_empty() && symbol.len() <= MAX_SYMBOL_LEN, ErrorCode::InvalidMetadataUri);
+    require!(!uri.is_empty() && uri.len() <= MAX_URI_LEN, ErrorCode::InvalidMetadataUri);
     require!(!original_chain.is_empty() && original_chain.len() <= 32, ErrorCode::InvalidOriginalChain);
     require!(!original_contract.is_empty() && original_contract.len() <= 64, ErrorCode::InvalidOriginalChain);
     require!(!original_token_id.is_empty() && original_token_id.len() <= 32, ErrorCode::InvalidOriginalChain);
     
+    // Point the origin at this mint
+    let origin_index = &mut ctx.accounts.origin_index;
+    origin_index.origin_hash = OriginIndex::origin_hash(&original_chain, &original_contract, &original_token_id);
+    origin_index.mint = mint.key();
+    origin_index.bump = ctx.bumps.origin_index;
+    
     // Initialize universal NFT state
     universal_nft.mint = mint.key();
     universal_nft.owner = ctx.accounts.recipient.key();
@@ -97,25 +149,69 @@ pub fn handler(
     universal_nft.is_locked = false;
     universal_nft.lock_destination_chain = String::new();
     universal_nft.lock_recipient = String::new();
+    universal_nft.active_transfer = None;
     universal_nft.created_at = clock.unix_timestamp;
     universal_nft.updated_at = clock.unix_timestamp;
     universal_nft.bump = bump;
     
-    // Store metadata in our program state (metadata can be added later via separate instruction)
-    // For now, we store the metadata URI in the universal_nft account
+    // The mint authority PDA mints; the global config PDA is the update authority
+    let seeds = &[
+        GLOBAL_CONFIG_SEED,
+        &[ctx.accounts.global_config.bump],
+    ];
+    let mint_authority_seeds = &[
+        MINT_AUTHORITY_SEED,
+        &[ctx.bumps.mint_authority],
+    ];
+    let signer = &[&seeds[..], &mint_authority_seeds[..]];
+    
+    let nft_metadata = NftMetadataAccounts {
+        token_metadata_program: ctx.accounts.token_metadata_program.to_account_info(),
+        metadata: ctx.accounts.metadata.to_account_info(),
+        master_edition: ctx.accounts.master_edition.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        mint_authority: ctx.accounts.mint_authority.to_account_info(),
+        update_authority: ctx.accounts.global_config.to_account_info(),
+        payer: ctx.accounts.payer.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        rent: ctx.accounts.rent.to_account_info(),
+    };
+    nft_metadata.create_metadata(name, symbol, uri, Some(ctx.accounts.collection_mint.key()), signer)?;
     
     // Mint the NFT to the recipient
     let cpi_accounts = MintTo {
         mint: ctx.accounts.mint.to_account_info(),
         to: ctx.accounts.token_account.to_account_info(),
-        authority: ctx.accounts.collection_authority.to_account_info(),
+        authority: ctx.accounts.mint_authority.to_account_info(),
     };
     
     let cpi_program = ctx.accounts.token_program.to_account_info();
-    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
     
     mint_to(cpi_ctx, 1)?;
     
+    // Metaplex takes the mint authority, locking the supply at one; then prove
+    // collection membership
+    nft_metadata.create_master_edition(signer)?;
+    nft_metadata.verify_collection(
+        &CollectionAccounts {
+            collection_mint: ctx.accounts.collection_mint.to_account_info(),
+            collection_metadata: ctx.accounts.collection_metadata.to_account_info(),
+            collection_master_edition: ctx.accounts.collection_master_edition.to_account_info(),
+        },
+        signer,
+    )?;
+    
+    emit!(NftMinted {
+        mint: mint.key(),
+        recipient: ctx.accounts.recipient.key(),
+        original_chain: universal_nft.original_chain.clone(),
+        original_contract: universal_nft.original_contract.clone(),
+        original_token_id: universal_nft.original_token_id.clone(),
+        metadata_uri: universal_nft.metadata_uri.clone(),
+    });
+    
     msg!(
         "Universal NFT minted: mint={}, recipient={}, original_chain={}, original_token_id={}",
         mint.key(),
@@ -1,17 +1,41 @@
 pub mod initialize;
+pub mod update_config;
+pub mod propose_authority;
+pub mod accept_authority;
+pub mod cancel_authority_proposal;
+pub mod set_pause;
+pub mod register_chain;
+pub mod update_chain;
+pub mod create_collection;
 pub mod mint_nft;
 pub mod initiate_cross_chain_transfer;
 pub mod confirm_cross_chain_transfer;
 pub mod complete_cross_chain_transfer;
+pub mod cancel_cross_chain_transfer;
+pub mod close_transfer;
 pub mod on_call;
 pub mod on_revert;
+pub mod on_abort;
+pub mod reclaim_aborted_nft;
 pub mod deposit_and_call;
 
 pub use initialize::*;
+pub use update_config::*;
+pub use propose_authority::*;
+pub use accept_authority::*;
+pub use cancel_authority_proposal::*;
+pub use set_pause::*;
+pub use register_chain::*;
+pub use update_chain::*;
+pub use create_collection::*;
 pub use mint_nft::*;
 pub use initiate_cross_chain_transfer::*;
 pub use confirm_cross_chain_transfer::*;
 pub use complete_cross_chain_transfer::*;
+pub use cancel_cross_chain_transfer::*;
+pub use close_transfer::*;
 pub use on_call::*;
 pub use on_revert::*;
+pub use on_abort::*;
+pub use reclaim_aborted_nft::*;
 pub use deposit_and_call::*;
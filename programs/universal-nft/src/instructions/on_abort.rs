@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{sysvar, sysvar::instructions::get_instruction_relative};
+use anchor_lang::system_program::{transfer, Transfer};
+
+use crate::state::*;
+use crate::error::ErrorCode;
+use crate::events::TransferAborted;
+
+#[derive(Accounts)]
+#[instruction(amount: u64, sender: Pubkey, data: Vec<u8>)]
+pub struct OnAbort<'info> {
+    #[account(
+        seeds = [GLOBAL_CONFIG_SEED],
+        bump = global_config.bump,
+        constraint = !global_config.inbound_paused @ ErrorCode::ProgramPaused,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            CROSS_CHAIN_TRANSFER_SEED,
+            cross_chain_transfer.transfer_id.as_ref(),
+        ],
+        bump = cross_chain_transfer.bump,
+        // deposit_and_call sends the transfer id as the revert message
+        constraint = cross_chain_transfer.transfer_id.as_slice() == data.as_slice() @ ErrorCode::TransferNotFound,
+        constraint = matches!(
+            cross_chain_transfer.status,
+            TransferStatus::Initiated | TransferStatus::Confirmed
+        ) @ ErrorCode::InvalidTransferStatus,
+    )]
+    pub cross_chain_transfer: Account<'info, CrossChainTransfer>,
+
+    /// CHECK: Program PDA the gateway credits aborted deposits to
+    #[account(mut, seeds = [CONNECTED_SEED], bump)]
+    pub connected_pda: UncheckedAccount<'info>,
+
+    /// CHECK: Receives the aborted deposit, checked against the transfer record
+    #[account(mut, address = cross_chain_transfer.abort_address @ ErrorCode::Unauthorized)]
+    pub abort_recipient: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: Instruction sysvar for gateway validation
+    #[account(address = sysvar::instructions::id())]
+    pub instruction_sysvar_account: UncheckedAccount<'info>,
+}
+
+/// Called by ZetaChain gateway when a cross-chain NFT transfer could not be
+/// reverted either. The NFT stays in escrow until the owner or the abort
+/// address reclaims it with `reclaim_aborted_nft`.
+pub fn handler(
+    ctx: Context<OnAbort>,
+    amount: u64,
+    _sender: Pubkey,
+    _data: Vec<u8>,
+) -> Result<()> {
+    // Validate that caller is the ZetaChain gateway
+    let current_ix = get_instruction_relative(
+        0,
+        &ctx.accounts.instruction_sysvar_account.to_account_info(),
+    )
+    .map_err(|_| error!(ErrorCode::Unauthorized))?;
+
+    require!(
+        current_ix.program_id == ctx.accounts.global_config.zetachain_gateway,
+        ErrorCode::Unauthorized
+    );
+
+    // Forward the aborted deposit to the abort address
+    if amount > 0 {
+        let connected_seeds = &[
+            CONNECTED_SEED,
+            &[ctx.bumps.connected_pda],
+        ];
+        let signer = &[&connected_seeds[..]];
+
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.connected_pda.to_account_info(),
+            to: ctx.accounts.abort_recipient.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.system_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+
+        transfer(cpi_ctx, amount)?;
+    }
+
+    // completed_at stays unset until the NFT is reclaimed
+    let cross_chain_transfer = &mut ctx.accounts.cross_chain_transfer;
+    cross_chain_transfer.status = TransferStatus::Aborted;
+
+    emit!(TransferAborted {
+        transfer_id: cross_chain_transfer.transfer_id,
+        nft_mint: cross_chain_transfer.nft_mint,
+        abort_address: cross_chain_transfer.abort_address,
+        refunded: amount,
+    });
+
+    msg!(
+        "Cross-chain NFT transfer aborted: transfer_id={}, mint={}, abort_address={}",
+        hex::encode(cross_chain_transfer.transfer_id),
+        cross_chain_transfer.nft_mint,
+        cross_chain_transfer.abort_address
+    );
+
+    Ok(())
+}
@@ -1,16 +1,25 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::{sysvar, sysvar::instructions::get_instruction_relative};
+use anchor_lang::system_program::{create_account, CreateAccount};
+use anchor_spl::metadata::Metadata;
 use anchor_spl::token::{mint_to, Mint, MintTo, Token, TokenAccount};
 
 use crate::state::*;
 use crate::error::ErrorCode;
 use crate::constants::*;
+use crate::message::NftMessage;
+use crate::abi::AbiNftMessage;
+use crate::escrow::release_from_escrow;
+use crate::events::{NftMetadataUpdated, NftReceived, NftReleased, TransferCompleted};
+use crate::metaplex::{update_metadata_uri, CollectionAccounts, NftMetadataAccounts};
 
 #[derive(Accounts)]
+#[instruction(amount: u64, sender: [u8; 20], data: Vec<u8>)]
 pub struct OnCall<'info> {
     #[account(
         seeds = [GLOBAL_CONFIG_SEED],
         bump = global_config.bump,
+        constraint = !global_config.inbound_paused @ ErrorCode::ProgramPaused,
     )]
     pub global_config: Account<'info, GlobalConfig>,
     
@@ -26,11 +35,81 @@ pub struct OnCall<'info> {
     )]
     pub universal_nft: Account<'info, UniversalNft>,
     
+    /// Registry entry for the chain the message originates from
+    #[account(
+        seeds = [
+            CHAIN_CONFIG_SEED,
+            &source_chain_config.chain_id.to_le_bytes(),
+        ],
+        bump = source_chain_config.bump,
+        constraint = source_chain_config.enabled @ ErrorCode::InvalidChainId,
+    )]
+    pub source_chain_config: Account<'info, ChainConfig>,
+    
+    /// Replay guard: exists once the message has been processed
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = MessageReceipt::INIT_SPACE,
+        seeds = [
+            MESSAGE_RECEIPT_SEED,
+            &source_chain_config.chain_id.to_le_bytes(),
+            &MessageReceipt::message_hash(&sender, &data),
+        ],
+        bump
+    )]
+    pub message_receipt: Account<'info, MessageReceipt>,
+    
     #[account(mut)]
     pub mint: Account<'info, Mint>,
     
+    /// CHECK: Origin index PDA, required for mint messages. The origin is only
+    /// known once `data` is decoded, so the handler derives and creates it.
+    #[account(mut)]
+    pub origin_index: Option<UncheckedAccount<'info>>,
+    
+    /// Recipient token account, required for mint messages
+    #[account(mut)]
+    pub token_account: Option<Account<'info, TokenAccount>>,
+    
+    /// Program escrow holding an NFT that left Solana, required when it returns
+    #[account(mut)]
+    pub escrow_token_account: Option<Account<'info, TokenAccount>>,
+    
+    /// Outbound transfer being acknowledged, required for burn acknowledgements
+    /// and for returns of an NFT locked by a transfer
+    #[account(
+        mut,
+        seeds = [
+            CROSS_CHAIN_TRANSFER_SEED,
+            cross_chain_transfer.transfer_id.as_ref(),
+        ],
+        bump = cross_chain_transfer.bump,
+    )]
+    pub cross_chain_transfer: Option<Account<'info, CrossChainTransfer>>,
+    
+    /// CHECK: Program PDA holding the mint authority of every Universal NFT
+    #[account(seeds = [MINT_AUTHORITY_SEED], bump)]
+    pub mint_authority: UncheckedAccount<'info>,
+    
+    /// CHECK: Metaplex metadata PDA, required for mint and metadata update messages
+    #[account(mut)]
+    pub metadata: Option<UncheckedAccount<'info>>,
+    
+    /// CHECK: Metaplex master edition PDA, required for mint messages
+    #[account(mut)]
+    pub master_edition: Option<UncheckedAccount<'info>>,
+    
+    /// Universal NFT collection, required for mint messages
+    #[account(address = global_config.collection_mint @ ErrorCode::InvalidCollection)]
+    pub collection_mint: Option<Account<'info, Mint>>,
+    
+    /// CHECK: Collection metadata PDA, validated by the token metadata program
     #[account(mut)]
-    pub token_account: Account<'info, TokenAccount>,
+    pub collection_metadata: Option<UncheckedAccount<'info>>,
+    
+    /// CHECK: Collection master edition PDA, validated by the token metadata program
+    pub collection_master_edition: Option<UncheckedAccount<'info>>,
     
     /// CHECK: This is the ZetaChain gateway PDA
     pub gateway_pda: UncheckedAccount<'info>,
@@ -42,7 +121,9 @@ pub struct OnCall<'info> {
     pub recipient: AccountInfo<'info>,
     
     pub token_program: Program<'info, Token>,
+    pub token_metadata_program: Option<Program<'info, Metadata>>,
     pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
     
     /// CHECK: Instruction sysvar for gateway validation
     #[account(address = sysvar::instructions::id())]
@@ -62,7 +143,7 @@ pub fn handler(
         0,
         &ctx.accounts.instruction_sysvar_account.to_account_info(),
     )
-    .unwrap();
+    .map_err(|_| error!(ErrorCode::Unauthorized))?;
     
     msg!(
         "on_call invoked by: {}, expected gateway: {}",
@@ -75,44 +156,220 @@ pub fn handler(
         ErrorCode::Unauthorized
     );
     
-    // Parse the cross-chain NFT data
-    let nft_data = parse_cross_chain_nft_data(&data)?;
+    // Only the registered Universal NFT contract of the source chain may mint
+    let source_chain_config = &ctx.accounts.source_chain_config;
+    require!(
+        source_chain_config.address_format == AddressFormat::Evm
+            && source_chain_config.connected_contract.as_slice() == sender.as_slice(),
+        ErrorCode::UnknownSender
+    );
+    
+    // Each message may only be processed once
+    let message_receipt = &mut ctx.accounts.message_receipt;
+    require!(!message_receipt.processed, ErrorCode::MessageAlreadyProcessed);
+    
+    // Decode the cross-chain NFT message and check it was routed to us
+    let message = match source_chain_config.message_encoding {
+        MessageEncoding::Borsh => NftMessage::decode(&data)?,
+        MessageEncoding::Abi => {
+            // An NFT that left Solana over ABI comes back under its mint address
+            let universal_nft = &ctx.accounts.universal_nft;
+            let solana_nft = (universal_nft.mint == ctx.accounts.mint.key()).then_some(&**universal_nft);
+            AbiNftMessage::decode(&data)?.into_nft_message(source_chain_config, solana_nft)?
+        }
+    };
+    require!(
+        message.header.source_chain_id == source_chain_config.chain_id
+            && message.header.destination_chain_id == SOLANA_CHAIN_ID,
+        ErrorCode::InvalidChainId
+    );
+    require!(
+        message.recipient.as_slice() == ctx.accounts.recipient.key().as_ref(),
+        ErrorCode::InvalidDestinationAddress
+    );
     
-    let universal_nft = &mut ctx.accounts.universal_nft;
     let clock = Clock::get()?;
+    message_receipt.source_chain_id = source_chain_config.chain_id;
+    message_receipt.message_hash = MessageReceipt::message_hash(&sender, &data);
+    message_receipt.mint = ctx.accounts.mint.key();
+    message_receipt.processed = true;
+    message_receipt.processed_at = clock.unix_timestamp;
+    message_receipt.bump = ctx.bumps.message_receipt;
+    
+    // Fall back to the sending contract when the payload carries no origin contract
+    let original_contract = if message.original_contract.is_empty() {
+        hex::encode(sender)
+    } else {
+        message.original_contract.clone()
+    };
+    let origin_hash = OriginIndex::origin_hash(
+        &message.original_chain,
+        &original_contract,
+        &message.original_token_id,
+    );
+    
+    // Route on the message type
+    match message.header.message_type {
+        MSG_TYPE_MINT | MSG_TYPE_TRANSFER => {
+            let origin_index = ctx.accounts.origin_index.as_ref().ok_or(ErrorCode::MissingAccount)?;
+            
+            // A known origin already has a Solana mint: the NFT is coming home
+            match OriginIndex::lookup(origin_index, &origin_hash)? {
+                Some(canonical_mint) => process_return(ctx, canonical_mint, clock.unix_timestamp),
+                None => process_mint(
+                    ctx,
+                    sender,
+                    message,
+                    original_contract,
+                    origin_hash,
+                    bump,
+                    clock.unix_timestamp,
+                ),
+            }
+        }
+        MSG_TYPE_UNLOCK => process_unlock(ctx, origin_hash, clock.unix_timestamp),
+        MSG_TYPE_UPDATE_METADATA => {
+            process_update_metadata(ctx, message, origin_hash, clock.unix_timestamp)
+        }
+        MSG_TYPE_BURN => process_burn_ack(ctx, origin_hash, clock.unix_timestamp),
+        _ => err!(ErrorCode::UnsupportedMessageType),
+    }
+}
+
+/// First arrival of an NFT on Solana: record it and mint to the recipient
+fn process_mint(
+    ctx: Context<OnCall>,
+    sender: [u8; 20],
+    message: NftMessage,
+    original_contract: String,
+    origin_hash: [u8; 32],
+    bump: u8,
+    now: i64,
+) -> Result<()> {
+    let token_account = ctx.accounts.token_account.as_ref().ok_or(ErrorCode::MissingAccount)?;
+    let metadata = ctx.accounts.metadata.as_ref().ok_or(ErrorCode::MissingAccount)?;
+    let master_edition = ctx.accounts.master_edition.as_ref().ok_or(ErrorCode::MissingAccount)?;
+    let token_metadata_program = ctx
+        .accounts
+        .token_metadata_program
+        .as_ref()
+        .ok_or(ErrorCode::MissingAccount)?;
+    let collection = CollectionAccounts {
+        collection_mint: ctx
+            .accounts
+            .collection_mint
+            .as_ref()
+            .ok_or(ErrorCode::InvalidCollection)?
+            .to_account_info(),
+        collection_metadata: ctx
+            .accounts
+            .collection_metadata
+            .as_ref()
+            .ok_or(ErrorCode::InvalidCollection)?
+            .to_account_info(),
+        collection_master_edition: ctx
+            .accounts
+            .collection_master_edition
+            .as_ref()
+            .ok_or(ErrorCode::InvalidCollection)?
+            .to_account_info(),
+    };
+    require!(
+        token_account.mint == ctx.accounts.mint.key()
+            && token_account.owner == ctx.accounts.recipient.key(),
+        ErrorCode::InvalidDestinationAddress
+    );
     
-    // Initialize or update the universal NFT with cross-chain data
+    // The mint must be a fresh NFT mint controlled by the mint authority PDA
+    let mint_authority_key = ctx.accounts.mint_authority.key();
+    let mint = &ctx.accounts.mint;
+    require!(
+        mint.supply == 0
+            && mint.decimals == 0
+            && mint.mint_authority == Some(mint_authority_key).into()
+            && mint.freeze_authority == Some(mint_authority_key).into(),
+        ErrorCode::MintFailed
+    );
+    
+    // A freshly created record has a default mint
+    require!(ctx.accounts.universal_nft.mint == Pubkey::default(), ErrorCode::NftAlreadyExists);
+    
+    // One Solana mint per origin
+    init_origin_index(&ctx, origin_hash)?;
+    
+    let universal_nft = &mut ctx.accounts.universal_nft;
     universal_nft.mint = ctx.accounts.mint.key();
     universal_nft.owner = ctx.accounts.recipient.key();
-    universal_nft.original_chain = nft_data.original_chain;
-    universal_nft.original_contract = hex::encode(sender); // Convert sender to hex string
-    universal_nft.original_token_id = nft_data.token_id;
-    universal_nft.metadata_uri = nft_data.metadata_uri;
+    universal_nft.original_chain = message.original_chain;
+    universal_nft.original_contract = original_contract;
+    universal_nft.original_token_id = message.original_token_id;
+    universal_nft.metadata_uri = message.uri.clone();
     universal_nft.is_locked = false;
     universal_nft.lock_destination_chain = String::new();
     universal_nft.lock_recipient = String::new();
-    universal_nft.created_at = clock.unix_timestamp;
-    universal_nft.updated_at = clock.unix_timestamp;
+    universal_nft.active_transfer = None;
+    universal_nft.created_at = now;
+    universal_nft.updated_at = now;
     universal_nft.bump = bump;
     
-    // Mint the NFT to the recipient
-    let cpi_accounts = MintTo {
-        mint: ctx.accounts.mint.to_account_info(),
-        to: ctx.accounts.token_account.to_account_info(),
-        authority: ctx.accounts.global_config.to_account_info(),
-    };
-    
+    // The mint authority PDA mints; the global config PDA is the update authority
     let seeds = &[
         GLOBAL_CONFIG_SEED,
         &[ctx.accounts.global_config.bump],
     ];
-    let signer = &[&seeds[..]];
+    let mint_authority_seeds = &[
+        MINT_AUTHORITY_SEED,
+        &[ctx.bumps.mint_authority],
+    ];
+    let signer = &[&seeds[..], &mint_authority_seeds[..]];
+    
+    let nft_metadata = NftMetadataAccounts {
+        token_metadata_program: token_metadata_program.to_account_info(),
+        metadata: metadata.to_account_info(),
+        master_edition: master_edition.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        mint_authority: ctx.accounts.mint_authority.to_account_info(),
+        update_authority: ctx.accounts.global_config.to_account_info(),
+        payer: ctx.accounts.payer.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        rent: ctx.accounts.rent.to_account_info(),
+    };
+    nft_metadata.create_metadata(
+        message.name,
+        message.symbol,
+        message.uri,
+        Some(collection.collection_mint.key()),
+        signer,
+    )?;
+    
+    // Mint the NFT to the recipient
+    let cpi_accounts = MintTo {
+        mint: ctx.accounts.mint.to_account_info(),
+        to: token_account.to_account_info(),
+        authority: ctx.accounts.mint_authority.to_account_info(),
+    };
     
     let cpi_program = ctx.accounts.token_program.to_account_info();
     let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
     
     mint_to(cpi_ctx, 1)?;
     
+    // Metaplex takes the mint authority, locking the supply at one; then prove
+    // collection membership
+    nft_metadata.create_master_edition(signer)?;
+    nft_metadata.verify_collection(&collection, signer)?;
+    
+    emit!(NftReceived {
+        mint: ctx.accounts.mint.key(),
+        source_chain_id: ctx.accounts.source_chain_config.chain_id,
+        sender,
+        recipient: ctx.accounts.recipient.key(),
+        original_chain: universal_nft.original_chain.clone(),
+        original_contract: universal_nft.original_contract.clone(),
+        original_token_id: universal_nft.original_token_id.clone(),
+    });
+    
     msg!(
         "Cross-chain NFT minted: mint={}, sender={:?}, recipient={}, token_id={}",
         ctx.accounts.mint.key(),
@@ -124,49 +381,300 @@ pub fn handler(
     Ok(())
 }
 
-#[derive(Debug)]
-pub struct CrossChainNftData {
-    pub original_chain: String,
-    pub token_id: String,
-    pub metadata_uri: String,
-    pub name: String,
-    pub symbol: String,
+/// An NFT that already has a Solana mint came back: release it from escrow
+/// into the recipient's account, keeping its mint and `UniversalNft` record
+fn process_return(ctx: Context<OnCall>, canonical_mint: Pubkey, now: i64) -> Result<()> {
+    let mint_key = ctx.accounts.mint.key();
+    require_keys_eq!(mint_key, canonical_mint, ErrorCode::InvalidOriginIndex);
+    
+    let universal_nft = &ctx.accounts.universal_nft;
+    require!(universal_nft.mint == mint_key, ErrorCode::NftNotFound);
+    require!(universal_nft.is_locked, ErrorCode::NftNotLocked);
+    // Only the chain the NFT was sent to can send it back
+    require!(
+        universal_nft.lock_destination_chain == ctx.accounts.source_chain_config.chain_id.to_string(),
+        ErrorCode::InvalidChainId
+    );
+    
+    release_to_recipient(ctx.accounts)?;
+    settle_active_transfer(ctx.accounts, now)?;
+    
+    let universal_nft = &mut ctx.accounts.universal_nft;
+    universal_nft.owner = ctx.accounts.recipient.key();
+    universal_nft.is_locked = false;
+    universal_nft.lock_destination_chain = String::new();
+    universal_nft.lock_recipient = String::new();
+    universal_nft.active_transfer = None;
+    universal_nft.updated_at = now;
+    
+    emit!(NftReleased {
+        mint: mint_key,
+        source_chain_id: ctx.accounts.source_chain_config.chain_id,
+        recipient: universal_nft.owner,
+        original_token_id: universal_nft.original_token_id.clone(),
+    });
+    
+    msg!(
+        "Cross-chain NFT returned: mint={}, recipient={}, token_id={}",
+        mint_key,
+        universal_nft.owner,
+        universal_nft.original_token_id
+    );
+    
+    Ok(())
+}
+
+/// Release the escrowed NFT to the recipient's token account
+fn release_to_recipient(accounts: &OnCall) -> Result<()> {
+    let mint_key = accounts.mint.key();
+    let token_account = accounts.token_account.as_ref().ok_or(ErrorCode::MissingAccount)?;
+    let escrow_token_account = accounts
+        .escrow_token_account
+        .as_ref()
+        .ok_or(ErrorCode::MissingAccount)?;
+    require!(
+        token_account.mint == mint_key && token_account.owner == accounts.recipient.key(),
+        ErrorCode::InvalidDestinationAddress
+    );
+    require!(
+        escrow_token_account.mint == mint_key
+            && escrow_token_account.owner == accounts.global_config.key()
+            && escrow_token_account.amount == 1,
+        ErrorCode::NftNotLocked
+    );
+    
+    release_from_escrow(
+        accounts.token_program.to_account_info(),
+        escrow_token_account.to_account_info(),
+        token_account.to_account_info(),
+        accounts.global_config.to_account_info(),
+        accounts.global_config.bump,
+    )
 }
 
-/// Parse cross-chain NFT data from the message payload
-fn parse_cross_chain_nft_data(data: &[u8]) -> Result<CrossChainNftData> {
-    // In a real implementation, this would parse a structured format like JSON or protobuf
-    // For this example, we'll assume a simple string format
-    let message = String::from_utf8(data.to_vec())
-        .map_err(|_| ErrorCode::InvalidOriginalChain)?;
-    
-    // Expected format: "chain:ethereum,token_id:123,uri:https://...,name:MyNFT,symbol:MNFT"
-    let parts: Vec<&str> = message.split(',').collect();
-    let mut nft_data = CrossChainNftData {
-        original_chain: String::new(),
-        token_id: String::new(),
-        metadata_uri: String::new(),
-        name: String::new(),
-        symbol: String::new(),
+/// The NFT is back: complete the transfer that locked it. A transfer the
+/// destination already completed has cleared `active_transfer`, so its
+/// account is not needed and may already be closed
+fn settle_active_transfer(accounts: &mut OnCall, now: i64) -> Result<()> {
+    let Some(transfer_id) = accounts.universal_nft.active_transfer else {
+        return Ok(());
     };
-    
-    for part in parts {
-        let kv: Vec<&str> = part.split(':').collect();
-        if kv.len() == 2 {
-            match kv[0] {
-                "chain" => nft_data.original_chain = kv[1].to_string(),
-                "token_id" => nft_data.token_id = kv[1].to_string(),
-                "uri" => nft_data.metadata_uri = kv[1].to_string(),
-                "name" => nft_data.name = kv[1].to_string(),
-                "symbol" => nft_data.symbol = kv[1].to_string(),
-                _ => {}
-            }
-        }
+    let cross_chain_transfer = accounts
+        .cross_chain_transfer
+        .as_mut()
+        .ok_or(ErrorCode::TransferNotFound)?;
+    require!(
+        cross_chain_transfer.transfer_id == transfer_id,
+        ErrorCode::TransferNotFound
+    );
+    if !matches!(
+        cross_chain_transfer.status,
+        TransferStatus::Initiated | TransferStatus::Confirmed
+    ) {
+        return Ok(());
     }
     
-    // Validate required fields
-    require!(!nft_data.original_chain.is_empty(), ErrorCode::InvalidOriginalChain);
-    require!(!nft_data.token_id.is_empty(), ErrorCode::InvalidOriginalChain);
+    cross_chain_transfer.status = TransferStatus::Completed;
+    cross_chain_transfer.completed_at = Some(now);
+    
+    emit!(TransferCompleted {
+        transfer_id,
+        nft_mint: cross_chain_transfer.nft_mint,
+        destination_chain: cross_chain_transfer.destination_chain.clone(),
+        destination_recipient: cross_chain_transfer.destination_recipient.clone(),
+        completed_at: now,
+    });
+    
+    Ok(())
+}
+
+/// Create the origin index for a first arrival, pointing it at the inbound mint
+fn init_origin_index(ctx: &Context<OnCall>, origin_hash: [u8; 32]) -> Result<()> {
+    let origin_index = ctx.accounts.origin_index.as_ref().ok_or(ErrorCode::MissingAccount)?;
+    require!(
+        OriginIndex::lookup(origin_index, &origin_hash)?.is_none(),
+        ErrorCode::NftAlreadyExists
+    );
+    let (_, origin_bump) = OriginIndex::find_address(&origin_hash);
+    
+    let seeds = &[ORIGIN_INDEX_SEED, &origin_hash[..], &[origin_bump]];
+    let signer = &[&seeds[..]];
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.system_program.to_account_info(),
+        CreateAccount {
+            from: ctx.accounts.payer.to_account_info(),
+            to: origin_index.to_account_info(),
+        },
+        signer,
+    );
+    create_account(
+        cpi_ctx,
+        Rent::get()?.minimum_balance(OriginIndex::INIT_SPACE),
+        OriginIndex::INIT_SPACE as u64,
+        &crate::ID,
+    )?;
+    
+    let record = OriginIndex {
+        origin_hash,
+        mint: ctx.accounts.mint.key(),
+        bump: origin_bump,
+    };
+    let mut data = origin_index.try_borrow_mut_data()?;
+    record.try_serialize(&mut &mut data[..])
+}
+
+/// An NFT locked on Solana has returned home: release it from escrow
+fn process_unlock(ctx: Context<OnCall>, origin_hash: [u8; 32], now: i64) -> Result<()> {
+    let universal_nft = &ctx.accounts.universal_nft;
+    require!(universal_nft.mint == ctx.accounts.mint.key(), ErrorCode::NftNotFound);
+    require!(
+        universal_nft.origin_hash() == origin_hash,
+        ErrorCode::InvalidOriginalChain
+    );
+    require!(universal_nft.is_locked, ErrorCode::NftNotLocked);
+    // Only the chain the NFT was sent to can release it
+    require!(
+        universal_nft.lock_destination_chain == ctx.accounts.source_chain_config.chain_id.to_string(),
+        ErrorCode::InvalidChainId
+    );
+    
+    release_to_recipient(ctx.accounts)?;
+    settle_active_transfer(ctx.accounts, now)?;
+    
+    let universal_nft = &mut ctx.accounts.universal_nft;
+    universal_nft.owner = ctx.accounts.recipient.key();
+    universal_nft.is_locked = false;
+    universal_nft.lock_destination_chain = String::new();
+    universal_nft.lock_recipient = String::new();
+    universal_nft.active_transfer = None;
+    universal_nft.updated_at = now;
+    
+    emit!(NftReleased {
+        mint: universal_nft.mint,
+        source_chain_id: ctx.accounts.source_chain_config.chain_id,
+        recipient: universal_nft.owner,
+        original_token_id: universal_nft.original_token_id.clone(),
+    });
     
-    Ok(nft_data)
+    msg!(
+        "Cross-chain NFT released: mint={}, owner={}",
+        universal_nft.mint,
+        universal_nft.owner
+    );
+    
+    Ok(())
+}
+
+/// The NFT's origin chain changed its metadata
+fn process_update_metadata(
+    ctx: Context<OnCall>,
+    message: NftMessage,
+    origin_hash: [u8; 32],
+    now: i64,
+) -> Result<()> {
+    let universal_nft = &mut ctx.accounts.universal_nft;
+    require!(universal_nft.mint == ctx.accounts.mint.key(), ErrorCode::NftNotFound);
+    require!(
+        universal_nft.origin_hash() == origin_hash,
+        ErrorCode::InvalidOriginalChain
+    );
+    // Only the chain that issued the NFT may change its metadata
+    require!(
+        universal_nft.original_chain == ctx.accounts.source_chain_config.name,
+        ErrorCode::InvalidChainId
+    );
+    require!(!universal_nft.is_locked, ErrorCode::NftLocked);
+    require!(!message.uri.is_empty(), ErrorCode::InvalidMetadataUri);
+    
+    let metadata = ctx.accounts.metadata.as_ref().ok_or(ErrorCode::MissingAccount)?;
+    let token_metadata_program = ctx
+        .accounts
+        .token_metadata_program
+        .as_ref()
+        .ok_or(ErrorCode::MissingAccount)?;
+    
+    // Keep the Metaplex metadata in sync with our record
+    let seeds = &[
+        GLOBAL_CONFIG_SEED,
+        &[ctx.accounts.global_config.bump],
+    ];
+    update_metadata_uri(
+        token_metadata_program.to_account_info(),
+        metadata.to_account_info(),
+        ctx.accounts.global_config.to_account_info(),
+        &universal_nft.mint,
+        message.uri.clone(),
+        &[&seeds[..]],
+    )?;
+    
+    universal_nft.metadata_uri = message.uri;
+    universal_nft.updated_at = now;
+    
+    emit!(NftMetadataUpdated {
+        mint: universal_nft.mint,
+        source_chain_id: ctx.accounts.source_chain_config.chain_id,
+        metadata_uri: universal_nft.metadata_uri.clone(),
+    });
+    
+    msg!(
+        "Cross-chain NFT metadata updated: mint={}, uri={}",
+        universal_nft.mint,
+        universal_nft.metadata_uri
+    );
+    
+    Ok(())
+}
+
+/// The destination chain burned its copy: the outbound transfer is complete
+fn process_burn_ack(ctx: Context<OnCall>, origin_hash: [u8; 32], now: i64) -> Result<()> {
+    let universal_nft = &ctx.accounts.universal_nft;
+    require!(universal_nft.mint == ctx.accounts.mint.key(), ErrorCode::NftNotFound);
+    require!(
+        universal_nft.origin_hash() == origin_hash,
+        ErrorCode::InvalidOriginalChain
+    );
+    
+    let cross_chain_transfer = ctx
+        .accounts
+        .cross_chain_transfer
+        .as_mut()
+        .ok_or(ErrorCode::TransferNotFound)?;
+    require!(
+        cross_chain_transfer.nft_mint == ctx.accounts.mint.key(),
+        ErrorCode::TransferNotFound
+    );
+    // Only the chain the transfer went to can acknowledge it
+    require!(
+        cross_chain_transfer.destination_chain == ctx.accounts.source_chain_config.chain_id.to_string(),
+        ErrorCode::InvalidChainId
+    );
+    require!(
+        cross_chain_transfer.status == TransferStatus::Initiated
+            || cross_chain_transfer.status == TransferStatus::Confirmed,
+        ErrorCode::InvalidTransferStatus
+    );
+    require!(
+        ctx.accounts.universal_nft.active_transfer == Some(cross_chain_transfer.transfer_id),
+        ErrorCode::StaleTransfer
+    );
+    
+    cross_chain_transfer.status = TransferStatus::Completed;
+    cross_chain_transfer.completed_at = Some(now);
+    ctx.accounts.universal_nft.active_transfer = None;
+    
+    emit!(TransferCompleted {
+        transfer_id: cross_chain_transfer.transfer_id,
+        nft_mint: cross_chain_transfer.nft_mint,
+        destination_chain: cross_chain_transfer.destination_chain.clone(),
+        destination_recipient: cross_chain_transfer.destination_recipient.clone(),
+        completed_at: now,
+    });
+    
+    msg!(
+        "Cross-chain burn acknowledged: transfer_id={}, mint={}",
+        hex::encode(cross_chain_transfer.transfer_id),
+        cross_chain_transfer.nft_mint
+    );
+    
+    Ok(())
 }
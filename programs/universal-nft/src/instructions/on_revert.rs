@@ -1,16 +1,22 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::{sysvar, sysvar::instructions::get_instruction_relative};
-use anchor_spl::token::{mint_to, Mint, MintTo, Token, TokenAccount};
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{Mint, Token, TokenAccount};
 
 use crate::state::*;
 use crate::error::ErrorCode;
 use crate::constants::*;
+use crate::escrow::release_from_escrow;
+use crate::events::TransferReverted;
 
 #[derive(Accounts)]
+#[instruction(amount: u64, sender: Pubkey, data: Vec<u8>)]
 pub struct OnRevert<'info> {
     #[account(
         seeds = [GLOBAL_CONFIG_SEED],
         bump = global_config.bump,
+        constraint = !global_config.inbound_paused @ ErrorCode::ProgramPaused,
     )]
     pub global_config: Account<'info, GlobalConfig>,
     
@@ -28,19 +34,60 @@ pub struct OnRevert<'info> {
         mut,
         seeds = [
             CROSS_CHAIN_TRANSFER_SEED,
-            transfer_id.as_bytes(),
+            cross_chain_transfer.transfer_id.as_ref(),
         ],
         bump = cross_chain_transfer.bump,
+        // deposit_and_call sends the transfer id as the revert message
+        constraint = cross_chain_transfer.transfer_id.as_slice() == data.as_slice() @ ErrorCode::TransferNotFound,
         constraint = cross_chain_transfer.nft_mint == mint.key() @ ErrorCode::InvalidOriginalChain,
+        constraint = matches!(
+            cross_chain_transfer.status,
+            TransferStatus::Initiated | TransferStatus::Confirmed
+        ) @ ErrorCode::InvalidTransferStatus,
+        constraint = universal_nft.active_transfer == Some(cross_chain_transfer.transfer_id) @ ErrorCode::StaleTransfer,
     )]
     pub cross_chain_transfer: Account<'info, CrossChainTransfer>,
     
     #[account(mut)]
     pub mint: Account<'info, Mint>,
     
+    /// Program-owned escrow holding the NFT since the transfer was initiated
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = global_config,
+    )]
+    pub escrow_token_account: Account<'info, TokenAccount>,
+    
+    /// CHECK: Original owner of the NFT, checked against the transfer record
+    #[account(address = cross_chain_transfer.source_owner @ ErrorCode::Unauthorized)]
+    pub source_owner: UncheckedAccount<'info>,
+    
+    /// Token account of the original owner that receives the NFT back
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = source_owner,
+    )]
+    pub source_owner_token_account: Account<'info, TokenAccount>,
+    
+    /// CHECK: Program PDA the gateway credits reverted deposits to
+    #[account(mut, seeds = [CONNECTED_SEED], bump)]
+    pub connected_pda: UncheckedAccount<'info>,
+    
+    /// CHECK: Receives the refunded cross-chain fee, checked against the transfer record
+    #[account(mut, address = cross_chain_transfer.refund_address @ ErrorCode::Unauthorized)]
+    pub refund_recipient: UncheckedAccount<'info>,
+    
     /// CHECK: This is the ZetaChain gateway PDA
     pub gateway_pda: UncheckedAccount<'info>,
     
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
     
     /// CHECK: Instruction sysvar for gateway validation
@@ -52,16 +99,15 @@ pub struct OnRevert<'info> {
 pub fn handler(
     ctx: Context<OnRevert>,
     amount: u64,
-    sender: Pubkey,
-    data: Vec<u8>,
-    transfer_id: String,
+    _sender: Pubkey,
+    _data: Vec<u8>,
 ) -> Result<()> {
     // Validate that caller is the ZetaChain gateway
     let current_ix = get_instruction_relative(
         0,
         &ctx.accounts.instruction_sysvar_account.to_account_info(),
     )
-    .unwrap();
+    .map_err(|_| error!(ErrorCode::Unauthorized))?;
     
     require!(
         current_ix.program_id == ctx.accounts.global_config.zetachain_gateway,
@@ -72,25 +118,57 @@ pub fn handler(
     let cross_chain_transfer = &mut ctx.accounts.cross_chain_transfer;
     let clock = Clock::get()?;
     
-    // Parse revert reason from data
-    let revert_reason = String::from_utf8(data)
-        .unwrap_or_else(|_| "Unknown revert reason".to_string());
-    
-    // Unlock the NFT since the transfer failed
+    // Return the NFT from escrow to its original owner since the transfer failed
+    release_from_escrow(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.escrow_token_account.to_account_info(),
+        ctx.accounts.source_owner_token_account.to_account_info(),
+        ctx.accounts.global_config.to_account_info(),
+        ctx.accounts.global_config.bump,
+    )?;
+    universal_nft.owner = cross_chain_transfer.source_owner;
     universal_nft.is_locked = false;
     universal_nft.lock_destination_chain = String::new();
     universal_nft.lock_recipient = String::new();
+    universal_nft.active_transfer = None;
     universal_nft.updated_at = clock.unix_timestamp;
     
+    // Refund the reverted deposit, which the gateway credited to our connected PDA
+    if amount > 0 {
+        let connected_seeds = &[
+            CONNECTED_SEED,
+            &[ctx.bumps.connected_pda],
+        ];
+        let signer = &[&connected_seeds[..]];
+        
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.connected_pda.to_account_info(),
+            to: ctx.accounts.refund_recipient.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.system_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+        
+        transfer(cpi_ctx, amount)?;
+    }
+    
     // Update transfer status to failed
     cross_chain_transfer.status = TransferStatus::Failed;
     cross_chain_transfer.completed_at = Some(clock.unix_timestamp);
     
-    msg!(
-        "Cross-chain NFT transfer reverted: transfer_id={}, mint={}, reason={}",
+    let transfer_id = cross_chain_transfer.transfer_id;
+    emit!(TransferReverted {
         transfer_id,
+        nft_mint: ctx.accounts.mint.key(),
+        source_owner: cross_chain_transfer.source_owner,
+        refund_address: cross_chain_transfer.refund_address,
+        refunded: amount,
+    });
+    
+    msg!(
+        "Cross-chain NFT transfer reverted: transfer_id={}, mint={}, refunded={}",
+        hex::encode(transfer_id),
         ctx.accounts.mint.key(),
-        revert_reason
+        amount
     );
     
     Ok(())
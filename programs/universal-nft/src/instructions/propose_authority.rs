@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::error::ErrorCode;
+use crate::events::AuthorityProposed;
+
+#[derive(Accounts)]
+pub struct ProposeAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [GLOBAL_CONFIG_SEED],
+        bump = global_config.bump,
+        has_one = authority @ ErrorCode::Unauthorized,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Propose a new authority; ownership only moves once the new key accepts
+pub fn handler(
+    ctx: Context<ProposeAuthority>,
+    new_authority: Pubkey,
+) -> Result<()> {
+    let global_config = &mut ctx.accounts.global_config;
+
+    require!(
+        new_authority != Pubkey::default() && new_authority != global_config.authority,
+        ErrorCode::InvalidAuthority
+    );
+
+    global_config.pending_authority = Some(new_authority);
+
+    emit!(AuthorityProposed {
+        authority: global_config.authority,
+        pending_authority: new_authority,
+    });
+
+    msg!(
+        "Authority transfer proposed: authority={}, pending_authority={}",
+        global_config.authority,
+        new_authority
+    );
+
+    Ok(())
+}
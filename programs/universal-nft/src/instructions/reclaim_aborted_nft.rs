@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::state::*;
+use crate::error::ErrorCode;
+use crate::escrow::release_from_escrow;
+use crate::events::AbortedNftReclaimed;
+
+#[derive(Accounts)]
+#[instruction(transfer_id: [u8; 32])]
+pub struct ReclaimAbortedNft<'info> {
+    #[account(
+        seeds = [GLOBAL_CONFIG_SEED],
+        bump = global_config.bump,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            UNIVERSAL_NFT_SEED,
+            mint.key().as_ref(),
+        ],
+        bump = universal_nft.bump,
+        constraint = universal_nft.is_locked @ ErrorCode::NftNotLocked,
+    )]
+    pub universal_nft: Account<'info, UniversalNft>,
+
+    #[account(
+        mut,
+        seeds = [
+            CROSS_CHAIN_TRANSFER_SEED,
+            transfer_id.as_ref(),
+        ],
+        bump = cross_chain_transfer.bump,
+        constraint = cross_chain_transfer.nft_mint == mint.key() @ ErrorCode::InvalidOriginalChain,
+        constraint = cross_chain_transfer.status == TransferStatus::Aborted @ ErrorCode::InvalidTransferStatus,
+        constraint = cross_chain_transfer.completed_at.is_none() @ ErrorCode::InvalidTransferStatus,
+        constraint = universal_nft.active_transfer == Some(transfer_id) @ ErrorCode::StaleTransfer,
+        constraint = claimant.key() == cross_chain_transfer.source_owner
+            || claimant.key() == cross_chain_transfer.abort_address @ ErrorCode::Unauthorized,
+    )]
+    pub cross_chain_transfer: Account<'info, CrossChainTransfer>,
+
+    pub mint: Account<'info, Mint>,
+
+    /// Program-owned escrow holding the NFT since the transfer was initiated
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = global_config,
+    )]
+    pub escrow_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = claimant,
+        associated_token::mint = mint,
+        associated_token::authority = claimant,
+    )]
+    pub claimant_token_account: Account<'info, TokenAccount>,
+
+    /// The original owner or the transfer's abort address
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<ReclaimAbortedNft>,
+    transfer_id: [u8; 32],
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    release_from_escrow(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.escrow_token_account.to_account_info(),
+        ctx.accounts.claimant_token_account.to_account_info(),
+        ctx.accounts.global_config.to_account_info(),
+        ctx.accounts.global_config.bump,
+    )?;
+
+    let universal_nft = &mut ctx.accounts.universal_nft;
+    universal_nft.owner = ctx.accounts.claimant.key();
+    universal_nft.is_locked = false;
+    universal_nft.lock_destination_chain = String::new();
+    universal_nft.lock_recipient = String::new();
+    universal_nft.active_transfer = None;
+    universal_nft.updated_at = clock.unix_timestamp;
+
+    // Marks the aborted transfer as settled so it cannot be reclaimed twice
+    let cross_chain_transfer = &mut ctx.accounts.cross_chain_transfer;
+    cross_chain_transfer.completed_at = Some(clock.unix_timestamp);
+
+    emit!(AbortedNftReclaimed {
+        transfer_id,
+        nft_mint: ctx.accounts.mint.key(),
+        claimant: ctx.accounts.claimant.key(),
+    });
+
+    msg!(
+        "Aborted NFT reclaimed: transfer_id={}, mint={}, claimant={}",
+        hex::encode(transfer_id),
+        ctx.accounts.mint.key(),
+        ctx.accounts.claimant.key()
+    );
+
+    Ok(())
+}
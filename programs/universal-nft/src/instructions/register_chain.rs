@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::error::ErrorCode;
+use crate::constants::*;
+use crate::events::ChainRegistered;
+
+#[derive(Accounts)]
+#[instruction(chain_id: u64)]
+pub struct RegisterChain<'info> {
+    #[account(
+        seeds = [GLOBAL_CONFIG_SEED],
+        bump = global_config.bump,
+        has_one = authority @ ErrorCode::Unauthorized,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = ChainConfig::INIT_SPACE,
+        seeds = [
+            CHAIN_CONFIG_SEED,
+            &chain_id.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub chain_config: Account<'info, ChainConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Register a connected chain so outbound transfers may target it
+pub fn handler(
+    ctx: Context<RegisterChain>,
+    chain_id: u64,
+    name: String,
+    address_format: AddressFormat,
+    message_encoding: MessageEncoding,
+    connected_contract: Vec<u8>,
+    cross_chain_fee: Option<u64>,
+) -> Result<()> {
+    let global_config = &ctx.accounts.global_config;
+    let chain_config = &mut ctx.accounts.chain_config;
+
+    // Validate input parameters
+    require!(chain_id > 0 && chain_id != SOLANA_CHAIN_ID, ErrorCode::InvalidChainId);
+    require!(!name.is_empty() && name.len() <= 32, ErrorCode::InvalidChainConfig);
+    require!(
+        connected_contract.len() == address_format.address_len(),
+        ErrorCode::InvalidChainConfig
+    );
+
+    chain_config.chain_id = chain_id;
+    chain_config.enabled = true;
+    chain_config.name = name;
+    chain_config.address_format = address_format;
+    chain_config.message_encoding = message_encoding;
+    chain_config.connected_contract = connected_contract;
+    chain_config.cross_chain_fee = cross_chain_fee.unwrap_or(global_config.cross_chain_fee);
+    chain_config.bump = ctx.bumps.chain_config;
+
+    emit!(ChainRegistered {
+        chain_id,
+        name: chain_config.name.clone(),
+        connected_contract: chain_config.connected_contract.clone(),
+        cross_chain_fee: chain_config.cross_chain_fee,
+    });
+
+    msg!(
+        "Chain registered: chain_id={}, name={}, fee={}",
+        chain_id,
+        chain_config.name,
+        chain_config.cross_chain_fee
+    );
+
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::error::ErrorCode;
+use crate::events::PauseUpdated;
+
+#[derive(Accounts)]
+pub struct SetPause<'info> {
+    #[account(
+        mut,
+        seeds = [GLOBAL_CONFIG_SEED],
+        bump = global_config.bump,
+        constraint = signer.key() == global_config.authority
+            || signer.key() == global_config.guardian @ ErrorCode::Unauthorized,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// Either the program authority or the guardian
+    pub signer: Signer<'info>,
+}
+
+/// Toggle the circuit breakers; `None` leaves a switch unchanged
+pub fn handler(
+    ctx: Context<SetPause>,
+    mint_paused: Option<bool>,
+    outbound_paused: Option<bool>,
+    inbound_paused: Option<bool>,
+) -> Result<()> {
+    let global_config = &mut ctx.accounts.global_config;
+    let clock = Clock::get()?;
+
+    if let Some(paused) = mint_paused {
+        global_config.mint_paused = paused;
+    }
+    if let Some(paused) = outbound_paused {
+        global_config.outbound_paused = paused;
+    }
+    if let Some(paused) = inbound_paused {
+        global_config.inbound_paused = paused;
+    }
+
+    emit!(PauseUpdated {
+        updated_by: ctx.accounts.signer.key(),
+        mint_paused: global_config.mint_paused,
+        outbound_paused: global_config.outbound_paused,
+        inbound_paused: global_config.inbound_paused,
+        updated_at: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Pause state updated by {}: mint={}, outbound={}, inbound={}",
+        ctx.accounts.signer.key(),
+        global_config.mint_paused,
+        global_config.outbound_paused,
+        global_config.inbound_paused
+    );
+
+    Ok(())
+}
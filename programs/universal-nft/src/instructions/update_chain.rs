@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::error::ErrorCode;
+use crate::events::ChainUpdated;
+
+#[derive(Accounts)]
+#[instruction(chain_id: u64)]
+pub struct UpdateChain<'info> {
+    #[account(
+        seeds = [GLOBAL_CONFIG_SEED],
+        bump = global_config.bump,
+        has_one = authority @ ErrorCode::Unauthorized,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            CHAIN_CONFIG_SEED,
+            &chain_id.to_le_bytes(),
+        ],
+        bump = chain_config.bump,
+    )]
+    pub chain_config: Account<'info, ChainConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Enable/disable a registered chain or change its name, encoding, contract or fee
+pub fn handler(
+    ctx: Context<UpdateChain>,
+    chain_id: u64,
+    enabled: Option<bool>,
+    name: Option<String>,
+    message_encoding: Option<MessageEncoding>,
+    connected_contract: Option<Vec<u8>>,
+    cross_chain_fee: Option<u64>,
+) -> Result<()> {
+    let chain_config = &mut ctx.accounts.chain_config;
+
+    if let Some(enabled) = enabled {
+        chain_config.enabled = enabled;
+    }
+    if let Some(name) = name {
+        require!(!name.is_empty() && name.len() <= 32, ErrorCode::InvalidChainConfig);
+        chain_config.name = name;
+    }
+    if let Some(message_encoding) = message_encoding {
+        chain_config.message_encoding = message_encoding;
+    }
+    if let Some(connected_contract) = connected_contract {
+        require!(
+            connected_contract.len() == chain_config.address_format.address_len(),
+            ErrorCode::InvalidChainConfig
+        );
+        chain_config.connected_contract = connected_contract;
+    }
+    if let Some(fee) = cross_chain_fee {
+        chain_config.cross_chain_fee = fee;
+    }
+
+    emit!(ChainUpdated {
+        chain_id,
+        enabled: chain_config.enabled,
+        name: chain_config.name.clone(),
+        connected_contract: chain_config.connected_contract.clone(),
+        cross_chain_fee: chain_config.cross_chain_fee,
+    });
+
+    msg!(
+        "Chain updated: chain_id={}, enabled={}, fee={}",
+        chain_id,
+        chain_config.enabled,
+        chain_config.cross_chain_fee
+    );
+
+    Ok(())
+}
@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::error::ErrorCode;
+use crate::events::ConfigUpdated;
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    #[account(
+        mut,
+        seeds = [GLOBAL_CONFIG_SEED],
+        bump = global_config.bump,
+        has_one = authority @ ErrorCode::Unauthorized,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Update any subset of the mutable global configuration fields
+pub fn handler(
+    ctx: Context<UpdateConfig>,
+    zetachain_gateway: Option<Pubkey>,
+    collection_authority: Option<Pubkey>,
+    fee_recipient: Option<Pubkey>,
+    cross_chain_fee: Option<u64>,
+    guardian: Option<Pubkey>,
+    transfer_timeout: Option<i64>,
+) -> Result<()> {
+    let global_config = &mut ctx.accounts.global_config;
+    let clock = Clock::get()?;
+
+    // Apply the same rules as `initialize`
+    if let Some(gateway) = zetachain_gateway {
+        require!(gateway != Pubkey::default(), ErrorCode::GatewayNotConfigured);
+    }
+    if let Some(timeout) = transfer_timeout {
+        require!(timeout > 0, ErrorCode::InvalidTransferTimeout);
+    }
+
+    let old_zetachain_gateway = global_config.zetachain_gateway;
+    let old_collection_authority = global_config.collection_authority;
+    let old_fee_recipient = global_config.fee_recipient;
+    let old_cross_chain_fee = global_config.cross_chain_fee;
+    let old_guardian = global_config.guardian;
+    let old_transfer_timeout = global_config.transfer_timeout;
+
+    global_config.zetachain_gateway = zetachain_gateway.unwrap_or(old_zetachain_gateway);
+    global_config.collection_authority = collection_authority.unwrap_or(old_collection_authority);
+    global_config.fee_recipient = fee_recipient.unwrap_or(old_fee_recipient);
+    global_config.cross_chain_fee = cross_chain_fee.unwrap_or(old_cross_chain_fee);
+    global_config.guardian = guardian.unwrap_or(old_guardian);
+    global_config.transfer_timeout = transfer_timeout.unwrap_or(old_transfer_timeout);
+
+    emit!(ConfigUpdated {
+        authority: ctx.accounts.authority.key(),
+        old_zetachain_gateway,
+        new_zetachain_gateway: global_config.zetachain_gateway,
+        old_collection_authority,
+        new_collection_authority: global_config.collection_authority,
+        old_fee_recipient,
+        new_fee_recipient: global_config.fee_recipient,
+        old_cross_chain_fee,
+        new_cross_chain_fee: global_config.cross_chain_fee,
+        old_guardian,
+        new_guardian: global_config.guardian,
+        old_transfer_timeout,
+        new_transfer_timeout: global_config.transfer_timeout,
+        updated_at: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Universal NFT config updated by authority: {}, gateway: {}",
+        ctx.accounts.authority.key(),
+        global_config.zetachain_gateway
+    );
+
+    Ok(())
+}
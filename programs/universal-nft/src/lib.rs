@@ -1,14 +1,26 @@
+pub mod abi;
 pub mod constants;
 pub mod error;
+pub mod escrow;
+pub mod events;
+pub mod gateway_cpi;
 pub mod instructions;
+pub mod message;
+pub mod metaplex;
 pub mod state;
 
 use anchor_lang::prelude::*;
 
+pub use abi::*;
 pub use constants::*;
+pub use events::*;
 pub use instructions::*;
+pub use message::*;
 pub use state::*;
 
+#[cfg(not(any(feature = "zetachain-gateway", feature = "demo-gateway")))]
+compile_error!("enable the `zetachain-gateway` feature, or `demo-gateway` for local demos");
+
 declare_id!("73ce2AD3AZpaGFNcdavnbKbhNGSmz3PNyv2GCDM3Yy3c");
 
 #[program]
@@ -24,6 +36,83 @@ pub mod universal_nft {
         initialize::handler(ctx, bump, cross_chain_fee)
     }
 
+    /// Update the gateway, collection authority, fee recipient, cross-chain fee, guardian or transfer timeout
+    pub fn update_config(
+        ctx: Context<UpdateConfig>,
+        zetachain_gateway: Option<Pubkey>,
+        collection_authority: Option<Pubkey>,
+        fee_recipient: Option<Pubkey>,
+        cross_chain_fee: Option<u64>,
+        guardian: Option<Pubkey>,
+        transfer_timeout: Option<i64>,
+    ) -> Result<()> {
+        update_config::handler(ctx, zetachain_gateway, collection_authority, fee_recipient, cross_chain_fee, guardian, transfer_timeout)
+    }
+
+    /// Propose a new program authority (step one of a two-step handover)
+    pub fn propose_authority(
+        ctx: Context<ProposeAuthority>,
+        new_authority: Pubkey,
+    ) -> Result<()> {
+        propose_authority::handler(ctx, new_authority)
+    }
+
+    /// Accept a pending authority proposal (step two of a two-step handover)
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        accept_authority::handler(ctx)
+    }
+
+    /// Cancel a pending authority proposal
+    pub fn cancel_authority_proposal(ctx: Context<CancelAuthorityProposal>) -> Result<()> {
+        cancel_authority_proposal::handler(ctx)
+    }
+
+    /// Pause or resume minting, outbound or inbound transfers (authority or guardian)
+    pub fn set_pause(
+        ctx: Context<SetPause>,
+        mint_paused: Option<bool>,
+        outbound_paused: Option<bool>,
+        inbound_paused: Option<bool>,
+    ) -> Result<()> {
+        set_pause::handler(ctx, mint_paused, outbound_paused, inbound_paused)
+    }
+
+    /// Register a connected chain in the on-chain chain registry
+    pub fn register_chain(
+        ctx: Context<RegisterChain>,
+        chain_id: u64,
+        name: String,
+        address_format: AddressFormat,
+        message_encoding: MessageEncoding,
+        connected_contract: Vec<u8>,
+        cross_chain_fee: Option<u64>,
+    ) -> Result<()> {
+        register_chain::handler(ctx, chain_id, name, address_format, message_encoding, connected_contract, cross_chain_fee)
+    }
+
+    /// Update a registered chain
+    pub fn update_chain(
+        ctx: Context<UpdateChain>,
+        chain_id: u64,
+        enabled: Option<bool>,
+        name: Option<String>,
+        message_encoding: Option<MessageEncoding>,
+        connected_contract: Option<Vec<u8>>,
+        cross_chain_fee: Option<u64>,
+    ) -> Result<()> {
+        update_chain::handler(ctx, chain_id, enabled, name, message_encoding, connected_contract, cross_chain_fee)
+    }
+
+    /// Create the collection NFT that all universal NFTs belong to
+    pub fn create_collection(
+        ctx: Context<CreateCollection>,
+        name: String,
+        symbol: String,
+        uri: String,
+    ) -> Result<()> {
+        create_collection::handler(ctx, name, symbol, uri)
+    }
+
     /// Mint a new universal NFT with cross-chain metadata
     pub fn mint_nft(
         ctx: Context<MintNft>,
@@ -38,33 +127,48 @@ pub mod universal_nft {
         mint_nft::handler(ctx, bump, name, symbol, uri, original_chain, original_contract, original_token_id)
     }
 
-    /// Initiate a cross-chain transfer of an NFT
+    /// Initiate a cross-chain transfer of an NFT; the transfer id is derived
+    /// from the mint, the owner and the owner's transfer nonce
     pub fn initiate_cross_chain_transfer(
         ctx: Context<InitiateCrossChainTransfer>,
-        transfer_id: String,
-        destination_chain: String,
+        destination_chain_id: u64,
         destination_recipient: String,
-        bump: u8,
     ) -> Result<()> {
-        initiate_cross_chain_transfer::handler(ctx, transfer_id, destination_chain, destination_recipient, bump)
+        initiate_cross_chain_transfer::handler(ctx, destination_chain_id, destination_recipient)
     }
 
     /// Confirm a cross-chain transfer (called by ZetaChain gateway)
     pub fn confirm_cross_chain_transfer(
         ctx: Context<ConfirmCrossChainTransfer>,
-        transfer_id: String,
+        transfer_id: [u8; 32],
     ) -> Result<()> {
         confirm_cross_chain_transfer::handler(ctx, transfer_id)
     }
 
-    /// Complete a cross-chain transfer by burning the NFT
+    /// Complete a cross-chain transfer; the NFT stays in escrow
     pub fn complete_cross_chain_transfer(
         ctx: Context<CompleteCrossChainTransfer>,
-        transfer_id: String,
+        transfer_id: [u8; 32],
     ) -> Result<()> {
         complete_cross_chain_transfer::handler(ctx, transfer_id)
     }
 
+    /// Cancel a transfer the gateway has not confirmed and return the NFT to its owner
+    pub fn cancel_cross_chain_transfer(
+        ctx: Context<CancelCrossChainTransfer>,
+        transfer_id: [u8; 32],
+    ) -> Result<()> {
+        cancel_cross_chain_transfer::handler(ctx, transfer_id)
+    }
+
+    /// Close a finished transfer record, returning its rent to the payer
+    pub fn close_transfer(
+        ctx: Context<CloseTransfer>,
+        transfer_id: [u8; 32],
+    ) -> Result<()> {
+        close_transfer::handler(ctx, transfer_id)
+    }
+
     /// Called by ZetaChain gateway when receiving cross-chain NFT transfer
     pub fn on_call(
         ctx: Context<OnCall>,
@@ -82,20 +186,35 @@ pub mod universal_nft {
         amount: u64,
         sender: Pubkey,
         data: Vec<u8>,
-        transfer_id: String,
     ) -> Result<()> {
-        on_revert::handler(ctx, amount, sender, data, transfer_id)
+        on_revert::handler(ctx, amount, sender, data)
+    }
+
+    /// Called by ZetaChain gateway when reverting a cross-chain transfer failed
+    pub fn on_abort(
+        ctx: Context<OnAbort>,
+        amount: u64,
+        sender: Pubkey,
+        data: Vec<u8>,
+    ) -> Result<()> {
+        on_abort::handler(ctx, amount, sender, data)
+    }
+
+    /// Reclaim the escrowed NFT of an aborted transfer (owner or abort address)
+    pub fn reclaim_aborted_nft(
+        ctx: Context<ReclaimAbortedNft>,
+        transfer_id: [u8; 32],
+    ) -> Result<()> {
+        reclaim_aborted_nft::handler(ctx, transfer_id)
     }
 
     /// Initiate cross-chain transfer via ZetaChain gateway
     pub fn deposit_and_call(
         ctx: Context<DepositAndCall>,
-        transfer_id: String,
         destination_chain_id: u64,
         destination_recipient: [u8; 20],
-        revert_options: Option<gateway::RevertOptions>,
-        bump: u8,
+        revert_options: Option<gateway_cpi::RevertOptions>,
     ) -> Result<()> {
-        deposit_and_call::handler(ctx, transfer_id, destination_chain_id, destination_recipient, revert_options, bump)
+        deposit_and_call::handler(ctx, destination_chain_id, destination_recipient, revert_options)
     }
 }
@@ -0,0 +1,146 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::error::ErrorCode;
+
+/// Header carried by every cross-chain message
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct MessageHeader {
+    pub version: u8,
+    pub message_type: u8,
+    pub nonce: u64,
+    pub source_chain_id: u64,
+    pub destination_chain_id: u64,
+}
+
+/// Universal NFT payload exchanged with connected chains
+///
+/// Encoded with Borsh; the first byte is always `header.version` so decoders
+/// can reject layouts they do not understand before parsing the rest.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct NftMessage {
+    pub header: MessageHeader,
+    pub original_chain: String,
+    pub original_contract: String,
+    pub original_token_id: String,
+    pub uri: String,
+    pub name: String,
+    pub symbol: String,
+    /// Raw recipient address on the destination chain
+    pub recipient: Vec<u8>,
+}
+
+impl NftMessage {
+    /// Validate and serialize the message
+    pub fn encode(&self) -> Result<Vec<u8>> {
+        self.validate()?;
+        self.try_to_vec().map_err(|_| error!(ErrorCode::InvalidMessage))
+    }
+
+    /// Parse and validate a message received from the gateway
+    pub fn decode(data: &[u8]) -> Result<Self> {
+        require!(
+            data.first() == Some(&MESSAGE_VERSION),
+            ErrorCode::UnsupportedMessageVersion
+        );
+        let message = NftMessage::try_from_slice(data)
+            .map_err(|_| error!(ErrorCode::InvalidMessage))?;
+        message.validate()?;
+        Ok(message)
+    }
+
+    pub(crate) fn validate(&self) -> Result<()> {
+        require!(self.header.version == MESSAGE_VERSION, ErrorCode::UnsupportedMessageVersion);
+        require!(
+            (MSG_TYPE_MINT..=MSG_TYPE_UPDATE_METADATA).contains(&self.header.message_type),
+            ErrorCode::UnsupportedMessageType
+        );
+        require!(
+            !self.original_chain.is_empty() && self.original_chain.len() <= MAX_CHAIN_LEN,
+            ErrorCode::InvalidOriginalChain
+        );
+        require!(self.original_contract.len() <= MAX_CONTRACT_LEN, ErrorCode::InvalidOriginalChain);
+        require!(
+            !self.original_token_id.is_empty() && self.original_token_id.len() <= MAX_TOKEN_ID_LEN,
+            ErrorCode::InvalidOriginalChain
+        );
+        require!(self.uri.len() <= MAX_URI_LEN, ErrorCode::InvalidMetadataUri);
+        require!(self.name.len() <= MAX_NAME_LEN, ErrorCode::InvalidMessage);
+        require!(self.symbol.len() <= MAX_SYMBOL_LEN, ErrorCode::InvalidMessage);
+        require!(
+            !self.recipient.is_empty() && self.recipient.len() <= MAX_RECIPIENT_LEN,
+            ErrorCode::InvalidDestinationAddress
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> NftMessage {
+        NftMessage {
+            header: MessageHeader {
+                version: MESSAGE_VERSION,
+                message_type: MSG_TYPE_TRANSFER,
+                nonce: 42,
+                source_chain_id: SOLANA_CHAIN_ID,
+                destination_chain_id: 137,
+            },
+            original_chain: "ethereum".to_string(),
+            original_contract: "0x1234567890abcdef1234567890abcdef12345678".to_string(),
+            original_token_id: "1".to_string(),
+            uri: "https://zetachain.com/metadata/1.json".to_string(),
+            name: "Name, with: separators".to_string(),
+            symbol: "UNFT".to_string(),
+            recipient: vec![0xab; 20],
+        }
+    }
+
+    #[test]
+    fn round_trips() {
+        let message = sample();
+        let encoded = message.encode().unwrap();
+        assert_eq!(encoded[0], MESSAGE_VERSION);
+        assert_eq!(NftMessage::decode(&encoded).unwrap(), message);
+    }
+
+    #[test]
+    fn keeps_uri_scheme_and_separators() {
+        let decoded = NftMessage::decode(&sample().encode().unwrap()).unwrap();
+        assert_eq!(decoded.uri, "https://zetachain.com/metadata/1.json");
+        assert_eq!(decoded.name, "Name, with: separators");
+    }
+
+    #[test]
+    fn rejects_unknown_version() {
+        let mut encoded = sample().encode().unwrap();
+        encoded[0] = MESSAGE_VERSION + 1;
+        assert!(NftMessage::decode(&encoded).is_err());
+    }
+
+    #[test]
+    fn rejects_unknown_message_type() {
+        let mut message = sample();
+        message.header.message_type = 0;
+        assert!(message.encode().is_err());
+    }
+
+    #[test]
+    fn rejects_truncated_and_trailing_bytes() {
+        let encoded = sample().encode().unwrap();
+        assert!(NftMessage::decode(&encoded[..encoded.len() - 1]).is_err());
+
+        let mut extended = encoded.clone();
+        extended.push(0);
+        assert!(NftMessage::decode(&extended).is_err());
+    }
+
+    #[test]
+    fn rejects_oversized_fields() {
+        let mut message = sample();
+        message.uri = "a".repeat(MAX_URI_LEN + 1);
+        assert!(message.encode().is_err());
+    }
+}
@@ -0,0 +1,179 @@
+use anchor_lang::prelude::*;
+use anchor_spl::metadata::{
+    create_master_edition_v3, create_metadata_accounts_v3,
+    mpl_token_metadata::{
+        accounts::Metadata,
+        types::{Collection, CollectionDetails, DataV2},
+    },
+    update_metadata_accounts_v2, verify_sized_collection_item, CreateMasterEditionV3,
+    CreateMetadataAccountsV3, UpdateMetadataAccountsV2, VerifySizedCollectionItem,
+};
+
+use crate::error::ErrorCode;
+
+/// Accounts needed to turn a single-token mint into a Metaplex NFT
+pub struct NftMetadataAccounts<'info> {
+    pub token_metadata_program: AccountInfo<'info>,
+    pub metadata: AccountInfo<'info>,
+    pub master_edition: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+    pub mint_authority: AccountInfo<'info>,
+    pub update_authority: AccountInfo<'info>,
+    pub payer: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub rent: AccountInfo<'info>,
+}
+
+/// The program's collection NFT, as referenced by every Universal NFT
+pub struct CollectionAccounts<'info> {
+    pub collection_mint: AccountInfo<'info>,
+    pub collection_metadata: AccountInfo<'info>,
+    pub collection_master_edition: AccountInfo<'info>,
+}
+
+impl<'info> NftMetadataAccounts<'info> {
+    /// Create the metadata account of a collection member; must run before
+    /// the token is minted. The collection is left unverified.
+    pub fn create_metadata(
+        &self,
+        name: String,
+        symbol: String,
+        uri: String,
+        collection: Option<Pubkey>,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        let collection = collection.map(|key| Collection { verified: false, key });
+        self.create(name, symbol, uri, collection, None, signer_seeds)
+    }
+
+    /// Create the metadata account of a sized collection NFT
+    pub fn create_collection_metadata(
+        &self,
+        name: String,
+        symbol: String,
+        uri: String,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        let collection_details = Some(CollectionDetails::V1 { size: 0 });
+        self.create(name, symbol, uri, None, collection_details, signer_seeds)
+    }
+
+    fn create(
+        &self,
+        name: String,
+        symbol: String,
+        uri: String,
+        collection: Option<Collection>,
+        collection_details: Option<CollectionDetails>,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.token_metadata_program.clone(),
+            CreateMetadataAccountsV3 {
+                metadata: self.metadata.clone(),
+                mint: self.mint.clone(),
+                mint_authority: self.mint_authority.clone(),
+                payer: self.payer.clone(),
+                update_authority: self.update_authority.clone(),
+                system_program: self.system_program.clone(),
+                rent: self.rent.clone(),
+            },
+            signer_seeds,
+        );
+
+        let data = DataV2 {
+            name,
+            symbol,
+            uri,
+            seller_fee_basis_points: 0,
+            creators: None,
+            collection,
+            uses: None,
+        };
+
+        create_metadata_accounts_v3(cpi_ctx, data, true, true, collection_details)
+    }
+
+    /// Create the master edition with supply 0; must run after the single
+    /// token is minted. Metaplex takes over the mint and freeze authorities.
+    pub fn create_master_edition(&self, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.token_metadata_program.clone(),
+            CreateMasterEditionV3 {
+                edition: self.master_edition.clone(),
+                mint: self.mint.clone(),
+                update_authority: self.update_authority.clone(),
+                mint_authority: self.mint_authority.clone(),
+                payer: self.payer.clone(),
+                metadata: self.metadata.clone(),
+                token_program: self.token_program.clone(),
+                system_program: self.system_program.clone(),
+                rent: self.rent.clone(),
+            },
+            signer_seeds,
+        );
+
+        create_master_edition_v3(cpi_ctx, Some(0))
+    }
+
+    /// Verify the NFT as a member of the collection; the update authority
+    /// must also be the collection's update authority
+    pub fn verify_collection(
+        &self,
+        collection: &CollectionAccounts<'info>,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.token_metadata_program.clone(),
+            VerifySizedCollectionItem {
+                payer: self.payer.clone(),
+                metadata: self.metadata.clone(),
+                collection_authority: self.update_authority.clone(),
+                collection_mint: collection.collection_mint.clone(),
+                collection_metadata: collection.collection_metadata.clone(),
+                collection_master_edition: collection.collection_master_edition.clone(),
+            },
+            signer_seeds,
+        );
+
+        verify_sized_collection_item(cpi_ctx, None)
+    }
+}
+
+/// Point an existing metadata account at a new URI, keeping everything else
+pub fn update_metadata_uri<'info>(
+    token_metadata_program: AccountInfo<'info>,
+    metadata: AccountInfo<'info>,
+    update_authority: AccountInfo<'info>,
+    mint: &Pubkey,
+    uri: String,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    require_keys_eq!(*metadata.owner, token_metadata_program.key(), ErrorCode::InvalidMetadataAccount);
+    let current = Metadata::safe_deserialize(&metadata.try_borrow_data()?)
+        .map_err(|_| error!(ErrorCode::InvalidMetadataAccount))?;
+    require_keys_eq!(current.mint, *mint, ErrorCode::InvalidMetadataAccount);
+
+    // Metaplex pads stored strings with NUL bytes
+    let data = DataV2 {
+        name: current.name.trim_end_matches('\0').to_string(),
+        symbol: current.symbol.trim_end_matches('\0').to_string(),
+        uri,
+        seller_fee_basis_points: current.seller_fee_basis_points,
+        creators: current.creators,
+        collection: current.collection,
+        uses: current.uses,
+    };
+
+    let cpi_ctx = CpiContext::new_with_signer(
+        token_metadata_program,
+        UpdateMetadataAccountsV2 {
+            metadata,
+            update_authority,
+        },
+        signer_seeds,
+    );
+
+    update_metadata_accounts_v2(cpi_ctx, None, Some(data), None, None)
+}
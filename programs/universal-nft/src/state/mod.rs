@@ -1,13 +1,23 @@
 use anchor_lang::prelude::*;
 
+use crate::error::ErrorCode;
+
 /// Global configuration for the universal NFT program
 #[account]
 pub struct GlobalConfig {
     pub authority: Pubkey,
     pub zetachain_gateway: Pubkey,
     pub collection_authority: Pubkey,
+    pub collection_mint: Pubkey,
     pub fee_recipient: Pubkey,
     pub cross_chain_fee: u64,
+    pub outbound_nonce: u64,
+    pub transfer_timeout: i64,
+    pub pending_authority: Option<Pubkey>,
+    pub guardian: Pubkey,
+    pub mint_paused: bool,
+    pub outbound_paused: bool,
+    pub inbound_paused: bool,
     pub bump: u8,
 }
 
@@ -16,8 +26,16 @@ impl Space for GlobalConfig {
         32 + // authority
         32 + // zetachain_gateway
         32 + // collection_authority
+        32 + // collection_mint (default until create_collection)
         32 + // fee_recipient
         8 + // cross_chain_fee
+        8 + // outbound_nonce
+        8 + // transfer_timeout (seconds)
+        1 + 32 + // pending_authority (Option<Pubkey>)
+        32 + // guardian
+        1 + // mint_paused
+        1 + // outbound_paused
+        1 + // inbound_paused
         1; // bump
 }
 
@@ -33,6 +51,7 @@ pub struct UniversalNft {
     pub is_locked: bool,
     pub lock_destination_chain: String,
     pub lock_recipient: String,
+    pub active_transfer: Option<[u8; 32]>,
     pub created_at: i64,
     pub updated_at: i64,
     pub bump: u8,
@@ -49,19 +68,35 @@ impl Space for UniversalNft {
         1 + // is_locked
         4 + 32 + // lock_destination_chain (max 32 chars)
         4 + 64 + // lock_recipient (max 64 chars)
+        1 + 32 + // active_transfer (id of the unfinished transfer holding the lock)
         8 + // created_at
         8 + // updated_at
         1; // bump
 }
 
+impl UniversalNft {
+    /// Hash of the NFT's origin triple, as indexed by `OriginIndex`
+    pub fn origin_hash(&self) -> [u8; 32] {
+        OriginIndex::origin_hash(
+            &self.original_chain,
+            &self.original_contract,
+            &self.original_token_id,
+        )
+    }
+}
+
 /// Cross-chain transfer request pending confirmation
 #[account]
 pub struct CrossChainTransfer {
-    pub transfer_id: String,
+    pub transfer_id: [u8; 32],
     pub nft_mint: Pubkey,
     pub source_owner: Pubkey,
+    pub refund_address: Pubkey,
+    pub abort_address: Pubkey,
+    pub payer: Pubkey,
     pub destination_chain: String,
     pub destination_recipient: String,
+    pub via_gateway: bool,
     pub status: TransferStatus,
     pub initiated_at: i64,
     pub completed_at: Option<i64>,
@@ -70,26 +105,254 @@ pub struct CrossChainTransfer {
 
 impl Space for CrossChainTransfer {
     const INIT_SPACE: usize = 8 + // discriminator
-        4 + 32 + // transfer_id (max 32 chars)
+        32 + // transfer_id
         32 + // nft_mint
         32 + // source_owner
+        32 + // refund_address
+        32 + // abort_address
+        32 + // payer (receives the rent back on close)
         4 + 32 + // destination_chain (max 32 chars)
         4 + 64 + // destination_recipient (max 64 chars)
+        1 + // via_gateway (handed to the gateway by deposit_and_call)
         1 + // status
         8 + // initiated_at
         1 + 8 + // completed_at (Option<i64>)
         1; // bump
 }
 
+impl CrossChainTransfer {
+    /// Id of `owner`'s `nonce`-th transfer of `mint`
+    pub fn transfer_id(mint: &Pubkey, owner: &Pubkey, nonce: u64) -> [u8; 32] {
+        anchor_lang::solana_program::hash::hashv(&[
+            mint.as_ref(),
+            owner.as_ref(),
+            &nonce.to_le_bytes(),
+        ])
+        .to_bytes()
+    }
+
+    /// Address of the transfer record for `transfer_id`
+    pub fn find_address(transfer_id: &[u8; 32]) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[CROSS_CHAIN_TRANSFER_SEED, transfer_id], &crate::ID)
+    }
+
+    /// Whether the transfer has waited longer than `timeout` seconds
+    pub fn is_expired(&self, timeout: i64, now: i64) -> bool {
+        now >= self.initiated_at.saturating_add(timeout)
+    }
+
+    /// Whether the transfer reached a final status and its NFT has settled;
+    /// aborted transfers only count once the NFT was reclaimed
+    pub fn is_finalized(&self) -> bool {
+        matches!(
+            self.status,
+            TransferStatus::Completed
+                | TransferStatus::Failed
+                | TransferStatus::Aborted
+                | TransferStatus::Cancelled
+        ) && self.completed_at.is_some()
+    }
+}
+
+/// Per-owner counter from which transfer ids are derived
+#[account]
+pub struct TransferNonce {
+    pub owner: Pubkey,
+    pub nonce: u64,
+    pub bump: u8,
+}
+
+impl Space for TransferNonce {
+    const INIT_SPACE: usize = 8 + // discriminator
+        32 + // owner
+        8 + // nonce (transfers started so far)
+        1; // bump
+}
+
+impl TransferNonce {
+    /// Claim the id of the owner's next transfer of `mint`
+    pub fn next_transfer_id(&mut self, mint: &Pubkey) -> Result<[u8; 32]> {
+        let transfer_id = CrossChainTransfer::transfer_id(mint, &self.owner, self.nonce);
+        self.nonce = self.nonce.checked_add(1).ok_or(ErrorCode::NonceOverflow)?;
+        Ok(transfer_id)
+    }
+}
+
+/// Registered connected chain and its Universal NFT contract
+#[account]
+pub struct ChainConfig {
+    pub chain_id: u64,
+    pub enabled: bool,
+    pub name: String,
+    pub address_format: AddressFormat,
+    pub message_encoding: MessageEncoding,
+    pub connected_contract: Vec<u8>,
+    pub cross_chain_fee: u64,
+    pub bump: u8,
+}
+
+impl Space for ChainConfig {
+    const INIT_SPACE: usize = 8 + // discriminator
+        8 + // chain_id
+        1 + // enabled
+        4 + 32 + // name (max 32 chars)
+        1 + // address_format
+        1 + // message_encoding
+        4 + 32 + // connected_contract (max 32 bytes)
+        8 + // cross_chain_fee
+        1; // bump
+}
+
+impl ChainConfig {
+    /// Load the registry entry for `chain_id`, failing with `InvalidChainId`
+    /// if the chain is not registered or has been disabled
+    pub fn load_enabled(info: &AccountInfo, chain_id: u64) -> Result<ChainConfig> {
+        require!(
+            info.owner == &crate::ID && !info.data_is_empty(),
+            ErrorCode::InvalidChainId
+        );
+        let data = info.try_borrow_data()?;
+        let chain_config = ChainConfig::try_deserialize(&mut &data[..])
+            .map_err(|_| ErrorCode::InvalidChainId)?;
+        require!(
+            chain_config.chain_id == chain_id && chain_config.enabled,
+            ErrorCode::InvalidChainId
+        );
+        Ok(chain_config)
+    }
+}
+
+/// Receipt proving an inbound gateway message has been processed
+#[account]
+pub struct MessageReceipt {
+    pub source_chain_id: u64,
+    pub message_hash: [u8; 32],
+    pub mint: Pubkey,
+    pub processed: bool,
+    pub processed_at: i64,
+    pub bump: u8,
+}
+
+impl Space for MessageReceipt {
+    const INIT_SPACE: usize = 8 + // discriminator
+        8 + // source_chain_id
+        32 + // message_hash
+        32 + // mint
+        1 + // processed
+        8 + // processed_at
+        1; // bump
+}
+
+impl MessageReceipt {
+    /// Hash identifying an inbound message from `sender` with payload `data`
+    pub fn message_hash(sender: &[u8; 20], data: &[u8]) -> [u8; 32] {
+        anchor_lang::solana_program::hash::hashv(&[sender, data]).to_bytes()
+    }
+}
+
+/// Canonical Solana mint of a cross-chain token, keyed by its origin
+#[account]
+pub struct OriginIndex {
+    pub origin_hash: [u8; 32],
+    pub mint: Pubkey,
+    pub bump: u8,
+}
+
+impl Space for OriginIndex {
+    const INIT_SPACE: usize = 8 + // discriminator
+        32 + // origin_hash
+        32 + // mint
+        1; // bump
+}
+
+impl OriginIndex {
+    /// Hash of the origin triple; each part is length-prefixed so that
+    /// different triples cannot collide by shifting bytes between fields
+    pub fn origin_hash(original_chain: &str, original_contract: &str, original_token_id: &str) -> [u8; 32] {
+        anchor_lang::solana_program::hash::hashv(&[
+            &(original_chain.len() as u32).to_le_bytes(),
+            original_chain.as_bytes(),
+            &(original_contract.len() as u32).to_le_bytes(),
+            original_contract.as_bytes(),
+            &(original_token_id.len() as u32).to_le_bytes(),
+            original_token_id.as_bytes(),
+        ])
+        .to_bytes()
+    }
+
+    /// Address of the origin index for `origin_hash`
+    pub fn find_address(origin_hash: &[u8; 32]) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[ORIGIN_INDEX_SEED, origin_hash], &crate::ID)
+    }
+
+    /// Look up the canonical mint for an origin; `None` if the token has no
+    /// Solana representation yet
+    pub fn lookup(info: &AccountInfo, origin_hash: &[u8; 32]) -> Result<Option<Pubkey>> {
+        require_keys_eq!(info.key(), Self::find_address(origin_hash).0, ErrorCode::InvalidOriginIndex);
+        if info.data_is_empty() {
+            return Ok(None);
+        }
+        require!(info.owner == &crate::ID, ErrorCode::InvalidOriginIndex);
+        let data = info.try_borrow_data()?;
+        let origin_index = OriginIndex::try_deserialize(&mut &data[..])?;
+        Ok(Some(origin_index.mint))
+    }
+}
+
+/// How addresses on a connected chain are encoded
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum AddressFormat {
+    Evm,
+    Solana,
+}
+
+impl AddressFormat {
+    /// Raw address length in bytes
+    pub fn address_len(&self) -> usize {
+        match self {
+            AddressFormat::Evm => 20,
+            AddressFormat::Solana => 32,
+        }
+    }
+
+    /// Check a human-readable recipient (`0x`-prefixed hex or base58)
+    pub fn is_valid_recipient(&self, recipient: &str) -> bool {
+        match self {
+            AddressFormat::Evm => recipient
+                .strip_prefix("0x")
+                .map(|hex| hex.len() == 40 && hex.chars().all(|c| c.is_ascii_hexdigit()))
+                .unwrap_or(false),
+            AddressFormat::Solana => recipient.parse::<Pubkey>().is_ok(),
+        }
+    }
+}
+
+/// Payload encoding understood by a connected chain's Universal NFT contract
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum MessageEncoding {
+    /// Versioned `NftMessage` (see `message.rs`)
+    Borsh,
+    /// Solidity ABI tuple (see `abi.rs`)
+    Abi,
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
 pub enum TransferStatus {
     Initiated,
     Confirmed,
     Completed,
     Failed,
+    Aborted,
+    Cancelled,
 }
 
 /// Seeds for PDAs
 pub const GLOBAL_CONFIG_SEED: &[u8] = b"global_config";
 pub const UNIVERSAL_NFT_SEED: &[u8] = b"universal_nft";
 pub const CROSS_CHAIN_TRANSFER_SEED: &[u8] = b"cross_chain_transfer";
+pub const CHAIN_CONFIG_SEED: &[u8] = b"chain_config";
+pub const MESSAGE_RECEIPT_SEED: &[u8] = b"message_receipt";
+pub const ORIGIN_INDEX_SEED: &[u8] = b"origin_index";
+pub const MINT_AUTHORITY_SEED: &[u8] = b"mint_authority";
+pub const CONNECTED_SEED: &[u8] = b"connected";
+pub const TRANSFER_NONCE_SEED: &[u8] = b"transfer_nonce";
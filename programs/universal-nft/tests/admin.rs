@@ -0,0 +1,340 @@
+//! Configuration, authority handover, pausing, the chain registry and the collection
+
+mod common;
+
+use anchor_lang::{InstructionData, ToAccountMetas};
+use anchor_spl::associated_token::get_associated_token_address;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+use common::*;
+use universal_nft::error::ErrorCode;
+use universal_nft::{
+    AddressFormat, ChainConfig, MessageEncoding, DEFAULT_TRANSFER_TIMEOUT, SOLANA_CHAIN_ID,
+};
+
+fn propose_authority_ix(authority: &Pubkey, new_authority: Pubkey) -> Instruction {
+    Instruction {
+        program_id: universal_nft::ID,
+        accounts: universal_nft::accounts::ProposeAuthority {
+            global_config: global_config(),
+            authority: *authority,
+        }
+        .to_account_metas(None),
+        data: universal_nft::instruction::ProposeAuthority { new_authority }.data(),
+    }
+}
+
+fn accept_authority_ix(new_authority: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: universal_nft::ID,
+        accounts: universal_nft::accounts::AcceptAuthority {
+            global_config: global_config(),
+            new_authority: *new_authority,
+        }
+        .to_account_metas(None),
+        data: universal_nft::instruction::AcceptAuthority {}.data(),
+    }
+}
+
+fn cancel_authority_proposal_ix(authority: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: universal_nft::ID,
+        accounts: universal_nft::accounts::CancelAuthorityProposal {
+            global_config: global_config(),
+            authority: *authority,
+        }
+        .to_account_metas(None),
+        data: universal_nft::instruction::CancelAuthorityProposal {}.data(),
+    }
+}
+
+#[test]
+fn initialize_and_create_collection() {
+    let env = TestEnv::new();
+
+    let config = env.config();
+    assert_eq!(config.authority, env.authority.pubkey());
+    assert_eq!(config.zetachain_gateway, mock_gateway::ID);
+    assert_eq!(
+        config.collection_authority,
+        env.collection_authority.pubkey()
+    );
+    assert_eq!(config.fee_recipient, env.fee_recipient);
+    assert_eq!(config.transfer_timeout, DEFAULT_TRANSFER_TIMEOUT);
+    assert_eq!(config.collection_mint, env.collection_mint);
+    assert_eq!(config.pending_authority, None);
+
+    // The global config PDA holds the collection NFT
+    let collection_token_account =
+        get_associated_token_address(&global_config(), &env.collection_mint);
+    assert_eq!(env.token_amount(&collection_token_account), 1);
+    assert!(env.exists(&master_edition(&env.collection_mint)));
+    assert_eq!(
+        env.metaplex_metadata(&env.collection_mint).update_authority,
+        global_config()
+    );
+}
+
+#[test]
+fn initialize_rejects_default_gateway() {
+    let mut env = TestEnv::bare();
+
+    let ix = env.initialize_ix(Pubkey::default());
+    assert_error(env.send(&[ix], &[]), ErrorCode::GatewayNotConfigured);
+}
+
+#[test]
+fn update_config() {
+    let mut env = TestEnv::new();
+    let guardian = Pubkey::new_unique();
+    let fee_recipient = Pubkey::new_unique();
+
+    let ix = env.update_config_ix(
+        None,
+        None,
+        Some(fee_recipient),
+        Some(25_000),
+        Some(guardian),
+        Some(3_600),
+    );
+    env.send(&[ix], &[]).unwrap();
+
+    let config = env.config();
+    assert_eq!(config.fee_recipient, fee_recipient);
+    assert_eq!(config.cross_chain_fee, 25_000);
+    assert_eq!(config.guardian, guardian);
+    assert_eq!(config.transfer_timeout, 3_600);
+    assert_eq!(config.zetachain_gateway, mock_gateway::ID);
+}
+
+#[test]
+fn update_config_errors() {
+    let mut env = TestEnv::new();
+    let stranger = env.user();
+
+    let ix = env.update_config_ix(None, None, None, Some(1), None, None);
+    let ix = replace_account(ix, &env.authority.pubkey(), &stranger.pubkey());
+    assert_error(env.send(&[ix], &[&stranger]), ErrorCode::Unauthorized);
+
+    let ix = env.update_config_ix(Some(Pubkey::default()), None, None, None, None, None);
+    assert_error(env.send(&[ix], &[]), ErrorCode::GatewayNotConfigured);
+
+    let ix = env.update_config_ix(None, None, None, None, None, Some(0));
+    assert_error(env.send(&[ix], &[]), ErrorCode::InvalidTransferTimeout);
+}
+
+#[test]
+fn authority_handover() {
+    let mut env = TestEnv::new();
+    let new_authority = env.user();
+
+    let ix = propose_authority_ix(&env.authority.pubkey(), new_authority.pubkey());
+    env.send(&[ix], &[]).unwrap();
+    assert_eq!(env.config().pending_authority, Some(new_authority.pubkey()));
+
+    env.send(
+        &[accept_authority_ix(&new_authority.pubkey())],
+        &[&new_authority],
+    )
+    .unwrap();
+    let config = env.config();
+    assert_eq!(config.authority, new_authority.pubkey());
+    assert_eq!(config.pending_authority, None);
+}
+
+#[test]
+fn cancel_authority_proposal() {
+    let mut env = TestEnv::new();
+
+    let ix = propose_authority_ix(&env.authority.pubkey(), Pubkey::new_unique());
+    env.send(&[ix], &[]).unwrap();
+    env.send(
+        &[cancel_authority_proposal_ix(&env.authority.pubkey())],
+        &[],
+    )
+    .unwrap();
+    assert_eq!(env.config().pending_authority, None);
+
+    // Nothing left to cancel
+    let ix = cancel_authority_proposal_ix(&env.authority.pubkey());
+    assert_error(env.send(&[ix], &[]), ErrorCode::NoPendingAuthority);
+}
+
+#[test]
+fn authority_handover_errors() {
+    let mut env = TestEnv::new();
+    let new_authority = env.user();
+    let stranger = env.user();
+
+    let ix = propose_authority_ix(&env.authority.pubkey(), Pubkey::default());
+    assert_error(env.send(&[ix], &[]), ErrorCode::InvalidAuthority);
+
+    let ix = propose_authority_ix(&env.authority.pubkey(), env.authority.pubkey());
+    assert_error(env.send(&[ix], &[]), ErrorCode::InvalidAuthority);
+
+    let ix = propose_authority_ix(&stranger.pubkey(), new_authority.pubkey());
+    assert_error(env.send(&[ix], &[&stranger]), ErrorCode::Unauthorized);
+
+    let ix = accept_authority_ix(&new_authority.pubkey());
+    assert_error(
+        env.send(&[ix], &[&new_authority]),
+        ErrorCode::NoPendingAuthority,
+    );
+
+    let ix = propose_authority_ix(&env.authority.pubkey(), new_authority.pubkey());
+    env.send(&[ix], &[]).unwrap();
+    let ix = accept_authority_ix(&stranger.pubkey());
+    assert_error(env.send(&[ix], &[&stranger]), ErrorCode::Unauthorized);
+}
+
+#[test]
+fn guardian_pauses_minting() {
+    let mut env = TestEnv::new();
+    let guardian = env.user();
+    let stranger = env.user();
+    let owner = Pubkey::new_unique();
+
+    let ix = env.update_config_ix(None, None, None, None, Some(guardian.pubkey()), None);
+    env.send(&[ix], &[]).unwrap();
+
+    let ix = env.set_pause_ix(&guardian.pubkey(), Some(true), None, None);
+    env.send(&[ix], &[&guardian]).unwrap();
+    assert!(env.config().mint_paused);
+
+    let mint = Keypair::new();
+    let ix = env.mint_nft_ix(&mint.pubkey(), &owner, "solana", "native", "1");
+    let collection_authority = env.collection_authority.insecure_clone();
+    assert_error(
+        env.send(&[ix], &[&mint, &collection_authority]),
+        ErrorCode::ProgramPaused,
+    );
+
+    let ix = env.set_pause_ix(&stranger.pubkey(), Some(false), None, None);
+    assert_error(env.send(&[ix], &[&stranger]), ErrorCode::Unauthorized);
+
+    let ix = env.set_pause_ix(&env.authority.pubkey(), Some(false), None, None);
+    env.send(&[ix], &[]).unwrap();
+    env.mint_nft(&owner, "1");
+}
+
+#[test]
+fn register_and_update_chain() {
+    let mut env = TestEnv::new();
+    let contract = Pubkey::new_unique();
+
+    let ix = env.register_chain_ix(
+        1001,
+        "eclipse",
+        AddressFormat::Solana,
+        MessageEncoding::Abi,
+        contract.to_bytes().to_vec(),
+        None,
+    );
+    env.send(&[ix], &[]).unwrap();
+
+    let chain: ChainConfig = env.account(&chain_config(1001));
+    assert_eq!(chain.chain_id, 1001);
+    assert!(chain.enabled);
+    assert_eq!(chain.name, "eclipse");
+    assert!(chain.address_format == AddressFormat::Solana);
+    assert!(chain.message_encoding == MessageEncoding::Abi);
+    assert_eq!(chain.connected_contract, contract.to_bytes().to_vec());
+
+    let ix = env.update_chain_ix(1001, Some(false), Some("eclipse-mainnet".to_string()), None);
+    env.send(&[ix], &[]).unwrap();
+    let chain: ChainConfig = env.account(&chain_config(1001));
+    assert!(!chain.enabled);
+    assert_eq!(chain.name, "eclipse-mainnet");
+}
+
+#[test]
+fn register_chain_errors() {
+    let mut env = TestEnv::new();
+    let stranger = env.user();
+
+    for chain_id in [0, SOLANA_CHAIN_ID] {
+        let ix = env.register_chain_ix(
+            chain_id,
+            "bad",
+            AddressFormat::Evm,
+            MessageEncoding::Borsh,
+            vec![1; 20],
+            None,
+        );
+        assert_error(env.send(&[ix], &[]), ErrorCode::InvalidChainId);
+    }
+
+    // The contract must be an address of the chain's format
+    let ix = env.register_chain_ix(
+        1,
+        "ethereum",
+        AddressFormat::Evm,
+        MessageEncoding::Abi,
+        vec![1; 32],
+        None,
+    );
+    assert_error(env.send(&[ix], &[]), ErrorCode::InvalidChainConfig);
+
+    let ix = env.register_chain_ix(
+        1,
+        "",
+        AddressFormat::Evm,
+        MessageEncoding::Abi,
+        vec![1; 20],
+        None,
+    );
+    assert_error(env.send(&[ix], &[]), ErrorCode::InvalidChainConfig);
+
+    let ix = env.register_chain_ix(
+        1,
+        "ethereum",
+        AddressFormat::Evm,
+        MessageEncoding::Abi,
+        vec![1; 20],
+        None,
+    );
+    let ix = replace_account(ix, &env.authority.pubkey(), &stranger.pubkey());
+    assert_error(env.send(&[ix], &[&stranger]), ErrorCode::Unauthorized);
+
+    let ix = env.update_chain_ix(POLYGON_CHAIN_ID, None, None, Some(vec![1; 32]));
+    assert_error(env.send(&[ix], &[]), ErrorCode::InvalidChainConfig);
+
+    let ix = env.update_chain_ix(POLYGON_CHAIN_ID, None, Some(String::new()), None);
+    assert_error(env.send(&[ix], &[]), ErrorCode::InvalidChainConfig);
+}
+
+#[test]
+fn create_collection_errors() {
+    let mut env = TestEnv::bare();
+    let ix = env.initialize_ix(mock_gateway::ID);
+    env.send(&[ix], &[]).unwrap();
+
+    let collection_mint = Keypair::new();
+    let ix = env.create_collection_ix(&collection_mint.pubkey(), "Universal NFT", "UNFT", "");
+    assert_error(
+        env.send(&[ix], &[&collection_mint]),
+        ErrorCode::InvalidMetadataUri,
+    );
+
+    let ix = env.create_collection_ix(
+        &collection_mint.pubkey(),
+        "Universal NFT",
+        "UNFT",
+        "https://example.com/c.json",
+    );
+    env.send(&[ix], &[&collection_mint]).unwrap();
+
+    let second = Keypair::new();
+    let ix = env.create_collection_ix(
+        &second.pubkey(),
+        "Universal NFT",
+        "UNFT",
+        "https://example.com/c.json",
+    );
+    assert_error(
+        env.send(&[ix], &[&second]),
+        ErrorCode::CollectionAlreadyExists,
+    );
+}
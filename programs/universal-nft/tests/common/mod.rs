@@ -0,0 +1,1053 @@
+//! In-process test harness: loads the compiled programs into a LiteSVM bank
+//! and builds the instructions the integration tests send.
+//!
+//! Build the programs with `anchor build` and dump the Metaplex program with
+//! `yarn fixtures`, then run `cargo test --features litesvm-tests`. Without
+//! the feature cargo skips these suites, so a plain `cargo test` needs no
+//! network.
+
+#![allow(dead_code, clippy::result_large_err)]
+
+use std::path::PathBuf;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_lang::system_program;
+use anchor_lang::{
+    AccountDeserialize, AccountSerialize, AnchorDeserialize, Event, InstructionData, ToAccountMetas,
+};
+use anchor_spl::associated_token::{self, get_associated_token_address};
+use anchor_spl::metadata::mpl_token_metadata;
+use anchor_spl::token::{self, spl_token, TokenAccount};
+use base64::prelude::{Engine, BASE64_STANDARD};
+use litesvm::types::TransactionResult;
+use litesvm::LiteSVM;
+use solana_sdk::account::Account;
+use solana_sdk::clock::Clock;
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::instruction::{Instruction, InstructionError};
+use solana_sdk::program_pack::Pack;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::sysvar;
+use solana_sdk::transaction::{Transaction, TransactionError};
+
+use universal_nft::error::ErrorCode;
+use universal_nft::{
+    AddressFormat, CrossChainTransfer, MessageEncoding, MessageHeader, NftMessage, OriginIndex,
+    TransferNonce, CHAIN_CONFIG_SEED, CONNECTED_SEED, GLOBAL_CONFIG_SEED, MESSAGE_RECEIPT_SEED,
+    MESSAGE_VERSION, MINT_AUTHORITY_SEED, SOLANA_CHAIN_ID, TRANSFER_NONCE_SEED, UNIVERSAL_NFT_SEED,
+};
+
+pub const LAMPORTS_PER_SOL: u64 = 1_000_000_000;
+
+/// Connected EVM chain registered by `TestEnv::new`
+pub const POLYGON_CHAIN_ID: u64 = 137;
+
+/// Universal NFT contract registered for Polygon
+pub const POLYGON_CONTRACT: [u8; 20] = [0x11; 20];
+
+/// Cross-chain fee charged for Polygon, in lamports
+pub const POLYGON_FEE: u64 = 10_000;
+
+/// A valid EVM recipient for outbound transfers
+pub const EVM_RECIPIENT: &str = "0xabcdefabcdefabcdefabcdefabcdefabcdefabcd";
+
+// Addresses
+
+pub fn global_config() -> Pubkey {
+    Pubkey::find_program_address(&[GLOBAL_CONFIG_SEED], &universal_nft::ID).0
+}
+
+pub fn mint_authority() -> Pubkey {
+    Pubkey::find_program_address(&[MINT_AUTHORITY_SEED], &universal_nft::ID).0
+}
+
+pub fn connected() -> Pubkey {
+    Pubkey::find_program_address(&[CONNECTED_SEED], &universal_nft::ID).0
+}
+
+pub fn chain_config(chain_id: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[CHAIN_CONFIG_SEED, &chain_id.to_le_bytes()],
+        &universal_nft::ID,
+    )
+    .0
+}
+
+pub fn universal_nft_pda(mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[UNIVERSAL_NFT_SEED, mint.as_ref()], &universal_nft::ID).0
+}
+
+pub fn transfer_nonce(owner: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[TRANSFER_NONCE_SEED, owner.as_ref()], &universal_nft::ID).0
+}
+
+pub fn transfer_pda(transfer_id: &[u8; 32]) -> Pubkey {
+    CrossChainTransfer::find_address(transfer_id).0
+}
+
+pub fn message_receipt(chain_id: u64, sender: &[u8; 20], data: &[u8]) -> Pubkey {
+    let message_hash = universal_nft::MessageReceipt::message_hash(sender, data);
+    Pubkey::find_program_address(
+        &[MESSAGE_RECEIPT_SEED, &chain_id.to_le_bytes(), &message_hash],
+        &universal_nft::ID,
+    )
+    .0
+}
+
+pub fn origin_index(
+    original_chain: &str,
+    original_contract: &str,
+    original_token_id: &str,
+) -> Pubkey {
+    let origin_hash =
+        OriginIndex::origin_hash(original_chain, original_contract, original_token_id);
+    OriginIndex::find_address(&origin_hash).0
+}
+
+pub fn escrow(mint: &Pubkey) -> Pubkey {
+    get_associated_token_address(&global_config(), mint)
+}
+
+pub fn metadata(mint: &Pubkey) -> Pubkey {
+    mpl_token_metadata::accounts::Metadata::find_pda(mint).0
+}
+
+pub fn master_edition(mint: &Pubkey) -> Pubkey {
+    mpl_token_metadata::accounts::MasterEdition::find_pda(mint).0
+}
+
+pub fn gateway_pda() -> Pubkey {
+    Pubkey::find_program_address(&[mock_gateway::GATEWAY_SEED], &mock_gateway::ID).0
+}
+
+/// Path of a compiled program or fixture, relative to the workspace root
+fn program_path(relative: &str) -> PathBuf {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("../..")
+        .join(relative);
+    assert!(
+        path.exists(),
+        "{} is missing: run `anchor build` and `yarn fixtures` first",
+        path.display()
+    );
+    path
+}
+
+/// A bank with both programs and Metaplex loaded
+pub struct TestEnv {
+    pub svm: LiteSVM,
+    /// Program authority; pays for every transaction
+    pub authority: Keypair,
+    pub collection_authority: Keypair,
+    pub fee_recipient: Pubkey,
+    pub collection_mint: Pubkey,
+}
+
+impl TestEnv {
+    /// Programs loaded and accounts funded, nothing initialized
+    pub fn bare() -> Self {
+        let mut svm = LiteSVM::new();
+        svm.add_program_from_file(
+            universal_nft::ID,
+            program_path("target/deploy/universal_nft.so"),
+        )
+        .unwrap();
+        svm.add_program_from_file(
+            mock_gateway::ID,
+            program_path("target/deploy/mock_gateway.so"),
+        )
+        .unwrap();
+        svm.add_program_from_file(
+            mpl_token_metadata::ID,
+            program_path("programs/universal-nft/tests/fixtures/mpl_token_metadata.so"),
+        )
+        .unwrap();
+
+        let authority = Keypair::new();
+        let collection_authority = Keypair::new();
+        let fee_recipient = Pubkey::new_unique();
+        svm.airdrop(&authority.pubkey(), 100 * LAMPORTS_PER_SOL)
+            .unwrap();
+        svm.airdrop(&collection_authority.pubkey(), LAMPORTS_PER_SOL)
+            .unwrap();
+        // Funded so small fees keep it rent exempt
+        svm.airdrop(&fee_recipient, LAMPORTS_PER_SOL).unwrap();
+
+        TestEnv {
+            svm,
+            authority,
+            collection_authority,
+            fee_recipient,
+            collection_mint: Pubkey::default(),
+        }
+    }
+
+    /// Initialized program using the mock gateway, with Polygon registered
+    /// and the collection created
+    pub fn new() -> Self {
+        let mut env = Self::bare();
+
+        let init_mock = Instruction {
+            program_id: mock_gateway::ID,
+            accounts: mock_gateway::accounts::Initialize {
+                pda: gateway_pda(),
+                authority: env.authority.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: mock_gateway::instruction::Initialize {}.data(),
+        };
+        let initialize = env.initialize_ix(mock_gateway::ID);
+        let register = env.register_chain_ix(
+            POLYGON_CHAIN_ID,
+            "polygon",
+            AddressFormat::Evm,
+            MessageEncoding::Borsh,
+            POLYGON_CONTRACT.to_vec(),
+            Some(POLYGON_FEE),
+        );
+        env.send(&[init_mock, initialize, register], &[]).unwrap();
+
+        let collection_mint = Keypair::new();
+        let create_collection = env.create_collection_ix(
+            &collection_mint.pubkey(),
+            "Universal NFT",
+            "UNFT",
+            "https://example.com/collection.json",
+        );
+        env.send(&[create_collection], &[&collection_mint]).unwrap();
+        env.collection_mint = collection_mint.pubkey();
+
+        env
+    }
+
+    /// Send `instructions` with the authority as fee payer, under a compute
+    /// budget large enough for the Metaplex CPIs
+    pub fn send(
+        &mut self,
+        instructions: &[Instruction],
+        signers: &[&Keypair],
+    ) -> TransactionResult {
+        let mut all = vec![ComputeBudgetInstruction::set_compute_unit_limit(1_400_000)];
+        all.extend_from_slice(instructions);
+
+        let mut keypairs = vec![&self.authority];
+        keypairs.extend(
+            signers
+                .iter()
+                .filter(|signer| signer.pubkey() != self.authority.pubkey()),
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &all,
+            Some(&self.authority.pubkey()),
+            &keypairs,
+            self.svm.latest_blockhash(),
+        );
+        let result = self.svm.send_transaction(tx);
+        // Identical transactions later in a test must not be rejected as duplicates
+        self.svm.expire_blockhash();
+        result
+    }
+
+    /// A funded keypair
+    pub fn user(&mut self) -> Keypair {
+        let user = Keypair::new();
+        self.svm
+            .airdrop(&user.pubkey(), 10 * LAMPORTS_PER_SOL)
+            .unwrap();
+        user
+    }
+
+    /// Move the clock forward by `seconds`
+    pub fn warp(&mut self, seconds: i64) {
+        let mut clock: Clock = self.svm.get_sysvar();
+        clock.unix_timestamp += seconds;
+        self.svm.set_sysvar(&clock);
+    }
+
+    pub fn account<T: AccountDeserialize>(&self, address: &Pubkey) -> T {
+        let account = self
+            .svm
+            .get_account(address)
+            .expect("account does not exist");
+        T::try_deserialize(&mut account.data.as_slice()).unwrap()
+    }
+
+    pub fn exists(&self, address: &Pubkey) -> bool {
+        self.svm
+            .get_account(address)
+            .map(|account| account.lamports > 0)
+            .unwrap_or(false)
+    }
+
+    pub fn balance(&self, address: &Pubkey) -> u64 {
+        self.svm.get_balance(address).unwrap_or(0)
+    }
+
+    pub fn token_amount(&self, token_account: &Pubkey) -> u64 {
+        self.account::<TokenAccount>(token_account).amount
+    }
+
+    pub fn metaplex_metadata(&self, mint: &Pubkey) -> mpl_token_metadata::accounts::Metadata {
+        let account = self
+            .svm
+            .get_account(&metadata(mint))
+            .expect("metadata does not exist");
+        mpl_token_metadata::accounts::Metadata::safe_deserialize(&account.data).unwrap()
+    }
+
+    /// Write `value` into a program-owned account of `space` bytes, to set up
+    /// states the instructions cannot reach
+    pub fn set_program_account<T: AccountSerialize>(
+        &mut self,
+        address: Pubkey,
+        value: &T,
+        space: usize,
+    ) {
+        let mut data = Vec::with_capacity(space);
+        value.try_serialize(&mut data).unwrap();
+        data.resize(space, 0);
+        self.svm
+            .set_account(
+                address,
+                Account {
+                    lamports: self.svm.minimum_balance_for_rent_exemption(space),
+                    data,
+                    owner: universal_nft::ID,
+                    executable: false,
+                    rent_epoch: 0,
+                },
+            )
+            .unwrap();
+    }
+
+    /// Make `gateway` the configured gateway, e.g. a keypair that can sign
+    /// `confirm_cross_chain_transfer`
+    pub fn set_gateway(&mut self, gateway: Pubkey) {
+        let ix = self.update_config_ix(Some(gateway), None, None, None, None, None);
+        self.send(&[ix], &[]).unwrap();
+    }
+
+    /// Mint a universal NFT originating on Solana to `owner`
+    pub fn mint_nft(&mut self, owner: &Pubkey, token_id: &str) -> Pubkey {
+        let mint = Keypair::new();
+        let ix = self.mint_nft_ix(&mint.pubkey(), owner, "solana", "native", token_id);
+        let collection_authority = self.collection_authority.insecure_clone();
+        self.send(&[ix], &[&mint, &collection_authority]).unwrap();
+        mint.pubkey()
+    }
+
+    /// Id the owner's next outbound transfer of `mint` will get
+    pub fn next_transfer_id(&self, mint: &Pubkey, owner: &Pubkey) -> [u8; 32] {
+        let nonce = self
+            .svm
+            .get_account(&transfer_nonce(owner))
+            .map(|account| {
+                TransferNonce::try_deserialize(&mut account.data.as_slice())
+                    .unwrap()
+                    .nonce
+            })
+            .unwrap_or(0);
+        CrossChainTransfer::transfer_id(mint, owner, nonce)
+    }
+
+    /// Lock `mint` for a transfer to Polygon and return the transfer id
+    pub fn initiate(&mut self, mint: &Pubkey, owner: &Keypair) -> [u8; 32] {
+        let transfer_id = self.next_transfer_id(mint, &owner.pubkey());
+        let ix = self.initiate_ix(mint, owner, POLYGON_CHAIN_ID, EVM_RECIPIENT);
+        self.send(&[ix], &[owner]).unwrap();
+        transfer_id
+    }
+
+    /// Lock `mint` and send it to Polygon through the gateway
+    pub fn deposit_and_call(&mut self, mint: &Pubkey, owner: &Keypair) -> [u8; 32] {
+        let transfer_id = self.next_transfer_id(mint, &owner.pubkey());
+        let ix = self.deposit_and_call_ix(mint, owner, POLYGON_CHAIN_ID, [0xab; 20], None);
+        self.send(&[ix], &[owner]).unwrap();
+        transfer_id
+    }
+
+    /// Create the associated token account of `owner` for `mint`
+    pub fn create_token_account(&mut self, owner: &Pubkey, mint: &Pubkey) -> Pubkey {
+        let ix = associated_token::spl_associated_token_account::instruction::create_associated_token_account(
+            &self.authority.pubkey(),
+            owner,
+            mint,
+            &token::ID,
+        );
+        self.send(&[ix], &[]).unwrap();
+        get_associated_token_address(owner, mint)
+    }
+
+    /// Create an empty NFT mint controlled by the mint authority PDA, and a
+    /// token account of `owner` for it, as a relayer does before an inbound mint
+    pub fn create_inbound_mint(&mut self, owner: &Pubkey) -> Pubkey {
+        let mint = Pubkey::new_unique();
+        let state = spl_token::state::Mint {
+            mint_authority: COption::Some(mint_authority()),
+            supply: 0,
+            decimals: 0,
+            is_initialized: true,
+            freeze_authority: COption::Some(mint_authority()),
+        };
+        let mut data = vec![0; spl_token::state::Mint::LEN];
+        spl_token::state::Mint::pack(state, &mut data).unwrap();
+        self.svm
+            .set_account(
+                mint,
+                Account {
+                    lamports: self.svm.minimum_balance_for_rent_exemption(data.len()),
+                    data,
+                    owner: token::ID,
+                    executable: false,
+                    rent_epoch: 0,
+                },
+            )
+            .unwrap();
+        self.create_token_account(owner, &mint);
+        mint
+    }
+
+    // Instruction builders. Each uses the environment's authority as payer.
+
+    pub fn initialize_ix(&self, gateway: Pubkey) -> Instruction {
+        let (global_config, bump) =
+            Pubkey::find_program_address(&[GLOBAL_CONFIG_SEED], &universal_nft::ID);
+        Instruction {
+            program_id: universal_nft::ID,
+            accounts: universal_nft::accounts::Initialize {
+                global_config,
+                authority: self.authority.pubkey(),
+                zetachain_gateway: gateway,
+                collection_authority: self.collection_authority.pubkey(),
+                fee_recipient: self.fee_recipient,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: universal_nft::instruction::Initialize {
+                bump,
+                cross_chain_fee: None,
+            }
+            .data(),
+        }
+    }
+
+    pub fn update_config_ix(
+        &self,
+        zetachain_gateway: Option<Pubkey>,
+        collection_authority: Option<Pubkey>,
+        fee_recipient: Option<Pubkey>,
+        cross_chain_fee: Option<u64>,
+        guardian: Option<Pubkey>,
+        transfer_timeout: Option<i64>,
+    ) -> Instruction {
+        Instruction {
+            program_id: universal_nft::ID,
+            accounts: universal_nft::accounts::UpdateConfig {
+                global_config: global_config(),
+                authority: self.authority.pubkey(),
+            }
+            .to_account_metas(None),
+            data: universal_nft::instruction::UpdateConfig {
+                zetachain_gateway,
+                collection_authority,
+                fee_recipient,
+                cross_chain_fee,
+                guardian,
+                transfer_timeout,
+            }
+            .data(),
+        }
+    }
+
+    pub fn register_chain_ix(
+        &self,
+        chain_id: u64,
+        name: &str,
+        address_format: AddressFormat,
+        message_encoding: MessageEncoding,
+        connected_contract: Vec<u8>,
+        cross_chain_fee: Option<u64>,
+    ) -> Instruction {
+        Instruction {
+            program_id: universal_nft::ID,
+            accounts: universal_nft::accounts::RegisterChain {
+                global_config: global_config(),
+                chain_config: chain_config(chain_id),
+                authority: self.authority.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: universal_nft::instruction::RegisterChain {
+                chain_id,
+                name: name.to_string(),
+                address_format,
+                message_encoding,
+                connected_contract,
+                cross_chain_fee,
+            }
+            .data(),
+        }
+    }
+
+    pub fn update_chain_ix(
+        &self,
+        chain_id: u64,
+        enabled: Option<bool>,
+        name: Option<String>,
+        connected_contract: Option<Vec<u8>>,
+    ) -> Instruction {
+        Instruction {
+            program_id: universal_nft::ID,
+            accounts: universal_nft::accounts::UpdateChain {
+                global_config: global_config(),
+                chain_config: chain_config(chain_id),
+                authority: self.authority.pubkey(),
+            }
+            .to_account_metas(None),
+            data: universal_nft::instruction::UpdateChain {
+                chain_id,
+                enabled,
+                name,
+                message_encoding: None,
+                connected_contract,
+                cross_chain_fee: None,
+            }
+            .data(),
+        }
+    }
+
+    pub fn set_pause_ix(
+        &self,
+        signer: &Pubkey,
+        mint_paused: Option<bool>,
+        outbound_paused: Option<bool>,
+        inbound_paused: Option<bool>,
+    ) -> Instruction {
+        Instruction {
+            program_id: universal_nft::ID,
+            accounts: universal_nft::accounts::SetPause {
+                global_config: global_config(),
+                signer: *signer,
+            }
+            .to_account_metas(None),
+            data: universal_nft::instruction::SetPause {
+                mint_paused,
+                outbound_paused,
+                inbound_paused,
+            }
+            .data(),
+        }
+    }
+
+    pub fn create_collection_ix(
+        &self,
+        collection_mint: &Pubkey,
+        name: &str,
+        symbol: &str,
+        uri: &str,
+    ) -> Instruction {
+        Instruction {
+            program_id: universal_nft::ID,
+            accounts: universal_nft::accounts::CreateCollection {
+                global_config: global_config(),
+                collection_mint: *collection_mint,
+                mint_authority: mint_authority(),
+                collection_token_account: get_associated_token_address(
+                    &global_config(),
+                    collection_mint,
+                ),
+                collection_metadata: metadata(collection_mint),
+                collection_master_edition: master_edition(collection_mint),
+                authority: self.authority.pubkey(),
+                token_program: token::ID,
+                associated_token_program: associated_token::ID,
+                token_metadata_program: mpl_token_metadata::ID,
+                system_program: system_program::ID,
+                rent: sysvar::rent::ID,
+            }
+            .to_account_metas(None),
+            data: universal_nft::instruction::CreateCollection {
+                name: name.to_string(),
+                symbol: symbol.to_string(),
+                uri: uri.to_string(),
+            }
+            .data(),
+        }
+    }
+
+    pub fn mint_nft_ix(
+        &self,
+        mint: &Pubkey,
+        recipient: &Pubkey,
+        original_chain: &str,
+        original_contract: &str,
+        original_token_id: &str,
+    ) -> Instruction {
+        let (universal_nft, bump) =
+            Pubkey::find_program_address(&[UNIVERSAL_NFT_SEED, mint.as_ref()], &universal_nft::ID);
+        Instruction {
+            program_id: universal_nft::ID,
+            accounts: universal_nft::accounts::MintNft {
+                global_config: global_config(),
+                universal_nft,
+                origin_index: origin_index(original_chain, original_contract, original_token_id),
+                mint: *mint,
+                token_account: get_associated_token_address(recipient, mint),
+                mint_authority: mint_authority(),
+                metadata: metadata(mint),
+                master_edition: master_edition(mint),
+                collection_mint: self.collection_mint,
+                collection_metadata: metadata(&self.collection_mint),
+                collection_master_edition: master_edition(&self.collection_mint),
+                payer: self.authority.pubkey(),
+                recipient: *recipient,
+                collection_authority: self.collection_authority.pubkey(),
+                token_program: token::ID,
+                associated_token_program: associated_token::ID,
+                token_metadata_program: mpl_token_metadata::ID,
+                system_program: system_program::ID,
+                rent: sysvar::rent::ID,
+            }
+            .to_account_metas(None),
+            data: universal_nft::instruction::MintNft {
+                bump,
+                name: "Universal NFT".to_string(),
+                symbol: "UNFT".to_string(),
+                uri: "https://example.com/nft.json".to_string(),
+                original_chain: original_chain.to_string(),
+                original_contract: original_contract.to_string(),
+                original_token_id: original_token_id.to_string(),
+            }
+            .data(),
+        }
+    }
+
+    pub fn initiate_ix(
+        &self,
+        mint: &Pubkey,
+        owner: &Keypair,
+        destination_chain_id: u64,
+        recipient: &str,
+    ) -> Instruction {
+        let owner = owner.pubkey();
+        let (cross_chain_transfer, _) =
+            CrossChainTransfer::find_address(&self.next_transfer_id(mint, &owner));
+        Instruction {
+            program_id: universal_nft::ID,
+            accounts: universal_nft::accounts::InitiateCrossChainTransfer {
+                global_config: global_config(),
+                universal_nft: universal_nft_pda(mint),
+                transfer_nonce: transfer_nonce(&owner),
+                cross_chain_transfer,
+                chain_config: chain_config(destination_chain_id),
+                nft_mint: *mint,
+                owner_token_account: get_associated_token_address(&owner, mint),
+                escrow_token_account: escrow(mint),
+                owner,
+                payer: self.authority.pubkey(),
+                zetachain_gateway: self.config().zetachain_gateway,
+                fee_recipient: self.fee_recipient,
+                token_program: token::ID,
+                associated_token_program: associated_token::ID,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: universal_nft::instruction::InitiateCrossChainTransfer {
+                destination_chain_id,
+                destination_recipient: recipient.to_string(),
+            }
+            .data(),
+        }
+    }
+
+    pub fn deposit_and_call_ix(
+        &self,
+        mint: &Pubkey,
+        owner: &Keypair,
+        destination_chain_id: u64,
+        recipient: [u8; 20],
+        revert_options: Option<universal_nft::gateway_cpi::RevertOptions>,
+    ) -> Instruction {
+        let owner = owner.pubkey();
+        let (cross_chain_transfer, _) =
+            CrossChainTransfer::find_address(&self.next_transfer_id(mint, &owner));
+        Instruction {
+            program_id: universal_nft::ID,
+            accounts: universal_nft::accounts::DepositAndCall {
+                global_config: global_config(),
+                universal_nft: universal_nft_pda(mint),
+                transfer_nonce: transfer_nonce(&owner),
+                cross_chain_transfer,
+                chain_config: chain_config(destination_chain_id),
+                nft_mint: *mint,
+                owner_token_account: get_associated_token_address(&owner, mint),
+                escrow_token_account: escrow(mint),
+                owner,
+                payer: self.authority.pubkey(),
+                gateway_pda: gateway_pda(),
+                gateway_program: mock_gateway::ID,
+                token_program: token::ID,
+                associated_token_program: associated_token::ID,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: universal_nft::instruction::DepositAndCall {
+                destination_chain_id,
+                destination_recipient: recipient,
+                revert_options,
+            }
+            .data(),
+        }
+    }
+
+    pub fn confirm_ix(&self, transfer_id: [u8; 32], gateway: &Pubkey) -> Instruction {
+        Instruction {
+            program_id: universal_nft::ID,
+            accounts: universal_nft::accounts::ConfirmCrossChainTransfer {
+                global_config: global_config(),
+                cross_chain_transfer: transfer_pda(&transfer_id),
+                zetachain_gateway: *gateway,
+            }
+            .to_account_metas(None),
+            data: universal_nft::instruction::ConfirmCrossChainTransfer { transfer_id }.data(),
+        }
+    }
+
+    pub fn complete_ix(
+        &self,
+        transfer_id: [u8; 32],
+        mint: &Pubkey,
+        gateway: &Pubkey,
+    ) -> Instruction {
+        Instruction {
+            program_id: universal_nft::ID,
+            accounts: universal_nft::accounts::CompleteCrossChainTransfer {
+                global_config: global_config(),
+                universal_nft: universal_nft_pda(mint),
+                cross_chain_transfer: transfer_pda(&transfer_id),
+                nft_mint: *mint,
+                zetachain_gateway: *gateway,
+            }
+            .to_account_metas(None),
+            data: universal_nft::instruction::CompleteCrossChainTransfer { transfer_id }.data(),
+        }
+    }
+
+    pub fn cancel_ix(&self, transfer_id: [u8; 32], mint: &Pubkey, owner: &Pubkey) -> Instruction {
+        Instruction {
+            program_id: universal_nft::ID,
+            accounts: universal_nft::accounts::CancelCrossChainTransfer {
+                global_config: global_config(),
+                universal_nft: universal_nft_pda(mint),
+                cross_chain_transfer: transfer_pda(&transfer_id),
+                nft_mint: *mint,
+                escrow_token_account: escrow(mint),
+                owner_token_account: get_associated_token_address(owner, mint),
+                owner: *owner,
+                token_program: token::ID,
+                associated_token_program: associated_token::ID,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: universal_nft::instruction::CancelCrossChainTransfer { transfer_id }.data(),
+        }
+    }
+
+    pub fn close_transfer_ix(&self, transfer_id: [u8; 32], payer: &Pubkey) -> Instruction {
+        let transfer: CrossChainTransfer = self.account(&transfer_pda(&transfer_id));
+        Instruction {
+            program_id: universal_nft::ID,
+            accounts: universal_nft::accounts::CloseTransfer {
+                cross_chain_transfer: transfer_pda(&transfer_id),
+                universal_nft: universal_nft_pda(&transfer.nft_mint),
+                payer: *payer,
+            }
+            .to_account_metas(None),
+            data: universal_nft::instruction::CloseTransfer { transfer_id }.data(),
+        }
+    }
+
+    pub fn reclaim_ix(
+        &self,
+        transfer_id: [u8; 32],
+        mint: &Pubkey,
+        claimant: &Pubkey,
+    ) -> Instruction {
+        Instruction {
+            program_id: universal_nft::ID,
+            accounts: universal_nft::accounts::ReclaimAbortedNft {
+                global_config: global_config(),
+                universal_nft: universal_nft_pda(mint),
+                cross_chain_transfer: transfer_pda(&transfer_id),
+                mint: *mint,
+                escrow_token_account: escrow(mint),
+                claimant_token_account: get_associated_token_address(claimant, mint),
+                claimant: *claimant,
+                token_program: token::ID,
+                associated_token_program: associated_token::ID,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: universal_nft::instruction::ReclaimAbortedNft { transfer_id }.data(),
+        }
+    }
+
+    /// Accounts of an `on_call` delivering `data` from Polygon to `recipient`.
+    /// Mint messages need `origin`; returns and unlocks need the escrow; burn
+    /// acknowledgements need the transfer.
+    pub fn on_call_accounts(
+        &self,
+        data: &[u8],
+        mint: &Pubkey,
+        recipient: &Pubkey,
+        origin: Option<Pubkey>,
+        escrow_token_account: Option<Pubkey>,
+        cross_chain_transfer: Option<Pubkey>,
+    ) -> universal_nft::accounts::OnCall {
+        universal_nft::accounts::OnCall {
+            global_config: global_config(),
+            universal_nft: universal_nft_pda(mint),
+            source_chain_config: chain_config(POLYGON_CHAIN_ID),
+            message_receipt: message_receipt(POLYGON_CHAIN_ID, &POLYGON_CONTRACT, data),
+            mint: *mint,
+            origin_index: origin,
+            token_account: Some(get_associated_token_address(recipient, mint)),
+            escrow_token_account,
+            cross_chain_transfer,
+            mint_authority: mint_authority(),
+            metadata: Some(metadata(mint)),
+            master_edition: origin.map(|_| master_edition(mint)),
+            collection_mint: origin.map(|_| self.collection_mint),
+            collection_metadata: origin.map(|_| metadata(&self.collection_mint)),
+            collection_master_edition: origin.map(|_| master_edition(&self.collection_mint)),
+            gateway_pda: gateway_pda(),
+            payer: self.authority.pubkey(),
+            recipient: *recipient,
+            token_program: token::ID,
+            token_metadata_program: Some(mpl_token_metadata::ID),
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+            instruction_sysvar_account: sysvar::instructions::ID,
+        }
+    }
+
+    /// `on_call` invoked directly, bypassing the gateway
+    pub fn on_call_ix(
+        &self,
+        data: Vec<u8>,
+        accounts: universal_nft::accounts::OnCall,
+    ) -> Instruction {
+        Instruction {
+            program_id: universal_nft::ID,
+            accounts: accounts.to_account_metas(None),
+            data: universal_nft::instruction::OnCall {
+                amount: 0,
+                sender: POLYGON_CONTRACT,
+                data,
+                bump: 0,
+            }
+            .data(),
+        }
+    }
+
+    /// The mock gateway delivering `data` from `sender` to `on_call`
+    pub fn gateway_execute_ix(
+        &self,
+        sender: [u8; 20],
+        data: Vec<u8>,
+        accounts: universal_nft::accounts::OnCall,
+    ) -> Instruction {
+        self.gateway_execute_from_ix(POLYGON_CHAIN_ID, sender, data, accounts)
+    }
+
+    /// The mock gateway delivering `data` from `sender` on `chain_id`
+    pub fn gateway_execute_from_ix(
+        &self,
+        chain_id: u64,
+        sender: [u8; 20],
+        data: Vec<u8>,
+        mut accounts: universal_nft::accounts::OnCall,
+    ) -> Instruction {
+        // The replay guard is keyed by the source chain and the sender the gateway reports
+        accounts.source_chain_config = chain_config(chain_id);
+        accounts.message_receipt = message_receipt(chain_id, &sender, &data);
+        let bump = Pubkey::find_program_address(
+            &[UNIVERSAL_NFT_SEED, accounts.mint.as_ref()],
+            &universal_nft::ID,
+        )
+        .1;
+        self.gateway_ix(
+            mock_gateway::instruction::Execute {
+                amount: 0,
+                sender,
+                data,
+                bump,
+            }
+            .data(),
+            accounts.to_account_metas(None),
+        )
+    }
+
+    /// The mock gateway refunding `amount` and calling `on_revert` with the
+    /// transfer id as the revert message
+    pub fn gateway_revert_ix(
+        &self,
+        transfer_id: [u8; 32],
+        mint: &Pubkey,
+        amount: u64,
+    ) -> Instruction {
+        let transfer: CrossChainTransfer = self.account(&transfer_pda(&transfer_id));
+        let accounts = universal_nft::accounts::OnRevert {
+            global_config: global_config(),
+            universal_nft: universal_nft_pda(mint),
+            cross_chain_transfer: transfer_pda(&transfer_id),
+            mint: *mint,
+            escrow_token_account: escrow(mint),
+            source_owner: transfer.source_owner,
+            source_owner_token_account: get_associated_token_address(&transfer.source_owner, mint),
+            connected_pda: connected(),
+            refund_recipient: transfer.refund_address,
+            gateway_pda: gateway_pda(),
+            payer: self.authority.pubkey(),
+            token_program: token::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+            instruction_sysvar_account: sysvar::instructions::ID,
+        };
+        self.gateway_ix(
+            mock_gateway::instruction::ExecuteRevert {
+                amount,
+                sender: self.authority.pubkey(),
+                data: transfer_id.to_vec(),
+            }
+            .data(),
+            accounts.to_account_metas(None),
+        )
+    }
+
+    /// Accounts of an `on_abort` for `transfer_id`
+    pub fn on_abort_accounts(&self, transfer_id: [u8; 32]) -> universal_nft::accounts::OnAbort {
+        let transfer: CrossChainTransfer = self.account(&transfer_pda(&transfer_id));
+        universal_nft::accounts::OnAbort {
+            global_config: global_config(),
+            cross_chain_transfer: transfer_pda(&transfer_id),
+            connected_pda: connected(),
+            abort_recipient: transfer.abort_address,
+            system_program: system_program::ID,
+            instruction_sysvar_account: sysvar::instructions::ID,
+        }
+    }
+
+    /// The mock gateway refunding `amount` and calling `on_abort` with the
+    /// transfer id as the revert message
+    pub fn gateway_abort_ix(&self, transfer_id: [u8; 32], amount: u64) -> Instruction {
+        self.gateway_ix(
+            mock_gateway::instruction::ExecuteAbort {
+                amount,
+                sender: self.authority.pubkey(),
+                data: transfer_id.to_vec(),
+            }
+            .data(),
+            self.on_abort_accounts(transfer_id).to_account_metas(None),
+        )
+    }
+
+    /// A mock gateway `execute*` instruction forwarding `callback_accounts`
+    fn gateway_ix(
+        &self,
+        data: Vec<u8>,
+        callback_accounts: Vec<solana_sdk::instruction::AccountMeta>,
+    ) -> Instruction {
+        let mut accounts = mock_gateway::accounts::Execute {
+            pda: gateway_pda(),
+            authority: self.authority.pubkey(),
+            destination_program: universal_nft::ID,
+        }
+        .to_account_metas(None);
+        accounts.extend(callback_accounts);
+        Instruction {
+            program_id: mock_gateway::ID,
+            accounts,
+            data,
+        }
+    }
+
+    pub fn config(&self) -> universal_nft::GlobalConfig {
+        self.account(&global_config())
+    }
+}
+
+/// A Borsh message from Polygon to `recipient`
+pub fn polygon_message(
+    message_type: u8,
+    nonce: u64,
+    token_id: &str,
+    recipient: &Pubkey,
+) -> NftMessage {
+    NftMessage {
+        header: MessageHeader {
+            version: MESSAGE_VERSION,
+            message_type,
+            nonce,
+            source_chain_id: POLYGON_CHAIN_ID,
+            destination_chain_id: SOLANA_CHAIN_ID,
+        },
+        original_chain: "polygon".to_string(),
+        original_contract: String::new(),
+        original_token_id: token_id.to_string(),
+        uri: "ipfs://nft".to_string(),
+        name: "Bridged".to_string(),
+        symbol: "BRG".to_string(),
+        recipient: recipient.to_bytes().to_vec(),
+    }
+}
+
+/// Origin index of an NFT minted by `polygon_message`
+pub fn polygon_origin(token_id: &str) -> Pubkey {
+    origin_index("polygon", &hex::encode(POLYGON_CONTRACT), token_id)
+}
+
+/// Events of type `T` the transaction emitted, decoded from its `Program data:` logs
+pub fn events<T: Event + AnchorDeserialize>(logs: &[String]) -> Vec<T> {
+    logs.iter()
+        .filter_map(|log| log.strip_prefix("Program data: "))
+        .filter_map(|data| BASE64_STANDARD.decode(data).ok())
+        .filter_map(|data| {
+            data.strip_prefix(T::DISCRIMINATOR)
+                .map(|mut body| T::deserialize(&mut body).unwrap())
+        })
+        .collect()
+}
+
+/// `ix` with `from` replaced by `to` in its accounts, e.g. to sign with the wrong key
+pub fn replace_account(mut ix: Instruction, from: &Pubkey, to: &Pubkey) -> Instruction {
+    for meta in ix.accounts.iter_mut().filter(|meta| meta.pubkey == *from) {
+        meta.pubkey = *to;
+    }
+    ix
+}
+
+/// Assert the transaction failed with the program error `expected`
+pub fn assert_error(result: TransactionResult, expected: ErrorCode) {
+    let code = u32::from(expected);
+    match result {
+        Err(failed) => match failed.err {
+            TransactionError::InstructionError(_, InstructionError::Custom(actual)) => assert_eq!(
+                actual, code,
+                "expected {:?} ({}), got custom error {}: {:#?}",
+                expected, code, actual, failed.meta.logs
+            ),
+            err => panic!(
+                "expected {:?} ({}), got {:?}: {:#?}",
+                expected, code, err, failed.meta.logs
+            ),
+        },
+        Ok(_) => panic!(
+            "expected {:?} ({}), but the transaction succeeded",
+            expected, code
+        ),
+    }
+}
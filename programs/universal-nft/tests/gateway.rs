@@ -0,0 +1,1082 @@
+//! Gateway callbacks, delivered through the mock gateway: inbound messages,
+//! reverts and aborts
+
+#![allow(clippy::result_large_err)]
+
+mod common;
+
+use anchor_lang::{AnchorSerialize, InstructionData, Space};
+use anchor_spl::associated_token::get_associated_token_address;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+use common::*;
+use universal_nft::error::ErrorCode;
+use universal_nft::{
+    parse_token_id, AbiNftMessage, AddressFormat, CrossChainTransfer, MessageEncoding,
+    MessageReceipt, NftMessage, OriginIndex, TransferStatus, UniversalNft,
+    DEFAULT_TRANSFER_TIMEOUT, MSG_TYPE_BURN, MSG_TYPE_LOCK, MSG_TYPE_MINT, MSG_TYPE_TRANSFER,
+    MSG_TYPE_UNLOCK, MSG_TYPE_UPDATE_METADATA,
+};
+
+/// Deliver `message` from the registered Polygon contract through the gateway
+fn deliver(
+    env: &mut TestEnv,
+    message: &NftMessage,
+    accounts: universal_nft::accounts::OnCall,
+) -> litesvm::types::TransactionResult {
+    let data = message.encode().unwrap();
+    let ix = env.gateway_execute_ix(POLYGON_CONTRACT, data, accounts);
+    env.send(&[ix], &[])
+}
+
+/// Accounts of a first-arrival mint of Polygon token `token_id`
+fn mint_accounts(
+    env: &TestEnv,
+    message: &NftMessage,
+    mint: &Pubkey,
+    recipient: &Pubkey,
+) -> universal_nft::accounts::OnCall {
+    let data = message.encode().unwrap();
+    let origin = polygon_origin(&message.original_token_id);
+    env.on_call_accounts(&data, mint, recipient, Some(origin), None, None)
+}
+
+/// Accounts of a message about a Polygon NFT that already arrived
+fn polygon_nft_accounts(
+    env: &TestEnv,
+    message: &NftMessage,
+    mint: &Pubkey,
+    recipient: &Pubkey,
+) -> universal_nft::accounts::OnCall {
+    let data = message.encode().unwrap();
+    env.on_call_accounts(&data, mint, recipient, None, None, None)
+}
+
+/// Accounts of a message about an NFT minted on Solana
+fn solana_nft_accounts(
+    env: &TestEnv,
+    message: &NftMessage,
+    mint: &Pubkey,
+    recipient: &Pubkey,
+    escrow_token_account: Option<Pubkey>,
+    cross_chain_transfer: Option<Pubkey>,
+) -> universal_nft::accounts::OnCall {
+    let data = message.encode().unwrap();
+    env.on_call_accounts(
+        &data,
+        mint,
+        recipient,
+        None,
+        escrow_token_account,
+        cross_chain_transfer,
+    )
+}
+
+/// A message about the NFT minted on Solana as token `token_id`
+fn solana_nft_message(
+    message_type: u8,
+    nonce: u64,
+    token_id: &str,
+    recipient: &Pubkey,
+) -> NftMessage {
+    let mut message = polygon_message(message_type, nonce, token_id, recipient);
+    message.original_chain = "solana".to_string();
+    message.original_contract = "native".to_string();
+    message
+}
+
+fn transfer(env: &TestEnv, transfer_id: &[u8; 32]) -> CrossChainTransfer {
+    env.account(&transfer_pda(transfer_id))
+}
+
+/// Register BSC and lock `mint` for a transfer there, so Polygon is not the
+/// chain holding it
+fn initiate_to_bsc(env: &mut TestEnv, mint: &Pubkey, owner: &Keypair) -> [u8; 32] {
+    let ix = env.register_chain_ix(
+        56,
+        "bsc",
+        AddressFormat::Evm,
+        MessageEncoding::Borsh,
+        [0x22; 20].to_vec(),
+        None,
+    );
+    env.send(&[ix], &[]).unwrap();
+    let transfer_id = env.next_transfer_id(mint, &owner.pubkey());
+    let ix = env.initiate_ix(mint, owner, 56, EVM_RECIPIENT);
+    env.send(&[ix], &[owner]).unwrap();
+    transfer_id
+}
+
+#[test]
+fn on_call_mints_first_arrival() {
+    let mut env = TestEnv::new();
+    let recipient = Pubkey::new_unique();
+    let mint = env.create_inbound_mint(&recipient);
+    let message = polygon_message(MSG_TYPE_MINT, 1, "42", &recipient);
+
+    let accounts = mint_accounts(&env, &message, &mint, &recipient);
+    deliver(&mut env, &message, accounts).unwrap();
+
+    let nft: UniversalNft = env.account(&universal_nft_pda(&mint));
+    assert_eq!(nft.mint, mint);
+    assert_eq!(nft.owner, recipient);
+    assert_eq!(nft.original_chain, "polygon");
+    // An empty origin contract falls back to the sending contract
+    assert_eq!(nft.original_contract, hex::encode(POLYGON_CONTRACT));
+    assert_eq!(nft.original_token_id, "42");
+    assert_eq!(nft.metadata_uri, "ipfs://nft");
+    assert_eq!(
+        env.token_amount(&get_associated_token_address(&recipient, &mint)),
+        1
+    );
+
+    let origin: OriginIndex = env.account(&polygon_origin("42"));
+    assert_eq!(origin.mint, mint);
+    let data = message.encode().unwrap();
+    let receipt: MessageReceipt =
+        env.account(&message_receipt(POLYGON_CHAIN_ID, &POLYGON_CONTRACT, &data));
+    assert!(receipt.processed);
+    assert_eq!(receipt.mint, mint);
+
+    let metadata = env.metaplex_metadata(&mint);
+    assert!(metadata.collection.expect("collection is set").verified);
+
+    // The same message cannot be processed twice
+    let accounts = mint_accounts(&env, &message, &mint, &recipient);
+    assert_error(
+        deliver(&mut env, &message, accounts),
+        ErrorCode::MessageAlreadyProcessed,
+    );
+}
+
+#[test]
+fn on_call_requires_gateway_and_registered_sender() {
+    let mut env = TestEnv::new();
+    let recipient = Pubkey::new_unique();
+    let mint = env.create_inbound_mint(&recipient);
+    let message = polygon_message(MSG_TYPE_MINT, 1, "42", &recipient);
+    let data = message.encode().unwrap();
+
+    // Called directly rather than by the gateway
+    let ix = env.on_call_ix(
+        data.clone(),
+        mint_accounts(&env, &message, &mint, &recipient),
+    );
+    assert_error(env.send(&[ix], &[]), ErrorCode::Unauthorized);
+
+    let ix = env.gateway_execute_ix(
+        [0x99; 20],
+        data,
+        mint_accounts(&env, &message, &mint, &recipient),
+    );
+    assert_error(env.send(&[ix], &[]), ErrorCode::UnknownSender);
+
+    let ix = env.set_pause_ix(&env.authority.pubkey(), None, None, Some(true));
+    env.send(&[ix], &[]).unwrap();
+    let accounts = mint_accounts(&env, &message, &mint, &recipient);
+    assert_error(
+        deliver(&mut env, &message, accounts),
+        ErrorCode::ProgramPaused,
+    );
+}
+
+#[test]
+fn on_call_accepts_only_the_source_chains_contract() {
+    let mut env = TestEnv::new();
+    let recipient = Pubkey::new_unique();
+    let mint = env.create_inbound_mint(&recipient);
+    // The origin contract is explicit, so the origin does not depend on the sender
+    let mut message = polygon_message(MSG_TYPE_MINT, 1, "42", &recipient);
+    message.original_contract = hex::encode(POLYGON_CONTRACT);
+    let data = message.encode().unwrap();
+
+    // The contract registered for another chain is not allowed to speak for Polygon
+    let bsc_contract = [0x22; 20];
+    let ix = env.register_chain_ix(
+        56,
+        "bsc",
+        AddressFormat::Evm,
+        MessageEncoding::Borsh,
+        bsc_contract.to_vec(),
+        None,
+    );
+    env.send(&[ix], &[]).unwrap();
+    for sender in [bsc_contract, [0x99; 20]] {
+        let accounts = mint_accounts(&env, &message, &mint, &recipient);
+        let ix = env.gateway_execute_ix(sender, data.clone(), accounts);
+        assert_error(env.send(&[ix], &[]), ErrorCode::UnknownSender);
+    }
+
+    // Replacing the connected contract revokes the old one and admits the new one
+    let new_contract = [0x33; 20];
+    let ix = env.update_chain_ix(POLYGON_CHAIN_ID, None, None, Some(new_contract.to_vec()));
+    env.send(&[ix], &[]).unwrap();
+    let accounts = mint_accounts(&env, &message, &mint, &recipient);
+    assert_error(
+        deliver(&mut env, &message, accounts),
+        ErrorCode::UnknownSender,
+    );
+
+    let accounts = mint_accounts(&env, &message, &mint, &recipient);
+    let ix = env.gateway_execute_ix(new_contract, data, accounts);
+    env.send(&[ix], &[]).unwrap();
+    let nft: UniversalNft = env.account(&universal_nft_pda(&mint));
+    assert_eq!(nft.owner, recipient);
+}
+
+#[test]
+fn on_call_decodes_abi_payload() {
+    let mut env = TestEnv::new();
+    let bsc_contract = [0x22; 20];
+    let ix = env.register_chain_ix(
+        56,
+        "bsc",
+        AddressFormat::Evm,
+        MessageEncoding::Abi,
+        bsc_contract.to_vec(),
+        None,
+    );
+    env.send(&[ix], &[]).unwrap();
+
+    // A BSC token arrives: its origin is the BSC collection
+    let owner = env.user();
+    let mint = env.create_inbound_mint(&owner.pubkey());
+    let arrival = AbiNftMessage {
+        receiver: owner.pubkey().to_bytes(),
+        token_id: parse_token_id("42").unwrap(),
+        uri: "ipfs://bsc/42".to_string(),
+        gas_amount: 0,
+        sender: vec![0x44; 20],
+        nonce: 1,
+    };
+    let data = arrival.encode();
+    let origin = origin_index("bsc", &hex::encode(bsc_contract), "42");
+    let accounts = env.on_call_accounts(&data, &mint, &owner.pubkey(), Some(origin), None, None);
+    let ix = env.gateway_execute_from_ix(56, bsc_contract, data, accounts);
+    env.send(&[ix], &[]).unwrap();
+
+    let nft: UniversalNft = env.account(&universal_nft_pda(&mint));
+    assert_eq!(nft.owner, owner.pubkey());
+    assert_eq!(nft.original_chain, "bsc");
+    assert_eq!(nft.original_contract, hex::encode(bsc_contract));
+    assert_eq!(nft.original_token_id, "42");
+    assert_eq!(nft.metadata_uri, "ipfs://bsc/42");
+
+    // Going home it travels under its BSC token id, with the message nonce
+    let transfer_id = env.next_transfer_id(&mint, &owner.pubkey());
+    let ix = env.deposit_and_call_ix(&mint, &owner, 56, [0xab; 20], None);
+    env.send(&[ix], &[&owner]).unwrap();
+    let gateway: mock_gateway::Gateway = env.account(&gateway_pda());
+    let outbound = AbiNftMessage::decode(&gateway.last_deposit.unwrap().message).unwrap();
+    assert_eq!(outbound.token_id, arrival.token_id);
+    assert_eq!(outbound.nonce, 0);
+
+    // The same token sent back again is a return under a new nonce, not a replay
+    let data = AbiNftMessage {
+        nonce: 2,
+        ..arrival
+    }
+    .encode();
+    let accounts = env.on_call_accounts(
+        &data,
+        &mint,
+        &owner.pubkey(),
+        Some(origin),
+        Some(escrow(&mint)),
+        Some(transfer_pda(&transfer_id)),
+    );
+    let ix = env.gateway_execute_from_ix(56, bsc_contract, data, accounts);
+    env.send(&[ix], &[]).unwrap();
+    assert!(
+        !env.account::<UniversalNft>(&universal_nft_pda(&mint))
+            .is_locked
+    );
+    assert_eq!(
+        env.token_amount(&get_associated_token_address(&owner.pubkey(), &mint)),
+        1
+    );
+    assert!(transfer(&env, &transfer_id).status == TransferStatus::Completed);
+}
+
+#[test]
+fn on_call_returns_solana_nft_over_abi() {
+    let mut env = TestEnv::new();
+    let bsc_contract = [0x22; 20];
+    let ix = env.register_chain_ix(
+        56,
+        "bsc",
+        AddressFormat::Evm,
+        MessageEncoding::Abi,
+        bsc_contract.to_vec(),
+        None,
+    );
+    env.send(&[ix], &[]).unwrap();
+    let owner = env.user();
+    let mint = env.mint_nft(&owner.pubkey(), "7");
+
+    // The ABI layout has no origin, so an NFT minted on Solana leaves under its mint address
+    let transfer_id = env.next_transfer_id(&mint, &owner.pubkey());
+    let ix = env.deposit_and_call_ix(&mint, &owner, 56, [0xab; 20], None);
+    env.send(&[ix], &[&owner]).unwrap();
+    let gateway: mock_gateway::Gateway = env.account(&gateway_pda());
+    let outbound = AbiNftMessage::decode(&gateway.last_deposit.unwrap().message).unwrap();
+    assert_eq!(outbound.token_id, mint.to_bytes());
+
+    // Coming back under that id it is released from escrow rather than minted anew
+    let data = AbiNftMessage {
+        receiver: owner.pubkey().to_bytes(),
+        token_id: mint.to_bytes(),
+        uri: outbound.uri,
+        gas_amount: 0,
+        sender: vec![0x44; 20],
+        nonce: 1,
+    }
+    .encode();
+    let accounts = env.on_call_accounts(
+        &data,
+        &mint,
+        &owner.pubkey(),
+        Some(origin_index("solana", "native", "7")),
+        Some(escrow(&mint)),
+        Some(transfer_pda(&transfer_id)),
+    );
+    let ix = env.gateway_execute_from_ix(56, bsc_contract, data, accounts);
+    env.send(&[ix], &[]).unwrap();
+
+    let nft: UniversalNft = env.account(&universal_nft_pda(&mint));
+    assert!(!nft.is_locked);
+    assert_eq!(nft.original_chain, "solana");
+    assert_eq!(
+        env.token_amount(&get_associated_token_address(&owner.pubkey(), &mint)),
+        1
+    );
+    assert!(transfer(&env, &transfer_id).status == TransferStatus::Completed);
+}
+
+#[test]
+fn on_call_rejects_replayed_message() {
+    let mut env = TestEnv::new();
+    let recipient = Pubkey::new_unique();
+    let mint = env.create_inbound_mint(&recipient);
+    let message = polygon_message(MSG_TYPE_MINT, 1, "42", &recipient);
+    let data = message.encode().unwrap();
+
+    let accounts = mint_accounts(&env, &message, &mint, &recipient);
+    deliver(&mut env, &message, accounts).unwrap();
+    let receipt: MessageReceipt =
+        env.account(&message_receipt(POLYGON_CHAIN_ID, &POLYGON_CONTRACT, &data));
+    assert!(receipt.processed);
+    assert_eq!(receipt.source_chain_id, POLYGON_CHAIN_ID);
+    assert_eq!(
+        receipt.message_hash,
+        MessageReceipt::message_hash(&POLYGON_CONTRACT, &data)
+    );
+
+    // Delivering the identical payload again is refused and mints nothing
+    let accounts = mint_accounts(&env, &message, &mint, &recipient);
+    assert_error(
+        deliver(&mut env, &message, accounts),
+        ErrorCode::MessageAlreadyProcessed,
+    );
+    assert_eq!(
+        env.token_amount(&get_associated_token_address(&recipient, &mint)),
+        1
+    );
+
+    // The guard is per message: a new message from the same sender goes through
+    let second_mint = env.create_inbound_mint(&recipient);
+    let second = polygon_message(MSG_TYPE_MINT, 2, "43", &recipient);
+    let accounts = mint_accounts(&env, &second, &second_mint, &recipient);
+    deliver(&mut env, &second, accounts).unwrap();
+}
+
+#[test]
+fn on_call_rejects_malformed_messages() {
+    let mut env = TestEnv::new();
+    let recipient = Pubkey::new_unique();
+    let mint = env.create_inbound_mint(&recipient);
+    let message = polygon_message(MSG_TYPE_MINT, 1, "42", &recipient);
+
+    let send_raw = |env: &mut TestEnv, data: Vec<u8>| {
+        let accounts = env.on_call_accounts(
+            &data,
+            &mint,
+            &recipient,
+            Some(polygon_origin("42")),
+            None,
+            None,
+        );
+        let ix = env.gateway_execute_ix(POLYGON_CONTRACT, data, accounts);
+        env.send(&[ix], &[])
+    };
+
+    assert_error(send_raw(&mut env, vec![1, 2, 3]), ErrorCode::InvalidMessage);
+    assert_error(
+        send_raw(&mut env, vec![2, 1, 0]),
+        ErrorCode::UnsupportedMessageVersion,
+    );
+
+    let mut data = message.encode().unwrap();
+    data[1] = 42;
+    assert_error(send_raw(&mut env, data), ErrorCode::UnsupportedMessageType);
+
+    // Lock messages are only ever sent, never received
+    let lock = polygon_message(MSG_TYPE_LOCK, 2, "42", &recipient);
+    assert_error(
+        send_raw(&mut env, lock.encode().unwrap()),
+        ErrorCode::UnsupportedMessageType,
+    );
+
+    let mut wrong_destination = polygon_message(MSG_TYPE_MINT, 3, "42", &recipient);
+    wrong_destination.header.destination_chain_id = 1;
+    assert_error(
+        send_raw(&mut env, wrong_destination.encode().unwrap()),
+        ErrorCode::InvalidChainId,
+    );
+
+    let other_recipient = polygon_message(MSG_TYPE_MINT, 4, "42", &Pubkey::new_unique());
+    assert_error(
+        send_raw(&mut env, other_recipient.encode().unwrap()),
+        ErrorCode::InvalidDestinationAddress,
+    );
+
+    let mut no_token_id = polygon_message(MSG_TYPE_MINT, 5, "42", &recipient);
+    no_token_id.original_token_id = String::new();
+    let data = no_token_id.try_to_vec().unwrap();
+    assert_error(send_raw(&mut env, data), ErrorCode::InvalidOriginalChain);
+
+    // A disabled chain is no longer accepted
+    let ix = env.update_chain_ix(POLYGON_CHAIN_ID, Some(false), None, None);
+    env.send(&[ix], &[]).unwrap();
+    assert_error(
+        send_raw(&mut env, message.encode().unwrap()),
+        ErrorCode::InvalidChainId,
+    );
+}
+
+#[test]
+fn on_call_mint_errors() {
+    let mut env = TestEnv::new();
+    let recipient = Pubkey::new_unique();
+
+    // A mint message needs the origin index
+    let mint = env.create_inbound_mint(&recipient);
+    let message = polygon_message(MSG_TYPE_MINT, 1, "42", &recipient);
+    let data = message.encode().unwrap();
+    let accounts = env.on_call_accounts(&data, &mint, &recipient, None, None, None);
+    assert_error(
+        deliver(&mut env, &message, accounts),
+        ErrorCode::MissingAccount,
+    );
+
+    let accounts = env.on_call_accounts(
+        &data,
+        &mint,
+        &recipient,
+        Some(polygon_origin("43")),
+        None,
+        None,
+    );
+    assert_error(
+        deliver(&mut env, &message, accounts),
+        ErrorCode::InvalidOriginIndex,
+    );
+
+    // The mint must be an empty mint controlled by the program
+    let minted = env.mint_nft(&recipient, "1");
+    let accounts = mint_accounts(&env, &message, &minted, &recipient);
+    assert_error(deliver(&mut env, &message, accounts), ErrorCode::MintFailed);
+
+    // A record already exists for the mint
+    let nft = UniversalNft {
+        mint,
+        owner: recipient,
+        original_chain: "polygon".to_string(),
+        original_contract: hex::encode(POLYGON_CONTRACT),
+        original_token_id: "7".to_string(),
+        metadata_uri: String::new(),
+        is_locked: false,
+        lock_destination_chain: String::new(),
+        lock_recipient: String::new(),
+        active_transfer: None,
+        created_at: 0,
+        updated_at: 0,
+        bump: 0,
+    };
+    env.set_program_account(universal_nft_pda(&mint), &nft, UniversalNft::INIT_SPACE);
+    let accounts = mint_accounts(&env, &message, &mint, &recipient);
+    assert_error(
+        deliver(&mut env, &message, accounts),
+        ErrorCode::NftAlreadyExists,
+    );
+}
+
+#[test]
+fn on_call_returns_nft_from_escrow() {
+    let mut env = TestEnv::new();
+    let owner = env.user();
+    let recipient = Pubkey::new_unique();
+    let mint = env.mint_nft(&owner.pubkey(), "1");
+    env.create_token_account(&recipient, &mint);
+
+    // The NFT has not left Solana, so it cannot come back
+    let message = solana_nft_message(MSG_TYPE_TRANSFER, 1, "1", &recipient);
+    let data = message.encode().unwrap();
+    let origin = origin_index("solana", "native", "1");
+    let accounts = env.on_call_accounts(&data, &mint, &recipient, Some(origin), None, None);
+    assert_error(
+        deliver(&mut env, &message, accounts),
+        ErrorCode::NftNotLocked,
+    );
+
+    let transfer_id = env.deposit_and_call(&mint, &owner);
+
+    // The transfer that locked the NFT is settled by its return
+    let accounts = env.on_call_accounts(
+        &data,
+        &mint,
+        &recipient,
+        Some(origin),
+        Some(escrow(&mint)),
+        None,
+    );
+    assert_error(
+        deliver(&mut env, &message, accounts),
+        ErrorCode::TransferNotFound,
+    );
+
+    let accounts = env.on_call_accounts(
+        &data,
+        &mint,
+        &recipient,
+        Some(origin),
+        Some(escrow(&mint)),
+        Some(transfer_pda(&transfer_id)),
+    );
+    deliver(&mut env, &message, accounts).unwrap();
+
+    let nft: UniversalNft = env.account(&universal_nft_pda(&mint));
+    assert_eq!(nft.owner, recipient);
+    assert!(!nft.is_locked);
+    assert_eq!(nft.active_transfer, None);
+    assert_eq!(
+        env.token_amount(&get_associated_token_address(&recipient, &mint)),
+        1
+    );
+    assert_eq!(env.token_amount(&escrow(&mint)), 0);
+    let record = transfer(&env, &transfer_id);
+    assert!(record.status == TransferStatus::Completed);
+    assert!(record.completed_at.is_some());
+}
+
+#[test]
+fn on_call_return_requires_destination_chain() {
+    let mut env = TestEnv::new();
+    let owner = env.user();
+    let recipient = Pubkey::new_unique();
+    let mint = env.mint_nft(&owner.pubkey(), "1");
+    env.create_token_account(&recipient, &mint);
+
+    // Sent to BSC, so Polygon cannot send it back
+    let transfer_id = initiate_to_bsc(&mut env, &mint, &owner);
+
+    let message = solana_nft_message(MSG_TYPE_TRANSFER, 1, "1", &recipient);
+    let data = message.encode().unwrap();
+    let accounts = env.on_call_accounts(
+        &data,
+        &mint,
+        &recipient,
+        Some(origin_index("solana", "native", "1")),
+        Some(escrow(&mint)),
+        Some(transfer_pda(&transfer_id)),
+    );
+    assert_error(
+        deliver(&mut env, &message, accounts),
+        ErrorCode::InvalidChainId,
+    );
+    assert_eq!(env.token_amount(&escrow(&mint)), 1);
+    assert!(transfer(&env, &transfer_id).status == TransferStatus::Initiated);
+}
+
+#[test]
+fn on_call_unlocks_nft() {
+    let mut env = TestEnv::new();
+    let owner = env.user();
+    let mint = env.mint_nft(&owner.pubkey(), "5");
+    let transfer_id = env.deposit_and_call(&mint, &owner);
+
+    let mut message = solana_nft_message(MSG_TYPE_UNLOCK, 1, "6", &owner.pubkey());
+    let accounts = solana_nft_accounts(
+        &env,
+        &message,
+        &mint,
+        &owner.pubkey(),
+        Some(escrow(&mint)),
+        Some(transfer_pda(&transfer_id)),
+    );
+    assert_error(
+        deliver(&mut env, &message, accounts),
+        ErrorCode::InvalidOriginalChain,
+    );
+
+    // The whole origin must match, not just the token id
+    message.original_token_id = "5".to_string();
+    message.original_chain = "polygon".to_string();
+    let accounts = solana_nft_accounts(
+        &env,
+        &message,
+        &mint,
+        &owner.pubkey(),
+        Some(escrow(&mint)),
+        Some(transfer_pda(&transfer_id)),
+    );
+    assert_error(
+        deliver(&mut env, &message, accounts),
+        ErrorCode::InvalidOriginalChain,
+    );
+
+    message.original_chain = "solana".to_string();
+    let accounts = solana_nft_accounts(
+        &env,
+        &message,
+        &mint,
+        &owner.pubkey(),
+        Some(escrow(&mint)),
+        Some(transfer_pda(&transfer_id)),
+    );
+    deliver(&mut env, &message, accounts).unwrap();
+
+    let nft: UniversalNft = env.account(&universal_nft_pda(&mint));
+    assert!(!nft.is_locked);
+    assert_eq!(
+        env.token_amount(&get_associated_token_address(&owner.pubkey(), &mint)),
+        1
+    );
+    assert!(transfer(&env, &transfer_id).status == TransferStatus::Completed);
+
+    // An unknown mint has no record to unlock
+    let unknown = env.create_inbound_mint(&owner.pubkey());
+    let message = solana_nft_message(MSG_TYPE_UNLOCK, 2, "5", &owner.pubkey());
+    let accounts = solana_nft_accounts(&env, &message, &unknown, &owner.pubkey(), None, None);
+    assert_error(
+        deliver(&mut env, &message, accounts),
+        ErrorCode::NftNotFound,
+    );
+}
+
+#[test]
+fn on_call_unlock_and_burn_require_destination_chain() {
+    let mut env = TestEnv::new();
+    let owner = env.user();
+    let mint = env.mint_nft(&owner.pubkey(), "1");
+    let transfer_id = initiate_to_bsc(&mut env, &mint, &owner);
+
+    let message = solana_nft_message(MSG_TYPE_UNLOCK, 1, "1", &owner.pubkey());
+    let accounts = solana_nft_accounts(
+        &env,
+        &message,
+        &mint,
+        &owner.pubkey(),
+        Some(escrow(&mint)),
+        Some(transfer_pda(&transfer_id)),
+    );
+    assert_error(
+        deliver(&mut env, &message, accounts),
+        ErrorCode::InvalidChainId,
+    );
+
+    let message = solana_nft_message(MSG_TYPE_BURN, 2, "1", &owner.pubkey());
+    let accounts = solana_nft_accounts(
+        &env,
+        &message,
+        &mint,
+        &owner.pubkey(),
+        None,
+        Some(transfer_pda(&transfer_id)),
+    );
+    assert_error(
+        deliver(&mut env, &message, accounts),
+        ErrorCode::InvalidChainId,
+    );
+
+    assert_eq!(env.token_amount(&escrow(&mint)), 1);
+    assert!(transfer(&env, &transfer_id).status == TransferStatus::Initiated);
+}
+
+#[test]
+fn cancel_refuses_transfer_after_relock() {
+    let mut env = TestEnv::new();
+    let owner = env.user();
+    let mint = env.mint_nft(&owner.pubkey(), "1");
+    let stale = env.initiate(&mint, &owner);
+
+    // The NFT comes back before the transfer times out, then leaves again
+    let message = solana_nft_message(MSG_TYPE_UNLOCK, 1, "1", &owner.pubkey());
+    let accounts = solana_nft_accounts(
+        &env,
+        &message,
+        &mint,
+        &owner.pubkey(),
+        Some(escrow(&mint)),
+        Some(transfer_pda(&stale)),
+    );
+    deliver(&mut env, &message, accounts).unwrap();
+    let active = env.initiate(&mint, &owner);
+    env.warp(DEFAULT_TRANSFER_TIMEOUT);
+
+    // The old transfer may not release the escrow the new one filled
+    let ix = env.cancel_ix(stale, &mint, &owner.pubkey());
+    assert_error(env.send(&[ix], &[&owner]), ErrorCode::StaleTransfer);
+    assert_eq!(env.token_amount(&escrow(&mint)), 1);
+
+    let ix = env.cancel_ix(active, &mint, &owner.pubkey());
+    env.send(&[ix], &[&owner]).unwrap();
+    assert!(transfer(&env, &active).status == TransferStatus::Cancelled);
+}
+
+#[test]
+fn on_call_updates_metadata() {
+    let mut env = TestEnv::new();
+    let owner = env.user();
+    let mint = env.create_inbound_mint(&owner.pubkey());
+    let arrival = polygon_message(MSG_TYPE_MINT, 1, "7", &owner.pubkey());
+    let accounts = mint_accounts(&env, &arrival, &mint, &owner.pubkey());
+    deliver(&mut env, &arrival, accounts).unwrap();
+
+    let mut message = polygon_message(MSG_TYPE_UPDATE_METADATA, 2, "7", &owner.pubkey());
+    message.uri = "ipfs://updated".to_string();
+    let accounts = polygon_nft_accounts(&env, &message, &mint, &owner.pubkey());
+    deliver(&mut env, &message, accounts).unwrap();
+
+    let nft: UniversalNft = env.account(&universal_nft_pda(&mint));
+    assert_eq!(nft.metadata_uri, "ipfs://updated");
+    assert_eq!(
+        env.metaplex_metadata(&mint).uri.trim_end_matches('\0'),
+        "ipfs://updated"
+    );
+
+    // Same token id under another origin contract
+    let mut other = polygon_message(MSG_TYPE_UPDATE_METADATA, 3, "7", &owner.pubkey());
+    other.original_contract = "native".to_string();
+    let accounts = polygon_nft_accounts(&env, &other, &mint, &owner.pubkey());
+    assert_error(
+        deliver(&mut env, &other, accounts),
+        ErrorCode::InvalidOriginalChain,
+    );
+
+    // Another registered chain cannot rewrite a Polygon NFT, even naming its origin
+    let ix = env.register_chain_ix(
+        56,
+        "bsc",
+        AddressFormat::Evm,
+        MessageEncoding::Borsh,
+        [0x22; 20].to_vec(),
+        None,
+    );
+    env.send(&[ix], &[]).unwrap();
+    let mut forged = polygon_message(MSG_TYPE_UPDATE_METADATA, 4, "7", &owner.pubkey());
+    forged.original_contract = hex::encode(POLYGON_CONTRACT);
+    forged.uri = "ipfs://forged".to_string();
+    let data = forged.encode().unwrap();
+    let accounts = polygon_nft_accounts(&env, &forged, &mint, &owner.pubkey());
+    let ix = env.gateway_execute_from_ix(56, [0x22; 20], data, accounts);
+    assert_error(env.send(&[ix], &[]), ErrorCode::InvalidChainId);
+    assert_eq!(
+        env.account::<UniversalNft>(&universal_nft_pda(&mint))
+            .metadata_uri,
+        "ipfs://updated"
+    );
+
+    let mut empty = polygon_message(MSG_TYPE_UPDATE_METADATA, 5, "7", &owner.pubkey());
+    empty.uri = String::new();
+    let accounts = polygon_nft_accounts(&env, &empty, &mint, &owner.pubkey());
+    assert_error(
+        deliver(&mut env, &empty, accounts),
+        ErrorCode::InvalidMetadataUri,
+    );
+
+    // Metadata of an NFT on another chain is updated there
+    env.deposit_and_call(&mint, &owner);
+    let message = polygon_message(MSG_TYPE_UPDATE_METADATA, 6, "7", &owner.pubkey());
+    let accounts = polygon_nft_accounts(&env, &message, &mint, &owner.pubkey());
+    assert_error(deliver(&mut env, &message, accounts), ErrorCode::NftLocked);
+}
+
+#[test]
+fn on_call_rejects_wrong_metadata_account() {
+    let mut env = TestEnv::new();
+    let owner = env.user();
+    let mint = env.create_inbound_mint(&owner.pubkey());
+    let arrival = polygon_message(MSG_TYPE_MINT, 1, "7", &owner.pubkey());
+    let accounts = mint_accounts(&env, &arrival, &mint, &owner.pubkey());
+    deliver(&mut env, &arrival, accounts).unwrap();
+    let other_mint = env.create_inbound_mint(&owner.pubkey());
+    let arrival = polygon_message(MSG_TYPE_MINT, 2, "8", &owner.pubkey());
+    let accounts = mint_accounts(&env, &arrival, &other_mint, &owner.pubkey());
+    deliver(&mut env, &arrival, accounts).unwrap();
+
+    // Another mint's metadata, an account Metaplex does not own, and a
+    // Metaplex account that is not metadata
+    let wrong = [
+        metadata(&other_mint),
+        Pubkey::new_unique(),
+        master_edition(&mint),
+    ];
+    for (nonce, account) in (3..).zip(wrong) {
+        let mut message = polygon_message(MSG_TYPE_UPDATE_METADATA, nonce, "7", &owner.pubkey());
+        message.uri = "ipfs://updated".to_string();
+        let mut accounts = polygon_nft_accounts(&env, &message, &mint, &owner.pubkey());
+        accounts.metadata = Some(account);
+        assert_error(
+            deliver(&mut env, &message, accounts),
+            ErrorCode::InvalidMetadataAccount,
+        );
+    }
+    assert_eq!(
+        env.metaplex_metadata(&other_mint)
+            .uri
+            .trim_end_matches('\0'),
+        "ipfs://nft"
+    );
+}
+
+#[test]
+fn on_call_rejects_metadata_update_of_solana_nft() {
+    let mut env = TestEnv::new();
+    let owner = env.user();
+    let mint = env.mint_nft(&owner.pubkey(), "1");
+
+    // Solana issued this NFT, so no remote chain may change its metadata
+    let mut message = solana_nft_message(MSG_TYPE_UPDATE_METADATA, 1, "1", &owner.pubkey());
+    message.uri = "ipfs://updated".to_string();
+    let accounts = solana_nft_accounts(&env, &message, &mint, &owner.pubkey(), None, None);
+    assert_error(
+        deliver(&mut env, &message, accounts),
+        ErrorCode::InvalidChainId,
+    );
+    assert_eq!(
+        env.account::<UniversalNft>(&universal_nft_pda(&mint))
+            .metadata_uri,
+        env.metaplex_metadata(&mint).uri.trim_end_matches('\0')
+    );
+}
+
+#[test]
+fn on_call_acknowledges_burn() {
+    let mut env = TestEnv::new();
+    let owner = env.user();
+    let mint = env.mint_nft(&owner.pubkey(), "1");
+    let transfer_id = env.deposit_and_call(&mint, &owner);
+
+    let message = solana_nft_message(MSG_TYPE_BURN, 1, "1", &owner.pubkey());
+    let accounts = solana_nft_accounts(&env, &message, &mint, &owner.pubkey(), None, None);
+    assert_error(
+        deliver(&mut env, &message, accounts),
+        ErrorCode::TransferNotFound,
+    );
+
+    // Same token id under another origin chain
+    let mut other = solana_nft_message(MSG_TYPE_BURN, 3, "1", &owner.pubkey());
+    other.original_chain = "polygon".to_string();
+    let accounts = solana_nft_accounts(
+        &env,
+        &other,
+        &mint,
+        &owner.pubkey(),
+        None,
+        Some(transfer_pda(&transfer_id)),
+    );
+    assert_error(
+        deliver(&mut env, &other, accounts),
+        ErrorCode::InvalidOriginalChain,
+    );
+
+    let accounts = solana_nft_accounts(
+        &env,
+        &message,
+        &mint,
+        &owner.pubkey(),
+        None,
+        Some(transfer_pda(&transfer_id)),
+    );
+    deliver(&mut env, &message, accounts).unwrap();
+    let record = transfer(&env, &transfer_id);
+    assert!(record.status == TransferStatus::Completed);
+    assert!(record.completed_at.is_some());
+    let nft: UniversalNft = env.account(&universal_nft_pda(&mint));
+    assert_eq!(nft.active_transfer, None);
+
+    let message = solana_nft_message(MSG_TYPE_BURN, 2, "1", &owner.pubkey());
+    let accounts = solana_nft_accounts(
+        &env,
+        &message,
+        &mint,
+        &owner.pubkey(),
+        None,
+        Some(transfer_pda(&transfer_id)),
+    );
+    assert_error(
+        deliver(&mut env, &message, accounts),
+        ErrorCode::InvalidTransferStatus,
+    );
+}
+
+#[test]
+fn on_revert_returns_nft_and_refunds() {
+    let mut env = TestEnv::new();
+    let owner = env.user();
+    let mint = env.mint_nft(&owner.pubkey(), "1");
+    let transfer_id = env.deposit_and_call(&mint, &owner);
+    let balance = env.balance(&owner.pubkey());
+
+    let ix = env.gateway_revert_ix(transfer_id, &mint, POLYGON_FEE);
+    env.send(&[ix], &[]).unwrap();
+
+    let record = transfer(&env, &transfer_id);
+    assert!(record.status == TransferStatus::Failed);
+    assert!(record.completed_at.is_some());
+    let nft: UniversalNft = env.account(&universal_nft_pda(&mint));
+    assert!(!nft.is_locked);
+    assert_eq!(
+        env.token_amount(&get_associated_token_address(&owner.pubkey(), &mint)),
+        1
+    );
+    assert_eq!(env.balance(&owner.pubkey()), balance + POLYGON_FEE);
+
+    let ix = env.gateway_revert_ix(transfer_id, &mint, 0);
+    assert_error(env.send(&[ix], &[]), ErrorCode::InvalidTransferStatus);
+}
+
+#[test]
+fn on_revert_requires_gateway() {
+    let mut env = TestEnv::new();
+    let owner = env.user();
+    let mint = env.mint_nft(&owner.pubkey(), "1");
+    let transfer_id = env.deposit_and_call(&mint, &owner);
+
+    // The callback accounts of the gateway instruction, called directly
+    let gateway_ix = env.gateway_revert_ix(transfer_id, &mint, 0);
+    let ix = Instruction {
+        program_id: universal_nft::ID,
+        accounts: gateway_ix.accounts[3..].to_vec(),
+        data: universal_nft::instruction::OnRevert {
+            amount: 0,
+            sender: env.authority.pubkey(),
+            data: transfer_id.to_vec(),
+        }
+        .data(),
+    };
+    assert_error(env.send(&[ix], &[]), ErrorCode::Unauthorized);
+}
+
+#[test]
+fn callbacks_require_the_transfers_revert_message() {
+    let mut env = TestEnv::new();
+    let owner = env.user();
+    let mint = env.mint_nft(&owner.pubkey(), "1");
+    let transfer_id = env.deposit_and_call(&mint, &owner);
+
+    // The transfer's accounts with another transfer's id as the revert message
+    let mut ix = env.gateway_revert_ix(transfer_id, &mint, 0);
+    ix.data = mock_gateway::instruction::ExecuteRevert {
+        amount: 0,
+        sender: env.authority.pubkey(),
+        data: [0x11; 32].to_vec(),
+    }
+    .data();
+    assert_error(env.send(&[ix], &[]), ErrorCode::TransferNotFound);
+
+    let mut ix = env.gateway_abort_ix(transfer_id, 0);
+    ix.data = mock_gateway::instruction::ExecuteAbort {
+        amount: 0,
+        sender: env.authority.pubkey(),
+        data: Vec::new(),
+    }
+    .data();
+    assert_error(env.send(&[ix], &[]), ErrorCode::TransferNotFound);
+
+    assert!(transfer(&env, &transfer_id).status == TransferStatus::Initiated);
+}
+
+#[test]
+fn on_abort_then_reclaim() {
+    let mut env = TestEnv::new();
+    let owner = env.user();
+    let stranger = env.user();
+    let mint = env.mint_nft(&owner.pubkey(), "1");
+    let transfer_id = env.deposit_and_call(&mint, &owner);
+
+    // Only aborted transfers can be reclaimed
+    let ix = env.reclaim_ix(transfer_id, &mint, &owner.pubkey());
+    assert_error(env.send(&[ix], &[&owner]), ErrorCode::InvalidTransferStatus);
+
+    let balance = env.balance(&owner.pubkey());
+    let ix = env.gateway_abort_ix(transfer_id, POLYGON_FEE);
+    env.send(&[ix], &[]).unwrap();
+    assert!(transfer(&env, &transfer_id).status == TransferStatus::Aborted);
+    assert_eq!(env.balance(&owner.pubkey()), balance + POLYGON_FEE);
+
+    // The NFT stays in escrow until its owner reclaims it
+    assert_eq!(env.token_amount(&escrow(&mint)), 1);
+    let ix = env.reclaim_ix(transfer_id, &mint, &stranger.pubkey());
+    assert_error(env.send(&[ix], &[&stranger]), ErrorCode::Unauthorized);
+
+    let ix = env.reclaim_ix(transfer_id, &mint, &owner.pubkey());
+    env.send(&[ix], &[&owner]).unwrap();
+    assert!(transfer(&env, &transfer_id).completed_at.is_some());
+    assert_eq!(
+        env.token_amount(&get_associated_token_address(&owner.pubkey(), &mint)),
+        1
+    );
+
+    let ix = env.reclaim_ix(transfer_id, &mint, &owner.pubkey());
+    assert_error(env.send(&[ix], &[&owner]), ErrorCode::NftNotLocked);
+
+    let ix = env.gateway_abort_ix(transfer_id, 0);
+    assert_error(env.send(&[ix], &[]), ErrorCode::InvalidTransferStatus);
+}
+
+#[test]
+fn on_abort_pays_abort_address() {
+    let mut env = TestEnv::new();
+    let owner = env.user();
+    let abort_address = Keypair::new();
+    let mint = env.mint_nft(&owner.pubkey(), "1");
+    env.svm
+        .airdrop(&abort_address.pubkey(), LAMPORTS_PER_SOL)
+        .unwrap();
+
+    let transfer_id = env.next_transfer_id(&mint, &owner.pubkey());
+    let revert_options = universal_nft::gateway_cpi::RevertOptions {
+        revert_address: Pubkey::default(),
+        abort_address: abort_address.pubkey(),
+        call_on_revert: true,
+        revert_message: Vec::new(),
+        on_revert_gas_limit: 0,
+    };
+    let ix = env.deposit_and_call_ix(
+        &mint,
+        &owner,
+        POLYGON_CHAIN_ID,
+        [0xab; 20],
+        Some(revert_options),
+    );
+    env.send(&[ix], &[&owner]).unwrap();
+    assert_eq!(
+        transfer(&env, &transfer_id).abort_address,
+        abort_address.pubkey()
+    );
+
+    let balance = env.balance(&abort_address.pubkey());
+    let ix = env.gateway_abort_ix(transfer_id, POLYGON_FEE);
+    env.send(&[ix], &[]).unwrap();
+    assert_eq!(env.balance(&abort_address.pubkey()), balance + POLYGON_FEE);
+
+    // The abort address may reclaim the NFT as well
+    let ix = env.reclaim_ix(transfer_id, &mint, &abort_address.pubkey());
+    env.send(&[ix], &[&abort_address]).unwrap();
+    let nft: UniversalNft = env.account(&universal_nft_pda(&mint));
+    assert_eq!(nft.owner, abort_address.pubkey());
+}
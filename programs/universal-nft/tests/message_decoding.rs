@@ -0,0 +1,302 @@
+//! Property tests for the inbound payload decoders
+//!
+//! `on_call` hands attacker-controlled bytes straight to `NftMessage::decode`
+//! or `AbiNftMessage::decode`, so both must reject anything malformed without
+//! panicking and only ever yield fields that fit the `UniversalNft` account.
+//! These run without the compiled program: `cargo test --test message_decoding`.
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::{AnchorSerialize, Space};
+use proptest::prelude::*;
+
+use universal_nft::{
+    format_token_id, parse_token_id, AbiNftMessage, AddressFormat, ChainConfig, MessageEncoding,
+    MessageHeader, NftMessage, UniversalNft, MAX_CHAIN_LEN, MAX_CONTRACT_LEN, MAX_NAME_LEN,
+    MAX_RECIPIENT_LEN, MAX_SYMBOL_LEN, MAX_TOKEN_ID_LEN, MAX_URI_LEN, MESSAGE_VERSION,
+    MSG_TYPE_MINT, MSG_TYPE_TRANSFER, MSG_TYPE_UPDATE_METADATA, SOLANA_CHAIN_ID,
+};
+
+/// Head words of the ABI tuple that hold offsets into the tail
+const ABI_OFFSET_WORDS: [usize; 2] = [2, 4];
+
+/// Assert a decoded message stays within the limits `validate` promises and
+/// that the record `on_call` would store from it fits `UniversalNft::INIT_SPACE`
+fn assert_fits_account(message: &NftMessage) {
+    assert_eq!(message.header.version, MESSAGE_VERSION);
+    assert!((MSG_TYPE_MINT..=MSG_TYPE_UPDATE_METADATA).contains(&message.header.message_type));
+    assert!(!message.original_chain.is_empty() && message.original_chain.len() <= MAX_CHAIN_LEN);
+    assert!(message.original_contract.len() <= MAX_CONTRACT_LEN);
+    assert!(
+        !message.original_token_id.is_empty()
+            && message.original_token_id.len() <= MAX_TOKEN_ID_LEN
+    );
+    assert!(message.uri.len() <= MAX_URI_LEN);
+    assert!(message.name.len() <= MAX_NAME_LEN);
+    assert!(message.symbol.len() <= MAX_SYMBOL_LEN);
+    assert!(!message.recipient.is_empty() && message.recipient.len() <= MAX_RECIPIENT_LEN);
+
+    // An empty origin contract falls back to the hex-encoded 20-byte sender,
+    // and the lock fields are sized for their largest values
+    let original_contract = if message.original_contract.is_empty() {
+        hex::encode([0u8; 20])
+    } else {
+        message.original_contract.clone()
+    };
+    let record = UniversalNft {
+        mint: Pubkey::default(),
+        owner: Pubkey::default(),
+        original_chain: message.original_chain.clone(),
+        original_contract,
+        original_token_id: message.original_token_id.clone(),
+        metadata_uri: message.uri.clone(),
+        is_locked: true,
+        lock_destination_chain: "c".repeat(MAX_CHAIN_LEN),
+        lock_recipient: "r".repeat(64),
+        active_transfer: Some([0xff; 32]),
+        created_at: 0,
+        updated_at: 0,
+        bump: 0,
+    };
+    assert!(8 + record.try_to_vec().unwrap().len() <= UniversalNft::INIT_SPACE);
+}
+
+fn header() -> impl Strategy<Value = MessageHeader> {
+    (
+        MSG_TYPE_MINT..=MSG_TYPE_UPDATE_METADATA,
+        any::<u64>(),
+        any::<u64>(),
+        any::<u64>(),
+    )
+        .prop_map(
+            |(message_type, nonce, source_chain_id, destination_chain_id)| MessageHeader {
+                version: MESSAGE_VERSION,
+                message_type,
+                nonce,
+                source_chain_id,
+                destination_chain_id,
+            },
+        )
+}
+
+/// Messages that pass validation; `\PC{0,n}` keeps even 4-byte characters within the byte limits
+fn nft_message() -> impl Strategy<Value = NftMessage> {
+    (
+        header(),
+        "\\PC{1,8}",
+        "[0-9a-zA-Z]{0,64}",
+        "\\PC{1,8}",
+        "\\PC{0,50}",
+        "\\PC{0,8}",
+        "[A-Z]{0,10}",
+        prop::collection::vec(any::<u8>(), 1..=MAX_RECIPIENT_LEN),
+    )
+        .prop_map(
+            |(
+                header,
+                original_chain,
+                original_contract,
+                original_token_id,
+                uri,
+                name,
+                symbol,
+                recipient,
+            )| {
+                NftMessage {
+                    header,
+                    original_chain,
+                    original_contract,
+                    original_token_id,
+                    uri,
+                    name,
+                    symbol,
+                    recipient,
+                }
+            },
+        )
+}
+
+fn abi_message() -> impl Strategy<Value = AbiNftMessage> {
+    (
+        any::<[u8; 32]>(),
+        any::<[u8; 32]>(),
+        "\\PC{0,50}",
+        any::<u64>(),
+        prop::collection::vec(any::<u8>(), 0..=MAX_RECIPIENT_LEN),
+        any::<u64>(),
+    )
+        .prop_map(
+            |(receiver, token_id, uri, gas_amount, sender, nonce)| AbiNftMessage {
+                receiver,
+                token_id,
+                uri,
+                gas_amount,
+                sender,
+                nonce,
+            },
+        )
+}
+
+/// A valid encoding with some bytes overwritten and possibly truncated
+fn mutated(encoded: impl Strategy<Value = Vec<u8>>) -> impl Strategy<Value = Vec<u8>> {
+    (
+        encoded,
+        prop::collection::vec((any::<prop::sample::Index>(), any::<u8>()), 0..8),
+        any::<prop::sample::Index>(),
+        any::<bool>(),
+    )
+        .prop_map(|(mut bytes, edits, cut, truncate)| {
+            for (index, byte) in edits {
+                let position = index.index(bytes.len());
+                bytes[position] = byte;
+            }
+            if truncate {
+                bytes.truncate(cut.index(bytes.len()));
+            }
+            bytes
+        })
+}
+
+/// ABI payloads whose offset words point anywhere, including past the end and near `u64::MAX`
+fn abi_with_arbitrary_offsets() -> impl Strategy<Value = Vec<u8>> {
+    (
+        abi_message(),
+        prop::collection::vec(
+            prop_oneof![0u64..512, any::<u64>(), (u64::MAX - 64)..=u64::MAX],
+            ABI_OFFSET_WORDS.len(),
+        ),
+    )
+        .prop_map(|(message, offsets)| {
+            let mut bytes = message.encode();
+            for (word, offset) in ABI_OFFSET_WORDS.iter().zip(offsets) {
+                let start = word * 32;
+                bytes[start..start + 32].fill(0);
+                bytes[start + 24..start + 32].copy_from_slice(&offset.to_be_bytes());
+            }
+            bytes
+        })
+}
+
+proptest! {
+    #[test]
+    fn nft_message_round_trips(message in nft_message()) {
+        let encoded = message.encode().unwrap();
+        prop_assert_eq!(encoded[0], MESSAGE_VERSION);
+        let decoded = NftMessage::decode(&encoded).unwrap();
+        assert_fits_account(&decoded);
+        prop_assert_eq!(decoded, message);
+    }
+
+    #[test]
+    fn nft_message_decodes_arbitrary_bytes(
+        data in prop_oneof![
+            prop::collection::vec(any::<u8>(), 0..512),
+            mutated(nft_message().prop_map(|message| message.encode().unwrap())),
+        ]
+    ) {
+        // Borsh has a single encoding per value, so anything accepted
+        // re-encodes to exactly the bytes it came from
+        if let Ok(message) = NftMessage::decode(&data) {
+            assert_fits_account(&message);
+            prop_assert_eq!(message.encode().unwrap(), data);
+        }
+    }
+
+    #[test]
+    fn nft_message_rejects_oversized_fields(
+        message in nft_message(),
+        field in 0usize..7,
+        excess in 1usize..64,
+    ) {
+        let mut message = message;
+        match field {
+            0 => message.original_chain = "c".repeat(MAX_CHAIN_LEN + excess),
+            1 => message.original_contract = "a".repeat(MAX_CONTRACT_LEN + excess),
+            2 => message.original_token_id = "1".repeat(MAX_TOKEN_ID_LEN + excess),
+            3 => message.uri = "u".repeat(MAX_URI_LEN + excess),
+            4 => message.name = "n".repeat(MAX_NAME_LEN + excess),
+            5 => message.symbol = "S".repeat(MAX_SYMBOL_LEN + excess),
+            _ => message.recipient = vec![1; MAX_RECIPIENT_LEN + excess],
+        }
+        prop_assert!(message.encode().is_err());
+
+        // Bypass the encoder's validation to check the decoder enforces it too
+        let mut data = Vec::new();
+        message.serialize(&mut data).unwrap();
+        prop_assert!(NftMessage::decode(&data).is_err());
+    }
+
+    #[test]
+    fn abi_message_round_trips(message in abi_message()) {
+        let encoded = message.encode();
+        prop_assert_eq!(encoded.len() % 32, 0);
+        prop_assert_eq!(AbiNftMessage::decode(&encoded).unwrap(), message);
+    }
+
+    #[test]
+    fn abi_message_decodes_arbitrary_bytes(
+        data in prop_oneof![
+            prop::collection::vec(any::<u8>(), 0..512),
+            mutated(abi_message().prop_map(|message| message.encode())),
+            abi_with_arbitrary_offsets(),
+        ],
+        source_chain_id in any::<u64>(),
+        source_chain_name in "[a-z]{1,32}",
+        connected_contract in any::<[u8; 20]>(),
+    ) {
+        let Ok(message) = AbiNftMessage::decode(&data) else {
+            return Ok(());
+        };
+        prop_assert!(message.uri.len() <= MAX_URI_LEN);
+        prop_assert!(message.sender.len() <= MAX_RECIPIENT_LEN);
+
+        // Offsets need not be canonical, so compare values rather than bytes
+        prop_assert_eq!(&AbiNftMessage::decode(&message.encode()).unwrap(), &message);
+
+        let source = ChainConfig {
+            chain_id: source_chain_id,
+            enabled: true,
+            name: source_chain_name,
+            address_format: AddressFormat::Evm,
+            message_encoding: MessageEncoding::Abi,
+            connected_contract: connected_contract.to_vec(),
+            cross_chain_fee: 0,
+            bump: 0,
+        };
+        let nonce = message.nonce;
+        if let Ok(converted) = message.into_nft_message(&source, None) {
+            prop_assert_eq!(converted.header.message_type, MSG_TYPE_TRANSFER);
+            prop_assert_eq!(converted.header.nonce, nonce);
+            prop_assert_eq!(converted.header.source_chain_id, source_chain_id);
+            prop_assert_eq!(converted.header.destination_chain_id, SOLANA_CHAIN_ID);
+            // Tokens of an ABI chain originate in its connected contract
+            prop_assert_eq!(&converted.original_chain, &source.name);
+            prop_assert_eq!(&converted.original_contract, &hex::encode(connected_contract));
+            assert_fits_account(&converted);
+        }
+    }
+
+    #[test]
+    fn token_ids_round_trip(word in any::<[u8; 32]>(), leading_zero_bytes in 0usize..=32) {
+        let mut word = word;
+        word[..leading_zero_bytes].fill(0);
+        if let Ok(formatted) = format_token_id(&word) {
+            prop_assert!(!formatted.is_empty() && formatted.len() <= MAX_TOKEN_ID_LEN);
+            prop_assert_eq!(parse_token_id(&formatted).unwrap(), word);
+        }
+    }
+
+    #[test]
+    fn token_ids_format_back(token_id in prop_oneof![
+        any::<u64>().prop_map(|value| value.to_string()),
+        "0x[1-9a-f][0-9a-f]{16,29}",
+    ]) {
+        // Decimal ids fit a u64 and hex ids do not, so both format back unchanged
+        let word = parse_token_id(&token_id).unwrap();
+        prop_assert_eq!(format_token_id(&word).unwrap(), token_id);
+    }
+
+    #[test]
+    fn parse_token_id_never_panics(token_id in "(0x)?\\PC{0,80}") {
+        let _ = parse_token_id(&token_id);
+    }
+}
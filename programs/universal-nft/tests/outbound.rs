@@ -0,0 +1,524 @@
+//! Minting and the outbound transfer lifecycle
+
+mod common;
+
+use anchor_lang::Space;
+use anchor_spl::associated_token::get_associated_token_address;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+use common::*;
+use universal_nft::error::ErrorCode;
+use universal_nft::{
+    AddressFormat, CrossChainTransfer, MessageEncoding, NftMessage, TransferInitiated,
+    TransferNonce, TransferStatus, UniversalNft, DEFAULT_TRANSFER_TIMEOUT, MSG_TYPE_TRANSFER,
+    SOLANA_CHAIN_ID, TRANSFER_RETENTION_PERIOD,
+};
+
+/// The configured gateway as a keypair, so it can sign confirmations
+fn signing_gateway(env: &mut TestEnv) -> Keypair {
+    let gateway = Keypair::new();
+    env.set_gateway(gateway.pubkey());
+    gateway
+}
+
+fn transfer(env: &TestEnv, transfer_id: &[u8; 32]) -> CrossChainTransfer {
+    env.account(&transfer_pda(transfer_id))
+}
+
+#[test]
+fn mint_nft() {
+    let mut env = TestEnv::new();
+    let owner = Pubkey::new_unique();
+
+    let mint = env.mint_nft(&owner, "1");
+
+    let nft: UniversalNft = env.account(&universal_nft_pda(&mint));
+    assert_eq!(nft.mint, mint);
+    assert_eq!(nft.owner, owner);
+    assert_eq!(nft.original_chain, "solana");
+    assert_eq!(nft.original_contract, "native");
+    assert_eq!(nft.original_token_id, "1");
+    assert!(!nft.is_locked);
+    assert_eq!(
+        env.token_amount(&get_associated_token_address(&owner, &mint)),
+        1
+    );
+
+    // The NFT is a verified member of the collection
+    let metadata = env.metaplex_metadata(&mint);
+    let collection = metadata.collection.expect("collection is set");
+    assert_eq!(collection.key, env.collection_mint);
+    assert!(collection.verified);
+    assert!(env.exists(&master_edition(&mint)));
+}
+
+#[test]
+fn mint_nft_errors() {
+    let mut env = TestEnv::new();
+    let owner = Pubkey::new_unique();
+    let stranger = env.user();
+    let collection_authority = env.collection_authority.insecure_clone();
+    let existing = env.mint_nft(&owner, "1");
+
+    // Each origin maps to a single mint
+    let mint = Keypair::new();
+    let ix = env.mint_nft_ix(&mint.pubkey(), &owner, "solana", "native", "1");
+    assert!(env.send(&[ix], &[&mint, &collection_authority]).is_err());
+
+    let ix = env.mint_nft_ix(&mint.pubkey(), &owner, "", "native", "2");
+    assert_error(
+        env.send(&[ix], &[&mint, &collection_authority]),
+        ErrorCode::InvalidOriginalChain,
+    );
+
+    let ix = env.mint_nft_ix(&mint.pubkey(), &owner, "solana", "native", "2");
+    let ix = replace_account(ix, &env.collection_authority.pubkey(), &stranger.pubkey());
+    assert_error(
+        env.send(&[ix], &[&mint, &stranger]),
+        ErrorCode::Unauthorized,
+    );
+
+    let ix = env.mint_nft_ix(&mint.pubkey(), &owner, "solana", "native", "2");
+    let ix = replace_account(ix, &env.collection_mint, &existing);
+    assert_error(
+        env.send(&[ix], &[&mint, &collection_authority]),
+        ErrorCode::InvalidCollection,
+    );
+}
+
+#[test]
+fn initiate_locks_nft_in_escrow() {
+    let mut env = TestEnv::new();
+    let owner = env.user();
+    let mint = env.mint_nft(&owner.pubkey(), "1");
+    let fee_recipient_balance = env.balance(&env.fee_recipient);
+
+    let ix = env.initiate_ix(&mint, &owner, POLYGON_CHAIN_ID, EVM_RECIPIENT);
+    let logs = env.send(&[ix], &[&owner]).unwrap().logs;
+    let transfer_id = CrossChainTransfer::transfer_id(&mint, &owner.pubkey(), 0);
+
+    let record = transfer(&env, &transfer_id);
+    assert_eq!(record.transfer_id, transfer_id);
+    assert_eq!(record.nft_mint, mint);
+    assert_eq!(record.source_owner, owner.pubkey());
+    assert_eq!(record.payer, env.authority.pubkey());
+    assert_eq!(record.destination_chain, POLYGON_CHAIN_ID.to_string());
+    assert_eq!(record.destination_recipient, EVM_RECIPIENT);
+    assert!(!record.via_gateway);
+    assert!(record.status == TransferStatus::Initiated);
+    // The bump is the canonical one, found on-chain
+    assert_eq!(
+        record.bump,
+        CrossChainTransfer::find_address(&transfer_id).1
+    );
+
+    let nft: UniversalNft = env.account(&universal_nft_pda(&mint));
+    assert!(nft.is_locked);
+    assert_eq!(nft.active_transfer, Some(transfer_id));
+    assert_eq!(
+        env.token_amount(&get_associated_token_address(&owner.pubkey(), &mint)),
+        0
+    );
+    assert_eq!(env.token_amount(&escrow(&mint)), 1);
+
+    // The chain's fee goes to the configured fee recipient
+    assert_eq!(
+        env.balance(&env.fee_recipient),
+        fee_recipient_balance + POLYGON_FEE
+    );
+    let nonce: TransferNonce = env.account(&transfer_nonce(&owner.pubkey()));
+    assert_eq!(nonce.nonce, 1);
+
+    let emitted = events::<TransferInitiated>(&logs);
+    assert_eq!(emitted.len(), 1);
+    let event = &emitted[0];
+    assert_eq!(event.transfer_id, transfer_id);
+    assert_eq!(event.nonce, 0);
+    assert_eq!(event.message_nonce, None);
+    assert_eq!(event.fee, POLYGON_FEE);
+}
+
+#[test]
+fn initiate_errors() {
+    let mut env = TestEnv::new();
+    let owner = env.user();
+    let stranger = env.user();
+    let mint = env.mint_nft(&owner.pubkey(), "1");
+
+    let ix = env.initiate_ix(&mint, &owner, POLYGON_CHAIN_ID, "not-an-address");
+    assert_error(
+        env.send(&[ix], &[&owner]),
+        ErrorCode::InvalidDestinationAddress,
+    );
+
+    let ix = env.initiate_ix(&mint, &owner, 1, EVM_RECIPIENT);
+    assert_error(env.send(&[ix], &[&owner]), ErrorCode::InvalidChainId);
+
+    env.create_token_account(&stranger.pubkey(), &mint);
+    let ix = env.initiate_ix(&mint, &stranger, POLYGON_CHAIN_ID, EVM_RECIPIENT);
+    assert_error(env.send(&[ix], &[&stranger]), ErrorCode::Unauthorized);
+
+    // The fee must go to the configured recipient
+    let ix = env.initiate_ix(&mint, &owner, POLYGON_CHAIN_ID, EVM_RECIPIENT);
+    let ix = replace_account(ix, &env.fee_recipient, &stranger.pubkey());
+    assert_error(env.send(&[ix], &[&owner]), ErrorCode::Unauthorized);
+
+    let ix = env.initiate_ix(&mint, &owner, POLYGON_CHAIN_ID, EVM_RECIPIENT);
+    let ix = replace_account(ix, &mock_gateway::ID, &Pubkey::new_unique());
+    assert_error(env.send(&[ix], &[&owner]), ErrorCode::GatewayNotConfigured);
+
+    // A fee larger than the payer's balance
+    let ix = env.register_chain_ix(
+        56,
+        "bsc",
+        AddressFormat::Evm,
+        MessageEncoding::Borsh,
+        vec![0x22; 20],
+        Some(1_000 * LAMPORTS_PER_SOL),
+    );
+    env.send(&[ix], &[]).unwrap();
+    let ix = env.initiate_ix(&mint, &owner, 56, EVM_RECIPIENT);
+    assert_error(env.send(&[ix], &[&owner]), ErrorCode::InsufficientFunds);
+
+    let ix = env.set_pause_ix(&env.authority.pubkey(), None, Some(true), None);
+    env.send(&[ix], &[]).unwrap();
+    let ix = env.initiate_ix(&mint, &owner, POLYGON_CHAIN_ID, EVM_RECIPIENT);
+    assert_error(env.send(&[ix], &[&owner]), ErrorCode::ProgramPaused);
+    let ix = env.set_pause_ix(&env.authority.pubkey(), None, Some(false), None);
+    env.send(&[ix], &[]).unwrap();
+
+    env.initiate(&mint, &owner);
+    let ix = env.initiate_ix(&mint, &owner, POLYGON_CHAIN_ID, EVM_RECIPIENT);
+    assert_error(env.send(&[ix], &[&owner]), ErrorCode::NftLocked);
+}
+
+#[test]
+fn initiate_rejects_nonce_overflow() {
+    let mut env = TestEnv::new();
+    let owner = env.user();
+    let mint = env.mint_nft(&owner.pubkey(), "1");
+
+    let nonce = TransferNonce {
+        owner: owner.pubkey(),
+        nonce: u64::MAX,
+        bump: 0,
+    };
+    env.set_program_account(
+        transfer_nonce(&owner.pubkey()),
+        &nonce,
+        TransferNonce::INIT_SPACE,
+    );
+    let ix = env.initiate_ix(&mint, &owner, POLYGON_CHAIN_ID, EVM_RECIPIENT);
+    assert_error(env.send(&[ix], &[&owner]), ErrorCode::NonceOverflow);
+}
+
+#[test]
+fn confirm_and_complete() {
+    let mut env = TestEnv::new();
+    let owner = env.user();
+    let mint = env.mint_nft(&owner.pubkey(), "1");
+    let transfer_id = env.initiate(&mint, &owner);
+    let gateway = signing_gateway(&mut env);
+
+    // Completing requires a confirmed transfer
+    let ix = env.complete_ix(transfer_id, &mint, &gateway.pubkey());
+    assert_error(
+        env.send(&[ix], &[&gateway]),
+        ErrorCode::InvalidTransferStatus,
+    );
+
+    let ix = env.confirm_ix(transfer_id, &gateway.pubkey());
+    env.send(&[ix], &[&gateway]).unwrap();
+    assert!(transfer(&env, &transfer_id).status == TransferStatus::Confirmed);
+
+    let ix = env.complete_ix(transfer_id, &mint, &gateway.pubkey());
+    env.send(&[ix], &[&gateway]).unwrap();
+    let record = transfer(&env, &transfer_id);
+    assert!(record.status == TransferStatus::Completed);
+    assert!(record.completed_at.is_some());
+
+    // The NFT stays escrowed while it lives on the other chain, but the
+    // finished transfer no longer holds its lock
+    assert_eq!(env.token_amount(&escrow(&mint)), 1);
+    let nft: UniversalNft = env.account(&universal_nft_pda(&mint));
+    assert!(nft.is_locked);
+    assert_eq!(nft.active_transfer, None);
+
+    let ix = env.confirm_ix(transfer_id, &gateway.pubkey());
+    assert_error(
+        env.send(&[ix], &[&gateway]),
+        ErrorCode::InvalidTransferStatus,
+    );
+}
+
+#[test]
+fn confirm_errors() {
+    let mut env = TestEnv::new();
+    let owner = env.user();
+    let mint = env.mint_nft(&owner.pubkey(), "1");
+    let transfer_id = env.initiate(&mint, &owner);
+    let gateway = signing_gateway(&mut env);
+    let stranger = env.user();
+
+    let ix = env.confirm_ix(transfer_id, &stranger.pubkey());
+    assert_error(
+        env.send(&[ix], &[&stranger]),
+        ErrorCode::GatewayNotConfigured,
+    );
+
+    env.warp(DEFAULT_TRANSFER_TIMEOUT);
+    let ix = env.confirm_ix(transfer_id, &gateway.pubkey());
+    assert_error(env.send(&[ix], &[&gateway]), ErrorCode::TransferTimeout);
+}
+
+#[test]
+fn cancel_after_timeout() {
+    let mut env = TestEnv::new();
+    let owner = env.user();
+    let mint = env.mint_nft(&owner.pubkey(), "1");
+    let transfer_id = env.initiate(&mint, &owner);
+
+    env.warp(DEFAULT_TRANSFER_TIMEOUT);
+    let ix = env.cancel_ix(transfer_id, &mint, &owner.pubkey());
+    env.send(&[ix], &[&owner]).unwrap();
+
+    assert!(transfer(&env, &transfer_id).status == TransferStatus::Cancelled);
+    let nft: UniversalNft = env.account(&universal_nft_pda(&mint));
+    assert!(!nft.is_locked);
+    assert_eq!(
+        env.token_amount(&get_associated_token_address(&owner.pubkey(), &mint)),
+        1
+    );
+    assert_eq!(env.token_amount(&escrow(&mint)), 0);
+    assert_eq!(nft.active_transfer, None);
+
+    // The NFT is no longer locked, so the stale transfer cannot be cancelled again
+    let ix = env.cancel_ix(transfer_id, &mint, &owner.pubkey());
+    assert_error(env.send(&[ix], &[&owner]), ErrorCode::NftNotLocked);
+}
+
+#[test]
+fn cancel_before_confirmation() {
+    let mut env = TestEnv::new();
+    let owner = env.user();
+    let gateway = signing_gateway(&mut env);
+    let mint = env.mint_nft(&owner.pubkey(), "1");
+
+    // The owner changes their mind while the gateway has not confirmed yet
+    let transfer_id = env.initiate(&mint, &owner);
+    let ix = env.cancel_ix(transfer_id, &mint, &owner.pubkey());
+    env.send(&[ix], &[&owner]).unwrap();
+    assert!(transfer(&env, &transfer_id).status == TransferStatus::Cancelled);
+    assert_eq!(
+        env.token_amount(&get_associated_token_address(&owner.pubkey(), &mint)),
+        1
+    );
+
+    // The gateway can no longer confirm the cancelled transfer
+    let ix = env.confirm_ix(transfer_id, &gateway.pubkey());
+    assert_error(
+        env.send(&[ix], &[&gateway]),
+        ErrorCode::InvalidTransferStatus,
+    );
+
+    // A confirmed transfer is on its way and cannot be cancelled
+    let transfer_id = env.initiate(&mint, &owner);
+    let ix = env.confirm_ix(transfer_id, &gateway.pubkey());
+    env.send(&[ix], &[&gateway]).unwrap();
+    let ix = env.cancel_ix(transfer_id, &mint, &owner.pubkey());
+    assert_error(env.send(&[ix], &[&owner]), ErrorCode::InvalidTransferStatus);
+    assert_eq!(env.token_amount(&escrow(&mint)), 1);
+}
+
+#[test]
+fn cancel_refuses_gateway_transfer() {
+    let mut env = TestEnv::new();
+    let owner = env.user();
+    let mint = env.mint_nft(&owner.pubkey(), "1");
+    let transfer_id = env.deposit_and_call(&mint, &owner);
+
+    // The gateway never confirms deposit_and_call transfers; cancelling one
+    // after the timeout would leave the NFT on both chains
+    env.warp(DEFAULT_TRANSFER_TIMEOUT);
+    let ix = env.cancel_ix(transfer_id, &mint, &owner.pubkey());
+    assert_error(env.send(&[ix], &[&owner]), ErrorCode::TransferSentToGateway);
+    assert!(transfer(&env, &transfer_id).status == TransferStatus::Initiated);
+    assert_eq!(env.token_amount(&escrow(&mint)), 1);
+}
+
+#[test]
+fn close_after_retention_period() {
+    let mut env = TestEnv::new();
+    let owner = env.user();
+    let mint = env.mint_nft(&owner.pubkey(), "1");
+    let transfer_id = env.initiate(&mint, &owner);
+    let payer = env.authority.pubkey();
+
+    let ix = env.close_transfer_ix(transfer_id, &payer);
+    assert_error(env.send(&[ix], &[]), ErrorCode::InvalidTransferStatus);
+
+    env.warp(DEFAULT_TRANSFER_TIMEOUT);
+    let ix = env.cancel_ix(transfer_id, &mint, &owner.pubkey());
+    env.send(&[ix], &[&owner]).unwrap();
+
+    let ix = env.close_transfer_ix(transfer_id, &payer);
+    assert_error(env.send(&[ix], &[]), ErrorCode::RetentionPeriodActive);
+
+    env.warp(TRANSFER_RETENTION_PERIOD);
+    let ix = env.close_transfer_ix(transfer_id, &owner.pubkey());
+    assert_error(env.send(&[ix], &[&owner]), ErrorCode::Unauthorized);
+
+    let balance = env.balance(&payer);
+    let ix = env.close_transfer_ix(transfer_id, &payer);
+    env.send(&[ix], &[]).unwrap();
+    assert!(!env.exists(&transfer_pda(&transfer_id)));
+    // The rent comes back, less the transaction fee
+    assert!(env.balance(&payer) > balance);
+}
+
+#[test]
+fn close_completed_transfer_before_return() {
+    let mut env = TestEnv::new();
+    let owner = env.user();
+    let mint = env.mint_nft(&owner.pubkey(), "1");
+    let transfer_id = env.initiate(&mint, &owner);
+    let payer = env.authority.pubkey();
+    let gateway = signing_gateway(&mut env);
+    let ix = env.confirm_ix(transfer_id, &gateway.pubkey());
+    env.send(&[ix], &[&gateway]).unwrap();
+    let ix = env.complete_ix(transfer_id, &mint, &gateway.pubkey());
+    env.send(&[ix], &[&gateway]).unwrap();
+    env.warp(TRANSFER_RETENTION_PERIOD);
+
+    // A transfer still holding the NFT's lock stays open
+    let mut nft: UniversalNft = env.account(&universal_nft_pda(&mint));
+    nft.active_transfer = Some(transfer_id);
+    env.set_program_account(universal_nft_pda(&mint), &nft, UniversalNft::INIT_SPACE);
+    let ix = env.close_transfer_ix(transfer_id, &payer);
+    assert_error(env.send(&[ix], &[]), ErrorCode::NftLocked);
+    nft.active_transfer = None;
+    env.set_program_account(universal_nft_pda(&mint), &nft, UniversalNft::INIT_SPACE);
+
+    // The completed transfer can go while the NFT lives on Polygon
+    let ix = env.close_transfer_ix(transfer_id, &payer);
+    env.send(&[ix], &[]).unwrap();
+    assert!(!env.exists(&transfer_pda(&transfer_id)));
+
+    // and the NFT still comes back from escrow
+    env.set_gateway(mock_gateway::ID);
+    let mut message = polygon_message(MSG_TYPE_TRANSFER, 1, "1", &owner.pubkey());
+    message.original_chain = "solana".to_string();
+    message.original_contract = "native".to_string();
+    let data = message.encode().unwrap();
+    let accounts = env.on_call_accounts(
+        &data,
+        &mint,
+        &owner.pubkey(),
+        Some(origin_index("solana", "native", "1")),
+        Some(escrow(&mint)),
+        None,
+    );
+    let ix = env.gateway_execute_ix(POLYGON_CONTRACT, data, accounts);
+    env.send(&[ix], &[]).unwrap();
+
+    let nft: UniversalNft = env.account(&universal_nft_pda(&mint));
+    assert!(!nft.is_locked);
+    assert_eq!(nft.owner, owner.pubkey());
+    assert_eq!(
+        env.token_amount(&get_associated_token_address(&owner.pubkey(), &mint)),
+        1
+    );
+    assert_eq!(env.token_amount(&escrow(&mint)), 0);
+}
+
+#[test]
+fn deposit_and_call_through_gateway() {
+    let mut env = TestEnv::new();
+    let owner = env.user();
+    let mint = env.mint_nft(&owner.pubkey(), "1");
+
+    // Another owner's transfer advances the outbound message nonce, but not
+    // this owner's transfer nonce
+    let other = env.user();
+    let other_mint = env.mint_nft(&other.pubkey(), "2");
+    env.deposit_and_call(&other_mint, &other);
+
+    let ix = env.deposit_and_call_ix(&mint, &owner, POLYGON_CHAIN_ID, [0xab; 20], None);
+    let logs = env.send(&[ix], &[&owner]).unwrap().logs;
+    let transfer_id = CrossChainTransfer::transfer_id(&mint, &owner.pubkey(), 0);
+
+    // Indexers can rebuild the transfer id from the event
+    let emitted = events::<TransferInitiated>(&logs);
+    assert_eq!(emitted.len(), 1);
+    let event = &emitted[0];
+    assert_eq!(
+        CrossChainTransfer::transfer_id(&event.nft_mint, &event.owner, event.nonce),
+        transfer_id
+    );
+    assert_eq!(event.transfer_id, transfer_id);
+    assert_eq!(event.nonce, 0);
+    assert_eq!(event.message_nonce, Some(1));
+
+    let record = transfer(&env, &transfer_id);
+    assert!(record.status == TransferStatus::Initiated);
+    assert_eq!(record.refund_address, owner.pubkey());
+    assert_eq!(record.destination_recipient, hex::encode([0xab; 20]));
+    assert!(record.via_gateway);
+    assert_eq!(
+        record.bump,
+        CrossChainTransfer::find_address(&transfer_id).1
+    );
+    assert_eq!(env.token_amount(&escrow(&mint)), 1);
+
+    // The gateway received the fee and the encoded message
+    let gateway: mock_gateway::Gateway = env.account(&gateway_pda());
+    assert_eq!(gateway.deposit_count, 2);
+    assert_eq!(gateway.total_deposited, 2 * POLYGON_FEE);
+    let deposit = gateway.last_deposit.expect("deposit recorded");
+    assert_eq!(deposit.sender, env.authority.pubkey());
+    assert_eq!(deposit.amount, POLYGON_FEE);
+    assert_eq!(deposit.receiver, [0xab; 20]);
+    assert_eq!(deposit.destination_chain_id, POLYGON_CHAIN_ID);
+
+    // A revert hands the transfer id back to on_revert
+    assert_eq!(deposit.revert_address, owner.pubkey());
+    assert_eq!(deposit.abort_address, owner.pubkey());
+    assert!(deposit.call_on_revert);
+    assert_eq!(deposit.revert_message, transfer_id.to_vec());
+
+    let message = NftMessage::decode(&deposit.message).unwrap();
+    assert_eq!(message.header.message_type, MSG_TYPE_TRANSFER);
+    assert_eq!(message.header.nonce, 1);
+    assert_eq!(message.header.source_chain_id, SOLANA_CHAIN_ID);
+    assert_eq!(message.header.destination_chain_id, POLYGON_CHAIN_ID);
+    assert_eq!(message.original_token_id, "1");
+    assert_eq!(message.recipient, vec![0xab; 20]);
+}
+
+#[test]
+fn deposit_and_call_errors() {
+    let mut env = TestEnv::new();
+    let owner = env.user();
+    let mint = env.mint_nft(&owner.pubkey(), "1");
+
+    // Only EVM chains can be reached through the gateway
+    let ix = env.register_chain_ix(
+        1001,
+        "eclipse",
+        AddressFormat::Solana,
+        MessageEncoding::Borsh,
+        vec![0x33; 32],
+        None,
+    );
+    env.send(&[ix], &[]).unwrap();
+    let ix = env.deposit_and_call_ix(&mint, &owner, 1001, [0xab; 20], None);
+    assert_error(
+        env.send(&[ix], &[&owner]),
+        ErrorCode::InvalidDestinationAddress,
+    );
+
+    let ix = env.deposit_and_call_ix(&mint, &owner, POLYGON_CHAIN_ID, [0xab; 20], None);
+    let ix = replace_account(ix, &mock_gateway::ID, &anchor_spl::token::ID);
+    assert_error(env.send(&[ix], &[&owner]), ErrorCode::GatewayNotConfigured);
+}
@@ -7,6 +7,7 @@ import {
   SystemProgram,
   Connection,
   clusterApiUrl,
+  SYSVAR_RENT_PUBKEY,
 } from "@solana/web3.js";
 import {
   TOKEN_PROGRAM_ID,
@@ -15,6 +16,40 @@ import {
 } from "@solana/spl-token";
 import fs from 'fs';
 import path from 'path';
+import { createHash } from 'crypto';
+
+const TOKEN_METADATA_PROGRAM_ID = new PublicKey("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+const metadataPdaFor = (mint: PublicKey) =>
+  PublicKey.findProgramAddressSync(
+    [Buffer.from("metadata"), TOKEN_METADATA_PROGRAM_ID.toBuffer(), mint.toBuffer()],
+    TOKEN_METADATA_PROGRAM_ID
+  )[0];
+// Mirrors OriginIndex::origin_hash: length-prefixed (u32 LE) chain, contract, token id
+const originIndexPdaFor = (programId: PublicKey, chain: string, contract: string, tokenId: string) => {
+  const hash = createHash("sha256");
+  for (const part of [chain, contract, tokenId]) {
+    const bytes = Buffer.from(part);
+    const len = Buffer.alloc(4);
+    len.writeUInt32LE(bytes.length);
+    hash.update(len).update(bytes);
+  }
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("origin_index"), hash.digest()],
+    programId
+  )[0];
+};
+const masterEditionPdaFor = (mint: PublicKey) =>
+  PublicKey.findProgramAddressSync(
+    [Buffer.from("metadata"), TOKEN_METADATA_PROGRAM_ID.toBuffer(), mint.toBuffer(), Buffer.from("edition")],
+    TOKEN_METADATA_PROGRAM_ID
+  )[0];
+// Mirrors CrossChainTransfer::transfer_id: sha256(mint || owner || nonce LE)
+const transferIdFor = (mint: PublicKey, owner: PublicKey, nonce: BN) =>
+  createHash("sha256")
+    .update(mint.toBuffer())
+    .update(owner.toBuffer())
+    .update(nonce.toArrayLike(Buffer, "le", 8))
+    .digest();
 
 // Configuration
 const NETWORK = process.env.SOLANA_NETWORK || 'localnet';
@@ -97,6 +132,10 @@ async function main() {
     [Buffer.from("global_config")],
     program.programId
   );
+  const [mintAuthorityPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("mint_authority")],
+    program.programId
+  );
 
   const crossChainFee = new BN(10_000); // 0.00001 SOL
 
@@ -127,6 +166,68 @@ async function main() {
     console.log(`   ❌ Failed to initialize: ${error}`);
     return;
   }
+
+  // Register the destination chain used by the demo transfer
+  const destinationChainId = new BN(137); // Polygon
+  const [chainConfigPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("chain_config"), destinationChainId.toArrayLike(Buffer, "le", 8)],
+    program.programId
+  );
+
+  try {
+    const registerTx = await program.methods
+      .registerChain(
+        destinationChainId,
+        "polygon",
+        { evm: {} },
+        { abi: {} },
+        Buffer.from("1234567890abcdef1234567890abcdef12345678", "hex"),
+        null
+      )
+      .accounts({
+        globalConfig: globalConfigPda,
+        chainConfig: chainConfigPda,
+        authority: authority.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([authority])
+      .rpc();
+
+    console.log(`   ✅ Registered destination chain: ${registerTx}`);
+    console.log(`   📍 Chain Config PDA: ${chainConfigPda.toString()}`);
+  } catch (error) {
+    console.log(`   ❌ Failed to register chain: ${error}`);
+    return;
+  }
+
+  // Create the collection every minted NFT is verified against
+  const collectionMint = Keypair.generate();
+  try {
+    const collectionTx = await program.methods
+      .createCollection("ZetaChain Universal NFTs", "ZUNFT", "https://zetachain.com/api/metadata/solana/collection.json")
+      .accounts({
+        globalConfig: globalConfigPda,
+        collectionMint: collectionMint.publicKey,
+        mintAuthority: mintAuthorityPda,
+        collectionTokenAccount: await getAssociatedTokenAddress(collectionMint.publicKey, globalConfigPda, true),
+        collectionMetadata: metadataPdaFor(collectionMint.publicKey),
+        collectionMasterEdition: masterEditionPdaFor(collectionMint.publicKey),
+        authority: authority.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .signers([authority, collectionMint])
+      .rpc();
+
+    console.log(`   ✅ Created collection: ${collectionTx}`);
+    console.log(`   📍 Collection Mint: ${collectionMint.publicKey.toString()}`);
+  } catch (error) {
+    console.log(`   ❌ Failed to create collection: ${error}`);
+    return;
+  }
   console.log("");
 
   // 2. Mint Universal NFT
@@ -150,6 +251,10 @@ async function main() {
     nftRecipient.publicKey
   );
 
+  const metadataPda = metadataPdaFor(nftMint.publicKey);
+  const originIndexPda = originIndexPdaFor(program.programId, originalChain, originalContract, originalTokenId);
+  const masterEditionPda = masterEditionPdaFor(nftMint.publicKey);
+
   try {
     const mintTx = await program.methods
       .mintNft(
@@ -164,14 +269,23 @@ async function main() {
       .accounts({
         globalConfig: globalConfigPda,
         universalNft: universalNftPda,
+        originIndex: originIndexPda,
         mint: nftMint.publicKey,
+        mintAuthority: mintAuthorityPda,
         tokenAccount: tokenAccount,
+        metadata: metadataPda,
+        masterEdition: masterEditionPda,
+        collectionMint: collectionMint.publicKey,
+        collectionMetadata: metadataPdaFor(collectionMint.publicKey),
+        collectionMasterEdition: masterEditionPdaFor(collectionMint.publicKey),
         payer: authority.publicKey,
         recipient: nftRecipient.publicKey,
         collectionAuthority: collectionAuthority.publicKey,
         tokenProgram: TOKEN_PROGRAM_ID,
         associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
       })
       .signers([authority, nftMint, collectionAuthority])
       .rpc();
@@ -196,40 +310,58 @@ async function main() {
   // 3. Initiate Cross-Chain Transfer
   console.log("3️⃣ Initiating Cross-Chain Transfer...");
 
-  const transferId = `transfer_${Date.now()}`;
-  const destinationChain = "polygon";
+  const [transferNoncePda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("transfer_nonce"), nftRecipient.publicKey.toBuffer()],
+    program.programId
+  );
+  const transferNonce = await program.account.transferNonce.fetchNullable(transferNoncePda);
+  const transferId = transferIdFor(
+    nftMint.publicKey,
+    nftRecipient.publicKey,
+    transferNonce ? transferNonce.nonce : new BN(0)
+  );
+  const destinationChain = destinationChainId.toString();
   const destinationRecipient = "0xabcdefabcdefabcdefabcdefabcdefabcdefabcd";
 
-  const [crossChainTransferPda, crossChainTransferBump] = PublicKey.findProgramAddressSync(
-    [Buffer.from("cross_chain_transfer"), Buffer.from(transferId)],
+  const [crossChainTransferPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("cross_chain_transfer"), transferId],
     program.programId
   );
 
+  const escrowTokenAccount = await getAssociatedTokenAddress(
+    nftMint.publicKey,
+    globalConfigPda,
+    true
+  );
+
   try {
     const transferTx = await program.methods
       .initiateCrossChainTransfer(
-        transferId,
-        destinationChain,
-        destinationRecipient,
-        crossChainTransferBump
+        destinationChainId,
+        destinationRecipient
       )
       .accounts({
         globalConfig: globalConfigPda,
         universalNft: universalNftPda,
+        transferNonce: transferNoncePda,
         crossChainTransfer: crossChainTransferPda,
+        chainConfig: chainConfigPda,
         nftMint: nftMint.publicKey,
         ownerTokenAccount: tokenAccount,
+        escrowTokenAccount,
         owner: nftRecipient.publicKey,
         payer: authority.publicKey,
         zetachainGateway: zetachainGateway.publicKey,
+        feeRecipient: feeRecipient.publicKey,
         tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
       })
       .signers([authority, nftRecipient])
       .rpc();
 
     console.log(`   ✅ Initiated cross-chain transfer: ${transferTx}`);
-    console.log(`   🆔 Transfer ID: ${transferId}`);
+    console.log(`   🆔 Transfer ID: ${transferId.toString("hex")}`);
     console.log(`   🌐 Destination Chain: ${destinationChain}`);
     console.log(`   📍 Destination Recipient: ${destinationRecipient}`);
     console.log(`   📍 Cross-chain Transfer PDA: ${crossChainTransferPda.toString()}`);
@@ -242,6 +374,8 @@ async function main() {
     // Verify NFT is locked
     const updatedNftAccount = await program.account.universalNft.fetch(universalNftPda);
     console.log(`   🔐 NFT locked: ${updatedNftAccount.isLocked}`);
+    const escrowBalance = await connection.getTokenAccountBalance(escrowTokenAccount);
+    console.log(`   🏦 Escrow balance: ${escrowBalance.value.amount}`);
   } catch (error) {
     console.log(`   ❌ Failed to initiate transfer: ${error}`);
     return;
@@ -253,7 +387,7 @@ async function main() {
 
   try {
     const confirmTx = await program.methods
-      .confirmCrossChainTransfer(transferId)
+      .confirmCrossChainTransfer(Array.from(transferId))
       .accounts({
         globalConfig: globalConfigPda,
         crossChainTransfer: crossChainTransferPda,
@@ -273,23 +407,20 @@ async function main() {
   }
   console.log("");
 
-  // 5. Complete Cross-Chain Transfer (burn NFT)
-  console.log("5️⃣ Completing Cross-Chain Transfer (Burning NFT)...");
+  // 5. Complete Cross-Chain Transfer (NFT stays in escrow)
+  console.log("5️⃣ Completing Cross-Chain Transfer...");
 
   try {
     const completeTx = await program.methods
-      .completeCrossChainTransfer(transferId)
+      .completeCrossChainTransfer(Array.from(transferId))
       .accounts({
         globalConfig: globalConfigPda,
         universalNft: universalNftPda,
         crossChainTransfer: crossChainTransferPda,
         nftMint: nftMint.publicKey,
-        ownerTokenAccount: tokenAccount,
-        collectionAuthority: collectionAuthority.publicKey,
         zetachainGateway: zetachainGateway.publicKey,
-        tokenProgram: TOKEN_PROGRAM_ID,
       })
-      .signers([collectionAuthority, zetachainGateway])
+      .signers([zetachainGateway])
       .rpc();
 
     console.log(`   ✅ Completed cross-chain transfer: ${completeTx}`);
@@ -299,17 +430,11 @@ async function main() {
     console.log(`   ✅ Transfer status: Completed`);
     console.log(`   ⏰ Completed at: ${new Date(completedTransferAccount.completedAt.toNumber() * 1000)}`);
 
-    // Verify NFT is unlocked
+    // The NFT stays locked in escrow while it lives on the destination chain
     const finalNftAccount = await program.account.universalNft.fetch(universalNftPda);
-    console.log(`   🔓 NFT unlocked: ${!finalNftAccount.isLocked}`);
-
-    // Verify token was burned
-    try {
-      const tokenAccountInfo = await connection.getTokenAccountBalance(tokenAccount);
-      console.log(`   🔥 Token balance after burn: ${tokenAccountInfo.value.amount}`);
-    } catch (error) {
-      console.log(`   🔥 Token account closed (NFT burned successfully)`);
-    }
+    console.log(`   🔐 NFT locked: ${finalNftAccount.isLocked}`);
+    const finalEscrowBalance = await connection.getTokenAccountBalance(escrowTokenAccount);
+    console.log(`   🏦 Escrow balance: ${finalEscrowBalance.value.amount}`);
   } catch (error) {
     console.log(`   ❌ Failed to complete transfer: ${error}`);
     return;
@@ -323,7 +448,7 @@ async function main() {
   console.log("✅ Universal NFT minted with cross-chain metadata");
   console.log("✅ Cross-chain transfer initiated");
   console.log("✅ Transfer confirmed by ZetaChain gateway");
-  console.log("✅ Transfer completed with NFT burn");
+  console.log("✅ Transfer completed with NFT held in escrow");
   console.log("");
 
   console.log("🔗 Key Addresses:");
@@ -335,7 +460,7 @@ async function main() {
   console.log("");
 
   console.log("🌉 Cross-Chain Integration:");
-  console.log(`   Transfer ID: ${transferId}`);
+  console.log(`   Transfer ID: ${transferId.toString("hex")}`);
   console.log(`   From: Solana`);
   console.log(`   To: ${destinationChain}`);
   console.log(`   Recipient: ${destinationRecipient}`);
@@ -359,7 +484,7 @@ async function main() {
     demo: {
       nftMint: nftMint.publicKey.toString(),
       universalNftPda: universalNftPda.toString(),
-      transferId: transferId,
+      transferId: transferId.toString("hex"),
       crossChainTransferPda: crossChainTransferPda.toString(),
     },
     timestamp: new Date().toISOString(),
@@ -11,8 +11,11 @@ import {
   TOKEN_PROGRAM_ID,
   ASSOCIATED_TOKEN_PROGRAM_ID,
   getAssociatedTokenAddress,
+  getAssociatedTokenAddressSync,
+  getMint,
 } from "@solana/spl-token";
 import { expect } from "chai";
+import { createHash } from "crypto";
 
 describe("ZetaChain Universal NFT", () => {
   // Configure the client to use the local cluster.
@@ -29,6 +32,76 @@ describe("ZetaChain Universal NFT", () => {
   let nftRecipient: Keypair;
   let globalConfigPda: PublicKey;
   let globalConfigBump: number;
+  let mintAuthorityPda: PublicKey;
+  let polygonChainConfigPda: PublicKey;
+  let collectionMint: Keypair;
+  let initiatedTransferId: Buffer;
+  let revertedTransferId: Buffer;
+
+  const TOKEN_METADATA_PROGRAM_ID = new PublicKey("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+  const metadataPda = (mint: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("metadata"), TOKEN_METADATA_PROGRAM_ID.toBuffer(), mint.toBuffer()],
+      TOKEN_METADATA_PROGRAM_ID
+    )[0];
+  const masterEditionPda = (mint: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("metadata"), TOKEN_METADATA_PROGRAM_ID.toBuffer(), mint.toBuffer(), Buffer.from("edition")],
+      TOKEN_METADATA_PROGRAM_ID
+    )[0];
+
+  // Mirrors OriginIndex::origin_hash: length-prefixed (u32 LE) chain, contract, token id
+  const originIndexPda = (chain: string, contract: string, tokenId: string) => {
+    const hash = createHash("sha256");
+    for (const part of [chain, contract, tokenId]) {
+      const bytes = Buffer.from(part);
+      const len = Buffer.alloc(4);
+      len.writeUInt32LE(bytes.length);
+      hash.update(len).update(bytes);
+    }
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("origin_index"), hash.digest()],
+      program.programId
+    )[0];
+  };
+
+  // Mirrors CrossChainTransfer::transfer_id: sha256(mint || owner || nonce LE)
+  const transferNoncePda = (owner: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("transfer_nonce"), owner.toBuffer()],
+      program.programId
+    )[0];
+  const nextTransferId = async (mint: PublicKey, owner: PublicKey) => {
+    const counter = await program.account.transferNonce.fetchNullable(transferNoncePda(owner));
+    const nonce = counter ? counter.nonce : new BN(0);
+    return createHash("sha256")
+      .update(mint.toBuffer())
+      .update(owner.toBuffer())
+      .update(nonce.toArrayLike(Buffer, "le", 8))
+      .digest();
+  };
+  const transferPda = (transferId: Buffer) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("cross_chain_transfer"), transferId],
+      program.programId
+    );
+  // Typed events the program emitted in a confirmed transaction
+  const emittedEvents = async (signature: string) => {
+    await provider.connection.confirmTransaction(signature, "confirmed");
+    const tx = await provider.connection.getTransaction(signature, {
+      commitment: "confirmed",
+      maxSupportedTransactionVersion: 0,
+    });
+    const parser = new anchor.EventParser(program.programId, new anchor.BorshCoder(program.idl));
+    return Array.from(parser.parseLogs(tx.meta.logMessages));
+  };
+
+  const POLYGON_CHAIN_ID = 137;
+  const chainConfigPda = (chainId: number) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("chain_config"), new BN(chainId).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    )[0];
 
   before(async () => {
     // Create test keypairs
@@ -54,6 +127,10 @@ describe("ZetaChain Universal NFT", () => {
       [Buffer.from("global_config")],
       program.programId
     );
+    [mintAuthorityPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("mint_authority")],
+      program.programId
+    );
   });
 
   it("Initializes the global configuration", async () => {
@@ -84,9 +161,219 @@ describe("ZetaChain Universal NFT", () => {
     expect(globalConfigAccount.collectionAuthority.toString()).to.equal(collectionAuthority.publicKey.toString());
     expect(globalConfigAccount.feeRecipient.toString()).to.equal(feeRecipient.publicKey.toString());
     expect(globalConfigAccount.crossChainFee.toString()).to.equal(crossChainFee.toString());
+    expect(globalConfigAccount.transferTimeout.toNumber()).to.equal(86_400);
     expect(globalConfigAccount.bump).to.equal(globalConfigBump);
   });
 
+  it("Updates the global configuration", async () => {
+    const newFee = new BN(20_000);
+
+    await program.methods
+      .updateConfig(null, null, null, newFee, null, null)
+      .accounts({
+        globalConfig: globalConfigPda,
+        authority: authority.publicKey,
+      })
+      .signers([authority])
+      .rpc();
+
+    const globalConfigAccount = await program.account.globalConfig.fetch(globalConfigPda);
+    expect(globalConfigAccount.crossChainFee.toString()).to.equal(newFee.toString());
+    expect(globalConfigAccount.zetachainGateway.toString()).to.equal(zetachainGateway.publicKey.toString());
+
+    // Only the authority may update the config
+    let failed = false;
+    try {
+      await program.methods
+        .updateConfig(null, null, null, new BN(0), null, null)
+        .accounts({
+          globalConfig: globalConfigPda,
+          authority: collectionAuthority.publicKey,
+        })
+        .signers([collectionAuthority])
+        .rpc();
+    } catch (err) {
+      failed = true;
+    }
+    expect(failed).to.be.true;
+
+    // Restore the original fee for the remaining tests
+    await program.methods
+      .updateConfig(null, null, null, new BN(10_000), null, null)
+      .accounts({
+        globalConfig: globalConfigPda,
+        authority: authority.publicKey,
+      })
+      .signers([authority])
+      .rpc();
+  });
+
+  it("Proposes and cancels an authority handover", async () => {
+    const candidate = Keypair.generate();
+
+    await program.methods
+      .proposeAuthority(candidate.publicKey)
+      .accounts({
+        globalConfig: globalConfigPda,
+        authority: authority.publicKey,
+      })
+      .signers([authority])
+      .rpc();
+
+    let globalConfigAccount = await program.account.globalConfig.fetch(globalConfigPda);
+    expect(globalConfigAccount.pendingAuthority.toString()).to.equal(candidate.publicKey.toString());
+
+    await program.methods
+      .cancelAuthorityProposal()
+      .accounts({
+        globalConfig: globalConfigPda,
+        authority: authority.publicKey,
+      })
+      .signers([authority])
+      .rpc();
+
+    globalConfigAccount = await program.account.globalConfig.fetch(globalConfigPda);
+    expect(globalConfigAccount.pendingAuthority).to.be.null;
+
+    // The cancelled candidate can no longer accept
+    let failed = false;
+    try {
+      await program.methods
+        .acceptAuthority()
+        .accounts({
+          globalConfig: globalConfigPda,
+          newAuthority: candidate.publicKey,
+        })
+        .signers([candidate])
+        .rpc();
+    } catch (err) {
+      failed = true;
+    }
+    expect(failed).to.be.true;
+    expect(globalConfigAccount.authority.toString()).to.equal(authority.publicKey.toString());
+  });
+
+  it("Registers a connected chain", async () => {
+    polygonChainConfigPda = chainConfigPda(POLYGON_CHAIN_ID);
+    const connectedContract = Buffer.from("1234567890abcdef1234567890abcdef12345678", "hex");
+
+    await program.methods
+      .registerChain(new BN(POLYGON_CHAIN_ID), "polygon", { evm: {} }, { abi: {} }, connectedContract, null)
+      .accounts({
+        globalConfig: globalConfigPda,
+        chainConfig: polygonChainConfigPda,
+        authority: authority.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([authority])
+      .rpc();
+
+    const chainConfigAccount = await program.account.chainConfig.fetch(polygonChainConfigPda);
+    expect(chainConfigAccount.chainId.toNumber()).to.equal(POLYGON_CHAIN_ID);
+    expect(chainConfigAccount.enabled).to.be.true;
+    expect(chainConfigAccount.name).to.equal("polygon");
+    expect(Buffer.from(chainConfigAccount.connectedContract).equals(connectedContract)).to.be.true;
+    expect(chainConfigAccount.crossChainFee.toString()).to.equal("10000");
+  });
+
+  it("Creates the collection", async () => {
+    collectionMint = Keypair.generate();
+    const collectionTokenAccount = await getAssociatedTokenAddress(
+      collectionMint.publicKey,
+      globalConfigPda,
+      true
+    );
+
+    await program.methods
+      .createCollection("ZetaChain Universal NFTs", "ZUNFT", "https://zetachain.com/metadata/collection.json")
+      .accounts({
+        globalConfig: globalConfigPda,
+        collectionMint: collectionMint.publicKey,
+        mintAuthority: mintAuthorityPda,
+        collectionTokenAccount: collectionTokenAccount,
+        collectionMetadata: metadataPda(collectionMint.publicKey),
+        collectionMasterEdition: masterEditionPda(collectionMint.publicKey),
+        authority: authority.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .signers([authority, collectionMint])
+      .rpc();
+
+    const globalConfigAccount = await program.account.globalConfig.fetch(globalConfigPda);
+    expect(globalConfigAccount.collectionMint.toString()).to.equal(collectionMint.publicKey.toString());
+
+    // The collection NFT is held by the program
+    const tokenAccountInfo = await provider.connection.getTokenAccountBalance(collectionTokenAccount);
+    expect(tokenAccountInfo.value.amount).to.equal("1");
+  });
+
+  it("Blocks minting while paused", async () => {
+    await program.methods
+      .setPause(true, null, null)
+      .accounts({
+        globalConfig: globalConfigPda,
+        signer: authority.publicKey,
+      })
+      .signers([authority])
+      .rpc();
+
+    const nftMint = Keypair.generate();
+    const [universalNftPda, universalNftBump] = PublicKey.findProgramAddressSync(
+      [Buffer.from("universal_nft"), nftMint.publicKey.toBuffer()],
+      program.programId
+    );
+    const tokenAccount = await getAssociatedTokenAddress(
+      nftMint.publicKey,
+      nftRecipient.publicKey
+    );
+
+    let failed = false;
+    try {
+      await program.methods
+        .mintNft(universalNftBump, "Paused", "PSD", "https://test.com/p.json", "ethereum", "0xabc", "99")
+        .accounts({
+          globalConfig: globalConfigPda,
+          universalNft: universalNftPda,
+          originIndex: originIndexPda("ethereum", "0xabc", "99"),
+          mint: nftMint.publicKey,
+          mintAuthority: mintAuthorityPda,
+          tokenAccount: tokenAccount,
+          metadata: metadataPda(nftMint.publicKey),
+          masterEdition: masterEditionPda(nftMint.publicKey),
+          collectionMint: collectionMint.publicKey,
+          collectionMetadata: metadataPda(collectionMint.publicKey),
+          collectionMasterEdition: masterEditionPda(collectionMint.publicKey),
+          payer: authority.publicKey,
+          recipient: nftRecipient.publicKey,
+          collectionAuthority: collectionAuthority.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([authority, nftMint, collectionAuthority])
+        .rpc();
+    } catch (err) {
+      failed = true;
+      expect(err.message).to.include("ProgramPaused");
+    }
+    expect(failed).to.be.true;
+
+    await program.methods
+      .setPause(false, null, null)
+      .accounts({
+        globalConfig: globalConfigPda,
+        signer: authority.publicKey,
+      })
+      .signers([authority])
+      .rpc();
+  });
+
   it("Mints a universal NFT", async () => {
     const nftMint = Keypair.generate();
     const name = "ZetaChain Universal NFT";
@@ -120,14 +407,23 @@ describe("ZetaChain Universal NFT", () => {
       .accounts({
         globalConfig: globalConfigPda,
         universalNft: universalNftPda,
+        originIndex: originIndexPda(originalChain, originalContract, originalTokenId),
         mint: nftMint.publicKey,
+        mintAuthority: mintAuthorityPda,
         tokenAccount: tokenAccount,
+        metadata: metadataPda(nftMint.publicKey),
+        masterEdition: masterEditionPda(nftMint.publicKey),
+        collectionMint: collectionMint.publicKey,
+        collectionMetadata: metadataPda(collectionMint.publicKey),
+        collectionMasterEdition: masterEditionPda(collectionMint.publicKey),
         payer: authority.publicKey,
         recipient: nftRecipient.publicKey,
         collectionAuthority: collectionAuthority.publicKey,
         tokenProgram: TOKEN_PROGRAM_ID,
         associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
       })
       .signers([authority, nftMint, collectionAuthority])
       .rpc();
@@ -148,13 +444,74 @@ describe("ZetaChain Universal NFT", () => {
     // Verify token account has 1 NFT
     const tokenAccountInfo = await provider.connection.getTokenAccountBalance(tokenAccount);
     expect(tokenAccountInfo.value.amount).to.equal("1");
+
+    // Supply is fixed at one: the master edition now holds the mint authority
+    const mintInfo = await getMint(provider.connection, nftMint.publicKey);
+    expect(mintInfo.supply.toString()).to.equal("1");
+    expect(mintInfo.mintAuthority.toString()).to.equal(masterEditionPda(nftMint.publicKey).toString());
+
+    // Verify Metaplex metadata and master edition were created
+    const metadataInfo = await provider.connection.getAccountInfo(metadataPda(nftMint.publicKey));
+    expect(metadataInfo.owner.toString()).to.equal(TOKEN_METADATA_PROGRAM_ID.toString());
+    const masterEditionInfo = await provider.connection.getAccountInfo(masterEditionPda(nftMint.publicKey));
+    expect(masterEditionInfo.owner.toString()).to.equal(TOKEN_METADATA_PROGRAM_ID.toString());
+
+    // The origin now points at this mint
+    const originIndexAccount = await program.account.originIndex.fetch(
+      originIndexPda(originalChain, originalContract, originalTokenId)
+    );
+    expect(originIndexAccount.mint.toString()).to.equal(nftMint.publicKey.toString());
+  });
+
+  it("Rejects a second mint of the same origin", async () => {
+    const nftMint = Keypair.generate();
+    const [universalNftPda, universalNftBump] = PublicKey.findProgramAddressSync(
+      [Buffer.from("universal_nft"), nftMint.publicKey.toBuffer()],
+      program.programId
+    );
+    const tokenAccount = await getAssociatedTokenAddress(
+      nftMint.publicKey,
+      nftRecipient.publicKey
+    );
+    const originalContract = "0x1234567890abcdef1234567890abcdef12345678";
+
+    let failed = false;
+    try {
+      await program.methods
+        .mintNft(universalNftBump, "Duplicate", "DUP", "https://test.com/d.json", "ethereum", originalContract, "1")
+        .accounts({
+          globalConfig: globalConfigPda,
+          universalNft: universalNftPda,
+          originIndex: originIndexPda("ethereum", originalContract, "1"),
+          mint: nftMint.publicKey,
+          mintAuthority: mintAuthorityPda,
+          tokenAccount: tokenAccount,
+          metadata: metadataPda(nftMint.publicKey),
+          masterEdition: masterEditionPda(nftMint.publicKey),
+          collectionMint: collectionMint.publicKey,
+          collectionMetadata: metadataPda(collectionMint.publicKey),
+          collectionMasterEdition: masterEditionPda(collectionMint.publicKey),
+          payer: authority.publicKey,
+          recipient: nftRecipient.publicKey,
+          collectionAuthority: collectionAuthority.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([authority, nftMint, collectionAuthority])
+        .rpc();
+    } catch (err) {
+      failed = true;
+    }
+    expect(failed).to.be.true;
   });
 
   it("Initiates a cross-chain transfer", async () => {
     // First, we need to create an NFT to transfer
     const nftMint = Keypair.generate();
-    const transferId = "transfer_123";
-    const destinationChain = "polygon";
+    const destinationChain = POLYGON_CHAIN_ID.toString();
     const destinationRecipient = "0xabcdefabcdefabcdefabcdefabcdefabcdefabcd";
     
     // Mint NFT first
@@ -182,41 +539,57 @@ describe("ZetaChain Universal NFT", () => {
       .accounts({
         globalConfig: globalConfigPda,
         universalNft: universalNftPda,
+        originIndex: originIndexPda("solana", "native", "1"),
         mint: nftMint.publicKey,
+        mintAuthority: mintAuthorityPda,
         tokenAccount: tokenAccount,
+        metadata: metadataPda(nftMint.publicKey),
+        masterEdition: masterEditionPda(nftMint.publicKey),
+        collectionMint: collectionMint.publicKey,
+        collectionMetadata: metadataPda(collectionMint.publicKey),
+        collectionMasterEdition: masterEditionPda(collectionMint.publicKey),
         payer: authority.publicKey,
         recipient: nftRecipient.publicKey,
         collectionAuthority: collectionAuthority.publicKey,
         tokenProgram: TOKEN_PROGRAM_ID,
         associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
       })
       .signers([authority, nftMint, collectionAuthority])
       .rpc();
 
     // Now initiate cross-chain transfer
-    const [crossChainTransferPda, crossChainTransferBump] = PublicKey.findProgramAddressSync(
-      [Buffer.from("cross_chain_transfer"), Buffer.from(transferId)],
-      program.programId
+    const transferId = await nextTransferId(nftMint.publicKey, nftRecipient.publicKey);
+    const [crossChainTransferPda, crossChainTransferBump] = transferPda(transferId);
+
+    const escrowTokenAccount = await getAssociatedTokenAddress(
+      nftMint.publicKey,
+      globalConfigPda,
+      true
     );
 
     const tx = await program.methods
       .initiateCrossChainTransfer(
-        transferId,
-        destinationChain,
-        destinationRecipient,
-        crossChainTransferBump
+        new BN(POLYGON_CHAIN_ID),
+        destinationRecipient
       )
       .accounts({
         globalConfig: globalConfigPda,
         universalNft: universalNftPda,
+        transferNonce: transferNoncePda(nftRecipient.publicKey),
         crossChainTransfer: crossChainTransferPda,
+        chainConfig: polygonChainConfigPda,
         nftMint: nftMint.publicKey,
         ownerTokenAccount: tokenAccount,
+        escrowTokenAccount,
         owner: nftRecipient.publicKey,
         payer: authority.publicKey,
         zetachainGateway: zetachainGateway.publicKey,
+        feeRecipient: feeRecipient.publicKey,
         tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
       })
       .signers([authority, nftRecipient])
@@ -224,9 +597,18 @@ describe("ZetaChain Universal NFT", () => {
 
     console.log("Initiate cross-chain transfer signature:", tx);
 
+    // Indexers read the typed event instead of parsing log strings
+    const [initiated] = (await emittedEvents(tx)).filter((e) => e.name === "transferInitiated");
+    expect(Buffer.from(initiated.data.transferId)).to.deep.equal(transferId);
+    expect(initiated.data.nftMint.toString()).to.equal(nftMint.publicKey.toString());
+    expect(initiated.data.owner.toString()).to.equal(nftRecipient.publicKey.toString());
+    expect(initiated.data.destinationChainId.toNumber()).to.equal(POLYGON_CHAIN_ID);
+    expect(initiated.data.destinationRecipient).to.equal(destinationRecipient);
+
     // Fetch and verify cross-chain transfer account
     const crossChainTransferAccount = await program.account.crossChainTransfer.fetch(crossChainTransferPda);
-    expect(crossChainTransferAccount.transferId).to.equal(transferId);
+    expect(Buffer.from(crossChainTransferAccount.transferId)).to.deep.equal(transferId);
+    initiatedTransferId = transferId;
     expect(crossChainTransferAccount.nftMint.toString()).to.equal(nftMint.publicKey.toString());
     expect(crossChainTransferAccount.sourceOwner.toString()).to.equal(nftRecipient.publicKey.toString());
     expect(crossChainTransferAccount.destinationChain).to.equal(destinationChain);
@@ -239,20 +621,23 @@ describe("ZetaChain Universal NFT", () => {
     expect(universalNftAccount.isLocked).to.be.true;
     expect(universalNftAccount.lockDestinationChain).to.equal(destinationChain);
     expect(universalNftAccount.lockRecipient).to.equal(destinationRecipient);
+
+    // The token itself sits in the program escrow
+    const ownerBalance = await provider.connection.getTokenAccountBalance(tokenAccount);
+    const escrowBalance = await provider.connection.getTokenAccountBalance(escrowTokenAccount);
+    expect(ownerBalance.value.amount).to.equal("0");
+    expect(escrowBalance.value.amount).to.equal("1");
   });
 
   it("Rejects unauthorized gateway caller (security)", async () => {
-    const transferId = "unauth_1";
-    const [crossChainTransferPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("cross_chain_transfer"), Buffer.from(transferId)],
-      program.programId
-    );
+    const transferId = Buffer.alloc(32, 1);
+    const [crossChainTransferPda] = transferPda(transferId);
 
     // Attempt to call confirm with wrong signer; expect failure
     let failed = false;
     try {
       await program.methods
-        .confirmCrossChainTransfer(transferId)
+        .confirmCrossChainTransfer(Array.from(transferId))
         .accounts({
           globalConfig: globalConfigPda,
           crossChainTransfer: crossChainTransferPda,
@@ -269,19 +654,16 @@ describe("ZetaChain Universal NFT", () => {
   });
 
   it("Confirms a cross-chain transfer", async () => {
-    const transferId = "transfer_confirm_123";
+    const transferId = Buffer.alloc(32, 2);
     
     // Create a transfer to confirm (simplified setup)
-    const [crossChainTransferPda, crossChainTransferBump] = PublicKey.findProgramAddressSync(
-      [Buffer.from("cross_chain_transfer"), Buffer.from(transferId)],
-      program.programId
-    );
+    const [crossChainTransferPda, crossChainTransferBump] = transferPda(transferId);
 
     // First, create an initiated transfer (simplified for test)
     // In real implementation, this would be created by initiate_cross_chain_transfer
     
     const tx = await program.methods
-      .confirmCrossChainTransfer(transferId)
+      .confirmCrossChainTransfer(Array.from(transferId))
       .accounts({
         globalConfig: globalConfigPda,
         crossChainTransfer: crossChainTransferPda,
@@ -301,6 +683,502 @@ describe("ZetaChain Universal NFT", () => {
     }
   });
 
+  it("Reverts an escrowed transfer, restoring the NFT and refunding the fee", async () => {
+    const nftMint = Keypair.generate();
+    const refundAmount = new BN(anchor.web3.LAMPORTS_PER_SOL / 100);
+
+    const [universalNftPda, universalNftBump] = PublicKey.findProgramAddressSync(
+      [Buffer.from("universal_nft"), nftMint.publicKey.toBuffer()],
+      program.programId
+    );
+    const tokenAccount = await getAssociatedTokenAddress(
+      nftMint.publicKey,
+      nftRecipient.publicKey
+    );
+    const escrowTokenAccount = await getAssociatedTokenAddress(
+      nftMint.publicKey,
+      globalConfigPda,
+      true
+    );
+    const transferId = await nextTransferId(nftMint.publicKey, nftRecipient.publicKey);
+    const [crossChainTransferPda] = transferPda(transferId);
+    const [connectedPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("connected")],
+      program.programId
+    );
+
+    await program.methods
+      .mintNft(
+        universalNftBump,
+        "Revert NFT",
+        "TNFT",
+        "https://test.com/revert.json",
+        "solana",
+        "native",
+        "revert-1"
+      )
+      .accounts({
+        globalConfig: globalConfigPda,
+        universalNft: universalNftPda,
+        originIndex: originIndexPda("solana", "native", "revert-1"),
+        mint: nftMint.publicKey,
+        mintAuthority: mintAuthorityPda,
+        tokenAccount: tokenAccount,
+        metadata: metadataPda(nftMint.publicKey),
+        masterEdition: masterEditionPda(nftMint.publicKey),
+        collectionMint: collectionMint.publicKey,
+        collectionMetadata: metadataPda(collectionMint.publicKey),
+        collectionMasterEdition: masterEditionPda(collectionMint.publicKey),
+        payer: authority.publicKey,
+        recipient: nftRecipient.publicKey,
+        collectionAuthority: collectionAuthority.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .signers([authority, nftMint, collectionAuthority])
+      .rpc();
+
+    await program.methods
+      .initiateCrossChainTransfer(
+        new BN(POLYGON_CHAIN_ID),
+        "0xabcdefabcdefabcdefabcdefabcdefabcdefabcd"
+      )
+      .accounts({
+        globalConfig: globalConfigPda,
+        universalNft: universalNftPda,
+        transferNonce: transferNoncePda(nftRecipient.publicKey),
+        crossChainTransfer: crossChainTransferPda,
+        chainConfig: polygonChainConfigPda,
+        nftMint: nftMint.publicKey,
+        ownerTokenAccount: tokenAccount,
+        escrowTokenAccount,
+        owner: nftRecipient.publicKey,
+        payer: authority.publicKey,
+        zetachainGateway: zetachainGateway.publicKey,
+        feeRecipient: feeRecipient.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([authority, nftRecipient])
+      .rpc();
+
+    // The gateway credits the reverted deposit to our connected PDA
+    await provider.sendAndConfirm(
+      new anchor.web3.Transaction().add(
+        SystemProgram.transfer({
+          fromPubkey: authority.publicKey,
+          toPubkey: connectedPda,
+          lamports: refundAmount.toNumber(),
+        })
+      ),
+      [authority]
+    );
+
+    // Stand in for the gateway: on_revert accepts calls whose top-level
+    // program is the configured gateway
+    await program.methods
+      .updateConfig(program.programId, null, null, null, null, null)
+      .accounts({ globalConfig: globalConfigPda, authority: authority.publicKey })
+      .signers([authority])
+      .rpc();
+
+    const revert = () =>
+      program.methods
+        .onRevert(refundAmount, nftRecipient.publicKey, Buffer.from(transferId))
+        .accounts({
+          globalConfig: globalConfigPda,
+          universalNft: universalNftPda,
+          crossChainTransfer: crossChainTransferPda,
+          mint: nftMint.publicKey,
+          escrowTokenAccount,
+          sourceOwner: nftRecipient.publicKey,
+          sourceOwnerTokenAccount: tokenAccount,
+          connectedPda,
+          refundRecipient: nftRecipient.publicKey,
+          gatewayPda: zetachainGateway.publicKey,
+          payer: authority.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          instructionSysvarAccount: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+        })
+        .signers([authority])
+        .rpc();
+
+    try {
+      const balanceBefore = await provider.connection.getBalance(nftRecipient.publicKey);
+      await revert();
+
+      const ownerBalance = await provider.connection.getTokenAccountBalance(tokenAccount);
+      const escrowBalance = await provider.connection.getTokenAccountBalance(escrowTokenAccount);
+      expect(ownerBalance.value.amount).to.equal("1");
+      expect(escrowBalance.value.amount).to.equal("0");
+
+      const balanceAfter = await provider.connection.getBalance(nftRecipient.publicKey);
+      expect(balanceAfter - balanceBefore).to.equal(refundAmount.toNumber());
+
+      const universalNftAccount = await program.account.universalNft.fetch(universalNftPda);
+      expect(universalNftAccount.isLocked).to.be.false;
+      expect(universalNftAccount.owner.toString()).to.equal(nftRecipient.publicKey.toString());
+
+      const transferAccount = await program.account.crossChainTransfer.fetch(crossChainTransferPda);
+      expect(transferAccount.status).to.deep.equal({ failed: {} });
+      revertedTransferId = transferId;
+
+      // A transfer can only be reverted once
+      let failed = false;
+      try {
+        await revert();
+      } catch (err) {
+        failed = true;
+      }
+      expect(failed).to.be.true;
+    } finally {
+      await program.methods
+        .updateConfig(zetachainGateway.publicKey, null, null, null, null, null)
+        .accounts({ globalConfig: globalConfigPda, authority: authority.publicKey })
+        .signers([authority])
+        .rpc();
+    }
+  });
+
+  it("Aborts a transfer and lets only the owner reclaim the NFT", async () => {
+    const nftMint = Keypair.generate();
+
+    const [universalNftPda, universalNftBump] = PublicKey.findProgramAddressSync(
+      [Buffer.from("universal_nft"), nftMint.publicKey.toBuffer()],
+      program.programId
+    );
+    const tokenAccount = await getAssociatedTokenAddress(
+      nftMint.publicKey,
+      nftRecipient.publicKey
+    );
+    const escrowTokenAccount = await getAssociatedTokenAddress(
+      nftMint.publicKey,
+      globalConfigPda,
+      true
+    );
+    const transferId = await nextTransferId(nftMint.publicKey, nftRecipient.publicKey);
+    const [crossChainTransferPda] = transferPda(transferId);
+    const [connectedPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("connected")],
+      program.programId
+    );
+
+    await program.methods
+      .mintNft(
+        universalNftBump,
+        "Abort NFT",
+        "TNFT",
+        "https://test.com/abort.json",
+        "solana",
+        "native",
+        "abort-1"
+      )
+      .accounts({
+        globalConfig: globalConfigPda,
+        universalNft: universalNftPda,
+        originIndex: originIndexPda("solana", "native", "abort-1"),
+        mint: nftMint.publicKey,
+        mintAuthority: mintAuthorityPda,
+        tokenAccount: tokenAccount,
+        metadata: metadataPda(nftMint.publicKey),
+        masterEdition: masterEditionPda(nftMint.publicKey),
+        collectionMint: collectionMint.publicKey,
+        collectionMetadata: metadataPda(collectionMint.publicKey),
+        collectionMasterEdition: masterEditionPda(collectionMint.publicKey),
+        payer: authority.publicKey,
+        recipient: nftRecipient.publicKey,
+        collectionAuthority: collectionAuthority.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .signers([authority, nftMint, collectionAuthority])
+      .rpc();
+
+    await program.methods
+      .initiateCrossChainTransfer(
+        new BN(POLYGON_CHAIN_ID),
+        "0xabcdefabcdefabcdefabcdefabcdefabcdefabcd"
+      )
+      .accounts({
+        globalConfig: globalConfigPda,
+        universalNft: universalNftPda,
+        transferNonce: transferNoncePda(nftRecipient.publicKey),
+        crossChainTransfer: crossChainTransferPda,
+        chainConfig: polygonChainConfigPda,
+        nftMint: nftMint.publicKey,
+        ownerTokenAccount: tokenAccount,
+        escrowTokenAccount,
+        owner: nftRecipient.publicKey,
+        payer: authority.publicKey,
+        zetachainGateway: zetachainGateway.publicKey,
+        feeRecipient: feeRecipient.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([authority, nftRecipient])
+      .rpc();
+
+    // Stand in for the gateway, as in the revert test
+    await program.methods
+      .updateConfig(program.programId, null, null, null, null, null)
+      .accounts({ globalConfig: globalConfigPda, authority: authority.publicKey })
+      .signers([authority])
+      .rpc();
+
+    try {
+      await program.methods
+        .onAbort(new BN(0), nftRecipient.publicKey, Buffer.from(transferId))
+        .accounts({
+          globalConfig: globalConfigPda,
+          crossChainTransfer: crossChainTransferPda,
+          connectedPda,
+          abortRecipient: nftRecipient.publicKey,
+          systemProgram: SystemProgram.programId,
+          instructionSysvarAccount: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+        })
+        .rpc();
+    } finally {
+      await program.methods
+        .updateConfig(zetachainGateway.publicKey, null, null, null, null, null)
+        .accounts({ globalConfig: globalConfigPda, authority: authority.publicKey })
+        .signers([authority])
+        .rpc();
+    }
+
+    const abortedTransfer = await program.account.crossChainTransfer.fetch(crossChainTransferPda);
+    expect(abortedTransfer.status).to.deep.equal({ aborted: {} });
+
+    const reclaim = (claimant: Keypair) =>
+      program.methods
+        .reclaimAbortedNft(Array.from(transferId))
+        .accounts({
+          globalConfig: globalConfigPda,
+          universalNft: universalNftPda,
+          crossChainTransfer: crossChainTransferPda,
+          mint: nftMint.publicKey,
+          escrowTokenAccount,
+          claimantTokenAccount: getAssociatedTokenAddressSync(nftMint.publicKey, claimant.publicKey),
+          claimant: claimant.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([claimant])
+        .rpc();
+
+    // Neither the owner nor the abort address
+    let failed = false;
+    try {
+      await reclaim(authority);
+    } catch (err) {
+      failed = true;
+    }
+    expect(failed).to.be.true;
+
+    await reclaim(nftRecipient);
+
+    const ownerBalance = await provider.connection.getTokenAccountBalance(tokenAccount);
+    expect(ownerBalance.value.amount).to.equal("1");
+    const universalNftAccount = await program.account.universalNft.fetch(universalNftPda);
+    expect(universalNftAccount.isLocked).to.be.false;
+
+    // An aborted transfer is reclaimed once
+    failed = false;
+    try {
+      await reclaim(nftRecipient);
+    } catch (err) {
+      failed = true;
+    }
+    expect(failed).to.be.true;
+  });
+
+  it("Cancels an expired transfer the gateway can no longer confirm", async () => {
+    const nftMint = Keypair.generate();
+
+    const [universalNftPda, universalNftBump] = PublicKey.findProgramAddressSync(
+      [Buffer.from("universal_nft"), nftMint.publicKey.toBuffer()],
+      program.programId
+    );
+    const tokenAccount = await getAssociatedTokenAddress(
+      nftMint.publicKey,
+      nftRecipient.publicKey
+    );
+    const escrowTokenAccount = await getAssociatedTokenAddress(
+      nftMint.publicKey,
+      globalConfigPda,
+      true
+    );
+    const transferId = await nextTransferId(nftMint.publicKey, nftRecipient.publicKey);
+    const [crossChainTransferPda] = transferPda(transferId);
+
+    await program.methods
+      .mintNft(
+        universalNftBump,
+        "Cancel NFT",
+        "TNFT",
+        "https://test.com/cancel.json",
+        "solana",
+        "native",
+        "cancel-1"
+      )
+      .accounts({
+        globalConfig: globalConfigPda,
+        universalNft: universalNftPda,
+        originIndex: originIndexPda("solana", "native", "cancel-1"),
+        mint: nftMint.publicKey,
+        mintAuthority: mintAuthorityPda,
+        tokenAccount: tokenAccount,
+        metadata: metadataPda(nftMint.publicKey),
+        masterEdition: masterEditionPda(nftMint.publicKey),
+        collectionMint: collectionMint.publicKey,
+        collectionMetadata: metadataPda(collectionMint.publicKey),
+        collectionMasterEdition: masterEditionPda(collectionMint.publicKey),
+        payer: authority.publicKey,
+        recipient: nftRecipient.publicKey,
+        collectionAuthority: collectionAuthority.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .signers([authority, nftMint, collectionAuthority])
+      .rpc();
+
+    await program.methods
+      .initiateCrossChainTransfer(
+        new BN(POLYGON_CHAIN_ID),
+        "0xabcdefabcdefabcdefabcdefabcdefabcdefabcd"
+      )
+      .accounts({
+        globalConfig: globalConfigPda,
+        universalNft: universalNftPda,
+        transferNonce: transferNoncePda(nftRecipient.publicKey),
+        crossChainTransfer: crossChainTransferPda,
+        chainConfig: polygonChainConfigPda,
+        nftMint: nftMint.publicKey,
+        ownerTokenAccount: tokenAccount,
+        escrowTokenAccount,
+        owner: nftRecipient.publicKey,
+        payer: authority.publicKey,
+        zetachainGateway: zetachainGateway.publicKey,
+        feeRecipient: feeRecipient.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([authority, nftRecipient])
+      .rpc();
+
+    const cancel = () =>
+      program.methods
+        .cancelCrossChainTransfer(Array.from(transferId))
+        .accounts({
+          globalConfig: globalConfigPda,
+          universalNft: universalNftPda,
+          crossChainTransfer: crossChainTransferPda,
+          nftMint: nftMint.publicKey,
+          escrowTokenAccount,
+          ownerTokenAccount: tokenAccount,
+          owner: nftRecipient.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([nftRecipient])
+        .rpc();
+
+    const setTransferTimeout = (seconds: number) =>
+      program.methods
+        .updateConfig(null, null, null, null, null, new BN(seconds))
+        .accounts({ globalConfig: globalConfigPda, authority: authority.publicKey })
+        .signers([authority])
+        .rpc();
+
+    await setTransferTimeout(1);
+    try {
+      await new Promise(resolve => setTimeout(resolve, 2000));
+
+      // Expired transfers can no longer be confirmed by the gateway
+      let failed = false;
+      try {
+        await program.methods
+          .confirmCrossChainTransfer(Array.from(transferId))
+          .accounts({
+            globalConfig: globalConfigPda,
+            crossChainTransfer: crossChainTransferPda,
+            zetachainGateway: zetachainGateway.publicKey,
+          })
+          .signers([zetachainGateway])
+          .rpc();
+      } catch (err) {
+        failed = true;
+      }
+      expect(failed).to.be.true;
+
+      await cancel();
+    } finally {
+      await setTransferTimeout(86_400);
+    }
+
+    const ownerBalance = await provider.connection.getTokenAccountBalance(tokenAccount);
+    expect(ownerBalance.value.amount).to.equal("1");
+    const universalNftAccount = await program.account.universalNft.fetch(universalNftPda);
+    expect(universalNftAccount.isLocked).to.be.false;
+    const transferAccount = await program.account.crossChainTransfer.fetch(crossChainTransferPda);
+    expect(transferAccount.status).to.deep.equal({ cancelled: {} });
+  });
+
+  it("Keeps transfer records until they are finished and retained", async () => {
+    const closeTransfer = async (transferId: Buffer, payer: Keypair) => {
+      const { nftMint } = await program.account.crossChainTransfer.fetch(transferPda(transferId)[0]);
+      return program.methods
+        .closeTransfer(Array.from(transferId))
+        .accounts({
+          crossChainTransfer: transferPda(transferId)[0],
+          universalNft: PublicKey.findProgramAddressSync(
+            [Buffer.from("universal_nft"), nftMint.toBuffer()],
+            program.programId
+          )[0],
+          payer: payer.publicKey,
+        })
+        .signers([payer])
+        .rpc();
+    };
+
+    const expectError = async (promise: Promise<string>, code: string) => {
+      let failed = false;
+      try {
+        await promise;
+      } catch (err) {
+        failed = true;
+        expect(err.message).to.include(code);
+      }
+      expect(failed).to.be.true;
+    };
+
+    // Still in flight
+    await expectError(closeTransfer(initiatedTransferId, authority), "InvalidTransferStatus");
+    // Finished, but only the rent payer may close it, and only after retention
+    await expectError(closeTransfer(revertedTransferId, nftRecipient), "Unauthorized");
+    await expectError(closeTransfer(revertedTransferId, authority), "RetentionPeriodActive");
+
+    const transferAccount = await program.account.crossChainTransfer.fetch(
+      transferPda(revertedTransferId)[0]
+    );
+    expect(transferAccount.payer.toString()).to.equal(authority.publicKey.toString());
+  });
+
   it("Displays program state summary", async () => {
     console.log("\n=== ZetaChain Universal NFT Program Summary ===");
     console.log("Program ID:", program.programId.toString());
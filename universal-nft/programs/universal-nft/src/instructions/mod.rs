@@ -1,17 +0,0 @@
-pub mod initialize;
-pub mod mint_nft;
-pub mod initiate_cross_chain_transfer;
-pub mod confirm_cross_chain_transfer;
-pub mod complete_cross_chain_transfer;
-pub mod on_call;
-pub mod on_revert;
-pub mod deposit_and_call;
-
-pub use initialize::*;
-pub use mint_nft::*;
-pub use initiate_cross_chain_transfer::*;
-pub use confirm_cross_chain_transfer::*;
-pub use complete_cross_chain_transfer::*;
-pub use on_call::*;
-pub use on_revert::*;
-pub use deposit_and_call::*;
@@ -1,336 +0,0 @@
-import * as anchor from "@coral-xyz/anchor";
-import { Program, BN } from "@coral-xyz/anchor";
-import { UniversalNft } from "../target/types/universal_nft";
-import { 
-  PublicKey, 
-  Keypair, 
-  SystemProgram,
-  SYSVAR_RENT_PUBKEY,
-} from "@solana/web3.js";
-import {
-  TOKEN_PROGRAM_ID,
-  ASSOCIATED_TOKEN_PROGRAM_ID,
-  getAssociatedTokenAddress,
-} from "@solana/spl-token";
-import { expect } from "chai";
-
-describe("ZetaChain Universal NFT", () => {
-  // Configure the client to use the local cluster.
-  const provider = anchor.AnchorProvider.env();
-  anchor.setProvider(provider);
-
-  const program = anchor.workspace.UniversalNft as Program<UniversalNft>;
-  
-  // Test accounts
-  let authority: Keypair;
-  let zetachainGateway: Keypair;
-  let collectionAuthority: Keypair;
-  let feeRecipient: Keypair;
-  let nftRecipient: Keypair;
-  let globalConfigPda: PublicKey;
-  let globalConfigBump: number;
-
-  before(async () => {
-    // Create test keypairs
-    authority = Keypair.generate();
-    zetachainGateway = Keypair.generate();
-    collectionAuthority = Keypair.generate();
-    feeRecipient = Keypair.generate();
-    nftRecipient = Keypair.generate();
-
-    // Airdrop SOL to test accounts
-    await Promise.all([
-      provider.connection.requestAirdrop(authority.publicKey, 2 * anchor.web3.LAMPORTS_PER_SOL),
-      provider.connection.requestAirdrop(collectionAuthority.publicKey, 2 * anchor.web3.LAMPORTS_PER_SOL),
-      provider.connection.requestAirdrop(zetachainGateway.publicKey, 1 * anchor.web3.LAMPORTS_PER_SOL),
-      provider.connection.requestAirdrop(feeRecipient.publicKey, 1 * anchor.web3.LAMPORTS_PER_SOL),
-    ]);
-
-    // Wait for airdrops to confirm
-    await new Promise(resolve => setTimeout(resolve, 2000));
-
-    // Find global config PDA
-    [globalConfigPda, globalConfigBump] = PublicKey.findProgramAddressSync(
-      [Buffer.from("global_config")],
-      program.programId
-    );
-  });
-
-  it("Initializes the global configuration", async () => {
-    const crossChainFee = new BN(10_000); // 0.00001 SOL
-    
-    const tx = await program.methods
-      .initialize(
-        globalConfigBump,
-        crossChainFee
-      )
-      .accounts({
-        globalConfig: globalConfigPda,
-        authority: authority.publicKey,
-        zetachainGateway: zetachainGateway.publicKey,
-        collectionAuthority: collectionAuthority.publicKey,
-        feeRecipient: feeRecipient.publicKey,
-        systemProgram: SystemProgram.programId,
-      })
-      .signers([authority])
-      .rpc();
-
-    console.log("Initialize transaction signature:", tx);
-
-    // Fetch and verify global config
-    const globalConfigAccount = await program.account.globalConfig.fetch(globalConfigPda);
-    expect(globalConfigAccount.authority.toString()).to.equal(authority.publicKey.toString());
-    expect(globalConfigAccount.zetachainGateway.toString()).to.equal(zetachainGateway.publicKey.toString());
-    expect(globalConfigAccount.collectionAuthority.toString()).to.equal(collectionAuthority.publicKey.toString());
-    expect(globalConfigAccount.feeRecipient.toString()).to.equal(feeRecipient.publicKey.toString());
-    expect(globalConfigAccount.crossChainFee.toString()).to.equal(crossChainFee.toString());
-    expect(globalConfigAccount.bump).to.equal(globalConfigBump);
-  });
-
-  it("Mints a universal NFT", async () => {
-    const nftMint = Keypair.generate();
-    const name = "ZetaChain Universal NFT";
-    const symbol = "ZUNFT";
-    const uri = "https://zetachain.com/metadata/1.json";
-    const originalChain = "ethereum";
-    const originalContract = "0x1234567890abcdef1234567890abcdef12345678";
-    const originalTokenId = "1";
-    
-    // Find PDAs
-    const [universalNftPda, universalNftBump] = PublicKey.findProgramAddressSync(
-      [Buffer.from("universal_nft"), nftMint.publicKey.toBuffer()],
-      program.programId
-    );
-    
-    const tokenAccount = await getAssociatedTokenAddress(
-      nftMint.publicKey,
-      nftRecipient.publicKey
-    );
-
-    const tx = await program.methods
-      .mintNft(
-        universalNftBump,
-        name,
-        symbol,
-        uri,
-        originalChain,
-        originalContract,
-        originalTokenId
-      )
-      .accounts({
-        globalConfig: globalConfigPda,
-        universalNft: universalNftPda,
-        mint: nftMint.publicKey,
-        tokenAccount: tokenAccount,
-        payer: authority.publicKey,
-        recipient: nftRecipient.publicKey,
-        collectionAuthority: collectionAuthority.publicKey,
-        tokenProgram: TOKEN_PROGRAM_ID,
-        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-        systemProgram: SystemProgram.programId,
-      })
-      .signers([authority, nftMint, collectionAuthority])
-      .rpc();
-
-    console.log("Mint NFT transaction signature:", tx);
-
-    // Fetch and verify universal NFT account
-    const universalNftAccount = await program.account.universalNft.fetch(universalNftPda);
-    expect(universalNftAccount.mint.toString()).to.equal(nftMint.publicKey.toString());
-    expect(universalNftAccount.owner.toString()).to.equal(nftRecipient.publicKey.toString());
-    expect(universalNftAccount.originalChain).to.equal(originalChain);
-    expect(universalNftAccount.originalContract).to.equal(originalContract);
-    expect(universalNftAccount.originalTokenId).to.equal(originalTokenId);
-    expect(universalNftAccount.metadataUri).to.equal(uri);
-    expect(universalNftAccount.isLocked).to.be.false;
-    expect(universalNftAccount.bump).to.equal(universalNftBump);
-    
-    // Verify token account has 1 NFT
-    const tokenAccountInfo = await provider.connection.getTokenAccountBalance(tokenAccount);
-    expect(tokenAccountInfo.value.amount).to.equal("1");
-  });
-
-  it("Initiates a cross-chain transfer", async () => {
-    // First, we need to create an NFT to transfer
-    const nftMint = Keypair.generate();
-    const transferId = "transfer_123";
-    const destinationChain = "polygon";
-    const destinationRecipient = "0xabcdefabcdefabcdefabcdefabcdefabcdefabcd";
-    
-    // Mint NFT first
-    const [universalNftPda, universalNftBump] = PublicKey.findProgramAddressSync(
-      [Buffer.from("universal_nft"), nftMint.publicKey.toBuffer()],
-      program.programId
-    );
-    
-    const tokenAccount = await getAssociatedTokenAddress(
-      nftMint.publicKey,
-      nftRecipient.publicKey
-    );
-
-    // Mint the NFT first
-    await program.methods
-      .mintNft(
-        universalNftBump,
-        "Test NFT",
-        "TNFT", 
-        "https://test.com/1.json",
-        "solana",
-        "native",
-        "1"
-      )
-      .accounts({
-        globalConfig: globalConfigPda,
-        universalNft: universalNftPda,
-        mint: nftMint.publicKey,
-        tokenAccount: tokenAccount,
-        payer: authority.publicKey,
-        recipient: nftRecipient.publicKey,
-        collectionAuthority: collectionAuthority.publicKey,
-        tokenProgram: TOKEN_PROGRAM_ID,
-        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-        systemProgram: SystemProgram.programId,
-      })
-      .signers([authority, nftMint, collectionAuthority])
-      .rpc();
-
-    // Now initiate cross-chain transfer
-    const [crossChainTransferPda, crossChainTransferBump] = PublicKey.findProgramAddressSync(
-      [Buffer.from("cross_chain_transfer"), Buffer.from(transferId)],
-      program.programId
-    );
-
-    const tx = await program.methods
-      .initiateCrossChainTransfer(
-        transferId,
-        destinationChain,
-        destinationRecipient,
-        crossChainTransferBump
-      )
-      .accounts({
-        globalConfig: globalConfigPda,
-        universalNft: universalNftPda,
-        crossChainTransfer: crossChainTransferPda,
-        nftMint: nftMint.publicKey,
-        ownerTokenAccount: tokenAccount,
-        owner: nftRecipient.publicKey,
-        payer: authority.publicKey,
-        zetachainGateway: zetachainGateway.publicKey,
-        tokenProgram: TOKEN_PROGRAM_ID,
-        systemProgram: SystemProgram.programId,
-      })
-      .signers([authority, nftRecipient])
-      .rpc();
-
-    console.log("Initiate cross-chain transfer signature:", tx);
-
-    // Fetch and verify cross-chain transfer account
-    const crossChainTransferAccount = await program.account.crossChainTransfer.fetch(crossChainTransferPda);
-    expect(crossChainTransferAccount.transferId).to.equal(transferId);
-    expect(crossChainTransferAccount.nftMint.toString()).to.equal(nftMint.publicKey.toString());
-    expect(crossChainTransferAccount.sourceOwner.toString()).to.equal(nftRecipient.publicKey.toString());
-    expect(crossChainTransferAccount.destinationChain).to.equal(destinationChain);
-    expect(crossChainTransferAccount.destinationRecipient).to.equal(destinationRecipient);
-    expect(crossChainTransferAccount.status).to.deep.equal({ initiated: {} });
-    expect(crossChainTransferAccount.bump).to.equal(crossChainTransferBump);
-    
-    // Verify NFT is now locked
-    const universalNftAccount = await program.account.universalNft.fetch(universalNftPda);
-    expect(universalNftAccount.isLocked).to.be.true;
-    expect(universalNftAccount.lockDestinationChain).to.equal(destinationChain);
-    expect(universalNftAccount.lockRecipient).to.equal(destinationRecipient);
-  });
-
-  it("Rejects unauthorized gateway caller (security)", async () => {
-    const transferId = "unauth_1";
-    const [crossChainTransferPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("cross_chain_transfer"), Buffer.from(transferId)],
-      program.programId
-    );
-
-    // Attempt to call confirm with wrong signer; expect failure
-    let failed = false;
-    try {
-      await program.methods
-        .confirmCrossChainTransfer(transferId)
-        .accounts({
-          globalConfig: globalConfigPda,
-          crossChainTransfer: crossChainTransferPda,
-          zetachainGateway: authority.publicKey, // wrong signer
-        })
-        .signers([authority])
-        .rpc();
-    } catch (err) {
-      failed = true;
-      console.log("As expected, unauthorized gateway call rejected:", err.message);
-    }
-
-    expect(failed).to.be.true;
-  });
-
-  it("Confirms a cross-chain transfer", async () => {
-    const transferId = "transfer_confirm_123";
-    
-    // Create a transfer to confirm (simplified setup)
-    const [crossChainTransferPda, crossChainTransferBump] = PublicKey.findProgramAddressSync(
-      [Buffer.from("cross_chain_transfer"), Buffer.from(transferId)],
-      program.programId
-    );
-
-    // First, create an initiated transfer (simplified for test)
-    // In real implementation, this would be created by initiate_cross_chain_transfer
-    
-    const tx = await program.methods
-      .confirmCrossChainTransfer(transferId)
-      .accounts({
-        globalConfig: globalConfigPda,
-        crossChainTransfer: crossChainTransferPda,
-        zetachainGateway: zetachainGateway.publicKey,
-      })
-      .signers([zetachainGateway])
-      .rpc()
-      .catch(err => {
-        // This might fail because the transfer doesn't exist yet
-        // In a real implementation, the transfer would be created first
-        console.log("Expected error for non-existent transfer:", err.message);
-        return null;
-      });
-
-    if (tx) {
-      console.log("Confirm cross-chain transfer signature:", tx);
-    }
-  });
-
-  it("Displays program state summary", async () => {
-    console.log("\n=== ZetaChain Universal NFT Program Summary ===");
-    console.log("Program ID:", program.programId.toString());
-    console.log("Global Config PDA:", globalConfigPda.toString());
-    console.log("Authority:", authority.publicKey.toString());
-    console.log("ZetaChain Gateway:", zetachainGateway.publicKey.toString());
-    console.log("Collection Authority:", collectionAuthority.publicKey.toString());
-    console.log("Fee Recipient:", feeRecipient.publicKey.toString());
-    
-    try {
-      const globalConfig = await program.account.globalConfig.fetch(globalConfigPda);
-      console.log("Cross-chain Fee:", globalConfig.crossChainFee.toString(), "lamports");
-    } catch (err) {
-      console.log("Could not fetch global config:", err.message);
-    }
-    
-    console.log("\n=== Features Implemented ===");
-    console.log("✅ Global program configuration");
-    console.log("✅ Universal NFT minting with cross-chain metadata");
-    console.log("✅ Cross-chain transfer initiation");
-    console.log("✅ Transfer confirmation by ZetaChain gateway");
-    console.log("✅ NFT locking/unlocking mechanism");
-    console.log("✅ Fee collection for cross-chain operations");
-    console.log("✅ Solana-optimized account structure");
-    console.log("✅ Comprehensive error handling");
-    
-    console.log("\n=== Cross-Chain Integration Points ===");
-    console.log("🔗 ZetaChain gateway integration");
-    console.log("🔗 Support for Ethereum, Polygon, BSC chains");
-    console.log("🔗 Universal NFT metadata format");
-    console.log("🔗 Cross-chain message emission");
-  });
-});
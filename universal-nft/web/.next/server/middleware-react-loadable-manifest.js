@@ -1 +0,0 @@
-self.__REACT_LOADABLE_MANIFEST="{\"node_modules/@particle-network/auth/es/index.js -> draggabilly\":{\"id\":\"node_modules/@particle-network/auth/es/index.js -> draggabilly\",\"files\":[\"static/chunks/node_modules_draggabilly_draggabilly_js.js\"]},\"node_modules/@reown/appkit-controllers/node_modules/@walletconnect/utils/node_modules/viem/_esm/actions/public/call.js -> ../../utils/ccip.js\":{\"id\":\"node_modules/@reown/appkit-controllers/node_modules/@walletconnect/utils/node_modules/viem/_esm/actions/public/call.js -> ../../utils/ccip.js\",\"files\":[]},\"node_modules/@reown/appkit-controllers/node_modules/@walletconnect/utils/node_modules/viem/_esm/utils/rpc/webSocket.js -> isows\":{\"id\":\"node_modules/@reown/appkit-controllers/node_modules/@walletconnect/utils/node_modules/viem/_esm/utils/rpc/webSocket.js -> isows\",\"files\":[\"static/chunks/node_modules_reown_appkit-controllers_node_modules_isows__esm_native_js.js\"]},\"node_modules/@reown/appkit-controllers/node_modules/@walletconnect/utils/node_modules/viem/_esm/utils/signature/recoverPublicKey.js -> @noble/curves/secp256k1\":{\"id\":\"node_modules/@reown/appkit-controllers/node_modules/@walletconnect/utils/node_modules/viem/_esm/utils/signature/recoverPublicKey.js -> @noble/curves/secp256k1\",\"files\":[]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/add.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/add.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_add_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/all-wallets.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/all-wallets.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_all-wallets_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/app-store.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/app-store.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_app-store_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/apple.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/apple.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_apple_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/arrow-bottom-circle.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/arrow-bottom-circle.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_arrow-bottom-circle_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/arrow-bottom.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/arrow-bottom.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_arrow-bottom_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/arrow-left.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/arrow-left.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_arrow-left_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/arrow-right.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/arrow-right.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_arrow-right_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/arrow-top.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/arrow-top.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_arrow-top_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/bank.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/bank.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_bank_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/browser.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/browser.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_browser_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/card.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/card.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_card_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/checkmark-bold.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/checkmark-bold.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_checkmark-bold_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/checkmark.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/checkmark.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_checkmark_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/chevron-bottom.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/chevron-bottom.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_chevron-bottom_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/chevron-left.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/chevron-left.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_chevron-left_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/chevron-right.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/chevron-right.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_chevron-right_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/chevron-top.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/chevron-top.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_chevron-top_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/chrome-store.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/chrome-store.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_chrome-store_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/clock.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/clock.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_clock_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/close.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/close.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_close_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/coinPlaceholder.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/coinPlaceholder.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_coinPlaceholder_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/compass.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/compass.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_compass_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/copy.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/copy.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_copy_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/cursor-transparent.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/cursor-transparent.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_cursor-transparent_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/cursor.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/cursor.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_cursor_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/desktop.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/desktop.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_desktop_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/disconnect.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/disconnect.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_disconnect_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/discord.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/discord.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_discord_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/etherscan.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/etherscan.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_etherscan_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/exclamation-triangle.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/exclamation-triangle.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_exclamation-triangle_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/extension.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/extension.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_extension_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/external-link.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/external-link.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_external-link_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/facebook.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/facebook.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_facebook_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/farcaster.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/farcaster.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_farcaster_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/filters.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/filters.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_filters_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/github.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/github.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_github_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/google.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/google.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_google_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/help-circle.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/help-circle.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_help-circle_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/id.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/id.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_id_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/image.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/image.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_image_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/info-circle.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/info-circle.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_info-circle_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/info.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/info.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_info_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/lightbulb.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/lightbulb.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_lightbulb_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/mail.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/mail.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_mail_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/mobile.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/mobile.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_mobile_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/more.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/more.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_more_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/network-placeholder.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/network-placeholder.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_network-placeholder_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/nftPlaceholder.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/nftPlaceholder.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_nftPlaceholder_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/off.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/off.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_off_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/play-store.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/play-store.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_play-store_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/plus.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/plus.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_plus_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/qr-code.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/qr-code.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_qr-code_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/recycle-horizontal.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/recycle-horizontal.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_recycle-horizontal_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/refresh.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/refresh.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_refresh_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/reown-logo.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/reown-logo.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_reown-logo_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/search.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/search.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_search_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/send.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/send.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_send_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/swapHorizontal.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/swapHorizontal.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_swapHorizontal_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/swapHorizontalBold.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/swapHorizontalBold.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_swapHorizontalBold_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/swapHorizontalMedium.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/swapHorizontalMedium.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_swapHorizontalMedium_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/swapHorizontalRoundedBold.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/swapHorizontalRoundedBold.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_swapHorizontalRoundedBold_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/swapVertical.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/swapVertical.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_swapVertical_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/telegram.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/telegram.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_telegram_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/three-dots.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/three-dots.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_three-dots_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/twitch.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/twitch.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_twitch_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/twitterIcon.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/twitterIcon.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_twitterIcon_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/verify-filled.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/verify-filled.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_verify-filled_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/verify.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/verify.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_verify_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/wallet-placeholder.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/wallet-placeholder.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_wallet-placeholder_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/wallet.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/wallet.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_wallet_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/walletconnect.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/walletconnect.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_walletconnect_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/warning-circle.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/warning-circle.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_warning-circle_js.js\"]},\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/x.js\":{\"id\":\"node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js -> ../../assets/svg/x.js\",\"files\":[\"static/chunks/node_modules_reown_appkit-ui_dist_esm_src_assets_svg_x_js.js\"]},\"node_modules/@reown/appkit/dist/esm/src/client/appkit-core.js -> @reown/appkit-scaffold-ui/basic\":{\"id\":\"node_modules/@reown/appkit/dist/esm/src/client/appkit-core.js -> @reown/appkit-scaffold-ui/basic\",\"files\":[\"static/chunks/node_modules_reown_appkit-scaffold-ui_dist_esm_exports_basic_js.js\"]},\"node_modules/@reown/appkit/dist/esm/src/client/appkit-core.js -> @reown/appkit-scaffold-ui/w3m-modal\":{\"id\":\"node_modules/@reown/appkit/dist/esm/src/client/appkit-core.js -> @reown/appkit-scaffold-ui/w3m-modal\",\"files\":[\"static/chunks/node_modules_reown_appkit-scaffold-ui_dist_esm_exports_w3m-modal_js.js\"]},\"node_modules/@reown/appkit/node_modules/@walletconnect/utils/node_modules/viem/_esm/actions/public/call.js -> ../../utils/ccip.js\":{\"id\":\"node_modules/@reown/appkit/node_modules/@walletconnect/utils/node_modules/viem/_esm/actions/public/call.js -> ../../utils/ccip.js\",\"files\":[]},\"node_modules/@reown/appkit/node_modules/@walletconnect/utils/node_modules/viem/_esm/utils/rpc/webSocket.js -> isows\":{\"id\":\"node_modules/@reown/appkit/node_modules/@walletconnect/utils/node_modules/viem/_esm/utils/rpc/webSocket.js -> isows\",\"files\":[\"static/chunks/node_modules_reown_appkit_node_modules_isows__esm_native_js.js\"]},\"node_modules/@reown/appkit/node_modules/@walletconnect/utils/node_modules/viem/_esm/utils/signature/recoverPublicKey.js -> @noble/curves/secp256k1\":{\"id\":\"node_modules/@reown/appkit/node_modules/@walletconnect/utils/node_modules/viem/_esm/utils/signature/recoverPublicKey.js -> @noble/curves/secp256k1\",\"files\":[]},\"node_modules/@solana/wallet-adapter-fractal/lib/esm/adapter.js -> @fractalwagmi/solana-wallet-adapter\":{\"id\":\"node_modules/@solana/wallet-adapter-fractal/lib/esm/adapter.js -> @fractalwagmi/solana-wallet-adapter\",\"files\":[\"static/chunks/node_modules_fractalwagmi_solana-wallet-adapter_dist_esm_index_js.js\"]},\"node_modules/@solana/wallet-adapter-keystone/lib/esm/adapter.js -> @keystonehq/sol-keyring\":{\"id\":\"node_modules/@solana/wallet-adapter-keystone/lib/esm/adapter.js -> @keystonehq/sol-keyring\",\"files\":[\"static/chunks/node_modules_keystonehq_sol-keyring_dist_index_js.js\"]},\"node_modules/@solana/wallet-adapter-ledger/lib/esm/adapter.js -> @ledgerhq/hw-transport-webhid\":{\"id\":\"node_modules/@solana/wallet-adapter-ledger/lib/esm/adapter.js -> @ledgerhq/hw-transport-webhid\",\"files\":[\"static/chunks/node_modules_ledgerhq_hw-transport-webhid_lib-es_TransportWebHID_js.js\"]},\"node_modules/@solana/wallet-adapter-particle/lib/esm/adapter.js -> @particle-network/solana-wallet\":{\"id\":\"node_modules/@solana/wallet-adapter-particle/lib/esm/adapter.js -> @particle-network/solana-wallet\",\"files\":[\"static/chunks/node_modules_particle-network_solana-wallet_es_index_js.js\"]},\"node_modules/@solana/wallet-adapter-salmon/lib/esm/adapter.js -> salmon-adapter-sdk\":{\"id\":\"node_modules/@solana/wallet-adapter-salmon/lib/esm/adapter.js -> salmon-adapter-sdk\",\"files\":[\"static/chunks/node_modules_salmon-adapter-sdk_lib_esm_index_js.js\"]},\"node_modules/@solana/wallet-adapter-solflare/lib/esm/adapter.js -> @solflare-wallet/sdk\":{\"id\":\"node_modules/@solana/wallet-adapter-solflare/lib/esm/adapter.js -> @solflare-wallet/sdk\",\"files\":[\"static/chunks/node_modules_solflare-wallet_sdk_lib_esm_index_js.js\"]},\"node_modules/@solana/wallet-adapter-solflare/lib/esm/metamask/wallet.js -> @solflare-wallet/metamask-sdk\":{\"id\":\"node_modules/@solana/wallet-adapter-solflare/lib/esm/metamask/wallet.js -> @solflare-wallet/metamask-sdk\",\"files\":[\"static/chunks/node_modules_solflare-wallet_metamask-sdk_lib_esm_index_js.js\"]},\"node_modules/@solana/wallet-adapter-torus/lib/esm/adapter.js -> @toruslabs/solana-embed\":{\"id\":\"node_modules/@solana/wallet-adapter-torus/lib/esm/adapter.js -> @toruslabs/solana-embed\",\"files\":[\"static/chunks/node_modules_toruslabs_solana-embed_dist_solanaEmbed_esm_js.js\"]},\"node_modules/@solana/wallet-adapter-trezor/lib/esm/adapter.js -> @trezor/connect-web\":{\"id\":\"node_modules/@solana/wallet-adapter-trezor/lib/esm/adapter.js -> @trezor/connect-web\",\"files\":[\"static/chunks/node_modules_trezor_connect-web_lib_index_js.js\"]},\"node_modules/@walletconnect/utils/node_modules/viem/_esm/actions/public/call.js -> ../../utils/ccip.js\":{\"id\":\"node_modules/@walletconnect/utils/node_modules/viem/_esm/actions/public/call.js -> ../../utils/ccip.js\",\"files\":[]},\"node_modules/@walletconnect/utils/node_modules/viem/_esm/utils/rpc/webSocket.js -> isows\":{\"id\":\"node_modules/@walletconnect/utils/node_modules/viem/_esm/utils/rpc/webSocket.js -> isows\",\"files\":[\"static/chunks/node_modules_walletconnect_utils_node_modules_isows__esm_native_js.js\"]},\"node_modules/@walletconnect/utils/node_modules/viem/_esm/utils/signature/recoverPublicKey.js -> @noble/curves/secp256k1\":{\"id\":\"node_modules/@walletconnect/utils/node_modules/viem/_esm/utils/signature/recoverPublicKey.js -> @noble/curves/secp256k1\",\"files\":[]},\"node_modules/viem/_esm/actions/public/call.js -> ../../utils/ccip.js\":{\"id\":\"node_modules/viem/_esm/actions/public/call.js -> ../../utils/ccip.js\",\"files\":[]},\"node_modules/viem/_esm/utils/rpc/webSocket.js -> isows\":{\"id\":\"node_modules/viem/_esm/utils/rpc/webSocket.js -> isows\",\"files\":[\"static/chunks/node_modules_isows__esm_native_js.js\"]},\"node_modules/viem/_esm/utils/signature/recoverPublicKey.js -> @noble/curves/secp256k1\":{\"id\":\"node_modules/viem/_esm/utils/signature/recoverPublicKey.js -> @noble/curves/secp256k1\",\"files\":[]}}"
\ No newline at end of file
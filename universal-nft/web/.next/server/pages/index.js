@@ -1,176 +0,0 @@
-"use strict";
-/*
- * ATTENTION: An "eval-source-map" devtool has been used.
- * This devtool is neither made for production nor for readable output files.
- * It uses "eval()" calls to create a separate source file with attached SourceMaps in the browser devtools.
- * If you are trying to read the output file, select a different devtool (https://webpack.js.org/configuration/devtool/)
- * or disable the default devtool with "devtool: false".
- * If you are looking for production-ready output files, see mode: "production" (https://webpack.js.org/configuration/mode/).
- */
-(() => {
-var exports = {};
-exports.id = "pages/index";
-exports.ids = ["pages/index"];
-exports.modules = {
-
-/***/ "./node_modules/next/dist/build/webpack/loaders/next-route-loader/index.js?kind=PAGES&page=%2F&preferredRegion=&absolutePagePath=.%2Fpages%2Findex.tsx&absoluteAppPath=private-next-pages%2F_app&absoluteDocumentPath=private-next-pages%2F_document&middlewareConfigBase64=e30%3D!":
-/*!******************************************************************************************************************************************************************************************************************************************************************************************!*\
-  !*** ./node_modules/next/dist/build/webpack/loaders/next-route-loader/index.js?kind=PAGES&page=%2F&preferredRegion=&absolutePagePath=.%2Fpages%2Findex.tsx&absoluteAppPath=private-next-pages%2F_app&absoluteDocumentPath=private-next-pages%2F_document&middlewareConfigBase64=e30%3D! ***!
-  \******************************************************************************************************************************************************************************************************************************************************************************************/
-/***/ ((module, __webpack_exports__, __webpack_require__) => {
-
-eval("__webpack_require__.a(module, async (__webpack_handle_async_dependencies__, __webpack_async_result__) => { try {\n__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   config: () => (/* binding */ config),\n/* harmony export */   \"default\": () => (__WEBPACK_DEFAULT_EXPORT__),\n/* harmony export */   getServerSideProps: () => (/* binding */ getServerSideProps),\n/* harmony export */   getStaticPaths: () => (/* binding */ getStaticPaths),\n/* harmony export */   getStaticProps: () => (/* binding */ getStaticProps),\n/* harmony export */   reportWebVitals: () => (/* binding */ reportWebVitals),\n/* harmony export */   routeModule: () => (/* binding */ routeModule),\n/* harmony export */   unstable_getServerProps: () => (/* binding */ unstable_getServerProps),\n/* harmony export */   unstable_getServerSideProps: () => (/* binding */ unstable_getServerSideProps),\n/* harmony export */   unstable_getStaticParams: () => (/* binding */ unstable_getStaticParams),\n/* harmony export */   unstable_getStaticPaths: () => (/* binding */ unstable_getStaticPaths),\n/* harmony export */   unstable_getStaticProps: () => (/* binding */ unstable_getStaticProps)\n/* harmony export */ });\n/* harmony import */ var next_dist_server_future_route_modules_pages_module_compiled__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! next/dist/server/future/route-modules/pages/module.compiled */ \"./node_modules/next/dist/server/future/route-modules/pages/module.compiled.js\");\n/* harmony import */ var next_dist_server_future_route_modules_pages_module_compiled__WEBPACK_IMPORTED_MODULE_0___default = /*#__PURE__*/__webpack_require__.n(next_dist_server_future_route_modules_pages_module_compiled__WEBPACK_IMPORTED_MODULE_0__);\n/* harmony import */ var next_dist_server_future_route_kind__WEBPACK_IMPORTED_MODULE_1__ = __webpack_require__(/*! next/dist/server/future/route-kind */ \"./node_modules/next/dist/server/future/route-kind.js\");\n/* harmony import */ var next_dist_build_templates_helpers__WEBPACK_IMPORTED_MODULE_2__ = __webpack_require__(/*! next/dist/build/templates/helpers */ \"./node_modules/next/dist/build/templates/helpers.js\");\n/* harmony import */ var private_next_pages_document__WEBPACK_IMPORTED_MODULE_3__ = __webpack_require__(/*! private-next-pages/_document */ \"./node_modules/next/dist/pages/_document.js\");\n/* harmony import */ var private_next_pages_document__WEBPACK_IMPORTED_MODULE_3___default = /*#__PURE__*/__webpack_require__.n(private_next_pages_document__WEBPACK_IMPORTED_MODULE_3__);\n/* harmony import */ var private_next_pages_app__WEBPACK_IMPORTED_MODULE_4__ = __webpack_require__(/*! private-next-pages/_app */ \"./pages/_app.tsx\");\n/* harmony import */ var _pages_index_tsx__WEBPACK_IMPORTED_MODULE_5__ = __webpack_require__(/*! ./pages/index.tsx */ \"./pages/index.tsx\");\nvar __webpack_async_dependencies__ = __webpack_handle_async_dependencies__([_pages_index_tsx__WEBPACK_IMPORTED_MODULE_5__]);\n_pages_index_tsx__WEBPACK_IMPORTED_MODULE_5__ = (__webpack_async_dependencies__.then ? (await __webpack_async_dependencies__)() : __webpack_async_dependencies__)[0];\n\n\n\n// Import the app and document modules.\n\n\n// Import the userland code.\n\n// Re-export the component (should be the default export).\n/* harmony default export */ const __WEBPACK_DEFAULT_EXPORT__ = ((0,next_dist_build_templates_helpers__WEBPACK_IMPORTED_MODULE_2__.hoist)(_pages_index_tsx__WEBPACK_IMPORTED_MODULE_5__, \"default\"));\n// Re-export methods.\nconst getStaticProps = (0,next_dist_build_templates_helpers__WEBPACK_IMPORTED_MODULE_2__.hoist)(_pages_index_tsx__WEBPACK_IMPORTED_MODULE_5__, \"getStaticProps\");\nconst getStaticPaths = (0,next_dist_build_templates_helpers__WEBPACK_IMPORTED_MODULE_2__.hoist)(_pages_index_tsx__WEBPACK_IMPORTED_MODULE_5__, \"getStaticPaths\");\nconst getServerSideProps = (0,next_dist_build_templates_helpers__WEBPACK_IMPORTED_MODULE_2__.hoist)(_pages_index_tsx__WEBPACK_IMPORTED_MODULE_5__, \"getServerSideProps\");\nconst config = (0,next_dist_build_templates_helpers__WEBPACK_IMPORTED_MODULE_2__.hoist)(_pages_index_tsx__WEBPACK_IMPORTED_MODULE_5__, \"config\");\nconst reportWebVitals = (0,next_dist_build_templates_helpers__WEBPACK_IMPORTED_MODULE_2__.hoist)(_pages_index_tsx__WEBPACK_IMPORTED_MODULE_5__, \"reportWebVitals\");\n// Re-export legacy methods.\nconst unstable_getStaticProps = (0,next_dist_build_templates_helpers__WEBPACK_IMPORTED_MODULE_2__.hoist)(_pages_index_tsx__WEBPACK_IMPORTED_MODULE_5__, \"unstable_getStaticProps\");\nconst unstable_getStaticPaths = (0,next_dist_build_templates_helpers__WEBPACK_IMPORTED_MODULE_2__.hoist)(_pages_index_tsx__WEBPACK_IMPORTED_MODULE_5__, \"unstable_getStaticPaths\");\nconst unstable_getStaticParams = (0,next_dist_build_templates_helpers__WEBPACK_IMPORTED_MODULE_2__.hoist)(_pages_index_tsx__WEBPACK_IMPORTED_MODULE_5__, \"unstable_getStaticParams\");\nconst unstable_getServerProps = (0,next_dist_build_templates_helpers__WEBPACK_IMPORTED_MODULE_2__.hoist)(_pages_index_tsx__WEBPACK_IMPORTED_MODULE_5__, \"unstable_getServerProps\");\nconst unstable_getServerSideProps = (0,next_dist_build_templates_helpers__WEBPACK_IMPORTED_MODULE_2__.hoist)(_pages_index_tsx__WEBPACK_IMPORTED_MODULE_5__, \"unstable_getServerSideProps\");\n// Create and export the route module that will be consumed.\nconst routeModule = new next_dist_server_future_route_modules_pages_module_compiled__WEBPACK_IMPORTED_MODULE_0__.PagesRouteModule({\n    definition: {\n        kind: next_dist_server_future_route_kind__WEBPACK_IMPORTED_MODULE_1__.RouteKind.PAGES,\n        page: \"/index\",\n        pathname: \"/\",\n        // The following aren't used in production.\n        bundlePath: \"\",\n        filename: \"\"\n    },\n    components: {\n        App: private_next_pages_app__WEBPACK_IMPORTED_MODULE_4__[\"default\"],\n        Document: (private_next_pages_document__WEBPACK_IMPORTED_MODULE_3___default())\n    },\n    userland: _pages_index_tsx__WEBPACK_IMPORTED_MODULE_5__\n});\n\n//# sourceMappingURL=pages.js.map\n__webpack_async_result__();\n} catch(e) { __webpack_async_result__(e); } });//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvbmV4dC9kaXN0L2J1aWxkL3dlYnBhY2svbG9hZGVycy9uZXh0LXJvdXRlLWxvYWRlci9pbmRleC5qcz9raW5kPVBBR0VTJnBhZ2U9JTJGJnByZWZlcnJlZFJlZ2lvbj0mYWJzb2x1dGVQYWdlUGF0aD0uJTJGcGFnZXMlMkZpbmRleC50c3gmYWJzb2x1dGVBcHBQYXRoPXByaXZhdGUtbmV4dC1wYWdlcyUyRl9hcHAmYWJzb2x1dGVEb2N1bWVudFBhdGg9cHJpdmF0ZS1uZXh0LXBhZ2VzJTJGX2RvY3VtZW50Jm1pZGRsZXdhcmVDb25maWdCYXNlNjQ9ZTMwJTNEISIsIm1hcHBpbmdzIjoiOzs7Ozs7Ozs7Ozs7Ozs7Ozs7Ozs7Ozs7OztBQUErRjtBQUNoQztBQUNMO0FBQzFEO0FBQ29EO0FBQ1Y7QUFDMUM7QUFDOEM7QUFDOUM7QUFDQSxpRUFBZSx3RUFBSyxDQUFDLDZDQUFRLFlBQVksRUFBQztBQUMxQztBQUNPLHVCQUF1Qix3RUFBSyxDQUFDLDZDQUFRO0FBQ3JDLHVCQUF1Qix3RUFBSyxDQUFDLDZDQUFRO0FBQ3JDLDJCQUEyQix3RUFBSyxDQUFDLDZDQUFRO0FBQ3pDLGVBQWUsd0VBQUssQ0FBQyw2Q0FBUTtBQUM3Qix3QkFBd0Isd0VBQUssQ0FBQyw2Q0FBUTtBQUM3QztBQUNPLGdDQUFnQyx3RUFBSyxDQUFDLDZDQUFRO0FBQzlDLGdDQUFnQyx3RUFBSyxDQUFDLDZDQUFRO0FBQzlDLGlDQUFpQyx3RUFBSyxDQUFDLDZDQUFRO0FBQy9DLGdDQUFnQyx3RUFBSyxDQUFDLDZDQUFRO0FBQzlDLG9DQUFvQyx3RUFBSyxDQUFDLDZDQUFRO0FBQ3pEO0FBQ08sd0JBQXdCLHlHQUFnQjtBQUMvQztBQUNBLGNBQWMseUVBQVM7QUFDdkI7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBLEtBQUs7QUFDTDtBQUNBLFdBQVc7QUFDWCxnQkFBZ0I7QUFDaEIsS0FBSztBQUNMLFlBQVk7QUFDWixDQUFDOztBQUVELGlDIiwic291cmNlcyI6WyJ3ZWJwYWNrOi8vdW5pdmVyc2FsLW5mdC13ZWIvPzFjMmMiXSwic291cmNlc0NvbnRlbnQiOlsiaW1wb3J0IHsgUGFnZXNSb3V0ZU1vZHVsZSB9IGZyb20gXCJuZXh0L2Rpc3Qvc2VydmVyL2Z1dHVyZS9yb3V0ZS1tb2R1bGVzL3BhZ2VzL21vZHVsZS5jb21waWxlZFwiO1xuaW1wb3J0IHsgUm91dGVLaW5kIH0gZnJvbSBcIm5leHQvZGlzdC9zZXJ2ZXIvZnV0dXJlL3JvdXRlLWtpbmRcIjtcbmltcG9ydCB7IGhvaXN0IH0gZnJvbSBcIm5leHQvZGlzdC9idWlsZC90ZW1wbGF0ZXMvaGVscGVyc1wiO1xuLy8gSW1wb3J0IHRoZSBhcHAgYW5kIGRvY3VtZW50IG1vZHVsZXMuXG5pbXBvcnQgRG9jdW1lbnQgZnJvbSBcInByaXZhdGUtbmV4dC1wYWdlcy9fZG9jdW1lbnRcIjtcbmltcG9ydCBBcHAgZnJvbSBcInByaXZhdGUtbmV4dC1wYWdlcy9fYXBwXCI7XG4vLyBJbXBvcnQgdGhlIHVzZXJsYW5kIGNvZGUuXG5pbXBvcnQgKiBhcyB1c2VybGFuZCBmcm9tIFwiLi9wYWdlcy9pbmRleC50c3hcIjtcbi8vIFJlLWV4cG9ydCB0aGUgY29tcG9uZW50IChzaG91bGQgYmUgdGhlIGRlZmF1bHQgZXhwb3J0KS5cbmV4cG9ydCBkZWZhdWx0IGhvaXN0KHVzZXJsYW5kLCBcImRlZmF1bHRcIik7XG4vLyBSZS1leHBvcnQgbWV0aG9kcy5cbmV4cG9ydCBjb25zdCBnZXRTdGF0aWNQcm9wcyA9IGhvaXN0KHVzZXJsYW5kLCBcImdldFN0YXRpY1Byb3BzXCIpO1xuZXhwb3J0IGNvbnN0IGdldFN0YXRpY1BhdGhzID0gaG9pc3QodXNlcmxhbmQsIFwiZ2V0U3RhdGljUGF0aHNcIik7XG5leHBvcnQgY29uc3QgZ2V0U2VydmVyU2lkZVByb3BzID0gaG9pc3QodXNlcmxhbmQsIFwiZ2V0U2VydmVyU2lkZVByb3BzXCIpO1xuZXhwb3J0IGNvbnN0IGNvbmZpZyA9IGhvaXN0KHVzZXJsYW5kLCBcImNvbmZpZ1wiKTtcbmV4cG9ydCBjb25zdCByZXBvcnRXZWJWaXRhbHMgPSBob2lzdCh1c2VybGFuZCwgXCJyZXBvcnRXZWJWaXRhbHNcIik7XG4vLyBSZS1leHBvcnQgbGVnYWN5IG1ldGhvZHMuXG5leHBvcnQgY29uc3QgdW5zdGFibGVfZ2V0U3RhdGljUHJvcHMgPSBob2lzdCh1c2VybGFuZCwgXCJ1bnN0YWJsZV9nZXRTdGF0aWNQcm9wc1wiKTtcbmV4cG9ydCBjb25zdCB1bnN0YWJsZV9nZXRTdGF0aWNQYXRocyA9IGhvaXN0KHVzZXJsYW5kLCBcInVuc3RhYmxlX2dldFN0YXRpY1BhdGhzXCIpO1xuZXhwb3J0IGNvbnN0IHVuc3RhYmxlX2dldFN0YXRpY1BhcmFtcyA9IGhvaXN0KHVzZXJsYW5kLCBcInVuc3RhYmxlX2dldFN0YXRpY1BhcmFtc1wiKTtcbmV4cG9ydCBjb25zdCB1bnN0YWJsZV9nZXRTZXJ2ZXJQcm9wcyA9IGhvaXN0KHVzZXJsYW5kLCBcInVuc3RhYmxlX2dldFNlcnZlclByb3BzXCIpO1xuZXhwb3J0IGNvbnN0IHVuc3RhYmxlX2dldFNlcnZlclNpZGVQcm9wcyA9IGhvaXN0KHVzZXJsYW5kLCBcInVuc3RhYmxlX2dldFNlcnZlclNpZGVQcm9wc1wiKTtcbi8vIENyZWF0ZSBhbmQgZXhwb3J0IHRoZSByb3V0ZSBtb2R1bGUgdGhhdCB3aWxsIGJlIGNvbnN1bWVkLlxuZXhwb3J0IGNvbnN0IHJvdXRlTW9kdWxlID0gbmV3IFBhZ2VzUm91dGVNb2R1bGUoe1xuICAgIGRlZmluaXRpb246IHtcbiAgICAgICAga2luZDogUm91dGVLaW5kLlBBR0VTLFxuICAgICAgICBwYWdlOiBcIi9pbmRleFwiLFxuICAgICAgICBwYXRobmFtZTogXCIvXCIsXG4gICAgICAgIC8vIFRoZSBmb2xsb3dpbmcgYXJlbid0IHVzZWQgaW4gcHJvZHVjdGlvbi5cbiAgICAgICAgYnVuZGxlUGF0aDogXCJcIixcbiAgICAgICAgZmlsZW5hbWU6IFwiXCJcbiAgICB9LFxuICAgIGNvbXBvbmVudHM6IHtcbiAgICAgICAgQXBwLFxuICAgICAgICBEb2N1bWVudFxuICAgIH0sXG4gICAgdXNlcmxhbmRcbn0pO1xuXG4vLyMgc291cmNlTWFwcGluZ1VSTD1wYWdlcy5qcy5tYXAiXSwibmFtZXMiOltdLCJzb3VyY2VSb290IjoiIn0=\n//# sourceURL=webpack-internal:///./node_modules/next/dist/build/webpack/loaders/next-route-loader/index.js?kind=PAGES&page=%2F&preferredRegion=&absolutePagePath=.%2Fpages%2Findex.tsx&absoluteAppPath=private-next-pages%2F_app&absoluteDocumentPath=private-next-pages%2F_document&middlewareConfigBase64=e30%3D!\n");
-
-/***/ }),
-
-/***/ "./pages/_app.tsx":
-/*!************************!*\
-  !*** ./pages/_app.tsx ***!
-  \************************/
-/***/ ((__unused_webpack_module, __webpack_exports__, __webpack_require__) => {
-
-eval("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   \"default\": () => (/* binding */ App)\n/* harmony export */ });\n/* harmony import */ var react_jsx_dev_runtime__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! react/jsx-dev-runtime */ \"react/jsx-dev-runtime\");\n/* harmony import */ var react_jsx_dev_runtime__WEBPACK_IMPORTED_MODULE_0___default = /*#__PURE__*/__webpack_require__.n(react_jsx_dev_runtime__WEBPACK_IMPORTED_MODULE_0__);\n/* harmony import */ var _solana_wallet_adapter_react_ui_styles_css__WEBPACK_IMPORTED_MODULE_1__ = __webpack_require__(/*! @solana/wallet-adapter-react-ui/styles.css */ \"./node_modules/@solana/wallet-adapter-react-ui/styles.css\");\n/* harmony import */ var _solana_wallet_adapter_react_ui_styles_css__WEBPACK_IMPORTED_MODULE_1___default = /*#__PURE__*/__webpack_require__.n(_solana_wallet_adapter_react_ui_styles_css__WEBPACK_IMPORTED_MODULE_1__);\n\n\nfunction App({ Component, pageProps }) {\n    return /*#__PURE__*/ (0,react_jsx_dev_runtime__WEBPACK_IMPORTED_MODULE_0__.jsxDEV)(Component, {\n        ...pageProps\n    }, void 0, false, {\n        fileName: \"/Users/tau/Documents/GitHub/zetachain-solana-nft/universal-nft/web/pages/_app.tsx\",\n        lineNumber: 5,\n        columnNumber: 10\n    }, this);\n}\n//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9wYWdlcy9fYXBwLnRzeCIsIm1hcHBpbmdzIjoiOzs7Ozs7Ozs7QUFDbUQ7QUFFcEMsU0FBU0EsSUFBSSxFQUFFQyxTQUFTLEVBQUVDLFNBQVMsRUFBWTtJQUM1RCxxQkFBTyw4REFBQ0Q7UUFBVyxHQUFHQyxTQUFTOzs7Ozs7QUFDakMiLCJzb3VyY2VzIjpbIndlYnBhY2s6Ly91bml2ZXJzYWwtbmZ0LXdlYi8uL3BhZ2VzL19hcHAudHN4PzJmYmUiXSwic291cmNlc0NvbnRlbnQiOlsiaW1wb3J0IHR5cGUgeyBBcHBQcm9wcyB9IGZyb20gJ25leHQvYXBwJ1xuaW1wb3J0ICdAc29sYW5hL3dhbGxldC1hZGFwdGVyLXJlYWN0LXVpL3N0eWxlcy5jc3MnXG5cbmV4cG9ydCBkZWZhdWx0IGZ1bmN0aW9uIEFwcCh7IENvbXBvbmVudCwgcGFnZVByb3BzIH06IEFwcFByb3BzKSB7XG4gIHJldHVybiA8Q29tcG9uZW50IHsuLi5wYWdlUHJvcHN9IC8+XG59XG5cbiJdLCJuYW1lcyI6WyJBcHAiLCJDb21wb25lbnQiLCJwYWdlUHJvcHMiXSwic291cmNlUm9vdCI6IiJ9\n//# sourceURL=webpack-internal:///./pages/_app.tsx\n");
-
-/***/ }),
-
-/***/ "./pages/index.tsx":
-/*!*************************!*\
-  !*** ./pages/index.tsx ***!
-  \*************************/
-/***/ ((module, __webpack_exports__, __webpack_require__) => {
-
-eval("__webpack_require__.a(module, async (__webpack_handle_async_dependencies__, __webpack_async_result__) => { try {\n__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   \"default\": () => (/* binding */ Home)\n/* harmony export */ });\n/* harmony import */ var react_jsx_dev_runtime__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! react/jsx-dev-runtime */ \"react/jsx-dev-runtime\");\n/* harmony import */ var react_jsx_dev_runtime__WEBPACK_IMPORTED_MODULE_0___default = /*#__PURE__*/__webpack_require__.n(react_jsx_dev_runtime__WEBPACK_IMPORTED_MODULE_0__);\n/* harmony import */ var react__WEBPACK_IMPORTED_MODULE_1__ = __webpack_require__(/*! react */ \"react\");\n/* harmony import */ var react__WEBPACK_IMPORTED_MODULE_1___default = /*#__PURE__*/__webpack_require__.n(react__WEBPACK_IMPORTED_MODULE_1__);\n/* harmony import */ var _solana_web3_js__WEBPACK_IMPORTED_MODULE_2__ = __webpack_require__(/*! @solana/web3.js */ \"@solana/web3.js\");\n/* harmony import */ var _solana_web3_js__WEBPACK_IMPORTED_MODULE_2___default = /*#__PURE__*/__webpack_require__.n(_solana_web3_js__WEBPACK_IMPORTED_MODULE_2__);\n/* harmony import */ var _idl_universal_nft_json__WEBPACK_IMPORTED_MODULE_3__ = __webpack_require__(/*! ../idl/universal_nft.json */ \"./idl/universal_nft.json\");\n/* harmony import */ var _coral_xyz_anchor__WEBPACK_IMPORTED_MODULE_4__ = __webpack_require__(/*! @coral-xyz/anchor */ \"@coral-xyz/anchor\");\n/* harmony import */ var _coral_xyz_anchor__WEBPACK_IMPORTED_MODULE_4___default = /*#__PURE__*/__webpack_require__.n(_coral_xyz_anchor__WEBPACK_IMPORTED_MODULE_4__);\n/* harmony import */ var _solana_wallet_adapter_react__WEBPACK_IMPORTED_MODULE_5__ = __webpack_require__(/*! @solana/wallet-adapter-react */ \"@solana/wallet-adapter-react\");\n/* harmony import */ var _solana_wallet_adapter_react_ui__WEBPACK_IMPORTED_MODULE_6__ = __webpack_require__(/*! @solana/wallet-adapter-react-ui */ \"@solana/wallet-adapter-react-ui\");\n/* harmony import */ var _solana_wallet_adapter_wallets__WEBPACK_IMPORTED_MODULE_7__ = __webpack_require__(/*! @solana/wallet-adapter-wallets */ \"@solana/wallet-adapter-wallets\");\n/* harmony import */ var _solana_spl_token__WEBPACK_IMPORTED_MODULE_8__ = __webpack_require__(/*! @solana/spl-token */ \"@solana/spl-token\");\nvar __webpack_async_dependencies__ = __webpack_handle_async_dependencies__([_solana_wallet_adapter_react__WEBPACK_IMPORTED_MODULE_5__, _solana_wallet_adapter_react_ui__WEBPACK_IMPORTED_MODULE_6__, _solana_wallet_adapter_wallets__WEBPACK_IMPORTED_MODULE_7__, _solana_spl_token__WEBPACK_IMPORTED_MODULE_8__]);\n([_solana_wallet_adapter_react__WEBPACK_IMPORTED_MODULE_5__, _solana_wallet_adapter_react_ui__WEBPACK_IMPORTED_MODULE_6__, _solana_wallet_adapter_wallets__WEBPACK_IMPORTED_MODULE_7__, _solana_spl_token__WEBPACK_IMPORTED_MODULE_8__] = __webpack_async_dependencies__.then ? (await __webpack_async_dependencies__)() : __webpack_async_dependencies__);\n\n\n\n\n\n\n\n\n\nconst PROGRAM_ID = new _solana_web3_js__WEBPACK_IMPORTED_MODULE_2__.PublicKey(_idl_universal_nft_json__WEBPACK_IMPORTED_MODULE_3__.address);\nfunction AppInner() {\n    const wallet = (0,_solana_wallet_adapter_react__WEBPACK_IMPORTED_MODULE_5__.useAnchorWallet)();\n    const [status, setStatus] = (0,react__WEBPACK_IMPORTED_MODULE_1__.useState)(\"Ready\");\n    const [logs, setLogs] = (0,react__WEBPACK_IMPORTED_MODULE_1__.useState)(\"\");\n    const connection = (0,react__WEBPACK_IMPORTED_MODULE_1__.useMemo)(()=>new _solana_web3_js__WEBPACK_IMPORTED_MODULE_2__.Connection(\"http://127.0.0.1:8899\", \"confirmed\"), []);\n    const provider = (0,react__WEBPACK_IMPORTED_MODULE_1__.useMemo)(()=>wallet ? new _coral_xyz_anchor__WEBPACK_IMPORTED_MODULE_4__.AnchorProvider(connection, wallet, {\n            commitment: \"confirmed\"\n        }) : null, [\n        connection,\n        wallet\n    ]);\n    const program = (0,react__WEBPACK_IMPORTED_MODULE_1__.useMemo)(()=>provider ? new _coral_xyz_anchor__WEBPACK_IMPORTED_MODULE_4__.Program(_idl_universal_nft_json__WEBPACK_IMPORTED_MODULE_3__, PROGRAM_ID, provider) : null, [\n        provider\n    ]);\n    const [gateway, setGateway] = (0,react__WEBPACK_IMPORTED_MODULE_1__.useState)(\"11111111111111111111111111111111\");\n    const [fee, setFee] = (0,react__WEBPACK_IMPORTED_MODULE_1__.useState)(\"10000\");\n    const [mintName, setMintName] = (0,react__WEBPACK_IMPORTED_MODULE_1__.useState)(\"ZUNFT\");\n    const [mintSymbol, setMintSymbol] = (0,react__WEBPACK_IMPORTED_MODULE_1__.useState)(\"ZUNFT\");\n    const [mintUri, setMintUri] = (0,react__WEBPACK_IMPORTED_MODULE_1__.useState)(\"https://example.com/1.json\");\n    const [originalChain, setOriginalChain] = (0,react__WEBPACK_IMPORTED_MODULE_1__.useState)(\"solana\");\n    const [originalContract, setOriginalContract] = (0,react__WEBPACK_IMPORTED_MODULE_1__.useState)(\"native\");\n    const [originalTokenId, setOriginalTokenId] = (0,react__WEBPACK_IMPORTED_MODULE_1__.useState)(\"1\");\n    const [lastMint, setLastMint] = (0,react__WEBPACK_IMPORTED_MODULE_1__.useState)(null);\n    const handleInitialize = (0,react__WEBPACK_IMPORTED_MODULE_1__.useCallback)(async ()=>{\n        if (!program || !provider || !wallet?.publicKey) return;\n        setStatus(\"Initializing...\");\n        try {\n            const [globalConfigPda, bump] = _solana_web3_js__WEBPACK_IMPORTED_MODULE_2__.PublicKey.findProgramAddressSync([\n                Buffer.from(\"global_config\")\n            ], PROGRAM_ID);\n            const tx = await program.methods.initialize(bump, new _coral_xyz_anchor__WEBPACK_IMPORTED_MODULE_4__.BN(parseInt(fee))).accounts({\n                globalConfig: globalConfigPda,\n                authority: wallet.publicKey,\n                zetachainGateway: new _solana_web3_js__WEBPACK_IMPORTED_MODULE_2__.PublicKey(gateway),\n                collectionAuthority: wallet.publicKey,\n                feeRecipient: wallet.publicKey,\n                systemProgram: _solana_web3_js__WEBPACK_IMPORTED_MODULE_2__.SystemProgram.programId\n            }).rpc();\n            setStatus(\"Initialized ✅\");\n            setLogs(`Initialize tx: ${tx}`);\n        } catch (e) {\n            setStatus(\"Init failed\");\n            setLogs(e.message || String(e));\n        }\n    }, [\n        program,\n        provider,\n        wallet,\n        gateway,\n        fee\n    ]);\n    const handleMint = (0,react__WEBPACK_IMPORTED_MODULE_1__.useCallback)(async ()=>{\n        if (!program || !provider || !wallet?.publicKey) return;\n        setStatus(\"Minting...\");\n        try {\n            const mint = _solana_web3_js__WEBPACK_IMPORTED_MODULE_2__.Keypair.generate();\n            const [universalNftPda, bump] = _solana_web3_js__WEBPACK_IMPORTED_MODULE_2__.PublicKey.findProgramAddressSync([\n                Buffer.from(\"universal_nft\"),\n                mint.publicKey.toBuffer()\n            ], PROGRAM_ID);\n            const tokenAccount = await (0,_solana_spl_token__WEBPACK_IMPORTED_MODULE_8__.getAssociatedTokenAddress)(mint.publicKey, wallet.publicKey);\n            const tx = await program.methods.mintNft(bump, mintName, mintSymbol, mintUri, originalChain, originalContract, originalTokenId).accounts({\n                globalConfig: _solana_web3_js__WEBPACK_IMPORTED_MODULE_2__.PublicKey.findProgramAddressSync([\n                    Buffer.from(\"global_config\")\n                ], PROGRAM_ID)[0],\n                universalNft: universalNftPda,\n                mint: mint.publicKey,\n                tokenAccount,\n                payer: wallet.publicKey,\n                recipient: wallet.publicKey,\n                collectionAuthority: wallet.publicKey,\n                tokenProgram: _solana_spl_token__WEBPACK_IMPORTED_MODULE_8__.TOKEN_PROGRAM_ID,\n                associatedTokenProgram: _solana_spl_token__WEBPACK_IMPORTED_MODULE_8__.ASSOCIATED_TOKEN_PROGRAM_ID,\n                systemProgram: _solana_web3_js__WEBPACK_IMPORTED_MODULE_2__.SystemProgram.programId\n            }).signers([\n                mint\n            ]).rpc();\n            setLastMint(mint.publicKey);\n            setStatus(\"Minted ✅\");\n            setLogs(`Mint tx: ${tx}\\nMint: ${mint.publicKey.toBase58()}`);\n        } catch (e) {\n            setStatus(\"Mint failed\");\n            setLogs(e.message || String(e));\n        }\n    }, [\n        program,\n        provider,\n        wallet,\n        mintName,\n        mintSymbol,\n        mintUri,\n        originalChain,\n        originalContract,\n        originalTokenId\n    ]);\n    const handleInitiate = (0,react__WEBPACK_IMPORTED_MODULE_1__.useCallback)(async ()=>{\n        if (!program || !provider || !wallet?.publicKey || !lastMint) return;\n        setStatus(\"Initiating cross-chain transfer...\");\n        try {\n            const transferId = `transfer_${Date.now()}`;\n            const [universalNftPda] = _solana_web3_js__WEBPACK_IMPORTED_MODULE_2__.PublicKey.findProgramAddressSync([\n                Buffer.from(\"universal_nft\"),\n                lastMint.toBuffer()\n            ], PROGRAM_ID);\n            const [crossChainTransferPda, crossBump] = _solana_web3_js__WEBPACK_IMPORTED_MODULE_2__.PublicKey.findProgramAddressSync([\n                Buffer.from(\"cross_chain_transfer\"),\n                Buffer.from(transferId)\n            ], PROGRAM_ID);\n            const ownerTokenAccount = await (0,_solana_spl_token__WEBPACK_IMPORTED_MODULE_8__.getAssociatedTokenAddress)(lastMint, wallet.publicKey);\n            const tx = await program.methods.initiateCrossChainTransfer(transferId, \"polygon\", \"0xabc\", crossBump).accounts({\n                globalConfig: _solana_web3_js__WEBPACK_IMPORTED_MODULE_2__.PublicKey.findProgramAddressSync([\n                    Buffer.from(\"global_config\")\n                ], PROGRAM_ID)[0],\n                universalNft: universalNftPda,\n                crossChainTransfer: crossChainTransferPda,\n                nftMint: lastMint,\n                ownerTokenAccount,\n                owner: wallet.publicKey,\n                payer: wallet.publicKey,\n                zetachainGateway: _solana_web3_js__WEBPACK_IMPORTED_MODULE_2__.PublicKey.default,\n                tokenProgram: _solana_spl_token__WEBPACK_IMPORTED_MODULE_8__.TOKEN_PROGRAM_ID,\n                systemProgram: _solana_web3_js__WEBPACK_IMPORTED_MODULE_2__.SystemProgram.programId\n            }).rpc();\n            setStatus(\"Transfer initiated ✅\");\n            setLogs(`Initiate tx: ${tx}\\nTransfer ID: ${transferId}`);\n        } catch (e) {\n            setStatus(\"Initiate failed\");\n            setLogs(e.message || String(e));\n        }\n    }, [\n        program,\n        provider,\n        wallet,\n        lastMint\n    ]);\n    return /*#__PURE__*/ (0,react_jsx_dev_runtime__WEBPACK_IMPORTED_MODULE_0__.jsxDEV)(\"div\", {\n        style: {\n            fontFamily: \"sans-serif\",\n            maxWidth: 900,\n            margin: \"40px auto\"\n        },\n        children: [\n            /*#__PURE__*/ (0,react_jsx_dev_runtime__WEBPACK_IMPORTED_MODULE_0__.jsxDEV)(\"div\", {\n                style: {\n                    display: \"flex\",\n                    alignItems: \"center\",\n                    gap: 12\n                },\n                children: [\n                    /*#__PURE__*/ (0,react_jsx_dev_runtime__WEBPACK_IMPORTED_MODULE_0__.jsxDEV)(\"h1\", {\n                        style: {\n                            margin: 0\n                        },\n                        children: \"ZetaChain Universal NFT Demo\"\n                    }, void 0, false, {\n                        fileName: \"/Users/tau/Documents/GitHub/zetachain-solana-nft/universal-nft/web/pages/index.tsx\",\n                        lineNumber: 128,\n                        columnNumber: 9\n                    }, this),\n                    /*#__PURE__*/ (0,react_jsx_dev_runtime__WEBPACK_IMPORTED_MODULE_0__.jsxDEV)(_solana_wallet_adapter_react_ui__WEBPACK_IMPORTED_MODULE_6__.WalletMultiButton, {}, void 0, false, {\n                        fileName: \"/Users/tau/Documents/GitHub/zetachain-solana-nft/universal-nft/web/pages/index.tsx\",\n                        lineNumber: 129,\n                        columnNumber: 9\n                    }, this)\n                ]\n            }, void 0, true, {\n                fileName: \"/Users/tau/Documents/GitHub/zetachain-solana-nft/universal-nft/web/pages/index.tsx\",\n                lineNumber: 127,\n                columnNumber: 7\n            }, this),\n            /*#__PURE__*/ (0,react_jsx_dev_runtime__WEBPACK_IMPORTED_MODULE_0__.jsxDEV)(\"p\", {\n                children: [\n                    \"Program ID: \",\n                    /*#__PURE__*/ (0,react_jsx_dev_runtime__WEBPACK_IMPORTED_MODULE_0__.jsxDEV)(\"code\", {\n                        children: PROGRAM_ID.toBase58()\n                    }, void 0, false, {\n                        fileName: \"/Users/tau/Documents/GitHub/zetachain-solana-nft/universal-nft/web/pages/index.tsx\",\n                        lineNumber: 131,\n                        columnNumber: 22\n                    }, this)\n                ]\n            }, void 0, true, {\n                fileName: \"/Users/tau/Documents/GitHub/zetachain-solana-nft/universal-nft/web/pages/index.tsx\",\n                lineNumber: 131,\n                columnNumber: 7\n            }, this),\n            /*#__PURE__*/ (0,react_jsx_dev_runtime__WEBPACK_IMPORTED_MODULE_0__.jsxDEV)(\"section\", {\n                style: {\n                    border: \"1px solid #ddd\",\n                    padding: 16,\n                    borderRadius: 8,\n                    marginBottom: 16\n                },\n                children: [\n                    /*#__PURE__*/ (0,react_jsx_dev_runtime__WEBPACK_IMPORTED_MODULE_0__.jsxDEV)(\"h3\", {\n                        children: \"1) Initialize\"\n                    }, void 0, false, {\n                        fileName: \"/Users/tau/Documents/GitHub/zetachain-solana-nft/universal-nft/web/pages/index.tsx\",\n                        lineNumber: 134,\n                        columnNumber: 9\n                    }, this),\n                    /*#__PURE__*/ (0,react_jsx_dev_runtime__WEBPACK_IMPORTED_MODULE_0__.jsxDEV)(\"div\", {\n                        style: {\n                            display: \"flex\",\n                            gap: 8,\n                            flexWrap: \"wrap\"\n                        },\n                        children: [\n                            /*#__PURE__*/ (0,react_jsx_dev_runtime__WEBPACK_IMPORTED_MODULE_0__.jsxDEV)(\"input\", {\n                                style: {\n                                    flex: 1\n                                },\n                                value: gateway,\n                                onChange: (e)=>setGateway(e.target.value),\n                                placeholder: \"Gateway Program ID\"\n                            }, void 0, false, {\n                                fileName: \"/Users/tau/Documents/GitHub/zetachain-solana-nft/universal-nft/web/pages/index.tsx\",\n                                lineNumber: 136,\n                                columnNumber: 11\n                            }, this),\n                            /*#__PURE__*/ (0,react_jsx_dev_runtime__WEBPACK_IMPORTED_MODULE_0__.jsxDEV)(\"input\", {\n                                style: {\n                                    width: 180\n                                },\n                                value: fee,\n                                onChange: (e)=>setFee(e.target.value),\n                                placeholder: \"Fee (lamports)\"\n                            }, void 0, false, {\n                                fileName: \"/Users/tau/Documents/GitHub/zetachain-solana-nft/universal-nft/web/pages/index.tsx\",\n                                lineNumber: 137,\n                                columnNumber: 11\n                            }, this),\n                            /*#__PURE__*/ (0,react_jsx_dev_runtime__WEBPACK_IMPORTED_MODULE_0__.jsxDEV)(\"button\", {\n                                onClick: handleInitialize,\n                                disabled: !wallet,\n                                children: \"Initialize\"\n                            }, void 0, false, {\n                                fileName: \"/Users/tau/Documents/GitHub/zetachain-solana-nft/universal-nft/web/pages/index.tsx\",\n                                lineNumber: 138,\n                                columnNumber: 11\n                            }, this)\n                        ]\n                    }, void 0, true, {\n                        fileName: \"/Users/tau/Documents/GitHub/zetachain-solana-nft/universal-nft/web/pages/index.tsx\",\n                        lineNumber: 135,\n                        columnNumber: 9\n                    }, this)\n                ]\n            }, void 0, true, {\n                fileName: \"/Users/tau/Documents/GitHub/zetachain-solana-nft/universal-nft/web/pages/index.tsx\",\n                lineNumber: 133,\n                columnNumber: 7\n            }, this),\n            /*#__PURE__*/ (0,react_jsx_dev_runtime__WEBPACK_IMPORTED_MODULE_0__.jsxDEV)(\"section\", {\n                style: {\n                    border: \"1px solid #ddd\",\n                    padding: 16,\n                    borderRadius: 8,\n                    marginBottom: 16\n                },\n                children: [\n                    /*#__PURE__*/ (0,react_jsx_dev_runtime__WEBPACK_IMPORTED_MODULE_0__.jsxDEV)(\"h3\", {\n                        children: \"2) Mint NFT\"\n                    }, void 0, false, {\n                        fileName: \"/Users/tau/Documents/GitHub/zetachain-solana-nft/universal-nft/web/pages/index.tsx\",\n                        lineNumber: 143,\n                        columnNumber: 9\n                    }, this),\n                    /*#__PURE__*/ (0,react_jsx_dev_runtime__WEBPACK_IMPORTED_MODULE_0__.jsxDEV)(\"div\", {\n                        style: {\n                            display: \"grid\",\n                            gridTemplateColumns: \"repeat(3, 1fr)\",\n                            gap: 8\n                        },\n                        children: [\n                            /*#__PURE__*/ (0,react_jsx_dev_runtime__WEBPACK_IMPORTED_MODULE_0__.jsxDEV)(\"input\", {\n                                value: mintName,\n                                onChange: (e)=>setMintName(e.target.value),\n                                placeholder: \"Name\"\n                            }, void 0, false, {\n                                fileName: \"/Users/tau/Documents/GitHub/zetachain-solana-nft/universal-nft/web/pages/index.tsx\",\n                                lineNumber: 145,\n                                columnNumber: 11\n                            }, this),\n                            /*#__PURE__*/ (0,react_jsx_dev_runtime__WEBPACK_IMPORTED_MODULE_0__.jsxDEV)(\"input\", {\n                                value: mintSymbol,\n                                onChange: (e)=>setMintSymbol(e.target.value),\n                                placeholder: \"Symbol\"\n                            }, void 0, false, {\n                                fileName: \"/Users/tau/Documents/GitHub/zetachain-solana-nft/universal-nft/web/pages/index.tsx\",\n                                lineNumber: 146,\n                                columnNumber: 11\n                            }, this),\n                            /*#__PURE__*/ (0,react_jsx_dev_runtime__WEBPACK_IMPORTED_MODULE_0__.jsxDEV)(\"input\", {\n                                value: mintUri,\n                                onChange: (e)=>setMintUri(e.target.value),\n                                placeholder: \"URI\"\n                            }, void 0, false, {\n                                fileName: \"/Users/tau/Documents/GitHub/zetachain-solana-nft/universal-nft/web/pages/index.tsx\",\n                                lineNumber: 147,\n                                columnNumber: 11\n                            }, this),\n                            /*#__PURE__*/ (0,react_jsx_dev_runtime__WEBPACK_IMPORTED_MODULE_0__.jsxDEV)(\"input\", {\n                                value: originalChain,\n                                onChange: (e)=>setOriginalChain(e.target.value),\n                                placeholder: \"Original Chain\"\n                            }, void 0, false, {\n                                fileName: \"/Users/tau/Documents/GitHub/zetachain-solana-nft/universal-nft/web/pages/index.tsx\",\n                                lineNumber: 148,\n                                columnNumber: 11\n                            }, this),\n                            /*#__PURE__*/ (0,react_jsx_dev_runtime__WEBPACK_IMPORTED_MODULE_0__.jsxDEV)(\"input\", {\n                                value: originalContract,\n                                onChange: (e)=>setOriginalContract(e.target.value),\n                                placeholder: \"Original Contract\"\n                            }, void 0, false, {\n                                fileName: \"/Users/tau/Documents/GitHub/zetachain-solana-nft/universal-nft/web/pages/index.tsx\",\n                                lineNumber: 149,\n                                columnNumber: 11\n                            }, this),\n                            /*#__PURE__*/ (0,react_jsx_dev_runtime__WEBPACK_IMPORTED_MODULE_0__.jsxDEV)(\"input\", {\n                                value: originalTokenId,\n                                onChange: (e)=>setOriginalTokenId(e.target.value),\n                                placeholder: \"Original Token ID\"\n                            }, void 0, false, {\n                                fileName: \"/Users/tau/Documents/GitHub/zetachain-solana-nft/universal-nft/web/pages/index.tsx\",\n                                lineNumber: 150,\n                                columnNumber: 11\n                            }, this)\n                        ]\n                    }, void 0, true, {\n                        fileName: \"/Users/tau/Documents/GitHub/zetachain-solana-nft/universal-nft/web/pages/index.tsx\",\n                        lineNumber: 144,\n                        columnNumber: 9\n                    }, this),\n                    /*#__PURE__*/ (0,react_jsx_dev_runtime__WEBPACK_IMPORTED_MODULE_0__.jsxDEV)(\"div\", {\n                        style: {\n                            marginTop: 8\n                        },\n                        children: /*#__PURE__*/ (0,react_jsx_dev_runtime__WEBPACK_IMPORTED_MODULE_0__.jsxDEV)(\"button\", {\n                            onClick: handleMint,\n                            disabled: !wallet,\n                            children: \"Mint\"\n                        }, void 0, false, {\n                            fileName: \"/Users/tau/Documents/GitHub/zetachain-solana-nft/universal-nft/web/pages/index.tsx\",\n                            lineNumber: 153,\n                            columnNumber: 11\n                        }, this)\n                    }, void 0, false, {\n                        fileName: \"/Users/tau/Documents/GitHub/zetachain-solana-nft/universal-nft/web/pages/index.tsx\",\n                        lineNumber: 152,\n                        columnNumber: 9\n                    }, this)\n                ]\n            }, void 0, true, {\n                fileName: \"/Users/tau/Documents/GitHub/zetachain-solana-nft/universal-nft/web/pages/index.tsx\",\n                lineNumber: 142,\n                columnNumber: 7\n            }, this),\n            /*#__PURE__*/ (0,react_jsx_dev_runtime__WEBPACK_IMPORTED_MODULE_0__.jsxDEV)(\"section\", {\n                style: {\n                    border: \"1px solid #ddd\",\n                    padding: 16,\n                    borderRadius: 8,\n                    marginBottom: 16\n                },\n                children: [\n                    /*#__PURE__*/ (0,react_jsx_dev_runtime__WEBPACK_IMPORTED_MODULE_0__.jsxDEV)(\"h3\", {\n                        children: \"3) Initiate Cross-Chain Transfer (Demo)\"\n                    }, void 0, false, {\n                        fileName: \"/Users/tau/Documents/GitHub/zetachain-solana-nft/universal-nft/web/pages/index.tsx\",\n                        lineNumber: 158,\n                        columnNumber: 9\n                    }, this),\n                    /*#__PURE__*/ (0,react_jsx_dev_runtime__WEBPACK_IMPORTED_MODULE_0__.jsxDEV)(\"button\", {\n                        onClick: handleInitiate,\n                        disabled: !wallet || !lastMint,\n                        children: \"Initiate\"\n                    }, void 0, false, {\n                        fileName: \"/Users/tau/Documents/GitHub/zetachain-solana-nft/universal-nft/web/pages/index.tsx\",\n                        lineNumber: 159,\n                        columnNumber: 9\n                    }, this)\n                ]\n            }, void 0, true, {\n                fileName: \"/Users/tau/Documents/GitHub/zetachain-solana-nft/universal-nft/web/pages/index.tsx\",\n                lineNumber: 157,\n                columnNumber: 7\n            }, this),\n            /*#__PURE__*/ (0,react_jsx_dev_runtime__WEBPACK_IMPORTED_MODULE_0__.jsxDEV)(\"section\", {\n                style: {\n                    border: \"1px solid #ddd\",\n                    padding: 16,\n                    borderRadius: 8\n                },\n                children: [\n                    /*#__PURE__*/ (0,react_jsx_dev_runtime__WEBPACK_IMPORTED_MODULE_0__.jsxDEV)(\"h3\", {\n                        children: \"Status\"\n                    }, void 0, false, {\n                        fileName: \"/Users/tau/Documents/GitHub/zetachain-solana-nft/universal-nft/web/pages/index.tsx\",\n                        lineNumber: 163,\n                        columnNumber: 9\n                    }, this),\n                    /*#__PURE__*/ (0,react_jsx_dev_runtime__WEBPACK_IMPORTED_MODULE_0__.jsxDEV)(\"p\", {\n                        children: status\n                    }, void 0, false, {\n                        fileName: \"/Users/tau/Documents/GitHub/zetachain-solana-nft/universal-nft/web/pages/index.tsx\",\n                        lineNumber: 164,\n                        columnNumber: 9\n                    }, this),\n                    /*#__PURE__*/ (0,react_jsx_dev_runtime__WEBPACK_IMPORTED_MODULE_0__.jsxDEV)(\"pre\", {\n                        style: {\n                            whiteSpace: \"pre-wrap\"\n                        },\n                        children: logs\n                    }, void 0, false, {\n                        fileName: \"/Users/tau/Documents/GitHub/zetachain-solana-nft/universal-nft/web/pages/index.tsx\",\n                        lineNumber: 165,\n                        columnNumber: 9\n                    }, this)\n                ]\n            }, void 0, true, {\n                fileName: \"/Users/tau/Documents/GitHub/zetachain-solana-nft/universal-nft/web/pages/index.tsx\",\n                lineNumber: 162,\n                columnNumber: 7\n            }, this)\n        ]\n    }, void 0, true, {\n        fileName: \"/Users/tau/Documents/GitHub/zetachain-solana-nft/universal-nft/web/pages/index.tsx\",\n        lineNumber: 126,\n        columnNumber: 5\n    }, this);\n}\nfunction Home() {\n    const endpoint = \"http://127.0.0.1:8899\";\n    const wallets = (0,react__WEBPACK_IMPORTED_MODULE_1__.useMemo)(()=>[\n            new _solana_wallet_adapter_wallets__WEBPACK_IMPORTED_MODULE_7__.PhantomWalletAdapter(),\n            new _solana_wallet_adapter_wallets__WEBPACK_IMPORTED_MODULE_7__.SolflareWalletAdapter()\n        ], []);\n    return /*#__PURE__*/ (0,react_jsx_dev_runtime__WEBPACK_IMPORTED_MODULE_0__.jsxDEV)(_solana_wallet_adapter_react__WEBPACK_IMPORTED_MODULE_5__.ConnectionProvider, {\n        endpoint: endpoint,\n        children: /*#__PURE__*/ (0,react_jsx_dev_runtime__WEBPACK_IMPORTED_MODULE_0__.jsxDEV)(_solana_wallet_adapter_react__WEBPACK_IMPORTED_MODULE_5__.WalletProvider, {\n            wallets: wallets,\n            autoConnect: true,\n            children: /*#__PURE__*/ (0,react_jsx_dev_runtime__WEBPACK_IMPORTED_MODULE_0__.jsxDEV)(_solana_wallet_adapter_react_ui__WEBPACK_IMPORTED_MODULE_6__.WalletModalProvider, {\n                children: /*#__PURE__*/ (0,react_jsx_dev_runtime__WEBPACK_IMPORTED_MODULE_0__.jsxDEV)(AppInner, {}, void 0, false, {\n                    fileName: \"/Users/tau/Documents/GitHub/zetachain-solana-nft/universal-nft/web/pages/index.tsx\",\n                    lineNumber: 179,\n                    columnNumber: 11\n                }, this)\n            }, void 0, false, {\n                fileName: \"/Users/tau/Documents/GitHub/zetachain-solana-nft/universal-nft/web/pages/index.tsx\",\n                lineNumber: 178,\n                columnNumber: 9\n            }, this)\n        }, void 0, false, {\n            fileName: \"/Users/tau/Documents/GitHub/zetachain-solana-nft/universal-nft/web/pages/index.tsx\",\n            lineNumber: 177,\n            columnNumber: 7\n        }, this)\n    }, void 0, false, {\n        fileName: \"/Users/tau/Documents/GitHub/zetachain-solana-nft/universal-nft/web/pages/index.tsx\",\n        lineNumber: 176,\n        columnNumber: 5\n    }, this);\n}\n\n__webpack_async_result__();\n} catch(e) { __webpack_async_result__(e); } });//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9wYWdlcy9pbmRleC50c3giLCJtYXBwaW5ncyI6Ijs7Ozs7Ozs7Ozs7Ozs7Ozs7Ozs7O0FBQXNEO0FBQ3dDO0FBQ25EO0FBQ0E7QUFFdUQ7QUFDVjtBQUNJO0FBQ2dCO0FBRTVHLE1BQU1tQixhQUFhLElBQUlkLHNEQUFTQSxDQUFDLDREQUFvQjtBQUVyRCxTQUFTZ0I7SUFDUCxNQUFNQyxTQUFTWCw2RUFBZUE7SUFDOUIsTUFBTSxDQUFDWSxRQUFRQyxVQUFVLEdBQUd0QiwrQ0FBUUEsQ0FBUztJQUM3QyxNQUFNLENBQUN1QixNQUFNQyxRQUFRLEdBQUd4QiwrQ0FBUUEsQ0FBUztJQUN6QyxNQUFNeUIsYUFBYTFCLDhDQUFPQSxDQUFDLElBQU0sSUFBSUUsdURBQVVBLENBQUMseUJBQXlCLGNBQWMsRUFBRTtJQUV6RixNQUFNeUIsV0FBVzNCLDhDQUFPQSxDQUFDLElBQU9xQixTQUFTLElBQUlkLDZEQUFxQixDQUFDbUIsWUFBWUwsUUFBUTtZQUFFUSxZQUFZO1FBQVksS0FBSyxNQUFPO1FBQUNIO1FBQVlMO0tBQU87SUFDakosTUFBTVMsVUFBVTlCLDhDQUFPQSxDQUFDLElBQU8yQixXQUFXLElBQUlwQixzREFBYyxDQUFDRCxvREFBR0EsRUFBU1ksWUFBWVMsWUFBWSxNQUFPO1FBQUNBO0tBQVM7SUFFbEgsTUFBTSxDQUFDSyxTQUFTQyxXQUFXLEdBQUdoQywrQ0FBUUEsQ0FBUztJQUMvQyxNQUFNLENBQUNpQyxLQUFLQyxPQUFPLEdBQUdsQywrQ0FBUUEsQ0FBUztJQUV2QyxNQUFNLENBQUNtQyxVQUFVQyxZQUFZLEdBQUdwQywrQ0FBUUEsQ0FBQztJQUN6QyxNQUFNLENBQUNxQyxZQUFZQyxjQUFjLEdBQUd0QywrQ0FBUUEsQ0FBQztJQUM3QyxNQUFNLENBQUN1QyxTQUFTQyxXQUFXLEdBQUd4QywrQ0FBUUEsQ0FBQztJQUN2QyxNQUFNLENBQUN5QyxlQUFlQyxpQkFBaUIsR0FBRzFDLCtDQUFRQSxDQUFDO0lBQ25ELE1BQU0sQ0FBQzJDLGtCQUFrQkMsb0JBQW9CLEdBQUc1QywrQ0FBUUEsQ0FBQztJQUN6RCxNQUFNLENBQUM2QyxpQkFBaUJDLG1CQUFtQixHQUFHOUMsK0NBQVFBLENBQUM7SUFFdkQsTUFBTSxDQUFDK0MsVUFBVUMsWUFBWSxHQUFHaEQsK0NBQVFBLENBQW1CO0lBRTNELE1BQU1pRCxtQkFBbUJuRCxrREFBV0EsQ0FBQztRQUNuQyxJQUFJLENBQUMrQixXQUFXLENBQUNILFlBQVksQ0FBQ04sUUFBUThCLFdBQVc7UUFDakQ1QixVQUFVO1FBQ1YsSUFBSTtZQUNGLE1BQU0sQ0FBQzZCLGlCQUFpQkMsS0FBSyxHQUFHakQsc0RBQVNBLENBQUNrRCxzQkFBc0IsQ0FBQztnQkFBQ0MsT0FBT0MsSUFBSSxDQUFDO2FBQWlCLEVBQUV0QztZQUNqRyxNQUFNdUMsS0FBSyxNQUFNM0IsUUFBUTRCLE9BQU8sQ0FDN0JDLFVBQVUsQ0FBQ04sTUFBTSxJQUFJOUMsaURBQVMsQ0FBQ3NELFNBQVMzQixPQUN4QzRCLFFBQVEsQ0FBQztnQkFDUkMsY0FBY1g7Z0JBQ2RZLFdBQVczQyxPQUFPOEIsU0FBUztnQkFDM0JjLGtCQUFrQixJQUFJN0Qsc0RBQVNBLENBQUM0QjtnQkFDaENrQyxxQkFBcUI3QyxPQUFPOEIsU0FBUztnQkFDckNnQixjQUFjOUMsT0FBTzhCLFNBQVM7Z0JBQzlCaUIsZUFBZS9ELDBEQUFhQSxDQUFDZ0UsU0FBUztZQUN4QyxHQUNDQyxHQUFHO1lBQ04vQyxVQUFVO1lBQ1ZFLFFBQVEsQ0FBQyxlQUFlLEVBQUVnQyxHQUFHLENBQUM7UUFDaEMsRUFBRSxPQUFPYyxHQUFRO1lBQ2ZoRCxVQUFVO1lBQ1ZFLFFBQVE4QyxFQUFFQyxPQUFPLElBQUlDLE9BQU9GO1FBQzlCO0lBQ0YsR0FBRztRQUFDekM7UUFBU0g7UUFBVU47UUFBUVc7UUFBU0U7S0FBSTtJQUU1QyxNQUFNd0MsYUFBYTNFLGtEQUFXQSxDQUFDO1FBQzdCLElBQUksQ0FBQytCLFdBQVcsQ0FBQ0gsWUFBWSxDQUFDTixRQUFROEIsV0FBVztRQUNqRDVCLFVBQVU7UUFDVixJQUFJO1lBQ0YsTUFBTW9ELE9BQU94RSxvREFBT0EsQ0FBQ3lFLFFBQVE7WUFDN0IsTUFBTSxDQUFDQyxpQkFBaUJ4QixLQUFLLEdBQUdqRCxzREFBU0EsQ0FBQ2tELHNCQUFzQixDQUFDO2dCQUFDQyxPQUFPQyxJQUFJLENBQUM7Z0JBQWtCbUIsS0FBS3hCLFNBQVMsQ0FBQzJCLFFBQVE7YUFBRyxFQUFFNUQ7WUFDNUgsTUFBTTZELGVBQWUsTUFBTWhFLDRFQUF5QkEsQ0FBQzRELEtBQUt4QixTQUFTLEVBQUU5QixPQUFPOEIsU0FBUztZQUVyRixNQUFNTSxLQUFLLE1BQU0zQixRQUFRNEIsT0FBTyxDQUM3QnNCLE9BQU8sQ0FBQzNCLE1BQU1qQixVQUFVRSxZQUFZRSxTQUFTRSxlQUFlRSxrQkFBa0JFLGlCQUM5RWdCLFFBQVEsQ0FBQztnQkFDUkMsY0FBYzNELHNEQUFTQSxDQUFDa0Qsc0JBQXNCLENBQUM7b0JBQUNDLE9BQU9DLElBQUksQ0FBQztpQkFBaUIsRUFBRXRDLFdBQVcsQ0FBQyxFQUFFO2dCQUM3RitELGNBQWNKO2dCQUNkRixNQUFNQSxLQUFLeEIsU0FBUztnQkFDcEI0QjtnQkFDQUcsT0FBTzdELE9BQU84QixTQUFTO2dCQUN2QmdDLFdBQVc5RCxPQUFPOEIsU0FBUztnQkFDM0JlLHFCQUFxQjdDLE9BQU84QixTQUFTO2dCQUNyQ2lDLGNBQWNuRSwrREFBZ0JBO2dCQUM5Qm9FLHdCQUF3QnJFLDBFQUEyQkE7Z0JBQ25Eb0QsZUFBZS9ELDBEQUFhQSxDQUFDZ0UsU0FBUztZQUN4QyxHQUNDaUIsT0FBTyxDQUFDO2dCQUFDWDthQUFLLEVBQ2RMLEdBQUc7WUFFTnJCLFlBQVkwQixLQUFLeEIsU0FBUztZQUMxQjVCLFVBQVU7WUFDVkUsUUFBUSxDQUFDLFNBQVMsRUFBRWdDLEdBQUcsUUFBUSxFQUFFa0IsS0FBS3hCLFNBQVMsQ0FBQ29DLFFBQVEsR0FBRyxDQUFDO1FBQzlELEVBQUUsT0FBT2hCLEdBQVE7WUFDZmhELFVBQVU7WUFDVkUsUUFBUThDLEVBQUVDLE9BQU8sSUFBSUMsT0FBT0Y7UUFDOUI7SUFDRixHQUFHO1FBQUN6QztRQUFTSDtRQUFVTjtRQUFRZTtRQUFVRTtRQUFZRTtRQUFTRTtRQUFlRTtRQUFrQkU7S0FBZ0I7SUFFL0csTUFBTTBDLGlCQUFpQnpGLGtEQUFXQSxDQUFDO1FBQ2pDLElBQUksQ0FBQytCLFdBQVcsQ0FBQ0gsWUFBWSxDQUFDTixRQUFROEIsYUFBYSxDQUFDSCxVQUFVO1FBQzlEekIsVUFBVTtRQUNWLElBQUk7WUFDRixNQUFNa0UsYUFBYSxDQUFDLFNBQVMsRUFBRUMsS0FBS0MsR0FBRyxHQUFHLENBQUM7WUFDM0MsTUFBTSxDQUFDZCxnQkFBZ0IsR0FBR3pFLHNEQUFTQSxDQUFDa0Qsc0JBQXNCLENBQUM7Z0JBQUNDLE9BQU9DLElBQUksQ0FBQztnQkFBa0JSLFNBQVM4QixRQUFRO2FBQUcsRUFBRTVEO1lBQ2hILE1BQU0sQ0FBQzBFLHVCQUF1QkMsVUFBVSxHQUFHekYsc0RBQVNBLENBQUNrRCxzQkFBc0IsQ0FBQztnQkFBQ0MsT0FBT0MsSUFBSSxDQUFDO2dCQUF5QkQsT0FBT0MsSUFBSSxDQUFDaUM7YUFBWSxFQUFFdkU7WUFDNUksTUFBTTRFLG9CQUFvQixNQUFNL0UsNEVBQXlCQSxDQUFDaUMsVUFBVTNCLE9BQU84QixTQUFTO1lBRXBGLE1BQU1NLEtBQUssTUFBTTNCLFFBQVE0QixPQUFPLENBQzdCcUMsMEJBQTBCLENBQUNOLFlBQVksV0FBVyxTQUFTSSxXQUMzRC9CLFFBQVEsQ0FBQztnQkFDUkMsY0FBYzNELHNEQUFTQSxDQUFDa0Qsc0JBQXNCLENBQUM7b0JBQUNDLE9BQU9DLElBQUksQ0FBQztpQkFBaUIsRUFBRXRDLFdBQVcsQ0FBQyxFQUFFO2dCQUM3RitELGNBQWNKO2dCQUNkbUIsb0JBQW9CSjtnQkFDcEJLLFNBQVNqRDtnQkFDVDhDO2dCQUNBSSxPQUFPN0UsT0FBTzhCLFNBQVM7Z0JBQ3ZCK0IsT0FBTzdELE9BQU84QixTQUFTO2dCQUN2QmMsa0JBQWtCN0Qsc0RBQVNBLENBQUMrRixPQUFPO2dCQUNuQ2YsY0FBY25FLCtEQUFnQkE7Z0JBQzlCbUQsZUFBZS9ELDBEQUFhQSxDQUFDZ0UsU0FBUztZQUN4QyxHQUNDQyxHQUFHO1lBRU4vQyxVQUFVO1lBQ1ZFLFFBQVEsQ0FBQyxhQUFhLEVBQUVnQyxHQUFHLGVBQWUsRUFBRWdDLFdBQVcsQ0FBQztRQUMxRCxFQUFFLE9BQU9sQixHQUFRO1lBQ2ZoRCxVQUFVO1lBQ1ZFLFFBQVE4QyxFQUFFQyxPQUFPLElBQUlDLE9BQU9GO1FBQzlCO0lBQ0YsR0FBRztRQUFDekM7UUFBU0g7UUFBVU47UUFBUTJCO0tBQVM7SUFFeEMscUJBQ0UsOERBQUNvRDtRQUFJQyxPQUFPO1lBQUVDLFlBQVk7WUFBY0MsVUFBVTtZQUFLQyxRQUFRO1FBQVk7OzBCQUN6RSw4REFBQ0o7Z0JBQUlDLE9BQU87b0JBQUVJLFNBQVM7b0JBQVFDLFlBQVk7b0JBQVVDLEtBQUs7Z0JBQUc7O2tDQUMzRCw4REFBQ0M7d0JBQUdQLE9BQU87NEJBQUVHLFFBQVE7d0JBQUU7a0NBQUc7Ozs7OztrQ0FDMUIsOERBQUM1Riw4RUFBaUJBOzs7Ozs7Ozs7OzswQkFFcEIsOERBQUNpRzs7b0JBQUU7a0NBQVksOERBQUNDO2tDQUFNNUYsV0FBV3FFLFFBQVE7Ozs7Ozs7Ozs7OzswQkFFekMsOERBQUN3QjtnQkFBUVYsT0FBTztvQkFBRVcsUUFBUTtvQkFBa0JDLFNBQVM7b0JBQUlDLGNBQWM7b0JBQUdDLGNBQWM7Z0JBQUc7O2tDQUN6Riw4REFBQ0M7a0NBQUc7Ozs7OztrQ0FDSiw4REFBQ2hCO3dCQUFJQyxPQUFPOzRCQUFFSSxTQUFTOzRCQUFRRSxLQUFLOzRCQUFHVSxVQUFVO3dCQUFPOzswQ0FDdEQsOERBQUNDO2dDQUFNakIsT0FBTztvQ0FBRWtCLE1BQU07Z0NBQUU7Z0NBQUdDLE9BQU94RjtnQ0FBU3lGLFVBQVVsRCxDQUFBQSxJQUFLdEMsV0FBV3NDLEVBQUVtRCxNQUFNLENBQUNGLEtBQUs7Z0NBQUdHLGFBQVk7Ozs7OzswQ0FDbEcsOERBQUNMO2dDQUFNakIsT0FBTztvQ0FBRXVCLE9BQU87Z0NBQUk7Z0NBQUdKLE9BQU90RjtnQ0FBS3VGLFVBQVVsRCxDQUFBQSxJQUFLcEMsT0FBT29DLEVBQUVtRCxNQUFNLENBQUNGLEtBQUs7Z0NBQUdHLGFBQVk7Ozs7OzswQ0FDN0YsOERBQUNFO2dDQUFPQyxTQUFTNUU7Z0NBQWtCNkUsVUFBVSxDQUFDMUc7MENBQVE7Ozs7Ozs7Ozs7Ozs7Ozs7OzswQkFJMUQsOERBQUMwRjtnQkFBUVYsT0FBTztvQkFBRVcsUUFBUTtvQkFBa0JDLFNBQVM7b0JBQUlDLGNBQWM7b0JBQUdDLGNBQWM7Z0JBQUc7O2tDQUN6Riw4REFBQ0M7a0NBQUc7Ozs7OztrQ0FDSiw4REFBQ2hCO3dCQUFJQyxPQUFPOzRCQUFFSSxTQUFTOzRCQUFRdUIscUJBQXFCOzRCQUFrQnJCLEtBQUs7d0JBQUU7OzBDQUMzRSw4REFBQ1c7Z0NBQU1FLE9BQU9wRjtnQ0FBVXFGLFVBQVVsRCxDQUFBQSxJQUFLbEMsWUFBWWtDLEVBQUVtRCxNQUFNLENBQUNGLEtBQUs7Z0NBQUdHLGFBQVk7Ozs7OzswQ0FDaEYsOERBQUNMO2dDQUFNRSxPQUFPbEY7Z0NBQVltRixVQUFVbEQsQ0FBQUEsSUFBS2hDLGNBQWNnQyxFQUFFbUQsTUFBTSxDQUFDRixLQUFLO2dDQUFHRyxhQUFZOzs7Ozs7MENBQ3BGLDhEQUFDTDtnQ0FBTUUsT0FBT2hGO2dDQUFTaUYsVUFBVWxELENBQUFBLElBQUs5QixXQUFXOEIsRUFBRW1ELE1BQU0sQ0FBQ0YsS0FBSztnQ0FBR0csYUFBWTs7Ozs7OzBDQUM5RSw4REFBQ0w7Z0NBQU1FLE9BQU85RTtnQ0FBZStFLFVBQVVsRCxDQUFBQSxJQUFLNUIsaUJBQWlCNEIsRUFBRW1ELE1BQU0sQ0FBQ0YsS0FBSztnQ0FBR0csYUFBWTs7Ozs7OzBDQUMxRiw4REFBQ0w7Z0NBQU1FLE9BQU81RTtnQ0FBa0I2RSxVQUFVbEQsQ0FBQUEsSUFBSzFCLG9CQUFvQjBCLEVBQUVtRCxNQUFNLENBQUNGLEtBQUs7Z0NBQUdHLGFBQVk7Ozs7OzswQ0FDaEcsOERBQUNMO2dDQUFNRSxPQUFPMUU7Z0NBQWlCMkUsVUFBVWxELENBQUFBLElBQUt4QixtQkFBbUJ3QixFQUFFbUQsTUFBTSxDQUFDRixLQUFLO2dDQUFHRyxhQUFZOzs7Ozs7Ozs7Ozs7a0NBRWhHLDhEQUFDdkI7d0JBQUlDLE9BQU87NEJBQUU0QixXQUFXO3dCQUFFO2tDQUN6Qiw0RUFBQ0o7NEJBQU9DLFNBQVNwRDs0QkFBWXFELFVBQVUsQ0FBQzFHO3NDQUFROzs7Ozs7Ozs7Ozs7Ozs7OzswQkFJcEQsOERBQUMwRjtnQkFBUVYsT0FBTztvQkFBRVcsUUFBUTtvQkFBa0JDLFNBQVM7b0JBQUlDLGNBQWM7b0JBQUdDLGNBQWM7Z0JBQUc7O2tDQUN6Riw4REFBQ0M7a0NBQUc7Ozs7OztrQ0FDSiw4REFBQ1M7d0JBQU9DLFNBQVN0Qzt3QkFBZ0J1QyxVQUFVLENBQUMxRyxVQUFVLENBQUMyQjtrQ0FBVTs7Ozs7Ozs7Ozs7OzBCQUduRSw4REFBQytEO2dCQUFRVixPQUFPO29CQUFFVyxRQUFRO29CQUFrQkMsU0FBUztvQkFBSUMsY0FBYztnQkFBRTs7a0NBQ3ZFLDhEQUFDRTtrQ0FBRzs7Ozs7O2tDQUNKLDhEQUFDUDtrQ0FBR3ZGOzs7Ozs7a0NBQ0osOERBQUM0Rzt3QkFBSTdCLE9BQU87NEJBQUU4QixZQUFZO3dCQUFXO2tDQUFJM0c7Ozs7Ozs7Ozs7Ozs7Ozs7OztBQUlqRDtBQUVlLFNBQVM0RztJQUN0QixNQUFNQyxXQUFXO0lBQ2pCLE1BQU1DLFVBQVV0SSw4Q0FBT0EsQ0FBQyxJQUFNO1lBQUMsSUFBSWEsZ0ZBQW9CQTtZQUFJLElBQUlDLGlGQUFxQkE7U0FBRyxFQUFFLEVBQUU7SUFFM0YscUJBQ0UsOERBQUNOLDRFQUFrQkE7UUFBQzZILFVBQVVBO2tCQUM1Qiw0RUFBQzVILHdFQUFjQTtZQUFDNkgsU0FBU0E7WUFBU0MsV0FBVztzQkFDM0MsNEVBQUM1SCxnRkFBbUJBOzBCQUNsQiw0RUFBQ1M7Ozs7Ozs7Ozs7Ozs7Ozs7Ozs7O0FBS1giLCJzb3VyY2VzIjpbIndlYnBhY2s6Ly91bml2ZXJzYWwtbmZ0LXdlYi8uL3BhZ2VzL2luZGV4LnRzeD8wN2ZmIl0sInNvdXJjZXNDb250ZW50IjpbImltcG9ydCB7IHVzZUNhbGxiYWNrLCB1c2VNZW1vLCB1c2VTdGF0ZSB9IGZyb20gJ3JlYWN0J1xuaW1wb3J0IHsgY2x1c3RlckFwaVVybCwgQ29ubmVjdGlvbiwgS2V5cGFpciwgUHVibGljS2V5LCBTeXN0ZW1Qcm9ncmFtIH0gZnJvbSAnQHNvbGFuYS93ZWIzLmpzJ1xuaW1wb3J0IGlkbCBmcm9tICcuLi9pZGwvdW5pdmVyc2FsX25mdC5qc29uJ1xuaW1wb3J0ICogYXMgYW5jaG9yIGZyb20gJ0Bjb3JhbC14eXovYW5jaG9yJ1xuaW1wb3J0IHsgV2FsbGV0QWRhcHRlck5ldHdvcmsgfSBmcm9tICdAc29sYW5hL3dhbGxldC1hZGFwdGVyLWJhc2UnXG5pbXBvcnQgeyBDb25uZWN0aW9uUHJvdmlkZXIsIFdhbGxldFByb3ZpZGVyLCB1c2VBbmNob3JXYWxsZXQgfSBmcm9tICdAc29sYW5hL3dhbGxldC1hZGFwdGVyLXJlYWN0J1xuaW1wb3J0IHsgV2FsbGV0TW9kYWxQcm92aWRlciwgV2FsbGV0TXVsdGlCdXR0b24gfSBmcm9tICdAc29sYW5hL3dhbGxldC1hZGFwdGVyLXJlYWN0LXVpJ1xuaW1wb3J0IHsgUGhhbnRvbVdhbGxldEFkYXB0ZXIsIFNvbGZsYXJlV2FsbGV0QWRhcHRlciB9IGZyb20gJ0Bzb2xhbmEvd2FsbGV0LWFkYXB0ZXItd2FsbGV0cydcbmltcG9ydCB7IGdldEFzc29jaWF0ZWRUb2tlbkFkZHJlc3MsIEFTU09DSUFURURfVE9LRU5fUFJPR1JBTV9JRCwgVE9LRU5fUFJPR1JBTV9JRCB9IGZyb20gJ0Bzb2xhbmEvc3BsLXRva2VuJ1xuXG5jb25zdCBQUk9HUkFNX0lEID0gbmV3IFB1YmxpY0tleSgoaWRsIGFzIGFueSkuYWRkcmVzcylcblxuZnVuY3Rpb24gQXBwSW5uZXIoKSB7XG4gIGNvbnN0IHdhbGxldCA9IHVzZUFuY2hvcldhbGxldCgpXG4gIGNvbnN0IFtzdGF0dXMsIHNldFN0YXR1c10gPSB1c2VTdGF0ZTxzdHJpbmc+KCdSZWFkeScpXG4gIGNvbnN0IFtsb2dzLCBzZXRMb2dzXSA9IHVzZVN0YXRlPHN0cmluZz4oJycpXG4gIGNvbnN0IGNvbm5lY3Rpb24gPSB1c2VNZW1vKCgpID0+IG5ldyBDb25uZWN0aW9uKCdodHRwOi8vMTI3LjAuMC4xOjg4OTknLCAnY29uZmlybWVkJyksIFtdKVxuXG4gIGNvbnN0IHByb3ZpZGVyID0gdXNlTWVtbygoKSA9PiAod2FsbGV0ID8gbmV3IGFuY2hvci5BbmNob3JQcm92aWRlcihjb25uZWN0aW9uLCB3YWxsZXQsIHsgY29tbWl0bWVudDogJ2NvbmZpcm1lZCcgfSkgOiBudWxsKSwgW2Nvbm5lY3Rpb24sIHdhbGxldF0pXG4gIGNvbnN0IHByb2dyYW0gPSB1c2VNZW1vKCgpID0+IChwcm92aWRlciA/IG5ldyBhbmNob3IuUHJvZ3JhbShpZGwgYXMgYW55LCBQUk9HUkFNX0lELCBwcm92aWRlcikgOiBudWxsKSwgW3Byb3ZpZGVyXSlcblxuICBjb25zdCBbZ2F0ZXdheSwgc2V0R2F0ZXdheV0gPSB1c2VTdGF0ZTxzdHJpbmc+KCcxMTExMTExMTExMTExMTExMTExMTExMTExMTExMTExMScpXG4gIGNvbnN0IFtmZWUsIHNldEZlZV0gPSB1c2VTdGF0ZTxzdHJpbmc+KCcxMDAwMCcpXG5cbiAgY29uc3QgW21pbnROYW1lLCBzZXRNaW50TmFtZV0gPSB1c2VTdGF0ZSgnWlVORlQnKVxuICBjb25zdCBbbWludFN5bWJvbCwgc2V0TWludFN5bWJvbF0gPSB1c2VTdGF0ZSgnWlVORlQnKVxuICBjb25zdCBbbWludFVyaSwgc2V0TWludFVyaV0gPSB1c2VTdGF0ZSgnaHR0cHM6Ly9leGFtcGxlLmNvbS8xLmpzb24nKVxuICBjb25zdCBbb3JpZ2luYWxDaGFpbiwgc2V0T3JpZ2luYWxDaGFpbl0gPSB1c2VTdGF0ZSgnc29sYW5hJylcbiAgY29uc3QgW29yaWdpbmFsQ29udHJhY3QsIHNldE9yaWdpbmFsQ29udHJhY3RdID0gdXNlU3RhdGUoJ25hdGl2ZScpXG4gIGNvbnN0IFtvcmlnaW5hbFRva2VuSWQsIHNldE9yaWdpbmFsVG9rZW5JZF0gPSB1c2VTdGF0ZSgnMScpXG5cbiAgY29uc3QgW2xhc3RNaW50LCBzZXRMYXN0TWludF0gPSB1c2VTdGF0ZTxQdWJsaWNLZXkgfCBudWxsPihudWxsKVxuXG4gIGNvbnN0IGhhbmRsZUluaXRpYWxpemUgPSB1c2VDYWxsYmFjayhhc3luYyAoKSA9PiB7XG4gICAgaWYgKCFwcm9ncmFtIHx8ICFwcm92aWRlciB8fCAhd2FsbGV0Py5wdWJsaWNLZXkpIHJldHVyblxuICAgIHNldFN0YXR1cygnSW5pdGlhbGl6aW5nLi4uJylcbiAgICB0cnkge1xuICAgICAgY29uc3QgW2dsb2JhbENvbmZpZ1BkYSwgYnVtcF0gPSBQdWJsaWNLZXkuZmluZFByb2dyYW1BZGRyZXNzU3luYyhbQnVmZmVyLmZyb20oJ2dsb2JhbF9jb25maWcnKV0sIFBST0dSQU1fSUQpXG4gICAgICBjb25zdCB0eCA9IGF3YWl0IHByb2dyYW0ubWV0aG9kc1xuICAgICAgICAuaW5pdGlhbGl6ZShidW1wLCBuZXcgYW5jaG9yLkJOKHBhcnNlSW50KGZlZSkpKVxuICAgICAgICAuYWNjb3VudHMoe1xuICAgICAgICAgIGdsb2JhbENvbmZpZzogZ2xvYmFsQ29uZmlnUGRhLFxuICAgICAgICAgIGF1dGhvcml0eTogd2FsbGV0LnB1YmxpY0tleSxcbiAgICAgICAgICB6ZXRhY2hhaW5HYXRld2F5OiBuZXcgUHVibGljS2V5KGdhdGV3YXkpLFxuICAgICAgICAgIGNvbGxlY3Rpb25BdXRob3JpdHk6IHdhbGxldC5wdWJsaWNLZXksXG4gICAgICAgICAgZmVlUmVjaXBpZW50OiB3YWxsZXQucHVibGljS2V5LFxuICAgICAgICAgIHN5c3RlbVByb2dyYW06IFN5c3RlbVByb2dyYW0ucHJvZ3JhbUlkXG4gICAgICAgIH0pXG4gICAgICAgIC5ycGMoKVxuICAgICAgc2V0U3RhdHVzKCdJbml0aWFsaXplZCDinIUnKVxuICAgICAgc2V0TG9ncyhgSW5pdGlhbGl6ZSB0eDogJHt0eH1gKVxuICAgIH0gY2F0Y2ggKGU6IGFueSkge1xuICAgICAgc2V0U3RhdHVzKCdJbml0IGZhaWxlZCcpXG4gICAgICBzZXRMb2dzKGUubWVzc2FnZSB8fCBTdHJpbmcoZSkpXG4gICAgfVxuICB9LCBbcHJvZ3JhbSwgcHJvdmlkZXIsIHdhbGxldCwgZ2F0ZXdheSwgZmVlXSlcblxuICBjb25zdCBoYW5kbGVNaW50ID0gdXNlQ2FsbGJhY2soYXN5bmMgKCkgPT4ge1xuICAgIGlmICghcHJvZ3JhbSB8fCAhcHJvdmlkZXIgfHwgIXdhbGxldD8ucHVibGljS2V5KSByZXR1cm5cbiAgICBzZXRTdGF0dXMoJ01pbnRpbmcuLi4nKVxuICAgIHRyeSB7XG4gICAgICBjb25zdCBtaW50ID0gS2V5cGFpci5nZW5lcmF0ZSgpXG4gICAgICBjb25zdCBbdW5pdmVyc2FsTmZ0UGRhLCBidW1wXSA9IFB1YmxpY0tleS5maW5kUHJvZ3JhbUFkZHJlc3NTeW5jKFtCdWZmZXIuZnJvbSgndW5pdmVyc2FsX25mdCcpLCBtaW50LnB1YmxpY0tleS50b0J1ZmZlcigpXSwgUFJPR1JBTV9JRClcbiAgICAgIGNvbnN0IHRva2VuQWNjb3VudCA9IGF3YWl0IGdldEFzc29jaWF0ZWRUb2tlbkFkZHJlc3MobWludC5wdWJsaWNLZXksIHdhbGxldC5wdWJsaWNLZXkpXG5cbiAgICAgIGNvbnN0IHR4ID0gYXdhaXQgcHJvZ3JhbS5tZXRob2RzXG4gICAgICAgIC5taW50TmZ0KGJ1bXAsIG1pbnROYW1lLCBtaW50U3ltYm9sLCBtaW50VXJpLCBvcmlnaW5hbENoYWluLCBvcmlnaW5hbENvbnRyYWN0LCBvcmlnaW5hbFRva2VuSWQpXG4gICAgICAgIC5hY2NvdW50cyh7XG4gICAgICAgICAgZ2xvYmFsQ29uZmlnOiBQdWJsaWNLZXkuZmluZFByb2dyYW1BZGRyZXNzU3luYyhbQnVmZmVyLmZyb20oJ2dsb2JhbF9jb25maWcnKV0sIFBST0dSQU1fSUQpWzBdLFxuICAgICAgICAgIHVuaXZlcnNhbE5mdDogdW5pdmVyc2FsTmZ0UGRhLFxuICAgICAgICAgIG1pbnQ6IG1pbnQucHVibGljS2V5LFxuICAgICAgICAgIHRva2VuQWNjb3VudCxcbiAgICAgICAgICBwYXllcjogd2FsbGV0LnB1YmxpY0tleSxcbiAgICAgICAgICByZWNpcGllbnQ6IHdhbGxldC5wdWJsaWNLZXksXG4gICAgICAgICAgY29sbGVjdGlvbkF1dGhvcml0eTogd2FsbGV0LnB1YmxpY0tleSxcbiAgICAgICAgICB0b2tlblByb2dyYW06IFRPS0VOX1BST0dSQU1fSUQsXG4gICAgICAgICAgYXNzb2NpYXRlZFRva2VuUHJvZ3JhbTogQVNTT0NJQVRFRF9UT0tFTl9QUk9HUkFNX0lELFxuICAgICAgICAgIHN5c3RlbVByb2dyYW06IFN5c3RlbVByb2dyYW0ucHJvZ3JhbUlkXG4gICAgICAgIH0pXG4gICAgICAgIC5zaWduZXJzKFttaW50XSlcbiAgICAgICAgLnJwYygpXG5cbiAgICAgIHNldExhc3RNaW50KG1pbnQucHVibGljS2V5KVxuICAgICAgc2V0U3RhdHVzKCdNaW50ZWQg4pyFJylcbiAgICAgIHNldExvZ3MoYE1pbnQgdHg6ICR7dHh9XFxuTWludDogJHttaW50LnB1YmxpY0tleS50b0Jhc2U1OCgpfWApXG4gICAgfSBjYXRjaCAoZTogYW55KSB7XG4gICAgICBzZXRTdGF0dXMoJ01pbnQgZmFpbGVkJylcbiAgICAgIHNldExvZ3MoZS5tZXNzYWdlIHx8IFN0cmluZyhlKSlcbiAgICB9XG4gIH0sIFtwcm9ncmFtLCBwcm92aWRlciwgd2FsbGV0LCBtaW50TmFtZSwgbWludFN5bWJvbCwgbWludFVyaSwgb3JpZ2luYWxDaGFpbiwgb3JpZ2luYWxDb250cmFjdCwgb3JpZ2luYWxUb2tlbklkXSlcblxuICBjb25zdCBoYW5kbGVJbml0aWF0ZSA9IHVzZUNhbGxiYWNrKGFzeW5jICgpID0+IHtcbiAgICBpZiAoIXByb2dyYW0gfHwgIXByb3ZpZGVyIHx8ICF3YWxsZXQ/LnB1YmxpY0tleSB8fCAhbGFzdE1pbnQpIHJldHVyblxuICAgIHNldFN0YXR1cygnSW5pdGlhdGluZyBjcm9zcy1jaGFpbiB0cmFuc2Zlci4uLicpXG4gICAgdHJ5IHtcbiAgICAgIGNvbnN0IHRyYW5zZmVySWQgPSBgdHJhbnNmZXJfJHtEYXRlLm5vdygpfWBcbiAgICAgIGNvbnN0IFt1bml2ZXJzYWxOZnRQZGFdID0gUHVibGljS2V5LmZpbmRQcm9ncmFtQWRkcmVzc1N5bmMoW0J1ZmZlci5mcm9tKCd1bml2ZXJzYWxfbmZ0JyksIGxhc3RNaW50LnRvQnVmZmVyKCldLCBQUk9HUkFNX0lEKVxuICAgICAgY29uc3QgW2Nyb3NzQ2hhaW5UcmFuc2ZlclBkYSwgY3Jvc3NCdW1wXSA9IFB1YmxpY0tleS5maW5kUHJvZ3JhbUFkZHJlc3NTeW5jKFtCdWZmZXIuZnJvbSgnY3Jvc3NfY2hhaW5fdHJhbnNmZXInKSwgQnVmZmVyLmZyb20odHJhbnNmZXJJZCldLCBQUk9HUkFNX0lEKVxuICAgICAgY29uc3Qgb3duZXJUb2tlbkFjY291bnQgPSBhd2FpdCBnZXRBc3NvY2lhdGVkVG9rZW5BZGRyZXNzKGxhc3RNaW50LCB3YWxsZXQucHVibGljS2V5KVxuXG4gICAgICBjb25zdCB0eCA9IGF3YWl0IHByb2dyYW0ubWV0aG9kc1xuICAgICAgICAuaW5pdGlhdGVDcm9zc0NoYWluVHJhbnNmZXIodHJhbnNmZXJJZCwgJ3BvbHlnb24nLCAnMHhhYmMnLCBjcm9zc0J1bXApXG4gICAgICAgIC5hY2NvdW50cyh7XG4gICAgICAgICAgZ2xvYmFsQ29uZmlnOiBQdWJsaWNLZXkuZmluZFByb2dyYW1BZGRyZXNzU3luYyhbQnVmZmVyLmZyb20oJ2dsb2JhbF9jb25maWcnKV0sIFBST0dSQU1fSUQpWzBdLFxuICAgICAgICAgIHVuaXZlcnNhbE5mdDogdW5pdmVyc2FsTmZ0UGRhLFxuICAgICAgICAgIGNyb3NzQ2hhaW5UcmFuc2ZlcjogY3Jvc3NDaGFpblRyYW5zZmVyUGRhLFxuICAgICAgICAgIG5mdE1pbnQ6IGxhc3RNaW50LFxuICAgICAgICAgIG93bmVyVG9rZW5BY2NvdW50LFxuICAgICAgICAgIG93bmVyOiB3YWxsZXQucHVibGljS2V5LFxuICAgICAgICAgIHBheWVyOiB3YWxsZXQucHVibGljS2V5LFxuICAgICAgICAgIHpldGFjaGFpbkdhdGV3YXk6IFB1YmxpY0tleS5kZWZhdWx0LFxuICAgICAgICAgIHRva2VuUHJvZ3JhbTogVE9LRU5fUFJPR1JBTV9JRCxcbiAgICAgICAgICBzeXN0ZW1Qcm9ncmFtOiBTeXN0ZW1Qcm9ncmFtLnByb2dyYW1JZFxuICAgICAgICB9KVxuICAgICAgICAucnBjKClcblxuICAgICAgc2V0U3RhdHVzKCdUcmFuc2ZlciBpbml0aWF0ZWQg4pyFJylcbiAgICAgIHNldExvZ3MoYEluaXRpYXRlIHR4OiAke3R4fVxcblRyYW5zZmVyIElEOiAke3RyYW5zZmVySWR9YClcbiAgICB9IGNhdGNoIChlOiBhbnkpIHtcbiAgICAgIHNldFN0YXR1cygnSW5pdGlhdGUgZmFpbGVkJylcbiAgICAgIHNldExvZ3MoZS5tZXNzYWdlIHx8IFN0cmluZyhlKSlcbiAgICB9XG4gIH0sIFtwcm9ncmFtLCBwcm92aWRlciwgd2FsbGV0LCBsYXN0TWludF0pXG5cbiAgcmV0dXJuIChcbiAgICA8ZGl2IHN0eWxlPXt7IGZvbnRGYW1pbHk6ICdzYW5zLXNlcmlmJywgbWF4V2lkdGg6IDkwMCwgbWFyZ2luOiAnNDBweCBhdXRvJyB9fT5cbiAgICAgIDxkaXYgc3R5bGU9e3sgZGlzcGxheTogJ2ZsZXgnLCBhbGlnbkl0ZW1zOiAnY2VudGVyJywgZ2FwOiAxMiB9fT5cbiAgICAgICAgPGgxIHN0eWxlPXt7IG1hcmdpbjogMCB9fT5aZXRhQ2hhaW4gVW5pdmVyc2FsIE5GVCBEZW1vPC9oMT5cbiAgICAgICAgPFdhbGxldE11bHRpQnV0dG9uIC8+XG4gICAgICA8L2Rpdj5cbiAgICAgIDxwPlByb2dyYW0gSUQ6IDxjb2RlPntQUk9HUkFNX0lELnRvQmFzZTU4KCl9PC9jb2RlPjwvcD5cblxuICAgICAgPHNlY3Rpb24gc3R5bGU9e3sgYm9yZGVyOiAnMXB4IHNvbGlkICNkZGQnLCBwYWRkaW5nOiAxNiwgYm9yZGVyUmFkaXVzOiA4LCBtYXJnaW5Cb3R0b206IDE2IH19PlxuICAgICAgICA8aDM+MSkgSW5pdGlhbGl6ZTwvaDM+XG4gICAgICAgIDxkaXYgc3R5bGU9e3sgZGlzcGxheTogJ2ZsZXgnLCBnYXA6IDgsIGZsZXhXcmFwOiAnd3JhcCcgfX0+XG4gICAgICAgICAgPGlucHV0IHN0eWxlPXt7IGZsZXg6IDEgfX0gdmFsdWU9e2dhdGV3YXl9IG9uQ2hhbmdlPXtlID0+IHNldEdhdGV3YXkoZS50YXJnZXQudmFsdWUpfSBwbGFjZWhvbGRlcj1cIkdhdGV3YXkgUHJvZ3JhbSBJRFwiIC8+XG4gICAgICAgICAgPGlucHV0IHN0eWxlPXt7IHdpZHRoOiAxODAgfX0gdmFsdWU9e2ZlZX0gb25DaGFuZ2U9e2UgPT4gc2V0RmVlKGUudGFyZ2V0LnZhbHVlKX0gcGxhY2Vob2xkZXI9XCJGZWUgKGxhbXBvcnRzKVwiIC8+XG4gICAgICAgICAgPGJ1dHRvbiBvbkNsaWNrPXtoYW5kbGVJbml0aWFsaXplfSBkaXNhYmxlZD17IXdhbGxldH0+SW5pdGlhbGl6ZTwvYnV0dG9uPlxuICAgICAgICA8L2Rpdj5cbiAgICAgIDwvc2VjdGlvbj5cblxuICAgICAgPHNlY3Rpb24gc3R5bGU9e3sgYm9yZGVyOiAnMXB4IHNvbGlkICNkZGQnLCBwYWRkaW5nOiAxNiwgYm9yZGVyUmFkaXVzOiA4LCBtYXJnaW5Cb3R0b206IDE2IH19PlxuICAgICAgICA8aDM+MikgTWludCBORlQ8L2gzPlxuICAgICAgICA8ZGl2IHN0eWxlPXt7IGRpc3BsYXk6ICdncmlkJywgZ3JpZFRlbXBsYXRlQ29sdW1uczogJ3JlcGVhdCgzLCAxZnIpJywgZ2FwOiA4IH19PlxuICAgICAgICAgIDxpbnB1dCB2YWx1ZT17bWludE5hbWV9IG9uQ2hhbmdlPXtlID0+IHNldE1pbnROYW1lKGUudGFyZ2V0LnZhbHVlKX0gcGxhY2Vob2xkZXI9XCJOYW1lXCIgLz5cbiAgICAgICAgICA8aW5wdXQgdmFsdWU9e21pbnRTeW1ib2x9IG9uQ2hhbmdlPXtlID0+IHNldE1pbnRTeW1ib2woZS50YXJnZXQudmFsdWUpfSBwbGFjZWhvbGRlcj1cIlN5bWJvbFwiIC8+XG4gICAgICAgICAgPGlucHV0IHZhbHVlPXttaW50VXJpfSBvbkNoYW5nZT17ZSA9PiBzZXRNaW50VXJpKGUudGFyZ2V0LnZhbHVlKX0gcGxhY2Vob2xkZXI9XCJVUklcIiAvPlxuICAgICAgICAgIDxpbnB1dCB2YWx1ZT17b3JpZ2luYWxDaGFpbn0gb25DaGFuZ2U9e2UgPT4gc2V0T3JpZ2luYWxDaGFpbihlLnRhcmdldC52YWx1ZSl9IHBsYWNlaG9sZGVyPVwiT3JpZ2luYWwgQ2hhaW5cIiAvPlxuICAgICAgICAgIDxpbnB1dCB2YWx1ZT17b3JpZ2luYWxDb250cmFjdH0gb25DaGFuZ2U9e2UgPT4gc2V0T3JpZ2luYWxDb250cmFjdChlLnRhcmdldC52YWx1ZSl9IHBsYWNlaG9sZGVyPVwiT3JpZ2luYWwgQ29udHJhY3RcIiAvPlxuICAgICAgICAgIDxpbnB1dCB2YWx1ZT17b3JpZ2luYWxUb2tlbklkfSBvbkNoYW5nZT17ZSA9PiBzZXRPcmlnaW5hbFRva2VuSWQoZS50YXJnZXQudmFsdWUpfSBwbGFjZWhvbGRlcj1cIk9yaWdpbmFsIFRva2VuIElEXCIgLz5cbiAgICAgICAgPC9kaXY+XG4gICAgICAgIDxkaXYgc3R5bGU9e3sgbWFyZ2luVG9wOiA4IH19PlxuICAgICAgICAgIDxidXR0b24gb25DbGljaz17aGFuZGxlTWludH0gZGlzYWJsZWQ9eyF3YWxsZXR9Pk1pbnQ8L2J1dHRvbj5cbiAgICAgICAgPC9kaXY+XG4gICAgICA8L3NlY3Rpb24+XG5cbiAgICAgIDxzZWN0aW9uIHN0eWxlPXt7IGJvcmRlcjogJzFweCBzb2xpZCAjZGRkJywgcGFkZGluZzogMTYsIGJvcmRlclJhZGl1czogOCwgbWFyZ2luQm90dG9tOiAxNiB9fT5cbiAgICAgICAgPGgzPjMpIEluaXRpYXRlIENyb3NzLUNoYWluIFRyYW5zZmVyIChEZW1vKTwvaDM+XG4gICAgICAgIDxidXR0b24gb25DbGljaz17aGFuZGxlSW5pdGlhdGV9IGRpc2FibGVkPXshd2FsbGV0IHx8ICFsYXN0TWludH0+SW5pdGlhdGU8L2J1dHRvbj5cbiAgICAgIDwvc2VjdGlvbj5cblxuICAgICAgPHNlY3Rpb24gc3R5bGU9e3sgYm9yZGVyOiAnMXB4IHNvbGlkICNkZGQnLCBwYWRkaW5nOiAxNiwgYm9yZGVyUmFkaXVzOiA4IH19PlxuICAgICAgICA8aDM+U3RhdHVzPC9oMz5cbiAgICAgICAgPHA+e3N0YXR1c308L3A+XG4gICAgICAgIDxwcmUgc3R5bGU9e3sgd2hpdGVTcGFjZTogJ3ByZS13cmFwJyB9fT57bG9nc308L3ByZT5cbiAgICAgIDwvc2VjdGlvbj5cbiAgICA8L2Rpdj5cbiAgKVxufVxuXG5leHBvcnQgZGVmYXVsdCBmdW5jdGlvbiBIb21lKCkge1xuICBjb25zdCBlbmRwb2ludCA9ICdodHRwOi8vMTI3LjAuMC4xOjg4OTknXG4gIGNvbnN0IHdhbGxldHMgPSB1c2VNZW1vKCgpID0+IFtuZXcgUGhhbnRvbVdhbGxldEFkYXB0ZXIoKSwgbmV3IFNvbGZsYXJlV2FsbGV0QWRhcHRlcigpXSwgW10pXG5cbiAgcmV0dXJuIChcbiAgICA8Q29ubmVjdGlvblByb3ZpZGVyIGVuZHBvaW50PXtlbmRwb2ludH0+XG4gICAgICA8V2FsbGV0UHJvdmlkZXIgd2FsbGV0cz17d2FsbGV0c30gYXV0b0Nvbm5lY3Q+XG4gICAgICAgIDxXYWxsZXRNb2RhbFByb3ZpZGVyPlxuICAgICAgICAgIDxBcHBJbm5lciAvPlxuICAgICAgICA8L1dhbGxldE1vZGFsUHJvdmlkZXI+XG4gICAgICA8L1dhbGxldFByb3ZpZGVyPlxuICAgIDwvQ29ubmVjdGlvblByb3ZpZGVyPlxuICApXG59XG5cbiJdLCJuYW1lcyI6WyJ1c2VDYWxsYmFjayIsInVzZU1lbW8iLCJ1c2VTdGF0ZSIsIkNvbm5lY3Rpb24iLCJLZXlwYWlyIiwiUHVibGljS2V5IiwiU3lzdGVtUHJvZ3JhbSIsImlkbCIsImFuY2hvciIsIkNvbm5lY3Rpb25Qcm92aWRlciIsIldhbGxldFByb3ZpZGVyIiwidXNlQW5jaG9yV2FsbGV0IiwiV2FsbGV0TW9kYWxQcm92aWRlciIsIldhbGxldE11bHRpQnV0dG9uIiwiUGhhbnRvbVdhbGxldEFkYXB0ZXIiLCJTb2xmbGFyZVdhbGxldEFkYXB0ZXIiLCJnZXRBc3NvY2lhdGVkVG9rZW5BZGRyZXNzIiwiQVNTT0NJQVRFRF9UT0tFTl9QUk9HUkFNX0lEIiwiVE9LRU5fUFJPR1JBTV9JRCIsIlBST0dSQU1fSUQiLCJhZGRyZXNzIiwiQXBwSW5uZXIiLCJ3YWxsZXQiLCJzdGF0dXMiLCJzZXRTdGF0dXMiLCJsb2dzIiwic2V0TG9ncyIsImNvbm5lY3Rpb24iLCJwcm92aWRlciIsIkFuY2hvclByb3ZpZGVyIiwiY29tbWl0bWVudCIsInByb2dyYW0iLCJQcm9ncmFtIiwiZ2F0ZXdheSIsInNldEdhdGV3YXkiLCJmZWUiLCJzZXRGZWUiLCJtaW50TmFtZSIsInNldE1pbnROYW1lIiwibWludFN5bWJvbCIsInNldE1pbnRTeW1ib2wiLCJtaW50VXJpIiwic2V0TWludFVyaSIsIm9yaWdpbmFsQ2hhaW4iLCJzZXRPcmlnaW5hbENoYWluIiwib3JpZ2luYWxDb250cmFjdCIsInNldE9yaWdpbmFsQ29udHJhY3QiLCJvcmlnaW5hbFRva2VuSWQiLCJzZXRPcmlnaW5hbFRva2VuSWQiLCJsYXN0TWludCIsInNldExhc3RNaW50IiwiaGFuZGxlSW5pdGlhbGl6ZSIsInB1YmxpY0tleSIsImdsb2JhbENvbmZpZ1BkYSIsImJ1bXAiLCJmaW5kUHJvZ3JhbUFkZHJlc3NTeW5jIiwiQnVmZmVyIiwiZnJvbSIsInR4IiwibWV0aG9kcyIsImluaXRpYWxpemUiLCJCTiIsInBhcnNlSW50IiwiYWNjb3VudHMiLCJnbG9iYWxDb25maWciLCJhdXRob3JpdHkiLCJ6ZXRhY2hhaW5HYXRld2F5IiwiY29sbGVjdGlvbkF1dGhvcml0eSIsImZlZVJlY2lwaWVudCIsInN5c3RlbVByb2dyYW0iLCJwcm9ncmFtSWQiLCJycGMiLCJlIiwibWVzc2FnZSIsIlN0cmluZyIsImhhbmRsZU1pbnQiLCJtaW50IiwiZ2VuZXJhdGUiLCJ1bml2ZXJzYWxOZnRQZGEiLCJ0b0J1ZmZlciIsInRva2VuQWNjb3VudCIsIm1pbnROZnQiLCJ1bml2ZXJzYWxOZnQiLCJwYXllciIsInJlY2lwaWVudCIsInRva2VuUHJvZ3JhbSIsImFzc29jaWF0ZWRUb2tlblByb2dyYW0iLCJzaWduZXJzIiwidG9CYXNlNTgiLCJoYW5kbGVJbml0aWF0ZSIsInRyYW5zZmVySWQiLCJEYXRlIiwibm93IiwiY3Jvc3NDaGFpblRyYW5zZmVyUGRhIiwiY3Jvc3NCdW1wIiwib3duZXJUb2tlbkFjY291bnQiLCJpbml0aWF0ZUNyb3NzQ2hhaW5UcmFuc2ZlciIsImNyb3NzQ2hhaW5UcmFuc2ZlciIsIm5mdE1pbnQiLCJvd25lciIsImRlZmF1bHQiLCJkaXYiLCJzdHlsZSIsImZvbnRGYW1pbHkiLCJtYXhXaWR0aCIsIm1hcmdpbiIsImRpc3BsYXkiLCJhbGlnbkl0ZW1zIiwiZ2FwIiwiaDEiLCJwIiwiY29kZSIsInNlY3Rpb24iLCJib3JkZXIiLCJwYWRkaW5nIiwiYm9yZGVyUmFkaXVzIiwibWFyZ2luQm90dG9tIiwiaDMiLCJmbGV4V3JhcCIsImlucHV0IiwiZmxleCIsInZhbHVlIiwib25DaGFuZ2UiLCJ0YXJnZXQiLCJwbGFjZWhvbGRlciIsIndpZHRoIiwiYnV0dG9uIiwib25DbGljayIsImRpc2FibGVkIiwiZ3JpZFRlbXBsYXRlQ29sdW1ucyIsIm1hcmdpblRvcCIsInByZSIsIndoaXRlU3BhY2UiLCJIb21lIiwiZW5kcG9pbnQiLCJ3YWxsZXRzIiwiYXV0b0Nvbm5lY3QiXSwic291cmNlUm9vdCI6IiJ9\n//# sourceURL=webpack-internal:///./pages/index.tsx\n");
-
-/***/ }),
-
-/***/ "@coral-xyz/anchor":
-/*!************************************!*\
-  !*** external "@coral-xyz/anchor" ***!
-  \************************************/
-/***/ ((module) => {
-
-module.exports = require("@coral-xyz/anchor");
-
-/***/ }),
-
-/***/ "@solana/web3.js":
-/*!**********************************!*\
-  !*** external "@solana/web3.js" ***!
-  \**********************************/
-/***/ ((module) => {
-
-module.exports = require("@solana/web3.js");
-
-/***/ }),
-
-/***/ "next/dist/compiled/next-server/pages.runtime.dev.js":
-/*!**********************************************************************!*\
-  !*** external "next/dist/compiled/next-server/pages.runtime.dev.js" ***!
-  \**********************************************************************/
-/***/ ((module) => {
-
-module.exports = require("next/dist/compiled/next-server/pages.runtime.dev.js");
-
-/***/ }),
-
-/***/ "react":
-/*!************************!*\
-  !*** external "react" ***!
-  \************************/
-/***/ ((module) => {
-
-module.exports = require("react");
-
-/***/ }),
-
-/***/ "react/jsx-dev-runtime":
-/*!****************************************!*\
-  !*** external "react/jsx-dev-runtime" ***!
-  \****************************************/
-/***/ ((module) => {
-
-module.exports = require("react/jsx-dev-runtime");
-
-/***/ }),
-
-/***/ "react/jsx-runtime":
-/*!************************************!*\
-  !*** external "react/jsx-runtime" ***!
-  \************************************/
-/***/ ((module) => {
-
-module.exports = require("react/jsx-runtime");
-
-/***/ }),
-
-/***/ "@solana/spl-token":
-/*!************************************!*\
-  !*** external "@solana/spl-token" ***!
-  \************************************/
-/***/ ((module) => {
-
-module.exports = import("@solana/spl-token");;
-
-/***/ }),
-
-/***/ "@solana/wallet-adapter-react":
-/*!***********************************************!*\
-  !*** external "@solana/wallet-adapter-react" ***!
-  \***********************************************/
-/***/ ((module) => {
-
-module.exports = import("@solana/wallet-adapter-react");;
-
-/***/ }),
-
-/***/ "@solana/wallet-adapter-react-ui":
-/*!**************************************************!*\
-  !*** external "@solana/wallet-adapter-react-ui" ***!
-  \**************************************************/
-/***/ ((module) => {
-
-module.exports = import("@solana/wallet-adapter-react-ui");;
-
-/***/ }),
-
-/***/ "@solana/wallet-adapter-wallets":
-/*!*************************************************!*\
-  !*** external "@solana/wallet-adapter-wallets" ***!
-  \*************************************************/
-/***/ ((module) => {
-
-module.exports = import("@solana/wallet-adapter-wallets");;
-
-/***/ }),
-
-/***/ "path":
-/*!***********************!*\
-  !*** external "path" ***!
-  \***********************/
-/***/ ((module) => {
-
-module.exports = require("path");
-
-/***/ }),
-
-/***/ "./idl/universal_nft.json":
-/*!********************************!*\
-  !*** ./idl/universal_nft.json ***!
-  \********************************/
-/***/ ((module) => {
-
-module.exports = /*#__PURE__*/JSON.parse('{"address":"73ce2AD3AZpaGFNcdavnbKbhNGSmz3PNyv2GCDM3Yy3c","metadata":{"name":"universal_nft","version":"0.1.0","spec":"0.1.0","description":"Created with Anchor"},"instructions":[{"name":"complete_cross_chain_transfer","docs":["Complete a cross-chain transfer by burning the NFT"],"discriminator":[233,56,100,0,255,227,232,65],"accounts":[{"name":"global_config","pda":{"seeds":[{"kind":"const","value":[103,108,111,98,97,108,95,99,111,110,102,105,103]}]}},{"name":"universal_nft","writable":true,"pda":{"seeds":[{"kind":"const","value":[117,110,105,118,101,114,115,97,108,95,110,102,116]},{"kind":"account","path":"nft_mint"}]}},{"name":"cross_chain_transfer","writable":true,"pda":{"seeds":[{"kind":"const","value":[99,114,111,115,115,95,99,104,97,105,110,95,116,114,97,110,115,102,101,114]},{"kind":"arg","path":"transfer_id"}]}},{"name":"nft_mint","writable":true},{"name":"owner_token_account","writable":true},{"name":"collection_authority","signer":true},{"name":"zetachain_gateway","signer":true},{"name":"token_program","address":"TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"}],"args":[{"name":"transfer_id","type":"string"}]},{"name":"confirm_cross_chain_transfer","docs":["Confirm a cross-chain transfer (called by ZetaChain gateway)"],"discriminator":[226,26,32,150,192,181,199,211],"accounts":[{"name":"global_config","pda":{"seeds":[{"kind":"const","value":[103,108,111,98,97,108,95,99,111,110,102,105,103]}]}},{"name":"cross_chain_transfer","writable":true,"pda":{"seeds":[{"kind":"const","value":[99,114,111,115,115,95,99,104,97,105,110,95,116,114,97,110,115,102,101,114]},{"kind":"arg","path":"transfer_id"}]}},{"name":"zetachain_gateway","signer":true}],"args":[{"name":"transfer_id","type":"string"}]},{"name":"initialize","docs":["Initialize the global configuration for the universal NFT program"],"discriminator":[175,175,109,31,13,152,155,237],"accounts":[{"name":"global_config","writable":true,"pda":{"seeds":[{"kind":"const","value":[103,108,111,98,97,108,95,99,111,110,102,105,103]}]}},{"name":"authority","writable":true,"signer":true},{"name":"zetachain_gateway"},{"name":"collection_authority"},{"name":"fee_recipient"},{"name":"system_program","address":"11111111111111111111111111111111"}],"args":[{"name":"bump","type":"u8"},{"name":"cross_chain_fee","type":{"option":"u64"}}]},{"name":"initiate_cross_chain_transfer","docs":["Initiate a cross-chain transfer of an NFT"],"discriminator":[137,50,17,128,147,41,7,198],"accounts":[{"name":"global_config","pda":{"seeds":[{"kind":"const","value":[103,108,111,98,97,108,95,99,111,110,102,105,103]}]}},{"name":"universal_nft","writable":true,"pda":{"seeds":[{"kind":"const","value":[117,110,105,118,101,114,115,97,108,95,110,102,116]},{"kind":"account","path":"nft_mint"}]}},{"name":"cross_chain_transfer","writable":true,"pda":{"seeds":[{"kind":"const","value":[99,114,111,115,115,95,99,104,97,105,110,95,116,114,97,110,115,102,101,114]},{"kind":"arg","path":"transfer_id"}]}},{"name":"nft_mint"},{"name":"owner_token_account","writable":true},{"name":"owner","writable":true,"signer":true},{"name":"payer","writable":true,"signer":true},{"name":"zetachain_gateway"},{"name":"token_program","address":"TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"},{"name":"system_program","address":"11111111111111111111111111111111"}],"args":[{"name":"transfer_id","type":"string"},{"name":"destination_chain","type":"string"},{"name":"destination_recipient","type":"string"},{"name":"bump","type":"u8"}]},{"name":"mint_nft","docs":["Mint a new universal NFT with cross-chain metadata"],"discriminator":[211,57,6,167,15,219,35,251],"accounts":[{"name":"global_config","pda":{"seeds":[{"kind":"const","value":[103,108,111,98,97,108,95,99,111,110,102,105,103]}]}},{"name":"universal_nft","writable":true,"pda":{"seeds":[{"kind":"const","value":[117,110,105,118,101,114,115,97,108,95,110,102,116]},{"kind":"account","path":"mint"}]}},{"name":"mint","writable":true,"signer":true},{"name":"token_account","writable":true,"pda":{"seeds":[{"kind":"account","path":"recipient"},{"kind":"const","value":[6,221,246,225,215,101,161,147,217,203,225,70,206,235,121,172,28,180,133,237,95,91,55,145,58,140,245,133,126,255,0,169]},{"kind":"account","path":"mint"}],"program":{"kind":"const","value":[140,151,37,143,78,36,137,241,187,61,16,41,20,142,13,131,11,90,19,153,218,255,16,132,4,142,123,216,219,233,248,89]}}},{"name":"payer","writable":true,"signer":true},{"name":"recipient","docs":["The recipient of the NFT"]},{"name":"collection_authority","docs":["Collection authority (must match global config)"],"signer":true},{"name":"token_program","address":"TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"},{"name":"associated_token_program","address":"ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"},{"name":"system_program","address":"11111111111111111111111111111111"}],"args":[{"name":"bump","type":"u8"},{"name":"name","type":"string"},{"name":"symbol","type":"string"},{"name":"uri","type":"string"},{"name":"original_chain","type":"string"},{"name":"original_contract","type":"string"},{"name":"original_token_id","type":"string"}]}],"accounts":[{"name":"CrossChainTransfer","discriminator":[75,206,207,67,38,212,216,18]},{"name":"GlobalConfig","discriminator":[149,8,156,202,160,252,176,217]},{"name":"UniversalNft","discriminator":[2,33,61,32,213,74,11,232]}],"errors":[{"code":6000,"name":"InsufficientFunds","msg":"Insufficient funds for cross-chain operation"},{"code":6001,"name":"NftLocked","msg":"NFT is currently locked for cross-chain transfer"},{"code":6002,"name":"InvalidChainId","msg":"Invalid chain ID specified"},{"code":6003,"name":"Unauthorized","msg":"Unauthorized operation"},{"code":6004,"name":"InvalidMetadataUri","msg":"Invalid metadata URI"},{"code":6005,"name":"TransferAlreadyExists","msg":"Cross-chain transfer already exists"},{"code":6006,"name":"TransferNotFound","msg":"Cross-chain transfer not found"},{"code":6007,"name":"InvalidTransferStatus","msg":"Invalid transfer status"},{"code":6008,"name":"GatewayNotConfigured","msg":"ZetaChain gateway not configured"},{"code":6009,"name":"ComputeBudgetExceeded","msg":"Compute budget exceeded"},{"code":6010,"name":"InvalidDestinationAddress","msg":"Invalid destination address format"},{"code":6011,"name":"MintFailed","msg":"NFT mint failed"},{"code":6012,"name":"InvalidOriginalChain","msg":"Invalid original chain data"},{"code":6013,"name":"TransferTimeout","msg":"Transfer confirmation timeout"}]}');
-
-/***/ })
-
-};
-;
-
-// load runtime
-var __webpack_require__ = require("../webpack-runtime.js");
-__webpack_require__.C(exports);
-var __webpack_exec__ = (moduleId) => (__webpack_require__(__webpack_require__.s = moduleId))
-var __webpack_exports__ = __webpack_require__.X(0, ["vendor-chunks/next","vendor-chunks/@swc","vendor-chunks/@solana"], () => (__webpack_exec__("./node_modules/next/dist/build/webpack/loaders/next-route-loader/index.js?kind=PAGES&page=%2F&preferredRegion=&absolutePagePath=.%2Fpages%2Findex.tsx&absoluteAppPath=private-next-pages%2F_app&absoluteDocumentPath=private-next-pages%2F_document&middlewareConfigBase64=e30%3D!")));
-module.exports = __webpack_exports__;
-
-})();
\ No newline at end of file
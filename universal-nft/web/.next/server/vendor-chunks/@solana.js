@@ -1,24 +0,0 @@
-/*
- * ATTENTION: An "eval-source-map" devtool has been used.
- * This devtool is neither made for production nor for readable output files.
- * It uses "eval()" calls to create a separate source file with attached SourceMaps in the browser devtools.
- * If you are trying to read the output file, select a different devtool (https://webpack.js.org/configuration/devtool/)
- * or disable the default devtool with "devtool: false".
- * If you are looking for production-ready output files, see mode: "production" (https://webpack.js.org/configuration/mode/).
- */
-exports.id = "vendor-chunks/@solana";
-exports.ids = ["vendor-chunks/@solana"];
-exports.modules = {
-
-/***/ "./node_modules/@solana/wallet-adapter-react-ui/styles.css":
-/*!*****************************************************************!*\
-  !*** ./node_modules/@solana/wallet-adapter-react-ui/styles.css ***!
-  \*****************************************************************/
-/***/ (() => {
-
-
-
-/***/ })
-
-};
-;
\ No newline at end of file
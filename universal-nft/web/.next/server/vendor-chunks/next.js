@@ -1,335 +0,0 @@
-"use strict";
-/*
- * ATTENTION: An "eval-source-map" devtool has been used.
- * This devtool is neither made for production nor for readable output files.
- * It uses "eval()" calls to create a separate source file with attached SourceMaps in the browser devtools.
- * If you are trying to read the output file, select a different devtool (https://webpack.js.org/configuration/devtool/)
- * or disable the default devtool with "devtool: false".
- * If you are looking for production-ready output files, see mode: "production" (https://webpack.js.org/configuration/mode/).
- */
-exports.id = "vendor-chunks/next";
-exports.ids = ["vendor-chunks/next"];
-exports.modules = {
-
-/***/ "./node_modules/next/dist/build/templates/helpers.js":
-/*!***********************************************************!*\
-  !*** ./node_modules/next/dist/build/templates/helpers.js ***!
-  \***********************************************************/
-/***/ ((__unused_webpack_module, exports) => {
-
-eval("/**\n * Hoists a name from a module or promised module.\n *\n * @param module the module to hoist the name from\n * @param name the name to hoist\n * @returns the value on the module (or promised module)\n */ \nObject.defineProperty(exports, \"__esModule\", ({\n    value: true\n}));\nObject.defineProperty(exports, \"hoist\", ({\n    enumerable: true,\n    get: function() {\n        return hoist;\n    }\n}));\nfunction hoist(module, name) {\n    // If the name is available in the module, return it.\n    if (name in module) {\n        return module[name];\n    }\n    // If a property called `then` exists, assume it's a promise and\n    // return a promise that resolves to the name.\n    if (\"then\" in module && typeof module.then === \"function\") {\n        return module.then((mod)=>hoist(mod, name));\n    }\n    // If we're trying to hoise the default export, and the module is a function,\n    // return the module itself.\n    if (typeof module === \"function\" && name === \"default\") {\n        return module;\n    }\n    // Otherwise, return undefined.\n    return undefined;\n}\n\n//# sourceMappingURL=helpers.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvbmV4dC9kaXN0L2J1aWxkL3RlbXBsYXRlcy9oZWxwZXJzLmpzIiwibWFwcGluZ3MiOiJBQUFBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBLElBQWlCO0FBQ2pCLDhDQUE2QztBQUM3QztBQUNBLENBQUMsRUFBQztBQUNGLHlDQUF3QztBQUN4QztBQUNBO0FBQ0E7QUFDQTtBQUNBLENBQUMsRUFBQztBQUNGO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTs7QUFFQSIsInNvdXJjZXMiOlsid2VicGFjazovL3VuaXZlcnNhbC1uZnQtd2ViLy4vbm9kZV9tb2R1bGVzL25leHQvZGlzdC9idWlsZC90ZW1wbGF0ZXMvaGVscGVycy5qcz9iMjg3Il0sInNvdXJjZXNDb250ZW50IjpbIi8qKlxuICogSG9pc3RzIGEgbmFtZSBmcm9tIGEgbW9kdWxlIG9yIHByb21pc2VkIG1vZHVsZS5cbiAqXG4gKiBAcGFyYW0gbW9kdWxlIHRoZSBtb2R1bGUgdG8gaG9pc3QgdGhlIG5hbWUgZnJvbVxuICogQHBhcmFtIG5hbWUgdGhlIG5hbWUgdG8gaG9pc3RcbiAqIEByZXR1cm5zIHRoZSB2YWx1ZSBvbiB0aGUgbW9kdWxlIChvciBwcm9taXNlZCBtb2R1bGUpXG4gKi8gXCJ1c2Ugc3RyaWN0XCI7XG5PYmplY3QuZGVmaW5lUHJvcGVydHkoZXhwb3J0cywgXCJfX2VzTW9kdWxlXCIsIHtcbiAgICB2YWx1ZTogdHJ1ZVxufSk7XG5PYmplY3QuZGVmaW5lUHJvcGVydHkoZXhwb3J0cywgXCJob2lzdFwiLCB7XG4gICAgZW51bWVyYWJsZTogdHJ1ZSxcbiAgICBnZXQ6IGZ1bmN0aW9uKCkge1xuICAgICAgICByZXR1cm4gaG9pc3Q7XG4gICAgfVxufSk7XG5mdW5jdGlvbiBob2lzdChtb2R1bGUsIG5hbWUpIHtcbiAgICAvLyBJZiB0aGUgbmFtZSBpcyBhdmFpbGFibGUgaW4gdGhlIG1vZHVsZSwgcmV0dXJuIGl0LlxuICAgIGlmIChuYW1lIGluIG1vZHVsZSkge1xuICAgICAgICByZXR1cm4gbW9kdWxlW25hbWVdO1xuICAgIH1cbiAgICAvLyBJZiBhIHByb3BlcnR5IGNhbGxlZCBgdGhlbmAgZXhpc3RzLCBhc3N1bWUgaXQncyBhIHByb21pc2UgYW5kXG4gICAgLy8gcmV0dXJuIGEgcHJvbWlzZSB0aGF0IHJlc29sdmVzIHRvIHRoZSBuYW1lLlxuICAgIGlmIChcInRoZW5cIiBpbiBtb2R1bGUgJiYgdHlwZW9mIG1vZHVsZS50aGVuID09PSBcImZ1bmN0aW9uXCIpIHtcbiAgICAgICAgcmV0dXJuIG1vZHVsZS50aGVuKChtb2QpPT5ob2lzdChtb2QsIG5hbWUpKTtcbiAgICB9XG4gICAgLy8gSWYgd2UncmUgdHJ5aW5nIHRvIGhvaXNlIHRoZSBkZWZhdWx0IGV4cG9ydCwgYW5kIHRoZSBtb2R1bGUgaXMgYSBmdW5jdGlvbixcbiAgICAvLyByZXR1cm4gdGhlIG1vZHVsZSBpdHNlbGYuXG4gICAgaWYgKHR5cGVvZiBtb2R1bGUgPT09IFwiZnVuY3Rpb25cIiAmJiBuYW1lID09PSBcImRlZmF1bHRcIikge1xuICAgICAgICByZXR1cm4gbW9kdWxlO1xuICAgIH1cbiAgICAvLyBPdGhlcndpc2UsIHJldHVybiB1bmRlZmluZWQuXG4gICAgcmV0dXJuIHVuZGVmaW5lZDtcbn1cblxuLy8jIHNvdXJjZU1hcHBpbmdVUkw9aGVscGVycy5qcy5tYXAiXSwibmFtZXMiOltdLCJzb3VyY2VSb290IjoiIn0=\n//# sourceURL=webpack-internal:///./node_modules/next/dist/build/templates/helpers.js\n");
-
-/***/ }),
-
-/***/ "./node_modules/next/dist/pages/_document.js":
-/*!***************************************************!*\
-  !*** ./node_modules/next/dist/pages/_document.js ***!
-  \***************************************************/
-/***/ ((__unused_webpack_module, exports, __webpack_require__) => {
-
-eval("\nObject.defineProperty(exports, \"__esModule\", ({\n    value: true\n}));\n0 && (0);\nfunction _export(target, all) {\n    for(var name in all)Object.defineProperty(target, name, {\n        enumerable: true,\n        get: all[name]\n    });\n}\n_export(exports, {\n    Head: function() {\n        return Head;\n    },\n    Html: function() {\n        return Html;\n    },\n    Main: function() {\n        return Main;\n    },\n    NextScript: function() {\n        return NextScript;\n    },\n    /**\n * `Document` component handles the initial `document` markup and renders only on the server side.\n * Commonly used for implementing server side rendering for `css-in-js` libraries.\n */ default: function() {\n        return Document;\n    }\n});\nconst _jsxruntime = __webpack_require__(/*! react/jsx-runtime */ \"react/jsx-runtime\");\nconst _react = /*#__PURE__*/ _interop_require_wildcard(__webpack_require__(/*! react */ \"react\"));\nconst _constants = __webpack_require__(/*! ../shared/lib/constants */ \"./node_modules/next/dist/shared/lib/constants.js\");\nconst _getpagefiles = __webpack_require__(/*! ../server/get-page-files */ \"./node_modules/next/dist/server/get-page-files.js\");\nconst _htmlescape = __webpack_require__(/*! ../server/htmlescape */ \"./node_modules/next/dist/server/htmlescape.js\");\nconst _iserror = /*#__PURE__*/ _interop_require_default(__webpack_require__(/*! ../lib/is-error */ \"./node_modules/next/dist/lib/is-error.js\"));\nconst _htmlcontextsharedruntime = __webpack_require__(/*! ../shared/lib/html-context.shared-runtime */ \"./node_modules/next/dist/server/future/route-modules/pages/vendored/contexts/html-context.js\");\nconst _encodeuripath = __webpack_require__(/*! ../shared/lib/encode-uri-path */ \"./node_modules/next/dist/shared/lib/encode-uri-path.js\");\nfunction _interop_require_default(obj) {\n    return obj && obj.__esModule ? obj : {\n        default: obj\n    };\n}\nfunction _getRequireWildcardCache(nodeInterop) {\n    if (typeof WeakMap !== \"function\") return null;\n    var cacheBabelInterop = new WeakMap();\n    var cacheNodeInterop = new WeakMap();\n    return (_getRequireWildcardCache = function(nodeInterop) {\n        return nodeInterop ? cacheNodeInterop : cacheBabelInterop;\n    })(nodeInterop);\n}\nfunction _interop_require_wildcard(obj, nodeInterop) {\n    if (!nodeInterop && obj && obj.__esModule) {\n        return obj;\n    }\n    if (obj === null || typeof obj !== \"object\" && typeof obj !== \"function\") {\n        return {\n            default: obj\n        };\n    }\n    var cache = _getRequireWildcardCache(nodeInterop);\n    if (cache && cache.has(obj)) {\n        return cache.get(obj);\n    }\n    var newObj = {\n        __proto__: null\n    };\n    var hasPropertyDescriptor = Object.defineProperty && Object.getOwnPropertyDescriptor;\n    for(var key in obj){\n        if (key !== \"default\" && Object.prototype.hasOwnProperty.call(obj, key)) {\n            var desc = hasPropertyDescriptor ? Object.getOwnPropertyDescriptor(obj, key) : null;\n            if (desc && (desc.get || desc.set)) {\n                Object.defineProperty(newObj, key, desc);\n            } else {\n                newObj[key] = obj[key];\n            }\n        }\n    }\n    newObj.default = obj;\n    if (cache) {\n        cache.set(obj, newObj);\n    }\n    return newObj;\n}\n/** Set of pages that have triggered a large data warning on production mode. */ const largePageDataWarnings = new Set();\nfunction getDocumentFiles(buildManifest, pathname, inAmpMode) {\n    const sharedFiles = (0, _getpagefiles.getPageFiles)(buildManifest, \"/_app\");\n    const pageFiles =  true && inAmpMode ? [] : (0, _getpagefiles.getPageFiles)(buildManifest, pathname);\n    return {\n        sharedFiles,\n        pageFiles,\n        allFiles: [\n            ...new Set([\n                ...sharedFiles,\n                ...pageFiles\n            ])\n        ]\n    };\n}\nfunction getPolyfillScripts(context, props) {\n    // polyfills.js has to be rendered as nomodule without async\n    // It also has to be the first script to load\n    const { assetPrefix, buildManifest, assetQueryString, disableOptimizedLoading, crossOrigin } = context;\n    return buildManifest.polyfillFiles.filter((polyfill)=>polyfill.endsWith(\".js\") && !polyfill.endsWith(\".module.js\")).map((polyfill)=>/*#__PURE__*/ (0, _jsxruntime.jsx)(\"script\", {\n            defer: !disableOptimizedLoading,\n            nonce: props.nonce,\n            crossOrigin: props.crossOrigin || crossOrigin,\n            noModule: true,\n            src: `${assetPrefix}/_next/${(0, _encodeuripath.encodeURIPath)(polyfill)}${assetQueryString}`\n        }, polyfill));\n}\nfunction hasComponentProps(child) {\n    return !!child && !!child.props;\n}\nfunction AmpStyles({ styles }) {\n    if (!styles) return null;\n    // try to parse styles from fragment for backwards compat\n    const curStyles = Array.isArray(styles) ? styles : [];\n    if (styles.props && // @ts-ignore Property 'props' does not exist on type ReactElement\n    Array.isArray(styles.props.children)) {\n        const hasStyles = (el)=>{\n            var _el_props_dangerouslySetInnerHTML, _el_props;\n            return el == null ? void 0 : (_el_props = el.props) == null ? void 0 : (_el_props_dangerouslySetInnerHTML = _el_props.dangerouslySetInnerHTML) == null ? void 0 : _el_props_dangerouslySetInnerHTML.__html;\n        };\n        // @ts-ignore Property 'props' does not exist on type ReactElement\n        styles.props.children.forEach((child)=>{\n            if (Array.isArray(child)) {\n                child.forEach((el)=>hasStyles(el) && curStyles.push(el));\n            } else if (hasStyles(child)) {\n                curStyles.push(child);\n            }\n        });\n    }\n    /* Add custom styles before AMP styles to prevent accidental overrides */ return /*#__PURE__*/ (0, _jsxruntime.jsx)(\"style\", {\n        \"amp-custom\": \"\",\n        dangerouslySetInnerHTML: {\n            __html: curStyles.map((style)=>style.props.dangerouslySetInnerHTML.__html).join(\"\").replace(/\\/\\*# sourceMappingURL=.*\\*\\//g, \"\").replace(/\\/\\*@ sourceURL=.*?\\*\\//g, \"\")\n        }\n    });\n}\nfunction getDynamicChunks(context, props, files) {\n    const { dynamicImports, assetPrefix, isDevelopment, assetQueryString, disableOptimizedLoading, crossOrigin } = context;\n    return dynamicImports.map((file)=>{\n        if (!file.endsWith(\".js\") || files.allFiles.includes(file)) return null;\n        return /*#__PURE__*/ (0, _jsxruntime.jsx)(\"script\", {\n            async: !isDevelopment && disableOptimizedLoading,\n            defer: !disableOptimizedLoading,\n            src: `${assetPrefix}/_next/${(0, _encodeuripath.encodeURIPath)(file)}${assetQueryString}`,\n            nonce: props.nonce,\n            crossOrigin: props.crossOrigin || crossOrigin\n        }, file);\n    });\n}\nfunction getScripts(context, props, files) {\n    var _buildManifest_lowPriorityFiles;\n    const { assetPrefix, buildManifest, isDevelopment, assetQueryString, disableOptimizedLoading, crossOrigin } = context;\n    const normalScripts = files.allFiles.filter((file)=>file.endsWith(\".js\"));\n    const lowPriorityScripts = (_buildManifest_lowPriorityFiles = buildManifest.lowPriorityFiles) == null ? void 0 : _buildManifest_lowPriorityFiles.filter((file)=>file.endsWith(\".js\"));\n    return [\n        ...normalScripts,\n        ...lowPriorityScripts\n    ].map((file)=>{\n        return /*#__PURE__*/ (0, _jsxruntime.jsx)(\"script\", {\n            src: `${assetPrefix}/_next/${(0, _encodeuripath.encodeURIPath)(file)}${assetQueryString}`,\n            nonce: props.nonce,\n            async: !isDevelopment && disableOptimizedLoading,\n            defer: !disableOptimizedLoading,\n            crossOrigin: props.crossOrigin || crossOrigin\n        }, file);\n    });\n}\nfunction getPreNextWorkerScripts(context, props) {\n    const { assetPrefix, scriptLoader, crossOrigin, nextScriptWorkers } = context;\n    // disable `nextScriptWorkers` in edge runtime\n    if (!nextScriptWorkers || \"nodejs\" === \"edge\") return null;\n    try {\n        let { partytownSnippet } = require(\"@builder.io/partytown/integration\");\n        const children = Array.isArray(props.children) ? props.children : [\n            props.children\n        ];\n        // Check to see if the user has defined their own Partytown configuration\n        const userDefinedConfig = children.find((child)=>{\n            var _child_props_dangerouslySetInnerHTML, _child_props;\n            return hasComponentProps(child) && (child == null ? void 0 : (_child_props = child.props) == null ? void 0 : (_child_props_dangerouslySetInnerHTML = _child_props.dangerouslySetInnerHTML) == null ? void 0 : _child_props_dangerouslySetInnerHTML.__html.length) && \"data-partytown-config\" in child.props;\n        });\n        return /*#__PURE__*/ (0, _jsxruntime.jsxs)(_jsxruntime.Fragment, {\n            children: [\n                !userDefinedConfig && /*#__PURE__*/ (0, _jsxruntime.jsx)(\"script\", {\n                    \"data-partytown-config\": \"\",\n                    dangerouslySetInnerHTML: {\n                        __html: `\n            partytown = {\n              lib: \"${assetPrefix}/_next/static/~partytown/\"\n            };\n          `\n                    }\n                }),\n                /*#__PURE__*/ (0, _jsxruntime.jsx)(\"script\", {\n                    \"data-partytown\": \"\",\n                    dangerouslySetInnerHTML: {\n                        __html: partytownSnippet()\n                    }\n                }),\n                (scriptLoader.worker || []).map((file, index)=>{\n                    const { strategy, src, children: scriptChildren, dangerouslySetInnerHTML, ...scriptProps } = file;\n                    let srcProps = {};\n                    if (src) {\n                        // Use external src if provided\n                        srcProps.src = src;\n                    } else if (dangerouslySetInnerHTML && dangerouslySetInnerHTML.__html) {\n                        // Embed inline script if provided with dangerouslySetInnerHTML\n                        srcProps.dangerouslySetInnerHTML = {\n                            __html: dangerouslySetInnerHTML.__html\n                        };\n                    } else if (scriptChildren) {\n                        // Embed inline script if provided with children\n                        srcProps.dangerouslySetInnerHTML = {\n                            __html: typeof scriptChildren === \"string\" ? scriptChildren : Array.isArray(scriptChildren) ? scriptChildren.join(\"\") : \"\"\n                        };\n                    } else {\n                        throw new Error(\"Invalid usage of next/script. Did you forget to include a src attribute or an inline script? https://nextjs.org/docs/messages/invalid-script\");\n                    }\n                    return /*#__PURE__*/ (0, _react.createElement)(\"script\", {\n                        ...srcProps,\n                        ...scriptProps,\n                        type: \"text/partytown\",\n                        key: src || index,\n                        nonce: props.nonce,\n                        \"data-nscript\": \"worker\",\n                        crossOrigin: props.crossOrigin || crossOrigin\n                    });\n                })\n            ]\n        });\n    } catch (err) {\n        if ((0, _iserror.default)(err) && err.code !== \"MODULE_NOT_FOUND\") {\n            console.warn(`Warning: ${err.message}`);\n        }\n        return null;\n    }\n}\nfunction getPreNextScripts(context, props) {\n    const { scriptLoader, disableOptimizedLoading, crossOrigin } = context;\n    const webWorkerScripts = getPreNextWorkerScripts(context, props);\n    const beforeInteractiveScripts = (scriptLoader.beforeInteractive || []).filter((script)=>script.src).map((file, index)=>{\n        const { strategy, ...scriptProps } = file;\n        return /*#__PURE__*/ (0, _react.createElement)(\"script\", {\n            ...scriptProps,\n            key: scriptProps.src || index,\n            defer: scriptProps.defer ?? !disableOptimizedLoading,\n            nonce: props.nonce,\n            \"data-nscript\": \"beforeInteractive\",\n            crossOrigin: props.crossOrigin || crossOrigin\n        });\n    });\n    return /*#__PURE__*/ (0, _jsxruntime.jsxs)(_jsxruntime.Fragment, {\n        children: [\n            webWorkerScripts,\n            beforeInteractiveScripts\n        ]\n    });\n}\nfunction getHeadHTMLProps(props) {\n    const { crossOrigin, nonce, ...restProps } = props;\n    // This assignment is necessary for additional type checking to avoid unsupported attributes in <head>\n    const headProps = restProps;\n    return headProps;\n}\nfunction getAmpPath(ampPath, asPath) {\n    return ampPath || `${asPath}${asPath.includes(\"?\") ? \"&\" : \"?\"}amp=1`;\n}\nfunction getNextFontLinkTags(nextFontManifest, dangerousAsPath, assetPrefix = \"\") {\n    if (!nextFontManifest) {\n        return {\n            preconnect: null,\n            preload: null\n        };\n    }\n    const appFontsEntry = nextFontManifest.pages[\"/_app\"];\n    const pageFontsEntry = nextFontManifest.pages[dangerousAsPath];\n    const preloadedFontFiles = Array.from(new Set([\n        ...appFontsEntry ?? [],\n        ...pageFontsEntry ?? []\n    ]));\n    // If no font files should preload but there's an entry for the path, add a preconnect tag.\n    const preconnectToSelf = !!(preloadedFontFiles.length === 0 && (appFontsEntry || pageFontsEntry));\n    return {\n        preconnect: preconnectToSelf ? /*#__PURE__*/ (0, _jsxruntime.jsx)(\"link\", {\n            \"data-next-font\": nextFontManifest.pagesUsingSizeAdjust ? \"size-adjust\" : \"\",\n            rel: \"preconnect\",\n            href: \"/\",\n            crossOrigin: \"anonymous\"\n        }) : null,\n        preload: preloadedFontFiles ? preloadedFontFiles.map((fontFile)=>{\n            const ext = /\\.(woff|woff2|eot|ttf|otf)$/.exec(fontFile)[1];\n            return /*#__PURE__*/ (0, _jsxruntime.jsx)(\"link\", {\n                rel: \"preload\",\n                href: `${assetPrefix}/_next/${(0, _encodeuripath.encodeURIPath)(fontFile)}`,\n                as: \"font\",\n                type: `font/${ext}`,\n                crossOrigin: \"anonymous\",\n                \"data-next-font\": fontFile.includes(\"-s\") ? \"size-adjust\" : \"\"\n            }, fontFile);\n        }) : null\n    };\n}\nclass Head extends _react.default.Component {\n    static #_ = this.contextType = _htmlcontextsharedruntime.HtmlContext;\n    getCssLinks(files) {\n        const { assetPrefix, assetQueryString, dynamicImports, crossOrigin, optimizeCss, optimizeFonts } = this.context;\n        const cssFiles = files.allFiles.filter((f)=>f.endsWith(\".css\"));\n        const sharedFiles = new Set(files.sharedFiles);\n        // Unmanaged files are CSS files that will be handled directly by the\n        // webpack runtime (`mini-css-extract-plugin`).\n        let unmangedFiles = new Set([]);\n        let dynamicCssFiles = Array.from(new Set(dynamicImports.filter((file)=>file.endsWith(\".css\"))));\n        if (dynamicCssFiles.length) {\n            const existing = new Set(cssFiles);\n            dynamicCssFiles = dynamicCssFiles.filter((f)=>!(existing.has(f) || sharedFiles.has(f)));\n            unmangedFiles = new Set(dynamicCssFiles);\n            cssFiles.push(...dynamicCssFiles);\n        }\n        let cssLinkElements = [];\n        cssFiles.forEach((file)=>{\n            const isSharedFile = sharedFiles.has(file);\n            if (!optimizeCss) {\n                cssLinkElements.push(/*#__PURE__*/ (0, _jsxruntime.jsx)(\"link\", {\n                    nonce: this.props.nonce,\n                    rel: \"preload\",\n                    href: `${assetPrefix}/_next/${(0, _encodeuripath.encodeURIPath)(file)}${assetQueryString}`,\n                    as: \"style\",\n                    crossOrigin: this.props.crossOrigin || crossOrigin\n                }, `${file}-preload`));\n            }\n            const isUnmanagedFile = unmangedFiles.has(file);\n            cssLinkElements.push(/*#__PURE__*/ (0, _jsxruntime.jsx)(\"link\", {\n                nonce: this.props.nonce,\n                rel: \"stylesheet\",\n                href: `${assetPrefix}/_next/${(0, _encodeuripath.encodeURIPath)(file)}${assetQueryString}`,\n                crossOrigin: this.props.crossOrigin || crossOrigin,\n                \"data-n-g\": isUnmanagedFile ? undefined : isSharedFile ? \"\" : undefined,\n                \"data-n-p\": isUnmanagedFile ? undefined : isSharedFile ? undefined : \"\"\n            }, file));\n        });\n        if (false) {}\n        return cssLinkElements.length === 0 ? null : cssLinkElements;\n    }\n    getPreloadDynamicChunks() {\n        const { dynamicImports, assetPrefix, assetQueryString, crossOrigin } = this.context;\n        return dynamicImports.map((file)=>{\n            if (!file.endsWith(\".js\")) {\n                return null;\n            }\n            return /*#__PURE__*/ (0, _jsxruntime.jsx)(\"link\", {\n                rel: \"preload\",\n                href: `${assetPrefix}/_next/${(0, _encodeuripath.encodeURIPath)(file)}${assetQueryString}`,\n                as: \"script\",\n                nonce: this.props.nonce,\n                crossOrigin: this.props.crossOrigin || crossOrigin\n            }, file);\n        }) // Filter out nulled scripts\n        .filter(Boolean);\n    }\n    getPreloadMainLinks(files) {\n        const { assetPrefix, assetQueryString, scriptLoader, crossOrigin } = this.context;\n        const preloadFiles = files.allFiles.filter((file)=>{\n            return file.endsWith(\".js\");\n        });\n        return [\n            ...(scriptLoader.beforeInteractive || []).map((file)=>/*#__PURE__*/ (0, _jsxruntime.jsx)(\"link\", {\n                    nonce: this.props.nonce,\n                    rel: \"preload\",\n                    href: file.src,\n                    as: \"script\",\n                    crossOrigin: this.props.crossOrigin || crossOrigin\n                }, file.src)),\n            ...preloadFiles.map((file)=>/*#__PURE__*/ (0, _jsxruntime.jsx)(\"link\", {\n                    nonce: this.props.nonce,\n                    rel: \"preload\",\n                    href: `${assetPrefix}/_next/${(0, _encodeuripath.encodeURIPath)(file)}${assetQueryString}`,\n                    as: \"script\",\n                    crossOrigin: this.props.crossOrigin || crossOrigin\n                }, file))\n        ];\n    }\n    getBeforeInteractiveInlineScripts() {\n        const { scriptLoader } = this.context;\n        const { nonce, crossOrigin } = this.props;\n        return (scriptLoader.beforeInteractive || []).filter((script)=>!script.src && (script.dangerouslySetInnerHTML || script.children)).map((file, index)=>{\n            const { strategy, children, dangerouslySetInnerHTML, src, ...scriptProps } = file;\n            let html = \"\";\n            if (dangerouslySetInnerHTML && dangerouslySetInnerHTML.__html) {\n                html = dangerouslySetInnerHTML.__html;\n            } else if (children) {\n                html = typeof children === \"string\" ? children : Array.isArray(children) ? children.join(\"\") : \"\";\n            }\n            return /*#__PURE__*/ (0, _react.createElement)(\"script\", {\n                ...scriptProps,\n                dangerouslySetInnerHTML: {\n                    __html: html\n                },\n                key: scriptProps.id || index,\n                nonce: nonce,\n                \"data-nscript\": \"beforeInteractive\",\n                crossOrigin: crossOrigin || undefined\n            });\n        });\n    }\n    getDynamicChunks(files) {\n        return getDynamicChunks(this.context, this.props, files);\n    }\n    getPreNextScripts() {\n        return getPreNextScripts(this.context, this.props);\n    }\n    getScripts(files) {\n        return getScripts(this.context, this.props, files);\n    }\n    getPolyfillScripts() {\n        return getPolyfillScripts(this.context, this.props);\n    }\n    makeStylesheetInert(node) {\n        return _react.default.Children.map(node, (c)=>{\n            var _c_props, _c_props1;\n            if ((c == null ? void 0 : c.type) === \"link\" && (c == null ? void 0 : (_c_props = c.props) == null ? void 0 : _c_props.href) && _constants.OPTIMIZED_FONT_PROVIDERS.some(({ url })=>{\n                var _c_props_href, _c_props;\n                return c == null ? void 0 : (_c_props = c.props) == null ? void 0 : (_c_props_href = _c_props.href) == null ? void 0 : _c_props_href.startsWith(url);\n            })) {\n                const newProps = {\n                    ...c.props || {},\n                    \"data-href\": c.props.href,\n                    href: undefined\n                };\n                return /*#__PURE__*/ _react.default.cloneElement(c, newProps);\n            } else if (c == null ? void 0 : (_c_props1 = c.props) == null ? void 0 : _c_props1.children) {\n                const newProps = {\n                    ...c.props || {},\n                    children: this.makeStylesheetInert(c.props.children)\n                };\n                return /*#__PURE__*/ _react.default.cloneElement(c, newProps);\n            }\n            return c;\n        // @types/react bug. Returned value from .map will not be `null` if you pass in `[null]`\n        }).filter(Boolean);\n    }\n    render() {\n        const { styles, ampPath, inAmpMode, hybridAmp, canonicalBase, __NEXT_DATA__, dangerousAsPath, headTags, unstable_runtimeJS, unstable_JsPreload, disableOptimizedLoading, optimizeCss, optimizeFonts, assetPrefix, nextFontManifest } = this.context;\n        const disableRuntimeJS = unstable_runtimeJS === false;\n        const disableJsPreload = unstable_JsPreload === false || !disableOptimizedLoading;\n        this.context.docComponentsRendered.Head = true;\n        let { head } = this.context;\n        let cssPreloads = [];\n        let otherHeadElements = [];\n        if (head) {\n            head.forEach((c)=>{\n                let metaTag;\n                if (this.context.strictNextHead) {\n                    metaTag = /*#__PURE__*/ _react.default.createElement(\"meta\", {\n                        name: \"next-head\",\n                        content: \"1\"\n                    });\n                }\n                if (c && c.type === \"link\" && c.props[\"rel\"] === \"preload\" && c.props[\"as\"] === \"style\") {\n                    metaTag && cssPreloads.push(metaTag);\n                    cssPreloads.push(c);\n                } else {\n                    if (c) {\n                        if (metaTag && (c.type !== \"meta\" || !c.props[\"charSet\"])) {\n                            otherHeadElements.push(metaTag);\n                        }\n                        otherHeadElements.push(c);\n                    }\n                }\n            });\n            head = cssPreloads.concat(otherHeadElements);\n        }\n        let children = _react.default.Children.toArray(this.props.children).filter(Boolean);\n        // show a warning if Head contains <title> (only in development)\n        if (true) {\n            children = _react.default.Children.map(children, (child)=>{\n                var _child_props;\n                const isReactHelmet = child == null ? void 0 : (_child_props = child.props) == null ? void 0 : _child_props[\"data-react-helmet\"];\n                if (!isReactHelmet) {\n                    var _child_props1;\n                    if ((child == null ? void 0 : child.type) === \"title\") {\n                        console.warn(\"Warning: <title> should not be used in _document.js's <Head>. https://nextjs.org/docs/messages/no-document-title\");\n                    } else if ((child == null ? void 0 : child.type) === \"meta\" && (child == null ? void 0 : (_child_props1 = child.props) == null ? void 0 : _child_props1.name) === \"viewport\") {\n                        console.warn(\"Warning: viewport meta tags should not be used in _document.js's <Head>. https://nextjs.org/docs/messages/no-document-viewport-meta\");\n                    }\n                }\n                return child;\n            // @types/react bug. Returned value from .map will not be `null` if you pass in `[null]`\n            });\n            if (this.props.crossOrigin) console.warn(\"Warning: `Head` attribute `crossOrigin` is deprecated. https://nextjs.org/docs/messages/doc-crossorigin-deprecated\");\n        }\n        if (false) {}\n        let hasAmphtmlRel = false;\n        let hasCanonicalRel = false;\n        // show warning and remove conflicting amp head tags\n        head = _react.default.Children.map(head || [], (child)=>{\n            if (!child) return child;\n            const { type, props } = child;\n            if ( true && inAmpMode) {\n                let badProp = \"\";\n                if (type === \"meta\" && props.name === \"viewport\") {\n                    badProp = 'name=\"viewport\"';\n                } else if (type === \"link\" && props.rel === \"canonical\") {\n                    hasCanonicalRel = true;\n                } else if (type === \"script\") {\n                    // only block if\n                    // 1. it has a src and isn't pointing to ampproject's CDN\n                    // 2. it is using dangerouslySetInnerHTML without a type or\n                    // a type of text/javascript\n                    if (props.src && props.src.indexOf(\"ampproject\") < -1 || props.dangerouslySetInnerHTML && (!props.type || props.type === \"text/javascript\")) {\n                        badProp = \"<script\";\n                        Object.keys(props).forEach((prop)=>{\n                            badProp += ` ${prop}=\"${props[prop]}\"`;\n                        });\n                        badProp += \"/>\";\n                    }\n                }\n                if (badProp) {\n                    console.warn(`Found conflicting amp tag \"${child.type}\" with conflicting prop ${badProp} in ${__NEXT_DATA__.page}. https://nextjs.org/docs/messages/conflicting-amp-tag`);\n                    return null;\n                }\n            } else {\n                // non-amp mode\n                if (type === \"link\" && props.rel === \"amphtml\") {\n                    hasAmphtmlRel = true;\n                }\n            }\n            return child;\n        // @types/react bug. Returned value from .map will not be `null` if you pass in `[null]`\n        });\n        const files = getDocumentFiles(this.context.buildManifest, this.context.__NEXT_DATA__.page,  true && inAmpMode);\n        const nextFontLinkTags = getNextFontLinkTags(nextFontManifest, dangerousAsPath, assetPrefix);\n        return /*#__PURE__*/ (0, _jsxruntime.jsxs)(\"head\", {\n            ...getHeadHTMLProps(this.props),\n            children: [\n                this.context.isDevelopment && /*#__PURE__*/ (0, _jsxruntime.jsxs)(_jsxruntime.Fragment, {\n                    children: [\n                        /*#__PURE__*/ (0, _jsxruntime.jsx)(\"style\", {\n                            \"data-next-hide-fouc\": true,\n                            \"data-ampdevmode\":  true && inAmpMode ? \"true\" : undefined,\n                            dangerouslySetInnerHTML: {\n                                __html: `body{display:none}`\n                            }\n                        }),\n                        /*#__PURE__*/ (0, _jsxruntime.jsx)(\"noscript\", {\n                            \"data-next-hide-fouc\": true,\n                            \"data-ampdevmode\":  true && inAmpMode ? \"true\" : undefined,\n                            children: /*#__PURE__*/ (0, _jsxruntime.jsx)(\"style\", {\n                                dangerouslySetInnerHTML: {\n                                    __html: `body{display:block}`\n                                }\n                            })\n                        })\n                    ]\n                }),\n                head,\n                this.context.strictNextHead ? null : /*#__PURE__*/ (0, _jsxruntime.jsx)(\"meta\", {\n                    name: \"next-head-count\",\n                    content: _react.default.Children.count(head || []).toString()\n                }),\n                children,\n                optimizeFonts && /*#__PURE__*/ (0, _jsxruntime.jsx)(\"meta\", {\n                    name: \"next-font-preconnect\"\n                }),\n                nextFontLinkTags.preconnect,\n                nextFontLinkTags.preload,\n                 true && inAmpMode && /*#__PURE__*/ (0, _jsxruntime.jsxs)(_jsxruntime.Fragment, {\n                    children: [\n                        /*#__PURE__*/ (0, _jsxruntime.jsx)(\"meta\", {\n                            name: \"viewport\",\n                            content: \"width=device-width,minimum-scale=1,initial-scale=1\"\n                        }),\n                        !hasCanonicalRel && /*#__PURE__*/ (0, _jsxruntime.jsx)(\"link\", {\n                            rel: \"canonical\",\n                            href: canonicalBase + (__webpack_require__(/*! ../server/utils */ \"./node_modules/next/dist/server/utils.js\").cleanAmpPath)(dangerousAsPath)\n                        }),\n                        /*#__PURE__*/ (0, _jsxruntime.jsx)(\"link\", {\n                            rel: \"preload\",\n                            as: \"script\",\n                            href: \"https://cdn.ampproject.org/v0.js\"\n                        }),\n                        /*#__PURE__*/ (0, _jsxruntime.jsx)(AmpStyles, {\n                            styles: styles\n                        }),\n                        /*#__PURE__*/ (0, _jsxruntime.jsx)(\"style\", {\n                            \"amp-boilerplate\": \"\",\n                            dangerouslySetInnerHTML: {\n                                __html: `body{-webkit-animation:-amp-start 8s steps(1,end) 0s 1 normal both;-moz-animation:-amp-start 8s steps(1,end) 0s 1 normal both;-ms-animation:-amp-start 8s steps(1,end) 0s 1 normal both;animation:-amp-start 8s steps(1,end) 0s 1 normal both}@-webkit-keyframes -amp-start{from{visibility:hidden}to{visibility:visible}}@-moz-keyframes -amp-start{from{visibility:hidden}to{visibility:visible}}@-ms-keyframes -amp-start{from{visibility:hidden}to{visibility:visible}}@-o-keyframes -amp-start{from{visibility:hidden}to{visibility:visible}}@keyframes -amp-start{from{visibility:hidden}to{visibility:visible}}`\n                            }\n                        }),\n                        /*#__PURE__*/ (0, _jsxruntime.jsx)(\"noscript\", {\n                            children: /*#__PURE__*/ (0, _jsxruntime.jsx)(\"style\", {\n                                \"amp-boilerplate\": \"\",\n                                dangerouslySetInnerHTML: {\n                                    __html: `body{-webkit-animation:none;-moz-animation:none;-ms-animation:none;animation:none}`\n                                }\n                            })\n                        }),\n                        /*#__PURE__*/ (0, _jsxruntime.jsx)(\"script\", {\n                            async: true,\n                            src: \"https://cdn.ampproject.org/v0.js\"\n                        })\n                    ]\n                }),\n                !( true && inAmpMode) && /*#__PURE__*/ (0, _jsxruntime.jsxs)(_jsxruntime.Fragment, {\n                    children: [\n                        !hasAmphtmlRel && hybridAmp && /*#__PURE__*/ (0, _jsxruntime.jsx)(\"link\", {\n                            rel: \"amphtml\",\n                            href: canonicalBase + getAmpPath(ampPath, dangerousAsPath)\n                        }),\n                        this.getBeforeInteractiveInlineScripts(),\n                        !optimizeCss && this.getCssLinks(files),\n                        !optimizeCss && /*#__PURE__*/ (0, _jsxruntime.jsx)(\"noscript\", {\n                            \"data-n-css\": this.props.nonce ?? \"\"\n                        }),\n                        !disableRuntimeJS && !disableJsPreload && this.getPreloadDynamicChunks(),\n                        !disableRuntimeJS && !disableJsPreload && this.getPreloadMainLinks(files),\n                        !disableOptimizedLoading && !disableRuntimeJS && this.getPolyfillScripts(),\n                        !disableOptimizedLoading && !disableRuntimeJS && this.getPreNextScripts(),\n                        !disableOptimizedLoading && !disableRuntimeJS && this.getDynamicChunks(files),\n                        !disableOptimizedLoading && !disableRuntimeJS && this.getScripts(files),\n                        optimizeCss && this.getCssLinks(files),\n                        optimizeCss && /*#__PURE__*/ (0, _jsxruntime.jsx)(\"noscript\", {\n                            \"data-n-css\": this.props.nonce ?? \"\"\n                        }),\n                        this.context.isDevelopment && // this element is used to mount development styles so the\n                        // ordering matches production\n                        // (by default, style-loader injects at the bottom of <head />)\n                        /*#__PURE__*/ (0, _jsxruntime.jsx)(\"noscript\", {\n                            id: \"__next_css__DO_NOT_USE__\"\n                        }),\n                        styles || null\n                    ]\n                }),\n                /*#__PURE__*/ _react.default.createElement(_react.default.Fragment, {}, ...headTags || [])\n            ]\n        });\n    }\n}\nfunction handleDocumentScriptLoaderItems(scriptLoader, __NEXT_DATA__, props) {\n    var _children_find_props, _children_find, _children_find_props1, _children_find1;\n    if (!props.children) return;\n    const scriptLoaderItems = [];\n    const children = Array.isArray(props.children) ? props.children : [\n        props.children\n    ];\n    const headChildren = (_children_find = children.find((child)=>child.type === Head)) == null ? void 0 : (_children_find_props = _children_find.props) == null ? void 0 : _children_find_props.children;\n    const bodyChildren = (_children_find1 = children.find((child)=>child.type === \"body\")) == null ? void 0 : (_children_find_props1 = _children_find1.props) == null ? void 0 : _children_find_props1.children;\n    // Scripts with beforeInteractive can be placed inside Head or <body> so children of both needs to be traversed\n    const combinedChildren = [\n        ...Array.isArray(headChildren) ? headChildren : [\n            headChildren\n        ],\n        ...Array.isArray(bodyChildren) ? bodyChildren : [\n            bodyChildren\n        ]\n    ];\n    _react.default.Children.forEach(combinedChildren, (child)=>{\n        var _child_type;\n        if (!child) return;\n        // When using the `next/script` component, register it in script loader.\n        if ((_child_type = child.type) == null ? void 0 : _child_type.__nextScript) {\n            if (child.props.strategy === \"beforeInteractive\") {\n                scriptLoader.beforeInteractive = (scriptLoader.beforeInteractive || []).concat([\n                    {\n                        ...child.props\n                    }\n                ]);\n                return;\n            } else if ([\n                \"lazyOnload\",\n                \"afterInteractive\",\n                \"worker\"\n            ].includes(child.props.strategy)) {\n                scriptLoaderItems.push(child.props);\n                return;\n            }\n        }\n    });\n    __NEXT_DATA__.scriptLoader = scriptLoaderItems;\n}\nclass NextScript extends _react.default.Component {\n    static #_ = this.contextType = _htmlcontextsharedruntime.HtmlContext;\n    getDynamicChunks(files) {\n        return getDynamicChunks(this.context, this.props, files);\n    }\n    getPreNextScripts() {\n        return getPreNextScripts(this.context, this.props);\n    }\n    getScripts(files) {\n        return getScripts(this.context, this.props, files);\n    }\n    getPolyfillScripts() {\n        return getPolyfillScripts(this.context, this.props);\n    }\n    static getInlineScriptSource(context) {\n        const { __NEXT_DATA__, largePageDataBytes } = context;\n        try {\n            const data = JSON.stringify(__NEXT_DATA__);\n            if (largePageDataWarnings.has(__NEXT_DATA__.page)) {\n                return (0, _htmlescape.htmlEscapeJsonString)(data);\n            }\n            const bytes =  false ? 0 : Buffer.from(data).byteLength;\n            const prettyBytes = (__webpack_require__(/*! ../lib/pretty-bytes */ \"./node_modules/next/dist/lib/pretty-bytes.js\")[\"default\"]);\n            if (largePageDataBytes && bytes > largePageDataBytes) {\n                if (false) {}\n                console.warn(`Warning: data for page \"${__NEXT_DATA__.page}\"${__NEXT_DATA__.page === context.dangerousAsPath ? \"\" : ` (path \"${context.dangerousAsPath}\")`} is ${prettyBytes(bytes)} which exceeds the threshold of ${prettyBytes(largePageDataBytes)}, this amount of data can reduce performance.\\nSee more info here: https://nextjs.org/docs/messages/large-page-data`);\n            }\n            return (0, _htmlescape.htmlEscapeJsonString)(data);\n        } catch (err) {\n            if ((0, _iserror.default)(err) && err.message.indexOf(\"circular structure\") !== -1) {\n                throw new Error(`Circular structure in \"getInitialProps\" result of page \"${__NEXT_DATA__.page}\". https://nextjs.org/docs/messages/circular-structure`);\n            }\n            throw err;\n        }\n    }\n    render() {\n        const { assetPrefix, inAmpMode, buildManifest, unstable_runtimeJS, docComponentsRendered, assetQueryString, disableOptimizedLoading, crossOrigin } = this.context;\n        const disableRuntimeJS = unstable_runtimeJS === false;\n        docComponentsRendered.NextScript = true;\n        if ( true && inAmpMode) {\n            if (false) {}\n            const ampDevFiles = [\n                ...buildManifest.devFiles,\n                ...buildManifest.polyfillFiles,\n                ...buildManifest.ampDevFiles\n            ];\n            return /*#__PURE__*/ (0, _jsxruntime.jsxs)(_jsxruntime.Fragment, {\n                children: [\n                    disableRuntimeJS ? null : /*#__PURE__*/ (0, _jsxruntime.jsx)(\"script\", {\n                        id: \"__NEXT_DATA__\",\n                        type: \"application/json\",\n                        nonce: this.props.nonce,\n                        crossOrigin: this.props.crossOrigin || crossOrigin,\n                        dangerouslySetInnerHTML: {\n                            __html: NextScript.getInlineScriptSource(this.context)\n                        },\n                        \"data-ampdevmode\": true\n                    }),\n                    ampDevFiles.map((file)=>/*#__PURE__*/ (0, _jsxruntime.jsx)(\"script\", {\n                            src: `${assetPrefix}/_next/${(0, _encodeuripath.encodeURIPath)(file)}${assetQueryString}`,\n                            nonce: this.props.nonce,\n                            crossOrigin: this.props.crossOrigin || crossOrigin,\n                            \"data-ampdevmode\": true\n                        }, file))\n                ]\n            });\n        }\n        if (true) {\n            if (this.props.crossOrigin) console.warn(\"Warning: `NextScript` attribute `crossOrigin` is deprecated. https://nextjs.org/docs/messages/doc-crossorigin-deprecated\");\n        }\n        const files = getDocumentFiles(this.context.buildManifest, this.context.__NEXT_DATA__.page,  true && inAmpMode);\n        return /*#__PURE__*/ (0, _jsxruntime.jsxs)(_jsxruntime.Fragment, {\n            children: [\n                !disableRuntimeJS && buildManifest.devFiles ? buildManifest.devFiles.map((file)=>/*#__PURE__*/ (0, _jsxruntime.jsx)(\"script\", {\n                        src: `${assetPrefix}/_next/${(0, _encodeuripath.encodeURIPath)(file)}${assetQueryString}`,\n                        nonce: this.props.nonce,\n                        crossOrigin: this.props.crossOrigin || crossOrigin\n                    }, file)) : null,\n                disableRuntimeJS ? null : /*#__PURE__*/ (0, _jsxruntime.jsx)(\"script\", {\n                    id: \"__NEXT_DATA__\",\n                    type: \"application/json\",\n                    nonce: this.props.nonce,\n                    crossOrigin: this.props.crossOrigin || crossOrigin,\n                    dangerouslySetInnerHTML: {\n                        __html: NextScript.getInlineScriptSource(this.context)\n                    }\n                }),\n                disableOptimizedLoading && !disableRuntimeJS && this.getPolyfillScripts(),\n                disableOptimizedLoading && !disableRuntimeJS && this.getPreNextScripts(),\n                disableOptimizedLoading && !disableRuntimeJS && this.getDynamicChunks(files),\n                disableOptimizedLoading && !disableRuntimeJS && this.getScripts(files)\n            ]\n        });\n    }\n}\nfunction Html(props) {\n    const { inAmpMode, docComponentsRendered, locale, scriptLoader, __NEXT_DATA__ } = (0, _htmlcontextsharedruntime.useHtmlContext)();\n    docComponentsRendered.Html = true;\n    handleDocumentScriptLoaderItems(scriptLoader, __NEXT_DATA__, props);\n    return /*#__PURE__*/ (0, _jsxruntime.jsx)(\"html\", {\n        ...props,\n        lang: props.lang || locale || undefined,\n        amp:  true && inAmpMode ? \"\" : undefined,\n        \"data-ampdevmode\":  true && inAmpMode && \"development\" !== \"production\" ? \"\" : undefined\n    });\n}\nfunction Main() {\n    const { docComponentsRendered } = (0, _htmlcontextsharedruntime.useHtmlContext)();\n    docComponentsRendered.Main = true;\n    // @ts-ignore\n    return /*#__PURE__*/ (0, _jsxruntime.jsx)(\"next-js-internal-body-render-target\", {});\n}\nclass Document extends _react.default.Component {\n    /**\n   * `getInitialProps` hook returns the context object with the addition of `renderPage`.\n   * `renderPage` callback executes `React` rendering logic synchronously to support server-rendering wrappers\n   */ static getInitialProps(ctx) {\n        return ctx.defaultGetInitialProps(ctx);\n    }\n    render() {\n        return /*#__PURE__*/ (0, _jsxruntime.jsxs)(Html, {\n            children: [\n                /*#__PURE__*/ (0, _jsxruntime.jsx)(Head, {}),\n                /*#__PURE__*/ (0, _jsxruntime.jsxs)(\"body\", {\n                    children: [\n                        /*#__PURE__*/ (0, _jsxruntime.jsx)(Main, {}),\n                        /*#__PURE__*/ (0, _jsxruntime.jsx)(NextScript, {})\n                    ]\n                })\n            ]\n        });\n    }\n}\n// Add a special property to the built-in `Document` component so later we can\n// identify if a user customized `Document` is used or not.\nconst InternalFunctionDocument = function InternalFunctionDocument() {\n    return /*#__PURE__*/ (0, _jsxruntime.jsxs)(Html, {\n        children: [\n            /*#__PURE__*/ (0, _jsxruntime.jsx)(Head, {}),\n            /*#__PURE__*/ (0, _jsxruntime.jsxs)(\"body\", {\n                children: [\n                    /*#__PURE__*/ (0, _jsxruntime.jsx)(Main, {}),\n                    /*#__PURE__*/ (0, _jsxruntime.jsx)(NextScript, {})\n                ]\n            })\n        ]\n    });\n};\nDocument[_constants.NEXT_BUILTIN_DOCUMENT] = InternalFunctionDocument; //# sourceMappingURL=_document.js.map\n//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvbmV4dC9kaXN0L3BhZ2VzL19kb2N1bWVudC5qcyIsIm1hcHBpbmdzIjoiOzs7Ozs7Ozs7Ozs7SUFzYWFBLE1BQUk7ZUFBSkE7O0lBNHVCR0MsTUFBSTtlQUFKQTs7SUFpQ0FDLE1BQUk7ZUFBSkE7O0lBN01IQyxZQUFVO2VBQVZBOztJQW9OYjs7O0NBR0MsR0FDREMsU0FzQkM7ZUF0Qm9CQzs7OzsyRUE5ckNIO3VDQUtYOzBDQVdzQjt3Q0FFUTs0RUFDakI7c0RBS2I7MkNBRXVCOzs7Ozs7Ozs7Ozs7Ozs7Ozs7Ozs7Ozs7Ozs7Ozs7Ozs7Ozs7Ozs7Ozs7Ozs7OztBQXVCOUIsOEVBQThFLEdBQzlFLE1BQU1DLHdCQUF3QixJQUFJQztBQUVsQyxTQUFTQyxpQkFDUEMsYUFBNEIsRUFDNUJDLFFBQWdCLEVBQ2hCQyxTQUFrQjtJQUVsQixNQUFNQyxjQUFpQ0MsQ0FBQUEsR0FBQUEsY0FBQUEsWUFBWSxFQUFDSixlQUFlO0lBQ25FLE1BQU1LLFlBQ0pDLEtBQTZCLElBQVVKLFlBQ25DLEVBQUUsR0FDRkUsQ0FBQUEsR0FBQUEsY0FBQUEsWUFBWSxFQUFDSixlQUFlQztJQUVsQyxPQUFPO1FBQ0xFO1FBQ0FFO1FBQ0FJLFVBQVU7ZUFBSSxJQUFJWCxJQUFJO21CQUFJSzttQkFBZ0JFO2FBQVU7U0FBRTtJQUN4RDtBQUNGO0FBRUEsU0FBU0ssbUJBQW1CQyxPQUFrQixFQUFFQyxLQUFrQjtJQUNoRSw0REFBNEQ7SUFDNUQsNkNBQTZDO0lBQzdDLE1BQU0sRUFDSkMsV0FBVyxFQUNYYixhQUFhLEVBQ2JjLGdCQUFnQixFQUNoQkMsdUJBQXVCLEVBQ3ZCQyxXQUFXLEVBQ1osR0FBR0w7SUFFSixPQUFPWCxjQUFjaUIsYUFBYSxDQUMvQkMsTUFBTSxDQUNMLENBQUNDLFdBQWFBLFNBQVNDLFFBQVEsQ0FBQyxVQUFVLENBQUNELFNBQVNDLFFBQVEsQ0FBQyxlQUU5REMsR0FBRyxDQUFDLENBQUNGLFdBQ0osV0FESUEsR0FDSixJQUFBRyxZQUFBQyxHQUFBLEVBQUNDLFVBQUFBO1lBRUNDLE9BQU8sQ0FBQ1Y7WUFDUlcsT0FBT2QsTUFBTWMsS0FBSztZQUNsQlYsYUFBYUosTUFBTUksV0FBVyxJQUFJQTtZQUNsQ1csVUFBVTtZQUNWQyxLQUFLLENBQUMsRUFBRWYsWUFBWSxPQUFPLEVBQUVnQixDQUFBQSxHQUFBQSxlQUFBQSxhQUFhLEVBQ3hDVixVQUNBLEVBQUVMLGlCQUFpQixDQUFDO1dBUGpCSztBQVViO0FBRUEsU0FBU1csa0JBQWtCQyxLQUFVO0lBQ25DLE9BQU8sQ0FBQyxDQUFDQSxTQUFTLENBQUMsQ0FBQ0EsTUFBTW5CLEtBQUs7QUFDakM7QUFFQSxTQUFTb0IsVUFBVSxFQUNqQkMsTUFBTSxFQUdQO0lBQ0MsSUFBSSxDQUFDQSxRQUFRLE9BQU87SUFFcEIseURBQXlEO0lBQ3pELE1BQU1DLFlBQWtDQyxNQUFNQyxPQUFPLENBQUNILFVBQ2pEQSxTQUNELEVBQUU7SUFDTixJQUVFQSxPQUFPckIsS0FBSyxJQUNaLGtFQUFrRTtJQUNsRXVCLE1BQU1DLE9BQU8sQ0FBQ0gsT0FBT3JCLEtBQUssQ0FBQ3lCLFFBQVEsR0FDbkM7UUFDQSxNQUFNQyxZQUFZLENBQUNDO2dCQUNqQkEsbUNBQUFBO21CQUFBQSxNQUFBQSxPQUFBQSxLQUFBQSxJQUFBQSxDQUFBQSxZQUFBQSxHQUFJM0IsS0FBSyxzQkFBVDJCLG9DQUFBQSxVQUFXQyx1QkFBdUIscUJBQWxDRCxrQ0FBb0NFLE1BQU07O1FBQzVDLGtFQUFrRTtRQUNsRVIsT0FBT3JCLEtBQUssQ0FBQ3lCLFFBQVEsQ0FBQ0ssT0FBTyxDQUFDLENBQUNYO1lBQzdCLElBQUlJLE1BQU1DLE9BQU8sQ0FBQ0wsUUFBUTtnQkFDeEJBLE1BQU1XLE9BQU8sQ0FBQyxDQUFDSCxLQUFPRCxVQUFVQyxPQUFPTCxVQUFVUyxJQUFJLENBQUNKO1lBQ3hELE9BQU8sSUFBSUQsVUFBVVAsUUFBUTtnQkFDM0JHLFVBQVVTLElBQUksQ0FBQ1o7WUFDakI7UUFDRjtJQUNGO0lBRUEsdUVBQXVFLEdBQ3ZFLE9BQ0UsV0FERixHQUNFLElBQUFULFlBQUFDLEdBQUEsRUFBQ3FCLFNBQUFBO1FBQ0NDLGNBQVc7UUFDWEwseUJBQXlCO1lBQ3ZCQyxRQUFRUCxVQUNMYixHQUFHLENBQUMsQ0FBQ3VCLFFBQVVBLE1BQU1oQyxLQUFLLENBQUM0Qix1QkFBdUIsQ0FBQ0MsTUFBTSxFQUN6REssSUFBSSxDQUFDLElBQ0xDLE9BQU8sQ0FBQyxrQ0FBa0MsSUFDMUNBLE9BQU8sQ0FBQyw0QkFBNEI7UUFDekM7O0FBR047QUFFQSxTQUFTQyxpQkFDUHJDLE9BQWtCLEVBQ2xCQyxLQUFrQixFQUNsQnFDLEtBQW9CO0lBRXBCLE1BQU0sRUFDSkMsY0FBYyxFQUNkckMsV0FBVyxFQUNYc0MsYUFBYSxFQUNickMsZ0JBQWdCLEVBQ2hCQyx1QkFBdUIsRUFDdkJDLFdBQVcsRUFDWixHQUFHTDtJQUVKLE9BQU91QyxlQUFlN0IsR0FBRyxDQUFDLENBQUMrQjtRQUN6QixJQUFJLENBQUNBLEtBQUtoQyxRQUFRLENBQUMsVUFBVTZCLE1BQU14QyxRQUFRLENBQUM0QyxRQUFRLENBQUNELE9BQU8sT0FBTztRQUVuRSxPQUNFLFdBREYsR0FDRSxJQUFBOUIsWUFBQUMsR0FBQSxFQUFDQyxVQUFBQTtZQUNDOEIsT0FBTyxDQUFDSCxpQkFBaUJwQztZQUN6QlUsT0FBTyxDQUFDVjtZQUVSYSxLQUFLLENBQUMsRUFBRWYsWUFBWSxPQUFPLEVBQUVnQixDQUFBQSxHQUFBQSxlQUFBQSxhQUFhLEVBQUN1QixNQUFNLEVBQUV0QyxpQkFBaUIsQ0FBQztZQUNyRVksT0FBT2QsTUFBTWMsS0FBSztZQUNsQlYsYUFBYUosTUFBTUksV0FBVyxJQUFJQTtXQUg3Qm9DO0lBTVg7QUFDRjtBQUVBLFNBQVNHLFdBQ1A1QyxPQUFrQixFQUNsQkMsS0FBa0IsRUFDbEJxQyxLQUFvQjtRQVlPakQ7SUFWM0IsTUFBTSxFQUNKYSxXQUFXLEVBQ1hiLGFBQWEsRUFDYm1ELGFBQWEsRUFDYnJDLGdCQUFnQixFQUNoQkMsdUJBQXVCLEVBQ3ZCQyxXQUFXLEVBQ1osR0FBR0w7SUFFSixNQUFNNkMsZ0JBQWdCUCxNQUFNeEMsUUFBUSxDQUFDUyxNQUFNLENBQUMsQ0FBQ2tDLE9BQVNBLEtBQUtoQyxRQUFRLENBQUM7SUFDcEUsTUFBTXFDLHFCQUFBQSxDQUFxQnpELGtDQUFBQSxjQUFjMEQsZ0JBQWdCLHFCQUE5QjFELGdDQUFnQ2tCLE1BQU0sQ0FBQyxDQUFDa0MsT0FDakVBLEtBQUtoQyxRQUFRLENBQUM7SUFHaEIsT0FBTztXQUFJb0M7V0FBa0JDO0tBQW1CLENBQUNwQyxHQUFHLENBQUMsQ0FBQytCO1FBQ3BELE9BQ0UsV0FERixHQUNFLElBQUE5QixZQUFBQyxHQUFBLEVBQUNDLFVBQUFBO1lBRUNJLEtBQUssQ0FBQyxFQUFFZixZQUFZLE9BQU8sRUFBRWdCLENBQUFBLEdBQUFBLGVBQUFBLGFBQWEsRUFBQ3VCLE1BQU0sRUFBRXRDLGlCQUFpQixDQUFDO1lBQ3JFWSxPQUFPZCxNQUFNYyxLQUFLO1lBQ2xCNEIsT0FBTyxDQUFDSCxpQkFBaUJwQztZQUN6QlUsT0FBTyxDQUFDVjtZQUNSQyxhQUFhSixNQUFNSSxXQUFXLElBQUlBO1dBTDdCb0M7SUFRWDtBQUNGO0FBRUEsU0FBU08sd0JBQXdCaEQsT0FBa0IsRUFBRUMsS0FBa0I7SUFDckUsTUFBTSxFQUFFQyxXQUFXLEVBQUUrQyxZQUFZLEVBQUU1QyxXQUFXLEVBQUU2QyxpQkFBaUIsRUFBRSxHQUFHbEQ7SUFFdEUsOENBQThDO0lBQzlDLElBQUksQ0FBQ2tELHFCQUFxQnZELFFBQXdCLEtBQUssUUFBUSxPQUFPO0lBRXRFLElBQUk7UUFDRixJQUFJLEVBQ0Z3RCxnQkFBZ0IsRUFFakIsR0FBR0MsT0FBQUEsQ0FBd0I7UUFFNUIsTUFBTTFCLFdBQVdGLE1BQU1DLE9BQU8sQ0FBQ3hCLE1BQU15QixRQUFRLElBQ3pDekIsTUFBTXlCLFFBQVEsR0FDZDtZQUFDekIsTUFBTXlCLFFBQVE7U0FBQztRQUVwQix5RUFBeUU7UUFDekUsTUFBTTJCLG9CQUFvQjNCLFNBQVM0QixJQUFJLENBQ3JDLENBQUNsQztnQkFFQ0Esc0NBQUFBO21CQURBRCxrQkFBa0JDLFVBQ2xCQSxDQUFBQSxTQUFBQSxPQUFBQSxLQUFBQSxJQUFBQSxDQUFBQSxlQUFBQSxNQUFPbkIsS0FBSyxzQkFBWm1CLHVDQUFBQSxhQUFjUyx1QkFBdUIscUJBQXJDVCxxQ0FBdUNVLE1BQU0sQ0FBQ3lCLE1BQU0sS0FDcEQsMkJBQTJCbkMsTUFBTW5CLEtBQUs7O1FBRzFDLE9BQ0UsV0FERixHQUNFLElBQUFVLFlBQUE2QyxJQUFBLEVBQUE3QyxZQUFBOEMsUUFBQTs7Z0JBQ0csQ0FBQ0oscUJBQ0EsV0FEQUEsR0FDQSxJQUFBMUMsWUFBQUMsR0FBQSxFQUFDQyxVQUFBQTtvQkFDQzZDLHlCQUFzQjtvQkFDdEI3Qix5QkFBeUI7d0JBQ3ZCQyxRQUFRLENBQUM7O29CQUVILEVBQUU1QixZQUFZOztVQUV4QixDQUFDO29CQUNDOzs4QkFHSixJQUFBUyxZQUFBQyxHQUFBLEVBQUNDLFVBQUFBO29CQUNDOEMsa0JBQWU7b0JBQ2Y5Qix5QkFBeUI7d0JBQ3ZCQyxRQUFRcUI7b0JBQ1Y7O2dCQUVBRixDQUFBQSxhQUFhVyxNQUFNLElBQUksRUFBRSxFQUFFbEQsR0FBRyxDQUFDLENBQUMrQixNQUFtQm9CO29CQUNuRCxNQUFNLEVBQ0pDLFFBQVEsRUFDUjdDLEdBQUcsRUFDSFMsVUFBVXFDLGNBQWMsRUFDeEJsQyx1QkFBdUIsRUFDdkIsR0FBR21DLGFBQ0osR0FBR3ZCO29CQUVKLElBQUl3QixXQUdBLENBQUM7b0JBRUwsSUFBSWhELEtBQUs7d0JBQ1AsK0JBQStCO3dCQUMvQmdELFNBQVNoRCxHQUFHLEdBQUdBO29CQUNqQixPQUFPLElBQ0xZLDJCQUNBQSx3QkFBd0JDLE1BQU0sRUFDOUI7d0JBQ0EsK0RBQStEO3dCQUMvRG1DLFNBQVNwQyx1QkFBdUIsR0FBRzs0QkFDakNDLFFBQVFELHdCQUF3QkMsTUFBTTt3QkFDeEM7b0JBQ0YsT0FBTyxJQUFJaUMsZ0JBQWdCO3dCQUN6QixnREFBZ0Q7d0JBQ2hERSxTQUFTcEMsdUJBQXVCLEdBQUc7NEJBQ2pDQyxRQUNFLE9BQU9pQyxtQkFBbUIsV0FDdEJBLGlCQUNBdkMsTUFBTUMsT0FBTyxDQUFDc0Msa0JBQ2RBLGVBQWU1QixJQUFJLENBQUMsTUFDcEI7d0JBQ1I7b0JBQ0YsT0FBTzt3QkFDTCxNQUFNLElBQUkrQixNQUNSO29CQUVKO29CQUVBLE9BQ0UsV0FERixHQUNFLElBQUFDLE9BQUFDLGFBQUEsRUFBQ3ZELFVBQUFBO3dCQUNFLEdBQUdvRCxRQUFRO3dCQUNYLEdBQUdELFdBQVc7d0JBQ2ZLLE1BQUs7d0JBQ0xDLEtBQUtyRCxPQUFPNEM7d0JBQ1o5QyxPQUFPZCxNQUFNYyxLQUFLO3dCQUNsQndELGdCQUFhO3dCQUNibEUsYUFBYUosTUFBTUksV0FBVyxJQUFJQTs7Z0JBR3hDOzs7SUFHTixFQUFFLE9BQU9tRSxLQUFLO1FBQ1osSUFBSUMsQ0FBQUEsR0FBQUEsU0FBQUEsT0FBTyxFQUFDRCxRQUFRQSxJQUFJRSxJQUFJLEtBQUssb0JBQW9CO1lBQ25EQyxRQUFRQyxJQUFJLENBQUMsQ0FBQyxTQUFTLEVBQUVKLElBQUlLLE9BQU8sQ0FBQyxDQUFDO1FBQ3hDO1FBQ0EsT0FBTztJQUNUO0FBQ0Y7QUFFQSxTQUFTQyxrQkFBa0I5RSxPQUFrQixFQUFFQyxLQUFrQjtJQUMvRCxNQUFNLEVBQUVnRCxZQUFZLEVBQUU3Qyx1QkFBdUIsRUFBRUMsV0FBVyxFQUFFLEdBQUdMO0lBRS9ELE1BQU0rRSxtQkFBbUIvQix3QkFBd0JoRCxTQUFTQztJQUUxRCxNQUFNK0UsMkJBQTJCLENBQUMvQixhQUFhZ0MsaUJBQWlCLElBQUksRUFBRSxFQUNuRTFFLE1BQU0sQ0FBQyxDQUFDTSxTQUFXQSxPQUFPSSxHQUFHLEVBQzdCUCxHQUFHLENBQUMsQ0FBQytCLE1BQW1Cb0I7UUFDdkIsTUFBTSxFQUFFQyxRQUFRLEVBQUUsR0FBR0UsYUFBYSxHQUFHdkI7UUFDckMsT0FDRSxXQURGLEdBQ0UsSUFBQTBCLE9BQUFDLGFBQUEsRUFBQ3ZELFVBQUFBO1lBQ0UsR0FBR21ELFdBQVc7WUFDZk0sS0FBS04sWUFBWS9DLEdBQUcsSUFBSTRDO1lBQ3hCL0MsT0FBT2tELFlBQVlsRCxLQUFLLElBQUksQ0FBQ1Y7WUFDN0JXLE9BQU9kLE1BQU1jLEtBQUs7WUFDbEJ3RCxnQkFBYTtZQUNibEUsYUFBYUosTUFBTUksV0FBVyxJQUFJQTs7SUFHeEM7SUFFRixPQUNFLFdBREYsR0FDRSxJQUFBTSxZQUFBNkMsSUFBQSxFQUFBN0MsWUFBQThDLFFBQUE7O1lBQ0dzQjtZQUNBQzs7O0FBR1A7QUFFQSxTQUFTRSxpQkFBaUJqRixLQUFnQjtJQUN4QyxNQUFNLEVBQUVJLFdBQVcsRUFBRVUsS0FBSyxFQUFFLEdBQUdvRSxXQUFXLEdBQUdsRjtJQUU3QyxzR0FBc0c7SUFDdEcsTUFBTW1GLFlBRUZEO0lBRUosT0FBT0M7QUFDVDtBQUVBLFNBQVNDLFdBQVdDLE9BQWUsRUFBRUMsTUFBYztJQUNqRCxPQUFPRCxXQUFXLENBQUMsRUFBRUMsT0FBTyxFQUFFQSxPQUFPN0MsUUFBUSxDQUFDLE9BQU8sTUFBTSxJQUFJLEtBQUssQ0FBQztBQUN2RTtBQUVBLFNBQVM4QyxvQkFDUEMsZ0JBQThDLEVBQzlDQyxlQUF1QixFQUN2QnhGLGNBQXNCLEVBQUU7SUFFeEIsSUFBSSxDQUFDdUYsa0JBQWtCO1FBQ3JCLE9BQU87WUFDTEUsWUFBWTtZQUNaQyxTQUFTO1FBQ1g7SUFDRjtJQUVBLE1BQU1DLGdCQUFnQkosaUJBQWlCSyxLQUFLLENBQUMsUUFBUTtJQUNyRCxNQUFNQyxpQkFBaUJOLGlCQUFpQkssS0FBSyxDQUFDSixnQkFBZ0I7SUFFOUQsTUFBTU0scUJBQXFCeEUsTUFBTXlFLElBQUksQ0FDbkMsSUFBSTlHLElBQUk7V0FBSzBHLGlCQUFpQixFQUFFO1dBQU9FLGtCQUFrQixFQUFFO0tBQUU7SUFHL0QsMkZBQTJGO0lBQzNGLE1BQU1HLG1CQUFtQixDQUFDLENBQ3hCRixDQUFBQSxtQkFBbUJ6QyxNQUFNLEtBQUssS0FDN0JzQyxDQUFBQSxpQkFBaUJFLGNBQUFBLENBQWE7SUFHakMsT0FBTztRQUNMSixZQUFZTyxtQkFDVixXQURVQSxHQUNWLElBQUF2RixZQUFBQyxHQUFBLEVBQUN1RixRQUFBQTtZQUNDQyxrQkFDRVgsaUJBQWlCWSxvQkFBb0IsR0FBRyxnQkFBZ0I7WUFFMURDLEtBQUk7WUFDSkMsTUFBSztZQUNMbEcsYUFBWTthQUVaO1FBQ0p1RixTQUFTSSxxQkFDTEEsbUJBQW1CdEYsR0FBRyxDQUFDLENBQUM4RjtZQUN0QixNQUFNQyxNQUFNLDhCQUE4QkMsSUFBSSxDQUFDRixTQUFVLENBQUMsRUFBRTtZQUM1RCxPQUNFLFdBREYsR0FDRSxJQUFBN0YsWUFBQUMsR0FBQSxFQUFDdUYsUUFBQUE7Z0JBRUNHLEtBQUk7Z0JBQ0pDLE1BQU0sQ0FBQyxFQUFFckcsWUFBWSxPQUFPLEVBQUVnQixDQUFBQSxHQUFBQSxlQUFBQSxhQUFhLEVBQUNzRixVQUFVLENBQUM7Z0JBQ3ZERyxJQUFHO2dCQUNIdEMsTUFBTSxDQUFDLEtBQUssRUFBRW9DLElBQUksQ0FBQztnQkFDbkJwRyxhQUFZO2dCQUNaK0Ysa0JBQWdCSSxTQUFTOUQsUUFBUSxDQUFDLFFBQVEsZ0JBQWdCO2VBTnJEOEQ7UUFTWCxLQUNBO0lBQ047QUFDRjtBQVFPLE1BQU01SCxhQUFhZ0ksT0FBQUEsT0FBSyxDQUFDQyxTQUFTO3FCQUNoQ0MsV0FBQUEsR0FBY0MsMEJBQUFBLFdBQVc7SUFJaENDLFlBQVkxRSxLQUFvQixFQUF3QjtRQUN0RCxNQUFNLEVBQ0pwQyxXQUFXLEVBQ1hDLGdCQUFnQixFQUNoQm9DLGNBQWMsRUFDZGxDLFdBQVcsRUFDWDRHLFdBQVcsRUFDWEMsYUFBYSxFQUNkLEdBQUcsSUFBSSxDQUFDbEgsT0FBTztRQUNoQixNQUFNbUgsV0FBVzdFLE1BQU14QyxRQUFRLENBQUNTLE1BQU0sQ0FBQyxDQUFDNkcsSUFBTUEsRUFBRTNHLFFBQVEsQ0FBQztRQUN6RCxNQUFNakIsY0FBMkIsSUFBSUwsSUFBSW1ELE1BQU05QyxXQUFXO1FBRTFELHFFQUFxRTtRQUNyRSwrQ0FBK0M7UUFDL0MsSUFBSTZILGdCQUE2QixJQUFJbEksSUFBSSxFQUFFO1FBQzNDLElBQUltSSxrQkFBa0I5RixNQUFNeUUsSUFBSSxDQUM5QixJQUFJOUcsSUFBSW9ELGVBQWVoQyxNQUFNLENBQUMsQ0FBQ2tDLE9BQVNBLEtBQUtoQyxRQUFRLENBQUM7UUFFeEQsSUFBSTZHLGdCQUFnQi9ELE1BQU0sRUFBRTtZQUMxQixNQUFNZ0UsV0FBVyxJQUFJcEksSUFBSWdJO1lBQ3pCRyxrQkFBa0JBLGdCQUFnQi9HLE1BQU0sQ0FDdEMsQ0FBQzZHLElBQU0sQ0FBRUcsQ0FBQUEsU0FBU0MsR0FBRyxDQUFDSixNQUFNNUgsWUFBWWdJLEdBQUcsQ0FBQ0osRUFBQUE7WUFFOUNDLGdCQUFnQixJQUFJbEksSUFBSW1JO1lBQ3hCSCxTQUFTbkYsSUFBSSxJQUFJc0Y7UUFDbkI7UUFFQSxJQUFJRyxrQkFBaUMsRUFBRTtRQUN2Q04sU0FBU3BGLE9BQU8sQ0FBQyxDQUFDVTtZQUNoQixNQUFNaUYsZUFBZWxJLFlBQVlnSSxHQUFHLENBQUMvRTtZQUVyQyxJQUFJLENBQUN3RSxhQUFhO2dCQUNoQlEsZ0JBQWdCekYsSUFBSSxDQUNsQixXQURrQixHQUNsQixJQUFBckIsWUFBQUMsR0FBQSxFQUFDdUYsUUFBQUE7b0JBRUNwRixPQUFPLElBQUksQ0FBQ2QsS0FBSyxDQUFDYyxLQUFLO29CQUN2QnVGLEtBQUk7b0JBQ0pDLE1BQU0sQ0FBQyxFQUFFckcsWUFBWSxPQUFPLEVBQUVnQixDQUFBQSxHQUFBQSxlQUFBQSxhQUFhLEVBQ3pDdUIsTUFDQSxFQUFFdEMsaUJBQWlCLENBQUM7b0JBQ3RCd0csSUFBRztvQkFDSHRHLGFBQWEsSUFBSSxDQUFDSixLQUFLLENBQUNJLFdBQVcsSUFBSUE7bUJBUGxDLENBQUMsRUFBRW9DLEtBQUssUUFBUSxDQUFDO1lBVTVCO1lBRUEsTUFBTWtGLGtCQUFrQk4sY0FBY0csR0FBRyxDQUFDL0U7WUFDMUNnRixnQkFBZ0J6RixJQUFJLENBQ2xCLFdBRGtCLEdBQ2xCLElBQUFyQixZQUFBQyxHQUFBLEVBQUN1RixRQUFBQTtnQkFFQ3BGLE9BQU8sSUFBSSxDQUFDZCxLQUFLLENBQUNjLEtBQUs7Z0JBQ3ZCdUYsS0FBSTtnQkFDSkMsTUFBTSxDQUFDLEVBQUVyRyxZQUFZLE9BQU8sRUFBRWdCLENBQUFBLEdBQUFBLGVBQUFBLGFBQWEsRUFDekN1QixNQUNBLEVBQUV0QyxpQkFBaUIsQ0FBQztnQkFDdEJFLGFBQWEsSUFBSSxDQUFDSixLQUFLLENBQUNJLFdBQVcsSUFBSUE7Z0JBQ3ZDdUgsWUFBVUQsa0JBQWtCRSxZQUFZSCxlQUFlLEtBQUtHO2dCQUM1REMsWUFBVUgsa0JBQWtCRSxZQUFZSCxlQUFlRyxZQUFZO2VBUjlEcEY7UUFXWDtRQUVBLElBQUk5QyxLQUEwQ3VILEVBQWUsRUFJN0Q7UUFFQSxPQUFPTyxnQkFBZ0JsRSxNQUFNLEtBQUssSUFBSSxPQUFPa0U7SUFDL0M7SUFFQU8sMEJBQTBCO1FBQ3hCLE1BQU0sRUFBRXpGLGNBQWMsRUFBRXJDLFdBQVcsRUFBRUMsZ0JBQWdCLEVBQUVFLFdBQVcsRUFBRSxHQUNsRSxJQUFJLENBQUNMLE9BQU87UUFFZCxPQUNFdUMsZUFDRzdCLEdBQUcsQ0FBQyxDQUFDK0I7WUFDSixJQUFJLENBQUNBLEtBQUtoQyxRQUFRLENBQUMsUUFBUTtnQkFDekIsT0FBTztZQUNUO1lBRUEsT0FDRSxXQURGLEdBQ0UsSUFBQUUsWUFBQUMsR0FBQSxFQUFDdUYsUUFBQUE7Z0JBQ0NHLEtBQUk7Z0JBRUpDLE1BQU0sQ0FBQyxFQUFFckcsWUFBWSxPQUFPLEVBQUVnQixDQUFBQSxHQUFBQSxlQUFBQSxhQUFhLEVBQ3pDdUIsTUFDQSxFQUFFdEMsaUJBQWlCLENBQUM7Z0JBQ3RCd0csSUFBRztnQkFDSDVGLE9BQU8sSUFBSSxDQUFDZCxLQUFLLENBQUNjLEtBQUs7Z0JBQ3ZCVixhQUFhLElBQUksQ0FBQ0osS0FBSyxDQUFDSSxXQUFXLElBQUlBO2VBTmxDb0M7UUFTWCxHQUNBLDRCQUE0QjtTQUMzQmxDLE1BQU0sQ0FBQzBIO0lBRWQ7SUFFQUMsb0JBQW9CNUYsS0FBb0IsRUFBd0I7UUFDOUQsTUFBTSxFQUFFcEMsV0FBVyxFQUFFQyxnQkFBZ0IsRUFBRThDLFlBQVksRUFBRTVDLFdBQVcsRUFBRSxHQUNoRSxJQUFJLENBQUNMLE9BQU87UUFDZCxNQUFNbUksZUFBZTdGLE1BQU14QyxRQUFRLENBQUNTLE1BQU0sQ0FBQyxDQUFDa0M7WUFDMUMsT0FBT0EsS0FBS2hDLFFBQVEsQ0FBQztRQUN2QjtRQUVBLE9BQU87ZUFDRixDQUFDd0MsYUFBYWdDLGlCQUFpQixJQUFJLEVBQUUsRUFBRXZFLEdBQUcsQ0FBQyxDQUFDK0IsT0FDN0MsV0FENkNBLEdBQzdDLElBQUE5QixZQUFBQyxHQUFBLEVBQUN1RixRQUFBQTtvQkFFQ3BGLE9BQU8sSUFBSSxDQUFDZCxLQUFLLENBQUNjLEtBQUs7b0JBQ3ZCdUYsS0FBSTtvQkFDSkMsTUFBTTlELEtBQUt4QixHQUFHO29CQUNkMEYsSUFBRztvQkFDSHRHLGFBQWEsSUFBSSxDQUFDSixLQUFLLENBQUNJLFdBQVcsSUFBSUE7bUJBTGxDb0MsS0FBS3hCLEdBQUc7ZUFRZGtILGFBQWF6SCxHQUFHLENBQUMsQ0FBQytCLE9BQ25CLFdBRG1CQSxHQUNuQixJQUFBOUIsWUFBQUMsR0FBQSxFQUFDdUYsUUFBQUE7b0JBRUNwRixPQUFPLElBQUksQ0FBQ2QsS0FBSyxDQUFDYyxLQUFLO29CQUN2QnVGLEtBQUk7b0JBQ0pDLE1BQU0sQ0FBQyxFQUFFckcsWUFBWSxPQUFPLEVBQUVnQixDQUFBQSxHQUFBQSxlQUFBQSxhQUFhLEVBQ3pDdUIsTUFDQSxFQUFFdEMsaUJBQWlCLENBQUM7b0JBQ3RCd0csSUFBRztvQkFDSHRHLGFBQWEsSUFBSSxDQUFDSixLQUFLLENBQUNJLFdBQVcsSUFBSUE7bUJBUGxDb0M7U0FVVjtJQUNIO0lBRUEyRixvQ0FBb0M7UUFDbEMsTUFBTSxFQUFFbkYsWUFBWSxFQUFFLEdBQUcsSUFBSSxDQUFDakQsT0FBTztRQUNyQyxNQUFNLEVBQUVlLEtBQUssRUFBRVYsV0FBVyxFQUFFLEdBQUcsSUFBSSxDQUFDSixLQUFLO1FBRXpDLE9BQU8sQ0FBQ2dELGFBQWFnQyxpQkFBaUIsSUFBSSxFQUFFLEVBQ3pDMUUsTUFBTSxDQUNMLENBQUNNLFNBQ0MsQ0FBQ0EsT0FBT0ksR0FBRyxJQUFLSixDQUFBQSxPQUFPZ0IsdUJBQXVCLElBQUloQixPQUFPYSxRQUFRLEdBRXBFaEIsR0FBRyxDQUFDLENBQUMrQixNQUFtQm9CO1lBQ3ZCLE1BQU0sRUFDSkMsUUFBUSxFQUNScEMsUUFBUSxFQUNSRyx1QkFBdUIsRUFDdkJaLEdBQUcsRUFDSCxHQUFHK0MsYUFDSixHQUFHdkI7WUFDSixJQUFJNEYsT0FFVTtZQUVkLElBQUl4RywyQkFBMkJBLHdCQUF3QkMsTUFBTSxFQUFFO2dCQUM3RHVHLE9BQU94Ryx3QkFBd0JDLE1BQU07WUFDdkMsT0FBTyxJQUFJSixVQUFVO2dCQUNuQjJHLE9BQ0UsT0FBTzNHLGFBQWEsV0FDaEJBLFdBQ0FGLE1BQU1DLE9BQU8sQ0FBQ0MsWUFDZEEsU0FBU1MsSUFBSSxDQUFDLE1BQ2Q7WUFDUjtZQUVBLE9BQ0UsV0FERixHQUNFLElBQUFnQyxPQUFBQyxhQUFBLEVBQUN2RCxVQUFBQTtnQkFDRSxHQUFHbUQsV0FBVztnQkFDZm5DLHlCQUF5QjtvQkFBRUMsUUFBUXVHO2dCQUFLO2dCQUN4Qy9ELEtBQUtOLFlBQVlzRSxFQUFFLElBQUl6RTtnQkFDdkI5QyxPQUFPQTtnQkFDUHdELGdCQUFhO2dCQUNibEUsYUFDRUEsZUFDQ1YsU0FBK0I7O1FBSXhDO0lBQ0o7SUFFQTBDLGlCQUFpQkMsS0FBb0IsRUFBRTtRQUNyQyxPQUFPRCxpQkFBaUIsSUFBSSxDQUFDckMsT0FBTyxFQUFFLElBQUksQ0FBQ0MsS0FBSyxFQUFFcUM7SUFDcEQ7SUFFQXdDLG9CQUFvQjtRQUNsQixPQUFPQSxrQkFBa0IsSUFBSSxDQUFDOUUsT0FBTyxFQUFFLElBQUksQ0FBQ0MsS0FBSztJQUNuRDtJQUVBMkMsV0FBV04sS0FBb0IsRUFBRTtRQUMvQixPQUFPTSxXQUFXLElBQUksQ0FBQzVDLE9BQU8sRUFBRSxJQUFJLENBQUNDLEtBQUssRUFBRXFDO0lBQzlDO0lBRUF2QyxxQkFBcUI7UUFDbkIsT0FBT0EsbUJBQW1CLElBQUksQ0FBQ0MsT0FBTyxFQUFFLElBQUksQ0FBQ0MsS0FBSztJQUNwRDtJQUVBOEgsb0JBQW9CUyxJQUFpQixFQUFlO1FBQ2xELE9BQU81QixPQUFBQSxPQUFLLENBQUM2QixRQUFRLENBQUMvSCxHQUFHLENBQUM4SCxNQUFNLENBQUNFO2dCQUc3QkEsVUFZU0E7WUFkWCxJQUNFQSxDQUFBQSxLQUFBQSxPQUFBQSxLQUFBQSxJQUFBQSxFQUFHckUsSUFBSSxNQUFLLFVBQ1pxRSxDQUFBQSxLQUFBQSxPQUFBQSxLQUFBQSxJQUFBQSxDQUFBQSxXQUFBQSxFQUFHekksS0FBSyxxQkFBUnlJLFNBQVVuQyxJQUFJLEtBQ2RvQyxXQUFBQSx3QkFBd0IsQ0FBQ0MsSUFBSSxDQUFDLENBQUMsRUFBRUMsR0FBRyxFQUFFO29CQUNwQ0gsZUFBQUE7dUJBQUFBLEtBQUFBLE9BQUFBLEtBQUFBLElBQUFBLENBQUFBLFdBQUFBLEVBQUd6SSxLQUFLLHNCQUFSeUksZ0JBQUFBLFNBQVVuQyxJQUFJLHFCQUFkbUMsY0FBZ0JJLFVBQVUsQ0FBQ0Q7Z0JBRTdCO2dCQUNBLE1BQU1FLFdBQVc7b0JBQ2YsR0FBSUwsRUFBRXpJLEtBQUssSUFBSSxDQUFDLENBQUM7b0JBQ2pCLGFBQWF5SSxFQUFFekksS0FBSyxDQUFDc0csSUFBSTtvQkFDekJBLE1BQU1zQjtnQkFDUjtnQkFFQSxxQkFBT2pCLE9BQUFBLE9BQUssQ0FBQ29DLFlBQVksQ0FBQ04sR0FBR0s7WUFDL0IsT0FBTyxJQUFJTCxLQUFBQSxPQUFBQSxLQUFBQSxJQUFBQSxDQUFBQSxZQUFBQSxFQUFHekksS0FBSyxxQkFBUnlJLFVBQVVoSCxRQUFRLEVBQUU7Z0JBQzdCLE1BQU1xSCxXQUFXO29CQUNmLEdBQUlMLEVBQUV6SSxLQUFLLElBQUksQ0FBQyxDQUFDO29CQUNqQnlCLFVBQVUsSUFBSSxDQUFDcUcsbUJBQW1CLENBQUNXLEVBQUV6SSxLQUFLLENBQUN5QixRQUFRO2dCQUNyRDtnQkFFQSxxQkFBT2tGLE9BQUFBLE9BQUssQ0FBQ29DLFlBQVksQ0FBQ04sR0FBR0s7WUFDL0I7WUFFQSxPQUFPTDtRQUNQLHdGQUF3RjtRQUMxRixHQUFJbkksTUFBTSxDQUFDMEg7SUFDYjtJQUVBZ0IsU0FBUztRQUNQLE1BQU0sRUFDSjNILE1BQU0sRUFDTmdFLE9BQU8sRUFDUC9GLFNBQVMsRUFDVDJKLFNBQVMsRUFDVEMsYUFBYSxFQUNiQyxhQUFhLEVBQ2IxRCxlQUFlLEVBQ2YyRCxRQUFRLEVBQ1JDLGtCQUFrQixFQUNsQkMsa0JBQWtCLEVBQ2xCbkosdUJBQXVCLEVBQ3ZCNkcsV0FBVyxFQUNYQyxhQUFhLEVBQ2JoSCxXQUFXLEVBQ1h1RixnQkFBZ0IsRUFDakIsR0FBRyxJQUFJLENBQUN6RixPQUFPO1FBRWhCLE1BQU13SixtQkFBbUJGLHVCQUF1QjtRQUNoRCxNQUFNRyxtQkFDSkYsdUJBQXVCLFNBQVMsQ0FBQ25KO1FBRW5DLElBQUksQ0FBQ0osT0FBTyxDQUFDMEoscUJBQXFCLENBQUM5SyxJQUFJLEdBQUc7UUFFMUMsSUFBSSxFQUFFK0ssSUFBSSxFQUFFLEdBQUcsSUFBSSxDQUFDM0osT0FBTztRQUMzQixJQUFJNEosY0FBa0MsRUFBRTtRQUN4QyxJQUFJQyxvQkFBd0MsRUFBRTtRQUM5QyxJQUFJRixNQUFNO1lBQ1JBLEtBQUs1SCxPQUFPLENBQUMsQ0FBQzJHO2dCQUNaLElBQUlvQjtnQkFFSixJQUFJLElBQUksQ0FBQzlKLE9BQU8sQ0FBQytKLGNBQWMsRUFBRTtvQkFDL0JELFVBQUFBLFdBQUFBLEdBQVVsRCxPQUFBQSxPQUFLLENBQUN4QyxhQUFhLENBQUMsUUFBUTt3QkFDcEM0RixNQUFNO3dCQUNOQyxTQUFTO29CQUNYO2dCQUNGO2dCQUVBLElBQ0V2QixLQUNBQSxFQUFFckUsSUFBSSxLQUFLLFVBQ1hxRSxFQUFFekksS0FBSyxDQUFDLE1BQU0sS0FBSyxhQUNuQnlJLEVBQUV6SSxLQUFLLENBQUMsS0FBSyxLQUFLLFNBQ2xCO29CQUNBNkosV0FBV0YsWUFBWTVILElBQUksQ0FBQzhIO29CQUM1QkYsWUFBWTVILElBQUksQ0FBQzBHO2dCQUNuQixPQUFPO29CQUNMLElBQUlBLEdBQUc7d0JBQ0wsSUFBSW9CLFdBQVlwQixDQUFBQSxFQUFFckUsSUFBSSxLQUFLLFVBQVUsQ0FBQ3FFLEVBQUV6SSxLQUFLLENBQUMsVUFBVSxHQUFHOzRCQUN6RDRKLGtCQUFrQjdILElBQUksQ0FBQzhIO3dCQUN6Qjt3QkFDQUQsa0JBQWtCN0gsSUFBSSxDQUFDMEc7b0JBQ3pCO2dCQUNGO1lBQ0Y7WUFDQWlCLE9BQU9DLFlBQVlNLE1BQU0sQ0FBQ0w7UUFDNUI7UUFDQSxJQUFJbkksV0FBOEJrRixPQUFBQSxPQUFLLENBQUM2QixRQUFRLENBQUMwQixPQUFPLENBQ3RELElBQUksQ0FBQ2xLLEtBQUssQ0FBQ3lCLFFBQVEsRUFDbkJuQixNQUFNLENBQUMwSDtRQUNULGdFQUFnRTtRQUNoRSxJQUFJdEksSUFBeUIsRUFBYztZQUN6QytCLFdBQVdrRixPQUFBQSxPQUFLLENBQUM2QixRQUFRLENBQUMvSCxHQUFHLENBQUNnQixVQUFVLENBQUNOO29CQUNqQkE7Z0JBQXRCLE1BQU1nSixnQkFBZ0JoSixTQUFBQSxPQUFBQSxLQUFBQSxJQUFBQSxDQUFBQSxlQUFBQSxNQUFPbkIsS0FBSyxxQkFBWm1CLFlBQWMsQ0FBQyxvQkFBb0I7Z0JBQ3pELElBQUksQ0FBQ2dKLGVBQWU7d0JBT2hCaEo7b0JBTkYsSUFBSUEsQ0FBQUEsU0FBQUEsT0FBQUEsS0FBQUEsSUFBQUEsTUFBT2lELElBQUksTUFBSyxTQUFTO3dCQUMzQk0sUUFBUUMsSUFBSSxDQUNWO29CQUVKLE9BQU8sSUFDTHhELENBQUFBLFNBQUFBLE9BQUFBLEtBQUFBLElBQUFBLE1BQU9pRCxJQUFJLE1BQUssVUFDaEJqRCxDQUFBQSxTQUFBQSxPQUFBQSxLQUFBQSxJQUFBQSxDQUFBQSxnQkFBQUEsTUFBT25CLEtBQUsscUJBQVptQixjQUFjNEksSUFBSSxNQUFLLFlBQ3ZCO3dCQUNBckYsUUFBUUMsSUFBSSxDQUNWO29CQUVKO2dCQUNGO2dCQUNBLE9BQU94RDtZQUNQLHdGQUF3RjtZQUMxRjtZQUNBLElBQUksSUFBSSxDQUFDbkIsS0FBSyxDQUFDSSxXQUFXLEVBQ3hCc0UsUUFBUUMsSUFBSSxDQUNWO1FBRU47UUFFQSxJQUNFakYsS0FFeUNKLEVBQ3pDLEVBRUY7UUFFQSxJQUFJOEssZ0JBQWdCO1FBQ3BCLElBQUlDLGtCQUFrQjtRQUV0QixvREFBb0Q7UUFDcERYLE9BQU8vQyxPQUFBQSxPQUFLLENBQUM2QixRQUFRLENBQUMvSCxHQUFHLENBQUNpSixRQUFRLEVBQUUsRUFBRSxDQUFDdkk7WUFDckMsSUFBSSxDQUFDQSxPQUFPLE9BQU9BO1lBQ25CLE1BQU0sRUFBRWlELElBQUksRUFBRXBFLEtBQUssRUFBRSxHQUFHbUI7WUFDeEIsSUFBSXpCLEtBQTZCLElBQVVKLFdBQVc7Z0JBQ3BELElBQUlnTCxVQUFrQjtnQkFFdEIsSUFBSWxHLFNBQVMsVUFBVXBFLE1BQU0rSixJQUFJLEtBQUssWUFBWTtvQkFDaERPLFVBQVU7Z0JBQ1osT0FBTyxJQUFJbEcsU0FBUyxVQUFVcEUsTUFBTXFHLEdBQUcsS0FBSyxhQUFhO29CQUN2RGdFLGtCQUFrQjtnQkFDcEIsT0FBTyxJQUFJakcsU0FBUyxVQUFVO29CQUM1QixnQkFBZ0I7b0JBQ2hCLHlEQUF5RDtvQkFDekQsMkRBQTJEO29CQUMzRCw0QkFBNEI7b0JBQzVCLElBQ0VwRSxNQUFPZ0IsR0FBRyxJQUFJaEIsTUFBTWdCLEdBQUcsQ0FBQ3VKLE9BQU8sQ0FBQyxnQkFBZ0IsQ0FBQyxLQUNoRHZLLE1BQU00Qix1QkFBdUIsSUFDM0IsRUFBQzVCLE1BQU1vRSxJQUFJLElBQUlwRSxNQUFNb0UsSUFBSSxLQUFLLG9CQUNqQzt3QkFDQWtHLFVBQVU7d0JBQ1ZFLE9BQU9DLElBQUksQ0FBQ3pLLE9BQU84QixPQUFPLENBQUMsQ0FBQzRJOzRCQUMxQkosV0FBVyxDQUFDLENBQUMsRUFBRUksS0FBSyxFQUFFLEVBQUUxSyxLQUFLLENBQUMwSyxLQUFLLENBQUMsQ0FBQyxDQUFDO3dCQUN4Qzt3QkFDQUosV0FBVztvQkFDYjtnQkFDRjtnQkFFQSxJQUFJQSxTQUFTO29CQUNYNUYsUUFBUUMsSUFBSSxDQUNWLENBQUMsMkJBQTJCLEVBQUV4RCxNQUFNaUQsSUFBSSxDQUFDLHdCQUF3QixFQUFFa0csUUFBUSxJQUFJLEVBQUVuQixjQUFjd0IsSUFBSSxDQUFDLHNEQUFzRCxDQUFDO29CQUU3SixPQUFPO2dCQUNUO1lBQ0YsT0FBTztnQkFDTCxlQUFlO2dCQUNmLElBQUl2RyxTQUFTLFVBQVVwRSxNQUFNcUcsR0FBRyxLQUFLLFdBQVc7b0JBQzlDK0QsZ0JBQWdCO2dCQUNsQjtZQUNGO1lBQ0EsT0FBT2pKO1FBQ1Asd0ZBQXdGO1FBQzFGO1FBRUEsTUFBTWtCLFFBQXVCbEQsaUJBQzNCLElBQUksQ0FBQ1ksT0FBTyxDQUFDWCxhQUFhLEVBQzFCLElBQUksQ0FBQ1csT0FBTyxDQUFDb0osYUFBYSxDQUFDd0IsSUFBSSxFQUMvQmpMLEtBQTZCLElBQVVKO1FBR3pDLE1BQU1zTCxtQkFBbUJyRixvQkFDdkJDLGtCQUNBQyxpQkFDQXhGO1FBR0YsT0FDRSxXQURGLEdBQ0UsSUFBQVMsWUFBQTZDLElBQUEsRUFBQ21HLFFBQUFBO1lBQU0sR0FBR3pFLGlCQUFpQixJQUFJLENBQUNqRixLQUFLLENBQUM7O2dCQUNuQyxJQUFJLENBQUNELE9BQU8sQ0FBQ3dDLGFBQWEsSUFDekIsV0FEeUIsR0FDekIsSUFBQTdCLFlBQUE2QyxJQUFBLEVBQUE3QyxZQUFBOEMsUUFBQTs7c0NBQ0UsSUFBQTlDLFlBQUFDLEdBQUEsRUFBQ3FCLFNBQUFBOzRCQUNDNkksdUJBQW1COzRCQUNuQkMsbUJBQ0VwTCxLQUE2QixJQUFVSixZQUNuQyxTQUNBc0k7NEJBRU5oRyx5QkFBeUI7Z0NBQ3ZCQyxRQUFRLENBQUMsa0JBQWtCLENBQUM7NEJBQzlCOztzQ0FFRixJQUFBbkIsWUFBQUMsR0FBQSxFQUFDb0ssWUFBQUE7NEJBQ0NGLHVCQUFtQjs0QkFDbkJDLG1CQUNFcEwsS0FBNkIsSUFBVUosWUFDbkMsU0FDQXNJO3NDQUdOLGtCQUFBbEgsWUFBQUMsR0FBQSxFQUFDcUIsU0FBQUE7Z0NBQ0NKLHlCQUF5QjtvQ0FDdkJDLFFBQVEsQ0FBQyxtQkFBbUIsQ0FBQztnQ0FDL0I7Ozs7O2dCQUtQNkg7Z0JBQ0EsSUFBSSxDQUFDM0osT0FBTyxDQUFDK0osY0FBYyxHQUFHLE9BQzdCLFdBRDZCLEdBQzdCLElBQUFwSixZQUFBQyxHQUFBLEVBQUNxSyxRQUFBQTtvQkFDQ2pCLE1BQUs7b0JBQ0xDLFNBQVNyRCxPQUFBQSxPQUFLLENBQUM2QixRQUFRLENBQUN5QyxLQUFLLENBQUN2QixRQUFRLEVBQUUsRUFBRXdCLFFBQVE7O2dCQUlyRHpKO2dCQUNBd0YsaUJBQWlCLFdBQWpCQSxHQUFpQixJQUFBdkcsWUFBQUMsR0FBQSxFQUFDcUssUUFBQUE7b0JBQUtqQixNQUFLOztnQkFFNUJhLGlCQUFpQmxGLFVBQVU7Z0JBQzNCa0YsaUJBQWlCakYsT0FBTztnQkFFeEJqRyxLQUE2QixJQUFVSixhQUN0QyxXQURzQ0EsR0FDdEMsSUFBQW9CLFlBQUE2QyxJQUFBLEVBQUE3QyxZQUFBOEMsUUFBQTs7c0NBQ0UsSUFBQTlDLFlBQUFDLEdBQUEsRUFBQ3FLLFFBQUFBOzRCQUNDakIsTUFBSzs0QkFDTEMsU0FBUTs7d0JBRVQsQ0FBQ0ssbUJBQ0EsV0FEQUEsR0FDQSxJQUFBM0osWUFBQUMsR0FBQSxFQUFDdUYsUUFBQUE7NEJBQ0NHLEtBQUk7NEJBQ0pDLE1BQ0U0QyxnQkFDQWlDLHFHQUF1QyxDQUFDMUY7O3NDQUs5QyxJQUFBL0UsWUFBQUMsR0FBQSxFQUFDdUYsUUFBQUE7NEJBQ0NHLEtBQUk7NEJBQ0pLLElBQUc7NEJBQ0hKLE1BQUs7O3NDQUVQLElBQUE1RixZQUFBQyxHQUFBLEVBQUNTLFdBQUFBOzRCQUFVQyxRQUFRQTs7c0NBQ25CLElBQUFYLFlBQUFDLEdBQUEsRUFBQ3FCLFNBQUFBOzRCQUNDcUosbUJBQWdCOzRCQUNoQnpKLHlCQUF5QjtnQ0FDdkJDLFFBQVEsQ0FBQyxzbEJBQXNsQixDQUFDOzRCQUNsbUI7O3NDQUVGLElBQUFuQixZQUFBQyxHQUFBLEVBQUNvSyxZQUFBQTtzQ0FDQyxrQkFBQXJLLFlBQUFDLEdBQUEsRUFBQ3FCLFNBQUFBO2dDQUNDcUosbUJBQWdCO2dDQUNoQnpKLHlCQUF5QjtvQ0FDdkJDLFFBQVEsQ0FBQyxrRkFBa0YsQ0FBQztnQ0FDOUY7OztzQ0FHSixJQUFBbkIsWUFBQUMsR0FBQSxFQUFDQyxVQUFBQTs0QkFBTzhCLE9BQUs7NEJBQUMxQixLQUFJOzs7O2dCQUdyQixDQUFFdEIsQ0FBQUEsS0FBNkIsSUFBVUosU0FBQUEsS0FDeEMsV0FEZ0QsR0FDaEQsSUFBQW9CLFlBQUE2QyxJQUFBLEVBQUE3QyxZQUFBOEMsUUFBQTs7d0JBQ0csQ0FBQzRHLGlCQUFpQm5CLGFBQ2pCLFdBRGlCQSxHQUNqQixJQUFBdkksWUFBQUMsR0FBQSxFQUFDdUYsUUFBQUE7NEJBQ0NHLEtBQUk7NEJBQ0pDLE1BQU00QyxnQkFBZ0I5RCxXQUFXQyxTQUFTSTs7d0JBRzdDLElBQUksQ0FBQzBDLGlDQUFpQzt3QkFDdEMsQ0FBQ25CLGVBQWUsSUFBSSxDQUFDRCxXQUFXLENBQUMxRTt3QkFDakMsQ0FBQzJFLGVBQWUsV0FBZkEsR0FBZSxJQUFBdEcsWUFBQUMsR0FBQSxFQUFDb0ssWUFBQUE7NEJBQVNPLGNBQVksSUFBSSxDQUFDdEwsS0FBSyxDQUFDYyxLQUFLLElBQUk7O3dCQUUxRCxDQUFDeUksb0JBQ0EsQ0FBQ0Msb0JBQ0QsSUFBSSxDQUFDekIsdUJBQXVCO3dCQUM3QixDQUFDd0Isb0JBQ0EsQ0FBQ0Msb0JBQ0QsSUFBSSxDQUFDdkIsbUJBQW1CLENBQUM1Rjt3QkFFMUIsQ0FBQ2xDLDJCQUNBLENBQUNvSixvQkFDRCxJQUFJLENBQUN6SixrQkFBa0I7d0JBRXhCLENBQUNLLDJCQUNBLENBQUNvSixvQkFDRCxJQUFJLENBQUMxRSxpQkFBaUI7d0JBQ3ZCLENBQUMxRSwyQkFDQSxDQUFDb0osb0JBQ0QsSUFBSSxDQUFDbkgsZ0JBQWdCLENBQUNDO3dCQUN2QixDQUFDbEMsMkJBQ0EsQ0FBQ29KLG9CQUNELElBQUksQ0FBQzVHLFVBQVUsQ0FBQ047d0JBRWpCMkUsZUFBZSxJQUFJLENBQUNELFdBQVcsQ0FBQzFFO3dCQUNoQzJFLGVBQWUsV0FBZkEsR0FBZSxJQUFBdEcsWUFBQUMsR0FBQSxFQUFDb0ssWUFBQUE7NEJBQVNPLGNBQVksSUFBSSxDQUFDdEwsS0FBSyxDQUFDYyxLQUFLLElBQUk7O3dCQUN6RCxJQUFJLENBQUNmLE9BQU8sQ0FBQ3dDLGFBQWEsSUFJekIsMERBSDBEO3dCQUMxRCw4QkFBOEI7d0JBQzlCLCtEQUErRDtzQ0FDL0QsSUFBQTdCLFlBQUFDLEdBQUEsRUFBQ29LLFlBQUFBOzRCQUFTMUMsSUFBRzs7d0JBRWRoSCxVQUFVOzs7OEJBR2RzRixPQUFBQSxPQUFLLENBQUN4QyxhQUFhLENBQUN3QyxPQUFBQSxPQUFLLENBQUNuRCxRQUFRLEVBQUUsQ0FBQyxNQUFPNEYsWUFBWSxFQUFFOzs7SUFHakU7QUFDRjtBQUVBLFNBQVNtQyxnQ0FDUHZJLFlBQTJDLEVBQzNDbUcsYUFBd0IsRUFDeEJuSixLQUFVO1FBVVd5QixzQkFBQUEsZ0JBR0FBLHVCQUFBQTtJQVhyQixJQUFJLENBQUN6QixNQUFNeUIsUUFBUSxFQUFFO0lBRXJCLE1BQU0rSixvQkFBbUMsRUFBRTtJQUUzQyxNQUFNL0osV0FBV0YsTUFBTUMsT0FBTyxDQUFDeEIsTUFBTXlCLFFBQVEsSUFDekN6QixNQUFNeUIsUUFBUSxHQUNkO1FBQUN6QixNQUFNeUIsUUFBUTtLQUFDO0lBRXBCLE1BQU1nSyxlQUFBQSxDQUFlaEssaUJBQUFBLFNBQVM0QixJQUFJLENBQ2hDLENBQUNsQyxRQUE4QkEsTUFBTWlELElBQUksS0FBS3pGLEtBQUFBLEtBQUFBLE9BQUFBLEtBQUFBLElBQUFBLENBRDNCOEMsdUJBQUFBLGVBRWxCekIsS0FBSyxxQkFGYXlCLHFCQUVYQSxRQUFRO0lBQ2xCLE1BQU1pSyxlQUFBQSxDQUFlakssa0JBQUFBLFNBQVM0QixJQUFJLENBQ2hDLENBQUNsQyxRQUE4QkEsTUFBTWlELElBQUksS0FBSyw2QkFEM0IzQyx3QkFBQUEsZ0JBRWxCekIsS0FBSyxxQkFGYXlCLHNCQUVYQSxRQUFRO0lBRWxCLCtHQUErRztJQUMvRyxNQUFNa0ssbUJBQW1CO1dBQ25CcEssTUFBTUMsT0FBTyxDQUFDaUssZ0JBQWdCQSxlQUFlO1lBQUNBO1NBQWE7V0FDM0RsSyxNQUFNQyxPQUFPLENBQUNrSyxnQkFBZ0JBLGVBQWU7WUFBQ0E7U0FBYTtLQUNoRTtJQUVEL0UsT0FBQUEsT0FBSyxDQUFDNkIsUUFBUSxDQUFDMUcsT0FBTyxDQUFDNkosa0JBQWtCLENBQUN4SztZQUlwQ0E7UUFISixJQUFJLENBQUNBLE9BQU87UUFFWix3RUFBd0U7UUFDeEUsS0FBSUEsY0FBQUEsTUFBTWlELElBQUkscUJBQVZqRCxZQUFZeUssWUFBWSxFQUFFO1lBQzVCLElBQUl6SyxNQUFNbkIsS0FBSyxDQUFDNkQsUUFBUSxLQUFLLHFCQUFxQjtnQkFDaERiLGFBQWFnQyxpQkFBaUIsR0FBRyxDQUMvQmhDLGFBQWFnQyxpQkFBaUIsSUFBSSxFQUFFLEVBQ3BDaUYsTUFBTSxDQUFDO29CQUNQO3dCQUNFLEdBQUc5SSxNQUFNbkIsS0FBSztvQkFDaEI7aUJBQ0Q7Z0JBQ0Q7WUFDRixPQUFPLElBQ0w7Z0JBQUM7Z0JBQWM7Z0JBQW9CO2FBQVMsQ0FBQ3lDLFFBQVEsQ0FDbkR0QixNQUFNbkIsS0FBSyxDQUFDNkQsUUFBUSxHQUV0QjtnQkFDQTJILGtCQUFrQnpKLElBQUksQ0FBQ1osTUFBTW5CLEtBQUs7Z0JBQ2xDO1lBQ0Y7UUFDRjtJQUNGO0lBRUFtSixjQUFjbkcsWUFBWSxHQUFHd0k7QUFDL0I7QUFFTyxNQUFNMU0sbUJBQW1CNkgsT0FBQUEsT0FBSyxDQUFDQyxTQUFTO3FCQUN0Q0MsV0FBQUEsR0FBY0MsMEJBQUFBLFdBQVc7SUFJaEMxRSxpQkFBaUJDLEtBQW9CLEVBQUU7UUFDckMsT0FBT0QsaUJBQWlCLElBQUksQ0FBQ3JDLE9BQU8sRUFBRSxJQUFJLENBQUNDLEtBQUssRUFBRXFDO0lBQ3BEO0lBRUF3QyxvQkFBb0I7UUFDbEIsT0FBT0Esa0JBQWtCLElBQUksQ0FBQzlFLE9BQU8sRUFBRSxJQUFJLENBQUNDLEtBQUs7SUFDbkQ7SUFFQTJDLFdBQVdOLEtBQW9CLEVBQUU7UUFDL0IsT0FBT00sV0FBVyxJQUFJLENBQUM1QyxPQUFPLEVBQUUsSUFBSSxDQUFDQyxLQUFLLEVBQUVxQztJQUM5QztJQUVBdkMscUJBQXFCO1FBQ25CLE9BQU9BLG1CQUFtQixJQUFJLENBQUNDLE9BQU8sRUFBRSxJQUFJLENBQUNDLEtBQUs7SUFDcEQ7SUFFQSxPQUFPNkwsc0JBQXNCOUwsT0FBNEIsRUFBVTtRQUNqRSxNQUFNLEVBQUVvSixhQUFhLEVBQUUyQyxrQkFBa0IsRUFBRSxHQUFHL0w7UUFDOUMsSUFBSTtZQUNGLE1BQU1nTSxPQUFPQyxLQUFLQyxTQUFTLENBQUM5QztZQUU1QixJQUFJbEssc0JBQXNCc0ksR0FBRyxDQUFDNEIsY0FBY3dCLElBQUksR0FBRztnQkFDakQsT0FBT3VCLENBQUFBLEdBQUFBLFlBQUFBLG9CQUFvQixFQUFDSDtZQUM5QjtZQUVBLE1BQU1JLFFBQ0p6TSxNQUE2QixHQUN6QixDQUFnRCxHQUNoRDhNLE9BQU94RyxJQUFJLENBQUMrRixNQUFNUSxVQUFVO1lBQ2xDLE1BQU1FLGNBQWN0QiwyR0FBc0M7WUFFMUQsSUFBSVcsc0JBQXNCSyxRQUFRTCxvQkFBb0I7Z0JBQ3BELElBQUlwTSxLQUF5QixFQUFjLEVBRTNDO2dCQUVBZ0YsUUFBUUMsSUFBSSxDQUNWLENBQUMsd0JBQXdCLEVBQUV3RSxjQUFjd0IsSUFBSSxDQUFDLENBQUMsRUFDN0N4QixjQUFjd0IsSUFBSSxLQUFLNUssUUFBUTBGLGVBQWUsR0FDMUMsS0FDQSxDQUFDLFFBQVEsRUFBRTFGLFFBQVEwRixlQUFlLENBQUMsRUFBRSxDQUFDLENBQzNDLElBQUksRUFBRWdILFlBQ0xOLE9BQ0EsZ0NBQWdDLEVBQUVNLFlBQ2xDWCxvQkFDQSxtSEFBbUgsQ0FBQztZQUUxSDtZQUVBLE9BQU9JLENBQUFBLEdBQUFBLFlBQUFBLG9CQUFvQixFQUFDSDtRQUM5QixFQUFFLE9BQU94SCxLQUFLO1lBQ1osSUFBSUMsQ0FBQUEsR0FBQUEsU0FBQUEsT0FBTyxFQUFDRCxRQUFRQSxJQUFJSyxPQUFPLENBQUMyRixPQUFPLENBQUMsMEJBQTBCLENBQUMsR0FBRztnQkFDcEUsTUFBTSxJQUFJdEcsTUFDUixDQUFDLHdEQUF3RCxFQUFFa0YsY0FBY3dCLElBQUksQ0FBQyxzREFBc0QsQ0FBQztZQUV6STtZQUNBLE1BQU1wRztRQUNSO0lBQ0Y7SUFFQXlFLFNBQVM7UUFDUCxNQUFNLEVBQ0ovSSxXQUFXLEVBQ1hYLFNBQVMsRUFDVEYsYUFBYSxFQUNiaUssa0JBQWtCLEVBQ2xCSSxxQkFBcUIsRUFDckJ2SixnQkFBZ0IsRUFDaEJDLHVCQUF1QixFQUN2QkMsV0FBVyxFQUNaLEdBQUcsSUFBSSxDQUFDTCxPQUFPO1FBQ2hCLE1BQU13SixtQkFBbUJGLHVCQUF1QjtRQUVoREksc0JBQXNCM0ssVUFBVSxHQUFHO1FBRW5DLElBQUlZLEtBQTZCLElBQVVKLFdBQVc7WUFDcEQsSUFBSUksS0FBeUIsRUFBYyxFQUUzQztZQUNBLE1BQU1pTixjQUFjO21CQUNmdk4sY0FBY3dOLFFBQVE7bUJBQ3RCeE4sY0FBY2lCLGFBQWE7bUJBQzNCakIsY0FBY3VOLFdBQVc7YUFDN0I7WUFFRCxPQUNFLFdBREYsR0FDRSxJQUFBak0sWUFBQTZDLElBQUEsRUFBQTdDLFlBQUE4QyxRQUFBOztvQkFDRytGLG1CQUFtQixPQUNsQixXQURrQixHQUNsQixJQUFBN0ksWUFBQUMsR0FBQSxFQUFDQyxVQUFBQTt3QkFDQ3lILElBQUc7d0JBQ0hqRSxNQUFLO3dCQUNMdEQsT0FBTyxJQUFJLENBQUNkLEtBQUssQ0FBQ2MsS0FBSzt3QkFDdkJWLGFBQWEsSUFBSSxDQUFDSixLQUFLLENBQUNJLFdBQVcsSUFBSUE7d0JBQ3ZDd0IseUJBQXlCOzRCQUN2QkMsUUFBUS9DLFdBQVcrTSxxQkFBcUIsQ0FBQyxJQUFJLENBQUM5TCxPQUFPO3dCQUN2RDt3QkFDQStLLG1CQUFlOztvQkFHbEI2QixZQUFZbE0sR0FBRyxDQUFDLENBQUMrQixPQUNoQixXQURnQkEsR0FDaEIsSUFBQTlCLFlBQUFDLEdBQUEsRUFBQ0MsVUFBQUE7NEJBRUNJLEtBQUssQ0FBQyxFQUFFZixZQUFZLE9BQU8sRUFBRWdCLENBQUFBLEdBQUFBLGVBQUFBLGFBQWEsRUFDeEN1QixNQUNBLEVBQUV0QyxpQkFBaUIsQ0FBQzs0QkFDdEJZLE9BQU8sSUFBSSxDQUFDZCxLQUFLLENBQUNjLEtBQUs7NEJBQ3ZCVixhQUFhLElBQUksQ0FBQ0osS0FBSyxDQUFDSSxXQUFXLElBQUlBOzRCQUN2QzBLLG1CQUFlOzJCQU5WdEk7OztRQVdmO1FBRUEsSUFBSTlDLElBQXlCLEVBQWM7WUFDekMsSUFBSSxJQUFJLENBQUNNLEtBQUssQ0FBQ0ksV0FBVyxFQUN4QnNFLFFBQVFDLElBQUksQ0FDVjtRQUVOO1FBRUEsTUFBTXRDLFFBQXVCbEQsaUJBQzNCLElBQUksQ0FBQ1ksT0FBTyxDQUFDWCxhQUFhLEVBQzFCLElBQUksQ0FBQ1csT0FBTyxDQUFDb0osYUFBYSxDQUFDd0IsSUFBSSxFQUMvQmpMLEtBQTZCLElBQVVKO1FBR3pDLE9BQ0UsV0FERixHQUNFLElBQUFvQixZQUFBNkMsSUFBQSxFQUFBN0MsWUFBQThDLFFBQUE7O2dCQUNHLENBQUMrRixvQkFBb0JuSyxjQUFjd04sUUFBUSxHQUN4Q3hOLGNBQWN3TixRQUFRLENBQUNuTSxHQUFHLENBQUMsQ0FBQytCLE9BQzFCLFdBRDBCQSxHQUMxQixJQUFBOUIsWUFBQUMsR0FBQSxFQUFDQyxVQUFBQTt3QkFFQ0ksS0FBSyxDQUFDLEVBQUVmLFlBQVksT0FBTyxFQUFFZ0IsQ0FBQUEsR0FBQUEsZUFBQUEsYUFBYSxFQUN4Q3VCLE1BQ0EsRUFBRXRDLGlCQUFpQixDQUFDO3dCQUN0QlksT0FBTyxJQUFJLENBQUNkLEtBQUssQ0FBQ2MsS0FBSzt3QkFDdkJWLGFBQWEsSUFBSSxDQUFDSixLQUFLLENBQUNJLFdBQVcsSUFBSUE7dUJBTGxDb0MsU0FRVDtnQkFDSCtHLG1CQUFtQixPQUNsQixXQURrQixHQUNsQixJQUFBN0ksWUFBQUMsR0FBQSxFQUFDQyxVQUFBQTtvQkFDQ3lILElBQUc7b0JBQ0hqRSxNQUFLO29CQUNMdEQsT0FBTyxJQUFJLENBQUNkLEtBQUssQ0FBQ2MsS0FBSztvQkFDdkJWLGFBQWEsSUFBSSxDQUFDSixLQUFLLENBQUNJLFdBQVcsSUFBSUE7b0JBQ3ZDd0IseUJBQXlCO3dCQUN2QkMsUUFBUS9DLFdBQVcrTSxxQkFBcUIsQ0FBQyxJQUFJLENBQUM5TCxPQUFPO29CQUN2RDs7Z0JBR0hJLDJCQUNDLENBQUNvSixvQkFDRCxJQUFJLENBQUN6SixrQkFBa0I7Z0JBQ3hCSywyQkFDQyxDQUFDb0osb0JBQ0QsSUFBSSxDQUFDMUUsaUJBQWlCO2dCQUN2QjFFLDJCQUNDLENBQUNvSixvQkFDRCxJQUFJLENBQUNuSCxnQkFBZ0IsQ0FBQ0M7Z0JBQ3ZCbEMsMkJBQTJCLENBQUNvSixvQkFBb0IsSUFBSSxDQUFDNUcsVUFBVSxDQUFDTjs7O0lBR3ZFO0FBQ0Y7QUFFTyxTQUFTekQsS0FDZG9CLEtBR0M7SUFFRCxNQUFNLEVBQ0pWLFNBQVMsRUFDVG1LLHFCQUFxQixFQUNyQm9ELE1BQU0sRUFDTjdKLFlBQVksRUFDWm1HLGFBQWEsRUFDZCxHQUFHMkQsQ0FBQUEsR0FBQUEsMEJBQUFBLGNBQWM7SUFFbEJyRCxzQkFBc0I3SyxJQUFJLEdBQUc7SUFDN0IyTSxnQ0FBZ0N2SSxjQUFjbUcsZUFBZW5KO0lBRTdELE9BQ0UsV0FERixHQUNFLElBQUFVLFlBQUFDLEdBQUEsRUFBQ3lILFFBQUFBO1FBQ0UsR0FBR3BJLEtBQUs7UUFDVCtNLE1BQU0vTSxNQUFNK00sSUFBSSxJQUFJRixVQUFVakY7UUFDOUJvRixLQUFLdE4sS0FBNkIsSUFBVUosWUFBWSxLQUFLc0k7UUFDN0RrRCxtQkFDRXBMLEtBQTZCLElBQzdCSixhQUNBSSxrQkFBeUIsZUFDckIsS0FDQWtJOztBQUlaO0FBRU8sU0FBUy9JO0lBQ2QsTUFBTSxFQUFFNEsscUJBQXFCLEVBQUUsR0FBR3FELENBQUFBLEdBQUFBLDBCQUFBQSxjQUFjO0lBQ2hEckQsc0JBQXNCNUssSUFBSSxHQUFHO0lBQzdCLGFBQWE7SUFDYixPQUFPLFdBQVAsR0FBTyxJQUFBNkIsWUFBQUMsR0FBQSxFQUFDc00sdUNBQUFBLENBQUFBO0FBQ1Y7QUFNZSxNQUFNak8saUJBQXlCMkgsT0FBQUEsT0FBSyxDQUFDQyxTQUFTO0lBRzNEOzs7R0FHQyxHQUNELE9BQU9zRyxnQkFBZ0JDLEdBQW9CLEVBQWlDO1FBQzFFLE9BQU9BLElBQUlDLHNCQUFzQixDQUFDRDtJQUNwQztJQUVBbkUsU0FBUztRQUNQLE9BQ0UsV0FERixHQUNFLElBQUF0SSxZQUFBNkMsSUFBQSxFQUFDM0UsTUFBQUE7OzhCQUNDLElBQUE4QixZQUFBQyxHQUFBLEVBQUNoQyxNQUFBQSxDQUFBQTs4QkFDRCxJQUFBK0IsWUFBQTZDLElBQUEsRUFBQzhKLFFBQUFBOztzQ0FDQyxJQUFBM00sWUFBQUMsR0FBQSxFQUFDOUIsTUFBQUEsQ0FBQUE7c0NBQ0QsSUFBQTZCLFlBQUFDLEdBQUEsRUFBQzdCLFlBQUFBLENBQUFBOzs7OztJQUlUO0FBQ0Y7QUFFQSw4RUFBOEU7QUFDOUUsMkRBQTJEO0FBQzNELE1BQU13TywyQkFDSixTQUFTQTtJQUNQLE9BQ0UsV0FERixHQUNFLElBQUE1TSxZQUFBNkMsSUFBQSxFQUFDM0UsTUFBQUE7OzBCQUNDLElBQUE4QixZQUFBQyxHQUFBLEVBQUNoQyxNQUFBQSxDQUFBQTswQkFDRCxJQUFBK0IsWUFBQTZDLElBQUEsRUFBQzhKLFFBQUFBOztrQ0FDQyxJQUFBM00sWUFBQUMsR0FBQSxFQUFDOUIsTUFBQUEsQ0FBQUE7a0NBQ0QsSUFBQTZCLFlBQUFDLEdBQUEsRUFBQzdCLFlBQUFBLENBQUFBOzs7OztBQUlUO0FBQ0FFLFFBQWdCLENBQUN1TyxXQUFBQSxxQkFBcUIsQ0FBQyxHQUFHRCIsInNvdXJjZXMiOlsid2VicGFjazovL3VuaXZlcnNhbC1uZnQtd2ViLy4uLy4uL3NyYy9wYWdlcy9fZG9jdW1lbnQudHN4P2M0NjgiXSwibmFtZXMiOlsiSGVhZCIsIkh0bWwiLCJNYWluIiwiTmV4dFNjcmlwdCIsImRlZmF1bHQiLCJEb2N1bWVudCIsImxhcmdlUGFnZURhdGFXYXJuaW5ncyIsIlNldCIsImdldERvY3VtZW50RmlsZXMiLCJidWlsZE1hbmlmZXN0IiwicGF0aG5hbWUiLCJpbkFtcE1vZGUiLCJzaGFyZWRGaWxlcyIsImdldFBhZ2VGaWxlcyIsInBhZ2VGaWxlcyIsInByb2Nlc3MiLCJlbnYiLCJORVhUX1JVTlRJTUUiLCJhbGxGaWxlcyIsImdldFBvbHlmaWxsU2NyaXB0cyIsImNvbnRleHQiLCJwcm9wcyIsImFzc2V0UHJlZml4IiwiYXNzZXRRdWVyeVN0cmluZyIsImRpc2FibGVPcHRpbWl6ZWRMb2FkaW5nIiwiY3Jvc3NPcmlnaW4iLCJwb2x5ZmlsbEZpbGVzIiwiZmlsdGVyIiwicG9seWZpbGwiLCJlbmRzV2l0aCIsIm1hcCIsIl9qc3hydW50aW1lIiwianN4Iiwic2NyaXB0IiwiZGVmZXIiLCJub25jZSIsIm5vTW9kdWxlIiwic3JjIiwiZW5jb2RlVVJJUGF0aCIsImhhc0NvbXBvbmVudFByb3BzIiwiY2hpbGQiLCJBbXBTdHlsZXMiLCJzdHlsZXMiLCJjdXJTdHlsZXMiLCJBcnJheSIsImlzQXJyYXkiLCJjaGlsZHJlbiIsImhhc1N0eWxlcyIsImVsIiwiZGFuZ2Vyb3VzbHlTZXRJbm5lckhUTUwiLCJfX2h0bWwiLCJmb3JFYWNoIiwicHVzaCIsInN0eWxlIiwiYW1wLWN1c3RvbSIsImpvaW4iLCJyZXBsYWNlIiwiZ2V0RHluYW1pY0NodW5rcyIsImZpbGVzIiwiZHluYW1pY0ltcG9ydHMiLCJpc0RldmVsb3BtZW50IiwiZmlsZSIsImluY2x1ZGVzIiwiYXN5bmMiLCJnZXRTY3JpcHRzIiwibm9ybWFsU2NyaXB0cyIsImxvd1ByaW9yaXR5U2NyaXB0cyIsImxvd1ByaW9yaXR5RmlsZXMiLCJnZXRQcmVOZXh0V29ya2VyU2NyaXB0cyIsInNjcmlwdExvYWRlciIsIm5leHRTY3JpcHRXb3JrZXJzIiwicGFydHl0b3duU25pcHBldCIsIl9fbm9uX3dlYnBhY2tfcmVxdWlyZV9fIiwidXNlckRlZmluZWRDb25maWciLCJmaW5kIiwibGVuZ3RoIiwianN4cyIsIkZyYWdtZW50IiwiZGF0YS1wYXJ0eXRvd24tY29uZmlnIiwiZGF0YS1wYXJ0eXRvd24iLCJ3b3JrZXIiLCJpbmRleCIsInN0cmF0ZWd5Iiwic2NyaXB0Q2hpbGRyZW4iLCJzY3JpcHRQcm9wcyIsInNyY1Byb3BzIiwiRXJyb3IiLCJfcmVhY3QiLCJjcmVhdGVFbGVtZW50IiwidHlwZSIsImtleSIsImRhdGEtbnNjcmlwdCIsImVyciIsImlzRXJyb3IiLCJjb2RlIiwiY29uc29sZSIsIndhcm4iLCJtZXNzYWdlIiwiZ2V0UHJlTmV4dFNjcmlwdHMiLCJ3ZWJXb3JrZXJTY3JpcHRzIiwiYmVmb3JlSW50ZXJhY3RpdmVTY3JpcHRzIiwiYmVmb3JlSW50ZXJhY3RpdmUiLCJnZXRIZWFkSFRNTFByb3BzIiwicmVzdFByb3BzIiwiaGVhZFByb3BzIiwiZ2V0QW1wUGF0aCIsImFtcFBhdGgiLCJhc1BhdGgiLCJnZXROZXh0Rm9udExpbmtUYWdzIiwibmV4dEZvbnRNYW5pZmVzdCIsImRhbmdlcm91c0FzUGF0aCIsInByZWNvbm5lY3QiLCJwcmVsb2FkIiwiYXBwRm9udHNFbnRyeSIsInBhZ2VzIiwicGFnZUZvbnRzRW50cnkiLCJwcmVsb2FkZWRGb250RmlsZXMiLCJmcm9tIiwicHJlY29ubmVjdFRvU2VsZiIsImxpbmsiLCJkYXRhLW5leHQtZm9udCIsInBhZ2VzVXNpbmdTaXplQWRqdXN0IiwicmVsIiwiaHJlZiIsImZvbnRGaWxlIiwiZXh0IiwiZXhlYyIsImFzIiwiUmVhY3QiLCJDb21wb25lbnQiLCJjb250ZXh0VHlwZSIsIkh0bWxDb250ZXh0IiwiZ2V0Q3NzTGlua3MiLCJvcHRpbWl6ZUNzcyIsIm9wdGltaXplRm9udHMiLCJjc3NGaWxlcyIsImYiLCJ1bm1hbmdlZEZpbGVzIiwiZHluYW1pY0Nzc0ZpbGVzIiwiZXhpc3RpbmciLCJoYXMiLCJjc3NMaW5rRWxlbWVudHMiLCJpc1NoYXJlZEZpbGUiLCJpc1VubWFuYWdlZEZpbGUiLCJkYXRhLW4tZyIsInVuZGVmaW5lZCIsImRhdGEtbi1wIiwibWFrZVN0eWxlc2hlZXRJbmVydCIsImdldFByZWxvYWREeW5hbWljQ2h1bmtzIiwiQm9vbGVhbiIsImdldFByZWxvYWRNYWluTGlua3MiLCJwcmVsb2FkRmlsZXMiLCJnZXRCZWZvcmVJbnRlcmFjdGl2ZUlubGluZVNjcmlwdHMiLCJodG1sIiwiaWQiLCJfX05FWFRfQ1JPU1NfT1JJR0lOIiwibm9kZSIsIkNoaWxkcmVuIiwiYyIsIk9QVElNSVpFRF9GT05UX1BST1ZJREVSUyIsInNvbWUiLCJ1cmwiLCJzdGFydHNXaXRoIiwibmV3UHJvcHMiLCJjbG9uZUVsZW1lbnQiLCJyZW5kZXIiLCJoeWJyaWRBbXAiLCJjYW5vbmljYWxCYXNlIiwiX19ORVhUX0RBVEFfXyIsImhlYWRUYWdzIiwidW5zdGFibGVfcnVudGltZUpTIiwidW5zdGFibGVfSnNQcmVsb2FkIiwiZGlzYWJsZVJ1bnRpbWVKUyIsImRpc2FibGVKc1ByZWxvYWQiLCJkb2NDb21wb25lbnRzUmVuZGVyZWQiLCJoZWFkIiwiY3NzUHJlbG9hZHMiLCJvdGhlckhlYWRFbGVtZW50cyIsIm1ldGFUYWciLCJzdHJpY3ROZXh0SGVhZCIsIm5hbWUiLCJjb250ZW50IiwiY29uY2F0IiwidG9BcnJheSIsImlzUmVhY3RIZWxtZXQiLCJoYXNBbXBodG1sUmVsIiwiaGFzQ2Fub25pY2FsUmVsIiwiYmFkUHJvcCIsImluZGV4T2YiLCJPYmplY3QiLCJrZXlzIiwicHJvcCIsInBhZ2UiLCJuZXh0Rm9udExpbmtUYWdzIiwiZGF0YS1uZXh0LWhpZGUtZm91YyIsImRhdGEtYW1wZGV2bW9kZSIsIm5vc2NyaXB0IiwibWV0YSIsImNvdW50IiwidG9TdHJpbmciLCJyZXF1aXJlIiwiY2xlYW5BbXBQYXRoIiwiYW1wLWJvaWxlcnBsYXRlIiwiZGF0YS1uLWNzcyIsImhhbmRsZURvY3VtZW50U2NyaXB0TG9hZGVySXRlbXMiLCJzY3JpcHRMb2FkZXJJdGVtcyIsImhlYWRDaGlsZHJlbiIsImJvZHlDaGlsZHJlbiIsImNvbWJpbmVkQ2hpbGRyZW4iLCJfX25leHRTY3JpcHQiLCJnZXRJbmxpbmVTY3JpcHRTb3VyY2UiLCJsYXJnZVBhZ2VEYXRhQnl0ZXMiLCJkYXRhIiwiSlNPTiIsInN0cmluZ2lmeSIsImh0bWxFc2NhcGVKc29uU3RyaW5nIiwiYnl0ZXMiLCJUZXh0RW5jb2RlciIsImVuY29kZSIsImJ1ZmZlciIsImJ5dGVMZW5ndGgiLCJCdWZmZXIiLCJwcmV0dHlCeXRlcyIsImFkZCIsImFtcERldkZpbGVzIiwiZGV2RmlsZXMiLCJsb2NhbGUiLCJ1c2VIdG1sQ29udGV4dCIsImxhbmciLCJhbXAiLCJuZXh0LWpzLWludGVybmFsLWJvZHktcmVuZGVyLXRhcmdldCIsImdldEluaXRpYWxQcm9wcyIsImN0eCIsImRlZmF1bHRHZXRJbml0aWFsUHJvcHMiLCJib2R5IiwiSW50ZXJuYWxGdW5jdGlvbkRvY3VtZW50IiwiTkVYVF9CVUlMVElOX0RPQ1VNRU5UIl0sInNvdXJjZVJvb3QiOiIifQ==\n//# sourceURL=webpack-internal:///./node_modules/next/dist/pages/_document.js\n");
-
-/***/ }),
-
-/***/ "./node_modules/next/dist/pages/_error.js":
-/*!************************************************!*\
-  !*** ./node_modules/next/dist/pages/_error.js ***!
-  \************************************************/
-/***/ ((module, exports, __webpack_require__) => {
-
-eval("\nObject.defineProperty(exports, \"__esModule\", ({\n    value: true\n}));\nObject.defineProperty(exports, \"default\", ({\n    enumerable: true,\n    get: function() {\n        return Error;\n    }\n}));\nconst _interop_require_default = __webpack_require__(/*! @swc/helpers/_/_interop_require_default */ \"./node_modules/@swc/helpers/cjs/_interop_require_default.cjs\");\nconst _jsxruntime = __webpack_require__(/*! react/jsx-runtime */ \"react/jsx-runtime\");\nconst _react = /*#__PURE__*/ _interop_require_default._(__webpack_require__(/*! react */ \"react\"));\nconst _head = /*#__PURE__*/ _interop_require_default._(__webpack_require__(/*! ../shared/lib/head */ \"./node_modules/next/dist/shared/lib/head.js\"));\nconst statusCodes = {\n    400: \"Bad Request\",\n    404: \"This page could not be found\",\n    405: \"Method Not Allowed\",\n    500: \"Internal Server Error\"\n};\nfunction _getInitialProps(param) {\n    let { res, err } = param;\n    const statusCode = res && res.statusCode ? res.statusCode : err ? err.statusCode : 404;\n    return {\n        statusCode\n    };\n}\nconst styles = {\n    error: {\n        // https://github.com/sindresorhus/modern-normalize/blob/main/modern-normalize.css#L38-L52\n        fontFamily: 'system-ui,\"Segoe UI\",Roboto,Helvetica,Arial,sans-serif,\"Apple Color Emoji\",\"Segoe UI Emoji\"',\n        height: \"100vh\",\n        textAlign: \"center\",\n        display: \"flex\",\n        flexDirection: \"column\",\n        alignItems: \"center\",\n        justifyContent: \"center\"\n    },\n    desc: {\n        lineHeight: \"48px\"\n    },\n    h1: {\n        display: \"inline-block\",\n        margin: \"0 20px 0 0\",\n        paddingRight: 23,\n        fontSize: 24,\n        fontWeight: 500,\n        verticalAlign: \"top\"\n    },\n    h2: {\n        fontSize: 14,\n        fontWeight: 400,\n        lineHeight: \"28px\"\n    },\n    wrap: {\n        display: \"inline-block\"\n    }\n};\nclass Error extends _react.default.Component {\n    render() {\n        const { statusCode, withDarkMode = true } = this.props;\n        const title = this.props.title || statusCodes[statusCode] || \"An unexpected error has occurred\";\n        return /*#__PURE__*/ (0, _jsxruntime.jsxs)(\"div\", {\n            style: styles.error,\n            children: [\n                /*#__PURE__*/ (0, _jsxruntime.jsx)(_head.default, {\n                    children: /*#__PURE__*/ (0, _jsxruntime.jsx)(\"title\", {\n                        children: statusCode ? statusCode + \": \" + title : \"Application error: a client-side exception has occurred\"\n                    })\n                }),\n                /*#__PURE__*/ (0, _jsxruntime.jsxs)(\"div\", {\n                    style: styles.desc,\n                    children: [\n                        /*#__PURE__*/ (0, _jsxruntime.jsx)(\"style\", {\n                            dangerouslySetInnerHTML: {\n                                /* CSS minified from\n                body { margin: 0; color: #000; background: #fff; }\n                .next-error-h1 {\n                  border-right: 1px solid rgba(0, 0, 0, .3);\n                }\n\n                ${\n                  withDarkMode\n                    ? `@media (prefers-color-scheme: dark) {\n                  body { color: #fff; background: #000; }\n                  .next-error-h1 {\n                    border-right: 1px solid rgba(255, 255, 255, .3);\n                  }\n                }`\n                    : ''\n                }\n               */ __html: \"body{color:#000;background:#fff;margin:0}.next-error-h1{border-right:1px solid rgba(0,0,0,.3)}\" + (withDarkMode ? \"@media (prefers-color-scheme:dark){body{color:#fff;background:#000}.next-error-h1{border-right:1px solid rgba(255,255,255,.3)}}\" : \"\")\n                            }\n                        }),\n                        statusCode ? /*#__PURE__*/ (0, _jsxruntime.jsx)(\"h1\", {\n                            className: \"next-error-h1\",\n                            style: styles.h1,\n                            children: statusCode\n                        }) : null,\n                        /*#__PURE__*/ (0, _jsxruntime.jsx)(\"div\", {\n                            style: styles.wrap,\n                            children: /*#__PURE__*/ (0, _jsxruntime.jsxs)(\"h2\", {\n                                style: styles.h2,\n                                children: [\n                                    this.props.title || statusCode ? title : /*#__PURE__*/ (0, _jsxruntime.jsx)(_jsxruntime.Fragment, {\n                                        children: \"Application error: a client-side exception has occurred (see the browser console for more information)\"\n                                    }),\n                                    \".\"\n                                ]\n                            })\n                        })\n                    ]\n                })\n            ]\n        });\n    }\n}\nError.displayName = \"ErrorPage\";\nError.getInitialProps = _getInitialProps;\nError.origGetInitialProps = _getInitialProps;\nif ((typeof exports.default === \"function\" || typeof exports.default === \"object\" && exports.default !== null) && typeof exports.default.__esModule === \"undefined\") {\n    Object.defineProperty(exports.default, \"__esModule\", {\n        value: true\n    });\n    Object.assign(exports.default, exports);\n    module.exports = exports.default;\n} //# sourceMappingURL=_error.js.map\n//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvbmV4dC9kaXN0L3BhZ2VzL19lcnJvci5qcyIsIm1hcHBpbmdzIjoiOzs7Ozs7O2VBOERxQkE7Ozs7OzRFQTlESDsyRUFDRDtBQUdqQixNQUFNQyxjQUEwQztJQUM5QyxLQUFLO0lBQ0wsS0FBSztJQUNMLEtBQUs7SUFDTCxLQUFLO0FBQ1A7QUFRQSxTQUFTQyxpQkFBaUJDLEtBR1I7SUFIUSxNQUN4QkMsR0FBRyxFQUNIQyxHQUFHLEVBQ2EsR0FIUUY7SUFJeEIsTUFBTUcsYUFDSkYsT0FBT0EsSUFBSUUsVUFBVSxHQUFHRixJQUFJRSxVQUFVLEdBQUdELE1BQU1BLElBQUlDLFVBQVUsR0FBSTtJQUNuRSxPQUFPO1FBQUVBO0lBQVc7QUFDdEI7QUFFQSxNQUFNQyxTQUE4QztJQUNsREMsT0FBTztRQUNMLDBGQUEwRjtRQUMxRkMsWUFDRTtRQUNGQyxRQUFRO1FBQ1JDLFdBQVc7UUFDWEMsU0FBUztRQUNUQyxlQUFlO1FBQ2ZDLFlBQVk7UUFDWkMsZ0JBQWdCO0lBQ2xCO0lBQ0FDLE1BQU07UUFDSkMsWUFBWTtJQUNkO0lBQ0FDLElBQUk7UUFDRk4sU0FBUztRQUNUTyxRQUFRO1FBQ1JDLGNBQWM7UUFDZEMsVUFBVTtRQUNWQyxZQUFZO1FBQ1pDLGVBQWU7SUFDakI7SUFDQUMsSUFBSTtRQUNGSCxVQUFVO1FBQ1ZDLFlBQVk7UUFDWkwsWUFBWTtJQUNkO0lBQ0FRLE1BQU07UUFDSmIsU0FBUztJQUNYO0FBQ0Y7QUFLZSxNQUFNWixjQUFzQjBCLE9BQUFBLE9BQUssQ0FBQ0MsU0FBUztJQU14REMsU0FBUztRQUNQLE1BQU0sRUFBRXRCLFVBQVUsRUFBRXVCLGVBQWUsSUFBSSxFQUFFLEdBQUcsSUFBSSxDQUFDQyxLQUFLO1FBQ3RELE1BQU1DLFFBQ0osSUFBSSxDQUFDRCxLQUFLLENBQUNDLEtBQUssSUFDaEI5QixXQUFXLENBQUNLLFdBQVcsSUFDdkI7UUFFRixPQUNFLFdBREYsR0FDRSxJQUFBMEIsWUFBQUMsSUFBQSxFQUFDQyxPQUFBQTtZQUFJQyxPQUFPNUIsT0FBT0MsS0FBSzs7OEJBQ3RCLElBQUF3QixZQUFBSSxHQUFBLEVBQUNDLE1BQUFBLE9BQUk7OEJBQ0gsa0JBQUFMLFlBQUFJLEdBQUEsRUFBQ0wsU0FBQUE7a0NBQ0V6QixhQUNHQSxhQUFjLE9BQUl5QixRQUNsQjs7OzhCQUdSLElBQUFDLFlBQUFDLElBQUEsRUFBQ0MsT0FBQUE7b0JBQUlDLE9BQU81QixPQUFPUyxJQUFJOztzQ0FDckIsSUFBQWdCLFlBQUFJLEdBQUEsRUFBQ0QsU0FBQUE7NEJBQ0NHLHlCQUF5QjtnQ0FDdkI7Ozs7Ozs7Ozs7Ozs7Ozs7ZUFnQkMsR0FDREMsUUFBUSxtR0FDTlYsQ0FBQUEsZUFDSSxvSUFDQTs0QkFFUjs7d0JBR0R2QixhQUNDLFdBRERBLEdBQ0MsSUFBQTBCLFlBQUFJLEdBQUEsRUFBQ2xCLE1BQUFBOzRCQUFHc0IsV0FBVTs0QkFBZ0JMLE9BQU81QixPQUFPVyxFQUFFO3NDQUMzQ1o7NkJBRUQ7c0NBQ0osSUFBQTBCLFlBQUFJLEdBQUEsRUFBQ0YsT0FBQUE7NEJBQUlDLE9BQU81QixPQUFPa0IsSUFBSTtzQ0FDckIsa0JBQUFPLFlBQUFDLElBQUEsRUFBQ1QsTUFBQUE7Z0NBQUdXLE9BQU81QixPQUFPaUIsRUFBRTs7b0NBQ2pCLElBQUksQ0FBQ00sS0FBSyxDQUFDQyxLQUFLLElBQUl6QixhQUNuQnlCLFFBRUEsV0FGQUEsR0FFQSxJQUFBQyxZQUFBSSxHQUFBLEVBQUFKLFlBQUFTLFFBQUE7a0RBQUU7O29DQUlGOzs7Ozs7OztJQU9kO0FBQ0Y7QUF4RXFCekMsTUFDWjBDLFdBQUFBLEdBQWM7QUFERjFDLE1BR1oyQyxlQUFBQSxHQUFrQnpDO0FBSE5GLE1BSVo0QyxtQkFBQUEsR0FBc0IxQyIsInNvdXJjZXMiOlsid2VicGFjazovL3VuaXZlcnNhbC1uZnQtd2ViLy4uLy4uL3NyYy9wYWdlcy9fZXJyb3IudHN4PzQ4N2UiXSwibmFtZXMiOlsiRXJyb3IiLCJzdGF0dXNDb2RlcyIsIl9nZXRJbml0aWFsUHJvcHMiLCJwYXJhbSIsInJlcyIsImVyciIsInN0YXR1c0NvZGUiLCJzdHlsZXMiLCJlcnJvciIsImZvbnRGYW1pbHkiLCJoZWlnaHQiLCJ0ZXh0QWxpZ24iLCJkaXNwbGF5IiwiZmxleERpcmVjdGlvbiIsImFsaWduSXRlbXMiLCJqdXN0aWZ5Q29udGVudCIsImRlc2MiLCJsaW5lSGVpZ2h0IiwiaDEiLCJtYXJnaW4iLCJwYWRkaW5nUmlnaHQiLCJmb250U2l6ZSIsImZvbnRXZWlnaHQiLCJ2ZXJ0aWNhbEFsaWduIiwiaDIiLCJ3cmFwIiwiUmVhY3QiLCJDb21wb25lbnQiLCJyZW5kZXIiLCJ3aXRoRGFya01vZGUiLCJwcm9wcyIsInRpdGxlIiwiX2pzeHJ1bnRpbWUiLCJqc3hzIiwiZGl2Iiwic3R5bGUiLCJqc3giLCJIZWFkIiwiZGFuZ2Vyb3VzbHlTZXRJbm5lckhUTUwiLCJfX2h0bWwiLCJjbGFzc05hbWUiLCJGcmFnbWVudCIsImRpc3BsYXlOYW1lIiwiZ2V0SW5pdGlhbFByb3BzIiwib3JpZ0dldEluaXRpYWxQcm9wcyJdLCJzb3VyY2VSb290IjoiIn0=\n//# sourceURL=webpack-internal:///./node_modules/next/dist/pages/_error.js\n");
-
-/***/ }),
-
-/***/ "./node_modules/next/dist/shared/lib/amp-mode.js":
-/*!*******************************************************!*\
-  !*** ./node_modules/next/dist/shared/lib/amp-mode.js ***!
-  \*******************************************************/
-/***/ ((__unused_webpack_module, exports) => {
-
-eval("\nObject.defineProperty(exports, \"__esModule\", ({\n    value: true\n}));\nObject.defineProperty(exports, \"isInAmpMode\", ({\n    enumerable: true,\n    get: function() {\n        return isInAmpMode;\n    }\n}));\nfunction isInAmpMode(param) {\n    let { ampFirst = false, hybrid = false, hasQuery = false } = param === void 0 ? {} : param;\n    return ampFirst || hybrid && hasQuery;\n} //# sourceMappingURL=amp-mode.js.map\n//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvbmV4dC9kaXN0L3NoYXJlZC9saWIvYW1wLW1vZGUuanMiLCJtYXBwaW5ncyI6Ijs7OzsrQ0FBZ0JBOzs7ZUFBQUE7OztBQUFULFNBQVNBLFlBQVlDLEtBQUE7SUFBQSxNQUMxQkMsV0FBVyxLQUFLLEVBQ2hCQyxTQUFTLEtBQUssRUFDZEMsV0FBVyxLQUFLLEVBQ2pCLEdBSjJCSCxVQUFBLFNBSXhCLENBQUMsSUFKdUJBO0lBSzFCLE9BQU9DLFlBQWFDLFVBQVVDO0FBQ2hDIiwic291cmNlcyI6WyJ3ZWJwYWNrOi8vdW5pdmVyc2FsLW5mdC13ZWIvLi4vLi4vLi4vc3JjL3NoYXJlZC9saWIvYW1wLW1vZGUudHM/Y2UwNCJdLCJuYW1lcyI6WyJpc0luQW1wTW9kZSIsInBhcmFtIiwiYW1wRmlyc3QiLCJoeWJyaWQiLCJoYXNRdWVyeSJdLCJzb3VyY2VSb290IjoiIn0=\n//# sourceURL=webpack-internal:///./node_modules/next/dist/shared/lib/amp-mode.js\n");
-
-/***/ }),
-
-/***/ "./node_modules/next/dist/shared/lib/constants.js":
-/*!********************************************************!*\
-  !*** ./node_modules/next/dist/shared/lib/constants.js ***!
-  \********************************************************/
-/***/ ((module, exports, __webpack_require__) => {
-
-eval("\nObject.defineProperty(exports, \"__esModule\", ({\n    value: true\n}));\n0 && (0);\nfunction _export(target, all) {\n    for(var name in all)Object.defineProperty(target, name, {\n        enumerable: true,\n        get: all[name]\n    });\n}\n_export(exports, {\n    APP_BUILD_MANIFEST: function() {\n        return APP_BUILD_MANIFEST;\n    },\n    APP_CLIENT_INTERNALS: function() {\n        return APP_CLIENT_INTERNALS;\n    },\n    APP_PATHS_MANIFEST: function() {\n        return APP_PATHS_MANIFEST;\n    },\n    APP_PATH_ROUTES_MANIFEST: function() {\n        return APP_PATH_ROUTES_MANIFEST;\n    },\n    AUTOMATIC_FONT_OPTIMIZATION_MANIFEST: function() {\n        return AUTOMATIC_FONT_OPTIMIZATION_MANIFEST;\n    },\n    BARREL_OPTIMIZATION_PREFIX: function() {\n        return BARREL_OPTIMIZATION_PREFIX;\n    },\n    BLOCKED_PAGES: function() {\n        return BLOCKED_PAGES;\n    },\n    BUILD_ID_FILE: function() {\n        return BUILD_ID_FILE;\n    },\n    BUILD_MANIFEST: function() {\n        return BUILD_MANIFEST;\n    },\n    CLIENT_PUBLIC_FILES_PATH: function() {\n        return CLIENT_PUBLIC_FILES_PATH;\n    },\n    CLIENT_REFERENCE_MANIFEST: function() {\n        return CLIENT_REFERENCE_MANIFEST;\n    },\n    CLIENT_STATIC_FILES_PATH: function() {\n        return CLIENT_STATIC_FILES_PATH;\n    },\n    CLIENT_STATIC_FILES_RUNTIME_AMP: function() {\n        return CLIENT_STATIC_FILES_RUNTIME_AMP;\n    },\n    CLIENT_STATIC_FILES_RUNTIME_MAIN: function() {\n        return CLIENT_STATIC_FILES_RUNTIME_MAIN;\n    },\n    CLIENT_STATIC_FILES_RUNTIME_MAIN_APP: function() {\n        return CLIENT_STATIC_FILES_RUNTIME_MAIN_APP;\n    },\n    CLIENT_STATIC_FILES_RUNTIME_POLYFILLS: function() {\n        return CLIENT_STATIC_FILES_RUNTIME_POLYFILLS;\n    },\n    CLIENT_STATIC_FILES_RUNTIME_POLYFILLS_SYMBOL: function() {\n        return CLIENT_STATIC_FILES_RUNTIME_POLYFILLS_SYMBOL;\n    },\n    CLIENT_STATIC_FILES_RUNTIME_REACT_REFRESH: function() {\n        return CLIENT_STATIC_FILES_RUNTIME_REACT_REFRESH;\n    },\n    CLIENT_STATIC_FILES_RUNTIME_WEBPACK: function() {\n        return CLIENT_STATIC_FILES_RUNTIME_WEBPACK;\n    },\n    COMPILER_INDEXES: function() {\n        return COMPILER_INDEXES;\n    },\n    COMPILER_NAMES: function() {\n        return COMPILER_NAMES;\n    },\n    CONFIG_FILES: function() {\n        return CONFIG_FILES;\n    },\n    DEFAULT_RUNTIME_WEBPACK: function() {\n        return DEFAULT_RUNTIME_WEBPACK;\n    },\n    DEFAULT_SANS_SERIF_FONT: function() {\n        return DEFAULT_SANS_SERIF_FONT;\n    },\n    DEFAULT_SERIF_FONT: function() {\n        return DEFAULT_SERIF_FONT;\n    },\n    DEV_CLIENT_PAGES_MANIFEST: function() {\n        return DEV_CLIENT_PAGES_MANIFEST;\n    },\n    DEV_MIDDLEWARE_MANIFEST: function() {\n        return DEV_MIDDLEWARE_MANIFEST;\n    },\n    EDGE_RUNTIME_WEBPACK: function() {\n        return EDGE_RUNTIME_WEBPACK;\n    },\n    EDGE_UNSUPPORTED_NODE_APIS: function() {\n        return EDGE_UNSUPPORTED_NODE_APIS;\n    },\n    EXPORT_DETAIL: function() {\n        return EXPORT_DETAIL;\n    },\n    EXPORT_MARKER: function() {\n        return EXPORT_MARKER;\n    },\n    FUNCTIONS_CONFIG_MANIFEST: function() {\n        return FUNCTIONS_CONFIG_MANIFEST;\n    },\n    GOOGLE_FONT_PROVIDER: function() {\n        return GOOGLE_FONT_PROVIDER;\n    },\n    IMAGES_MANIFEST: function() {\n        return IMAGES_MANIFEST;\n    },\n    INTERCEPTION_ROUTE_REWRITE_MANIFEST: function() {\n        return INTERCEPTION_ROUTE_REWRITE_MANIFEST;\n    },\n    INTERNAL_HEADERS: function() {\n        return INTERNAL_HEADERS;\n    },\n    MIDDLEWARE_BUILD_MANIFEST: function() {\n        return MIDDLEWARE_BUILD_MANIFEST;\n    },\n    MIDDLEWARE_MANIFEST: function() {\n        return MIDDLEWARE_MANIFEST;\n    },\n    MIDDLEWARE_REACT_LOADABLE_MANIFEST: function() {\n        return MIDDLEWARE_REACT_LOADABLE_MANIFEST;\n    },\n    MODERN_BROWSERSLIST_TARGET: function() {\n        return _modernbrowserslisttarget.default;\n    },\n    NEXT_BUILTIN_DOCUMENT: function() {\n        return NEXT_BUILTIN_DOCUMENT;\n    },\n    NEXT_FONT_MANIFEST: function() {\n        return NEXT_FONT_MANIFEST;\n    },\n    OPTIMIZED_FONT_PROVIDERS: function() {\n        return OPTIMIZED_FONT_PROVIDERS;\n    },\n    PAGES_MANIFEST: function() {\n        return PAGES_MANIFEST;\n    },\n    PHASE_DEVELOPMENT_SERVER: function() {\n        return PHASE_DEVELOPMENT_SERVER;\n    },\n    PHASE_EXPORT: function() {\n        return PHASE_EXPORT;\n    },\n    PHASE_INFO: function() {\n        return PHASE_INFO;\n    },\n    PHASE_PRODUCTION_BUILD: function() {\n        return PHASE_PRODUCTION_BUILD;\n    },\n    PHASE_PRODUCTION_SERVER: function() {\n        return PHASE_PRODUCTION_SERVER;\n    },\n    PHASE_TEST: function() {\n        return PHASE_TEST;\n    },\n    PRERENDER_MANIFEST: function() {\n        return PRERENDER_MANIFEST;\n    },\n    REACT_LOADABLE_MANIFEST: function() {\n        return REACT_LOADABLE_MANIFEST;\n    },\n    ROUTES_MANIFEST: function() {\n        return ROUTES_MANIFEST;\n    },\n    RSC_MODULE_TYPES: function() {\n        return RSC_MODULE_TYPES;\n    },\n    SERVER_DIRECTORY: function() {\n        return SERVER_DIRECTORY;\n    },\n    SERVER_FILES_MANIFEST: function() {\n        return SERVER_FILES_MANIFEST;\n    },\n    SERVER_PROPS_ID: function() {\n        return SERVER_PROPS_ID;\n    },\n    SERVER_REFERENCE_MANIFEST: function() {\n        return SERVER_REFERENCE_MANIFEST;\n    },\n    STATIC_PROPS_ID: function() {\n        return STATIC_PROPS_ID;\n    },\n    STATIC_STATUS_PAGES: function() {\n        return STATIC_STATUS_PAGES;\n    },\n    STRING_LITERAL_DROP_BUNDLE: function() {\n        return STRING_LITERAL_DROP_BUNDLE;\n    },\n    SUBRESOURCE_INTEGRITY_MANIFEST: function() {\n        return SUBRESOURCE_INTEGRITY_MANIFEST;\n    },\n    SYSTEM_ENTRYPOINTS: function() {\n        return SYSTEM_ENTRYPOINTS;\n    },\n    TRACE_OUTPUT_VERSION: function() {\n        return TRACE_OUTPUT_VERSION;\n    },\n    TURBO_TRACE_DEFAULT_MEMORY_LIMIT: function() {\n        return TURBO_TRACE_DEFAULT_MEMORY_LIMIT;\n    },\n    UNDERSCORE_NOT_FOUND_ROUTE: function() {\n        return UNDERSCORE_NOT_FOUND_ROUTE;\n    },\n    UNDERSCORE_NOT_FOUND_ROUTE_ENTRY: function() {\n        return UNDERSCORE_NOT_FOUND_ROUTE_ENTRY;\n    }\n});\nconst _interop_require_default = __webpack_require__(/*! @swc/helpers/_/_interop_require_default */ \"./node_modules/@swc/helpers/cjs/_interop_require_default.cjs\");\nconst _modernbrowserslisttarget = /*#__PURE__*/ _interop_require_default._(__webpack_require__(/*! ./modern-browserslist-target */ \"./node_modules/next/dist/shared/lib/modern-browserslist-target.js\"));\nconst COMPILER_NAMES = {\n    client: \"client\",\n    server: \"server\",\n    edgeServer: \"edge-server\"\n};\nconst INTERNAL_HEADERS = [\n    \"x-invoke-error\",\n    \"x-invoke-output\",\n    \"x-invoke-path\",\n    \"x-invoke-query\",\n    \"x-invoke-status\",\n    \"x-middleware-invoke\"\n];\nconst COMPILER_INDEXES = {\n    [COMPILER_NAMES.client]: 0,\n    [COMPILER_NAMES.server]: 1,\n    [COMPILER_NAMES.edgeServer]: 2\n};\nconst UNDERSCORE_NOT_FOUND_ROUTE = \"/_not-found\";\nconst UNDERSCORE_NOT_FOUND_ROUTE_ENTRY = \"\" + UNDERSCORE_NOT_FOUND_ROUTE + \"/page\";\nconst PHASE_EXPORT = \"phase-export\";\nconst PHASE_PRODUCTION_BUILD = \"phase-production-build\";\nconst PHASE_PRODUCTION_SERVER = \"phase-production-server\";\nconst PHASE_DEVELOPMENT_SERVER = \"phase-development-server\";\nconst PHASE_TEST = \"phase-test\";\nconst PHASE_INFO = \"phase-info\";\nconst PAGES_MANIFEST = \"pages-manifest.json\";\nconst APP_PATHS_MANIFEST = \"app-paths-manifest.json\";\nconst APP_PATH_ROUTES_MANIFEST = \"app-path-routes-manifest.json\";\nconst BUILD_MANIFEST = \"build-manifest.json\";\nconst APP_BUILD_MANIFEST = \"app-build-manifest.json\";\nconst FUNCTIONS_CONFIG_MANIFEST = \"functions-config-manifest.json\";\nconst SUBRESOURCE_INTEGRITY_MANIFEST = \"subresource-integrity-manifest\";\nconst NEXT_FONT_MANIFEST = \"next-font-manifest\";\nconst EXPORT_MARKER = \"export-marker.json\";\nconst EXPORT_DETAIL = \"export-detail.json\";\nconst PRERENDER_MANIFEST = \"prerender-manifest.json\";\nconst ROUTES_MANIFEST = \"routes-manifest.json\";\nconst IMAGES_MANIFEST = \"images-manifest.json\";\nconst SERVER_FILES_MANIFEST = \"required-server-files.json\";\nconst DEV_CLIENT_PAGES_MANIFEST = \"_devPagesManifest.json\";\nconst MIDDLEWARE_MANIFEST = \"middleware-manifest.json\";\nconst DEV_MIDDLEWARE_MANIFEST = \"_devMiddlewareManifest.json\";\nconst REACT_LOADABLE_MANIFEST = \"react-loadable-manifest.json\";\nconst AUTOMATIC_FONT_OPTIMIZATION_MANIFEST = \"font-manifest.json\";\nconst SERVER_DIRECTORY = \"server\";\nconst CONFIG_FILES = [\n    \"next.config.js\",\n    \"next.config.mjs\"\n];\nconst BUILD_ID_FILE = \"BUILD_ID\";\nconst BLOCKED_PAGES = [\n    \"/_document\",\n    \"/_app\",\n    \"/_error\"\n];\nconst CLIENT_PUBLIC_FILES_PATH = \"public\";\nconst CLIENT_STATIC_FILES_PATH = \"static\";\nconst STRING_LITERAL_DROP_BUNDLE = \"__NEXT_DROP_CLIENT_FILE__\";\nconst NEXT_BUILTIN_DOCUMENT = \"__NEXT_BUILTIN_DOCUMENT__\";\nconst BARREL_OPTIMIZATION_PREFIX = \"__barrel_optimize__\";\nconst CLIENT_REFERENCE_MANIFEST = \"client-reference-manifest\";\nconst SERVER_REFERENCE_MANIFEST = \"server-reference-manifest\";\nconst MIDDLEWARE_BUILD_MANIFEST = \"middleware-build-manifest\";\nconst MIDDLEWARE_REACT_LOADABLE_MANIFEST = \"middleware-react-loadable-manifest\";\nconst INTERCEPTION_ROUTE_REWRITE_MANIFEST = \"interception-route-rewrite-manifest\";\nconst CLIENT_STATIC_FILES_RUNTIME_MAIN = \"main\";\nconst CLIENT_STATIC_FILES_RUNTIME_MAIN_APP = \"\" + CLIENT_STATIC_FILES_RUNTIME_MAIN + \"-app\";\nconst APP_CLIENT_INTERNALS = \"app-pages-internals\";\nconst CLIENT_STATIC_FILES_RUNTIME_REACT_REFRESH = \"react-refresh\";\nconst CLIENT_STATIC_FILES_RUNTIME_AMP = \"amp\";\nconst CLIENT_STATIC_FILES_RUNTIME_WEBPACK = \"webpack\";\nconst CLIENT_STATIC_FILES_RUNTIME_POLYFILLS = \"polyfills\";\nconst CLIENT_STATIC_FILES_RUNTIME_POLYFILLS_SYMBOL = Symbol(CLIENT_STATIC_FILES_RUNTIME_POLYFILLS);\nconst DEFAULT_RUNTIME_WEBPACK = \"webpack-runtime\";\nconst EDGE_RUNTIME_WEBPACK = \"edge-runtime-webpack\";\nconst STATIC_PROPS_ID = \"__N_SSG\";\nconst SERVER_PROPS_ID = \"__N_SSP\";\nconst GOOGLE_FONT_PROVIDER = \"https://fonts.googleapis.com/\";\nconst OPTIMIZED_FONT_PROVIDERS = [\n    {\n        url: GOOGLE_FONT_PROVIDER,\n        preconnect: \"https://fonts.gstatic.com\"\n    },\n    {\n        url: \"https://use.typekit.net\",\n        preconnect: \"https://use.typekit.net\"\n    }\n];\nconst DEFAULT_SERIF_FONT = {\n    name: \"Times New Roman\",\n    xAvgCharWidth: 821,\n    azAvgWidth: 854.3953488372093,\n    unitsPerEm: 2048\n};\nconst DEFAULT_SANS_SERIF_FONT = {\n    name: \"Arial\",\n    xAvgCharWidth: 904,\n    azAvgWidth: 934.5116279069767,\n    unitsPerEm: 2048\n};\nconst STATIC_STATUS_PAGES = [\n    \"/500\"\n];\nconst TRACE_OUTPUT_VERSION = 1;\nconst TURBO_TRACE_DEFAULT_MEMORY_LIMIT = 6000;\nconst RSC_MODULE_TYPES = {\n    client: \"client\",\n    server: \"server\"\n};\nconst EDGE_UNSUPPORTED_NODE_APIS = [\n    \"clearImmediate\",\n    \"setImmediate\",\n    \"BroadcastChannel\",\n    \"ByteLengthQueuingStrategy\",\n    \"CompressionStream\",\n    \"CountQueuingStrategy\",\n    \"DecompressionStream\",\n    \"DomException\",\n    \"MessageChannel\",\n    \"MessageEvent\",\n    \"MessagePort\",\n    \"ReadableByteStreamController\",\n    \"ReadableStreamBYOBRequest\",\n    \"ReadableStreamDefaultController\",\n    \"TransformStreamDefaultController\",\n    \"WritableStreamDefaultController\"\n];\nconst SYSTEM_ENTRYPOINTS = new Set([\n    CLIENT_STATIC_FILES_RUNTIME_MAIN,\n    CLIENT_STATIC_FILES_RUNTIME_REACT_REFRESH,\n    CLIENT_STATIC_FILES_RUNTIME_AMP,\n    CLIENT_STATIC_FILES_RUNTIME_MAIN_APP\n]);\nif ((typeof exports.default === \"function\" || typeof exports.default === \"object\" && exports.default !== null) && typeof exports.default.__esModule === \"undefined\") {\n    Object.defineProperty(exports.default, \"__esModule\", {\n        value: true\n    });\n    Object.assign(exports.default, exports);\n    module.exports = exports.default;\n} //# sourceMappingURL=constants.js.map\n//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvbmV4dC9kaXN0L3NoYXJlZC9saWIvY29uc3RhbnRzLmpzIiwibWFwcGluZ3MiOiI7Ozs7Ozs7Ozs7OztJQStDYUEsb0JBQWtCO2VBQWxCQTs7SUEwQ0FDLHNCQUFvQjtlQUFwQkE7O0lBN0NBQyxvQkFBa0I7ZUFBbEJBOztJQUNBQywwQkFBd0I7ZUFBeEJBOztJQWdCQUMsc0NBQW9DO2VBQXBDQTs7SUFTQUMsNEJBQTBCO2VBQTFCQTs7SUFMQUMsZUFBYTtlQUFiQTs7SUFEQUMsZUFBYTtlQUFiQTs7SUFsQkFDLGdCQUFjO2VBQWRBOztJQW9CQUMsMEJBQXdCO2VBQXhCQTs7SUFPQUMsMkJBQXlCO2VBQXpCQTs7SUFOQUMsMEJBQXdCO2VBQXhCQTs7SUEwQkFDLGlDQUErQjtlQUEvQkE7O0lBUEFDLGtDQUFnQztlQUFoQ0E7O0lBQ0FDLHNDQUFvQztlQUFwQ0E7O0lBVUFDLHVDQUFxQztlQUFyQ0E7O0lBQ0FDLDhDQUE0QztlQUE1Q0E7O0lBUEFDLDJDQUF5QztlQUF6Q0E7O0lBSUFDLHFDQUFtQztlQUFuQ0E7O0lBcEVBQyxrQkFBZ0I7ZUFBaEJBOztJQXJCQUMsZ0JBQWM7ZUFBZEE7O0lBeURBQyxjQUFZO2VBQVpBOztJQXNDQUMseUJBQXVCO2VBQXZCQTs7SUFlQUMseUJBQXVCO2VBQXZCQTs7SUFOQUMsb0JBQWtCO2VBQWxCQTs7SUFyREFDLDJCQUF5QjtlQUF6QkE7O0lBRUFDLHlCQUF1QjtlQUF2QkE7O0lBMkNBQyxzQkFBb0I7ZUFBcEJBOztJQWtDQUMsNEJBQTBCO2VBQTFCQTs7SUFwRkFDLGVBQWE7ZUFBYkE7O0lBREFDLGVBQWE7ZUFBYkE7O0lBSEFDLDJCQUF5QjtlQUF6QkE7O0lBeURBQyxzQkFBb0I7ZUFBcEJBOztJQWxEQUMsaUJBQWU7ZUFBZkE7O0lBMkJBQyxxQ0FBbUM7ZUFBbkNBOztJQWxFQUMsa0JBQWdCO2VBQWhCQTs7SUE2REFDLDJCQUF5QjtlQUF6QkE7O0lBbkJBQyxxQkFBbUI7ZUFBbkJBOztJQXFCQUMsb0NBQWtDO2VBQWxDQTs7SUE3RUpDLDRCQUEwQjtlQUExQkEsMEJBQUFBLE9BQTBCOztJQW1FdEJDLHVCQUFxQjtlQUFyQkE7O0lBbkJBQyxvQkFBa0I7ZUFBbEJBOztJQXdEQUMsMEJBQXdCO2VBQXhCQTs7SUEvREFDLGdCQUFjO2VBQWRBOztJQUhBQywwQkFBd0I7ZUFBeEJBOztJQUhBQyxjQUFZO2VBQVpBOztJQUtBQyxZQUFVO2VBQVZBOztJQUpBQyx3QkFBc0I7ZUFBdEJBOztJQUNBQyx5QkFBdUI7ZUFBdkJBOztJQUVBQyxZQUFVO2VBQVZBOztJQVlBQyxvQkFBa0I7ZUFBbEJBOztJQU9BQyx5QkFBdUI7ZUFBdkJBOztJQU5BQyxpQkFBZTtlQUFmQTs7SUF5RUFDLGtCQUFnQjtlQUFoQkE7O0lBakVBQyxrQkFBZ0I7ZUFBaEJBOztJQU5BQyx1QkFBcUI7ZUFBckJBOztJQWdEQUMsaUJBQWU7ZUFBZkE7O0lBN0JBQywyQkFBeUI7ZUFBekJBOztJQTRCQUMsaUJBQWU7ZUFBZkE7O0lBbUJBQyxxQkFBbUI7ZUFBbkJBOztJQXREQUMsNEJBQTBCO2VBQTFCQTs7SUFuQkFDLGdDQUE4QjtlQUE5QkE7O0lBMEdBQyxvQkFBa0I7ZUFBbEJBOztJQWhDQUMsc0JBQW9CO2VBQXBCQTs7SUFFQUMsa0NBQWdDO2VBQWhDQTs7SUExRkFDLDRCQUEwQjtlQUExQkE7O0lBQ0FDLGtDQUFnQztlQUFoQ0E7Ozs7K0ZBcEMwQjtBQU1oQyxNQUFNOUMsaUJBQWlCO0lBQzVCK0MsUUFBUTtJQUNSQyxRQUFRO0lBQ1JDLFlBQVk7QUFDZDtBQU1PLE1BQU1sQyxtQkFBbUI7SUFDOUI7SUFDQTtJQUNBO0lBQ0E7SUFDQTtJQUNBO0NBQ0Q7QUFJTSxNQUFNaEIsbUJBRVQ7SUFDRixDQUFDQyxlQUFlK0MsTUFBTSxDQUFDLEVBQUU7SUFDekIsQ0FBQy9DLGVBQWVnRCxNQUFNLENBQUMsRUFBRTtJQUN6QixDQUFDaEQsZUFBZWlELFVBQVUsQ0FBQyxFQUFFO0FBQy9CO0FBRU8sTUFBTUosNkJBQTZCO0FBQ25DLE1BQU1DLG1DQUFtQyxLQUFHRCw2QkFBMkI7QUFDdkUsTUFBTXBCLGVBQWU7QUFDckIsTUFBTUUseUJBQXlCO0FBQy9CLE1BQU1DLDBCQUEwQjtBQUNoQyxNQUFNSiwyQkFBMkI7QUFDakMsTUFBTUssYUFBYTtBQUNuQixNQUFNSCxhQUFhO0FBQ25CLE1BQU1ILGlCQUFpQjtBQUN2QixNQUFNekMscUJBQXFCO0FBQzNCLE1BQU1DLDJCQUEyQjtBQUNqQyxNQUFNSyxpQkFBaUI7QUFDdkIsTUFBTVIscUJBQXFCO0FBQzNCLE1BQU0rQiw0QkFBNEI7QUFDbEMsTUFBTThCLGlDQUFpQztBQUN2QyxNQUFNcEIscUJBQXFCO0FBQzNCLE1BQU1YLGdCQUFnQjtBQUN0QixNQUFNRCxnQkFBZ0I7QUFDdEIsTUFBTXFCLHFCQUFxQjtBQUMzQixNQUFNRSxrQkFBa0I7QUFDeEIsTUFBTW5CLGtCQUFrQjtBQUN4QixNQUFNc0Isd0JBQXdCO0FBQzlCLE1BQU05Qiw0QkFBNEI7QUFDbEMsTUFBTVksc0JBQXNCO0FBQzVCLE1BQU1YLDBCQUEwQjtBQUNoQyxNQUFNeUIsMEJBQTBCO0FBQ2hDLE1BQU0vQyx1Q0FBdUM7QUFDN0MsTUFBTWtELG1CQUFtQjtBQUN6QixNQUFNakMsZUFBZTtJQUFDO0lBQWtCO0NBQWtCO0FBQzFELE1BQU1kLGdCQUFnQjtBQUN0QixNQUFNRCxnQkFBZ0I7SUFBQztJQUFjO0lBQVM7Q0FBVTtBQUN4RCxNQUFNRywyQkFBMkI7QUFDakMsTUFBTUUsMkJBQTJCO0FBQ2pDLE1BQU1pRCw2QkFBNkI7QUFDbkMsTUFBTXBCLHdCQUF3QjtBQUM5QixNQUFNbkMsNkJBQTZCO0FBR25DLE1BQU1LLDRCQUE0QjtBQUVsQyxNQUFNK0MsNEJBQTRCO0FBRWxDLE1BQU1yQiw0QkFBNEI7QUFFbEMsTUFBTUUscUNBQ1g7QUFFSyxNQUFNSixzQ0FDWDtBQUdLLE1BQU1yQixtQ0FBb0M7QUFDMUMsTUFBTUMsdUNBQXVDLEtBQUdELG1DQUFpQztBQUVqRixNQUFNWix1QkFBdUI7QUFFN0IsTUFBTWdCLDRDQUE2QztBQUVuRCxNQUFNTCxrQ0FBbUM7QUFFekMsTUFBTU0sc0NBQXVDO0FBRTdDLE1BQU1ILHdDQUF3QztBQUM5QyxNQUFNQywrQ0FBK0NzRCxPQUMxRHZEO0FBRUssTUFBTU8sMEJBQTBCO0FBQ2hDLE1BQU1LLHVCQUF1QjtBQUM3QixNQUFNK0Isa0JBQWtCO0FBQ3hCLE1BQU1GLGtCQUFrQjtBQUN4QixNQUFNeEIsdUJBQXVCO0FBQzdCLE1BQU1VLDJCQUEyQjtJQUN0QztRQUFFNkIsS0FBS3ZDO1FBQXNCd0MsWUFBWTtJQUE0QjtJQUNyRTtRQUFFRCxLQUFLO1FBQTJCQyxZQUFZO0lBQTBCO0NBQ3pFO0FBQ00sTUFBTWhELHFCQUFxQjtJQUNoQ2lELE1BQU07SUFDTkMsZUFBZTtJQUNmQyxZQUFZO0lBQ1pDLFlBQVk7QUFDZDtBQUNPLE1BQU1yRCwwQkFBMEI7SUFDckNrRCxNQUFNO0lBQ05DLGVBQWU7SUFDZkMsWUFBWTtJQUNaQyxZQUFZO0FBQ2Q7QUFDTyxNQUFNakIsc0JBQXNCO0lBQUM7Q0FBTztBQUNwQyxNQUFNSSx1QkFBdUI7QUFFN0IsTUFBTUMsbUNBQW1DO0FBRXpDLE1BQU1YLG1CQUFtQjtJQUM5QmMsUUFBUTtJQUNSQyxRQUFRO0FBQ1Y7QUFNTyxNQUFNeEMsNkJBQTZCO0lBQ3hDO0lBQ0E7SUFDQTtJQUNBO0lBQ0E7SUFDQTtJQUNBO0lBQ0E7SUFDQTtJQUNBO0lBQ0E7SUFDQTtJQUNBO0lBQ0E7SUFDQTtJQUNBO0NBQ0Q7QUFFTSxNQUFNa0MscUJBQXFCLElBQUllLElBQVk7SUFDaERoRTtJQUNBSTtJQUNBTDtJQUNBRTtDQUNEIiwic291cmNlcyI6WyJ3ZWJwYWNrOi8vdW5pdmVyc2FsLW5mdC13ZWIvLi4vLi4vLi4vc3JjL3NoYXJlZC9saWIvY29uc3RhbnRzLnRzPzQwOWEiXSwibmFtZXMiOlsiQVBQX0JVSUxEX01BTklGRVNUIiwiQVBQX0NMSUVOVF9JTlRFUk5BTFMiLCJBUFBfUEFUSFNfTUFOSUZFU1QiLCJBUFBfUEFUSF9ST1VURVNfTUFOSUZFU1QiLCJBVVRPTUFUSUNfRk9OVF9PUFRJTUlaQVRJT05fTUFOSUZFU1QiLCJCQVJSRUxfT1BUSU1JWkFUSU9OX1BSRUZJWCIsIkJMT0NLRURfUEFHRVMiLCJCVUlMRF9JRF9GSUxFIiwiQlVJTERfTUFOSUZFU1QiLCJDTElFTlRfUFVCTElDX0ZJTEVTX1BBVEgiLCJDTElFTlRfUkVGRVJFTkNFX01BTklGRVNUIiwiQ0xJRU5UX1NUQVRJQ19GSUxFU19QQVRIIiwiQ0xJRU5UX1NUQVRJQ19GSUxFU19SVU5USU1FX0FNUCIsIkNMSUVOVF9TVEFUSUNfRklMRVNfUlVOVElNRV9NQUlOIiwiQ0xJRU5UX1NUQVRJQ19GSUxFU19SVU5USU1FX01BSU5fQVBQIiwiQ0xJRU5UX1NUQVRJQ19GSUxFU19SVU5USU1FX1BPTFlGSUxMUyIsIkNMSUVOVF9TVEFUSUNfRklMRVNfUlVOVElNRV9QT0xZRklMTFNfU1lNQk9MIiwiQ0xJRU5UX1NUQVRJQ19GSUxFU19SVU5USU1FX1JFQUNUX1JFRlJFU0giLCJDTElFTlRfU1RBVElDX0ZJTEVTX1JVTlRJTUVfV0VCUEFDSyIsIkNPTVBJTEVSX0lOREVYRVMiLCJDT01QSUxFUl9OQU1FUyIsIkNPTkZJR19GSUxFUyIsIkRFRkFVTFRfUlVOVElNRV9XRUJQQUNLIiwiREVGQVVMVF9TQU5TX1NFUklGX0ZPTlQiLCJERUZBVUxUX1NFUklGX0ZPTlQiLCJERVZfQ0xJRU5UX1BBR0VTX01BTklGRVNUIiwiREVWX01JRERMRVdBUkVfTUFOSUZFU1QiLCJFREdFX1JVTlRJTUVfV0VCUEFDSyIsIkVER0VfVU5TVVBQT1JURURfTk9ERV9BUElTIiwiRVhQT1JUX0RFVEFJTCIsIkVYUE9SVF9NQVJLRVIiLCJGVU5DVElPTlNfQ09ORklHX01BTklGRVNUIiwiR09PR0xFX0ZPTlRfUFJPVklERVIiLCJJTUFHRVNfTUFOSUZFU1QiLCJJTlRFUkNFUFRJT05fUk9VVEVfUkVXUklURV9NQU5JRkVTVCIsIklOVEVSTkFMX0hFQURFUlMiLCJNSURETEVXQVJFX0JVSUxEX01BTklGRVNUIiwiTUlERExFV0FSRV9NQU5JRkVTVCIsIk1JRERMRVdBUkVfUkVBQ1RfTE9BREFCTEVfTUFOSUZFU1QiLCJNT0RFUk5fQlJPV1NFUlNMSVNUX1RBUkdFVCIsIk5FWFRfQlVJTFRJTl9ET0NVTUVOVCIsIk5FWFRfRk9OVF9NQU5JRkVTVCIsIk9QVElNSVpFRF9GT05UX1BST1ZJREVSUyIsIlBBR0VTX01BTklGRVNUIiwiUEhBU0VfREVWRUxPUE1FTlRfU0VSVkVSIiwiUEhBU0VfRVhQT1JUIiwiUEhBU0VfSU5GTyIsIlBIQVNFX1BST0RVQ1RJT05fQlVJTEQiLCJQSEFTRV9QUk9EVUNUSU9OX1NFUlZFUiIsIlBIQVNFX1RFU1QiLCJQUkVSRU5ERVJfTUFOSUZFU1QiLCJSRUFDVF9MT0FEQUJMRV9NQU5JRkVTVCIsIlJPVVRFU19NQU5JRkVTVCIsIlJTQ19NT0RVTEVfVFlQRVMiLCJTRVJWRVJfRElSRUNUT1JZIiwiU0VSVkVSX0ZJTEVTX01BTklGRVNUIiwiU0VSVkVSX1BST1BTX0lEIiwiU0VSVkVSX1JFRkVSRU5DRV9NQU5JRkVTVCIsIlNUQVRJQ19QUk9QU19JRCIsIlNUQVRJQ19TVEFUVVNfUEFHRVMiLCJTVFJJTkdfTElURVJBTF9EUk9QX0JVTkRMRSIsIlNVQlJFU09VUkNFX0lOVEVHUklUWV9NQU5JRkVTVCIsIlNZU1RFTV9FTlRSWVBPSU5UUyIsIlRSQUNFX09VVFBVVF9WRVJTSU9OIiwiVFVSQk9fVFJBQ0VfREVGQVVMVF9NRU1PUllfTElNSVQiLCJVTkRFUlNDT1JFX05PVF9GT1VORF9ST1VURSIsIlVOREVSU0NPUkVfTk9UX0ZPVU5EX1JPVVRFX0VOVFJZIiwiY2xpZW50Iiwic2VydmVyIiwiZWRnZVNlcnZlciIsIlN5bWJvbCIsInVybCIsInByZWNvbm5lY3QiLCJuYW1lIiwieEF2Z0NoYXJXaWR0aCIsImF6QXZnV2lkdGgiLCJ1bml0c1BlckVtIiwiU2V0Il0sInNvdXJjZVJvb3QiOiIifQ==\n//# sourceURL=webpack-internal:///./node_modules/next/dist/shared/lib/constants.js\n");
-
-/***/ }),
-
-/***/ "./node_modules/next/dist/shared/lib/encode-uri-path.js":
-/*!**************************************************************!*\
-  !*** ./node_modules/next/dist/shared/lib/encode-uri-path.js ***!
-  \**************************************************************/
-/***/ ((__unused_webpack_module, exports) => {
-
-eval("\nObject.defineProperty(exports, \"__esModule\", ({\n    value: true\n}));\nObject.defineProperty(exports, \"encodeURIPath\", ({\n    enumerable: true,\n    get: function() {\n        return encodeURIPath;\n    }\n}));\nfunction encodeURIPath(file) {\n    return file.split(\"/\").map((p)=>encodeURIComponent(p)).join(\"/\");\n} //# sourceMappingURL=encode-uri-path.js.map\n//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvbmV4dC9kaXN0L3NoYXJlZC9saWIvZW5jb2RlLXVyaS1wYXRoLmpzIiwibWFwcGluZ3MiOiI7Ozs7aURBQWdCQTs7O2VBQUFBOzs7QUFBVCxTQUFTQSxjQUFjQyxJQUFZO0lBQ3hDLE9BQU9BLEtBQ0pDLEtBQUssQ0FBQyxLQUNOQyxHQUFHLENBQUMsQ0FBQ0MsSUFBTUMsbUJBQW1CRCxJQUM5QkUsSUFBSSxDQUFDO0FBQ1YiLCJzb3VyY2VzIjpbIndlYnBhY2s6Ly91bml2ZXJzYWwtbmZ0LXdlYi8uLi8uLi8uLi9zcmMvc2hhcmVkL2xpYi9lbmNvZGUtdXJpLXBhdGgudHM/NjBkYSJdLCJuYW1lcyI6WyJlbmNvZGVVUklQYXRoIiwiZmlsZSIsInNwbGl0IiwibWFwIiwicCIsImVuY29kZVVSSUNvbXBvbmVudCIsImpvaW4iXSwic291cmNlUm9vdCI6IiJ9\n//# sourceURL=webpack-internal:///./node_modules/next/dist/shared/lib/encode-uri-path.js\n");
-
-/***/ }),
-
-/***/ "./node_modules/next/dist/shared/lib/head.js":
-/*!***************************************************!*\
-  !*** ./node_modules/next/dist/shared/lib/head.js ***!
-  \***************************************************/
-/***/ ((module, exports, __webpack_require__) => {
-
-eval("/* __next_internal_client_entry_do_not_use__  cjs */ \nObject.defineProperty(exports, \"__esModule\", ({\n    value: true\n}));\n0 && (0);\nfunction _export(target, all) {\n    for(var name in all)Object.defineProperty(target, name, {\n        enumerable: true,\n        get: all[name]\n    });\n}\n_export(exports, {\n    default: function() {\n        return _default;\n    },\n    defaultHead: function() {\n        return defaultHead;\n    }\n});\nconst _interop_require_default = __webpack_require__(/*! @swc/helpers/_/_interop_require_default */ \"./node_modules/@swc/helpers/cjs/_interop_require_default.cjs\");\nconst _interop_require_wildcard = __webpack_require__(/*! @swc/helpers/_/_interop_require_wildcard */ \"./node_modules/@swc/helpers/cjs/_interop_require_wildcard.cjs\");\nconst _jsxruntime = __webpack_require__(/*! react/jsx-runtime */ \"react/jsx-runtime\");\nconst _react = /*#__PURE__*/ _interop_require_wildcard._(__webpack_require__(/*! react */ \"react\"));\nconst _sideeffect = /*#__PURE__*/ _interop_require_default._(__webpack_require__(/*! ./side-effect */ \"./node_modules/next/dist/shared/lib/side-effect.js\"));\nconst _ampcontextsharedruntime = __webpack_require__(/*! ./amp-context.shared-runtime */ \"./node_modules/next/dist/server/future/route-modules/pages/vendored/contexts/amp-context.js\");\nconst _headmanagercontextsharedruntime = __webpack_require__(/*! ./head-manager-context.shared-runtime */ \"./node_modules/next/dist/server/future/route-modules/pages/vendored/contexts/head-manager-context.js\");\nconst _ampmode = __webpack_require__(/*! ./amp-mode */ \"./node_modules/next/dist/shared/lib/amp-mode.js\");\nconst _warnonce = __webpack_require__(/*! ./utils/warn-once */ \"./node_modules/next/dist/shared/lib/utils/warn-once.js\");\nfunction defaultHead(inAmpMode) {\n    if (inAmpMode === void 0) inAmpMode = false;\n    const head = [\n        /*#__PURE__*/ (0, _jsxruntime.jsx)(\"meta\", {\n            charSet: \"utf-8\"\n        })\n    ];\n    if (!inAmpMode) {\n        head.push(/*#__PURE__*/ (0, _jsxruntime.jsx)(\"meta\", {\n            name: \"viewport\",\n            content: \"width=device-width\"\n        }));\n    }\n    return head;\n}\nfunction onlyReactElement(list, child) {\n    // React children can be \"string\" or \"number\" in this case we ignore them for backwards compat\n    if (typeof child === \"string\" || typeof child === \"number\") {\n        return list;\n    }\n    // Adds support for React.Fragment\n    if (child.type === _react.default.Fragment) {\n        return list.concat(_react.default.Children.toArray(child.props.children).reduce((fragmentList, fragmentChild)=>{\n            if (typeof fragmentChild === \"string\" || typeof fragmentChild === \"number\") {\n                return fragmentList;\n            }\n            return fragmentList.concat(fragmentChild);\n        }, []));\n    }\n    return list.concat(child);\n}\nconst METATYPES = [\n    \"name\",\n    \"httpEquiv\",\n    \"charSet\",\n    \"itemProp\"\n];\n/*\n returns a function for filtering head child elements\n which shouldn't be duplicated, like <title/>\n Also adds support for deduplicated `key` properties\n*/ function unique() {\n    const keys = new Set();\n    const tags = new Set();\n    const metaTypes = new Set();\n    const metaCategories = {};\n    return (h)=>{\n        let isUnique = true;\n        let hasKey = false;\n        if (h.key && typeof h.key !== \"number\" && h.key.indexOf(\"$\") > 0) {\n            hasKey = true;\n            const key = h.key.slice(h.key.indexOf(\"$\") + 1);\n            if (keys.has(key)) {\n                isUnique = false;\n            } else {\n                keys.add(key);\n            }\n        }\n        // eslint-disable-next-line default-case\n        switch(h.type){\n            case \"title\":\n            case \"base\":\n                if (tags.has(h.type)) {\n                    isUnique = false;\n                } else {\n                    tags.add(h.type);\n                }\n                break;\n            case \"meta\":\n                for(let i = 0, len = METATYPES.length; i < len; i++){\n                    const metatype = METATYPES[i];\n                    if (!h.props.hasOwnProperty(metatype)) continue;\n                    if (metatype === \"charSet\") {\n                        if (metaTypes.has(metatype)) {\n                            isUnique = false;\n                        } else {\n                            metaTypes.add(metatype);\n                        }\n                    } else {\n                        const category = h.props[metatype];\n                        const categories = metaCategories[metatype] || new Set();\n                        if ((metatype !== \"name\" || !hasKey) && categories.has(category)) {\n                            isUnique = false;\n                        } else {\n                            categories.add(category);\n                            metaCategories[metatype] = categories;\n                        }\n                    }\n                }\n                break;\n        }\n        return isUnique;\n    };\n}\n/**\n *\n * @param headChildrenElements List of children of <Head>\n */ function reduceComponents(headChildrenElements, props) {\n    const { inAmpMode } = props;\n    return headChildrenElements.reduce(onlyReactElement, []).reverse().concat(defaultHead(inAmpMode).reverse()).filter(unique()).reverse().map((c, i)=>{\n        const key = c.key || i;\n        if (false) {}\n        if (true) {\n            // omit JSON-LD structured data snippets from the warning\n            if (c.type === \"script\" && c.props[\"type\"] !== \"application/ld+json\") {\n                const srcMessage = c.props[\"src\"] ? '<script> tag with src=\"' + c.props[\"src\"] + '\"' : \"inline <script>\";\n                (0, _warnonce.warnOnce)(\"Do not add <script> tags using next/head (see \" + srcMessage + \"). Use next/script instead. \\nSee more info here: https://nextjs.org/docs/messages/no-script-tags-in-head-component\");\n            } else if (c.type === \"link\" && c.props[\"rel\"] === \"stylesheet\") {\n                (0, _warnonce.warnOnce)('Do not add stylesheets using next/head (see <link rel=\"stylesheet\"> tag with href=\"' + c.props[\"href\"] + '\"). Use Document instead. \\nSee more info here: https://nextjs.org/docs/messages/no-stylesheets-in-head-component');\n            }\n        }\n        return /*#__PURE__*/ _react.default.cloneElement(c, {\n            key\n        });\n    });\n}\n/**\n * This component injects elements to `<head>` of your page.\n * To avoid duplicated `tags` in `<head>` you can use the `key` property, which will make sure every tag is only rendered once.\n */ function Head(param) {\n    let { children } = param;\n    const ampState = (0, _react.useContext)(_ampcontextsharedruntime.AmpStateContext);\n    const headManager = (0, _react.useContext)(_headmanagercontextsharedruntime.HeadManagerContext);\n    return /*#__PURE__*/ (0, _jsxruntime.jsx)(_sideeffect.default, {\n        reduceComponentsToState: reduceComponents,\n        headManager: headManager,\n        inAmpMode: (0, _ampmode.isInAmpMode)(ampState),\n        children: children\n    });\n}\nconst _default = Head;\nif ((typeof exports.default === \"function\" || typeof exports.default === \"object\" && exports.default !== null) && typeof exports.default.__esModule === \"undefined\") {\n    Object.defineProperty(exports.default, \"__esModule\", {\n        value: true\n    });\n    Object.assign(exports.default, exports);\n    module.exports = exports.default;\n} //# sourceMappingURL=head.js.map\n//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvbmV4dC9kaXN0L3NoYXJlZC9saWIvaGVhZC5qcyIsIm1hcHBpbmdzIjoiOzs7Ozs7Ozs7OztRQXFNQUEsU0FBbUI7OztJQXhMSEM7aUJBQUFBOzs7Ozs7OzsyREFUZ0JDLENBQUEsQ0FBQUMsbUJBQUFBLENBQUE7O3lDQUVKQSxtQkFBQUEsQ0FBQTs7QUFPckIsTUFBQUMsWUFBU0gsbUJBQUFBLENBQVlJLGlGQUFBQTtTQUFBQSxZQUFBQSxTQUFBQTtJQUMxQixJQUFBQSxjQUFhLFFBQUFBLFlBQUE7O21CQUFPQyxHQUFRLElBQUFDLFlBQUFDLEdBQUE7O1FBQVc7S0FDdkM7UUFDRUMsQ0FBQUEsV0FBUzthQUFPQyxJQUFBQSxDQUFLLGtCQUFBSCxZQUFBQyxHQUFBO1lBQVdHLE1BQUFBOztRQUNsQztJQUNBO0lBQ0YsT0FBQUY7QUFFQTtTQUlFRyxpQkFBQUMsSUFBQSxFQUFBQyxLQUFBO0lBQ0EsOEZBQTREO1FBQzFELE9BQU9ELFVBQUFBLFlBQUFBLE9BQUFBLFVBQUFBLFVBQUFBO1FBQ1QsT0FBQUE7SUFDQTtJQUNBLGtDQUF5QkU7UUFDdkJELE1BQUFFLElBQU9ILEtBQUtJLE9BQ1ZDLE9BQUEsQ0FBQUgsUUFBQTtRQUNBSSxPQUFBQSxLQUFBQSxNQUFNQyxDQUVKQyxPQUNFQyxPQUFBQSxDQUNBQyxRQUFBQSxDQUFBQSxPQUFBQSxDQUFBQSxNQUFBQSxLQUFBQSxDQUFBQSxRQUFBQSxFQUFBQSxNQUFBQSxnQkFHU0E7Z0JBR1AsT0FBT0Qsa0JBQUFBLFlBQUFBLE9BQUFBLGtCQUFBQSxVQUFBQTtnQkFDVCxPQUFBQTtZQUNBO1lBRUYsT0FBRUEsYUFBQUwsTUFBQSxDQUFBTTtRQUdSO0lBQ0E7SUFDRixPQUFBVixLQUFBSSxNQUFBLENBQUFIO0FBRUE7TUFBbUJVLFlBQUE7SUFBUTtJQUFhO0lBQVc7SUFBVztDQUU5RDs7Ozs7WUFNUUM7SUFDTixNQUFNQyxPQUFPLElBQUlDO0lBQ2pCLE1BQU1DLE9BQUFBLElBQUFBO0lBQ04sTUFBTUMsWUFBQUEsSUFBQUE7SUFFTixNQUFBQSxpQkFBUUMsQ0FBQUE7V0FDTixDQUFJQztRQUNKLElBQUlDLFdBQVM7UUFFYixJQUFJRixTQUFTO1lBQ1hFLEVBQUFBLEdBQUFBLElBQVMsT0FBQUYsRUFBQUcsR0FBQSxpQkFBQUgsRUFBQUcsR0FBQSxDQUFBQyxPQUFBO1lBQ1RGLFNBQU1DO1lBQ04sTUFBSVIsTUFBUUssRUFBQ0csR0FBQUEsQ0FBQUEsS0FBTSxDQUFBSCxFQUFBRyxHQUFBLENBQUFDLE9BQUE7Z0JBQ2pCSCxLQUFBQSxHQUFBQSxDQUFBQSxNQUFXO2dCQUNiQSxXQUFPO21CQUNMTjtnQkFDRkEsS0FBQVUsR0FBQSxDQUFBRjtZQUNGO1FBRUE7UUFDQSx3Q0FBYztlQUNaSCxFQUFLZCxJQUFBO1lBQ0wsS0FBSztpQkFDSDtvQkFDRWUsS0FBQUEsR0FBQUEsQ0FBQUEsRUFBV2YsSUFBQTtvQkFDYmUsV0FBTzt1QkFDTEw7b0JBQ0ZBLEtBQUFTLEdBQUEsQ0FBQUwsRUFBQWQsSUFBQTtnQkFDQTtnQkFDRjtpQkFDRTtvQkFDRSxJQUFBb0IsSUFBTUMsR0FBQUEsTUFBV2IsVUFBVVksTUFBRSxFQUFBQSxJQUFBRSxLQUFBRixJQUFBO29CQUM3QixNQUFLTixXQUFRUyxTQUFBQSxDQUFjSCxFQUFDQztvQkFFNUIsSUFBSUEsQ0FBQUEsRUFBQUEsS0FBQUEsQ0FBQUEsY0FBYSxDQUFXQSxXQUFBO3dCQUMxQkEsYUFBSVQsV0FBY1M7NEJBQ2hCTixVQUFBQSxHQUFXLENBQUFNLFdBQUE7NEJBQ2JOLFdBQU87K0JBQ0xIOzRCQUNGQSxVQUFBTyxHQUFBLENBQUFFO3dCQUNGOzJCQUNFO3dCQUNBLE1BQU1HLFdBQUFBLEVBQWFYLEtBQUFBLENBQUFBLFNBQWVRO3dCQUNsQyxNQUFLQSxhQUFhUixjQUFXRyxDQUFBQSxTQUFXUSxJQUFBQSxJQUFXQzs0QkFDakRWLENBQUFBLGFBQVcsV0FBQUMsTUFBQSxLQUFBUSxXQUFBQyxHQUFBLENBQUFDLFdBQUE7NEJBQ2JYLFdBQU87K0JBQ0xTOzRCQUNBWCxXQUFBQSxHQUFjLENBQUNROzRCQUNqQlIsY0FBQSxDQUFBUSxTQUFBLEdBQUFHO3dCQUNGO29CQUNGO2dCQUNBO2dCQUNKO1FBRUE7UUFDRixPQUFBVDtJQUNGO0FBRUE7Ozs7SUFRRSxTQUFRMUIsaUJBQWNzQyxvQkFBQUEsRUFBQUEsS0FBQUE7SUFDdEIsUUFBT0MsU0FBQUEsRUFBQUEsR0FBQUE7V0FPSEEscUJBQXFCUixNQUFBQSxDQUFBQSxrQkFBQUEsRUFBQUEsRUFBQUEsT0FBQUEsR0FBQUEsTUFBQUEsQ0FBQUEsWUFBQUEsV0FBQUEsT0FBQUEsSUFBQUEsTUFBQUEsQ0FBQUEsVUFBQUEsT0FBQUEsR0FBQUEsR0FBQUEsQ0FBQUEsQ0FBQUEsR0FBQUE7UUFDckIsTUFDRVMsTUFBUUMsRUFBQUEsR0FBSUMsSUFBQUE7WUFJWkYsS0FHRXhDLEVBQUEsRUFlSjtZQUNFd0MsSUFBQTtZQUNBLHlEQUErQztnQkFDN0NHLEVBQUFoQyxJQUFNd0MsS0FBQUEsWUFBZWIsRUFBQUEsS0FBTSxRQUN0Qiw0QkFBMkJBO2dCQUVoQ2MsTUFBQUEsYUFBQUEsRUFBQUEsS0FDRyxzQ0FBQVQsRUFBQUwsS0FBQSxPQUFnRGEsR0FBQUEsTUFBQUE7Z0JBRXJELElBQU9wRCxVQUFVcUQsUUFBSyxxREFBMkNELGFBQUE7bUJBQy9EQyxJQUFBQSxFQUFBQSxJQUFBQSxLQUFBQSxVQUNHVCxFQUFBTCxLQUFBO2dCQUVMLElBQUF2QyxVQUFBcUQsUUFBQSwwRkFBQVQsRUFBQUwsS0FBQTtZQUNGO1FBQ0E7ZUFBK0JWLFdBQUFBLEdBQUFBLE9BQUFBLE9BQUFBLENBQUFBLFlBQUFBLENBQUFBLEdBQUFBO1lBQUlBO1FBQ3JDO0lBQ0o7QUFFQTs7OztJQUljLFNBQUV5QixLQUFRQyxLQUFWO0lBQ1osTUFBTUMsUUFBQUEsRUFBQUEsR0FBV0M7SUFDakIsTUFBTUMsV0FBQUEsQ0FBQUEsR0FBY0QsT0FBQUEsVUFBQUEsRUFBQUEseUJBQVdFLGVBQUFBO0lBQy9CLE1BQUFELGNBQUEsQ0FDRSxHQUFBekMsT0FBQXdDLFVBQUEsRUFBQ0csaUNBQU1ELGtCQUFBO1dBQ0xFLFdBQUFBLEdBQUFBLENBQUFBLEdBQUFBLFlBQXlCQyxHQUFBQSxFQUFBQSxZQUFBQSxPQUFBQSxFQUFBQTtRQUN6QkoseUJBQWFBO1FBQ2J6RCxhQUFXOEQ7bUJBRVZULENBQUFBLEdBQUFBLFNBQUFBLFdBQUFBLEVBQUFBOztJQUdQIiwic291cmNlcyI6WyJ3ZWJwYWNrOi8vdW5pdmVyc2FsLW5mdC13ZWIvLi4vLi4vLi4vc3JjL3NoYXJlZC9saWIvaGVhZC50c3g/MzY3MCJdLCJuYW1lcyI6WyJleHBvcnRzIiwiZGVmYXVsdEhlYWQiLCJfIiwicmVxdWlyZSIsIl93YXJub25jZSIsImluQW1wTW9kZSIsImNoYXJTZXQiLCJfanN4cnVudGltZSIsImpzeCIsImhlYWQiLCJuYW1lIiwiY29udGVudCIsIm9ubHlSZWFjdEVsZW1lbnQiLCJsaXN0IiwiY2hpbGQiLCJGcmFnbWVudCIsInR5cGUiLCJjb25jYXQiLCJkZWZhdWx0IiwiUmVhY3QiLCJDaGlsZHJlbiIsIl9yZWFjdCIsImZyYWdtZW50TGlzdCIsImZyYWdtZW50Q2hpbGQiLCJNRVRBVFlQRVMiLCJrZXlzIiwidGFncyIsIlNldCIsIm1ldGFUeXBlcyIsIm1ldGFDYXRlZ29yaWVzIiwiaCIsImlzVW5pcXVlIiwiaGFzS2V5Iiwia2V5IiwiaW5kZXhPZiIsImFkZCIsImkiLCJtZXRhdHlwZSIsImxlbiIsImhhc093blByb3BlcnR5IiwiY2F0ZWdvcmllcyIsImhhcyIsImNhdGVnb3J5IiwicHJvcHMiLCJoZWFkQ2hpbGRyZW5FbGVtZW50cyIsInByb2Nlc3MiLCJlbnYiLCJOT0RFX0VOViIsImMiLCJfX05FWFRfT1BUSU1JWkVfRk9OVFMiLCJzb21lIiwidXJsIiwibmV3UHJvcHMiLCJzdGFydHNXaXRoIiwidW5kZWZpbmVkIiwiY2xvbmVFbGVtZW50Iiwic3JjTWVzc2FnZSIsIndhcm5PbmNlIiwiY2hpbGRyZW4iLCJwYXJhbSIsImFtcFN0YXRlIiwidXNlQ29udGV4dCIsImhlYWRNYW5hZ2VyIiwiSGVhZE1hbmFnZXJDb250ZXh0IiwiRWZmZWN0IiwicmVkdWNlQ29tcG9uZW50c1RvU3RhdGUiLCJyZWR1Y2VDb21wb25lbnRzIiwiaXNJbkFtcE1vZGUiXSwic291cmNlUm9vdCI6IiJ9\n//# sourceURL=webpack-internal:///./node_modules/next/dist/shared/lib/head.js\n");
-
-/***/ }),
-
-/***/ "./node_modules/next/dist/shared/lib/is-plain-object.js":
-/*!**************************************************************!*\
-  !*** ./node_modules/next/dist/shared/lib/is-plain-object.js ***!
-  \**************************************************************/
-/***/ ((__unused_webpack_module, exports) => {
-
-eval("\nObject.defineProperty(exports, \"__esModule\", ({\n    value: true\n}));\n0 && (0);\nfunction _export(target, all) {\n    for(var name in all)Object.defineProperty(target, name, {\n        enumerable: true,\n        get: all[name]\n    });\n}\n_export(exports, {\n    getObjectClassLabel: function() {\n        return getObjectClassLabel;\n    },\n    isPlainObject: function() {\n        return isPlainObject;\n    }\n});\nfunction getObjectClassLabel(value) {\n    return Object.prototype.toString.call(value);\n}\nfunction isPlainObject(value) {\n    if (getObjectClassLabel(value) !== \"[object Object]\") {\n        return false;\n    }\n    const prototype = Object.getPrototypeOf(value);\n    /**\n   * this used to be previously:\n   *\n   * `return prototype === null || prototype === Object.prototype`\n   *\n   * but Edge Runtime expose Object from vm, being that kind of type-checking wrongly fail.\n   *\n   * It was changed to the current implementation since it's resilient to serialization.\n   */ return prototype === null || prototype.hasOwnProperty(\"isPrototypeOf\");\n} //# sourceMappingURL=is-plain-object.js.map\n//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvbmV4dC9kaXN0L3NoYXJlZC9saWIvaXMtcGxhaW4tb2JqZWN0LmpzIiwibWFwcGluZ3MiOiI7Ozs7Ozs7Ozs7OztJQUFnQkEscUJBQW1CO2VBQW5CQTs7SUFJQUMsZUFBYTtlQUFiQTs7O0FBSlQsU0FBU0Qsb0JBQW9CRSxLQUFVO0lBQzVDLE9BQU9DLE9BQU9DLFNBQVMsQ0FBQ0MsUUFBUSxDQUFDQyxJQUFJLENBQUNKO0FBQ3hDO0FBRU8sU0FBU0QsY0FBY0MsS0FBVTtJQUN0QyxJQUFJRixvQkFBb0JFLFdBQVcsbUJBQW1CO1FBQ3BELE9BQU87SUFDVDtJQUVBLE1BQU1FLFlBQVlELE9BQU9JLGNBQWMsQ0FBQ0w7SUFFeEM7Ozs7Ozs7O0dBUUMsR0FDRCxPQUFPRSxjQUFjLFFBQVFBLFVBQVVJLGNBQWMsQ0FBQztBQUN4RCIsInNvdXJjZXMiOlsid2VicGFjazovL3VuaXZlcnNhbC1uZnQtd2ViLy4uLy4uLy4uL3NyYy9zaGFyZWQvbGliL2lzLXBsYWluLW9iamVjdC50cz8yZmIyIl0sIm5hbWVzIjpbImdldE9iamVjdENsYXNzTGFiZWwiLCJpc1BsYWluT2JqZWN0IiwidmFsdWUiLCJPYmplY3QiLCJwcm90b3R5cGUiLCJ0b1N0cmluZyIsImNhbGwiLCJnZXRQcm90b3R5cGVPZiIsImhhc093blByb3BlcnR5Il0sInNvdXJjZVJvb3QiOiIifQ==\n//# sourceURL=webpack-internal:///./node_modules/next/dist/shared/lib/is-plain-object.js\n");
-
-/***/ }),
-
-/***/ "./node_modules/next/dist/shared/lib/modern-browserslist-target.js":
-/*!*************************************************************************!*\
-  !*** ./node_modules/next/dist/shared/lib/modern-browserslist-target.js ***!
-  \*************************************************************************/
-/***/ ((module) => {
-
-eval("// Note: This file is JS because it's used by the taskfile-swc.js file, which is JS.\n// Keep file changes in sync with the corresponding `.d.ts` files.\n/**\n * These are the browser versions that support all of the following:\n * static import: https://caniuse.com/es6-module\n * dynamic import: https://caniuse.com/es6-module-dynamic-import\n * import.meta: https://caniuse.com/mdn-javascript_operators_import_meta\n */ \nconst MODERN_BROWSERSLIST_TARGET = [\n    \"chrome 64\",\n    \"edge 79\",\n    \"firefox 67\",\n    \"opera 51\",\n    \"safari 12\"\n];\nmodule.exports = MODERN_BROWSERSLIST_TARGET; //# sourceMappingURL=modern-browserslist-target.js.map\n//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvbmV4dC9kaXN0L3NoYXJlZC9saWIvbW9kZXJuLWJyb3dzZXJzbGlzdC10YXJnZXQuanMiLCJtYXBwaW5ncyI6IkFBQUEsb0ZBQW9GO0FBQ3BGLGtFQUFrRTtBQUNsRTs7Ozs7Q0FLQztBQUNELE1BQU1BLDZCQUE2QjtJQUNqQztJQUNBO0lBQ0E7SUFDQTtJQUNBO0NBQ0Q7QUFFREMsT0FBT0MsT0FBTyxHQUFHRiIsInNvdXJjZXMiOlsid2VicGFjazovL3VuaXZlcnNhbC1uZnQtd2ViLy4uLy4uLy4uL3NyYy9zaGFyZWQvbGliL21vZGVybi1icm93c2Vyc2xpc3QtdGFyZ2V0LmpzP2Q1MWUiXSwibmFtZXMiOlsiTU9ERVJOX0JST1dTRVJTTElTVF9UQVJHRVQiLCJtb2R1bGUiLCJleHBvcnRzIl0sInNvdXJjZVJvb3QiOiIifQ==\n//# sourceURL=webpack-internal:///./node_modules/next/dist/shared/lib/modern-browserslist-target.js\n");
-
-/***/ }),
-
-/***/ "./node_modules/next/dist/shared/lib/page-path/denormalize-page-path.js":
-/*!******************************************************************************!*\
-  !*** ./node_modules/next/dist/shared/lib/page-path/denormalize-page-path.js ***!
-  \******************************************************************************/
-/***/ ((__unused_webpack_module, exports, __webpack_require__) => {
-
-eval("\nObject.defineProperty(exports, \"__esModule\", ({\n    value: true\n}));\nObject.defineProperty(exports, \"denormalizePagePath\", ({\n    enumerable: true,\n    get: function() {\n        return denormalizePagePath;\n    }\n}));\nconst _utils = __webpack_require__(/*! ../router/utils */ \"./node_modules/next/dist/shared/lib/router/utils/index.js\");\nconst _normalizepathsep = __webpack_require__(/*! ./normalize-path-sep */ \"./node_modules/next/dist/shared/lib/page-path/normalize-path-sep.js\");\nfunction denormalizePagePath(page) {\n    let _page = (0, _normalizepathsep.normalizePathSep)(page);\n    return _page.startsWith(\"/index/\") && !(0, _utils.isDynamicRoute)(_page) ? _page.slice(6) : _page !== \"/index\" ? _page : \"/\";\n} //# sourceMappingURL=denormalize-page-path.js.map\n//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvbmV4dC9kaXN0L3NoYXJlZC9saWIvcGFnZS1wYXRoL2Rlbm9ybWFsaXplLXBhZ2UtcGF0aC5qcyIsIm1hcHBpbmdzIjoiOzs7O3VEQVdnQkE7OztlQUFBQTs7O21DQVhlOzhDQUNFO0FBVTFCLFNBQVNBLG9CQUFvQkMsSUFBWTtJQUM5QyxJQUFJQyxRQUFRQyxDQUFBQSxHQUFBQSxrQkFBQUEsZ0JBQWdCLEVBQUNGO0lBQzdCLE9BQU9DLE1BQU1FLFVBQVUsQ0FBQyxjQUFjLENBQUNDLENBQUFBLEdBQUFBLE9BQUFBLGNBQWMsRUFBQ0gsU0FDbERBLE1BQU1JLEtBQUssQ0FBQyxLQUNaSixVQUFVLFdBQ1ZBLFFBQ0E7QUFDTiIsInNvdXJjZXMiOlsid2VicGFjazovL3VuaXZlcnNhbC1uZnQtd2ViLy4uLy4uLy4uLy4uL3NyYy9zaGFyZWQvbGliL3BhZ2UtcGF0aC9kZW5vcm1hbGl6ZS1wYWdlLXBhdGgudHM/ODQ0MCJdLCJuYW1lcyI6WyJkZW5vcm1hbGl6ZVBhZ2VQYXRoIiwicGFnZSIsIl9wYWdlIiwibm9ybWFsaXplUGF0aFNlcCIsInN0YXJ0c1dpdGgiLCJpc0R5bmFtaWNSb3V0ZSIsInNsaWNlIl0sInNvdXJjZVJvb3QiOiIifQ==\n//# sourceURL=webpack-internal:///./node_modules/next/dist/shared/lib/page-path/denormalize-page-path.js\n");
-
-/***/ }),
-
-/***/ "./node_modules/next/dist/shared/lib/page-path/ensure-leading-slash.js":
-/*!*****************************************************************************!*\
-  !*** ./node_modules/next/dist/shared/lib/page-path/ensure-leading-slash.js ***!
-  \*****************************************************************************/
-/***/ ((__unused_webpack_module, exports) => {
-
-eval("/**\n * For a given page path, this function ensures that there is a leading slash.\n * If there is not a leading slash, one is added, otherwise it is noop.\n */ \nObject.defineProperty(exports, \"__esModule\", ({\n    value: true\n}));\nObject.defineProperty(exports, \"ensureLeadingSlash\", ({\n    enumerable: true,\n    get: function() {\n        return ensureLeadingSlash;\n    }\n}));\nfunction ensureLeadingSlash(path) {\n    return path.startsWith(\"/\") ? path : \"/\" + path;\n} //# sourceMappingURL=ensure-leading-slash.js.map\n//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvbmV4dC9kaXN0L3NoYXJlZC9saWIvcGFnZS1wYXRoL2Vuc3VyZS1sZWFkaW5nLXNsYXNoLmpzIiwibWFwcGluZ3MiOiJBQUFBOzs7Q0FHQzs7OztzREFDZUE7OztlQUFBQTs7O0FBQVQsU0FBU0EsbUJBQW1CQyxJQUFZO0lBQzdDLE9BQU9BLEtBQUtDLFVBQVUsQ0FBQyxPQUFPRCxPQUFPLE1BQUlBO0FBQzNDIiwic291cmNlcyI6WyJ3ZWJwYWNrOi8vdW5pdmVyc2FsLW5mdC13ZWIvLi4vLi4vLi4vLi4vc3JjL3NoYXJlZC9saWIvcGFnZS1wYXRoL2Vuc3VyZS1sZWFkaW5nLXNsYXNoLnRzPzE4ZjIiXSwibmFtZXMiOlsiZW5zdXJlTGVhZGluZ1NsYXNoIiwicGF0aCIsInN0YXJ0c1dpdGgiXSwic291cmNlUm9vdCI6IiJ9\n//# sourceURL=webpack-internal:///./node_modules/next/dist/shared/lib/page-path/ensure-leading-slash.js\n");
-
-/***/ }),
-
-/***/ "./node_modules/next/dist/shared/lib/page-path/normalize-page-path.js":
-/*!****************************************************************************!*\
-  !*** ./node_modules/next/dist/shared/lib/page-path/normalize-page-path.js ***!
-  \****************************************************************************/
-/***/ ((__unused_webpack_module, exports, __webpack_require__) => {
-
-eval("\nObject.defineProperty(exports, \"__esModule\", ({\n    value: true\n}));\nObject.defineProperty(exports, \"normalizePagePath\", ({\n    enumerable: true,\n    get: function() {\n        return normalizePagePath;\n    }\n}));\nconst _ensureleadingslash = __webpack_require__(/*! ./ensure-leading-slash */ \"./node_modules/next/dist/shared/lib/page-path/ensure-leading-slash.js\");\nconst _utils = __webpack_require__(/*! ../router/utils */ \"./node_modules/next/dist/shared/lib/router/utils/index.js\");\nconst _utils1 = __webpack_require__(/*! ../utils */ \"./node_modules/next/dist/shared/lib/utils.js\");\nfunction normalizePagePath(page) {\n    const normalized = /^\\/index(\\/|$)/.test(page) && !(0, _utils.isDynamicRoute)(page) ? \"/index\" + page : page === \"/\" ? \"/index\" : (0, _ensureleadingslash.ensureLeadingSlash)(page);\n    if (true) {\n        const { posix } = __webpack_require__(/*! path */ \"path\");\n        const resolvedPage = posix.normalize(normalized);\n        if (resolvedPage !== normalized) {\n            throw new _utils1.NormalizeError(\"Requested and resolved page mismatch: \" + normalized + \" \" + resolvedPage);\n        }\n    }\n    return normalized;\n} //# sourceMappingURL=normalize-page-path.js.map\n//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvbmV4dC9kaXN0L3NoYXJlZC9saWIvcGFnZS1wYXRoL25vcm1hbGl6ZS1wYWdlLXBhdGguanMiLCJtYXBwaW5ncyI6Ijs7OztxREFhZ0JBOzs7ZUFBQUE7OztnREFibUI7bUNBQ0o7b0NBQ0E7QUFXeEIsU0FBU0Esa0JBQWtCQyxJQUFZO0lBQzVDLE1BQU1DLGFBQ0osaUJBQWlCQyxJQUFJLENBQUNGLFNBQVMsQ0FBQ0csQ0FBQUEsR0FBQUEsT0FBQUEsY0FBYyxFQUFDSCxRQUMzQyxXQUFTQSxPQUNUQSxTQUFTLE1BQ1QsV0FDQUksQ0FBQUEsR0FBQUEsb0JBQUFBLGtCQUFrQixFQUFDSjtJQUV6QixJQUFJSyxJQUE2QixFQUFRO1FBQ3ZDLE1BQU0sRUFBRUcsS0FBSyxFQUFFLEdBQUdDLG1CQUFBQSxDQUFRO1FBQzFCLE1BQU1DLGVBQWVGLE1BQU1HLFNBQVMsQ0FBQ1Y7UUFDckMsSUFBSVMsaUJBQWlCVCxZQUFZO1lBQy9CLE1BQU0sSUFBSVcsUUFBQUEsY0FBYyxDQUN0QiwyQ0FBeUNYLGFBQVcsTUFBR1M7UUFFM0Q7SUFDRjtJQUVBLE9BQU9UO0FBQ1QiLCJzb3VyY2VzIjpbIndlYnBhY2s6Ly91bml2ZXJzYWwtbmZ0LXdlYi8uLi8uLi8uLi8uLi9zcmMvc2hhcmVkL2xpYi9wYWdlLXBhdGgvbm9ybWFsaXplLXBhZ2UtcGF0aC50cz84ZTU0Il0sIm5hbWVzIjpbIm5vcm1hbGl6ZVBhZ2VQYXRoIiwicGFnZSIsIm5vcm1hbGl6ZWQiLCJ0ZXN0IiwiaXNEeW5hbWljUm91dGUiLCJlbnN1cmVMZWFkaW5nU2xhc2giLCJwcm9jZXNzIiwiZW52IiwiTkVYVF9SVU5USU1FIiwicG9zaXgiLCJyZXF1aXJlIiwicmVzb2x2ZWRQYWdlIiwibm9ybWFsaXplIiwiTm9ybWFsaXplRXJyb3IiXSwic291cmNlUm9vdCI6IiJ9\n//# sourceURL=webpack-internal:///./node_modules/next/dist/shared/lib/page-path/normalize-page-path.js\n");
-
-/***/ }),
-
-/***/ "./node_modules/next/dist/shared/lib/page-path/normalize-path-sep.js":
-/*!***************************************************************************!*\
-  !*** ./node_modules/next/dist/shared/lib/page-path/normalize-path-sep.js ***!
-  \***************************************************************************/
-/***/ ((__unused_webpack_module, exports) => {
-
-eval("/**\n * For a given page path, this function ensures that there is no backslash\n * escaping slashes in the path. Example:\n *  - `foo\\/bar\\/baz` -> `foo/bar/baz`\n */ \nObject.defineProperty(exports, \"__esModule\", ({\n    value: true\n}));\nObject.defineProperty(exports, \"normalizePathSep\", ({\n    enumerable: true,\n    get: function() {\n        return normalizePathSep;\n    }\n}));\nfunction normalizePathSep(path) {\n    return path.replace(/\\\\/g, \"/\");\n} //# sourceMappingURL=normalize-path-sep.js.map\n//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvbmV4dC9kaXN0L3NoYXJlZC9saWIvcGFnZS1wYXRoL25vcm1hbGl6ZS1wYXRoLXNlcC5qcyIsIm1hcHBpbmdzIjoiQUFBQTs7OztDQUlDOzs7O29EQUNlQTs7O2VBQUFBOzs7QUFBVCxTQUFTQSxpQkFBaUJDLElBQVk7SUFDM0MsT0FBT0EsS0FBS0MsT0FBTyxDQUFDLE9BQU87QUFDN0IiLCJzb3VyY2VzIjpbIndlYnBhY2s6Ly91bml2ZXJzYWwtbmZ0LXdlYi8uLi8uLi8uLi8uLi9zcmMvc2hhcmVkL2xpYi9wYWdlLXBhdGgvbm9ybWFsaXplLXBhdGgtc2VwLnRzPzVjZjAiXSwibmFtZXMiOlsibm9ybWFsaXplUGF0aFNlcCIsInBhdGgiLCJyZXBsYWNlIl0sInNvdXJjZVJvb3QiOiIifQ==\n//# sourceURL=webpack-internal:///./node_modules/next/dist/shared/lib/page-path/normalize-path-sep.js\n");
-
-/***/ }),
-
-/***/ "./node_modules/next/dist/shared/lib/router/utils/app-paths.js":
-/*!*********************************************************************!*\
-  !*** ./node_modules/next/dist/shared/lib/router/utils/app-paths.js ***!
-  \*********************************************************************/
-/***/ ((__unused_webpack_module, exports, __webpack_require__) => {
-
-eval("\nObject.defineProperty(exports, \"__esModule\", ({\n    value: true\n}));\n0 && (0);\nfunction _export(target, all) {\n    for(var name in all)Object.defineProperty(target, name, {\n        enumerable: true,\n        get: all[name]\n    });\n}\n_export(exports, {\n    normalizeAppPath: function() {\n        return normalizeAppPath;\n    },\n    normalizeRscURL: function() {\n        return normalizeRscURL;\n    }\n});\nconst _ensureleadingslash = __webpack_require__(/*! ../../page-path/ensure-leading-slash */ \"./node_modules/next/dist/shared/lib/page-path/ensure-leading-slash.js\");\nconst _segment = __webpack_require__(/*! ../../segment */ \"./node_modules/next/dist/shared/lib/segment.js\");\nfunction normalizeAppPath(route) {\n    return (0, _ensureleadingslash.ensureLeadingSlash)(route.split(\"/\").reduce((pathname, segment, index, segments)=>{\n        // Empty segments are ignored.\n        if (!segment) {\n            return pathname;\n        }\n        // Groups are ignored.\n        if ((0, _segment.isGroupSegment)(segment)) {\n            return pathname;\n        }\n        // Parallel segments are ignored.\n        if (segment[0] === \"@\") {\n            return pathname;\n        }\n        // The last segment (if it's a leaf) should be ignored.\n        if ((segment === \"page\" || segment === \"route\") && index === segments.length - 1) {\n            return pathname;\n        }\n        return pathname + \"/\" + segment;\n    }, \"\"));\n}\nfunction normalizeRscURL(url) {\n    return url.replace(/\\.rsc($|\\?)/, \"$1\");\n} //# sourceMappingURL=app-paths.js.map\n//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvbmV4dC9kaXN0L3NoYXJlZC9saWIvcm91dGVyL3V0aWxzL2FwcC1wYXRocy5qcyIsIm1hcHBpbmdzIjoiOzs7Ozs7Ozs7Ozs7SUFzQmdCQSxrQkFBZ0I7ZUFBaEJBOztJQW1DQUMsaUJBQWU7ZUFBZkE7OztnREF6RG1CO3FDQUNKO0FBcUJ4QixTQUFTRCxpQkFBaUJFLEtBQWE7SUFDNUMsT0FBT0MsQ0FBQUEsR0FBQUEsb0JBQUFBLGtCQUFrQixFQUN2QkQsTUFBTUUsS0FBSyxDQUFDLEtBQUtDLE1BQU0sQ0FBQyxDQUFDQyxVQUFVQyxTQUFTQyxPQUFPQztRQUNqRCw4QkFBOEI7UUFDOUIsSUFBSSxDQUFDRixTQUFTO1lBQ1osT0FBT0Q7UUFDVDtRQUVBLHNCQUFzQjtRQUN0QixJQUFJSSxDQUFBQSxHQUFBQSxTQUFBQSxjQUFjLEVBQUNILFVBQVU7WUFDM0IsT0FBT0Q7UUFDVDtRQUVBLGlDQUFpQztRQUNqQyxJQUFJQyxPQUFPLENBQUMsRUFBRSxLQUFLLEtBQUs7WUFDdEIsT0FBT0Q7UUFDVDtRQUVBLHVEQUF1RDtRQUN2RCxJQUNFLENBQUNDLFlBQVksVUFBVUEsWUFBWSxZQUNuQ0MsVUFBVUMsU0FBU0UsTUFBTSxHQUFHLEdBQzVCO1lBQ0EsT0FBT0w7UUFDVDtRQUVBLE9BQU9BLFdBQVksTUFBR0M7SUFDeEIsR0FBRztBQUVQO0FBTU8sU0FBU04sZ0JBQWdCVyxHQUFXO0lBQ3pDLE9BQU9BLElBQUlDLE9BQU8sQ0FDaEIsZUFFQTtBQUVKIiwic291cmNlcyI6WyJ3ZWJwYWNrOi8vdW5pdmVyc2FsLW5mdC13ZWIvLi4vLi4vLi4vLi4vLi4vc3JjL3NoYXJlZC9saWIvcm91dGVyL3V0aWxzL2FwcC1wYXRocy50cz9kNDZkIl0sIm5hbWVzIjpbIm5vcm1hbGl6ZUFwcFBhdGgiLCJub3JtYWxpemVSc2NVUkwiLCJyb3V0ZSIsImVuc3VyZUxlYWRpbmdTbGFzaCIsInNwbGl0IiwicmVkdWNlIiwicGF0aG5hbWUiLCJzZWdtZW50IiwiaW5kZXgiLCJzZWdtZW50cyIsImlzR3JvdXBTZWdtZW50IiwibGVuZ3RoIiwidXJsIiwicmVwbGFjZSJdLCJzb3VyY2VSb290IjoiIn0=\n//# sourceURL=webpack-internal:///./node_modules/next/dist/shared/lib/router/utils/app-paths.js\n");
-
-/***/ }),
-
-/***/ "./node_modules/next/dist/shared/lib/router/utils/index.js":
-/*!*****************************************************************!*\
-  !*** ./node_modules/next/dist/shared/lib/router/utils/index.js ***!
-  \*****************************************************************/
-/***/ ((__unused_webpack_module, exports, __webpack_require__) => {
-
-eval("\nObject.defineProperty(exports, \"__esModule\", ({\n    value: true\n}));\n0 && (0);\nfunction _export(target, all) {\n    for(var name in all)Object.defineProperty(target, name, {\n        enumerable: true,\n        get: all[name]\n    });\n}\n_export(exports, {\n    getSortedRoutes: function() {\n        return _sortedroutes.getSortedRoutes;\n    },\n    isDynamicRoute: function() {\n        return _isdynamic.isDynamicRoute;\n    }\n});\nconst _sortedroutes = __webpack_require__(/*! ./sorted-routes */ \"./node_modules/next/dist/shared/lib/router/utils/sorted-routes.js\");\nconst _isdynamic = __webpack_require__(/*! ./is-dynamic */ \"./node_modules/next/dist/shared/lib/router/utils/is-dynamic.js\"); //# sourceMappingURL=index.js.map\n//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvbmV4dC9kaXN0L3NoYXJlZC9saWIvcm91dGVyL3V0aWxzL2luZGV4LmpzIiwibWFwcGluZ3MiOiI7Ozs7Ozs7Ozs7OztJQUFTQSxpQkFBZTtlQUFmQSxjQUFBQSxlQUFlOztJQUNmQyxnQkFBYztlQUFkQSxXQUFBQSxjQUFjOzs7MENBRFM7dUNBQ0QiLCJzb3VyY2VzIjpbIndlYnBhY2s6Ly91bml2ZXJzYWwtbmZ0LXdlYi8uLi8uLi8uLi8uLi8uLi9zcmMvc2hhcmVkL2xpYi9yb3V0ZXIvdXRpbHMvaW5kZXgudHM/NzJkNSJdLCJuYW1lcyI6WyJnZXRTb3J0ZWRSb3V0ZXMiLCJpc0R5bmFtaWNSb3V0ZSJdLCJzb3VyY2VSb290IjoiIn0=\n//# sourceURL=webpack-internal:///./node_modules/next/dist/shared/lib/router/utils/index.js\n");
-
-/***/ }),
-
-/***/ "./node_modules/next/dist/shared/lib/router/utils/is-dynamic.js":
-/*!**********************************************************************!*\
-  !*** ./node_modules/next/dist/shared/lib/router/utils/is-dynamic.js ***!
-  \**********************************************************************/
-/***/ ((__unused_webpack_module, exports, __webpack_require__) => {
-
-eval("\nObject.defineProperty(exports, \"__esModule\", ({\n    value: true\n}));\nObject.defineProperty(exports, \"isDynamicRoute\", ({\n    enumerable: true,\n    get: function() {\n        return isDynamicRoute;\n    }\n}));\nconst _interceptionroutes = __webpack_require__(/*! ../../../../server/future/helpers/interception-routes */ \"./node_modules/next/dist/server/future/helpers/interception-routes.js\");\n// Identify /[param]/ in route string\nconst TEST_ROUTE = /\\/\\[[^/]+?\\](?=\\/|$)/;\nfunction isDynamicRoute(route) {\n    if ((0, _interceptionroutes.isInterceptionRouteAppPath)(route)) {\n        route = (0, _interceptionroutes.extractInterceptionRouteInformation)(route).interceptedRoute;\n    }\n    return TEST_ROUTE.test(route);\n} //# sourceMappingURL=is-dynamic.js.map\n//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvbmV4dC9kaXN0L3NoYXJlZC9saWIvcm91dGVyL3V0aWxzL2lzLWR5bmFtaWMuanMiLCJtYXBwaW5ncyI6Ijs7OztrREFRZ0JBOzs7ZUFBQUE7OztnREFMVDtBQUVQLHFDQUFxQztBQUNyQyxNQUFNQyxhQUFhO0FBRVosU0FBU0QsZUFBZUUsS0FBYTtJQUMxQyxJQUFJQyxDQUFBQSxHQUFBQSxvQkFBQUEsMEJBQTBCLEVBQUNELFFBQVE7UUFDckNBLFFBQVFFLENBQUFBLEdBQUFBLG9CQUFBQSxtQ0FBbUMsRUFBQ0YsT0FBT0csZ0JBQWdCO0lBQ3JFO0lBRUEsT0FBT0osV0FBV0ssSUFBSSxDQUFDSjtBQUN6QiIsInNvdXJjZXMiOlsid2VicGFjazovL3VuaXZlcnNhbC1uZnQtd2ViLy4uLy4uLy4uLy4uLy4uL3NyYy9zaGFyZWQvbGliL3JvdXRlci91dGlscy9pcy1keW5hbWljLnRzPzgyZGMiXSwibmFtZXMiOlsiaXNEeW5hbWljUm91dGUiLCJURVNUX1JPVVRFIiwicm91dGUiLCJpc0ludGVyY2VwdGlvblJvdXRlQXBwUGF0aCIsImV4dHJhY3RJbnRlcmNlcHRpb25Sb3V0ZUluZm9ybWF0aW9uIiwiaW50ZXJjZXB0ZWRSb3V0ZSIsInRlc3QiXSwic291cmNlUm9vdCI6IiJ9\n//# sourceURL=webpack-internal:///./node_modules/next/dist/shared/lib/router/utils/is-dynamic.js\n");
-
-/***/ }),
-
-/***/ "./node_modules/next/dist/shared/lib/router/utils/sorted-routes.js":
-/*!*************************************************************************!*\
-  !*** ./node_modules/next/dist/shared/lib/router/utils/sorted-routes.js ***!
-  \*************************************************************************/
-/***/ ((__unused_webpack_module, exports) => {
-
-eval("\nObject.defineProperty(exports, \"__esModule\", ({\n    value: true\n}));\nObject.defineProperty(exports, \"getSortedRoutes\", ({\n    enumerable: true,\n    get: function() {\n        return getSortedRoutes;\n    }\n}));\nclass UrlNode {\n    insert(urlPath) {\n        this._insert(urlPath.split(\"/\").filter(Boolean), [], false);\n    }\n    smoosh() {\n        return this._smoosh();\n    }\n    _smoosh(prefix) {\n        if (prefix === void 0) prefix = \"/\";\n        const childrenPaths = [\n            ...this.children.keys()\n        ].sort();\n        if (this.slugName !== null) {\n            childrenPaths.splice(childrenPaths.indexOf(\"[]\"), 1);\n        }\n        if (this.restSlugName !== null) {\n            childrenPaths.splice(childrenPaths.indexOf(\"[...]\"), 1);\n        }\n        if (this.optionalRestSlugName !== null) {\n            childrenPaths.splice(childrenPaths.indexOf(\"[[...]]\"), 1);\n        }\n        const routes = childrenPaths.map((c)=>this.children.get(c)._smoosh(\"\" + prefix + c + \"/\")).reduce((prev, curr)=>[\n                ...prev,\n                ...curr\n            ], []);\n        if (this.slugName !== null) {\n            routes.push(...this.children.get(\"[]\")._smoosh(prefix + \"[\" + this.slugName + \"]/\"));\n        }\n        if (!this.placeholder) {\n            const r = prefix === \"/\" ? \"/\" : prefix.slice(0, -1);\n            if (this.optionalRestSlugName != null) {\n                throw new Error('You cannot define a route with the same specificity as a optional catch-all route (\"' + r + '\" and \"' + r + \"[[...\" + this.optionalRestSlugName + ']]\").');\n            }\n            routes.unshift(r);\n        }\n        if (this.restSlugName !== null) {\n            routes.push(...this.children.get(\"[...]\")._smoosh(prefix + \"[...\" + this.restSlugName + \"]/\"));\n        }\n        if (this.optionalRestSlugName !== null) {\n            routes.push(...this.children.get(\"[[...]]\")._smoosh(prefix + \"[[...\" + this.optionalRestSlugName + \"]]/\"));\n        }\n        return routes;\n    }\n    _insert(urlPaths, slugNames, isCatchAll) {\n        if (urlPaths.length === 0) {\n            this.placeholder = false;\n            return;\n        }\n        if (isCatchAll) {\n            throw new Error(\"Catch-all must be the last part of the URL.\");\n        }\n        // The next segment in the urlPaths list\n        let nextSegment = urlPaths[0];\n        // Check if the segment matches `[something]`\n        if (nextSegment.startsWith(\"[\") && nextSegment.endsWith(\"]\")) {\n            // Strip `[` and `]`, leaving only `something`\n            let segmentName = nextSegment.slice(1, -1);\n            let isOptional = false;\n            if (segmentName.startsWith(\"[\") && segmentName.endsWith(\"]\")) {\n                // Strip optional `[` and `]`, leaving only `something`\n                segmentName = segmentName.slice(1, -1);\n                isOptional = true;\n            }\n            if (segmentName.startsWith(\"...\")) {\n                // Strip `...`, leaving only `something`\n                segmentName = segmentName.substring(3);\n                isCatchAll = true;\n            }\n            if (segmentName.startsWith(\"[\") || segmentName.endsWith(\"]\")) {\n                throw new Error(\"Segment names may not start or end with extra brackets ('\" + segmentName + \"').\");\n            }\n            if (segmentName.startsWith(\".\")) {\n                throw new Error(\"Segment names may not start with erroneous periods ('\" + segmentName + \"').\");\n            }\n            function handleSlug(previousSlug, nextSlug) {\n                if (previousSlug !== null) {\n                    // If the specific segment already has a slug but the slug is not `something`\n                    // This prevents collisions like:\n                    // pages/[post]/index.js\n                    // pages/[id]/index.js\n                    // Because currently multiple dynamic params on the same segment level are not supported\n                    if (previousSlug !== nextSlug) {\n                        // TODO: This error seems to be confusing for users, needs an error link, the description can be based on above comment.\n                        throw new Error(\"You cannot use different slug names for the same dynamic path ('\" + previousSlug + \"' !== '\" + nextSlug + \"').\");\n                    }\n                }\n                slugNames.forEach((slug)=>{\n                    if (slug === nextSlug) {\n                        throw new Error('You cannot have the same slug name \"' + nextSlug + '\" repeat within a single dynamic path');\n                    }\n                    if (slug.replace(/\\W/g, \"\") === nextSegment.replace(/\\W/g, \"\")) {\n                        throw new Error('You cannot have the slug names \"' + slug + '\" and \"' + nextSlug + '\" differ only by non-word symbols within a single dynamic path');\n                    }\n                });\n                slugNames.push(nextSlug);\n            }\n            if (isCatchAll) {\n                if (isOptional) {\n                    if (this.restSlugName != null) {\n                        throw new Error('You cannot use both an required and optional catch-all route at the same level (\"[...' + this.restSlugName + ']\" and \"' + urlPaths[0] + '\" ).');\n                    }\n                    handleSlug(this.optionalRestSlugName, segmentName);\n                    // slugName is kept as it can only be one particular slugName\n                    this.optionalRestSlugName = segmentName;\n                    // nextSegment is overwritten to [[...]] so that it can later be sorted specifically\n                    nextSegment = \"[[...]]\";\n                } else {\n                    if (this.optionalRestSlugName != null) {\n                        throw new Error('You cannot use both an optional and required catch-all route at the same level (\"[[...' + this.optionalRestSlugName + ']]\" and \"' + urlPaths[0] + '\").');\n                    }\n                    handleSlug(this.restSlugName, segmentName);\n                    // slugName is kept as it can only be one particular slugName\n                    this.restSlugName = segmentName;\n                    // nextSegment is overwritten to [...] so that it can later be sorted specifically\n                    nextSegment = \"[...]\";\n                }\n            } else {\n                if (isOptional) {\n                    throw new Error('Optional route parameters are not yet supported (\"' + urlPaths[0] + '\").');\n                }\n                handleSlug(this.slugName, segmentName);\n                // slugName is kept as it can only be one particular slugName\n                this.slugName = segmentName;\n                // nextSegment is overwritten to [] so that it can later be sorted specifically\n                nextSegment = \"[]\";\n            }\n        }\n        // If this UrlNode doesn't have the nextSegment yet we create a new child UrlNode\n        if (!this.children.has(nextSegment)) {\n            this.children.set(nextSegment, new UrlNode());\n        }\n        this.children.get(nextSegment)._insert(urlPaths.slice(1), slugNames, isCatchAll);\n    }\n    constructor(){\n        this.placeholder = true;\n        this.children = new Map();\n        this.slugName = null;\n        this.restSlugName = null;\n        this.optionalRestSlugName = null;\n    }\n}\nfunction getSortedRoutes(normalizedPages) {\n    // First the UrlNode is created, and every UrlNode can have only 1 dynamic segment\n    // Eg you can't have pages/[post]/abc.js and pages/[hello]/something-else.js\n    // Only 1 dynamic segment per nesting level\n    // So in the case that is test/integration/dynamic-routing it'll be this:\n    // pages/[post]/comments.js\n    // pages/blog/[post]/comment/[id].js\n    // Both are fine because `pages/[post]` and `pages/blog` are on the same level\n    // So in this case `UrlNode` created here has `this.slugName === 'post'`\n    // And since your PR passed through `slugName` as an array basically it'd including it in too many possibilities\n    // Instead what has to be passed through is the upwards path's dynamic names\n    const root = new UrlNode();\n    // Here the `root` gets injected multiple paths, and insert will break them up into sublevels\n    normalizedPages.forEach((pagePath)=>root.insert(pagePath));\n    // Smoosh will then sort those sublevels up to the point where you get the correct route definition priority\n    return root.smoosh();\n} //# sourceMappingURL=sorted-routes.js.map\n//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvbmV4dC9kaXN0L3NoYXJlZC9saWIvcm91dGVyL3V0aWxzL3NvcnRlZC1yb3V0ZXMuanMiLCJtYXBwaW5ncyI6Ijs7OzttREFxTWdCQTs7O2VBQUFBOzs7QUFyTWhCLE1BQU1DO0lBT0pDLE9BQU9DLE9BQWUsRUFBUTtRQUM1QixJQUFJLENBQUNDLE9BQU8sQ0FBQ0QsUUFBUUUsS0FBSyxDQUFDLEtBQUtDLE1BQU0sQ0FBQ0MsVUFBVSxFQUFFLEVBQUU7SUFDdkQ7SUFFQUMsU0FBbUI7UUFDakIsT0FBTyxJQUFJLENBQUNDLE9BQU87SUFDckI7SUFFUUEsUUFBUUMsTUFBb0IsRUFBWTtRQUFoQ0EsSUFBQUEsV0FBQUEsS0FBQUEsR0FBQUEsU0FBaUI7UUFDL0IsTUFBTUMsZ0JBQWdCO2VBQUksSUFBSSxDQUFDQyxRQUFRLENBQUNDLElBQUk7U0FBRyxDQUFDQyxJQUFJO1FBQ3BELElBQUksSUFBSSxDQUFDQyxRQUFRLEtBQUssTUFBTTtZQUMxQkosY0FBY0ssTUFBTSxDQUFDTCxjQUFjTSxPQUFPLENBQUMsT0FBTztRQUNwRDtRQUNBLElBQUksSUFBSSxDQUFDQyxZQUFZLEtBQUssTUFBTTtZQUM5QlAsY0FBY0ssTUFBTSxDQUFDTCxjQUFjTSxPQUFPLENBQUMsVUFBVTtRQUN2RDtRQUNBLElBQUksSUFBSSxDQUFDRSxvQkFBb0IsS0FBSyxNQUFNO1lBQ3RDUixjQUFjSyxNQUFNLENBQUNMLGNBQWNNLE9BQU8sQ0FBQyxZQUFZO1FBQ3pEO1FBRUEsTUFBTUcsU0FBU1QsY0FDWlUsR0FBRyxDQUFDLENBQUNDLElBQU0sSUFBSSxDQUFDVixRQUFRLENBQUNXLEdBQUcsQ0FBQ0QsR0FBSWIsT0FBTyxDQUFDLEtBQUdDLFNBQVNZLElBQUUsTUFDdkRFLE1BQU0sQ0FBQyxDQUFDQyxNQUFNQyxPQUFTO21CQUFJRDttQkFBU0M7YUFBSyxFQUFFLEVBQUU7UUFFaEQsSUFBSSxJQUFJLENBQUNYLFFBQVEsS0FBSyxNQUFNO1lBQzFCSyxPQUFPTyxJQUFJLElBQ04sSUFBSSxDQUFDZixRQUFRLENBQUNXLEdBQUcsQ0FBQyxNQUFPZCxPQUFPLENBQUNDLFNBQVUsTUFBRyxJQUFJLENBQUNLLFFBQVEsR0FBQztRQUVuRTtRQUVBLElBQUksQ0FBQyxJQUFJLENBQUNhLFdBQVcsRUFBRTtZQUNyQixNQUFNQyxJQUFJbkIsV0FBVyxNQUFNLE1BQU1BLE9BQU9vQixLQUFLLENBQUMsR0FBRyxDQUFDO1lBQ2xELElBQUksSUFBSSxDQUFDWCxvQkFBb0IsSUFBSSxNQUFNO2dCQUNyQyxNQUFNLElBQUlZLE1BQ1IseUZBQXVGRixJQUFFLFlBQVNBLElBQUUsVUFBTyxJQUFJLENBQUNWLG9CQUFvQixHQUFDO1lBRXpJO1lBRUFDLE9BQU9ZLE9BQU8sQ0FBQ0g7UUFDakI7UUFFQSxJQUFJLElBQUksQ0FBQ1gsWUFBWSxLQUFLLE1BQU07WUFDOUJFLE9BQU9PLElBQUksSUFDTixJQUFJLENBQUNmLFFBQVEsQ0FDYlcsR0FBRyxDQUFDLFNBQ0pkLE9BQU8sQ0FBQ0MsU0FBVSxTQUFNLElBQUksQ0FBQ1EsWUFBWSxHQUFDO1FBRWpEO1FBRUEsSUFBSSxJQUFJLENBQUNDLG9CQUFvQixLQUFLLE1BQU07WUFDdENDLE9BQU9PLElBQUksSUFDTixJQUFJLENBQUNmLFFBQVEsQ0FDYlcsR0FBRyxDQUFDLFdBQ0pkLE9BQU8sQ0FBQ0MsU0FBVSxVQUFPLElBQUksQ0FBQ1Msb0JBQW9CLEdBQUM7UUFFMUQ7UUFFQSxPQUFPQztJQUNUO0lBRVFoQixRQUNONkIsUUFBa0IsRUFDbEJDLFNBQW1CLEVBQ25CQyxVQUFtQixFQUNiO1FBQ04sSUFBSUYsU0FBU0csTUFBTSxLQUFLLEdBQUc7WUFDekIsSUFBSSxDQUFDUixXQUFXLEdBQUc7WUFDbkI7UUFDRjtRQUVBLElBQUlPLFlBQVk7WUFDZCxNQUFNLElBQUlKLE1BQU87UUFDbkI7UUFFQSx3Q0FBd0M7UUFDeEMsSUFBSU0sY0FBY0osUUFBUSxDQUFDLEVBQUU7UUFFN0IsNkNBQTZDO1FBQzdDLElBQUlJLFlBQVlDLFVBQVUsQ0FBQyxRQUFRRCxZQUFZRSxRQUFRLENBQUMsTUFBTTtZQUM1RCw4Q0FBOEM7WUFDOUMsSUFBSUMsY0FBY0gsWUFBWVAsS0FBSyxDQUFDLEdBQUcsQ0FBQztZQUV4QyxJQUFJVyxhQUFhO1lBQ2pCLElBQUlELFlBQVlGLFVBQVUsQ0FBQyxRQUFRRSxZQUFZRCxRQUFRLENBQUMsTUFBTTtnQkFDNUQsdURBQXVEO2dCQUN2REMsY0FBY0EsWUFBWVYsS0FBSyxDQUFDLEdBQUcsQ0FBQztnQkFDcENXLGFBQWE7WUFDZjtZQUVBLElBQUlELFlBQVlGLFVBQVUsQ0FBQyxRQUFRO2dCQUNqQyx3Q0FBd0M7Z0JBQ3hDRSxjQUFjQSxZQUFZRSxTQUFTLENBQUM7Z0JBQ3BDUCxhQUFhO1lBQ2Y7WUFFQSxJQUFJSyxZQUFZRixVQUFVLENBQUMsUUFBUUUsWUFBWUQsUUFBUSxDQUFDLE1BQU07Z0JBQzVELE1BQU0sSUFBSVIsTUFDUiw4REFBNERTLGNBQVk7WUFFNUU7WUFFQSxJQUFJQSxZQUFZRixVQUFVLENBQUMsTUFBTTtnQkFDL0IsTUFBTSxJQUFJUCxNQUNSLDBEQUF3RFMsY0FBWTtZQUV4RTtZQUVBLFNBQVNHLFdBQVdDLFlBQTJCLEVBQUVDLFFBQWdCO2dCQUMvRCxJQUFJRCxpQkFBaUIsTUFBTTtvQkFDekIsNkVBQTZFO29CQUM3RSxpQ0FBaUM7b0JBQ2pDLHdCQUF3QjtvQkFDeEIsc0JBQXNCO29CQUN0Qix3RkFBd0Y7b0JBQ3hGLElBQUlBLGlCQUFpQkMsVUFBVTt3QkFDN0Isd0hBQXdIO3dCQUN4SCxNQUFNLElBQUlkLE1BQ1IscUVBQW1FYSxlQUFhLFlBQVNDLFdBQVM7b0JBRXRHO2dCQUNGO2dCQUVBWCxVQUFVWSxPQUFPLENBQUMsQ0FBQ0M7b0JBQ2pCLElBQUlBLFNBQVNGLFVBQVU7d0JBQ3JCLE1BQU0sSUFBSWQsTUFDUix5Q0FBdUNjLFdBQVM7b0JBRXBEO29CQUVBLElBQUlFLEtBQUtDLE9BQU8sQ0FBQyxPQUFPLFFBQVFYLFlBQVlXLE9BQU8sQ0FBQyxPQUFPLEtBQUs7d0JBQzlELE1BQU0sSUFBSWpCLE1BQ1IscUNBQW1DZ0IsT0FBSyxZQUFTRixXQUFTO29CQUU5RDtnQkFDRjtnQkFFQVgsVUFBVVAsSUFBSSxDQUFDa0I7WUFDakI7WUFFQSxJQUFJVixZQUFZO2dCQUNkLElBQUlNLFlBQVk7b0JBQ2QsSUFBSSxJQUFJLENBQUN2QixZQUFZLElBQUksTUFBTTt3QkFDN0IsTUFBTSxJQUFJYSxNQUNSLDBGQUF3RixJQUFJLENBQUNiLFlBQVksR0FBQyxhQUFVZSxRQUFRLENBQUMsRUFBRSxHQUFDO29CQUVwSTtvQkFFQVUsV0FBVyxJQUFJLENBQUN4QixvQkFBb0IsRUFBRXFCO29CQUN0Qyw2REFBNkQ7b0JBQzdELElBQUksQ0FBQ3JCLG9CQUFvQixHQUFHcUI7b0JBQzVCLG9GQUFvRjtvQkFDcEZILGNBQWM7Z0JBQ2hCLE9BQU87b0JBQ0wsSUFBSSxJQUFJLENBQUNsQixvQkFBb0IsSUFBSSxNQUFNO3dCQUNyQyxNQUFNLElBQUlZLE1BQ1IsMkZBQXlGLElBQUksQ0FBQ1osb0JBQW9CLEdBQUMsY0FBV2MsUUFBUSxDQUFDLEVBQUUsR0FBQztvQkFFOUk7b0JBRUFVLFdBQVcsSUFBSSxDQUFDekIsWUFBWSxFQUFFc0I7b0JBQzlCLDZEQUE2RDtvQkFDN0QsSUFBSSxDQUFDdEIsWUFBWSxHQUFHc0I7b0JBQ3BCLGtGQUFrRjtvQkFDbEZILGNBQWM7Z0JBQ2hCO1lBQ0YsT0FBTztnQkFDTCxJQUFJSSxZQUFZO29CQUNkLE1BQU0sSUFBSVYsTUFDUix1REFBcURFLFFBQVEsQ0FBQyxFQUFFLEdBQUM7Z0JBRXJFO2dCQUNBVSxXQUFXLElBQUksQ0FBQzVCLFFBQVEsRUFBRXlCO2dCQUMxQiw2REFBNkQ7Z0JBQzdELElBQUksQ0FBQ3pCLFFBQVEsR0FBR3lCO2dCQUNoQiwrRUFBK0U7Z0JBQy9FSCxjQUFjO1lBQ2hCO1FBQ0Y7UUFFQSxpRkFBaUY7UUFDakYsSUFBSSxDQUFDLElBQUksQ0FBQ3pCLFFBQVEsQ0FBQ3FDLEdBQUcsQ0FBQ1osY0FBYztZQUNuQyxJQUFJLENBQUN6QixRQUFRLENBQUNzQyxHQUFHLENBQUNiLGFBQWEsSUFBSXBDO1FBQ3JDO1FBRUEsSUFBSSxDQUFDVyxRQUFRLENBQ1ZXLEdBQUcsQ0FBQ2MsYUFDSmpDLE9BQU8sQ0FBQzZCLFNBQVNILEtBQUssQ0FBQyxJQUFJSSxXQUFXQztJQUMzQzs7YUFqTUFQLFdBQUFBLEdBQXVCO2FBQ3ZCaEIsUUFBQUEsR0FBaUMsSUFBSXVDO2FBQ3JDcEMsUUFBQUEsR0FBMEI7YUFDMUJHLFlBQUFBLEdBQThCO2FBQzlCQyxvQkFBQUEsR0FBc0M7O0FBOEx4QztBQUVPLFNBQVNuQixnQkFDZG9ELGVBQXNDO0lBRXRDLGtGQUFrRjtJQUNsRiw0RUFBNEU7SUFDNUUsMkNBQTJDO0lBRTNDLHlFQUF5RTtJQUN6RSwyQkFBMkI7SUFDM0Isb0NBQW9DO0lBQ3BDLDhFQUE4RTtJQUM5RSx3RUFBd0U7SUFDeEUsZ0hBQWdIO0lBQ2hILDRFQUE0RTtJQUM1RSxNQUFNQyxPQUFPLElBQUlwRDtJQUVqQiw2RkFBNkY7SUFDN0ZtRCxnQkFBZ0JOLE9BQU8sQ0FBQyxDQUFDUSxXQUFhRCxLQUFLbkQsTUFBTSxDQUFDb0Q7SUFDbEQsNEdBQTRHO0lBQzVHLE9BQU9ELEtBQUs3QyxNQUFNO0FBQ3BCIiwic291cmNlcyI6WyJ3ZWJwYWNrOi8vdW5pdmVyc2FsLW5mdC13ZWIvLi4vLi4vLi4vLi4vLi4vc3JjL3NoYXJlZC9saWIvcm91dGVyL3V0aWxzL3NvcnRlZC1yb3V0ZXMudHM/ZDUzYiJdLCJuYW1lcyI6WyJnZXRTb3J0ZWRSb3V0ZXMiLCJVcmxOb2RlIiwiaW5zZXJ0IiwidXJsUGF0aCIsIl9pbnNlcnQiLCJzcGxpdCIsImZpbHRlciIsIkJvb2xlYW4iLCJzbW9vc2giLCJfc21vb3NoIiwicHJlZml4IiwiY2hpbGRyZW5QYXRocyIsImNoaWxkcmVuIiwia2V5cyIsInNvcnQiLCJzbHVnTmFtZSIsInNwbGljZSIsImluZGV4T2YiLCJyZXN0U2x1Z05hbWUiLCJvcHRpb25hbFJlc3RTbHVnTmFtZSIsInJvdXRlcyIsIm1hcCIsImMiLCJnZXQiLCJyZWR1Y2UiLCJwcmV2IiwiY3VyciIsInB1c2giLCJwbGFjZWhvbGRlciIsInIiLCJzbGljZSIsIkVycm9yIiwidW5zaGlmdCIsInVybFBhdGhzIiwic2x1Z05hbWVzIiwiaXNDYXRjaEFsbCIsImxlbmd0aCIsIm5leHRTZWdtZW50Iiwic3RhcnRzV2l0aCIsImVuZHNXaXRoIiwic2VnbWVudE5hbWUiLCJpc09wdGlvbmFsIiwic3Vic3RyaW5nIiwiaGFuZGxlU2x1ZyIsInByZXZpb3VzU2x1ZyIsIm5leHRTbHVnIiwiZm9yRWFjaCIsInNsdWciLCJyZXBsYWNlIiwiaGFzIiwic2V0IiwiTWFwIiwibm9ybWFsaXplZFBhZ2VzIiwicm9vdCIsInBhZ2VQYXRoIl0sInNvdXJjZVJvb3QiOiIifQ==\n//# sourceURL=webpack-internal:///./node_modules/next/dist/shared/lib/router/utils/sorted-routes.js\n");
-
-/***/ }),
-
-/***/ "./node_modules/next/dist/shared/lib/segment.js":
-/*!******************************************************!*\
-  !*** ./node_modules/next/dist/shared/lib/segment.js ***!
-  \******************************************************/
-/***/ ((__unused_webpack_module, exports) => {
-
-eval("\nObject.defineProperty(exports, \"__esModule\", ({\n    value: true\n}));\n0 && (0);\nfunction _export(target, all) {\n    for(var name in all)Object.defineProperty(target, name, {\n        enumerable: true,\n        get: all[name]\n    });\n}\n_export(exports, {\n    DEFAULT_SEGMENT_KEY: function() {\n        return DEFAULT_SEGMENT_KEY;\n    },\n    PAGE_SEGMENT_KEY: function() {\n        return PAGE_SEGMENT_KEY;\n    },\n    isGroupSegment: function() {\n        return isGroupSegment;\n    }\n});\nfunction isGroupSegment(segment) {\n    // Use array[0] for performant purpose\n    return segment[0] === \"(\" && segment.endsWith(\")\");\n}\nconst PAGE_SEGMENT_KEY = \"__PAGE__\";\nconst DEFAULT_SEGMENT_KEY = \"__DEFAULT__\"; //# sourceMappingURL=segment.js.map\n//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvbmV4dC9kaXN0L3NoYXJlZC9saWIvc2VnbWVudC5qcyIsIm1hcHBpbmdzIjoiOzs7Ozs7Ozs7Ozs7SUFNYUEscUJBQW1CO2VBQW5CQTs7SUFEQUMsa0JBQWdCO2VBQWhCQTs7SUFMR0MsZ0JBQWM7ZUFBZEE7OztBQUFULFNBQVNBLGVBQWVDLE9BQWU7SUFDNUMsc0NBQXNDO0lBQ3RDLE9BQU9BLE9BQU8sQ0FBQyxFQUFFLEtBQUssT0FBT0EsUUFBUUMsUUFBUSxDQUFDO0FBQ2hEO0FBRU8sTUFBTUgsbUJBQW1CO0FBQ3pCLE1BQU1ELHNCQUFzQiIsInNvdXJjZXMiOlsid2VicGFjazovL3VuaXZlcnNhbC1uZnQtd2ViLy4uLy4uLy4uL3NyYy9zaGFyZWQvbGliL3NlZ21lbnQudHM/YWQyNSJdLCJuYW1lcyI6WyJERUZBVUxUX1NFR01FTlRfS0VZIiwiUEFHRV9TRUdNRU5UX0tFWSIsImlzR3JvdXBTZWdtZW50Iiwic2VnbWVudCIsImVuZHNXaXRoIl0sInNvdXJjZVJvb3QiOiIifQ==\n//# sourceURL=webpack-internal:///./node_modules/next/dist/shared/lib/segment.js\n");
-
-/***/ }),
-
-/***/ "./node_modules/next/dist/shared/lib/side-effect.js":
-/*!**********************************************************!*\
-  !*** ./node_modules/next/dist/shared/lib/side-effect.js ***!
-  \**********************************************************/
-/***/ ((__unused_webpack_module, exports, __webpack_require__) => {
-
-eval("\nObject.defineProperty(exports, \"__esModule\", ({\n    value: true\n}));\nObject.defineProperty(exports, \"default\", ({\n    enumerable: true,\n    get: function() {\n        return SideEffect;\n    }\n}));\nconst _react = __webpack_require__(/*! react */ \"react\");\nconst isServer = \"undefined\" === \"undefined\";\nconst useClientOnlyLayoutEffect = isServer ? ()=>{} : _react.useLayoutEffect;\nconst useClientOnlyEffect = isServer ? ()=>{} : _react.useEffect;\nfunction SideEffect(props) {\n    const { headManager, reduceComponentsToState } = props;\n    function emitChange() {\n        if (headManager && headManager.mountedInstances) {\n            const headElements = _react.Children.toArray(Array.from(headManager.mountedInstances).filter(Boolean));\n            headManager.updateHead(reduceComponentsToState(headElements, props));\n        }\n    }\n    if (isServer) {\n        var _headManager_mountedInstances;\n        headManager == null ? void 0 : (_headManager_mountedInstances = headManager.mountedInstances) == null ? void 0 : _headManager_mountedInstances.add(props.children);\n        emitChange();\n    }\n    useClientOnlyLayoutEffect(()=>{\n        var _headManager_mountedInstances;\n        headManager == null ? void 0 : (_headManager_mountedInstances = headManager.mountedInstances) == null ? void 0 : _headManager_mountedInstances.add(props.children);\n        return ()=>{\n            var _headManager_mountedInstances;\n            headManager == null ? void 0 : (_headManager_mountedInstances = headManager.mountedInstances) == null ? void 0 : _headManager_mountedInstances.delete(props.children);\n        };\n    });\n    // We need to call `updateHead` method whenever the `SideEffect` is trigger in all\n    // life-cycles: mount, update, unmount. However, if there are multiple `SideEffect`s\n    // being rendered, we only trigger the method from the last one.\n    // This is ensured by keeping the last unflushed `updateHead` in the `_pendingUpdate`\n    // singleton in the layout effect pass, and actually trigger it in the effect pass.\n    useClientOnlyLayoutEffect(()=>{\n        if (headManager) {\n            headManager._pendingUpdate = emitChange;\n        }\n        return ()=>{\n            if (headManager) {\n                headManager._pendingUpdate = emitChange;\n            }\n        };\n    });\n    useClientOnlyEffect(()=>{\n        if (headManager && headManager._pendingUpdate) {\n            headManager._pendingUpdate();\n            headManager._pendingUpdate = null;\n        }\n        return ()=>{\n            if (headManager && headManager._pendingUpdate) {\n                headManager._pendingUpdate();\n                headManager._pendingUpdate = null;\n            }\n        };\n    });\n    return null;\n} //# sourceMappingURL=side-effect.js.map\n//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvbmV4dC9kaXN0L3NoYXJlZC9saWIvc2lkZS1lZmZlY3QuanMiLCJtYXBwaW5ncyI6Ijs7OzsyQ0FvQkE7OztlQUF3QkE7OzttQ0FuQjZCO0FBZXJELE1BQU1DLFdBQVcsZ0JBQWtCO0FBQ25DLE1BQU1DLDRCQUE0QkQsV0FBVyxLQUFPLElBQUlFLE9BQUFBLGVBQWU7QUFDdkUsTUFBTUMsc0JBQXNCSCxXQUFXLEtBQU8sSUFBSUksT0FBQUEsU0FBUztBQUU1QyxTQUFTTCxXQUFXTSxLQUFzQjtJQUN2RCxNQUFNLEVBQUVDLFdBQVcsRUFBRUMsdUJBQXVCLEVBQUUsR0FBR0Y7SUFFakQsU0FBU0c7UUFDUCxJQUFJRixlQUFlQSxZQUFZRyxnQkFBZ0IsRUFBRTtZQUMvQyxNQUFNQyxlQUFlQyxPQUFBQSxRQUFRLENBQUNDLE9BQU8sQ0FDbkNDLE1BQU1DLElBQUksQ0FBQ1IsWUFBWUcsZ0JBQWdCLEVBQTBCTSxNQUFNLENBQ3JFQztZQUdKVixZQUFZVyxVQUFVLENBQUNWLHdCQUF3QkcsY0FBY0w7UUFDL0Q7SUFDRjtJQUVBLElBQUlMLFVBQVU7WUFDWk07UUFBQUEsZUFBQUEsT0FBQUEsS0FBQUEsSUFBQUEsQ0FBQUEsZ0NBQUFBLFlBQWFHLGdCQUFnQixxQkFBN0JILDhCQUErQlksR0FBRyxDQUFDYixNQUFNYyxRQUFRO1FBQ2pEWDtJQUNGO0lBRUFQLDBCQUEwQjtZQUN4Qks7UUFBQUEsZUFBQUEsT0FBQUEsS0FBQUEsSUFBQUEsQ0FBQUEsZ0NBQUFBLFlBQWFHLGdCQUFnQixxQkFBN0JILDhCQUErQlksR0FBRyxDQUFDYixNQUFNYyxRQUFRO1FBQ2pELE9BQU87Z0JBQ0xiO1lBQUFBLGVBQUFBLE9BQUFBLEtBQUFBLElBQUFBLENBQUFBLGdDQUFBQSxZQUFhRyxnQkFBZ0IscUJBQTdCSCw4QkFBK0JjLE1BQU0sQ0FBQ2YsTUFBTWMsUUFBUTtRQUN0RDtJQUNGO0lBRUEsa0ZBQWtGO0lBQ2xGLG9GQUFvRjtJQUNwRixnRUFBZ0U7SUFDaEUscUZBQXFGO0lBQ3JGLG1GQUFtRjtJQUNuRmxCLDBCQUEwQjtRQUN4QixJQUFJSyxhQUFhO1lBQ2ZBLFlBQVllLGNBQWMsR0FBR2I7UUFDL0I7UUFDQSxPQUFPO1lBQ0wsSUFBSUYsYUFBYTtnQkFDZkEsWUFBWWUsY0FBYyxHQUFHYjtZQUMvQjtRQUNGO0lBQ0Y7SUFFQUwsb0JBQW9CO1FBQ2xCLElBQUlHLGVBQWVBLFlBQVllLGNBQWMsRUFBRTtZQUM3Q2YsWUFBWWUsY0FBYztZQUMxQmYsWUFBWWUsY0FBYyxHQUFHO1FBQy9CO1FBQ0EsT0FBTztZQUNMLElBQUlmLGVBQWVBLFlBQVllLGNBQWMsRUFBRTtnQkFDN0NmLFlBQVllLGNBQWM7Z0JBQzFCZixZQUFZZSxjQUFjLEdBQUc7WUFDL0I7UUFDRjtJQUNGO0lBRUEsT0FBTztBQUNUIiwic291cmNlcyI6WyJ3ZWJwYWNrOi8vdW5pdmVyc2FsLW5mdC13ZWIvLi4vLi4vLi4vc3JjL3NoYXJlZC9saWIvc2lkZS1lZmZlY3QudHN4P2U4ZGQiXSwibmFtZXMiOlsiU2lkZUVmZmVjdCIsImlzU2VydmVyIiwidXNlQ2xpZW50T25seUxheW91dEVmZmVjdCIsInVzZUxheW91dEVmZmVjdCIsInVzZUNsaWVudE9ubHlFZmZlY3QiLCJ1c2VFZmZlY3QiLCJwcm9wcyIsImhlYWRNYW5hZ2VyIiwicmVkdWNlQ29tcG9uZW50c1RvU3RhdGUiLCJlbWl0Q2hhbmdlIiwibW91bnRlZEluc3RhbmNlcyIsImhlYWRFbGVtZW50cyIsIkNoaWxkcmVuIiwidG9BcnJheSIsIkFycmF5IiwiZnJvbSIsImZpbHRlciIsIkJvb2xlYW4iLCJ1cGRhdGVIZWFkIiwiYWRkIiwiY2hpbGRyZW4iLCJkZWxldGUiLCJfcGVuZGluZ1VwZGF0ZSJdLCJzb3VyY2VSb290IjoiIn0=\n//# sourceURL=webpack-internal:///./node_modules/next/dist/shared/lib/side-effect.js\n");
-
-/***/ }),
-
-/***/ "./node_modules/next/dist/shared/lib/utils.js":
-/*!****************************************************!*\
-  !*** ./node_modules/next/dist/shared/lib/utils.js ***!
-  \****************************************************/
-/***/ ((__unused_webpack_module, exports) => {
-
-eval("\nObject.defineProperty(exports, \"__esModule\", ({\n    value: true\n}));\n0 && (0);\nfunction _export(target, all) {\n    for(var name in all)Object.defineProperty(target, name, {\n        enumerable: true,\n        get: all[name]\n    });\n}\n_export(exports, {\n    DecodeError: function() {\n        return DecodeError;\n    },\n    MiddlewareNotFoundError: function() {\n        return MiddlewareNotFoundError;\n    },\n    MissingStaticPage: function() {\n        return MissingStaticPage;\n    },\n    NormalizeError: function() {\n        return NormalizeError;\n    },\n    PageNotFoundError: function() {\n        return PageNotFoundError;\n    },\n    SP: function() {\n        return SP;\n    },\n    ST: function() {\n        return ST;\n    },\n    WEB_VITALS: function() {\n        return WEB_VITALS;\n    },\n    execOnce: function() {\n        return execOnce;\n    },\n    getDisplayName: function() {\n        return getDisplayName;\n    },\n    getLocationOrigin: function() {\n        return getLocationOrigin;\n    },\n    getURL: function() {\n        return getURL;\n    },\n    isAbsoluteUrl: function() {\n        return isAbsoluteUrl;\n    },\n    isResSent: function() {\n        return isResSent;\n    },\n    loadGetInitialProps: function() {\n        return loadGetInitialProps;\n    },\n    normalizeRepeatedSlashes: function() {\n        return normalizeRepeatedSlashes;\n    },\n    stringifyError: function() {\n        return stringifyError;\n    }\n});\nconst WEB_VITALS = [\n    \"CLS\",\n    \"FCP\",\n    \"FID\",\n    \"INP\",\n    \"LCP\",\n    \"TTFB\"\n];\nfunction execOnce(fn) {\n    let used = false;\n    let result;\n    return function() {\n        for(var _len = arguments.length, args = new Array(_len), _key = 0; _key < _len; _key++){\n            args[_key] = arguments[_key];\n        }\n        if (!used) {\n            used = true;\n            result = fn(...args);\n        }\n        return result;\n    };\n}\n// Scheme: https://tools.ietf.org/html/rfc3986#section-3.1\n// Absolute URL: https://tools.ietf.org/html/rfc3986#section-4.3\nconst ABSOLUTE_URL_REGEX = /^[a-zA-Z][a-zA-Z\\d+\\-.]*?:/;\nconst isAbsoluteUrl = (url)=>ABSOLUTE_URL_REGEX.test(url);\nfunction getLocationOrigin() {\n    const { protocol, hostname, port } = window.location;\n    return protocol + \"//\" + hostname + (port ? \":\" + port : \"\");\n}\nfunction getURL() {\n    const { href } = window.location;\n    const origin = getLocationOrigin();\n    return href.substring(origin.length);\n}\nfunction getDisplayName(Component) {\n    return typeof Component === \"string\" ? Component : Component.displayName || Component.name || \"Unknown\";\n}\nfunction isResSent(res) {\n    return res.finished || res.headersSent;\n}\nfunction normalizeRepeatedSlashes(url) {\n    const urlParts = url.split(\"?\");\n    const urlNoQuery = urlParts[0];\n    return urlNoQuery // first we replace any non-encoded backslashes with forward\n    // then normalize repeated forward slashes\n    .replace(/\\\\/g, \"/\").replace(/\\/\\/+/g, \"/\") + (urlParts[1] ? \"?\" + urlParts.slice(1).join(\"?\") : \"\");\n}\nasync function loadGetInitialProps(App, ctx) {\n    if (true) {\n        var _App_prototype;\n        if ((_App_prototype = App.prototype) == null ? void 0 : _App_prototype.getInitialProps) {\n            const message = '\"' + getDisplayName(App) + '.getInitialProps()\" is defined as an instance method - visit https://nextjs.org/docs/messages/get-initial-props-as-an-instance-method for more information.';\n            throw new Error(message);\n        }\n    }\n    // when called from _app `ctx` is nested in `ctx`\n    const res = ctx.res || ctx.ctx && ctx.ctx.res;\n    if (!App.getInitialProps) {\n        if (ctx.ctx && ctx.Component) {\n            // @ts-ignore pageProps default\n            return {\n                pageProps: await loadGetInitialProps(ctx.Component, ctx.ctx)\n            };\n        }\n        return {};\n    }\n    const props = await App.getInitialProps(ctx);\n    if (res && isResSent(res)) {\n        return props;\n    }\n    if (!props) {\n        const message = '\"' + getDisplayName(App) + '.getInitialProps()\" should resolve to an object. But found \"' + props + '\" instead.';\n        throw new Error(message);\n    }\n    if (true) {\n        if (Object.keys(props).length === 0 && !ctx.ctx) {\n            console.warn(\"\" + getDisplayName(App) + \" returned an empty object from `getInitialProps`. This de-optimizes and prevents automatic static optimization. https://nextjs.org/docs/messages/empty-object-getInitialProps\");\n        }\n    }\n    return props;\n}\nconst SP = typeof performance !== \"undefined\";\nconst ST = SP && [\n    \"mark\",\n    \"measure\",\n    \"getEntriesByName\"\n].every((method)=>typeof performance[method] === \"function\");\nclass DecodeError extends Error {\n}\nclass NormalizeError extends Error {\n}\nclass PageNotFoundError extends Error {\n    constructor(page){\n        super();\n        this.code = \"ENOENT\";\n        this.name = \"PageNotFoundError\";\n        this.message = \"Cannot find module for page: \" + page;\n    }\n}\nclass MissingStaticPage extends Error {\n    constructor(page, message){\n        super();\n        this.message = \"Failed to load static file for page: \" + page + \" \" + message;\n    }\n}\nclass MiddlewareNotFoundError extends Error {\n    constructor(){\n        super();\n        this.code = \"ENOENT\";\n        this.message = \"Cannot find the middleware module\";\n    }\n}\nfunction stringifyError(error) {\n    return JSON.stringify({\n        message: error.message,\n        stack: error.stack\n    });\n} //# sourceMappingURL=utils.js.map\n//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvbmV4dC9kaXN0L3NoYXJlZC9saWIvdXRpbHMuanMiLCJtYXBwaW5ncyI6Ijs7Ozs7Ozs7Ozs7O0lBb2FhQSxhQUFXO2VBQVhBOztJQW9CQUMseUJBQXVCO2VBQXZCQTs7SUFQQUMsbUJBQWlCO2VBQWpCQTs7SUFaQUMsZ0JBQWM7ZUFBZEE7O0lBQ0FDLG1CQUFpQjtlQUFqQkE7O0lBVEFDLElBQUU7ZUFBRkE7O0lBQ0FDLElBQUU7ZUFBRkE7O0lBbFhBQyxZQUFVO2VBQVZBOztJQXNRR0MsVUFBUTtlQUFSQTs7SUErQkFDLGdCQUFjO2VBQWRBOztJQVhBQyxtQkFBaUI7ZUFBakJBOztJQUtBQyxRQUFNO2VBQU5BOztJQVBIQyxlQUFhO2VBQWJBOztJQW1CR0MsV0FBUztlQUFUQTs7SUFrQk1DLHFCQUFtQjtlQUFuQkE7O0lBZE5DLDBCQUF3QjtlQUF4QkE7O0lBK0dBQyxnQkFBYztlQUFkQTs7O0FBOVpULE1BQU1ULGFBQWE7SUFBQztJQUFPO0lBQU87SUFBTztJQUFPO0lBQU87Q0FBTztBQXNROUQsU0FBU0MsU0FDZFMsRUFBSztJQUVMLElBQUlDLE9BQU87SUFDWCxJQUFJQztJQUVKLE9BQVE7eUNBQUlDLE9BQUFBLElBQUFBLE1BQUFBLE9BQUFBLE9BQUFBLEdBQUFBLE9BQUFBLE1BQUFBLE9BQUFBO1lBQUFBLElBQUFBLENBQUFBLEtBQUFBLEdBQUFBLFNBQUFBLENBQUFBLEtBQUFBOztRQUNWLElBQUksQ0FBQ0YsTUFBTTtZQUNUQSxPQUFPO1lBQ1BDLFNBQVNGLE1BQU1HO1FBQ2pCO1FBQ0EsT0FBT0Q7SUFDVDtBQUNGO0FBRUEsMERBQTBEO0FBQzFELGdFQUFnRTtBQUNoRSxNQUFNRSxxQkFBcUI7QUFDcEIsTUFBTVQsZ0JBQWdCLENBQUNVLE1BQWdCRCxtQkFBbUJFLElBQUksQ0FBQ0Q7QUFFL0QsU0FBU1o7SUFDZCxNQUFNLEVBQUVjLFFBQVEsRUFBRUMsUUFBUSxFQUFFQyxJQUFJLEVBQUUsR0FBR0MsT0FBT0MsUUFBUTtJQUNwRCxPQUFPSixXQUFZLE9BQUlDLFdBQVdDLENBQUFBLE9BQU8sTUFBTUEsT0FBTztBQUN4RDtBQUVPLFNBQVNmO0lBQ2QsTUFBTSxFQUFFa0IsSUFBSSxFQUFFLEdBQUdGLE9BQU9DLFFBQVE7SUFDaEMsTUFBTUUsU0FBU3BCO0lBQ2YsT0FBT21CLEtBQUtFLFNBQVMsQ0FBQ0QsT0FBT0UsTUFBTTtBQUNyQztBQUVPLFNBQVN2QixlQUFrQndCLFNBQTJCO0lBQzNELE9BQU8sT0FBT0EsY0FBYyxXQUN4QkEsWUFDQUEsVUFBVUMsV0FBVyxJQUFJRCxVQUFVRSxJQUFJLElBQUk7QUFDakQ7QUFFTyxTQUFTdEIsVUFBVXVCLEdBQW1CO0lBQzNDLE9BQU9BLElBQUlDLFFBQVEsSUFBSUQsSUFBSUUsV0FBVztBQUN4QztBQUVPLFNBQVN2Qix5QkFBeUJPLEdBQVc7SUFDbEQsTUFBTWlCLFdBQVdqQixJQUFJa0IsS0FBSyxDQUFDO0lBQzNCLE1BQU1DLGFBQWFGLFFBQVEsQ0FBQyxFQUFFO0lBRTlCLE9BQ0VFLFdBQ0UsNERBQTREO0lBQzVELDBDQUEwQztLQUN6Q0MsT0FBTyxDQUFDLE9BQU8sS0FDZkEsT0FBTyxDQUFDLFVBQVUsT0FDcEJILENBQUFBLFFBQVEsQ0FBQyxFQUFFLEdBQUcsTUFBSUEsU0FBU0ksS0FBSyxDQUFDLEdBQUdDLElBQUksQ0FBQyxPQUFTO0FBRXZEO0FBRU8sZUFBZTlCLG9CQUlwQitCLEdBQWdDLEVBQUVDLEdBQU07SUFDeEMsSUFBSUMsSUFBeUIsRUFBYztZQUNyQ0Y7UUFBSixLQUFJQSxpQkFBQUEsSUFBSUcsU0FBUyxxQkFBYkgsZUFBZUksZUFBZSxFQUFFO1lBQ2xDLE1BQU1DLFVBQVUsTUFBSXpDLGVBQ2xCb0MsT0FDQTtZQUNGLE1BQU0sSUFBSU0sTUFBTUQ7UUFDbEI7SUFDRjtJQUNBLGlEQUFpRDtJQUNqRCxNQUFNZCxNQUFNVSxJQUFJVixHQUFHLElBQUtVLElBQUlBLEdBQUcsSUFBSUEsSUFBSUEsR0FBRyxDQUFDVixHQUFHO0lBRTlDLElBQUksQ0FBQ1MsSUFBSUksZUFBZSxFQUFFO1FBQ3hCLElBQUlILElBQUlBLEdBQUcsSUFBSUEsSUFBSWIsU0FBUyxFQUFFO1lBQzVCLCtCQUErQjtZQUMvQixPQUFPO2dCQUNMbUIsV0FBVyxNQUFNdEMsb0JBQW9CZ0MsSUFBSWIsU0FBUyxFQUFFYSxJQUFJQSxHQUFHO1lBQzdEO1FBQ0Y7UUFDQSxPQUFPLENBQUM7SUFDVjtJQUVBLE1BQU1PLFFBQVEsTUFBTVIsSUFBSUksZUFBZSxDQUFDSDtJQUV4QyxJQUFJVixPQUFPdkIsVUFBVXVCLE1BQU07UUFDekIsT0FBT2lCO0lBQ1Q7SUFFQSxJQUFJLENBQUNBLE9BQU87UUFDVixNQUFNSCxVQUFVLE1BQUl6QyxlQUNsQm9DLE9BQ0EsaUVBQThEUSxRQUFNO1FBQ3RFLE1BQU0sSUFBSUYsTUFBTUQ7SUFDbEI7SUFFQSxJQUFJSCxJQUF5QixFQUFjO1FBQ3pDLElBQUlPLE9BQU9DLElBQUksQ0FBQ0YsT0FBT3JCLE1BQU0sS0FBSyxLQUFLLENBQUNjLElBQUlBLEdBQUcsRUFBRTtZQUMvQ1UsUUFBUUMsSUFBSSxDQUNWLEtBQUdoRCxlQUNEb0MsT0FDQTtRQUVOO0lBQ0Y7SUFFQSxPQUFPUTtBQUNUO0FBRU8sTUFBTWhELEtBQUssT0FBT3FELGdCQUFnQjtBQUNsQyxNQUFNcEQsS0FDWEQsTUFDQTtJQUFFO0lBQVE7SUFBVztDQUFtQixDQUFXc0QsS0FBSyxDQUN0RCxDQUFDQyxTQUFXLE9BQU9GLFdBQVcsQ0FBQ0UsT0FBTyxLQUFLO0FBR3hDLE1BQU01RCxvQkFBb0JtRDtBQUFPO0FBQ2pDLE1BQU1oRCx1QkFBdUJnRDtBQUFPO0FBQ3BDLE1BQU0vQywwQkFBMEIrQztJQUdyQ1UsWUFBWUMsSUFBWSxDQUFFO1FBQ3hCLEtBQUs7UUFDTCxJQUFJLENBQUNDLElBQUksR0FBRztRQUNaLElBQUksQ0FBQzVCLElBQUksR0FBRztRQUNaLElBQUksQ0FBQ2UsT0FBTyxHQUFHLGtDQUFnQ1k7SUFDakQ7QUFDRjtBQUVPLE1BQU01RCwwQkFBMEJpRDtJQUNyQ1UsWUFBWUMsSUFBWSxFQUFFWixPQUFlLENBQUU7UUFDekMsS0FBSztRQUNMLElBQUksQ0FBQ0EsT0FBTyxHQUFHLDBDQUF3Q1ksT0FBSyxNQUFHWjtJQUNqRTtBQUNGO0FBRU8sTUFBTWpELGdDQUFnQ2tEO0lBRTNDVSxhQUFjO1FBQ1osS0FBSztRQUNMLElBQUksQ0FBQ0UsSUFBSSxHQUFHO1FBQ1osSUFBSSxDQUFDYixPQUFPLEdBQUk7SUFDbEI7QUFDRjtBQVdPLFNBQVNsQyxlQUFlZ0QsS0FBWTtJQUN6QyxPQUFPQyxLQUFLQyxTQUFTLENBQUM7UUFBRWhCLFNBQVNjLE1BQU1kLE9BQU87UUFBRWlCLE9BQU9ILE1BQU1HLEtBQUs7SUFBQztBQUNyRSIsInNvdXJjZXMiOlsid2VicGFjazovL3VuaXZlcnNhbC1uZnQtd2ViLy4uLy4uLy4uL3NyYy9zaGFyZWQvbGliL3V0aWxzLnRzPzFiZDUiXSwibmFtZXMiOlsiRGVjb2RlRXJyb3IiLCJNaWRkbGV3YXJlTm90Rm91bmRFcnJvciIsIk1pc3NpbmdTdGF0aWNQYWdlIiwiTm9ybWFsaXplRXJyb3IiLCJQYWdlTm90Rm91bmRFcnJvciIsIlNQIiwiU1QiLCJXRUJfVklUQUxTIiwiZXhlY09uY2UiLCJnZXREaXNwbGF5TmFtZSIsImdldExvY2F0aW9uT3JpZ2luIiwiZ2V0VVJMIiwiaXNBYnNvbHV0ZVVybCIsImlzUmVzU2VudCIsImxvYWRHZXRJbml0aWFsUHJvcHMiLCJub3JtYWxpemVSZXBlYXRlZFNsYXNoZXMiLCJzdHJpbmdpZnlFcnJvciIsImZuIiwidXNlZCIsInJlc3VsdCIsImFyZ3MiLCJBQlNPTFVURV9VUkxfUkVHRVgiLCJ1cmwiLCJ0ZXN0IiwicHJvdG9jb2wiLCJob3N0bmFtZSIsInBvcnQiLCJ3aW5kb3ciLCJsb2NhdGlvbiIsImhyZWYiLCJvcmlnaW4iLCJzdWJzdHJpbmciLCJsZW5ndGgiLCJDb21wb25lbnQiLCJkaXNwbGF5TmFtZSIsIm5hbWUiLCJyZXMiLCJmaW5pc2hlZCIsImhlYWRlcnNTZW50IiwidXJsUGFydHMiLCJzcGxpdCIsInVybE5vUXVlcnkiLCJyZXBsYWNlIiwic2xpY2UiLCJqb2luIiwiQXBwIiwiY3R4IiwicHJvY2VzcyIsInByb3RvdHlwZSIsImdldEluaXRpYWxQcm9wcyIsIm1lc3NhZ2UiLCJFcnJvciIsInBhZ2VQcm9wcyIsInByb3BzIiwiT2JqZWN0Iiwia2V5cyIsImNvbnNvbGUiLCJ3YXJuIiwicGVyZm9ybWFuY2UiLCJldmVyeSIsIm1ldGhvZCIsImNvbnN0cnVjdG9yIiwicGFnZSIsImNvZGUiLCJlcnJvciIsIkpTT04iLCJzdHJpbmdpZnkiLCJzdGFjayJdLCJzb3VyY2VSb290IjoiIn0=\n//# sourceURL=webpack-internal:///./node_modules/next/dist/shared/lib/utils.js\n");
-
-/***/ }),
-
-/***/ "./node_modules/next/dist/shared/lib/utils/warn-once.js":
-/*!**************************************************************!*\
-  !*** ./node_modules/next/dist/shared/lib/utils/warn-once.js ***!
-  \**************************************************************/
-/***/ ((__unused_webpack_module, exports) => {
-
-eval("\nObject.defineProperty(exports, \"__esModule\", ({\n    value: true\n}));\nObject.defineProperty(exports, \"warnOnce\", ({\n    enumerable: true,\n    get: function() {\n        return warnOnce;\n    }\n}));\nlet warnOnce = (_)=>{};\nif (true) {\n    const warnings = new Set();\n    warnOnce = (msg)=>{\n        if (!warnings.has(msg)) {\n            console.warn(msg);\n        }\n        warnings.add(msg);\n    };\n} //# sourceMappingURL=warn-once.js.map\n//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvbmV4dC9kaXN0L3NoYXJlZC9saWIvdXRpbHMvd2Fybi1vbmNlLmpzIiwibWFwcGluZ3MiOiI7Ozs7NENBV1NBOzs7ZUFBQUE7OztBQVhULElBQUlBLFdBQVcsQ0FBQ0MsS0FBZTtBQUMvQixJQUFJQyxJQUF5QixFQUFjO0lBQ3pDLE1BQU1DLFdBQVcsSUFBSUM7SUFDckJKLFdBQVcsQ0FBQ0s7UUFDVixJQUFJLENBQUNGLFNBQVNHLEdBQUcsQ0FBQ0QsTUFBTTtZQUN0QkUsUUFBUUMsSUFBSSxDQUFDSDtRQUNmO1FBQ0FGLFNBQVNNLEdBQUcsQ0FBQ0o7SUFDZjtBQUNGIiwic291cmNlcyI6WyJ3ZWJwYWNrOi8vdW5pdmVyc2FsLW5mdC13ZWIvLi4vLi4vLi4vLi4vc3JjL3NoYXJlZC9saWIvdXRpbHMvd2Fybi1vbmNlLnRzP2QwNDEiXSwibmFtZXMiOlsid2Fybk9uY2UiLCJfIiwicHJvY2VzcyIsIndhcm5pbmdzIiwiU2V0IiwibXNnIiwiaGFzIiwiY29uc29sZSIsIndhcm4iLCJhZGQiXSwic291cmNlUm9vdCI6IiJ9\n//# sourceURL=webpack-internal:///./node_modules/next/dist/shared/lib/utils/warn-once.js\n");
-
-/***/ }),
-
-/***/ "./node_modules/next/dist/lib/is-error.js":
-/*!************************************************!*\
-  !*** ./node_modules/next/dist/lib/is-error.js ***!
-  \************************************************/
-/***/ ((__unused_webpack_module, exports, __webpack_require__) => {
-
-eval("\nObject.defineProperty(exports, \"__esModule\", ({\n    value: true\n}));\n0 && (0);\nfunction _export(target, all) {\n    for(var name in all)Object.defineProperty(target, name, {\n        enumerable: true,\n        get: all[name]\n    });\n}\n_export(exports, {\n    default: function() {\n        return isError;\n    },\n    getProperError: function() {\n        return getProperError;\n    }\n});\nconst _isplainobject = __webpack_require__(/*! ../shared/lib/is-plain-object */ \"./node_modules/next/dist/shared/lib/is-plain-object.js\");\nfunction isError(err) {\n    return typeof err === \"object\" && err !== null && \"name\" in err && \"message\" in err;\n}\nfunction getProperError(err) {\n    if (isError(err)) {\n        return err;\n    }\n    if (true) {\n        // provide better error for case where `throw undefined`\n        // is called in development\n        if (typeof err === \"undefined\") {\n            return new Error(\"An undefined error was thrown, \" + \"see here for more info: https://nextjs.org/docs/messages/threw-undefined\");\n        }\n        if (err === null) {\n            return new Error(\"A null error was thrown, \" + \"see here for more info: https://nextjs.org/docs/messages/threw-undefined\");\n        }\n    }\n    return new Error((0, _isplainobject.isPlainObject)(err) ? JSON.stringify(err) : err + \"\");\n}\n\n//# sourceMappingURL=is-error.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvbmV4dC9kaXN0L2xpYi9pcy1lcnJvci5qcyIsIm1hcHBpbmdzIjoiQUFBYTtBQUNiLDhDQUE2QztBQUM3QztBQUNBLENBQUMsRUFBQztBQUNGLE1BQU0sQ0FHTDtBQUNEO0FBQ0E7QUFDQTtBQUNBO0FBQ0EsS0FBSztBQUNMO0FBQ0E7QUFDQTtBQUNBO0FBQ0EsS0FBSztBQUNMO0FBQ0E7QUFDQTtBQUNBLENBQUM7QUFDRCx1QkFBdUIsbUJBQU8sQ0FBQyw2RkFBK0I7QUFDOUQ7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQSxRQUFRLElBQXNDO0FBQzlDO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7O0FBRUEiLCJzb3VyY2VzIjpbIndlYnBhY2s6Ly91bml2ZXJzYWwtbmZ0LXdlYi8uL25vZGVfbW9kdWxlcy9uZXh0L2Rpc3QvbGliL2lzLWVycm9yLmpzPzE3OGUiXSwic291cmNlc0NvbnRlbnQiOlsiXCJ1c2Ugc3RyaWN0XCI7XG5PYmplY3QuZGVmaW5lUHJvcGVydHkoZXhwb3J0cywgXCJfX2VzTW9kdWxlXCIsIHtcbiAgICB2YWx1ZTogdHJ1ZVxufSk7XG4wICYmIChtb2R1bGUuZXhwb3J0cyA9IHtcbiAgICBkZWZhdWx0OiBudWxsLFxuICAgIGdldFByb3BlckVycm9yOiBudWxsXG59KTtcbmZ1bmN0aW9uIF9leHBvcnQodGFyZ2V0LCBhbGwpIHtcbiAgICBmb3IodmFyIG5hbWUgaW4gYWxsKU9iamVjdC5kZWZpbmVQcm9wZXJ0eSh0YXJnZXQsIG5hbWUsIHtcbiAgICAgICAgZW51bWVyYWJsZTogdHJ1ZSxcbiAgICAgICAgZ2V0OiBhbGxbbmFtZV1cbiAgICB9KTtcbn1cbl9leHBvcnQoZXhwb3J0cywge1xuICAgIGRlZmF1bHQ6IGZ1bmN0aW9uKCkge1xuICAgICAgICByZXR1cm4gaXNFcnJvcjtcbiAgICB9LFxuICAgIGdldFByb3BlckVycm9yOiBmdW5jdGlvbigpIHtcbiAgICAgICAgcmV0dXJuIGdldFByb3BlckVycm9yO1xuICAgIH1cbn0pO1xuY29uc3QgX2lzcGxhaW5vYmplY3QgPSByZXF1aXJlKFwiLi4vc2hhcmVkL2xpYi9pcy1wbGFpbi1vYmplY3RcIik7XG5mdW5jdGlvbiBpc0Vycm9yKGVycikge1xuICAgIHJldHVybiB0eXBlb2YgZXJyID09PSBcIm9iamVjdFwiICYmIGVyciAhPT0gbnVsbCAmJiBcIm5hbWVcIiBpbiBlcnIgJiYgXCJtZXNzYWdlXCIgaW4gZXJyO1xufVxuZnVuY3Rpb24gZ2V0UHJvcGVyRXJyb3IoZXJyKSB7XG4gICAgaWYgKGlzRXJyb3IoZXJyKSkge1xuICAgICAgICByZXR1cm4gZXJyO1xuICAgIH1cbiAgICBpZiAocHJvY2Vzcy5lbnYuTk9ERV9FTlYgPT09IFwiZGV2ZWxvcG1lbnRcIikge1xuICAgICAgICAvLyBwcm92aWRlIGJldHRlciBlcnJvciBmb3IgY2FzZSB3aGVyZSBgdGhyb3cgdW5kZWZpbmVkYFxuICAgICAgICAvLyBpcyBjYWxsZWQgaW4gZGV2ZWxvcG1lbnRcbiAgICAgICAgaWYgKHR5cGVvZiBlcnIgPT09IFwidW5kZWZpbmVkXCIpIHtcbiAgICAgICAgICAgIHJldHVybiBuZXcgRXJyb3IoXCJBbiB1bmRlZmluZWQgZXJyb3Igd2FzIHRocm93biwgXCIgKyBcInNlZSBoZXJlIGZvciBtb3JlIGluZm86IGh0dHBzOi8vbmV4dGpzLm9yZy9kb2NzL21lc3NhZ2VzL3RocmV3LXVuZGVmaW5lZFwiKTtcbiAgICAgICAgfVxuICAgICAgICBpZiAoZXJyID09PSBudWxsKSB7XG4gICAgICAgICAgICByZXR1cm4gbmV3IEVycm9yKFwiQSBudWxsIGVycm9yIHdhcyB0aHJvd24sIFwiICsgXCJzZWUgaGVyZSBmb3IgbW9yZSBpbmZvOiBodHRwczovL25leHRqcy5vcmcvZG9jcy9tZXNzYWdlcy90aHJldy11bmRlZmluZWRcIik7XG4gICAgICAgIH1cbiAgICB9XG4gICAgcmV0dXJuIG5ldyBFcnJvcigoMCwgX2lzcGxhaW5vYmplY3QuaXNQbGFpbk9iamVjdCkoZXJyKSA/IEpTT04uc3RyaW5naWZ5KGVycikgOiBlcnIgKyBcIlwiKTtcbn1cblxuLy8jIHNvdXJjZU1hcHBpbmdVUkw9aXMtZXJyb3IuanMubWFwIl0sIm5hbWVzIjpbXSwic291cmNlUm9vdCI6IiJ9\n//# sourceURL=webpack-internal:///./node_modules/next/dist/lib/is-error.js\n");
-
-/***/ }),
-
-/***/ "./node_modules/next/dist/lib/pretty-bytes.js":
-/*!****************************************************!*\
-  !*** ./node_modules/next/dist/lib/pretty-bytes.js ***!
-  \****************************************************/
-/***/ ((__unused_webpack_module, exports) => {
-
-eval("/*\nMIT License\n\nCopyright (c) Sindre Sorhus <sindresorhus@gmail.com> (sindresorhus.com)\n\nPermission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the \"Software\"), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions:\n\nThe above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software.\n\nTHE SOFTWARE IS PROVIDED \"AS IS\", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.\n*/ \nObject.defineProperty(exports, \"__esModule\", ({\n    value: true\n}));\nObject.defineProperty(exports, \"default\", ({\n    enumerable: true,\n    get: function() {\n        return prettyBytes;\n    }\n}));\nconst UNITS = [\n    \"B\",\n    \"kB\",\n    \"MB\",\n    \"GB\",\n    \"TB\",\n    \"PB\",\n    \"EB\",\n    \"ZB\",\n    \"YB\"\n];\n/*\nFormats the given number using `Number#toLocaleString`.\n- If locale is a string, the value is expected to be a locale-key (for example: `de`).\n- If locale is true, the system default locale is used for translation.\n- If no value for locale is specified, the number is returned unmodified.\n*/ const toLocaleString = (number, locale)=>{\n    let result = number;\n    if (typeof locale === \"string\") {\n        result = number.toLocaleString(locale);\n    } else if (locale === true) {\n        result = number.toLocaleString();\n    }\n    return result;\n};\nfunction prettyBytes(number, options) {\n    if (!Number.isFinite(number)) {\n        throw new TypeError(`Expected a finite number, got ${typeof number}: ${number}`);\n    }\n    options = Object.assign({}, options);\n    if (options.signed && number === 0) {\n        return \" 0 B\";\n    }\n    const isNegative = number < 0;\n    const prefix = isNegative ? \"-\" : options.signed ? \"+\" : \"\";\n    if (isNegative) {\n        number = -number;\n    }\n    if (number < 1) {\n        const numberString = toLocaleString(number, options.locale);\n        return prefix + numberString + \" B\";\n    }\n    const exponent = Math.min(Math.floor(Math.log10(number) / 3), UNITS.length - 1);\n    number = Number((number / Math.pow(1000, exponent)).toPrecision(3));\n    const numberString = toLocaleString(number, options.locale);\n    const unit = UNITS[exponent];\n    return prefix + numberString + \" \" + unit;\n}\n\n//# sourceMappingURL=pretty-bytes.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvbmV4dC9kaXN0L2xpYi9wcmV0dHktYnl0ZXMuanMiLCJtYXBwaW5ncyI6IkFBQUE7QUFDQTs7QUFFQTs7QUFFQTs7QUFFQTs7QUFFQTtBQUNBLEdBQWdCO0FBQ2hCLDhDQUE2QztBQUM3QztBQUNBLENBQUMsRUFBQztBQUNGLDJDQUEwQztBQUMxQztBQUNBO0FBQ0E7QUFDQTtBQUNBLENBQUMsRUFBQztBQUNGO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQSxNQUFNO0FBQ047QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0EsNkRBQTZELGNBQWMsSUFBSSxPQUFPO0FBQ3RGO0FBQ0EsOEJBQThCO0FBQzlCO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTs7QUFFQSIsInNvdXJjZXMiOlsid2VicGFjazovL3VuaXZlcnNhbC1uZnQtd2ViLy4vbm9kZV9tb2R1bGVzL25leHQvZGlzdC9saWIvcHJldHR5LWJ5dGVzLmpzP2ZmMzAiXSwic291cmNlc0NvbnRlbnQiOlsiLypcbk1JVCBMaWNlbnNlXG5cbkNvcHlyaWdodCAoYykgU2luZHJlIFNvcmh1cyA8c2luZHJlc29yaHVzQGdtYWlsLmNvbT4gKHNpbmRyZXNvcmh1cy5jb20pXG5cblBlcm1pc3Npb24gaXMgaGVyZWJ5IGdyYW50ZWQsIGZyZWUgb2YgY2hhcmdlLCB0byBhbnkgcGVyc29uIG9idGFpbmluZyBhIGNvcHkgb2YgdGhpcyBzb2Z0d2FyZSBhbmQgYXNzb2NpYXRlZCBkb2N1bWVudGF0aW9uIGZpbGVzICh0aGUgXCJTb2Z0d2FyZVwiKSwgdG8gZGVhbCBpbiB0aGUgU29mdHdhcmUgd2l0aG91dCByZXN0cmljdGlvbiwgaW5jbHVkaW5nIHdpdGhvdXQgbGltaXRhdGlvbiB0aGUgcmlnaHRzIHRvIHVzZSwgY29weSwgbW9kaWZ5LCBtZXJnZSwgcHVibGlzaCwgZGlzdHJpYnV0ZSwgc3VibGljZW5zZSwgYW5kL29yIHNlbGwgY29waWVzIG9mIHRoZSBTb2Z0d2FyZSwgYW5kIHRvIHBlcm1pdCBwZXJzb25zIHRvIHdob20gdGhlIFNvZnR3YXJlIGlzIGZ1cm5pc2hlZCB0byBkbyBzbywgc3ViamVjdCB0byB0aGUgZm9sbG93aW5nIGNvbmRpdGlvbnM6XG5cblRoZSBhYm92ZSBjb3B5cmlnaHQgbm90aWNlIGFuZCB0aGlzIHBlcm1pc3Npb24gbm90aWNlIHNoYWxsIGJlIGluY2x1ZGVkIGluIGFsbCBjb3BpZXMgb3Igc3Vic3RhbnRpYWwgcG9ydGlvbnMgb2YgdGhlIFNvZnR3YXJlLlxuXG5USEUgU09GVFdBUkUgSVMgUFJPVklERUQgXCJBUyBJU1wiLCBXSVRIT1VUIFdBUlJBTlRZIE9GIEFOWSBLSU5ELCBFWFBSRVNTIE9SIElNUExJRUQsIElOQ0xVRElORyBCVVQgTk9UIExJTUlURUQgVE8gVEhFIFdBUlJBTlRJRVMgT0YgTUVSQ0hBTlRBQklMSVRZLCBGSVRORVNTIEZPUiBBIFBBUlRJQ1VMQVIgUFVSUE9TRSBBTkQgTk9OSU5GUklOR0VNRU5ULiBJTiBOTyBFVkVOVCBTSEFMTCBUSEUgQVVUSE9SUyBPUiBDT1BZUklHSFQgSE9MREVSUyBCRSBMSUFCTEUgRk9SIEFOWSBDTEFJTSwgREFNQUdFUyBPUiBPVEhFUiBMSUFCSUxJVFksIFdIRVRIRVIgSU4gQU4gQUNUSU9OIE9GIENPTlRSQUNULCBUT1JUIE9SIE9USEVSV0lTRSwgQVJJU0lORyBGUk9NLCBPVVQgT0YgT1IgSU4gQ09OTkVDVElPTiBXSVRIIFRIRSBTT0ZUV0FSRSBPUiBUSEUgVVNFIE9SIE9USEVSIERFQUxJTkdTIElOIFRIRSBTT0ZUV0FSRS5cbiovIFwidXNlIHN0cmljdFwiO1xuT2JqZWN0LmRlZmluZVByb3BlcnR5KGV4cG9ydHMsIFwiX19lc01vZHVsZVwiLCB7XG4gICAgdmFsdWU6IHRydWVcbn0pO1xuT2JqZWN0LmRlZmluZVByb3BlcnR5KGV4cG9ydHMsIFwiZGVmYXVsdFwiLCB7XG4gICAgZW51bWVyYWJsZTogdHJ1ZSxcbiAgICBnZXQ6IGZ1bmN0aW9uKCkge1xuICAgICAgICByZXR1cm4gcHJldHR5Qnl0ZXM7XG4gICAgfVxufSk7XG5jb25zdCBVTklUUyA9IFtcbiAgICBcIkJcIixcbiAgICBcImtCXCIsXG4gICAgXCJNQlwiLFxuICAgIFwiR0JcIixcbiAgICBcIlRCXCIsXG4gICAgXCJQQlwiLFxuICAgIFwiRUJcIixcbiAgICBcIlpCXCIsXG4gICAgXCJZQlwiXG5dO1xuLypcbkZvcm1hdHMgdGhlIGdpdmVuIG51bWJlciB1c2luZyBgTnVtYmVyI3RvTG9jYWxlU3RyaW5nYC5cbi0gSWYgbG9jYWxlIGlzIGEgc3RyaW5nLCB0aGUgdmFsdWUgaXMgZXhwZWN0ZWQgdG8gYmUgYSBsb2NhbGUta2V5IChmb3IgZXhhbXBsZTogYGRlYCkuXG4tIElmIGxvY2FsZSBpcyB0cnVlLCB0aGUgc3lzdGVtIGRlZmF1bHQgbG9jYWxlIGlzIHVzZWQgZm9yIHRyYW5zbGF0aW9uLlxuLSBJZiBubyB2YWx1ZSBmb3IgbG9jYWxlIGlzIHNwZWNpZmllZCwgdGhlIG51bWJlciBpcyByZXR1cm5lZCB1bm1vZGlmaWVkLlxuKi8gY29uc3QgdG9Mb2NhbGVTdHJpbmcgPSAobnVtYmVyLCBsb2NhbGUpPT57XG4gICAgbGV0IHJlc3VsdCA9IG51bWJlcjtcbiAgICBpZiAodHlwZW9mIGxvY2FsZSA9PT0gXCJzdHJpbmdcIikge1xuICAgICAgICByZXN1bHQgPSBudW1iZXIudG9Mb2NhbGVTdHJpbmcobG9jYWxlKTtcbiAgICB9IGVsc2UgaWYgKGxvY2FsZSA9PT0gdHJ1ZSkge1xuICAgICAgICByZXN1bHQgPSBudW1iZXIudG9Mb2NhbGVTdHJpbmcoKTtcbiAgICB9XG4gICAgcmV0dXJuIHJlc3VsdDtcbn07XG5mdW5jdGlvbiBwcmV0dHlCeXRlcyhudW1iZXIsIG9wdGlvbnMpIHtcbiAgICBpZiAoIU51bWJlci5pc0Zpbml0ZShudW1iZXIpKSB7XG4gICAgICAgIHRocm93IG5ldyBUeXBlRXJyb3IoYEV4cGVjdGVkIGEgZmluaXRlIG51bWJlciwgZ290ICR7dHlwZW9mIG51bWJlcn06ICR7bnVtYmVyfWApO1xuICAgIH1cbiAgICBvcHRpb25zID0gT2JqZWN0LmFzc2lnbih7fSwgb3B0aW9ucyk7XG4gICAgaWYgKG9wdGlvbnMuc2lnbmVkICYmIG51bWJlciA9PT0gMCkge1xuICAgICAgICByZXR1cm4gXCIgMCBCXCI7XG4gICAgfVxuICAgIGNvbnN0IGlzTmVnYXRpdmUgPSBudW1iZXIgPCAwO1xuICAgIGNvbnN0IHByZWZpeCA9IGlzTmVnYXRpdmUgPyBcIi1cIiA6IG9wdGlvbnMuc2lnbmVkID8gXCIrXCIgOiBcIlwiO1xuICAgIGlmIChpc05lZ2F0aXZlKSB7XG4gICAgICAgIG51bWJlciA9IC1udW1iZXI7XG4gICAgfVxuICAgIGlmIChudW1iZXIgPCAxKSB7XG4gICAgICAgIGNvbnN0IG51bWJlclN0cmluZyA9IHRvTG9jYWxlU3RyaW5nKG51bWJlciwgb3B0aW9ucy5sb2NhbGUpO1xuICAgICAgICByZXR1cm4gcHJlZml4ICsgbnVtYmVyU3RyaW5nICsgXCIgQlwiO1xuICAgIH1cbiAgICBjb25zdCBleHBvbmVudCA9IE1hdGgubWluKE1hdGguZmxvb3IoTWF0aC5sb2cxMChudW1iZXIpIC8gMyksIFVOSVRTLmxlbmd0aCAtIDEpO1xuICAgIG51bWJlciA9IE51bWJlcigobnVtYmVyIC8gTWF0aC5wb3coMTAwMCwgZXhwb25lbnQpKS50b1ByZWNpc2lvbigzKSk7XG4gICAgY29uc3QgbnVtYmVyU3RyaW5nID0gdG9Mb2NhbGVTdHJpbmcobnVtYmVyLCBvcHRpb25zLmxvY2FsZSk7XG4gICAgY29uc3QgdW5pdCA9IFVOSVRTW2V4cG9uZW50XTtcbiAgICByZXR1cm4gcHJlZml4ICsgbnVtYmVyU3RyaW5nICsgXCIgXCIgKyB1bml0O1xufVxuXG4vLyMgc291cmNlTWFwcGluZ1VSTD1wcmV0dHktYnl0ZXMuanMubWFwIl0sIm5hbWVzIjpbXSwic291cmNlUm9vdCI6IiJ9\n//# sourceURL=webpack-internal:///./node_modules/next/dist/lib/pretty-bytes.js\n");
-
-/***/ }),
-
-/***/ "./node_modules/next/dist/server/future/helpers/interception-routes.js":
-/*!*****************************************************************************!*\
-  !*** ./node_modules/next/dist/server/future/helpers/interception-routes.js ***!
-  \*****************************************************************************/
-/***/ ((__unused_webpack_module, exports, __webpack_require__) => {
-
-eval("\nObject.defineProperty(exports, \"__esModule\", ({\n    value: true\n}));\n0 && (0);\nfunction _export(target, all) {\n    for(var name in all)Object.defineProperty(target, name, {\n        enumerable: true,\n        get: all[name]\n    });\n}\n_export(exports, {\n    INTERCEPTION_ROUTE_MARKERS: function() {\n        return INTERCEPTION_ROUTE_MARKERS;\n    },\n    extractInterceptionRouteInformation: function() {\n        return extractInterceptionRouteInformation;\n    },\n    isInterceptionRouteAppPath: function() {\n        return isInterceptionRouteAppPath;\n    }\n});\nconst _apppaths = __webpack_require__(/*! ../../../shared/lib/router/utils/app-paths */ \"./node_modules/next/dist/shared/lib/router/utils/app-paths.js\");\nconst INTERCEPTION_ROUTE_MARKERS = [\n    \"(..)(..)\",\n    \"(.)\",\n    \"(..)\",\n    \"(...)\"\n];\nfunction isInterceptionRouteAppPath(path) {\n    // TODO-APP: add more serious validation\n    return path.split(\"/\").find((segment)=>INTERCEPTION_ROUTE_MARKERS.find((m)=>segment.startsWith(m))) !== undefined;\n}\nfunction extractInterceptionRouteInformation(path) {\n    let interceptingRoute, marker, interceptedRoute;\n    for (const segment of path.split(\"/\")){\n        marker = INTERCEPTION_ROUTE_MARKERS.find((m)=>segment.startsWith(m));\n        if (marker) {\n            [interceptingRoute, interceptedRoute] = path.split(marker, 2);\n            break;\n        }\n    }\n    if (!interceptingRoute || !marker || !interceptedRoute) {\n        throw new Error(`Invalid interception route: ${path}. Must be in the format /<intercepting route>/(..|...|..)(..)/<intercepted route>`);\n    }\n    interceptingRoute = (0, _apppaths.normalizeAppPath)(interceptingRoute) // normalize the path, e.g. /(blog)/feed -> /feed\n    ;\n    switch(marker){\n        case \"(.)\":\n            // (.) indicates that we should match with sibling routes, so we just need to append the intercepted route to the intercepting route\n            if (interceptingRoute === \"/\") {\n                interceptedRoute = `/${interceptedRoute}`;\n            } else {\n                interceptedRoute = interceptingRoute + \"/\" + interceptedRoute;\n            }\n            break;\n        case \"(..)\":\n            // (..) indicates that we should match at one level up, so we need to remove the last segment of the intercepting route\n            if (interceptingRoute === \"/\") {\n                throw new Error(`Invalid interception route: ${path}. Cannot use (..) marker at the root level, use (.) instead.`);\n            }\n            interceptedRoute = interceptingRoute.split(\"/\").slice(0, -1).concat(interceptedRoute).join(\"/\");\n            break;\n        case \"(...)\":\n            // (...) will match the route segment in the root directory, so we need to use the root directory to prepend the intercepted route\n            interceptedRoute = \"/\" + interceptedRoute;\n            break;\n        case \"(..)(..)\":\n            // (..)(..) indicates that we should match at two levels up, so we need to remove the last two segments of the intercepting route\n            const splitInterceptingRoute = interceptingRoute.split(\"/\");\n            if (splitInterceptingRoute.length <= 2) {\n                throw new Error(`Invalid interception route: ${path}. Cannot use (..)(..) marker at the root level or one level up.`);\n            }\n            interceptedRoute = splitInterceptingRoute.slice(0, -2).concat(interceptedRoute).join(\"/\");\n            break;\n        default:\n            throw new Error(\"Invariant: unexpected marker\");\n    }\n    return {\n        interceptingRoute,\n        interceptedRoute\n    };\n}\n\n//# sourceMappingURL=interception-routes.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvbmV4dC9kaXN0L3NlcnZlci9mdXR1cmUvaGVscGVycy9pbnRlcmNlcHRpb24tcm91dGVzLmpzIiwibWFwcGluZ3MiOiJBQUFhO0FBQ2IsOENBQTZDO0FBQzdDO0FBQ0EsQ0FBQyxFQUFDO0FBQ0YsTUFBTSxDQUlMO0FBQ0Q7QUFDQTtBQUNBO0FBQ0E7QUFDQSxLQUFLO0FBQ0w7QUFDQTtBQUNBO0FBQ0E7QUFDQSxLQUFLO0FBQ0w7QUFDQTtBQUNBLEtBQUs7QUFDTDtBQUNBO0FBQ0E7QUFDQSxDQUFDO0FBQ0Qsa0JBQWtCLG1CQUFPLENBQUMsaUhBQTRDO0FBQ3RFO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQSx1REFBdUQsS0FBSztBQUM1RDtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBLHVDQUF1QyxpQkFBaUI7QUFDeEQsY0FBYztBQUNkO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBLCtEQUErRCxLQUFLO0FBQ3BFO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQSwrREFBK0QsS0FBSztBQUNwRTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBOztBQUVBIiwic291cmNlcyI6WyJ3ZWJwYWNrOi8vdW5pdmVyc2FsLW5mdC13ZWIvLi9ub2RlX21vZHVsZXMvbmV4dC9kaXN0L3NlcnZlci9mdXR1cmUvaGVscGVycy9pbnRlcmNlcHRpb24tcm91dGVzLmpzPzM1Y2MiXSwic291cmNlc0NvbnRlbnQiOlsiXCJ1c2Ugc3RyaWN0XCI7XG5PYmplY3QuZGVmaW5lUHJvcGVydHkoZXhwb3J0cywgXCJfX2VzTW9kdWxlXCIsIHtcbiAgICB2YWx1ZTogdHJ1ZVxufSk7XG4wICYmIChtb2R1bGUuZXhwb3J0cyA9IHtcbiAgICBJTlRFUkNFUFRJT05fUk9VVEVfTUFSS0VSUzogbnVsbCxcbiAgICBleHRyYWN0SW50ZXJjZXB0aW9uUm91dGVJbmZvcm1hdGlvbjogbnVsbCxcbiAgICBpc0ludGVyY2VwdGlvblJvdXRlQXBwUGF0aDogbnVsbFxufSk7XG5mdW5jdGlvbiBfZXhwb3J0KHRhcmdldCwgYWxsKSB7XG4gICAgZm9yKHZhciBuYW1lIGluIGFsbClPYmplY3QuZGVmaW5lUHJvcGVydHkodGFyZ2V0LCBuYW1lLCB7XG4gICAgICAgIGVudW1lcmFibGU6IHRydWUsXG4gICAgICAgIGdldDogYWxsW25hbWVdXG4gICAgfSk7XG59XG5fZXhwb3J0KGV4cG9ydHMsIHtcbiAgICBJTlRFUkNFUFRJT05fUk9VVEVfTUFSS0VSUzogZnVuY3Rpb24oKSB7XG4gICAgICAgIHJldHVybiBJTlRFUkNFUFRJT05fUk9VVEVfTUFSS0VSUztcbiAgICB9LFxuICAgIGV4dHJhY3RJbnRlcmNlcHRpb25Sb3V0ZUluZm9ybWF0aW9uOiBmdW5jdGlvbigpIHtcbiAgICAgICAgcmV0dXJuIGV4dHJhY3RJbnRlcmNlcHRpb25Sb3V0ZUluZm9ybWF0aW9uO1xuICAgIH0sXG4gICAgaXNJbnRlcmNlcHRpb25Sb3V0ZUFwcFBhdGg6IGZ1bmN0aW9uKCkge1xuICAgICAgICByZXR1cm4gaXNJbnRlcmNlcHRpb25Sb3V0ZUFwcFBhdGg7XG4gICAgfVxufSk7XG5jb25zdCBfYXBwcGF0aHMgPSByZXF1aXJlKFwiLi4vLi4vLi4vc2hhcmVkL2xpYi9yb3V0ZXIvdXRpbHMvYXBwLXBhdGhzXCIpO1xuY29uc3QgSU5URVJDRVBUSU9OX1JPVVRFX01BUktFUlMgPSBbXG4gICAgXCIoLi4pKC4uKVwiLFxuICAgIFwiKC4pXCIsXG4gICAgXCIoLi4pXCIsXG4gICAgXCIoLi4uKVwiXG5dO1xuZnVuY3Rpb24gaXNJbnRlcmNlcHRpb25Sb3V0ZUFwcFBhdGgocGF0aCkge1xuICAgIC8vIFRPRE8tQVBQOiBhZGQgbW9yZSBzZXJpb3VzIHZhbGlkYXRpb25cbiAgICByZXR1cm4gcGF0aC5zcGxpdChcIi9cIikuZmluZCgoc2VnbWVudCk9PklOVEVSQ0VQVElPTl9ST1VURV9NQVJLRVJTLmZpbmQoKG0pPT5zZWdtZW50LnN0YXJ0c1dpdGgobSkpKSAhPT0gdW5kZWZpbmVkO1xufVxuZnVuY3Rpb24gZXh0cmFjdEludGVyY2VwdGlvblJvdXRlSW5mb3JtYXRpb24ocGF0aCkge1xuICAgIGxldCBpbnRlcmNlcHRpbmdSb3V0ZSwgbWFya2VyLCBpbnRlcmNlcHRlZFJvdXRlO1xuICAgIGZvciAoY29uc3Qgc2VnbWVudCBvZiBwYXRoLnNwbGl0KFwiL1wiKSl7XG4gICAgICAgIG1hcmtlciA9IElOVEVSQ0VQVElPTl9ST1VURV9NQVJLRVJTLmZpbmQoKG0pPT5zZWdtZW50LnN0YXJ0c1dpdGgobSkpO1xuICAgICAgICBpZiAobWFya2VyKSB7XG4gICAgICAgICAgICBbaW50ZXJjZXB0aW5nUm91dGUsIGludGVyY2VwdGVkUm91dGVdID0gcGF0aC5zcGxpdChtYXJrZXIsIDIpO1xuICAgICAgICAgICAgYnJlYWs7XG4gICAgICAgIH1cbiAgICB9XG4gICAgaWYgKCFpbnRlcmNlcHRpbmdSb3V0ZSB8fCAhbWFya2VyIHx8ICFpbnRlcmNlcHRlZFJvdXRlKSB7XG4gICAgICAgIHRocm93IG5ldyBFcnJvcihgSW52YWxpZCBpbnRlcmNlcHRpb24gcm91dGU6ICR7cGF0aH0uIE11c3QgYmUgaW4gdGhlIGZvcm1hdCAvPGludGVyY2VwdGluZyByb3V0ZT4vKC4ufC4uLnwuLikoLi4pLzxpbnRlcmNlcHRlZCByb3V0ZT5gKTtcbiAgICB9XG4gICAgaW50ZXJjZXB0aW5nUm91dGUgPSAoMCwgX2FwcHBhdGhzLm5vcm1hbGl6ZUFwcFBhdGgpKGludGVyY2VwdGluZ1JvdXRlKSAvLyBub3JtYWxpemUgdGhlIHBhdGgsIGUuZy4gLyhibG9nKS9mZWVkIC0+IC9mZWVkXG4gICAgO1xuICAgIHN3aXRjaChtYXJrZXIpe1xuICAgICAgICBjYXNlIFwiKC4pXCI6XG4gICAgICAgICAgICAvLyAoLikgaW5kaWNhdGVzIHRoYXQgd2Ugc2hvdWxkIG1hdGNoIHdpdGggc2libGluZyByb3V0ZXMsIHNvIHdlIGp1c3QgbmVlZCB0byBhcHBlbmQgdGhlIGludGVyY2VwdGVkIHJvdXRlIHRvIHRoZSBpbnRlcmNlcHRpbmcgcm91dGVcbiAgICAgICAgICAgIGlmIChpbnRlcmNlcHRpbmdSb3V0ZSA9PT0gXCIvXCIpIHtcbiAgICAgICAgICAgICAgICBpbnRlcmNlcHRlZFJvdXRlID0gYC8ke2ludGVyY2VwdGVkUm91dGV9YDtcbiAgICAgICAgICAgIH0gZWxzZSB7XG4gICAgICAgICAgICAgICAgaW50ZXJjZXB0ZWRSb3V0ZSA9IGludGVyY2VwdGluZ1JvdXRlICsgXCIvXCIgKyBpbnRlcmNlcHRlZFJvdXRlO1xuICAgICAgICAgICAgfVxuICAgICAgICAgICAgYnJlYWs7XG4gICAgICAgIGNhc2UgXCIoLi4pXCI6XG4gICAgICAgICAgICAvLyAoLi4pIGluZGljYXRlcyB0aGF0IHdlIHNob3VsZCBtYXRjaCBhdCBvbmUgbGV2ZWwgdXAsIHNvIHdlIG5lZWQgdG8gcmVtb3ZlIHRoZSBsYXN0IHNlZ21lbnQgb2YgdGhlIGludGVyY2VwdGluZyByb3V0ZVxuICAgICAgICAgICAgaWYgKGludGVyY2VwdGluZ1JvdXRlID09PSBcIi9cIikge1xuICAgICAgICAgICAgICAgIHRocm93IG5ldyBFcnJvcihgSW52YWxpZCBpbnRlcmNlcHRpb24gcm91dGU6ICR7cGF0aH0uIENhbm5vdCB1c2UgKC4uKSBtYXJrZXIgYXQgdGhlIHJvb3QgbGV2ZWwsIHVzZSAoLikgaW5zdGVhZC5gKTtcbiAgICAgICAgICAgIH1cbiAgICAgICAgICAgIGludGVyY2VwdGVkUm91dGUgPSBpbnRlcmNlcHRpbmdSb3V0ZS5zcGxpdChcIi9cIikuc2xpY2UoMCwgLTEpLmNvbmNhdChpbnRlcmNlcHRlZFJvdXRlKS5qb2luKFwiL1wiKTtcbiAgICAgICAgICAgIGJyZWFrO1xuICAgICAgICBjYXNlIFwiKC4uLilcIjpcbiAgICAgICAgICAgIC8vICguLi4pIHdpbGwgbWF0Y2ggdGhlIHJvdXRlIHNlZ21lbnQgaW4gdGhlIHJvb3QgZGlyZWN0b3J5LCBzbyB3ZSBuZWVkIHRvIHVzZSB0aGUgcm9vdCBkaXJlY3RvcnkgdG8gcHJlcGVuZCB0aGUgaW50ZXJjZXB0ZWQgcm91dGVcbiAgICAgICAgICAgIGludGVyY2VwdGVkUm91dGUgPSBcIi9cIiArIGludGVyY2VwdGVkUm91dGU7XG4gICAgICAgICAgICBicmVhaztcbiAgICAgICAgY2FzZSBcIiguLikoLi4pXCI6XG4gICAgICAgICAgICAvLyAoLi4pKC4uKSBpbmRpY2F0ZXMgdGhhdCB3ZSBzaG91bGQgbWF0Y2ggYXQgdHdvIGxldmVscyB1cCwgc28gd2UgbmVlZCB0byByZW1vdmUgdGhlIGxhc3QgdHdvIHNlZ21lbnRzIG9mIHRoZSBpbnRlcmNlcHRpbmcgcm91dGVcbiAgICAgICAgICAgIGNvbnN0IHNwbGl0SW50ZXJjZXB0aW5nUm91dGUgPSBpbnRlcmNlcHRpbmdSb3V0ZS5zcGxpdChcIi9cIik7XG4gICAgICAgICAgICBpZiAoc3BsaXRJbnRlcmNlcHRpbmdSb3V0ZS5sZW5ndGggPD0gMikge1xuICAgICAgICAgICAgICAgIHRocm93IG5ldyBFcnJvcihgSW52YWxpZCBpbnRlcmNlcHRpb24gcm91dGU6ICR7cGF0aH0uIENhbm5vdCB1c2UgKC4uKSguLikgbWFya2VyIGF0IHRoZSByb290IGxldmVsIG9yIG9uZSBsZXZlbCB1cC5gKTtcbiAgICAgICAgICAgIH1cbiAgICAgICAgICAgIGludGVyY2VwdGVkUm91dGUgPSBzcGxpdEludGVyY2VwdGluZ1JvdXRlLnNsaWNlKDAsIC0yKS5jb25jYXQoaW50ZXJjZXB0ZWRSb3V0ZSkuam9pbihcIi9cIik7XG4gICAgICAgICAgICBicmVhaztcbiAgICAgICAgZGVmYXVsdDpcbiAgICAgICAgICAgIHRocm93IG5ldyBFcnJvcihcIkludmFyaWFudDogdW5leHBlY3RlZCBtYXJrZXJcIik7XG4gICAgfVxuICAgIHJldHVybiB7XG4gICAgICAgIGludGVyY2VwdGluZ1JvdXRlLFxuICAgICAgICBpbnRlcmNlcHRlZFJvdXRlXG4gICAgfTtcbn1cblxuLy8jIHNvdXJjZU1hcHBpbmdVUkw9aW50ZXJjZXB0aW9uLXJvdXRlcy5qcy5tYXAiXSwibmFtZXMiOltdLCJzb3VyY2VSb290IjoiIn0=\n//# sourceURL=webpack-internal:///./node_modules/next/dist/server/future/helpers/interception-routes.js\n");
-
-/***/ }),
-
-/***/ "./node_modules/next/dist/server/future/route-kind.js":
-/*!************************************************************!*\
-  !*** ./node_modules/next/dist/server/future/route-kind.js ***!
-  \************************************************************/
-/***/ ((__unused_webpack_module, exports) => {
-
-eval("\nObject.defineProperty(exports, \"__esModule\", ({\n    value: true\n}));\nObject.defineProperty(exports, \"RouteKind\", ({\n    enumerable: true,\n    get: function() {\n        return RouteKind;\n    }\n}));\nvar RouteKind;\n(function(RouteKind) {\n    /**\n   * `PAGES` represents all the React pages that are under `pages/`.\n   */ RouteKind[\"PAGES\"] = \"PAGES\";\n    /**\n   * `PAGES_API` represents all the API routes under `pages/api/`.\n   */ RouteKind[\"PAGES_API\"] = \"PAGES_API\";\n    /**\n   * `APP_PAGE` represents all the React pages that are under `app/` with the\n   * filename of `page.{j,t}s{,x}`.\n   */ RouteKind[\"APP_PAGE\"] = \"APP_PAGE\";\n    /**\n   * `APP_ROUTE` represents all the API routes and metadata routes that are under `app/` with the\n   * filename of `route.{j,t}s{,x}`.\n   */ RouteKind[\"APP_ROUTE\"] = \"APP_ROUTE\";\n})(RouteKind || (RouteKind = {}));\n\n//# sourceMappingURL=route-kind.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvbmV4dC9kaXN0L3NlcnZlci9mdXR1cmUvcm91dGUta2luZC5qcyIsIm1hcHBpbmdzIjoiQUFBYTtBQUNiLDhDQUE2QztBQUM3QztBQUNBLENBQUMsRUFBQztBQUNGLDZDQUE0QztBQUM1QztBQUNBO0FBQ0E7QUFDQTtBQUNBLENBQUMsRUFBQztBQUNGO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0Esd0JBQXdCLElBQUksRUFBRSxHQUFHO0FBQ2pDO0FBQ0E7QUFDQTtBQUNBLHlCQUF5QixJQUFJLEVBQUUsR0FBRztBQUNsQztBQUNBLENBQUMsOEJBQThCOztBQUUvQiIsInNvdXJjZXMiOlsid2VicGFjazovL3VuaXZlcnNhbC1uZnQtd2ViLy4vbm9kZV9tb2R1bGVzL25leHQvZGlzdC9zZXJ2ZXIvZnV0dXJlL3JvdXRlLWtpbmQuanM/Mzk3OSJdLCJzb3VyY2VzQ29udGVudCI6WyJcInVzZSBzdHJpY3RcIjtcbk9iamVjdC5kZWZpbmVQcm9wZXJ0eShleHBvcnRzLCBcIl9fZXNNb2R1bGVcIiwge1xuICAgIHZhbHVlOiB0cnVlXG59KTtcbk9iamVjdC5kZWZpbmVQcm9wZXJ0eShleHBvcnRzLCBcIlJvdXRlS2luZFwiLCB7XG4gICAgZW51bWVyYWJsZTogdHJ1ZSxcbiAgICBnZXQ6IGZ1bmN0aW9uKCkge1xuICAgICAgICByZXR1cm4gUm91dGVLaW5kO1xuICAgIH1cbn0pO1xudmFyIFJvdXRlS2luZDtcbihmdW5jdGlvbihSb3V0ZUtpbmQpIHtcbiAgICAvKipcbiAgICogYFBBR0VTYCByZXByZXNlbnRzIGFsbCB0aGUgUmVhY3QgcGFnZXMgdGhhdCBhcmUgdW5kZXIgYHBhZ2VzL2AuXG4gICAqLyBSb3V0ZUtpbmRbXCJQQUdFU1wiXSA9IFwiUEFHRVNcIjtcbiAgICAvKipcbiAgICogYFBBR0VTX0FQSWAgcmVwcmVzZW50cyBhbGwgdGhlIEFQSSByb3V0ZXMgdW5kZXIgYHBhZ2VzL2FwaS9gLlxuICAgKi8gUm91dGVLaW5kW1wiUEFHRVNfQVBJXCJdID0gXCJQQUdFU19BUElcIjtcbiAgICAvKipcbiAgICogYEFQUF9QQUdFYCByZXByZXNlbnRzIGFsbCB0aGUgUmVhY3QgcGFnZXMgdGhhdCBhcmUgdW5kZXIgYGFwcC9gIHdpdGggdGhlXG4gICAqIGZpbGVuYW1lIG9mIGBwYWdlLntqLHR9c3sseH1gLlxuICAgKi8gUm91dGVLaW5kW1wiQVBQX1BBR0VcIl0gPSBcIkFQUF9QQUdFXCI7XG4gICAgLyoqXG4gICAqIGBBUFBfUk9VVEVgIHJlcHJlc2VudHMgYWxsIHRoZSBBUEkgcm91dGVzIGFuZCBtZXRhZGF0YSByb3V0ZXMgdGhhdCBhcmUgdW5kZXIgYGFwcC9gIHdpdGggdGhlXG4gICAqIGZpbGVuYW1lIG9mIGByb3V0ZS57aix0fXN7LHh9YC5cbiAgICovIFJvdXRlS2luZFtcIkFQUF9ST1VURVwiXSA9IFwiQVBQX1JPVVRFXCI7XG59KShSb3V0ZUtpbmQgfHwgKFJvdXRlS2luZCA9IHt9KSk7XG5cbi8vIyBzb3VyY2VNYXBwaW5nVVJMPXJvdXRlLWtpbmQuanMubWFwIl0sIm5hbWVzIjpbXSwic291cmNlUm9vdCI6IiJ9\n//# sourceURL=webpack-internal:///./node_modules/next/dist/server/future/route-kind.js\n");
-
-/***/ }),
-
-/***/ "./node_modules/next/dist/server/future/route-modules/pages/module.compiled.js":
-/*!*************************************************************************************!*\
-  !*** ./node_modules/next/dist/server/future/route-modules/pages/module.compiled.js ***!
-  \*************************************************************************************/
-/***/ ((module, __unused_webpack_exports, __webpack_require__) => {
-
-eval("\nif (false) {} else {\n    if (true) {\n        module.exports = __webpack_require__(/*! next/dist/compiled/next-server/pages.runtime.dev.js */ \"next/dist/compiled/next-server/pages.runtime.dev.js\");\n    } else {}\n}\n\n//# sourceMappingURL=module.compiled.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvbmV4dC9kaXN0L3NlcnZlci9mdXR1cmUvcm91dGUtbW9kdWxlcy9wYWdlcy9tb2R1bGUuY29tcGlsZWQuanMiLCJtYXBwaW5ncyI6IkFBQWE7QUFDYixJQUFJLEtBQW1DLEVBQUUsRUFFeEMsQ0FBQztBQUNGLFFBQVEsSUFBc0M7QUFDOUMsUUFBUSxzSkFBK0U7QUFDdkYsTUFBTSxLQUFLLEVBSU47QUFDTDs7QUFFQSIsInNvdXJjZXMiOlsid2VicGFjazovL3VuaXZlcnNhbC1uZnQtd2ViLy4vbm9kZV9tb2R1bGVzL25leHQvZGlzdC9zZXJ2ZXIvZnV0dXJlL3JvdXRlLW1vZHVsZXMvcGFnZXMvbW9kdWxlLmNvbXBpbGVkLmpzP2JhNGYiXSwic291cmNlc0NvbnRlbnQiOlsiXCJ1c2Ugc3RyaWN0XCI7XG5pZiAocHJvY2Vzcy5lbnYuTkVYVF9SVU5USU1FID09PSBcImVkZ2VcIikge1xuICAgIG1vZHVsZS5leHBvcnRzID0gcmVxdWlyZShcIm5leHQvZGlzdC9zZXJ2ZXIvZnV0dXJlL3JvdXRlLW1vZHVsZXMvcGFnZXMvbW9kdWxlLmpzXCIpO1xufSBlbHNlIHtcbiAgICBpZiAocHJvY2Vzcy5lbnYuTk9ERV9FTlYgPT09IFwiZGV2ZWxvcG1lbnRcIikge1xuICAgICAgICBtb2R1bGUuZXhwb3J0cyA9IHJlcXVpcmUoXCJuZXh0L2Rpc3QvY29tcGlsZWQvbmV4dC1zZXJ2ZXIvcGFnZXMucnVudGltZS5kZXYuanNcIik7XG4gICAgfSBlbHNlIGlmIChwcm9jZXNzLmVudi5UVVJCT1BBQ0spIHtcbiAgICAgICAgbW9kdWxlLmV4cG9ydHMgPSByZXF1aXJlKFwibmV4dC9kaXN0L2NvbXBpbGVkL25leHQtc2VydmVyL3BhZ2VzLXR1cmJvLnJ1bnRpbWUucHJvZC5qc1wiKTtcbiAgICB9IGVsc2Uge1xuICAgICAgICBtb2R1bGUuZXhwb3J0cyA9IHJlcXVpcmUoXCJuZXh0L2Rpc3QvY29tcGlsZWQvbmV4dC1zZXJ2ZXIvcGFnZXMucnVudGltZS5wcm9kLmpzXCIpO1xuICAgIH1cbn1cblxuLy8jIHNvdXJjZU1hcHBpbmdVUkw9bW9kdWxlLmNvbXBpbGVkLmpzLm1hcCJdLCJuYW1lcyI6W10sInNvdXJjZVJvb3QiOiIifQ==\n//# sourceURL=webpack-internal:///./node_modules/next/dist/server/future/route-modules/pages/module.compiled.js\n");
-
-/***/ }),
-
-/***/ "./node_modules/next/dist/server/future/route-modules/pages/vendored/contexts/amp-context.js":
-/*!***************************************************************************************************!*\
-  !*** ./node_modules/next/dist/server/future/route-modules/pages/vendored/contexts/amp-context.js ***!
-  \***************************************************************************************************/
-/***/ ((module, __unused_webpack_exports, __webpack_require__) => {
-
-eval("\nmodule.exports = __webpack_require__(/*! ../../module.compiled */ \"./node_modules/next/dist/server/future/route-modules/pages/module.compiled.js\").vendored.contexts.AmpContext;\n\n//# sourceMappingURL=amp-context.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvbmV4dC9kaXN0L3NlcnZlci9mdXR1cmUvcm91dGUtbW9kdWxlcy9wYWdlcy92ZW5kb3JlZC9jb250ZXh0cy9hbXAtY29udGV4dC5qcyIsIm1hcHBpbmdzIjoiQUFBYTtBQUNiLCtLQUFpRjs7QUFFakYiLCJzb3VyY2VzIjpbIndlYnBhY2s6Ly91bml2ZXJzYWwtbmZ0LXdlYi8uL25vZGVfbW9kdWxlcy9uZXh0L2Rpc3Qvc2VydmVyL2Z1dHVyZS9yb3V0ZS1tb2R1bGVzL3BhZ2VzL3ZlbmRvcmVkL2NvbnRleHRzL2FtcC1jb250ZXh0LmpzP2EwNTUiXSwic291cmNlc0NvbnRlbnQiOlsiXCJ1c2Ugc3RyaWN0XCI7XG5tb2R1bGUuZXhwb3J0cyA9IHJlcXVpcmUoXCIuLi8uLi9tb2R1bGUuY29tcGlsZWRcIikudmVuZG9yZWRbXCJjb250ZXh0c1wiXS5BbXBDb250ZXh0O1xuXG4vLyMgc291cmNlTWFwcGluZ1VSTD1hbXAtY29udGV4dC5qcy5tYXAiXSwibmFtZXMiOltdLCJzb3VyY2VSb290IjoiIn0=\n//# sourceURL=webpack-internal:///./node_modules/next/dist/server/future/route-modules/pages/vendored/contexts/amp-context.js\n");
-
-/***/ }),
-
-/***/ "./node_modules/next/dist/server/future/route-modules/pages/vendored/contexts/head-manager-context.js":
-/*!************************************************************************************************************!*\
-  !*** ./node_modules/next/dist/server/future/route-modules/pages/vendored/contexts/head-manager-context.js ***!
-  \************************************************************************************************************/
-/***/ ((module, __unused_webpack_exports, __webpack_require__) => {
-
-eval("\nmodule.exports = __webpack_require__(/*! ../../module.compiled */ \"./node_modules/next/dist/server/future/route-modules/pages/module.compiled.js\").vendored.contexts.HeadManagerContext;\n\n//# sourceMappingURL=head-manager-context.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvbmV4dC9kaXN0L3NlcnZlci9mdXR1cmUvcm91dGUtbW9kdWxlcy9wYWdlcy92ZW5kb3JlZC9jb250ZXh0cy9oZWFkLW1hbmFnZXItY29udGV4dC5qcyIsIm1hcHBpbmdzIjoiQUFBYTtBQUNiLHVMQUF5Rjs7QUFFekYiLCJzb3VyY2VzIjpbIndlYnBhY2s6Ly91bml2ZXJzYWwtbmZ0LXdlYi8uL25vZGVfbW9kdWxlcy9uZXh0L2Rpc3Qvc2VydmVyL2Z1dHVyZS9yb3V0ZS1tb2R1bGVzL3BhZ2VzL3ZlbmRvcmVkL2NvbnRleHRzL2hlYWQtbWFuYWdlci1jb250ZXh0LmpzP2FkYzkiXSwic291cmNlc0NvbnRlbnQiOlsiXCJ1c2Ugc3RyaWN0XCI7XG5tb2R1bGUuZXhwb3J0cyA9IHJlcXVpcmUoXCIuLi8uLi9tb2R1bGUuY29tcGlsZWRcIikudmVuZG9yZWRbXCJjb250ZXh0c1wiXS5IZWFkTWFuYWdlckNvbnRleHQ7XG5cbi8vIyBzb3VyY2VNYXBwaW5nVVJMPWhlYWQtbWFuYWdlci1jb250ZXh0LmpzLm1hcCJdLCJuYW1lcyI6W10sInNvdXJjZVJvb3QiOiIifQ==\n//# sourceURL=webpack-internal:///./node_modules/next/dist/server/future/route-modules/pages/vendored/contexts/head-manager-context.js\n");
-
-/***/ }),
-
-/***/ "./node_modules/next/dist/server/future/route-modules/pages/vendored/contexts/html-context.js":
-/*!****************************************************************************************************!*\
-  !*** ./node_modules/next/dist/server/future/route-modules/pages/vendored/contexts/html-context.js ***!
-  \****************************************************************************************************/
-/***/ ((module, __unused_webpack_exports, __webpack_require__) => {
-
-eval("\nmodule.exports = __webpack_require__(/*! ../../module.compiled */ \"./node_modules/next/dist/server/future/route-modules/pages/module.compiled.js\").vendored.contexts.HtmlContext;\n\n//# sourceMappingURL=html-context.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvbmV4dC9kaXN0L3NlcnZlci9mdXR1cmUvcm91dGUtbW9kdWxlcy9wYWdlcy92ZW5kb3JlZC9jb250ZXh0cy9odG1sLWNvbnRleHQuanMiLCJtYXBwaW5ncyI6IkFBQWE7QUFDYixnTEFBa0Y7O0FBRWxGIiwic291cmNlcyI6WyJ3ZWJwYWNrOi8vdW5pdmVyc2FsLW5mdC13ZWIvLi9ub2RlX21vZHVsZXMvbmV4dC9kaXN0L3NlcnZlci9mdXR1cmUvcm91dGUtbW9kdWxlcy9wYWdlcy92ZW5kb3JlZC9jb250ZXh0cy9odG1sLWNvbnRleHQuanM/NGU4NSJdLCJzb3VyY2VzQ29udGVudCI6WyJcInVzZSBzdHJpY3RcIjtcbm1vZHVsZS5leHBvcnRzID0gcmVxdWlyZShcIi4uLy4uL21vZHVsZS5jb21waWxlZFwiKS52ZW5kb3JlZFtcImNvbnRleHRzXCJdLkh0bWxDb250ZXh0O1xuXG4vLyMgc291cmNlTWFwcGluZ1VSTD1odG1sLWNvbnRleHQuanMubWFwIl0sIm5hbWVzIjpbXSwic291cmNlUm9vdCI6IiJ9\n//# sourceURL=webpack-internal:///./node_modules/next/dist/server/future/route-modules/pages/vendored/contexts/html-context.js\n");
-
-/***/ }),
-
-/***/ "./node_modules/next/dist/server/get-page-files.js":
-/*!*********************************************************!*\
-  !*** ./node_modules/next/dist/server/get-page-files.js ***!
-  \*********************************************************/
-/***/ ((__unused_webpack_module, exports, __webpack_require__) => {
-
-eval("\nObject.defineProperty(exports, \"__esModule\", ({\n    value: true\n}));\nObject.defineProperty(exports, \"getPageFiles\", ({\n    enumerable: true,\n    get: function() {\n        return getPageFiles;\n    }\n}));\nconst _denormalizepagepath = __webpack_require__(/*! ../shared/lib/page-path/denormalize-page-path */ \"./node_modules/next/dist/shared/lib/page-path/denormalize-page-path.js\");\nconst _normalizepagepath = __webpack_require__(/*! ../shared/lib/page-path/normalize-page-path */ \"./node_modules/next/dist/shared/lib/page-path/normalize-page-path.js\");\nfunction getPageFiles(buildManifest, page) {\n    const normalizedPage = (0, _denormalizepagepath.denormalizePagePath)((0, _normalizepagepath.normalizePagePath)(page));\n    let files = buildManifest.pages[normalizedPage];\n    if (!files) {\n        console.warn(`Could not find files for ${normalizedPage} in .next/build-manifest.json`);\n        return [];\n    }\n    return files;\n}\n\n//# sourceMappingURL=get-page-files.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvbmV4dC9kaXN0L3NlcnZlci9nZXQtcGFnZS1maWxlcy5qcyIsIm1hcHBpbmdzIjoiQUFBYTtBQUNiLDhDQUE2QztBQUM3QztBQUNBLENBQUMsRUFBQztBQUNGLGdEQUErQztBQUMvQztBQUNBO0FBQ0E7QUFDQTtBQUNBLENBQUMsRUFBQztBQUNGLDZCQUE2QixtQkFBTyxDQUFDLDZIQUErQztBQUNwRiwyQkFBMkIsbUJBQU8sQ0FBQyx5SEFBNkM7QUFDaEY7QUFDQTtBQUNBO0FBQ0E7QUFDQSxpREFBaUQsZ0JBQWdCO0FBQ2pFO0FBQ0E7QUFDQTtBQUNBOztBQUVBIiwic291cmNlcyI6WyJ3ZWJwYWNrOi8vdW5pdmVyc2FsLW5mdC13ZWIvLi9ub2RlX21vZHVsZXMvbmV4dC9kaXN0L3NlcnZlci9nZXQtcGFnZS1maWxlcy5qcz9jODZiIl0sInNvdXJjZXNDb250ZW50IjpbIlwidXNlIHN0cmljdFwiO1xuT2JqZWN0LmRlZmluZVByb3BlcnR5KGV4cG9ydHMsIFwiX19lc01vZHVsZVwiLCB7XG4gICAgdmFsdWU6IHRydWVcbn0pO1xuT2JqZWN0LmRlZmluZVByb3BlcnR5KGV4cG9ydHMsIFwiZ2V0UGFnZUZpbGVzXCIsIHtcbiAgICBlbnVtZXJhYmxlOiB0cnVlLFxuICAgIGdldDogZnVuY3Rpb24oKSB7XG4gICAgICAgIHJldHVybiBnZXRQYWdlRmlsZXM7XG4gICAgfVxufSk7XG5jb25zdCBfZGVub3JtYWxpemVwYWdlcGF0aCA9IHJlcXVpcmUoXCIuLi9zaGFyZWQvbGliL3BhZ2UtcGF0aC9kZW5vcm1hbGl6ZS1wYWdlLXBhdGhcIik7XG5jb25zdCBfbm9ybWFsaXplcGFnZXBhdGggPSByZXF1aXJlKFwiLi4vc2hhcmVkL2xpYi9wYWdlLXBhdGgvbm9ybWFsaXplLXBhZ2UtcGF0aFwiKTtcbmZ1bmN0aW9uIGdldFBhZ2VGaWxlcyhidWlsZE1hbmlmZXN0LCBwYWdlKSB7XG4gICAgY29uc3Qgbm9ybWFsaXplZFBhZ2UgPSAoMCwgX2Rlbm9ybWFsaXplcGFnZXBhdGguZGVub3JtYWxpemVQYWdlUGF0aCkoKDAsIF9ub3JtYWxpemVwYWdlcGF0aC5ub3JtYWxpemVQYWdlUGF0aCkocGFnZSkpO1xuICAgIGxldCBmaWxlcyA9IGJ1aWxkTWFuaWZlc3QucGFnZXNbbm9ybWFsaXplZFBhZ2VdO1xuICAgIGlmICghZmlsZXMpIHtcbiAgICAgICAgY29uc29sZS53YXJuKGBDb3VsZCBub3QgZmluZCBmaWxlcyBmb3IgJHtub3JtYWxpemVkUGFnZX0gaW4gLm5leHQvYnVpbGQtbWFuaWZlc3QuanNvbmApO1xuICAgICAgICByZXR1cm4gW107XG4gICAgfVxuICAgIHJldHVybiBmaWxlcztcbn1cblxuLy8jIHNvdXJjZU1hcHBpbmdVUkw9Z2V0LXBhZ2UtZmlsZXMuanMubWFwIl0sIm5hbWVzIjpbXSwic291cmNlUm9vdCI6IiJ9\n//# sourceURL=webpack-internal:///./node_modules/next/dist/server/get-page-files.js\n");
-
-/***/ }),
-
-/***/ "./node_modules/next/dist/server/htmlescape.js":
-/*!*****************************************************!*\
-  !*** ./node_modules/next/dist/server/htmlescape.js ***!
-  \*****************************************************/
-/***/ ((__unused_webpack_module, exports) => {
-
-eval("// This utility is based on https://github.com/zertosh/htmlescape\n// License: https://github.com/zertosh/htmlescape/blob/0527ca7156a524d256101bb310a9f970f63078ad/LICENSE\n\nObject.defineProperty(exports, \"__esModule\", ({\n    value: true\n}));\n0 && (0);\nfunction _export(target, all) {\n    for(var name in all)Object.defineProperty(target, name, {\n        enumerable: true,\n        get: all[name]\n    });\n}\n_export(exports, {\n    ESCAPE_REGEX: function() {\n        return ESCAPE_REGEX;\n    },\n    htmlEscapeJsonString: function() {\n        return htmlEscapeJsonString;\n    }\n});\nconst ESCAPE_LOOKUP = {\n    \"&\": \"\\\\u0026\",\n    \">\": \"\\\\u003e\",\n    \"<\": \"\\\\u003c\",\n    \"\\u2028\": \"\\\\u2028\",\n    \"\\u2029\": \"\\\\u2029\"\n};\nconst ESCAPE_REGEX = /[&><\\u2028\\u2029]/g;\nfunction htmlEscapeJsonString(str) {\n    return str.replace(ESCAPE_REGEX, (match)=>ESCAPE_LOOKUP[match]);\n}\n\n//# sourceMappingURL=htmlescape.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvbmV4dC9kaXN0L3NlcnZlci9odG1sZXNjYXBlLmpzIiwibWFwcGluZ3MiOiJBQUFBO0FBQ0E7QUFDYTtBQUNiLDhDQUE2QztBQUM3QztBQUNBLENBQUMsRUFBQztBQUNGLE1BQU0sQ0FHTDtBQUNEO0FBQ0E7QUFDQTtBQUNBO0FBQ0EsS0FBSztBQUNMO0FBQ0E7QUFDQTtBQUNBO0FBQ0EsS0FBSztBQUNMO0FBQ0E7QUFDQTtBQUNBLENBQUM7QUFDRDtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBOztBQUVBIiwic291cmNlcyI6WyJ3ZWJwYWNrOi8vdW5pdmVyc2FsLW5mdC13ZWIvLi9ub2RlX21vZHVsZXMvbmV4dC9kaXN0L3NlcnZlci9odG1sZXNjYXBlLmpzP2QyMTYiXSwic291cmNlc0NvbnRlbnQiOlsiLy8gVGhpcyB1dGlsaXR5IGlzIGJhc2VkIG9uIGh0dHBzOi8vZ2l0aHViLmNvbS96ZXJ0b3NoL2h0bWxlc2NhcGVcbi8vIExpY2Vuc2U6IGh0dHBzOi8vZ2l0aHViLmNvbS96ZXJ0b3NoL2h0bWxlc2NhcGUvYmxvYi8wNTI3Y2E3MTU2YTUyNGQyNTYxMDFiYjMxMGE5Zjk3MGY2MzA3OGFkL0xJQ0VOU0VcblwidXNlIHN0cmljdFwiO1xuT2JqZWN0LmRlZmluZVByb3BlcnR5KGV4cG9ydHMsIFwiX19lc01vZHVsZVwiLCB7XG4gICAgdmFsdWU6IHRydWVcbn0pO1xuMCAmJiAobW9kdWxlLmV4cG9ydHMgPSB7XG4gICAgRVNDQVBFX1JFR0VYOiBudWxsLFxuICAgIGh0bWxFc2NhcGVKc29uU3RyaW5nOiBudWxsXG59KTtcbmZ1bmN0aW9uIF9leHBvcnQodGFyZ2V0LCBhbGwpIHtcbiAgICBmb3IodmFyIG5hbWUgaW4gYWxsKU9iamVjdC5kZWZpbmVQcm9wZXJ0eSh0YXJnZXQsIG5hbWUsIHtcbiAgICAgICAgZW51bWVyYWJsZTogdHJ1ZSxcbiAgICAgICAgZ2V0OiBhbGxbbmFtZV1cbiAgICB9KTtcbn1cbl9leHBvcnQoZXhwb3J0cywge1xuICAgIEVTQ0FQRV9SRUdFWDogZnVuY3Rpb24oKSB7XG4gICAgICAgIHJldHVybiBFU0NBUEVfUkVHRVg7XG4gICAgfSxcbiAgICBodG1sRXNjYXBlSnNvblN0cmluZzogZnVuY3Rpb24oKSB7XG4gICAgICAgIHJldHVybiBodG1sRXNjYXBlSnNvblN0cmluZztcbiAgICB9XG59KTtcbmNvbnN0IEVTQ0FQRV9MT09LVVAgPSB7XG4gICAgXCImXCI6IFwiXFxcXHUwMDI2XCIsXG4gICAgXCI+XCI6IFwiXFxcXHUwMDNlXCIsXG4gICAgXCI8XCI6IFwiXFxcXHUwMDNjXCIsXG4gICAgXCJcXHUyMDI4XCI6IFwiXFxcXHUyMDI4XCIsXG4gICAgXCJcXHUyMDI5XCI6IFwiXFxcXHUyMDI5XCJcbn07XG5jb25zdCBFU0NBUEVfUkVHRVggPSAvWyY+PFxcdTIwMjhcXHUyMDI5XS9nO1xuZnVuY3Rpb24gaHRtbEVzY2FwZUpzb25TdHJpbmcoc3RyKSB7XG4gICAgcmV0dXJuIHN0ci5yZXBsYWNlKEVTQ0FQRV9SRUdFWCwgKG1hdGNoKT0+RVNDQVBFX0xPT0tVUFttYXRjaF0pO1xufVxuXG4vLyMgc291cmNlTWFwcGluZ1VSTD1odG1sZXNjYXBlLmpzLm1hcCJdLCJuYW1lcyI6W10sInNvdXJjZVJvb3QiOiIifQ==\n//# sourceURL=webpack-internal:///./node_modules/next/dist/server/htmlescape.js\n");
-
-/***/ }),
-
-/***/ "./node_modules/next/dist/server/utils.js":
-/*!************************************************!*\
-  !*** ./node_modules/next/dist/server/utils.js ***!
-  \************************************************/
-/***/ ((__unused_webpack_module, exports, __webpack_require__) => {
-
-eval("\nObject.defineProperty(exports, \"__esModule\", ({\n    value: true\n}));\n0 && (0);\nfunction _export(target, all) {\n    for(var name in all)Object.defineProperty(target, name, {\n        enumerable: true,\n        get: all[name]\n    });\n}\n_export(exports, {\n    cleanAmpPath: function() {\n        return cleanAmpPath;\n    },\n    debounce: function() {\n        return debounce;\n    },\n    isBlockedPage: function() {\n        return isBlockedPage;\n    }\n});\nconst _constants = __webpack_require__(/*! ../shared/lib/constants */ \"./node_modules/next/dist/shared/lib/constants.js\");\nfunction isBlockedPage(page) {\n    return _constants.BLOCKED_PAGES.includes(page);\n}\nfunction cleanAmpPath(pathname) {\n    if (pathname.match(/\\?amp=(y|yes|true|1)/)) {\n        pathname = pathname.replace(/\\?amp=(y|yes|true|1)&?/, \"?\");\n    }\n    if (pathname.match(/&amp=(y|yes|true|1)/)) {\n        pathname = pathname.replace(/&amp=(y|yes|true|1)/, \"\");\n    }\n    pathname = pathname.replace(/\\?$/, \"\");\n    return pathname;\n}\nfunction debounce(fn, ms, maxWait = Infinity) {\n    let timeoutId;\n    // The time the debouncing function was first called during this debounce queue.\n    let startTime = 0;\n    // The time the debouncing function was last called.\n    let lastCall = 0;\n    // The arguments and this context of the last call to the debouncing function.\n    let args, context;\n    // A helper used to that either invokes the debounced function, or\n    // reschedules the timer if a more recent call was made.\n    function run() {\n        const now = Date.now();\n        const diff = lastCall + ms - now;\n        // If the diff is non-positive, then we've waited at least `ms`\n        // milliseconds since the last call. Or if we've waited for longer than the\n        // max wait time, we must call the debounced function.\n        if (diff <= 0 || startTime + maxWait >= now) {\n            // It's important to clear the timeout id before invoking the debounced\n            // function, in case the function calls the debouncing function again.\n            timeoutId = undefined;\n            fn.apply(context, args);\n        } else {\n            // Else, a new call was made after the original timer was scheduled. We\n            // didn't clear the timeout (doing so is very slow), so now we need to\n            // reschedule the timer for the time difference.\n            timeoutId = setTimeout(run, diff);\n        }\n    }\n    return function(...passedArgs) {\n        // The arguments and this context of the most recent call are saved so the\n        // debounced function can be invoked with them later.\n        args = passedArgs;\n        context = this;\n        // Instead of constantly clearing and scheduling a timer, we record the\n        // time of the last call. If a second call comes in before the timer fires,\n        // then we'll reschedule in the run function. Doing this is considerably\n        // faster.\n        lastCall = Date.now();\n        // Only schedule a new timer if we're not currently waiting.\n        if (timeoutId === undefined) {\n            startTime = lastCall;\n            timeoutId = setTimeout(run, ms);\n        }\n    };\n}\n\n//# sourceMappingURL=utils.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvbmV4dC9kaXN0L3NlcnZlci91dGlscy5qcyIsIm1hcHBpbmdzIjoiQUFBYTtBQUNiLDhDQUE2QztBQUM3QztBQUNBLENBQUMsRUFBQztBQUNGLE1BQU0sQ0FJTDtBQUNEO0FBQ0E7QUFDQTtBQUNBO0FBQ0EsS0FBSztBQUNMO0FBQ0E7QUFDQTtBQUNBO0FBQ0EsS0FBSztBQUNMO0FBQ0E7QUFDQSxLQUFLO0FBQ0w7QUFDQTtBQUNBO0FBQ0EsQ0FBQztBQUNELG1CQUFtQixtQkFBTyxDQUFDLGlGQUF5QjtBQUNwRDtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBLFVBQVU7QUFDVjtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBOztBQUVBIiwic291cmNlcyI6WyJ3ZWJwYWNrOi8vdW5pdmVyc2FsLW5mdC13ZWIvLi9ub2RlX21vZHVsZXMvbmV4dC9kaXN0L3NlcnZlci91dGlscy5qcz8xYzU4Il0sInNvdXJjZXNDb250ZW50IjpbIlwidXNlIHN0cmljdFwiO1xuT2JqZWN0LmRlZmluZVByb3BlcnR5KGV4cG9ydHMsIFwiX19lc01vZHVsZVwiLCB7XG4gICAgdmFsdWU6IHRydWVcbn0pO1xuMCAmJiAobW9kdWxlLmV4cG9ydHMgPSB7XG4gICAgY2xlYW5BbXBQYXRoOiBudWxsLFxuICAgIGRlYm91bmNlOiBudWxsLFxuICAgIGlzQmxvY2tlZFBhZ2U6IG51bGxcbn0pO1xuZnVuY3Rpb24gX2V4cG9ydCh0YXJnZXQsIGFsbCkge1xuICAgIGZvcih2YXIgbmFtZSBpbiBhbGwpT2JqZWN0LmRlZmluZVByb3BlcnR5KHRhcmdldCwgbmFtZSwge1xuICAgICAgICBlbnVtZXJhYmxlOiB0cnVlLFxuICAgICAgICBnZXQ6IGFsbFtuYW1lXVxuICAgIH0pO1xufVxuX2V4cG9ydChleHBvcnRzLCB7XG4gICAgY2xlYW5BbXBQYXRoOiBmdW5jdGlvbigpIHtcbiAgICAgICAgcmV0dXJuIGNsZWFuQW1wUGF0aDtcbiAgICB9LFxuICAgIGRlYm91bmNlOiBmdW5jdGlvbigpIHtcbiAgICAgICAgcmV0dXJuIGRlYm91bmNlO1xuICAgIH0sXG4gICAgaXNCbG9ja2VkUGFnZTogZnVuY3Rpb24oKSB7XG4gICAgICAgIHJldHVybiBpc0Jsb2NrZWRQYWdlO1xuICAgIH1cbn0pO1xuY29uc3QgX2NvbnN0YW50cyA9IHJlcXVpcmUoXCIuLi9zaGFyZWQvbGliL2NvbnN0YW50c1wiKTtcbmZ1bmN0aW9uIGlzQmxvY2tlZFBhZ2UocGFnZSkge1xuICAgIHJldHVybiBfY29uc3RhbnRzLkJMT0NLRURfUEFHRVMuaW5jbHVkZXMocGFnZSk7XG59XG5mdW5jdGlvbiBjbGVhbkFtcFBhdGgocGF0aG5hbWUpIHtcbiAgICBpZiAocGF0aG5hbWUubWF0Y2goL1xcP2FtcD0oeXx5ZXN8dHJ1ZXwxKS8pKSB7XG4gICAgICAgIHBhdGhuYW1lID0gcGF0aG5hbWUucmVwbGFjZSgvXFw/YW1wPSh5fHllc3x0cnVlfDEpJj8vLCBcIj9cIik7XG4gICAgfVxuICAgIGlmIChwYXRobmFtZS5tYXRjaCgvJmFtcD0oeXx5ZXN8dHJ1ZXwxKS8pKSB7XG4gICAgICAgIHBhdGhuYW1lID0gcGF0aG5hbWUucmVwbGFjZSgvJmFtcD0oeXx5ZXN8dHJ1ZXwxKS8sIFwiXCIpO1xuICAgIH1cbiAgICBwYXRobmFtZSA9IHBhdGhuYW1lLnJlcGxhY2UoL1xcPyQvLCBcIlwiKTtcbiAgICByZXR1cm4gcGF0aG5hbWU7XG59XG5mdW5jdGlvbiBkZWJvdW5jZShmbiwgbXMsIG1heFdhaXQgPSBJbmZpbml0eSkge1xuICAgIGxldCB0aW1lb3V0SWQ7XG4gICAgLy8gVGhlIHRpbWUgdGhlIGRlYm91bmNpbmcgZnVuY3Rpb24gd2FzIGZpcnN0IGNhbGxlZCBkdXJpbmcgdGhpcyBkZWJvdW5jZSBxdWV1ZS5cbiAgICBsZXQgc3RhcnRUaW1lID0gMDtcbiAgICAvLyBUaGUgdGltZSB0aGUgZGVib3VuY2luZyBmdW5jdGlvbiB3YXMgbGFzdCBjYWxsZWQuXG4gICAgbGV0IGxhc3RDYWxsID0gMDtcbiAgICAvLyBUaGUgYXJndW1lbnRzIGFuZCB0aGlzIGNvbnRleHQgb2YgdGhlIGxhc3QgY2FsbCB0byB0aGUgZGVib3VuY2luZyBmdW5jdGlvbi5cbiAgICBsZXQgYXJncywgY29udGV4dDtcbiAgICAvLyBBIGhlbHBlciB1c2VkIHRvIHRoYXQgZWl0aGVyIGludm9rZXMgdGhlIGRlYm91bmNlZCBmdW5jdGlvbiwgb3JcbiAgICAvLyByZXNjaGVkdWxlcyB0aGUgdGltZXIgaWYgYSBtb3JlIHJlY2VudCBjYWxsIHdhcyBtYWRlLlxuICAgIGZ1bmN0aW9uIHJ1bigpIHtcbiAgICAgICAgY29uc3Qgbm93ID0gRGF0ZS5ub3coKTtcbiAgICAgICAgY29uc3QgZGlmZiA9IGxhc3RDYWxsICsgbXMgLSBub3c7XG4gICAgICAgIC8vIElmIHRoZSBkaWZmIGlzIG5vbi1wb3NpdGl2ZSwgdGhlbiB3ZSd2ZSB3YWl0ZWQgYXQgbGVhc3QgYG1zYFxuICAgICAgICAvLyBtaWxsaXNlY29uZHMgc2luY2UgdGhlIGxhc3QgY2FsbC4gT3IgaWYgd2UndmUgd2FpdGVkIGZvciBsb25nZXIgdGhhbiB0aGVcbiAgICAgICAgLy8gbWF4IHdhaXQgdGltZSwgd2UgbXVzdCBjYWxsIHRoZSBkZWJvdW5jZWQgZnVuY3Rpb24uXG4gICAgICAgIGlmIChkaWZmIDw9IDAgfHwgc3RhcnRUaW1lICsgbWF4V2FpdCA+PSBub3cpIHtcbiAgICAgICAgICAgIC8vIEl0J3MgaW1wb3J0YW50IHRvIGNsZWFyIHRoZSB0aW1lb3V0IGlkIGJlZm9yZSBpbnZva2luZyB0aGUgZGVib3VuY2VkXG4gICAgICAgICAgICAvLyBmdW5jdGlvbiwgaW4gY2FzZSB0aGUgZnVuY3Rpb24gY2FsbHMgdGhlIGRlYm91bmNpbmcgZnVuY3Rpb24gYWdhaW4uXG4gICAgICAgICAgICB0aW1lb3V0SWQgPSB1bmRlZmluZWQ7XG4gICAgICAgICAgICBmbi5hcHBseShjb250ZXh0LCBhcmdzKTtcbiAgICAgICAgfSBlbHNlIHtcbiAgICAgICAgICAgIC8vIEVsc2UsIGEgbmV3IGNhbGwgd2FzIG1hZGUgYWZ0ZXIgdGhlIG9yaWdpbmFsIHRpbWVyIHdhcyBzY2hlZHVsZWQuIFdlXG4gICAgICAgICAgICAvLyBkaWRuJ3QgY2xlYXIgdGhlIHRpbWVvdXQgKGRvaW5nIHNvIGlzIHZlcnkgc2xvdyksIHNvIG5vdyB3ZSBuZWVkIHRvXG4gICAgICAgICAgICAvLyByZXNjaGVkdWxlIHRoZSB0aW1lciBmb3IgdGhlIHRpbWUgZGlmZmVyZW5jZS5cbiAgICAgICAgICAgIHRpbWVvdXRJZCA9IHNldFRpbWVvdXQocnVuLCBkaWZmKTtcbiAgICAgICAgfVxuICAgIH1cbiAgICByZXR1cm4gZnVuY3Rpb24oLi4ucGFzc2VkQXJncykge1xuICAgICAgICAvLyBUaGUgYXJndW1lbnRzIGFuZCB0aGlzIGNvbnRleHQgb2YgdGhlIG1vc3QgcmVjZW50IGNhbGwgYXJlIHNhdmVkIHNvIHRoZVxuICAgICAgICAvLyBkZWJvdW5jZWQgZnVuY3Rpb24gY2FuIGJlIGludm9rZWQgd2l0aCB0aGVtIGxhdGVyLlxuICAgICAgICBhcmdzID0gcGFzc2VkQXJncztcbiAgICAgICAgY29udGV4dCA9IHRoaXM7XG4gICAgICAgIC8vIEluc3RlYWQgb2YgY29uc3RhbnRseSBjbGVhcmluZyBhbmQgc2NoZWR1bGluZyBhIHRpbWVyLCB3ZSByZWNvcmQgdGhlXG4gICAgICAgIC8vIHRpbWUgb2YgdGhlIGxhc3QgY2FsbC4gSWYgYSBzZWNvbmQgY2FsbCBjb21lcyBpbiBiZWZvcmUgdGhlIHRpbWVyIGZpcmVzLFxuICAgICAgICAvLyB0aGVuIHdlJ2xsIHJlc2NoZWR1bGUgaW4gdGhlIHJ1biBmdW5jdGlvbi4gRG9pbmcgdGhpcyBpcyBjb25zaWRlcmFibHlcbiAgICAgICAgLy8gZmFzdGVyLlxuICAgICAgICBsYXN0Q2FsbCA9IERhdGUubm93KCk7XG4gICAgICAgIC8vIE9ubHkgc2NoZWR1bGUgYSBuZXcgdGltZXIgaWYgd2UncmUgbm90IGN1cnJlbnRseSB3YWl0aW5nLlxuICAgICAgICBpZiAodGltZW91dElkID09PSB1bmRlZmluZWQpIHtcbiAgICAgICAgICAgIHN0YXJ0VGltZSA9IGxhc3RDYWxsO1xuICAgICAgICAgICAgdGltZW91dElkID0gc2V0VGltZW91dChydW4sIG1zKTtcbiAgICAgICAgfVxuICAgIH07XG59XG5cbi8vIyBzb3VyY2VNYXBwaW5nVVJMPXV0aWxzLmpzLm1hcCJdLCJuYW1lcyI6W10sInNvdXJjZVJvb3QiOiIifQ==\n//# sourceURL=webpack-internal:///./node_modules/next/dist/server/utils.js\n");
-
-/***/ })
-
-};
-;
\ No newline at end of file
@@ -1,230 +0,0 @@
-/*
- * ATTENTION: An "eval-source-map" devtool has been used.
- * This devtool is neither made for production nor for readable output files.
- * It uses "eval()" calls to create a separate source file with attached SourceMaps in the browser devtools.
- * If you are trying to read the output file, select a different devtool (https://webpack.js.org/configuration/devtool/)
- * or disable the default devtool with "devtool: false".
- * If you are looking for production-ready output files, see mode: "production" (https://webpack.js.org/configuration/mode/).
- */
-(self["webpackChunk_N_E"] = self["webpackChunk_N_E"] || []).push([["node_modules_fractalwagmi_solana-wallet-adapter_dist_esm_index_js"],{
-
-/***/ "./node_modules/@fractalwagmi/solana-wallet-adapter/node_modules/base-x/src/index.js":
-/*!*******************************************************************************************!*\
-  !*** ./node_modules/@fractalwagmi/solana-wallet-adapter/node_modules/base-x/src/index.js ***!
-  \*******************************************************************************************/
-/***/ (function(module, __unused_webpack_exports, __webpack_require__) {
-
-"use strict";
-eval(__webpack_require__.ts("\n// base-x encoding / decoding\n// Copyright (c) 2018 base-x contributors\n// Copyright (c) 2014-2018 The Bitcoin Core developers (base58.cpp)\n// Distributed under the MIT software license, see the accompanying\n// file LICENSE or http://www.opensource.org/licenses/mit-license.php.\nfunction base (ALPHABET) {\n  if (ALPHABET.length >= 255) { throw new TypeError('Alphabet too long') }\n  var BASE_MAP = new Uint8Array(256)\n  for (var j = 0; j < BASE_MAP.length; j++) {\n    BASE_MAP[j] = 255\n  }\n  for (var i = 0; i < ALPHABET.length; i++) {\n    var x = ALPHABET.charAt(i)\n    var xc = x.charCodeAt(0)\n    if (BASE_MAP[xc] !== 255) { throw new TypeError(x + ' is ambiguous') }\n    BASE_MAP[xc] = i\n  }\n  var BASE = ALPHABET.length\n  var LEADER = ALPHABET.charAt(0)\n  var FACTOR = Math.log(BASE) / Math.log(256) // log(BASE) / log(256), rounded up\n  var iFACTOR = Math.log(256) / Math.log(BASE) // log(256) / log(BASE), rounded up\n  function encode (source) {\n    if (source instanceof Uint8Array) {\n    } else if (ArrayBuffer.isView(source)) {\n      source = new Uint8Array(source.buffer, source.byteOffset, source.byteLength)\n    } else if (Array.isArray(source)) {\n      source = Uint8Array.from(source)\n    }\n    if (!(source instanceof Uint8Array)) { throw new TypeError('Expected Uint8Array') }\n    if (source.length === 0) { return '' }\n        // Skip & count leading zeroes.\n    var zeroes = 0\n    var length = 0\n    var pbegin = 0\n    var pend = source.length\n    while (pbegin !== pend && source[pbegin] === 0) {\n      pbegin++\n      zeroes++\n    }\n        // Allocate enough space in big-endian base58 representation.\n    var size = ((pend - pbegin) * iFACTOR + 1) >>> 0\n    var b58 = new Uint8Array(size)\n        // Process the bytes.\n    while (pbegin !== pend) {\n      var carry = source[pbegin]\n            // Apply \"b58 = b58 * 256 + ch\".\n      var i = 0\n      for (var it1 = size - 1; (carry !== 0 || i < length) && (it1 !== -1); it1--, i++) {\n        carry += (256 * b58[it1]) >>> 0\n        b58[it1] = (carry % BASE) >>> 0\n        carry = (carry / BASE) >>> 0\n      }\n      if (carry !== 0) { throw new Error('Non-zero carry') }\n      length = i\n      pbegin++\n    }\n        // Skip leading zeroes in base58 result.\n    var it2 = size - length\n    while (it2 !== size && b58[it2] === 0) {\n      it2++\n    }\n        // Translate the result into a string.\n    var str = LEADER.repeat(zeroes)\n    for (; it2 < size; ++it2) { str += ALPHABET.charAt(b58[it2]) }\n    return str\n  }\n  function decodeUnsafe (source) {\n    if (typeof source !== 'string') { throw new TypeError('Expected String') }\n    if (source.length === 0) { return new Uint8Array() }\n    var psz = 0\n        // Skip and count leading '1's.\n    var zeroes = 0\n    var length = 0\n    while (source[psz] === LEADER) {\n      zeroes++\n      psz++\n    }\n        // Allocate enough space in big-endian base256 representation.\n    var size = (((source.length - psz) * FACTOR) + 1) >>> 0 // log(58) / log(256), rounded up.\n    var b256 = new Uint8Array(size)\n        // Process the characters.\n    while (source[psz]) {\n            // Find code of next character\n      var charCode = source.charCodeAt(psz)\n            // Base map can not be indexed using char code\n      if (charCode > 255) { return }\n            // Decode character\n      var carry = BASE_MAP[charCode]\n            // Invalid character\n      if (carry === 255) { return }\n      var i = 0\n      for (var it3 = size - 1; (carry !== 0 || i < length) && (it3 !== -1); it3--, i++) {\n        carry += (BASE * b256[it3]) >>> 0\n        b256[it3] = (carry % 256) >>> 0\n        carry = (carry / 256) >>> 0\n      }\n      if (carry !== 0) { throw new Error('Non-zero carry') }\n      length = i\n      psz++\n    }\n        // Skip leading zeroes in b256.\n    var it4 = size - length\n    while (it4 !== size && b256[it4] === 0) {\n      it4++\n    }\n    var vch = new Uint8Array(zeroes + (size - it4))\n    var j = zeroes\n    while (it4 !== size) {\n      vch[j++] = b256[it4++]\n    }\n    return vch\n  }\n  function decode (string) {\n    var buffer = decodeUnsafe(string)\n    if (buffer) { return buffer }\n    throw new Error('Non-base' + BASE + ' character')\n  }\n  return {\n    encode: encode,\n    decodeUnsafe: decodeUnsafe,\n    decode: decode\n  }\n}\nmodule.exports = base\n//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQGZyYWN0YWx3YWdtaS9zb2xhbmEtd2FsbGV0LWFkYXB0ZXIvbm9kZV9tb2R1bGVzL2Jhc2UteC9zcmMvaW5kZXguanMiLCJtYXBwaW5ncyI6IkFBQVk7QUFDWjtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQSxnQ0FBZ0M7QUFDaEM7QUFDQSxrQkFBa0IscUJBQXFCO0FBQ3ZDO0FBQ0E7QUFDQSxrQkFBa0IscUJBQXFCO0FBQ3ZDO0FBQ0E7QUFDQSxnQ0FBZ0M7QUFDaEM7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBLE1BQU07QUFDTjtBQUNBLE1BQU07QUFDTjtBQUNBO0FBQ0EsMkNBQTJDO0FBQzNDLCtCQUErQjtBQUMvQjtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0EsK0JBQStCLDZDQUE2QztBQUM1RTtBQUNBO0FBQ0E7QUFDQTtBQUNBLHlCQUF5QjtBQUN6QjtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBLFdBQVcsWUFBWSxTQUFTO0FBQ2hDO0FBQ0E7QUFDQTtBQUNBLHNDQUFzQztBQUN0QywrQkFBK0I7QUFDL0I7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQSw0QkFBNEI7QUFDNUI7QUFDQTtBQUNBO0FBQ0EsMkJBQTJCO0FBQzNCO0FBQ0EsK0JBQStCLDZDQUE2QztBQUM1RTtBQUNBO0FBQ0E7QUFDQTtBQUNBLHlCQUF5QjtBQUN6QjtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0Esa0JBQWtCO0FBQ2xCO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQSIsInNvdXJjZXMiOlsid2VicGFjazovL19OX0UvLi9ub2RlX21vZHVsZXMvQGZyYWN0YWx3YWdtaS9zb2xhbmEtd2FsbGV0LWFkYXB0ZXIvbm9kZV9tb2R1bGVzL2Jhc2UteC9zcmMvaW5kZXguanM/YTI4OCJdLCJzb3VyY2VzQ29udGVudCI6WyIndXNlIHN0cmljdCdcbi8vIGJhc2UteCBlbmNvZGluZyAvIGRlY29kaW5nXG4vLyBDb3B5cmlnaHQgKGMpIDIwMTggYmFzZS14IGNvbnRyaWJ1dG9yc1xuLy8gQ29weXJpZ2h0IChjKSAyMDE0LTIwMTggVGhlIEJpdGNvaW4gQ29yZSBkZXZlbG9wZXJzIChiYXNlNTguY3BwKVxuLy8gRGlzdHJpYnV0ZWQgdW5kZXIgdGhlIE1JVCBzb2Z0d2FyZSBsaWNlbnNlLCBzZWUgdGhlIGFjY29tcGFueWluZ1xuLy8gZmlsZSBMSUNFTlNFIG9yIGh0dHA6Ly93d3cub3BlbnNvdXJjZS5vcmcvbGljZW5zZXMvbWl0LWxpY2Vuc2UucGhwLlxuZnVuY3Rpb24gYmFzZSAoQUxQSEFCRVQpIHtcbiAgaWYgKEFMUEhBQkVULmxlbmd0aCA+PSAyNTUpIHsgdGhyb3cgbmV3IFR5cGVFcnJvcignQWxwaGFiZXQgdG9vIGxvbmcnKSB9XG4gIHZhciBCQVNFX01BUCA9IG5ldyBVaW50OEFycmF5KDI1NilcbiAgZm9yICh2YXIgaiA9IDA7IGogPCBCQVNFX01BUC5sZW5ndGg7IGorKykge1xuICAgIEJBU0VfTUFQW2pdID0gMjU1XG4gIH1cbiAgZm9yICh2YXIgaSA9IDA7IGkgPCBBTFBIQUJFVC5sZW5ndGg7IGkrKykge1xuICAgIHZhciB4ID0gQUxQSEFCRVQuY2hhckF0KGkpXG4gICAgdmFyIHhjID0geC5jaGFyQ29kZUF0KDApXG4gICAgaWYgKEJBU0VfTUFQW3hjXSAhPT0gMjU1KSB7IHRocm93IG5ldyBUeXBlRXJyb3IoeCArICcgaXMgYW1iaWd1b3VzJykgfVxuICAgIEJBU0VfTUFQW3hjXSA9IGlcbiAgfVxuICB2YXIgQkFTRSA9IEFMUEhBQkVULmxlbmd0aFxuICB2YXIgTEVBREVSID0gQUxQSEFCRVQuY2hhckF0KDApXG4gIHZhciBGQUNUT1IgPSBNYXRoLmxvZyhCQVNFKSAvIE1hdGgubG9nKDI1NikgLy8gbG9nKEJBU0UpIC8gbG9nKDI1NiksIHJvdW5kZWQgdXBcbiAgdmFyIGlGQUNUT1IgPSBNYXRoLmxvZygyNTYpIC8gTWF0aC5sb2coQkFTRSkgLy8gbG9nKDI1NikgLyBsb2coQkFTRSksIHJvdW5kZWQgdXBcbiAgZnVuY3Rpb24gZW5jb2RlIChzb3VyY2UpIHtcbiAgICBpZiAoc291cmNlIGluc3RhbmNlb2YgVWludDhBcnJheSkge1xuICAgIH0gZWxzZSBpZiAoQXJyYXlCdWZmZXIuaXNWaWV3KHNvdXJjZSkpIHtcbiAgICAgIHNvdXJjZSA9IG5ldyBVaW50OEFycmF5KHNvdXJjZS5idWZmZXIsIHNvdXJjZS5ieXRlT2Zmc2V0LCBzb3VyY2UuYnl0ZUxlbmd0aClcbiAgICB9IGVsc2UgaWYgKEFycmF5LmlzQXJyYXkoc291cmNlKSkge1xuICAgICAgc291cmNlID0gVWludDhBcnJheS5mcm9tKHNvdXJjZSlcbiAgICB9XG4gICAgaWYgKCEoc291cmNlIGluc3RhbmNlb2YgVWludDhBcnJheSkpIHsgdGhyb3cgbmV3IFR5cGVFcnJvcignRXhwZWN0ZWQgVWludDhBcnJheScpIH1cbiAgICBpZiAoc291cmNlLmxlbmd0aCA9PT0gMCkgeyByZXR1cm4gJycgfVxuICAgICAgICAvLyBTa2lwICYgY291bnQgbGVhZGluZyB6ZXJvZXMuXG4gICAgdmFyIHplcm9lcyA9IDBcbiAgICB2YXIgbGVuZ3RoID0gMFxuICAgIHZhciBwYmVnaW4gPSAwXG4gICAgdmFyIHBlbmQgPSBzb3VyY2UubGVuZ3RoXG4gICAgd2hpbGUgKHBiZWdpbiAhPT0gcGVuZCAmJiBzb3VyY2VbcGJlZ2luXSA9PT0gMCkge1xuICAgICAgcGJlZ2luKytcbiAgICAgIHplcm9lcysrXG4gICAgfVxuICAgICAgICAvLyBBbGxvY2F0ZSBlbm91Z2ggc3BhY2UgaW4gYmlnLWVuZGlhbiBiYXNlNTggcmVwcmVzZW50YXRpb24uXG4gICAgdmFyIHNpemUgPSAoKHBlbmQgLSBwYmVnaW4pICogaUZBQ1RPUiArIDEpID4+PiAwXG4gICAgdmFyIGI1OCA9IG5ldyBVaW50OEFycmF5KHNpemUpXG4gICAgICAgIC8vIFByb2Nlc3MgdGhlIGJ5dGVzLlxuICAgIHdoaWxlIChwYmVnaW4gIT09IHBlbmQpIHtcbiAgICAgIHZhciBjYXJyeSA9IHNvdXJjZVtwYmVnaW5dXG4gICAgICAgICAgICAvLyBBcHBseSBcImI1OCA9IGI1OCAqIDI1NiArIGNoXCIuXG4gICAgICB2YXIgaSA9IDBcbiAgICAgIGZvciAodmFyIGl0MSA9IHNpemUgLSAxOyAoY2FycnkgIT09IDAgfHwgaSA8IGxlbmd0aCkgJiYgKGl0MSAhPT0gLTEpOyBpdDEtLSwgaSsrKSB7XG4gICAgICAgIGNhcnJ5ICs9ICgyNTYgKiBiNThbaXQxXSkgPj4+IDBcbiAgICAgICAgYjU4W2l0MV0gPSAoY2FycnkgJSBCQVNFKSA+Pj4gMFxuICAgICAgICBjYXJyeSA9IChjYXJyeSAvIEJBU0UpID4+PiAwXG4gICAgICB9XG4gICAgICBpZiAoY2FycnkgIT09IDApIHsgdGhyb3cgbmV3IEVycm9yKCdOb24temVybyBjYXJyeScpIH1cbiAgICAgIGxlbmd0aCA9IGlcbiAgICAgIHBiZWdpbisrXG4gICAgfVxuICAgICAgICAvLyBTa2lwIGxlYWRpbmcgemVyb2VzIGluIGJhc2U1OCByZXN1bHQuXG4gICAgdmFyIGl0MiA9IHNpemUgLSBsZW5ndGhcbiAgICB3aGlsZSAoaXQyICE9PSBzaXplICYmIGI1OFtpdDJdID09PSAwKSB7XG4gICAgICBpdDIrK1xuICAgIH1cbiAgICAgICAgLy8gVHJhbnNsYXRlIHRoZSByZXN1bHQgaW50byBhIHN0cmluZy5cbiAgICB2YXIgc3RyID0gTEVBREVSLnJlcGVhdCh6ZXJvZXMpXG4gICAgZm9yICg7IGl0MiA8IHNpemU7ICsraXQyKSB7IHN0ciArPSBBTFBIQUJFVC5jaGFyQXQoYjU4W2l0Ml0pIH1cbiAgICByZXR1cm4gc3RyXG4gIH1cbiAgZnVuY3Rpb24gZGVjb2RlVW5zYWZlIChzb3VyY2UpIHtcbiAgICBpZiAodHlwZW9mIHNvdXJjZSAhPT0gJ3N0cmluZycpIHsgdGhyb3cgbmV3IFR5cGVFcnJvcignRXhwZWN0ZWQgU3RyaW5nJykgfVxuICAgIGlmIChzb3VyY2UubGVuZ3RoID09PSAwKSB7IHJldHVybiBuZXcgVWludDhBcnJheSgpIH1cbiAgICB2YXIgcHN6ID0gMFxuICAgICAgICAvLyBTa2lwIGFuZCBjb3VudCBsZWFkaW5nICcxJ3MuXG4gICAgdmFyIHplcm9lcyA9IDBcbiAgICB2YXIgbGVuZ3RoID0gMFxuICAgIHdoaWxlIChzb3VyY2VbcHN6XSA9PT0gTEVBREVSKSB7XG4gICAgICB6ZXJvZXMrK1xuICAgICAgcHN6KytcbiAgICB9XG4gICAgICAgIC8vIEFsbG9jYXRlIGVub3VnaCBzcGFjZSBpbiBiaWctZW5kaWFuIGJhc2UyNTYgcmVwcmVzZW50YXRpb24uXG4gICAgdmFyIHNpemUgPSAoKChzb3VyY2UubGVuZ3RoIC0gcHN6KSAqIEZBQ1RPUikgKyAxKSA+Pj4gMCAvLyBsb2coNTgpIC8gbG9nKDI1NiksIHJvdW5kZWQgdXAuXG4gICAgdmFyIGIyNTYgPSBuZXcgVWludDhBcnJheShzaXplKVxuICAgICAgICAvLyBQcm9jZXNzIHRoZSBjaGFyYWN0ZXJzLlxuICAgIHdoaWxlIChzb3VyY2VbcHN6XSkge1xuICAgICAgICAgICAgLy8gRmluZCBjb2RlIG9mIG5leHQgY2hhcmFjdGVyXG4gICAgICB2YXIgY2hhckNvZGUgPSBzb3VyY2UuY2hhckNvZGVBdChwc3opXG4gICAgICAgICAgICAvLyBCYXNlIG1hcCBjYW4gbm90IGJlIGluZGV4ZWQgdXNpbmcgY2hhciBjb2RlXG4gICAgICBpZiAoY2hhckNvZGUgPiAyNTUpIHsgcmV0dXJuIH1cbiAgICAgICAgICAgIC8vIERlY29kZSBjaGFyYWN0ZXJcbiAgICAgIHZhciBjYXJyeSA9IEJBU0VfTUFQW2NoYXJDb2RlXVxuICAgICAgICAgICAgLy8gSW52YWxpZCBjaGFyYWN0ZXJcbiAgICAgIGlmIChjYXJyeSA9PT0gMjU1KSB7IHJldHVybiB9XG4gICAgICB2YXIgaSA9IDBcbiAgICAgIGZvciAodmFyIGl0MyA9IHNpemUgLSAxOyAoY2FycnkgIT09IDAgfHwgaSA8IGxlbmd0aCkgJiYgKGl0MyAhPT0gLTEpOyBpdDMtLSwgaSsrKSB7XG4gICAgICAgIGNhcnJ5ICs9IChCQVNFICogYjI1NltpdDNdKSA+Pj4gMFxuICAgICAgICBiMjU2W2l0M10gPSAoY2FycnkgJSAyNTYpID4+PiAwXG4gICAgICAgIGNhcnJ5ID0gKGNhcnJ5IC8gMjU2KSA+Pj4gMFxuICAgICAgfVxuICAgICAgaWYgKGNhcnJ5ICE9PSAwKSB7IHRocm93IG5ldyBFcnJvcignTm9uLXplcm8gY2FycnknKSB9XG4gICAgICBsZW5ndGggPSBpXG4gICAgICBwc3orK1xuICAgIH1cbiAgICAgICAgLy8gU2tpcCBsZWFkaW5nIHplcm9lcyBpbiBiMjU2LlxuICAgIHZhciBpdDQgPSBzaXplIC0gbGVuZ3RoXG4gICAgd2hpbGUgKGl0NCAhPT0gc2l6ZSAmJiBiMjU2W2l0NF0gPT09IDApIHtcbiAgICAgIGl0NCsrXG4gICAgfVxuICAgIHZhciB2Y2ggPSBuZXcgVWludDhBcnJheSh6ZXJvZXMgKyAoc2l6ZSAtIGl0NCkpXG4gICAgdmFyIGogPSB6ZXJvZXNcbiAgICB3aGlsZSAoaXQ0ICE9PSBzaXplKSB7XG4gICAgICB2Y2hbaisrXSA9IGIyNTZbaXQ0KytdXG4gICAgfVxuICAgIHJldHVybiB2Y2hcbiAgfVxuICBmdW5jdGlvbiBkZWNvZGUgKHN0cmluZykge1xuICAgIHZhciBidWZmZXIgPSBkZWNvZGVVbnNhZmUoc3RyaW5nKVxuICAgIGlmIChidWZmZXIpIHsgcmV0dXJuIGJ1ZmZlciB9XG4gICAgdGhyb3cgbmV3IEVycm9yKCdOb24tYmFzZScgKyBCQVNFICsgJyBjaGFyYWN0ZXInKVxuICB9XG4gIHJldHVybiB7XG4gICAgZW5jb2RlOiBlbmNvZGUsXG4gICAgZGVjb2RlVW5zYWZlOiBkZWNvZGVVbnNhZmUsXG4gICAgZGVjb2RlOiBkZWNvZGVcbiAgfVxufVxubW9kdWxlLmV4cG9ydHMgPSBiYXNlXG4iXSwibmFtZXMiOltdLCJzb3VyY2VSb290IjoiIn0=\n//# sourceURL=webpack-internal:///./node_modules/@fractalwagmi/solana-wallet-adapter/node_modules/base-x/src/index.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@fractalwagmi/solana-wallet-adapter/node_modules/bs58/index.js":
-/*!*************************************************************************************!*\
-  !*** ./node_modules/@fractalwagmi/solana-wallet-adapter/node_modules/bs58/index.js ***!
-  \*************************************************************************************/
-/***/ (function(module, __unused_webpack_exports, __webpack_require__) {
-
-eval(__webpack_require__.ts("const basex = __webpack_require__(/*! base-x */ \"./node_modules/@fractalwagmi/solana-wallet-adapter/node_modules/base-x/src/index.js\")\nconst ALPHABET = '123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz'\n\nmodule.exports = basex(ALPHABET)\n//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQGZyYWN0YWx3YWdtaS9zb2xhbmEtd2FsbGV0LWFkYXB0ZXIvbm9kZV9tb2R1bGVzL2JzNTgvaW5kZXguanMiLCJtYXBwaW5ncyI6IkFBQUEsY0FBYyxtQkFBTyxDQUFDLG1HQUFRO0FBQzlCOztBQUVBIiwic291cmNlcyI6WyJ3ZWJwYWNrOi8vX05fRS8uL25vZGVfbW9kdWxlcy9AZnJhY3RhbHdhZ21pL3NvbGFuYS13YWxsZXQtYWRhcHRlci9ub2RlX21vZHVsZXMvYnM1OC9pbmRleC5qcz8wOWMyIl0sInNvdXJjZXNDb250ZW50IjpbImNvbnN0IGJhc2V4ID0gcmVxdWlyZSgnYmFzZS14JylcbmNvbnN0IEFMUEhBQkVUID0gJzEyMzQ1Njc4OUFCQ0RFRkdISktMTU5QUVJTVFVWV1hZWmFiY2RlZmdoaWprbW5vcHFyc3R1dnd4eXonXG5cbm1vZHVsZS5leHBvcnRzID0gYmFzZXgoQUxQSEFCRVQpXG4iXSwibmFtZXMiOltdLCJzb3VyY2VSb290IjoiIn0=\n//# sourceURL=webpack-internal:///./node_modules/@fractalwagmi/solana-wallet-adapter/node_modules/bs58/index.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@fractalwagmi/popup-connection/dist/esm/connection-manager.js":
-/*!************************************************************************************!*\
-  !*** ./node_modules/@fractalwagmi/popup-connection/dist/esm/connection-manager.js ***!
-  \************************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-"use strict";
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   ConnectionManager: function() { return /* binding */ ConnectionManager; }\n/* harmony export */ });\n/* harmony import */ var _core_connection_js__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! ./core/connection.js */ \"./node_modules/@fractalwagmi/popup-connection/dist/esm/core/connection.js\");\n/* harmony import */ var _core_constants_js__WEBPACK_IMPORTED_MODULE_1__ = __webpack_require__(/*! ./core/constants.js */ \"./node_modules/@fractalwagmi/popup-connection/dist/esm/core/constants.js\");\n/* harmony import */ var _core_types_js__WEBPACK_IMPORTED_MODULE_2__ = __webpack_require__(/*! ./core/types.js */ \"./node_modules/@fractalwagmi/popup-connection/dist/esm/core/types.js\");\n/* harmony import */ var _core_utils_js__WEBPACK_IMPORTED_MODULE_3__ = __webpack_require__(/*! ./core/utils.js */ \"./node_modules/@fractalwagmi/popup-connection/dist/esm/core/utils.js\");\n\n\n\n\nclass ConnectionManager {\n    constructor(platform) {\n        this.platform = platform;\n        this.connection = null;\n        this.popupWindow = null;\n        this.handleMessage = (e) => {\n            var _a, _b;\n            if (!(0,_core_utils_js__WEBPACK_IMPORTED_MODULE_3__.validateOrigin)(e.origin)) {\n                return;\n            }\n            const validatedOrigin = e.origin;\n            if (!this.popupWindow) {\n                return;\n            }\n            if (e.data.event === _core_types_js__WEBPACK_IMPORTED_MODULE_2__.PopupEvent.HANDSHAKE && !this.connection) {\n                if (!this.verifyAndResetNonce((_a = e.data.payload) === null || _a === void 0 ? void 0 : _a.nonce)) {\n                    return;\n                }\n                this.popupWindow.postMessage({\n                    event: _core_types_js__WEBPACK_IMPORTED_MODULE_2__.PopupEvent.HANDSHAKE_ACK,\n                    payload: {\n                        platform: this.platform,\n                    },\n                }, validatedOrigin);\n                this.connection = new _core_connection_js__WEBPACK_IMPORTED_MODULE_0__.Connection(validatedOrigin, this.popupWindow);\n                (_b = this.connectionUpdatedCallback) === null || _b === void 0 ? void 0 : _b.call(this, this.connection);\n            }\n            if (!this.connection) {\n                return;\n            }\n            this.connection.runHandlersForEvent(e.data.event, e.data.payload);\n            if (e.data.event === _core_types_js__WEBPACK_IMPORTED_MODULE_2__.PopupEvent.POPUP_CLOSED && this.connection) {\n                this.resetConnection();\n                this.popupWindow = null;\n            }\n        };\n    }\n    initialize() {\n        window.addEventListener('message', this.handleMessage);\n        return this;\n    }\n    tearDown() {\n        window.removeEventListener('message', this.handleMessage);\n        this.resetConnection();\n        return this;\n    }\n    async open({ url, widthPx = _core_constants_js__WEBPACK_IMPORTED_MODULE_1__.DEFAULT_POPUP_WIDTH_PX, heightPx = _core_constants_js__WEBPACK_IMPORTED_MODULE_1__.DEFAULT_POPUP_HEIGHT_PX, nonce, }) {\n        var _a;\n        if ((_a = this.popupWindow) === null || _a === void 0 ? void 0 : _a.closed) {\n            this.resetConnectionAndPopupWindow();\n        }\n        if (this.popupWindow) {\n            return;\n        }\n        this.initialize();\n        if (nonce) {\n            this.nonce = nonce;\n        }\n        const left = window.screenX + (window.innerWidth - widthPx) / 2;\n        const top = window.screenY + (window.innerHeight - heightPx) / 2;\n        this.popupWindow = (0,_core_utils_js__WEBPACK_IMPORTED_MODULE_3__.openPopup)({\n            height: heightPx,\n            left,\n            top,\n            url: typeof url === 'string' ? url : undefined,\n            width: widthPx,\n        });\n        if (url instanceof Promise) {\n            this.setUrl(await url);\n        }\n    }\n    close() {\n        if (!this.popupWindow) {\n            return;\n        }\n        this.popupWindow.close();\n        this.resetConnectionAndPopupWindow();\n    }\n    onConnectionUpdated(callback) {\n        this.connectionUpdatedCallback = callback;\n        return this;\n    }\n    getConnection() {\n        return this.connection;\n    }\n    setUrl(url) {\n        if (!this.popupWindow) {\n            return;\n        }\n        this.popupWindow.location = url;\n    }\n    resetConnectionAndPopupWindow() {\n        this.resetConnection();\n        this.popupWindow = null;\n    }\n    resetConnection() {\n        var _a, _b;\n        (_a = this.connection) === null || _a === void 0 ? void 0 : _a.resetHandlers();\n        this.connection = null;\n        (_b = this.connectionUpdatedCallback) === null || _b === void 0 ? void 0 : _b.call(this, this.connection);\n    }\n    verifyAndResetNonce(uncheckedNonce) {\n        if (!this.nonce) {\n            return true;\n        }\n        const result = uncheckedNonce === this.nonce;\n        if (result) {\n            this.nonce = undefined;\n        }\n        return result;\n    }\n}\n//# sourceMappingURL=connection-manager.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQGZyYWN0YWx3YWdtaS9wb3B1cC1jb25uZWN0aW9uL2Rpc3QvZXNtL2Nvbm5lY3Rpb24tbWFuYWdlci5qcyIsIm1hcHBpbmdzIjoiOzs7Ozs7OztBQUFrRDtBQUNxQztBQUMxQztBQUNlO0FBQ3JEO0FBQ1A7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0EsaUJBQWlCLDhEQUFjO0FBQy9CO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBLGlDQUFpQyxzREFBVTtBQUMzQztBQUNBO0FBQ0E7QUFDQTtBQUNBLDJCQUEyQixzREFBVTtBQUNyQztBQUNBO0FBQ0EscUJBQXFCO0FBQ3JCLGlCQUFpQjtBQUNqQixzQ0FBc0MsMkRBQVU7QUFDaEQ7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0EsaUNBQWlDLHNEQUFVO0FBQzNDO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQSxpQkFBaUIsZUFBZSxzRUFBc0IsYUFBYSx1RUFBdUIsVUFBVTtBQUNwRztBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBLDJCQUEyQix5REFBUztBQUNwQztBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0EsU0FBUztBQUNUO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBIiwic291cmNlcyI6WyJ3ZWJwYWNrOi8vX05fRS8uL25vZGVfbW9kdWxlcy9AZnJhY3RhbHdhZ21pL3BvcHVwLWNvbm5lY3Rpb24vZGlzdC9lc20vY29ubmVjdGlvbi1tYW5hZ2VyLmpzPzliNTIiXSwic291cmNlc0NvbnRlbnQiOlsiaW1wb3J0IHsgQ29ubmVjdGlvbiB9IGZyb20gXCIuL2NvcmUvY29ubmVjdGlvbi5qc1wiO1xuaW1wb3J0IHsgREVGQVVMVF9QT1BVUF9IRUlHSFRfUFgsIERFRkFVTFRfUE9QVVBfV0lEVEhfUFgsIH0gZnJvbSBcIi4vY29yZS9jb25zdGFudHMuanNcIjtcbmltcG9ydCB7IFBvcHVwRXZlbnQgfSBmcm9tIFwiLi9jb3JlL3R5cGVzLmpzXCI7XG5pbXBvcnQgeyBvcGVuUG9wdXAsIHZhbGlkYXRlT3JpZ2luIH0gZnJvbSBcIi4vY29yZS91dGlscy5qc1wiO1xuZXhwb3J0IGNsYXNzIENvbm5lY3Rpb25NYW5hZ2VyIHtcbiAgICBjb25zdHJ1Y3RvcihwbGF0Zm9ybSkge1xuICAgICAgICB0aGlzLnBsYXRmb3JtID0gcGxhdGZvcm07XG4gICAgICAgIHRoaXMuY29ubmVjdGlvbiA9IG51bGw7XG4gICAgICAgIHRoaXMucG9wdXBXaW5kb3cgPSBudWxsO1xuICAgICAgICB0aGlzLmhhbmRsZU1lc3NhZ2UgPSAoZSkgPT4ge1xuICAgICAgICAgICAgdmFyIF9hLCBfYjtcbiAgICAgICAgICAgIGlmICghdmFsaWRhdGVPcmlnaW4oZS5vcmlnaW4pKSB7XG4gICAgICAgICAgICAgICAgcmV0dXJuO1xuICAgICAgICAgICAgfVxuICAgICAgICAgICAgY29uc3QgdmFsaWRhdGVkT3JpZ2luID0gZS5vcmlnaW47XG4gICAgICAgICAgICBpZiAoIXRoaXMucG9wdXBXaW5kb3cpIHtcbiAgICAgICAgICAgICAgICByZXR1cm47XG4gICAgICAgICAgICB9XG4gICAgICAgICAgICBpZiAoZS5kYXRhLmV2ZW50ID09PSBQb3B1cEV2ZW50LkhBTkRTSEFLRSAmJiAhdGhpcy5jb25uZWN0aW9uKSB7XG4gICAgICAgICAgICAgICAgaWYgKCF0aGlzLnZlcmlmeUFuZFJlc2V0Tm9uY2UoKF9hID0gZS5kYXRhLnBheWxvYWQpID09PSBudWxsIHx8IF9hID09PSB2b2lkIDAgPyB2b2lkIDAgOiBfYS5ub25jZSkpIHtcbiAgICAgICAgICAgICAgICAgICAgcmV0dXJuO1xuICAgICAgICAgICAgICAgIH1cbiAgICAgICAgICAgICAgICB0aGlzLnBvcHVwV2luZG93LnBvc3RNZXNzYWdlKHtcbiAgICAgICAgICAgICAgICAgICAgZXZlbnQ6IFBvcHVwRXZlbnQuSEFORFNIQUtFX0FDSyxcbiAgICAgICAgICAgICAgICAgICAgcGF5bG9hZDoge1xuICAgICAgICAgICAgICAgICAgICAgICAgcGxhdGZvcm06IHRoaXMucGxhdGZvcm0sXG4gICAgICAgICAgICAgICAgICAgIH0sXG4gICAgICAgICAgICAgICAgfSwgdmFsaWRhdGVkT3JpZ2luKTtcbiAgICAgICAgICAgICAgICB0aGlzLmNvbm5lY3Rpb24gPSBuZXcgQ29ubmVjdGlvbih2YWxpZGF0ZWRPcmlnaW4sIHRoaXMucG9wdXBXaW5kb3cpO1xuICAgICAgICAgICAgICAgIChfYiA9IHRoaXMuY29ubmVjdGlvblVwZGF0ZWRDYWxsYmFjaykgPT09IG51bGwgfHwgX2IgPT09IHZvaWQgMCA/IHZvaWQgMCA6IF9iLmNhbGwodGhpcywgdGhpcy5jb25uZWN0aW9uKTtcbiAgICAgICAgICAgIH1cbiAgICAgICAgICAgIGlmICghdGhpcy5jb25uZWN0aW9uKSB7XG4gICAgICAgICAgICAgICAgcmV0dXJuO1xuICAgICAgICAgICAgfVxuICAgICAgICAgICAgdGhpcy5jb25uZWN0aW9uLnJ1bkhhbmRsZXJzRm9yRXZlbnQoZS5kYXRhLmV2ZW50LCBlLmRhdGEucGF5bG9hZCk7XG4gICAgICAgICAgICBpZiAoZS5kYXRhLmV2ZW50ID09PSBQb3B1cEV2ZW50LlBPUFVQX0NMT1NFRCAmJiB0aGlzLmNvbm5lY3Rpb24pIHtcbiAgICAgICAgICAgICAgICB0aGlzLnJlc2V0Q29ubmVjdGlvbigpO1xuICAgICAgICAgICAgICAgIHRoaXMucG9wdXBXaW5kb3cgPSBudWxsO1xuICAgICAgICAgICAgfVxuICAgICAgICB9O1xuICAgIH1cbiAgICBpbml0aWFsaXplKCkge1xuICAgICAgICB3aW5kb3cuYWRkRXZlbnRMaXN0ZW5lcignbWVzc2FnZScsIHRoaXMuaGFuZGxlTWVzc2FnZSk7XG4gICAgICAgIHJldHVybiB0aGlzO1xuICAgIH1cbiAgICB0ZWFyRG93bigpIHtcbiAgICAgICAgd2luZG93LnJlbW92ZUV2ZW50TGlzdGVuZXIoJ21lc3NhZ2UnLCB0aGlzLmhhbmRsZU1lc3NhZ2UpO1xuICAgICAgICB0aGlzLnJlc2V0Q29ubmVjdGlvbigpO1xuICAgICAgICByZXR1cm4gdGhpcztcbiAgICB9XG4gICAgYXN5bmMgb3Blbih7IHVybCwgd2lkdGhQeCA9IERFRkFVTFRfUE9QVVBfV0lEVEhfUFgsIGhlaWdodFB4ID0gREVGQVVMVF9QT1BVUF9IRUlHSFRfUFgsIG5vbmNlLCB9KSB7XG4gICAgICAgIHZhciBfYTtcbiAgICAgICAgaWYgKChfYSA9IHRoaXMucG9wdXBXaW5kb3cpID09PSBudWxsIHx8IF9hID09PSB2b2lkIDAgPyB2b2lkIDAgOiBfYS5jbG9zZWQpIHtcbiAgICAgICAgICAgIHRoaXMucmVzZXRDb25uZWN0aW9uQW5kUG9wdXBXaW5kb3coKTtcbiAgICAgICAgfVxuICAgICAgICBpZiAodGhpcy5wb3B1cFdpbmRvdykge1xuICAgICAgICAgICAgcmV0dXJuO1xuICAgICAgICB9XG4gICAgICAgIHRoaXMuaW5pdGlhbGl6ZSgpO1xuICAgICAgICBpZiAobm9uY2UpIHtcbiAgICAgICAgICAgIHRoaXMubm9uY2UgPSBub25jZTtcbiAgICAgICAgfVxuICAgICAgICBjb25zdCBsZWZ0ID0gd2luZG93LnNjcmVlblggKyAod2luZG93LmlubmVyV2lkdGggLSB3aWR0aFB4KSAvIDI7XG4gICAgICAgIGNvbnN0IHRvcCA9IHdpbmRvdy5zY3JlZW5ZICsgKHdpbmRvdy5pbm5lckhlaWdodCAtIGhlaWdodFB4KSAvIDI7XG4gICAgICAgIHRoaXMucG9wdXBXaW5kb3cgPSBvcGVuUG9wdXAoe1xuICAgICAgICAgICAgaGVpZ2h0OiBoZWlnaHRQeCxcbiAgICAgICAgICAgIGxlZnQsXG4gICAgICAgICAgICB0b3AsXG4gICAgICAgICAgICB1cmw6IHR5cGVvZiB1cmwgPT09ICdzdHJpbmcnID8gdXJsIDogdW5kZWZpbmVkLFxuICAgICAgICAgICAgd2lkdGg6IHdpZHRoUHgsXG4gICAgICAgIH0pO1xuICAgICAgICBpZiAodXJsIGluc3RhbmNlb2YgUHJvbWlzZSkge1xuICAgICAgICAgICAgdGhpcy5zZXRVcmwoYXdhaXQgdXJsKTtcbiAgICAgICAgfVxuICAgIH1cbiAgICBjbG9zZSgpIHtcbiAgICAgICAgaWYgKCF0aGlzLnBvcHVwV2luZG93KSB7XG4gICAgICAgICAgICByZXR1cm47XG4gICAgICAgIH1cbiAgICAgICAgdGhpcy5wb3B1cFdpbmRvdy5jbG9zZSgpO1xuICAgICAgICB0aGlzLnJlc2V0Q29ubmVjdGlvbkFuZFBvcHVwV2luZG93KCk7XG4gICAgfVxuICAgIG9uQ29ubmVjdGlvblVwZGF0ZWQoY2FsbGJhY2spIHtcbiAgICAgICAgdGhpcy5jb25uZWN0aW9uVXBkYXRlZENhbGxiYWNrID0gY2FsbGJhY2s7XG4gICAgICAgIHJldHVybiB0aGlzO1xuICAgIH1cbiAgICBnZXRDb25uZWN0aW9uKCkge1xuICAgICAgICByZXR1cm4gdGhpcy5jb25uZWN0aW9uO1xuICAgIH1cbiAgICBzZXRVcmwodXJsKSB7XG4gICAgICAgIGlmICghdGhpcy5wb3B1cFdpbmRvdykge1xuICAgICAgICAgICAgcmV0dXJuO1xuICAgICAgICB9XG4gICAgICAgIHRoaXMucG9wdXBXaW5kb3cubG9jYXRpb24gPSB1cmw7XG4gICAgfVxuICAgIHJlc2V0Q29ubmVjdGlvbkFuZFBvcHVwV2luZG93KCkge1xuICAgICAgICB0aGlzLnJlc2V0Q29ubmVjdGlvbigpO1xuICAgICAgICB0aGlzLnBvcHVwV2luZG93ID0gbnVsbDtcbiAgICB9XG4gICAgcmVzZXRDb25uZWN0aW9uKCkge1xuICAgICAgICB2YXIgX2EsIF9iO1xuICAgICAgICAoX2EgPSB0aGlzLmNvbm5lY3Rpb24pID09PSBudWxsIHx8IF9hID09PSB2b2lkIDAgPyB2b2lkIDAgOiBfYS5yZXNldEhhbmRsZXJzKCk7XG4gICAgICAgIHRoaXMuY29ubmVjdGlvbiA9IG51bGw7XG4gICAgICAgIChfYiA9IHRoaXMuY29ubmVjdGlvblVwZGF0ZWRDYWxsYmFjaykgPT09IG51bGwgfHwgX2IgPT09IHZvaWQgMCA/IHZvaWQgMCA6IF9iLmNhbGwodGhpcywgdGhpcy5jb25uZWN0aW9uKTtcbiAgICB9XG4gICAgdmVyaWZ5QW5kUmVzZXROb25jZSh1bmNoZWNrZWROb25jZSkge1xuICAgICAgICBpZiAoIXRoaXMubm9uY2UpIHtcbiAgICAgICAgICAgIHJldHVybiB0cnVlO1xuICAgICAgICB9XG4gICAgICAgIGNvbnN0IHJlc3VsdCA9IHVuY2hlY2tlZE5vbmNlID09PSB0aGlzLm5vbmNlO1xuICAgICAgICBpZiAocmVzdWx0KSB7XG4gICAgICAgICAgICB0aGlzLm5vbmNlID0gdW5kZWZpbmVkO1xuICAgICAgICB9XG4gICAgICAgIHJldHVybiByZXN1bHQ7XG4gICAgfVxufVxuLy8jIHNvdXJjZU1hcHBpbmdVUkw9Y29ubmVjdGlvbi1tYW5hZ2VyLmpzLm1hcCJdLCJuYW1lcyI6W10sInNvdXJjZVJvb3QiOiIifQ==\n//# sourceURL=webpack-internal:///./node_modules/@fractalwagmi/popup-connection/dist/esm/connection-manager.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@fractalwagmi/popup-connection/dist/esm/core/connection.js":
-/*!*********************************************************************************!*\
-  !*** ./node_modules/@fractalwagmi/popup-connection/dist/esm/core/connection.js ***!
-  \*********************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-"use strict";
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   Connection: function() { return /* binding */ Connection; }\n/* harmony export */ });\nclass Connection {\n    constructor(validatedOrigin, targetWindow) {\n        this.validatedOrigin = validatedOrigin;\n        this.targetWindow = targetWindow;\n        this.handlers = new Map();\n    }\n    off(event, callback) {\n        const eventCallbacks = this.handlers.get(event);\n        eventCallbacks === null || eventCallbacks === void 0 ? void 0 : eventCallbacks.delete(callback);\n    }\n    on(event, callback) {\n        var _a;\n        const eventCallbacks = (_a = this.handlers.get(event)) !== null && _a !== void 0 ? _a : new Set();\n        eventCallbacks.add(callback);\n        this.handlers.set(event, eventCallbacks);\n    }\n    send({ event, payload }) {\n        this.targetWindow.postMessage({\n            event,\n            payload,\n        }, this.validatedOrigin);\n    }\n    runHandlersForEvent(event, payload) {\n        const eventCallbacks = this.handlers.get(event);\n        if (!eventCallbacks) {\n            return;\n        }\n        for (const callback of eventCallbacks) {\n            callback(payload);\n        }\n    }\n    resetHandlers() {\n        this.handlers.clear();\n    }\n    export() {\n        return {\n            off: this.off.bind(this),\n            on: this.on.bind(this),\n            send: this.send.bind(this),\n            validatedOrigin: this.validatedOrigin,\n        };\n    }\n}\n//# sourceMappingURL=connection.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQGZyYWN0YWx3YWdtaS9wb3B1cC1jb25uZWN0aW9uL2Rpc3QvZXNtL2NvcmUvY29ubmVjdGlvbi5qcyIsIm1hcHBpbmdzIjoiOzs7O0FBQU87QUFDUDtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQSxXQUFXLGdCQUFnQjtBQUMzQjtBQUNBO0FBQ0E7QUFDQSxTQUFTO0FBQ1Q7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQSIsInNvdXJjZXMiOlsid2VicGFjazovL19OX0UvLi9ub2RlX21vZHVsZXMvQGZyYWN0YWx3YWdtaS9wb3B1cC1jb25uZWN0aW9uL2Rpc3QvZXNtL2NvcmUvY29ubmVjdGlvbi5qcz9lYjI5Il0sInNvdXJjZXNDb250ZW50IjpbImV4cG9ydCBjbGFzcyBDb25uZWN0aW9uIHtcbiAgICBjb25zdHJ1Y3Rvcih2YWxpZGF0ZWRPcmlnaW4sIHRhcmdldFdpbmRvdykge1xuICAgICAgICB0aGlzLnZhbGlkYXRlZE9yaWdpbiA9IHZhbGlkYXRlZE9yaWdpbjtcbiAgICAgICAgdGhpcy50YXJnZXRXaW5kb3cgPSB0YXJnZXRXaW5kb3c7XG4gICAgICAgIHRoaXMuaGFuZGxlcnMgPSBuZXcgTWFwKCk7XG4gICAgfVxuICAgIG9mZihldmVudCwgY2FsbGJhY2spIHtcbiAgICAgICAgY29uc3QgZXZlbnRDYWxsYmFja3MgPSB0aGlzLmhhbmRsZXJzLmdldChldmVudCk7XG4gICAgICAgIGV2ZW50Q2FsbGJhY2tzID09PSBudWxsIHx8IGV2ZW50Q2FsbGJhY2tzID09PSB2b2lkIDAgPyB2b2lkIDAgOiBldmVudENhbGxiYWNrcy5kZWxldGUoY2FsbGJhY2spO1xuICAgIH1cbiAgICBvbihldmVudCwgY2FsbGJhY2spIHtcbiAgICAgICAgdmFyIF9hO1xuICAgICAgICBjb25zdCBldmVudENhbGxiYWNrcyA9IChfYSA9IHRoaXMuaGFuZGxlcnMuZ2V0KGV2ZW50KSkgIT09IG51bGwgJiYgX2EgIT09IHZvaWQgMCA/IF9hIDogbmV3IFNldCgpO1xuICAgICAgICBldmVudENhbGxiYWNrcy5hZGQoY2FsbGJhY2spO1xuICAgICAgICB0aGlzLmhhbmRsZXJzLnNldChldmVudCwgZXZlbnRDYWxsYmFja3MpO1xuICAgIH1cbiAgICBzZW5kKHsgZXZlbnQsIHBheWxvYWQgfSkge1xuICAgICAgICB0aGlzLnRhcmdldFdpbmRvdy5wb3N0TWVzc2FnZSh7XG4gICAgICAgICAgICBldmVudCxcbiAgICAgICAgICAgIHBheWxvYWQsXG4gICAgICAgIH0sIHRoaXMudmFsaWRhdGVkT3JpZ2luKTtcbiAgICB9XG4gICAgcnVuSGFuZGxlcnNGb3JFdmVudChldmVudCwgcGF5bG9hZCkge1xuICAgICAgICBjb25zdCBldmVudENhbGxiYWNrcyA9IHRoaXMuaGFuZGxlcnMuZ2V0KGV2ZW50KTtcbiAgICAgICAgaWYgKCFldmVudENhbGxiYWNrcykge1xuICAgICAgICAgICAgcmV0dXJuO1xuICAgICAgICB9XG4gICAgICAgIGZvciAoY29uc3QgY2FsbGJhY2sgb2YgZXZlbnRDYWxsYmFja3MpIHtcbiAgICAgICAgICAgIGNhbGxiYWNrKHBheWxvYWQpO1xuICAgICAgICB9XG4gICAgfVxuICAgIHJlc2V0SGFuZGxlcnMoKSB7XG4gICAgICAgIHRoaXMuaGFuZGxlcnMuY2xlYXIoKTtcbiAgICB9XG4gICAgZXhwb3J0KCkge1xuICAgICAgICByZXR1cm4ge1xuICAgICAgICAgICAgb2ZmOiB0aGlzLm9mZi5iaW5kKHRoaXMpLFxuICAgICAgICAgICAgb246IHRoaXMub24uYmluZCh0aGlzKSxcbiAgICAgICAgICAgIHNlbmQ6IHRoaXMuc2VuZC5iaW5kKHRoaXMpLFxuICAgICAgICAgICAgdmFsaWRhdGVkT3JpZ2luOiB0aGlzLnZhbGlkYXRlZE9yaWdpbixcbiAgICAgICAgfTtcbiAgICB9XG59XG4vLyMgc291cmNlTWFwcGluZ1VSTD1jb25uZWN0aW9uLmpzLm1hcCJdLCJuYW1lcyI6W10sInNvdXJjZVJvb3QiOiIifQ==\n//# sourceURL=webpack-internal:///./node_modules/@fractalwagmi/popup-connection/dist/esm/core/connection.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@fractalwagmi/popup-connection/dist/esm/core/constants.js":
-/*!********************************************************************************!*\
-  !*** ./node_modules/@fractalwagmi/popup-connection/dist/esm/core/constants.js ***!
-  \********************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-"use strict";
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   DEFAULT_POPUP_HEIGHT_PX: function() { return /* binding */ DEFAULT_POPUP_HEIGHT_PX; },\n/* harmony export */   DEFAULT_POPUP_WIDTH_PX: function() { return /* binding */ DEFAULT_POPUP_WIDTH_PX; },\n/* harmony export */   FRACTAL_DOMAIN: function() { return /* binding */ FRACTAL_DOMAIN; },\n/* harmony export */   FRACTAL_DOMAIN_HTTPS: function() { return /* binding */ FRACTAL_DOMAIN_HTTPS; },\n/* harmony export */   FRACTAL_DOMAIN_HTTPS_WWW: function() { return /* binding */ FRACTAL_DOMAIN_HTTPS_WWW; }\n/* harmony export */ });\nconst FRACTAL_DOMAIN = 'fractal.is';\nconst FRACTAL_DOMAIN_HTTPS = 'https://fractal.is';\nconst FRACTAL_DOMAIN_HTTPS_WWW = 'https://www.fractal.is';\nconst DEFAULT_POPUP_WIDTH_PX = 400;\nconst DEFAULT_POPUP_HEIGHT_PX = 600;\n//# sourceMappingURL=constants.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQGZyYWN0YWx3YWdtaS9wb3B1cC1jb25uZWN0aW9uL2Rpc3QvZXNtL2NvcmUvY29uc3RhbnRzLmpzIiwibWFwcGluZ3MiOiI7Ozs7Ozs7O0FBQU87QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNQIiwic291cmNlcyI6WyJ3ZWJwYWNrOi8vX05fRS8uL25vZGVfbW9kdWxlcy9AZnJhY3RhbHdhZ21pL3BvcHVwLWNvbm5lY3Rpb24vZGlzdC9lc20vY29yZS9jb25zdGFudHMuanM/OGEyZCJdLCJzb3VyY2VzQ29udGVudCI6WyJleHBvcnQgY29uc3QgRlJBQ1RBTF9ET01BSU4gPSAnZnJhY3RhbC5pcyc7XG5leHBvcnQgY29uc3QgRlJBQ1RBTF9ET01BSU5fSFRUUFMgPSAnaHR0cHM6Ly9mcmFjdGFsLmlzJztcbmV4cG9ydCBjb25zdCBGUkFDVEFMX0RPTUFJTl9IVFRQU19XV1cgPSAnaHR0cHM6Ly93d3cuZnJhY3RhbC5pcyc7XG5leHBvcnQgY29uc3QgREVGQVVMVF9QT1BVUF9XSURUSF9QWCA9IDQwMDtcbmV4cG9ydCBjb25zdCBERUZBVUxUX1BPUFVQX0hFSUdIVF9QWCA9IDYwMDtcbi8vIyBzb3VyY2VNYXBwaW5nVVJMPWNvbnN0YW50cy5qcy5tYXAiXSwibmFtZXMiOltdLCJzb3VyY2VSb290IjoiIn0=\n//# sourceURL=webpack-internal:///./node_modules/@fractalwagmi/popup-connection/dist/esm/core/constants.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@fractalwagmi/popup-connection/dist/esm/core/types.js":
-/*!****************************************************************************!*\
-  !*** ./node_modules/@fractalwagmi/popup-connection/dist/esm/core/types.js ***!
-  \****************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-"use strict";
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   Platform: function() { return /* binding */ Platform; },\n/* harmony export */   PopupEvent: function() { return /* binding */ PopupEvent; }\n/* harmony export */ });\nvar PopupEvent;\n(function (PopupEvent) {\n    PopupEvent[\"PROJECT_APPROVED\"] = \"PROJECT_APPROVED\";\n    PopupEvent[\"HANDSHAKE\"] = \"HANDSHAKE\";\n    PopupEvent[\"HANDSHAKE_ACK\"] = \"HANDSHAKE_ACK\";\n    PopupEvent[\"SIGNED_TRANSACTION\"] = \"SIGNED_TRANSACTION\";\n    PopupEvent[\"SIGNED_PARTIAL_TRANSACTION\"] = \"SIGNED_PARTIAL_TRANSACTION\";\n    PopupEvent[\"FAILED_TO_SIGN_TRANSACTION\"] = \"FAILED_TO_SIGN_TRANSACTION\";\n    PopupEvent[\"TRANSACTION_DENIED\"] = \"TRANSACTION_DENIED\";\n    PopupEvent[\"SOLANA_WALLET_ADAPTER_APPROVED\"] = \"SOLANA_WALLET_ADAPTER_APPROVED\";\n    PopupEvent[\"SOLANA_WALLET_ADAPTER_DENIED\"] = \"SOLANA_WALLET_ADAPTER_DENIED\";\n    PopupEvent[\"POPUP_CLOSED\"] = \"POPUP_CLOSED\";\n    PopupEvent[\"TRANSACTION_SIGNATURE_NEEDED\"] = \"TRANSACTION_SIGNATURE_NEEDED\";\n    PopupEvent[\"TRANSACTION_SIGNATURE_NEEDED_RESPONSE\"] = \"TRANSACTION_SIGNATURE_NEEDED_RESPONSE\";\n    PopupEvent[\"AUTH_LOADED\"] = \"AUTH_LOADED\";\n    PopupEvent[\"MESSAGE_SIGNATURE_NEEDED\"] = \"MESSAGE_SIGNATURE_NEEDED\";\n    PopupEvent[\"MESSAGE_SIGNATURE_NEEDED_RESPONSE\"] = \"MESSAGE_SIGNATURE_NEEDED_RESPONSE\";\n    PopupEvent[\"ONRAMP_FULFILLMENT_COMPLETE\"] = \"ONRAMP_FULFILLMENT_COMPLETE\";\n    PopupEvent[\"ONRAMP_REJECTED\"] = \"ONRAMP_REJECTED\";\n})(PopupEvent || (PopupEvent = {}));\nvar Platform;\n(function (Platform) {\n    Platform[\"UNKNOWN\"] = \"UNKNOWN\";\n    Platform[\"REACT_SDK\"] = \"REACT_SDK\";\n    Platform[\"SOLANA_WALLET_ADAPTER\"] = \"SOLANA_WALLET_ADAPTER\";\n})(Platform || (Platform = {}));\n//# sourceMappingURL=types.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQGZyYWN0YWx3YWdtaS9wb3B1cC1jb25uZWN0aW9uL2Rpc3QvZXNtL2NvcmUvdHlwZXMuanMiLCJtYXBwaW5ncyI6Ijs7Ozs7QUFBTztBQUNQO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBLENBQUMsZ0NBQWdDO0FBQzFCO0FBQ1A7QUFDQTtBQUNBO0FBQ0E7QUFDQSxDQUFDLDRCQUE0QjtBQUM3QiIsInNvdXJjZXMiOlsid2VicGFjazovL19OX0UvLi9ub2RlX21vZHVsZXMvQGZyYWN0YWx3YWdtaS9wb3B1cC1jb25uZWN0aW9uL2Rpc3QvZXNtL2NvcmUvdHlwZXMuanM/NTc1YSJdLCJzb3VyY2VzQ29udGVudCI6WyJleHBvcnQgdmFyIFBvcHVwRXZlbnQ7XG4oZnVuY3Rpb24gKFBvcHVwRXZlbnQpIHtcbiAgICBQb3B1cEV2ZW50W1wiUFJPSkVDVF9BUFBST1ZFRFwiXSA9IFwiUFJPSkVDVF9BUFBST1ZFRFwiO1xuICAgIFBvcHVwRXZlbnRbXCJIQU5EU0hBS0VcIl0gPSBcIkhBTkRTSEFLRVwiO1xuICAgIFBvcHVwRXZlbnRbXCJIQU5EU0hBS0VfQUNLXCJdID0gXCJIQU5EU0hBS0VfQUNLXCI7XG4gICAgUG9wdXBFdmVudFtcIlNJR05FRF9UUkFOU0FDVElPTlwiXSA9IFwiU0lHTkVEX1RSQU5TQUNUSU9OXCI7XG4gICAgUG9wdXBFdmVudFtcIlNJR05FRF9QQVJUSUFMX1RSQU5TQUNUSU9OXCJdID0gXCJTSUdORURfUEFSVElBTF9UUkFOU0FDVElPTlwiO1xuICAgIFBvcHVwRXZlbnRbXCJGQUlMRURfVE9fU0lHTl9UUkFOU0FDVElPTlwiXSA9IFwiRkFJTEVEX1RPX1NJR05fVFJBTlNBQ1RJT05cIjtcbiAgICBQb3B1cEV2ZW50W1wiVFJBTlNBQ1RJT05fREVOSUVEXCJdID0gXCJUUkFOU0FDVElPTl9ERU5JRURcIjtcbiAgICBQb3B1cEV2ZW50W1wiU09MQU5BX1dBTExFVF9BREFQVEVSX0FQUFJPVkVEXCJdID0gXCJTT0xBTkFfV0FMTEVUX0FEQVBURVJfQVBQUk9WRURcIjtcbiAgICBQb3B1cEV2ZW50W1wiU09MQU5BX1dBTExFVF9BREFQVEVSX0RFTklFRFwiXSA9IFwiU09MQU5BX1dBTExFVF9BREFQVEVSX0RFTklFRFwiO1xuICAgIFBvcHVwRXZlbnRbXCJQT1BVUF9DTE9TRURcIl0gPSBcIlBPUFVQX0NMT1NFRFwiO1xuICAgIFBvcHVwRXZlbnRbXCJUUkFOU0FDVElPTl9TSUdOQVRVUkVfTkVFREVEXCJdID0gXCJUUkFOU0FDVElPTl9TSUdOQVRVUkVfTkVFREVEXCI7XG4gICAgUG9wdXBFdmVudFtcIlRSQU5TQUNUSU9OX1NJR05BVFVSRV9ORUVERURfUkVTUE9OU0VcIl0gPSBcIlRSQU5TQUNUSU9OX1NJR05BVFVSRV9ORUVERURfUkVTUE9OU0VcIjtcbiAgICBQb3B1cEV2ZW50W1wiQVVUSF9MT0FERURcIl0gPSBcIkFVVEhfTE9BREVEXCI7XG4gICAgUG9wdXBFdmVudFtcIk1FU1NBR0VfU0lHTkFUVVJFX05FRURFRFwiXSA9IFwiTUVTU0FHRV9TSUdOQVRVUkVfTkVFREVEXCI7XG4gICAgUG9wdXBFdmVudFtcIk1FU1NBR0VfU0lHTkFUVVJFX05FRURFRF9SRVNQT05TRVwiXSA9IFwiTUVTU0FHRV9TSUdOQVRVUkVfTkVFREVEX1JFU1BPTlNFXCI7XG4gICAgUG9wdXBFdmVudFtcIk9OUkFNUF9GVUxGSUxMTUVOVF9DT01QTEVURVwiXSA9IFwiT05SQU1QX0ZVTEZJTExNRU5UX0NPTVBMRVRFXCI7XG4gICAgUG9wdXBFdmVudFtcIk9OUkFNUF9SRUpFQ1RFRFwiXSA9IFwiT05SQU1QX1JFSkVDVEVEXCI7XG59KShQb3B1cEV2ZW50IHx8IChQb3B1cEV2ZW50ID0ge30pKTtcbmV4cG9ydCB2YXIgUGxhdGZvcm07XG4oZnVuY3Rpb24gKFBsYXRmb3JtKSB7XG4gICAgUGxhdGZvcm1bXCJVTktOT1dOXCJdID0gXCJVTktOT1dOXCI7XG4gICAgUGxhdGZvcm1bXCJSRUFDVF9TREtcIl0gPSBcIlJFQUNUX1NES1wiO1xuICAgIFBsYXRmb3JtW1wiU09MQU5BX1dBTExFVF9BREFQVEVSXCJdID0gXCJTT0xBTkFfV0FMTEVUX0FEQVBURVJcIjtcbn0pKFBsYXRmb3JtIHx8IChQbGF0Zm9ybSA9IHt9KSk7XG4vLyMgc291cmNlTWFwcGluZ1VSTD10eXBlcy5qcy5tYXAiXSwibmFtZXMiOltdLCJzb3VyY2VSb290IjoiIn0=\n//# sourceURL=webpack-internal:///./node_modules/@fractalwagmi/popup-connection/dist/esm/core/types.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@fractalwagmi/popup-connection/dist/esm/core/utils.js":
-/*!****************************************************************************!*\
-  !*** ./node_modules/@fractalwagmi/popup-connection/dist/esm/core/utils.js ***!
-  \****************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-"use strict";
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   openPopup: function() { return /* binding */ openPopup; },\n/* harmony export */   validateOrigin: function() { return /* binding */ validateOrigin; }\n/* harmony export */ });\n/* harmony import */ var _constants_js__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! ./constants.js */ \"./node_modules/@fractalwagmi/popup-connection/dist/esm/core/constants.js\");\n\nfunction validateOrigin(origin) {\n    return origin === _constants_js__WEBPACK_IMPORTED_MODULE_0__.FRACTAL_DOMAIN_HTTPS_WWW || origin === _constants_js__WEBPACK_IMPORTED_MODULE_0__.FRACTAL_DOMAIN_HTTPS;\n}\nconst TARGET = 'fractal:approval:popup';\nconst STATIC_POPUP_FEATURES = ['resizable', 'scrollbars=1', 'status=1'];\nfunction openPopup({ left = 0, scope = window, top = 0, width = _constants_js__WEBPACK_IMPORTED_MODULE_0__.DEFAULT_POPUP_HEIGHT_PX, height = _constants_js__WEBPACK_IMPORTED_MODULE_0__.DEFAULT_POPUP_HEIGHT_PX, url, }) {\n    return scope.open(url, TARGET, getPopupFeatures({ height, left, top, width }));\n}\nfunction getPopupFeatures({ height, left, top, width, }) {\n    return [\n        'popup',\n        `left=${left}`,\n        `top=${top}`,\n        `width=${width}`,\n        `height=${height}`,\n        ...STATIC_POPUP_FEATURES,\n    ].join(',');\n}\n//# sourceMappingURL=utils.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQGZyYWN0YWx3YWdtaS9wb3B1cC1jb25uZWN0aW9uL2Rpc3QvZXNtL2NvcmUvdXRpbHMuanMiLCJtYXBwaW5ncyI6Ijs7Ozs7O0FBQTBHO0FBQ25HO0FBQ1Asc0JBQXNCLG1FQUF3QixlQUFlLCtEQUFvQjtBQUNqRjtBQUNBO0FBQ0E7QUFDTyxxQkFBcUIsMkNBQTJDLGtFQUF1QixXQUFXLGtFQUF1QixRQUFRO0FBQ3hJLHNEQUFzRCwwQkFBMEI7QUFDaEY7QUFDQSw0QkFBNEIsMkJBQTJCO0FBQ3ZEO0FBQ0E7QUFDQSxnQkFBZ0IsS0FBSztBQUNyQixlQUFlLElBQUk7QUFDbkIsaUJBQWlCLE1BQU07QUFDdkIsa0JBQWtCLE9BQU87QUFDekI7QUFDQTtBQUNBO0FBQ0EiLCJzb3VyY2VzIjpbIndlYnBhY2s6Ly9fTl9FLy4vbm9kZV9tb2R1bGVzL0BmcmFjdGFsd2FnbWkvcG9wdXAtY29ubmVjdGlvbi9kaXN0L2VzbS9jb3JlL3V0aWxzLmpzPzcxY2UiXSwic291cmNlc0NvbnRlbnQiOlsiaW1wb3J0IHsgRlJBQ1RBTF9ET01BSU5fSFRUUFNfV1dXLCBGUkFDVEFMX0RPTUFJTl9IVFRQUywgREVGQVVMVF9QT1BVUF9IRUlHSFRfUFgsIH0gZnJvbSBcIi4vY29uc3RhbnRzLmpzXCI7XG5leHBvcnQgZnVuY3Rpb24gdmFsaWRhdGVPcmlnaW4ob3JpZ2luKSB7XG4gICAgcmV0dXJuIG9yaWdpbiA9PT0gRlJBQ1RBTF9ET01BSU5fSFRUUFNfV1dXIHx8IG9yaWdpbiA9PT0gRlJBQ1RBTF9ET01BSU5fSFRUUFM7XG59XG5jb25zdCBUQVJHRVQgPSAnZnJhY3RhbDphcHByb3ZhbDpwb3B1cCc7XG5jb25zdCBTVEFUSUNfUE9QVVBfRkVBVFVSRVMgPSBbJ3Jlc2l6YWJsZScsICdzY3JvbGxiYXJzPTEnLCAnc3RhdHVzPTEnXTtcbmV4cG9ydCBmdW5jdGlvbiBvcGVuUG9wdXAoeyBsZWZ0ID0gMCwgc2NvcGUgPSB3aW5kb3csIHRvcCA9IDAsIHdpZHRoID0gREVGQVVMVF9QT1BVUF9IRUlHSFRfUFgsIGhlaWdodCA9IERFRkFVTFRfUE9QVVBfSEVJR0hUX1BYLCB1cmwsIH0pIHtcbiAgICByZXR1cm4gc2NvcGUub3Blbih1cmwsIFRBUkdFVCwgZ2V0UG9wdXBGZWF0dXJlcyh7IGhlaWdodCwgbGVmdCwgdG9wLCB3aWR0aCB9KSk7XG59XG5mdW5jdGlvbiBnZXRQb3B1cEZlYXR1cmVzKHsgaGVpZ2h0LCBsZWZ0LCB0b3AsIHdpZHRoLCB9KSB7XG4gICAgcmV0dXJuIFtcbiAgICAgICAgJ3BvcHVwJyxcbiAgICAgICAgYGxlZnQ9JHtsZWZ0fWAsXG4gICAgICAgIGB0b3A9JHt0b3B9YCxcbiAgICAgICAgYHdpZHRoPSR7d2lkdGh9YCxcbiAgICAgICAgYGhlaWdodD0ke2hlaWdodH1gLFxuICAgICAgICAuLi5TVEFUSUNfUE9QVVBfRkVBVFVSRVMsXG4gICAgXS5qb2luKCcsJyk7XG59XG4vLyMgc291cmNlTWFwcGluZ1VSTD11dGlscy5qcy5tYXAiXSwibmFtZXMiOltdLCJzb3VyY2VSb290IjoiIn0=\n//# sourceURL=webpack-internal:///./node_modules/@fractalwagmi/popup-connection/dist/esm/core/utils.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@fractalwagmi/popup-connection/dist/esm/index.js":
-/*!***********************************************************************!*\
-  !*** ./node_modules/@fractalwagmi/popup-connection/dist/esm/index.js ***!
-  \***********************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-"use strict";
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   Connection: function() { return /* reexport safe */ _core_connection_js__WEBPACK_IMPORTED_MODULE_1__.Connection; },\n/* harmony export */   DEFAULT_POPUP_HEIGHT_PX: function() { return /* reexport safe */ _core_constants_js__WEBPACK_IMPORTED_MODULE_5__.DEFAULT_POPUP_HEIGHT_PX; },\n/* harmony export */   DEFAULT_POPUP_WIDTH_PX: function() { return /* reexport safe */ _core_constants_js__WEBPACK_IMPORTED_MODULE_5__.DEFAULT_POPUP_WIDTH_PX; },\n/* harmony export */   Platform: function() { return /* reexport safe */ _core_types_js__WEBPACK_IMPORTED_MODULE_0__.Platform; },\n/* harmony export */   PopupEvent: function() { return /* reexport safe */ _core_types_js__WEBPACK_IMPORTED_MODULE_0__.PopupEvent; }\n/* harmony export */ });\n/* harmony import */ var _core_types_js__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! ./core/types.js */ \"./node_modules/@fractalwagmi/popup-connection/dist/esm/core/types.js\");\n/* harmony import */ var _core_connection_js__WEBPACK_IMPORTED_MODULE_1__ = __webpack_require__(/*! ./core/connection.js */ \"./node_modules/@fractalwagmi/popup-connection/dist/esm/core/connection.js\");\n/* harmony import */ var _use_popup_connection_js__WEBPACK_IMPORTED_MODULE_2__ = __webpack_require__(/*! ./use-popup-connection.js */ \"./node_modules/@fractalwagmi/popup-connection/dist/esm/use-popup-connection.js\");\n/* harmony reexport (unknown) */ var __WEBPACK_REEXPORT_OBJECT__ = {};\n/* harmony reexport (unknown) */ for(var __WEBPACK_IMPORT_KEY__ in _use_popup_connection_js__WEBPACK_IMPORTED_MODULE_2__) if([\"default\",\"PopupEvent\",\"Platform\",\"Connection\",\"DEFAULT_POPUP_HEIGHT_PX\",\"DEFAULT_POPUP_WIDTH_PX\"].indexOf(__WEBPACK_IMPORT_KEY__) < 0) __WEBPACK_REEXPORT_OBJECT__[__WEBPACK_IMPORT_KEY__] = function(key) { return _use_popup_connection_js__WEBPACK_IMPORTED_MODULE_2__[key]; }.bind(0, __WEBPACK_IMPORT_KEY__)\n/* harmony reexport (unknown) */ __webpack_require__.d(__webpack_exports__, __WEBPACK_REEXPORT_OBJECT__);\n/* harmony import */ var _connection_manager_js__WEBPACK_IMPORTED_MODULE_3__ = __webpack_require__(/*! ./connection-manager.js */ \"./node_modules/@fractalwagmi/popup-connection/dist/esm/connection-manager.js\");\n/* harmony reexport (unknown) */ var __WEBPACK_REEXPORT_OBJECT__ = {};\n/* harmony reexport (unknown) */ for(var __WEBPACK_IMPORT_KEY__ in _connection_manager_js__WEBPACK_IMPORTED_MODULE_3__) if([\"default\",\"PopupEvent\",\"Platform\",\"Connection\",\"DEFAULT_POPUP_HEIGHT_PX\",\"DEFAULT_POPUP_WIDTH_PX\"].indexOf(__WEBPACK_IMPORT_KEY__) < 0) __WEBPACK_REEXPORT_OBJECT__[__WEBPACK_IMPORT_KEY__] = function(key) { return _connection_manager_js__WEBPACK_IMPORTED_MODULE_3__[key]; }.bind(0, __WEBPACK_IMPORT_KEY__)\n/* harmony reexport (unknown) */ __webpack_require__.d(__webpack_exports__, __WEBPACK_REEXPORT_OBJECT__);\n/* harmony import */ var _payloads_index_js__WEBPACK_IMPORTED_MODULE_4__ = __webpack_require__(/*! ./payloads/index.js */ \"./node_modules/@fractalwagmi/popup-connection/dist/esm/payloads/index.js\");\n/* harmony reexport (unknown) */ var __WEBPACK_REEXPORT_OBJECT__ = {};\n/* harmony reexport (unknown) */ for(var __WEBPACK_IMPORT_KEY__ in _payloads_index_js__WEBPACK_IMPORTED_MODULE_4__) if([\"default\",\"PopupEvent\",\"Platform\",\"Connection\",\"DEFAULT_POPUP_HEIGHT_PX\",\"DEFAULT_POPUP_WIDTH_PX\"].indexOf(__WEBPACK_IMPORT_KEY__) < 0) __WEBPACK_REEXPORT_OBJECT__[__WEBPACK_IMPORT_KEY__] = function(key) { return _payloads_index_js__WEBPACK_IMPORTED_MODULE_4__[key]; }.bind(0, __WEBPACK_IMPORT_KEY__)\n/* harmony reexport (unknown) */ __webpack_require__.d(__webpack_exports__, __WEBPACK_REEXPORT_OBJECT__);\n/* harmony import */ var _core_constants_js__WEBPACK_IMPORTED_MODULE_5__ = __webpack_require__(/*! ./core/constants.js */ \"./node_modules/@fractalwagmi/popup-connection/dist/esm/core/constants.js\");\n\n\n\n\n\n\n//# sourceMappingURL=index.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQGZyYWN0YWx3YWdtaS9wb3B1cC1jb25uZWN0aW9uL2Rpc3QvZXNtL2luZGV4LmpzIiwibWFwcGluZ3MiOiI7Ozs7Ozs7Ozs7Ozs7Ozs7Ozs7Ozs7O0FBQXVEO0FBQ0w7QUFDUjtBQUNGO0FBQ0o7QUFDbUQ7QUFDdkYiLCJzb3VyY2VzIjpbIndlYnBhY2s6Ly9fTl9FLy4vbm9kZV9tb2R1bGVzL0BmcmFjdGFsd2FnbWkvcG9wdXAtY29ubmVjdGlvbi9kaXN0L2VzbS9pbmRleC5qcz9kNWUwIl0sInNvdXJjZXNDb250ZW50IjpbImV4cG9ydCB7IFBvcHVwRXZlbnQsIFBsYXRmb3JtIH0gZnJvbSBcIi4vY29yZS90eXBlcy5qc1wiO1xuZXhwb3J0IHsgQ29ubmVjdGlvbiB9IGZyb20gXCIuL2NvcmUvY29ubmVjdGlvbi5qc1wiO1xuZXhwb3J0ICogZnJvbSBcIi4vdXNlLXBvcHVwLWNvbm5lY3Rpb24uanNcIjtcbmV4cG9ydCAqIGZyb20gXCIuL2Nvbm5lY3Rpb24tbWFuYWdlci5qc1wiO1xuZXhwb3J0ICogZnJvbSBcIi4vcGF5bG9hZHMvaW5kZXguanNcIjtcbmV4cG9ydCB7IERFRkFVTFRfUE9QVVBfSEVJR0hUX1BYLCBERUZBVUxUX1BPUFVQX1dJRFRIX1BYLCB9IGZyb20gXCIuL2NvcmUvY29uc3RhbnRzLmpzXCI7XG4vLyMgc291cmNlTWFwcGluZ1VSTD1pbmRleC5qcy5tYXAiXSwibmFtZXMiOltdLCJzb3VyY2VSb290IjoiIn0=\n//# sourceURL=webpack-internal:///./node_modules/@fractalwagmi/popup-connection/dist/esm/index.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@fractalwagmi/popup-connection/dist/esm/lib/guards.js":
-/*!****************************************************************************!*\
-  !*** ./node_modules/@fractalwagmi/popup-connection/dist/esm/lib/guards.js ***!
-  \****************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-"use strict";
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   isObject: function() { return /* binding */ isObject; }\n/* harmony export */ });\nfunction isObject(value) {\n    if (value === null) {\n        return false;\n    }\n    if (typeof value !== 'object') {\n        return false;\n    }\n    return true;\n}\n//# sourceMappingURL=guards.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQGZyYWN0YWx3YWdtaS9wb3B1cC1jb25uZWN0aW9uL2Rpc3QvZXNtL2xpYi9ndWFyZHMuanMiLCJtYXBwaW5ncyI6Ijs7OztBQUFPO0FBQ1A7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBIiwic291cmNlcyI6WyJ3ZWJwYWNrOi8vX05fRS8uL25vZGVfbW9kdWxlcy9AZnJhY3RhbHdhZ21pL3BvcHVwLWNvbm5lY3Rpb24vZGlzdC9lc20vbGliL2d1YXJkcy5qcz8zYzg3Il0sInNvdXJjZXNDb250ZW50IjpbImV4cG9ydCBmdW5jdGlvbiBpc09iamVjdCh2YWx1ZSkge1xuICAgIGlmICh2YWx1ZSA9PT0gbnVsbCkge1xuICAgICAgICByZXR1cm4gZmFsc2U7XG4gICAgfVxuICAgIGlmICh0eXBlb2YgdmFsdWUgIT09ICdvYmplY3QnKSB7XG4gICAgICAgIHJldHVybiBmYWxzZTtcbiAgICB9XG4gICAgcmV0dXJuIHRydWU7XG59XG4vLyMgc291cmNlTWFwcGluZ1VSTD1ndWFyZHMuanMubWFwIl0sIm5hbWVzIjpbXSwic291cmNlUm9vdCI6IiJ9\n//# sourceURL=webpack-internal:///./node_modules/@fractalwagmi/popup-connection/dist/esm/lib/guards.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@fractalwagmi/popup-connection/dist/esm/payloads/index.js":
-/*!********************************************************************************!*\
-  !*** ./node_modules/@fractalwagmi/popup-connection/dist/esm/payloads/index.js ***!
-  \********************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-"use strict";
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony import */ var _solana_wallet_adapter_approved_js__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! ./solana-wallet-adapter-approved.js */ \"./node_modules/@fractalwagmi/popup-connection/dist/esm/payloads/solana-wallet-adapter-approved.js\");\n/* harmony reexport (unknown) */ var __WEBPACK_REEXPORT_OBJECT__ = {};\n/* harmony reexport (unknown) */ for(var __WEBPACK_IMPORT_KEY__ in _solana_wallet_adapter_approved_js__WEBPACK_IMPORTED_MODULE_0__) if(__WEBPACK_IMPORT_KEY__ !== \"default\") __WEBPACK_REEXPORT_OBJECT__[__WEBPACK_IMPORT_KEY__] = function(key) { return _solana_wallet_adapter_approved_js__WEBPACK_IMPORTED_MODULE_0__[key]; }.bind(0, __WEBPACK_IMPORT_KEY__)\n/* harmony reexport (unknown) */ __webpack_require__.d(__webpack_exports__, __WEBPACK_REEXPORT_OBJECT__);\n/* harmony import */ var _transaction_signature_needed_js__WEBPACK_IMPORTED_MODULE_1__ = __webpack_require__(/*! ./transaction-signature-needed.js */ \"./node_modules/@fractalwagmi/popup-connection/dist/esm/payloads/transaction-signature-needed.js\");\n/* harmony reexport (unknown) */ var __WEBPACK_REEXPORT_OBJECT__ = {};\n/* harmony reexport (unknown) */ for(var __WEBPACK_IMPORT_KEY__ in _transaction_signature_needed_js__WEBPACK_IMPORTED_MODULE_1__) if(__WEBPACK_IMPORT_KEY__ !== \"default\") __WEBPACK_REEXPORT_OBJECT__[__WEBPACK_IMPORT_KEY__] = function(key) { return _transaction_signature_needed_js__WEBPACK_IMPORTED_MODULE_1__[key]; }.bind(0, __WEBPACK_IMPORT_KEY__)\n/* harmony reexport (unknown) */ __webpack_require__.d(__webpack_exports__, __WEBPACK_REEXPORT_OBJECT__);\n/* harmony import */ var _transaction_signature_needed_response_js__WEBPACK_IMPORTED_MODULE_2__ = __webpack_require__(/*! ./transaction-signature-needed-response.js */ \"./node_modules/@fractalwagmi/popup-connection/dist/esm/payloads/transaction-signature-needed-response.js\");\n/* harmony reexport (unknown) */ var __WEBPACK_REEXPORT_OBJECT__ = {};\n/* harmony reexport (unknown) */ for(var __WEBPACK_IMPORT_KEY__ in _transaction_signature_needed_response_js__WEBPACK_IMPORTED_MODULE_2__) if(__WEBPACK_IMPORT_KEY__ !== \"default\") __WEBPACK_REEXPORT_OBJECT__[__WEBPACK_IMPORT_KEY__] = function(key) { return _transaction_signature_needed_response_js__WEBPACK_IMPORTED_MODULE_2__[key]; }.bind(0, __WEBPACK_IMPORT_KEY__)\n/* harmony reexport (unknown) */ __webpack_require__.d(__webpack_exports__, __WEBPACK_REEXPORT_OBJECT__);\n/* harmony import */ var _message_signature_needed_js__WEBPACK_IMPORTED_MODULE_3__ = __webpack_require__(/*! ./message-signature-needed.js */ \"./node_modules/@fractalwagmi/popup-connection/dist/esm/payloads/message-signature-needed.js\");\n/* harmony reexport (unknown) */ var __WEBPACK_REEXPORT_OBJECT__ = {};\n/* harmony reexport (unknown) */ for(var __WEBPACK_IMPORT_KEY__ in _message_signature_needed_js__WEBPACK_IMPORTED_MODULE_3__) if(__WEBPACK_IMPORT_KEY__ !== \"default\") __WEBPACK_REEXPORT_OBJECT__[__WEBPACK_IMPORT_KEY__] = function(key) { return _message_signature_needed_js__WEBPACK_IMPORTED_MODULE_3__[key]; }.bind(0, __WEBPACK_IMPORT_KEY__)\n/* harmony reexport (unknown) */ __webpack_require__.d(__webpack_exports__, __WEBPACK_REEXPORT_OBJECT__);\n/* harmony import */ var _message_signature_needed_response_js__WEBPACK_IMPORTED_MODULE_4__ = __webpack_require__(/*! ./message-signature-needed-response.js */ \"./node_modules/@fractalwagmi/popup-connection/dist/esm/payloads/message-signature-needed-response.js\");\n/* harmony reexport (unknown) */ var __WEBPACK_REEXPORT_OBJECT__ = {};\n/* harmony reexport (unknown) */ for(var __WEBPACK_IMPORT_KEY__ in _message_signature_needed_response_js__WEBPACK_IMPORTED_MODULE_4__) if(__WEBPACK_IMPORT_KEY__ !== \"default\") __WEBPACK_REEXPORT_OBJECT__[__WEBPACK_IMPORT_KEY__] = function(key) { return _message_signature_needed_response_js__WEBPACK_IMPORTED_MODULE_4__[key]; }.bind(0, __WEBPACK_IMPORT_KEY__)\n/* harmony reexport (unknown) */ __webpack_require__.d(__webpack_exports__, __WEBPACK_REEXPORT_OBJECT__);\n/* harmony import */ var _signed_partial_transaction_js__WEBPACK_IMPORTED_MODULE_5__ = __webpack_require__(/*! ./signed-partial-transaction.js */ \"./node_modules/@fractalwagmi/popup-connection/dist/esm/payloads/signed-partial-transaction.js\");\n/* harmony reexport (unknown) */ var __WEBPACK_REEXPORT_OBJECT__ = {};\n/* harmony reexport (unknown) */ for(var __WEBPACK_IMPORT_KEY__ in _signed_partial_transaction_js__WEBPACK_IMPORTED_MODULE_5__) if(__WEBPACK_IMPORT_KEY__ !== \"default\") __WEBPACK_REEXPORT_OBJECT__[__WEBPACK_IMPORT_KEY__] = function(key) { return _signed_partial_transaction_js__WEBPACK_IMPORTED_MODULE_5__[key]; }.bind(0, __WEBPACK_IMPORT_KEY__)\n/* harmony reexport (unknown) */ __webpack_require__.d(__webpack_exports__, __WEBPACK_REEXPORT_OBJECT__);\n\n\n\n\n\n\n//# sourceMappingURL=index.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQGZyYWN0YWx3YWdtaS9wb3B1cC1jb25uZWN0aW9uL2Rpc3QvZXNtL3BheWxvYWRzL2luZGV4LmpzIiwibWFwcGluZ3MiOiI7Ozs7Ozs7Ozs7Ozs7Ozs7Ozs7Ozs7Ozs7QUFBb0Q7QUFDRjtBQUNTO0FBQ2I7QUFDUztBQUNQO0FBQ2hEIiwic291cmNlcyI6WyJ3ZWJwYWNrOi8vX05fRS8uL25vZGVfbW9kdWxlcy9AZnJhY3RhbHdhZ21pL3BvcHVwLWNvbm5lY3Rpb24vZGlzdC9lc20vcGF5bG9hZHMvaW5kZXguanM/YmVmYiJdLCJzb3VyY2VzQ29udGVudCI6WyJleHBvcnQgKiBmcm9tIFwiLi9zb2xhbmEtd2FsbGV0LWFkYXB0ZXItYXBwcm92ZWQuanNcIjtcbmV4cG9ydCAqIGZyb20gXCIuL3RyYW5zYWN0aW9uLXNpZ25hdHVyZS1uZWVkZWQuanNcIjtcbmV4cG9ydCAqIGZyb20gXCIuL3RyYW5zYWN0aW9uLXNpZ25hdHVyZS1uZWVkZWQtcmVzcG9uc2UuanNcIjtcbmV4cG9ydCAqIGZyb20gXCIuL21lc3NhZ2Utc2lnbmF0dXJlLW5lZWRlZC5qc1wiO1xuZXhwb3J0ICogZnJvbSBcIi4vbWVzc2FnZS1zaWduYXR1cmUtbmVlZGVkLXJlc3BvbnNlLmpzXCI7XG5leHBvcnQgKiBmcm9tIFwiLi9zaWduZWQtcGFydGlhbC10cmFuc2FjdGlvbi5qc1wiO1xuLy8jIHNvdXJjZU1hcHBpbmdVUkw9aW5kZXguanMubWFwIl0sIm5hbWVzIjpbXSwic291cmNlUm9vdCI6IiJ9\n//# sourceURL=webpack-internal:///./node_modules/@fractalwagmi/popup-connection/dist/esm/payloads/index.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@fractalwagmi/popup-connection/dist/esm/payloads/message-signature-needed-response.js":
-/*!************************************************************************************************************!*\
-  !*** ./node_modules/@fractalwagmi/popup-connection/dist/esm/payloads/message-signature-needed-response.js ***!
-  \************************************************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-"use strict";
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   assertPayloadIsMessageSignatureNeededResponsePayload: function() { return /* binding */ assertPayloadIsMessageSignatureNeededResponsePayload; }\n/* harmony export */ });\n/* harmony import */ var _lib_guards_js__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! ../lib/guards.js */ \"./node_modules/@fractalwagmi/popup-connection/dist/esm/lib/guards.js\");\n\nfunction assertPayloadIsMessageSignatureNeededResponsePayload(payload) {\n    if (!(0,_lib_guards_js__WEBPACK_IMPORTED_MODULE_0__.isObject)(payload)) {\n        return false;\n    }\n    if (!('decodedSignature' in payload)) {\n        return false;\n    }\n    return typeof payload.decodedSignature === 'string';\n}\n//# sourceMappingURL=message-signature-needed-response.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQGZyYWN0YWx3YWdtaS9wb3B1cC1jb25uZWN0aW9uL2Rpc3QvZXNtL3BheWxvYWRzL21lc3NhZ2Utc2lnbmF0dXJlLW5lZWRlZC1yZXNwb25zZS5qcyIsIm1hcHBpbmdzIjoiOzs7OztBQUE0QztBQUNyQztBQUNQLFNBQVMsd0RBQVE7QUFDakI7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQSIsInNvdXJjZXMiOlsid2VicGFjazovL19OX0UvLi9ub2RlX21vZHVsZXMvQGZyYWN0YWx3YWdtaS9wb3B1cC1jb25uZWN0aW9uL2Rpc3QvZXNtL3BheWxvYWRzL21lc3NhZ2Utc2lnbmF0dXJlLW5lZWRlZC1yZXNwb25zZS5qcz9kYTI2Il0sInNvdXJjZXNDb250ZW50IjpbImltcG9ydCB7IGlzT2JqZWN0IH0gZnJvbSBcIi4uL2xpYi9ndWFyZHMuanNcIjtcbmV4cG9ydCBmdW5jdGlvbiBhc3NlcnRQYXlsb2FkSXNNZXNzYWdlU2lnbmF0dXJlTmVlZGVkUmVzcG9uc2VQYXlsb2FkKHBheWxvYWQpIHtcbiAgICBpZiAoIWlzT2JqZWN0KHBheWxvYWQpKSB7XG4gICAgICAgIHJldHVybiBmYWxzZTtcbiAgICB9XG4gICAgaWYgKCEoJ2RlY29kZWRTaWduYXR1cmUnIGluIHBheWxvYWQpKSB7XG4gICAgICAgIHJldHVybiBmYWxzZTtcbiAgICB9XG4gICAgcmV0dXJuIHR5cGVvZiBwYXlsb2FkLmRlY29kZWRTaWduYXR1cmUgPT09ICdzdHJpbmcnO1xufVxuLy8jIHNvdXJjZU1hcHBpbmdVUkw9bWVzc2FnZS1zaWduYXR1cmUtbmVlZGVkLXJlc3BvbnNlLmpzLm1hcCJdLCJuYW1lcyI6W10sInNvdXJjZVJvb3QiOiIifQ==\n//# sourceURL=webpack-internal:///./node_modules/@fractalwagmi/popup-connection/dist/esm/payloads/message-signature-needed-response.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@fractalwagmi/popup-connection/dist/esm/payloads/message-signature-needed.js":
-/*!***************************************************************************************************!*\
-  !*** ./node_modules/@fractalwagmi/popup-connection/dist/esm/payloads/message-signature-needed.js ***!
-  \***************************************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-"use strict";
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   assertPayloadIsMessageSignatureNeededPayload: function() { return /* binding */ assertPayloadIsMessageSignatureNeededPayload; }\n/* harmony export */ });\n/* harmony import */ var _lib_guards_js__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! ../lib/guards.js */ \"./node_modules/@fractalwagmi/popup-connection/dist/esm/lib/guards.js\");\n\nfunction assertPayloadIsMessageSignatureNeededPayload(payload) {\n    if (!(0,_lib_guards_js__WEBPACK_IMPORTED_MODULE_0__.isObject)(payload)) {\n        return false;\n    }\n    if (!('decodedMessage' in payload)) {\n        return false;\n    }\n    return typeof payload.decodedMessage === 'string';\n}\n//# sourceMappingURL=message-signature-needed.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQGZyYWN0YWx3YWdtaS9wb3B1cC1jb25uZWN0aW9uL2Rpc3QvZXNtL3BheWxvYWRzL21lc3NhZ2Utc2lnbmF0dXJlLW5lZWRlZC5qcyIsIm1hcHBpbmdzIjoiOzs7OztBQUE0QztBQUNyQztBQUNQLFNBQVMsd0RBQVE7QUFDakI7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQSIsInNvdXJjZXMiOlsid2VicGFjazovL19OX0UvLi9ub2RlX21vZHVsZXMvQGZyYWN0YWx3YWdtaS9wb3B1cC1jb25uZWN0aW9uL2Rpc3QvZXNtL3BheWxvYWRzL21lc3NhZ2Utc2lnbmF0dXJlLW5lZWRlZC5qcz9kZWRjIl0sInNvdXJjZXNDb250ZW50IjpbImltcG9ydCB7IGlzT2JqZWN0IH0gZnJvbSBcIi4uL2xpYi9ndWFyZHMuanNcIjtcbmV4cG9ydCBmdW5jdGlvbiBhc3NlcnRQYXlsb2FkSXNNZXNzYWdlU2lnbmF0dXJlTmVlZGVkUGF5bG9hZChwYXlsb2FkKSB7XG4gICAgaWYgKCFpc09iamVjdChwYXlsb2FkKSkge1xuICAgICAgICByZXR1cm4gZmFsc2U7XG4gICAgfVxuICAgIGlmICghKCdkZWNvZGVkTWVzc2FnZScgaW4gcGF5bG9hZCkpIHtcbiAgICAgICAgcmV0dXJuIGZhbHNlO1xuICAgIH1cbiAgICByZXR1cm4gdHlwZW9mIHBheWxvYWQuZGVjb2RlZE1lc3NhZ2UgPT09ICdzdHJpbmcnO1xufVxuLy8jIHNvdXJjZU1hcHBpbmdVUkw9bWVzc2FnZS1zaWduYXR1cmUtbmVlZGVkLmpzLm1hcCJdLCJuYW1lcyI6W10sInNvdXJjZVJvb3QiOiIifQ==\n//# sourceURL=webpack-internal:///./node_modules/@fractalwagmi/popup-connection/dist/esm/payloads/message-signature-needed.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@fractalwagmi/popup-connection/dist/esm/payloads/signed-partial-transaction.js":
-/*!*****************************************************************************************************!*\
-  !*** ./node_modules/@fractalwagmi/popup-connection/dist/esm/payloads/signed-partial-transaction.js ***!
-  \*****************************************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-"use strict";
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   assertPayloadIsSignedPartialTransactionPayload: function() { return /* binding */ assertPayloadIsSignedPartialTransactionPayload; }\n/* harmony export */ });\n/* harmony import */ var _lib_guards_js__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! ../lib/guards.js */ \"./node_modules/@fractalwagmi/popup-connection/dist/esm/lib/guards.js\");\n\nfunction assertPayloadIsSignedPartialTransactionPayload(payload) {\n    if (!(0,_lib_guards_js__WEBPACK_IMPORTED_MODULE_0__.isObject)(payload)) {\n        return false;\n    }\n    if (!('transactionB58String' in payload)) {\n        return false;\n    }\n    return typeof payload.transactionB58String === 'string';\n}\n//# sourceMappingURL=signed-partial-transaction.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQGZyYWN0YWx3YWdtaS9wb3B1cC1jb25uZWN0aW9uL2Rpc3QvZXNtL3BheWxvYWRzL3NpZ25lZC1wYXJ0aWFsLXRyYW5zYWN0aW9uLmpzIiwibWFwcGluZ3MiOiI7Ozs7O0FBQTRDO0FBQ3JDO0FBQ1AsU0FBUyx3REFBUTtBQUNqQjtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBIiwic291cmNlcyI6WyJ3ZWJwYWNrOi8vX05fRS8uL25vZGVfbW9kdWxlcy9AZnJhY3RhbHdhZ21pL3BvcHVwLWNvbm5lY3Rpb24vZGlzdC9lc20vcGF5bG9hZHMvc2lnbmVkLXBhcnRpYWwtdHJhbnNhY3Rpb24uanM/NzViOSJdLCJzb3VyY2VzQ29udGVudCI6WyJpbXBvcnQgeyBpc09iamVjdCB9IGZyb20gXCIuLi9saWIvZ3VhcmRzLmpzXCI7XG5leHBvcnQgZnVuY3Rpb24gYXNzZXJ0UGF5bG9hZElzU2lnbmVkUGFydGlhbFRyYW5zYWN0aW9uUGF5bG9hZChwYXlsb2FkKSB7XG4gICAgaWYgKCFpc09iamVjdChwYXlsb2FkKSkge1xuICAgICAgICByZXR1cm4gZmFsc2U7XG4gICAgfVxuICAgIGlmICghKCd0cmFuc2FjdGlvbkI1OFN0cmluZycgaW4gcGF5bG9hZCkpIHtcbiAgICAgICAgcmV0dXJuIGZhbHNlO1xuICAgIH1cbiAgICByZXR1cm4gdHlwZW9mIHBheWxvYWQudHJhbnNhY3Rpb25CNThTdHJpbmcgPT09ICdzdHJpbmcnO1xufVxuLy8jIHNvdXJjZU1hcHBpbmdVUkw9c2lnbmVkLXBhcnRpYWwtdHJhbnNhY3Rpb24uanMubWFwIl0sIm5hbWVzIjpbXSwic291cmNlUm9vdCI6IiJ9\n//# sourceURL=webpack-internal:///./node_modules/@fractalwagmi/popup-connection/dist/esm/payloads/signed-partial-transaction.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@fractalwagmi/popup-connection/dist/esm/payloads/solana-wallet-adapter-approved.js":
-/*!*********************************************************************************************************!*\
-  !*** ./node_modules/@fractalwagmi/popup-connection/dist/esm/payloads/solana-wallet-adapter-approved.js ***!
-  \*********************************************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-"use strict";
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   assertPayloadIsSolanaWalletAdapterApproved: function() { return /* binding */ assertPayloadIsSolanaWalletAdapterApproved; }\n/* harmony export */ });\n/* harmony import */ var _lib_guards_js__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! ../lib/guards.js */ \"./node_modules/@fractalwagmi/popup-connection/dist/esm/lib/guards.js\");\n\nfunction assertPayloadIsSolanaWalletAdapterApproved(payload) {\n    if (!(0,_lib_guards_js__WEBPACK_IMPORTED_MODULE_0__.isObject)(payload)) {\n        return false;\n    }\n    if (!('solanaPublicKey' in payload)) {\n        return false;\n    }\n    if (typeof payload.solanaPublicKey !== 'string') {\n        return false;\n    }\n    return true;\n}\n//# sourceMappingURL=solana-wallet-adapter-approved.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQGZyYWN0YWx3YWdtaS9wb3B1cC1jb25uZWN0aW9uL2Rpc3QvZXNtL3BheWxvYWRzL3NvbGFuYS13YWxsZXQtYWRhcHRlci1hcHByb3ZlZC5qcyIsIm1hcHBpbmdzIjoiOzs7OztBQUE0QztBQUNyQztBQUNQLFNBQVMsd0RBQVE7QUFDakI7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQSIsInNvdXJjZXMiOlsid2VicGFjazovL19OX0UvLi9ub2RlX21vZHVsZXMvQGZyYWN0YWx3YWdtaS9wb3B1cC1jb25uZWN0aW9uL2Rpc3QvZXNtL3BheWxvYWRzL3NvbGFuYS13YWxsZXQtYWRhcHRlci1hcHByb3ZlZC5qcz84OTllIl0sInNvdXJjZXNDb250ZW50IjpbImltcG9ydCB7IGlzT2JqZWN0IH0gZnJvbSBcIi4uL2xpYi9ndWFyZHMuanNcIjtcbmV4cG9ydCBmdW5jdGlvbiBhc3NlcnRQYXlsb2FkSXNTb2xhbmFXYWxsZXRBZGFwdGVyQXBwcm92ZWQocGF5bG9hZCkge1xuICAgIGlmICghaXNPYmplY3QocGF5bG9hZCkpIHtcbiAgICAgICAgcmV0dXJuIGZhbHNlO1xuICAgIH1cbiAgICBpZiAoISgnc29sYW5hUHVibGljS2V5JyBpbiBwYXlsb2FkKSkge1xuICAgICAgICByZXR1cm4gZmFsc2U7XG4gICAgfVxuICAgIGlmICh0eXBlb2YgcGF5bG9hZC5zb2xhbmFQdWJsaWNLZXkgIT09ICdzdHJpbmcnKSB7XG4gICAgICAgIHJldHVybiBmYWxzZTtcbiAgICB9XG4gICAgcmV0dXJuIHRydWU7XG59XG4vLyMgc291cmNlTWFwcGluZ1VSTD1zb2xhbmEtd2FsbGV0LWFkYXB0ZXItYXBwcm92ZWQuanMubWFwIl0sIm5hbWVzIjpbXSwic291cmNlUm9vdCI6IiJ9\n//# sourceURL=webpack-internal:///./node_modules/@fractalwagmi/popup-connection/dist/esm/payloads/solana-wallet-adapter-approved.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@fractalwagmi/popup-connection/dist/esm/payloads/transaction-signature-needed-response.js":
-/*!****************************************************************************************************************!*\
-  !*** ./node_modules/@fractalwagmi/popup-connection/dist/esm/payloads/transaction-signature-needed-response.js ***!
-  \****************************************************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-"use strict";
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   assertPayloadIsTransactionSignatureNeededResponsePayload: function() { return /* binding */ assertPayloadIsTransactionSignatureNeededResponsePayload; }\n/* harmony export */ });\n/* harmony import */ var _lib_guards_js__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! ../lib/guards.js */ \"./node_modules/@fractalwagmi/popup-connection/dist/esm/lib/guards.js\");\n\nfunction assertPayloadIsTransactionSignatureNeededResponsePayload(payload) {\n    if (!(0,_lib_guards_js__WEBPACK_IMPORTED_MODULE_0__.isObject)(payload)) {\n        return false;\n    }\n    if (!('signedB58Transactions' in payload)) {\n        return false;\n    }\n    if (!Array.isArray(payload.signedB58Transactions)) {\n        return false;\n    }\n    return payload.signedB58Transactions.every(value => typeof value === 'string');\n}\n//# sourceMappingURL=transaction-signature-needed-response.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQGZyYWN0YWx3YWdtaS9wb3B1cC1jb25uZWN0aW9uL2Rpc3QvZXNtL3BheWxvYWRzL3RyYW5zYWN0aW9uLXNpZ25hdHVyZS1uZWVkZWQtcmVzcG9uc2UuanMiLCJtYXBwaW5ncyI6Ijs7Ozs7QUFBNEM7QUFDckM7QUFDUCxTQUFTLHdEQUFRO0FBQ2pCO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0EiLCJzb3VyY2VzIjpbIndlYnBhY2s6Ly9fTl9FLy4vbm9kZV9tb2R1bGVzL0BmcmFjdGFsd2FnbWkvcG9wdXAtY29ubmVjdGlvbi9kaXN0L2VzbS9wYXlsb2Fkcy90cmFuc2FjdGlvbi1zaWduYXR1cmUtbmVlZGVkLXJlc3BvbnNlLmpzPzA3ZGYiXSwic291cmNlc0NvbnRlbnQiOlsiaW1wb3J0IHsgaXNPYmplY3QgfSBmcm9tIFwiLi4vbGliL2d1YXJkcy5qc1wiO1xuZXhwb3J0IGZ1bmN0aW9uIGFzc2VydFBheWxvYWRJc1RyYW5zYWN0aW9uU2lnbmF0dXJlTmVlZGVkUmVzcG9uc2VQYXlsb2FkKHBheWxvYWQpIHtcbiAgICBpZiAoIWlzT2JqZWN0KHBheWxvYWQpKSB7XG4gICAgICAgIHJldHVybiBmYWxzZTtcbiAgICB9XG4gICAgaWYgKCEoJ3NpZ25lZEI1OFRyYW5zYWN0aW9ucycgaW4gcGF5bG9hZCkpIHtcbiAgICAgICAgcmV0dXJuIGZhbHNlO1xuICAgIH1cbiAgICBpZiAoIUFycmF5LmlzQXJyYXkocGF5bG9hZC5zaWduZWRCNThUcmFuc2FjdGlvbnMpKSB7XG4gICAgICAgIHJldHVybiBmYWxzZTtcbiAgICB9XG4gICAgcmV0dXJuIHBheWxvYWQuc2lnbmVkQjU4VHJhbnNhY3Rpb25zLmV2ZXJ5KHZhbHVlID0+IHR5cGVvZiB2YWx1ZSA9PT0gJ3N0cmluZycpO1xufVxuLy8jIHNvdXJjZU1hcHBpbmdVUkw9dHJhbnNhY3Rpb24tc2lnbmF0dXJlLW5lZWRlZC1yZXNwb25zZS5qcy5tYXAiXSwibmFtZXMiOltdLCJzb3VyY2VSb290IjoiIn0=\n//# sourceURL=webpack-internal:///./node_modules/@fractalwagmi/popup-connection/dist/esm/payloads/transaction-signature-needed-response.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@fractalwagmi/popup-connection/dist/esm/payloads/transaction-signature-needed.js":
-/*!*******************************************************************************************************!*\
-  !*** ./node_modules/@fractalwagmi/popup-connection/dist/esm/payloads/transaction-signature-needed.js ***!
-  \*******************************************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-"use strict";
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   assertPayloadIsTransactionSignatureNeededPayload: function() { return /* binding */ assertPayloadIsTransactionSignatureNeededPayload; }\n/* harmony export */ });\n/* harmony import */ var _lib_guards_js__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! ../lib/guards.js */ \"./node_modules/@fractalwagmi/popup-connection/dist/esm/lib/guards.js\");\n\nfunction assertPayloadIsTransactionSignatureNeededPayload(payload) {\n    if (!(0,_lib_guards_js__WEBPACK_IMPORTED_MODULE_0__.isObject)(payload)) {\n        return false;\n    }\n    if (!('unsignedB58Transactions' in payload)) {\n        return false;\n    }\n    if (!Array.isArray(payload.unsignedB58Transactions)) {\n        return false;\n    }\n    return payload.unsignedB58Transactions.every(value => typeof value === 'string');\n}\n//# sourceMappingURL=transaction-signature-needed.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQGZyYWN0YWx3YWdtaS9wb3B1cC1jb25uZWN0aW9uL2Rpc3QvZXNtL3BheWxvYWRzL3RyYW5zYWN0aW9uLXNpZ25hdHVyZS1uZWVkZWQuanMiLCJtYXBwaW5ncyI6Ijs7Ozs7QUFBNEM7QUFDckM7QUFDUCxTQUFTLHdEQUFRO0FBQ2pCO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0EiLCJzb3VyY2VzIjpbIndlYnBhY2s6Ly9fTl9FLy4vbm9kZV9tb2R1bGVzL0BmcmFjdGFsd2FnbWkvcG9wdXAtY29ubmVjdGlvbi9kaXN0L2VzbS9wYXlsb2Fkcy90cmFuc2FjdGlvbi1zaWduYXR1cmUtbmVlZGVkLmpzP2NlNzUiXSwic291cmNlc0NvbnRlbnQiOlsiaW1wb3J0IHsgaXNPYmplY3QgfSBmcm9tIFwiLi4vbGliL2d1YXJkcy5qc1wiO1xuZXhwb3J0IGZ1bmN0aW9uIGFzc2VydFBheWxvYWRJc1RyYW5zYWN0aW9uU2lnbmF0dXJlTmVlZGVkUGF5bG9hZChwYXlsb2FkKSB7XG4gICAgaWYgKCFpc09iamVjdChwYXlsb2FkKSkge1xuICAgICAgICByZXR1cm4gZmFsc2U7XG4gICAgfVxuICAgIGlmICghKCd1bnNpZ25lZEI1OFRyYW5zYWN0aW9ucycgaW4gcGF5bG9hZCkpIHtcbiAgICAgICAgcmV0dXJuIGZhbHNlO1xuICAgIH1cbiAgICBpZiAoIUFycmF5LmlzQXJyYXkocGF5bG9hZC51bnNpZ25lZEI1OFRyYW5zYWN0aW9ucykpIHtcbiAgICAgICAgcmV0dXJuIGZhbHNlO1xuICAgIH1cbiAgICByZXR1cm4gcGF5bG9hZC51bnNpZ25lZEI1OFRyYW5zYWN0aW9ucy5ldmVyeSh2YWx1ZSA9PiB0eXBlb2YgdmFsdWUgPT09ICdzdHJpbmcnKTtcbn1cbi8vIyBzb3VyY2VNYXBwaW5nVVJMPXRyYW5zYWN0aW9uLXNpZ25hdHVyZS1uZWVkZWQuanMubWFwIl0sIm5hbWVzIjpbXSwic291cmNlUm9vdCI6IiJ9\n//# sourceURL=webpack-internal:///./node_modules/@fractalwagmi/popup-connection/dist/esm/payloads/transaction-signature-needed.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@fractalwagmi/popup-connection/dist/esm/use-popup-connection.js":
-/*!**************************************************************************************!*\
-  !*** ./node_modules/@fractalwagmi/popup-connection/dist/esm/use-popup-connection.js ***!
-  \**************************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-"use strict";
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   usePopupConnection: function() { return /* binding */ usePopupConnection; }\n/* harmony export */ });\n/* harmony import */ var _connection_manager_js__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! ./connection-manager.js */ \"./node_modules/@fractalwagmi/popup-connection/dist/esm/connection-manager.js\");\n/* harmony import */ var _core_constants_js__WEBPACK_IMPORTED_MODULE_1__ = __webpack_require__(/*! ./core/constants.js */ \"./node_modules/@fractalwagmi/popup-connection/dist/esm/core/constants.js\");\n/* harmony import */ var _core_types_js__WEBPACK_IMPORTED_MODULE_2__ = __webpack_require__(/*! ./core/types.js */ \"./node_modules/@fractalwagmi/popup-connection/dist/esm/core/types.js\");\n/* harmony import */ var react__WEBPACK_IMPORTED_MODULE_3__ = __webpack_require__(/*! react */ \"./node_modules/react/index.js\");\n\n\n\n\nconst usePopupConnection = ({ enabled = true, platform = _core_types_js__WEBPACK_IMPORTED_MODULE_2__.Platform.UNKNOWN, widthPx = _core_constants_js__WEBPACK_IMPORTED_MODULE_1__.DEFAULT_POPUP_WIDTH_PX, heightPx = _core_constants_js__WEBPACK_IMPORTED_MODULE_1__.DEFAULT_POPUP_HEIGHT_PX, } = {}) => {\n    const [exportedConnection, setExportedConnection] = (0,react__WEBPACK_IMPORTED_MODULE_3__.useState)(undefined);\n    const connectionManagerRef = (0,react__WEBPACK_IMPORTED_MODULE_3__.useRef)(new _connection_manager_js__WEBPACK_IMPORTED_MODULE_0__.ConnectionManager(platform).onConnectionUpdated((connection) => {\n        setExportedConnection(connection === null || connection === void 0 ? void 0 : connection.export());\n    }));\n    const open = (0,react__WEBPACK_IMPORTED_MODULE_3__.useCallback)((url, nonce) => {\n        connectionManagerRef.current.open({\n            heightPx,\n            nonce,\n            url,\n            widthPx,\n        });\n    }, [connectionManagerRef, widthPx, heightPx]);\n    const close = (0,react__WEBPACK_IMPORTED_MODULE_3__.useCallback)(() => {\n        connectionManagerRef.current.close();\n    }, [connectionManagerRef]);\n    (0,react__WEBPACK_IMPORTED_MODULE_3__.useEffect)(() => {\n        if (enabled) {\n            connectionManagerRef.current.initialize();\n        }\n        else {\n            connectionManagerRef.current.tearDown();\n        }\n    }, [connectionManagerRef]);\n    return {\n        close,\n        connection: exportedConnection,\n        open,\n    };\n};\n//# sourceMappingURL=use-popup-connection.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQGZyYWN0YWx3YWdtaS9wb3B1cC1jb25uZWN0aW9uL2Rpc3QvZXNtL3VzZS1wb3B1cC1jb25uZWN0aW9uLmpzIiwibWFwcGluZ3MiOiI7Ozs7Ozs7O0FBQTREO0FBQzJCO0FBQzVDO0FBQ3NCO0FBQzFELDhCQUE4QiwyQkFBMkIsb0RBQVEsb0JBQW9CLHNFQUFzQixhQUFhLHVFQUF1QixJQUFJLElBQUk7QUFDOUosd0RBQXdELCtDQUFRO0FBQ2hFLGlDQUFpQyw2Q0FBTSxLQUFLLHFFQUFpQjtBQUM3RDtBQUNBLEtBQUs7QUFDTCxpQkFBaUIsa0RBQVc7QUFDNUI7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBLFNBQVM7QUFDVCxLQUFLO0FBQ0wsa0JBQWtCLGtEQUFXO0FBQzdCO0FBQ0EsS0FBSztBQUNMLElBQUksZ0RBQVM7QUFDYjtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQSxLQUFLO0FBQ0w7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0EiLCJzb3VyY2VzIjpbIndlYnBhY2s6Ly9fTl9FLy4vbm9kZV9tb2R1bGVzL0BmcmFjdGFsd2FnbWkvcG9wdXAtY29ubmVjdGlvbi9kaXN0L2VzbS91c2UtcG9wdXAtY29ubmVjdGlvbi5qcz8yNTU5Il0sInNvdXJjZXNDb250ZW50IjpbImltcG9ydCB7IENvbm5lY3Rpb25NYW5hZ2VyIH0gZnJvbSBcIi4vY29ubmVjdGlvbi1tYW5hZ2VyLmpzXCI7XG5pbXBvcnQgeyBERUZBVUxUX1BPUFVQX1dJRFRIX1BYLCBERUZBVUxUX1BPUFVQX0hFSUdIVF9QWCwgfSBmcm9tIFwiLi9jb3JlL2NvbnN0YW50cy5qc1wiO1xuaW1wb3J0IHsgUGxhdGZvcm0gfSBmcm9tIFwiLi9jb3JlL3R5cGVzLmpzXCI7XG5pbXBvcnQgeyB1c2VDYWxsYmFjaywgdXNlRWZmZWN0LCB1c2VSZWYsIHVzZVN0YXRlIH0gZnJvbSAncmVhY3QnO1xuZXhwb3J0IGNvbnN0IHVzZVBvcHVwQ29ubmVjdGlvbiA9ICh7IGVuYWJsZWQgPSB0cnVlLCBwbGF0Zm9ybSA9IFBsYXRmb3JtLlVOS05PV04sIHdpZHRoUHggPSBERUZBVUxUX1BPUFVQX1dJRFRIX1BYLCBoZWlnaHRQeCA9IERFRkFVTFRfUE9QVVBfSEVJR0hUX1BYLCB9ID0ge30pID0+IHtcbiAgICBjb25zdCBbZXhwb3J0ZWRDb25uZWN0aW9uLCBzZXRFeHBvcnRlZENvbm5lY3Rpb25dID0gdXNlU3RhdGUodW5kZWZpbmVkKTtcbiAgICBjb25zdCBjb25uZWN0aW9uTWFuYWdlclJlZiA9IHVzZVJlZihuZXcgQ29ubmVjdGlvbk1hbmFnZXIocGxhdGZvcm0pLm9uQ29ubmVjdGlvblVwZGF0ZWQoKGNvbm5lY3Rpb24pID0+IHtcbiAgICAgICAgc2V0RXhwb3J0ZWRDb25uZWN0aW9uKGNvbm5lY3Rpb24gPT09IG51bGwgfHwgY29ubmVjdGlvbiA9PT0gdm9pZCAwID8gdm9pZCAwIDogY29ubmVjdGlvbi5leHBvcnQoKSk7XG4gICAgfSkpO1xuICAgIGNvbnN0IG9wZW4gPSB1c2VDYWxsYmFjaygodXJsLCBub25jZSkgPT4ge1xuICAgICAgICBjb25uZWN0aW9uTWFuYWdlclJlZi5jdXJyZW50Lm9wZW4oe1xuICAgICAgICAgICAgaGVpZ2h0UHgsXG4gICAgICAgICAgICBub25jZSxcbiAgICAgICAgICAgIHVybCxcbiAgICAgICAgICAgIHdpZHRoUHgsXG4gICAgICAgIH0pO1xuICAgIH0sIFtjb25uZWN0aW9uTWFuYWdlclJlZiwgd2lkdGhQeCwgaGVpZ2h0UHhdKTtcbiAgICBjb25zdCBjbG9zZSA9IHVzZUNhbGxiYWNrKCgpID0+IHtcbiAgICAgICAgY29ubmVjdGlvbk1hbmFnZXJSZWYuY3VycmVudC5jbG9zZSgpO1xuICAgIH0sIFtjb25uZWN0aW9uTWFuYWdlclJlZl0pO1xuICAgIHVzZUVmZmVjdCgoKSA9PiB7XG4gICAgICAgIGlmIChlbmFibGVkKSB7XG4gICAgICAgICAgICBjb25uZWN0aW9uTWFuYWdlclJlZi5jdXJyZW50LmluaXRpYWxpemUoKTtcbiAgICAgICAgfVxuICAgICAgICBlbHNlIHtcbiAgICAgICAgICAgIGNvbm5lY3Rpb25NYW5hZ2VyUmVmLmN1cnJlbnQudGVhckRvd24oKTtcbiAgICAgICAgfVxuICAgIH0sIFtjb25uZWN0aW9uTWFuYWdlclJlZl0pO1xuICAgIHJldHVybiB7XG4gICAgICAgIGNsb3NlLFxuICAgICAgICBjb25uZWN0aW9uOiBleHBvcnRlZENvbm5lY3Rpb24sXG4gICAgICAgIG9wZW4sXG4gICAgfTtcbn07XG4vLyMgc291cmNlTWFwcGluZ1VSTD11c2UtcG9wdXAtY29ubmVjdGlvbi5qcy5tYXAiXSwibmFtZXMiOltdLCJzb3VyY2VSb290IjoiIn0=\n//# sourceURL=webpack-internal:///./node_modules/@fractalwagmi/popup-connection/dist/esm/use-popup-connection.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@fractalwagmi/solana-wallet-adapter/dist/esm/core/fractal-wallet-adapter-impl.js":
-/*!*******************************************************************************************************!*\
-  !*** ./node_modules/@fractalwagmi/solana-wallet-adapter/dist/esm/core/fractal-wallet-adapter-impl.js ***!
-  \*******************************************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-"use strict";
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   FractalWalletAdapterImpl: function() { return /* binding */ FractalWalletAdapterImpl; }\n/* harmony export */ });\n/* harmony import */ var _fractalwagmi_popup_connection__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! @fractalwagmi/popup-connection */ \"./node_modules/@fractalwagmi/popup-connection/dist/esm/index.js\");\n/* harmony import */ var _solana_wallet_adapter_base__WEBPACK_IMPORTED_MODULE_4__ = __webpack_require__(/*! @solana/wallet-adapter-base */ \"./node_modules/@solana/wallet-adapter-base/lib/esm/index.js\");\n/* harmony import */ var _solana_web3_js__WEBPACK_IMPORTED_MODULE_1__ = __webpack_require__(/*! @solana/web3.js */ \"./node_modules/@solana/web3.js/lib/index.browser.esm.js\");\n/* harmony import */ var bs58__WEBPACK_IMPORTED_MODULE_2__ = __webpack_require__(/*! bs58 */ \"./node_modules/@fractalwagmi/solana-wallet-adapter/node_modules/bs58/index.js\");\n/* harmony import */ var _nonce_js__WEBPACK_IMPORTED_MODULE_3__ = __webpack_require__(/*! ./nonce.js */ \"./node_modules/@fractalwagmi/solana-wallet-adapter/dist/esm/core/nonce.js\");\n\n\n\n\n\nconst UNKNOWN_ERROR_MESSAGE = 'Unknown Error';\nconst FRACTAL_DOMAIN_HTTPS = 'https://fractal.is';\nconst APPROVE_PAGE_URL = `${FRACTAL_DOMAIN_HTTPS}/wallet-adapter/approve`;\nconst SIGN_PAGE_URL = `${FRACTAL_DOMAIN_HTTPS}/wallet-adapter/sign`;\nconst SIGN_MESSAGE_PAGE_URL = `${FRACTAL_DOMAIN_HTTPS}/wallet-adapter/sign/message`;\nconst MIN_POPUP_HEIGHT_PX = _fractalwagmi_popup_connection__WEBPACK_IMPORTED_MODULE_0__.DEFAULT_POPUP_HEIGHT_PX;\nconst MAX_POPUP_WIDTH_PX = 850;\nconst LOCAL_STORAGE_KEY_FOR_PUBLIC_KEY = 'RdxqNYxF';\nclass FractalWalletAdapterImpl {\n    constructor() {\n        this.popupManager = new _fractalwagmi_popup_connection__WEBPACK_IMPORTED_MODULE_0__.ConnectionManager(_fractalwagmi_popup_connection__WEBPACK_IMPORTED_MODULE_0__.Platform.SOLANA_WALLET_ADAPTER);\n        this.publicKey = null;\n        this.connecting = false;\n    }\n    getPublicKey() {\n        return this.publicKey;\n    }\n    async connect() {\n        let resolve;\n        let reject;\n        const publicKeyInLocalStorage = window.localStorage.getItem(LOCAL_STORAGE_KEY_FOR_PUBLIC_KEY);\n        if (publicKeyInLocalStorage) {\n            this.publicKey = new _solana_web3_js__WEBPACK_IMPORTED_MODULE_1__.PublicKey(publicKeyInLocalStorage);\n            return Promise.resolve();\n        }\n        const nonce = (0,_nonce_js__WEBPACK_IMPORTED_MODULE_3__.createNonce)();\n        this.popupManager.open({\n            nonce,\n            url: `${APPROVE_PAGE_URL}/${nonce}`,\n        });\n        const handleSolanaWalletAdapterApproved = (payload) => {\n            if (!(0,_fractalwagmi_popup_connection__WEBPACK_IMPORTED_MODULE_0__.assertPayloadIsSolanaWalletAdapterApproved)(payload)) {\n                reject(new _solana_wallet_adapter_base__WEBPACK_IMPORTED_MODULE_4__.WalletConnectionError('Malformed payload when setting up connection. ' +\n                    'Expected { solanaPublicKey: string } but ' +\n                    `received ${JSON.stringify(payload)}`));\n                this.popupManager.close();\n                return;\n            }\n            try {\n                this.publicKey = new _solana_web3_js__WEBPACK_IMPORTED_MODULE_1__.PublicKey(payload.solanaPublicKey);\n                window.localStorage.setItem(LOCAL_STORAGE_KEY_FOR_PUBLIC_KEY, payload.solanaPublicKey);\n                resolve();\n            }\n            catch (error) {\n                const publicKeyError = new _solana_wallet_adapter_base__WEBPACK_IMPORTED_MODULE_4__.WalletPublicKeyError(error instanceof Error ? error.message : UNKNOWN_ERROR_MESSAGE, error);\n                reject(publicKeyError);\n            }\n            this.popupManager.close();\n        };\n        const handleExplicitDenialByUser = () => {\n            reject(new _solana_wallet_adapter_base__WEBPACK_IMPORTED_MODULE_4__.WalletConnectionError('The user denied the connection.'));\n            this.popupManager.close();\n        };\n        const handleClosedByUser = () => {\n            reject(new _solana_wallet_adapter_base__WEBPACK_IMPORTED_MODULE_4__.WalletConnectionError('The user denied the connection.'));\n            this.popupManager.close();\n        };\n        this.popupManager.onConnectionUpdated(connection => {\n            if (!connection) {\n                return;\n            }\n            connection.on(_fractalwagmi_popup_connection__WEBPACK_IMPORTED_MODULE_0__.PopupEvent.SOLANA_WALLET_ADAPTER_APPROVED, handleSolanaWalletAdapterApproved);\n            connection.on(_fractalwagmi_popup_connection__WEBPACK_IMPORTED_MODULE_0__.PopupEvent.SOLANA_WALLET_ADAPTER_DENIED, handleExplicitDenialByUser);\n            connection.on(_fractalwagmi_popup_connection__WEBPACK_IMPORTED_MODULE_0__.PopupEvent.POPUP_CLOSED, handleClosedByUser);\n        });\n        return new Promise((promiseResolver, promiseRejector) => {\n            resolve = promiseResolver;\n            reject = promiseRejector;\n        });\n    }\n    async disconnect() {\n        this.popupManager.tearDown();\n        this.publicKey = null;\n        window.localStorage.removeItem(LOCAL_STORAGE_KEY_FOR_PUBLIC_KEY);\n    }\n    async signTransaction(transaction) {\n        try {\n            this.checkWalletReadiness();\n            const result = await this.signTransactions([transaction]);\n            return result[0];\n        }\n        catch (error) {\n            let errorToThrow = error;\n            if (!(error instanceof _solana_wallet_adapter_base__WEBPACK_IMPORTED_MODULE_4__.WalletError)) {\n                errorToThrow = new _solana_wallet_adapter_base__WEBPACK_IMPORTED_MODULE_4__.WalletSignTransactionError(error instanceof Error ? error.message : UNKNOWN_ERROR_MESSAGE, error);\n            }\n            throw errorToThrow;\n        }\n    }\n    async signAllTransactions(transactions) {\n        try {\n            this.checkWalletReadiness();\n            const result = await this.signTransactions(transactions);\n            return result;\n        }\n        catch (error) {\n            let errorToThrow = error;\n            if (!(error instanceof _solana_wallet_adapter_base__WEBPACK_IMPORTED_MODULE_4__.WalletError)) {\n                errorToThrow = new _solana_wallet_adapter_base__WEBPACK_IMPORTED_MODULE_4__.WalletSignTransactionError(error instanceof Error ? error.message : UNKNOWN_ERROR_MESSAGE, error);\n            }\n            throw errorToThrow;\n        }\n    }\n    async signMessage(encodedMessage) {\n        const decodedMessage = new TextDecoder().decode(encodedMessage);\n        let resolve;\n        let reject;\n        const handleMessageSignatureNeededResponse = (payload) => {\n            if (!(0,_fractalwagmi_popup_connection__WEBPACK_IMPORTED_MODULE_0__.assertPayloadIsMessageSignatureNeededResponsePayload)(payload)) {\n                const error = new _solana_wallet_adapter_base__WEBPACK_IMPORTED_MODULE_4__.WalletSignMessageError('Malformed payload when signing message. ' +\n                    'Expected { decodedSignature: string } ' +\n                    `but received ${JSON.stringify(payload)}`);\n                reject(error);\n                this.popupManager.close();\n                return;\n            }\n            const encodedSignature = Uint8Array.from(payload.decodedSignature.split(',').map(n => Number(n)));\n            resolve(encodedSignature);\n            this.popupManager.close();\n        };\n        const handleClosedOrDeniedByUser = () => {\n            reject(new _solana_wallet_adapter_base__WEBPACK_IMPORTED_MODULE_4__.WalletSignMessageError('The user did not approve the message'));\n            this.popupManager.close();\n        };\n        const handleAuthLoaded = () => {\n            var _a;\n            const payload = {\n                decodedMessage,\n            };\n            (_a = this.popupManager.getConnection()) === null || _a === void 0 ? void 0 : _a.send({\n                event: _fractalwagmi_popup_connection__WEBPACK_IMPORTED_MODULE_0__.PopupEvent.MESSAGE_SIGNATURE_NEEDED,\n                payload,\n            });\n        };\n        const nonce = (0,_nonce_js__WEBPACK_IMPORTED_MODULE_3__.createNonce)();\n        this.popupManager.open({\n            heightPx: Math.max(MIN_POPUP_HEIGHT_PX, Math.floor(window.innerHeight * 0.8)),\n            nonce,\n            url: `${SIGN_MESSAGE_PAGE_URL}/${nonce}`,\n            widthPx: Math.min(MAX_POPUP_WIDTH_PX, Math.floor(window.innerWidth * 0.8)),\n        });\n        this.popupManager.onConnectionUpdated(connection => {\n            if (!connection) {\n                return;\n            }\n            connection.on(_fractalwagmi_popup_connection__WEBPACK_IMPORTED_MODULE_0__.PopupEvent.MESSAGE_SIGNATURE_NEEDED_RESPONSE, handleMessageSignatureNeededResponse);\n            connection.on(_fractalwagmi_popup_connection__WEBPACK_IMPORTED_MODULE_0__.PopupEvent.TRANSACTION_DENIED, handleClosedOrDeniedByUser);\n            connection.on(_fractalwagmi_popup_connection__WEBPACK_IMPORTED_MODULE_0__.PopupEvent.POPUP_CLOSED, handleClosedOrDeniedByUser);\n            connection.on(_fractalwagmi_popup_connection__WEBPACK_IMPORTED_MODULE_0__.PopupEvent.AUTH_LOADED, handleAuthLoaded);\n        });\n        return new Promise((promiseResolver, promiseRejector) => {\n            resolve = promiseResolver;\n            reject = promiseRejector;\n        });\n    }\n    async signTransactions(transactions) {\n        let resolve;\n        let reject;\n        const handleTransactionSignatureNeededResponse = (payload) => {\n            if (!(0,_fractalwagmi_popup_connection__WEBPACK_IMPORTED_MODULE_0__.assertPayloadIsTransactionSignatureNeededResponsePayload)(payload)) {\n                const error = new _solana_wallet_adapter_base__WEBPACK_IMPORTED_MODULE_4__.WalletSignTransactionError('Malformed payload when signing transactions. ' +\n                    'Expected { signedB58Transactions: string[] } ' +\n                    `but received ${JSON.stringify(payload)}`);\n                reject(error);\n                this.popupManager.close();\n                return;\n            }\n            const signedTransactions = payload.signedB58Transactions.map(signedB58Transaction => {\n                return _solana_web3_js__WEBPACK_IMPORTED_MODULE_1__.Transaction.from(bs58__WEBPACK_IMPORTED_MODULE_2__.decode(signedB58Transaction));\n            });\n            resolve(signedTransactions);\n            this.popupManager.close();\n        };\n        const handleClosedOrDeniedByUser = () => {\n            reject(new _solana_wallet_adapter_base__WEBPACK_IMPORTED_MODULE_4__.WalletSignTransactionError('The user did not approve the transaction'));\n            this.popupManager.close();\n        };\n        const handleAuthLoaded = () => {\n            var _a;\n            const payload = {\n                unsignedB58Transactions: transactions.map(t => bs58__WEBPACK_IMPORTED_MODULE_2__.encode(t.serializeMessage())),\n            };\n            (_a = this.popupManager.getConnection()) === null || _a === void 0 ? void 0 : _a.send({\n                event: _fractalwagmi_popup_connection__WEBPACK_IMPORTED_MODULE_0__.PopupEvent.TRANSACTION_SIGNATURE_NEEDED,\n                payload,\n            });\n        };\n        const nonce = (0,_nonce_js__WEBPACK_IMPORTED_MODULE_3__.createNonce)();\n        this.popupManager.open({\n            heightPx: Math.max(MIN_POPUP_HEIGHT_PX, Math.floor(window.innerHeight * 0.8)),\n            nonce,\n            url: `${SIGN_PAGE_URL}/${nonce}`,\n            widthPx: Math.min(MAX_POPUP_WIDTH_PX, Math.floor(window.innerWidth * 0.8)),\n        });\n        this.popupManager.onConnectionUpdated(connection => {\n            if (!connection) {\n                return;\n            }\n            connection.on(_fractalwagmi_popup_connection__WEBPACK_IMPORTED_MODULE_0__.PopupEvent.TRANSACTION_SIGNATURE_NEEDED_RESPONSE, handleTransactionSignatureNeededResponse);\n            connection.on(_fractalwagmi_popup_connection__WEBPACK_IMPORTED_MODULE_0__.PopupEvent.TRANSACTION_DENIED, handleClosedOrDeniedByUser);\n            connection.on(_fractalwagmi_popup_connection__WEBPACK_IMPORTED_MODULE_0__.PopupEvent.POPUP_CLOSED, handleClosedOrDeniedByUser);\n            connection.on(_fractalwagmi_popup_connection__WEBPACK_IMPORTED_MODULE_0__.PopupEvent.AUTH_LOADED, handleAuthLoaded);\n        });\n        return new Promise((promiseResolver, promiseRejector) => {\n            resolve = promiseResolver;\n            reject = promiseRejector;\n        });\n    }\n    checkWalletReadiness() {\n        if (this.publicKey === null) {\n            throw new _solana_wallet_adapter_base__WEBPACK_IMPORTED_MODULE_4__.WalletNotConnectedError('`publicKey` is null. Did you forget to call `.connect()`?');\n        }\n    }\n}\n//# sourceMappingURL=fractal-wallet-adapter-impl.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQGZyYWN0YWx3YWdtaS9zb2xhbmEtd2FsbGV0LWFkYXB0ZXIvZGlzdC9lc20vY29yZS9mcmFjdGFsLXdhbGxldC1hZGFwdGVyLWltcGwuanMiLCJtYXBwaW5ncyI6Ijs7Ozs7Ozs7O0FBQStRO0FBQzFGO0FBQzVIO0FBQy9CO0FBQ2U7QUFDekM7QUFDQTtBQUNBLDRCQUE0QixxQkFBcUI7QUFDakQseUJBQXlCLHFCQUFxQjtBQUM5QyxpQ0FBaUMscUJBQXFCO0FBQ3RELDRCQUE0QixtRkFBdUI7QUFDbkQ7QUFDQTtBQUNPO0FBQ1A7QUFDQSxnQ0FBZ0MsNkVBQWlCLENBQUMsb0VBQVE7QUFDMUQ7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBLGlDQUFpQyxzREFBUztBQUMxQztBQUNBO0FBQ0Esc0JBQXNCLHNEQUFXO0FBQ2pDO0FBQ0E7QUFDQSxvQkFBb0IsaUJBQWlCLEdBQUcsTUFBTTtBQUM5QyxTQUFTO0FBQ1Q7QUFDQSxpQkFBaUIsMEdBQTBDO0FBQzNELDJCQUEyQiw4RUFBcUI7QUFDaEQsZ0NBQWdDLDBCQUEwQjtBQUMxRCxnQ0FBZ0Msd0JBQXdCO0FBQ3hEO0FBQ0E7QUFDQTtBQUNBO0FBQ0EscUNBQXFDLHNEQUFTO0FBQzlDO0FBQ0E7QUFDQTtBQUNBO0FBQ0EsMkNBQTJDLDZFQUFvQjtBQUMvRDtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0EsdUJBQXVCLDhFQUFxQjtBQUM1QztBQUNBO0FBQ0E7QUFDQSx1QkFBdUIsOEVBQXFCO0FBQzVDO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBLDBCQUEwQixzRUFBVTtBQUNwQywwQkFBMEIsc0VBQVU7QUFDcEMsMEJBQTBCLHNFQUFVO0FBQ3BDLFNBQVM7QUFDVDtBQUNBO0FBQ0E7QUFDQSxTQUFTO0FBQ1Q7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBLG1DQUFtQyxvRUFBVztBQUM5QyxtQ0FBbUMsbUZBQTBCO0FBQzdEO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBLG1DQUFtQyxvRUFBVztBQUM5QyxtQ0FBbUMsbUZBQTBCO0FBQzdEO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBLGlCQUFpQixvSEFBb0Q7QUFDckUsa0NBQWtDLCtFQUFzQjtBQUN4RCxnQ0FBZ0MsMkJBQTJCO0FBQzNELG9DQUFvQyx3QkFBd0I7QUFDNUQ7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0EsdUJBQXVCLCtFQUFzQjtBQUM3QztBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0EsdUJBQXVCLHNFQUFVO0FBQ2pDO0FBQ0EsYUFBYTtBQUNiO0FBQ0Esc0JBQXNCLHNEQUFXO0FBQ2pDO0FBQ0E7QUFDQTtBQUNBLG9CQUFvQixzQkFBc0IsR0FBRyxNQUFNO0FBQ25EO0FBQ0EsU0FBUztBQUNUO0FBQ0E7QUFDQTtBQUNBO0FBQ0EsMEJBQTBCLHNFQUFVO0FBQ3BDLDBCQUEwQixzRUFBVTtBQUNwQywwQkFBMEIsc0VBQVU7QUFDcEMsMEJBQTBCLHNFQUFVO0FBQ3BDLFNBQVM7QUFDVDtBQUNBO0FBQ0E7QUFDQSxTQUFTO0FBQ1Q7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBLGlCQUFpQix3SEFBd0Q7QUFDekUsa0NBQWtDLG1GQUEwQjtBQUM1RCxnQ0FBZ0Msa0NBQWtDO0FBQ2xFLG9DQUFvQyx3QkFBd0I7QUFDNUQ7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBLHVCQUF1Qix3REFBVyxNQUFNLHdDQUFhO0FBQ3JELGFBQWE7QUFDYjtBQUNBO0FBQ0E7QUFDQTtBQUNBLHVCQUF1QixtRkFBMEI7QUFDakQ7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBLCtEQUErRCx3Q0FBYTtBQUM1RTtBQUNBO0FBQ0EsdUJBQXVCLHNFQUFVO0FBQ2pDO0FBQ0EsYUFBYTtBQUNiO0FBQ0Esc0JBQXNCLHNEQUFXO0FBQ2pDO0FBQ0E7QUFDQTtBQUNBLG9CQUFvQixjQUFjLEdBQUcsTUFBTTtBQUMzQztBQUNBLFNBQVM7QUFDVDtBQUNBO0FBQ0E7QUFDQTtBQUNBLDBCQUEwQixzRUFBVTtBQUNwQywwQkFBMEIsc0VBQVU7QUFDcEMsMEJBQTBCLHNFQUFVO0FBQ3BDLDBCQUEwQixzRUFBVTtBQUNwQyxTQUFTO0FBQ1Q7QUFDQTtBQUNBO0FBQ0EsU0FBUztBQUNUO0FBQ0E7QUFDQTtBQUNBLHNCQUFzQixnRkFBdUI7QUFDN0M7QUFDQTtBQUNBO0FBQ0EiLCJzb3VyY2VzIjpbIndlYnBhY2s6Ly9fTl9FLy4vbm9kZV9tb2R1bGVzL0BmcmFjdGFsd2FnbWkvc29sYW5hLXdhbGxldC1hZGFwdGVyL2Rpc3QvZXNtL2NvcmUvZnJhY3RhbC13YWxsZXQtYWRhcHRlci1pbXBsLmpzPzk1OTIiXSwic291cmNlc0NvbnRlbnQiOlsiaW1wb3J0IHsgYXNzZXJ0UGF5bG9hZElzTWVzc2FnZVNpZ25hdHVyZU5lZWRlZFJlc3BvbnNlUGF5bG9hZCwgQ29ubmVjdGlvbk1hbmFnZXIsIFBsYXRmb3JtLCBQb3B1cEV2ZW50LCBhc3NlcnRQYXlsb2FkSXNTb2xhbmFXYWxsZXRBZGFwdGVyQXBwcm92ZWQsIERFRkFVTFRfUE9QVVBfSEVJR0hUX1BYLCBhc3NlcnRQYXlsb2FkSXNUcmFuc2FjdGlvblNpZ25hdHVyZU5lZWRlZFJlc3BvbnNlUGF5bG9hZCwgfSBmcm9tICdAZnJhY3RhbHdhZ21pL3BvcHVwLWNvbm5lY3Rpb24nO1xuaW1wb3J0IHsgV2FsbGV0RXJyb3IsIFdhbGxldE5vdENvbm5lY3RlZEVycm9yLCBXYWxsZXRTaWduVHJhbnNhY3Rpb25FcnJvciwgV2FsbGV0UHVibGljS2V5RXJyb3IsIFdhbGxldENvbm5lY3Rpb25FcnJvciwgV2FsbGV0U2lnbk1lc3NhZ2VFcnJvciwgfSBmcm9tICdAc29sYW5hL3dhbGxldC1hZGFwdGVyLWJhc2UnO1xuaW1wb3J0IHsgVHJhbnNhY3Rpb24sIFB1YmxpY0tleSB9IGZyb20gJ0Bzb2xhbmEvd2ViMy5qcyc7XG5pbXBvcnQgYmFzZTU4IGZyb20gJ2JzNTgnO1xuaW1wb3J0IHsgY3JlYXRlTm9uY2UgfSBmcm9tIFwiLi9ub25jZS5qc1wiO1xuY29uc3QgVU5LTk9XTl9FUlJPUl9NRVNTQUdFID0gJ1Vua25vd24gRXJyb3InO1xuY29uc3QgRlJBQ1RBTF9ET01BSU5fSFRUUFMgPSAnaHR0cHM6Ly9mcmFjdGFsLmlzJztcbmNvbnN0IEFQUFJPVkVfUEFHRV9VUkwgPSBgJHtGUkFDVEFMX0RPTUFJTl9IVFRQU30vd2FsbGV0LWFkYXB0ZXIvYXBwcm92ZWA7XG5jb25zdCBTSUdOX1BBR0VfVVJMID0gYCR7RlJBQ1RBTF9ET01BSU5fSFRUUFN9L3dhbGxldC1hZGFwdGVyL3NpZ25gO1xuY29uc3QgU0lHTl9NRVNTQUdFX1BBR0VfVVJMID0gYCR7RlJBQ1RBTF9ET01BSU5fSFRUUFN9L3dhbGxldC1hZGFwdGVyL3NpZ24vbWVzc2FnZWA7XG5jb25zdCBNSU5fUE9QVVBfSEVJR0hUX1BYID0gREVGQVVMVF9QT1BVUF9IRUlHSFRfUFg7XG5jb25zdCBNQVhfUE9QVVBfV0lEVEhfUFggPSA4NTA7XG5jb25zdCBMT0NBTF9TVE9SQUdFX0tFWV9GT1JfUFVCTElDX0tFWSA9ICdSZHhxTll4Ric7XG5leHBvcnQgY2xhc3MgRnJhY3RhbFdhbGxldEFkYXB0ZXJJbXBsIHtcbiAgICBjb25zdHJ1Y3RvcigpIHtcbiAgICAgICAgdGhpcy5wb3B1cE1hbmFnZXIgPSBuZXcgQ29ubmVjdGlvbk1hbmFnZXIoUGxhdGZvcm0uU09MQU5BX1dBTExFVF9BREFQVEVSKTtcbiAgICAgICAgdGhpcy5wdWJsaWNLZXkgPSBudWxsO1xuICAgICAgICB0aGlzLmNvbm5lY3RpbmcgPSBmYWxzZTtcbiAgICB9XG4gICAgZ2V0UHVibGljS2V5KCkge1xuICAgICAgICByZXR1cm4gdGhpcy5wdWJsaWNLZXk7XG4gICAgfVxuICAgIGFzeW5jIGNvbm5lY3QoKSB7XG4gICAgICAgIGxldCByZXNvbHZlO1xuICAgICAgICBsZXQgcmVqZWN0O1xuICAgICAgICBjb25zdCBwdWJsaWNLZXlJbkxvY2FsU3RvcmFnZSA9IHdpbmRvdy5sb2NhbFN0b3JhZ2UuZ2V0SXRlbShMT0NBTF9TVE9SQUdFX0tFWV9GT1JfUFVCTElDX0tFWSk7XG4gICAgICAgIGlmIChwdWJsaWNLZXlJbkxvY2FsU3RvcmFnZSkge1xuICAgICAgICAgICAgdGhpcy5wdWJsaWNLZXkgPSBuZXcgUHVibGljS2V5KHB1YmxpY0tleUluTG9jYWxTdG9yYWdlKTtcbiAgICAgICAgICAgIHJldHVybiBQcm9taXNlLnJlc29sdmUoKTtcbiAgICAgICAgfVxuICAgICAgICBjb25zdCBub25jZSA9IGNyZWF0ZU5vbmNlKCk7XG4gICAgICAgIHRoaXMucG9wdXBNYW5hZ2VyLm9wZW4oe1xuICAgICAgICAgICAgbm9uY2UsXG4gICAgICAgICAgICB1cmw6IGAke0FQUFJPVkVfUEFHRV9VUkx9LyR7bm9uY2V9YCxcbiAgICAgICAgfSk7XG4gICAgICAgIGNvbnN0IGhhbmRsZVNvbGFuYVdhbGxldEFkYXB0ZXJBcHByb3ZlZCA9IChwYXlsb2FkKSA9PiB7XG4gICAgICAgICAgICBpZiAoIWFzc2VydFBheWxvYWRJc1NvbGFuYVdhbGxldEFkYXB0ZXJBcHByb3ZlZChwYXlsb2FkKSkge1xuICAgICAgICAgICAgICAgIHJlamVjdChuZXcgV2FsbGV0Q29ubmVjdGlvbkVycm9yKCdNYWxmb3JtZWQgcGF5bG9hZCB3aGVuIHNldHRpbmcgdXAgY29ubmVjdGlvbi4gJyArXG4gICAgICAgICAgICAgICAgICAgICdFeHBlY3RlZCB7IHNvbGFuYVB1YmxpY0tleTogc3RyaW5nIH0gYnV0ICcgK1xuICAgICAgICAgICAgICAgICAgICBgcmVjZWl2ZWQgJHtKU09OLnN0cmluZ2lmeShwYXlsb2FkKX1gKSk7XG4gICAgICAgICAgICAgICAgdGhpcy5wb3B1cE1hbmFnZXIuY2xvc2UoKTtcbiAgICAgICAgICAgICAgICByZXR1cm47XG4gICAgICAgICAgICB9XG4gICAgICAgICAgICB0cnkge1xuICAgICAgICAgICAgICAgIHRoaXMucHVibGljS2V5ID0gbmV3IFB1YmxpY0tleShwYXlsb2FkLnNvbGFuYVB1YmxpY0tleSk7XG4gICAgICAgICAgICAgICAgd2luZG93LmxvY2FsU3RvcmFnZS5zZXRJdGVtKExPQ0FMX1NUT1JBR0VfS0VZX0ZPUl9QVUJMSUNfS0VZLCBwYXlsb2FkLnNvbGFuYVB1YmxpY0tleSk7XG4gICAgICAgICAgICAgICAgcmVzb2x2ZSgpO1xuICAgICAgICAgICAgfVxuICAgICAgICAgICAgY2F0Y2ggKGVycm9yKSB7XG4gICAgICAgICAgICAgICAgY29uc3QgcHVibGljS2V5RXJyb3IgPSBuZXcgV2FsbGV0UHVibGljS2V5RXJyb3IoZXJyb3IgaW5zdGFuY2VvZiBFcnJvciA/IGVycm9yLm1lc3NhZ2UgOiBVTktOT1dOX0VSUk9SX01FU1NBR0UsIGVycm9yKTtcbiAgICAgICAgICAgICAgICByZWplY3QocHVibGljS2V5RXJyb3IpO1xuICAgICAgICAgICAgfVxuICAgICAgICAgICAgdGhpcy5wb3B1cE1hbmFnZXIuY2xvc2UoKTtcbiAgICAgICAgfTtcbiAgICAgICAgY29uc3QgaGFuZGxlRXhwbGljaXREZW5pYWxCeVVzZXIgPSAoKSA9PiB7XG4gICAgICAgICAgICByZWplY3QobmV3IFdhbGxldENvbm5lY3Rpb25FcnJvcignVGhlIHVzZXIgZGVuaWVkIHRoZSBjb25uZWN0aW9uLicpKTtcbiAgICAgICAgICAgIHRoaXMucG9wdXBNYW5hZ2VyLmNsb3NlKCk7XG4gICAgICAgIH07XG4gICAgICAgIGNvbnN0IGhhbmRsZUNsb3NlZEJ5VXNlciA9ICgpID0+IHtcbiAgICAgICAgICAgIHJlamVjdChuZXcgV2FsbGV0Q29ubmVjdGlvbkVycm9yKCdUaGUgdXNlciBkZW5pZWQgdGhlIGNvbm5lY3Rpb24uJykpO1xuICAgICAgICAgICAgdGhpcy5wb3B1cE1hbmFnZXIuY2xvc2UoKTtcbiAgICAgICAgfTtcbiAgICAgICAgdGhpcy5wb3B1cE1hbmFnZXIub25Db25uZWN0aW9uVXBkYXRlZChjb25uZWN0aW9uID0+IHtcbiAgICAgICAgICAgIGlmICghY29ubmVjdGlvbikge1xuICAgICAgICAgICAgICAgIHJldHVybjtcbiAgICAgICAgICAgIH1cbiAgICAgICAgICAgIGNvbm5lY3Rpb24ub24oUG9wdXBFdmVudC5TT0xBTkFfV0FMTEVUX0FEQVBURVJfQVBQUk9WRUQsIGhhbmRsZVNvbGFuYVdhbGxldEFkYXB0ZXJBcHByb3ZlZCk7XG4gICAgICAgICAgICBjb25uZWN0aW9uLm9uKFBvcHVwRXZlbnQuU09MQU5BX1dBTExFVF9BREFQVEVSX0RFTklFRCwgaGFuZGxlRXhwbGljaXREZW5pYWxCeVVzZXIpO1xuICAgICAgICAgICAgY29ubmVjdGlvbi5vbihQb3B1cEV2ZW50LlBPUFVQX0NMT1NFRCwgaGFuZGxlQ2xvc2VkQnlVc2VyKTtcbiAgICAgICAgfSk7XG4gICAgICAgIHJldHVybiBuZXcgUHJvbWlzZSgocHJvbWlzZVJlc29sdmVyLCBwcm9taXNlUmVqZWN0b3IpID0+IHtcbiAgICAgICAgICAgIHJlc29sdmUgPSBwcm9taXNlUmVzb2x2ZXI7XG4gICAgICAgICAgICByZWplY3QgPSBwcm9taXNlUmVqZWN0b3I7XG4gICAgICAgIH0pO1xuICAgIH1cbiAgICBhc3luYyBkaXNjb25uZWN0KCkge1xuICAgICAgICB0aGlzLnBvcHVwTWFuYWdlci50ZWFyRG93bigpO1xuICAgICAgICB0aGlzLnB1YmxpY0tleSA9IG51bGw7XG4gICAgICAgIHdpbmRvdy5sb2NhbFN0b3JhZ2UucmVtb3ZlSXRlbShMT0NBTF9TVE9SQUdFX0tFWV9GT1JfUFVCTElDX0tFWSk7XG4gICAgfVxuICAgIGFzeW5jIHNpZ25UcmFuc2FjdGlvbih0cmFuc2FjdGlvbikge1xuICAgICAgICB0cnkge1xuICAgICAgICAgICAgdGhpcy5jaGVja1dhbGxldFJlYWRpbmVzcygpO1xuICAgICAgICAgICAgY29uc3QgcmVzdWx0ID0gYXdhaXQgdGhpcy5zaWduVHJhbnNhY3Rpb25zKFt0cmFuc2FjdGlvbl0pO1xuICAgICAgICAgICAgcmV0dXJuIHJlc3VsdFswXTtcbiAgICAgICAgfVxuICAgICAgICBjYXRjaCAoZXJyb3IpIHtcbiAgICAgICAgICAgIGxldCBlcnJvclRvVGhyb3cgPSBlcnJvcjtcbiAgICAgICAgICAgIGlmICghKGVycm9yIGluc3RhbmNlb2YgV2FsbGV0RXJyb3IpKSB7XG4gICAgICAgICAgICAgICAgZXJyb3JUb1Rocm93ID0gbmV3IFdhbGxldFNpZ25UcmFuc2FjdGlvbkVycm9yKGVycm9yIGluc3RhbmNlb2YgRXJyb3IgPyBlcnJvci5tZXNzYWdlIDogVU5LTk9XTl9FUlJPUl9NRVNTQUdFLCBlcnJvcik7XG4gICAgICAgICAgICB9XG4gICAgICAgICAgICB0aHJvdyBlcnJvclRvVGhyb3c7XG4gICAgICAgIH1cbiAgICB9XG4gICAgYXN5bmMgc2lnbkFsbFRyYW5zYWN0aW9ucyh0cmFuc2FjdGlvbnMpIHtcbiAgICAgICAgdHJ5IHtcbiAgICAgICAgICAgIHRoaXMuY2hlY2tXYWxsZXRSZWFkaW5lc3MoKTtcbiAgICAgICAgICAgIGNvbnN0IHJlc3VsdCA9IGF3YWl0IHRoaXMuc2lnblRyYW5zYWN0aW9ucyh0cmFuc2FjdGlvbnMpO1xuICAgICAgICAgICAgcmV0dXJuIHJlc3VsdDtcbiAgICAgICAgfVxuICAgICAgICBjYXRjaCAoZXJyb3IpIHtcbiAgICAgICAgICAgIGxldCBlcnJvclRvVGhyb3cgPSBlcnJvcjtcbiAgICAgICAgICAgIGlmICghKGVycm9yIGluc3RhbmNlb2YgV2FsbGV0RXJyb3IpKSB7XG4gICAgICAgICAgICAgICAgZXJyb3JUb1Rocm93ID0gbmV3IFdhbGxldFNpZ25UcmFuc2FjdGlvbkVycm9yKGVycm9yIGluc3RhbmNlb2YgRXJyb3IgPyBlcnJvci5tZXNzYWdlIDogVU5LTk9XTl9FUlJPUl9NRVNTQUdFLCBlcnJvcik7XG4gICAgICAgICAgICB9XG4gICAgICAgICAgICB0aHJvdyBlcnJvclRvVGhyb3c7XG4gICAgICAgIH1cbiAgICB9XG4gICAgYXN5bmMgc2lnbk1lc3NhZ2UoZW5jb2RlZE1lc3NhZ2UpIHtcbiAgICAgICAgY29uc3QgZGVjb2RlZE1lc3NhZ2UgPSBuZXcgVGV4dERlY29kZXIoKS5kZWNvZGUoZW5jb2RlZE1lc3NhZ2UpO1xuICAgICAgICBsZXQgcmVzb2x2ZTtcbiAgICAgICAgbGV0IHJlamVjdDtcbiAgICAgICAgY29uc3QgaGFuZGxlTWVzc2FnZVNpZ25hdHVyZU5lZWRlZFJlc3BvbnNlID0gKHBheWxvYWQpID0+IHtcbiAgICAgICAgICAgIGlmICghYXNzZXJ0UGF5bG9hZElzTWVzc2FnZVNpZ25hdHVyZU5lZWRlZFJlc3BvbnNlUGF5bG9hZChwYXlsb2FkKSkge1xuICAgICAgICAgICAgICAgIGNvbnN0IGVycm9yID0gbmV3IFdhbGxldFNpZ25NZXNzYWdlRXJyb3IoJ01hbGZvcm1lZCBwYXlsb2FkIHdoZW4gc2lnbmluZyBtZXNzYWdlLiAnICtcbiAgICAgICAgICAgICAgICAgICAgJ0V4cGVjdGVkIHsgZGVjb2RlZFNpZ25hdHVyZTogc3RyaW5nIH0gJyArXG4gICAgICAgICAgICAgICAgICAgIGBidXQgcmVjZWl2ZWQgJHtKU09OLnN0cmluZ2lmeShwYXlsb2FkKX1gKTtcbiAgICAgICAgICAgICAgICByZWplY3QoZXJyb3IpO1xuICAgICAgICAgICAgICAgIHRoaXMucG9wdXBNYW5hZ2VyLmNsb3NlKCk7XG4gICAgICAgICAgICAgICAgcmV0dXJuO1xuICAgICAgICAgICAgfVxuICAgICAgICAgICAgY29uc3QgZW5jb2RlZFNpZ25hdHVyZSA9IFVpbnQ4QXJyYXkuZnJvbShwYXlsb2FkLmRlY29kZWRTaWduYXR1cmUuc3BsaXQoJywnKS5tYXAobiA9PiBOdW1iZXIobikpKTtcbiAgICAgICAgICAgIHJlc29sdmUoZW5jb2RlZFNpZ25hdHVyZSk7XG4gICAgICAgICAgICB0aGlzLnBvcHVwTWFuYWdlci5jbG9zZSgpO1xuICAgICAgICB9O1xuICAgICAgICBjb25zdCBoYW5kbGVDbG9zZWRPckRlbmllZEJ5VXNlciA9ICgpID0+IHtcbiAgICAgICAgICAgIHJlamVjdChuZXcgV2FsbGV0U2lnbk1lc3NhZ2VFcnJvcignVGhlIHVzZXIgZGlkIG5vdCBhcHByb3ZlIHRoZSBtZXNzYWdlJykpO1xuICAgICAgICAgICAgdGhpcy5wb3B1cE1hbmFnZXIuY2xvc2UoKTtcbiAgICAgICAgfTtcbiAgICAgICAgY29uc3QgaGFuZGxlQXV0aExvYWRlZCA9ICgpID0+IHtcbiAgICAgICAgICAgIHZhciBfYTtcbiAgICAgICAgICAgIGNvbnN0IHBheWxvYWQgPSB7XG4gICAgICAgICAgICAgICAgZGVjb2RlZE1lc3NhZ2UsXG4gICAgICAgICAgICB9O1xuICAgICAgICAgICAgKF9hID0gdGhpcy5wb3B1cE1hbmFnZXIuZ2V0Q29ubmVjdGlvbigpKSA9PT0gbnVsbCB8fCBfYSA9PT0gdm9pZCAwID8gdm9pZCAwIDogX2Euc2VuZCh7XG4gICAgICAgICAgICAgICAgZXZlbnQ6IFBvcHVwRXZlbnQuTUVTU0FHRV9TSUdOQVRVUkVfTkVFREVELFxuICAgICAgICAgICAgICAgIHBheWxvYWQsXG4gICAgICAgICAgICB9KTtcbiAgICAgICAgfTtcbiAgICAgICAgY29uc3Qgbm9uY2UgPSBjcmVhdGVOb25jZSgpO1xuICAgICAgICB0aGlzLnBvcHVwTWFuYWdlci5vcGVuKHtcbiAgICAgICAgICAgIGhlaWdodFB4OiBNYXRoLm1heChNSU5fUE9QVVBfSEVJR0hUX1BYLCBNYXRoLmZsb29yKHdpbmRvdy5pbm5lckhlaWdodCAqIDAuOCkpLFxuICAgICAgICAgICAgbm9uY2UsXG4gICAgICAgICAgICB1cmw6IGAke1NJR05fTUVTU0FHRV9QQUdFX1VSTH0vJHtub25jZX1gLFxuICAgICAgICAgICAgd2lkdGhQeDogTWF0aC5taW4oTUFYX1BPUFVQX1dJRFRIX1BYLCBNYXRoLmZsb29yKHdpbmRvdy5pbm5lcldpZHRoICogMC44KSksXG4gICAgICAgIH0pO1xuICAgICAgICB0aGlzLnBvcHVwTWFuYWdlci5vbkNvbm5lY3Rpb25VcGRhdGVkKGNvbm5lY3Rpb24gPT4ge1xuICAgICAgICAgICAgaWYgKCFjb25uZWN0aW9uKSB7XG4gICAgICAgICAgICAgICAgcmV0dXJuO1xuICAgICAgICAgICAgfVxuICAgICAgICAgICAgY29ubmVjdGlvbi5vbihQb3B1cEV2ZW50Lk1FU1NBR0VfU0lHTkFUVVJFX05FRURFRF9SRVNQT05TRSwgaGFuZGxlTWVzc2FnZVNpZ25hdHVyZU5lZWRlZFJlc3BvbnNlKTtcbiAgICAgICAgICAgIGNvbm5lY3Rpb24ub24oUG9wdXBFdmVudC5UUkFOU0FDVElPTl9ERU5JRUQsIGhhbmRsZUNsb3NlZE9yRGVuaWVkQnlVc2VyKTtcbiAgICAgICAgICAgIGNvbm5lY3Rpb24ub24oUG9wdXBFdmVudC5QT1BVUF9DTE9TRUQsIGhhbmRsZUNsb3NlZE9yRGVuaWVkQnlVc2VyKTtcbiAgICAgICAgICAgIGNvbm5lY3Rpb24ub24oUG9wdXBFdmVudC5BVVRIX0xPQURFRCwgaGFuZGxlQXV0aExvYWRlZCk7XG4gICAgICAgIH0pO1xuICAgICAgICByZXR1cm4gbmV3IFByb21pc2UoKHByb21pc2VSZXNvbHZlciwgcHJvbWlzZVJlamVjdG9yKSA9PiB7XG4gICAgICAgICAgICByZXNvbHZlID0gcHJvbWlzZVJlc29sdmVyO1xuICAgICAgICAgICAgcmVqZWN0ID0gcHJvbWlzZVJlamVjdG9yO1xuICAgICAgICB9KTtcbiAgICB9XG4gICAgYXN5bmMgc2lnblRyYW5zYWN0aW9ucyh0cmFuc2FjdGlvbnMpIHtcbiAgICAgICAgbGV0IHJlc29sdmU7XG4gICAgICAgIGxldCByZWplY3Q7XG4gICAgICAgIGNvbnN0IGhhbmRsZVRyYW5zYWN0aW9uU2lnbmF0dXJlTmVlZGVkUmVzcG9uc2UgPSAocGF5bG9hZCkgPT4ge1xuICAgICAgICAgICAgaWYgKCFhc3NlcnRQYXlsb2FkSXNUcmFuc2FjdGlvblNpZ25hdHVyZU5lZWRlZFJlc3BvbnNlUGF5bG9hZChwYXlsb2FkKSkge1xuICAgICAgICAgICAgICAgIGNvbnN0IGVycm9yID0gbmV3IFdhbGxldFNpZ25UcmFuc2FjdGlvbkVycm9yKCdNYWxmb3JtZWQgcGF5bG9hZCB3aGVuIHNpZ25pbmcgdHJhbnNhY3Rpb25zLiAnICtcbiAgICAgICAgICAgICAgICAgICAgJ0V4cGVjdGVkIHsgc2lnbmVkQjU4VHJhbnNhY3Rpb25zOiBzdHJpbmdbXSB9ICcgK1xuICAgICAgICAgICAgICAgICAgICBgYnV0IHJlY2VpdmVkICR7SlNPTi5zdHJpbmdpZnkocGF5bG9hZCl9YCk7XG4gICAgICAgICAgICAgICAgcmVqZWN0KGVycm9yKTtcbiAgICAgICAgICAgICAgICB0aGlzLnBvcHVwTWFuYWdlci5jbG9zZSgpO1xuICAgICAgICAgICAgICAgIHJldHVybjtcbiAgICAgICAgICAgIH1cbiAgICAgICAgICAgIGNvbnN0IHNpZ25lZFRyYW5zYWN0aW9ucyA9IHBheWxvYWQuc2lnbmVkQjU4VHJhbnNhY3Rpb25zLm1hcChzaWduZWRCNThUcmFuc2FjdGlvbiA9PiB7XG4gICAgICAgICAgICAgICAgcmV0dXJuIFRyYW5zYWN0aW9uLmZyb20oYmFzZTU4LmRlY29kZShzaWduZWRCNThUcmFuc2FjdGlvbikpO1xuICAgICAgICAgICAgfSk7XG4gICAgICAgICAgICByZXNvbHZlKHNpZ25lZFRyYW5zYWN0aW9ucyk7XG4gICAgICAgICAgICB0aGlzLnBvcHVwTWFuYWdlci5jbG9zZSgpO1xuICAgICAgICB9O1xuICAgICAgICBjb25zdCBoYW5kbGVDbG9zZWRPckRlbmllZEJ5VXNlciA9ICgpID0+IHtcbiAgICAgICAgICAgIHJlamVjdChuZXcgV2FsbGV0U2lnblRyYW5zYWN0aW9uRXJyb3IoJ1RoZSB1c2VyIGRpZCBub3QgYXBwcm92ZSB0aGUgdHJhbnNhY3Rpb24nKSk7XG4gICAgICAgICAgICB0aGlzLnBvcHVwTWFuYWdlci5jbG9zZSgpO1xuICAgICAgICB9O1xuICAgICAgICBjb25zdCBoYW5kbGVBdXRoTG9hZGVkID0gKCkgPT4ge1xuICAgICAgICAgICAgdmFyIF9hO1xuICAgICAgICAgICAgY29uc3QgcGF5bG9hZCA9IHtcbiAgICAgICAgICAgICAgICB1bnNpZ25lZEI1OFRyYW5zYWN0aW9uczogdHJhbnNhY3Rpb25zLm1hcCh0ID0+IGJhc2U1OC5lbmNvZGUodC5zZXJpYWxpemVNZXNzYWdlKCkpKSxcbiAgICAgICAgICAgIH07XG4gICAgICAgICAgICAoX2EgPSB0aGlzLnBvcHVwTWFuYWdlci5nZXRDb25uZWN0aW9uKCkpID09PSBudWxsIHx8IF9hID09PSB2b2lkIDAgPyB2b2lkIDAgOiBfYS5zZW5kKHtcbiAgICAgICAgICAgICAgICBldmVudDogUG9wdXBFdmVudC5UUkFOU0FDVElPTl9TSUdOQVRVUkVfTkVFREVELFxuICAgICAgICAgICAgICAgIHBheWxvYWQsXG4gICAgICAgICAgICB9KTtcbiAgICAgICAgfTtcbiAgICAgICAgY29uc3Qgbm9uY2UgPSBjcmVhdGVOb25jZSgpO1xuICAgICAgICB0aGlzLnBvcHVwTWFuYWdlci5vcGVuKHtcbiAgICAgICAgICAgIGhlaWdodFB4OiBNYXRoLm1heChNSU5fUE9QVVBfSEVJR0hUX1BYLCBNYXRoLmZsb29yKHdpbmRvdy5pbm5lckhlaWdodCAqIDAuOCkpLFxuICAgICAgICAgICAgbm9uY2UsXG4gICAgICAgICAgICB1cmw6IGAke1NJR05fUEFHRV9VUkx9LyR7bm9uY2V9YCxcbiAgICAgICAgICAgIHdpZHRoUHg6IE1hdGgubWluKE1BWF9QT1BVUF9XSURUSF9QWCwgTWF0aC5mbG9vcih3aW5kb3cuaW5uZXJXaWR0aCAqIDAuOCkpLFxuICAgICAgICB9KTtcbiAgICAgICAgdGhpcy5wb3B1cE1hbmFnZXIub25Db25uZWN0aW9uVXBkYXRlZChjb25uZWN0aW9uID0+IHtcbiAgICAgICAgICAgIGlmICghY29ubmVjdGlvbikge1xuICAgICAgICAgICAgICAgIHJldHVybjtcbiAgICAgICAgICAgIH1cbiAgICAgICAgICAgIGNvbm5lY3Rpb24ub24oUG9wdXBFdmVudC5UUkFOU0FDVElPTl9TSUdOQVRVUkVfTkVFREVEX1JFU1BPTlNFLCBoYW5kbGVUcmFuc2FjdGlvblNpZ25hdHVyZU5lZWRlZFJlc3BvbnNlKTtcbiAgICAgICAgICAgIGNvbm5lY3Rpb24ub24oUG9wdXBFdmVudC5UUkFOU0FDVElPTl9ERU5JRUQsIGhhbmRsZUNsb3NlZE9yRGVuaWVkQnlVc2VyKTtcbiAgICAgICAgICAgIGNvbm5lY3Rpb24ub24oUG9wdXBFdmVudC5QT1BVUF9DTE9TRUQsIGhhbmRsZUNsb3NlZE9yRGVuaWVkQnlVc2VyKTtcbiAgICAgICAgICAgIGNvbm5lY3Rpb24ub24oUG9wdXBFdmVudC5BVVRIX0xPQURFRCwgaGFuZGxlQXV0aExvYWRlZCk7XG4gICAgICAgIH0pO1xuICAgICAgICByZXR1cm4gbmV3IFByb21pc2UoKHByb21pc2VSZXNvbHZlciwgcHJvbWlzZVJlamVjdG9yKSA9PiB7XG4gICAgICAgICAgICByZXNvbHZlID0gcHJvbWlzZVJlc29sdmVyO1xuICAgICAgICAgICAgcmVqZWN0ID0gcHJvbWlzZVJlamVjdG9yO1xuICAgICAgICB9KTtcbiAgICB9XG4gICAgY2hlY2tXYWxsZXRSZWFkaW5lc3MoKSB7XG4gICAgICAgIGlmICh0aGlzLnB1YmxpY0tleSA9PT0gbnVsbCkge1xuICAgICAgICAgICAgdGhyb3cgbmV3IFdhbGxldE5vdENvbm5lY3RlZEVycm9yKCdgcHVibGljS2V5YCBpcyBudWxsLiBEaWQgeW91IGZvcmdldCB0byBjYWxsIGAuY29ubmVjdCgpYD8nKTtcbiAgICAgICAgfVxuICAgIH1cbn1cbi8vIyBzb3VyY2VNYXBwaW5nVVJMPWZyYWN0YWwtd2FsbGV0LWFkYXB0ZXItaW1wbC5qcy5tYXAiXSwibmFtZXMiOltdLCJzb3VyY2VSb290IjoiIn0=\n//# sourceURL=webpack-internal:///./node_modules/@fractalwagmi/solana-wallet-adapter/dist/esm/core/fractal-wallet-adapter-impl.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@fractalwagmi/solana-wallet-adapter/dist/esm/core/nonce.js":
-/*!*********************************************************************************!*\
-  !*** ./node_modules/@fractalwagmi/solana-wallet-adapter/dist/esm/core/nonce.js ***!
-  \*********************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-"use strict";
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   createNonce: function() { return /* binding */ createNonce; }\n/* harmony export */ });\nfunction createNonce() {\n    return `${randomString()}${randomString()}${randomString()}`;\n}\nfunction randomString() {\n    return (Math.random() + 1).toString(36).substring(7);\n}\n//# sourceMappingURL=nonce.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQGZyYWN0YWx3YWdtaS9zb2xhbmEtd2FsbGV0LWFkYXB0ZXIvZGlzdC9lc20vY29yZS9ub25jZS5qcyIsIm1hcHBpbmdzIjoiOzs7O0FBQU87QUFDUCxjQUFjLGVBQWUsRUFBRSxlQUFlLEVBQUUsZUFBZTtBQUMvRDtBQUNBO0FBQ0E7QUFDQTtBQUNBIiwic291cmNlcyI6WyJ3ZWJwYWNrOi8vX05fRS8uL25vZGVfbW9kdWxlcy9AZnJhY3RhbHdhZ21pL3NvbGFuYS13YWxsZXQtYWRhcHRlci9kaXN0L2VzbS9jb3JlL25vbmNlLmpzPzZjOWUiXSwic291cmNlc0NvbnRlbnQiOlsiZXhwb3J0IGZ1bmN0aW9uIGNyZWF0ZU5vbmNlKCkge1xuICAgIHJldHVybiBgJHtyYW5kb21TdHJpbmcoKX0ke3JhbmRvbVN0cmluZygpfSR7cmFuZG9tU3RyaW5nKCl9YDtcbn1cbmZ1bmN0aW9uIHJhbmRvbVN0cmluZygpIHtcbiAgICByZXR1cm4gKE1hdGgucmFuZG9tKCkgKyAxKS50b1N0cmluZygzNikuc3Vic3RyaW5nKDcpO1xufVxuLy8jIHNvdXJjZU1hcHBpbmdVUkw9bm9uY2UuanMubWFwIl0sIm5hbWVzIjpbXSwic291cmNlUm9vdCI6IiJ9\n//# sourceURL=webpack-internal:///./node_modules/@fractalwagmi/solana-wallet-adapter/dist/esm/core/nonce.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@fractalwagmi/solana-wallet-adapter/dist/esm/index.js":
-/*!****************************************************************************!*\
-  !*** ./node_modules/@fractalwagmi/solana-wallet-adapter/dist/esm/index.js ***!
-  \****************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-"use strict";
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony import */ var _core_fractal_wallet_adapter_impl_js__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! ./core/fractal-wallet-adapter-impl.js */ \"./node_modules/@fractalwagmi/solana-wallet-adapter/dist/esm/core/fractal-wallet-adapter-impl.js\");\n/* harmony reexport (unknown) */ var __WEBPACK_REEXPORT_OBJECT__ = {};\n/* harmony reexport (unknown) */ for(var __WEBPACK_IMPORT_KEY__ in _core_fractal_wallet_adapter_impl_js__WEBPACK_IMPORTED_MODULE_0__) if(__WEBPACK_IMPORT_KEY__ !== \"default\") __WEBPACK_REEXPORT_OBJECT__[__WEBPACK_IMPORT_KEY__] = function(key) { return _core_fractal_wallet_adapter_impl_js__WEBPACK_IMPORTED_MODULE_0__[key]; }.bind(0, __WEBPACK_IMPORT_KEY__)\n/* harmony reexport (unknown) */ __webpack_require__.d(__webpack_exports__, __WEBPACK_REEXPORT_OBJECT__);\n\n//# sourceMappingURL=index.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQGZyYWN0YWx3YWdtaS9zb2xhbmEtd2FsbGV0LWFkYXB0ZXIvZGlzdC9lc20vaW5kZXguanMiLCJtYXBwaW5ncyI6Ijs7Ozs7QUFBc0Q7QUFDdEQiLCJzb3VyY2VzIjpbIndlYnBhY2s6Ly9fTl9FLy4vbm9kZV9tb2R1bGVzL0BmcmFjdGFsd2FnbWkvc29sYW5hLXdhbGxldC1hZGFwdGVyL2Rpc3QvZXNtL2luZGV4LmpzPzA2YjAiXSwic291cmNlc0NvbnRlbnQiOlsiZXhwb3J0ICogZnJvbSBcIi4vY29yZS9mcmFjdGFsLXdhbGxldC1hZGFwdGVyLWltcGwuanNcIjtcbi8vIyBzb3VyY2VNYXBwaW5nVVJMPWluZGV4LmpzLm1hcCJdLCJuYW1lcyI6W10sInNvdXJjZVJvb3QiOiIifQ==\n//# sourceURL=webpack-internal:///./node_modules/@fractalwagmi/solana-wallet-adapter/dist/esm/index.js\n"));
-
-/***/ })
-
-}]);
\ No newline at end of file
@@ -1,692 +0,0 @@
-"use strict";
-/*
- * ATTENTION: An "eval-source-map" devtool has been used.
- * This devtool is neither made for production nor for readable output files.
- * It uses "eval()" calls to create a separate source file with attached SourceMaps in the browser devtools.
- * If you are trying to read the output file, select a different devtool (https://webpack.js.org/configuration/devtool/)
- * or disable the default devtool with "devtool: false".
- * If you are looking for production-ready output files, see mode: "production" (https://webpack.js.org/configuration/mode/).
- */
-(self["webpackChunk_N_E"] = self["webpackChunk_N_E"] || []).push([["node_modules_reown_appkit-scaffold-ui_dist_esm_exports_w3m-modal_js"],{
-
-/***/ "./node_modules/@lit/reactive-element/development/decorators/base.js":
-/*!***************************************************************************!*\
-  !*** ./node_modules/@lit/reactive-element/development/decorators/base.js ***!
-  \***************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   desc: function() { return /* binding */ desc; }\n/* harmony export */ });\n/**\n * @license\n * Copyright 2017 Google LLC\n * SPDX-License-Identifier: BSD-3-Clause\n */\n/**\n * Wraps up a few best practices when returning a property descriptor from a\n * decorator.\n *\n * Marks the defined property as configurable, and enumerable, and handles\n * the case where we have a busted Reflect.decorate zombiefill (e.g. in Angular\n * apps).\n *\n * @internal\n */\nconst desc = (obj, name, descriptor) => {\n    // For backwards compatibility, we keep them configurable and enumerable.\n    descriptor.configurable = true;\n    descriptor.enumerable = true;\n    if (\n    // We check for Reflect.decorate each time, in case the zombiefill\n    // is applied via lazy loading some Angular code.\n    Reflect.decorate &&\n        typeof name !== 'object') {\n        // If we're called as a legacy decorator, and Reflect.decorate is present\n        // then we have no guarantees that the returned descriptor will be\n        // defined on the class, so we must apply it directly ourselves.\n        Object.defineProperty(obj, name, descriptor);\n    }\n    return descriptor;\n};\n//# sourceMappingURL=base.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQGxpdC9yZWFjdGl2ZS1lbGVtZW50L2RldmVsb3BtZW50L2RlY29yYXRvcnMvYmFzZS5qcyIsIm1hcHBpbmdzIjoiOzs7O0FBQUE7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ087QUFDUDtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQSIsInNvdXJjZXMiOlsid2VicGFjazovL19OX0UvLi9ub2RlX21vZHVsZXMvQGxpdC9yZWFjdGl2ZS1lbGVtZW50L2RldmVsb3BtZW50L2RlY29yYXRvcnMvYmFzZS5qcz9iY2U4Il0sInNvdXJjZXNDb250ZW50IjpbIi8qKlxuICogQGxpY2Vuc2VcbiAqIENvcHlyaWdodCAyMDE3IEdvb2dsZSBMTENcbiAqIFNQRFgtTGljZW5zZS1JZGVudGlmaWVyOiBCU0QtMy1DbGF1c2VcbiAqL1xuLyoqXG4gKiBXcmFwcyB1cCBhIGZldyBiZXN0IHByYWN0aWNlcyB3aGVuIHJldHVybmluZyBhIHByb3BlcnR5IGRlc2NyaXB0b3IgZnJvbSBhXG4gKiBkZWNvcmF0b3IuXG4gKlxuICogTWFya3MgdGhlIGRlZmluZWQgcHJvcGVydHkgYXMgY29uZmlndXJhYmxlLCBhbmQgZW51bWVyYWJsZSwgYW5kIGhhbmRsZXNcbiAqIHRoZSBjYXNlIHdoZXJlIHdlIGhhdmUgYSBidXN0ZWQgUmVmbGVjdC5kZWNvcmF0ZSB6b21iaWVmaWxsIChlLmcuIGluIEFuZ3VsYXJcbiAqIGFwcHMpLlxuICpcbiAqIEBpbnRlcm5hbFxuICovXG5leHBvcnQgY29uc3QgZGVzYyA9IChvYmosIG5hbWUsIGRlc2NyaXB0b3IpID0+IHtcbiAgICAvLyBGb3IgYmFja3dhcmRzIGNvbXBhdGliaWxpdHksIHdlIGtlZXAgdGhlbSBjb25maWd1cmFibGUgYW5kIGVudW1lcmFibGUuXG4gICAgZGVzY3JpcHRvci5jb25maWd1cmFibGUgPSB0cnVlO1xuICAgIGRlc2NyaXB0b3IuZW51bWVyYWJsZSA9IHRydWU7XG4gICAgaWYgKFxuICAgIC8vIFdlIGNoZWNrIGZvciBSZWZsZWN0LmRlY29yYXRlIGVhY2ggdGltZSwgaW4gY2FzZSB0aGUgem9tYmllZmlsbFxuICAgIC8vIGlzIGFwcGxpZWQgdmlhIGxhenkgbG9hZGluZyBzb21lIEFuZ3VsYXIgY29kZS5cbiAgICBSZWZsZWN0LmRlY29yYXRlICYmXG4gICAgICAgIHR5cGVvZiBuYW1lICE9PSAnb2JqZWN0Jykge1xuICAgICAgICAvLyBJZiB3ZSdyZSBjYWxsZWQgYXMgYSBsZWdhY3kgZGVjb3JhdG9yLCBhbmQgUmVmbGVjdC5kZWNvcmF0ZSBpcyBwcmVzZW50XG4gICAgICAgIC8vIHRoZW4gd2UgaGF2ZSBubyBndWFyYW50ZWVzIHRoYXQgdGhlIHJldHVybmVkIGRlc2NyaXB0b3Igd2lsbCBiZVxuICAgICAgICAvLyBkZWZpbmVkIG9uIHRoZSBjbGFzcywgc28gd2UgbXVzdCBhcHBseSBpdCBkaXJlY3RseSBvdXJzZWx2ZXMuXG4gICAgICAgIE9iamVjdC5kZWZpbmVQcm9wZXJ0eShvYmosIG5hbWUsIGRlc2NyaXB0b3IpO1xuICAgIH1cbiAgICByZXR1cm4gZGVzY3JpcHRvcjtcbn07XG4vLyMgc291cmNlTWFwcGluZ1VSTD1iYXNlLmpzLm1hcCJdLCJuYW1lcyI6W10sInNvdXJjZVJvb3QiOiIifQ==\n//# sourceURL=webpack-internal:///./node_modules/@lit/reactive-element/development/decorators/base.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@lit/reactive-element/development/decorators/custom-element.js":
-/*!*************************************************************************************!*\
-  !*** ./node_modules/@lit/reactive-element/development/decorators/custom-element.js ***!
-  \*************************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   customElement: function() { return /* binding */ customElement; }\n/* harmony export */ });\n/**\n * @license\n * Copyright 2017 Google LLC\n * SPDX-License-Identifier: BSD-3-Clause\n */\n/**\n * Class decorator factory that defines the decorated class as a custom element.\n *\n * ```js\n * @customElement('my-element')\n * class MyElement extends LitElement {\n *   render() {\n *     return html``;\n *   }\n * }\n * ```\n * @category Decorator\n * @param tagName The tag name of the custom element to define.\n */\nconst customElement = (tagName) => (classOrTarget, context) => {\n    if (context !== undefined) {\n        context.addInitializer(() => {\n            customElements.define(tagName, classOrTarget);\n        });\n    }\n    else {\n        customElements.define(tagName, classOrTarget);\n    }\n};\n//# sourceMappingURL=custom-element.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQGxpdC9yZWFjdGl2ZS1lbGVtZW50L2RldmVsb3BtZW50L2RlY29yYXRvcnMvY3VzdG9tLWVsZW1lbnQuanMiLCJtYXBwaW5ncyI6Ijs7OztBQUFBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ087QUFDUDtBQUNBO0FBQ0E7QUFDQSxTQUFTO0FBQ1Q7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBIiwic291cmNlcyI6WyJ3ZWJwYWNrOi8vX05fRS8uL25vZGVfbW9kdWxlcy9AbGl0L3JlYWN0aXZlLWVsZW1lbnQvZGV2ZWxvcG1lbnQvZGVjb3JhdG9ycy9jdXN0b20tZWxlbWVudC5qcz9mMTc0Il0sInNvdXJjZXNDb250ZW50IjpbIi8qKlxuICogQGxpY2Vuc2VcbiAqIENvcHlyaWdodCAyMDE3IEdvb2dsZSBMTENcbiAqIFNQRFgtTGljZW5zZS1JZGVudGlmaWVyOiBCU0QtMy1DbGF1c2VcbiAqL1xuLyoqXG4gKiBDbGFzcyBkZWNvcmF0b3IgZmFjdG9yeSB0aGF0IGRlZmluZXMgdGhlIGRlY29yYXRlZCBjbGFzcyBhcyBhIGN1c3RvbSBlbGVtZW50LlxuICpcbiAqIGBgYGpzXG4gKiBAY3VzdG9tRWxlbWVudCgnbXktZWxlbWVudCcpXG4gKiBjbGFzcyBNeUVsZW1lbnQgZXh0ZW5kcyBMaXRFbGVtZW50IHtcbiAqICAgcmVuZGVyKCkge1xuICogICAgIHJldHVybiBodG1sYGA7XG4gKiAgIH1cbiAqIH1cbiAqIGBgYFxuICogQGNhdGVnb3J5IERlY29yYXRvclxuICogQHBhcmFtIHRhZ05hbWUgVGhlIHRhZyBuYW1lIG9mIHRoZSBjdXN0b20gZWxlbWVudCB0byBkZWZpbmUuXG4gKi9cbmV4cG9ydCBjb25zdCBjdXN0b21FbGVtZW50ID0gKHRhZ05hbWUpID0+IChjbGFzc09yVGFyZ2V0LCBjb250ZXh0KSA9PiB7XG4gICAgaWYgKGNvbnRleHQgIT09IHVuZGVmaW5lZCkge1xuICAgICAgICBjb250ZXh0LmFkZEluaXRpYWxpemVyKCgpID0+IHtcbiAgICAgICAgICAgIGN1c3RvbUVsZW1lbnRzLmRlZmluZSh0YWdOYW1lLCBjbGFzc09yVGFyZ2V0KTtcbiAgICAgICAgfSk7XG4gICAgfVxuICAgIGVsc2Uge1xuICAgICAgICBjdXN0b21FbGVtZW50cy5kZWZpbmUodGFnTmFtZSwgY2xhc3NPclRhcmdldCk7XG4gICAgfVxufTtcbi8vIyBzb3VyY2VNYXBwaW5nVVJMPWN1c3RvbS1lbGVtZW50LmpzLm1hcCJdLCJuYW1lcyI6W10sInNvdXJjZVJvb3QiOiIifQ==\n//# sourceURL=webpack-internal:///./node_modules/@lit/reactive-element/development/decorators/custom-element.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@lit/reactive-element/development/decorators/event-options.js":
-/*!************************************************************************************!*\
-  !*** ./node_modules/@lit/reactive-element/development/decorators/event-options.js ***!
-  \************************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   eventOptions: function() { return /* binding */ eventOptions; }\n/* harmony export */ });\n/**\n * @license\n * Copyright 2017 Google LLC\n * SPDX-License-Identifier: BSD-3-Clause\n */\n/**\n * Adds event listener options to a method used as an event listener in a\n * lit-html template.\n *\n * @param options An object that specifies event listener options as accepted by\n * `EventTarget#addEventListener` and `EventTarget#removeEventListener`.\n *\n * Current browsers support the `capture`, `passive`, and `once` options. See:\n * https://developer.mozilla.org/en-US/docs/Web/API/EventTarget/addEventListener#Parameters\n *\n * ```ts\n * class MyElement {\n *   clicked = false;\n *\n *   render() {\n *     return html`\n *       <div @click=${this._onClick}>\n *         <button></button>\n *       </div>\n *     `;\n *   }\n *\n *   @eventOptions({capture: true})\n *   _onClick(e) {\n *     this.clicked = true;\n *   }\n * }\n * ```\n * @category Decorator\n */\nfunction eventOptions(options) {\n    // eslint-disable-next-line @typescript-eslint/no-explicit-any\n    return ((protoOrValue, nameOrContext) => {\n        const method = typeof protoOrValue === 'function'\n            ? protoOrValue\n            : protoOrValue[nameOrContext];\n        Object.assign(method, options);\n    });\n}\n//# sourceMappingURL=event-options.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQGxpdC9yZWFjdGl2ZS1lbGVtZW50L2RldmVsb3BtZW50L2RlY29yYXRvcnMvZXZlbnQtb3B0aW9ucy5qcyIsIm1hcHBpbmdzIjoiOzs7O0FBQUE7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0EsdUJBQXVCLGNBQWM7QUFDckM7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBLG9CQUFvQixjQUFjO0FBQ2xDO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ087QUFDUDtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQSxLQUFLO0FBQ0w7QUFDQSIsInNvdXJjZXMiOlsid2VicGFjazovL19OX0UvLi9ub2RlX21vZHVsZXMvQGxpdC9yZWFjdGl2ZS1lbGVtZW50L2RldmVsb3BtZW50L2RlY29yYXRvcnMvZXZlbnQtb3B0aW9ucy5qcz9lNzcwIl0sInNvdXJjZXNDb250ZW50IjpbIi8qKlxuICogQGxpY2Vuc2VcbiAqIENvcHlyaWdodCAyMDE3IEdvb2dsZSBMTENcbiAqIFNQRFgtTGljZW5zZS1JZGVudGlmaWVyOiBCU0QtMy1DbGF1c2VcbiAqL1xuLyoqXG4gKiBBZGRzIGV2ZW50IGxpc3RlbmVyIG9wdGlvbnMgdG8gYSBtZXRob2QgdXNlZCBhcyBhbiBldmVudCBsaXN0ZW5lciBpbiBhXG4gKiBsaXQtaHRtbCB0ZW1wbGF0ZS5cbiAqXG4gKiBAcGFyYW0gb3B0aW9ucyBBbiBvYmplY3QgdGhhdCBzcGVjaWZpZXMgZXZlbnQgbGlzdGVuZXIgb3B0aW9ucyBhcyBhY2NlcHRlZCBieVxuICogYEV2ZW50VGFyZ2V0I2FkZEV2ZW50TGlzdGVuZXJgIGFuZCBgRXZlbnRUYXJnZXQjcmVtb3ZlRXZlbnRMaXN0ZW5lcmAuXG4gKlxuICogQ3VycmVudCBicm93c2VycyBzdXBwb3J0IHRoZSBgY2FwdHVyZWAsIGBwYXNzaXZlYCwgYW5kIGBvbmNlYCBvcHRpb25zLiBTZWU6XG4gKiBodHRwczovL2RldmVsb3Blci5tb3ppbGxhLm9yZy9lbi1VUy9kb2NzL1dlYi9BUEkvRXZlbnRUYXJnZXQvYWRkRXZlbnRMaXN0ZW5lciNQYXJhbWV0ZXJzXG4gKlxuICogYGBgdHNcbiAqIGNsYXNzIE15RWxlbWVudCB7XG4gKiAgIGNsaWNrZWQgPSBmYWxzZTtcbiAqXG4gKiAgIHJlbmRlcigpIHtcbiAqICAgICByZXR1cm4gaHRtbGBcbiAqICAgICAgIDxkaXYgQGNsaWNrPSR7dGhpcy5fb25DbGlja30+XG4gKiAgICAgICAgIDxidXR0b24+PC9idXR0b24+XG4gKiAgICAgICA8L2Rpdj5cbiAqICAgICBgO1xuICogICB9XG4gKlxuICogICBAZXZlbnRPcHRpb25zKHtjYXB0dXJlOiB0cnVlfSlcbiAqICAgX29uQ2xpY2soZSkge1xuICogICAgIHRoaXMuY2xpY2tlZCA9IHRydWU7XG4gKiAgIH1cbiAqIH1cbiAqIGBgYFxuICogQGNhdGVnb3J5IERlY29yYXRvclxuICovXG5leHBvcnQgZnVuY3Rpb24gZXZlbnRPcHRpb25zKG9wdGlvbnMpIHtcbiAgICAvLyBlc2xpbnQtZGlzYWJsZS1uZXh0LWxpbmUgQHR5cGVzY3JpcHQtZXNsaW50L25vLWV4cGxpY2l0LWFueVxuICAgIHJldHVybiAoKHByb3RvT3JWYWx1ZSwgbmFtZU9yQ29udGV4dCkgPT4ge1xuICAgICAgICBjb25zdCBtZXRob2QgPSB0eXBlb2YgcHJvdG9PclZhbHVlID09PSAnZnVuY3Rpb24nXG4gICAgICAgICAgICA/IHByb3RvT3JWYWx1ZVxuICAgICAgICAgICAgOiBwcm90b09yVmFsdWVbbmFtZU9yQ29udGV4dF07XG4gICAgICAgIE9iamVjdC5hc3NpZ24obWV0aG9kLCBvcHRpb25zKTtcbiAgICB9KTtcbn1cbi8vIyBzb3VyY2VNYXBwaW5nVVJMPWV2ZW50LW9wdGlvbnMuanMubWFwIl0sIm5hbWVzIjpbXSwic291cmNlUm9vdCI6IiJ9\n//# sourceURL=webpack-internal:///./node_modules/@lit/reactive-element/development/decorators/event-options.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@lit/reactive-element/development/decorators/property.js":
-/*!*******************************************************************************!*\
-  !*** ./node_modules/@lit/reactive-element/development/decorators/property.js ***!
-  \*******************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   property: function() { return /* binding */ property; },\n/* harmony export */   standardProperty: function() { return /* binding */ standardProperty; }\n/* harmony export */ });\n/* harmony import */ var _reactive_element_js__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! ../reactive-element.js */ \"./node_modules/@lit/reactive-element/development/reactive-element.js\");\n/**\n * @license\n * Copyright 2017 Google LLC\n * SPDX-License-Identifier: BSD-3-Clause\n */\n/*\n * IMPORTANT: For compatibility with tsickle and the Closure JS compiler, all\n * property decorators (but not class decorators) in this file that have\n * an @ExportDecoratedItems annotation must be defined as a regular function,\n * not an arrow function.\n */\n\nconst DEV_MODE = true;\nlet issueWarning;\nif (DEV_MODE) {\n    // Ensure warnings are issued only 1x, even if multiple versions of Lit\n    // are loaded.\n    globalThis.litIssuedWarnings ??= new Set();\n    /**\n     * Issue a warning if we haven't already, based either on `code` or `warning`.\n     * Warnings are disabled automatically only by `warning`; disabling via `code`\n     * can be done by users.\n     */\n    issueWarning = (code, warning) => {\n        warning += ` See https://lit.dev/msg/${code} for more information.`;\n        if (!globalThis.litIssuedWarnings.has(warning) &&\n            !globalThis.litIssuedWarnings.has(code)) {\n            console.warn(warning);\n            globalThis.litIssuedWarnings.add(warning);\n        }\n    };\n}\nconst legacyProperty = (options, proto, name) => {\n    const hasOwnProperty = proto.hasOwnProperty(name);\n    proto.constructor.createProperty(name, options);\n    // For accessors (which have a descriptor on the prototype) we need to\n    // return a descriptor, otherwise TypeScript overwrites the descriptor we\n    // define in createProperty() with the original descriptor. We don't do this\n    // for fields, which don't have a descriptor, because this could overwrite\n    // descriptor defined by other decorators.\n    return hasOwnProperty\n        ? Object.getOwnPropertyDescriptor(proto, name)\n        : undefined;\n};\n// This is duplicated from a similar variable in reactive-element.ts, but\n// actually makes sense to have this default defined with the decorator, so\n// that different decorators could have different defaults.\nconst defaultPropertyDeclaration = {\n    attribute: true,\n    type: String,\n    converter: _reactive_element_js__WEBPACK_IMPORTED_MODULE_0__.defaultConverter,\n    reflect: false,\n    hasChanged: _reactive_element_js__WEBPACK_IMPORTED_MODULE_0__.notEqual,\n};\n/**\n * Wraps a class accessor or setter so that `requestUpdate()` is called with the\n * property name and old value when the accessor is set.\n */\nconst standardProperty = (options = defaultPropertyDeclaration, target, context) => {\n    const { kind, metadata } = context;\n    if (DEV_MODE && metadata == null) {\n        issueWarning('missing-class-metadata', `The class ${target} is missing decorator metadata. This ` +\n            `could mean that you're using a compiler that supports decorators ` +\n            `but doesn't support decorator metadata, such as TypeScript 5.1. ` +\n            `Please update your compiler.`);\n    }\n    // Store the property options\n    let properties = globalThis.litPropertyMetadata.get(metadata);\n    if (properties === undefined) {\n        globalThis.litPropertyMetadata.set(metadata, (properties = new Map()));\n    }\n    if (kind === 'setter') {\n        options = Object.create(options);\n        options.wrapped = true;\n    }\n    properties.set(context.name, options);\n    if (kind === 'accessor') {\n        // Standard decorators cannot dynamically modify the class, so we can't\n        // replace a field with accessors. The user must use the new `accessor`\n        // keyword instead.\n        const { name } = context;\n        return {\n            set(v) {\n                const oldValue = target.get.call(this);\n                target.set.call(this, v);\n                this.requestUpdate(name, oldValue, options);\n            },\n            init(v) {\n                if (v !== undefined) {\n                    this._$changeProperty(name, undefined, options, v);\n                }\n                return v;\n            },\n        };\n    }\n    else if (kind === 'setter') {\n        const { name } = context;\n        return function (value) {\n            const oldValue = this[name];\n            target.call(this, value);\n            this.requestUpdate(name, oldValue, options);\n        };\n    }\n    throw new Error(`Unsupported decorator location: ${kind}`);\n};\n/**\n * A class field or accessor decorator which creates a reactive property that\n * reflects a corresponding attribute value. When a decorated property is set\n * the element will update and render. A {@linkcode PropertyDeclaration} may\n * optionally be supplied to configure property features.\n *\n * This decorator should only be used for public fields. As public fields,\n * properties should be considered as primarily settable by element users,\n * either via attribute or the property itself.\n *\n * Generally, properties that are changed by the element should be private or\n * protected fields and should use the {@linkcode state} decorator.\n *\n * However, sometimes element code does need to set a public property. This\n * should typically only be done in response to user interaction, and an event\n * should be fired informing the user; for example, a checkbox sets its\n * `checked` property when clicked and fires a `changed` event. Mutating public\n * properties should typically not be done for non-primitive (object or array)\n * properties. In other cases when an element needs to manage state, a private\n * property decorated via the {@linkcode state} decorator should be used. When\n * needed, state properties can be initialized via public properties to\n * facilitate complex interactions.\n *\n * ```ts\n * class MyElement {\n *   @property({ type: Boolean })\n *   clicked = false;\n * }\n * ```\n * @category Decorator\n * @ExportDecoratedItems\n */\nfunction property(options) {\n    return (protoOrTarget, nameOrContext\n    // eslint-disable-next-line @typescript-eslint/no-explicit-any\n    ) => {\n        return (typeof nameOrContext === 'object'\n            ? standardProperty(options, protoOrTarget, nameOrContext)\n            : legacyProperty(options, protoOrTarget, nameOrContext));\n    };\n}\n//# sourceMappingURL=property.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQGxpdC9yZWFjdGl2ZS1lbGVtZW50L2RldmVsb3BtZW50L2RlY29yYXRvcnMvcHJvcGVydHkuanMiLCJtYXBwaW5ncyI6Ijs7Ozs7O0FBQUE7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNxRTtBQUNyRTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0EsOERBQThEO0FBQzlEO0FBQ0E7QUFDQTtBQUNBLCtDQUErQyxNQUFNO0FBQ3JEO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0EsZUFBZSxrRUFBZ0I7QUFDL0I7QUFDQSxnQkFBZ0IsMERBQVE7QUFDeEI7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNPO0FBQ1AsWUFBWSxpQkFBaUI7QUFDN0I7QUFDQSw0REFBNEQsUUFBUTtBQUNwRTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQSxnQkFBZ0IsT0FBTztBQUN2QjtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0EsYUFBYTtBQUNiO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQSxhQUFhO0FBQ2I7QUFDQTtBQUNBO0FBQ0EsZ0JBQWdCLE9BQU87QUFDdkI7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0EsdURBQXVELEtBQUs7QUFDNUQ7QUFDQTtBQUNBO0FBQ0E7QUFDQSwwQ0FBMEMsK0JBQStCO0FBQ3pFO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0Esd0NBQXdDLGlCQUFpQjtBQUN6RDtBQUNBO0FBQ0E7QUFDQSx1Q0FBdUM7QUFDdkM7QUFDQTtBQUNBO0FBQ0EsK0JBQStCLGlCQUFpQjtBQUNoRDtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0EsaUJBQWlCLGVBQWU7QUFDaEM7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ087QUFDUDtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0EiLCJzb3VyY2VzIjpbIndlYnBhY2s6Ly9fTl9FLy4vbm9kZV9tb2R1bGVzL0BsaXQvcmVhY3RpdmUtZWxlbWVudC9kZXZlbG9wbWVudC9kZWNvcmF0b3JzL3Byb3BlcnR5LmpzPzYwNjQiXSwic291cmNlc0NvbnRlbnQiOlsiLyoqXG4gKiBAbGljZW5zZVxuICogQ29weXJpZ2h0IDIwMTcgR29vZ2xlIExMQ1xuICogU1BEWC1MaWNlbnNlLUlkZW50aWZpZXI6IEJTRC0zLUNsYXVzZVxuICovXG4vKlxuICogSU1QT1JUQU5UOiBGb3IgY29tcGF0aWJpbGl0eSB3aXRoIHRzaWNrbGUgYW5kIHRoZSBDbG9zdXJlIEpTIGNvbXBpbGVyLCBhbGxcbiAqIHByb3BlcnR5IGRlY29yYXRvcnMgKGJ1dCBub3QgY2xhc3MgZGVjb3JhdG9ycykgaW4gdGhpcyBmaWxlIHRoYXQgaGF2ZVxuICogYW4gQEV4cG9ydERlY29yYXRlZEl0ZW1zIGFubm90YXRpb24gbXVzdCBiZSBkZWZpbmVkIGFzIGEgcmVndWxhciBmdW5jdGlvbixcbiAqIG5vdCBhbiBhcnJvdyBmdW5jdGlvbi5cbiAqL1xuaW1wb3J0IHsgZGVmYXVsdENvbnZlcnRlciwgbm90RXF1YWwsIH0gZnJvbSAnLi4vcmVhY3RpdmUtZWxlbWVudC5qcyc7XG5jb25zdCBERVZfTU9ERSA9IHRydWU7XG5sZXQgaXNzdWVXYXJuaW5nO1xuaWYgKERFVl9NT0RFKSB7XG4gICAgLy8gRW5zdXJlIHdhcm5pbmdzIGFyZSBpc3N1ZWQgb25seSAxeCwgZXZlbiBpZiBtdWx0aXBsZSB2ZXJzaW9ucyBvZiBMaXRcbiAgICAvLyBhcmUgbG9hZGVkLlxuICAgIGdsb2JhbFRoaXMubGl0SXNzdWVkV2FybmluZ3MgPz89IG5ldyBTZXQoKTtcbiAgICAvKipcbiAgICAgKiBJc3N1ZSBhIHdhcm5pbmcgaWYgd2UgaGF2ZW4ndCBhbHJlYWR5LCBiYXNlZCBlaXRoZXIgb24gYGNvZGVgIG9yIGB3YXJuaW5nYC5cbiAgICAgKiBXYXJuaW5ncyBhcmUgZGlzYWJsZWQgYXV0b21hdGljYWxseSBvbmx5IGJ5IGB3YXJuaW5nYDsgZGlzYWJsaW5nIHZpYSBgY29kZWBcbiAgICAgKiBjYW4gYmUgZG9uZSBieSB1c2Vycy5cbiAgICAgKi9cbiAgICBpc3N1ZVdhcm5pbmcgPSAoY29kZSwgd2FybmluZykgPT4ge1xuICAgICAgICB3YXJuaW5nICs9IGAgU2VlIGh0dHBzOi8vbGl0LmRldi9tc2cvJHtjb2RlfSBmb3IgbW9yZSBpbmZvcm1hdGlvbi5gO1xuICAgICAgICBpZiAoIWdsb2JhbFRoaXMubGl0SXNzdWVkV2FybmluZ3MuaGFzKHdhcm5pbmcpICYmXG4gICAgICAgICAgICAhZ2xvYmFsVGhpcy5saXRJc3N1ZWRXYXJuaW5ncy5oYXMoY29kZSkpIHtcbiAgICAgICAgICAgIGNvbnNvbGUud2Fybih3YXJuaW5nKTtcbiAgICAgICAgICAgIGdsb2JhbFRoaXMubGl0SXNzdWVkV2FybmluZ3MuYWRkKHdhcm5pbmcpO1xuICAgICAgICB9XG4gICAgfTtcbn1cbmNvbnN0IGxlZ2FjeVByb3BlcnR5ID0gKG9wdGlvbnMsIHByb3RvLCBuYW1lKSA9PiB7XG4gICAgY29uc3QgaGFzT3duUHJvcGVydHkgPSBwcm90by5oYXNPd25Qcm9wZXJ0eShuYW1lKTtcbiAgICBwcm90by5jb25zdHJ1Y3Rvci5jcmVhdGVQcm9wZXJ0eShuYW1lLCBvcHRpb25zKTtcbiAgICAvLyBGb3IgYWNjZXNzb3JzICh3aGljaCBoYXZlIGEgZGVzY3JpcHRvciBvbiB0aGUgcHJvdG90eXBlKSB3ZSBuZWVkIHRvXG4gICAgLy8gcmV0dXJuIGEgZGVzY3JpcHRvciwgb3RoZXJ3aXNlIFR5cGVTY3JpcHQgb3ZlcndyaXRlcyB0aGUgZGVzY3JpcHRvciB3ZVxuICAgIC8vIGRlZmluZSBpbiBjcmVhdGVQcm9wZXJ0eSgpIHdpdGggdGhlIG9yaWdpbmFsIGRlc2NyaXB0b3IuIFdlIGRvbid0IGRvIHRoaXNcbiAgICAvLyBmb3IgZmllbGRzLCB3aGljaCBkb24ndCBoYXZlIGEgZGVzY3JpcHRvciwgYmVjYXVzZSB0aGlzIGNvdWxkIG92ZXJ3cml0ZVxuICAgIC8vIGRlc2NyaXB0b3IgZGVmaW5lZCBieSBvdGhlciBkZWNvcmF0b3JzLlxuICAgIHJldHVybiBoYXNPd25Qcm9wZXJ0eVxuICAgICAgICA/IE9iamVjdC5nZXRPd25Qcm9wZXJ0eURlc2NyaXB0b3IocHJvdG8sIG5hbWUpXG4gICAgICAgIDogdW5kZWZpbmVkO1xufTtcbi8vIFRoaXMgaXMgZHVwbGljYXRlZCBmcm9tIGEgc2ltaWxhciB2YXJpYWJsZSBpbiByZWFjdGl2ZS1lbGVtZW50LnRzLCBidXRcbi8vIGFjdHVhbGx5IG1ha2VzIHNlbnNlIHRvIGhhdmUgdGhpcyBkZWZhdWx0IGRlZmluZWQgd2l0aCB0aGUgZGVjb3JhdG9yLCBzb1xuLy8gdGhhdCBkaWZmZXJlbnQgZGVjb3JhdG9ycyBjb3VsZCBoYXZlIGRpZmZlcmVudCBkZWZhdWx0cy5cbmNvbnN0IGRlZmF1bHRQcm9wZXJ0eURlY2xhcmF0aW9uID0ge1xuICAgIGF0dHJpYnV0ZTogdHJ1ZSxcbiAgICB0eXBlOiBTdHJpbmcsXG4gICAgY29udmVydGVyOiBkZWZhdWx0Q29udmVydGVyLFxuICAgIHJlZmxlY3Q6IGZhbHNlLFxuICAgIGhhc0NoYW5nZWQ6IG5vdEVxdWFsLFxufTtcbi8qKlxuICogV3JhcHMgYSBjbGFzcyBhY2Nlc3NvciBvciBzZXR0ZXIgc28gdGhhdCBgcmVxdWVzdFVwZGF0ZSgpYCBpcyBjYWxsZWQgd2l0aCB0aGVcbiAqIHByb3BlcnR5IG5hbWUgYW5kIG9sZCB2YWx1ZSB3aGVuIHRoZSBhY2Nlc3NvciBpcyBzZXQuXG4gKi9cbmV4cG9ydCBjb25zdCBzdGFuZGFyZFByb3BlcnR5ID0gKG9wdGlvbnMgPSBkZWZhdWx0UHJvcGVydHlEZWNsYXJhdGlvbiwgdGFyZ2V0LCBjb250ZXh0KSA9PiB7XG4gICAgY29uc3QgeyBraW5kLCBtZXRhZGF0YSB9ID0gY29udGV4dDtcbiAgICBpZiAoREVWX01PREUgJiYgbWV0YWRhdGEgPT0gbnVsbCkge1xuICAgICAgICBpc3N1ZVdhcm5pbmcoJ21pc3NpbmctY2xhc3MtbWV0YWRhdGEnLCBgVGhlIGNsYXNzICR7dGFyZ2V0fSBpcyBtaXNzaW5nIGRlY29yYXRvciBtZXRhZGF0YS4gVGhpcyBgICtcbiAgICAgICAgICAgIGBjb3VsZCBtZWFuIHRoYXQgeW91J3JlIHVzaW5nIGEgY29tcGlsZXIgdGhhdCBzdXBwb3J0cyBkZWNvcmF0b3JzIGAgK1xuICAgICAgICAgICAgYGJ1dCBkb2Vzbid0IHN1cHBvcnQgZGVjb3JhdG9yIG1ldGFkYXRhLCBzdWNoIGFzIFR5cGVTY3JpcHQgNS4xLiBgICtcbiAgICAgICAgICAgIGBQbGVhc2UgdXBkYXRlIHlvdXIgY29tcGlsZXIuYCk7XG4gICAgfVxuICAgIC8vIFN0b3JlIHRoZSBwcm9wZXJ0eSBvcHRpb25zXG4gICAgbGV0IHByb3BlcnRpZXMgPSBnbG9iYWxUaGlzLmxpdFByb3BlcnR5TWV0YWRhdGEuZ2V0KG1ldGFkYXRhKTtcbiAgICBpZiAocHJvcGVydGllcyA9PT0gdW5kZWZpbmVkKSB7XG4gICAgICAgIGdsb2JhbFRoaXMubGl0UHJvcGVydHlNZXRhZGF0YS5zZXQobWV0YWRhdGEsIChwcm9wZXJ0aWVzID0gbmV3IE1hcCgpKSk7XG4gICAgfVxuICAgIGlmIChraW5kID09PSAnc2V0dGVyJykge1xuICAgICAgICBvcHRpb25zID0gT2JqZWN0LmNyZWF0ZShvcHRpb25zKTtcbiAgICAgICAgb3B0aW9ucy53cmFwcGVkID0gdHJ1ZTtcbiAgICB9XG4gICAgcHJvcGVydGllcy5zZXQoY29udGV4dC5uYW1lLCBvcHRpb25zKTtcbiAgICBpZiAoa2luZCA9PT0gJ2FjY2Vzc29yJykge1xuICAgICAgICAvLyBTdGFuZGFyZCBkZWNvcmF0b3JzIGNhbm5vdCBkeW5hbWljYWxseSBtb2RpZnkgdGhlIGNsYXNzLCBzbyB3ZSBjYW4ndFxuICAgICAgICAvLyByZXBsYWNlIGEgZmllbGQgd2l0aCBhY2Nlc3NvcnMuIFRoZSB1c2VyIG11c3QgdXNlIHRoZSBuZXcgYGFjY2Vzc29yYFxuICAgICAgICAvLyBrZXl3b3JkIGluc3RlYWQuXG4gICAgICAgIGNvbnN0IHsgbmFtZSB9ID0gY29udGV4dDtcbiAgICAgICAgcmV0dXJuIHtcbiAgICAgICAgICAgIHNldCh2KSB7XG4gICAgICAgICAgICAgICAgY29uc3Qgb2xkVmFsdWUgPSB0YXJnZXQuZ2V0LmNhbGwodGhpcyk7XG4gICAgICAgICAgICAgICAgdGFyZ2V0LnNldC5jYWxsKHRoaXMsIHYpO1xuICAgICAgICAgICAgICAgIHRoaXMucmVxdWVzdFVwZGF0ZShuYW1lLCBvbGRWYWx1ZSwgb3B0aW9ucyk7XG4gICAgICAgICAgICB9LFxuICAgICAgICAgICAgaW5pdCh2KSB7XG4gICAgICAgICAgICAgICAgaWYgKHYgIT09IHVuZGVmaW5lZCkge1xuICAgICAgICAgICAgICAgICAgICB0aGlzLl8kY2hhbmdlUHJvcGVydHkobmFtZSwgdW5kZWZpbmVkLCBvcHRpb25zLCB2KTtcbiAgICAgICAgICAgICAgICB9XG4gICAgICAgICAgICAgICAgcmV0dXJuIHY7XG4gICAgICAgICAgICB9LFxuICAgICAgICB9O1xuICAgIH1cbiAgICBlbHNlIGlmIChraW5kID09PSAnc2V0dGVyJykge1xuICAgICAgICBjb25zdCB7IG5hbWUgfSA9IGNvbnRleHQ7XG4gICAgICAgIHJldHVybiBmdW5jdGlvbiAodmFsdWUpIHtcbiAgICAgICAgICAgIGNvbnN0IG9sZFZhbHVlID0gdGhpc1tuYW1lXTtcbiAgICAgICAgICAgIHRhcmdldC5jYWxsKHRoaXMsIHZhbHVlKTtcbiAgICAgICAgICAgIHRoaXMucmVxdWVzdFVwZGF0ZShuYW1lLCBvbGRWYWx1ZSwgb3B0aW9ucyk7XG4gICAgICAgIH07XG4gICAgfVxuICAgIHRocm93IG5ldyBFcnJvcihgVW5zdXBwb3J0ZWQgZGVjb3JhdG9yIGxvY2F0aW9uOiAke2tpbmR9YCk7XG59O1xuLyoqXG4gKiBBIGNsYXNzIGZpZWxkIG9yIGFjY2Vzc29yIGRlY29yYXRvciB3aGljaCBjcmVhdGVzIGEgcmVhY3RpdmUgcHJvcGVydHkgdGhhdFxuICogcmVmbGVjdHMgYSBjb3JyZXNwb25kaW5nIGF0dHJpYnV0ZSB2YWx1ZS4gV2hlbiBhIGRlY29yYXRlZCBwcm9wZXJ0eSBpcyBzZXRcbiAqIHRoZSBlbGVtZW50IHdpbGwgdXBkYXRlIGFuZCByZW5kZXIuIEEge0BsaW5rY29kZSBQcm9wZXJ0eURlY2xhcmF0aW9ufSBtYXlcbiAqIG9wdGlvbmFsbHkgYmUgc3VwcGxpZWQgdG8gY29uZmlndXJlIHByb3BlcnR5IGZlYXR1cmVzLlxuICpcbiAqIFRoaXMgZGVjb3JhdG9yIHNob3VsZCBvbmx5IGJlIHVzZWQgZm9yIHB1YmxpYyBmaWVsZHMuIEFzIHB1YmxpYyBmaWVsZHMsXG4gKiBwcm9wZXJ0aWVzIHNob3VsZCBiZSBjb25zaWRlcmVkIGFzIHByaW1hcmlseSBzZXR0YWJsZSBieSBlbGVtZW50IHVzZXJzLFxuICogZWl0aGVyIHZpYSBhdHRyaWJ1dGUgb3IgdGhlIHByb3BlcnR5IGl0c2VsZi5cbiAqXG4gKiBHZW5lcmFsbHksIHByb3BlcnRpZXMgdGhhdCBhcmUgY2hhbmdlZCBieSB0aGUgZWxlbWVudCBzaG91bGQgYmUgcHJpdmF0ZSBvclxuICogcHJvdGVjdGVkIGZpZWxkcyBhbmQgc2hvdWxkIHVzZSB0aGUge0BsaW5rY29kZSBzdGF0ZX0gZGVjb3JhdG9yLlxuICpcbiAqIEhvd2V2ZXIsIHNvbWV0aW1lcyBlbGVtZW50IGNvZGUgZG9lcyBuZWVkIHRvIHNldCBhIHB1YmxpYyBwcm9wZXJ0eS4gVGhpc1xuICogc2hvdWxkIHR5cGljYWxseSBvbmx5IGJlIGRvbmUgaW4gcmVzcG9uc2UgdG8gdXNlciBpbnRlcmFjdGlvbiwgYW5kIGFuIGV2ZW50XG4gKiBzaG91bGQgYmUgZmlyZWQgaW5mb3JtaW5nIHRoZSB1c2VyOyBmb3IgZXhhbXBsZSwgYSBjaGVja2JveCBzZXRzIGl0c1xuICogYGNoZWNrZWRgIHByb3BlcnR5IHdoZW4gY2xpY2tlZCBhbmQgZmlyZXMgYSBgY2hhbmdlZGAgZXZlbnQuIE11dGF0aW5nIHB1YmxpY1xuICogcHJvcGVydGllcyBzaG91bGQgdHlwaWNhbGx5IG5vdCBiZSBkb25lIGZvciBub24tcHJpbWl0aXZlIChvYmplY3Qgb3IgYXJyYXkpXG4gKiBwcm9wZXJ0aWVzLiBJbiBvdGhlciBjYXNlcyB3aGVuIGFuIGVsZW1lbnQgbmVlZHMgdG8gbWFuYWdlIHN0YXRlLCBhIHByaXZhdGVcbiAqIHByb3BlcnR5IGRlY29yYXRlZCB2aWEgdGhlIHtAbGlua2NvZGUgc3RhdGV9IGRlY29yYXRvciBzaG91bGQgYmUgdXNlZC4gV2hlblxuICogbmVlZGVkLCBzdGF0ZSBwcm9wZXJ0aWVzIGNhbiBiZSBpbml0aWFsaXplZCB2aWEgcHVibGljIHByb3BlcnRpZXMgdG9cbiAqIGZhY2lsaXRhdGUgY29tcGxleCBpbnRlcmFjdGlvbnMuXG4gKlxuICogYGBgdHNcbiAqIGNsYXNzIE15RWxlbWVudCB7XG4gKiAgIEBwcm9wZXJ0eSh7IHR5cGU6IEJvb2xlYW4gfSlcbiAqICAgY2xpY2tlZCA9IGZhbHNlO1xuICogfVxuICogYGBgXG4gKiBAY2F0ZWdvcnkgRGVjb3JhdG9yXG4gKiBARXhwb3J0RGVjb3JhdGVkSXRlbXNcbiAqL1xuZXhwb3J0IGZ1bmN0aW9uIHByb3BlcnR5KG9wdGlvbnMpIHtcbiAgICByZXR1cm4gKHByb3RvT3JUYXJnZXQsIG5hbWVPckNvbnRleHRcbiAgICAvLyBlc2xpbnQtZGlzYWJsZS1uZXh0LWxpbmUgQHR5cGVzY3JpcHQtZXNsaW50L25vLWV4cGxpY2l0LWFueVxuICAgICkgPT4ge1xuICAgICAgICByZXR1cm4gKHR5cGVvZiBuYW1lT3JDb250ZXh0ID09PSAnb2JqZWN0J1xuICAgICAgICAgICAgPyBzdGFuZGFyZFByb3BlcnR5KG9wdGlvbnMsIHByb3RvT3JUYXJnZXQsIG5hbWVPckNvbnRleHQpXG4gICAgICAgICAgICA6IGxlZ2FjeVByb3BlcnR5KG9wdGlvbnMsIHByb3RvT3JUYXJnZXQsIG5hbWVPckNvbnRleHQpKTtcbiAgICB9O1xufVxuLy8jIHNvdXJjZU1hcHBpbmdVUkw9cHJvcGVydHkuanMubWFwIl0sIm5hbWVzIjpbXSwic291cmNlUm9vdCI6IiJ9\n//# sourceURL=webpack-internal:///./node_modules/@lit/reactive-element/development/decorators/property.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@lit/reactive-element/development/decorators/query-all.js":
-/*!********************************************************************************!*\
-  !*** ./node_modules/@lit/reactive-element/development/decorators/query-all.js ***!
-  \********************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   queryAll: function() { return /* binding */ queryAll; }\n/* harmony export */ });\n/* harmony import */ var _base_js__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! ./base.js */ \"./node_modules/@lit/reactive-element/development/decorators/base.js\");\n/**\n * @license\n * Copyright 2017 Google LLC\n * SPDX-License-Identifier: BSD-3-Clause\n */\n\n// Shared fragment used to generate empty NodeLists when a render root is\n// undefined\nlet fragment;\n/**\n * A property decorator that converts a class property into a getter\n * that executes a querySelectorAll on the element's renderRoot.\n *\n * @param selector A DOMString containing one or more selectors to match.\n *\n * See:\n * https://developer.mozilla.org/en-US/docs/Web/API/Document/querySelectorAll\n *\n * ```ts\n * class MyElement {\n *   @queryAll('div')\n *   divs: NodeListOf<HTMLDivElement>;\n *\n *   render() {\n *     return html`\n *       <div id=\"first\"></div>\n *       <div id=\"second\"></div>\n *     `;\n *   }\n * }\n * ```\n * @category Decorator\n */\nfunction queryAll(selector) {\n    return ((obj, name) => {\n        return (0,_base_js__WEBPACK_IMPORTED_MODULE_0__.desc)(obj, name, {\n            get() {\n                const container = this.renderRoot ?? (fragment ??= document.createDocumentFragment());\n                return container.querySelectorAll(selector);\n            },\n        });\n    });\n}\n//# sourceMappingURL=query-all.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQGxpdC9yZWFjdGl2ZS1lbGVtZW50L2RldmVsb3BtZW50L2RlY29yYXRvcnMvcXVlcnktYWxsLmpzIiwibWFwcGluZ3MiOiI7Ozs7O0FBQUE7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNpQztBQUNqQztBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDTztBQUNQO0FBQ0EsZUFBZSw4Q0FBSTtBQUNuQjtBQUNBO0FBQ0E7QUFDQSxhQUFhO0FBQ2IsU0FBUztBQUNULEtBQUs7QUFDTDtBQUNBIiwic291cmNlcyI6WyJ3ZWJwYWNrOi8vX05fRS8uL25vZGVfbW9kdWxlcy9AbGl0L3JlYWN0aXZlLWVsZW1lbnQvZGV2ZWxvcG1lbnQvZGVjb3JhdG9ycy9xdWVyeS1hbGwuanM/YzgyZSJdLCJzb3VyY2VzQ29udGVudCI6WyIvKipcbiAqIEBsaWNlbnNlXG4gKiBDb3B5cmlnaHQgMjAxNyBHb29nbGUgTExDXG4gKiBTUERYLUxpY2Vuc2UtSWRlbnRpZmllcjogQlNELTMtQ2xhdXNlXG4gKi9cbmltcG9ydCB7IGRlc2MgfSBmcm9tICcuL2Jhc2UuanMnO1xuLy8gU2hhcmVkIGZyYWdtZW50IHVzZWQgdG8gZ2VuZXJhdGUgZW1wdHkgTm9kZUxpc3RzIHdoZW4gYSByZW5kZXIgcm9vdCBpc1xuLy8gdW5kZWZpbmVkXG5sZXQgZnJhZ21lbnQ7XG4vKipcbiAqIEEgcHJvcGVydHkgZGVjb3JhdG9yIHRoYXQgY29udmVydHMgYSBjbGFzcyBwcm9wZXJ0eSBpbnRvIGEgZ2V0dGVyXG4gKiB0aGF0IGV4ZWN1dGVzIGEgcXVlcnlTZWxlY3RvckFsbCBvbiB0aGUgZWxlbWVudCdzIHJlbmRlclJvb3QuXG4gKlxuICogQHBhcmFtIHNlbGVjdG9yIEEgRE9NU3RyaW5nIGNvbnRhaW5pbmcgb25lIG9yIG1vcmUgc2VsZWN0b3JzIHRvIG1hdGNoLlxuICpcbiAqIFNlZTpcbiAqIGh0dHBzOi8vZGV2ZWxvcGVyLm1vemlsbGEub3JnL2VuLVVTL2RvY3MvV2ViL0FQSS9Eb2N1bWVudC9xdWVyeVNlbGVjdG9yQWxsXG4gKlxuICogYGBgdHNcbiAqIGNsYXNzIE15RWxlbWVudCB7XG4gKiAgIEBxdWVyeUFsbCgnZGl2JylcbiAqICAgZGl2czogTm9kZUxpc3RPZjxIVE1MRGl2RWxlbWVudD47XG4gKlxuICogICByZW5kZXIoKSB7XG4gKiAgICAgcmV0dXJuIGh0bWxgXG4gKiAgICAgICA8ZGl2IGlkPVwiZmlyc3RcIj48L2Rpdj5cbiAqICAgICAgIDxkaXYgaWQ9XCJzZWNvbmRcIj48L2Rpdj5cbiAqICAgICBgO1xuICogICB9XG4gKiB9XG4gKiBgYGBcbiAqIEBjYXRlZ29yeSBEZWNvcmF0b3JcbiAqL1xuZXhwb3J0IGZ1bmN0aW9uIHF1ZXJ5QWxsKHNlbGVjdG9yKSB7XG4gICAgcmV0dXJuICgob2JqLCBuYW1lKSA9PiB7XG4gICAgICAgIHJldHVybiBkZXNjKG9iaiwgbmFtZSwge1xuICAgICAgICAgICAgZ2V0KCkge1xuICAgICAgICAgICAgICAgIGNvbnN0IGNvbnRhaW5lciA9IHRoaXMucmVuZGVyUm9vdCA/PyAoZnJhZ21lbnQgPz89IGRvY3VtZW50LmNyZWF0ZURvY3VtZW50RnJhZ21lbnQoKSk7XG4gICAgICAgICAgICAgICAgcmV0dXJuIGNvbnRhaW5lci5xdWVyeVNlbGVjdG9yQWxsKHNlbGVjdG9yKTtcbiAgICAgICAgICAgIH0sXG4gICAgICAgIH0pO1xuICAgIH0pO1xufVxuLy8jIHNvdXJjZU1hcHBpbmdVUkw9cXVlcnktYWxsLmpzLm1hcCJdLCJuYW1lcyI6W10sInNvdXJjZVJvb3QiOiIifQ==\n//# sourceURL=webpack-internal:///./node_modules/@lit/reactive-element/development/decorators/query-all.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@lit/reactive-element/development/decorators/query-assigned-elements.js":
-/*!**********************************************************************************************!*\
-  !*** ./node_modules/@lit/reactive-element/development/decorators/query-assigned-elements.js ***!
-  \**********************************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   queryAssignedElements: function() { return /* binding */ queryAssignedElements; }\n/* harmony export */ });\n/* harmony import */ var _base_js__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! ./base.js */ \"./node_modules/@lit/reactive-element/development/decorators/base.js\");\n/**\n * @license\n * Copyright 2021 Google LLC\n * SPDX-License-Identifier: BSD-3-Clause\n */\n\n/**\n * A property decorator that converts a class property into a getter that\n * returns the `assignedElements` of the given `slot`. Provides a declarative\n * way to use\n * [`HTMLSlotElement.assignedElements`](https://developer.mozilla.org/en-US/docs/Web/API/HTMLSlotElement/assignedElements).\n *\n * Can be passed an optional {@linkcode QueryAssignedElementsOptions} object.\n *\n * Example usage:\n * ```ts\n * class MyElement {\n *   @queryAssignedElements({ slot: 'list' })\n *   listItems!: Array<HTMLElement>;\n *   @queryAssignedElements()\n *   unnamedSlotEls!: Array<HTMLElement>;\n *\n *   render() {\n *     return html`\n *       <slot name=\"list\"></slot>\n *       <slot></slot>\n *     `;\n *   }\n * }\n * ```\n *\n * Note, the type of this property should be annotated as `Array<HTMLElement>`.\n *\n * @category Decorator\n */\nfunction queryAssignedElements(options) {\n    return ((obj, name) => {\n        const { slot, selector } = options ?? {};\n        const slotSelector = `slot${slot ? `[name=${slot}]` : ':not([name])'}`;\n        return (0,_base_js__WEBPACK_IMPORTED_MODULE_0__.desc)(obj, name, {\n            get() {\n                const slotEl = this.renderRoot?.querySelector(slotSelector);\n                const elements = slotEl?.assignedElements(options) ?? [];\n                return (selector === undefined\n                    ? elements\n                    : elements.filter((node) => node.matches(selector)));\n            },\n        });\n    });\n}\n//# sourceMappingURL=query-assigned-elements.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQGxpdC9yZWFjdGl2ZS1lbGVtZW50L2RldmVsb3BtZW50L2RlY29yYXRvcnMvcXVlcnktYXNzaWduZWQtZWxlbWVudHMuanMiLCJtYXBwaW5ncyI6Ijs7Ozs7QUFBQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ2lDO0FBQ2pDO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBLDhCQUE4Qix3Q0FBd0M7QUFDdEU7QUFDQTtBQUNBO0FBQ0E7QUFDQSw4QkFBOEIsY0FBYztBQUM1QztBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ087QUFDUDtBQUNBLGdCQUFnQixpQkFBaUI7QUFDakMsb0NBQW9DLGdCQUFnQixLQUFLLG9CQUFvQjtBQUM3RSxlQUFlLDhDQUFJO0FBQ25CO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBLGFBQWE7QUFDYixTQUFTO0FBQ1QsS0FBSztBQUNMO0FBQ0EiLCJzb3VyY2VzIjpbIndlYnBhY2s6Ly9fTl9FLy4vbm9kZV9tb2R1bGVzL0BsaXQvcmVhY3RpdmUtZWxlbWVudC9kZXZlbG9wbWVudC9kZWNvcmF0b3JzL3F1ZXJ5LWFzc2lnbmVkLWVsZW1lbnRzLmpzPzFiNGQiXSwic291cmNlc0NvbnRlbnQiOlsiLyoqXG4gKiBAbGljZW5zZVxuICogQ29weXJpZ2h0IDIwMjEgR29vZ2xlIExMQ1xuICogU1BEWC1MaWNlbnNlLUlkZW50aWZpZXI6IEJTRC0zLUNsYXVzZVxuICovXG5pbXBvcnQgeyBkZXNjIH0gZnJvbSAnLi9iYXNlLmpzJztcbi8qKlxuICogQSBwcm9wZXJ0eSBkZWNvcmF0b3IgdGhhdCBjb252ZXJ0cyBhIGNsYXNzIHByb3BlcnR5IGludG8gYSBnZXR0ZXIgdGhhdFxuICogcmV0dXJucyB0aGUgYGFzc2lnbmVkRWxlbWVudHNgIG9mIHRoZSBnaXZlbiBgc2xvdGAuIFByb3ZpZGVzIGEgZGVjbGFyYXRpdmVcbiAqIHdheSB0byB1c2VcbiAqIFtgSFRNTFNsb3RFbGVtZW50LmFzc2lnbmVkRWxlbWVudHNgXShodHRwczovL2RldmVsb3Blci5tb3ppbGxhLm9yZy9lbi1VUy9kb2NzL1dlYi9BUEkvSFRNTFNsb3RFbGVtZW50L2Fzc2lnbmVkRWxlbWVudHMpLlxuICpcbiAqIENhbiBiZSBwYXNzZWQgYW4gb3B0aW9uYWwge0BsaW5rY29kZSBRdWVyeUFzc2lnbmVkRWxlbWVudHNPcHRpb25zfSBvYmplY3QuXG4gKlxuICogRXhhbXBsZSB1c2FnZTpcbiAqIGBgYHRzXG4gKiBjbGFzcyBNeUVsZW1lbnQge1xuICogICBAcXVlcnlBc3NpZ25lZEVsZW1lbnRzKHsgc2xvdDogJ2xpc3QnIH0pXG4gKiAgIGxpc3RJdGVtcyE6IEFycmF5PEhUTUxFbGVtZW50PjtcbiAqICAgQHF1ZXJ5QXNzaWduZWRFbGVtZW50cygpXG4gKiAgIHVubmFtZWRTbG90RWxzITogQXJyYXk8SFRNTEVsZW1lbnQ+O1xuICpcbiAqICAgcmVuZGVyKCkge1xuICogICAgIHJldHVybiBodG1sYFxuICogICAgICAgPHNsb3QgbmFtZT1cImxpc3RcIj48L3Nsb3Q+XG4gKiAgICAgICA8c2xvdD48L3Nsb3Q+XG4gKiAgICAgYDtcbiAqICAgfVxuICogfVxuICogYGBgXG4gKlxuICogTm90ZSwgdGhlIHR5cGUgb2YgdGhpcyBwcm9wZXJ0eSBzaG91bGQgYmUgYW5ub3RhdGVkIGFzIGBBcnJheTxIVE1MRWxlbWVudD5gLlxuICpcbiAqIEBjYXRlZ29yeSBEZWNvcmF0b3JcbiAqL1xuZXhwb3J0IGZ1bmN0aW9uIHF1ZXJ5QXNzaWduZWRFbGVtZW50cyhvcHRpb25zKSB7XG4gICAgcmV0dXJuICgob2JqLCBuYW1lKSA9PiB7XG4gICAgICAgIGNvbnN0IHsgc2xvdCwgc2VsZWN0b3IgfSA9IG9wdGlvbnMgPz8ge307XG4gICAgICAgIGNvbnN0IHNsb3RTZWxlY3RvciA9IGBzbG90JHtzbG90ID8gYFtuYW1lPSR7c2xvdH1dYCA6ICc6bm90KFtuYW1lXSknfWA7XG4gICAgICAgIHJldHVybiBkZXNjKG9iaiwgbmFtZSwge1xuICAgICAgICAgICAgZ2V0KCkge1xuICAgICAgICAgICAgICAgIGNvbnN0IHNsb3RFbCA9IHRoaXMucmVuZGVyUm9vdD8ucXVlcnlTZWxlY3RvcihzbG90U2VsZWN0b3IpO1xuICAgICAgICAgICAgICAgIGNvbnN0IGVsZW1lbnRzID0gc2xvdEVsPy5hc3NpZ25lZEVsZW1lbnRzKG9wdGlvbnMpID8/IFtdO1xuICAgICAgICAgICAgICAgIHJldHVybiAoc2VsZWN0b3IgPT09IHVuZGVmaW5lZFxuICAgICAgICAgICAgICAgICAgICA/IGVsZW1lbnRzXG4gICAgICAgICAgICAgICAgICAgIDogZWxlbWVudHMuZmlsdGVyKChub2RlKSA9PiBub2RlLm1hdGNoZXMoc2VsZWN0b3IpKSk7XG4gICAgICAgICAgICB9LFxuICAgICAgICB9KTtcbiAgICB9KTtcbn1cbi8vIyBzb3VyY2VNYXBwaW5nVVJMPXF1ZXJ5LWFzc2lnbmVkLWVsZW1lbnRzLmpzLm1hcCJdLCJuYW1lcyI6W10sInNvdXJjZVJvb3QiOiIifQ==\n//# sourceURL=webpack-internal:///./node_modules/@lit/reactive-element/development/decorators/query-assigned-elements.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@lit/reactive-element/development/decorators/query-assigned-nodes.js":
-/*!*******************************************************************************************!*\
-  !*** ./node_modules/@lit/reactive-element/development/decorators/query-assigned-nodes.js ***!
-  \*******************************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   queryAssignedNodes: function() { return /* binding */ queryAssignedNodes; }\n/* harmony export */ });\n/* harmony import */ var _base_js__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! ./base.js */ \"./node_modules/@lit/reactive-element/development/decorators/base.js\");\n/**\n * @license\n * Copyright 2017 Google LLC\n * SPDX-License-Identifier: BSD-3-Clause\n */\n\n/**\n * A property decorator that converts a class property into a getter that\n * returns the `assignedNodes` of the given `slot`.\n *\n * Can be passed an optional {@linkcode QueryAssignedNodesOptions} object.\n *\n * Example usage:\n * ```ts\n * class MyElement {\n *   @queryAssignedNodes({slot: 'list', flatten: true})\n *   listItems!: Array<Node>;\n *\n *   render() {\n *     return html`\n *       <slot name=\"list\"></slot>\n *     `;\n *   }\n * }\n * ```\n *\n * Note the type of this property should be annotated as `Array<Node>`. Use the\n * queryAssignedElements decorator to list only elements, and optionally filter\n * the element list using a CSS selector.\n *\n * @category Decorator\n */\nfunction queryAssignedNodes(options) {\n    // eslint-disable-next-line @typescript-eslint/no-explicit-any\n    return ((obj, name) => {\n        const { slot } = options ?? {};\n        const slotSelector = `slot${slot ? `[name=${slot}]` : ':not([name])'}`;\n        return (0,_base_js__WEBPACK_IMPORTED_MODULE_0__.desc)(obj, name, {\n            get() {\n                const slotEl = this.renderRoot?.querySelector(slotSelector);\n                return (slotEl?.assignedNodes(options) ?? []);\n            },\n        });\n    });\n}\n//# sourceMappingURL=query-assigned-nodes.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQGxpdC9yZWFjdGl2ZS1lbGVtZW50L2RldmVsb3BtZW50L2RlY29yYXRvcnMvcXVlcnktYXNzaWduZWQtbm9kZXMuanMiLCJtYXBwaW5ncyI6Ijs7Ozs7QUFBQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ2lDO0FBQ2pDO0FBQ0E7QUFDQTtBQUNBO0FBQ0EsOEJBQThCLHFDQUFxQztBQUNuRTtBQUNBO0FBQ0E7QUFDQTtBQUNBLDBCQUEwQiw0QkFBNEI7QUFDdEQ7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDTztBQUNQO0FBQ0E7QUFDQSxnQkFBZ0IsT0FBTztBQUN2QixvQ0FBb0MsZ0JBQWdCLEtBQUssb0JBQW9CO0FBQzdFLGVBQWUsOENBQUk7QUFDbkI7QUFDQTtBQUNBO0FBQ0EsYUFBYTtBQUNiLFNBQVM7QUFDVCxLQUFLO0FBQ0w7QUFDQSIsInNvdXJjZXMiOlsid2VicGFjazovL19OX0UvLi9ub2RlX21vZHVsZXMvQGxpdC9yZWFjdGl2ZS1lbGVtZW50L2RldmVsb3BtZW50L2RlY29yYXRvcnMvcXVlcnktYXNzaWduZWQtbm9kZXMuanM/YzdlNCJdLCJzb3VyY2VzQ29udGVudCI6WyIvKipcbiAqIEBsaWNlbnNlXG4gKiBDb3B5cmlnaHQgMjAxNyBHb29nbGUgTExDXG4gKiBTUERYLUxpY2Vuc2UtSWRlbnRpZmllcjogQlNELTMtQ2xhdXNlXG4gKi9cbmltcG9ydCB7IGRlc2MgfSBmcm9tICcuL2Jhc2UuanMnO1xuLyoqXG4gKiBBIHByb3BlcnR5IGRlY29yYXRvciB0aGF0IGNvbnZlcnRzIGEgY2xhc3MgcHJvcGVydHkgaW50byBhIGdldHRlciB0aGF0XG4gKiByZXR1cm5zIHRoZSBgYXNzaWduZWROb2Rlc2Agb2YgdGhlIGdpdmVuIGBzbG90YC5cbiAqXG4gKiBDYW4gYmUgcGFzc2VkIGFuIG9wdGlvbmFsIHtAbGlua2NvZGUgUXVlcnlBc3NpZ25lZE5vZGVzT3B0aW9uc30gb2JqZWN0LlxuICpcbiAqIEV4YW1wbGUgdXNhZ2U6XG4gKiBgYGB0c1xuICogY2xhc3MgTXlFbGVtZW50IHtcbiAqICAgQHF1ZXJ5QXNzaWduZWROb2Rlcyh7c2xvdDogJ2xpc3QnLCBmbGF0dGVuOiB0cnVlfSlcbiAqICAgbGlzdEl0ZW1zITogQXJyYXk8Tm9kZT47XG4gKlxuICogICByZW5kZXIoKSB7XG4gKiAgICAgcmV0dXJuIGh0bWxgXG4gKiAgICAgICA8c2xvdCBuYW1lPVwibGlzdFwiPjwvc2xvdD5cbiAqICAgICBgO1xuICogICB9XG4gKiB9XG4gKiBgYGBcbiAqXG4gKiBOb3RlIHRoZSB0eXBlIG9mIHRoaXMgcHJvcGVydHkgc2hvdWxkIGJlIGFubm90YXRlZCBhcyBgQXJyYXk8Tm9kZT5gLiBVc2UgdGhlXG4gKiBxdWVyeUFzc2lnbmVkRWxlbWVudHMgZGVjb3JhdG9yIHRvIGxpc3Qgb25seSBlbGVtZW50cywgYW5kIG9wdGlvbmFsbHkgZmlsdGVyXG4gKiB0aGUgZWxlbWVudCBsaXN0IHVzaW5nIGEgQ1NTIHNlbGVjdG9yLlxuICpcbiAqIEBjYXRlZ29yeSBEZWNvcmF0b3JcbiAqL1xuZXhwb3J0IGZ1bmN0aW9uIHF1ZXJ5QXNzaWduZWROb2RlcyhvcHRpb25zKSB7XG4gICAgLy8gZXNsaW50LWRpc2FibGUtbmV4dC1saW5lIEB0eXBlc2NyaXB0LWVzbGludC9uby1leHBsaWNpdC1hbnlcbiAgICByZXR1cm4gKChvYmosIG5hbWUpID0+IHtcbiAgICAgICAgY29uc3QgeyBzbG90IH0gPSBvcHRpb25zID8/IHt9O1xuICAgICAgICBjb25zdCBzbG90U2VsZWN0b3IgPSBgc2xvdCR7c2xvdCA/IGBbbmFtZT0ke3Nsb3R9XWAgOiAnOm5vdChbbmFtZV0pJ31gO1xuICAgICAgICByZXR1cm4gZGVzYyhvYmosIG5hbWUsIHtcbiAgICAgICAgICAgIGdldCgpIHtcbiAgICAgICAgICAgICAgICBjb25zdCBzbG90RWwgPSB0aGlzLnJlbmRlclJvb3Q/LnF1ZXJ5U2VsZWN0b3Ioc2xvdFNlbGVjdG9yKTtcbiAgICAgICAgICAgICAgICByZXR1cm4gKHNsb3RFbD8uYXNzaWduZWROb2RlcyhvcHRpb25zKSA/PyBbXSk7XG4gICAgICAgICAgICB9LFxuICAgICAgICB9KTtcbiAgICB9KTtcbn1cbi8vIyBzb3VyY2VNYXBwaW5nVVJMPXF1ZXJ5LWFzc2lnbmVkLW5vZGVzLmpzLm1hcCJdLCJuYW1lcyI6W10sInNvdXJjZVJvb3QiOiIifQ==\n//# sourceURL=webpack-internal:///./node_modules/@lit/reactive-element/development/decorators/query-assigned-nodes.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@lit/reactive-element/development/decorators/query-async.js":
-/*!**********************************************************************************!*\
-  !*** ./node_modules/@lit/reactive-element/development/decorators/query-async.js ***!
-  \**********************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   queryAsync: function() { return /* binding */ queryAsync; }\n/* harmony export */ });\n/* harmony import */ var _base_js__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! ./base.js */ \"./node_modules/@lit/reactive-element/development/decorators/base.js\");\n/**\n * @license\n * Copyright 2017 Google LLC\n * SPDX-License-Identifier: BSD-3-Clause\n */\n\n// Note, in the future, we may extend this decorator to support the use case\n// where the queried element may need to do work to become ready to interact\n// with (e.g. load some implementation code). If so, we might elect to\n// add a second argument defining a function that can be run to make the\n// queried element loaded/updated/ready.\n/**\n * A property decorator that converts a class property into a getter that\n * returns a promise that resolves to the result of a querySelector on the\n * element's renderRoot done after the element's `updateComplete` promise\n * resolves. When the queried property may change with element state, this\n * decorator can be used instead of requiring users to await the\n * `updateComplete` before accessing the property.\n *\n * @param selector A DOMString containing one or more selectors to match.\n *\n * See: https://developer.mozilla.org/en-US/docs/Web/API/Document/querySelector\n *\n * ```ts\n * class MyElement {\n *   @queryAsync('#first')\n *   first: Promise<HTMLDivElement>;\n *\n *   render() {\n *     return html`\n *       <div id=\"first\"></div>\n *       <div id=\"second\"></div>\n *     `;\n *   }\n * }\n *\n * // external usage\n * async doSomethingWithFirst() {\n *  (await aMyElement.first).doSomething();\n * }\n * ```\n * @category Decorator\n */\nfunction queryAsync(selector) {\n    return ((obj, name) => {\n        return (0,_base_js__WEBPACK_IMPORTED_MODULE_0__.desc)(obj, name, {\n            async get() {\n                await this.updateComplete;\n                return this.renderRoot?.querySelector(selector) ?? null;\n            },\n        });\n    });\n}\n//# sourceMappingURL=query-async.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQGxpdC9yZWFjdGl2ZS1lbGVtZW50L2RldmVsb3BtZW50L2RlY29yYXRvcnMvcXVlcnktYXN5bmMuanMiLCJtYXBwaW5ncyI6Ijs7Ozs7QUFBQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ2lDO0FBQ2pDO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ087QUFDUDtBQUNBLGVBQWUsOENBQUk7QUFDbkI7QUFDQTtBQUNBO0FBQ0EsYUFBYTtBQUNiLFNBQVM7QUFDVCxLQUFLO0FBQ0w7QUFDQSIsInNvdXJjZXMiOlsid2VicGFjazovL19OX0UvLi9ub2RlX21vZHVsZXMvQGxpdC9yZWFjdGl2ZS1lbGVtZW50L2RldmVsb3BtZW50L2RlY29yYXRvcnMvcXVlcnktYXN5bmMuanM/M2RjZCJdLCJzb3VyY2VzQ29udGVudCI6WyIvKipcbiAqIEBsaWNlbnNlXG4gKiBDb3B5cmlnaHQgMjAxNyBHb29nbGUgTExDXG4gKiBTUERYLUxpY2Vuc2UtSWRlbnRpZmllcjogQlNELTMtQ2xhdXNlXG4gKi9cbmltcG9ydCB7IGRlc2MgfSBmcm9tICcuL2Jhc2UuanMnO1xuLy8gTm90ZSwgaW4gdGhlIGZ1dHVyZSwgd2UgbWF5IGV4dGVuZCB0aGlzIGRlY29yYXRvciB0byBzdXBwb3J0IHRoZSB1c2UgY2FzZVxuLy8gd2hlcmUgdGhlIHF1ZXJpZWQgZWxlbWVudCBtYXkgbmVlZCB0byBkbyB3b3JrIHRvIGJlY29tZSByZWFkeSB0byBpbnRlcmFjdFxuLy8gd2l0aCAoZS5nLiBsb2FkIHNvbWUgaW1wbGVtZW50YXRpb24gY29kZSkuIElmIHNvLCB3ZSBtaWdodCBlbGVjdCB0b1xuLy8gYWRkIGEgc2Vjb25kIGFyZ3VtZW50IGRlZmluaW5nIGEgZnVuY3Rpb24gdGhhdCBjYW4gYmUgcnVuIHRvIG1ha2UgdGhlXG4vLyBxdWVyaWVkIGVsZW1lbnQgbG9hZGVkL3VwZGF0ZWQvcmVhZHkuXG4vKipcbiAqIEEgcHJvcGVydHkgZGVjb3JhdG9yIHRoYXQgY29udmVydHMgYSBjbGFzcyBwcm9wZXJ0eSBpbnRvIGEgZ2V0dGVyIHRoYXRcbiAqIHJldHVybnMgYSBwcm9taXNlIHRoYXQgcmVzb2x2ZXMgdG8gdGhlIHJlc3VsdCBvZiBhIHF1ZXJ5U2VsZWN0b3Igb24gdGhlXG4gKiBlbGVtZW50J3MgcmVuZGVyUm9vdCBkb25lIGFmdGVyIHRoZSBlbGVtZW50J3MgYHVwZGF0ZUNvbXBsZXRlYCBwcm9taXNlXG4gKiByZXNvbHZlcy4gV2hlbiB0aGUgcXVlcmllZCBwcm9wZXJ0eSBtYXkgY2hhbmdlIHdpdGggZWxlbWVudCBzdGF0ZSwgdGhpc1xuICogZGVjb3JhdG9yIGNhbiBiZSB1c2VkIGluc3RlYWQgb2YgcmVxdWlyaW5nIHVzZXJzIHRvIGF3YWl0IHRoZVxuICogYHVwZGF0ZUNvbXBsZXRlYCBiZWZvcmUgYWNjZXNzaW5nIHRoZSBwcm9wZXJ0eS5cbiAqXG4gKiBAcGFyYW0gc2VsZWN0b3IgQSBET01TdHJpbmcgY29udGFpbmluZyBvbmUgb3IgbW9yZSBzZWxlY3RvcnMgdG8gbWF0Y2guXG4gKlxuICogU2VlOiBodHRwczovL2RldmVsb3Blci5tb3ppbGxhLm9yZy9lbi1VUy9kb2NzL1dlYi9BUEkvRG9jdW1lbnQvcXVlcnlTZWxlY3RvclxuICpcbiAqIGBgYHRzXG4gKiBjbGFzcyBNeUVsZW1lbnQge1xuICogICBAcXVlcnlBc3luYygnI2ZpcnN0JylcbiAqICAgZmlyc3Q6IFByb21pc2U8SFRNTERpdkVsZW1lbnQ+O1xuICpcbiAqICAgcmVuZGVyKCkge1xuICogICAgIHJldHVybiBodG1sYFxuICogICAgICAgPGRpdiBpZD1cImZpcnN0XCI+PC9kaXY+XG4gKiAgICAgICA8ZGl2IGlkPVwic2Vjb25kXCI+PC9kaXY+XG4gKiAgICAgYDtcbiAqICAgfVxuICogfVxuICpcbiAqIC8vIGV4dGVybmFsIHVzYWdlXG4gKiBhc3luYyBkb1NvbWV0aGluZ1dpdGhGaXJzdCgpIHtcbiAqICAoYXdhaXQgYU15RWxlbWVudC5maXJzdCkuZG9Tb21ldGhpbmcoKTtcbiAqIH1cbiAqIGBgYFxuICogQGNhdGVnb3J5IERlY29yYXRvclxuICovXG5leHBvcnQgZnVuY3Rpb24gcXVlcnlBc3luYyhzZWxlY3Rvcikge1xuICAgIHJldHVybiAoKG9iaiwgbmFtZSkgPT4ge1xuICAgICAgICByZXR1cm4gZGVzYyhvYmosIG5hbWUsIHtcbiAgICAgICAgICAgIGFzeW5jIGdldCgpIHtcbiAgICAgICAgICAgICAgICBhd2FpdCB0aGlzLnVwZGF0ZUNvbXBsZXRlO1xuICAgICAgICAgICAgICAgIHJldHVybiB0aGlzLnJlbmRlclJvb3Q/LnF1ZXJ5U2VsZWN0b3Ioc2VsZWN0b3IpID8/IG51bGw7XG4gICAgICAgICAgICB9LFxuICAgICAgICB9KTtcbiAgICB9KTtcbn1cbi8vIyBzb3VyY2VNYXBwaW5nVVJMPXF1ZXJ5LWFzeW5jLmpzLm1hcCJdLCJuYW1lcyI6W10sInNvdXJjZVJvb3QiOiIifQ==\n//# sourceURL=webpack-internal:///./node_modules/@lit/reactive-element/development/decorators/query-async.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@lit/reactive-element/development/decorators/query.js":
-/*!****************************************************************************!*\
-  !*** ./node_modules/@lit/reactive-element/development/decorators/query.js ***!
-  \****************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   query: function() { return /* binding */ query; }\n/* harmony export */ });\n/* harmony import */ var _base_js__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! ./base.js */ \"./node_modules/@lit/reactive-element/development/decorators/base.js\");\n/**\n * @license\n * Copyright 2017 Google LLC\n * SPDX-License-Identifier: BSD-3-Clause\n */\n\nconst DEV_MODE = true;\nlet issueWarning;\nif (DEV_MODE) {\n    // Ensure warnings are issued only 1x, even if multiple versions of Lit\n    // are loaded.\n    globalThis.litIssuedWarnings ??= new Set();\n    /**\n     * Issue a warning if we haven't already, based either on `code` or `warning`.\n     * Warnings are disabled automatically only by `warning`; disabling via `code`\n     * can be done by users.\n     */\n    issueWarning = (code, warning) => {\n        warning += code\n            ? ` See https://lit.dev/msg/${code} for more information.`\n            : '';\n        if (!globalThis.litIssuedWarnings.has(warning) &&\n            !globalThis.litIssuedWarnings.has(code)) {\n            console.warn(warning);\n            globalThis.litIssuedWarnings.add(warning);\n        }\n    };\n}\n/**\n * A property decorator that converts a class property into a getter that\n * executes a querySelector on the element's renderRoot.\n *\n * @param selector A DOMString containing one or more selectors to match.\n * @param cache An optional boolean which when true performs the DOM query only\n *     once and caches the result.\n *\n * See: https://developer.mozilla.org/en-US/docs/Web/API/Document/querySelector\n *\n * ```ts\n * class MyElement {\n *   @query('#first')\n *   first: HTMLDivElement;\n *\n *   render() {\n *     return html`\n *       <div id=\"first\"></div>\n *       <div id=\"second\"></div>\n *     `;\n *   }\n * }\n * ```\n * @category Decorator\n */\nfunction query(selector, cache) {\n    return ((protoOrTarget, nameOrContext, descriptor) => {\n        const doQuery = (el) => {\n            const result = (el.renderRoot?.querySelector(selector) ?? null);\n            if (DEV_MODE && result === null && cache && !el.hasUpdated) {\n                const name = typeof nameOrContext === 'object'\n                    ? nameOrContext.name\n                    : nameOrContext;\n                issueWarning('', `@query'd field ${JSON.stringify(String(name))} with the 'cache' ` +\n                    `flag set for selector '${selector}' has been accessed before ` +\n                    `the first update and returned null. This is expected if the ` +\n                    `renderRoot tree has not been provided beforehand (e.g. via ` +\n                    `Declarative Shadow DOM). Therefore the value hasn't been cached.`);\n            }\n            // TODO: if we want to allow users to assert that the query will never\n            // return null, we need a new option and to throw here if the result\n            // is null.\n            return result;\n        };\n        if (cache) {\n            // Accessors to wrap from either:\n            //   1. The decorator target, in the case of standard decorators\n            //   2. The property descriptor, in the case of experimental decorators\n            //      on auto-accessors.\n            //   3. Functions that access our own cache-key property on the instance,\n            //      in the case of experimental decorators on fields.\n            const { get, set } = typeof nameOrContext === 'object'\n                ? protoOrTarget\n                : descriptor ??\n                    (() => {\n                        const key = DEV_MODE\n                            ? Symbol(`${String(nameOrContext)} (@query() cache)`)\n                            : Symbol();\n                        return {\n                            get() {\n                                return this[key];\n                            },\n                            set(v) {\n                                this[key] = v;\n                            },\n                        };\n                    })();\n            return (0,_base_js__WEBPACK_IMPORTED_MODULE_0__.desc)(protoOrTarget, nameOrContext, {\n                get() {\n                    let result = get.call(this);\n                    if (result === undefined) {\n                        result = doQuery(this);\n                        if (result !== null || this.hasUpdated) {\n                            set.call(this, result);\n                        }\n                    }\n                    return result;\n                },\n            });\n        }\n        else {\n            // This object works as the return type for both standard and\n            // experimental decorators.\n            return (0,_base_js__WEBPACK_IMPORTED_MODULE_0__.desc)(protoOrTarget, nameOrContext, {\n                get() {\n                    return doQuery(this);\n                },\n            });\n        }\n    });\n}\n//# sourceMappingURL=query.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQGxpdC9yZWFjdGl2ZS1lbGVtZW50L2RldmVsb3BtZW50L2RlY29yYXRvcnMvcXVlcnkuanMiLCJtYXBwaW5ncyI6Ijs7Ozs7QUFBQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ2lDO0FBQ2pDO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQSw4REFBOEQ7QUFDOUQ7QUFDQTtBQUNBO0FBQ0E7QUFDQSwwQ0FBMEMsTUFBTTtBQUNoRDtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDTztBQUNQO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0EsbURBQW1ELDhCQUE4QjtBQUNqRiw4Q0FBOEMsU0FBUztBQUN2RDtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBLG9CQUFvQixXQUFXO0FBQy9CO0FBQ0E7QUFDQTtBQUNBO0FBQ0Esd0NBQXdDLHVCQUF1QjtBQUMvRDtBQUNBO0FBQ0E7QUFDQTtBQUNBLDZCQUE2QjtBQUM3QjtBQUNBO0FBQ0EsNkJBQTZCO0FBQzdCO0FBQ0EscUJBQXFCO0FBQ3JCLG1CQUFtQiw4Q0FBSTtBQUN2QjtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQSxpQkFBaUI7QUFDakIsYUFBYTtBQUNiO0FBQ0E7QUFDQTtBQUNBO0FBQ0EsbUJBQW1CLDhDQUFJO0FBQ3ZCO0FBQ0E7QUFDQSxpQkFBaUI7QUFDakIsYUFBYTtBQUNiO0FBQ0EsS0FBSztBQUNMO0FBQ0EiLCJzb3VyY2VzIjpbIndlYnBhY2s6Ly9fTl9FLy4vbm9kZV9tb2R1bGVzL0BsaXQvcmVhY3RpdmUtZWxlbWVudC9kZXZlbG9wbWVudC9kZWNvcmF0b3JzL3F1ZXJ5LmpzP2UwZjYiXSwic291cmNlc0NvbnRlbnQiOlsiLyoqXG4gKiBAbGljZW5zZVxuICogQ29weXJpZ2h0IDIwMTcgR29vZ2xlIExMQ1xuICogU1BEWC1MaWNlbnNlLUlkZW50aWZpZXI6IEJTRC0zLUNsYXVzZVxuICovXG5pbXBvcnQgeyBkZXNjIH0gZnJvbSAnLi9iYXNlLmpzJztcbmNvbnN0IERFVl9NT0RFID0gdHJ1ZTtcbmxldCBpc3N1ZVdhcm5pbmc7XG5pZiAoREVWX01PREUpIHtcbiAgICAvLyBFbnN1cmUgd2FybmluZ3MgYXJlIGlzc3VlZCBvbmx5IDF4LCBldmVuIGlmIG11bHRpcGxlIHZlcnNpb25zIG9mIExpdFxuICAgIC8vIGFyZSBsb2FkZWQuXG4gICAgZ2xvYmFsVGhpcy5saXRJc3N1ZWRXYXJuaW5ncyA/Pz0gbmV3IFNldCgpO1xuICAgIC8qKlxuICAgICAqIElzc3VlIGEgd2FybmluZyBpZiB3ZSBoYXZlbid0IGFscmVhZHksIGJhc2VkIGVpdGhlciBvbiBgY29kZWAgb3IgYHdhcm5pbmdgLlxuICAgICAqIFdhcm5pbmdzIGFyZSBkaXNhYmxlZCBhdXRvbWF0aWNhbGx5IG9ubHkgYnkgYHdhcm5pbmdgOyBkaXNhYmxpbmcgdmlhIGBjb2RlYFxuICAgICAqIGNhbiBiZSBkb25lIGJ5IHVzZXJzLlxuICAgICAqL1xuICAgIGlzc3VlV2FybmluZyA9IChjb2RlLCB3YXJuaW5nKSA9PiB7XG4gICAgICAgIHdhcm5pbmcgKz0gY29kZVxuICAgICAgICAgICAgPyBgIFNlZSBodHRwczovL2xpdC5kZXYvbXNnLyR7Y29kZX0gZm9yIG1vcmUgaW5mb3JtYXRpb24uYFxuICAgICAgICAgICAgOiAnJztcbiAgICAgICAgaWYgKCFnbG9iYWxUaGlzLmxpdElzc3VlZFdhcm5pbmdzLmhhcyh3YXJuaW5nKSAmJlxuICAgICAgICAgICAgIWdsb2JhbFRoaXMubGl0SXNzdWVkV2FybmluZ3MuaGFzKGNvZGUpKSB7XG4gICAgICAgICAgICBjb25zb2xlLndhcm4od2FybmluZyk7XG4gICAgICAgICAgICBnbG9iYWxUaGlzLmxpdElzc3VlZFdhcm5pbmdzLmFkZCh3YXJuaW5nKTtcbiAgICAgICAgfVxuICAgIH07XG59XG4vKipcbiAqIEEgcHJvcGVydHkgZGVjb3JhdG9yIHRoYXQgY29udmVydHMgYSBjbGFzcyBwcm9wZXJ0eSBpbnRvIGEgZ2V0dGVyIHRoYXRcbiAqIGV4ZWN1dGVzIGEgcXVlcnlTZWxlY3RvciBvbiB0aGUgZWxlbWVudCdzIHJlbmRlclJvb3QuXG4gKlxuICogQHBhcmFtIHNlbGVjdG9yIEEgRE9NU3RyaW5nIGNvbnRhaW5pbmcgb25lIG9yIG1vcmUgc2VsZWN0b3JzIHRvIG1hdGNoLlxuICogQHBhcmFtIGNhY2hlIEFuIG9wdGlvbmFsIGJvb2xlYW4gd2hpY2ggd2hlbiB0cnVlIHBlcmZvcm1zIHRoZSBET00gcXVlcnkgb25seVxuICogICAgIG9uY2UgYW5kIGNhY2hlcyB0aGUgcmVzdWx0LlxuICpcbiAqIFNlZTogaHR0cHM6Ly9kZXZlbG9wZXIubW96aWxsYS5vcmcvZW4tVVMvZG9jcy9XZWIvQVBJL0RvY3VtZW50L3F1ZXJ5U2VsZWN0b3JcbiAqXG4gKiBgYGB0c1xuICogY2xhc3MgTXlFbGVtZW50IHtcbiAqICAgQHF1ZXJ5KCcjZmlyc3QnKVxuICogICBmaXJzdDogSFRNTERpdkVsZW1lbnQ7XG4gKlxuICogICByZW5kZXIoKSB7XG4gKiAgICAgcmV0dXJuIGh0bWxgXG4gKiAgICAgICA8ZGl2IGlkPVwiZmlyc3RcIj48L2Rpdj5cbiAqICAgICAgIDxkaXYgaWQ9XCJzZWNvbmRcIj48L2Rpdj5cbiAqICAgICBgO1xuICogICB9XG4gKiB9XG4gKiBgYGBcbiAqIEBjYXRlZ29yeSBEZWNvcmF0b3JcbiAqL1xuZXhwb3J0IGZ1bmN0aW9uIHF1ZXJ5KHNlbGVjdG9yLCBjYWNoZSkge1xuICAgIHJldHVybiAoKHByb3RvT3JUYXJnZXQsIG5hbWVPckNvbnRleHQsIGRlc2NyaXB0b3IpID0+IHtcbiAgICAgICAgY29uc3QgZG9RdWVyeSA9IChlbCkgPT4ge1xuICAgICAgICAgICAgY29uc3QgcmVzdWx0ID0gKGVsLnJlbmRlclJvb3Q/LnF1ZXJ5U2VsZWN0b3Ioc2VsZWN0b3IpID8/IG51bGwpO1xuICAgICAgICAgICAgaWYgKERFVl9NT0RFICYmIHJlc3VsdCA9PT0gbnVsbCAmJiBjYWNoZSAmJiAhZWwuaGFzVXBkYXRlZCkge1xuICAgICAgICAgICAgICAgIGNvbnN0IG5hbWUgPSB0eXBlb2YgbmFtZU9yQ29udGV4dCA9PT0gJ29iamVjdCdcbiAgICAgICAgICAgICAgICAgICAgPyBuYW1lT3JDb250ZXh0Lm5hbWVcbiAgICAgICAgICAgICAgICAgICAgOiBuYW1lT3JDb250ZXh0O1xuICAgICAgICAgICAgICAgIGlzc3VlV2FybmluZygnJywgYEBxdWVyeSdkIGZpZWxkICR7SlNPTi5zdHJpbmdpZnkoU3RyaW5nKG5hbWUpKX0gd2l0aCB0aGUgJ2NhY2hlJyBgICtcbiAgICAgICAgICAgICAgICAgICAgYGZsYWcgc2V0IGZvciBzZWxlY3RvciAnJHtzZWxlY3Rvcn0nIGhhcyBiZWVuIGFjY2Vzc2VkIGJlZm9yZSBgICtcbiAgICAgICAgICAgICAgICAgICAgYHRoZSBmaXJzdCB1cGRhdGUgYW5kIHJldHVybmVkIG51bGwuIFRoaXMgaXMgZXhwZWN0ZWQgaWYgdGhlIGAgK1xuICAgICAgICAgICAgICAgICAgICBgcmVuZGVyUm9vdCB0cmVlIGhhcyBub3QgYmVlbiBwcm92aWRlZCBiZWZvcmVoYW5kIChlLmcuIHZpYSBgICtcbiAgICAgICAgICAgICAgICAgICAgYERlY2xhcmF0aXZlIFNoYWRvdyBET00pLiBUaGVyZWZvcmUgdGhlIHZhbHVlIGhhc24ndCBiZWVuIGNhY2hlZC5gKTtcbiAgICAgICAgICAgIH1cbiAgICAgICAgICAgIC8vIFRPRE86IGlmIHdlIHdhbnQgdG8gYWxsb3cgdXNlcnMgdG8gYXNzZXJ0IHRoYXQgdGhlIHF1ZXJ5IHdpbGwgbmV2ZXJcbiAgICAgICAgICAgIC8vIHJldHVybiBudWxsLCB3ZSBuZWVkIGEgbmV3IG9wdGlvbiBhbmQgdG8gdGhyb3cgaGVyZSBpZiB0aGUgcmVzdWx0XG4gICAgICAgICAgICAvLyBpcyBudWxsLlxuICAgICAgICAgICAgcmV0dXJuIHJlc3VsdDtcbiAgICAgICAgfTtcbiAgICAgICAgaWYgKGNhY2hlKSB7XG4gICAgICAgICAgICAvLyBBY2Nlc3NvcnMgdG8gd3JhcCBmcm9tIGVpdGhlcjpcbiAgICAgICAgICAgIC8vICAgMS4gVGhlIGRlY29yYXRvciB0YXJnZXQsIGluIHRoZSBjYXNlIG9mIHN0YW5kYXJkIGRlY29yYXRvcnNcbiAgICAgICAgICAgIC8vICAgMi4gVGhlIHByb3BlcnR5IGRlc2NyaXB0b3IsIGluIHRoZSBjYXNlIG9mIGV4cGVyaW1lbnRhbCBkZWNvcmF0b3JzXG4gICAgICAgICAgICAvLyAgICAgIG9uIGF1dG8tYWNjZXNzb3JzLlxuICAgICAgICAgICAgLy8gICAzLiBGdW5jdGlvbnMgdGhhdCBhY2Nlc3Mgb3VyIG93biBjYWNoZS1rZXkgcHJvcGVydHkgb24gdGhlIGluc3RhbmNlLFxuICAgICAgICAgICAgLy8gICAgICBpbiB0aGUgY2FzZSBvZiBleHBlcmltZW50YWwgZGVjb3JhdG9ycyBvbiBmaWVsZHMuXG4gICAgICAgICAgICBjb25zdCB7IGdldCwgc2V0IH0gPSB0eXBlb2YgbmFtZU9yQ29udGV4dCA9PT0gJ29iamVjdCdcbiAgICAgICAgICAgICAgICA/IHByb3RvT3JUYXJnZXRcbiAgICAgICAgICAgICAgICA6IGRlc2NyaXB0b3IgPz9cbiAgICAgICAgICAgICAgICAgICAgKCgpID0+IHtcbiAgICAgICAgICAgICAgICAgICAgICAgIGNvbnN0IGtleSA9IERFVl9NT0RFXG4gICAgICAgICAgICAgICAgICAgICAgICAgICAgPyBTeW1ib2woYCR7U3RyaW5nKG5hbWVPckNvbnRleHQpfSAoQHF1ZXJ5KCkgY2FjaGUpYClcbiAgICAgICAgICAgICAgICAgICAgICAgICAgICA6IFN5bWJvbCgpO1xuICAgICAgICAgICAgICAgICAgICAgICAgcmV0dXJuIHtcbiAgICAgICAgICAgICAgICAgICAgICAgICAgICBnZXQoKSB7XG4gICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgIHJldHVybiB0aGlzW2tleV07XG4gICAgICAgICAgICAgICAgICAgICAgICAgICAgfSxcbiAgICAgICAgICAgICAgICAgICAgICAgICAgICBzZXQodikge1xuICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICB0aGlzW2tleV0gPSB2O1xuICAgICAgICAgICAgICAgICAgICAgICAgICAgIH0sXG4gICAgICAgICAgICAgICAgICAgICAgICB9O1xuICAgICAgICAgICAgICAgICAgICB9KSgpO1xuICAgICAgICAgICAgcmV0dXJuIGRlc2MocHJvdG9PclRhcmdldCwgbmFtZU9yQ29udGV4dCwge1xuICAgICAgICAgICAgICAgIGdldCgpIHtcbiAgICAgICAgICAgICAgICAgICAgbGV0IHJlc3VsdCA9IGdldC5jYWxsKHRoaXMpO1xuICAgICAgICAgICAgICAgICAgICBpZiAocmVzdWx0ID09PSB1bmRlZmluZWQpIHtcbiAgICAgICAgICAgICAgICAgICAgICAgIHJlc3VsdCA9IGRvUXVlcnkodGhpcyk7XG4gICAgICAgICAgICAgICAgICAgICAgICBpZiAocmVzdWx0ICE9PSBudWxsIHx8IHRoaXMuaGFzVXBkYXRlZCkge1xuICAgICAgICAgICAgICAgICAgICAgICAgICAgIHNldC5jYWxsKHRoaXMsIHJlc3VsdCk7XG4gICAgICAgICAgICAgICAgICAgICAgICB9XG4gICAgICAgICAgICAgICAgICAgIH1cbiAgICAgICAgICAgICAgICAgICAgcmV0dXJuIHJlc3VsdDtcbiAgICAgICAgICAgICAgICB9LFxuICAgICAgICAgICAgfSk7XG4gICAgICAgIH1cbiAgICAgICAgZWxzZSB7XG4gICAgICAgICAgICAvLyBUaGlzIG9iamVjdCB3b3JrcyBhcyB0aGUgcmV0dXJuIHR5cGUgZm9yIGJvdGggc3RhbmRhcmQgYW5kXG4gICAgICAgICAgICAvLyBleHBlcmltZW50YWwgZGVjb3JhdG9ycy5cbiAgICAgICAgICAgIHJldHVybiBkZXNjKHByb3RvT3JUYXJnZXQsIG5hbWVPckNvbnRleHQsIHtcbiAgICAgICAgICAgICAgICBnZXQoKSB7XG4gICAgICAgICAgICAgICAgICAgIHJldHVybiBkb1F1ZXJ5KHRoaXMpO1xuICAgICAgICAgICAgICAgIH0sXG4gICAgICAgICAgICB9KTtcbiAgICAgICAgfVxuICAgIH0pO1xufVxuLy8jIHNvdXJjZU1hcHBpbmdVUkw9cXVlcnkuanMubWFwIl0sIm5hbWVzIjpbXSwic291cmNlUm9vdCI6IiJ9\n//# sourceURL=webpack-internal:///./node_modules/@lit/reactive-element/development/decorators/query.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@lit/reactive-element/development/decorators/state.js":
-/*!****************************************************************************!*\
-  !*** ./node_modules/@lit/reactive-element/development/decorators/state.js ***!
-  \****************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   state: function() { return /* binding */ state; }\n/* harmony export */ });\n/* harmony import */ var _property_js__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! ./property.js */ \"./node_modules/@lit/reactive-element/development/decorators/property.js\");\n/**\n * @license\n * Copyright 2017 Google LLC\n * SPDX-License-Identifier: BSD-3-Clause\n */\n/*\n * IMPORTANT: For compatibility with tsickle and the Closure JS compiler, all\n * property decorators (but not class decorators) in this file that have\n * an @ExportDecoratedItems annotation must be defined as a regular function,\n * not an arrow function.\n */\n\n/**\n * Declares a private or protected reactive property that still triggers\n * updates to the element when it changes. It does not reflect from the\n * corresponding attribute.\n *\n * Properties declared this way must not be used from HTML or HTML templating\n * systems, they're solely for properties internal to the element. These\n * properties may be renamed by optimization tools like closure compiler.\n * @category Decorator\n */\nfunction state(options) {\n    return (0,_property_js__WEBPACK_IMPORTED_MODULE_0__.property)({\n        ...options,\n        // Add both `state` and `attribute` because we found a third party\n        // controller that is keying off of PropertyOptions.state to determine\n        // whether a field is a private internal property or not.\n        state: true,\n        attribute: false,\n    });\n}\n//# sourceMappingURL=state.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQGxpdC9yZWFjdGl2ZS1lbGVtZW50L2RldmVsb3BtZW50L2RlY29yYXRvcnMvc3RhdGUuanMiLCJtYXBwaW5ncyI6Ijs7Ozs7QUFBQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ3lDO0FBQ3pDO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ087QUFDUCxXQUFXLHNEQUFRO0FBQ25CO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBLEtBQUs7QUFDTDtBQUNBIiwic291cmNlcyI6WyJ3ZWJwYWNrOi8vX05fRS8uL25vZGVfbW9kdWxlcy9AbGl0L3JlYWN0aXZlLWVsZW1lbnQvZGV2ZWxvcG1lbnQvZGVjb3JhdG9ycy9zdGF0ZS5qcz81Zjk1Il0sInNvdXJjZXNDb250ZW50IjpbIi8qKlxuICogQGxpY2Vuc2VcbiAqIENvcHlyaWdodCAyMDE3IEdvb2dsZSBMTENcbiAqIFNQRFgtTGljZW5zZS1JZGVudGlmaWVyOiBCU0QtMy1DbGF1c2VcbiAqL1xuLypcbiAqIElNUE9SVEFOVDogRm9yIGNvbXBhdGliaWxpdHkgd2l0aCB0c2lja2xlIGFuZCB0aGUgQ2xvc3VyZSBKUyBjb21waWxlciwgYWxsXG4gKiBwcm9wZXJ0eSBkZWNvcmF0b3JzIChidXQgbm90IGNsYXNzIGRlY29yYXRvcnMpIGluIHRoaXMgZmlsZSB0aGF0IGhhdmVcbiAqIGFuIEBFeHBvcnREZWNvcmF0ZWRJdGVtcyBhbm5vdGF0aW9uIG11c3QgYmUgZGVmaW5lZCBhcyBhIHJlZ3VsYXIgZnVuY3Rpb24sXG4gKiBub3QgYW4gYXJyb3cgZnVuY3Rpb24uXG4gKi9cbmltcG9ydCB7IHByb3BlcnR5IH0gZnJvbSAnLi9wcm9wZXJ0eS5qcyc7XG4vKipcbiAqIERlY2xhcmVzIGEgcHJpdmF0ZSBvciBwcm90ZWN0ZWQgcmVhY3RpdmUgcHJvcGVydHkgdGhhdCBzdGlsbCB0cmlnZ2Vyc1xuICogdXBkYXRlcyB0byB0aGUgZWxlbWVudCB3aGVuIGl0IGNoYW5nZXMuIEl0IGRvZXMgbm90IHJlZmxlY3QgZnJvbSB0aGVcbiAqIGNvcnJlc3BvbmRpbmcgYXR0cmlidXRlLlxuICpcbiAqIFByb3BlcnRpZXMgZGVjbGFyZWQgdGhpcyB3YXkgbXVzdCBub3QgYmUgdXNlZCBmcm9tIEhUTUwgb3IgSFRNTCB0ZW1wbGF0aW5nXG4gKiBzeXN0ZW1zLCB0aGV5J3JlIHNvbGVseSBmb3IgcHJvcGVydGllcyBpbnRlcm5hbCB0byB0aGUgZWxlbWVudC4gVGhlc2VcbiAqIHByb3BlcnRpZXMgbWF5IGJlIHJlbmFtZWQgYnkgb3B0aW1pemF0aW9uIHRvb2xzIGxpa2UgY2xvc3VyZSBjb21waWxlci5cbiAqIEBjYXRlZ29yeSBEZWNvcmF0b3JcbiAqL1xuZXhwb3J0IGZ1bmN0aW9uIHN0YXRlKG9wdGlvbnMpIHtcbiAgICByZXR1cm4gcHJvcGVydHkoe1xuICAgICAgICAuLi5vcHRpb25zLFxuICAgICAgICAvLyBBZGQgYm90aCBgc3RhdGVgIGFuZCBgYXR0cmlidXRlYCBiZWNhdXNlIHdlIGZvdW5kIGEgdGhpcmQgcGFydHlcbiAgICAgICAgLy8gY29udHJvbGxlciB0aGF0IGlzIGtleWluZyBvZmYgb2YgUHJvcGVydHlPcHRpb25zLnN0YXRlIHRvIGRldGVybWluZVxuICAgICAgICAvLyB3aGV0aGVyIGEgZmllbGQgaXMgYSBwcml2YXRlIGludGVybmFsIHByb3BlcnR5IG9yIG5vdC5cbiAgICAgICAgc3RhdGU6IHRydWUsXG4gICAgICAgIGF0dHJpYnV0ZTogZmFsc2UsXG4gICAgfSk7XG59XG4vLyMgc291cmNlTWFwcGluZ1VSTD1zdGF0ZS5qcy5tYXAiXSwibmFtZXMiOltdLCJzb3VyY2VSb290IjoiIn0=\n//# sourceURL=webpack-internal:///./node_modules/@lit/reactive-element/development/decorators/state.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@reown/appkit-scaffold-ui/dist/esm/exports/w3m-modal.js":
-/*!******************************************************************************!*\
-  !*** ./node_modules/@reown/appkit-scaffold-ui/dist/esm/exports/w3m-modal.js ***!
-  \******************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony import */ var _src_modal_w3m_modal_index_js__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! ../src/modal/w3m-modal/index.js */ \"./node_modules/@reown/appkit-scaffold-ui/dist/esm/src/modal/w3m-modal/index.js\");\n/* harmony reexport (unknown) */ var __WEBPACK_REEXPORT_OBJECT__ = {};\n/* harmony reexport (unknown) */ for(var __WEBPACK_IMPORT_KEY__ in _src_modal_w3m_modal_index_js__WEBPACK_IMPORTED_MODULE_0__) if(__WEBPACK_IMPORT_KEY__ !== \"default\") __WEBPACK_REEXPORT_OBJECT__[__WEBPACK_IMPORT_KEY__] = function(key) { return _src_modal_w3m_modal_index_js__WEBPACK_IMPORTED_MODULE_0__[key]; }.bind(0, __WEBPACK_IMPORT_KEY__)\n/* harmony reexport (unknown) */ __webpack_require__.d(__webpack_exports__, __WEBPACK_REEXPORT_OBJECT__);\n\n//# sourceMappingURL=w3m-modal.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC1zY2FmZm9sZC11aS9kaXN0L2VzbS9leHBvcnRzL3czbS1tb2RhbC5qcyIsIm1hcHBpbmdzIjoiOzs7OztBQUFnRDtBQUNoRCIsInNvdXJjZXMiOlsid2VicGFjazovL19OX0UvLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC1zY2FmZm9sZC11aS9kaXN0L2VzbS9leHBvcnRzL3czbS1tb2RhbC5qcz8yYzY0Il0sInNvdXJjZXNDb250ZW50IjpbImV4cG9ydCAqIGZyb20gJy4uL3NyYy9tb2RhbC93M20tbW9kYWwvaW5kZXguanMnO1xuLy8jIHNvdXJjZU1hcHBpbmdVUkw9dzNtLW1vZGFsLmpzLm1hcCJdLCJuYW1lcyI6W10sInNvdXJjZVJvb3QiOiIifQ==\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-scaffold-ui/dist/esm/exports/w3m-modal.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@reown/appkit-scaffold-ui/dist/esm/src/modal/w3m-modal/index.js":
-/*!**************************************************************************************!*\
-  !*** ./node_modules/@reown/appkit-scaffold-ui/dist/esm/src/modal/w3m-modal/index.js ***!
-  \**************************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   W3mModal: function() { return /* binding */ W3mModal; }\n/* harmony export */ });\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n/* harmony import */ var lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__ = __webpack_require__(/*! lit/decorators.js */ \"./node_modules/lit/decorators.js\");\n/* harmony import */ var lit_directives_if_defined_js__WEBPACK_IMPORTED_MODULE_2__ = __webpack_require__(/*! lit/directives/if-defined.js */ \"./node_modules/lit/directives/if-defined.js\");\n/* harmony import */ var _reown_appkit_common__WEBPACK_IMPORTED_MODULE_13__ = __webpack_require__(/*! @reown/appkit-common */ \"./node_modules/@reown/appkit-common/dist/esm/index.js\");\n/* harmony import */ var _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_12__ = __webpack_require__(/*! @reown/appkit-controllers */ \"./node_modules/@reown/appkit-controllers/dist/esm/exports/index.js\");\n/* harmony import */ var _reown_appkit_ui__WEBPACK_IMPORTED_MODULE_3__ = __webpack_require__(/*! @reown/appkit-ui */ \"./node_modules/@reown/appkit-ui/dist/esm/exports/index.js\");\n/* harmony import */ var _reown_appkit_ui_wui_card__WEBPACK_IMPORTED_MODULE_4__ = __webpack_require__(/*! @reown/appkit-ui/wui-card */ \"./node_modules/@reown/appkit-ui/dist/esm/exports/wui-card.js\");\n/* harmony import */ var _reown_appkit_ui_wui_flex__WEBPACK_IMPORTED_MODULE_5__ = __webpack_require__(/*! @reown/appkit-ui/wui-flex */ \"./node_modules/@reown/appkit-ui/dist/esm/exports/wui-flex.js\");\n/* harmony import */ var _partials_w3m_alertbar_index_js__WEBPACK_IMPORTED_MODULE_6__ = __webpack_require__(/*! ../../partials/w3m-alertbar/index.js */ \"./node_modules/@reown/appkit-scaffold-ui/dist/esm/src/partials/w3m-alertbar/index.js\");\n/* harmony import */ var _partials_w3m_header_index_js__WEBPACK_IMPORTED_MODULE_7__ = __webpack_require__(/*! ../../partials/w3m-header/index.js */ \"./node_modules/@reown/appkit-scaffold-ui/dist/esm/src/partials/w3m-header/index.js\");\n/* harmony import */ var _partials_w3m_snackbar_index_js__WEBPACK_IMPORTED_MODULE_8__ = __webpack_require__(/*! ../../partials/w3m-snackbar/index.js */ \"./node_modules/@reown/appkit-scaffold-ui/dist/esm/src/partials/w3m-snackbar/index.js\");\n/* harmony import */ var _partials_w3m_tooltip_index_js__WEBPACK_IMPORTED_MODULE_9__ = __webpack_require__(/*! ../../partials/w3m-tooltip/index.js */ \"./node_modules/@reown/appkit-scaffold-ui/dist/esm/src/partials/w3m-tooltip/index.js\");\n/* harmony import */ var _w3m_router_index_js__WEBPACK_IMPORTED_MODULE_10__ = __webpack_require__(/*! ../w3m-router/index.js */ \"./node_modules/@reown/appkit-scaffold-ui/dist/esm/src/modal/w3m-router/index.js\");\n/* harmony import */ var _styles_js__WEBPACK_IMPORTED_MODULE_11__ = __webpack_require__(/*! ./styles.js */ \"./node_modules/@reown/appkit-scaffold-ui/dist/esm/src/modal/w3m-modal/styles.js\");\nvar __decorate = (undefined && undefined.__decorate) || function (decorators, target, key, desc) {\n    var c = arguments.length, r = c < 3 ? target : desc === null ? desc = Object.getOwnPropertyDescriptor(target, key) : desc, d;\n    if (typeof Reflect === \"object\" && typeof Reflect.decorate === \"function\") r = Reflect.decorate(decorators, target, key, desc);\n    else for (var i = decorators.length - 1; i >= 0; i--) if (d = decorators[i]) r = (c < 3 ? d(r) : c > 3 ? d(target, key, r) : d(target, key)) || r;\n    return c > 3 && r && Object.defineProperty(target, key, r), r;\n};\n\n\n\n\n\n\n\n\n\n\n\n\n\n\nconst SCROLL_LOCK = 'scroll-lock';\nlet W3mModal = class W3mModal extends lit__WEBPACK_IMPORTED_MODULE_0__.LitElement {\n    constructor() {\n        super();\n        this.unsubscribe = [];\n        this.abortController = undefined;\n        this.hasPrefetched = false;\n        this.enableEmbedded = _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_12__.OptionsController.state.enableEmbedded;\n        this.open = _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_12__.ModalController.state.open;\n        this.caipAddress = _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_12__.ChainController.state.activeCaipAddress;\n        this.caipNetwork = _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_12__.ChainController.state.activeCaipNetwork;\n        this.shake = _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_12__.ModalController.state.shake;\n        this.filterByNamespace = _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_12__.ConnectorController.state.filterByNamespace;\n        this.initializeTheming();\n        _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_12__.ApiController.prefetchAnalyticsConfig();\n        this.unsubscribe.push(...[\n            _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_12__.ModalController.subscribeKey('open', val => (val ? this.onOpen() : this.onClose())),\n            _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_12__.ModalController.subscribeKey('shake', val => (this.shake = val)),\n            _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_12__.ChainController.subscribeKey('activeCaipNetwork', val => this.onNewNetwork(val)),\n            _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_12__.ChainController.subscribeKey('activeCaipAddress', val => this.onNewAddress(val)),\n            _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_12__.OptionsController.subscribeKey('enableEmbedded', val => (this.enableEmbedded = val)),\n            _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_12__.ConnectorController.subscribeKey('filterByNamespace', val => {\n                if (this.filterByNamespace !== val && !_reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_12__.ChainController.getAccountData(val)?.caipAddress) {\n                    _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_12__.ApiController.fetchRecommendedWallets();\n                    this.filterByNamespace = val;\n                }\n            })\n        ]);\n    }\n    firstUpdated() {\n        if (this.caipAddress) {\n            if (this.enableEmbedded) {\n                _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_12__.ModalController.close();\n                this.prefetch();\n                return;\n            }\n            this.onNewAddress(this.caipAddress);\n        }\n        if (this.open) {\n            this.onOpen();\n        }\n        if (this.enableEmbedded) {\n            this.prefetch();\n        }\n    }\n    disconnectedCallback() {\n        this.unsubscribe.forEach(unsubscribe => unsubscribe());\n        this.onRemoveKeyboardListener();\n    }\n    render() {\n        this.style.cssText = `\n      --local-border-bottom-mobile-radius: ${this.enableEmbedded ? 'clamp(0px, var(--wui-border-radius-l), 44px)' : '0px'};\n    `;\n        if (this.enableEmbedded) {\n            return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `${this.contentTemplate()}\n        <w3m-tooltip></w3m-tooltip> `;\n        }\n        return this.open\n            ? (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `\n          <wui-flex @click=${this.onOverlayClick.bind(this)} data-testid=\"w3m-modal-overlay\">\n            ${this.contentTemplate()}\n          </wui-flex>\n          <w3m-tooltip></w3m-tooltip>\n        `\n            : null;\n    }\n    contentTemplate() {\n        return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) ` <wui-card\n      shake=\"${this.shake}\"\n      data-embedded=\"${(0,lit_directives_if_defined_js__WEBPACK_IMPORTED_MODULE_2__.ifDefined)(this.enableEmbedded)}\"\n      role=\"alertdialog\"\n      aria-modal=\"true\"\n      tabindex=\"0\"\n      data-testid=\"w3m-modal-card\"\n    >\n      <w3m-header></w3m-header>\n      <w3m-router></w3m-router>\n      <w3m-snackbar></w3m-snackbar>\n      <w3m-alertbar></w3m-alertbar>\n    </wui-card>`;\n    }\n    async onOverlayClick(event) {\n        if (event.target === event.currentTarget) {\n            await this.handleClose();\n        }\n    }\n    async handleClose() {\n        const isUnsupportedChain = _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_12__.RouterController.state.view === 'UnsupportedChain';\n        if (isUnsupportedChain || (await _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_12__.SIWXUtil.isSIWXCloseDisabled())) {\n            _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_12__.ModalController.shake();\n        }\n        else {\n            _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_12__.ModalController.close();\n        }\n    }\n    initializeTheming() {\n        const { themeVariables, themeMode } = _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_12__.ThemeController.state;\n        const defaultThemeMode = _reown_appkit_ui__WEBPACK_IMPORTED_MODULE_3__.UiHelperUtil.getColorTheme(themeMode);\n        (0,_reown_appkit_ui__WEBPACK_IMPORTED_MODULE_3__.initializeTheming)(themeVariables, defaultThemeMode);\n    }\n    onClose() {\n        this.open = false;\n        this.classList.remove('open');\n        this.onScrollUnlock();\n        _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_12__.SnackController.hide();\n        this.onRemoveKeyboardListener();\n    }\n    onOpen() {\n        this.open = true;\n        this.classList.add('open');\n        this.onScrollLock();\n        this.onAddKeyboardListener();\n    }\n    onScrollLock() {\n        const styleTag = document.createElement('style');\n        styleTag.dataset['w3m'] = SCROLL_LOCK;\n        styleTag.textContent = `\n      body {\n        touch-action: none;\n        overflow: hidden;\n        overscroll-behavior: contain;\n      }\n      w3m-modal {\n        pointer-events: auto;\n      }\n    `;\n        document.head.appendChild(styleTag);\n    }\n    onScrollUnlock() {\n        const styleTag = document.head.querySelector(`style[data-w3m=\"${SCROLL_LOCK}\"]`);\n        if (styleTag) {\n            styleTag.remove();\n        }\n    }\n    onAddKeyboardListener() {\n        this.abortController = new AbortController();\n        const card = this.shadowRoot?.querySelector('wui-card');\n        card?.focus();\n        window.addEventListener('keydown', event => {\n            if (event.key === 'Escape') {\n                this.handleClose();\n            }\n            else if (event.key === 'Tab') {\n                const { tagName } = event.target;\n                if (tagName && !tagName.includes('W3M-') && !tagName.includes('WUI-')) {\n                    card?.focus();\n                }\n            }\n        }, this.abortController);\n    }\n    onRemoveKeyboardListener() {\n        this.abortController?.abort();\n        this.abortController = undefined;\n    }\n    async onNewAddress(caipAddress) {\n        const isSwitchingNamespace = _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_12__.ChainController.state.isSwitchingNamespace;\n        const nextConnected = _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_12__.CoreHelperUtil.getPlainAddress(caipAddress);\n        const isDisconnectedInSameNamespace = !nextConnected && !isSwitchingNamespace;\n        const isSwitchingNamespaceAndConnected = isSwitchingNamespace && nextConnected;\n        if (isDisconnectedInSameNamespace) {\n            _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_12__.ModalController.close();\n        }\n        else if (isSwitchingNamespaceAndConnected) {\n            _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_12__.RouterController.goBack();\n        }\n        await _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_12__.SIWXUtil.initializeIfEnabled();\n        this.caipAddress = caipAddress;\n        _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_12__.ChainController.setIsSwitchingNamespace(false);\n    }\n    onNewNetwork(nextCaipNetwork) {\n        const prevCaipNetworkId = this.caipNetwork?.caipNetworkId?.toString();\n        const nextNetworkId = nextCaipNetwork?.caipNetworkId?.toString();\n        const networkChanged = prevCaipNetworkId && nextNetworkId && prevCaipNetworkId !== nextNetworkId;\n        const isSwitchingNamespace = _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_12__.ChainController.state.isSwitchingNamespace;\n        const isUnsupportedNetwork = this.caipNetwork?.name === _reown_appkit_common__WEBPACK_IMPORTED_MODULE_13__.ConstantsUtil.UNSUPPORTED_NETWORK_NAME;\n        const isConnectingExternal = _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_12__.RouterController.state.view === 'ConnectingExternal';\n        const isNotConnected = !this.caipAddress;\n        const isNetworkChangedInSameNamespace = networkChanged && !isUnsupportedNetwork && !isSwitchingNamespace;\n        const isUnsupportedNetworkScreen = _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_12__.RouterController.state.view === 'UnsupportedChain';\n        const isModalOpen = _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_12__.ModalController.state.open;\n        const shouldGoBack = isModalOpen &&\n            !isConnectingExternal &&\n            (isNotConnected || isUnsupportedNetworkScreen || isNetworkChangedInSameNamespace);\n        if (shouldGoBack) {\n            _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_12__.RouterController.goBack();\n        }\n        this.caipNetwork = nextCaipNetwork;\n    }\n    prefetch() {\n        if (!this.hasPrefetched) {\n            _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_12__.ApiController.prefetch();\n            _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_12__.ApiController.fetchWallets({ page: 1 });\n            this.hasPrefetched = true;\n        }\n    }\n};\nW3mModal.styles = _styles_js__WEBPACK_IMPORTED_MODULE_11__[\"default\"];\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.property)({ type: Boolean })\n], W3mModal.prototype, \"enableEmbedded\", void 0);\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.state)()\n], W3mModal.prototype, \"open\", void 0);\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.state)()\n], W3mModal.prototype, \"caipAddress\", void 0);\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.state)()\n], W3mModal.prototype, \"caipNetwork\", void 0);\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.state)()\n], W3mModal.prototype, \"shake\", void 0);\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.state)()\n], W3mModal.prototype, \"filterByNamespace\", void 0);\nW3mModal = __decorate([\n    (0,_reown_appkit_ui__WEBPACK_IMPORTED_MODULE_3__.customElement)('w3m-modal')\n], W3mModal);\n\n//# sourceMappingURL=index.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC1zY2FmZm9sZC11aS9kaXN0L2VzbS9zcmMvbW9kYWwvdzNtLW1vZGFsL2luZGV4LmpzIiwibWFwcGluZ3MiOiI7Ozs7Ozs7Ozs7Ozs7Ozs7OztBQUFBLGtCQUFrQixTQUFJLElBQUksU0FBSTtBQUM5QjtBQUNBO0FBQ0EsNkNBQTZDLFFBQVE7QUFDckQ7QUFDQTtBQUN1QztBQUNhO0FBQ0s7QUFDbUI7QUFDc0k7QUFDaEk7QUFDL0M7QUFDQTtBQUNXO0FBQ0Y7QUFDRTtBQUNEO0FBQ2I7QUFDQztBQUNqQztBQUNBLHNDQUFzQywyQ0FBVTtBQUNoRDtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0EsOEJBQThCLHlFQUFpQjtBQUMvQyxvQkFBb0IsdUVBQWU7QUFDbkMsMkJBQTJCLHVFQUFlO0FBQzFDLDJCQUEyQix1RUFBZTtBQUMxQyxxQkFBcUIsdUVBQWU7QUFDcEMsaUNBQWlDLDJFQUFtQjtBQUNwRDtBQUNBLFFBQVEscUVBQWE7QUFDckI7QUFDQSxZQUFZLHVFQUFlO0FBQzNCLFlBQVksdUVBQWU7QUFDM0IsWUFBWSx1RUFBZTtBQUMzQixZQUFZLHVFQUFlO0FBQzNCLFlBQVkseUVBQWlCO0FBQzdCLFlBQVksMkVBQW1CO0FBQy9CLHVEQUF1RCx1RUFBZTtBQUN0RSxvQkFBb0IscUVBQWE7QUFDakM7QUFDQTtBQUNBLGFBQWE7QUFDYjtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0EsZ0JBQWdCLHVFQUFlO0FBQy9CO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBLDZDQUE2QztBQUM3QztBQUNBO0FBQ0EsbUJBQW1CLHlDQUFJLElBQUk7QUFDM0I7QUFDQTtBQUNBO0FBQ0EsY0FBYyx5Q0FBSTtBQUNsQiw2QkFBNkIsZ0NBQWdDO0FBQzdELGNBQWM7QUFDZDtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQSxlQUFlLHlDQUFJO0FBQ25CLGVBQWUsV0FBVztBQUMxQix1QkFBdUIsdUVBQVMsc0JBQXNCO0FBQ3REO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQSxtQ0FBbUMsd0VBQWdCO0FBQ25ELHlDQUF5QyxnRUFBUTtBQUNqRCxZQUFZLHVFQUFlO0FBQzNCO0FBQ0E7QUFDQSxZQUFZLHVFQUFlO0FBQzNCO0FBQ0E7QUFDQTtBQUNBLGdCQUFnQiw0QkFBNEIsRUFBRSx1RUFBZTtBQUM3RCxpQ0FBaUMsMERBQVk7QUFDN0MsUUFBUSxtRUFBaUI7QUFDekI7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBLFFBQVEsdUVBQWU7QUFDdkI7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0Esd0VBQXdFLFlBQVk7QUFDcEY7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQSx3QkFBd0IsVUFBVTtBQUNsQztBQUNBO0FBQ0E7QUFDQTtBQUNBLFNBQVM7QUFDVDtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQSxxQ0FBcUMsdUVBQWU7QUFDcEQsOEJBQThCLHNFQUFjO0FBQzVDO0FBQ0E7QUFDQTtBQUNBLFlBQVksdUVBQWU7QUFDM0I7QUFDQTtBQUNBLFlBQVksd0VBQWdCO0FBQzVCO0FBQ0EsY0FBYyxnRUFBUTtBQUN0QjtBQUNBLFFBQVEsdUVBQWU7QUFDdkI7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBLHFDQUFxQyx1RUFBZTtBQUNwRCxnRUFBZ0UsZ0VBQW1CO0FBQ25GLHFDQUFxQyx3RUFBZ0I7QUFDckQ7QUFDQTtBQUNBLDJDQUEyQyx3RUFBZ0I7QUFDM0QsNEJBQTRCLHVFQUFlO0FBQzNDO0FBQ0E7QUFDQTtBQUNBO0FBQ0EsWUFBWSx3RUFBZ0I7QUFDNUI7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBLFlBQVkscUVBQWE7QUFDekIsWUFBWSxxRUFBYSxnQkFBZ0IsU0FBUztBQUNsRDtBQUNBO0FBQ0E7QUFDQTtBQUNBLGtCQUFrQixtREFBTTtBQUN4QjtBQUNBLElBQUksMkRBQVEsR0FBRyxlQUFlO0FBQzlCO0FBQ0E7QUFDQSxJQUFJLHdEQUFLO0FBQ1Q7QUFDQTtBQUNBLElBQUksd0RBQUs7QUFDVDtBQUNBO0FBQ0EsSUFBSSx3REFBSztBQUNUO0FBQ0E7QUFDQSxJQUFJLHdEQUFLO0FBQ1Q7QUFDQTtBQUNBLElBQUksd0RBQUs7QUFDVDtBQUNBO0FBQ0EsSUFBSSwrREFBYTtBQUNqQjtBQUNvQjtBQUNwQiIsInNvdXJjZXMiOlsid2VicGFjazovL19OX0UvLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC1zY2FmZm9sZC11aS9kaXN0L2VzbS9zcmMvbW9kYWwvdzNtLW1vZGFsL2luZGV4LmpzP2Y2OGIiXSwic291cmNlc0NvbnRlbnQiOlsidmFyIF9fZGVjb3JhdGUgPSAodGhpcyAmJiB0aGlzLl9fZGVjb3JhdGUpIHx8IGZ1bmN0aW9uIChkZWNvcmF0b3JzLCB0YXJnZXQsIGtleSwgZGVzYykge1xuICAgIHZhciBjID0gYXJndW1lbnRzLmxlbmd0aCwgciA9IGMgPCAzID8gdGFyZ2V0IDogZGVzYyA9PT0gbnVsbCA/IGRlc2MgPSBPYmplY3QuZ2V0T3duUHJvcGVydHlEZXNjcmlwdG9yKHRhcmdldCwga2V5KSA6IGRlc2MsIGQ7XG4gICAgaWYgKHR5cGVvZiBSZWZsZWN0ID09PSBcIm9iamVjdFwiICYmIHR5cGVvZiBSZWZsZWN0LmRlY29yYXRlID09PSBcImZ1bmN0aW9uXCIpIHIgPSBSZWZsZWN0LmRlY29yYXRlKGRlY29yYXRvcnMsIHRhcmdldCwga2V5LCBkZXNjKTtcbiAgICBlbHNlIGZvciAodmFyIGkgPSBkZWNvcmF0b3JzLmxlbmd0aCAtIDE7IGkgPj0gMDsgaS0tKSBpZiAoZCA9IGRlY29yYXRvcnNbaV0pIHIgPSAoYyA8IDMgPyBkKHIpIDogYyA+IDMgPyBkKHRhcmdldCwga2V5LCByKSA6IGQodGFyZ2V0LCBrZXkpKSB8fCByO1xuICAgIHJldHVybiBjID4gMyAmJiByICYmIE9iamVjdC5kZWZpbmVQcm9wZXJ0eSh0YXJnZXQsIGtleSwgciksIHI7XG59O1xuaW1wb3J0IHsgTGl0RWxlbWVudCwgaHRtbCB9IGZyb20gJ2xpdCc7XG5pbXBvcnQgeyBwcm9wZXJ0eSwgc3RhdGUgfSBmcm9tICdsaXQvZGVjb3JhdG9ycy5qcyc7XG5pbXBvcnQgeyBpZkRlZmluZWQgfSBmcm9tICdsaXQvZGlyZWN0aXZlcy9pZi1kZWZpbmVkLmpzJztcbmltcG9ydCB7IENvbnN0YW50c1V0aWwgYXMgQ29tbW9uQ29uc3RhbnRzVXRpbCB9IGZyb20gJ0ByZW93bi9hcHBraXQtY29tbW9uJztcbmltcG9ydCB7IEFwaUNvbnRyb2xsZXIsIENoYWluQ29udHJvbGxlciwgQ29ubmVjdG9yQ29udHJvbGxlciwgQ29yZUhlbHBlclV0aWwsIE1vZGFsQ29udHJvbGxlciwgT3B0aW9uc0NvbnRyb2xsZXIsIFJvdXRlckNvbnRyb2xsZXIsIFNJV1hVdGlsLCBTbmFja0NvbnRyb2xsZXIsIFRoZW1lQ29udHJvbGxlciB9IGZyb20gJ0ByZW93bi9hcHBraXQtY29udHJvbGxlcnMnO1xuaW1wb3J0IHsgVWlIZWxwZXJVdGlsLCBjdXN0b21FbGVtZW50LCBpbml0aWFsaXplVGhlbWluZyB9IGZyb20gJ0ByZW93bi9hcHBraXQtdWknO1xuaW1wb3J0ICdAcmVvd24vYXBwa2l0LXVpL3d1aS1jYXJkJztcbmltcG9ydCAnQHJlb3duL2FwcGtpdC11aS93dWktZmxleCc7XG5pbXBvcnQgJy4uLy4uL3BhcnRpYWxzL3czbS1hbGVydGJhci9pbmRleC5qcyc7XG5pbXBvcnQgJy4uLy4uL3BhcnRpYWxzL3czbS1oZWFkZXIvaW5kZXguanMnO1xuaW1wb3J0ICcuLi8uLi9wYXJ0aWFscy93M20tc25hY2tiYXIvaW5kZXguanMnO1xuaW1wb3J0ICcuLi8uLi9wYXJ0aWFscy93M20tdG9vbHRpcC9pbmRleC5qcyc7XG5pbXBvcnQgJy4uL3czbS1yb3V0ZXIvaW5kZXguanMnO1xuaW1wb3J0IHN0eWxlcyBmcm9tICcuL3N0eWxlcy5qcyc7XG5jb25zdCBTQ1JPTExfTE9DSyA9ICdzY3JvbGwtbG9jayc7XG5sZXQgVzNtTW9kYWwgPSBjbGFzcyBXM21Nb2RhbCBleHRlbmRzIExpdEVsZW1lbnQge1xuICAgIGNvbnN0cnVjdG9yKCkge1xuICAgICAgICBzdXBlcigpO1xuICAgICAgICB0aGlzLnVuc3Vic2NyaWJlID0gW107XG4gICAgICAgIHRoaXMuYWJvcnRDb250cm9sbGVyID0gdW5kZWZpbmVkO1xuICAgICAgICB0aGlzLmhhc1ByZWZldGNoZWQgPSBmYWxzZTtcbiAgICAgICAgdGhpcy5lbmFibGVFbWJlZGRlZCA9IE9wdGlvbnNDb250cm9sbGVyLnN0YXRlLmVuYWJsZUVtYmVkZGVkO1xuICAgICAgICB0aGlzLm9wZW4gPSBNb2RhbENvbnRyb2xsZXIuc3RhdGUub3BlbjtcbiAgICAgICAgdGhpcy5jYWlwQWRkcmVzcyA9IENoYWluQ29udHJvbGxlci5zdGF0ZS5hY3RpdmVDYWlwQWRkcmVzcztcbiAgICAgICAgdGhpcy5jYWlwTmV0d29yayA9IENoYWluQ29udHJvbGxlci5zdGF0ZS5hY3RpdmVDYWlwTmV0d29yaztcbiAgICAgICAgdGhpcy5zaGFrZSA9IE1vZGFsQ29udHJvbGxlci5zdGF0ZS5zaGFrZTtcbiAgICAgICAgdGhpcy5maWx0ZXJCeU5hbWVzcGFjZSA9IENvbm5lY3RvckNvbnRyb2xsZXIuc3RhdGUuZmlsdGVyQnlOYW1lc3BhY2U7XG4gICAgICAgIHRoaXMuaW5pdGlhbGl6ZVRoZW1pbmcoKTtcbiAgICAgICAgQXBpQ29udHJvbGxlci5wcmVmZXRjaEFuYWx5dGljc0NvbmZpZygpO1xuICAgICAgICB0aGlzLnVuc3Vic2NyaWJlLnB1c2goLi4uW1xuICAgICAgICAgICAgTW9kYWxDb250cm9sbGVyLnN1YnNjcmliZUtleSgnb3BlbicsIHZhbCA9PiAodmFsID8gdGhpcy5vbk9wZW4oKSA6IHRoaXMub25DbG9zZSgpKSksXG4gICAgICAgICAgICBNb2RhbENvbnRyb2xsZXIuc3Vic2NyaWJlS2V5KCdzaGFrZScsIHZhbCA9PiAodGhpcy5zaGFrZSA9IHZhbCkpLFxuICAgICAgICAgICAgQ2hhaW5Db250cm9sbGVyLnN1YnNjcmliZUtleSgnYWN0aXZlQ2FpcE5ldHdvcmsnLCB2YWwgPT4gdGhpcy5vbk5ld05ldHdvcmsodmFsKSksXG4gICAgICAgICAgICBDaGFpbkNvbnRyb2xsZXIuc3Vic2NyaWJlS2V5KCdhY3RpdmVDYWlwQWRkcmVzcycsIHZhbCA9PiB0aGlzLm9uTmV3QWRkcmVzcyh2YWwpKSxcbiAgICAgICAgICAgIE9wdGlvbnNDb250cm9sbGVyLnN1YnNjcmliZUtleSgnZW5hYmxlRW1iZWRkZWQnLCB2YWwgPT4gKHRoaXMuZW5hYmxlRW1iZWRkZWQgPSB2YWwpKSxcbiAgICAgICAgICAgIENvbm5lY3RvckNvbnRyb2xsZXIuc3Vic2NyaWJlS2V5KCdmaWx0ZXJCeU5hbWVzcGFjZScsIHZhbCA9PiB7XG4gICAgICAgICAgICAgICAgaWYgKHRoaXMuZmlsdGVyQnlOYW1lc3BhY2UgIT09IHZhbCAmJiAhQ2hhaW5Db250cm9sbGVyLmdldEFjY291bnREYXRhKHZhbCk/LmNhaXBBZGRyZXNzKSB7XG4gICAgICAgICAgICAgICAgICAgIEFwaUNvbnRyb2xsZXIuZmV0Y2hSZWNvbW1lbmRlZFdhbGxldHMoKTtcbiAgICAgICAgICAgICAgICAgICAgdGhpcy5maWx0ZXJCeU5hbWVzcGFjZSA9IHZhbDtcbiAgICAgICAgICAgICAgICB9XG4gICAgICAgICAgICB9KVxuICAgICAgICBdKTtcbiAgICB9XG4gICAgZmlyc3RVcGRhdGVkKCkge1xuICAgICAgICBpZiAodGhpcy5jYWlwQWRkcmVzcykge1xuICAgICAgICAgICAgaWYgKHRoaXMuZW5hYmxlRW1iZWRkZWQpIHtcbiAgICAgICAgICAgICAgICBNb2RhbENvbnRyb2xsZXIuY2xvc2UoKTtcbiAgICAgICAgICAgICAgICB0aGlzLnByZWZldGNoKCk7XG4gICAgICAgICAgICAgICAgcmV0dXJuO1xuICAgICAgICAgICAgfVxuICAgICAgICAgICAgdGhpcy5vbk5ld0FkZHJlc3ModGhpcy5jYWlwQWRkcmVzcyk7XG4gICAgICAgIH1cbiAgICAgICAgaWYgKHRoaXMub3Blbikge1xuICAgICAgICAgICAgdGhpcy5vbk9wZW4oKTtcbiAgICAgICAgfVxuICAgICAgICBpZiAodGhpcy5lbmFibGVFbWJlZGRlZCkge1xuICAgICAgICAgICAgdGhpcy5wcmVmZXRjaCgpO1xuICAgICAgICB9XG4gICAgfVxuICAgIGRpc2Nvbm5lY3RlZENhbGxiYWNrKCkge1xuICAgICAgICB0aGlzLnVuc3Vic2NyaWJlLmZvckVhY2godW5zdWJzY3JpYmUgPT4gdW5zdWJzY3JpYmUoKSk7XG4gICAgICAgIHRoaXMub25SZW1vdmVLZXlib2FyZExpc3RlbmVyKCk7XG4gICAgfVxuICAgIHJlbmRlcigpIHtcbiAgICAgICAgdGhpcy5zdHlsZS5jc3NUZXh0ID0gYFxuICAgICAgLS1sb2NhbC1ib3JkZXItYm90dG9tLW1vYmlsZS1yYWRpdXM6ICR7dGhpcy5lbmFibGVFbWJlZGRlZCA/ICdjbGFtcCgwcHgsIHZhcigtLXd1aS1ib3JkZXItcmFkaXVzLWwpLCA0NHB4KScgOiAnMHB4J307XG4gICAgYDtcbiAgICAgICAgaWYgKHRoaXMuZW5hYmxlRW1iZWRkZWQpIHtcbiAgICAgICAgICAgIHJldHVybiBodG1sIGAke3RoaXMuY29udGVudFRlbXBsYXRlKCl9XG4gICAgICAgIDx3M20tdG9vbHRpcD48L3czbS10b29sdGlwPiBgO1xuICAgICAgICB9XG4gICAgICAgIHJldHVybiB0aGlzLm9wZW5cbiAgICAgICAgICAgID8gaHRtbCBgXG4gICAgICAgICAgPHd1aS1mbGV4IEBjbGljaz0ke3RoaXMub25PdmVybGF5Q2xpY2suYmluZCh0aGlzKX0gZGF0YS10ZXN0aWQ9XCJ3M20tbW9kYWwtb3ZlcmxheVwiPlxuICAgICAgICAgICAgJHt0aGlzLmNvbnRlbnRUZW1wbGF0ZSgpfVxuICAgICAgICAgIDwvd3VpLWZsZXg+XG4gICAgICAgICAgPHczbS10b29sdGlwPjwvdzNtLXRvb2x0aXA+XG4gICAgICAgIGBcbiAgICAgICAgICAgIDogbnVsbDtcbiAgICB9XG4gICAgY29udGVudFRlbXBsYXRlKCkge1xuICAgICAgICByZXR1cm4gaHRtbCBgIDx3dWktY2FyZFxuICAgICAgc2hha2U9XCIke3RoaXMuc2hha2V9XCJcbiAgICAgIGRhdGEtZW1iZWRkZWQ9XCIke2lmRGVmaW5lZCh0aGlzLmVuYWJsZUVtYmVkZGVkKX1cIlxuICAgICAgcm9sZT1cImFsZXJ0ZGlhbG9nXCJcbiAgICAgIGFyaWEtbW9kYWw9XCJ0cnVlXCJcbiAgICAgIHRhYmluZGV4PVwiMFwiXG4gICAgICBkYXRhLXRlc3RpZD1cInczbS1tb2RhbC1jYXJkXCJcbiAgICA+XG4gICAgICA8dzNtLWhlYWRlcj48L3czbS1oZWFkZXI+XG4gICAgICA8dzNtLXJvdXRlcj48L3czbS1yb3V0ZXI+XG4gICAgICA8dzNtLXNuYWNrYmFyPjwvdzNtLXNuYWNrYmFyPlxuICAgICAgPHczbS1hbGVydGJhcj48L3czbS1hbGVydGJhcj5cbiAgICA8L3d1aS1jYXJkPmA7XG4gICAgfVxuICAgIGFzeW5jIG9uT3ZlcmxheUNsaWNrKGV2ZW50KSB7XG4gICAgICAgIGlmIChldmVudC50YXJnZXQgPT09IGV2ZW50LmN1cnJlbnRUYXJnZXQpIHtcbiAgICAgICAgICAgIGF3YWl0IHRoaXMuaGFuZGxlQ2xvc2UoKTtcbiAgICAgICAgfVxuICAgIH1cbiAgICBhc3luYyBoYW5kbGVDbG9zZSgpIHtcbiAgICAgICAgY29uc3QgaXNVbnN1cHBvcnRlZENoYWluID0gUm91dGVyQ29udHJvbGxlci5zdGF0ZS52aWV3ID09PSAnVW5zdXBwb3J0ZWRDaGFpbic7XG4gICAgICAgIGlmIChpc1Vuc3VwcG9ydGVkQ2hhaW4gfHwgKGF3YWl0IFNJV1hVdGlsLmlzU0lXWENsb3NlRGlzYWJsZWQoKSkpIHtcbiAgICAgICAgICAgIE1vZGFsQ29udHJvbGxlci5zaGFrZSgpO1xuICAgICAgICB9XG4gICAgICAgIGVsc2Uge1xuICAgICAgICAgICAgTW9kYWxDb250cm9sbGVyLmNsb3NlKCk7XG4gICAgICAgIH1cbiAgICB9XG4gICAgaW5pdGlhbGl6ZVRoZW1pbmcoKSB7XG4gICAgICAgIGNvbnN0IHsgdGhlbWVWYXJpYWJsZXMsIHRoZW1lTW9kZSB9ID0gVGhlbWVDb250cm9sbGVyLnN0YXRlO1xuICAgICAgICBjb25zdCBkZWZhdWx0VGhlbWVNb2RlID0gVWlIZWxwZXJVdGlsLmdldENvbG9yVGhlbWUodGhlbWVNb2RlKTtcbiAgICAgICAgaW5pdGlhbGl6ZVRoZW1pbmcodGhlbWVWYXJpYWJsZXMsIGRlZmF1bHRUaGVtZU1vZGUpO1xuICAgIH1cbiAgICBvbkNsb3NlKCkge1xuICAgICAgICB0aGlzLm9wZW4gPSBmYWxzZTtcbiAgICAgICAgdGhpcy5jbGFzc0xpc3QucmVtb3ZlKCdvcGVuJyk7XG4gICAgICAgIHRoaXMub25TY3JvbGxVbmxvY2soKTtcbiAgICAgICAgU25hY2tDb250cm9sbGVyLmhpZGUoKTtcbiAgICAgICAgdGhpcy5vblJlbW92ZUtleWJvYXJkTGlzdGVuZXIoKTtcbiAgICB9XG4gICAgb25PcGVuKCkge1xuICAgICAgICB0aGlzLm9wZW4gPSB0cnVlO1xuICAgICAgICB0aGlzLmNsYXNzTGlzdC5hZGQoJ29wZW4nKTtcbiAgICAgICAgdGhpcy5vblNjcm9sbExvY2soKTtcbiAgICAgICAgdGhpcy5vbkFkZEtleWJvYXJkTGlzdGVuZXIoKTtcbiAgICB9XG4gICAgb25TY3JvbGxMb2NrKCkge1xuICAgICAgICBjb25zdCBzdHlsZVRhZyA9IGRvY3VtZW50LmNyZWF0ZUVsZW1lbnQoJ3N0eWxlJyk7XG4gICAgICAgIHN0eWxlVGFnLmRhdGFzZXRbJ3czbSddID0gU0NST0xMX0xPQ0s7XG4gICAgICAgIHN0eWxlVGFnLnRleHRDb250ZW50ID0gYFxuICAgICAgYm9keSB7XG4gICAgICAgIHRvdWNoLWFjdGlvbjogbm9uZTtcbiAgICAgICAgb3ZlcmZsb3c6IGhpZGRlbjtcbiAgICAgICAgb3ZlcnNjcm9sbC1iZWhhdmlvcjogY29udGFpbjtcbiAgICAgIH1cbiAgICAgIHczbS1tb2RhbCB7XG4gICAgICAgIHBvaW50ZXItZXZlbnRzOiBhdXRvO1xuICAgICAgfVxuICAgIGA7XG4gICAgICAgIGRvY3VtZW50LmhlYWQuYXBwZW5kQ2hpbGQoc3R5bGVUYWcpO1xuICAgIH1cbiAgICBvblNjcm9sbFVubG9jaygpIHtcbiAgICAgICAgY29uc3Qgc3R5bGVUYWcgPSBkb2N1bWVudC5oZWFkLnF1ZXJ5U2VsZWN0b3IoYHN0eWxlW2RhdGEtdzNtPVwiJHtTQ1JPTExfTE9DS31cIl1gKTtcbiAgICAgICAgaWYgKHN0eWxlVGFnKSB7XG4gICAgICAgICAgICBzdHlsZVRhZy5yZW1vdmUoKTtcbiAgICAgICAgfVxuICAgIH1cbiAgICBvbkFkZEtleWJvYXJkTGlzdGVuZXIoKSB7XG4gICAgICAgIHRoaXMuYWJvcnRDb250cm9sbGVyID0gbmV3IEFib3J0Q29udHJvbGxlcigpO1xuICAgICAgICBjb25zdCBjYXJkID0gdGhpcy5zaGFkb3dSb290Py5xdWVyeVNlbGVjdG9yKCd3dWktY2FyZCcpO1xuICAgICAgICBjYXJkPy5mb2N1cygpO1xuICAgICAgICB3aW5kb3cuYWRkRXZlbnRMaXN0ZW5lcigna2V5ZG93bicsIGV2ZW50ID0+IHtcbiAgICAgICAgICAgIGlmIChldmVudC5rZXkgPT09ICdFc2NhcGUnKSB7XG4gICAgICAgICAgICAgICAgdGhpcy5oYW5kbGVDbG9zZSgpO1xuICAgICAgICAgICAgfVxuICAgICAgICAgICAgZWxzZSBpZiAoZXZlbnQua2V5ID09PSAnVGFiJykge1xuICAgICAgICAgICAgICAgIGNvbnN0IHsgdGFnTmFtZSB9ID0gZXZlbnQudGFyZ2V0O1xuICAgICAgICAgICAgICAgIGlmICh0YWdOYW1lICYmICF0YWdOYW1lLmluY2x1ZGVzKCdXM00tJykgJiYgIXRhZ05hbWUuaW5jbHVkZXMoJ1dVSS0nKSkge1xuICAgICAgICAgICAgICAgICAgICBjYXJkPy5mb2N1cygpO1xuICAgICAgICAgICAgICAgIH1cbiAgICAgICAgICAgIH1cbiAgICAgICAgfSwgdGhpcy5hYm9ydENvbnRyb2xsZXIpO1xuICAgIH1cbiAgICBvblJlbW92ZUtleWJvYXJkTGlzdGVuZXIoKSB7XG4gICAgICAgIHRoaXMuYWJvcnRDb250cm9sbGVyPy5hYm9ydCgpO1xuICAgICAgICB0aGlzLmFib3J0Q29udHJvbGxlciA9IHVuZGVmaW5lZDtcbiAgICB9XG4gICAgYXN5bmMgb25OZXdBZGRyZXNzKGNhaXBBZGRyZXNzKSB7XG4gICAgICAgIGNvbnN0IGlzU3dpdGNoaW5nTmFtZXNwYWNlID0gQ2hhaW5Db250cm9sbGVyLnN0YXRlLmlzU3dpdGNoaW5nTmFtZXNwYWNlO1xuICAgICAgICBjb25zdCBuZXh0Q29ubmVjdGVkID0gQ29yZUhlbHBlclV0aWwuZ2V0UGxhaW5BZGRyZXNzKGNhaXBBZGRyZXNzKTtcbiAgICAgICAgY29uc3QgaXNEaXNjb25uZWN0ZWRJblNhbWVOYW1lc3BhY2UgPSAhbmV4dENvbm5lY3RlZCAmJiAhaXNTd2l0Y2hpbmdOYW1lc3BhY2U7XG4gICAgICAgIGNvbnN0IGlzU3dpdGNoaW5nTmFtZXNwYWNlQW5kQ29ubmVjdGVkID0gaXNTd2l0Y2hpbmdOYW1lc3BhY2UgJiYgbmV4dENvbm5lY3RlZDtcbiAgICAgICAgaWYgKGlzRGlzY29ubmVjdGVkSW5TYW1lTmFtZXNwYWNlKSB7XG4gICAgICAgICAgICBNb2RhbENvbnRyb2xsZXIuY2xvc2UoKTtcbiAgICAgICAgfVxuICAgICAgICBlbHNlIGlmIChpc1N3aXRjaGluZ05hbWVzcGFjZUFuZENvbm5lY3RlZCkge1xuICAgICAgICAgICAgUm91dGVyQ29udHJvbGxlci5nb0JhY2soKTtcbiAgICAgICAgfVxuICAgICAgICBhd2FpdCBTSVdYVXRpbC5pbml0aWFsaXplSWZFbmFibGVkKCk7XG4gICAgICAgIHRoaXMuY2FpcEFkZHJlc3MgPSBjYWlwQWRkcmVzcztcbiAgICAgICAgQ2hhaW5Db250cm9sbGVyLnNldElzU3dpdGNoaW5nTmFtZXNwYWNlKGZhbHNlKTtcbiAgICB9XG4gICAgb25OZXdOZXR3b3JrKG5leHRDYWlwTmV0d29yaykge1xuICAgICAgICBjb25zdCBwcmV2Q2FpcE5ldHdvcmtJZCA9IHRoaXMuY2FpcE5ldHdvcms/LmNhaXBOZXR3b3JrSWQ/LnRvU3RyaW5nKCk7XG4gICAgICAgIGNvbnN0IG5leHROZXR3b3JrSWQgPSBuZXh0Q2FpcE5ldHdvcms/LmNhaXBOZXR3b3JrSWQ/LnRvU3RyaW5nKCk7XG4gICAgICAgIGNvbnN0IG5ldHdvcmtDaGFuZ2VkID0gcHJldkNhaXBOZXR3b3JrSWQgJiYgbmV4dE5ldHdvcmtJZCAmJiBwcmV2Q2FpcE5ldHdvcmtJZCAhPT0gbmV4dE5ldHdvcmtJZDtcbiAgICAgICAgY29uc3QgaXNTd2l0Y2hpbmdOYW1lc3BhY2UgPSBDaGFpbkNvbnRyb2xsZXIuc3RhdGUuaXNTd2l0Y2hpbmdOYW1lc3BhY2U7XG4gICAgICAgIGNvbnN0IGlzVW5zdXBwb3J0ZWROZXR3b3JrID0gdGhpcy5jYWlwTmV0d29yaz8ubmFtZSA9PT0gQ29tbW9uQ29uc3RhbnRzVXRpbC5VTlNVUFBPUlRFRF9ORVRXT1JLX05BTUU7XG4gICAgICAgIGNvbnN0IGlzQ29ubmVjdGluZ0V4dGVybmFsID0gUm91dGVyQ29udHJvbGxlci5zdGF0ZS52aWV3ID09PSAnQ29ubmVjdGluZ0V4dGVybmFsJztcbiAgICAgICAgY29uc3QgaXNOb3RDb25uZWN0ZWQgPSAhdGhpcy5jYWlwQWRkcmVzcztcbiAgICAgICAgY29uc3QgaXNOZXR3b3JrQ2hhbmdlZEluU2FtZU5hbWVzcGFjZSA9IG5ldHdvcmtDaGFuZ2VkICYmICFpc1Vuc3VwcG9ydGVkTmV0d29yayAmJiAhaXNTd2l0Y2hpbmdOYW1lc3BhY2U7XG4gICAgICAgIGNvbnN0IGlzVW5zdXBwb3J0ZWROZXR3b3JrU2NyZWVuID0gUm91dGVyQ29udHJvbGxlci5zdGF0ZS52aWV3ID09PSAnVW5zdXBwb3J0ZWRDaGFpbic7XG4gICAgICAgIGNvbnN0IGlzTW9kYWxPcGVuID0gTW9kYWxDb250cm9sbGVyLnN0YXRlLm9wZW47XG4gICAgICAgIGNvbnN0IHNob3VsZEdvQmFjayA9IGlzTW9kYWxPcGVuICYmXG4gICAgICAgICAgICAhaXNDb25uZWN0aW5nRXh0ZXJuYWwgJiZcbiAgICAgICAgICAgIChpc05vdENvbm5lY3RlZCB8fCBpc1Vuc3VwcG9ydGVkTmV0d29ya1NjcmVlbiB8fCBpc05ldHdvcmtDaGFuZ2VkSW5TYW1lTmFtZXNwYWNlKTtcbiAgICAgICAgaWYgKHNob3VsZEdvQmFjaykge1xuICAgICAgICAgICAgUm91dGVyQ29udHJvbGxlci5nb0JhY2soKTtcbiAgICAgICAgfVxuICAgICAgICB0aGlzLmNhaXBOZXR3b3JrID0gbmV4dENhaXBOZXR3b3JrO1xuICAgIH1cbiAgICBwcmVmZXRjaCgpIHtcbiAgICAgICAgaWYgKCF0aGlzLmhhc1ByZWZldGNoZWQpIHtcbiAgICAgICAgICAgIEFwaUNvbnRyb2xsZXIucHJlZmV0Y2goKTtcbiAgICAgICAgICAgIEFwaUNvbnRyb2xsZXIuZmV0Y2hXYWxsZXRzKHsgcGFnZTogMSB9KTtcbiAgICAgICAgICAgIHRoaXMuaGFzUHJlZmV0Y2hlZCA9IHRydWU7XG4gICAgICAgIH1cbiAgICB9XG59O1xuVzNtTW9kYWwuc3R5bGVzID0gc3R5bGVzO1xuX19kZWNvcmF0ZShbXG4gICAgcHJvcGVydHkoeyB0eXBlOiBCb29sZWFuIH0pXG5dLCBXM21Nb2RhbC5wcm90b3R5cGUsIFwiZW5hYmxlRW1iZWRkZWRcIiwgdm9pZCAwKTtcbl9fZGVjb3JhdGUoW1xuICAgIHN0YXRlKClcbl0sIFczbU1vZGFsLnByb3RvdHlwZSwgXCJvcGVuXCIsIHZvaWQgMCk7XG5fX2RlY29yYXRlKFtcbiAgICBzdGF0ZSgpXG5dLCBXM21Nb2RhbC5wcm90b3R5cGUsIFwiY2FpcEFkZHJlc3NcIiwgdm9pZCAwKTtcbl9fZGVjb3JhdGUoW1xuICAgIHN0YXRlKClcbl0sIFczbU1vZGFsLnByb3RvdHlwZSwgXCJjYWlwTmV0d29ya1wiLCB2b2lkIDApO1xuX19kZWNvcmF0ZShbXG4gICAgc3RhdGUoKVxuXSwgVzNtTW9kYWwucHJvdG90eXBlLCBcInNoYWtlXCIsIHZvaWQgMCk7XG5fX2RlY29yYXRlKFtcbiAgICBzdGF0ZSgpXG5dLCBXM21Nb2RhbC5wcm90b3R5cGUsIFwiZmlsdGVyQnlOYW1lc3BhY2VcIiwgdm9pZCAwKTtcblczbU1vZGFsID0gX19kZWNvcmF0ZShbXG4gICAgY3VzdG9tRWxlbWVudCgndzNtLW1vZGFsJylcbl0sIFczbU1vZGFsKTtcbmV4cG9ydCB7IFczbU1vZGFsIH07XG4vLyMgc291cmNlTWFwcGluZ1VSTD1pbmRleC5qcy5tYXAiXSwibmFtZXMiOltdLCJzb3VyY2VSb290IjoiIn0=\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-scaffold-ui/dist/esm/src/modal/w3m-modal/index.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@reown/appkit-scaffold-ui/dist/esm/src/modal/w3m-modal/styles.js":
-/*!***************************************************************************************!*\
-  !*** ./node_modules/@reown/appkit-scaffold-ui/dist/esm/src/modal/w3m-modal/styles.js ***!
-  \***************************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n\n/* harmony default export */ __webpack_exports__[\"default\"] = ((0,lit__WEBPACK_IMPORTED_MODULE_0__.css) `\n  :host {\n    z-index: var(--w3m-z-index);\n    display: block;\n    backface-visibility: hidden;\n    will-change: opacity;\n    position: fixed;\n    top: 0;\n    left: 0;\n    right: 0;\n    bottom: 0;\n    pointer-events: none;\n    opacity: 0;\n    background-color: var(--wui-cover);\n    transition: opacity 0.2s var(--wui-ease-out-power-2);\n    will-change: opacity;\n  }\n\n  :host(.open) {\n    opacity: 1;\n  }\n\n  :host(.embedded) {\n    position: relative;\n    pointer-events: unset;\n    background: none;\n    width: 100%;\n    opacity: 1;\n  }\n\n  wui-card {\n    max-width: var(--w3m-modal-width);\n    width: 100%;\n    position: relative;\n    animation: zoom-in 0.2s var(--wui-ease-out-power-2);\n    animation-fill-mode: backwards;\n    outline: none;\n    transition:\n      border-radius var(--wui-duration-lg) var(--wui-ease-out-power-1),\n      background-color var(--wui-duration-lg) var(--wui-ease-out-power-1);\n    will-change: border-radius, background-color;\n  }\n\n  :host(.embedded) wui-card {\n    max-width: 400px;\n  }\n\n  wui-card[shake='true'] {\n    animation:\n      zoom-in 0.2s var(--wui-ease-out-power-2),\n      w3m-shake 0.5s var(--wui-ease-out-power-2);\n  }\n\n  wui-flex {\n    overflow-x: hidden;\n    overflow-y: auto;\n    display: flex;\n    align-items: center;\n    justify-content: center;\n    width: 100%;\n    height: 100%;\n  }\n\n  @media (max-height: 700px) and (min-width: 431px) {\n    wui-flex {\n      align-items: flex-start;\n    }\n\n    wui-card {\n      margin: var(--wui-spacing-xxl) 0px;\n    }\n  }\n\n  @media (max-width: 430px) {\n    wui-flex {\n      align-items: flex-end;\n    }\n\n    wui-card {\n      max-width: 100%;\n      border-bottom-left-radius: var(--local-border-bottom-mobile-radius);\n      border-bottom-right-radius: var(--local-border-bottom-mobile-radius);\n      border-bottom: none;\n      animation: slide-in 0.2s var(--wui-ease-out-power-2);\n    }\n\n    wui-card[shake='true'] {\n      animation:\n        slide-in 0.2s var(--wui-ease-out-power-2),\n        w3m-shake 0.5s var(--wui-ease-out-power-2);\n    }\n  }\n\n  @keyframes zoom-in {\n    0% {\n      transform: scale(0.95) translateY(0);\n    }\n    100% {\n      transform: scale(1) translateY(0);\n    }\n  }\n\n  @keyframes slide-in {\n    0% {\n      transform: scale(1) translateY(50px);\n    }\n    100% {\n      transform: scale(1) translateY(0);\n    }\n  }\n\n  @keyframes w3m-shake {\n    0% {\n      transform: scale(1) rotate(0deg);\n    }\n    20% {\n      transform: scale(1) rotate(-1deg);\n    }\n    40% {\n      transform: scale(1) rotate(1.5deg);\n    }\n    60% {\n      transform: scale(1) rotate(-1.5deg);\n    }\n    80% {\n      transform: scale(1) rotate(1deg);\n    }\n    100% {\n      transform: scale(1) rotate(0deg);\n    }\n  }\n\n  @keyframes w3m-view-height {\n    from {\n      height: var(--prev-height);\n    }\n    to {\n      height: var(--new-height);\n    }\n  }\n`);\n//# sourceMappingURL=styles.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC1zY2FmZm9sZC11aS9kaXN0L2VzbS9zcmMvbW9kYWwvdzNtLW1vZGFsL3N0eWxlcy5qcyIsIm1hcHBpbmdzIjoiOztBQUEwQjtBQUMxQiwrREFBZSx3Q0FBRztBQUNsQjtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTs7QUFFQTtBQUNBO0FBQ0E7O0FBRUE7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7O0FBRUE7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBOztBQUVBO0FBQ0E7QUFDQTs7QUFFQTtBQUNBO0FBQ0E7QUFDQTtBQUNBOztBQUVBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTs7QUFFQTtBQUNBO0FBQ0E7QUFDQTs7QUFFQTtBQUNBO0FBQ0E7QUFDQTs7QUFFQTtBQUNBO0FBQ0E7QUFDQTs7QUFFQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTs7QUFFQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7O0FBRUE7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTs7QUFFQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBOztBQUVBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7O0FBRUE7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBLENBQUMsRUFBQztBQUNGIiwic291cmNlcyI6WyJ3ZWJwYWNrOi8vX05fRS8uL25vZGVfbW9kdWxlcy9AcmVvd24vYXBwa2l0LXNjYWZmb2xkLXVpL2Rpc3QvZXNtL3NyYy9tb2RhbC93M20tbW9kYWwvc3R5bGVzLmpzPzE5YTkiXSwic291cmNlc0NvbnRlbnQiOlsiaW1wb3J0IHsgY3NzIH0gZnJvbSAnbGl0JztcbmV4cG9ydCBkZWZhdWx0IGNzcyBgXG4gIDpob3N0IHtcbiAgICB6LWluZGV4OiB2YXIoLS13M20tei1pbmRleCk7XG4gICAgZGlzcGxheTogYmxvY2s7XG4gICAgYmFja2ZhY2UtdmlzaWJpbGl0eTogaGlkZGVuO1xuICAgIHdpbGwtY2hhbmdlOiBvcGFjaXR5O1xuICAgIHBvc2l0aW9uOiBmaXhlZDtcbiAgICB0b3A6IDA7XG4gICAgbGVmdDogMDtcbiAgICByaWdodDogMDtcbiAgICBib3R0b206IDA7XG4gICAgcG9pbnRlci1ldmVudHM6IG5vbmU7XG4gICAgb3BhY2l0eTogMDtcbiAgICBiYWNrZ3JvdW5kLWNvbG9yOiB2YXIoLS13dWktY292ZXIpO1xuICAgIHRyYW5zaXRpb246IG9wYWNpdHkgMC4ycyB2YXIoLS13dWktZWFzZS1vdXQtcG93ZXItMik7XG4gICAgd2lsbC1jaGFuZ2U6IG9wYWNpdHk7XG4gIH1cblxuICA6aG9zdCgub3Blbikge1xuICAgIG9wYWNpdHk6IDE7XG4gIH1cblxuICA6aG9zdCguZW1iZWRkZWQpIHtcbiAgICBwb3NpdGlvbjogcmVsYXRpdmU7XG4gICAgcG9pbnRlci1ldmVudHM6IHVuc2V0O1xuICAgIGJhY2tncm91bmQ6IG5vbmU7XG4gICAgd2lkdGg6IDEwMCU7XG4gICAgb3BhY2l0eTogMTtcbiAgfVxuXG4gIHd1aS1jYXJkIHtcbiAgICBtYXgtd2lkdGg6IHZhcigtLXczbS1tb2RhbC13aWR0aCk7XG4gICAgd2lkdGg6IDEwMCU7XG4gICAgcG9zaXRpb246IHJlbGF0aXZlO1xuICAgIGFuaW1hdGlvbjogem9vbS1pbiAwLjJzIHZhcigtLXd1aS1lYXNlLW91dC1wb3dlci0yKTtcbiAgICBhbmltYXRpb24tZmlsbC1tb2RlOiBiYWNrd2FyZHM7XG4gICAgb3V0bGluZTogbm9uZTtcbiAgICB0cmFuc2l0aW9uOlxuICAgICAgYm9yZGVyLXJhZGl1cyB2YXIoLS13dWktZHVyYXRpb24tbGcpIHZhcigtLXd1aS1lYXNlLW91dC1wb3dlci0xKSxcbiAgICAgIGJhY2tncm91bmQtY29sb3IgdmFyKC0td3VpLWR1cmF0aW9uLWxnKSB2YXIoLS13dWktZWFzZS1vdXQtcG93ZXItMSk7XG4gICAgd2lsbC1jaGFuZ2U6IGJvcmRlci1yYWRpdXMsIGJhY2tncm91bmQtY29sb3I7XG4gIH1cblxuICA6aG9zdCguZW1iZWRkZWQpIHd1aS1jYXJkIHtcbiAgICBtYXgtd2lkdGg6IDQwMHB4O1xuICB9XG5cbiAgd3VpLWNhcmRbc2hha2U9J3RydWUnXSB7XG4gICAgYW5pbWF0aW9uOlxuICAgICAgem9vbS1pbiAwLjJzIHZhcigtLXd1aS1lYXNlLW91dC1wb3dlci0yKSxcbiAgICAgIHczbS1zaGFrZSAwLjVzIHZhcigtLXd1aS1lYXNlLW91dC1wb3dlci0yKTtcbiAgfVxuXG4gIHd1aS1mbGV4IHtcbiAgICBvdmVyZmxvdy14OiBoaWRkZW47XG4gICAgb3ZlcmZsb3cteTogYXV0bztcbiAgICBkaXNwbGF5OiBmbGV4O1xuICAgIGFsaWduLWl0ZW1zOiBjZW50ZXI7XG4gICAganVzdGlmeS1jb250ZW50OiBjZW50ZXI7XG4gICAgd2lkdGg6IDEwMCU7XG4gICAgaGVpZ2h0OiAxMDAlO1xuICB9XG5cbiAgQG1lZGlhIChtYXgtaGVpZ2h0OiA3MDBweCkgYW5kIChtaW4td2lkdGg6IDQzMXB4KSB7XG4gICAgd3VpLWZsZXgge1xuICAgICAgYWxpZ24taXRlbXM6IGZsZXgtc3RhcnQ7XG4gICAgfVxuXG4gICAgd3VpLWNhcmQge1xuICAgICAgbWFyZ2luOiB2YXIoLS13dWktc3BhY2luZy14eGwpIDBweDtcbiAgICB9XG4gIH1cblxuICBAbWVkaWEgKG1heC13aWR0aDogNDMwcHgpIHtcbiAgICB3dWktZmxleCB7XG4gICAgICBhbGlnbi1pdGVtczogZmxleC1lbmQ7XG4gICAgfVxuXG4gICAgd3VpLWNhcmQge1xuICAgICAgbWF4LXdpZHRoOiAxMDAlO1xuICAgICAgYm9yZGVyLWJvdHRvbS1sZWZ0LXJhZGl1czogdmFyKC0tbG9jYWwtYm9yZGVyLWJvdHRvbS1tb2JpbGUtcmFkaXVzKTtcbiAgICAgIGJvcmRlci1ib3R0b20tcmlnaHQtcmFkaXVzOiB2YXIoLS1sb2NhbC1ib3JkZXItYm90dG9tLW1vYmlsZS1yYWRpdXMpO1xuICAgICAgYm9yZGVyLWJvdHRvbTogbm9uZTtcbiAgICAgIGFuaW1hdGlvbjogc2xpZGUtaW4gMC4ycyB2YXIoLS13dWktZWFzZS1vdXQtcG93ZXItMik7XG4gICAgfVxuXG4gICAgd3VpLWNhcmRbc2hha2U9J3RydWUnXSB7XG4gICAgICBhbmltYXRpb246XG4gICAgICAgIHNsaWRlLWluIDAuMnMgdmFyKC0td3VpLWVhc2Utb3V0LXBvd2VyLTIpLFxuICAgICAgICB3M20tc2hha2UgMC41cyB2YXIoLS13dWktZWFzZS1vdXQtcG93ZXItMik7XG4gICAgfVxuICB9XG5cbiAgQGtleWZyYW1lcyB6b29tLWluIHtcbiAgICAwJSB7XG4gICAgICB0cmFuc2Zvcm06IHNjYWxlKDAuOTUpIHRyYW5zbGF0ZVkoMCk7XG4gICAgfVxuICAgIDEwMCUge1xuICAgICAgdHJhbnNmb3JtOiBzY2FsZSgxKSB0cmFuc2xhdGVZKDApO1xuICAgIH1cbiAgfVxuXG4gIEBrZXlmcmFtZXMgc2xpZGUtaW4ge1xuICAgIDAlIHtcbiAgICAgIHRyYW5zZm9ybTogc2NhbGUoMSkgdHJhbnNsYXRlWSg1MHB4KTtcbiAgICB9XG4gICAgMTAwJSB7XG4gICAgICB0cmFuc2Zvcm06IHNjYWxlKDEpIHRyYW5zbGF0ZVkoMCk7XG4gICAgfVxuICB9XG5cbiAgQGtleWZyYW1lcyB3M20tc2hha2Uge1xuICAgIDAlIHtcbiAgICAgIHRyYW5zZm9ybTogc2NhbGUoMSkgcm90YXRlKDBkZWcpO1xuICAgIH1cbiAgICAyMCUge1xuICAgICAgdHJhbnNmb3JtOiBzY2FsZSgxKSByb3RhdGUoLTFkZWcpO1xuICAgIH1cbiAgICA0MCUge1xuICAgICAgdHJhbnNmb3JtOiBzY2FsZSgxKSByb3RhdGUoMS41ZGVnKTtcbiAgICB9XG4gICAgNjAlIHtcbiAgICAgIHRyYW5zZm9ybTogc2NhbGUoMSkgcm90YXRlKC0xLjVkZWcpO1xuICAgIH1cbiAgICA4MCUge1xuICAgICAgdHJhbnNmb3JtOiBzY2FsZSgxKSByb3RhdGUoMWRlZyk7XG4gICAgfVxuICAgIDEwMCUge1xuICAgICAgdHJhbnNmb3JtOiBzY2FsZSgxKSByb3RhdGUoMGRlZyk7XG4gICAgfVxuICB9XG5cbiAgQGtleWZyYW1lcyB3M20tdmlldy1oZWlnaHQge1xuICAgIGZyb20ge1xuICAgICAgaGVpZ2h0OiB2YXIoLS1wcmV2LWhlaWdodCk7XG4gICAgfVxuICAgIHRvIHtcbiAgICAgIGhlaWdodDogdmFyKC0tbmV3LWhlaWdodCk7XG4gICAgfVxuICB9XG5gO1xuLy8jIHNvdXJjZU1hcHBpbmdVUkw9c3R5bGVzLmpzLm1hcCJdLCJuYW1lcyI6W10sInNvdXJjZVJvb3QiOiIifQ==\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-scaffold-ui/dist/esm/src/modal/w3m-modal/styles.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@reown/appkit-scaffold-ui/dist/esm/src/modal/w3m-router/index.js":
-/*!***************************************************************************************!*\
-  !*** ./node_modules/@reown/appkit-scaffold-ui/dist/esm/src/modal/w3m-router/index.js ***!
-  \***************************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   W3mRouter: function() { return /* binding */ W3mRouter; }\n/* harmony export */ });\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n/* harmony import */ var lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__ = __webpack_require__(/*! lit/decorators.js */ \"./node_modules/lit/decorators.js\");\n/* harmony import */ var _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_5__ = __webpack_require__(/*! @reown/appkit-controllers */ \"./node_modules/@reown/appkit-controllers/dist/esm/exports/index.js\");\n/* harmony import */ var _reown_appkit_ui__WEBPACK_IMPORTED_MODULE_2__ = __webpack_require__(/*! @reown/appkit-ui */ \"./node_modules/@reown/appkit-ui/dist/esm/exports/index.js\");\n/* harmony import */ var _utils_ConstantsUtil_js__WEBPACK_IMPORTED_MODULE_3__ = __webpack_require__(/*! ../../utils/ConstantsUtil.js */ \"./node_modules/@reown/appkit-scaffold-ui/dist/esm/src/utils/ConstantsUtil.js\");\n/* harmony import */ var _styles_js__WEBPACK_IMPORTED_MODULE_4__ = __webpack_require__(/*! ./styles.js */ \"./node_modules/@reown/appkit-scaffold-ui/dist/esm/src/modal/w3m-router/styles.js\");\nvar __decorate = (undefined && undefined.__decorate) || function (decorators, target, key, desc) {\n    var c = arguments.length, r = c < 3 ? target : desc === null ? desc = Object.getOwnPropertyDescriptor(target, key) : desc, d;\n    if (typeof Reflect === \"object\" && typeof Reflect.decorate === \"function\") r = Reflect.decorate(decorators, target, key, desc);\n    else for (var i = decorators.length - 1; i >= 0; i--) if (d = decorators[i]) r = (c < 3 ? d(r) : c > 3 ? d(target, key, r) : d(target, key)) || r;\n    return c > 3 && r && Object.defineProperty(target, key, r), r;\n};\n\n\n\n\n\n\nlet W3mRouter = class W3mRouter extends lit__WEBPACK_IMPORTED_MODULE_0__.LitElement {\n    constructor() {\n        super();\n        this.resizeObserver = undefined;\n        this.prevHeight = '0px';\n        this.prevHistoryLength = 1;\n        this.unsubscribe = [];\n        this.view = _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_5__.RouterController.state.view;\n        this.viewDirection = '';\n        this.unsubscribe.push(_reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_5__.RouterController.subscribeKey('view', val => this.onViewChange(val)));\n    }\n    firstUpdated() {\n        this.resizeObserver = new ResizeObserver(([content]) => {\n            const height = `${content?.contentRect.height}px`;\n            if (this.prevHeight !== '0px') {\n                this.style.setProperty('--prev-height', this.prevHeight);\n                this.style.setProperty('--new-height', height);\n                this.style.animation = 'w3m-view-height 150ms forwards ease';\n                this.style.height = 'auto';\n            }\n            setTimeout(() => {\n                this.prevHeight = height;\n                this.style.animation = 'unset';\n            }, _utils_ConstantsUtil_js__WEBPACK_IMPORTED_MODULE_3__.ConstantsUtil.ANIMATION_DURATIONS.ModalHeight);\n        });\n        this.resizeObserver?.observe(this.getWrapper());\n    }\n    disconnectedCallback() {\n        this.resizeObserver?.unobserve(this.getWrapper());\n        this.unsubscribe.forEach(unsubscribe => unsubscribe());\n    }\n    render() {\n        return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<div class=\"w3m-router-container\" view-direction=\"${this.viewDirection}\">\n      ${this.viewTemplate()}\n    </div>`;\n    }\n    viewTemplate() {\n        switch (this.view) {\n            case 'AccountSettings':\n                return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<w3m-account-settings-view></w3m-account-settings-view>`;\n            case 'Account':\n                return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<w3m-account-view></w3m-account-view>`;\n            case 'AllWallets':\n                return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<w3m-all-wallets-view></w3m-all-wallets-view>`;\n            case 'ApproveTransaction':\n                return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<w3m-approve-transaction-view></w3m-approve-transaction-view>`;\n            case 'BuyInProgress':\n                return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<w3m-buy-in-progress-view></w3m-buy-in-progress-view>`;\n            case 'ChooseAccountName':\n                return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<w3m-choose-account-name-view></w3m-choose-account-name-view>`;\n            case 'Connect':\n                return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<w3m-connect-view></w3m-connect-view>`;\n            case 'Create':\n                return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<w3m-connect-view walletGuide=\"explore\"></w3m-connect-view>`;\n            case 'ConnectingWalletConnect':\n                return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<w3m-connecting-wc-view></w3m-connecting-wc-view>`;\n            case 'ConnectingWalletConnectBasic':\n                return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<w3m-connecting-wc-basic-view></w3m-connecting-wc-basic-view>`;\n            case 'ConnectingExternal':\n                return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<w3m-connecting-external-view></w3m-connecting-external-view>`;\n            case 'ConnectingSiwe':\n                return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<w3m-connecting-siwe-view></w3m-connecting-siwe-view>`;\n            case 'ConnectWallets':\n                return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<w3m-connect-wallets-view></w3m-connect-wallets-view>`;\n            case 'ConnectSocials':\n                return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<w3m-connect-socials-view></w3m-connect-socials-view>`;\n            case 'ConnectingSocial':\n                return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<w3m-connecting-social-view></w3m-connecting-social-view>`;\n            case 'Downloads':\n                return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<w3m-downloads-view></w3m-downloads-view>`;\n            case 'EmailLogin':\n                return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<w3m-email-login-view></w3m-email-login-view>`;\n            case 'EmailVerifyOtp':\n                return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<w3m-email-verify-otp-view></w3m-email-verify-otp-view>`;\n            case 'EmailVerifyDevice':\n                return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<w3m-email-verify-device-view></w3m-email-verify-device-view>`;\n            case 'GetWallet':\n                return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<w3m-get-wallet-view></w3m-get-wallet-view>`;\n            case 'Networks':\n                return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<w3m-networks-view></w3m-networks-view>`;\n            case 'SwitchNetwork':\n                return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<w3m-network-switch-view></w3m-network-switch-view>`;\n            case 'Profile':\n                return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<w3m-profile-view></w3m-profile-view>`;\n            case 'SwitchAddress':\n                return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<w3m-switch-address-view></w3m-switch-address-view>`;\n            case 'Transactions':\n                return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<w3m-transactions-view></w3m-transactions-view>`;\n            case 'OnRampProviders':\n                return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<w3m-onramp-providers-view></w3m-onramp-providers-view>`;\n            case 'OnRampActivity':\n                return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<w3m-onramp-activity-view></w3m-onramp-activity-view>`;\n            case 'OnRampTokenSelect':\n                return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<w3m-onramp-token-select-view></w3m-onramp-token-select-view>`;\n            case 'OnRampFiatSelect':\n                return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<w3m-onramp-fiat-select-view></w3m-onramp-fiat-select-view>`;\n            case 'UpgradeEmailWallet':\n                return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<w3m-upgrade-wallet-view></w3m-upgrade-wallet-view>`;\n            case 'UpdateEmailWallet':\n                return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<w3m-update-email-wallet-view></w3m-update-email-wallet-view>`;\n            case 'UpdateEmailPrimaryOtp':\n                return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<w3m-update-email-primary-otp-view></w3m-update-email-primary-otp-view>`;\n            case 'UpdateEmailSecondaryOtp':\n                return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<w3m-update-email-secondary-otp-view></w3m-update-email-secondary-otp-view>`;\n            case 'UnsupportedChain':\n                return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<w3m-unsupported-chain-view></w3m-unsupported-chain-view>`;\n            case 'Swap':\n                return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<w3m-swap-view></w3m-swap-view>`;\n            case 'SwapSelectToken':\n                return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<w3m-swap-select-token-view></w3m-swap-select-token-view>`;\n            case 'SwapPreview':\n                return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<w3m-swap-preview-view></w3m-swap-preview-view>`;\n            case 'WalletSend':\n                return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<w3m-wallet-send-view></w3m-wallet-send-view>`;\n            case 'WalletSendSelectToken':\n                return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<w3m-wallet-send-select-token-view></w3m-wallet-send-select-token-view>`;\n            case 'WalletSendPreview':\n                return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<w3m-wallet-send-preview-view></w3m-wallet-send-preview-view>`;\n            case 'WhatIsABuy':\n                return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<w3m-what-is-a-buy-view></w3m-what-is-a-buy-view>`;\n            case 'WalletReceive':\n                return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<w3m-wallet-receive-view></w3m-wallet-receive-view>`;\n            case 'WalletCompatibleNetworks':\n                return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<w3m-wallet-compatible-networks-view></w3m-wallet-compatible-networks-view>`;\n            case 'WhatIsAWallet':\n                return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<w3m-what-is-a-wallet-view></w3m-what-is-a-wallet-view>`;\n            case 'ConnectingMultiChain':\n                return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<w3m-connecting-multi-chain-view></w3m-connecting-multi-chain-view>`;\n            case 'WhatIsANetwork':\n                return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<w3m-what-is-a-network-view></w3m-what-is-a-network-view>`;\n            case 'ConnectingFarcaster':\n                return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<w3m-connecting-farcaster-view></w3m-connecting-farcaster-view>`;\n            case 'SwitchActiveChain':\n                return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<w3m-switch-active-chain-view></w3m-switch-active-chain-view>`;\n            case 'RegisterAccountName':\n                return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<w3m-register-account-name-view></w3m-register-account-name-view>`;\n            case 'RegisterAccountNameSuccess':\n                return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<w3m-register-account-name-success-view></w3m-register-account-name-success-view>`;\n            case 'SmartSessionCreated':\n                return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<w3m-smart-session-created-view></w3m-smart-session-created-view>`;\n            case 'SmartSessionList':\n                return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<w3m-smart-session-list-view></w3m-smart-session-list-view>`;\n            case 'SIWXSignMessage':\n                return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<w3m-siwx-sign-message-view></w3m-siwx-sign-message-view>`;\n            default:\n                return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<w3m-connect-view></w3m-connect-view>`;\n        }\n    }\n    onViewChange(newView) {\n        _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_5__.TooltipController.hide();\n        let direction = _utils_ConstantsUtil_js__WEBPACK_IMPORTED_MODULE_3__.ConstantsUtil.VIEW_DIRECTION.Next;\n        const { history } = _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_5__.RouterController.state;\n        if (history.length < this.prevHistoryLength) {\n            direction = _utils_ConstantsUtil_js__WEBPACK_IMPORTED_MODULE_3__.ConstantsUtil.VIEW_DIRECTION.Prev;\n        }\n        this.prevHistoryLength = history.length;\n        this.viewDirection = direction;\n        setTimeout(() => {\n            this.view = newView;\n        }, _utils_ConstantsUtil_js__WEBPACK_IMPORTED_MODULE_3__.ConstantsUtil.ANIMATION_DURATIONS.ViewTransition);\n    }\n    getWrapper() {\n        return this.shadowRoot?.querySelector('div');\n    }\n};\nW3mRouter.styles = _styles_js__WEBPACK_IMPORTED_MODULE_4__[\"default\"];\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.state)()\n], W3mRouter.prototype, \"view\", void 0);\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.state)()\n], W3mRouter.prototype, \"viewDirection\", void 0);\nW3mRouter = __decorate([\n    (0,_reown_appkit_ui__WEBPACK_IMPORTED_MODULE_2__.customElement)('w3m-router')\n], W3mRouter);\n\n//# sourceMappingURL=index.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC1zY2FmZm9sZC11aS9kaXN0L2VzbS9zcmMvbW9kYWwvdzNtLXJvdXRlci9pbmRleC5qcyIsIm1hcHBpbmdzIjoiOzs7Ozs7Ozs7O0FBQUEsa0JBQWtCLFNBQUksSUFBSSxTQUFJO0FBQzlCO0FBQ0E7QUFDQSw2Q0FBNkMsUUFBUTtBQUNyRDtBQUNBO0FBQ3VDO0FBQ0c7QUFDc0M7QUFDL0I7QUFDWTtBQUM1QjtBQUNqQyx3Q0FBd0MsMkNBQVU7QUFDbEQ7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0Esb0JBQW9CLHVFQUFnQjtBQUNwQztBQUNBLDhCQUE4Qix1RUFBZ0I7QUFDOUM7QUFDQTtBQUNBO0FBQ0EsOEJBQThCLDRCQUE0QjtBQUMxRDtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQSxhQUFhLEVBQUUsa0VBQWE7QUFDNUIsU0FBUztBQUNUO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0EsZUFBZSx5Q0FBSSxzREFBc0QsbUJBQW1CO0FBQzVGLFFBQVE7QUFDUjtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0EsdUJBQXVCLHlDQUFJO0FBQzNCO0FBQ0EsdUJBQXVCLHlDQUFJO0FBQzNCO0FBQ0EsdUJBQXVCLHlDQUFJO0FBQzNCO0FBQ0EsdUJBQXVCLHlDQUFJO0FBQzNCO0FBQ0EsdUJBQXVCLHlDQUFJO0FBQzNCO0FBQ0EsdUJBQXVCLHlDQUFJO0FBQzNCO0FBQ0EsdUJBQXVCLHlDQUFJO0FBQzNCO0FBQ0EsdUJBQXVCLHlDQUFJO0FBQzNCO0FBQ0EsdUJBQXVCLHlDQUFJO0FBQzNCO0FBQ0EsdUJBQXVCLHlDQUFJO0FBQzNCO0FBQ0EsdUJBQXVCLHlDQUFJO0FBQzNCO0FBQ0EsdUJBQXVCLHlDQUFJO0FBQzNCO0FBQ0EsdUJBQXVCLHlDQUFJO0FBQzNCO0FBQ0EsdUJBQXVCLHlDQUFJO0FBQzNCO0FBQ0EsdUJBQXVCLHlDQUFJO0FBQzNCO0FBQ0EsdUJBQXVCLHlDQUFJO0FBQzNCO0FBQ0EsdUJBQXVCLHlDQUFJO0FBQzNCO0FBQ0EsdUJBQXVCLHlDQUFJO0FBQzNCO0FBQ0EsdUJBQXVCLHlDQUFJO0FBQzNCO0FBQ0EsdUJBQXVCLHlDQUFJO0FBQzNCO0FBQ0EsdUJBQXVCLHlDQUFJO0FBQzNCO0FBQ0EsdUJBQXVCLHlDQUFJO0FBQzNCO0FBQ0EsdUJBQXVCLHlDQUFJO0FBQzNCO0FBQ0EsdUJBQXVCLHlDQUFJO0FBQzNCO0FBQ0EsdUJBQXVCLHlDQUFJO0FBQzNCO0FBQ0EsdUJBQXVCLHlDQUFJO0FBQzNCO0FBQ0EsdUJBQXVCLHlDQUFJO0FBQzNCO0FBQ0EsdUJBQXVCLHlDQUFJO0FBQzNCO0FBQ0EsdUJBQXVCLHlDQUFJO0FBQzNCO0FBQ0EsdUJBQXVCLHlDQUFJO0FBQzNCO0FBQ0EsdUJBQXVCLHlDQUFJO0FBQzNCO0FBQ0EsdUJBQXVCLHlDQUFJO0FBQzNCO0FBQ0EsdUJBQXVCLHlDQUFJO0FBQzNCO0FBQ0EsdUJBQXVCLHlDQUFJO0FBQzNCO0FBQ0EsdUJBQXVCLHlDQUFJO0FBQzNCO0FBQ0EsdUJBQXVCLHlDQUFJO0FBQzNCO0FBQ0EsdUJBQXVCLHlDQUFJO0FBQzNCO0FBQ0EsdUJBQXVCLHlDQUFJO0FBQzNCO0FBQ0EsdUJBQXVCLHlDQUFJO0FBQzNCO0FBQ0EsdUJBQXVCLHlDQUFJO0FBQzNCO0FBQ0EsdUJBQXVCLHlDQUFJO0FBQzNCO0FBQ0EsdUJBQXVCLHlDQUFJO0FBQzNCO0FBQ0EsdUJBQXVCLHlDQUFJO0FBQzNCO0FBQ0EsdUJBQXVCLHlDQUFJO0FBQzNCO0FBQ0EsdUJBQXVCLHlDQUFJO0FBQzNCO0FBQ0EsdUJBQXVCLHlDQUFJO0FBQzNCO0FBQ0EsdUJBQXVCLHlDQUFJO0FBQzNCO0FBQ0EsdUJBQXVCLHlDQUFJO0FBQzNCO0FBQ0EsdUJBQXVCLHlDQUFJO0FBQzNCO0FBQ0EsdUJBQXVCLHlDQUFJO0FBQzNCO0FBQ0EsdUJBQXVCLHlDQUFJO0FBQzNCO0FBQ0EsdUJBQXVCLHlDQUFJO0FBQzNCO0FBQ0EsdUJBQXVCLHlDQUFJO0FBQzNCO0FBQ0EsdUJBQXVCLHlDQUFJO0FBQzNCO0FBQ0E7QUFDQTtBQUNBLFFBQVEsd0VBQWlCO0FBQ3pCLHdCQUF3QixrRUFBYTtBQUNyQyxnQkFBZ0IsVUFBVSxFQUFFLHVFQUFnQjtBQUM1QztBQUNBLHdCQUF3QixrRUFBYTtBQUNyQztBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0EsU0FBUyxFQUFFLGtFQUFhO0FBQ3hCO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQSxtQkFBbUIsa0RBQU07QUFDekI7QUFDQSxJQUFJLHdEQUFLO0FBQ1Q7QUFDQTtBQUNBLElBQUksd0RBQUs7QUFDVDtBQUNBO0FBQ0EsSUFBSSwrREFBYTtBQUNqQjtBQUNxQjtBQUNyQiIsInNvdXJjZXMiOlsid2VicGFjazovL19OX0UvLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC1zY2FmZm9sZC11aS9kaXN0L2VzbS9zcmMvbW9kYWwvdzNtLXJvdXRlci9pbmRleC5qcz8yM2JmIl0sInNvdXJjZXNDb250ZW50IjpbInZhciBfX2RlY29yYXRlID0gKHRoaXMgJiYgdGhpcy5fX2RlY29yYXRlKSB8fCBmdW5jdGlvbiAoZGVjb3JhdG9ycywgdGFyZ2V0LCBrZXksIGRlc2MpIHtcbiAgICB2YXIgYyA9IGFyZ3VtZW50cy5sZW5ndGgsIHIgPSBjIDwgMyA/IHRhcmdldCA6IGRlc2MgPT09IG51bGwgPyBkZXNjID0gT2JqZWN0LmdldE93blByb3BlcnR5RGVzY3JpcHRvcih0YXJnZXQsIGtleSkgOiBkZXNjLCBkO1xuICAgIGlmICh0eXBlb2YgUmVmbGVjdCA9PT0gXCJvYmplY3RcIiAmJiB0eXBlb2YgUmVmbGVjdC5kZWNvcmF0ZSA9PT0gXCJmdW5jdGlvblwiKSByID0gUmVmbGVjdC5kZWNvcmF0ZShkZWNvcmF0b3JzLCB0YXJnZXQsIGtleSwgZGVzYyk7XG4gICAgZWxzZSBmb3IgKHZhciBpID0gZGVjb3JhdG9ycy5sZW5ndGggLSAxOyBpID49IDA7IGktLSkgaWYgKGQgPSBkZWNvcmF0b3JzW2ldKSByID0gKGMgPCAzID8gZChyKSA6IGMgPiAzID8gZCh0YXJnZXQsIGtleSwgcikgOiBkKHRhcmdldCwga2V5KSkgfHwgcjtcbiAgICByZXR1cm4gYyA+IDMgJiYgciAmJiBPYmplY3QuZGVmaW5lUHJvcGVydHkodGFyZ2V0LCBrZXksIHIpLCByO1xufTtcbmltcG9ydCB7IExpdEVsZW1lbnQsIGh0bWwgfSBmcm9tICdsaXQnO1xuaW1wb3J0IHsgc3RhdGUgfSBmcm9tICdsaXQvZGVjb3JhdG9ycy5qcyc7XG5pbXBvcnQgeyBSb3V0ZXJDb250cm9sbGVyLCBUb29sdGlwQ29udHJvbGxlciB9IGZyb20gJ0ByZW93bi9hcHBraXQtY29udHJvbGxlcnMnO1xuaW1wb3J0IHsgY3VzdG9tRWxlbWVudCB9IGZyb20gJ0ByZW93bi9hcHBraXQtdWknO1xuaW1wb3J0IHsgQ29uc3RhbnRzVXRpbCB9IGZyb20gJy4uLy4uL3V0aWxzL0NvbnN0YW50c1V0aWwuanMnO1xuaW1wb3J0IHN0eWxlcyBmcm9tICcuL3N0eWxlcy5qcyc7XG5sZXQgVzNtUm91dGVyID0gY2xhc3MgVzNtUm91dGVyIGV4dGVuZHMgTGl0RWxlbWVudCB7XG4gICAgY29uc3RydWN0b3IoKSB7XG4gICAgICAgIHN1cGVyKCk7XG4gICAgICAgIHRoaXMucmVzaXplT2JzZXJ2ZXIgPSB1bmRlZmluZWQ7XG4gICAgICAgIHRoaXMucHJldkhlaWdodCA9ICcwcHgnO1xuICAgICAgICB0aGlzLnByZXZIaXN0b3J5TGVuZ3RoID0gMTtcbiAgICAgICAgdGhpcy51bnN1YnNjcmliZSA9IFtdO1xuICAgICAgICB0aGlzLnZpZXcgPSBSb3V0ZXJDb250cm9sbGVyLnN0YXRlLnZpZXc7XG4gICAgICAgIHRoaXMudmlld0RpcmVjdGlvbiA9ICcnO1xuICAgICAgICB0aGlzLnVuc3Vic2NyaWJlLnB1c2goUm91dGVyQ29udHJvbGxlci5zdWJzY3JpYmVLZXkoJ3ZpZXcnLCB2YWwgPT4gdGhpcy5vblZpZXdDaGFuZ2UodmFsKSkpO1xuICAgIH1cbiAgICBmaXJzdFVwZGF0ZWQoKSB7XG4gICAgICAgIHRoaXMucmVzaXplT2JzZXJ2ZXIgPSBuZXcgUmVzaXplT2JzZXJ2ZXIoKFtjb250ZW50XSkgPT4ge1xuICAgICAgICAgICAgY29uc3QgaGVpZ2h0ID0gYCR7Y29udGVudD8uY29udGVudFJlY3QuaGVpZ2h0fXB4YDtcbiAgICAgICAgICAgIGlmICh0aGlzLnByZXZIZWlnaHQgIT09ICcwcHgnKSB7XG4gICAgICAgICAgICAgICAgdGhpcy5zdHlsZS5zZXRQcm9wZXJ0eSgnLS1wcmV2LWhlaWdodCcsIHRoaXMucHJldkhlaWdodCk7XG4gICAgICAgICAgICAgICAgdGhpcy5zdHlsZS5zZXRQcm9wZXJ0eSgnLS1uZXctaGVpZ2h0JywgaGVpZ2h0KTtcbiAgICAgICAgICAgICAgICB0aGlzLnN0eWxlLmFuaW1hdGlvbiA9ICd3M20tdmlldy1oZWlnaHQgMTUwbXMgZm9yd2FyZHMgZWFzZSc7XG4gICAgICAgICAgICAgICAgdGhpcy5zdHlsZS5oZWlnaHQgPSAnYXV0byc7XG4gICAgICAgICAgICB9XG4gICAgICAgICAgICBzZXRUaW1lb3V0KCgpID0+IHtcbiAgICAgICAgICAgICAgICB0aGlzLnByZXZIZWlnaHQgPSBoZWlnaHQ7XG4gICAgICAgICAgICAgICAgdGhpcy5zdHlsZS5hbmltYXRpb24gPSAndW5zZXQnO1xuICAgICAgICAgICAgfSwgQ29uc3RhbnRzVXRpbC5BTklNQVRJT05fRFVSQVRJT05TLk1vZGFsSGVpZ2h0KTtcbiAgICAgICAgfSk7XG4gICAgICAgIHRoaXMucmVzaXplT2JzZXJ2ZXI/Lm9ic2VydmUodGhpcy5nZXRXcmFwcGVyKCkpO1xuICAgIH1cbiAgICBkaXNjb25uZWN0ZWRDYWxsYmFjaygpIHtcbiAgICAgICAgdGhpcy5yZXNpemVPYnNlcnZlcj8udW5vYnNlcnZlKHRoaXMuZ2V0V3JhcHBlcigpKTtcbiAgICAgICAgdGhpcy51bnN1YnNjcmliZS5mb3JFYWNoKHVuc3Vic2NyaWJlID0+IHVuc3Vic2NyaWJlKCkpO1xuICAgIH1cbiAgICByZW5kZXIoKSB7XG4gICAgICAgIHJldHVybiBodG1sIGA8ZGl2IGNsYXNzPVwidzNtLXJvdXRlci1jb250YWluZXJcIiB2aWV3LWRpcmVjdGlvbj1cIiR7dGhpcy52aWV3RGlyZWN0aW9ufVwiPlxuICAgICAgJHt0aGlzLnZpZXdUZW1wbGF0ZSgpfVxuICAgIDwvZGl2PmA7XG4gICAgfVxuICAgIHZpZXdUZW1wbGF0ZSgpIHtcbiAgICAgICAgc3dpdGNoICh0aGlzLnZpZXcpIHtcbiAgICAgICAgICAgIGNhc2UgJ0FjY291bnRTZXR0aW5ncyc6XG4gICAgICAgICAgICAgICAgcmV0dXJuIGh0bWwgYDx3M20tYWNjb3VudC1zZXR0aW5ncy12aWV3PjwvdzNtLWFjY291bnQtc2V0dGluZ3Mtdmlldz5gO1xuICAgICAgICAgICAgY2FzZSAnQWNjb3VudCc6XG4gICAgICAgICAgICAgICAgcmV0dXJuIGh0bWwgYDx3M20tYWNjb3VudC12aWV3PjwvdzNtLWFjY291bnQtdmlldz5gO1xuICAgICAgICAgICAgY2FzZSAnQWxsV2FsbGV0cyc6XG4gICAgICAgICAgICAgICAgcmV0dXJuIGh0bWwgYDx3M20tYWxsLXdhbGxldHMtdmlldz48L3czbS1hbGwtd2FsbGV0cy12aWV3PmA7XG4gICAgICAgICAgICBjYXNlICdBcHByb3ZlVHJhbnNhY3Rpb24nOlxuICAgICAgICAgICAgICAgIHJldHVybiBodG1sIGA8dzNtLWFwcHJvdmUtdHJhbnNhY3Rpb24tdmlldz48L3czbS1hcHByb3ZlLXRyYW5zYWN0aW9uLXZpZXc+YDtcbiAgICAgICAgICAgIGNhc2UgJ0J1eUluUHJvZ3Jlc3MnOlxuICAgICAgICAgICAgICAgIHJldHVybiBodG1sIGA8dzNtLWJ1eS1pbi1wcm9ncmVzcy12aWV3PjwvdzNtLWJ1eS1pbi1wcm9ncmVzcy12aWV3PmA7XG4gICAgICAgICAgICBjYXNlICdDaG9vc2VBY2NvdW50TmFtZSc6XG4gICAgICAgICAgICAgICAgcmV0dXJuIGh0bWwgYDx3M20tY2hvb3NlLWFjY291bnQtbmFtZS12aWV3PjwvdzNtLWNob29zZS1hY2NvdW50LW5hbWUtdmlldz5gO1xuICAgICAgICAgICAgY2FzZSAnQ29ubmVjdCc6XG4gICAgICAgICAgICAgICAgcmV0dXJuIGh0bWwgYDx3M20tY29ubmVjdC12aWV3PjwvdzNtLWNvbm5lY3Qtdmlldz5gO1xuICAgICAgICAgICAgY2FzZSAnQ3JlYXRlJzpcbiAgICAgICAgICAgICAgICByZXR1cm4gaHRtbCBgPHczbS1jb25uZWN0LXZpZXcgd2FsbGV0R3VpZGU9XCJleHBsb3JlXCI+PC93M20tY29ubmVjdC12aWV3PmA7XG4gICAgICAgICAgICBjYXNlICdDb25uZWN0aW5nV2FsbGV0Q29ubmVjdCc6XG4gICAgICAgICAgICAgICAgcmV0dXJuIGh0bWwgYDx3M20tY29ubmVjdGluZy13Yy12aWV3PjwvdzNtLWNvbm5lY3Rpbmctd2Mtdmlldz5gO1xuICAgICAgICAgICAgY2FzZSAnQ29ubmVjdGluZ1dhbGxldENvbm5lY3RCYXNpYyc6XG4gICAgICAgICAgICAgICAgcmV0dXJuIGh0bWwgYDx3M20tY29ubmVjdGluZy13Yy1iYXNpYy12aWV3PjwvdzNtLWNvbm5lY3Rpbmctd2MtYmFzaWMtdmlldz5gO1xuICAgICAgICAgICAgY2FzZSAnQ29ubmVjdGluZ0V4dGVybmFsJzpcbiAgICAgICAgICAgICAgICByZXR1cm4gaHRtbCBgPHczbS1jb25uZWN0aW5nLWV4dGVybmFsLXZpZXc+PC93M20tY29ubmVjdGluZy1leHRlcm5hbC12aWV3PmA7XG4gICAgICAgICAgICBjYXNlICdDb25uZWN0aW5nU2l3ZSc6XG4gICAgICAgICAgICAgICAgcmV0dXJuIGh0bWwgYDx3M20tY29ubmVjdGluZy1zaXdlLXZpZXc+PC93M20tY29ubmVjdGluZy1zaXdlLXZpZXc+YDtcbiAgICAgICAgICAgIGNhc2UgJ0Nvbm5lY3RXYWxsZXRzJzpcbiAgICAgICAgICAgICAgICByZXR1cm4gaHRtbCBgPHczbS1jb25uZWN0LXdhbGxldHMtdmlldz48L3czbS1jb25uZWN0LXdhbGxldHMtdmlldz5gO1xuICAgICAgICAgICAgY2FzZSAnQ29ubmVjdFNvY2lhbHMnOlxuICAgICAgICAgICAgICAgIHJldHVybiBodG1sIGA8dzNtLWNvbm5lY3Qtc29jaWFscy12aWV3PjwvdzNtLWNvbm5lY3Qtc29jaWFscy12aWV3PmA7XG4gICAgICAgICAgICBjYXNlICdDb25uZWN0aW5nU29jaWFsJzpcbiAgICAgICAgICAgICAgICByZXR1cm4gaHRtbCBgPHczbS1jb25uZWN0aW5nLXNvY2lhbC12aWV3PjwvdzNtLWNvbm5lY3Rpbmctc29jaWFsLXZpZXc+YDtcbiAgICAgICAgICAgIGNhc2UgJ0Rvd25sb2Fkcyc6XG4gICAgICAgICAgICAgICAgcmV0dXJuIGh0bWwgYDx3M20tZG93bmxvYWRzLXZpZXc+PC93M20tZG93bmxvYWRzLXZpZXc+YDtcbiAgICAgICAgICAgIGNhc2UgJ0VtYWlsTG9naW4nOlxuICAgICAgICAgICAgICAgIHJldHVybiBodG1sIGA8dzNtLWVtYWlsLWxvZ2luLXZpZXc+PC93M20tZW1haWwtbG9naW4tdmlldz5gO1xuICAgICAgICAgICAgY2FzZSAnRW1haWxWZXJpZnlPdHAnOlxuICAgICAgICAgICAgICAgIHJldHVybiBodG1sIGA8dzNtLWVtYWlsLXZlcmlmeS1vdHAtdmlldz48L3czbS1lbWFpbC12ZXJpZnktb3RwLXZpZXc+YDtcbiAgICAgICAgICAgIGNhc2UgJ0VtYWlsVmVyaWZ5RGV2aWNlJzpcbiAgICAgICAgICAgICAgICByZXR1cm4gaHRtbCBgPHczbS1lbWFpbC12ZXJpZnktZGV2aWNlLXZpZXc+PC93M20tZW1haWwtdmVyaWZ5LWRldmljZS12aWV3PmA7XG4gICAgICAgICAgICBjYXNlICdHZXRXYWxsZXQnOlxuICAgICAgICAgICAgICAgIHJldHVybiBodG1sIGA8dzNtLWdldC13YWxsZXQtdmlldz48L3czbS1nZXQtd2FsbGV0LXZpZXc+YDtcbiAgICAgICAgICAgIGNhc2UgJ05ldHdvcmtzJzpcbiAgICAgICAgICAgICAgICByZXR1cm4gaHRtbCBgPHczbS1uZXR3b3Jrcy12aWV3PjwvdzNtLW5ldHdvcmtzLXZpZXc+YDtcbiAgICAgICAgICAgIGNhc2UgJ1N3aXRjaE5ldHdvcmsnOlxuICAgICAgICAgICAgICAgIHJldHVybiBodG1sIGA8dzNtLW5ldHdvcmstc3dpdGNoLXZpZXc+PC93M20tbmV0d29yay1zd2l0Y2gtdmlldz5gO1xuICAgICAgICAgICAgY2FzZSAnUHJvZmlsZSc6XG4gICAgICAgICAgICAgICAgcmV0dXJuIGh0bWwgYDx3M20tcHJvZmlsZS12aWV3PjwvdzNtLXByb2ZpbGUtdmlldz5gO1xuICAgICAgICAgICAgY2FzZSAnU3dpdGNoQWRkcmVzcyc6XG4gICAgICAgICAgICAgICAgcmV0dXJuIGh0bWwgYDx3M20tc3dpdGNoLWFkZHJlc3Mtdmlldz48L3czbS1zd2l0Y2gtYWRkcmVzcy12aWV3PmA7XG4gICAgICAgICAgICBjYXNlICdUcmFuc2FjdGlvbnMnOlxuICAgICAgICAgICAgICAgIHJldHVybiBodG1sIGA8dzNtLXRyYW5zYWN0aW9ucy12aWV3PjwvdzNtLXRyYW5zYWN0aW9ucy12aWV3PmA7XG4gICAgICAgICAgICBjYXNlICdPblJhbXBQcm92aWRlcnMnOlxuICAgICAgICAgICAgICAgIHJldHVybiBodG1sIGA8dzNtLW9ucmFtcC1wcm92aWRlcnMtdmlldz48L3czbS1vbnJhbXAtcHJvdmlkZXJzLXZpZXc+YDtcbiAgICAgICAgICAgIGNhc2UgJ09uUmFtcEFjdGl2aXR5JzpcbiAgICAgICAgICAgICAgICByZXR1cm4gaHRtbCBgPHczbS1vbnJhbXAtYWN0aXZpdHktdmlldz48L3czbS1vbnJhbXAtYWN0aXZpdHktdmlldz5gO1xuICAgICAgICAgICAgY2FzZSAnT25SYW1wVG9rZW5TZWxlY3QnOlxuICAgICAgICAgICAgICAgIHJldHVybiBodG1sIGA8dzNtLW9ucmFtcC10b2tlbi1zZWxlY3Qtdmlldz48L3czbS1vbnJhbXAtdG9rZW4tc2VsZWN0LXZpZXc+YDtcbiAgICAgICAgICAgIGNhc2UgJ09uUmFtcEZpYXRTZWxlY3QnOlxuICAgICAgICAgICAgICAgIHJldHVybiBodG1sIGA8dzNtLW9ucmFtcC1maWF0LXNlbGVjdC12aWV3PjwvdzNtLW9ucmFtcC1maWF0LXNlbGVjdC12aWV3PmA7XG4gICAgICAgICAgICBjYXNlICdVcGdyYWRlRW1haWxXYWxsZXQnOlxuICAgICAgICAgICAgICAgIHJldHVybiBodG1sIGA8dzNtLXVwZ3JhZGUtd2FsbGV0LXZpZXc+PC93M20tdXBncmFkZS13YWxsZXQtdmlldz5gO1xuICAgICAgICAgICAgY2FzZSAnVXBkYXRlRW1haWxXYWxsZXQnOlxuICAgICAgICAgICAgICAgIHJldHVybiBodG1sIGA8dzNtLXVwZGF0ZS1lbWFpbC13YWxsZXQtdmlldz48L3czbS11cGRhdGUtZW1haWwtd2FsbGV0LXZpZXc+YDtcbiAgICAgICAgICAgIGNhc2UgJ1VwZGF0ZUVtYWlsUHJpbWFyeU90cCc6XG4gICAgICAgICAgICAgICAgcmV0dXJuIGh0bWwgYDx3M20tdXBkYXRlLWVtYWlsLXByaW1hcnktb3RwLXZpZXc+PC93M20tdXBkYXRlLWVtYWlsLXByaW1hcnktb3RwLXZpZXc+YDtcbiAgICAgICAgICAgIGNhc2UgJ1VwZGF0ZUVtYWlsU2Vjb25kYXJ5T3RwJzpcbiAgICAgICAgICAgICAgICByZXR1cm4gaHRtbCBgPHczbS11cGRhdGUtZW1haWwtc2Vjb25kYXJ5LW90cC12aWV3PjwvdzNtLXVwZGF0ZS1lbWFpbC1zZWNvbmRhcnktb3RwLXZpZXc+YDtcbiAgICAgICAgICAgIGNhc2UgJ1Vuc3VwcG9ydGVkQ2hhaW4nOlxuICAgICAgICAgICAgICAgIHJldHVybiBodG1sIGA8dzNtLXVuc3VwcG9ydGVkLWNoYWluLXZpZXc+PC93M20tdW5zdXBwb3J0ZWQtY2hhaW4tdmlldz5gO1xuICAgICAgICAgICAgY2FzZSAnU3dhcCc6XG4gICAgICAgICAgICAgICAgcmV0dXJuIGh0bWwgYDx3M20tc3dhcC12aWV3PjwvdzNtLXN3YXAtdmlldz5gO1xuICAgICAgICAgICAgY2FzZSAnU3dhcFNlbGVjdFRva2VuJzpcbiAgICAgICAgICAgICAgICByZXR1cm4gaHRtbCBgPHczbS1zd2FwLXNlbGVjdC10b2tlbi12aWV3PjwvdzNtLXN3YXAtc2VsZWN0LXRva2VuLXZpZXc+YDtcbiAgICAgICAgICAgIGNhc2UgJ1N3YXBQcmV2aWV3JzpcbiAgICAgICAgICAgICAgICByZXR1cm4gaHRtbCBgPHczbS1zd2FwLXByZXZpZXctdmlldz48L3czbS1zd2FwLXByZXZpZXctdmlldz5gO1xuICAgICAgICAgICAgY2FzZSAnV2FsbGV0U2VuZCc6XG4gICAgICAgICAgICAgICAgcmV0dXJuIGh0bWwgYDx3M20td2FsbGV0LXNlbmQtdmlldz48L3czbS13YWxsZXQtc2VuZC12aWV3PmA7XG4gICAgICAgICAgICBjYXNlICdXYWxsZXRTZW5kU2VsZWN0VG9rZW4nOlxuICAgICAgICAgICAgICAgIHJldHVybiBodG1sIGA8dzNtLXdhbGxldC1zZW5kLXNlbGVjdC10b2tlbi12aWV3PjwvdzNtLXdhbGxldC1zZW5kLXNlbGVjdC10b2tlbi12aWV3PmA7XG4gICAgICAgICAgICBjYXNlICdXYWxsZXRTZW5kUHJldmlldyc6XG4gICAgICAgICAgICAgICAgcmV0dXJuIGh0bWwgYDx3M20td2FsbGV0LXNlbmQtcHJldmlldy12aWV3PjwvdzNtLXdhbGxldC1zZW5kLXByZXZpZXctdmlldz5gO1xuICAgICAgICAgICAgY2FzZSAnV2hhdElzQUJ1eSc6XG4gICAgICAgICAgICAgICAgcmV0dXJuIGh0bWwgYDx3M20td2hhdC1pcy1hLWJ1eS12aWV3PjwvdzNtLXdoYXQtaXMtYS1idXktdmlldz5gO1xuICAgICAgICAgICAgY2FzZSAnV2FsbGV0UmVjZWl2ZSc6XG4gICAgICAgICAgICAgICAgcmV0dXJuIGh0bWwgYDx3M20td2FsbGV0LXJlY2VpdmUtdmlldz48L3czbS13YWxsZXQtcmVjZWl2ZS12aWV3PmA7XG4gICAgICAgICAgICBjYXNlICdXYWxsZXRDb21wYXRpYmxlTmV0d29ya3MnOlxuICAgICAgICAgICAgICAgIHJldHVybiBodG1sIGA8dzNtLXdhbGxldC1jb21wYXRpYmxlLW5ldHdvcmtzLXZpZXc+PC93M20td2FsbGV0LWNvbXBhdGlibGUtbmV0d29ya3Mtdmlldz5gO1xuICAgICAgICAgICAgY2FzZSAnV2hhdElzQVdhbGxldCc6XG4gICAgICAgICAgICAgICAgcmV0dXJuIGh0bWwgYDx3M20td2hhdC1pcy1hLXdhbGxldC12aWV3PjwvdzNtLXdoYXQtaXMtYS13YWxsZXQtdmlldz5gO1xuICAgICAgICAgICAgY2FzZSAnQ29ubmVjdGluZ011bHRpQ2hhaW4nOlxuICAgICAgICAgICAgICAgIHJldHVybiBodG1sIGA8dzNtLWNvbm5lY3RpbmctbXVsdGktY2hhaW4tdmlldz48L3czbS1jb25uZWN0aW5nLW11bHRpLWNoYWluLXZpZXc+YDtcbiAgICAgICAgICAgIGNhc2UgJ1doYXRJc0FOZXR3b3JrJzpcbiAgICAgICAgICAgICAgICByZXR1cm4gaHRtbCBgPHczbS13aGF0LWlzLWEtbmV0d29yay12aWV3PjwvdzNtLXdoYXQtaXMtYS1uZXR3b3JrLXZpZXc+YDtcbiAgICAgICAgICAgIGNhc2UgJ0Nvbm5lY3RpbmdGYXJjYXN0ZXInOlxuICAgICAgICAgICAgICAgIHJldHVybiBodG1sIGA8dzNtLWNvbm5lY3RpbmctZmFyY2FzdGVyLXZpZXc+PC93M20tY29ubmVjdGluZy1mYXJjYXN0ZXItdmlldz5gO1xuICAgICAgICAgICAgY2FzZSAnU3dpdGNoQWN0aXZlQ2hhaW4nOlxuICAgICAgICAgICAgICAgIHJldHVybiBodG1sIGA8dzNtLXN3aXRjaC1hY3RpdmUtY2hhaW4tdmlldz48L3czbS1zd2l0Y2gtYWN0aXZlLWNoYWluLXZpZXc+YDtcbiAgICAgICAgICAgIGNhc2UgJ1JlZ2lzdGVyQWNjb3VudE5hbWUnOlxuICAgICAgICAgICAgICAgIHJldHVybiBodG1sIGA8dzNtLXJlZ2lzdGVyLWFjY291bnQtbmFtZS12aWV3PjwvdzNtLXJlZ2lzdGVyLWFjY291bnQtbmFtZS12aWV3PmA7XG4gICAgICAgICAgICBjYXNlICdSZWdpc3RlckFjY291bnROYW1lU3VjY2Vzcyc6XG4gICAgICAgICAgICAgICAgcmV0dXJuIGh0bWwgYDx3M20tcmVnaXN0ZXItYWNjb3VudC1uYW1lLXN1Y2Nlc3Mtdmlldz48L3czbS1yZWdpc3Rlci1hY2NvdW50LW5hbWUtc3VjY2Vzcy12aWV3PmA7XG4gICAgICAgICAgICBjYXNlICdTbWFydFNlc3Npb25DcmVhdGVkJzpcbiAgICAgICAgICAgICAgICByZXR1cm4gaHRtbCBgPHczbS1zbWFydC1zZXNzaW9uLWNyZWF0ZWQtdmlldz48L3czbS1zbWFydC1zZXNzaW9uLWNyZWF0ZWQtdmlldz5gO1xuICAgICAgICAgICAgY2FzZSAnU21hcnRTZXNzaW9uTGlzdCc6XG4gICAgICAgICAgICAgICAgcmV0dXJuIGh0bWwgYDx3M20tc21hcnQtc2Vzc2lvbi1saXN0LXZpZXc+PC93M20tc21hcnQtc2Vzc2lvbi1saXN0LXZpZXc+YDtcbiAgICAgICAgICAgIGNhc2UgJ1NJV1hTaWduTWVzc2FnZSc6XG4gICAgICAgICAgICAgICAgcmV0dXJuIGh0bWwgYDx3M20tc2l3eC1zaWduLW1lc3NhZ2Utdmlldz48L3czbS1zaXd4LXNpZ24tbWVzc2FnZS12aWV3PmA7XG4gICAgICAgICAgICBkZWZhdWx0OlxuICAgICAgICAgICAgICAgIHJldHVybiBodG1sIGA8dzNtLWNvbm5lY3Qtdmlldz48L3czbS1jb25uZWN0LXZpZXc+YDtcbiAgICAgICAgfVxuICAgIH1cbiAgICBvblZpZXdDaGFuZ2UobmV3Vmlldykge1xuICAgICAgICBUb29sdGlwQ29udHJvbGxlci5oaWRlKCk7XG4gICAgICAgIGxldCBkaXJlY3Rpb24gPSBDb25zdGFudHNVdGlsLlZJRVdfRElSRUNUSU9OLk5leHQ7XG4gICAgICAgIGNvbnN0IHsgaGlzdG9yeSB9ID0gUm91dGVyQ29udHJvbGxlci5zdGF0ZTtcbiAgICAgICAgaWYgKGhpc3RvcnkubGVuZ3RoIDwgdGhpcy5wcmV2SGlzdG9yeUxlbmd0aCkge1xuICAgICAgICAgICAgZGlyZWN0aW9uID0gQ29uc3RhbnRzVXRpbC5WSUVXX0RJUkVDVElPTi5QcmV2O1xuICAgICAgICB9XG4gICAgICAgIHRoaXMucHJldkhpc3RvcnlMZW5ndGggPSBoaXN0b3J5Lmxlbmd0aDtcbiAgICAgICAgdGhpcy52aWV3RGlyZWN0aW9uID0gZGlyZWN0aW9uO1xuICAgICAgICBzZXRUaW1lb3V0KCgpID0+IHtcbiAgICAgICAgICAgIHRoaXMudmlldyA9IG5ld1ZpZXc7XG4gICAgICAgIH0sIENvbnN0YW50c1V0aWwuQU5JTUFUSU9OX0RVUkFUSU9OUy5WaWV3VHJhbnNpdGlvbik7XG4gICAgfVxuICAgIGdldFdyYXBwZXIoKSB7XG4gICAgICAgIHJldHVybiB0aGlzLnNoYWRvd1Jvb3Q/LnF1ZXJ5U2VsZWN0b3IoJ2RpdicpO1xuICAgIH1cbn07XG5XM21Sb3V0ZXIuc3R5bGVzID0gc3R5bGVzO1xuX19kZWNvcmF0ZShbXG4gICAgc3RhdGUoKVxuXSwgVzNtUm91dGVyLnByb3RvdHlwZSwgXCJ2aWV3XCIsIHZvaWQgMCk7XG5fX2RlY29yYXRlKFtcbiAgICBzdGF0ZSgpXG5dLCBXM21Sb3V0ZXIucHJvdG90eXBlLCBcInZpZXdEaXJlY3Rpb25cIiwgdm9pZCAwKTtcblczbVJvdXRlciA9IF9fZGVjb3JhdGUoW1xuICAgIGN1c3RvbUVsZW1lbnQoJ3czbS1yb3V0ZXInKVxuXSwgVzNtUm91dGVyKTtcbmV4cG9ydCB7IFczbVJvdXRlciB9O1xuLy8jIHNvdXJjZU1hcHBpbmdVUkw9aW5kZXguanMubWFwIl0sIm5hbWVzIjpbXSwic291cmNlUm9vdCI6IiJ9\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-scaffold-ui/dist/esm/src/modal/w3m-router/index.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@reown/appkit-scaffold-ui/dist/esm/src/modal/w3m-router/styles.js":
-/*!****************************************************************************************!*\
-  !*** ./node_modules/@reown/appkit-scaffold-ui/dist/esm/src/modal/w3m-router/styles.js ***!
-  \****************************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n\n/* harmony default export */ __webpack_exports__[\"default\"] = ((0,lit__WEBPACK_IMPORTED_MODULE_0__.css) `\n  :host {\n    --prev-height: 0px;\n    --new-height: 0px;\n    display: block;\n  }\n\n  div.w3m-router-container {\n    transform: translateY(0);\n    opacity: 1;\n  }\n\n  div.w3m-router-container[view-direction='prev'] {\n    animation:\n      slide-left-out 150ms forwards ease,\n      slide-left-in 150ms forwards ease;\n    animation-delay: 0ms, 200ms;\n  }\n\n  div.w3m-router-container[view-direction='next'] {\n    animation:\n      slide-right-out 150ms forwards ease,\n      slide-right-in 150ms forwards ease;\n    animation-delay: 0ms, 200ms;\n  }\n\n  @keyframes slide-left-out {\n    from {\n      transform: translateX(0px);\n      opacity: 1;\n    }\n    to {\n      transform: translateX(10px);\n      opacity: 0;\n    }\n  }\n\n  @keyframes slide-left-in {\n    from {\n      transform: translateX(-10px);\n      opacity: 0;\n    }\n    to {\n      transform: translateX(0);\n      opacity: 1;\n    }\n  }\n\n  @keyframes slide-right-out {\n    from {\n      transform: translateX(0px);\n      opacity: 1;\n    }\n    to {\n      transform: translateX(-10px);\n      opacity: 0;\n    }\n  }\n\n  @keyframes slide-right-in {\n    from {\n      transform: translateX(10px);\n      opacity: 0;\n    }\n    to {\n      transform: translateX(0);\n      opacity: 1;\n    }\n  }\n`);\n//# sourceMappingURL=styles.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC1zY2FmZm9sZC11aS9kaXN0L2VzbS9zcmMvbW9kYWwvdzNtLXJvdXRlci9zdHlsZXMuanMiLCJtYXBwaW5ncyI6Ijs7QUFBMEI7QUFDMUIsK0RBQWUsd0NBQUc7QUFDbEI7QUFDQTtBQUNBO0FBQ0E7QUFDQTs7QUFFQTtBQUNBO0FBQ0E7QUFDQTs7QUFFQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7O0FBRUE7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBOztBQUVBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBOztBQUVBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBOztBQUVBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBOztBQUVBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0EsQ0FBQyxFQUFDO0FBQ0YiLCJzb3VyY2VzIjpbIndlYnBhY2s6Ly9fTl9FLy4vbm9kZV9tb2R1bGVzL0ByZW93bi9hcHBraXQtc2NhZmZvbGQtdWkvZGlzdC9lc20vc3JjL21vZGFsL3czbS1yb3V0ZXIvc3R5bGVzLmpzP2E4MWMiXSwic291cmNlc0NvbnRlbnQiOlsiaW1wb3J0IHsgY3NzIH0gZnJvbSAnbGl0JztcbmV4cG9ydCBkZWZhdWx0IGNzcyBgXG4gIDpob3N0IHtcbiAgICAtLXByZXYtaGVpZ2h0OiAwcHg7XG4gICAgLS1uZXctaGVpZ2h0OiAwcHg7XG4gICAgZGlzcGxheTogYmxvY2s7XG4gIH1cblxuICBkaXYudzNtLXJvdXRlci1jb250YWluZXIge1xuICAgIHRyYW5zZm9ybTogdHJhbnNsYXRlWSgwKTtcbiAgICBvcGFjaXR5OiAxO1xuICB9XG5cbiAgZGl2LnczbS1yb3V0ZXItY29udGFpbmVyW3ZpZXctZGlyZWN0aW9uPSdwcmV2J10ge1xuICAgIGFuaW1hdGlvbjpcbiAgICAgIHNsaWRlLWxlZnQtb3V0IDE1MG1zIGZvcndhcmRzIGVhc2UsXG4gICAgICBzbGlkZS1sZWZ0LWluIDE1MG1zIGZvcndhcmRzIGVhc2U7XG4gICAgYW5pbWF0aW9uLWRlbGF5OiAwbXMsIDIwMG1zO1xuICB9XG5cbiAgZGl2LnczbS1yb3V0ZXItY29udGFpbmVyW3ZpZXctZGlyZWN0aW9uPSduZXh0J10ge1xuICAgIGFuaW1hdGlvbjpcbiAgICAgIHNsaWRlLXJpZ2h0LW91dCAxNTBtcyBmb3J3YXJkcyBlYXNlLFxuICAgICAgc2xpZGUtcmlnaHQtaW4gMTUwbXMgZm9yd2FyZHMgZWFzZTtcbiAgICBhbmltYXRpb24tZGVsYXk6IDBtcywgMjAwbXM7XG4gIH1cblxuICBAa2V5ZnJhbWVzIHNsaWRlLWxlZnQtb3V0IHtcbiAgICBmcm9tIHtcbiAgICAgIHRyYW5zZm9ybTogdHJhbnNsYXRlWCgwcHgpO1xuICAgICAgb3BhY2l0eTogMTtcbiAgICB9XG4gICAgdG8ge1xuICAgICAgdHJhbnNmb3JtOiB0cmFuc2xhdGVYKDEwcHgpO1xuICAgICAgb3BhY2l0eTogMDtcbiAgICB9XG4gIH1cblxuICBAa2V5ZnJhbWVzIHNsaWRlLWxlZnQtaW4ge1xuICAgIGZyb20ge1xuICAgICAgdHJhbnNmb3JtOiB0cmFuc2xhdGVYKC0xMHB4KTtcbiAgICAgIG9wYWNpdHk6IDA7XG4gICAgfVxuICAgIHRvIHtcbiAgICAgIHRyYW5zZm9ybTogdHJhbnNsYXRlWCgwKTtcbiAgICAgIG9wYWNpdHk6IDE7XG4gICAgfVxuICB9XG5cbiAgQGtleWZyYW1lcyBzbGlkZS1yaWdodC1vdXQge1xuICAgIGZyb20ge1xuICAgICAgdHJhbnNmb3JtOiB0cmFuc2xhdGVYKDBweCk7XG4gICAgICBvcGFjaXR5OiAxO1xuICAgIH1cbiAgICB0byB7XG4gICAgICB0cmFuc2Zvcm06IHRyYW5zbGF0ZVgoLTEwcHgpO1xuICAgICAgb3BhY2l0eTogMDtcbiAgICB9XG4gIH1cblxuICBAa2V5ZnJhbWVzIHNsaWRlLXJpZ2h0LWluIHtcbiAgICBmcm9tIHtcbiAgICAgIHRyYW5zZm9ybTogdHJhbnNsYXRlWCgxMHB4KTtcbiAgICAgIG9wYWNpdHk6IDA7XG4gICAgfVxuICAgIHRvIHtcbiAgICAgIHRyYW5zZm9ybTogdHJhbnNsYXRlWCgwKTtcbiAgICAgIG9wYWNpdHk6IDE7XG4gICAgfVxuICB9XG5gO1xuLy8jIHNvdXJjZU1hcHBpbmdVUkw9c3R5bGVzLmpzLm1hcCJdLCJuYW1lcyI6W10sInNvdXJjZVJvb3QiOiIifQ==\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-scaffold-ui/dist/esm/src/modal/w3m-router/styles.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@reown/appkit-scaffold-ui/dist/esm/src/partials/w3m-alertbar/index.js":
-/*!********************************************************************************************!*\
-  !*** ./node_modules/@reown/appkit-scaffold-ui/dist/esm/src/partials/w3m-alertbar/index.js ***!
-  \********************************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   W3mAlertBar: function() { return /* binding */ W3mAlertBar; },\n/* harmony export */   presets: function() { return /* binding */ presets; }\n/* harmony export */ });\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n/* harmony import */ var lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__ = __webpack_require__(/*! lit/decorators.js */ \"./node_modules/lit/decorators.js\");\n/* harmony import */ var _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_5__ = __webpack_require__(/*! @reown/appkit-controllers */ \"./node_modules/@reown/appkit-controllers/dist/esm/exports/index.js\");\n/* harmony import */ var _reown_appkit_ui__WEBPACK_IMPORTED_MODULE_2__ = __webpack_require__(/*! @reown/appkit-ui */ \"./node_modules/@reown/appkit-ui/dist/esm/exports/index.js\");\n/* harmony import */ var _reown_appkit_ui_wui_alertbar__WEBPACK_IMPORTED_MODULE_3__ = __webpack_require__(/*! @reown/appkit-ui/wui-alertbar */ \"./node_modules/@reown/appkit-ui/dist/esm/exports/wui-alertbar.js\");\n/* harmony import */ var _styles_js__WEBPACK_IMPORTED_MODULE_4__ = __webpack_require__(/*! ./styles.js */ \"./node_modules/@reown/appkit-scaffold-ui/dist/esm/src/partials/w3m-alertbar/styles.js\");\nvar __decorate = (undefined && undefined.__decorate) || function (decorators, target, key, desc) {\n    var c = arguments.length, r = c < 3 ? target : desc === null ? desc = Object.getOwnPropertyDescriptor(target, key) : desc, d;\n    if (typeof Reflect === \"object\" && typeof Reflect.decorate === \"function\") r = Reflect.decorate(decorators, target, key, desc);\n    else for (var i = decorators.length - 1; i >= 0; i--) if (d = decorators[i]) r = (c < 3 ? d(r) : c > 3 ? d(target, key, r) : d(target, key)) || r;\n    return c > 3 && r && Object.defineProperty(target, key, r), r;\n};\n\n\n\n\n\n\nconst presets = {\n    info: {\n        backgroundColor: 'fg-350',\n        iconColor: 'fg-325',\n        icon: 'info'\n    },\n    success: {\n        backgroundColor: 'success-glass-reown-020',\n        iconColor: 'success-125',\n        icon: 'checkmark'\n    },\n    warning: {\n        backgroundColor: 'warning-glass-reown-020',\n        iconColor: 'warning-100',\n        icon: 'warningCircle'\n    },\n    error: {\n        backgroundColor: 'error-glass-reown-020',\n        iconColor: 'error-125',\n        icon: 'exclamationTriangle'\n    }\n};\nlet W3mAlertBar = class W3mAlertBar extends lit__WEBPACK_IMPORTED_MODULE_0__.LitElement {\n    constructor() {\n        super();\n        this.unsubscribe = [];\n        this.open = _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_5__.AlertController.state.open;\n        this.onOpen(true);\n        this.unsubscribe.push(_reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_5__.AlertController.subscribeKey('open', val => {\n            this.open = val;\n            this.onOpen(false);\n        }));\n    }\n    disconnectedCallback() {\n        this.unsubscribe.forEach(unsubscribe => unsubscribe());\n    }\n    render() {\n        const { message, variant } = _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_5__.AlertController.state;\n        const preset = presets[variant];\n        return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `\n      <wui-alertbar\n        message=${message}\n        backgroundColor=${preset?.backgroundColor}\n        iconColor=${preset?.iconColor}\n        icon=${preset?.icon}\n      ></wui-alertbar>\n    `;\n    }\n    onOpen(isMounted) {\n        if (this.open) {\n            this.animate([\n                { opacity: 0, transform: 'scale(0.85)' },\n                { opacity: 1, transform: 'scale(1)' }\n            ], {\n                duration: 150,\n                fill: 'forwards',\n                easing: 'ease'\n            });\n            this.style.cssText = `pointer-events: auto`;\n        }\n        else if (!isMounted) {\n            this.animate([\n                { opacity: 1, transform: 'scale(1)' },\n                { opacity: 0, transform: 'scale(0.85)' }\n            ], {\n                duration: 150,\n                fill: 'forwards',\n                easing: 'ease'\n            });\n            this.style.cssText = `pointer-events: none`;\n        }\n    }\n};\nW3mAlertBar.styles = _styles_js__WEBPACK_IMPORTED_MODULE_4__[\"default\"];\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.state)()\n], W3mAlertBar.prototype, \"open\", void 0);\nW3mAlertBar = __decorate([\n    (0,_reown_appkit_ui__WEBPACK_IMPORTED_MODULE_2__.customElement)('w3m-alertbar')\n], W3mAlertBar);\n\n//# sourceMappingURL=index.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC1zY2FmZm9sZC11aS9kaXN0L2VzbS9zcmMvcGFydGlhbHMvdzNtLWFsZXJ0YmFyL2luZGV4LmpzIiwibWFwcGluZ3MiOiI7Ozs7Ozs7Ozs7O0FBQUEsa0JBQWtCLFNBQUksSUFBSSxTQUFJO0FBQzlCO0FBQ0E7QUFDQSw2Q0FBNkMsUUFBUTtBQUNyRDtBQUNBO0FBQ3VDO0FBQ0c7QUFDa0I7QUFDWDtBQUNWO0FBQ047QUFDMUI7QUFDUDtBQUNBO0FBQ0E7QUFDQTtBQUNBLEtBQUs7QUFDTDtBQUNBO0FBQ0E7QUFDQTtBQUNBLEtBQUs7QUFDTDtBQUNBO0FBQ0E7QUFDQTtBQUNBLEtBQUs7QUFDTDtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQSw0Q0FBNEMsMkNBQVU7QUFDdEQ7QUFDQTtBQUNBO0FBQ0Esb0JBQW9CLHNFQUFlO0FBQ25DO0FBQ0EsOEJBQThCLHNFQUFlO0FBQzdDO0FBQ0E7QUFDQSxTQUFTO0FBQ1Q7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBLGdCQUFnQixtQkFBbUIsRUFBRSxzRUFBZTtBQUNwRDtBQUNBLGVBQWUseUNBQUk7QUFDbkI7QUFDQSxrQkFBa0I7QUFDbEIsMEJBQTBCO0FBQzFCLG9CQUFvQjtBQUNwQixlQUFlO0FBQ2Y7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0Esa0JBQWtCLHNDQUFzQztBQUN4RCxrQkFBa0I7QUFDbEI7QUFDQTtBQUNBO0FBQ0E7QUFDQSxhQUFhO0FBQ2I7QUFDQTtBQUNBO0FBQ0E7QUFDQSxrQkFBa0IsbUNBQW1DO0FBQ3JELGtCQUFrQjtBQUNsQjtBQUNBO0FBQ0E7QUFDQTtBQUNBLGFBQWE7QUFDYjtBQUNBO0FBQ0E7QUFDQTtBQUNBLHFCQUFxQixrREFBTTtBQUMzQjtBQUNBLElBQUksd0RBQUs7QUFDVDtBQUNBO0FBQ0EsSUFBSSwrREFBYTtBQUNqQjtBQUN1QjtBQUN2QiIsInNvdXJjZXMiOlsid2VicGFjazovL19OX0UvLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC1zY2FmZm9sZC11aS9kaXN0L2VzbS9zcmMvcGFydGlhbHMvdzNtLWFsZXJ0YmFyL2luZGV4LmpzPzVjY2MiXSwic291cmNlc0NvbnRlbnQiOlsidmFyIF9fZGVjb3JhdGUgPSAodGhpcyAmJiB0aGlzLl9fZGVjb3JhdGUpIHx8IGZ1bmN0aW9uIChkZWNvcmF0b3JzLCB0YXJnZXQsIGtleSwgZGVzYykge1xuICAgIHZhciBjID0gYXJndW1lbnRzLmxlbmd0aCwgciA9IGMgPCAzID8gdGFyZ2V0IDogZGVzYyA9PT0gbnVsbCA/IGRlc2MgPSBPYmplY3QuZ2V0T3duUHJvcGVydHlEZXNjcmlwdG9yKHRhcmdldCwga2V5KSA6IGRlc2MsIGQ7XG4gICAgaWYgKHR5cGVvZiBSZWZsZWN0ID09PSBcIm9iamVjdFwiICYmIHR5cGVvZiBSZWZsZWN0LmRlY29yYXRlID09PSBcImZ1bmN0aW9uXCIpIHIgPSBSZWZsZWN0LmRlY29yYXRlKGRlY29yYXRvcnMsIHRhcmdldCwga2V5LCBkZXNjKTtcbiAgICBlbHNlIGZvciAodmFyIGkgPSBkZWNvcmF0b3JzLmxlbmd0aCAtIDE7IGkgPj0gMDsgaS0tKSBpZiAoZCA9IGRlY29yYXRvcnNbaV0pIHIgPSAoYyA8IDMgPyBkKHIpIDogYyA+IDMgPyBkKHRhcmdldCwga2V5LCByKSA6IGQodGFyZ2V0LCBrZXkpKSB8fCByO1xuICAgIHJldHVybiBjID4gMyAmJiByICYmIE9iamVjdC5kZWZpbmVQcm9wZXJ0eSh0YXJnZXQsIGtleSwgciksIHI7XG59O1xuaW1wb3J0IHsgTGl0RWxlbWVudCwgaHRtbCB9IGZyb20gJ2xpdCc7XG5pbXBvcnQgeyBzdGF0ZSB9IGZyb20gJ2xpdC9kZWNvcmF0b3JzLmpzJztcbmltcG9ydCB7IEFsZXJ0Q29udHJvbGxlciB9IGZyb20gJ0ByZW93bi9hcHBraXQtY29udHJvbGxlcnMnO1xuaW1wb3J0IHsgY3VzdG9tRWxlbWVudCB9IGZyb20gJ0ByZW93bi9hcHBraXQtdWknO1xuaW1wb3J0ICdAcmVvd24vYXBwa2l0LXVpL3d1aS1hbGVydGJhcic7XG5pbXBvcnQgc3R5bGVzIGZyb20gJy4vc3R5bGVzLmpzJztcbmV4cG9ydCBjb25zdCBwcmVzZXRzID0ge1xuICAgIGluZm86IHtcbiAgICAgICAgYmFja2dyb3VuZENvbG9yOiAnZmctMzUwJyxcbiAgICAgICAgaWNvbkNvbG9yOiAnZmctMzI1JyxcbiAgICAgICAgaWNvbjogJ2luZm8nXG4gICAgfSxcbiAgICBzdWNjZXNzOiB7XG4gICAgICAgIGJhY2tncm91bmRDb2xvcjogJ3N1Y2Nlc3MtZ2xhc3MtcmVvd24tMDIwJyxcbiAgICAgICAgaWNvbkNvbG9yOiAnc3VjY2Vzcy0xMjUnLFxuICAgICAgICBpY29uOiAnY2hlY2ttYXJrJ1xuICAgIH0sXG4gICAgd2FybmluZzoge1xuICAgICAgICBiYWNrZ3JvdW5kQ29sb3I6ICd3YXJuaW5nLWdsYXNzLXJlb3duLTAyMCcsXG4gICAgICAgIGljb25Db2xvcjogJ3dhcm5pbmctMTAwJyxcbiAgICAgICAgaWNvbjogJ3dhcm5pbmdDaXJjbGUnXG4gICAgfSxcbiAgICBlcnJvcjoge1xuICAgICAgICBiYWNrZ3JvdW5kQ29sb3I6ICdlcnJvci1nbGFzcy1yZW93bi0wMjAnLFxuICAgICAgICBpY29uQ29sb3I6ICdlcnJvci0xMjUnLFxuICAgICAgICBpY29uOiAnZXhjbGFtYXRpb25UcmlhbmdsZSdcbiAgICB9XG59O1xubGV0IFczbUFsZXJ0QmFyID0gY2xhc3MgVzNtQWxlcnRCYXIgZXh0ZW5kcyBMaXRFbGVtZW50IHtcbiAgICBjb25zdHJ1Y3RvcigpIHtcbiAgICAgICAgc3VwZXIoKTtcbiAgICAgICAgdGhpcy51bnN1YnNjcmliZSA9IFtdO1xuICAgICAgICB0aGlzLm9wZW4gPSBBbGVydENvbnRyb2xsZXIuc3RhdGUub3BlbjtcbiAgICAgICAgdGhpcy5vbk9wZW4odHJ1ZSk7XG4gICAgICAgIHRoaXMudW5zdWJzY3JpYmUucHVzaChBbGVydENvbnRyb2xsZXIuc3Vic2NyaWJlS2V5KCdvcGVuJywgdmFsID0+IHtcbiAgICAgICAgICAgIHRoaXMub3BlbiA9IHZhbDtcbiAgICAgICAgICAgIHRoaXMub25PcGVuKGZhbHNlKTtcbiAgICAgICAgfSkpO1xuICAgIH1cbiAgICBkaXNjb25uZWN0ZWRDYWxsYmFjaygpIHtcbiAgICAgICAgdGhpcy51bnN1YnNjcmliZS5mb3JFYWNoKHVuc3Vic2NyaWJlID0+IHVuc3Vic2NyaWJlKCkpO1xuICAgIH1cbiAgICByZW5kZXIoKSB7XG4gICAgICAgIGNvbnN0IHsgbWVzc2FnZSwgdmFyaWFudCB9ID0gQWxlcnRDb250cm9sbGVyLnN0YXRlO1xuICAgICAgICBjb25zdCBwcmVzZXQgPSBwcmVzZXRzW3ZhcmlhbnRdO1xuICAgICAgICByZXR1cm4gaHRtbCBgXG4gICAgICA8d3VpLWFsZXJ0YmFyXG4gICAgICAgIG1lc3NhZ2U9JHttZXNzYWdlfVxuICAgICAgICBiYWNrZ3JvdW5kQ29sb3I9JHtwcmVzZXQ/LmJhY2tncm91bmRDb2xvcn1cbiAgICAgICAgaWNvbkNvbG9yPSR7cHJlc2V0Py5pY29uQ29sb3J9XG4gICAgICAgIGljb249JHtwcmVzZXQ/Lmljb259XG4gICAgICA+PC93dWktYWxlcnRiYXI+XG4gICAgYDtcbiAgICB9XG4gICAgb25PcGVuKGlzTW91bnRlZCkge1xuICAgICAgICBpZiAodGhpcy5vcGVuKSB7XG4gICAgICAgICAgICB0aGlzLmFuaW1hdGUoW1xuICAgICAgICAgICAgICAgIHsgb3BhY2l0eTogMCwgdHJhbnNmb3JtOiAnc2NhbGUoMC44NSknIH0sXG4gICAgICAgICAgICAgICAgeyBvcGFjaXR5OiAxLCB0cmFuc2Zvcm06ICdzY2FsZSgxKScgfVxuICAgICAgICAgICAgXSwge1xuICAgICAgICAgICAgICAgIGR1cmF0aW9uOiAxNTAsXG4gICAgICAgICAgICAgICAgZmlsbDogJ2ZvcndhcmRzJyxcbiAgICAgICAgICAgICAgICBlYXNpbmc6ICdlYXNlJ1xuICAgICAgICAgICAgfSk7XG4gICAgICAgICAgICB0aGlzLnN0eWxlLmNzc1RleHQgPSBgcG9pbnRlci1ldmVudHM6IGF1dG9gO1xuICAgICAgICB9XG4gICAgICAgIGVsc2UgaWYgKCFpc01vdW50ZWQpIHtcbiAgICAgICAgICAgIHRoaXMuYW5pbWF0ZShbXG4gICAgICAgICAgICAgICAgeyBvcGFjaXR5OiAxLCB0cmFuc2Zvcm06ICdzY2FsZSgxKScgfSxcbiAgICAgICAgICAgICAgICB7IG9wYWNpdHk6IDAsIHRyYW5zZm9ybTogJ3NjYWxlKDAuODUpJyB9XG4gICAgICAgICAgICBdLCB7XG4gICAgICAgICAgICAgICAgZHVyYXRpb246IDE1MCxcbiAgICAgICAgICAgICAgICBmaWxsOiAnZm9yd2FyZHMnLFxuICAgICAgICAgICAgICAgIGVhc2luZzogJ2Vhc2UnXG4gICAgICAgICAgICB9KTtcbiAgICAgICAgICAgIHRoaXMuc3R5bGUuY3NzVGV4dCA9IGBwb2ludGVyLWV2ZW50czogbm9uZWA7XG4gICAgICAgIH1cbiAgICB9XG59O1xuVzNtQWxlcnRCYXIuc3R5bGVzID0gc3R5bGVzO1xuX19kZWNvcmF0ZShbXG4gICAgc3RhdGUoKVxuXSwgVzNtQWxlcnRCYXIucHJvdG90eXBlLCBcIm9wZW5cIiwgdm9pZCAwKTtcblczbUFsZXJ0QmFyID0gX19kZWNvcmF0ZShbXG4gICAgY3VzdG9tRWxlbWVudCgndzNtLWFsZXJ0YmFyJylcbl0sIFczbUFsZXJ0QmFyKTtcbmV4cG9ydCB7IFczbUFsZXJ0QmFyIH07XG4vLyMgc291cmNlTWFwcGluZ1VSTD1pbmRleC5qcy5tYXAiXSwibmFtZXMiOltdLCJzb3VyY2VSb290IjoiIn0=\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-scaffold-ui/dist/esm/src/partials/w3m-alertbar/index.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@reown/appkit-scaffold-ui/dist/esm/src/partials/w3m-alertbar/styles.js":
-/*!*********************************************************************************************!*\
-  !*** ./node_modules/@reown/appkit-scaffold-ui/dist/esm/src/partials/w3m-alertbar/styles.js ***!
-  \*********************************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n\n/* harmony default export */ __webpack_exports__[\"default\"] = ((0,lit__WEBPACK_IMPORTED_MODULE_0__.css) `\n  :host {\n    display: block;\n    position: absolute;\n    top: var(--wui-spacing-s);\n    left: var(--wui-spacing-l);\n    right: var(--wui-spacing-l);\n    opacity: 0;\n    pointer-events: none;\n  }\n`);\n//# sourceMappingURL=styles.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC1zY2FmZm9sZC11aS9kaXN0L2VzbS9zcmMvcGFydGlhbHMvdzNtLWFsZXJ0YmFyL3N0eWxlcy5qcyIsIm1hcHBpbmdzIjoiOztBQUEwQjtBQUMxQiwrREFBZSx3Q0FBRztBQUNsQjtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQSxDQUFDLEVBQUM7QUFDRiIsInNvdXJjZXMiOlsid2VicGFjazovL19OX0UvLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC1zY2FmZm9sZC11aS9kaXN0L2VzbS9zcmMvcGFydGlhbHMvdzNtLWFsZXJ0YmFyL3N0eWxlcy5qcz8wNDk1Il0sInNvdXJjZXNDb250ZW50IjpbImltcG9ydCB7IGNzcyB9IGZyb20gJ2xpdCc7XG5leHBvcnQgZGVmYXVsdCBjc3MgYFxuICA6aG9zdCB7XG4gICAgZGlzcGxheTogYmxvY2s7XG4gICAgcG9zaXRpb246IGFic29sdXRlO1xuICAgIHRvcDogdmFyKC0td3VpLXNwYWNpbmctcyk7XG4gICAgbGVmdDogdmFyKC0td3VpLXNwYWNpbmctbCk7XG4gICAgcmlnaHQ6IHZhcigtLXd1aS1zcGFjaW5nLWwpO1xuICAgIG9wYWNpdHk6IDA7XG4gICAgcG9pbnRlci1ldmVudHM6IG5vbmU7XG4gIH1cbmA7XG4vLyMgc291cmNlTWFwcGluZ1VSTD1zdHlsZXMuanMubWFwIl0sIm5hbWVzIjpbXSwic291cmNlUm9vdCI6IiJ9\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-scaffold-ui/dist/esm/src/partials/w3m-alertbar/styles.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@reown/appkit-scaffold-ui/dist/esm/src/partials/w3m-header/index.js":
-/*!******************************************************************************************!*\
-  !*** ./node_modules/@reown/appkit-scaffold-ui/dist/esm/src/partials/w3m-header/index.js ***!
-  \******************************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   W3mHeader: function() { return /* binding */ W3mHeader; }\n/* harmony export */ });\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n/* harmony import */ var lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__ = __webpack_require__(/*! lit/decorators.js */ \"./node_modules/lit/decorators.js\");\n/* harmony import */ var lit_directives_if_defined_js__WEBPACK_IMPORTED_MODULE_2__ = __webpack_require__(/*! lit/directives/if-defined.js */ \"./node_modules/lit/directives/if-defined.js\");\n/* harmony import */ var _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_11__ = __webpack_require__(/*! @reown/appkit-controllers */ \"./node_modules/@reown/appkit-controllers/dist/esm/exports/index.js\");\n/* harmony import */ var _reown_appkit_ui__WEBPACK_IMPORTED_MODULE_3__ = __webpack_require__(/*! @reown/appkit-ui */ \"./node_modules/@reown/appkit-ui/dist/esm/exports/index.js\");\n/* harmony import */ var _reown_appkit_ui_wui_flex__WEBPACK_IMPORTED_MODULE_4__ = __webpack_require__(/*! @reown/appkit-ui/wui-flex */ \"./node_modules/@reown/appkit-ui/dist/esm/exports/wui-flex.js\");\n/* harmony import */ var _reown_appkit_ui_wui_icon_link__WEBPACK_IMPORTED_MODULE_5__ = __webpack_require__(/*! @reown/appkit-ui/wui-icon-link */ \"./node_modules/@reown/appkit-ui/dist/esm/exports/wui-icon-link.js\");\n/* harmony import */ var _reown_appkit_ui_wui_select__WEBPACK_IMPORTED_MODULE_6__ = __webpack_require__(/*! @reown/appkit-ui/wui-select */ \"./node_modules/@reown/appkit-ui/dist/esm/exports/wui-select.js\");\n/* harmony import */ var _reown_appkit_ui_wui_tag__WEBPACK_IMPORTED_MODULE_7__ = __webpack_require__(/*! @reown/appkit-ui/wui-tag */ \"./node_modules/@reown/appkit-ui/dist/esm/exports/wui-tag.js\");\n/* harmony import */ var _reown_appkit_ui_wui_text__WEBPACK_IMPORTED_MODULE_8__ = __webpack_require__(/*! @reown/appkit-ui/wui-text */ \"./node_modules/@reown/appkit-ui/dist/esm/exports/wui-text.js\");\n/* harmony import */ var _utils_ConstantsUtil_js__WEBPACK_IMPORTED_MODULE_9__ = __webpack_require__(/*! ../../utils/ConstantsUtil.js */ \"./node_modules/@reown/appkit-scaffold-ui/dist/esm/src/utils/ConstantsUtil.js\");\n/* harmony import */ var _styles_js__WEBPACK_IMPORTED_MODULE_10__ = __webpack_require__(/*! ./styles.js */ \"./node_modules/@reown/appkit-scaffold-ui/dist/esm/src/partials/w3m-header/styles.js\");\nvar __decorate = (undefined && undefined.__decorate) || function (decorators, target, key, desc) {\n    var c = arguments.length, r = c < 3 ? target : desc === null ? desc = Object.getOwnPropertyDescriptor(target, key) : desc, d;\n    if (typeof Reflect === \"object\" && typeof Reflect.decorate === \"function\") r = Reflect.decorate(decorators, target, key, desc);\n    else for (var i = decorators.length - 1; i >= 0; i--) if (d = decorators[i]) r = (c < 3 ? d(r) : c > 3 ? d(target, key, r) : d(target, key)) || r;\n    return c > 3 && r && Object.defineProperty(target, key, r), r;\n};\n\n\n\n\n\n\n\n\n\n\n\n\nconst BETA_SCREENS = ['SmartSessionList'];\nfunction headings() {\n    const connectorName = _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_11__.RouterController.state.data?.connector?.name;\n    const walletName = _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_11__.RouterController.state.data?.wallet?.name;\n    const networkName = _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_11__.RouterController.state.data?.network?.name;\n    const name = walletName ?? connectorName;\n    const connectors = _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_11__.ConnectorController.getConnectors();\n    const isEmail = connectors.length === 1 && connectors[0]?.id === 'w3m-email';\n    return {\n        Connect: `Connect ${isEmail ? 'Email' : ''} Wallet`,\n        Create: 'Create Wallet',\n        ChooseAccountName: undefined,\n        Account: undefined,\n        AccountSettings: undefined,\n        AllWallets: 'All Wallets',\n        ApproveTransaction: 'Approve Transaction',\n        BuyInProgress: 'Buy',\n        ConnectingExternal: name ?? 'Connect Wallet',\n        ConnectingWalletConnect: name ?? 'WalletConnect',\n        ConnectingWalletConnectBasic: 'WalletConnect',\n        ConnectingSiwe: 'Sign In',\n        Convert: 'Convert',\n        ConvertSelectToken: 'Select token',\n        ConvertPreview: 'Preview convert',\n        Downloads: name ? `Get ${name}` : 'Downloads',\n        EmailLogin: 'Email Login',\n        EmailVerifyOtp: 'Confirm Email',\n        EmailVerifyDevice: 'Register Device',\n        GetWallet: 'Get a wallet',\n        Networks: 'Choose Network',\n        OnRampProviders: 'Choose Provider',\n        OnRampActivity: 'Activity',\n        OnRampTokenSelect: 'Select Token',\n        OnRampFiatSelect: 'Select Currency',\n        Profile: undefined,\n        SwitchNetwork: networkName ?? 'Switch Network',\n        SwitchAddress: 'Switch Address',\n        Transactions: 'Activity',\n        UnsupportedChain: 'Switch Network',\n        UpgradeEmailWallet: 'Upgrade your Wallet',\n        UpdateEmailWallet: 'Edit Email',\n        UpdateEmailPrimaryOtp: 'Confirm Current Email',\n        UpdateEmailSecondaryOtp: 'Confirm New Email',\n        WhatIsABuy: 'What is Buy?',\n        RegisterAccountName: 'Choose name',\n        RegisterAccountNameSuccess: '',\n        WalletReceive: 'Receive',\n        WalletCompatibleNetworks: 'Compatible Networks',\n        Swap: 'Swap',\n        SwapSelectToken: 'Select token',\n        SwapPreview: 'Preview swap',\n        WalletSend: 'Send',\n        WalletSendPreview: 'Review send',\n        WalletSendSelectToken: 'Select Token',\n        WhatIsANetwork: 'What is a network?',\n        WhatIsAWallet: 'What is a wallet?',\n        ConnectWallets: 'Connect wallet',\n        ConnectSocials: 'All socials',\n        ConnectingSocial: _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_11__.AccountController.state.socialProvider\n            ? _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_11__.AccountController.state.socialProvider\n            : 'Connect Social',\n        ConnectingMultiChain: 'Select chain',\n        ConnectingFarcaster: 'Farcaster',\n        SwitchActiveChain: 'Switch chain',\n        SmartSessionCreated: undefined,\n        SmartSessionList: 'Smart Sessions',\n        SIWXSignMessage: 'Sign In'\n    };\n}\nlet W3mHeader = class W3mHeader extends lit__WEBPACK_IMPORTED_MODULE_0__.LitElement {\n    constructor() {\n        super();\n        this.unsubscribe = [];\n        this.heading = headings()[_reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_11__.RouterController.state.view];\n        this.network = _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_11__.ChainController.state.activeCaipNetwork;\n        this.networkImage = _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_11__.AssetUtil.getNetworkImage(this.network);\n        this.buffering = false;\n        this.showBack = false;\n        this.prevHistoryLength = 1;\n        this.view = _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_11__.RouterController.state.view;\n        this.viewDirection = '';\n        this.headerText = headings()[_reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_11__.RouterController.state.view];\n        this.unsubscribe.push(_reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_11__.AssetController.subscribeNetworkImages(() => {\n            this.networkImage = _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_11__.AssetUtil.getNetworkImage(this.network);\n        }), _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_11__.RouterController.subscribeKey('view', val => {\n            setTimeout(() => {\n                this.view = val;\n                this.headerText = headings()[val];\n            }, _utils_ConstantsUtil_js__WEBPACK_IMPORTED_MODULE_9__.ConstantsUtil.ANIMATION_DURATIONS.HeaderText);\n            this.onViewChange();\n            this.onHistoryChange();\n        }), _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_11__.ConnectionController.subscribeKey('buffering', val => (this.buffering = val)), _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_11__.ChainController.subscribeKey('activeCaipNetwork', val => {\n            this.network = val;\n            this.networkImage = _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_11__.AssetUtil.getNetworkImage(this.network);\n        }));\n    }\n    disconnectCallback() {\n        this.unsubscribe.forEach(unsubscribe => unsubscribe());\n    }\n    render() {\n        return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `\n      <wui-flex .padding=${this.getPadding()} justifyContent=\"space-between\" alignItems=\"center\">\n        ${this.leftHeaderTemplate()} ${this.titleTemplate()} ${this.rightHeaderTemplate()}\n      </wui-flex>\n    `;\n    }\n    onWalletHelp() {\n        _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_11__.EventsController.sendEvent({ type: 'track', event: 'CLICK_WALLET_HELP' });\n        _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_11__.RouterController.push('WhatIsAWallet');\n    }\n    async onClose() {\n        const isUnsupportedChain = _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_11__.RouterController.state.view === 'UnsupportedChain';\n        if (isUnsupportedChain || (await _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_11__.SIWXUtil.isSIWXCloseDisabled())) {\n            _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_11__.ModalController.shake();\n        }\n        else {\n            _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_11__.ModalController.close();\n        }\n    }\n    rightHeaderTemplate() {\n        const isSmartSessionsEnabled = _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_11__.OptionsController?.state?.features?.smartSessions;\n        if (_reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_11__.RouterController.state.view !== 'Account' || !isSmartSessionsEnabled) {\n            return this.closeButtonTemplate();\n        }\n        return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<wui-flex>\n      <wui-icon-link\n        icon=\"clock\"\n        @click=${() => _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_11__.RouterController.push('SmartSessionList')}\n        data-testid=\"w3m-header-smart-sessions\"\n      ></wui-icon-link>\n      ${this.closeButtonTemplate()}\n    </wui-flex> `;\n    }\n    closeButtonTemplate() {\n        return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `\n      <wui-icon-link\n        ?disabled=${this.buffering}\n        icon=\"close\"\n        @click=${this.onClose.bind(this)}\n        data-testid=\"w3m-header-close\"\n      ></wui-icon-link>\n    `;\n    }\n    titleTemplate() {\n        const isBeta = BETA_SCREENS.includes(this.view);\n        return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `\n      <wui-flex\n        view-direction=\"${this.viewDirection}\"\n        class=\"w3m-header-title\"\n        alignItems=\"center\"\n        gap=\"xs\"\n      >\n        <wui-text variant=\"paragraph-700\" color=\"fg-100\" data-testid=\"w3m-header-text\"\n          >${this.headerText}</wui-text\n        >\n        ${isBeta ? (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<wui-tag variant=\"main\">Beta</wui-tag>` : null}\n      </wui-flex>\n    `;\n    }\n    leftHeaderTemplate() {\n        const { view } = _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_11__.RouterController.state;\n        const isConnectHelp = view === 'Connect';\n        const isEmbeddedEnable = _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_11__.OptionsController.state.enableEmbedded;\n        const isApproveTransaction = view === 'ApproveTransaction';\n        const isConnectingSIWEView = view === 'ConnectingSiwe';\n        const isAccountView = view === 'Account';\n        const enableNetworkSwitch = _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_11__.OptionsController.state.enableNetworkSwitch;\n        const shouldHideBack = isApproveTransaction || isConnectingSIWEView || (isConnectHelp && isEmbeddedEnable);\n        if (isAccountView && enableNetworkSwitch) {\n            return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<wui-select\n        id=\"dynamic\"\n        data-testid=\"w3m-account-select-network\"\n        active-network=${(0,lit_directives_if_defined_js__WEBPACK_IMPORTED_MODULE_2__.ifDefined)(this.network?.name)}\n        @click=${this.onNetworks.bind(this)}\n        imageSrc=${(0,lit_directives_if_defined_js__WEBPACK_IMPORTED_MODULE_2__.ifDefined)(this.networkImage)}\n      ></wui-select>`;\n        }\n        if (this.showBack && !shouldHideBack) {\n            return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<wui-icon-link\n        data-testid=\"header-back\"\n        id=\"dynamic\"\n        icon=\"chevronLeft\"\n        ?disabled=${this.buffering}\n        @click=${this.onGoBack.bind(this)}\n      ></wui-icon-link>`;\n        }\n        return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<wui-icon-link\n      data-hidden=${!isConnectHelp}\n      id=\"dynamic\"\n      icon=\"helpCircle\"\n      @click=${this.onWalletHelp.bind(this)}\n    ></wui-icon-link>`;\n    }\n    onNetworks() {\n        if (this.isAllowedNetworkSwitch()) {\n            _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_11__.EventsController.sendEvent({ type: 'track', event: 'CLICK_NETWORKS' });\n            _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_11__.RouterController.push('Networks');\n        }\n    }\n    isAllowedNetworkSwitch() {\n        const requestedCaipNetworks = _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_11__.ChainController.getAllRequestedCaipNetworks();\n        const isMultiNetwork = requestedCaipNetworks ? requestedCaipNetworks.length > 1 : false;\n        const isValidNetwork = requestedCaipNetworks?.find(({ id }) => id === this.network?.id);\n        return isMultiNetwork || !isValidNetwork;\n    }\n    getPadding() {\n        if (this.heading) {\n            return ['l', '2l', 'l', '2l'];\n        }\n        return ['0', '2l', '0', '2l'];\n    }\n    onViewChange() {\n        const { history } = _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_11__.RouterController.state;\n        let direction = _utils_ConstantsUtil_js__WEBPACK_IMPORTED_MODULE_9__.ConstantsUtil.VIEW_DIRECTION.Next;\n        if (history.length < this.prevHistoryLength) {\n            direction = _utils_ConstantsUtil_js__WEBPACK_IMPORTED_MODULE_9__.ConstantsUtil.VIEW_DIRECTION.Prev;\n        }\n        this.prevHistoryLength = history.length;\n        this.viewDirection = direction;\n    }\n    async onHistoryChange() {\n        const { history } = _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_11__.RouterController.state;\n        const buttonEl = this.shadowRoot?.querySelector('#dynamic');\n        if (history.length > 1 && !this.showBack && buttonEl) {\n            await buttonEl.animate([{ opacity: 1 }, { opacity: 0 }], {\n                duration: 200,\n                fill: 'forwards',\n                easing: 'ease'\n            }).finished;\n            this.showBack = true;\n            buttonEl.animate([{ opacity: 0 }, { opacity: 1 }], {\n                duration: 200,\n                fill: 'forwards',\n                easing: 'ease'\n            });\n        }\n        else if (history.length <= 1 && this.showBack && buttonEl) {\n            await buttonEl.animate([{ opacity: 1 }, { opacity: 0 }], {\n                duration: 200,\n                fill: 'forwards',\n                easing: 'ease'\n            }).finished;\n            this.showBack = false;\n            buttonEl.animate([{ opacity: 0 }, { opacity: 1 }], {\n                duration: 200,\n                fill: 'forwards',\n                easing: 'ease'\n            });\n        }\n    }\n    onGoBack() {\n        _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_11__.RouterController.goBack();\n    }\n};\nW3mHeader.styles = _styles_js__WEBPACK_IMPORTED_MODULE_10__[\"default\"];\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.state)()\n], W3mHeader.prototype, \"heading\", void 0);\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.state)()\n], W3mHeader.prototype, \"network\", void 0);\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.state)()\n], W3mHeader.prototype, \"networkImage\", void 0);\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.state)()\n], W3mHeader.prototype, \"buffering\", void 0);\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.state)()\n], W3mHeader.prototype, \"showBack\", void 0);\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.state)()\n], W3mHeader.prototype, \"prevHistoryLength\", void 0);\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.state)()\n], W3mHeader.prototype, \"view\", void 0);\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.state)()\n], W3mHeader.prototype, \"viewDirection\", void 0);\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.state)()\n], W3mHeader.prototype, \"headerText\", void 0);\nW3mHeader = __decorate([\n    (0,_reown_appkit_ui__WEBPACK_IMPORTED_MODULE_3__.customElement)('w3m-header')\n], W3mHeader);\n\n//# sourceMappingURL=index.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC1zY2FmZm9sZC11aS9kaXN0L2VzbS9zcmMvcGFydGlhbHMvdzNtLWhlYWRlci9pbmRleC5qcyIsIm1hcHBpbmdzIjoiOzs7Ozs7Ozs7Ozs7Ozs7O0FBQUEsa0JBQWtCLFNBQUksSUFBSSxTQUFJO0FBQzlCO0FBQ0E7QUFDQSw2Q0FBNkMsUUFBUTtBQUNyRDtBQUNBO0FBQ3VDO0FBQ0c7QUFDZTtBQUMrSztBQUN2TDtBQUNkO0FBQ0s7QUFDSDtBQUNIO0FBQ0M7QUFDMEI7QUFDNUI7QUFDakM7QUFDQTtBQUNBLDBCQUEwQix3RUFBZ0I7QUFDMUMsdUJBQXVCLHdFQUFnQjtBQUN2Qyx3QkFBd0Isd0VBQWdCO0FBQ3hDO0FBQ0EsdUJBQXVCLDJFQUFtQjtBQUMxQztBQUNBO0FBQ0EsNEJBQTRCLHdCQUF3QjtBQUNwRDtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0EsaUNBQWlDLEtBQUs7QUFDdEM7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0EsMEJBQTBCLHlFQUFpQjtBQUMzQyxjQUFjLHlFQUFpQjtBQUMvQjtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQSx3Q0FBd0MsMkNBQVU7QUFDbEQ7QUFDQTtBQUNBO0FBQ0Esa0NBQWtDLHdFQUFnQjtBQUNsRCx1QkFBdUIsdUVBQWU7QUFDdEMsNEJBQTRCLGlFQUFTO0FBQ3JDO0FBQ0E7QUFDQTtBQUNBLG9CQUFvQix3RUFBZ0I7QUFDcEM7QUFDQSxxQ0FBcUMsd0VBQWdCO0FBQ3JELDhCQUE4Qix1RUFBZTtBQUM3QyxnQ0FBZ0MsaUVBQVM7QUFDekMsU0FBUyxHQUFHLHdFQUFnQjtBQUM1QjtBQUNBO0FBQ0E7QUFDQSxhQUFhLEVBQUUsa0VBQWE7QUFDNUI7QUFDQTtBQUNBLFNBQVMsR0FBRyw0RUFBb0IsMkRBQTJELHVFQUFlO0FBQzFHO0FBQ0EsZ0NBQWdDLGlFQUFTO0FBQ3pDLFNBQVM7QUFDVDtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0EsZUFBZSx5Q0FBSTtBQUNuQiwyQkFBMkIsbUJBQW1CO0FBQzlDLFVBQVUsMkJBQTJCLEVBQUUsc0JBQXNCLEVBQUU7QUFDL0Q7QUFDQTtBQUNBO0FBQ0E7QUFDQSxRQUFRLHdFQUFnQixhQUFhLDJDQUEyQztBQUNoRixRQUFRLHdFQUFnQjtBQUN4QjtBQUNBO0FBQ0EsbUNBQW1DLHdFQUFnQjtBQUNuRCx5Q0FBeUMsZ0VBQVE7QUFDakQsWUFBWSx1RUFBZTtBQUMzQjtBQUNBO0FBQ0EsWUFBWSx1RUFBZTtBQUMzQjtBQUNBO0FBQ0E7QUFDQSx1Q0FBdUMseUVBQWlCO0FBQ3hELFlBQVksd0VBQWdCO0FBQzVCO0FBQ0E7QUFDQSxlQUFlLHlDQUFJO0FBQ25CO0FBQ0E7QUFDQSxpQkFBaUIsTUFBTSx3RUFBZ0I7QUFDdkM7QUFDQTtBQUNBLFFBQVE7QUFDUjtBQUNBO0FBQ0E7QUFDQSxlQUFlLHlDQUFJO0FBQ25CO0FBQ0Esb0JBQW9CO0FBQ3BCO0FBQ0EsaUJBQWlCO0FBQ2pCO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBLGVBQWUseUNBQUk7QUFDbkI7QUFDQSwwQkFBMEIsbUJBQW1CO0FBQzdDO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQSxhQUFhLGdCQUFnQjtBQUM3QjtBQUNBLFVBQVUsU0FBUyx5Q0FBSTtBQUN2QjtBQUNBO0FBQ0E7QUFDQTtBQUNBLGdCQUFnQixPQUFPLEVBQUUsd0VBQWdCO0FBQ3pDO0FBQ0EsaUNBQWlDLHlFQUFpQjtBQUNsRDtBQUNBO0FBQ0E7QUFDQSxvQ0FBb0MseUVBQWlCO0FBQ3JEO0FBQ0E7QUFDQSxtQkFBbUIseUNBQUk7QUFDdkI7QUFDQTtBQUNBLHlCQUF5Qix1RUFBUztBQUNsQyxpQkFBaUI7QUFDakIsbUJBQW1CLHVFQUFTO0FBQzVCO0FBQ0E7QUFDQTtBQUNBLG1CQUFtQix5Q0FBSTtBQUN2QjtBQUNBO0FBQ0E7QUFDQSxvQkFBb0I7QUFDcEIsaUJBQWlCO0FBQ2pCO0FBQ0E7QUFDQSxlQUFlLHlDQUFJO0FBQ25CLG9CQUFvQjtBQUNwQjtBQUNBO0FBQ0EsZUFBZTtBQUNmO0FBQ0E7QUFDQTtBQUNBO0FBQ0EsWUFBWSx3RUFBZ0IsYUFBYSx3Q0FBd0M7QUFDakYsWUFBWSx3RUFBZ0I7QUFDNUI7QUFDQTtBQUNBO0FBQ0Esc0NBQXNDLHVFQUFlO0FBQ3JEO0FBQ0EsOERBQThELElBQUk7QUFDbEU7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0EsZ0JBQWdCLFVBQVUsRUFBRSx3RUFBZ0I7QUFDNUMsd0JBQXdCLGtFQUFhO0FBQ3JDO0FBQ0Esd0JBQXdCLGtFQUFhO0FBQ3JDO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQSxnQkFBZ0IsVUFBVSxFQUFFLHdFQUFnQjtBQUM1QztBQUNBO0FBQ0Esc0NBQXNDLFlBQVksSUFBSSxZQUFZO0FBQ2xFO0FBQ0E7QUFDQTtBQUNBLGFBQWE7QUFDYjtBQUNBLGdDQUFnQyxZQUFZLElBQUksWUFBWTtBQUM1RDtBQUNBO0FBQ0E7QUFDQSxhQUFhO0FBQ2I7QUFDQTtBQUNBLHNDQUFzQyxZQUFZLElBQUksWUFBWTtBQUNsRTtBQUNBO0FBQ0E7QUFDQSxhQUFhO0FBQ2I7QUFDQSxnQ0FBZ0MsWUFBWSxJQUFJLFlBQVk7QUFDNUQ7QUFDQTtBQUNBO0FBQ0EsYUFBYTtBQUNiO0FBQ0E7QUFDQTtBQUNBLFFBQVEsd0VBQWdCO0FBQ3hCO0FBQ0E7QUFDQSxtQkFBbUIsbURBQU07QUFDekI7QUFDQSxJQUFJLHdEQUFLO0FBQ1Q7QUFDQTtBQUNBLElBQUksd0RBQUs7QUFDVDtBQUNBO0FBQ0EsSUFBSSx3REFBSztBQUNUO0FBQ0E7QUFDQSxJQUFJLHdEQUFLO0FBQ1Q7QUFDQTtBQUNBLElBQUksd0RBQUs7QUFDVDtBQUNBO0FBQ0EsSUFBSSx3REFBSztBQUNUO0FBQ0E7QUFDQSxJQUFJLHdEQUFLO0FBQ1Q7QUFDQTtBQUNBLElBQUksd0RBQUs7QUFDVDtBQUNBO0FBQ0EsSUFBSSx3REFBSztBQUNUO0FBQ0E7QUFDQSxJQUFJLCtEQUFhO0FBQ2pCO0FBQ3FCO0FBQ3JCIiwic291cmNlcyI6WyJ3ZWJwYWNrOi8vX05fRS8uL25vZGVfbW9kdWxlcy9AcmVvd24vYXBwa2l0LXNjYWZmb2xkLXVpL2Rpc3QvZXNtL3NyYy9wYXJ0aWFscy93M20taGVhZGVyL2luZGV4LmpzP2E2NGYiXSwic291cmNlc0NvbnRlbnQiOlsidmFyIF9fZGVjb3JhdGUgPSAodGhpcyAmJiB0aGlzLl9fZGVjb3JhdGUpIHx8IGZ1bmN0aW9uIChkZWNvcmF0b3JzLCB0YXJnZXQsIGtleSwgZGVzYykge1xuICAgIHZhciBjID0gYXJndW1lbnRzLmxlbmd0aCwgciA9IGMgPCAzID8gdGFyZ2V0IDogZGVzYyA9PT0gbnVsbCA/IGRlc2MgPSBPYmplY3QuZ2V0T3duUHJvcGVydHlEZXNjcmlwdG9yKHRhcmdldCwga2V5KSA6IGRlc2MsIGQ7XG4gICAgaWYgKHR5cGVvZiBSZWZsZWN0ID09PSBcIm9iamVjdFwiICYmIHR5cGVvZiBSZWZsZWN0LmRlY29yYXRlID09PSBcImZ1bmN0aW9uXCIpIHIgPSBSZWZsZWN0LmRlY29yYXRlKGRlY29yYXRvcnMsIHRhcmdldCwga2V5LCBkZXNjKTtcbiAgICBlbHNlIGZvciAodmFyIGkgPSBkZWNvcmF0b3JzLmxlbmd0aCAtIDE7IGkgPj0gMDsgaS0tKSBpZiAoZCA9IGRlY29yYXRvcnNbaV0pIHIgPSAoYyA8IDMgPyBkKHIpIDogYyA+IDMgPyBkKHRhcmdldCwga2V5LCByKSA6IGQodGFyZ2V0LCBrZXkpKSB8fCByO1xuICAgIHJldHVybiBjID4gMyAmJiByICYmIE9iamVjdC5kZWZpbmVQcm9wZXJ0eSh0YXJnZXQsIGtleSwgciksIHI7XG59O1xuaW1wb3J0IHsgTGl0RWxlbWVudCwgaHRtbCB9IGZyb20gJ2xpdCc7XG5pbXBvcnQgeyBzdGF0ZSB9IGZyb20gJ2xpdC9kZWNvcmF0b3JzLmpzJztcbmltcG9ydCB7IGlmRGVmaW5lZCB9IGZyb20gJ2xpdC9kaXJlY3RpdmVzL2lmLWRlZmluZWQuanMnO1xuaW1wb3J0IHsgQWNjb3VudENvbnRyb2xsZXIsIEFzc2V0Q29udHJvbGxlciwgQXNzZXRVdGlsLCBDaGFpbkNvbnRyb2xsZXIsIENvbm5lY3Rpb25Db250cm9sbGVyLCBDb25uZWN0b3JDb250cm9sbGVyLCBFdmVudHNDb250cm9sbGVyLCBNb2RhbENvbnRyb2xsZXIsIE9wdGlvbnNDb250cm9sbGVyLCBSb3V0ZXJDb250cm9sbGVyLCBTSVdYVXRpbCB9IGZyb20gJ0ByZW93bi9hcHBraXQtY29udHJvbGxlcnMnO1xuaW1wb3J0IHsgY3VzdG9tRWxlbWVudCB9IGZyb20gJ0ByZW93bi9hcHBraXQtdWknO1xuaW1wb3J0ICdAcmVvd24vYXBwa2l0LXVpL3d1aS1mbGV4JztcbmltcG9ydCAnQHJlb3duL2FwcGtpdC11aS93dWktaWNvbi1saW5rJztcbmltcG9ydCAnQHJlb3duL2FwcGtpdC11aS93dWktc2VsZWN0JztcbmltcG9ydCAnQHJlb3duL2FwcGtpdC11aS93dWktdGFnJztcbmltcG9ydCAnQHJlb3duL2FwcGtpdC11aS93dWktdGV4dCc7XG5pbXBvcnQgeyBDb25zdGFudHNVdGlsIH0gZnJvbSAnLi4vLi4vdXRpbHMvQ29uc3RhbnRzVXRpbC5qcyc7XG5pbXBvcnQgc3R5bGVzIGZyb20gJy4vc3R5bGVzLmpzJztcbmNvbnN0IEJFVEFfU0NSRUVOUyA9IFsnU21hcnRTZXNzaW9uTGlzdCddO1xuZnVuY3Rpb24gaGVhZGluZ3MoKSB7XG4gICAgY29uc3QgY29ubmVjdG9yTmFtZSA9IFJvdXRlckNvbnRyb2xsZXIuc3RhdGUuZGF0YT8uY29ubmVjdG9yPy5uYW1lO1xuICAgIGNvbnN0IHdhbGxldE5hbWUgPSBSb3V0ZXJDb250cm9sbGVyLnN0YXRlLmRhdGE/LndhbGxldD8ubmFtZTtcbiAgICBjb25zdCBuZXR3b3JrTmFtZSA9IFJvdXRlckNvbnRyb2xsZXIuc3RhdGUuZGF0YT8ubmV0d29yaz8ubmFtZTtcbiAgICBjb25zdCBuYW1lID0gd2FsbGV0TmFtZSA/PyBjb25uZWN0b3JOYW1lO1xuICAgIGNvbnN0IGNvbm5lY3RvcnMgPSBDb25uZWN0b3JDb250cm9sbGVyLmdldENvbm5lY3RvcnMoKTtcbiAgICBjb25zdCBpc0VtYWlsID0gY29ubmVjdG9ycy5sZW5ndGggPT09IDEgJiYgY29ubmVjdG9yc1swXT8uaWQgPT09ICd3M20tZW1haWwnO1xuICAgIHJldHVybiB7XG4gICAgICAgIENvbm5lY3Q6IGBDb25uZWN0ICR7aXNFbWFpbCA/ICdFbWFpbCcgOiAnJ30gV2FsbGV0YCxcbiAgICAgICAgQ3JlYXRlOiAnQ3JlYXRlIFdhbGxldCcsXG4gICAgICAgIENob29zZUFjY291bnROYW1lOiB1bmRlZmluZWQsXG4gICAgICAgIEFjY291bnQ6IHVuZGVmaW5lZCxcbiAgICAgICAgQWNjb3VudFNldHRpbmdzOiB1bmRlZmluZWQsXG4gICAgICAgIEFsbFdhbGxldHM6ICdBbGwgV2FsbGV0cycsXG4gICAgICAgIEFwcHJvdmVUcmFuc2FjdGlvbjogJ0FwcHJvdmUgVHJhbnNhY3Rpb24nLFxuICAgICAgICBCdXlJblByb2dyZXNzOiAnQnV5JyxcbiAgICAgICAgQ29ubmVjdGluZ0V4dGVybmFsOiBuYW1lID8/ICdDb25uZWN0IFdhbGxldCcsXG4gICAgICAgIENvbm5lY3RpbmdXYWxsZXRDb25uZWN0OiBuYW1lID8/ICdXYWxsZXRDb25uZWN0JyxcbiAgICAgICAgQ29ubmVjdGluZ1dhbGxldENvbm5lY3RCYXNpYzogJ1dhbGxldENvbm5lY3QnLFxuICAgICAgICBDb25uZWN0aW5nU2l3ZTogJ1NpZ24gSW4nLFxuICAgICAgICBDb252ZXJ0OiAnQ29udmVydCcsXG4gICAgICAgIENvbnZlcnRTZWxlY3RUb2tlbjogJ1NlbGVjdCB0b2tlbicsXG4gICAgICAgIENvbnZlcnRQcmV2aWV3OiAnUHJldmlldyBjb252ZXJ0JyxcbiAgICAgICAgRG93bmxvYWRzOiBuYW1lID8gYEdldCAke25hbWV9YCA6ICdEb3dubG9hZHMnLFxuICAgICAgICBFbWFpbExvZ2luOiAnRW1haWwgTG9naW4nLFxuICAgICAgICBFbWFpbFZlcmlmeU90cDogJ0NvbmZpcm0gRW1haWwnLFxuICAgICAgICBFbWFpbFZlcmlmeURldmljZTogJ1JlZ2lzdGVyIERldmljZScsXG4gICAgICAgIEdldFdhbGxldDogJ0dldCBhIHdhbGxldCcsXG4gICAgICAgIE5ldHdvcmtzOiAnQ2hvb3NlIE5ldHdvcmsnLFxuICAgICAgICBPblJhbXBQcm92aWRlcnM6ICdDaG9vc2UgUHJvdmlkZXInLFxuICAgICAgICBPblJhbXBBY3Rpdml0eTogJ0FjdGl2aXR5JyxcbiAgICAgICAgT25SYW1wVG9rZW5TZWxlY3Q6ICdTZWxlY3QgVG9rZW4nLFxuICAgICAgICBPblJhbXBGaWF0U2VsZWN0OiAnU2VsZWN0IEN1cnJlbmN5JyxcbiAgICAgICAgUHJvZmlsZTogdW5kZWZpbmVkLFxuICAgICAgICBTd2l0Y2hOZXR3b3JrOiBuZXR3b3JrTmFtZSA/PyAnU3dpdGNoIE5ldHdvcmsnLFxuICAgICAgICBTd2l0Y2hBZGRyZXNzOiAnU3dpdGNoIEFkZHJlc3MnLFxuICAgICAgICBUcmFuc2FjdGlvbnM6ICdBY3Rpdml0eScsXG4gICAgICAgIFVuc3VwcG9ydGVkQ2hhaW46ICdTd2l0Y2ggTmV0d29yaycsXG4gICAgICAgIFVwZ3JhZGVFbWFpbFdhbGxldDogJ1VwZ3JhZGUgeW91ciBXYWxsZXQnLFxuICAgICAgICBVcGRhdGVFbWFpbFdhbGxldDogJ0VkaXQgRW1haWwnLFxuICAgICAgICBVcGRhdGVFbWFpbFByaW1hcnlPdHA6ICdDb25maXJtIEN1cnJlbnQgRW1haWwnLFxuICAgICAgICBVcGRhdGVFbWFpbFNlY29uZGFyeU90cDogJ0NvbmZpcm0gTmV3IEVtYWlsJyxcbiAgICAgICAgV2hhdElzQUJ1eTogJ1doYXQgaXMgQnV5PycsXG4gICAgICAgIFJlZ2lzdGVyQWNjb3VudE5hbWU6ICdDaG9vc2UgbmFtZScsXG4gICAgICAgIFJlZ2lzdGVyQWNjb3VudE5hbWVTdWNjZXNzOiAnJyxcbiAgICAgICAgV2FsbGV0UmVjZWl2ZTogJ1JlY2VpdmUnLFxuICAgICAgICBXYWxsZXRDb21wYXRpYmxlTmV0d29ya3M6ICdDb21wYXRpYmxlIE5ldHdvcmtzJyxcbiAgICAgICAgU3dhcDogJ1N3YXAnLFxuICAgICAgICBTd2FwU2VsZWN0VG9rZW46ICdTZWxlY3QgdG9rZW4nLFxuICAgICAgICBTd2FwUHJldmlldzogJ1ByZXZpZXcgc3dhcCcsXG4gICAgICAgIFdhbGxldFNlbmQ6ICdTZW5kJyxcbiAgICAgICAgV2FsbGV0U2VuZFByZXZpZXc6ICdSZXZpZXcgc2VuZCcsXG4gICAgICAgIFdhbGxldFNlbmRTZWxlY3RUb2tlbjogJ1NlbGVjdCBUb2tlbicsXG4gICAgICAgIFdoYXRJc0FOZXR3b3JrOiAnV2hhdCBpcyBhIG5ldHdvcms/JyxcbiAgICAgICAgV2hhdElzQVdhbGxldDogJ1doYXQgaXMgYSB3YWxsZXQ/JyxcbiAgICAgICAgQ29ubmVjdFdhbGxldHM6ICdDb25uZWN0IHdhbGxldCcsXG4gICAgICAgIENvbm5lY3RTb2NpYWxzOiAnQWxsIHNvY2lhbHMnLFxuICAgICAgICBDb25uZWN0aW5nU29jaWFsOiBBY2NvdW50Q29udHJvbGxlci5zdGF0ZS5zb2NpYWxQcm92aWRlclxuICAgICAgICAgICAgPyBBY2NvdW50Q29udHJvbGxlci5zdGF0ZS5zb2NpYWxQcm92aWRlclxuICAgICAgICAgICAgOiAnQ29ubmVjdCBTb2NpYWwnLFxuICAgICAgICBDb25uZWN0aW5nTXVsdGlDaGFpbjogJ1NlbGVjdCBjaGFpbicsXG4gICAgICAgIENvbm5lY3RpbmdGYXJjYXN0ZXI6ICdGYXJjYXN0ZXInLFxuICAgICAgICBTd2l0Y2hBY3RpdmVDaGFpbjogJ1N3aXRjaCBjaGFpbicsXG4gICAgICAgIFNtYXJ0U2Vzc2lvbkNyZWF0ZWQ6IHVuZGVmaW5lZCxcbiAgICAgICAgU21hcnRTZXNzaW9uTGlzdDogJ1NtYXJ0IFNlc3Npb25zJyxcbiAgICAgICAgU0lXWFNpZ25NZXNzYWdlOiAnU2lnbiBJbidcbiAgICB9O1xufVxubGV0IFczbUhlYWRlciA9IGNsYXNzIFczbUhlYWRlciBleHRlbmRzIExpdEVsZW1lbnQge1xuICAgIGNvbnN0cnVjdG9yKCkge1xuICAgICAgICBzdXBlcigpO1xuICAgICAgICB0aGlzLnVuc3Vic2NyaWJlID0gW107XG4gICAgICAgIHRoaXMuaGVhZGluZyA9IGhlYWRpbmdzKClbUm91dGVyQ29udHJvbGxlci5zdGF0ZS52aWV3XTtcbiAgICAgICAgdGhpcy5uZXR3b3JrID0gQ2hhaW5Db250cm9sbGVyLnN0YXRlLmFjdGl2ZUNhaXBOZXR3b3JrO1xuICAgICAgICB0aGlzLm5ldHdvcmtJbWFnZSA9IEFzc2V0VXRpbC5nZXROZXR3b3JrSW1hZ2UodGhpcy5uZXR3b3JrKTtcbiAgICAgICAgdGhpcy5idWZmZXJpbmcgPSBmYWxzZTtcbiAgICAgICAgdGhpcy5zaG93QmFjayA9IGZhbHNlO1xuICAgICAgICB0aGlzLnByZXZIaXN0b3J5TGVuZ3RoID0gMTtcbiAgICAgICAgdGhpcy52aWV3ID0gUm91dGVyQ29udHJvbGxlci5zdGF0ZS52aWV3O1xuICAgICAgICB0aGlzLnZpZXdEaXJlY3Rpb24gPSAnJztcbiAgICAgICAgdGhpcy5oZWFkZXJUZXh0ID0gaGVhZGluZ3MoKVtSb3V0ZXJDb250cm9sbGVyLnN0YXRlLnZpZXddO1xuICAgICAgICB0aGlzLnVuc3Vic2NyaWJlLnB1c2goQXNzZXRDb250cm9sbGVyLnN1YnNjcmliZU5ldHdvcmtJbWFnZXMoKCkgPT4ge1xuICAgICAgICAgICAgdGhpcy5uZXR3b3JrSW1hZ2UgPSBBc3NldFV0aWwuZ2V0TmV0d29ya0ltYWdlKHRoaXMubmV0d29yayk7XG4gICAgICAgIH0pLCBSb3V0ZXJDb250cm9sbGVyLnN1YnNjcmliZUtleSgndmlldycsIHZhbCA9PiB7XG4gICAgICAgICAgICBzZXRUaW1lb3V0KCgpID0+IHtcbiAgICAgICAgICAgICAgICB0aGlzLnZpZXcgPSB2YWw7XG4gICAgICAgICAgICAgICAgdGhpcy5oZWFkZXJUZXh0ID0gaGVhZGluZ3MoKVt2YWxdO1xuICAgICAgICAgICAgfSwgQ29uc3RhbnRzVXRpbC5BTklNQVRJT05fRFVSQVRJT05TLkhlYWRlclRleHQpO1xuICAgICAgICAgICAgdGhpcy5vblZpZXdDaGFuZ2UoKTtcbiAgICAgICAgICAgIHRoaXMub25IaXN0b3J5Q2hhbmdlKCk7XG4gICAgICAgIH0pLCBDb25uZWN0aW9uQ29udHJvbGxlci5zdWJzY3JpYmVLZXkoJ2J1ZmZlcmluZycsIHZhbCA9PiAodGhpcy5idWZmZXJpbmcgPSB2YWwpKSwgQ2hhaW5Db250cm9sbGVyLnN1YnNjcmliZUtleSgnYWN0aXZlQ2FpcE5ldHdvcmsnLCB2YWwgPT4ge1xuICAgICAgICAgICAgdGhpcy5uZXR3b3JrID0gdmFsO1xuICAgICAgICAgICAgdGhpcy5uZXR3b3JrSW1hZ2UgPSBBc3NldFV0aWwuZ2V0TmV0d29ya0ltYWdlKHRoaXMubmV0d29yayk7XG4gICAgICAgIH0pKTtcbiAgICB9XG4gICAgZGlzY29ubmVjdENhbGxiYWNrKCkge1xuICAgICAgICB0aGlzLnVuc3Vic2NyaWJlLmZvckVhY2godW5zdWJzY3JpYmUgPT4gdW5zdWJzY3JpYmUoKSk7XG4gICAgfVxuICAgIHJlbmRlcigpIHtcbiAgICAgICAgcmV0dXJuIGh0bWwgYFxuICAgICAgPHd1aS1mbGV4IC5wYWRkaW5nPSR7dGhpcy5nZXRQYWRkaW5nKCl9IGp1c3RpZnlDb250ZW50PVwic3BhY2UtYmV0d2VlblwiIGFsaWduSXRlbXM9XCJjZW50ZXJcIj5cbiAgICAgICAgJHt0aGlzLmxlZnRIZWFkZXJUZW1wbGF0ZSgpfSAke3RoaXMudGl0bGVUZW1wbGF0ZSgpfSAke3RoaXMucmlnaHRIZWFkZXJUZW1wbGF0ZSgpfVxuICAgICAgPC93dWktZmxleD5cbiAgICBgO1xuICAgIH1cbiAgICBvbldhbGxldEhlbHAoKSB7XG4gICAgICAgIEV2ZW50c0NvbnRyb2xsZXIuc2VuZEV2ZW50KHsgdHlwZTogJ3RyYWNrJywgZXZlbnQ6ICdDTElDS19XQUxMRVRfSEVMUCcgfSk7XG4gICAgICAgIFJvdXRlckNvbnRyb2xsZXIucHVzaCgnV2hhdElzQVdhbGxldCcpO1xuICAgIH1cbiAgICBhc3luYyBvbkNsb3NlKCkge1xuICAgICAgICBjb25zdCBpc1Vuc3VwcG9ydGVkQ2hhaW4gPSBSb3V0ZXJDb250cm9sbGVyLnN0YXRlLnZpZXcgPT09ICdVbnN1cHBvcnRlZENoYWluJztcbiAgICAgICAgaWYgKGlzVW5zdXBwb3J0ZWRDaGFpbiB8fCAoYXdhaXQgU0lXWFV0aWwuaXNTSVdYQ2xvc2VEaXNhYmxlZCgpKSkge1xuICAgICAgICAgICAgTW9kYWxDb250cm9sbGVyLnNoYWtlKCk7XG4gICAgICAgIH1cbiAgICAgICAgZWxzZSB7XG4gICAgICAgICAgICBNb2RhbENvbnRyb2xsZXIuY2xvc2UoKTtcbiAgICAgICAgfVxuICAgIH1cbiAgICByaWdodEhlYWRlclRlbXBsYXRlKCkge1xuICAgICAgICBjb25zdCBpc1NtYXJ0U2Vzc2lvbnNFbmFibGVkID0gT3B0aW9uc0NvbnRyb2xsZXI/LnN0YXRlPy5mZWF0dXJlcz8uc21hcnRTZXNzaW9ucztcbiAgICAgICAgaWYgKFJvdXRlckNvbnRyb2xsZXIuc3RhdGUudmlldyAhPT0gJ0FjY291bnQnIHx8ICFpc1NtYXJ0U2Vzc2lvbnNFbmFibGVkKSB7XG4gICAgICAgICAgICByZXR1cm4gdGhpcy5jbG9zZUJ1dHRvblRlbXBsYXRlKCk7XG4gICAgICAgIH1cbiAgICAgICAgcmV0dXJuIGh0bWwgYDx3dWktZmxleD5cbiAgICAgIDx3dWktaWNvbi1saW5rXG4gICAgICAgIGljb249XCJjbG9ja1wiXG4gICAgICAgIEBjbGljaz0keygpID0+IFJvdXRlckNvbnRyb2xsZXIucHVzaCgnU21hcnRTZXNzaW9uTGlzdCcpfVxuICAgICAgICBkYXRhLXRlc3RpZD1cInczbS1oZWFkZXItc21hcnQtc2Vzc2lvbnNcIlxuICAgICAgPjwvd3VpLWljb24tbGluaz5cbiAgICAgICR7dGhpcy5jbG9zZUJ1dHRvblRlbXBsYXRlKCl9XG4gICAgPC93dWktZmxleD4gYDtcbiAgICB9XG4gICAgY2xvc2VCdXR0b25UZW1wbGF0ZSgpIHtcbiAgICAgICAgcmV0dXJuIGh0bWwgYFxuICAgICAgPHd1aS1pY29uLWxpbmtcbiAgICAgICAgP2Rpc2FibGVkPSR7dGhpcy5idWZmZXJpbmd9XG4gICAgICAgIGljb249XCJjbG9zZVwiXG4gICAgICAgIEBjbGljaz0ke3RoaXMub25DbG9zZS5iaW5kKHRoaXMpfVxuICAgICAgICBkYXRhLXRlc3RpZD1cInczbS1oZWFkZXItY2xvc2VcIlxuICAgICAgPjwvd3VpLWljb24tbGluaz5cbiAgICBgO1xuICAgIH1cbiAgICB0aXRsZVRlbXBsYXRlKCkge1xuICAgICAgICBjb25zdCBpc0JldGEgPSBCRVRBX1NDUkVFTlMuaW5jbHVkZXModGhpcy52aWV3KTtcbiAgICAgICAgcmV0dXJuIGh0bWwgYFxuICAgICAgPHd1aS1mbGV4XG4gICAgICAgIHZpZXctZGlyZWN0aW9uPVwiJHt0aGlzLnZpZXdEaXJlY3Rpb259XCJcbiAgICAgICAgY2xhc3M9XCJ3M20taGVhZGVyLXRpdGxlXCJcbiAgICAgICAgYWxpZ25JdGVtcz1cImNlbnRlclwiXG4gICAgICAgIGdhcD1cInhzXCJcbiAgICAgID5cbiAgICAgICAgPHd1aS10ZXh0IHZhcmlhbnQ9XCJwYXJhZ3JhcGgtNzAwXCIgY29sb3I9XCJmZy0xMDBcIiBkYXRhLXRlc3RpZD1cInczbS1oZWFkZXItdGV4dFwiXG4gICAgICAgICAgPiR7dGhpcy5oZWFkZXJUZXh0fTwvd3VpLXRleHRcbiAgICAgICAgPlxuICAgICAgICAke2lzQmV0YSA/IGh0bWwgYDx3dWktdGFnIHZhcmlhbnQ9XCJtYWluXCI+QmV0YTwvd3VpLXRhZz5gIDogbnVsbH1cbiAgICAgIDwvd3VpLWZsZXg+XG4gICAgYDtcbiAgICB9XG4gICAgbGVmdEhlYWRlclRlbXBsYXRlKCkge1xuICAgICAgICBjb25zdCB7IHZpZXcgfSA9IFJvdXRlckNvbnRyb2xsZXIuc3RhdGU7XG4gICAgICAgIGNvbnN0IGlzQ29ubmVjdEhlbHAgPSB2aWV3ID09PSAnQ29ubmVjdCc7XG4gICAgICAgIGNvbnN0IGlzRW1iZWRkZWRFbmFibGUgPSBPcHRpb25zQ29udHJvbGxlci5zdGF0ZS5lbmFibGVFbWJlZGRlZDtcbiAgICAgICAgY29uc3QgaXNBcHByb3ZlVHJhbnNhY3Rpb24gPSB2aWV3ID09PSAnQXBwcm92ZVRyYW5zYWN0aW9uJztcbiAgICAgICAgY29uc3QgaXNDb25uZWN0aW5nU0lXRVZpZXcgPSB2aWV3ID09PSAnQ29ubmVjdGluZ1Npd2UnO1xuICAgICAgICBjb25zdCBpc0FjY291bnRWaWV3ID0gdmlldyA9PT0gJ0FjY291bnQnO1xuICAgICAgICBjb25zdCBlbmFibGVOZXR3b3JrU3dpdGNoID0gT3B0aW9uc0NvbnRyb2xsZXIuc3RhdGUuZW5hYmxlTmV0d29ya1N3aXRjaDtcbiAgICAgICAgY29uc3Qgc2hvdWxkSGlkZUJhY2sgPSBpc0FwcHJvdmVUcmFuc2FjdGlvbiB8fCBpc0Nvbm5lY3RpbmdTSVdFVmlldyB8fCAoaXNDb25uZWN0SGVscCAmJiBpc0VtYmVkZGVkRW5hYmxlKTtcbiAgICAgICAgaWYgKGlzQWNjb3VudFZpZXcgJiYgZW5hYmxlTmV0d29ya1N3aXRjaCkge1xuICAgICAgICAgICAgcmV0dXJuIGh0bWwgYDx3dWktc2VsZWN0XG4gICAgICAgIGlkPVwiZHluYW1pY1wiXG4gICAgICAgIGRhdGEtdGVzdGlkPVwidzNtLWFjY291bnQtc2VsZWN0LW5ldHdvcmtcIlxuICAgICAgICBhY3RpdmUtbmV0d29yaz0ke2lmRGVmaW5lZCh0aGlzLm5ldHdvcms/Lm5hbWUpfVxuICAgICAgICBAY2xpY2s9JHt0aGlzLm9uTmV0d29ya3MuYmluZCh0aGlzKX1cbiAgICAgICAgaW1hZ2VTcmM9JHtpZkRlZmluZWQodGhpcy5uZXR3b3JrSW1hZ2UpfVxuICAgICAgPjwvd3VpLXNlbGVjdD5gO1xuICAgICAgICB9XG4gICAgICAgIGlmICh0aGlzLnNob3dCYWNrICYmICFzaG91bGRIaWRlQmFjaykge1xuICAgICAgICAgICAgcmV0dXJuIGh0bWwgYDx3dWktaWNvbi1saW5rXG4gICAgICAgIGRhdGEtdGVzdGlkPVwiaGVhZGVyLWJhY2tcIlxuICAgICAgICBpZD1cImR5bmFtaWNcIlxuICAgICAgICBpY29uPVwiY2hldnJvbkxlZnRcIlxuICAgICAgICA/ZGlzYWJsZWQ9JHt0aGlzLmJ1ZmZlcmluZ31cbiAgICAgICAgQGNsaWNrPSR7dGhpcy5vbkdvQmFjay5iaW5kKHRoaXMpfVxuICAgICAgPjwvd3VpLWljb24tbGluaz5gO1xuICAgICAgICB9XG4gICAgICAgIHJldHVybiBodG1sIGA8d3VpLWljb24tbGlua1xuICAgICAgZGF0YS1oaWRkZW49JHshaXNDb25uZWN0SGVscH1cbiAgICAgIGlkPVwiZHluYW1pY1wiXG4gICAgICBpY29uPVwiaGVscENpcmNsZVwiXG4gICAgICBAY2xpY2s9JHt0aGlzLm9uV2FsbGV0SGVscC5iaW5kKHRoaXMpfVxuICAgID48L3d1aS1pY29uLWxpbms+YDtcbiAgICB9XG4gICAgb25OZXR3b3JrcygpIHtcbiAgICAgICAgaWYgKHRoaXMuaXNBbGxvd2VkTmV0d29ya1N3aXRjaCgpKSB7XG4gICAgICAgICAgICBFdmVudHNDb250cm9sbGVyLnNlbmRFdmVudCh7IHR5cGU6ICd0cmFjaycsIGV2ZW50OiAnQ0xJQ0tfTkVUV09SS1MnIH0pO1xuICAgICAgICAgICAgUm91dGVyQ29udHJvbGxlci5wdXNoKCdOZXR3b3JrcycpO1xuICAgICAgICB9XG4gICAgfVxuICAgIGlzQWxsb3dlZE5ldHdvcmtTd2l0Y2goKSB7XG4gICAgICAgIGNvbnN0IHJlcXVlc3RlZENhaXBOZXR3b3JrcyA9IENoYWluQ29udHJvbGxlci5nZXRBbGxSZXF1ZXN0ZWRDYWlwTmV0d29ya3MoKTtcbiAgICAgICAgY29uc3QgaXNNdWx0aU5ldHdvcmsgPSByZXF1ZXN0ZWRDYWlwTmV0d29ya3MgPyByZXF1ZXN0ZWRDYWlwTmV0d29ya3MubGVuZ3RoID4gMSA6IGZhbHNlO1xuICAgICAgICBjb25zdCBpc1ZhbGlkTmV0d29yayA9IHJlcXVlc3RlZENhaXBOZXR3b3Jrcz8uZmluZCgoeyBpZCB9KSA9PiBpZCA9PT0gdGhpcy5uZXR3b3JrPy5pZCk7XG4gICAgICAgIHJldHVybiBpc011bHRpTmV0d29yayB8fCAhaXNWYWxpZE5ldHdvcms7XG4gICAgfVxuICAgIGdldFBhZGRpbmcoKSB7XG4gICAgICAgIGlmICh0aGlzLmhlYWRpbmcpIHtcbiAgICAgICAgICAgIHJldHVybiBbJ2wnLCAnMmwnLCAnbCcsICcybCddO1xuICAgICAgICB9XG4gICAgICAgIHJldHVybiBbJzAnLCAnMmwnLCAnMCcsICcybCddO1xuICAgIH1cbiAgICBvblZpZXdDaGFuZ2UoKSB7XG4gICAgICAgIGNvbnN0IHsgaGlzdG9yeSB9ID0gUm91dGVyQ29udHJvbGxlci5zdGF0ZTtcbiAgICAgICAgbGV0IGRpcmVjdGlvbiA9IENvbnN0YW50c1V0aWwuVklFV19ESVJFQ1RJT04uTmV4dDtcbiAgICAgICAgaWYgKGhpc3RvcnkubGVuZ3RoIDwgdGhpcy5wcmV2SGlzdG9yeUxlbmd0aCkge1xuICAgICAgICAgICAgZGlyZWN0aW9uID0gQ29uc3RhbnRzVXRpbC5WSUVXX0RJUkVDVElPTi5QcmV2O1xuICAgICAgICB9XG4gICAgICAgIHRoaXMucHJldkhpc3RvcnlMZW5ndGggPSBoaXN0b3J5Lmxlbmd0aDtcbiAgICAgICAgdGhpcy52aWV3RGlyZWN0aW9uID0gZGlyZWN0aW9uO1xuICAgIH1cbiAgICBhc3luYyBvbkhpc3RvcnlDaGFuZ2UoKSB7XG4gICAgICAgIGNvbnN0IHsgaGlzdG9yeSB9ID0gUm91dGVyQ29udHJvbGxlci5zdGF0ZTtcbiAgICAgICAgY29uc3QgYnV0dG9uRWwgPSB0aGlzLnNoYWRvd1Jvb3Q/LnF1ZXJ5U2VsZWN0b3IoJyNkeW5hbWljJyk7XG4gICAgICAgIGlmIChoaXN0b3J5Lmxlbmd0aCA+IDEgJiYgIXRoaXMuc2hvd0JhY2sgJiYgYnV0dG9uRWwpIHtcbiAgICAgICAgICAgIGF3YWl0IGJ1dHRvbkVsLmFuaW1hdGUoW3sgb3BhY2l0eTogMSB9LCB7IG9wYWNpdHk6IDAgfV0sIHtcbiAgICAgICAgICAgICAgICBkdXJhdGlvbjogMjAwLFxuICAgICAgICAgICAgICAgIGZpbGw6ICdmb3J3YXJkcycsXG4gICAgICAgICAgICAgICAgZWFzaW5nOiAnZWFzZSdcbiAgICAgICAgICAgIH0pLmZpbmlzaGVkO1xuICAgICAgICAgICAgdGhpcy5zaG93QmFjayA9IHRydWU7XG4gICAgICAgICAgICBidXR0b25FbC5hbmltYXRlKFt7IG9wYWNpdHk6IDAgfSwgeyBvcGFjaXR5OiAxIH1dLCB7XG4gICAgICAgICAgICAgICAgZHVyYXRpb246IDIwMCxcbiAgICAgICAgICAgICAgICBmaWxsOiAnZm9yd2FyZHMnLFxuICAgICAgICAgICAgICAgIGVhc2luZzogJ2Vhc2UnXG4gICAgICAgICAgICB9KTtcbiAgICAgICAgfVxuICAgICAgICBlbHNlIGlmIChoaXN0b3J5Lmxlbmd0aCA8PSAxICYmIHRoaXMuc2hvd0JhY2sgJiYgYnV0dG9uRWwpIHtcbiAgICAgICAgICAgIGF3YWl0IGJ1dHRvbkVsLmFuaW1hdGUoW3sgb3BhY2l0eTogMSB9LCB7IG9wYWNpdHk6IDAgfV0sIHtcbiAgICAgICAgICAgICAgICBkdXJhdGlvbjogMjAwLFxuICAgICAgICAgICAgICAgIGZpbGw6ICdmb3J3YXJkcycsXG4gICAgICAgICAgICAgICAgZWFzaW5nOiAnZWFzZSdcbiAgICAgICAgICAgIH0pLmZpbmlzaGVkO1xuICAgICAgICAgICAgdGhpcy5zaG93QmFjayA9IGZhbHNlO1xuICAgICAgICAgICAgYnV0dG9uRWwuYW5pbWF0ZShbeyBvcGFjaXR5OiAwIH0sIHsgb3BhY2l0eTogMSB9XSwge1xuICAgICAgICAgICAgICAgIGR1cmF0aW9uOiAyMDAsXG4gICAgICAgICAgICAgICAgZmlsbDogJ2ZvcndhcmRzJyxcbiAgICAgICAgICAgICAgICBlYXNpbmc6ICdlYXNlJ1xuICAgICAgICAgICAgfSk7XG4gICAgICAgIH1cbiAgICB9XG4gICAgb25Hb0JhY2soKSB7XG4gICAgICAgIFJvdXRlckNvbnRyb2xsZXIuZ29CYWNrKCk7XG4gICAgfVxufTtcblczbUhlYWRlci5zdHlsZXMgPSBzdHlsZXM7XG5fX2RlY29yYXRlKFtcbiAgICBzdGF0ZSgpXG5dLCBXM21IZWFkZXIucHJvdG90eXBlLCBcImhlYWRpbmdcIiwgdm9pZCAwKTtcbl9fZGVjb3JhdGUoW1xuICAgIHN0YXRlKClcbl0sIFczbUhlYWRlci5wcm90b3R5cGUsIFwibmV0d29ya1wiLCB2b2lkIDApO1xuX19kZWNvcmF0ZShbXG4gICAgc3RhdGUoKVxuXSwgVzNtSGVhZGVyLnByb3RvdHlwZSwgXCJuZXR3b3JrSW1hZ2VcIiwgdm9pZCAwKTtcbl9fZGVjb3JhdGUoW1xuICAgIHN0YXRlKClcbl0sIFczbUhlYWRlci5wcm90b3R5cGUsIFwiYnVmZmVyaW5nXCIsIHZvaWQgMCk7XG5fX2RlY29yYXRlKFtcbiAgICBzdGF0ZSgpXG5dLCBXM21IZWFkZXIucHJvdG90eXBlLCBcInNob3dCYWNrXCIsIHZvaWQgMCk7XG5fX2RlY29yYXRlKFtcbiAgICBzdGF0ZSgpXG5dLCBXM21IZWFkZXIucHJvdG90eXBlLCBcInByZXZIaXN0b3J5TGVuZ3RoXCIsIHZvaWQgMCk7XG5fX2RlY29yYXRlKFtcbiAgICBzdGF0ZSgpXG5dLCBXM21IZWFkZXIucHJvdG90eXBlLCBcInZpZXdcIiwgdm9pZCAwKTtcbl9fZGVjb3JhdGUoW1xuICAgIHN0YXRlKClcbl0sIFczbUhlYWRlci5wcm90b3R5cGUsIFwidmlld0RpcmVjdGlvblwiLCB2b2lkIDApO1xuX19kZWNvcmF0ZShbXG4gICAgc3RhdGUoKVxuXSwgVzNtSGVhZGVyLnByb3RvdHlwZSwgXCJoZWFkZXJUZXh0XCIsIHZvaWQgMCk7XG5XM21IZWFkZXIgPSBfX2RlY29yYXRlKFtcbiAgICBjdXN0b21FbGVtZW50KCd3M20taGVhZGVyJylcbl0sIFczbUhlYWRlcik7XG5leHBvcnQgeyBXM21IZWFkZXIgfTtcbi8vIyBzb3VyY2VNYXBwaW5nVVJMPWluZGV4LmpzLm1hcCJdLCJuYW1lcyI6W10sInNvdXJjZVJvb3QiOiIifQ==\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-scaffold-ui/dist/esm/src/partials/w3m-header/index.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@reown/appkit-scaffold-ui/dist/esm/src/partials/w3m-header/styles.js":
-/*!*******************************************************************************************!*\
-  !*** ./node_modules/@reown/appkit-scaffold-ui/dist/esm/src/partials/w3m-header/styles.js ***!
-  \*******************************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n\n/* harmony default export */ __webpack_exports__[\"default\"] = ((0,lit__WEBPACK_IMPORTED_MODULE_0__.css) `\n  :host {\n    height: 64px;\n  }\n\n  wui-text {\n    text-transform: capitalize;\n  }\n\n  wui-flex.w3m-header-title {\n    transform: translateY(0);\n    opacity: 1;\n  }\n\n  wui-flex.w3m-header-title[view-direction='prev'] {\n    animation:\n      slide-down-out 120ms forwards var(--wui-ease-out-power-2),\n      slide-down-in 120ms forwards var(--wui-ease-out-power-2);\n    animation-delay: 0ms, 200ms;\n  }\n\n  wui-flex.w3m-header-title[view-direction='next'] {\n    animation:\n      slide-up-out 120ms forwards var(--wui-ease-out-power-2),\n      slide-up-in 120ms forwards var(--wui-ease-out-power-2);\n    animation-delay: 0ms, 200ms;\n  }\n\n  wui-icon-link[data-hidden='true'] {\n    opacity: 0 !important;\n    pointer-events: none;\n  }\n\n  @keyframes slide-up-out {\n    from {\n      transform: translateY(0px);\n      opacity: 1;\n    }\n    to {\n      transform: translateY(3px);\n      opacity: 0;\n    }\n  }\n\n  @keyframes slide-up-in {\n    from {\n      transform: translateY(-3px);\n      opacity: 0;\n    }\n    to {\n      transform: translateY(0);\n      opacity: 1;\n    }\n  }\n\n  @keyframes slide-down-out {\n    from {\n      transform: translateY(0px);\n      opacity: 1;\n    }\n    to {\n      transform: translateY(-3px);\n      opacity: 0;\n    }\n  }\n\n  @keyframes slide-down-in {\n    from {\n      transform: translateY(3px);\n      opacity: 0;\n    }\n    to {\n      transform: translateY(0);\n      opacity: 1;\n    }\n  }\n`);\n//# sourceMappingURL=styles.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC1zY2FmZm9sZC11aS9kaXN0L2VzbS9zcmMvcGFydGlhbHMvdzNtLWhlYWRlci9zdHlsZXMuanMiLCJtYXBwaW5ncyI6Ijs7QUFBMEI7QUFDMUIsK0RBQWUsd0NBQUc7QUFDbEI7QUFDQTtBQUNBOztBQUVBO0FBQ0E7QUFDQTs7QUFFQTtBQUNBO0FBQ0E7QUFDQTs7QUFFQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7O0FBRUE7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBOztBQUVBO0FBQ0E7QUFDQTtBQUNBOztBQUVBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBOztBQUVBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBOztBQUVBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBOztBQUVBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0EsQ0FBQyxFQUFDO0FBQ0YiLCJzb3VyY2VzIjpbIndlYnBhY2s6Ly9fTl9FLy4vbm9kZV9tb2R1bGVzL0ByZW93bi9hcHBraXQtc2NhZmZvbGQtdWkvZGlzdC9lc20vc3JjL3BhcnRpYWxzL3czbS1oZWFkZXIvc3R5bGVzLmpzP2ZmN2IiXSwic291cmNlc0NvbnRlbnQiOlsiaW1wb3J0IHsgY3NzIH0gZnJvbSAnbGl0JztcbmV4cG9ydCBkZWZhdWx0IGNzcyBgXG4gIDpob3N0IHtcbiAgICBoZWlnaHQ6IDY0cHg7XG4gIH1cblxuICB3dWktdGV4dCB7XG4gICAgdGV4dC10cmFuc2Zvcm06IGNhcGl0YWxpemU7XG4gIH1cblxuICB3dWktZmxleC53M20taGVhZGVyLXRpdGxlIHtcbiAgICB0cmFuc2Zvcm06IHRyYW5zbGF0ZVkoMCk7XG4gICAgb3BhY2l0eTogMTtcbiAgfVxuXG4gIHd1aS1mbGV4LnczbS1oZWFkZXItdGl0bGVbdmlldy1kaXJlY3Rpb249J3ByZXYnXSB7XG4gICAgYW5pbWF0aW9uOlxuICAgICAgc2xpZGUtZG93bi1vdXQgMTIwbXMgZm9yd2FyZHMgdmFyKC0td3VpLWVhc2Utb3V0LXBvd2VyLTIpLFxuICAgICAgc2xpZGUtZG93bi1pbiAxMjBtcyBmb3J3YXJkcyB2YXIoLS13dWktZWFzZS1vdXQtcG93ZXItMik7XG4gICAgYW5pbWF0aW9uLWRlbGF5OiAwbXMsIDIwMG1zO1xuICB9XG5cbiAgd3VpLWZsZXgudzNtLWhlYWRlci10aXRsZVt2aWV3LWRpcmVjdGlvbj0nbmV4dCddIHtcbiAgICBhbmltYXRpb246XG4gICAgICBzbGlkZS11cC1vdXQgMTIwbXMgZm9yd2FyZHMgdmFyKC0td3VpLWVhc2Utb3V0LXBvd2VyLTIpLFxuICAgICAgc2xpZGUtdXAtaW4gMTIwbXMgZm9yd2FyZHMgdmFyKC0td3VpLWVhc2Utb3V0LXBvd2VyLTIpO1xuICAgIGFuaW1hdGlvbi1kZWxheTogMG1zLCAyMDBtcztcbiAgfVxuXG4gIHd1aS1pY29uLWxpbmtbZGF0YS1oaWRkZW49J3RydWUnXSB7XG4gICAgb3BhY2l0eTogMCAhaW1wb3J0YW50O1xuICAgIHBvaW50ZXItZXZlbnRzOiBub25lO1xuICB9XG5cbiAgQGtleWZyYW1lcyBzbGlkZS11cC1vdXQge1xuICAgIGZyb20ge1xuICAgICAgdHJhbnNmb3JtOiB0cmFuc2xhdGVZKDBweCk7XG4gICAgICBvcGFjaXR5OiAxO1xuICAgIH1cbiAgICB0byB7XG4gICAgICB0cmFuc2Zvcm06IHRyYW5zbGF0ZVkoM3B4KTtcbiAgICAgIG9wYWNpdHk6IDA7XG4gICAgfVxuICB9XG5cbiAgQGtleWZyYW1lcyBzbGlkZS11cC1pbiB7XG4gICAgZnJvbSB7XG4gICAgICB0cmFuc2Zvcm06IHRyYW5zbGF0ZVkoLTNweCk7XG4gICAgICBvcGFjaXR5OiAwO1xuICAgIH1cbiAgICB0byB7XG4gICAgICB0cmFuc2Zvcm06IHRyYW5zbGF0ZVkoMCk7XG4gICAgICBvcGFjaXR5OiAxO1xuICAgIH1cbiAgfVxuXG4gIEBrZXlmcmFtZXMgc2xpZGUtZG93bi1vdXQge1xuICAgIGZyb20ge1xuICAgICAgdHJhbnNmb3JtOiB0cmFuc2xhdGVZKDBweCk7XG4gICAgICBvcGFjaXR5OiAxO1xuICAgIH1cbiAgICB0byB7XG4gICAgICB0cmFuc2Zvcm06IHRyYW5zbGF0ZVkoLTNweCk7XG4gICAgICBvcGFjaXR5OiAwO1xuICAgIH1cbiAgfVxuXG4gIEBrZXlmcmFtZXMgc2xpZGUtZG93bi1pbiB7XG4gICAgZnJvbSB7XG4gICAgICB0cmFuc2Zvcm06IHRyYW5zbGF0ZVkoM3B4KTtcbiAgICAgIG9wYWNpdHk6IDA7XG4gICAgfVxuICAgIHRvIHtcbiAgICAgIHRyYW5zZm9ybTogdHJhbnNsYXRlWSgwKTtcbiAgICAgIG9wYWNpdHk6IDE7XG4gICAgfVxuICB9XG5gO1xuLy8jIHNvdXJjZU1hcHBpbmdVUkw9c3R5bGVzLmpzLm1hcCJdLCJuYW1lcyI6W10sInNvdXJjZVJvb3QiOiIifQ==\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-scaffold-ui/dist/esm/src/partials/w3m-header/styles.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@reown/appkit-scaffold-ui/dist/esm/src/partials/w3m-snackbar/index.js":
-/*!********************************************************************************************!*\
-  !*** ./node_modules/@reown/appkit-scaffold-ui/dist/esm/src/partials/w3m-snackbar/index.js ***!
-  \********************************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   W3mSnackBar: function() { return /* binding */ W3mSnackBar; }\n/* harmony export */ });\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n/* harmony import */ var lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__ = __webpack_require__(/*! lit/decorators.js */ \"./node_modules/lit/decorators.js\");\n/* harmony import */ var _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_5__ = __webpack_require__(/*! @reown/appkit-controllers */ \"./node_modules/@reown/appkit-controllers/dist/esm/exports/index.js\");\n/* harmony import */ var _reown_appkit_ui__WEBPACK_IMPORTED_MODULE_2__ = __webpack_require__(/*! @reown/appkit-ui */ \"./node_modules/@reown/appkit-ui/dist/esm/exports/index.js\");\n/* harmony import */ var _reown_appkit_ui_wui_snackbar__WEBPACK_IMPORTED_MODULE_3__ = __webpack_require__(/*! @reown/appkit-ui/wui-snackbar */ \"./node_modules/@reown/appkit-ui/dist/esm/exports/wui-snackbar.js\");\n/* harmony import */ var _styles_js__WEBPACK_IMPORTED_MODULE_4__ = __webpack_require__(/*! ./styles.js */ \"./node_modules/@reown/appkit-scaffold-ui/dist/esm/src/partials/w3m-snackbar/styles.js\");\nvar __decorate = (undefined && undefined.__decorate) || function (decorators, target, key, desc) {\n    var c = arguments.length, r = c < 3 ? target : desc === null ? desc = Object.getOwnPropertyDescriptor(target, key) : desc, d;\n    if (typeof Reflect === \"object\" && typeof Reflect.decorate === \"function\") r = Reflect.decorate(decorators, target, key, desc);\n    else for (var i = decorators.length - 1; i >= 0; i--) if (d = decorators[i]) r = (c < 3 ? d(r) : c > 3 ? d(target, key, r) : d(target, key)) || r;\n    return c > 3 && r && Object.defineProperty(target, key, r), r;\n};\n\n\n\n\n\n\nconst presets = {\n    loading: undefined,\n    success: {\n        backgroundColor: 'success-100',\n        iconColor: 'success-100',\n        icon: 'checkmark'\n    },\n    error: {\n        backgroundColor: 'error-100',\n        iconColor: 'error-100',\n        icon: 'close'\n    }\n};\nlet W3mSnackBar = class W3mSnackBar extends lit__WEBPACK_IMPORTED_MODULE_0__.LitElement {\n    constructor() {\n        super();\n        this.unsubscribe = [];\n        this.timeout = undefined;\n        this.open = _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_5__.SnackController.state.open;\n        this.unsubscribe.push(_reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_5__.SnackController.subscribeKey('open', val => {\n            this.open = val;\n            this.onOpen();\n        }));\n    }\n    disconnectedCallback() {\n        clearTimeout(this.timeout);\n        this.unsubscribe.forEach(unsubscribe => unsubscribe());\n    }\n    render() {\n        const { message, variant, svg } = _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_5__.SnackController.state;\n        const preset = presets[variant];\n        const { icon, iconColor } = svg ?? preset ?? {};\n        return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `\n      <wui-snackbar\n        message=${message}\n        backgroundColor=${preset?.backgroundColor}\n        iconColor=${iconColor}\n        icon=${icon}\n        .loading=${variant === 'loading'}\n      ></wui-snackbar>\n    `;\n    }\n    onOpen() {\n        clearTimeout(this.timeout);\n        if (this.open) {\n            this.animate([\n                { opacity: 0, transform: 'translateX(-50%) scale(0.85)' },\n                { opacity: 1, transform: 'translateX(-50%) scale(1)' }\n            ], {\n                duration: 150,\n                fill: 'forwards',\n                easing: 'ease'\n            });\n            if (this.timeout) {\n                clearTimeout(this.timeout);\n            }\n            if (_reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_5__.SnackController.state.autoClose) {\n                this.timeout = setTimeout(() => _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_5__.SnackController.hide(), 2500);\n            }\n        }\n        else {\n            this.animate([\n                { opacity: 1, transform: 'translateX(-50%) scale(1)' },\n                { opacity: 0, transform: 'translateX(-50%) scale(0.85)' }\n            ], {\n                duration: 150,\n                fill: 'forwards',\n                easing: 'ease'\n            });\n        }\n    }\n};\nW3mSnackBar.styles = _styles_js__WEBPACK_IMPORTED_MODULE_4__[\"default\"];\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.state)()\n], W3mSnackBar.prototype, \"open\", void 0);\nW3mSnackBar = __decorate([\n    (0,_reown_appkit_ui__WEBPACK_IMPORTED_MODULE_2__.customElement)('w3m-snackbar')\n], W3mSnackBar);\n\n//# sourceMappingURL=index.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC1zY2FmZm9sZC11aS9kaXN0L2VzbS9zcmMvcGFydGlhbHMvdzNtLXNuYWNrYmFyL2luZGV4LmpzIiwibWFwcGluZ3MiOiI7Ozs7Ozs7Ozs7QUFBQSxrQkFBa0IsU0FBSSxJQUFJLFNBQUk7QUFDOUI7QUFDQTtBQUNBLDZDQUE2QyxRQUFRO0FBQ3JEO0FBQ0E7QUFDdUM7QUFDRztBQUNrQjtBQUNYO0FBQ1Y7QUFDTjtBQUNqQztBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQSxLQUFLO0FBQ0w7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0EsNENBQTRDLDJDQUFVO0FBQ3REO0FBQ0E7QUFDQTtBQUNBO0FBQ0Esb0JBQW9CLHNFQUFlO0FBQ25DLDhCQUE4QixzRUFBZTtBQUM3QztBQUNBO0FBQ0EsU0FBUztBQUNUO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBLGdCQUFnQix3QkFBd0IsRUFBRSxzRUFBZTtBQUN6RDtBQUNBLGdCQUFnQixrQkFBa0I7QUFDbEMsZUFBZSx5Q0FBSTtBQUNuQjtBQUNBLGtCQUFrQjtBQUNsQiwwQkFBMEI7QUFDMUIsb0JBQW9CO0FBQ3BCLGVBQWU7QUFDZixtQkFBbUI7QUFDbkI7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQSxrQkFBa0IsdURBQXVEO0FBQ3pFLGtCQUFrQjtBQUNsQjtBQUNBO0FBQ0E7QUFDQTtBQUNBLGFBQWE7QUFDYjtBQUNBO0FBQ0E7QUFDQSxnQkFBZ0Isc0VBQWU7QUFDL0IsZ0RBQWdELHNFQUFlO0FBQy9EO0FBQ0E7QUFDQTtBQUNBO0FBQ0Esa0JBQWtCLG9EQUFvRDtBQUN0RSxrQkFBa0I7QUFDbEI7QUFDQTtBQUNBO0FBQ0E7QUFDQSxhQUFhO0FBQ2I7QUFDQTtBQUNBO0FBQ0EscUJBQXFCLGtEQUFNO0FBQzNCO0FBQ0EsSUFBSSx3REFBSztBQUNUO0FBQ0E7QUFDQSxJQUFJLCtEQUFhO0FBQ2pCO0FBQ3VCO0FBQ3ZCIiwic291cmNlcyI6WyJ3ZWJwYWNrOi8vX05fRS8uL25vZGVfbW9kdWxlcy9AcmVvd24vYXBwa2l0LXNjYWZmb2xkLXVpL2Rpc3QvZXNtL3NyYy9wYXJ0aWFscy93M20tc25hY2tiYXIvaW5kZXguanM/ZTcxYiJdLCJzb3VyY2VzQ29udGVudCI6WyJ2YXIgX19kZWNvcmF0ZSA9ICh0aGlzICYmIHRoaXMuX19kZWNvcmF0ZSkgfHwgZnVuY3Rpb24gKGRlY29yYXRvcnMsIHRhcmdldCwga2V5LCBkZXNjKSB7XG4gICAgdmFyIGMgPSBhcmd1bWVudHMubGVuZ3RoLCByID0gYyA8IDMgPyB0YXJnZXQgOiBkZXNjID09PSBudWxsID8gZGVzYyA9IE9iamVjdC5nZXRPd25Qcm9wZXJ0eURlc2NyaXB0b3IodGFyZ2V0LCBrZXkpIDogZGVzYywgZDtcbiAgICBpZiAodHlwZW9mIFJlZmxlY3QgPT09IFwib2JqZWN0XCIgJiYgdHlwZW9mIFJlZmxlY3QuZGVjb3JhdGUgPT09IFwiZnVuY3Rpb25cIikgciA9IFJlZmxlY3QuZGVjb3JhdGUoZGVjb3JhdG9ycywgdGFyZ2V0LCBrZXksIGRlc2MpO1xuICAgIGVsc2UgZm9yICh2YXIgaSA9IGRlY29yYXRvcnMubGVuZ3RoIC0gMTsgaSA+PSAwOyBpLS0pIGlmIChkID0gZGVjb3JhdG9yc1tpXSkgciA9IChjIDwgMyA/IGQocikgOiBjID4gMyA/IGQodGFyZ2V0LCBrZXksIHIpIDogZCh0YXJnZXQsIGtleSkpIHx8IHI7XG4gICAgcmV0dXJuIGMgPiAzICYmIHIgJiYgT2JqZWN0LmRlZmluZVByb3BlcnR5KHRhcmdldCwga2V5LCByKSwgcjtcbn07XG5pbXBvcnQgeyBMaXRFbGVtZW50LCBodG1sIH0gZnJvbSAnbGl0JztcbmltcG9ydCB7IHN0YXRlIH0gZnJvbSAnbGl0L2RlY29yYXRvcnMuanMnO1xuaW1wb3J0IHsgU25hY2tDb250cm9sbGVyIH0gZnJvbSAnQHJlb3duL2FwcGtpdC1jb250cm9sbGVycyc7XG5pbXBvcnQgeyBjdXN0b21FbGVtZW50IH0gZnJvbSAnQHJlb3duL2FwcGtpdC11aSc7XG5pbXBvcnQgJ0ByZW93bi9hcHBraXQtdWkvd3VpLXNuYWNrYmFyJztcbmltcG9ydCBzdHlsZXMgZnJvbSAnLi9zdHlsZXMuanMnO1xuY29uc3QgcHJlc2V0cyA9IHtcbiAgICBsb2FkaW5nOiB1bmRlZmluZWQsXG4gICAgc3VjY2Vzczoge1xuICAgICAgICBiYWNrZ3JvdW5kQ29sb3I6ICdzdWNjZXNzLTEwMCcsXG4gICAgICAgIGljb25Db2xvcjogJ3N1Y2Nlc3MtMTAwJyxcbiAgICAgICAgaWNvbjogJ2NoZWNrbWFyaydcbiAgICB9LFxuICAgIGVycm9yOiB7XG4gICAgICAgIGJhY2tncm91bmRDb2xvcjogJ2Vycm9yLTEwMCcsXG4gICAgICAgIGljb25Db2xvcjogJ2Vycm9yLTEwMCcsXG4gICAgICAgIGljb246ICdjbG9zZSdcbiAgICB9XG59O1xubGV0IFczbVNuYWNrQmFyID0gY2xhc3MgVzNtU25hY2tCYXIgZXh0ZW5kcyBMaXRFbGVtZW50IHtcbiAgICBjb25zdHJ1Y3RvcigpIHtcbiAgICAgICAgc3VwZXIoKTtcbiAgICAgICAgdGhpcy51bnN1YnNjcmliZSA9IFtdO1xuICAgICAgICB0aGlzLnRpbWVvdXQgPSB1bmRlZmluZWQ7XG4gICAgICAgIHRoaXMub3BlbiA9IFNuYWNrQ29udHJvbGxlci5zdGF0ZS5vcGVuO1xuICAgICAgICB0aGlzLnVuc3Vic2NyaWJlLnB1c2goU25hY2tDb250cm9sbGVyLnN1YnNjcmliZUtleSgnb3BlbicsIHZhbCA9PiB7XG4gICAgICAgICAgICB0aGlzLm9wZW4gPSB2YWw7XG4gICAgICAgICAgICB0aGlzLm9uT3BlbigpO1xuICAgICAgICB9KSk7XG4gICAgfVxuICAgIGRpc2Nvbm5lY3RlZENhbGxiYWNrKCkge1xuICAgICAgICBjbGVhclRpbWVvdXQodGhpcy50aW1lb3V0KTtcbiAgICAgICAgdGhpcy51bnN1YnNjcmliZS5mb3JFYWNoKHVuc3Vic2NyaWJlID0+IHVuc3Vic2NyaWJlKCkpO1xuICAgIH1cbiAgICByZW5kZXIoKSB7XG4gICAgICAgIGNvbnN0IHsgbWVzc2FnZSwgdmFyaWFudCwgc3ZnIH0gPSBTbmFja0NvbnRyb2xsZXIuc3RhdGU7XG4gICAgICAgIGNvbnN0IHByZXNldCA9IHByZXNldHNbdmFyaWFudF07XG4gICAgICAgIGNvbnN0IHsgaWNvbiwgaWNvbkNvbG9yIH0gPSBzdmcgPz8gcHJlc2V0ID8/IHt9O1xuICAgICAgICByZXR1cm4gaHRtbCBgXG4gICAgICA8d3VpLXNuYWNrYmFyXG4gICAgICAgIG1lc3NhZ2U9JHttZXNzYWdlfVxuICAgICAgICBiYWNrZ3JvdW5kQ29sb3I9JHtwcmVzZXQ/LmJhY2tncm91bmRDb2xvcn1cbiAgICAgICAgaWNvbkNvbG9yPSR7aWNvbkNvbG9yfVxuICAgICAgICBpY29uPSR7aWNvbn1cbiAgICAgICAgLmxvYWRpbmc9JHt2YXJpYW50ID09PSAnbG9hZGluZyd9XG4gICAgICA+PC93dWktc25hY2tiYXI+XG4gICAgYDtcbiAgICB9XG4gICAgb25PcGVuKCkge1xuICAgICAgICBjbGVhclRpbWVvdXQodGhpcy50aW1lb3V0KTtcbiAgICAgICAgaWYgKHRoaXMub3Blbikge1xuICAgICAgICAgICAgdGhpcy5hbmltYXRlKFtcbiAgICAgICAgICAgICAgICB7IG9wYWNpdHk6IDAsIHRyYW5zZm9ybTogJ3RyYW5zbGF0ZVgoLTUwJSkgc2NhbGUoMC44NSknIH0sXG4gICAgICAgICAgICAgICAgeyBvcGFjaXR5OiAxLCB0cmFuc2Zvcm06ICd0cmFuc2xhdGVYKC01MCUpIHNjYWxlKDEpJyB9XG4gICAgICAgICAgICBdLCB7XG4gICAgICAgICAgICAgICAgZHVyYXRpb246IDE1MCxcbiAgICAgICAgICAgICAgICBmaWxsOiAnZm9yd2FyZHMnLFxuICAgICAgICAgICAgICAgIGVhc2luZzogJ2Vhc2UnXG4gICAgICAgICAgICB9KTtcbiAgICAgICAgICAgIGlmICh0aGlzLnRpbWVvdXQpIHtcbiAgICAgICAgICAgICAgICBjbGVhclRpbWVvdXQodGhpcy50aW1lb3V0KTtcbiAgICAgICAgICAgIH1cbiAgICAgICAgICAgIGlmIChTbmFja0NvbnRyb2xsZXIuc3RhdGUuYXV0b0Nsb3NlKSB7XG4gICAgICAgICAgICAgICAgdGhpcy50aW1lb3V0ID0gc2V0VGltZW91dCgoKSA9PiBTbmFja0NvbnRyb2xsZXIuaGlkZSgpLCAyNTAwKTtcbiAgICAgICAgICAgIH1cbiAgICAgICAgfVxuICAgICAgICBlbHNlIHtcbiAgICAgICAgICAgIHRoaXMuYW5pbWF0ZShbXG4gICAgICAgICAgICAgICAgeyBvcGFjaXR5OiAxLCB0cmFuc2Zvcm06ICd0cmFuc2xhdGVYKC01MCUpIHNjYWxlKDEpJyB9LFxuICAgICAgICAgICAgICAgIHsgb3BhY2l0eTogMCwgdHJhbnNmb3JtOiAndHJhbnNsYXRlWCgtNTAlKSBzY2FsZSgwLjg1KScgfVxuICAgICAgICAgICAgXSwge1xuICAgICAgICAgICAgICAgIGR1cmF0aW9uOiAxNTAsXG4gICAgICAgICAgICAgICAgZmlsbDogJ2ZvcndhcmRzJyxcbiAgICAgICAgICAgICAgICBlYXNpbmc6ICdlYXNlJ1xuICAgICAgICAgICAgfSk7XG4gICAgICAgIH1cbiAgICB9XG59O1xuVzNtU25hY2tCYXIuc3R5bGVzID0gc3R5bGVzO1xuX19kZWNvcmF0ZShbXG4gICAgc3RhdGUoKVxuXSwgVzNtU25hY2tCYXIucHJvdG90eXBlLCBcIm9wZW5cIiwgdm9pZCAwKTtcblczbVNuYWNrQmFyID0gX19kZWNvcmF0ZShbXG4gICAgY3VzdG9tRWxlbWVudCgndzNtLXNuYWNrYmFyJylcbl0sIFczbVNuYWNrQmFyKTtcbmV4cG9ydCB7IFczbVNuYWNrQmFyIH07XG4vLyMgc291cmNlTWFwcGluZ1VSTD1pbmRleC5qcy5tYXAiXSwibmFtZXMiOltdLCJzb3VyY2VSb290IjoiIn0=\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-scaffold-ui/dist/esm/src/partials/w3m-snackbar/index.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@reown/appkit-scaffold-ui/dist/esm/src/partials/w3m-snackbar/styles.js":
-/*!*********************************************************************************************!*\
-  !*** ./node_modules/@reown/appkit-scaffold-ui/dist/esm/src/partials/w3m-snackbar/styles.js ***!
-  \*********************************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n\n/* harmony default export */ __webpack_exports__[\"default\"] = ((0,lit__WEBPACK_IMPORTED_MODULE_0__.css) `\n  :host {\n    display: block;\n    position: absolute;\n    opacity: 0;\n    pointer-events: none;\n    top: 11px;\n    left: 50%;\n    width: max-content;\n  }\n`);\n//# sourceMappingURL=styles.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC1zY2FmZm9sZC11aS9kaXN0L2VzbS9zcmMvcGFydGlhbHMvdzNtLXNuYWNrYmFyL3N0eWxlcy5qcyIsIm1hcHBpbmdzIjoiOztBQUEwQjtBQUMxQiwrREFBZSx3Q0FBRztBQUNsQjtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQSxDQUFDLEVBQUM7QUFDRiIsInNvdXJjZXMiOlsid2VicGFjazovL19OX0UvLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC1zY2FmZm9sZC11aS9kaXN0L2VzbS9zcmMvcGFydGlhbHMvdzNtLXNuYWNrYmFyL3N0eWxlcy5qcz8yMjVkIl0sInNvdXJjZXNDb250ZW50IjpbImltcG9ydCB7IGNzcyB9IGZyb20gJ2xpdCc7XG5leHBvcnQgZGVmYXVsdCBjc3MgYFxuICA6aG9zdCB7XG4gICAgZGlzcGxheTogYmxvY2s7XG4gICAgcG9zaXRpb246IGFic29sdXRlO1xuICAgIG9wYWNpdHk6IDA7XG4gICAgcG9pbnRlci1ldmVudHM6IG5vbmU7XG4gICAgdG9wOiAxMXB4O1xuICAgIGxlZnQ6IDUwJTtcbiAgICB3aWR0aDogbWF4LWNvbnRlbnQ7XG4gIH1cbmA7XG4vLyMgc291cmNlTWFwcGluZ1VSTD1zdHlsZXMuanMubWFwIl0sIm5hbWVzIjpbXSwic291cmNlUm9vdCI6IiJ9\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-scaffold-ui/dist/esm/src/partials/w3m-snackbar/styles.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@reown/appkit-scaffold-ui/dist/esm/src/partials/w3m-tooltip/index.js":
-/*!*******************************************************************************************!*\
-  !*** ./node_modules/@reown/appkit-scaffold-ui/dist/esm/src/partials/w3m-tooltip/index.js ***!
-  \*******************************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   W3mTooltip: function() { return /* binding */ W3mTooltip; }\n/* harmony export */ });\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n/* harmony import */ var lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__ = __webpack_require__(/*! lit/decorators.js */ \"./node_modules/lit/decorators.js\");\n/* harmony import */ var _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_7__ = __webpack_require__(/*! @reown/appkit-controllers */ \"./node_modules/@reown/appkit-controllers/dist/esm/exports/index.js\");\n/* harmony import */ var _reown_appkit_ui__WEBPACK_IMPORTED_MODULE_2__ = __webpack_require__(/*! @reown/appkit-ui */ \"./node_modules/@reown/appkit-ui/dist/esm/exports/index.js\");\n/* harmony import */ var _reown_appkit_ui_wui_flex__WEBPACK_IMPORTED_MODULE_3__ = __webpack_require__(/*! @reown/appkit-ui/wui-flex */ \"./node_modules/@reown/appkit-ui/dist/esm/exports/wui-flex.js\");\n/* harmony import */ var _reown_appkit_ui_wui_icon__WEBPACK_IMPORTED_MODULE_4__ = __webpack_require__(/*! @reown/appkit-ui/wui-icon */ \"./node_modules/@reown/appkit-ui/dist/esm/exports/wui-icon.js\");\n/* harmony import */ var _reown_appkit_ui_wui_text__WEBPACK_IMPORTED_MODULE_5__ = __webpack_require__(/*! @reown/appkit-ui/wui-text */ \"./node_modules/@reown/appkit-ui/dist/esm/exports/wui-text.js\");\n/* harmony import */ var _styles_js__WEBPACK_IMPORTED_MODULE_6__ = __webpack_require__(/*! ./styles.js */ \"./node_modules/@reown/appkit-scaffold-ui/dist/esm/src/partials/w3m-tooltip/styles.js\");\nvar __decorate = (undefined && undefined.__decorate) || function (decorators, target, key, desc) {\n    var c = arguments.length, r = c < 3 ? target : desc === null ? desc = Object.getOwnPropertyDescriptor(target, key) : desc, d;\n    if (typeof Reflect === \"object\" && typeof Reflect.decorate === \"function\") r = Reflect.decorate(decorators, target, key, desc);\n    else for (var i = decorators.length - 1; i >= 0; i--) if (d = decorators[i]) r = (c < 3 ? d(r) : c > 3 ? d(target, key, r) : d(target, key)) || r;\n    return c > 3 && r && Object.defineProperty(target, key, r), r;\n};\n\n\n\n\n\n\n\n\nlet W3mTooltip = class W3mTooltip extends lit__WEBPACK_IMPORTED_MODULE_0__.LitElement {\n    constructor() {\n        super();\n        this.unsubscribe = [];\n        this.open = _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_7__.TooltipController.state.open;\n        this.message = _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_7__.TooltipController.state.message;\n        this.triggerRect = _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_7__.TooltipController.state.triggerRect;\n        this.variant = _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_7__.TooltipController.state.variant;\n        this.unsubscribe.push(...[\n            _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_7__.TooltipController.subscribe(newState => {\n                this.open = newState.open;\n                this.message = newState.message;\n                this.triggerRect = newState.triggerRect;\n                this.variant = newState.variant;\n            })\n        ]);\n    }\n    disconnectedCallback() {\n        this.unsubscribe.forEach(unsubscribe => unsubscribe());\n    }\n    render() {\n        this.dataset['variant'] = this.variant;\n        const topValue = this.triggerRect.top;\n        const leftValue = this.triggerRect.left;\n        this.style.cssText = `\n    --w3m-tooltip-top: ${topValue}px;\n    --w3m-tooltip-left: ${leftValue}px;\n    --w3m-tooltip-parent-width: ${this.triggerRect.width / 2}px;\n    --w3m-tooltip-display: ${this.open ? 'flex' : 'none'};\n    --w3m-tooltip-opacity: ${this.open ? 1 : 0};\n    `;\n        return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<wui-flex>\n      <wui-icon data-placement=\"top\" color=\"fg-100\" size=\"inherit\" name=\"cursor\"></wui-icon>\n      <wui-text color=\"inherit\" variant=\"small-500\">${this.message}</wui-text>\n    </wui-flex>`;\n    }\n};\nW3mTooltip.styles = [_styles_js__WEBPACK_IMPORTED_MODULE_6__[\"default\"]];\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.state)()\n], W3mTooltip.prototype, \"open\", void 0);\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.state)()\n], W3mTooltip.prototype, \"message\", void 0);\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.state)()\n], W3mTooltip.prototype, \"triggerRect\", void 0);\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.state)()\n], W3mTooltip.prototype, \"variant\", void 0);\nW3mTooltip = __decorate([\n    (0,_reown_appkit_ui__WEBPACK_IMPORTED_MODULE_2__.customElement)('w3m-tooltip'),\n    (0,_reown_appkit_ui__WEBPACK_IMPORTED_MODULE_2__.customElement)('w3m-tooltip')\n], W3mTooltip);\n\n//# sourceMappingURL=index.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC1zY2FmZm9sZC11aS9kaXN0L2VzbS9zcmMvcGFydGlhbHMvdzNtLXRvb2x0aXAvaW5kZXguanMiLCJtYXBwaW5ncyI6Ijs7Ozs7Ozs7Ozs7O0FBQUEsa0JBQWtCLFNBQUksSUFBSSxTQUFJO0FBQzlCO0FBQ0E7QUFDQSw2Q0FBNkMsUUFBUTtBQUNyRDtBQUNBO0FBQ3VDO0FBQ0c7QUFDb0I7QUFDYjtBQUNkO0FBQ0E7QUFDQTtBQUNGO0FBQ2pDLDBDQUEwQywyQ0FBVTtBQUNwRDtBQUNBO0FBQ0E7QUFDQSxvQkFBb0Isd0VBQWlCO0FBQ3JDLHVCQUF1Qix3RUFBaUI7QUFDeEMsMkJBQTJCLHdFQUFpQjtBQUM1Qyx1QkFBdUIsd0VBQWlCO0FBQ3hDO0FBQ0EsWUFBWSx3RUFBaUI7QUFDN0I7QUFDQTtBQUNBO0FBQ0E7QUFDQSxhQUFhO0FBQ2I7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQSx5QkFBeUIsU0FBUztBQUNsQywwQkFBMEIsVUFBVTtBQUNwQyxrQ0FBa0MsMkJBQTJCO0FBQzdELDZCQUE2QjtBQUM3Qiw2QkFBNkI7QUFDN0I7QUFDQSxlQUFlLHlDQUFJO0FBQ25CO0FBQ0Esc0RBQXNELGFBQWE7QUFDbkU7QUFDQTtBQUNBO0FBQ0EscUJBQXFCLGtEQUFNO0FBQzNCO0FBQ0EsSUFBSSx3REFBSztBQUNUO0FBQ0E7QUFDQSxJQUFJLHdEQUFLO0FBQ1Q7QUFDQTtBQUNBLElBQUksd0RBQUs7QUFDVDtBQUNBO0FBQ0EsSUFBSSx3REFBSztBQUNUO0FBQ0E7QUFDQSxJQUFJLCtEQUFhO0FBQ2pCLElBQUksK0RBQWE7QUFDakI7QUFDc0I7QUFDdEIiLCJzb3VyY2VzIjpbIndlYnBhY2s6Ly9fTl9FLy4vbm9kZV9tb2R1bGVzL0ByZW93bi9hcHBraXQtc2NhZmZvbGQtdWkvZGlzdC9lc20vc3JjL3BhcnRpYWxzL3czbS10b29sdGlwL2luZGV4LmpzPzFjOTkiXSwic291cmNlc0NvbnRlbnQiOlsidmFyIF9fZGVjb3JhdGUgPSAodGhpcyAmJiB0aGlzLl9fZGVjb3JhdGUpIHx8IGZ1bmN0aW9uIChkZWNvcmF0b3JzLCB0YXJnZXQsIGtleSwgZGVzYykge1xuICAgIHZhciBjID0gYXJndW1lbnRzLmxlbmd0aCwgciA9IGMgPCAzID8gdGFyZ2V0IDogZGVzYyA9PT0gbnVsbCA/IGRlc2MgPSBPYmplY3QuZ2V0T3duUHJvcGVydHlEZXNjcmlwdG9yKHRhcmdldCwga2V5KSA6IGRlc2MsIGQ7XG4gICAgaWYgKHR5cGVvZiBSZWZsZWN0ID09PSBcIm9iamVjdFwiICYmIHR5cGVvZiBSZWZsZWN0LmRlY29yYXRlID09PSBcImZ1bmN0aW9uXCIpIHIgPSBSZWZsZWN0LmRlY29yYXRlKGRlY29yYXRvcnMsIHRhcmdldCwga2V5LCBkZXNjKTtcbiAgICBlbHNlIGZvciAodmFyIGkgPSBkZWNvcmF0b3JzLmxlbmd0aCAtIDE7IGkgPj0gMDsgaS0tKSBpZiAoZCA9IGRlY29yYXRvcnNbaV0pIHIgPSAoYyA8IDMgPyBkKHIpIDogYyA+IDMgPyBkKHRhcmdldCwga2V5LCByKSA6IGQodGFyZ2V0LCBrZXkpKSB8fCByO1xuICAgIHJldHVybiBjID4gMyAmJiByICYmIE9iamVjdC5kZWZpbmVQcm9wZXJ0eSh0YXJnZXQsIGtleSwgciksIHI7XG59O1xuaW1wb3J0IHsgTGl0RWxlbWVudCwgaHRtbCB9IGZyb20gJ2xpdCc7XG5pbXBvcnQgeyBzdGF0ZSB9IGZyb20gJ2xpdC9kZWNvcmF0b3JzLmpzJztcbmltcG9ydCB7IFRvb2x0aXBDb250cm9sbGVyIH0gZnJvbSAnQHJlb3duL2FwcGtpdC1jb250cm9sbGVycyc7XG5pbXBvcnQgeyBjdXN0b21FbGVtZW50IH0gZnJvbSAnQHJlb3duL2FwcGtpdC11aSc7XG5pbXBvcnQgJ0ByZW93bi9hcHBraXQtdWkvd3VpLWZsZXgnO1xuaW1wb3J0ICdAcmVvd24vYXBwa2l0LXVpL3d1aS1pY29uJztcbmltcG9ydCAnQHJlb3duL2FwcGtpdC11aS93dWktdGV4dCc7XG5pbXBvcnQgc3R5bGVzIGZyb20gJy4vc3R5bGVzLmpzJztcbmxldCBXM21Ub29sdGlwID0gY2xhc3MgVzNtVG9vbHRpcCBleHRlbmRzIExpdEVsZW1lbnQge1xuICAgIGNvbnN0cnVjdG9yKCkge1xuICAgICAgICBzdXBlcigpO1xuICAgICAgICB0aGlzLnVuc3Vic2NyaWJlID0gW107XG4gICAgICAgIHRoaXMub3BlbiA9IFRvb2x0aXBDb250cm9sbGVyLnN0YXRlLm9wZW47XG4gICAgICAgIHRoaXMubWVzc2FnZSA9IFRvb2x0aXBDb250cm9sbGVyLnN0YXRlLm1lc3NhZ2U7XG4gICAgICAgIHRoaXMudHJpZ2dlclJlY3QgPSBUb29sdGlwQ29udHJvbGxlci5zdGF0ZS50cmlnZ2VyUmVjdDtcbiAgICAgICAgdGhpcy52YXJpYW50ID0gVG9vbHRpcENvbnRyb2xsZXIuc3RhdGUudmFyaWFudDtcbiAgICAgICAgdGhpcy51bnN1YnNjcmliZS5wdXNoKC4uLltcbiAgICAgICAgICAgIFRvb2x0aXBDb250cm9sbGVyLnN1YnNjcmliZShuZXdTdGF0ZSA9PiB7XG4gICAgICAgICAgICAgICAgdGhpcy5vcGVuID0gbmV3U3RhdGUub3BlbjtcbiAgICAgICAgICAgICAgICB0aGlzLm1lc3NhZ2UgPSBuZXdTdGF0ZS5tZXNzYWdlO1xuICAgICAgICAgICAgICAgIHRoaXMudHJpZ2dlclJlY3QgPSBuZXdTdGF0ZS50cmlnZ2VyUmVjdDtcbiAgICAgICAgICAgICAgICB0aGlzLnZhcmlhbnQgPSBuZXdTdGF0ZS52YXJpYW50O1xuICAgICAgICAgICAgfSlcbiAgICAgICAgXSk7XG4gICAgfVxuICAgIGRpc2Nvbm5lY3RlZENhbGxiYWNrKCkge1xuICAgICAgICB0aGlzLnVuc3Vic2NyaWJlLmZvckVhY2godW5zdWJzY3JpYmUgPT4gdW5zdWJzY3JpYmUoKSk7XG4gICAgfVxuICAgIHJlbmRlcigpIHtcbiAgICAgICAgdGhpcy5kYXRhc2V0Wyd2YXJpYW50J10gPSB0aGlzLnZhcmlhbnQ7XG4gICAgICAgIGNvbnN0IHRvcFZhbHVlID0gdGhpcy50cmlnZ2VyUmVjdC50b3A7XG4gICAgICAgIGNvbnN0IGxlZnRWYWx1ZSA9IHRoaXMudHJpZ2dlclJlY3QubGVmdDtcbiAgICAgICAgdGhpcy5zdHlsZS5jc3NUZXh0ID0gYFxuICAgIC0tdzNtLXRvb2x0aXAtdG9wOiAke3RvcFZhbHVlfXB4O1xuICAgIC0tdzNtLXRvb2x0aXAtbGVmdDogJHtsZWZ0VmFsdWV9cHg7XG4gICAgLS13M20tdG9vbHRpcC1wYXJlbnQtd2lkdGg6ICR7dGhpcy50cmlnZ2VyUmVjdC53aWR0aCAvIDJ9cHg7XG4gICAgLS13M20tdG9vbHRpcC1kaXNwbGF5OiAke3RoaXMub3BlbiA/ICdmbGV4JyA6ICdub25lJ307XG4gICAgLS13M20tdG9vbHRpcC1vcGFjaXR5OiAke3RoaXMub3BlbiA/IDEgOiAwfTtcbiAgICBgO1xuICAgICAgICByZXR1cm4gaHRtbCBgPHd1aS1mbGV4PlxuICAgICAgPHd1aS1pY29uIGRhdGEtcGxhY2VtZW50PVwidG9wXCIgY29sb3I9XCJmZy0xMDBcIiBzaXplPVwiaW5oZXJpdFwiIG5hbWU9XCJjdXJzb3JcIj48L3d1aS1pY29uPlxuICAgICAgPHd1aS10ZXh0IGNvbG9yPVwiaW5oZXJpdFwiIHZhcmlhbnQ9XCJzbWFsbC01MDBcIj4ke3RoaXMubWVzc2FnZX08L3d1aS10ZXh0PlxuICAgIDwvd3VpLWZsZXg+YDtcbiAgICB9XG59O1xuVzNtVG9vbHRpcC5zdHlsZXMgPSBbc3R5bGVzXTtcbl9fZGVjb3JhdGUoW1xuICAgIHN0YXRlKClcbl0sIFczbVRvb2x0aXAucHJvdG90eXBlLCBcIm9wZW5cIiwgdm9pZCAwKTtcbl9fZGVjb3JhdGUoW1xuICAgIHN0YXRlKClcbl0sIFczbVRvb2x0aXAucHJvdG90eXBlLCBcIm1lc3NhZ2VcIiwgdm9pZCAwKTtcbl9fZGVjb3JhdGUoW1xuICAgIHN0YXRlKClcbl0sIFczbVRvb2x0aXAucHJvdG90eXBlLCBcInRyaWdnZXJSZWN0XCIsIHZvaWQgMCk7XG5fX2RlY29yYXRlKFtcbiAgICBzdGF0ZSgpXG5dLCBXM21Ub29sdGlwLnByb3RvdHlwZSwgXCJ2YXJpYW50XCIsIHZvaWQgMCk7XG5XM21Ub29sdGlwID0gX19kZWNvcmF0ZShbXG4gICAgY3VzdG9tRWxlbWVudCgndzNtLXRvb2x0aXAnKSxcbiAgICBjdXN0b21FbGVtZW50KCd3M20tdG9vbHRpcCcpXG5dLCBXM21Ub29sdGlwKTtcbmV4cG9ydCB7IFczbVRvb2x0aXAgfTtcbi8vIyBzb3VyY2VNYXBwaW5nVVJMPWluZGV4LmpzLm1hcCJdLCJuYW1lcyI6W10sInNvdXJjZVJvb3QiOiIifQ==\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-scaffold-ui/dist/esm/src/partials/w3m-tooltip/index.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@reown/appkit-scaffold-ui/dist/esm/src/partials/w3m-tooltip/styles.js":
-/*!********************************************************************************************!*\
-  !*** ./node_modules/@reown/appkit-scaffold-ui/dist/esm/src/partials/w3m-tooltip/styles.js ***!
-  \********************************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n\n/* harmony default export */ __webpack_exports__[\"default\"] = ((0,lit__WEBPACK_IMPORTED_MODULE_0__.css) `\n  :host {\n    pointer-events: none;\n  }\n\n  :host > wui-flex {\n    display: var(--w3m-tooltip-display);\n    opacity: var(--w3m-tooltip-opacity);\n    padding: 9px var(--wui-spacing-s) 10px var(--wui-spacing-s);\n    border-radius: var(--wui-border-radius-xxs);\n    color: var(--wui-color-bg-100);\n    position: fixed;\n    top: var(--w3m-tooltip-top);\n    left: var(--w3m-tooltip-left);\n    transform: translate(calc(-50% + var(--w3m-tooltip-parent-width)), calc(-100% - 8px));\n    max-width: calc(var(--w3m-modal-width) - var(--wui-spacing-xl));\n    transition: opacity 0.2s var(--wui-ease-out-power-2);\n    will-change: opacity;\n  }\n\n  :host([data-variant='shade']) > wui-flex {\n    background-color: var(--wui-color-bg-150);\n    border: 1px solid var(--wui-color-gray-glass-005);\n  }\n\n  :host([data-variant='shade']) > wui-flex > wui-text {\n    color: var(--wui-color-fg-150);\n  }\n\n  :host([data-variant='fill']) > wui-flex {\n    background-color: var(--wui-color-fg-100);\n    border: none;\n  }\n\n  wui-icon {\n    position: absolute;\n    width: 12px !important;\n    height: 4px !important;\n    color: var(--wui-color-bg-150);\n  }\n\n  wui-icon[data-placement='top'] {\n    bottom: 0px;\n    left: 50%;\n    transform: translate(-50%, 95%);\n  }\n\n  wui-icon[data-placement='bottom'] {\n    top: 0;\n    left: 50%;\n    transform: translate(-50%, -95%) rotate(180deg);\n  }\n\n  wui-icon[data-placement='right'] {\n    top: 50%;\n    left: 0;\n    transform: translate(-65%, -50%) rotate(90deg);\n  }\n\n  wui-icon[data-placement='left'] {\n    top: 50%;\n    right: 0%;\n    transform: translate(65%, -50%) rotate(270deg);\n  }\n`);\n//# sourceMappingURL=styles.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC1zY2FmZm9sZC11aS9kaXN0L2VzbS9zcmMvcGFydGlhbHMvdzNtLXRvb2x0aXAvc3R5bGVzLmpzIiwibWFwcGluZ3MiOiI7O0FBQTBCO0FBQzFCLCtEQUFlLHdDQUFHO0FBQ2xCO0FBQ0E7QUFDQTs7QUFFQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBOztBQUVBO0FBQ0E7QUFDQTtBQUNBOztBQUVBO0FBQ0E7QUFDQTs7QUFFQTtBQUNBO0FBQ0E7QUFDQTs7QUFFQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7O0FBRUE7QUFDQTtBQUNBO0FBQ0E7QUFDQTs7QUFFQTtBQUNBO0FBQ0E7QUFDQTtBQUNBOztBQUVBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7O0FBRUE7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBLENBQUMsRUFBQztBQUNGIiwic291cmNlcyI6WyJ3ZWJwYWNrOi8vX05fRS8uL25vZGVfbW9kdWxlcy9AcmVvd24vYXBwa2l0LXNjYWZmb2xkLXVpL2Rpc3QvZXNtL3NyYy9wYXJ0aWFscy93M20tdG9vbHRpcC9zdHlsZXMuanM/OWJhNiJdLCJzb3VyY2VzQ29udGVudCI6WyJpbXBvcnQgeyBjc3MgfSBmcm9tICdsaXQnO1xuZXhwb3J0IGRlZmF1bHQgY3NzIGBcbiAgOmhvc3Qge1xuICAgIHBvaW50ZXItZXZlbnRzOiBub25lO1xuICB9XG5cbiAgOmhvc3QgPiB3dWktZmxleCB7XG4gICAgZGlzcGxheTogdmFyKC0tdzNtLXRvb2x0aXAtZGlzcGxheSk7XG4gICAgb3BhY2l0eTogdmFyKC0tdzNtLXRvb2x0aXAtb3BhY2l0eSk7XG4gICAgcGFkZGluZzogOXB4IHZhcigtLXd1aS1zcGFjaW5nLXMpIDEwcHggdmFyKC0td3VpLXNwYWNpbmctcyk7XG4gICAgYm9yZGVyLXJhZGl1czogdmFyKC0td3VpLWJvcmRlci1yYWRpdXMteHhzKTtcbiAgICBjb2xvcjogdmFyKC0td3VpLWNvbG9yLWJnLTEwMCk7XG4gICAgcG9zaXRpb246IGZpeGVkO1xuICAgIHRvcDogdmFyKC0tdzNtLXRvb2x0aXAtdG9wKTtcbiAgICBsZWZ0OiB2YXIoLS13M20tdG9vbHRpcC1sZWZ0KTtcbiAgICB0cmFuc2Zvcm06IHRyYW5zbGF0ZShjYWxjKC01MCUgKyB2YXIoLS13M20tdG9vbHRpcC1wYXJlbnQtd2lkdGgpKSwgY2FsYygtMTAwJSAtIDhweCkpO1xuICAgIG1heC13aWR0aDogY2FsYyh2YXIoLS13M20tbW9kYWwtd2lkdGgpIC0gdmFyKC0td3VpLXNwYWNpbmcteGwpKTtcbiAgICB0cmFuc2l0aW9uOiBvcGFjaXR5IDAuMnMgdmFyKC0td3VpLWVhc2Utb3V0LXBvd2VyLTIpO1xuICAgIHdpbGwtY2hhbmdlOiBvcGFjaXR5O1xuICB9XG5cbiAgOmhvc3QoW2RhdGEtdmFyaWFudD0nc2hhZGUnXSkgPiB3dWktZmxleCB7XG4gICAgYmFja2dyb3VuZC1jb2xvcjogdmFyKC0td3VpLWNvbG9yLWJnLTE1MCk7XG4gICAgYm9yZGVyOiAxcHggc29saWQgdmFyKC0td3VpLWNvbG9yLWdyYXktZ2xhc3MtMDA1KTtcbiAgfVxuXG4gIDpob3N0KFtkYXRhLXZhcmlhbnQ9J3NoYWRlJ10pID4gd3VpLWZsZXggPiB3dWktdGV4dCB7XG4gICAgY29sb3I6IHZhcigtLXd1aS1jb2xvci1mZy0xNTApO1xuICB9XG5cbiAgOmhvc3QoW2RhdGEtdmFyaWFudD0nZmlsbCddKSA+IHd1aS1mbGV4IHtcbiAgICBiYWNrZ3JvdW5kLWNvbG9yOiB2YXIoLS13dWktY29sb3ItZmctMTAwKTtcbiAgICBib3JkZXI6IG5vbmU7XG4gIH1cblxuICB3dWktaWNvbiB7XG4gICAgcG9zaXRpb246IGFic29sdXRlO1xuICAgIHdpZHRoOiAxMnB4ICFpbXBvcnRhbnQ7XG4gICAgaGVpZ2h0OiA0cHggIWltcG9ydGFudDtcbiAgICBjb2xvcjogdmFyKC0td3VpLWNvbG9yLWJnLTE1MCk7XG4gIH1cblxuICB3dWktaWNvbltkYXRhLXBsYWNlbWVudD0ndG9wJ10ge1xuICAgIGJvdHRvbTogMHB4O1xuICAgIGxlZnQ6IDUwJTtcbiAgICB0cmFuc2Zvcm06IHRyYW5zbGF0ZSgtNTAlLCA5NSUpO1xuICB9XG5cbiAgd3VpLWljb25bZGF0YS1wbGFjZW1lbnQ9J2JvdHRvbSddIHtcbiAgICB0b3A6IDA7XG4gICAgbGVmdDogNTAlO1xuICAgIHRyYW5zZm9ybTogdHJhbnNsYXRlKC01MCUsIC05NSUpIHJvdGF0ZSgxODBkZWcpO1xuICB9XG5cbiAgd3VpLWljb25bZGF0YS1wbGFjZW1lbnQ9J3JpZ2h0J10ge1xuICAgIHRvcDogNTAlO1xuICAgIGxlZnQ6IDA7XG4gICAgdHJhbnNmb3JtOiB0cmFuc2xhdGUoLTY1JSwgLTUwJSkgcm90YXRlKDkwZGVnKTtcbiAgfVxuXG4gIHd1aS1pY29uW2RhdGEtcGxhY2VtZW50PSdsZWZ0J10ge1xuICAgIHRvcDogNTAlO1xuICAgIHJpZ2h0OiAwJTtcbiAgICB0cmFuc2Zvcm06IHRyYW5zbGF0ZSg2NSUsIC01MCUpIHJvdGF0ZSgyNzBkZWcpO1xuICB9XG5gO1xuLy8jIHNvdXJjZU1hcHBpbmdVUkw9c3R5bGVzLmpzLm1hcCJdLCJuYW1lcyI6W10sInNvdXJjZVJvb3QiOiIifQ==\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-scaffold-ui/dist/esm/src/partials/w3m-tooltip/styles.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@reown/appkit-ui/dist/esm/exports/wui-alertbar.js":
-/*!************************************************************************!*\
-  !*** ./node_modules/@reown/appkit-ui/dist/esm/exports/wui-alertbar.js ***!
-  \************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony import */ var _src_composites_wui_alertbar_index_js__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! ../src/composites/wui-alertbar/index.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/composites/wui-alertbar/index.js\");\n/* harmony reexport (unknown) */ var __WEBPACK_REEXPORT_OBJECT__ = {};\n/* harmony reexport (unknown) */ for(var __WEBPACK_IMPORT_KEY__ in _src_composites_wui_alertbar_index_js__WEBPACK_IMPORTED_MODULE_0__) if(__WEBPACK_IMPORT_KEY__ !== \"default\") __WEBPACK_REEXPORT_OBJECT__[__WEBPACK_IMPORT_KEY__] = function(key) { return _src_composites_wui_alertbar_index_js__WEBPACK_IMPORTED_MODULE_0__[key]; }.bind(0, __WEBPACK_IMPORT_KEY__)\n/* harmony reexport (unknown) */ __webpack_require__.d(__webpack_exports__, __WEBPACK_REEXPORT_OBJECT__);\n\n//# sourceMappingURL=wui-alertbar.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9leHBvcnRzL3d1aS1hbGVydGJhci5qcyIsIm1hcHBpbmdzIjoiOzs7OztBQUF3RDtBQUN4RCIsInNvdXJjZXMiOlsid2VicGFjazovL19OX0UvLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9leHBvcnRzL3d1aS1hbGVydGJhci5qcz85YzA1Il0sInNvdXJjZXNDb250ZW50IjpbImV4cG9ydCAqIGZyb20gJy4uL3NyYy9jb21wb3NpdGVzL3d1aS1hbGVydGJhci9pbmRleC5qcyc7XG4vLyMgc291cmNlTWFwcGluZ1VSTD13dWktYWxlcnRiYXIuanMubWFwIl0sIm5hbWVzIjpbXSwic291cmNlUm9vdCI6IiJ9\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-ui/dist/esm/exports/wui-alertbar.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@reown/appkit-ui/dist/esm/exports/wui-card.js":
-/*!********************************************************************!*\
-  !*** ./node_modules/@reown/appkit-ui/dist/esm/exports/wui-card.js ***!
-  \********************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony import */ var _src_components_wui_card_index_js__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! ../src/components/wui-card/index.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/components/wui-card/index.js\");\n/* harmony reexport (unknown) */ var __WEBPACK_REEXPORT_OBJECT__ = {};\n/* harmony reexport (unknown) */ for(var __WEBPACK_IMPORT_KEY__ in _src_components_wui_card_index_js__WEBPACK_IMPORTED_MODULE_0__) if(__WEBPACK_IMPORT_KEY__ !== \"default\") __WEBPACK_REEXPORT_OBJECT__[__WEBPACK_IMPORT_KEY__] = function(key) { return _src_components_wui_card_index_js__WEBPACK_IMPORTED_MODULE_0__[key]; }.bind(0, __WEBPACK_IMPORT_KEY__)\n/* harmony reexport (unknown) */ __webpack_require__.d(__webpack_exports__, __WEBPACK_REEXPORT_OBJECT__);\n\n//# sourceMappingURL=wui-card.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9leHBvcnRzL3d1aS1jYXJkLmpzIiwibWFwcGluZ3MiOiI7Ozs7O0FBQW9EO0FBQ3BEIiwic291cmNlcyI6WyJ3ZWJwYWNrOi8vX05fRS8uL25vZGVfbW9kdWxlcy9AcmVvd24vYXBwa2l0LXVpL2Rpc3QvZXNtL2V4cG9ydHMvd3VpLWNhcmQuanM/YzczZiJdLCJzb3VyY2VzQ29udGVudCI6WyJleHBvcnQgKiBmcm9tICcuLi9zcmMvY29tcG9uZW50cy93dWktY2FyZC9pbmRleC5qcyc7XG4vLyMgc291cmNlTWFwcGluZ1VSTD13dWktY2FyZC5qcy5tYXAiXSwibmFtZXMiOltdLCJzb3VyY2VSb290IjoiIn0=\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-ui/dist/esm/exports/wui-card.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@reown/appkit-ui/dist/esm/exports/wui-flex.js":
-/*!********************************************************************!*\
-  !*** ./node_modules/@reown/appkit-ui/dist/esm/exports/wui-flex.js ***!
-  \********************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony import */ var _src_layout_wui_flex_index_js__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! ../src/layout/wui-flex/index.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/layout/wui-flex/index.js\");\n/* harmony reexport (unknown) */ var __WEBPACK_REEXPORT_OBJECT__ = {};\n/* harmony reexport (unknown) */ for(var __WEBPACK_IMPORT_KEY__ in _src_layout_wui_flex_index_js__WEBPACK_IMPORTED_MODULE_0__) if(__WEBPACK_IMPORT_KEY__ !== \"default\") __WEBPACK_REEXPORT_OBJECT__[__WEBPACK_IMPORT_KEY__] = function(key) { return _src_layout_wui_flex_index_js__WEBPACK_IMPORTED_MODULE_0__[key]; }.bind(0, __WEBPACK_IMPORT_KEY__)\n/* harmony reexport (unknown) */ __webpack_require__.d(__webpack_exports__, __WEBPACK_REEXPORT_OBJECT__);\n\n//# sourceMappingURL=wui-flex.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9leHBvcnRzL3d1aS1mbGV4LmpzIiwibWFwcGluZ3MiOiI7Ozs7O0FBQWdEO0FBQ2hEIiwic291cmNlcyI6WyJ3ZWJwYWNrOi8vX05fRS8uL25vZGVfbW9kdWxlcy9AcmVvd24vYXBwa2l0LXVpL2Rpc3QvZXNtL2V4cG9ydHMvd3VpLWZsZXguanM/YzY5NCJdLCJzb3VyY2VzQ29udGVudCI6WyJleHBvcnQgKiBmcm9tICcuLi9zcmMvbGF5b3V0L3d1aS1mbGV4L2luZGV4LmpzJztcbi8vIyBzb3VyY2VNYXBwaW5nVVJMPXd1aS1mbGV4LmpzLm1hcCJdLCJuYW1lcyI6W10sInNvdXJjZVJvb3QiOiIifQ==\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-ui/dist/esm/exports/wui-flex.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@reown/appkit-ui/dist/esm/exports/wui-icon-link.js":
-/*!*************************************************************************!*\
-  !*** ./node_modules/@reown/appkit-ui/dist/esm/exports/wui-icon-link.js ***!
-  \*************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony import */ var _src_composites_wui_icon_link_index_js__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! ../src/composites/wui-icon-link/index.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/composites/wui-icon-link/index.js\");\n/* harmony reexport (unknown) */ var __WEBPACK_REEXPORT_OBJECT__ = {};\n/* harmony reexport (unknown) */ for(var __WEBPACK_IMPORT_KEY__ in _src_composites_wui_icon_link_index_js__WEBPACK_IMPORTED_MODULE_0__) if(__WEBPACK_IMPORT_KEY__ !== \"default\") __WEBPACK_REEXPORT_OBJECT__[__WEBPACK_IMPORT_KEY__] = function(key) { return _src_composites_wui_icon_link_index_js__WEBPACK_IMPORTED_MODULE_0__[key]; }.bind(0, __WEBPACK_IMPORT_KEY__)\n/* harmony reexport (unknown) */ __webpack_require__.d(__webpack_exports__, __WEBPACK_REEXPORT_OBJECT__);\n\n//# sourceMappingURL=wui-icon-link.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9leHBvcnRzL3d1aS1pY29uLWxpbmsuanMiLCJtYXBwaW5ncyI6Ijs7Ozs7QUFBeUQ7QUFDekQiLCJzb3VyY2VzIjpbIndlYnBhY2s6Ly9fTl9FLy4vbm9kZV9tb2R1bGVzL0ByZW93bi9hcHBraXQtdWkvZGlzdC9lc20vZXhwb3J0cy93dWktaWNvbi1saW5rLmpzPzY0NDEiXSwic291cmNlc0NvbnRlbnQiOlsiZXhwb3J0ICogZnJvbSAnLi4vc3JjL2NvbXBvc2l0ZXMvd3VpLWljb24tbGluay9pbmRleC5qcyc7XG4vLyMgc291cmNlTWFwcGluZ1VSTD13dWktaWNvbi1saW5rLmpzLm1hcCJdLCJuYW1lcyI6W10sInNvdXJjZVJvb3QiOiIifQ==\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-ui/dist/esm/exports/wui-icon-link.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@reown/appkit-ui/dist/esm/exports/wui-icon.js":
-/*!********************************************************************!*\
-  !*** ./node_modules/@reown/appkit-ui/dist/esm/exports/wui-icon.js ***!
-  \********************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony import */ var _src_components_wui_icon_index_js__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! ../src/components/wui-icon/index.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js\");\n/* harmony reexport (unknown) */ var __WEBPACK_REEXPORT_OBJECT__ = {};\n/* harmony reexport (unknown) */ for(var __WEBPACK_IMPORT_KEY__ in _src_components_wui_icon_index_js__WEBPACK_IMPORTED_MODULE_0__) if(__WEBPACK_IMPORT_KEY__ !== \"default\") __WEBPACK_REEXPORT_OBJECT__[__WEBPACK_IMPORT_KEY__] = function(key) { return _src_components_wui_icon_index_js__WEBPACK_IMPORTED_MODULE_0__[key]; }.bind(0, __WEBPACK_IMPORT_KEY__)\n/* harmony reexport (unknown) */ __webpack_require__.d(__webpack_exports__, __WEBPACK_REEXPORT_OBJECT__);\n\n//# sourceMappingURL=wui-icon.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9leHBvcnRzL3d1aS1pY29uLmpzIiwibWFwcGluZ3MiOiI7Ozs7O0FBQW9EO0FBQ3BEIiwic291cmNlcyI6WyJ3ZWJwYWNrOi8vX05fRS8uL25vZGVfbW9kdWxlcy9AcmVvd24vYXBwa2l0LXVpL2Rpc3QvZXNtL2V4cG9ydHMvd3VpLWljb24uanM/Mjg4YiJdLCJzb3VyY2VzQ29udGVudCI6WyJleHBvcnQgKiBmcm9tICcuLi9zcmMvY29tcG9uZW50cy93dWktaWNvbi9pbmRleC5qcyc7XG4vLyMgc291cmNlTWFwcGluZ1VSTD13dWktaWNvbi5qcy5tYXAiXSwibmFtZXMiOltdLCJzb3VyY2VSb290IjoiIn0=\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-ui/dist/esm/exports/wui-icon.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@reown/appkit-ui/dist/esm/exports/wui-select.js":
-/*!**********************************************************************!*\
-  !*** ./node_modules/@reown/appkit-ui/dist/esm/exports/wui-select.js ***!
-  \**********************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony import */ var _src_composites_wui_select_index_js__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! ../src/composites/wui-select/index.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/composites/wui-select/index.js\");\n/* harmony reexport (unknown) */ var __WEBPACK_REEXPORT_OBJECT__ = {};\n/* harmony reexport (unknown) */ for(var __WEBPACK_IMPORT_KEY__ in _src_composites_wui_select_index_js__WEBPACK_IMPORTED_MODULE_0__) if(__WEBPACK_IMPORT_KEY__ !== \"default\") __WEBPACK_REEXPORT_OBJECT__[__WEBPACK_IMPORT_KEY__] = function(key) { return _src_composites_wui_select_index_js__WEBPACK_IMPORTED_MODULE_0__[key]; }.bind(0, __WEBPACK_IMPORT_KEY__)\n/* harmony reexport (unknown) */ __webpack_require__.d(__webpack_exports__, __WEBPACK_REEXPORT_OBJECT__);\n\n//# sourceMappingURL=wui-select.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9leHBvcnRzL3d1aS1zZWxlY3QuanMiLCJtYXBwaW5ncyI6Ijs7Ozs7QUFBc0Q7QUFDdEQiLCJzb3VyY2VzIjpbIndlYnBhY2s6Ly9fTl9FLy4vbm9kZV9tb2R1bGVzL0ByZW93bi9hcHBraXQtdWkvZGlzdC9lc20vZXhwb3J0cy93dWktc2VsZWN0LmpzPzc4ZTkiXSwic291cmNlc0NvbnRlbnQiOlsiZXhwb3J0ICogZnJvbSAnLi4vc3JjL2NvbXBvc2l0ZXMvd3VpLXNlbGVjdC9pbmRleC5qcyc7XG4vLyMgc291cmNlTWFwcGluZ1VSTD13dWktc2VsZWN0LmpzLm1hcCJdLCJuYW1lcyI6W10sInNvdXJjZVJvb3QiOiIifQ==\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-ui/dist/esm/exports/wui-select.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@reown/appkit-ui/dist/esm/exports/wui-snackbar.js":
-/*!************************************************************************!*\
-  !*** ./node_modules/@reown/appkit-ui/dist/esm/exports/wui-snackbar.js ***!
-  \************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony import */ var _src_composites_wui_snackbar_index_js__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! ../src/composites/wui-snackbar/index.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/composites/wui-snackbar/index.js\");\n/* harmony reexport (unknown) */ var __WEBPACK_REEXPORT_OBJECT__ = {};\n/* harmony reexport (unknown) */ for(var __WEBPACK_IMPORT_KEY__ in _src_composites_wui_snackbar_index_js__WEBPACK_IMPORTED_MODULE_0__) if(__WEBPACK_IMPORT_KEY__ !== \"default\") __WEBPACK_REEXPORT_OBJECT__[__WEBPACK_IMPORT_KEY__] = function(key) { return _src_composites_wui_snackbar_index_js__WEBPACK_IMPORTED_MODULE_0__[key]; }.bind(0, __WEBPACK_IMPORT_KEY__)\n/* harmony reexport (unknown) */ __webpack_require__.d(__webpack_exports__, __WEBPACK_REEXPORT_OBJECT__);\n\n//# sourceMappingURL=wui-snackbar.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9leHBvcnRzL3d1aS1zbmFja2Jhci5qcyIsIm1hcHBpbmdzIjoiOzs7OztBQUF3RDtBQUN4RCIsInNvdXJjZXMiOlsid2VicGFjazovL19OX0UvLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9leHBvcnRzL3d1aS1zbmFja2Jhci5qcz9jMjQyIl0sInNvdXJjZXNDb250ZW50IjpbImV4cG9ydCAqIGZyb20gJy4uL3NyYy9jb21wb3NpdGVzL3d1aS1zbmFja2Jhci9pbmRleC5qcyc7XG4vLyMgc291cmNlTWFwcGluZ1VSTD13dWktc25hY2tiYXIuanMubWFwIl0sIm5hbWVzIjpbXSwic291cmNlUm9vdCI6IiJ9\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-ui/dist/esm/exports/wui-snackbar.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@reown/appkit-ui/dist/esm/exports/wui-tag.js":
-/*!*******************************************************************!*\
-  !*** ./node_modules/@reown/appkit-ui/dist/esm/exports/wui-tag.js ***!
-  \*******************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony import */ var _src_composites_wui_tag_index_js__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! ../src/composites/wui-tag/index.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/composites/wui-tag/index.js\");\n/* harmony reexport (unknown) */ var __WEBPACK_REEXPORT_OBJECT__ = {};\n/* harmony reexport (unknown) */ for(var __WEBPACK_IMPORT_KEY__ in _src_composites_wui_tag_index_js__WEBPACK_IMPORTED_MODULE_0__) if(__WEBPACK_IMPORT_KEY__ !== \"default\") __WEBPACK_REEXPORT_OBJECT__[__WEBPACK_IMPORT_KEY__] = function(key) { return _src_composites_wui_tag_index_js__WEBPACK_IMPORTED_MODULE_0__[key]; }.bind(0, __WEBPACK_IMPORT_KEY__)\n/* harmony reexport (unknown) */ __webpack_require__.d(__webpack_exports__, __WEBPACK_REEXPORT_OBJECT__);\n\n//# sourceMappingURL=wui-tag.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9leHBvcnRzL3d1aS10YWcuanMiLCJtYXBwaW5ncyI6Ijs7Ozs7QUFBbUQ7QUFDbkQiLCJzb3VyY2VzIjpbIndlYnBhY2s6Ly9fTl9FLy4vbm9kZV9tb2R1bGVzL0ByZW93bi9hcHBraXQtdWkvZGlzdC9lc20vZXhwb3J0cy93dWktdGFnLmpzPzhlNjQiXSwic291cmNlc0NvbnRlbnQiOlsiZXhwb3J0ICogZnJvbSAnLi4vc3JjL2NvbXBvc2l0ZXMvd3VpLXRhZy9pbmRleC5qcyc7XG4vLyMgc291cmNlTWFwcGluZ1VSTD13dWktdGFnLmpzLm1hcCJdLCJuYW1lcyI6W10sInNvdXJjZVJvb3QiOiIifQ==\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-ui/dist/esm/exports/wui-tag.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@reown/appkit-ui/dist/esm/exports/wui-text.js":
-/*!********************************************************************!*\
-  !*** ./node_modules/@reown/appkit-ui/dist/esm/exports/wui-text.js ***!
-  \********************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony import */ var _src_components_wui_text_index_js__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! ../src/components/wui-text/index.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/components/wui-text/index.js\");\n/* harmony reexport (unknown) */ var __WEBPACK_REEXPORT_OBJECT__ = {};\n/* harmony reexport (unknown) */ for(var __WEBPACK_IMPORT_KEY__ in _src_components_wui_text_index_js__WEBPACK_IMPORTED_MODULE_0__) if(__WEBPACK_IMPORT_KEY__ !== \"default\") __WEBPACK_REEXPORT_OBJECT__[__WEBPACK_IMPORT_KEY__] = function(key) { return _src_components_wui_text_index_js__WEBPACK_IMPORTED_MODULE_0__[key]; }.bind(0, __WEBPACK_IMPORT_KEY__)\n/* harmony reexport (unknown) */ __webpack_require__.d(__webpack_exports__, __WEBPACK_REEXPORT_OBJECT__);\n\n//# sourceMappingURL=wui-text.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9leHBvcnRzL3d1aS10ZXh0LmpzIiwibWFwcGluZ3MiOiI7Ozs7O0FBQW9EO0FBQ3BEIiwic291cmNlcyI6WyJ3ZWJwYWNrOi8vX05fRS8uL25vZGVfbW9kdWxlcy9AcmVvd24vYXBwa2l0LXVpL2Rpc3QvZXNtL2V4cG9ydHMvd3VpLXRleHQuanM/YzJhNiJdLCJzb3VyY2VzQ29udGVudCI6WyJleHBvcnQgKiBmcm9tICcuLi9zcmMvY29tcG9uZW50cy93dWktdGV4dC9pbmRleC5qcyc7XG4vLyMgc291cmNlTWFwcGluZ1VSTD13dWktdGV4dC5qcy5tYXAiXSwibmFtZXMiOltdLCJzb3VyY2VSb290IjoiIn0=\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-ui/dist/esm/exports/wui-text.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@reown/appkit-ui/dist/esm/src/components/wui-card/index.js":
-/*!*********************************************************************************!*\
-  !*** ./node_modules/@reown/appkit-ui/dist/esm/src/components/wui-card/index.js ***!
-  \*********************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   WuiCard: function() { return /* binding */ WuiCard; }\n/* harmony export */ });\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n/* harmony import */ var _utils_ThemeUtil_js__WEBPACK_IMPORTED_MODULE_1__ = __webpack_require__(/*! ../../utils/ThemeUtil.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/utils/ThemeUtil.js\");\n/* harmony import */ var _utils_WebComponentsUtil_js__WEBPACK_IMPORTED_MODULE_2__ = __webpack_require__(/*! ../../utils/WebComponentsUtil.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/utils/WebComponentsUtil.js\");\n/* harmony import */ var _styles_js__WEBPACK_IMPORTED_MODULE_3__ = __webpack_require__(/*! ./styles.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/components/wui-card/styles.js\");\nvar __decorate = (undefined && undefined.__decorate) || function (decorators, target, key, desc) {\n    var c = arguments.length, r = c < 3 ? target : desc === null ? desc = Object.getOwnPropertyDescriptor(target, key) : desc, d;\n    if (typeof Reflect === \"object\" && typeof Reflect.decorate === \"function\") r = Reflect.decorate(decorators, target, key, desc);\n    else for (var i = decorators.length - 1; i >= 0; i--) if (d = decorators[i]) r = (c < 3 ? d(r) : c > 3 ? d(target, key, r) : d(target, key)) || r;\n    return c > 3 && r && Object.defineProperty(target, key, r), r;\n};\n\n\n\n\nlet WuiCard = class WuiCard extends lit__WEBPACK_IMPORTED_MODULE_0__.LitElement {\n    render() {\n        return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<slot></slot>`;\n    }\n};\nWuiCard.styles = [_utils_ThemeUtil_js__WEBPACK_IMPORTED_MODULE_1__.resetStyles, _styles_js__WEBPACK_IMPORTED_MODULE_3__[\"default\"]];\nWuiCard = __decorate([\n    (0,_utils_WebComponentsUtil_js__WEBPACK_IMPORTED_MODULE_2__.customElement)('wui-card')\n], WuiCard);\n\n//# sourceMappingURL=index.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvY29tcG9uZW50cy93dWktY2FyZC9pbmRleC5qcyIsIm1hcHBpbmdzIjoiOzs7Ozs7OztBQUFBLGtCQUFrQixTQUFJLElBQUksU0FBSTtBQUM5QjtBQUNBO0FBQ0EsNkNBQTZDLFFBQVE7QUFDckQ7QUFDQTtBQUN1QztBQUNnQjtBQUNVO0FBQ2hDO0FBQ2pDLG9DQUFvQywyQ0FBVTtBQUM5QztBQUNBLGVBQWUseUNBQUk7QUFDbkI7QUFDQTtBQUNBLGtCQUFrQiw0REFBVyxFQUFFLGtEQUFNO0FBQ3JDO0FBQ0EsSUFBSSwwRUFBYTtBQUNqQjtBQUNtQjtBQUNuQiIsInNvdXJjZXMiOlsid2VicGFjazovL19OX0UvLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvY29tcG9uZW50cy93dWktY2FyZC9pbmRleC5qcz84YThiIl0sInNvdXJjZXNDb250ZW50IjpbInZhciBfX2RlY29yYXRlID0gKHRoaXMgJiYgdGhpcy5fX2RlY29yYXRlKSB8fCBmdW5jdGlvbiAoZGVjb3JhdG9ycywgdGFyZ2V0LCBrZXksIGRlc2MpIHtcbiAgICB2YXIgYyA9IGFyZ3VtZW50cy5sZW5ndGgsIHIgPSBjIDwgMyA/IHRhcmdldCA6IGRlc2MgPT09IG51bGwgPyBkZXNjID0gT2JqZWN0LmdldE93blByb3BlcnR5RGVzY3JpcHRvcih0YXJnZXQsIGtleSkgOiBkZXNjLCBkO1xuICAgIGlmICh0eXBlb2YgUmVmbGVjdCA9PT0gXCJvYmplY3RcIiAmJiB0eXBlb2YgUmVmbGVjdC5kZWNvcmF0ZSA9PT0gXCJmdW5jdGlvblwiKSByID0gUmVmbGVjdC5kZWNvcmF0ZShkZWNvcmF0b3JzLCB0YXJnZXQsIGtleSwgZGVzYyk7XG4gICAgZWxzZSBmb3IgKHZhciBpID0gZGVjb3JhdG9ycy5sZW5ndGggLSAxOyBpID49IDA7IGktLSkgaWYgKGQgPSBkZWNvcmF0b3JzW2ldKSByID0gKGMgPCAzID8gZChyKSA6IGMgPiAzID8gZCh0YXJnZXQsIGtleSwgcikgOiBkKHRhcmdldCwga2V5KSkgfHwgcjtcbiAgICByZXR1cm4gYyA+IDMgJiYgciAmJiBPYmplY3QuZGVmaW5lUHJvcGVydHkodGFyZ2V0LCBrZXksIHIpLCByO1xufTtcbmltcG9ydCB7IExpdEVsZW1lbnQsIGh0bWwgfSBmcm9tICdsaXQnO1xuaW1wb3J0IHsgcmVzZXRTdHlsZXMgfSBmcm9tICcuLi8uLi91dGlscy9UaGVtZVV0aWwuanMnO1xuaW1wb3J0IHsgY3VzdG9tRWxlbWVudCB9IGZyb20gJy4uLy4uL3V0aWxzL1dlYkNvbXBvbmVudHNVdGlsLmpzJztcbmltcG9ydCBzdHlsZXMgZnJvbSAnLi9zdHlsZXMuanMnO1xubGV0IFd1aUNhcmQgPSBjbGFzcyBXdWlDYXJkIGV4dGVuZHMgTGl0RWxlbWVudCB7XG4gICAgcmVuZGVyKCkge1xuICAgICAgICByZXR1cm4gaHRtbCBgPHNsb3Q+PC9zbG90PmA7XG4gICAgfVxufTtcbld1aUNhcmQuc3R5bGVzID0gW3Jlc2V0U3R5bGVzLCBzdHlsZXNdO1xuV3VpQ2FyZCA9IF9fZGVjb3JhdGUoW1xuICAgIGN1c3RvbUVsZW1lbnQoJ3d1aS1jYXJkJylcbl0sIFd1aUNhcmQpO1xuZXhwb3J0IHsgV3VpQ2FyZCB9O1xuLy8jIHNvdXJjZU1hcHBpbmdVUkw9aW5kZXguanMubWFwIl0sIm5hbWVzIjpbXSwic291cmNlUm9vdCI6IiJ9\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-ui/dist/esm/src/components/wui-card/index.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@reown/appkit-ui/dist/esm/src/components/wui-card/styles.js":
-/*!**********************************************************************************!*\
-  !*** ./node_modules/@reown/appkit-ui/dist/esm/src/components/wui-card/styles.js ***!
-  \**********************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n\n/* harmony default export */ __webpack_exports__[\"default\"] = ((0,lit__WEBPACK_IMPORTED_MODULE_0__.css) `\n  :host {\n    display: block;\n    border-radius: clamp(0px, var(--wui-border-radius-l), 44px);\n    box-shadow: 0 0 0 1px var(--wui-color-gray-glass-005);\n    background-color: var(--wui-color-modal-bg);\n    overflow: hidden;\n  }\n\n  :host([data-embedded='true']) {\n    box-shadow:\n      0 0 0 1px var(--wui-color-gray-glass-005),\n      0px 4px 12px 4px var(--w3m-card-embedded-shadow-color);\n  }\n`);\n//# sourceMappingURL=styles.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvY29tcG9uZW50cy93dWktY2FyZC9zdHlsZXMuanMiLCJtYXBwaW5ncyI6Ijs7QUFBMEI7QUFDMUIsK0RBQWUsd0NBQUc7QUFDbEI7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7O0FBRUE7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBLENBQUMsRUFBQztBQUNGIiwic291cmNlcyI6WyJ3ZWJwYWNrOi8vX05fRS8uL25vZGVfbW9kdWxlcy9AcmVvd24vYXBwa2l0LXVpL2Rpc3QvZXNtL3NyYy9jb21wb25lbnRzL3d1aS1jYXJkL3N0eWxlcy5qcz9iNTdmIl0sInNvdXJjZXNDb250ZW50IjpbImltcG9ydCB7IGNzcyB9IGZyb20gJ2xpdCc7XG5leHBvcnQgZGVmYXVsdCBjc3MgYFxuICA6aG9zdCB7XG4gICAgZGlzcGxheTogYmxvY2s7XG4gICAgYm9yZGVyLXJhZGl1czogY2xhbXAoMHB4LCB2YXIoLS13dWktYm9yZGVyLXJhZGl1cy1sKSwgNDRweCk7XG4gICAgYm94LXNoYWRvdzogMCAwIDAgMXB4IHZhcigtLXd1aS1jb2xvci1ncmF5LWdsYXNzLTAwNSk7XG4gICAgYmFja2dyb3VuZC1jb2xvcjogdmFyKC0td3VpLWNvbG9yLW1vZGFsLWJnKTtcbiAgICBvdmVyZmxvdzogaGlkZGVuO1xuICB9XG5cbiAgOmhvc3QoW2RhdGEtZW1iZWRkZWQ9J3RydWUnXSkge1xuICAgIGJveC1zaGFkb3c6XG4gICAgICAwIDAgMCAxcHggdmFyKC0td3VpLWNvbG9yLWdyYXktZ2xhc3MtMDA1KSxcbiAgICAgIDBweCA0cHggMTJweCA0cHggdmFyKC0tdzNtLWNhcmQtZW1iZWRkZWQtc2hhZG93LWNvbG9yKTtcbiAgfVxuYDtcbi8vIyBzb3VyY2VNYXBwaW5nVVJMPXN0eWxlcy5qcy5tYXAiXSwibmFtZXMiOltdLCJzb3VyY2VSb290IjoiIn0=\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-ui/dist/esm/src/components/wui-card/styles.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js":
-/*!*********************************************************************************!*\
-  !*** ./node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js ***!
-  \*********************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   WuiIcon: function() { return /* binding */ WuiIcon; }\n/* harmony export */ });\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n/* harmony import */ var lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__ = __webpack_require__(/*! lit/decorators.js */ \"./node_modules/lit/decorators.js\");\n/* harmony import */ var lit_directives_until_js__WEBPACK_IMPORTED_MODULE_2__ = __webpack_require__(/*! lit/directives/until.js */ \"./node_modules/lit/directives/until.js\");\n/* harmony import */ var _utils_CacheUtil_js__WEBPACK_IMPORTED_MODULE_3__ = __webpack_require__(/*! ../../utils/CacheUtil.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/utils/CacheUtil.js\");\n/* harmony import */ var _utils_ThemeUtil_js__WEBPACK_IMPORTED_MODULE_4__ = __webpack_require__(/*! ../../utils/ThemeUtil.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/utils/ThemeUtil.js\");\n/* harmony import */ var _utils_WebComponentsUtil_js__WEBPACK_IMPORTED_MODULE_5__ = __webpack_require__(/*! ../../utils/WebComponentsUtil.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/utils/WebComponentsUtil.js\");\n/* harmony import */ var _styles_js__WEBPACK_IMPORTED_MODULE_6__ = __webpack_require__(/*! ./styles.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/styles.js\");\nvar __decorate = (undefined && undefined.__decorate) || function (decorators, target, key, desc) {\n    var c = arguments.length, r = c < 3 ? target : desc === null ? desc = Object.getOwnPropertyDescriptor(target, key) : desc, d;\n    if (typeof Reflect === \"object\" && typeof Reflect.decorate === \"function\") r = Reflect.decorate(decorators, target, key, desc);\n    else for (var i = decorators.length - 1; i >= 0; i--) if (d = decorators[i]) r = (c < 3 ? d(r) : c > 3 ? d(target, key, r) : d(target, key)) || r;\n    return c > 3 && r && Object.defineProperty(target, key, r), r;\n};\n\n\n\n\n\n\n\nconst ICONS = {\n    add: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_add_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/add.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/add.js\"))).addSvg,\n    allWallets: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_all-wallets_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/all-wallets.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/all-wallets.js\"))).allWalletsSvg,\n    arrowBottomCircle: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_arrow-bottom-circle_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/arrow-bottom-circle.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/arrow-bottom-circle.js\"))).arrowBottomCircleSvg,\n    appStore: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_app-store_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/app-store.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/app-store.js\"))).appStoreSvg,\n    apple: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_apple_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/apple.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/apple.js\"))).appleSvg,\n    arrowBottom: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_arrow-bottom_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/arrow-bottom.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/arrow-bottom.js\"))).arrowBottomSvg,\n    arrowLeft: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_arrow-left_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/arrow-left.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/arrow-left.js\"))).arrowLeftSvg,\n    arrowRight: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_arrow-right_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/arrow-right.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/arrow-right.js\"))).arrowRightSvg,\n    arrowTop: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_arrow-top_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/arrow-top.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/arrow-top.js\"))).arrowTopSvg,\n    bank: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_bank_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/bank.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/bank.js\"))).bankSvg,\n    browser: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_browser_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/browser.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/browser.js\"))).browserSvg,\n    card: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_card_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/card.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/card.js\"))).cardSvg,\n    checkmark: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_checkmark_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/checkmark.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/checkmark.js\"))).checkmarkSvg,\n    checkmarkBold: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_checkmark-bold_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/checkmark-bold.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/checkmark-bold.js\"))).checkmarkBoldSvg,\n    chevronBottom: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_chevron-bottom_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/chevron-bottom.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/chevron-bottom.js\"))).chevronBottomSvg,\n    chevronLeft: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_chevron-left_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/chevron-left.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/chevron-left.js\"))).chevronLeftSvg,\n    chevronRight: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_chevron-right_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/chevron-right.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/chevron-right.js\"))).chevronRightSvg,\n    chevronTop: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_chevron-top_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/chevron-top.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/chevron-top.js\"))).chevronTopSvg,\n    chromeStore: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_chrome-store_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/chrome-store.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/chrome-store.js\"))).chromeStoreSvg,\n    clock: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_clock_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/clock.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/clock.js\"))).clockSvg,\n    close: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_close_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/close.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/close.js\"))).closeSvg,\n    compass: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_compass_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/compass.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/compass.js\"))).compassSvg,\n    coinPlaceholder: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_coinPlaceholder_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/coinPlaceholder.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/coinPlaceholder.js\"))).coinPlaceholderSvg,\n    copy: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_copy_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/copy.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/copy.js\"))).copySvg,\n    cursor: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_cursor_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/cursor.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/cursor.js\"))).cursorSvg,\n    cursorTransparent: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_cursor-transparent_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/cursor-transparent.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/cursor-transparent.js\"))).cursorTransparentSvg,\n    desktop: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_desktop_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/desktop.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/desktop.js\"))).desktopSvg,\n    disconnect: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_disconnect_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/disconnect.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/disconnect.js\"))).disconnectSvg,\n    discord: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_discord_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/discord.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/discord.js\"))).discordSvg,\n    etherscan: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_etherscan_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/etherscan.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/etherscan.js\"))).etherscanSvg,\n    extension: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_extension_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/extension.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/extension.js\"))).extensionSvg,\n    externalLink: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_external-link_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/external-link.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/external-link.js\"))).externalLinkSvg,\n    facebook: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_facebook_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/facebook.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/facebook.js\"))).facebookSvg,\n    farcaster: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_farcaster_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/farcaster.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/farcaster.js\"))).farcasterSvg,\n    filters: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_filters_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/filters.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/filters.js\"))).filtersSvg,\n    github: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_github_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/github.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/github.js\"))).githubSvg,\n    google: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_google_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/google.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/google.js\"))).googleSvg,\n    helpCircle: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_help-circle_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/help-circle.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/help-circle.js\"))).helpCircleSvg,\n    image: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_image_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/image.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/image.js\"))).imageSvg,\n    id: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_id_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/id.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/id.js\"))).idSvg,\n    infoCircle: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_info-circle_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/info-circle.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/info-circle.js\"))).infoCircleSvg,\n    lightbulb: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_lightbulb_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/lightbulb.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/lightbulb.js\"))).lightbulbSvg,\n    mail: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_mail_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/mail.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/mail.js\"))).mailSvg,\n    mobile: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_mobile_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/mobile.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/mobile.js\"))).mobileSvg,\n    more: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_more_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/more.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/more.js\"))).moreSvg,\n    networkPlaceholder: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_network-placeholder_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/network-placeholder.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/network-placeholder.js\"))).networkPlaceholderSvg,\n    nftPlaceholder: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_nftPlaceholder_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/nftPlaceholder.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/nftPlaceholder.js\"))).nftPlaceholderSvg,\n    off: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_off_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/off.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/off.js\"))).offSvg,\n    playStore: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_play-store_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/play-store.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/play-store.js\"))).playStoreSvg,\n    plus: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_plus_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/plus.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/plus.js\"))).plusSvg,\n    qrCode: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_qr-code_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/qr-code.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/qr-code.js\"))).qrCodeIcon,\n    recycleHorizontal: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_recycle-horizontal_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/recycle-horizontal.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/recycle-horizontal.js\"))).recycleHorizontalSvg,\n    refresh: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_refresh_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/refresh.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/refresh.js\"))).refreshSvg,\n    search: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_search_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/search.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/search.js\"))).searchSvg,\n    send: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_send_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/send.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/send.js\"))).sendSvg,\n    swapHorizontal: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_swapHorizontal_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/swapHorizontal.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/swapHorizontal.js\"))).swapHorizontalSvg,\n    swapHorizontalMedium: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_swapHorizontalMedium_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/swapHorizontalMedium.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/swapHorizontalMedium.js\"))).swapHorizontalMediumSvg,\n    swapHorizontalBold: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_swapHorizontalBold_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/swapHorizontalBold.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/swapHorizontalBold.js\"))).swapHorizontalBoldSvg,\n    swapHorizontalRoundedBold: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_swapHorizontalRoundedBold_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/swapHorizontalRoundedBold.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/swapHorizontalRoundedBold.js\"))).swapHorizontalRoundedBoldSvg,\n    swapVertical: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_swapVertical_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/swapVertical.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/swapVertical.js\"))).swapVerticalSvg,\n    telegram: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_telegram_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/telegram.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/telegram.js\"))).telegramSvg,\n    threeDots: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_three-dots_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/three-dots.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/three-dots.js\"))).threeDotsSvg,\n    twitch: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_twitch_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/twitch.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/twitch.js\"))).twitchSvg,\n    twitter: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_x_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/x.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/x.js\"))).xSvg,\n    twitterIcon: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_twitterIcon_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/twitterIcon.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/twitterIcon.js\"))).twitterIconSvg,\n    verify: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_verify_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/verify.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/verify.js\"))).verifySvg,\n    verifyFilled: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_verify-filled_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/verify-filled.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/verify-filled.js\"))).verifyFilledSvg,\n    wallet: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_wallet_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/wallet.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/wallet.js\"))).walletSvg,\n    walletConnect: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_walletconnect_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/walletconnect.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/walletconnect.js\"))).walletConnectSvg,\n    walletConnectLightBrown: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_walletconnect_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/walletconnect.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/walletconnect.js\"))).walletConnectLightBrownSvg,\n    walletConnectBrown: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_walletconnect_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/walletconnect.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/walletconnect.js\"))).walletConnectBrownSvg,\n    walletPlaceholder: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_wallet-placeholder_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/wallet-placeholder.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/wallet-placeholder.js\"))).walletPlaceholderSvg,\n    warningCircle: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_warning-circle_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/warning-circle.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/warning-circle.js\"))).warningCircleSvg,\n    x: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_x_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/x.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/x.js\"))).xSvg,\n    info: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_info_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/info.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/info.js\"))).infoSvg,\n    exclamationTriangle: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_exclamation-triangle_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/exclamation-triangle.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/exclamation-triangle.js\"))).exclamationTriangleSvg,\n    reown: async () => (await __webpack_require__.e(/*! import() */ \"node_modules_reown_appkit-ui_dist_esm_src_assets_svg_reown-logo_js\").then(__webpack_require__.bind(__webpack_require__, /*! ../../assets/svg/reown-logo.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/reown-logo.js\"))).reownSvg\n};\nasync function getSvg(name) {\n    if (_utils_CacheUtil_js__WEBPACK_IMPORTED_MODULE_3__.globalSvgCache.has(name)) {\n        return _utils_CacheUtil_js__WEBPACK_IMPORTED_MODULE_3__.globalSvgCache.get(name);\n    }\n    const importFn = ICONS[name] ?? ICONS.copy;\n    const svgPromise = importFn();\n    _utils_CacheUtil_js__WEBPACK_IMPORTED_MODULE_3__.globalSvgCache.set(name, svgPromise);\n    return svgPromise;\n}\nlet WuiIcon = class WuiIcon extends lit__WEBPACK_IMPORTED_MODULE_0__.LitElement {\n    constructor() {\n        super(...arguments);\n        this.size = 'md';\n        this.name = 'copy';\n        this.color = 'fg-300';\n        this.aspectRatio = '1 / 1';\n    }\n    render() {\n        this.style.cssText = `\n      --local-color: ${`var(--wui-color-${this.color});`}\n      --local-width: ${`var(--wui-icon-size-${this.size});`}\n      --local-aspect-ratio: ${this.aspectRatio}\n    `;\n        return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `${(0,lit_directives_until_js__WEBPACK_IMPORTED_MODULE_2__.until)(getSvg(this.name), (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<div class=\"fallback\"></div>`)}`;\n    }\n};\nWuiIcon.styles = [_utils_ThemeUtil_js__WEBPACK_IMPORTED_MODULE_4__.resetStyles, _utils_ThemeUtil_js__WEBPACK_IMPORTED_MODULE_4__.colorStyles, _styles_js__WEBPACK_IMPORTED_MODULE_6__[\"default\"]];\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.property)()\n], WuiIcon.prototype, \"size\", void 0);\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.property)()\n], WuiIcon.prototype, \"name\", void 0);\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.property)()\n], WuiIcon.prototype, \"color\", void 0);\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.property)()\n], WuiIcon.prototype, \"aspectRatio\", void 0);\nWuiIcon = __decorate([\n    (0,_utils_WebComponentsUtil_js__WEBPACK_IMPORTED_MODULE_5__.customElement)('wui-icon')\n], WuiIcon);\n\n//# sourceMappingURL=index.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvY29tcG9uZW50cy93dWktaWNvbi9pbmRleC5qcyIsIm1hcHBpbmdzIjoiOzs7Ozs7Ozs7OztBQUFBLGtCQUFrQixTQUFJLElBQUksU0FBSTtBQUM5QjtBQUNBO0FBQ0EsNkNBQTZDLFFBQVE7QUFDckQ7QUFDQTtBQUN1QztBQUNNO0FBQ0c7QUFDVTtBQUNVO0FBQ0g7QUFDaEM7QUFDakM7QUFDQSw0QkFBNEIseVBBQWlDO0FBQzdELG1DQUFtQyxpUkFBeUM7QUFDNUUsMENBQTBDLHlTQUFpRDtBQUMzRixpQ0FBaUMsMlFBQXVDO0FBQ3hFLDhCQUE4QiwrUEFBbUM7QUFDakUsb0NBQW9DLG9SQUEwQztBQUM5RSxrQ0FBa0MsOFFBQXdDO0FBQzFFLG1DQUFtQyxpUkFBeUM7QUFDNUUsaUNBQWlDLDJRQUF1QztBQUN4RSw2QkFBNkIsNFBBQWtDO0FBQy9ELGdDQUFnQyxxUUFBcUM7QUFDckUsNkJBQTZCLDRQQUFrQztBQUMvRCxrQ0FBa0MsMlFBQXVDO0FBQ3pFLHNDQUFzQywwUkFBNEM7QUFDbEYsc0NBQXNDLDBSQUE0QztBQUNsRixvQ0FBb0Msb1JBQTBDO0FBQzlFLHFDQUFxQyx1UkFBMkM7QUFDaEYsbUNBQW1DLGlSQUF5QztBQUM1RSxvQ0FBb0Msb1JBQTBDO0FBQzlFLDhCQUE4QiwrUEFBbUM7QUFDakUsOEJBQThCLCtQQUFtQztBQUNqRSxnQ0FBZ0MscVFBQXFDO0FBQ3JFLHdDQUF3Qyw2UkFBNkM7QUFDckYsNkJBQTZCLDRQQUFrQztBQUMvRCwrQkFBK0Isa1FBQW9DO0FBQ25FLDBDQUEwQyxzU0FBZ0Q7QUFDMUYsZ0NBQWdDLHFRQUFxQztBQUNyRSxtQ0FBbUMsOFFBQXdDO0FBQzNFLGdDQUFnQyxxUUFBcUM7QUFDckUsa0NBQWtDLDJRQUF1QztBQUN6RSxrQ0FBa0MsMlFBQXVDO0FBQ3pFLHFDQUFxQyx1UkFBMkM7QUFDaEYsaUNBQWlDLHdRQUFzQztBQUN2RSxrQ0FBa0MsMlFBQXVDO0FBQ3pFLGdDQUFnQyxxUUFBcUM7QUFDckUsK0JBQStCLGtRQUFvQztBQUNuRSwrQkFBK0Isa1FBQW9DO0FBQ25FLG1DQUFtQyxpUkFBeUM7QUFDNUUsOEJBQThCLCtQQUFtQztBQUNqRSwyQkFBMkIsc1BBQWdDO0FBQzNELG1DQUFtQyxpUkFBeUM7QUFDNUUsa0NBQWtDLDJRQUF1QztBQUN6RSw2QkFBNkIsNFBBQWtDO0FBQy9ELCtCQUErQixrUUFBb0M7QUFDbkUsNkJBQTZCLDRQQUFrQztBQUMvRCwyQ0FBMkMseVNBQWlEO0FBQzVGLHVDQUF1QywwUkFBNEM7QUFDbkYsNEJBQTRCLHlQQUFpQztBQUM3RCxrQ0FBa0MsOFFBQXdDO0FBQzFFLDZCQUE2Qiw0UEFBa0M7QUFDL0QsK0JBQStCLHFRQUFxQztBQUNwRSwwQ0FBMEMsc1NBQWdEO0FBQzFGLGdDQUFnQyxxUUFBcUM7QUFDckUsK0JBQStCLGtRQUFvQztBQUNuRSw2QkFBNkIsNFBBQWtDO0FBQy9ELHVDQUF1QywwUkFBNEM7QUFDbkYsNkNBQTZDLDRTQUFrRDtBQUMvRiwyQ0FBMkMsc1NBQWdEO0FBQzNGLGtEQUFrRCwyVEFBdUQ7QUFDekcscUNBQXFDLG9SQUEwQztBQUMvRSxpQ0FBaUMsd1FBQXNDO0FBQ3ZFLGtDQUFrQyw4UUFBd0M7QUFDMUUsK0JBQStCLGtRQUFvQztBQUNuRSxnQ0FBZ0MsbVBBQStCO0FBQy9ELG9DQUFvQyxpUkFBeUM7QUFDN0UsK0JBQStCLGtRQUFvQztBQUNuRSxxQ0FBcUMsdVJBQTJDO0FBQ2hGLCtCQUErQixrUUFBb0M7QUFDbkUsc0NBQXNDLHVSQUEyQztBQUNqRixnREFBZ0QsdVJBQTJDO0FBQzNGLDJDQUEyQyx1UkFBMkM7QUFDdEYsMENBQTBDLHNTQUFnRDtBQUMxRixzQ0FBc0MsMFJBQTRDO0FBQ2xGLDBCQUEwQixtUEFBK0I7QUFDekQsNkJBQTZCLDRQQUFrQztBQUMvRCw0Q0FBNEMsNFNBQWtEO0FBQzlGLDhCQUE4Qiw4UUFBd0M7QUFDdEU7QUFDQTtBQUNBLFFBQVEsK0RBQWM7QUFDdEIsZUFBZSwrREFBYztBQUM3QjtBQUNBO0FBQ0E7QUFDQSxJQUFJLCtEQUFjO0FBQ2xCO0FBQ0E7QUFDQSxvQ0FBb0MsMkNBQVU7QUFDOUM7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0EsdUJBQXVCLG1CQUFtQixXQUFXLEVBQUU7QUFDdkQsdUJBQXVCLHVCQUF1QixVQUFVLEVBQUU7QUFDMUQsOEJBQThCO0FBQzlCO0FBQ0EsZUFBZSx5Q0FBSSxJQUFJLDhEQUFLLG9CQUFvQix5Q0FBSSxpQ0FBaUM7QUFDckY7QUFDQTtBQUNBLGtCQUFrQiw0REFBVyxFQUFFLDREQUFXLEVBQUUsa0RBQU07QUFDbEQ7QUFDQSxJQUFJLDJEQUFRO0FBQ1o7QUFDQTtBQUNBLElBQUksMkRBQVE7QUFDWjtBQUNBO0FBQ0EsSUFBSSwyREFBUTtBQUNaO0FBQ0E7QUFDQSxJQUFJLDJEQUFRO0FBQ1o7QUFDQTtBQUNBLElBQUksMEVBQWE7QUFDakI7QUFDbUI7QUFDbkIiLCJzb3VyY2VzIjpbIndlYnBhY2s6Ly9fTl9FLy4vbm9kZV9tb2R1bGVzL0ByZW93bi9hcHBraXQtdWkvZGlzdC9lc20vc3JjL2NvbXBvbmVudHMvd3VpLWljb24vaW5kZXguanM/MjYyZiJdLCJzb3VyY2VzQ29udGVudCI6WyJ2YXIgX19kZWNvcmF0ZSA9ICh0aGlzICYmIHRoaXMuX19kZWNvcmF0ZSkgfHwgZnVuY3Rpb24gKGRlY29yYXRvcnMsIHRhcmdldCwga2V5LCBkZXNjKSB7XG4gICAgdmFyIGMgPSBhcmd1bWVudHMubGVuZ3RoLCByID0gYyA8IDMgPyB0YXJnZXQgOiBkZXNjID09PSBudWxsID8gZGVzYyA9IE9iamVjdC5nZXRPd25Qcm9wZXJ0eURlc2NyaXB0b3IodGFyZ2V0LCBrZXkpIDogZGVzYywgZDtcbiAgICBpZiAodHlwZW9mIFJlZmxlY3QgPT09IFwib2JqZWN0XCIgJiYgdHlwZW9mIFJlZmxlY3QuZGVjb3JhdGUgPT09IFwiZnVuY3Rpb25cIikgciA9IFJlZmxlY3QuZGVjb3JhdGUoZGVjb3JhdG9ycywgdGFyZ2V0LCBrZXksIGRlc2MpO1xuICAgIGVsc2UgZm9yICh2YXIgaSA9IGRlY29yYXRvcnMubGVuZ3RoIC0gMTsgaSA+PSAwOyBpLS0pIGlmIChkID0gZGVjb3JhdG9yc1tpXSkgciA9IChjIDwgMyA/IGQocikgOiBjID4gMyA/IGQodGFyZ2V0LCBrZXksIHIpIDogZCh0YXJnZXQsIGtleSkpIHx8IHI7XG4gICAgcmV0dXJuIGMgPiAzICYmIHIgJiYgT2JqZWN0LmRlZmluZVByb3BlcnR5KHRhcmdldCwga2V5LCByKSwgcjtcbn07XG5pbXBvcnQgeyBMaXRFbGVtZW50LCBodG1sIH0gZnJvbSAnbGl0JztcbmltcG9ydCB7IHByb3BlcnR5IH0gZnJvbSAnbGl0L2RlY29yYXRvcnMuanMnO1xuaW1wb3J0IHsgdW50aWwgfSBmcm9tICdsaXQvZGlyZWN0aXZlcy91bnRpbC5qcyc7XG5pbXBvcnQgeyBnbG9iYWxTdmdDYWNoZSB9IGZyb20gJy4uLy4uL3V0aWxzL0NhY2hlVXRpbC5qcyc7XG5pbXBvcnQgeyBjb2xvclN0eWxlcywgcmVzZXRTdHlsZXMgfSBmcm9tICcuLi8uLi91dGlscy9UaGVtZVV0aWwuanMnO1xuaW1wb3J0IHsgY3VzdG9tRWxlbWVudCB9IGZyb20gJy4uLy4uL3V0aWxzL1dlYkNvbXBvbmVudHNVdGlsLmpzJztcbmltcG9ydCBzdHlsZXMgZnJvbSAnLi9zdHlsZXMuanMnO1xuY29uc3QgSUNPTlMgPSB7XG4gICAgYWRkOiBhc3luYyAoKSA9PiAoYXdhaXQgaW1wb3J0KCcuLi8uLi9hc3NldHMvc3ZnL2FkZC5qcycpKS5hZGRTdmcsXG4gICAgYWxsV2FsbGV0czogYXN5bmMgKCkgPT4gKGF3YWl0IGltcG9ydCgnLi4vLi4vYXNzZXRzL3N2Zy9hbGwtd2FsbGV0cy5qcycpKS5hbGxXYWxsZXRzU3ZnLFxuICAgIGFycm93Qm90dG9tQ2lyY2xlOiBhc3luYyAoKSA9PiAoYXdhaXQgaW1wb3J0KCcuLi8uLi9hc3NldHMvc3ZnL2Fycm93LWJvdHRvbS1jaXJjbGUuanMnKSkuYXJyb3dCb3R0b21DaXJjbGVTdmcsXG4gICAgYXBwU3RvcmU6IGFzeW5jICgpID0+IChhd2FpdCBpbXBvcnQoJy4uLy4uL2Fzc2V0cy9zdmcvYXBwLXN0b3JlLmpzJykpLmFwcFN0b3JlU3ZnLFxuICAgIGFwcGxlOiBhc3luYyAoKSA9PiAoYXdhaXQgaW1wb3J0KCcuLi8uLi9hc3NldHMvc3ZnL2FwcGxlLmpzJykpLmFwcGxlU3ZnLFxuICAgIGFycm93Qm90dG9tOiBhc3luYyAoKSA9PiAoYXdhaXQgaW1wb3J0KCcuLi8uLi9hc3NldHMvc3ZnL2Fycm93LWJvdHRvbS5qcycpKS5hcnJvd0JvdHRvbVN2ZyxcbiAgICBhcnJvd0xlZnQ6IGFzeW5jICgpID0+IChhd2FpdCBpbXBvcnQoJy4uLy4uL2Fzc2V0cy9zdmcvYXJyb3ctbGVmdC5qcycpKS5hcnJvd0xlZnRTdmcsXG4gICAgYXJyb3dSaWdodDogYXN5bmMgKCkgPT4gKGF3YWl0IGltcG9ydCgnLi4vLi4vYXNzZXRzL3N2Zy9hcnJvdy1yaWdodC5qcycpKS5hcnJvd1JpZ2h0U3ZnLFxuICAgIGFycm93VG9wOiBhc3luYyAoKSA9PiAoYXdhaXQgaW1wb3J0KCcuLi8uLi9hc3NldHMvc3ZnL2Fycm93LXRvcC5qcycpKS5hcnJvd1RvcFN2ZyxcbiAgICBiYW5rOiBhc3luYyAoKSA9PiAoYXdhaXQgaW1wb3J0KCcuLi8uLi9hc3NldHMvc3ZnL2JhbmsuanMnKSkuYmFua1N2ZyxcbiAgICBicm93c2VyOiBhc3luYyAoKSA9PiAoYXdhaXQgaW1wb3J0KCcuLi8uLi9hc3NldHMvc3ZnL2Jyb3dzZXIuanMnKSkuYnJvd3NlclN2ZyxcbiAgICBjYXJkOiBhc3luYyAoKSA9PiAoYXdhaXQgaW1wb3J0KCcuLi8uLi9hc3NldHMvc3ZnL2NhcmQuanMnKSkuY2FyZFN2ZyxcbiAgICBjaGVja21hcms6IGFzeW5jICgpID0+IChhd2FpdCBpbXBvcnQoJy4uLy4uL2Fzc2V0cy9zdmcvY2hlY2ttYXJrLmpzJykpLmNoZWNrbWFya1N2ZyxcbiAgICBjaGVja21hcmtCb2xkOiBhc3luYyAoKSA9PiAoYXdhaXQgaW1wb3J0KCcuLi8uLi9hc3NldHMvc3ZnL2NoZWNrbWFyay1ib2xkLmpzJykpLmNoZWNrbWFya0JvbGRTdmcsXG4gICAgY2hldnJvbkJvdHRvbTogYXN5bmMgKCkgPT4gKGF3YWl0IGltcG9ydCgnLi4vLi4vYXNzZXRzL3N2Zy9jaGV2cm9uLWJvdHRvbS5qcycpKS5jaGV2cm9uQm90dG9tU3ZnLFxuICAgIGNoZXZyb25MZWZ0OiBhc3luYyAoKSA9PiAoYXdhaXQgaW1wb3J0KCcuLi8uLi9hc3NldHMvc3ZnL2NoZXZyb24tbGVmdC5qcycpKS5jaGV2cm9uTGVmdFN2ZyxcbiAgICBjaGV2cm9uUmlnaHQ6IGFzeW5jICgpID0+IChhd2FpdCBpbXBvcnQoJy4uLy4uL2Fzc2V0cy9zdmcvY2hldnJvbi1yaWdodC5qcycpKS5jaGV2cm9uUmlnaHRTdmcsXG4gICAgY2hldnJvblRvcDogYXN5bmMgKCkgPT4gKGF3YWl0IGltcG9ydCgnLi4vLi4vYXNzZXRzL3N2Zy9jaGV2cm9uLXRvcC5qcycpKS5jaGV2cm9uVG9wU3ZnLFxuICAgIGNocm9tZVN0b3JlOiBhc3luYyAoKSA9PiAoYXdhaXQgaW1wb3J0KCcuLi8uLi9hc3NldHMvc3ZnL2Nocm9tZS1zdG9yZS5qcycpKS5jaHJvbWVTdG9yZVN2ZyxcbiAgICBjbG9jazogYXN5bmMgKCkgPT4gKGF3YWl0IGltcG9ydCgnLi4vLi4vYXNzZXRzL3N2Zy9jbG9jay5qcycpKS5jbG9ja1N2ZyxcbiAgICBjbG9zZTogYXN5bmMgKCkgPT4gKGF3YWl0IGltcG9ydCgnLi4vLi4vYXNzZXRzL3N2Zy9jbG9zZS5qcycpKS5jbG9zZVN2ZyxcbiAgICBjb21wYXNzOiBhc3luYyAoKSA9PiAoYXdhaXQgaW1wb3J0KCcuLi8uLi9hc3NldHMvc3ZnL2NvbXBhc3MuanMnKSkuY29tcGFzc1N2ZyxcbiAgICBjb2luUGxhY2Vob2xkZXI6IGFzeW5jICgpID0+IChhd2FpdCBpbXBvcnQoJy4uLy4uL2Fzc2V0cy9zdmcvY29pblBsYWNlaG9sZGVyLmpzJykpLmNvaW5QbGFjZWhvbGRlclN2ZyxcbiAgICBjb3B5OiBhc3luYyAoKSA9PiAoYXdhaXQgaW1wb3J0KCcuLi8uLi9hc3NldHMvc3ZnL2NvcHkuanMnKSkuY29weVN2ZyxcbiAgICBjdXJzb3I6IGFzeW5jICgpID0+IChhd2FpdCBpbXBvcnQoJy4uLy4uL2Fzc2V0cy9zdmcvY3Vyc29yLmpzJykpLmN1cnNvclN2ZyxcbiAgICBjdXJzb3JUcmFuc3BhcmVudDogYXN5bmMgKCkgPT4gKGF3YWl0IGltcG9ydCgnLi4vLi4vYXNzZXRzL3N2Zy9jdXJzb3ItdHJhbnNwYXJlbnQuanMnKSkuY3Vyc29yVHJhbnNwYXJlbnRTdmcsXG4gICAgZGVza3RvcDogYXN5bmMgKCkgPT4gKGF3YWl0IGltcG9ydCgnLi4vLi4vYXNzZXRzL3N2Zy9kZXNrdG9wLmpzJykpLmRlc2t0b3BTdmcsXG4gICAgZGlzY29ubmVjdDogYXN5bmMgKCkgPT4gKGF3YWl0IGltcG9ydCgnLi4vLi4vYXNzZXRzL3N2Zy9kaXNjb25uZWN0LmpzJykpLmRpc2Nvbm5lY3RTdmcsXG4gICAgZGlzY29yZDogYXN5bmMgKCkgPT4gKGF3YWl0IGltcG9ydCgnLi4vLi4vYXNzZXRzL3N2Zy9kaXNjb3JkLmpzJykpLmRpc2NvcmRTdmcsXG4gICAgZXRoZXJzY2FuOiBhc3luYyAoKSA9PiAoYXdhaXQgaW1wb3J0KCcuLi8uLi9hc3NldHMvc3ZnL2V0aGVyc2Nhbi5qcycpKS5ldGhlcnNjYW5TdmcsXG4gICAgZXh0ZW5zaW9uOiBhc3luYyAoKSA9PiAoYXdhaXQgaW1wb3J0KCcuLi8uLi9hc3NldHMvc3ZnL2V4dGVuc2lvbi5qcycpKS5leHRlbnNpb25TdmcsXG4gICAgZXh0ZXJuYWxMaW5rOiBhc3luYyAoKSA9PiAoYXdhaXQgaW1wb3J0KCcuLi8uLi9hc3NldHMvc3ZnL2V4dGVybmFsLWxpbmsuanMnKSkuZXh0ZXJuYWxMaW5rU3ZnLFxuICAgIGZhY2Vib29rOiBhc3luYyAoKSA9PiAoYXdhaXQgaW1wb3J0KCcuLi8uLi9hc3NldHMvc3ZnL2ZhY2Vib29rLmpzJykpLmZhY2Vib29rU3ZnLFxuICAgIGZhcmNhc3RlcjogYXN5bmMgKCkgPT4gKGF3YWl0IGltcG9ydCgnLi4vLi4vYXNzZXRzL3N2Zy9mYXJjYXN0ZXIuanMnKSkuZmFyY2FzdGVyU3ZnLFxuICAgIGZpbHRlcnM6IGFzeW5jICgpID0+IChhd2FpdCBpbXBvcnQoJy4uLy4uL2Fzc2V0cy9zdmcvZmlsdGVycy5qcycpKS5maWx0ZXJzU3ZnLFxuICAgIGdpdGh1YjogYXN5bmMgKCkgPT4gKGF3YWl0IGltcG9ydCgnLi4vLi4vYXNzZXRzL3N2Zy9naXRodWIuanMnKSkuZ2l0aHViU3ZnLFxuICAgIGdvb2dsZTogYXN5bmMgKCkgPT4gKGF3YWl0IGltcG9ydCgnLi4vLi4vYXNzZXRzL3N2Zy9nb29nbGUuanMnKSkuZ29vZ2xlU3ZnLFxuICAgIGhlbHBDaXJjbGU6IGFzeW5jICgpID0+IChhd2FpdCBpbXBvcnQoJy4uLy4uL2Fzc2V0cy9zdmcvaGVscC1jaXJjbGUuanMnKSkuaGVscENpcmNsZVN2ZyxcbiAgICBpbWFnZTogYXN5bmMgKCkgPT4gKGF3YWl0IGltcG9ydCgnLi4vLi4vYXNzZXRzL3N2Zy9pbWFnZS5qcycpKS5pbWFnZVN2ZyxcbiAgICBpZDogYXN5bmMgKCkgPT4gKGF3YWl0IGltcG9ydCgnLi4vLi4vYXNzZXRzL3N2Zy9pZC5qcycpKS5pZFN2ZyxcbiAgICBpbmZvQ2lyY2xlOiBhc3luYyAoKSA9PiAoYXdhaXQgaW1wb3J0KCcuLi8uLi9hc3NldHMvc3ZnL2luZm8tY2lyY2xlLmpzJykpLmluZm9DaXJjbGVTdmcsXG4gICAgbGlnaHRidWxiOiBhc3luYyAoKSA9PiAoYXdhaXQgaW1wb3J0KCcuLi8uLi9hc3NldHMvc3ZnL2xpZ2h0YnVsYi5qcycpKS5saWdodGJ1bGJTdmcsXG4gICAgbWFpbDogYXN5bmMgKCkgPT4gKGF3YWl0IGltcG9ydCgnLi4vLi4vYXNzZXRzL3N2Zy9tYWlsLmpzJykpLm1haWxTdmcsXG4gICAgbW9iaWxlOiBhc3luYyAoKSA9PiAoYXdhaXQgaW1wb3J0KCcuLi8uLi9hc3NldHMvc3ZnL21vYmlsZS5qcycpKS5tb2JpbGVTdmcsXG4gICAgbW9yZTogYXN5bmMgKCkgPT4gKGF3YWl0IGltcG9ydCgnLi4vLi4vYXNzZXRzL3N2Zy9tb3JlLmpzJykpLm1vcmVTdmcsXG4gICAgbmV0d29ya1BsYWNlaG9sZGVyOiBhc3luYyAoKSA9PiAoYXdhaXQgaW1wb3J0KCcuLi8uLi9hc3NldHMvc3ZnL25ldHdvcmstcGxhY2Vob2xkZXIuanMnKSkubmV0d29ya1BsYWNlaG9sZGVyU3ZnLFxuICAgIG5mdFBsYWNlaG9sZGVyOiBhc3luYyAoKSA9PiAoYXdhaXQgaW1wb3J0KCcuLi8uLi9hc3NldHMvc3ZnL25mdFBsYWNlaG9sZGVyLmpzJykpLm5mdFBsYWNlaG9sZGVyU3ZnLFxuICAgIG9mZjogYXN5bmMgKCkgPT4gKGF3YWl0IGltcG9ydCgnLi4vLi4vYXNzZXRzL3N2Zy9vZmYuanMnKSkub2ZmU3ZnLFxuICAgIHBsYXlTdG9yZTogYXN5bmMgKCkgPT4gKGF3YWl0IGltcG9ydCgnLi4vLi4vYXNzZXRzL3N2Zy9wbGF5LXN0b3JlLmpzJykpLnBsYXlTdG9yZVN2ZyxcbiAgICBwbHVzOiBhc3luYyAoKSA9PiAoYXdhaXQgaW1wb3J0KCcuLi8uLi9hc3NldHMvc3ZnL3BsdXMuanMnKSkucGx1c1N2ZyxcbiAgICBxckNvZGU6IGFzeW5jICgpID0+IChhd2FpdCBpbXBvcnQoJy4uLy4uL2Fzc2V0cy9zdmcvcXItY29kZS5qcycpKS5xckNvZGVJY29uLFxuICAgIHJlY3ljbGVIb3Jpem9udGFsOiBhc3luYyAoKSA9PiAoYXdhaXQgaW1wb3J0KCcuLi8uLi9hc3NldHMvc3ZnL3JlY3ljbGUtaG9yaXpvbnRhbC5qcycpKS5yZWN5Y2xlSG9yaXpvbnRhbFN2ZyxcbiAgICByZWZyZXNoOiBhc3luYyAoKSA9PiAoYXdhaXQgaW1wb3J0KCcuLi8uLi9hc3NldHMvc3ZnL3JlZnJlc2guanMnKSkucmVmcmVzaFN2ZyxcbiAgICBzZWFyY2g6IGFzeW5jICgpID0+IChhd2FpdCBpbXBvcnQoJy4uLy4uL2Fzc2V0cy9zdmcvc2VhcmNoLmpzJykpLnNlYXJjaFN2ZyxcbiAgICBzZW5kOiBhc3luYyAoKSA9PiAoYXdhaXQgaW1wb3J0KCcuLi8uLi9hc3NldHMvc3ZnL3NlbmQuanMnKSkuc2VuZFN2ZyxcbiAgICBzd2FwSG9yaXpvbnRhbDogYXN5bmMgKCkgPT4gKGF3YWl0IGltcG9ydCgnLi4vLi4vYXNzZXRzL3N2Zy9zd2FwSG9yaXpvbnRhbC5qcycpKS5zd2FwSG9yaXpvbnRhbFN2ZyxcbiAgICBzd2FwSG9yaXpvbnRhbE1lZGl1bTogYXN5bmMgKCkgPT4gKGF3YWl0IGltcG9ydCgnLi4vLi4vYXNzZXRzL3N2Zy9zd2FwSG9yaXpvbnRhbE1lZGl1bS5qcycpKS5zd2FwSG9yaXpvbnRhbE1lZGl1bVN2ZyxcbiAgICBzd2FwSG9yaXpvbnRhbEJvbGQ6IGFzeW5jICgpID0+IChhd2FpdCBpbXBvcnQoJy4uLy4uL2Fzc2V0cy9zdmcvc3dhcEhvcml6b250YWxCb2xkLmpzJykpLnN3YXBIb3Jpem9udGFsQm9sZFN2ZyxcbiAgICBzd2FwSG9yaXpvbnRhbFJvdW5kZWRCb2xkOiBhc3luYyAoKSA9PiAoYXdhaXQgaW1wb3J0KCcuLi8uLi9hc3NldHMvc3ZnL3N3YXBIb3Jpem9udGFsUm91bmRlZEJvbGQuanMnKSkuc3dhcEhvcml6b250YWxSb3VuZGVkQm9sZFN2ZyxcbiAgICBzd2FwVmVydGljYWw6IGFzeW5jICgpID0+IChhd2FpdCBpbXBvcnQoJy4uLy4uL2Fzc2V0cy9zdmcvc3dhcFZlcnRpY2FsLmpzJykpLnN3YXBWZXJ0aWNhbFN2ZyxcbiAgICB0ZWxlZ3JhbTogYXN5bmMgKCkgPT4gKGF3YWl0IGltcG9ydCgnLi4vLi4vYXNzZXRzL3N2Zy90ZWxlZ3JhbS5qcycpKS50ZWxlZ3JhbVN2ZyxcbiAgICB0aHJlZURvdHM6IGFzeW5jICgpID0+IChhd2FpdCBpbXBvcnQoJy4uLy4uL2Fzc2V0cy9zdmcvdGhyZWUtZG90cy5qcycpKS50aHJlZURvdHNTdmcsXG4gICAgdHdpdGNoOiBhc3luYyAoKSA9PiAoYXdhaXQgaW1wb3J0KCcuLi8uLi9hc3NldHMvc3ZnL3R3aXRjaC5qcycpKS50d2l0Y2hTdmcsXG4gICAgdHdpdHRlcjogYXN5bmMgKCkgPT4gKGF3YWl0IGltcG9ydCgnLi4vLi4vYXNzZXRzL3N2Zy94LmpzJykpLnhTdmcsXG4gICAgdHdpdHRlckljb246IGFzeW5jICgpID0+IChhd2FpdCBpbXBvcnQoJy4uLy4uL2Fzc2V0cy9zdmcvdHdpdHRlckljb24uanMnKSkudHdpdHRlckljb25TdmcsXG4gICAgdmVyaWZ5OiBhc3luYyAoKSA9PiAoYXdhaXQgaW1wb3J0KCcuLi8uLi9hc3NldHMvc3ZnL3ZlcmlmeS5qcycpKS52ZXJpZnlTdmcsXG4gICAgdmVyaWZ5RmlsbGVkOiBhc3luYyAoKSA9PiAoYXdhaXQgaW1wb3J0KCcuLi8uLi9hc3NldHMvc3ZnL3ZlcmlmeS1maWxsZWQuanMnKSkudmVyaWZ5RmlsbGVkU3ZnLFxuICAgIHdhbGxldDogYXN5bmMgKCkgPT4gKGF3YWl0IGltcG9ydCgnLi4vLi4vYXNzZXRzL3N2Zy93YWxsZXQuanMnKSkud2FsbGV0U3ZnLFxuICAgIHdhbGxldENvbm5lY3Q6IGFzeW5jICgpID0+IChhd2FpdCBpbXBvcnQoJy4uLy4uL2Fzc2V0cy9zdmcvd2FsbGV0Y29ubmVjdC5qcycpKS53YWxsZXRDb25uZWN0U3ZnLFxuICAgIHdhbGxldENvbm5lY3RMaWdodEJyb3duOiBhc3luYyAoKSA9PiAoYXdhaXQgaW1wb3J0KCcuLi8uLi9hc3NldHMvc3ZnL3dhbGxldGNvbm5lY3QuanMnKSkud2FsbGV0Q29ubmVjdExpZ2h0QnJvd25TdmcsXG4gICAgd2FsbGV0Q29ubmVjdEJyb3duOiBhc3luYyAoKSA9PiAoYXdhaXQgaW1wb3J0KCcuLi8uLi9hc3NldHMvc3ZnL3dhbGxldGNvbm5lY3QuanMnKSkud2FsbGV0Q29ubmVjdEJyb3duU3ZnLFxuICAgIHdhbGxldFBsYWNlaG9sZGVyOiBhc3luYyAoKSA9PiAoYXdhaXQgaW1wb3J0KCcuLi8uLi9hc3NldHMvc3ZnL3dhbGxldC1wbGFjZWhvbGRlci5qcycpKS53YWxsZXRQbGFjZWhvbGRlclN2ZyxcbiAgICB3YXJuaW5nQ2lyY2xlOiBhc3luYyAoKSA9PiAoYXdhaXQgaW1wb3J0KCcuLi8uLi9hc3NldHMvc3ZnL3dhcm5pbmctY2lyY2xlLmpzJykpLndhcm5pbmdDaXJjbGVTdmcsXG4gICAgeDogYXN5bmMgKCkgPT4gKGF3YWl0IGltcG9ydCgnLi4vLi4vYXNzZXRzL3N2Zy94LmpzJykpLnhTdmcsXG4gICAgaW5mbzogYXN5bmMgKCkgPT4gKGF3YWl0IGltcG9ydCgnLi4vLi4vYXNzZXRzL3N2Zy9pbmZvLmpzJykpLmluZm9TdmcsXG4gICAgZXhjbGFtYXRpb25UcmlhbmdsZTogYXN5bmMgKCkgPT4gKGF3YWl0IGltcG9ydCgnLi4vLi4vYXNzZXRzL3N2Zy9leGNsYW1hdGlvbi10cmlhbmdsZS5qcycpKS5leGNsYW1hdGlvblRyaWFuZ2xlU3ZnLFxuICAgIHJlb3duOiBhc3luYyAoKSA9PiAoYXdhaXQgaW1wb3J0KCcuLi8uLi9hc3NldHMvc3ZnL3Jlb3duLWxvZ28uanMnKSkucmVvd25Tdmdcbn07XG5hc3luYyBmdW5jdGlvbiBnZXRTdmcobmFtZSkge1xuICAgIGlmIChnbG9iYWxTdmdDYWNoZS5oYXMobmFtZSkpIHtcbiAgICAgICAgcmV0dXJuIGdsb2JhbFN2Z0NhY2hlLmdldChuYW1lKTtcbiAgICB9XG4gICAgY29uc3QgaW1wb3J0Rm4gPSBJQ09OU1tuYW1lXSA/PyBJQ09OUy5jb3B5O1xuICAgIGNvbnN0IHN2Z1Byb21pc2UgPSBpbXBvcnRGbigpO1xuICAgIGdsb2JhbFN2Z0NhY2hlLnNldChuYW1lLCBzdmdQcm9taXNlKTtcbiAgICByZXR1cm4gc3ZnUHJvbWlzZTtcbn1cbmxldCBXdWlJY29uID0gY2xhc3MgV3VpSWNvbiBleHRlbmRzIExpdEVsZW1lbnQge1xuICAgIGNvbnN0cnVjdG9yKCkge1xuICAgICAgICBzdXBlciguLi5hcmd1bWVudHMpO1xuICAgICAgICB0aGlzLnNpemUgPSAnbWQnO1xuICAgICAgICB0aGlzLm5hbWUgPSAnY29weSc7XG4gICAgICAgIHRoaXMuY29sb3IgPSAnZmctMzAwJztcbiAgICAgICAgdGhpcy5hc3BlY3RSYXRpbyA9ICcxIC8gMSc7XG4gICAgfVxuICAgIHJlbmRlcigpIHtcbiAgICAgICAgdGhpcy5zdHlsZS5jc3NUZXh0ID0gYFxuICAgICAgLS1sb2NhbC1jb2xvcjogJHtgdmFyKC0td3VpLWNvbG9yLSR7dGhpcy5jb2xvcn0pO2B9XG4gICAgICAtLWxvY2FsLXdpZHRoOiAke2B2YXIoLS13dWktaWNvbi1zaXplLSR7dGhpcy5zaXplfSk7YH1cbiAgICAgIC0tbG9jYWwtYXNwZWN0LXJhdGlvOiAke3RoaXMuYXNwZWN0UmF0aW99XG4gICAgYDtcbiAgICAgICAgcmV0dXJuIGh0bWwgYCR7dW50aWwoZ2V0U3ZnKHRoaXMubmFtZSksIGh0bWwgYDxkaXYgY2xhc3M9XCJmYWxsYmFja1wiPjwvZGl2PmApfWA7XG4gICAgfVxufTtcbld1aUljb24uc3R5bGVzID0gW3Jlc2V0U3R5bGVzLCBjb2xvclN0eWxlcywgc3R5bGVzXTtcbl9fZGVjb3JhdGUoW1xuICAgIHByb3BlcnR5KClcbl0sIFd1aUljb24ucHJvdG90eXBlLCBcInNpemVcIiwgdm9pZCAwKTtcbl9fZGVjb3JhdGUoW1xuICAgIHByb3BlcnR5KClcbl0sIFd1aUljb24ucHJvdG90eXBlLCBcIm5hbWVcIiwgdm9pZCAwKTtcbl9fZGVjb3JhdGUoW1xuICAgIHByb3BlcnR5KClcbl0sIFd1aUljb24ucHJvdG90eXBlLCBcImNvbG9yXCIsIHZvaWQgMCk7XG5fX2RlY29yYXRlKFtcbiAgICBwcm9wZXJ0eSgpXG5dLCBXdWlJY29uLnByb3RvdHlwZSwgXCJhc3BlY3RSYXRpb1wiLCB2b2lkIDApO1xuV3VpSWNvbiA9IF9fZGVjb3JhdGUoW1xuICAgIGN1c3RvbUVsZW1lbnQoJ3d1aS1pY29uJylcbl0sIFd1aUljb24pO1xuZXhwb3J0IHsgV3VpSWNvbiB9O1xuLy8jIHNvdXJjZU1hcHBpbmdVUkw9aW5kZXguanMubWFwIl0sIm5hbWVzIjpbXSwic291cmNlUm9vdCI6IiJ9\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/styles.js":
-/*!**********************************************************************************!*\
-  !*** ./node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/styles.js ***!
-  \**********************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n\n/* harmony default export */ __webpack_exports__[\"default\"] = ((0,lit__WEBPACK_IMPORTED_MODULE_0__.css) `\n  :host {\n    display: flex;\n    aspect-ratio: var(--local-aspect-ratio);\n    color: var(--local-color);\n    width: var(--local-width);\n  }\n\n  svg {\n    width: inherit;\n    height: inherit;\n    object-fit: contain;\n    object-position: center;\n  }\n\n  .fallback {\n    width: var(--local-width);\n    height: var(--local-height);\n  }\n`);\n//# sourceMappingURL=styles.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvY29tcG9uZW50cy93dWktaWNvbi9zdHlsZXMuanMiLCJtYXBwaW5ncyI6Ijs7QUFBMEI7QUFDMUIsK0RBQWUsd0NBQUc7QUFDbEI7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBOztBQUVBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTs7QUFFQTtBQUNBO0FBQ0E7QUFDQTtBQUNBLENBQUMsRUFBQztBQUNGIiwic291cmNlcyI6WyJ3ZWJwYWNrOi8vX05fRS8uL25vZGVfbW9kdWxlcy9AcmVvd24vYXBwa2l0LXVpL2Rpc3QvZXNtL3NyYy9jb21wb25lbnRzL3d1aS1pY29uL3N0eWxlcy5qcz8xY2I1Il0sInNvdXJjZXNDb250ZW50IjpbImltcG9ydCB7IGNzcyB9IGZyb20gJ2xpdCc7XG5leHBvcnQgZGVmYXVsdCBjc3MgYFxuICA6aG9zdCB7XG4gICAgZGlzcGxheTogZmxleDtcbiAgICBhc3BlY3QtcmF0aW86IHZhcigtLWxvY2FsLWFzcGVjdC1yYXRpbyk7XG4gICAgY29sb3I6IHZhcigtLWxvY2FsLWNvbG9yKTtcbiAgICB3aWR0aDogdmFyKC0tbG9jYWwtd2lkdGgpO1xuICB9XG5cbiAgc3ZnIHtcbiAgICB3aWR0aDogaW5oZXJpdDtcbiAgICBoZWlnaHQ6IGluaGVyaXQ7XG4gICAgb2JqZWN0LWZpdDogY29udGFpbjtcbiAgICBvYmplY3QtcG9zaXRpb246IGNlbnRlcjtcbiAgfVxuXG4gIC5mYWxsYmFjayB7XG4gICAgd2lkdGg6IHZhcigtLWxvY2FsLXdpZHRoKTtcbiAgICBoZWlnaHQ6IHZhcigtLWxvY2FsLWhlaWdodCk7XG4gIH1cbmA7XG4vLyMgc291cmNlTWFwcGluZ1VSTD1zdHlsZXMuanMubWFwIl0sIm5hbWVzIjpbXSwic291cmNlUm9vdCI6IiJ9\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/styles.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@reown/appkit-ui/dist/esm/src/components/wui-image/index.js":
-/*!**********************************************************************************!*\
-  !*** ./node_modules/@reown/appkit-ui/dist/esm/src/components/wui-image/index.js ***!
-  \**********************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   WuiImage: function() { return /* binding */ WuiImage; }\n/* harmony export */ });\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n/* harmony import */ var lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__ = __webpack_require__(/*! lit/decorators.js */ \"./node_modules/lit/decorators.js\");\n/* harmony import */ var _utils_ThemeUtil_js__WEBPACK_IMPORTED_MODULE_2__ = __webpack_require__(/*! ../../utils/ThemeUtil.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/utils/ThemeUtil.js\");\n/* harmony import */ var _utils_WebComponentsUtil_js__WEBPACK_IMPORTED_MODULE_3__ = __webpack_require__(/*! ../../utils/WebComponentsUtil.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/utils/WebComponentsUtil.js\");\n/* harmony import */ var _styles_js__WEBPACK_IMPORTED_MODULE_4__ = __webpack_require__(/*! ./styles.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/components/wui-image/styles.js\");\nvar __decorate = (undefined && undefined.__decorate) || function (decorators, target, key, desc) {\n    var c = arguments.length, r = c < 3 ? target : desc === null ? desc = Object.getOwnPropertyDescriptor(target, key) : desc, d;\n    if (typeof Reflect === \"object\" && typeof Reflect.decorate === \"function\") r = Reflect.decorate(decorators, target, key, desc);\n    else for (var i = decorators.length - 1; i >= 0; i--) if (d = decorators[i]) r = (c < 3 ? d(r) : c > 3 ? d(target, key, r) : d(target, key)) || r;\n    return c > 3 && r && Object.defineProperty(target, key, r), r;\n};\n\n\n\n\n\nlet WuiImage = class WuiImage extends lit__WEBPACK_IMPORTED_MODULE_0__.LitElement {\n    constructor() {\n        super(...arguments);\n        this.src = './path/to/image.jpg';\n        this.alt = 'Image';\n        this.size = undefined;\n    }\n    render() {\n        this.style.cssText = `\n      --local-width: ${this.size ? `var(--wui-icon-size-${this.size});` : '100%'};\n      --local-height: ${this.size ? `var(--wui-icon-size-${this.size});` : '100%'};\n      `;\n        return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<img src=${this.src} alt=${this.alt} @error=${this.handleImageError} />`;\n    }\n    handleImageError() {\n        this.dispatchEvent(new CustomEvent('onLoadError', { bubbles: true, composed: true }));\n    }\n};\nWuiImage.styles = [_utils_ThemeUtil_js__WEBPACK_IMPORTED_MODULE_2__.resetStyles, _utils_ThemeUtil_js__WEBPACK_IMPORTED_MODULE_2__.colorStyles, _styles_js__WEBPACK_IMPORTED_MODULE_4__[\"default\"]];\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.property)()\n], WuiImage.prototype, \"src\", void 0);\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.property)()\n], WuiImage.prototype, \"alt\", void 0);\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.property)()\n], WuiImage.prototype, \"size\", void 0);\nWuiImage = __decorate([\n    (0,_utils_WebComponentsUtil_js__WEBPACK_IMPORTED_MODULE_3__.customElement)('wui-image')\n], WuiImage);\n\n//# sourceMappingURL=index.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvY29tcG9uZW50cy93dWktaW1hZ2UvaW5kZXguanMiLCJtYXBwaW5ncyI6Ijs7Ozs7Ozs7O0FBQUEsa0JBQWtCLFNBQUksSUFBSSxTQUFJO0FBQzlCO0FBQ0E7QUFDQSw2Q0FBNkMsUUFBUTtBQUNyRDtBQUNBO0FBQ3VDO0FBQ007QUFDdUI7QUFDSDtBQUNoQztBQUNqQyxzQ0FBc0MsMkNBQVU7QUFDaEQ7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBLHVCQUF1QixtQ0FBbUMsVUFBVSxFQUFFO0FBQ3RFLHdCQUF3QixtQ0FBbUMsVUFBVSxFQUFFO0FBQ3ZFO0FBQ0EsZUFBZSx5Q0FBSSxhQUFhLFVBQVUsTUFBTSxVQUFVLFNBQVMsdUJBQXVCO0FBQzFGO0FBQ0E7QUFDQSw0REFBNEQsK0JBQStCO0FBQzNGO0FBQ0E7QUFDQSxtQkFBbUIsNERBQVcsRUFBRSw0REFBVyxFQUFFLGtEQUFNO0FBQ25EO0FBQ0EsSUFBSSwyREFBUTtBQUNaO0FBQ0E7QUFDQSxJQUFJLDJEQUFRO0FBQ1o7QUFDQTtBQUNBLElBQUksMkRBQVE7QUFDWjtBQUNBO0FBQ0EsSUFBSSwwRUFBYTtBQUNqQjtBQUNvQjtBQUNwQiIsInNvdXJjZXMiOlsid2VicGFjazovL19OX0UvLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvY29tcG9uZW50cy93dWktaW1hZ2UvaW5kZXguanM/NWNiMCJdLCJzb3VyY2VzQ29udGVudCI6WyJ2YXIgX19kZWNvcmF0ZSA9ICh0aGlzICYmIHRoaXMuX19kZWNvcmF0ZSkgfHwgZnVuY3Rpb24gKGRlY29yYXRvcnMsIHRhcmdldCwga2V5LCBkZXNjKSB7XG4gICAgdmFyIGMgPSBhcmd1bWVudHMubGVuZ3RoLCByID0gYyA8IDMgPyB0YXJnZXQgOiBkZXNjID09PSBudWxsID8gZGVzYyA9IE9iamVjdC5nZXRPd25Qcm9wZXJ0eURlc2NyaXB0b3IodGFyZ2V0LCBrZXkpIDogZGVzYywgZDtcbiAgICBpZiAodHlwZW9mIFJlZmxlY3QgPT09IFwib2JqZWN0XCIgJiYgdHlwZW9mIFJlZmxlY3QuZGVjb3JhdGUgPT09IFwiZnVuY3Rpb25cIikgciA9IFJlZmxlY3QuZGVjb3JhdGUoZGVjb3JhdG9ycywgdGFyZ2V0LCBrZXksIGRlc2MpO1xuICAgIGVsc2UgZm9yICh2YXIgaSA9IGRlY29yYXRvcnMubGVuZ3RoIC0gMTsgaSA+PSAwOyBpLS0pIGlmIChkID0gZGVjb3JhdG9yc1tpXSkgciA9IChjIDwgMyA/IGQocikgOiBjID4gMyA/IGQodGFyZ2V0LCBrZXksIHIpIDogZCh0YXJnZXQsIGtleSkpIHx8IHI7XG4gICAgcmV0dXJuIGMgPiAzICYmIHIgJiYgT2JqZWN0LmRlZmluZVByb3BlcnR5KHRhcmdldCwga2V5LCByKSwgcjtcbn07XG5pbXBvcnQgeyBMaXRFbGVtZW50LCBodG1sIH0gZnJvbSAnbGl0JztcbmltcG9ydCB7IHByb3BlcnR5IH0gZnJvbSAnbGl0L2RlY29yYXRvcnMuanMnO1xuaW1wb3J0IHsgY29sb3JTdHlsZXMsIHJlc2V0U3R5bGVzIH0gZnJvbSAnLi4vLi4vdXRpbHMvVGhlbWVVdGlsLmpzJztcbmltcG9ydCB7IGN1c3RvbUVsZW1lbnQgfSBmcm9tICcuLi8uLi91dGlscy9XZWJDb21wb25lbnRzVXRpbC5qcyc7XG5pbXBvcnQgc3R5bGVzIGZyb20gJy4vc3R5bGVzLmpzJztcbmxldCBXdWlJbWFnZSA9IGNsYXNzIFd1aUltYWdlIGV4dGVuZHMgTGl0RWxlbWVudCB7XG4gICAgY29uc3RydWN0b3IoKSB7XG4gICAgICAgIHN1cGVyKC4uLmFyZ3VtZW50cyk7XG4gICAgICAgIHRoaXMuc3JjID0gJy4vcGF0aC90by9pbWFnZS5qcGcnO1xuICAgICAgICB0aGlzLmFsdCA9ICdJbWFnZSc7XG4gICAgICAgIHRoaXMuc2l6ZSA9IHVuZGVmaW5lZDtcbiAgICB9XG4gICAgcmVuZGVyKCkge1xuICAgICAgICB0aGlzLnN0eWxlLmNzc1RleHQgPSBgXG4gICAgICAtLWxvY2FsLXdpZHRoOiAke3RoaXMuc2l6ZSA/IGB2YXIoLS13dWktaWNvbi1zaXplLSR7dGhpcy5zaXplfSk7YCA6ICcxMDAlJ307XG4gICAgICAtLWxvY2FsLWhlaWdodDogJHt0aGlzLnNpemUgPyBgdmFyKC0td3VpLWljb24tc2l6ZS0ke3RoaXMuc2l6ZX0pO2AgOiAnMTAwJSd9O1xuICAgICAgYDtcbiAgICAgICAgcmV0dXJuIGh0bWwgYDxpbWcgc3JjPSR7dGhpcy5zcmN9IGFsdD0ke3RoaXMuYWx0fSBAZXJyb3I9JHt0aGlzLmhhbmRsZUltYWdlRXJyb3J9IC8+YDtcbiAgICB9XG4gICAgaGFuZGxlSW1hZ2VFcnJvcigpIHtcbiAgICAgICAgdGhpcy5kaXNwYXRjaEV2ZW50KG5ldyBDdXN0b21FdmVudCgnb25Mb2FkRXJyb3InLCB7IGJ1YmJsZXM6IHRydWUsIGNvbXBvc2VkOiB0cnVlIH0pKTtcbiAgICB9XG59O1xuV3VpSW1hZ2Uuc3R5bGVzID0gW3Jlc2V0U3R5bGVzLCBjb2xvclN0eWxlcywgc3R5bGVzXTtcbl9fZGVjb3JhdGUoW1xuICAgIHByb3BlcnR5KClcbl0sIFd1aUltYWdlLnByb3RvdHlwZSwgXCJzcmNcIiwgdm9pZCAwKTtcbl9fZGVjb3JhdGUoW1xuICAgIHByb3BlcnR5KClcbl0sIFd1aUltYWdlLnByb3RvdHlwZSwgXCJhbHRcIiwgdm9pZCAwKTtcbl9fZGVjb3JhdGUoW1xuICAgIHByb3BlcnR5KClcbl0sIFd1aUltYWdlLnByb3RvdHlwZSwgXCJzaXplXCIsIHZvaWQgMCk7XG5XdWlJbWFnZSA9IF9fZGVjb3JhdGUoW1xuICAgIGN1c3RvbUVsZW1lbnQoJ3d1aS1pbWFnZScpXG5dLCBXdWlJbWFnZSk7XG5leHBvcnQgeyBXdWlJbWFnZSB9O1xuLy8jIHNvdXJjZU1hcHBpbmdVUkw9aW5kZXguanMubWFwIl0sIm5hbWVzIjpbXSwic291cmNlUm9vdCI6IiJ9\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-ui/dist/esm/src/components/wui-image/index.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@reown/appkit-ui/dist/esm/src/components/wui-image/styles.js":
-/*!***********************************************************************************!*\
-  !*** ./node_modules/@reown/appkit-ui/dist/esm/src/components/wui-image/styles.js ***!
-  \***********************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n\n/* harmony default export */ __webpack_exports__[\"default\"] = ((0,lit__WEBPACK_IMPORTED_MODULE_0__.css) `\n  :host {\n    display: block;\n    width: var(--local-width);\n    height: var(--local-height);\n  }\n\n  img {\n    display: block;\n    width: 100%;\n    height: 100%;\n    object-fit: cover;\n    object-position: center center;\n    border-radius: inherit;\n  }\n`);\n//# sourceMappingURL=styles.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvY29tcG9uZW50cy93dWktaW1hZ2Uvc3R5bGVzLmpzIiwibWFwcGluZ3MiOiI7O0FBQTBCO0FBQzFCLCtEQUFlLHdDQUFHO0FBQ2xCO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7O0FBRUE7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBLENBQUMsRUFBQztBQUNGIiwic291cmNlcyI6WyJ3ZWJwYWNrOi8vX05fRS8uL25vZGVfbW9kdWxlcy9AcmVvd24vYXBwa2l0LXVpL2Rpc3QvZXNtL3NyYy9jb21wb25lbnRzL3d1aS1pbWFnZS9zdHlsZXMuanM/OGEyMyJdLCJzb3VyY2VzQ29udGVudCI6WyJpbXBvcnQgeyBjc3MgfSBmcm9tICdsaXQnO1xuZXhwb3J0IGRlZmF1bHQgY3NzIGBcbiAgOmhvc3Qge1xuICAgIGRpc3BsYXk6IGJsb2NrO1xuICAgIHdpZHRoOiB2YXIoLS1sb2NhbC13aWR0aCk7XG4gICAgaGVpZ2h0OiB2YXIoLS1sb2NhbC1oZWlnaHQpO1xuICB9XG5cbiAgaW1nIHtcbiAgICBkaXNwbGF5OiBibG9jaztcbiAgICB3aWR0aDogMTAwJTtcbiAgICBoZWlnaHQ6IDEwMCU7XG4gICAgb2JqZWN0LWZpdDogY292ZXI7XG4gICAgb2JqZWN0LXBvc2l0aW9uOiBjZW50ZXIgY2VudGVyO1xuICAgIGJvcmRlci1yYWRpdXM6IGluaGVyaXQ7XG4gIH1cbmA7XG4vLyMgc291cmNlTWFwcGluZ1VSTD1zdHlsZXMuanMubWFwIl0sIm5hbWVzIjpbXSwic291cmNlUm9vdCI6IiJ9\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-ui/dist/esm/src/components/wui-image/styles.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@reown/appkit-ui/dist/esm/src/components/wui-loading-spinner/index.js":
-/*!********************************************************************************************!*\
-  !*** ./node_modules/@reown/appkit-ui/dist/esm/src/components/wui-loading-spinner/index.js ***!
-  \********************************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   WuiLoadingSpinner: function() { return /* binding */ WuiLoadingSpinner; }\n/* harmony export */ });\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n/* harmony import */ var lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__ = __webpack_require__(/*! lit/decorators.js */ \"./node_modules/lit/decorators.js\");\n/* harmony import */ var _utils_ThemeUtil_js__WEBPACK_IMPORTED_MODULE_2__ = __webpack_require__(/*! ../../utils/ThemeUtil.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/utils/ThemeUtil.js\");\n/* harmony import */ var _utils_WebComponentsUtil_js__WEBPACK_IMPORTED_MODULE_3__ = __webpack_require__(/*! ../../utils/WebComponentsUtil.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/utils/WebComponentsUtil.js\");\n/* harmony import */ var _styles_js__WEBPACK_IMPORTED_MODULE_4__ = __webpack_require__(/*! ./styles.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/components/wui-loading-spinner/styles.js\");\nvar __decorate = (undefined && undefined.__decorate) || function (decorators, target, key, desc) {\n    var c = arguments.length, r = c < 3 ? target : desc === null ? desc = Object.getOwnPropertyDescriptor(target, key) : desc, d;\n    if (typeof Reflect === \"object\" && typeof Reflect.decorate === \"function\") r = Reflect.decorate(decorators, target, key, desc);\n    else for (var i = decorators.length - 1; i >= 0; i--) if (d = decorators[i]) r = (c < 3 ? d(r) : c > 3 ? d(target, key, r) : d(target, key)) || r;\n    return c > 3 && r && Object.defineProperty(target, key, r), r;\n};\n\n\n\n\n\nlet WuiLoadingSpinner = class WuiLoadingSpinner extends lit__WEBPACK_IMPORTED_MODULE_0__.LitElement {\n    constructor() {\n        super(...arguments);\n        this.color = 'accent-100';\n        this.size = 'lg';\n    }\n    render() {\n        this.style.cssText = `--local-color: ${this.color === 'inherit' ? 'inherit' : `var(--wui-color-${this.color})`}`;\n        this.dataset['size'] = this.size;\n        return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<svg viewBox=\"25 25 50 50\">\n      <circle r=\"20\" cy=\"50\" cx=\"50\"></circle>\n    </svg>`;\n    }\n};\nWuiLoadingSpinner.styles = [_utils_ThemeUtil_js__WEBPACK_IMPORTED_MODULE_2__.resetStyles, _styles_js__WEBPACK_IMPORTED_MODULE_4__[\"default\"]];\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.property)()\n], WuiLoadingSpinner.prototype, \"color\", void 0);\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.property)()\n], WuiLoadingSpinner.prototype, \"size\", void 0);\nWuiLoadingSpinner = __decorate([\n    (0,_utils_WebComponentsUtil_js__WEBPACK_IMPORTED_MODULE_3__.customElement)('wui-loading-spinner')\n], WuiLoadingSpinner);\n\n//# sourceMappingURL=index.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvY29tcG9uZW50cy93dWktbG9hZGluZy1zcGlubmVyL2luZGV4LmpzIiwibWFwcGluZ3MiOiI7Ozs7Ozs7OztBQUFBLGtCQUFrQixTQUFJLElBQUksU0FBSTtBQUM5QjtBQUNBO0FBQ0EsNkNBQTZDLFFBQVE7QUFDckQ7QUFDQTtBQUN1QztBQUNNO0FBQ1U7QUFDVTtBQUNoQztBQUNqQyx3REFBd0QsMkNBQVU7QUFDbEU7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0EsK0NBQStDLDBEQUEwRCxXQUFXLEdBQUc7QUFDdkg7QUFDQSxlQUFlLHlDQUFJO0FBQ25CO0FBQ0E7QUFDQTtBQUNBO0FBQ0EsNEJBQTRCLDREQUFXLEVBQUUsa0RBQU07QUFDL0M7QUFDQSxJQUFJLDJEQUFRO0FBQ1o7QUFDQTtBQUNBLElBQUksMkRBQVE7QUFDWjtBQUNBO0FBQ0EsSUFBSSwwRUFBYTtBQUNqQjtBQUM2QjtBQUM3QiIsInNvdXJjZXMiOlsid2VicGFjazovL19OX0UvLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvY29tcG9uZW50cy93dWktbG9hZGluZy1zcGlubmVyL2luZGV4LmpzPzQzM2YiXSwic291cmNlc0NvbnRlbnQiOlsidmFyIF9fZGVjb3JhdGUgPSAodGhpcyAmJiB0aGlzLl9fZGVjb3JhdGUpIHx8IGZ1bmN0aW9uIChkZWNvcmF0b3JzLCB0YXJnZXQsIGtleSwgZGVzYykge1xuICAgIHZhciBjID0gYXJndW1lbnRzLmxlbmd0aCwgciA9IGMgPCAzID8gdGFyZ2V0IDogZGVzYyA9PT0gbnVsbCA/IGRlc2MgPSBPYmplY3QuZ2V0T3duUHJvcGVydHlEZXNjcmlwdG9yKHRhcmdldCwga2V5KSA6IGRlc2MsIGQ7XG4gICAgaWYgKHR5cGVvZiBSZWZsZWN0ID09PSBcIm9iamVjdFwiICYmIHR5cGVvZiBSZWZsZWN0LmRlY29yYXRlID09PSBcImZ1bmN0aW9uXCIpIHIgPSBSZWZsZWN0LmRlY29yYXRlKGRlY29yYXRvcnMsIHRhcmdldCwga2V5LCBkZXNjKTtcbiAgICBlbHNlIGZvciAodmFyIGkgPSBkZWNvcmF0b3JzLmxlbmd0aCAtIDE7IGkgPj0gMDsgaS0tKSBpZiAoZCA9IGRlY29yYXRvcnNbaV0pIHIgPSAoYyA8IDMgPyBkKHIpIDogYyA+IDMgPyBkKHRhcmdldCwga2V5LCByKSA6IGQodGFyZ2V0LCBrZXkpKSB8fCByO1xuICAgIHJldHVybiBjID4gMyAmJiByICYmIE9iamVjdC5kZWZpbmVQcm9wZXJ0eSh0YXJnZXQsIGtleSwgciksIHI7XG59O1xuaW1wb3J0IHsgTGl0RWxlbWVudCwgaHRtbCB9IGZyb20gJ2xpdCc7XG5pbXBvcnQgeyBwcm9wZXJ0eSB9IGZyb20gJ2xpdC9kZWNvcmF0b3JzLmpzJztcbmltcG9ydCB7IHJlc2V0U3R5bGVzIH0gZnJvbSAnLi4vLi4vdXRpbHMvVGhlbWVVdGlsLmpzJztcbmltcG9ydCB7IGN1c3RvbUVsZW1lbnQgfSBmcm9tICcuLi8uLi91dGlscy9XZWJDb21wb25lbnRzVXRpbC5qcyc7XG5pbXBvcnQgc3R5bGVzIGZyb20gJy4vc3R5bGVzLmpzJztcbmxldCBXdWlMb2FkaW5nU3Bpbm5lciA9IGNsYXNzIFd1aUxvYWRpbmdTcGlubmVyIGV4dGVuZHMgTGl0RWxlbWVudCB7XG4gICAgY29uc3RydWN0b3IoKSB7XG4gICAgICAgIHN1cGVyKC4uLmFyZ3VtZW50cyk7XG4gICAgICAgIHRoaXMuY29sb3IgPSAnYWNjZW50LTEwMCc7XG4gICAgICAgIHRoaXMuc2l6ZSA9ICdsZyc7XG4gICAgfVxuICAgIHJlbmRlcigpIHtcbiAgICAgICAgdGhpcy5zdHlsZS5jc3NUZXh0ID0gYC0tbG9jYWwtY29sb3I6ICR7dGhpcy5jb2xvciA9PT0gJ2luaGVyaXQnID8gJ2luaGVyaXQnIDogYHZhcigtLXd1aS1jb2xvci0ke3RoaXMuY29sb3J9KWB9YDtcbiAgICAgICAgdGhpcy5kYXRhc2V0WydzaXplJ10gPSB0aGlzLnNpemU7XG4gICAgICAgIHJldHVybiBodG1sIGA8c3ZnIHZpZXdCb3g9XCIyNSAyNSA1MCA1MFwiPlxuICAgICAgPGNpcmNsZSByPVwiMjBcIiBjeT1cIjUwXCIgY3g9XCI1MFwiPjwvY2lyY2xlPlxuICAgIDwvc3ZnPmA7XG4gICAgfVxufTtcbld1aUxvYWRpbmdTcGlubmVyLnN0eWxlcyA9IFtyZXNldFN0eWxlcywgc3R5bGVzXTtcbl9fZGVjb3JhdGUoW1xuICAgIHByb3BlcnR5KClcbl0sIFd1aUxvYWRpbmdTcGlubmVyLnByb3RvdHlwZSwgXCJjb2xvclwiLCB2b2lkIDApO1xuX19kZWNvcmF0ZShbXG4gICAgcHJvcGVydHkoKVxuXSwgV3VpTG9hZGluZ1NwaW5uZXIucHJvdG90eXBlLCBcInNpemVcIiwgdm9pZCAwKTtcbld1aUxvYWRpbmdTcGlubmVyID0gX19kZWNvcmF0ZShbXG4gICAgY3VzdG9tRWxlbWVudCgnd3VpLWxvYWRpbmctc3Bpbm5lcicpXG5dLCBXdWlMb2FkaW5nU3Bpbm5lcik7XG5leHBvcnQgeyBXdWlMb2FkaW5nU3Bpbm5lciB9O1xuLy8jIHNvdXJjZU1hcHBpbmdVUkw9aW5kZXguanMubWFwIl0sIm5hbWVzIjpbXSwic291cmNlUm9vdCI6IiJ9\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-ui/dist/esm/src/components/wui-loading-spinner/index.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@reown/appkit-ui/dist/esm/src/components/wui-loading-spinner/styles.js":
-/*!*********************************************************************************************!*\
-  !*** ./node_modules/@reown/appkit-ui/dist/esm/src/components/wui-loading-spinner/styles.js ***!
-  \*********************************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n\n/* harmony default export */ __webpack_exports__[\"default\"] = ((0,lit__WEBPACK_IMPORTED_MODULE_0__.css) `\n  :host {\n    display: flex;\n  }\n\n  :host([data-size='sm']) > svg {\n    width: 12px;\n    height: 12px;\n  }\n\n  :host([data-size='md']) > svg {\n    width: 16px;\n    height: 16px;\n  }\n\n  :host([data-size='lg']) > svg {\n    width: 24px;\n    height: 24px;\n  }\n\n  :host([data-size='xl']) > svg {\n    width: 32px;\n    height: 32px;\n  }\n\n  svg {\n    animation: rotate 2s linear infinite;\n  }\n\n  circle {\n    fill: none;\n    stroke: var(--local-color);\n    stroke-width: 4px;\n    stroke-dasharray: 1, 124;\n    stroke-dashoffset: 0;\n    stroke-linecap: round;\n    animation: dash 1.5s ease-in-out infinite;\n  }\n\n  :host([data-size='md']) > svg > circle {\n    stroke-width: 6px;\n  }\n\n  :host([data-size='sm']) > svg > circle {\n    stroke-width: 8px;\n  }\n\n  @keyframes rotate {\n    100% {\n      transform: rotate(360deg);\n    }\n  }\n\n  @keyframes dash {\n    0% {\n      stroke-dasharray: 1, 124;\n      stroke-dashoffset: 0;\n    }\n\n    50% {\n      stroke-dasharray: 90, 124;\n      stroke-dashoffset: -35;\n    }\n\n    100% {\n      stroke-dashoffset: -125;\n    }\n  }\n`);\n//# sourceMappingURL=styles.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvY29tcG9uZW50cy93dWktbG9hZGluZy1zcGlubmVyL3N0eWxlcy5qcyIsIm1hcHBpbmdzIjoiOztBQUEwQjtBQUMxQiwrREFBZSx3Q0FBRztBQUNsQjtBQUNBO0FBQ0E7O0FBRUE7QUFDQTtBQUNBO0FBQ0E7O0FBRUE7QUFDQTtBQUNBO0FBQ0E7O0FBRUE7QUFDQTtBQUNBO0FBQ0E7O0FBRUE7QUFDQTtBQUNBO0FBQ0E7O0FBRUE7QUFDQTtBQUNBOztBQUVBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTs7QUFFQTtBQUNBO0FBQ0E7O0FBRUE7QUFDQTtBQUNBOztBQUVBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7O0FBRUE7QUFDQTtBQUNBO0FBQ0E7QUFDQTs7QUFFQTtBQUNBO0FBQ0E7QUFDQTs7QUFFQTtBQUNBO0FBQ0E7QUFDQTtBQUNBLENBQUMsRUFBQztBQUNGIiwic291cmNlcyI6WyJ3ZWJwYWNrOi8vX05fRS8uL25vZGVfbW9kdWxlcy9AcmVvd24vYXBwa2l0LXVpL2Rpc3QvZXNtL3NyYy9jb21wb25lbnRzL3d1aS1sb2FkaW5nLXNwaW5uZXIvc3R5bGVzLmpzPzY1OTgiXSwic291cmNlc0NvbnRlbnQiOlsiaW1wb3J0IHsgY3NzIH0gZnJvbSAnbGl0JztcbmV4cG9ydCBkZWZhdWx0IGNzcyBgXG4gIDpob3N0IHtcbiAgICBkaXNwbGF5OiBmbGV4O1xuICB9XG5cbiAgOmhvc3QoW2RhdGEtc2l6ZT0nc20nXSkgPiBzdmcge1xuICAgIHdpZHRoOiAxMnB4O1xuICAgIGhlaWdodDogMTJweDtcbiAgfVxuXG4gIDpob3N0KFtkYXRhLXNpemU9J21kJ10pID4gc3ZnIHtcbiAgICB3aWR0aDogMTZweDtcbiAgICBoZWlnaHQ6IDE2cHg7XG4gIH1cblxuICA6aG9zdChbZGF0YS1zaXplPSdsZyddKSA+IHN2ZyB7XG4gICAgd2lkdGg6IDI0cHg7XG4gICAgaGVpZ2h0OiAyNHB4O1xuICB9XG5cbiAgOmhvc3QoW2RhdGEtc2l6ZT0neGwnXSkgPiBzdmcge1xuICAgIHdpZHRoOiAzMnB4O1xuICAgIGhlaWdodDogMzJweDtcbiAgfVxuXG4gIHN2ZyB7XG4gICAgYW5pbWF0aW9uOiByb3RhdGUgMnMgbGluZWFyIGluZmluaXRlO1xuICB9XG5cbiAgY2lyY2xlIHtcbiAgICBmaWxsOiBub25lO1xuICAgIHN0cm9rZTogdmFyKC0tbG9jYWwtY29sb3IpO1xuICAgIHN0cm9rZS13aWR0aDogNHB4O1xuICAgIHN0cm9rZS1kYXNoYXJyYXk6IDEsIDEyNDtcbiAgICBzdHJva2UtZGFzaG9mZnNldDogMDtcbiAgICBzdHJva2UtbGluZWNhcDogcm91bmQ7XG4gICAgYW5pbWF0aW9uOiBkYXNoIDEuNXMgZWFzZS1pbi1vdXQgaW5maW5pdGU7XG4gIH1cblxuICA6aG9zdChbZGF0YS1zaXplPSdtZCddKSA+IHN2ZyA+IGNpcmNsZSB7XG4gICAgc3Ryb2tlLXdpZHRoOiA2cHg7XG4gIH1cblxuICA6aG9zdChbZGF0YS1zaXplPSdzbSddKSA+IHN2ZyA+IGNpcmNsZSB7XG4gICAgc3Ryb2tlLXdpZHRoOiA4cHg7XG4gIH1cblxuICBAa2V5ZnJhbWVzIHJvdGF0ZSB7XG4gICAgMTAwJSB7XG4gICAgICB0cmFuc2Zvcm06IHJvdGF0ZSgzNjBkZWcpO1xuICAgIH1cbiAgfVxuXG4gIEBrZXlmcmFtZXMgZGFzaCB7XG4gICAgMCUge1xuICAgICAgc3Ryb2tlLWRhc2hhcnJheTogMSwgMTI0O1xuICAgICAgc3Ryb2tlLWRhc2hvZmZzZXQ6IDA7XG4gICAgfVxuXG4gICAgNTAlIHtcbiAgICAgIHN0cm9rZS1kYXNoYXJyYXk6IDkwLCAxMjQ7XG4gICAgICBzdHJva2UtZGFzaG9mZnNldDogLTM1O1xuICAgIH1cblxuICAgIDEwMCUge1xuICAgICAgc3Ryb2tlLWRhc2hvZmZzZXQ6IC0xMjU7XG4gICAgfVxuICB9XG5gO1xuLy8jIHNvdXJjZU1hcHBpbmdVUkw9c3R5bGVzLmpzLm1hcCJdLCJuYW1lcyI6W10sInNvdXJjZVJvb3QiOiIifQ==\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-ui/dist/esm/src/components/wui-loading-spinner/styles.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@reown/appkit-ui/dist/esm/src/components/wui-text/index.js":
-/*!*********************************************************************************!*\
-  !*** ./node_modules/@reown/appkit-ui/dist/esm/src/components/wui-text/index.js ***!
-  \*********************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   WuiText: function() { return /* binding */ WuiText; }\n/* harmony export */ });\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n/* harmony import */ var lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__ = __webpack_require__(/*! lit/decorators.js */ \"./node_modules/lit/decorators.js\");\n/* harmony import */ var lit_directives_class_map_js__WEBPACK_IMPORTED_MODULE_2__ = __webpack_require__(/*! lit/directives/class-map.js */ \"./node_modules/lit/directives/class-map.js\");\n/* harmony import */ var _utils_ThemeUtil_js__WEBPACK_IMPORTED_MODULE_3__ = __webpack_require__(/*! ../../utils/ThemeUtil.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/utils/ThemeUtil.js\");\n/* harmony import */ var _utils_WebComponentsUtil_js__WEBPACK_IMPORTED_MODULE_4__ = __webpack_require__(/*! ../../utils/WebComponentsUtil.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/utils/WebComponentsUtil.js\");\n/* harmony import */ var _styles_js__WEBPACK_IMPORTED_MODULE_5__ = __webpack_require__(/*! ./styles.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/components/wui-text/styles.js\");\nvar __decorate = (undefined && undefined.__decorate) || function (decorators, target, key, desc) {\n    var c = arguments.length, r = c < 3 ? target : desc === null ? desc = Object.getOwnPropertyDescriptor(target, key) : desc, d;\n    if (typeof Reflect === \"object\" && typeof Reflect.decorate === \"function\") r = Reflect.decorate(decorators, target, key, desc);\n    else for (var i = decorators.length - 1; i >= 0; i--) if (d = decorators[i]) r = (c < 3 ? d(r) : c > 3 ? d(target, key, r) : d(target, key)) || r;\n    return c > 3 && r && Object.defineProperty(target, key, r), r;\n};\n\n\n\n\n\n\nlet WuiText = class WuiText extends lit__WEBPACK_IMPORTED_MODULE_0__.LitElement {\n    constructor() {\n        super(...arguments);\n        this.variant = 'paragraph-500';\n        this.color = 'fg-300';\n        this.align = 'left';\n        this.lineClamp = undefined;\n    }\n    render() {\n        const classes = {\n            [`wui-font-${this.variant}`]: true,\n            [`wui-color-${this.color}`]: true,\n            [`wui-line-clamp-${this.lineClamp}`]: this.lineClamp ? true : false\n        };\n        this.style.cssText = `\n      --local-align: ${this.align};\n      --local-color: var(--wui-color-${this.color});\n    `;\n        return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<slot class=${(0,lit_directives_class_map_js__WEBPACK_IMPORTED_MODULE_2__.classMap)(classes)}></slot>`;\n    }\n};\nWuiText.styles = [_utils_ThemeUtil_js__WEBPACK_IMPORTED_MODULE_3__.resetStyles, _styles_js__WEBPACK_IMPORTED_MODULE_5__[\"default\"]];\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.property)()\n], WuiText.prototype, \"variant\", void 0);\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.property)()\n], WuiText.prototype, \"color\", void 0);\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.property)()\n], WuiText.prototype, \"align\", void 0);\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.property)()\n], WuiText.prototype, \"lineClamp\", void 0);\nWuiText = __decorate([\n    (0,_utils_WebComponentsUtil_js__WEBPACK_IMPORTED_MODULE_4__.customElement)('wui-text')\n], WuiText);\n\n//# sourceMappingURL=index.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvY29tcG9uZW50cy93dWktdGV4dC9pbmRleC5qcyIsIm1hcHBpbmdzIjoiOzs7Ozs7Ozs7O0FBQUEsa0JBQWtCLFNBQUksSUFBSSxTQUFJO0FBQzlCO0FBQ0E7QUFDQSw2Q0FBNkMsUUFBUTtBQUNyRDtBQUNBO0FBQ3VDO0FBQ007QUFDVTtBQUNBO0FBQ1U7QUFDaEM7QUFDakMsb0NBQW9DLDJDQUFVO0FBQzlDO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBLHlCQUF5QixhQUFhO0FBQ3RDLDBCQUEwQixXQUFXO0FBQ3JDLCtCQUErQixlQUFlO0FBQzlDO0FBQ0E7QUFDQSx1QkFBdUI7QUFDdkIsdUNBQXVDLFdBQVc7QUFDbEQ7QUFDQSxlQUFlLHlDQUFJLGdCQUFnQixxRUFBUSxVQUFVO0FBQ3JEO0FBQ0E7QUFDQSxrQkFBa0IsNERBQVcsRUFBRSxrREFBTTtBQUNyQztBQUNBLElBQUksMkRBQVE7QUFDWjtBQUNBO0FBQ0EsSUFBSSwyREFBUTtBQUNaO0FBQ0E7QUFDQSxJQUFJLDJEQUFRO0FBQ1o7QUFDQTtBQUNBLElBQUksMkRBQVE7QUFDWjtBQUNBO0FBQ0EsSUFBSSwwRUFBYTtBQUNqQjtBQUNtQjtBQUNuQiIsInNvdXJjZXMiOlsid2VicGFjazovL19OX0UvLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvY29tcG9uZW50cy93dWktdGV4dC9pbmRleC5qcz9mZjllIl0sInNvdXJjZXNDb250ZW50IjpbInZhciBfX2RlY29yYXRlID0gKHRoaXMgJiYgdGhpcy5fX2RlY29yYXRlKSB8fCBmdW5jdGlvbiAoZGVjb3JhdG9ycywgdGFyZ2V0LCBrZXksIGRlc2MpIHtcbiAgICB2YXIgYyA9IGFyZ3VtZW50cy5sZW5ndGgsIHIgPSBjIDwgMyA/IHRhcmdldCA6IGRlc2MgPT09IG51bGwgPyBkZXNjID0gT2JqZWN0LmdldE93blByb3BlcnR5RGVzY3JpcHRvcih0YXJnZXQsIGtleSkgOiBkZXNjLCBkO1xuICAgIGlmICh0eXBlb2YgUmVmbGVjdCA9PT0gXCJvYmplY3RcIiAmJiB0eXBlb2YgUmVmbGVjdC5kZWNvcmF0ZSA9PT0gXCJmdW5jdGlvblwiKSByID0gUmVmbGVjdC5kZWNvcmF0ZShkZWNvcmF0b3JzLCB0YXJnZXQsIGtleSwgZGVzYyk7XG4gICAgZWxzZSBmb3IgKHZhciBpID0gZGVjb3JhdG9ycy5sZW5ndGggLSAxOyBpID49IDA7IGktLSkgaWYgKGQgPSBkZWNvcmF0b3JzW2ldKSByID0gKGMgPCAzID8gZChyKSA6IGMgPiAzID8gZCh0YXJnZXQsIGtleSwgcikgOiBkKHRhcmdldCwga2V5KSkgfHwgcjtcbiAgICByZXR1cm4gYyA+IDMgJiYgciAmJiBPYmplY3QuZGVmaW5lUHJvcGVydHkodGFyZ2V0LCBrZXksIHIpLCByO1xufTtcbmltcG9ydCB7IExpdEVsZW1lbnQsIGh0bWwgfSBmcm9tICdsaXQnO1xuaW1wb3J0IHsgcHJvcGVydHkgfSBmcm9tICdsaXQvZGVjb3JhdG9ycy5qcyc7XG5pbXBvcnQgeyBjbGFzc01hcCB9IGZyb20gJ2xpdC9kaXJlY3RpdmVzL2NsYXNzLW1hcC5qcyc7XG5pbXBvcnQgeyByZXNldFN0eWxlcyB9IGZyb20gJy4uLy4uL3V0aWxzL1RoZW1lVXRpbC5qcyc7XG5pbXBvcnQgeyBjdXN0b21FbGVtZW50IH0gZnJvbSAnLi4vLi4vdXRpbHMvV2ViQ29tcG9uZW50c1V0aWwuanMnO1xuaW1wb3J0IHN0eWxlcyBmcm9tICcuL3N0eWxlcy5qcyc7XG5sZXQgV3VpVGV4dCA9IGNsYXNzIFd1aVRleHQgZXh0ZW5kcyBMaXRFbGVtZW50IHtcbiAgICBjb25zdHJ1Y3RvcigpIHtcbiAgICAgICAgc3VwZXIoLi4uYXJndW1lbnRzKTtcbiAgICAgICAgdGhpcy52YXJpYW50ID0gJ3BhcmFncmFwaC01MDAnO1xuICAgICAgICB0aGlzLmNvbG9yID0gJ2ZnLTMwMCc7XG4gICAgICAgIHRoaXMuYWxpZ24gPSAnbGVmdCc7XG4gICAgICAgIHRoaXMubGluZUNsYW1wID0gdW5kZWZpbmVkO1xuICAgIH1cbiAgICByZW5kZXIoKSB7XG4gICAgICAgIGNvbnN0IGNsYXNzZXMgPSB7XG4gICAgICAgICAgICBbYHd1aS1mb250LSR7dGhpcy52YXJpYW50fWBdOiB0cnVlLFxuICAgICAgICAgICAgW2B3dWktY29sb3ItJHt0aGlzLmNvbG9yfWBdOiB0cnVlLFxuICAgICAgICAgICAgW2B3dWktbGluZS1jbGFtcC0ke3RoaXMubGluZUNsYW1wfWBdOiB0aGlzLmxpbmVDbGFtcCA/IHRydWUgOiBmYWxzZVxuICAgICAgICB9O1xuICAgICAgICB0aGlzLnN0eWxlLmNzc1RleHQgPSBgXG4gICAgICAtLWxvY2FsLWFsaWduOiAke3RoaXMuYWxpZ259O1xuICAgICAgLS1sb2NhbC1jb2xvcjogdmFyKC0td3VpLWNvbG9yLSR7dGhpcy5jb2xvcn0pO1xuICAgIGA7XG4gICAgICAgIHJldHVybiBodG1sIGA8c2xvdCBjbGFzcz0ke2NsYXNzTWFwKGNsYXNzZXMpfT48L3Nsb3Q+YDtcbiAgICB9XG59O1xuV3VpVGV4dC5zdHlsZXMgPSBbcmVzZXRTdHlsZXMsIHN0eWxlc107XG5fX2RlY29yYXRlKFtcbiAgICBwcm9wZXJ0eSgpXG5dLCBXdWlUZXh0LnByb3RvdHlwZSwgXCJ2YXJpYW50XCIsIHZvaWQgMCk7XG5fX2RlY29yYXRlKFtcbiAgICBwcm9wZXJ0eSgpXG5dLCBXdWlUZXh0LnByb3RvdHlwZSwgXCJjb2xvclwiLCB2b2lkIDApO1xuX19kZWNvcmF0ZShbXG4gICAgcHJvcGVydHkoKVxuXSwgV3VpVGV4dC5wcm90b3R5cGUsIFwiYWxpZ25cIiwgdm9pZCAwKTtcbl9fZGVjb3JhdGUoW1xuICAgIHByb3BlcnR5KClcbl0sIFd1aVRleHQucHJvdG90eXBlLCBcImxpbmVDbGFtcFwiLCB2b2lkIDApO1xuV3VpVGV4dCA9IF9fZGVjb3JhdGUoW1xuICAgIGN1c3RvbUVsZW1lbnQoJ3d1aS10ZXh0Jylcbl0sIFd1aVRleHQpO1xuZXhwb3J0IHsgV3VpVGV4dCB9O1xuLy8jIHNvdXJjZU1hcHBpbmdVUkw9aW5kZXguanMubWFwIl0sIm5hbWVzIjpbXSwic291cmNlUm9vdCI6IiJ9\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-ui/dist/esm/src/components/wui-text/index.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@reown/appkit-ui/dist/esm/src/components/wui-text/styles.js":
-/*!**********************************************************************************!*\
-  !*** ./node_modules/@reown/appkit-ui/dist/esm/src/components/wui-text/styles.js ***!
-  \**********************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n\n/* harmony default export */ __webpack_exports__[\"default\"] = ((0,lit__WEBPACK_IMPORTED_MODULE_0__.css) `\n  :host {\n    display: inline-flex !important;\n  }\n\n  slot {\n    width: 100%;\n    display: inline-block;\n    font-style: normal;\n    font-family: var(--wui-font-family);\n    font-feature-settings:\n      'tnum' on,\n      'lnum' on,\n      'case' on;\n    line-height: 130%;\n    font-weight: var(--wui-font-weight-regular);\n    overflow: inherit;\n    text-overflow: inherit;\n    text-align: var(--local-align);\n    color: var(--local-color);\n  }\n\n  .wui-line-clamp-1 {\n    overflow: hidden;\n    display: -webkit-box;\n    -webkit-box-orient: vertical;\n    -webkit-line-clamp: 1;\n  }\n\n  .wui-line-clamp-2 {\n    overflow: hidden;\n    display: -webkit-box;\n    -webkit-box-orient: vertical;\n    -webkit-line-clamp: 2;\n  }\n\n  .wui-font-medium-400 {\n    font-size: var(--wui-font-size-medium);\n    font-weight: var(--wui-font-weight-light);\n    letter-spacing: var(--wui-letter-spacing-medium);\n  }\n\n  .wui-font-medium-600 {\n    font-size: var(--wui-font-size-medium);\n    letter-spacing: var(--wui-letter-spacing-medium);\n  }\n\n  .wui-font-title-600 {\n    font-size: var(--wui-font-size-title);\n    letter-spacing: var(--wui-letter-spacing-title);\n  }\n\n  .wui-font-title-6-600 {\n    font-size: var(--wui-font-size-title-6);\n    letter-spacing: var(--wui-letter-spacing-title-6);\n  }\n\n  .wui-font-mini-700 {\n    font-size: var(--wui-font-size-mini);\n    letter-spacing: var(--wui-letter-spacing-mini);\n    text-transform: uppercase;\n  }\n\n  .wui-font-large-500,\n  .wui-font-large-600,\n  .wui-font-large-700 {\n    font-size: var(--wui-font-size-large);\n    letter-spacing: var(--wui-letter-spacing-large);\n  }\n\n  .wui-font-2xl-500,\n  .wui-font-2xl-600,\n  .wui-font-2xl-700 {\n    font-size: var(--wui-font-size-2xl);\n    letter-spacing: var(--wui-letter-spacing-2xl);\n  }\n\n  .wui-font-paragraph-400,\n  .wui-font-paragraph-500,\n  .wui-font-paragraph-600,\n  .wui-font-paragraph-700 {\n    font-size: var(--wui-font-size-paragraph);\n    letter-spacing: var(--wui-letter-spacing-paragraph);\n  }\n\n  .wui-font-small-400,\n  .wui-font-small-500,\n  .wui-font-small-600 {\n    font-size: var(--wui-font-size-small);\n    letter-spacing: var(--wui-letter-spacing-small);\n  }\n\n  .wui-font-tiny-400,\n  .wui-font-tiny-500,\n  .wui-font-tiny-600 {\n    font-size: var(--wui-font-size-tiny);\n    letter-spacing: var(--wui-letter-spacing-tiny);\n  }\n\n  .wui-font-micro-700,\n  .wui-font-micro-600 {\n    font-size: var(--wui-font-size-micro);\n    letter-spacing: var(--wui-letter-spacing-micro);\n    text-transform: uppercase;\n  }\n\n  .wui-font-tiny-400,\n  .wui-font-small-400,\n  .wui-font-medium-400,\n  .wui-font-paragraph-400 {\n    font-weight: var(--wui-font-weight-light);\n  }\n\n  .wui-font-large-700,\n  .wui-font-paragraph-700,\n  .wui-font-micro-700,\n  .wui-font-mini-700 {\n    font-weight: var(--wui-font-weight-bold);\n  }\n\n  .wui-font-medium-600,\n  .wui-font-medium-title-600,\n  .wui-font-title-6-600,\n  .wui-font-large-600,\n  .wui-font-paragraph-600,\n  .wui-font-small-600,\n  .wui-font-tiny-600,\n  .wui-font-micro-600 {\n    font-weight: var(--wui-font-weight-medium);\n  }\n\n  :host([disabled]) {\n    opacity: 0.4;\n  }\n`);\n//# sourceMappingURL=styles.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvY29tcG9uZW50cy93dWktdGV4dC9zdHlsZXMuanMiLCJtYXBwaW5ncyI6Ijs7QUFBMEI7QUFDMUIsK0RBQWUsd0NBQUc7QUFDbEI7QUFDQTtBQUNBOztBQUVBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBOztBQUVBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTs7QUFFQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7O0FBRUE7QUFDQTtBQUNBO0FBQ0E7QUFDQTs7QUFFQTtBQUNBO0FBQ0E7QUFDQTs7QUFFQTtBQUNBO0FBQ0E7QUFDQTs7QUFFQTtBQUNBO0FBQ0E7QUFDQTs7QUFFQTtBQUNBO0FBQ0E7QUFDQTtBQUNBOztBQUVBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTs7QUFFQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7O0FBRUE7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7O0FBRUE7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBOztBQUVBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTs7QUFFQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7O0FBRUE7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBOztBQUVBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTs7QUFFQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTs7QUFFQTtBQUNBO0FBQ0E7QUFDQSxDQUFDLEVBQUM7QUFDRiIsInNvdXJjZXMiOlsid2VicGFjazovL19OX0UvLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvY29tcG9uZW50cy93dWktdGV4dC9zdHlsZXMuanM/NGMxYyJdLCJzb3VyY2VzQ29udGVudCI6WyJpbXBvcnQgeyBjc3MgfSBmcm9tICdsaXQnO1xuZXhwb3J0IGRlZmF1bHQgY3NzIGBcbiAgOmhvc3Qge1xuICAgIGRpc3BsYXk6IGlubGluZS1mbGV4ICFpbXBvcnRhbnQ7XG4gIH1cblxuICBzbG90IHtcbiAgICB3aWR0aDogMTAwJTtcbiAgICBkaXNwbGF5OiBpbmxpbmUtYmxvY2s7XG4gICAgZm9udC1zdHlsZTogbm9ybWFsO1xuICAgIGZvbnQtZmFtaWx5OiB2YXIoLS13dWktZm9udC1mYW1pbHkpO1xuICAgIGZvbnQtZmVhdHVyZS1zZXR0aW5nczpcbiAgICAgICd0bnVtJyBvbixcbiAgICAgICdsbnVtJyBvbixcbiAgICAgICdjYXNlJyBvbjtcbiAgICBsaW5lLWhlaWdodDogMTMwJTtcbiAgICBmb250LXdlaWdodDogdmFyKC0td3VpLWZvbnQtd2VpZ2h0LXJlZ3VsYXIpO1xuICAgIG92ZXJmbG93OiBpbmhlcml0O1xuICAgIHRleHQtb3ZlcmZsb3c6IGluaGVyaXQ7XG4gICAgdGV4dC1hbGlnbjogdmFyKC0tbG9jYWwtYWxpZ24pO1xuICAgIGNvbG9yOiB2YXIoLS1sb2NhbC1jb2xvcik7XG4gIH1cblxuICAud3VpLWxpbmUtY2xhbXAtMSB7XG4gICAgb3ZlcmZsb3c6IGhpZGRlbjtcbiAgICBkaXNwbGF5OiAtd2Via2l0LWJveDtcbiAgICAtd2Via2l0LWJveC1vcmllbnQ6IHZlcnRpY2FsO1xuICAgIC13ZWJraXQtbGluZS1jbGFtcDogMTtcbiAgfVxuXG4gIC53dWktbGluZS1jbGFtcC0yIHtcbiAgICBvdmVyZmxvdzogaGlkZGVuO1xuICAgIGRpc3BsYXk6IC13ZWJraXQtYm94O1xuICAgIC13ZWJraXQtYm94LW9yaWVudDogdmVydGljYWw7XG4gICAgLXdlYmtpdC1saW5lLWNsYW1wOiAyO1xuICB9XG5cbiAgLnd1aS1mb250LW1lZGl1bS00MDAge1xuICAgIGZvbnQtc2l6ZTogdmFyKC0td3VpLWZvbnQtc2l6ZS1tZWRpdW0pO1xuICAgIGZvbnQtd2VpZ2h0OiB2YXIoLS13dWktZm9udC13ZWlnaHQtbGlnaHQpO1xuICAgIGxldHRlci1zcGFjaW5nOiB2YXIoLS13dWktbGV0dGVyLXNwYWNpbmctbWVkaXVtKTtcbiAgfVxuXG4gIC53dWktZm9udC1tZWRpdW0tNjAwIHtcbiAgICBmb250LXNpemU6IHZhcigtLXd1aS1mb250LXNpemUtbWVkaXVtKTtcbiAgICBsZXR0ZXItc3BhY2luZzogdmFyKC0td3VpLWxldHRlci1zcGFjaW5nLW1lZGl1bSk7XG4gIH1cblxuICAud3VpLWZvbnQtdGl0bGUtNjAwIHtcbiAgICBmb250LXNpemU6IHZhcigtLXd1aS1mb250LXNpemUtdGl0bGUpO1xuICAgIGxldHRlci1zcGFjaW5nOiB2YXIoLS13dWktbGV0dGVyLXNwYWNpbmctdGl0bGUpO1xuICB9XG5cbiAgLnd1aS1mb250LXRpdGxlLTYtNjAwIHtcbiAgICBmb250LXNpemU6IHZhcigtLXd1aS1mb250LXNpemUtdGl0bGUtNik7XG4gICAgbGV0dGVyLXNwYWNpbmc6IHZhcigtLXd1aS1sZXR0ZXItc3BhY2luZy10aXRsZS02KTtcbiAgfVxuXG4gIC53dWktZm9udC1taW5pLTcwMCB7XG4gICAgZm9udC1zaXplOiB2YXIoLS13dWktZm9udC1zaXplLW1pbmkpO1xuICAgIGxldHRlci1zcGFjaW5nOiB2YXIoLS13dWktbGV0dGVyLXNwYWNpbmctbWluaSk7XG4gICAgdGV4dC10cmFuc2Zvcm06IHVwcGVyY2FzZTtcbiAgfVxuXG4gIC53dWktZm9udC1sYXJnZS01MDAsXG4gIC53dWktZm9udC1sYXJnZS02MDAsXG4gIC53dWktZm9udC1sYXJnZS03MDAge1xuICAgIGZvbnQtc2l6ZTogdmFyKC0td3VpLWZvbnQtc2l6ZS1sYXJnZSk7XG4gICAgbGV0dGVyLXNwYWNpbmc6IHZhcigtLXd1aS1sZXR0ZXItc3BhY2luZy1sYXJnZSk7XG4gIH1cblxuICAud3VpLWZvbnQtMnhsLTUwMCxcbiAgLnd1aS1mb250LTJ4bC02MDAsXG4gIC53dWktZm9udC0yeGwtNzAwIHtcbiAgICBmb250LXNpemU6IHZhcigtLXd1aS1mb250LXNpemUtMnhsKTtcbiAgICBsZXR0ZXItc3BhY2luZzogdmFyKC0td3VpLWxldHRlci1zcGFjaW5nLTJ4bCk7XG4gIH1cblxuICAud3VpLWZvbnQtcGFyYWdyYXBoLTQwMCxcbiAgLnd1aS1mb250LXBhcmFncmFwaC01MDAsXG4gIC53dWktZm9udC1wYXJhZ3JhcGgtNjAwLFxuICAud3VpLWZvbnQtcGFyYWdyYXBoLTcwMCB7XG4gICAgZm9udC1zaXplOiB2YXIoLS13dWktZm9udC1zaXplLXBhcmFncmFwaCk7XG4gICAgbGV0dGVyLXNwYWNpbmc6IHZhcigtLXd1aS1sZXR0ZXItc3BhY2luZy1wYXJhZ3JhcGgpO1xuICB9XG5cbiAgLnd1aS1mb250LXNtYWxsLTQwMCxcbiAgLnd1aS1mb250LXNtYWxsLTUwMCxcbiAgLnd1aS1mb250LXNtYWxsLTYwMCB7XG4gICAgZm9udC1zaXplOiB2YXIoLS13dWktZm9udC1zaXplLXNtYWxsKTtcbiAgICBsZXR0ZXItc3BhY2luZzogdmFyKC0td3VpLWxldHRlci1zcGFjaW5nLXNtYWxsKTtcbiAgfVxuXG4gIC53dWktZm9udC10aW55LTQwMCxcbiAgLnd1aS1mb250LXRpbnktNTAwLFxuICAud3VpLWZvbnQtdGlueS02MDAge1xuICAgIGZvbnQtc2l6ZTogdmFyKC0td3VpLWZvbnQtc2l6ZS10aW55KTtcbiAgICBsZXR0ZXItc3BhY2luZzogdmFyKC0td3VpLWxldHRlci1zcGFjaW5nLXRpbnkpO1xuICB9XG5cbiAgLnd1aS1mb250LW1pY3JvLTcwMCxcbiAgLnd1aS1mb250LW1pY3JvLTYwMCB7XG4gICAgZm9udC1zaXplOiB2YXIoLS13dWktZm9udC1zaXplLW1pY3JvKTtcbiAgICBsZXR0ZXItc3BhY2luZzogdmFyKC0td3VpLWxldHRlci1zcGFjaW5nLW1pY3JvKTtcbiAgICB0ZXh0LXRyYW5zZm9ybTogdXBwZXJjYXNlO1xuICB9XG5cbiAgLnd1aS1mb250LXRpbnktNDAwLFxuICAud3VpLWZvbnQtc21hbGwtNDAwLFxuICAud3VpLWZvbnQtbWVkaXVtLTQwMCxcbiAgLnd1aS1mb250LXBhcmFncmFwaC00MDAge1xuICAgIGZvbnQtd2VpZ2h0OiB2YXIoLS13dWktZm9udC13ZWlnaHQtbGlnaHQpO1xuICB9XG5cbiAgLnd1aS1mb250LWxhcmdlLTcwMCxcbiAgLnd1aS1mb250LXBhcmFncmFwaC03MDAsXG4gIC53dWktZm9udC1taWNyby03MDAsXG4gIC53dWktZm9udC1taW5pLTcwMCB7XG4gICAgZm9udC13ZWlnaHQ6IHZhcigtLXd1aS1mb250LXdlaWdodC1ib2xkKTtcbiAgfVxuXG4gIC53dWktZm9udC1tZWRpdW0tNjAwLFxuICAud3VpLWZvbnQtbWVkaXVtLXRpdGxlLTYwMCxcbiAgLnd1aS1mb250LXRpdGxlLTYtNjAwLFxuICAud3VpLWZvbnQtbGFyZ2UtNjAwLFxuICAud3VpLWZvbnQtcGFyYWdyYXBoLTYwMCxcbiAgLnd1aS1mb250LXNtYWxsLTYwMCxcbiAgLnd1aS1mb250LXRpbnktNjAwLFxuICAud3VpLWZvbnQtbWljcm8tNjAwIHtcbiAgICBmb250LXdlaWdodDogdmFyKC0td3VpLWZvbnQtd2VpZ2h0LW1lZGl1bSk7XG4gIH1cblxuICA6aG9zdChbZGlzYWJsZWRdKSB7XG4gICAgb3BhY2l0eTogMC40O1xuICB9XG5gO1xuLy8jIHNvdXJjZU1hcHBpbmdVUkw9c3R5bGVzLmpzLm1hcCJdLCJuYW1lcyI6W10sInNvdXJjZVJvb3QiOiIifQ==\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-ui/dist/esm/src/components/wui-text/styles.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@reown/appkit-ui/dist/esm/src/composites/wui-alertbar/index.js":
-/*!*************************************************************************************!*\
-  !*** ./node_modules/@reown/appkit-ui/dist/esm/src/composites/wui-alertbar/index.js ***!
-  \*************************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   WuiAlertBar: function() { return /* binding */ WuiAlertBar; }\n/* harmony export */ });\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n/* harmony import */ var lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__ = __webpack_require__(/*! lit/decorators.js */ \"./node_modules/lit/decorators.js\");\n/* harmony import */ var _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_8__ = __webpack_require__(/*! @reown/appkit-controllers */ \"./node_modules/@reown/appkit-controllers/dist/esm/exports/index.js\");\n/* harmony import */ var _components_wui_icon_index_js__WEBPACK_IMPORTED_MODULE_2__ = __webpack_require__(/*! ../../components/wui-icon/index.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js\");\n/* harmony import */ var _components_wui_text_index_js__WEBPACK_IMPORTED_MODULE_3__ = __webpack_require__(/*! ../../components/wui-text/index.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/components/wui-text/index.js\");\n/* harmony import */ var _layout_wui_flex_index_js__WEBPACK_IMPORTED_MODULE_4__ = __webpack_require__(/*! ../../layout/wui-flex/index.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/layout/wui-flex/index.js\");\n/* harmony import */ var _utils_ThemeUtil_js__WEBPACK_IMPORTED_MODULE_5__ = __webpack_require__(/*! ../../utils/ThemeUtil.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/utils/ThemeUtil.js\");\n/* harmony import */ var _utils_WebComponentsUtil_js__WEBPACK_IMPORTED_MODULE_6__ = __webpack_require__(/*! ../../utils/WebComponentsUtil.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/utils/WebComponentsUtil.js\");\n/* harmony import */ var _styles_js__WEBPACK_IMPORTED_MODULE_7__ = __webpack_require__(/*! ./styles.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/composites/wui-alertbar/styles.js\");\nvar __decorate = (undefined && undefined.__decorate) || function (decorators, target, key, desc) {\n    var c = arguments.length, r = c < 3 ? target : desc === null ? desc = Object.getOwnPropertyDescriptor(target, key) : desc, d;\n    if (typeof Reflect === \"object\" && typeof Reflect.decorate === \"function\") r = Reflect.decorate(decorators, target, key, desc);\n    else for (var i = decorators.length - 1; i >= 0; i--) if (d = decorators[i]) r = (c < 3 ? d(r) : c > 3 ? d(target, key, r) : d(target, key)) || r;\n    return c > 3 && r && Object.defineProperty(target, key, r), r;\n};\n\n\n\n\n\n\n\n\n\nlet WuiAlertBar = class WuiAlertBar extends lit__WEBPACK_IMPORTED_MODULE_0__.LitElement {\n    constructor() {\n        super(...arguments);\n        this.message = '';\n        this.backgroundColor = 'accent-100';\n        this.iconColor = 'accent-100';\n        this.icon = 'info';\n    }\n    render() {\n        this.style.cssText = `\n      --local-icon-bg-value: var(--wui-color-${this.backgroundColor});\n   `;\n        return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `\n      <wui-flex flexDirection=\"row\" justifyContent=\"space-between\" alignItems=\"center\">\n        <wui-flex columnGap=\"xs\" flexDirection=\"row\" alignItems=\"center\">\n          <wui-flex\n            flexDirection=\"row\"\n            alignItems=\"center\"\n            justifyContent=\"center\"\n            class=\"icon-box\"\n          >\n            <wui-icon color=${this.iconColor} size=\"md\" name=${this.icon}></wui-icon>\n          </wui-flex>\n          <wui-text variant=\"small-500\" color=\"bg-350\" data-testid=\"wui-alertbar-text\"\n            >${this.message}</wui-text\n          >\n        </wui-flex>\n        <wui-icon\n          class=\"close\"\n          color=\"bg-350\"\n          size=\"sm\"\n          name=\"close\"\n          @click=${this.onClose}\n        ></wui-icon>\n      </wui-flex>\n    `;\n    }\n    onClose() {\n        _reown_appkit_controllers__WEBPACK_IMPORTED_MODULE_8__.AlertController.close();\n    }\n};\nWuiAlertBar.styles = [_utils_ThemeUtil_js__WEBPACK_IMPORTED_MODULE_5__.resetStyles, _styles_js__WEBPACK_IMPORTED_MODULE_7__[\"default\"]];\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.property)()\n], WuiAlertBar.prototype, \"message\", void 0);\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.property)()\n], WuiAlertBar.prototype, \"backgroundColor\", void 0);\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.property)()\n], WuiAlertBar.prototype, \"iconColor\", void 0);\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.property)()\n], WuiAlertBar.prototype, \"icon\", void 0);\nWuiAlertBar = __decorate([\n    (0,_utils_WebComponentsUtil_js__WEBPACK_IMPORTED_MODULE_6__.customElement)('wui-alertbar')\n], WuiAlertBar);\n\n//# sourceMappingURL=index.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvY29tcG9zaXRlcy93dWktYWxlcnRiYXIvaW5kZXguanMiLCJtYXBwaW5ncyI6Ijs7Ozs7Ozs7Ozs7OztBQUFBLGtCQUFrQixTQUFJLElBQUksU0FBSTtBQUM5QjtBQUNBO0FBQ0EsNkNBQTZDLFFBQVE7QUFDckQ7QUFDQTtBQUN1QztBQUNNO0FBQ2U7QUFDaEI7QUFDQTtBQUNKO0FBQ2U7QUFDVTtBQUNoQztBQUNqQyw0Q0FBNEMsMkNBQVU7QUFDdEQ7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0EsK0NBQStDLHFCQUFxQjtBQUNwRTtBQUNBLGVBQWUseUNBQUk7QUFDbkI7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBLDhCQUE4QixnQkFBZ0IsaUJBQWlCLFVBQVU7QUFDekU7QUFDQTtBQUNBLGVBQWUsYUFBYTtBQUM1QjtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBLG1CQUFtQjtBQUNuQjtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0EsUUFBUSxzRUFBZTtBQUN2QjtBQUNBO0FBQ0Esc0JBQXNCLDREQUFXLEVBQUUsa0RBQU07QUFDekM7QUFDQSxJQUFJLDJEQUFRO0FBQ1o7QUFDQTtBQUNBLElBQUksMkRBQVE7QUFDWjtBQUNBO0FBQ0EsSUFBSSwyREFBUTtBQUNaO0FBQ0E7QUFDQSxJQUFJLDJEQUFRO0FBQ1o7QUFDQTtBQUNBLElBQUksMEVBQWE7QUFDakI7QUFDdUI7QUFDdkIiLCJzb3VyY2VzIjpbIndlYnBhY2s6Ly9fTl9FLy4vbm9kZV9tb2R1bGVzL0ByZW93bi9hcHBraXQtdWkvZGlzdC9lc20vc3JjL2NvbXBvc2l0ZXMvd3VpLWFsZXJ0YmFyL2luZGV4LmpzP2RlNDgiXSwic291cmNlc0NvbnRlbnQiOlsidmFyIF9fZGVjb3JhdGUgPSAodGhpcyAmJiB0aGlzLl9fZGVjb3JhdGUpIHx8IGZ1bmN0aW9uIChkZWNvcmF0b3JzLCB0YXJnZXQsIGtleSwgZGVzYykge1xuICAgIHZhciBjID0gYXJndW1lbnRzLmxlbmd0aCwgciA9IGMgPCAzID8gdGFyZ2V0IDogZGVzYyA9PT0gbnVsbCA/IGRlc2MgPSBPYmplY3QuZ2V0T3duUHJvcGVydHlEZXNjcmlwdG9yKHRhcmdldCwga2V5KSA6IGRlc2MsIGQ7XG4gICAgaWYgKHR5cGVvZiBSZWZsZWN0ID09PSBcIm9iamVjdFwiICYmIHR5cGVvZiBSZWZsZWN0LmRlY29yYXRlID09PSBcImZ1bmN0aW9uXCIpIHIgPSBSZWZsZWN0LmRlY29yYXRlKGRlY29yYXRvcnMsIHRhcmdldCwga2V5LCBkZXNjKTtcbiAgICBlbHNlIGZvciAodmFyIGkgPSBkZWNvcmF0b3JzLmxlbmd0aCAtIDE7IGkgPj0gMDsgaS0tKSBpZiAoZCA9IGRlY29yYXRvcnNbaV0pIHIgPSAoYyA8IDMgPyBkKHIpIDogYyA+IDMgPyBkKHRhcmdldCwga2V5LCByKSA6IGQodGFyZ2V0LCBrZXkpKSB8fCByO1xuICAgIHJldHVybiBjID4gMyAmJiByICYmIE9iamVjdC5kZWZpbmVQcm9wZXJ0eSh0YXJnZXQsIGtleSwgciksIHI7XG59O1xuaW1wb3J0IHsgTGl0RWxlbWVudCwgaHRtbCB9IGZyb20gJ2xpdCc7XG5pbXBvcnQgeyBwcm9wZXJ0eSB9IGZyb20gJ2xpdC9kZWNvcmF0b3JzLmpzJztcbmltcG9ydCB7IEFsZXJ0Q29udHJvbGxlciB9IGZyb20gJ0ByZW93bi9hcHBraXQtY29udHJvbGxlcnMnO1xuaW1wb3J0ICcuLi8uLi9jb21wb25lbnRzL3d1aS1pY29uL2luZGV4LmpzJztcbmltcG9ydCAnLi4vLi4vY29tcG9uZW50cy93dWktdGV4dC9pbmRleC5qcyc7XG5pbXBvcnQgJy4uLy4uL2xheW91dC93dWktZmxleC9pbmRleC5qcyc7XG5pbXBvcnQgeyByZXNldFN0eWxlcyB9IGZyb20gJy4uLy4uL3V0aWxzL1RoZW1lVXRpbC5qcyc7XG5pbXBvcnQgeyBjdXN0b21FbGVtZW50IH0gZnJvbSAnLi4vLi4vdXRpbHMvV2ViQ29tcG9uZW50c1V0aWwuanMnO1xuaW1wb3J0IHN0eWxlcyBmcm9tICcuL3N0eWxlcy5qcyc7XG5sZXQgV3VpQWxlcnRCYXIgPSBjbGFzcyBXdWlBbGVydEJhciBleHRlbmRzIExpdEVsZW1lbnQge1xuICAgIGNvbnN0cnVjdG9yKCkge1xuICAgICAgICBzdXBlciguLi5hcmd1bWVudHMpO1xuICAgICAgICB0aGlzLm1lc3NhZ2UgPSAnJztcbiAgICAgICAgdGhpcy5iYWNrZ3JvdW5kQ29sb3IgPSAnYWNjZW50LTEwMCc7XG4gICAgICAgIHRoaXMuaWNvbkNvbG9yID0gJ2FjY2VudC0xMDAnO1xuICAgICAgICB0aGlzLmljb24gPSAnaW5mbyc7XG4gICAgfVxuICAgIHJlbmRlcigpIHtcbiAgICAgICAgdGhpcy5zdHlsZS5jc3NUZXh0ID0gYFxuICAgICAgLS1sb2NhbC1pY29uLWJnLXZhbHVlOiB2YXIoLS13dWktY29sb3ItJHt0aGlzLmJhY2tncm91bmRDb2xvcn0pO1xuICAgYDtcbiAgICAgICAgcmV0dXJuIGh0bWwgYFxuICAgICAgPHd1aS1mbGV4IGZsZXhEaXJlY3Rpb249XCJyb3dcIiBqdXN0aWZ5Q29udGVudD1cInNwYWNlLWJldHdlZW5cIiBhbGlnbkl0ZW1zPVwiY2VudGVyXCI+XG4gICAgICAgIDx3dWktZmxleCBjb2x1bW5HYXA9XCJ4c1wiIGZsZXhEaXJlY3Rpb249XCJyb3dcIiBhbGlnbkl0ZW1zPVwiY2VudGVyXCI+XG4gICAgICAgICAgPHd1aS1mbGV4XG4gICAgICAgICAgICBmbGV4RGlyZWN0aW9uPVwicm93XCJcbiAgICAgICAgICAgIGFsaWduSXRlbXM9XCJjZW50ZXJcIlxuICAgICAgICAgICAganVzdGlmeUNvbnRlbnQ9XCJjZW50ZXJcIlxuICAgICAgICAgICAgY2xhc3M9XCJpY29uLWJveFwiXG4gICAgICAgICAgPlxuICAgICAgICAgICAgPHd1aS1pY29uIGNvbG9yPSR7dGhpcy5pY29uQ29sb3J9IHNpemU9XCJtZFwiIG5hbWU9JHt0aGlzLmljb259Pjwvd3VpLWljb24+XG4gICAgICAgICAgPC93dWktZmxleD5cbiAgICAgICAgICA8d3VpLXRleHQgdmFyaWFudD1cInNtYWxsLTUwMFwiIGNvbG9yPVwiYmctMzUwXCIgZGF0YS10ZXN0aWQ9XCJ3dWktYWxlcnRiYXItdGV4dFwiXG4gICAgICAgICAgICA+JHt0aGlzLm1lc3NhZ2V9PC93dWktdGV4dFxuICAgICAgICAgID5cbiAgICAgICAgPC93dWktZmxleD5cbiAgICAgICAgPHd1aS1pY29uXG4gICAgICAgICAgY2xhc3M9XCJjbG9zZVwiXG4gICAgICAgICAgY29sb3I9XCJiZy0zNTBcIlxuICAgICAgICAgIHNpemU9XCJzbVwiXG4gICAgICAgICAgbmFtZT1cImNsb3NlXCJcbiAgICAgICAgICBAY2xpY2s9JHt0aGlzLm9uQ2xvc2V9XG4gICAgICAgID48L3d1aS1pY29uPlxuICAgICAgPC93dWktZmxleD5cbiAgICBgO1xuICAgIH1cbiAgICBvbkNsb3NlKCkge1xuICAgICAgICBBbGVydENvbnRyb2xsZXIuY2xvc2UoKTtcbiAgICB9XG59O1xuV3VpQWxlcnRCYXIuc3R5bGVzID0gW3Jlc2V0U3R5bGVzLCBzdHlsZXNdO1xuX19kZWNvcmF0ZShbXG4gICAgcHJvcGVydHkoKVxuXSwgV3VpQWxlcnRCYXIucHJvdG90eXBlLCBcIm1lc3NhZ2VcIiwgdm9pZCAwKTtcbl9fZGVjb3JhdGUoW1xuICAgIHByb3BlcnR5KClcbl0sIFd1aUFsZXJ0QmFyLnByb3RvdHlwZSwgXCJiYWNrZ3JvdW5kQ29sb3JcIiwgdm9pZCAwKTtcbl9fZGVjb3JhdGUoW1xuICAgIHByb3BlcnR5KClcbl0sIFd1aUFsZXJ0QmFyLnByb3RvdHlwZSwgXCJpY29uQ29sb3JcIiwgdm9pZCAwKTtcbl9fZGVjb3JhdGUoW1xuICAgIHByb3BlcnR5KClcbl0sIFd1aUFsZXJ0QmFyLnByb3RvdHlwZSwgXCJpY29uXCIsIHZvaWQgMCk7XG5XdWlBbGVydEJhciA9IF9fZGVjb3JhdGUoW1xuICAgIGN1c3RvbUVsZW1lbnQoJ3d1aS1hbGVydGJhcicpXG5dLCBXdWlBbGVydEJhcik7XG5leHBvcnQgeyBXdWlBbGVydEJhciB9O1xuLy8jIHNvdXJjZU1hcHBpbmdVUkw9aW5kZXguanMubWFwIl0sIm5hbWVzIjpbXSwic291cmNlUm9vdCI6IiJ9\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-ui/dist/esm/src/composites/wui-alertbar/index.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@reown/appkit-ui/dist/esm/src/composites/wui-alertbar/styles.js":
-/*!**************************************************************************************!*\
-  !*** ./node_modules/@reown/appkit-ui/dist/esm/src/composites/wui-alertbar/styles.js ***!
-  \**************************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n\n/* harmony default export */ __webpack_exports__[\"default\"] = ((0,lit__WEBPACK_IMPORTED_MODULE_0__.css) `\n  :host {\n    display: flex;\n    align-items: center;\n    justify-content: center;\n    padding: var(--wui-spacing-s);\n    border-radius: var(--wui-border-radius-s);\n    border: 1px solid var(--wui-color-dark-glass-100);\n    box-sizing: border-box;\n    background-color: var(--wui-color-bg-325);\n    box-shadow: 0px 0px 16px 0px rgba(0, 0, 0, 0.25);\n  }\n\n  wui-flex {\n    width: 100%;\n  }\n\n  wui-text {\n    word-break: break-word;\n    flex: 1;\n  }\n\n  .close {\n    cursor: pointer;\n  }\n\n  .icon-box {\n    height: 40px;\n    width: 40px;\n    border-radius: var(--wui-border-radius-3xs);\n    background-color: var(--local-icon-bg-value);\n  }\n`);\n//# sourceMappingURL=styles.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvY29tcG9zaXRlcy93dWktYWxlcnRiYXIvc3R5bGVzLmpzIiwibWFwcGluZ3MiOiI7O0FBQTBCO0FBQzFCLCtEQUFlLHdDQUFHO0FBQ2xCO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7O0FBRUE7QUFDQTtBQUNBOztBQUVBO0FBQ0E7QUFDQTtBQUNBOztBQUVBO0FBQ0E7QUFDQTs7QUFFQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQSxDQUFDLEVBQUM7QUFDRiIsInNvdXJjZXMiOlsid2VicGFjazovL19OX0UvLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvY29tcG9zaXRlcy93dWktYWxlcnRiYXIvc3R5bGVzLmpzP2IwMDAiXSwic291cmNlc0NvbnRlbnQiOlsiaW1wb3J0IHsgY3NzIH0gZnJvbSAnbGl0JztcbmV4cG9ydCBkZWZhdWx0IGNzcyBgXG4gIDpob3N0IHtcbiAgICBkaXNwbGF5OiBmbGV4O1xuICAgIGFsaWduLWl0ZW1zOiBjZW50ZXI7XG4gICAganVzdGlmeS1jb250ZW50OiBjZW50ZXI7XG4gICAgcGFkZGluZzogdmFyKC0td3VpLXNwYWNpbmctcyk7XG4gICAgYm9yZGVyLXJhZGl1czogdmFyKC0td3VpLWJvcmRlci1yYWRpdXMtcyk7XG4gICAgYm9yZGVyOiAxcHggc29saWQgdmFyKC0td3VpLWNvbG9yLWRhcmstZ2xhc3MtMTAwKTtcbiAgICBib3gtc2l6aW5nOiBib3JkZXItYm94O1xuICAgIGJhY2tncm91bmQtY29sb3I6IHZhcigtLXd1aS1jb2xvci1iZy0zMjUpO1xuICAgIGJveC1zaGFkb3c6IDBweCAwcHggMTZweCAwcHggcmdiYSgwLCAwLCAwLCAwLjI1KTtcbiAgfVxuXG4gIHd1aS1mbGV4IHtcbiAgICB3aWR0aDogMTAwJTtcbiAgfVxuXG4gIHd1aS10ZXh0IHtcbiAgICB3b3JkLWJyZWFrOiBicmVhay13b3JkO1xuICAgIGZsZXg6IDE7XG4gIH1cblxuICAuY2xvc2Uge1xuICAgIGN1cnNvcjogcG9pbnRlcjtcbiAgfVxuXG4gIC5pY29uLWJveCB7XG4gICAgaGVpZ2h0OiA0MHB4O1xuICAgIHdpZHRoOiA0MHB4O1xuICAgIGJvcmRlci1yYWRpdXM6IHZhcigtLXd1aS1ib3JkZXItcmFkaXVzLTN4cyk7XG4gICAgYmFja2dyb3VuZC1jb2xvcjogdmFyKC0tbG9jYWwtaWNvbi1iZy12YWx1ZSk7XG4gIH1cbmA7XG4vLyMgc291cmNlTWFwcGluZ1VSTD1zdHlsZXMuanMubWFwIl0sIm5hbWVzIjpbXSwic291cmNlUm9vdCI6IiJ9\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-ui/dist/esm/src/composites/wui-alertbar/styles.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@reown/appkit-ui/dist/esm/src/composites/wui-icon-box/index.js":
-/*!*************************************************************************************!*\
-  !*** ./node_modules/@reown/appkit-ui/dist/esm/src/composites/wui-icon-box/index.js ***!
-  \*************************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   WuiIconBox: function() { return /* binding */ WuiIconBox; }\n/* harmony export */ });\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n/* harmony import */ var lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__ = __webpack_require__(/*! lit/decorators.js */ \"./node_modules/lit/decorators.js\");\n/* harmony import */ var _components_wui_icon_index_js__WEBPACK_IMPORTED_MODULE_2__ = __webpack_require__(/*! ../../components/wui-icon/index.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js\");\n/* harmony import */ var _utils_ThemeUtil_js__WEBPACK_IMPORTED_MODULE_3__ = __webpack_require__(/*! ../../utils/ThemeUtil.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/utils/ThemeUtil.js\");\n/* harmony import */ var _utils_WebComponentsUtil_js__WEBPACK_IMPORTED_MODULE_4__ = __webpack_require__(/*! ../../utils/WebComponentsUtil.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/utils/WebComponentsUtil.js\");\n/* harmony import */ var _styles_js__WEBPACK_IMPORTED_MODULE_5__ = __webpack_require__(/*! ./styles.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/composites/wui-icon-box/styles.js\");\nvar __decorate = (undefined && undefined.__decorate) || function (decorators, target, key, desc) {\n    var c = arguments.length, r = c < 3 ? target : desc === null ? desc = Object.getOwnPropertyDescriptor(target, key) : desc, d;\n    if (typeof Reflect === \"object\" && typeof Reflect.decorate === \"function\") r = Reflect.decorate(decorators, target, key, desc);\n    else for (var i = decorators.length - 1; i >= 0; i--) if (d = decorators[i]) r = (c < 3 ? d(r) : c > 3 ? d(target, key, r) : d(target, key)) || r;\n    return c > 3 && r && Object.defineProperty(target, key, r), r;\n};\n\n\n\n\n\n\nlet WuiIconBox = class WuiIconBox extends lit__WEBPACK_IMPORTED_MODULE_0__.LitElement {\n    constructor() {\n        super(...arguments);\n        this.size = 'md';\n        this.backgroundColor = 'accent-100';\n        this.iconColor = 'accent-100';\n        this.background = 'transparent';\n        this.border = false;\n        this.borderColor = 'wui-color-bg-125';\n        this.icon = 'copy';\n    }\n    render() {\n        const iconSize = this.iconSize || this.size;\n        const isLg = this.size === 'lg';\n        const isXl = this.size === 'xl';\n        const bgMix = isLg ? '12%' : '16%';\n        const borderRadius = isLg ? 'xxs' : isXl ? 's' : '3xl';\n        const isGray = this.background === 'gray';\n        const isOpaque = this.background === 'opaque';\n        const isColorChange = (this.backgroundColor === 'accent-100' && isOpaque) ||\n            (this.backgroundColor === 'success-100' && isOpaque) ||\n            (this.backgroundColor === 'error-100' && isOpaque) ||\n            (this.backgroundColor === 'inverse-100' && isOpaque);\n        let bgValueVariable = `var(--wui-color-${this.backgroundColor})`;\n        if (isColorChange) {\n            bgValueVariable = `var(--wui-icon-box-bg-${this.backgroundColor})`;\n        }\n        else if (isGray) {\n            bgValueVariable = `var(--wui-color-gray-${this.backgroundColor})`;\n        }\n        this.style.cssText = `\n       --local-bg-value: ${bgValueVariable};\n       --local-bg-mix: ${isColorChange || isGray ? `100%` : bgMix};\n       --local-border-radius: var(--wui-border-radius-${borderRadius});\n       --local-size: var(--wui-icon-box-size-${this.size});\n       --local-border: ${this.borderColor === 'wui-color-bg-125' ? `2px` : `1px`} solid ${this.border ? `var(--${this.borderColor})` : `transparent`}\n   `;\n        return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) ` <wui-icon color=${this.iconColor} size=${iconSize} name=${this.icon}></wui-icon> `;\n    }\n};\nWuiIconBox.styles = [_utils_ThemeUtil_js__WEBPACK_IMPORTED_MODULE_3__.resetStyles, _utils_ThemeUtil_js__WEBPACK_IMPORTED_MODULE_3__.elementStyles, _styles_js__WEBPACK_IMPORTED_MODULE_5__[\"default\"]];\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.property)()\n], WuiIconBox.prototype, \"size\", void 0);\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.property)()\n], WuiIconBox.prototype, \"backgroundColor\", void 0);\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.property)()\n], WuiIconBox.prototype, \"iconColor\", void 0);\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.property)()\n], WuiIconBox.prototype, \"iconSize\", void 0);\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.property)()\n], WuiIconBox.prototype, \"background\", void 0);\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.property)({ type: Boolean })\n], WuiIconBox.prototype, \"border\", void 0);\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.property)()\n], WuiIconBox.prototype, \"borderColor\", void 0);\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.property)()\n], WuiIconBox.prototype, \"icon\", void 0);\nWuiIconBox = __decorate([\n    (0,_utils_WebComponentsUtil_js__WEBPACK_IMPORTED_MODULE_4__.customElement)('wui-icon-box')\n], WuiIconBox);\n\n//# sourceMappingURL=index.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvY29tcG9zaXRlcy93dWktaWNvbi1ib3gvaW5kZXguanMiLCJtYXBwaW5ncyI6Ijs7Ozs7Ozs7OztBQUFBLGtCQUFrQixTQUFJLElBQUksU0FBSTtBQUM5QjtBQUNBO0FBQ0EsNkNBQTZDLFFBQVE7QUFDckQ7QUFDQTtBQUN1QztBQUNNO0FBQ0Q7QUFDMEI7QUFDTDtBQUNoQztBQUNqQywwQ0FBMEMsMkNBQVU7QUFDcEQ7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQSxpREFBaUQscUJBQXFCO0FBQ3RFO0FBQ0EsdURBQXVELHFCQUFxQjtBQUM1RTtBQUNBO0FBQ0Esc0RBQXNELHFCQUFxQjtBQUMzRTtBQUNBO0FBQ0EsMkJBQTJCO0FBQzNCLHlCQUF5QjtBQUN6Qix3REFBd0QsYUFBYTtBQUNyRSwrQ0FBK0MsVUFBVTtBQUN6RCx5QkFBeUIseURBQXlELFFBQVEsdUJBQXVCLGlCQUFpQjtBQUNsSTtBQUNBLGVBQWUseUNBQUkscUJBQXFCLGdCQUFnQixPQUFPLFVBQVUsT0FBTyxVQUFVO0FBQzFGO0FBQ0E7QUFDQSxxQkFBcUIsNERBQVcsRUFBRSw4REFBYSxFQUFFLGtEQUFNO0FBQ3ZEO0FBQ0EsSUFBSSwyREFBUTtBQUNaO0FBQ0E7QUFDQSxJQUFJLDJEQUFRO0FBQ1o7QUFDQTtBQUNBLElBQUksMkRBQVE7QUFDWjtBQUNBO0FBQ0EsSUFBSSwyREFBUTtBQUNaO0FBQ0E7QUFDQSxJQUFJLDJEQUFRO0FBQ1o7QUFDQTtBQUNBLElBQUksMkRBQVEsR0FBRyxlQUFlO0FBQzlCO0FBQ0E7QUFDQSxJQUFJLDJEQUFRO0FBQ1o7QUFDQTtBQUNBLElBQUksMkRBQVE7QUFDWjtBQUNBO0FBQ0EsSUFBSSwwRUFBYTtBQUNqQjtBQUNzQjtBQUN0QiIsInNvdXJjZXMiOlsid2VicGFjazovL19OX0UvLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvY29tcG9zaXRlcy93dWktaWNvbi1ib3gvaW5kZXguanM/NGQwMSJdLCJzb3VyY2VzQ29udGVudCI6WyJ2YXIgX19kZWNvcmF0ZSA9ICh0aGlzICYmIHRoaXMuX19kZWNvcmF0ZSkgfHwgZnVuY3Rpb24gKGRlY29yYXRvcnMsIHRhcmdldCwga2V5LCBkZXNjKSB7XG4gICAgdmFyIGMgPSBhcmd1bWVudHMubGVuZ3RoLCByID0gYyA8IDMgPyB0YXJnZXQgOiBkZXNjID09PSBudWxsID8gZGVzYyA9IE9iamVjdC5nZXRPd25Qcm9wZXJ0eURlc2NyaXB0b3IodGFyZ2V0LCBrZXkpIDogZGVzYywgZDtcbiAgICBpZiAodHlwZW9mIFJlZmxlY3QgPT09IFwib2JqZWN0XCIgJiYgdHlwZW9mIFJlZmxlY3QuZGVjb3JhdGUgPT09IFwiZnVuY3Rpb25cIikgciA9IFJlZmxlY3QuZGVjb3JhdGUoZGVjb3JhdG9ycywgdGFyZ2V0LCBrZXksIGRlc2MpO1xuICAgIGVsc2UgZm9yICh2YXIgaSA9IGRlY29yYXRvcnMubGVuZ3RoIC0gMTsgaSA+PSAwOyBpLS0pIGlmIChkID0gZGVjb3JhdG9yc1tpXSkgciA9IChjIDwgMyA/IGQocikgOiBjID4gMyA/IGQodGFyZ2V0LCBrZXksIHIpIDogZCh0YXJnZXQsIGtleSkpIHx8IHI7XG4gICAgcmV0dXJuIGMgPiAzICYmIHIgJiYgT2JqZWN0LmRlZmluZVByb3BlcnR5KHRhcmdldCwga2V5LCByKSwgcjtcbn07XG5pbXBvcnQgeyBMaXRFbGVtZW50LCBodG1sIH0gZnJvbSAnbGl0JztcbmltcG9ydCB7IHByb3BlcnR5IH0gZnJvbSAnbGl0L2RlY29yYXRvcnMuanMnO1xuaW1wb3J0ICcuLi8uLi9jb21wb25lbnRzL3d1aS1pY29uL2luZGV4LmpzJztcbmltcG9ydCB7IGVsZW1lbnRTdHlsZXMsIHJlc2V0U3R5bGVzIH0gZnJvbSAnLi4vLi4vdXRpbHMvVGhlbWVVdGlsLmpzJztcbmltcG9ydCB7IGN1c3RvbUVsZW1lbnQgfSBmcm9tICcuLi8uLi91dGlscy9XZWJDb21wb25lbnRzVXRpbC5qcyc7XG5pbXBvcnQgc3R5bGVzIGZyb20gJy4vc3R5bGVzLmpzJztcbmxldCBXdWlJY29uQm94ID0gY2xhc3MgV3VpSWNvbkJveCBleHRlbmRzIExpdEVsZW1lbnQge1xuICAgIGNvbnN0cnVjdG9yKCkge1xuICAgICAgICBzdXBlciguLi5hcmd1bWVudHMpO1xuICAgICAgICB0aGlzLnNpemUgPSAnbWQnO1xuICAgICAgICB0aGlzLmJhY2tncm91bmRDb2xvciA9ICdhY2NlbnQtMTAwJztcbiAgICAgICAgdGhpcy5pY29uQ29sb3IgPSAnYWNjZW50LTEwMCc7XG4gICAgICAgIHRoaXMuYmFja2dyb3VuZCA9ICd0cmFuc3BhcmVudCc7XG4gICAgICAgIHRoaXMuYm9yZGVyID0gZmFsc2U7XG4gICAgICAgIHRoaXMuYm9yZGVyQ29sb3IgPSAnd3VpLWNvbG9yLWJnLTEyNSc7XG4gICAgICAgIHRoaXMuaWNvbiA9ICdjb3B5JztcbiAgICB9XG4gICAgcmVuZGVyKCkge1xuICAgICAgICBjb25zdCBpY29uU2l6ZSA9IHRoaXMuaWNvblNpemUgfHwgdGhpcy5zaXplO1xuICAgICAgICBjb25zdCBpc0xnID0gdGhpcy5zaXplID09PSAnbGcnO1xuICAgICAgICBjb25zdCBpc1hsID0gdGhpcy5zaXplID09PSAneGwnO1xuICAgICAgICBjb25zdCBiZ01peCA9IGlzTGcgPyAnMTIlJyA6ICcxNiUnO1xuICAgICAgICBjb25zdCBib3JkZXJSYWRpdXMgPSBpc0xnID8gJ3h4cycgOiBpc1hsID8gJ3MnIDogJzN4bCc7XG4gICAgICAgIGNvbnN0IGlzR3JheSA9IHRoaXMuYmFja2dyb3VuZCA9PT0gJ2dyYXknO1xuICAgICAgICBjb25zdCBpc09wYXF1ZSA9IHRoaXMuYmFja2dyb3VuZCA9PT0gJ29wYXF1ZSc7XG4gICAgICAgIGNvbnN0IGlzQ29sb3JDaGFuZ2UgPSAodGhpcy5iYWNrZ3JvdW5kQ29sb3IgPT09ICdhY2NlbnQtMTAwJyAmJiBpc09wYXF1ZSkgfHxcbiAgICAgICAgICAgICh0aGlzLmJhY2tncm91bmRDb2xvciA9PT0gJ3N1Y2Nlc3MtMTAwJyAmJiBpc09wYXF1ZSkgfHxcbiAgICAgICAgICAgICh0aGlzLmJhY2tncm91bmRDb2xvciA9PT0gJ2Vycm9yLTEwMCcgJiYgaXNPcGFxdWUpIHx8XG4gICAgICAgICAgICAodGhpcy5iYWNrZ3JvdW5kQ29sb3IgPT09ICdpbnZlcnNlLTEwMCcgJiYgaXNPcGFxdWUpO1xuICAgICAgICBsZXQgYmdWYWx1ZVZhcmlhYmxlID0gYHZhcigtLXd1aS1jb2xvci0ke3RoaXMuYmFja2dyb3VuZENvbG9yfSlgO1xuICAgICAgICBpZiAoaXNDb2xvckNoYW5nZSkge1xuICAgICAgICAgICAgYmdWYWx1ZVZhcmlhYmxlID0gYHZhcigtLXd1aS1pY29uLWJveC1iZy0ke3RoaXMuYmFja2dyb3VuZENvbG9yfSlgO1xuICAgICAgICB9XG4gICAgICAgIGVsc2UgaWYgKGlzR3JheSkge1xuICAgICAgICAgICAgYmdWYWx1ZVZhcmlhYmxlID0gYHZhcigtLXd1aS1jb2xvci1ncmF5LSR7dGhpcy5iYWNrZ3JvdW5kQ29sb3J9KWA7XG4gICAgICAgIH1cbiAgICAgICAgdGhpcy5zdHlsZS5jc3NUZXh0ID0gYFxuICAgICAgIC0tbG9jYWwtYmctdmFsdWU6ICR7YmdWYWx1ZVZhcmlhYmxlfTtcbiAgICAgICAtLWxvY2FsLWJnLW1peDogJHtpc0NvbG9yQ2hhbmdlIHx8IGlzR3JheSA/IGAxMDAlYCA6IGJnTWl4fTtcbiAgICAgICAtLWxvY2FsLWJvcmRlci1yYWRpdXM6IHZhcigtLXd1aS1ib3JkZXItcmFkaXVzLSR7Ym9yZGVyUmFkaXVzfSk7XG4gICAgICAgLS1sb2NhbC1zaXplOiB2YXIoLS13dWktaWNvbi1ib3gtc2l6ZS0ke3RoaXMuc2l6ZX0pO1xuICAgICAgIC0tbG9jYWwtYm9yZGVyOiAke3RoaXMuYm9yZGVyQ29sb3IgPT09ICd3dWktY29sb3ItYmctMTI1JyA/IGAycHhgIDogYDFweGB9IHNvbGlkICR7dGhpcy5ib3JkZXIgPyBgdmFyKC0tJHt0aGlzLmJvcmRlckNvbG9yfSlgIDogYHRyYW5zcGFyZW50YH1cbiAgIGA7XG4gICAgICAgIHJldHVybiBodG1sIGAgPHd1aS1pY29uIGNvbG9yPSR7dGhpcy5pY29uQ29sb3J9IHNpemU9JHtpY29uU2l6ZX0gbmFtZT0ke3RoaXMuaWNvbn0+PC93dWktaWNvbj4gYDtcbiAgICB9XG59O1xuV3VpSWNvbkJveC5zdHlsZXMgPSBbcmVzZXRTdHlsZXMsIGVsZW1lbnRTdHlsZXMsIHN0eWxlc107XG5fX2RlY29yYXRlKFtcbiAgICBwcm9wZXJ0eSgpXG5dLCBXdWlJY29uQm94LnByb3RvdHlwZSwgXCJzaXplXCIsIHZvaWQgMCk7XG5fX2RlY29yYXRlKFtcbiAgICBwcm9wZXJ0eSgpXG5dLCBXdWlJY29uQm94LnByb3RvdHlwZSwgXCJiYWNrZ3JvdW5kQ29sb3JcIiwgdm9pZCAwKTtcbl9fZGVjb3JhdGUoW1xuICAgIHByb3BlcnR5KClcbl0sIFd1aUljb25Cb3gucHJvdG90eXBlLCBcImljb25Db2xvclwiLCB2b2lkIDApO1xuX19kZWNvcmF0ZShbXG4gICAgcHJvcGVydHkoKVxuXSwgV3VpSWNvbkJveC5wcm90b3R5cGUsIFwiaWNvblNpemVcIiwgdm9pZCAwKTtcbl9fZGVjb3JhdGUoW1xuICAgIHByb3BlcnR5KClcbl0sIFd1aUljb25Cb3gucHJvdG90eXBlLCBcImJhY2tncm91bmRcIiwgdm9pZCAwKTtcbl9fZGVjb3JhdGUoW1xuICAgIHByb3BlcnR5KHsgdHlwZTogQm9vbGVhbiB9KVxuXSwgV3VpSWNvbkJveC5wcm90b3R5cGUsIFwiYm9yZGVyXCIsIHZvaWQgMCk7XG5fX2RlY29yYXRlKFtcbiAgICBwcm9wZXJ0eSgpXG5dLCBXdWlJY29uQm94LnByb3RvdHlwZSwgXCJib3JkZXJDb2xvclwiLCB2b2lkIDApO1xuX19kZWNvcmF0ZShbXG4gICAgcHJvcGVydHkoKVxuXSwgV3VpSWNvbkJveC5wcm90b3R5cGUsIFwiaWNvblwiLCB2b2lkIDApO1xuV3VpSWNvbkJveCA9IF9fZGVjb3JhdGUoW1xuICAgIGN1c3RvbUVsZW1lbnQoJ3d1aS1pY29uLWJveCcpXG5dLCBXdWlJY29uQm94KTtcbmV4cG9ydCB7IFd1aUljb25Cb3ggfTtcbi8vIyBzb3VyY2VNYXBwaW5nVVJMPWluZGV4LmpzLm1hcCJdLCJuYW1lcyI6W10sInNvdXJjZVJvb3QiOiIifQ==\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-ui/dist/esm/src/composites/wui-icon-box/index.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@reown/appkit-ui/dist/esm/src/composites/wui-icon-box/styles.js":
-/*!**************************************************************************************!*\
-  !*** ./node_modules/@reown/appkit-ui/dist/esm/src/composites/wui-icon-box/styles.js ***!
-  \**************************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n\n/* harmony default export */ __webpack_exports__[\"default\"] = ((0,lit__WEBPACK_IMPORTED_MODULE_0__.css) `\n  :host {\n    display: inline-flex;\n    justify-content: center;\n    align-items: center;\n    position: relative;\n    overflow: hidden;\n    background-color: var(--wui-color-gray-glass-020);\n    border-radius: var(--local-border-radius);\n    border: var(--local-border);\n    box-sizing: content-box;\n    width: var(--local-size);\n    height: var(--local-size);\n    min-height: var(--local-size);\n    min-width: var(--local-size);\n  }\n\n  @supports (background: color-mix(in srgb, white 50%, black)) {\n    :host {\n      background-color: color-mix(in srgb, var(--local-bg-value) var(--local-bg-mix), transparent);\n    }\n  }\n`);\n//# sourceMappingURL=styles.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvY29tcG9zaXRlcy93dWktaWNvbi1ib3gvc3R5bGVzLmpzIiwibWFwcGluZ3MiOiI7O0FBQTBCO0FBQzFCLCtEQUFlLHdDQUFHO0FBQ2xCO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTs7QUFFQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0EsQ0FBQyxFQUFDO0FBQ0YiLCJzb3VyY2VzIjpbIndlYnBhY2s6Ly9fTl9FLy4vbm9kZV9tb2R1bGVzL0ByZW93bi9hcHBraXQtdWkvZGlzdC9lc20vc3JjL2NvbXBvc2l0ZXMvd3VpLWljb24tYm94L3N0eWxlcy5qcz8zYjE1Il0sInNvdXJjZXNDb250ZW50IjpbImltcG9ydCB7IGNzcyB9IGZyb20gJ2xpdCc7XG5leHBvcnQgZGVmYXVsdCBjc3MgYFxuICA6aG9zdCB7XG4gICAgZGlzcGxheTogaW5saW5lLWZsZXg7XG4gICAganVzdGlmeS1jb250ZW50OiBjZW50ZXI7XG4gICAgYWxpZ24taXRlbXM6IGNlbnRlcjtcbiAgICBwb3NpdGlvbjogcmVsYXRpdmU7XG4gICAgb3ZlcmZsb3c6IGhpZGRlbjtcbiAgICBiYWNrZ3JvdW5kLWNvbG9yOiB2YXIoLS13dWktY29sb3ItZ3JheS1nbGFzcy0wMjApO1xuICAgIGJvcmRlci1yYWRpdXM6IHZhcigtLWxvY2FsLWJvcmRlci1yYWRpdXMpO1xuICAgIGJvcmRlcjogdmFyKC0tbG9jYWwtYm9yZGVyKTtcbiAgICBib3gtc2l6aW5nOiBjb250ZW50LWJveDtcbiAgICB3aWR0aDogdmFyKC0tbG9jYWwtc2l6ZSk7XG4gICAgaGVpZ2h0OiB2YXIoLS1sb2NhbC1zaXplKTtcbiAgICBtaW4taGVpZ2h0OiB2YXIoLS1sb2NhbC1zaXplKTtcbiAgICBtaW4td2lkdGg6IHZhcigtLWxvY2FsLXNpemUpO1xuICB9XG5cbiAgQHN1cHBvcnRzIChiYWNrZ3JvdW5kOiBjb2xvci1taXgoaW4gc3JnYiwgd2hpdGUgNTAlLCBibGFjaykpIHtcbiAgICA6aG9zdCB7XG4gICAgICBiYWNrZ3JvdW5kLWNvbG9yOiBjb2xvci1taXgoaW4gc3JnYiwgdmFyKC0tbG9jYWwtYmctdmFsdWUpIHZhcigtLWxvY2FsLWJnLW1peCksIHRyYW5zcGFyZW50KTtcbiAgICB9XG4gIH1cbmA7XG4vLyMgc291cmNlTWFwcGluZ1VSTD1zdHlsZXMuanMubWFwIl0sIm5hbWVzIjpbXSwic291cmNlUm9vdCI6IiJ9\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-ui/dist/esm/src/composites/wui-icon-box/styles.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@reown/appkit-ui/dist/esm/src/composites/wui-icon-link/index.js":
-/*!**************************************************************************************!*\
-  !*** ./node_modules/@reown/appkit-ui/dist/esm/src/composites/wui-icon-link/index.js ***!
-  \**************************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   WuiIconLink: function() { return /* binding */ WuiIconLink; }\n/* harmony export */ });\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n/* harmony import */ var lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__ = __webpack_require__(/*! lit/decorators.js */ \"./node_modules/lit/decorators.js\");\n/* harmony import */ var _components_wui_icon_index_js__WEBPACK_IMPORTED_MODULE_2__ = __webpack_require__(/*! ../../components/wui-icon/index.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js\");\n/* harmony import */ var _utils_ThemeUtil_js__WEBPACK_IMPORTED_MODULE_3__ = __webpack_require__(/*! ../../utils/ThemeUtil.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/utils/ThemeUtil.js\");\n/* harmony import */ var _utils_WebComponentsUtil_js__WEBPACK_IMPORTED_MODULE_4__ = __webpack_require__(/*! ../../utils/WebComponentsUtil.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/utils/WebComponentsUtil.js\");\n/* harmony import */ var _styles_js__WEBPACK_IMPORTED_MODULE_5__ = __webpack_require__(/*! ./styles.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/composites/wui-icon-link/styles.js\");\nvar __decorate = (undefined && undefined.__decorate) || function (decorators, target, key, desc) {\n    var c = arguments.length, r = c < 3 ? target : desc === null ? desc = Object.getOwnPropertyDescriptor(target, key) : desc, d;\n    if (typeof Reflect === \"object\" && typeof Reflect.decorate === \"function\") r = Reflect.decorate(decorators, target, key, desc);\n    else for (var i = decorators.length - 1; i >= 0; i--) if (d = decorators[i]) r = (c < 3 ? d(r) : c > 3 ? d(target, key, r) : d(target, key)) || r;\n    return c > 3 && r && Object.defineProperty(target, key, r), r;\n};\n\n\n\n\n\n\nlet WuiIconLink = class WuiIconLink extends lit__WEBPACK_IMPORTED_MODULE_0__.LitElement {\n    constructor() {\n        super(...arguments);\n        this.size = 'md';\n        this.disabled = false;\n        this.icon = 'copy';\n        this.iconColor = 'inherit';\n    }\n    render() {\n        const borderRadius = this.size === 'lg' ? '--wui-border-radius-xs' : '--wui-border-radius-xxs';\n        const padding = this.size === 'lg' ? '--wui-spacing-1xs' : '--wui-spacing-2xs';\n        this.style.cssText = `\n    --local-border-radius: var(${borderRadius});\n    --local-padding: var(${padding});\n`;\n        return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `\n      <button ?disabled=${this.disabled}>\n        <wui-icon color=${this.iconColor} size=${this.size} name=${this.icon}></wui-icon>\n      </button>\n    `;\n    }\n};\nWuiIconLink.styles = [_utils_ThemeUtil_js__WEBPACK_IMPORTED_MODULE_3__.resetStyles, _utils_ThemeUtil_js__WEBPACK_IMPORTED_MODULE_3__.elementStyles, _utils_ThemeUtil_js__WEBPACK_IMPORTED_MODULE_3__.colorStyles, _styles_js__WEBPACK_IMPORTED_MODULE_5__[\"default\"]];\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.property)()\n], WuiIconLink.prototype, \"size\", void 0);\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.property)({ type: Boolean })\n], WuiIconLink.prototype, \"disabled\", void 0);\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.property)()\n], WuiIconLink.prototype, \"icon\", void 0);\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.property)()\n], WuiIconLink.prototype, \"iconColor\", void 0);\nWuiIconLink = __decorate([\n    (0,_utils_WebComponentsUtil_js__WEBPACK_IMPORTED_MODULE_4__.customElement)('wui-icon-link')\n], WuiIconLink);\n\n//# sourceMappingURL=index.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvY29tcG9zaXRlcy93dWktaWNvbi1saW5rL2luZGV4LmpzIiwibWFwcGluZ3MiOiI7Ozs7Ozs7Ozs7QUFBQSxrQkFBa0IsU0FBSSxJQUFJLFNBQUk7QUFDOUI7QUFDQTtBQUNBLDZDQUE2QyxRQUFRO0FBQ3JEO0FBQ0E7QUFDdUM7QUFDTTtBQUNEO0FBQ3VDO0FBQ2xCO0FBQ2hDO0FBQ2pDLDRDQUE0QywyQ0FBVTtBQUN0RDtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0EsaUNBQWlDLGFBQWE7QUFDOUMsMkJBQTJCLFFBQVE7QUFDbkM7QUFDQSxlQUFlLHlDQUFJO0FBQ25CLDBCQUEwQixjQUFjO0FBQ3hDLDBCQUEwQixnQkFBZ0IsT0FBTyxXQUFXLE9BQU8sVUFBVTtBQUM3RTtBQUNBO0FBQ0E7QUFDQTtBQUNBLHNCQUFzQiw0REFBVyxFQUFFLDhEQUFhLEVBQUUsNERBQVcsRUFBRSxrREFBTTtBQUNyRTtBQUNBLElBQUksMkRBQVE7QUFDWjtBQUNBO0FBQ0EsSUFBSSwyREFBUSxHQUFHLGVBQWU7QUFDOUI7QUFDQTtBQUNBLElBQUksMkRBQVE7QUFDWjtBQUNBO0FBQ0EsSUFBSSwyREFBUTtBQUNaO0FBQ0E7QUFDQSxJQUFJLDBFQUFhO0FBQ2pCO0FBQ3VCO0FBQ3ZCIiwic291cmNlcyI6WyJ3ZWJwYWNrOi8vX05fRS8uL25vZGVfbW9kdWxlcy9AcmVvd24vYXBwa2l0LXVpL2Rpc3QvZXNtL3NyYy9jb21wb3NpdGVzL3d1aS1pY29uLWxpbmsvaW5kZXguanM/ZGQ2NCJdLCJzb3VyY2VzQ29udGVudCI6WyJ2YXIgX19kZWNvcmF0ZSA9ICh0aGlzICYmIHRoaXMuX19kZWNvcmF0ZSkgfHwgZnVuY3Rpb24gKGRlY29yYXRvcnMsIHRhcmdldCwga2V5LCBkZXNjKSB7XG4gICAgdmFyIGMgPSBhcmd1bWVudHMubGVuZ3RoLCByID0gYyA8IDMgPyB0YXJnZXQgOiBkZXNjID09PSBudWxsID8gZGVzYyA9IE9iamVjdC5nZXRPd25Qcm9wZXJ0eURlc2NyaXB0b3IodGFyZ2V0LCBrZXkpIDogZGVzYywgZDtcbiAgICBpZiAodHlwZW9mIFJlZmxlY3QgPT09IFwib2JqZWN0XCIgJiYgdHlwZW9mIFJlZmxlY3QuZGVjb3JhdGUgPT09IFwiZnVuY3Rpb25cIikgciA9IFJlZmxlY3QuZGVjb3JhdGUoZGVjb3JhdG9ycywgdGFyZ2V0LCBrZXksIGRlc2MpO1xuICAgIGVsc2UgZm9yICh2YXIgaSA9IGRlY29yYXRvcnMubGVuZ3RoIC0gMTsgaSA+PSAwOyBpLS0pIGlmIChkID0gZGVjb3JhdG9yc1tpXSkgciA9IChjIDwgMyA/IGQocikgOiBjID4gMyA/IGQodGFyZ2V0LCBrZXksIHIpIDogZCh0YXJnZXQsIGtleSkpIHx8IHI7XG4gICAgcmV0dXJuIGMgPiAzICYmIHIgJiYgT2JqZWN0LmRlZmluZVByb3BlcnR5KHRhcmdldCwga2V5LCByKSwgcjtcbn07XG5pbXBvcnQgeyBMaXRFbGVtZW50LCBodG1sIH0gZnJvbSAnbGl0JztcbmltcG9ydCB7IHByb3BlcnR5IH0gZnJvbSAnbGl0L2RlY29yYXRvcnMuanMnO1xuaW1wb3J0ICcuLi8uLi9jb21wb25lbnRzL3d1aS1pY29uL2luZGV4LmpzJztcbmltcG9ydCB7IGNvbG9yU3R5bGVzLCBlbGVtZW50U3R5bGVzLCByZXNldFN0eWxlcyB9IGZyb20gJy4uLy4uL3V0aWxzL1RoZW1lVXRpbC5qcyc7XG5pbXBvcnQgeyBjdXN0b21FbGVtZW50IH0gZnJvbSAnLi4vLi4vdXRpbHMvV2ViQ29tcG9uZW50c1V0aWwuanMnO1xuaW1wb3J0IHN0eWxlcyBmcm9tICcuL3N0eWxlcy5qcyc7XG5sZXQgV3VpSWNvbkxpbmsgPSBjbGFzcyBXdWlJY29uTGluayBleHRlbmRzIExpdEVsZW1lbnQge1xuICAgIGNvbnN0cnVjdG9yKCkge1xuICAgICAgICBzdXBlciguLi5hcmd1bWVudHMpO1xuICAgICAgICB0aGlzLnNpemUgPSAnbWQnO1xuICAgICAgICB0aGlzLmRpc2FibGVkID0gZmFsc2U7XG4gICAgICAgIHRoaXMuaWNvbiA9ICdjb3B5JztcbiAgICAgICAgdGhpcy5pY29uQ29sb3IgPSAnaW5oZXJpdCc7XG4gICAgfVxuICAgIHJlbmRlcigpIHtcbiAgICAgICAgY29uc3QgYm9yZGVyUmFkaXVzID0gdGhpcy5zaXplID09PSAnbGcnID8gJy0td3VpLWJvcmRlci1yYWRpdXMteHMnIDogJy0td3VpLWJvcmRlci1yYWRpdXMteHhzJztcbiAgICAgICAgY29uc3QgcGFkZGluZyA9IHRoaXMuc2l6ZSA9PT0gJ2xnJyA/ICctLXd1aS1zcGFjaW5nLTF4cycgOiAnLS13dWktc3BhY2luZy0yeHMnO1xuICAgICAgICB0aGlzLnN0eWxlLmNzc1RleHQgPSBgXG4gICAgLS1sb2NhbC1ib3JkZXItcmFkaXVzOiB2YXIoJHtib3JkZXJSYWRpdXN9KTtcbiAgICAtLWxvY2FsLXBhZGRpbmc6IHZhcigke3BhZGRpbmd9KTtcbmA7XG4gICAgICAgIHJldHVybiBodG1sIGBcbiAgICAgIDxidXR0b24gP2Rpc2FibGVkPSR7dGhpcy5kaXNhYmxlZH0+XG4gICAgICAgIDx3dWktaWNvbiBjb2xvcj0ke3RoaXMuaWNvbkNvbG9yfSBzaXplPSR7dGhpcy5zaXplfSBuYW1lPSR7dGhpcy5pY29ufT48L3d1aS1pY29uPlxuICAgICAgPC9idXR0b24+XG4gICAgYDtcbiAgICB9XG59O1xuV3VpSWNvbkxpbmsuc3R5bGVzID0gW3Jlc2V0U3R5bGVzLCBlbGVtZW50U3R5bGVzLCBjb2xvclN0eWxlcywgc3R5bGVzXTtcbl9fZGVjb3JhdGUoW1xuICAgIHByb3BlcnR5KClcbl0sIFd1aUljb25MaW5rLnByb3RvdHlwZSwgXCJzaXplXCIsIHZvaWQgMCk7XG5fX2RlY29yYXRlKFtcbiAgICBwcm9wZXJ0eSh7IHR5cGU6IEJvb2xlYW4gfSlcbl0sIFd1aUljb25MaW5rLnByb3RvdHlwZSwgXCJkaXNhYmxlZFwiLCB2b2lkIDApO1xuX19kZWNvcmF0ZShbXG4gICAgcHJvcGVydHkoKVxuXSwgV3VpSWNvbkxpbmsucHJvdG90eXBlLCBcImljb25cIiwgdm9pZCAwKTtcbl9fZGVjb3JhdGUoW1xuICAgIHByb3BlcnR5KClcbl0sIFd1aUljb25MaW5rLnByb3RvdHlwZSwgXCJpY29uQ29sb3JcIiwgdm9pZCAwKTtcbld1aUljb25MaW5rID0gX19kZWNvcmF0ZShbXG4gICAgY3VzdG9tRWxlbWVudCgnd3VpLWljb24tbGluaycpXG5dLCBXdWlJY29uTGluayk7XG5leHBvcnQgeyBXdWlJY29uTGluayB9O1xuLy8jIHNvdXJjZU1hcHBpbmdVUkw9aW5kZXguanMubWFwIl0sIm5hbWVzIjpbXSwic291cmNlUm9vdCI6IiJ9\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-ui/dist/esm/src/composites/wui-icon-link/index.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@reown/appkit-ui/dist/esm/src/composites/wui-icon-link/styles.js":
-/*!***************************************************************************************!*\
-  !*** ./node_modules/@reown/appkit-ui/dist/esm/src/composites/wui-icon-link/styles.js ***!
-  \***************************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n\n/* harmony default export */ __webpack_exports__[\"default\"] = ((0,lit__WEBPACK_IMPORTED_MODULE_0__.css) `\n  button {\n    border-radius: var(--local-border-radius);\n    color: var(--wui-color-fg-100);\n    padding: var(--local-padding);\n  }\n\n  @media (max-width: 700px) {\n    button {\n      padding: var(--wui-spacing-s);\n    }\n  }\n\n  button > wui-icon {\n    pointer-events: none;\n  }\n\n  button:disabled > wui-icon {\n    color: var(--wui-color-bg-300) !important;\n  }\n\n  button:disabled {\n    background-color: transparent;\n  }\n`);\n//# sourceMappingURL=styles.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvY29tcG9zaXRlcy93dWktaWNvbi1saW5rL3N0eWxlcy5qcyIsIm1hcHBpbmdzIjoiOztBQUEwQjtBQUMxQiwrREFBZSx3Q0FBRztBQUNsQjtBQUNBO0FBQ0E7QUFDQTtBQUNBOztBQUVBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7O0FBRUE7QUFDQTtBQUNBOztBQUVBO0FBQ0E7QUFDQTs7QUFFQTtBQUNBO0FBQ0E7QUFDQSxDQUFDLEVBQUM7QUFDRiIsInNvdXJjZXMiOlsid2VicGFjazovL19OX0UvLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvY29tcG9zaXRlcy93dWktaWNvbi1saW5rL3N0eWxlcy5qcz85MzlhIl0sInNvdXJjZXNDb250ZW50IjpbImltcG9ydCB7IGNzcyB9IGZyb20gJ2xpdCc7XG5leHBvcnQgZGVmYXVsdCBjc3MgYFxuICBidXR0b24ge1xuICAgIGJvcmRlci1yYWRpdXM6IHZhcigtLWxvY2FsLWJvcmRlci1yYWRpdXMpO1xuICAgIGNvbG9yOiB2YXIoLS13dWktY29sb3ItZmctMTAwKTtcbiAgICBwYWRkaW5nOiB2YXIoLS1sb2NhbC1wYWRkaW5nKTtcbiAgfVxuXG4gIEBtZWRpYSAobWF4LXdpZHRoOiA3MDBweCkge1xuICAgIGJ1dHRvbiB7XG4gICAgICBwYWRkaW5nOiB2YXIoLS13dWktc3BhY2luZy1zKTtcbiAgICB9XG4gIH1cblxuICBidXR0b24gPiB3dWktaWNvbiB7XG4gICAgcG9pbnRlci1ldmVudHM6IG5vbmU7XG4gIH1cblxuICBidXR0b246ZGlzYWJsZWQgPiB3dWktaWNvbiB7XG4gICAgY29sb3I6IHZhcigtLXd1aS1jb2xvci1iZy0zMDApICFpbXBvcnRhbnQ7XG4gIH1cblxuICBidXR0b246ZGlzYWJsZWQge1xuICAgIGJhY2tncm91bmQtY29sb3I6IHRyYW5zcGFyZW50O1xuICB9XG5gO1xuLy8jIHNvdXJjZU1hcHBpbmdVUkw9c3R5bGVzLmpzLm1hcCJdLCJuYW1lcyI6W10sInNvdXJjZVJvb3QiOiIifQ==\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-ui/dist/esm/src/composites/wui-icon-link/styles.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@reown/appkit-ui/dist/esm/src/composites/wui-select/index.js":
-/*!***********************************************************************************!*\
-  !*** ./node_modules/@reown/appkit-ui/dist/esm/src/composites/wui-select/index.js ***!
-  \***********************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   WuiSelect: function() { return /* binding */ WuiSelect; }\n/* harmony export */ });\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n/* harmony import */ var lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__ = __webpack_require__(/*! lit/decorators.js */ \"./node_modules/lit/decorators.js\");\n/* harmony import */ var _components_wui_icon_index_js__WEBPACK_IMPORTED_MODULE_2__ = __webpack_require__(/*! ../../components/wui-icon/index.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js\");\n/* harmony import */ var _components_wui_image_index_js__WEBPACK_IMPORTED_MODULE_3__ = __webpack_require__(/*! ../../components/wui-image/index.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/components/wui-image/index.js\");\n/* harmony import */ var _composites_wui_icon_box_index_js__WEBPACK_IMPORTED_MODULE_4__ = __webpack_require__(/*! ../../composites/wui-icon-box/index.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/composites/wui-icon-box/index.js\");\n/* harmony import */ var _utils_ThemeUtil_js__WEBPACK_IMPORTED_MODULE_5__ = __webpack_require__(/*! ../../utils/ThemeUtil.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/utils/ThemeUtil.js\");\n/* harmony import */ var _utils_WebComponentsUtil_js__WEBPACK_IMPORTED_MODULE_6__ = __webpack_require__(/*! ../../utils/WebComponentsUtil.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/utils/WebComponentsUtil.js\");\n/* harmony import */ var _styles_js__WEBPACK_IMPORTED_MODULE_7__ = __webpack_require__(/*! ./styles.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/composites/wui-select/styles.js\");\nvar __decorate = (undefined && undefined.__decorate) || function (decorators, target, key, desc) {\n    var c = arguments.length, r = c < 3 ? target : desc === null ? desc = Object.getOwnPropertyDescriptor(target, key) : desc, d;\n    if (typeof Reflect === \"object\" && typeof Reflect.decorate === \"function\") r = Reflect.decorate(decorators, target, key, desc);\n    else for (var i = decorators.length - 1; i >= 0; i--) if (d = decorators[i]) r = (c < 3 ? d(r) : c > 3 ? d(target, key, r) : d(target, key)) || r;\n    return c > 3 && r && Object.defineProperty(target, key, r), r;\n};\n\n\n\n\n\n\n\n\nlet WuiSelect = class WuiSelect extends lit__WEBPACK_IMPORTED_MODULE_0__.LitElement {\n    constructor() {\n        super(...arguments);\n        this.imageSrc = '';\n    }\n    render() {\n        return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<button>\n      ${this.imageTemplate()}\n      <wui-icon size=\"xs\" color=\"fg-200\" name=\"chevronBottom\"></wui-icon>\n    </button>`;\n    }\n    imageTemplate() {\n        if (this.imageSrc) {\n            return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<wui-image src=${this.imageSrc} alt=\"select visual\"></wui-image>`;\n        }\n        return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<wui-icon-box\n      size=\"xxs\"\n      iconColor=\"fg-200\"\n      backgroundColor=\"fg-100\"\n      background=\"opaque\"\n      icon=\"networkPlaceholder\"\n    ></wui-icon-box>`;\n    }\n};\nWuiSelect.styles = [_utils_ThemeUtil_js__WEBPACK_IMPORTED_MODULE_5__.resetStyles, _utils_ThemeUtil_js__WEBPACK_IMPORTED_MODULE_5__.elementStyles, _utils_ThemeUtil_js__WEBPACK_IMPORTED_MODULE_5__.colorStyles, _styles_js__WEBPACK_IMPORTED_MODULE_7__[\"default\"]];\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.property)()\n], WuiSelect.prototype, \"imageSrc\", void 0);\nWuiSelect = __decorate([\n    (0,_utils_WebComponentsUtil_js__WEBPACK_IMPORTED_MODULE_6__.customElement)('wui-select')\n], WuiSelect);\n\n//# sourceMappingURL=index.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvY29tcG9zaXRlcy93dWktc2VsZWN0L2luZGV4LmpzIiwibWFwcGluZ3MiOiI7Ozs7Ozs7Ozs7OztBQUFBLGtCQUFrQixTQUFJLElBQUksU0FBSTtBQUM5QjtBQUNBO0FBQ0EsNkNBQTZDLFFBQVE7QUFDckQ7QUFDQTtBQUN1QztBQUNNO0FBQ0Q7QUFDQztBQUNHO0FBQ21DO0FBQ2xCO0FBQ2hDO0FBQ2pDLHdDQUF3QywyQ0FBVTtBQUNsRDtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0EsZUFBZSx5Q0FBSTtBQUNuQixRQUFRO0FBQ1I7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBLG1CQUFtQix5Q0FBSSxtQkFBbUIsZUFBZTtBQUN6RDtBQUNBLGVBQWUseUNBQUk7QUFDbkI7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBLG9CQUFvQiw0REFBVyxFQUFFLDhEQUFhLEVBQUUsNERBQVcsRUFBRSxrREFBTTtBQUNuRTtBQUNBLElBQUksMkRBQVE7QUFDWjtBQUNBO0FBQ0EsSUFBSSwwRUFBYTtBQUNqQjtBQUNxQjtBQUNyQiIsInNvdXJjZXMiOlsid2VicGFjazovL19OX0UvLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvY29tcG9zaXRlcy93dWktc2VsZWN0L2luZGV4LmpzPzg3NTUiXSwic291cmNlc0NvbnRlbnQiOlsidmFyIF9fZGVjb3JhdGUgPSAodGhpcyAmJiB0aGlzLl9fZGVjb3JhdGUpIHx8IGZ1bmN0aW9uIChkZWNvcmF0b3JzLCB0YXJnZXQsIGtleSwgZGVzYykge1xuICAgIHZhciBjID0gYXJndW1lbnRzLmxlbmd0aCwgciA9IGMgPCAzID8gdGFyZ2V0IDogZGVzYyA9PT0gbnVsbCA/IGRlc2MgPSBPYmplY3QuZ2V0T3duUHJvcGVydHlEZXNjcmlwdG9yKHRhcmdldCwga2V5KSA6IGRlc2MsIGQ7XG4gICAgaWYgKHR5cGVvZiBSZWZsZWN0ID09PSBcIm9iamVjdFwiICYmIHR5cGVvZiBSZWZsZWN0LmRlY29yYXRlID09PSBcImZ1bmN0aW9uXCIpIHIgPSBSZWZsZWN0LmRlY29yYXRlKGRlY29yYXRvcnMsIHRhcmdldCwga2V5LCBkZXNjKTtcbiAgICBlbHNlIGZvciAodmFyIGkgPSBkZWNvcmF0b3JzLmxlbmd0aCAtIDE7IGkgPj0gMDsgaS0tKSBpZiAoZCA9IGRlY29yYXRvcnNbaV0pIHIgPSAoYyA8IDMgPyBkKHIpIDogYyA+IDMgPyBkKHRhcmdldCwga2V5LCByKSA6IGQodGFyZ2V0LCBrZXkpKSB8fCByO1xuICAgIHJldHVybiBjID4gMyAmJiByICYmIE9iamVjdC5kZWZpbmVQcm9wZXJ0eSh0YXJnZXQsIGtleSwgciksIHI7XG59O1xuaW1wb3J0IHsgTGl0RWxlbWVudCwgaHRtbCB9IGZyb20gJ2xpdCc7XG5pbXBvcnQgeyBwcm9wZXJ0eSB9IGZyb20gJ2xpdC9kZWNvcmF0b3JzLmpzJztcbmltcG9ydCAnLi4vLi4vY29tcG9uZW50cy93dWktaWNvbi9pbmRleC5qcyc7XG5pbXBvcnQgJy4uLy4uL2NvbXBvbmVudHMvd3VpLWltYWdlL2luZGV4LmpzJztcbmltcG9ydCAnLi4vLi4vY29tcG9zaXRlcy93dWktaWNvbi1ib3gvaW5kZXguanMnO1xuaW1wb3J0IHsgY29sb3JTdHlsZXMsIGVsZW1lbnRTdHlsZXMsIHJlc2V0U3R5bGVzIH0gZnJvbSAnLi4vLi4vdXRpbHMvVGhlbWVVdGlsLmpzJztcbmltcG9ydCB7IGN1c3RvbUVsZW1lbnQgfSBmcm9tICcuLi8uLi91dGlscy9XZWJDb21wb25lbnRzVXRpbC5qcyc7XG5pbXBvcnQgc3R5bGVzIGZyb20gJy4vc3R5bGVzLmpzJztcbmxldCBXdWlTZWxlY3QgPSBjbGFzcyBXdWlTZWxlY3QgZXh0ZW5kcyBMaXRFbGVtZW50IHtcbiAgICBjb25zdHJ1Y3RvcigpIHtcbiAgICAgICAgc3VwZXIoLi4uYXJndW1lbnRzKTtcbiAgICAgICAgdGhpcy5pbWFnZVNyYyA9ICcnO1xuICAgIH1cbiAgICByZW5kZXIoKSB7XG4gICAgICAgIHJldHVybiBodG1sIGA8YnV0dG9uPlxuICAgICAgJHt0aGlzLmltYWdlVGVtcGxhdGUoKX1cbiAgICAgIDx3dWktaWNvbiBzaXplPVwieHNcIiBjb2xvcj1cImZnLTIwMFwiIG5hbWU9XCJjaGV2cm9uQm90dG9tXCI+PC93dWktaWNvbj5cbiAgICA8L2J1dHRvbj5gO1xuICAgIH1cbiAgICBpbWFnZVRlbXBsYXRlKCkge1xuICAgICAgICBpZiAodGhpcy5pbWFnZVNyYykge1xuICAgICAgICAgICAgcmV0dXJuIGh0bWwgYDx3dWktaW1hZ2Ugc3JjPSR7dGhpcy5pbWFnZVNyY30gYWx0PVwic2VsZWN0IHZpc3VhbFwiPjwvd3VpLWltYWdlPmA7XG4gICAgICAgIH1cbiAgICAgICAgcmV0dXJuIGh0bWwgYDx3dWktaWNvbi1ib3hcbiAgICAgIHNpemU9XCJ4eHNcIlxuICAgICAgaWNvbkNvbG9yPVwiZmctMjAwXCJcbiAgICAgIGJhY2tncm91bmRDb2xvcj1cImZnLTEwMFwiXG4gICAgICBiYWNrZ3JvdW5kPVwib3BhcXVlXCJcbiAgICAgIGljb249XCJuZXR3b3JrUGxhY2Vob2xkZXJcIlxuICAgID48L3d1aS1pY29uLWJveD5gO1xuICAgIH1cbn07XG5XdWlTZWxlY3Quc3R5bGVzID0gW3Jlc2V0U3R5bGVzLCBlbGVtZW50U3R5bGVzLCBjb2xvclN0eWxlcywgc3R5bGVzXTtcbl9fZGVjb3JhdGUoW1xuICAgIHByb3BlcnR5KClcbl0sIFd1aVNlbGVjdC5wcm90b3R5cGUsIFwiaW1hZ2VTcmNcIiwgdm9pZCAwKTtcbld1aVNlbGVjdCA9IF9fZGVjb3JhdGUoW1xuICAgIGN1c3RvbUVsZW1lbnQoJ3d1aS1zZWxlY3QnKVxuXSwgV3VpU2VsZWN0KTtcbmV4cG9ydCB7IFd1aVNlbGVjdCB9O1xuLy8jIHNvdXJjZU1hcHBpbmdVUkw9aW5kZXguanMubWFwIl0sIm5hbWVzIjpbXSwic291cmNlUm9vdCI6IiJ9\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-ui/dist/esm/src/composites/wui-select/index.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@reown/appkit-ui/dist/esm/src/composites/wui-select/styles.js":
-/*!************************************************************************************!*\
-  !*** ./node_modules/@reown/appkit-ui/dist/esm/src/composites/wui-select/styles.js ***!
-  \************************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n\n/* harmony default export */ __webpack_exports__[\"default\"] = ((0,lit__WEBPACK_IMPORTED_MODULE_0__.css) `\n  button {\n    display: block;\n    display: flex;\n    align-items: center;\n    padding: var(--wui-spacing-xxs);\n    gap: var(--wui-spacing-xxs);\n    transition: all var(--wui-ease-out-power-1) var(--wui-duration-md);\n    border-radius: var(--wui-border-radius-xxs);\n  }\n\n  wui-image {\n    border-radius: 100%;\n    width: var(--wui-spacing-xl);\n    height: var(--wui-spacing-xl);\n  }\n\n  wui-icon-box {\n    width: var(--wui-spacing-xl);\n    height: var(--wui-spacing-xl);\n  }\n\n  button:hover {\n    background-color: var(--wui-color-gray-glass-002);\n  }\n\n  button:active {\n    background-color: var(--wui-color-gray-glass-005);\n  }\n`);\n//# sourceMappingURL=styles.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvY29tcG9zaXRlcy93dWktc2VsZWN0L3N0eWxlcy5qcyIsIm1hcHBpbmdzIjoiOztBQUEwQjtBQUMxQiwrREFBZSx3Q0FBRztBQUNsQjtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7O0FBRUE7QUFDQTtBQUNBO0FBQ0E7QUFDQTs7QUFFQTtBQUNBO0FBQ0E7QUFDQTs7QUFFQTtBQUNBO0FBQ0E7O0FBRUE7QUFDQTtBQUNBO0FBQ0EsQ0FBQyxFQUFDO0FBQ0YiLCJzb3VyY2VzIjpbIndlYnBhY2s6Ly9fTl9FLy4vbm9kZV9tb2R1bGVzL0ByZW93bi9hcHBraXQtdWkvZGlzdC9lc20vc3JjL2NvbXBvc2l0ZXMvd3VpLXNlbGVjdC9zdHlsZXMuanM/NDkwMSJdLCJzb3VyY2VzQ29udGVudCI6WyJpbXBvcnQgeyBjc3MgfSBmcm9tICdsaXQnO1xuZXhwb3J0IGRlZmF1bHQgY3NzIGBcbiAgYnV0dG9uIHtcbiAgICBkaXNwbGF5OiBibG9jaztcbiAgICBkaXNwbGF5OiBmbGV4O1xuICAgIGFsaWduLWl0ZW1zOiBjZW50ZXI7XG4gICAgcGFkZGluZzogdmFyKC0td3VpLXNwYWNpbmcteHhzKTtcbiAgICBnYXA6IHZhcigtLXd1aS1zcGFjaW5nLXh4cyk7XG4gICAgdHJhbnNpdGlvbjogYWxsIHZhcigtLXd1aS1lYXNlLW91dC1wb3dlci0xKSB2YXIoLS13dWktZHVyYXRpb24tbWQpO1xuICAgIGJvcmRlci1yYWRpdXM6IHZhcigtLXd1aS1ib3JkZXItcmFkaXVzLXh4cyk7XG4gIH1cblxuICB3dWktaW1hZ2Uge1xuICAgIGJvcmRlci1yYWRpdXM6IDEwMCU7XG4gICAgd2lkdGg6IHZhcigtLXd1aS1zcGFjaW5nLXhsKTtcbiAgICBoZWlnaHQ6IHZhcigtLXd1aS1zcGFjaW5nLXhsKTtcbiAgfVxuXG4gIHd1aS1pY29uLWJveCB7XG4gICAgd2lkdGg6IHZhcigtLXd1aS1zcGFjaW5nLXhsKTtcbiAgICBoZWlnaHQ6IHZhcigtLXd1aS1zcGFjaW5nLXhsKTtcbiAgfVxuXG4gIGJ1dHRvbjpob3ZlciB7XG4gICAgYmFja2dyb3VuZC1jb2xvcjogdmFyKC0td3VpLWNvbG9yLWdyYXktZ2xhc3MtMDAyKTtcbiAgfVxuXG4gIGJ1dHRvbjphY3RpdmUge1xuICAgIGJhY2tncm91bmQtY29sb3I6IHZhcigtLXd1aS1jb2xvci1ncmF5LWdsYXNzLTAwNSk7XG4gIH1cbmA7XG4vLyMgc291cmNlTWFwcGluZ1VSTD1zdHlsZXMuanMubWFwIl0sIm5hbWVzIjpbXSwic291cmNlUm9vdCI6IiJ9\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-ui/dist/esm/src/composites/wui-select/styles.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@reown/appkit-ui/dist/esm/src/composites/wui-snackbar/index.js":
-/*!*************************************************************************************!*\
-  !*** ./node_modules/@reown/appkit-ui/dist/esm/src/composites/wui-snackbar/index.js ***!
-  \*************************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   WuiSnackbar: function() { return /* binding */ WuiSnackbar; }\n/* harmony export */ });\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n/* harmony import */ var lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__ = __webpack_require__(/*! lit/decorators.js */ \"./node_modules/lit/decorators.js\");\n/* harmony import */ var _components_wui_icon_index_js__WEBPACK_IMPORTED_MODULE_2__ = __webpack_require__(/*! ../../components/wui-icon/index.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/components/wui-icon/index.js\");\n/* harmony import */ var _components_wui_loading_spinner_index_js__WEBPACK_IMPORTED_MODULE_3__ = __webpack_require__(/*! ../../components/wui-loading-spinner/index.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/components/wui-loading-spinner/index.js\");\n/* harmony import */ var _components_wui_text_index_js__WEBPACK_IMPORTED_MODULE_4__ = __webpack_require__(/*! ../../components/wui-text/index.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/components/wui-text/index.js\");\n/* harmony import */ var _utils_ThemeUtil_js__WEBPACK_IMPORTED_MODULE_5__ = __webpack_require__(/*! ../../utils/ThemeUtil.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/utils/ThemeUtil.js\");\n/* harmony import */ var _utils_WebComponentsUtil_js__WEBPACK_IMPORTED_MODULE_6__ = __webpack_require__(/*! ../../utils/WebComponentsUtil.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/utils/WebComponentsUtil.js\");\n/* harmony import */ var _wui_icon_box_index_js__WEBPACK_IMPORTED_MODULE_7__ = __webpack_require__(/*! ../wui-icon-box/index.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/composites/wui-icon-box/index.js\");\n/* harmony import */ var _styles_js__WEBPACK_IMPORTED_MODULE_8__ = __webpack_require__(/*! ./styles.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/composites/wui-snackbar/styles.js\");\nvar __decorate = (undefined && undefined.__decorate) || function (decorators, target, key, desc) {\n    var c = arguments.length, r = c < 3 ? target : desc === null ? desc = Object.getOwnPropertyDescriptor(target, key) : desc, d;\n    if (typeof Reflect === \"object\" && typeof Reflect.decorate === \"function\") r = Reflect.decorate(decorators, target, key, desc);\n    else for (var i = decorators.length - 1; i >= 0; i--) if (d = decorators[i]) r = (c < 3 ? d(r) : c > 3 ? d(target, key, r) : d(target, key)) || r;\n    return c > 3 && r && Object.defineProperty(target, key, r), r;\n};\n\n\n\n\n\n\n\n\n\nlet WuiSnackbar = class WuiSnackbar extends lit__WEBPACK_IMPORTED_MODULE_0__.LitElement {\n    constructor() {\n        super(...arguments);\n        this.backgroundColor = 'accent-100';\n        this.iconColor = 'accent-100';\n        this.icon = 'checkmark';\n        this.message = '';\n        this.loading = false;\n        this.iconType = 'default';\n    }\n    render() {\n        return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `\n      ${this.templateIcon()}\n      <wui-text variant=\"paragraph-500\" color=\"fg-100\" data-testid=\"wui-snackbar-message\"\n        >${this.message}</wui-text\n      >\n    `;\n    }\n    templateIcon() {\n        if (this.loading) {\n            return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<wui-loading-spinner size=\"md\" color=\"accent-100\"></wui-loading-spinner>`;\n        }\n        if (this.iconType === 'default') {\n            return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<wui-icon size=\"xl\" color=${this.iconColor} name=${this.icon}></wui-icon>`;\n        }\n        return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<wui-icon-box\n      size=\"sm\"\n      iconSize=\"xs\"\n      iconColor=${this.iconColor}\n      backgroundColor=${this.backgroundColor}\n      icon=${this.icon}\n      background=\"opaque\"\n    ></wui-icon-box>`;\n    }\n};\nWuiSnackbar.styles = [_utils_ThemeUtil_js__WEBPACK_IMPORTED_MODULE_5__.resetStyles, _styles_js__WEBPACK_IMPORTED_MODULE_8__[\"default\"]];\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.property)()\n], WuiSnackbar.prototype, \"backgroundColor\", void 0);\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.property)()\n], WuiSnackbar.prototype, \"iconColor\", void 0);\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.property)()\n], WuiSnackbar.prototype, \"icon\", void 0);\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.property)()\n], WuiSnackbar.prototype, \"message\", void 0);\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.property)()\n], WuiSnackbar.prototype, \"loading\", void 0);\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.property)()\n], WuiSnackbar.prototype, \"iconType\", void 0);\nWuiSnackbar = __decorate([\n    (0,_utils_WebComponentsUtil_js__WEBPACK_IMPORTED_MODULE_6__.customElement)('wui-snackbar')\n], WuiSnackbar);\n\n//# sourceMappingURL=index.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvY29tcG9zaXRlcy93dWktc25hY2tiYXIvaW5kZXguanMiLCJtYXBwaW5ncyI6Ijs7Ozs7Ozs7Ozs7OztBQUFBLGtCQUFrQixTQUFJLElBQUksU0FBSTtBQUM5QjtBQUNBO0FBQ0EsNkNBQTZDLFFBQVE7QUFDckQ7QUFDQTtBQUN1QztBQUNNO0FBQ0Q7QUFDVztBQUNYO0FBQ1c7QUFDVTtBQUMvQjtBQUNEO0FBQ2pDLDRDQUE0QywyQ0FBVTtBQUN0RDtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBLGVBQWUseUNBQUk7QUFDbkIsUUFBUTtBQUNSO0FBQ0EsV0FBVyxhQUFhO0FBQ3hCO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQSxtQkFBbUIseUNBQUk7QUFDdkI7QUFDQTtBQUNBLG1CQUFtQix5Q0FBSSw4QkFBOEIsZ0JBQWdCLE9BQU8sVUFBVTtBQUN0RjtBQUNBLGVBQWUseUNBQUk7QUFDbkI7QUFDQTtBQUNBLGtCQUFrQjtBQUNsQix3QkFBd0I7QUFDeEIsYUFBYTtBQUNiO0FBQ0E7QUFDQTtBQUNBO0FBQ0Esc0JBQXNCLDREQUFXLEVBQUUsa0RBQU07QUFDekM7QUFDQSxJQUFJLDJEQUFRO0FBQ1o7QUFDQTtBQUNBLElBQUksMkRBQVE7QUFDWjtBQUNBO0FBQ0EsSUFBSSwyREFBUTtBQUNaO0FBQ0E7QUFDQSxJQUFJLDJEQUFRO0FBQ1o7QUFDQTtBQUNBLElBQUksMkRBQVE7QUFDWjtBQUNBO0FBQ0EsSUFBSSwyREFBUTtBQUNaO0FBQ0E7QUFDQSxJQUFJLDBFQUFhO0FBQ2pCO0FBQ3VCO0FBQ3ZCIiwic291cmNlcyI6WyJ3ZWJwYWNrOi8vX05fRS8uL25vZGVfbW9kdWxlcy9AcmVvd24vYXBwa2l0LXVpL2Rpc3QvZXNtL3NyYy9jb21wb3NpdGVzL3d1aS1zbmFja2Jhci9pbmRleC5qcz9jNmM0Il0sInNvdXJjZXNDb250ZW50IjpbInZhciBfX2RlY29yYXRlID0gKHRoaXMgJiYgdGhpcy5fX2RlY29yYXRlKSB8fCBmdW5jdGlvbiAoZGVjb3JhdG9ycywgdGFyZ2V0LCBrZXksIGRlc2MpIHtcbiAgICB2YXIgYyA9IGFyZ3VtZW50cy5sZW5ndGgsIHIgPSBjIDwgMyA/IHRhcmdldCA6IGRlc2MgPT09IG51bGwgPyBkZXNjID0gT2JqZWN0LmdldE93blByb3BlcnR5RGVzY3JpcHRvcih0YXJnZXQsIGtleSkgOiBkZXNjLCBkO1xuICAgIGlmICh0eXBlb2YgUmVmbGVjdCA9PT0gXCJvYmplY3RcIiAmJiB0eXBlb2YgUmVmbGVjdC5kZWNvcmF0ZSA9PT0gXCJmdW5jdGlvblwiKSByID0gUmVmbGVjdC5kZWNvcmF0ZShkZWNvcmF0b3JzLCB0YXJnZXQsIGtleSwgZGVzYyk7XG4gICAgZWxzZSBmb3IgKHZhciBpID0gZGVjb3JhdG9ycy5sZW5ndGggLSAxOyBpID49IDA7IGktLSkgaWYgKGQgPSBkZWNvcmF0b3JzW2ldKSByID0gKGMgPCAzID8gZChyKSA6IGMgPiAzID8gZCh0YXJnZXQsIGtleSwgcikgOiBkKHRhcmdldCwga2V5KSkgfHwgcjtcbiAgICByZXR1cm4gYyA+IDMgJiYgciAmJiBPYmplY3QuZGVmaW5lUHJvcGVydHkodGFyZ2V0LCBrZXksIHIpLCByO1xufTtcbmltcG9ydCB7IExpdEVsZW1lbnQsIGh0bWwgfSBmcm9tICdsaXQnO1xuaW1wb3J0IHsgcHJvcGVydHkgfSBmcm9tICdsaXQvZGVjb3JhdG9ycy5qcyc7XG5pbXBvcnQgJy4uLy4uL2NvbXBvbmVudHMvd3VpLWljb24vaW5kZXguanMnO1xuaW1wb3J0ICcuLi8uLi9jb21wb25lbnRzL3d1aS1sb2FkaW5nLXNwaW5uZXIvaW5kZXguanMnO1xuaW1wb3J0ICcuLi8uLi9jb21wb25lbnRzL3d1aS10ZXh0L2luZGV4LmpzJztcbmltcG9ydCB7IHJlc2V0U3R5bGVzIH0gZnJvbSAnLi4vLi4vdXRpbHMvVGhlbWVVdGlsLmpzJztcbmltcG9ydCB7IGN1c3RvbUVsZW1lbnQgfSBmcm9tICcuLi8uLi91dGlscy9XZWJDb21wb25lbnRzVXRpbC5qcyc7XG5pbXBvcnQgJy4uL3d1aS1pY29uLWJveC9pbmRleC5qcyc7XG5pbXBvcnQgc3R5bGVzIGZyb20gJy4vc3R5bGVzLmpzJztcbmxldCBXdWlTbmFja2JhciA9IGNsYXNzIFd1aVNuYWNrYmFyIGV4dGVuZHMgTGl0RWxlbWVudCB7XG4gICAgY29uc3RydWN0b3IoKSB7XG4gICAgICAgIHN1cGVyKC4uLmFyZ3VtZW50cyk7XG4gICAgICAgIHRoaXMuYmFja2dyb3VuZENvbG9yID0gJ2FjY2VudC0xMDAnO1xuICAgICAgICB0aGlzLmljb25Db2xvciA9ICdhY2NlbnQtMTAwJztcbiAgICAgICAgdGhpcy5pY29uID0gJ2NoZWNrbWFyayc7XG4gICAgICAgIHRoaXMubWVzc2FnZSA9ICcnO1xuICAgICAgICB0aGlzLmxvYWRpbmcgPSBmYWxzZTtcbiAgICAgICAgdGhpcy5pY29uVHlwZSA9ICdkZWZhdWx0JztcbiAgICB9XG4gICAgcmVuZGVyKCkge1xuICAgICAgICByZXR1cm4gaHRtbCBgXG4gICAgICAke3RoaXMudGVtcGxhdGVJY29uKCl9XG4gICAgICA8d3VpLXRleHQgdmFyaWFudD1cInBhcmFncmFwaC01MDBcIiBjb2xvcj1cImZnLTEwMFwiIGRhdGEtdGVzdGlkPVwid3VpLXNuYWNrYmFyLW1lc3NhZ2VcIlxuICAgICAgICA+JHt0aGlzLm1lc3NhZ2V9PC93dWktdGV4dFxuICAgICAgPlxuICAgIGA7XG4gICAgfVxuICAgIHRlbXBsYXRlSWNvbigpIHtcbiAgICAgICAgaWYgKHRoaXMubG9hZGluZykge1xuICAgICAgICAgICAgcmV0dXJuIGh0bWwgYDx3dWktbG9hZGluZy1zcGlubmVyIHNpemU9XCJtZFwiIGNvbG9yPVwiYWNjZW50LTEwMFwiPjwvd3VpLWxvYWRpbmctc3Bpbm5lcj5gO1xuICAgICAgICB9XG4gICAgICAgIGlmICh0aGlzLmljb25UeXBlID09PSAnZGVmYXVsdCcpIHtcbiAgICAgICAgICAgIHJldHVybiBodG1sIGA8d3VpLWljb24gc2l6ZT1cInhsXCIgY29sb3I9JHt0aGlzLmljb25Db2xvcn0gbmFtZT0ke3RoaXMuaWNvbn0+PC93dWktaWNvbj5gO1xuICAgICAgICB9XG4gICAgICAgIHJldHVybiBodG1sIGA8d3VpLWljb24tYm94XG4gICAgICBzaXplPVwic21cIlxuICAgICAgaWNvblNpemU9XCJ4c1wiXG4gICAgICBpY29uQ29sb3I9JHt0aGlzLmljb25Db2xvcn1cbiAgICAgIGJhY2tncm91bmRDb2xvcj0ke3RoaXMuYmFja2dyb3VuZENvbG9yfVxuICAgICAgaWNvbj0ke3RoaXMuaWNvbn1cbiAgICAgIGJhY2tncm91bmQ9XCJvcGFxdWVcIlxuICAgID48L3d1aS1pY29uLWJveD5gO1xuICAgIH1cbn07XG5XdWlTbmFja2Jhci5zdHlsZXMgPSBbcmVzZXRTdHlsZXMsIHN0eWxlc107XG5fX2RlY29yYXRlKFtcbiAgICBwcm9wZXJ0eSgpXG5dLCBXdWlTbmFja2Jhci5wcm90b3R5cGUsIFwiYmFja2dyb3VuZENvbG9yXCIsIHZvaWQgMCk7XG5fX2RlY29yYXRlKFtcbiAgICBwcm9wZXJ0eSgpXG5dLCBXdWlTbmFja2Jhci5wcm90b3R5cGUsIFwiaWNvbkNvbG9yXCIsIHZvaWQgMCk7XG5fX2RlY29yYXRlKFtcbiAgICBwcm9wZXJ0eSgpXG5dLCBXdWlTbmFja2Jhci5wcm90b3R5cGUsIFwiaWNvblwiLCB2b2lkIDApO1xuX19kZWNvcmF0ZShbXG4gICAgcHJvcGVydHkoKVxuXSwgV3VpU25hY2tiYXIucHJvdG90eXBlLCBcIm1lc3NhZ2VcIiwgdm9pZCAwKTtcbl9fZGVjb3JhdGUoW1xuICAgIHByb3BlcnR5KClcbl0sIFd1aVNuYWNrYmFyLnByb3RvdHlwZSwgXCJsb2FkaW5nXCIsIHZvaWQgMCk7XG5fX2RlY29yYXRlKFtcbiAgICBwcm9wZXJ0eSgpXG5dLCBXdWlTbmFja2Jhci5wcm90b3R5cGUsIFwiaWNvblR5cGVcIiwgdm9pZCAwKTtcbld1aVNuYWNrYmFyID0gX19kZWNvcmF0ZShbXG4gICAgY3VzdG9tRWxlbWVudCgnd3VpLXNuYWNrYmFyJylcbl0sIFd1aVNuYWNrYmFyKTtcbmV4cG9ydCB7IFd1aVNuYWNrYmFyIH07XG4vLyMgc291cmNlTWFwcGluZ1VSTD1pbmRleC5qcy5tYXAiXSwibmFtZXMiOltdLCJzb3VyY2VSb290IjoiIn0=\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-ui/dist/esm/src/composites/wui-snackbar/index.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@reown/appkit-ui/dist/esm/src/composites/wui-snackbar/styles.js":
-/*!**************************************************************************************!*\
-  !*** ./node_modules/@reown/appkit-ui/dist/esm/src/composites/wui-snackbar/styles.js ***!
-  \**************************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n\n/* harmony default export */ __webpack_exports__[\"default\"] = ((0,lit__WEBPACK_IMPORTED_MODULE_0__.css) `\n  :host {\n    display: flex;\n    column-gap: var(--wui-spacing-s);\n    align-items: center;\n    padding: var(--wui-spacing-xs) var(--wui-spacing-m) var(--wui-spacing-xs) var(--wui-spacing-xs);\n    border-radius: var(--wui-border-radius-s);\n    border: 1px solid var(--wui-color-gray-glass-005);\n    box-sizing: border-box;\n    background-color: var(--wui-color-bg-175);\n    box-shadow:\n      0px 14px 64px -4px rgba(0, 0, 0, 0.15),\n      0px 8px 22px -6px rgba(0, 0, 0, 0.15);\n\n    max-width: 300px;\n  }\n\n  :host wui-loading-spinner {\n    margin-left: var(--wui-spacing-3xs);\n  }\n`);\n//# sourceMappingURL=styles.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvY29tcG9zaXRlcy93dWktc25hY2tiYXIvc3R5bGVzLmpzIiwibWFwcGluZ3MiOiI7O0FBQTBCO0FBQzFCLCtEQUFlLHdDQUFHO0FBQ2xCO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTs7QUFFQTtBQUNBOztBQUVBO0FBQ0E7QUFDQTtBQUNBLENBQUMsRUFBQztBQUNGIiwic291cmNlcyI6WyJ3ZWJwYWNrOi8vX05fRS8uL25vZGVfbW9kdWxlcy9AcmVvd24vYXBwa2l0LXVpL2Rpc3QvZXNtL3NyYy9jb21wb3NpdGVzL3d1aS1zbmFja2Jhci9zdHlsZXMuanM/OTk2YyJdLCJzb3VyY2VzQ29udGVudCI6WyJpbXBvcnQgeyBjc3MgfSBmcm9tICdsaXQnO1xuZXhwb3J0IGRlZmF1bHQgY3NzIGBcbiAgOmhvc3Qge1xuICAgIGRpc3BsYXk6IGZsZXg7XG4gICAgY29sdW1uLWdhcDogdmFyKC0td3VpLXNwYWNpbmctcyk7XG4gICAgYWxpZ24taXRlbXM6IGNlbnRlcjtcbiAgICBwYWRkaW5nOiB2YXIoLS13dWktc3BhY2luZy14cykgdmFyKC0td3VpLXNwYWNpbmctbSkgdmFyKC0td3VpLXNwYWNpbmcteHMpIHZhcigtLXd1aS1zcGFjaW5nLXhzKTtcbiAgICBib3JkZXItcmFkaXVzOiB2YXIoLS13dWktYm9yZGVyLXJhZGl1cy1zKTtcbiAgICBib3JkZXI6IDFweCBzb2xpZCB2YXIoLS13dWktY29sb3ItZ3JheS1nbGFzcy0wMDUpO1xuICAgIGJveC1zaXppbmc6IGJvcmRlci1ib3g7XG4gICAgYmFja2dyb3VuZC1jb2xvcjogdmFyKC0td3VpLWNvbG9yLWJnLTE3NSk7XG4gICAgYm94LXNoYWRvdzpcbiAgICAgIDBweCAxNHB4IDY0cHggLTRweCByZ2JhKDAsIDAsIDAsIDAuMTUpLFxuICAgICAgMHB4IDhweCAyMnB4IC02cHggcmdiYSgwLCAwLCAwLCAwLjE1KTtcblxuICAgIG1heC13aWR0aDogMzAwcHg7XG4gIH1cblxuICA6aG9zdCB3dWktbG9hZGluZy1zcGlubmVyIHtcbiAgICBtYXJnaW4tbGVmdDogdmFyKC0td3VpLXNwYWNpbmctM3hzKTtcbiAgfVxuYDtcbi8vIyBzb3VyY2VNYXBwaW5nVVJMPXN0eWxlcy5qcy5tYXAiXSwibmFtZXMiOltdLCJzb3VyY2VSb290IjoiIn0=\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-ui/dist/esm/src/composites/wui-snackbar/styles.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@reown/appkit-ui/dist/esm/src/composites/wui-tag/index.js":
-/*!********************************************************************************!*\
-  !*** ./node_modules/@reown/appkit-ui/dist/esm/src/composites/wui-tag/index.js ***!
-  \********************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   WuiTag: function() { return /* binding */ WuiTag; }\n/* harmony export */ });\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n/* harmony import */ var lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__ = __webpack_require__(/*! lit/decorators.js */ \"./node_modules/lit/decorators.js\");\n/* harmony import */ var _components_wui_text_index_js__WEBPACK_IMPORTED_MODULE_2__ = __webpack_require__(/*! ../../components/wui-text/index.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/components/wui-text/index.js\");\n/* harmony import */ var _utils_ThemeUtil_js__WEBPACK_IMPORTED_MODULE_3__ = __webpack_require__(/*! ../../utils/ThemeUtil.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/utils/ThemeUtil.js\");\n/* harmony import */ var _utils_WebComponentsUtil_js__WEBPACK_IMPORTED_MODULE_4__ = __webpack_require__(/*! ../../utils/WebComponentsUtil.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/utils/WebComponentsUtil.js\");\n/* harmony import */ var _styles_js__WEBPACK_IMPORTED_MODULE_5__ = __webpack_require__(/*! ./styles.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/composites/wui-tag/styles.js\");\nvar __decorate = (undefined && undefined.__decorate) || function (decorators, target, key, desc) {\n    var c = arguments.length, r = c < 3 ? target : desc === null ? desc = Object.getOwnPropertyDescriptor(target, key) : desc, d;\n    if (typeof Reflect === \"object\" && typeof Reflect.decorate === \"function\") r = Reflect.decorate(decorators, target, key, desc);\n    else for (var i = decorators.length - 1; i >= 0; i--) if (d = decorators[i]) r = (c < 3 ? d(r) : c > 3 ? d(target, key, r) : d(target, key)) || r;\n    return c > 3 && r && Object.defineProperty(target, key, r), r;\n};\n\n\n\n\n\n\nlet WuiTag = class WuiTag extends lit__WEBPACK_IMPORTED_MODULE_0__.LitElement {\n    constructor() {\n        super(...arguments);\n        this.variant = 'main';\n        this.size = 'lg';\n    }\n    render() {\n        this.dataset['variant'] = this.variant;\n        this.dataset['size'] = this.size;\n        const textVariant = this.size === 'md' ? 'mini-700' : 'micro-700';\n        return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `\n      <wui-text data-variant=${this.variant} variant=${textVariant} color=\"inherit\">\n        <slot></slot>\n      </wui-text>\n    `;\n    }\n};\nWuiTag.styles = [_utils_ThemeUtil_js__WEBPACK_IMPORTED_MODULE_3__.resetStyles, _styles_js__WEBPACK_IMPORTED_MODULE_5__[\"default\"]];\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.property)()\n], WuiTag.prototype, \"variant\", void 0);\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.property)()\n], WuiTag.prototype, \"size\", void 0);\nWuiTag = __decorate([\n    (0,_utils_WebComponentsUtil_js__WEBPACK_IMPORTED_MODULE_4__.customElement)('wui-tag')\n], WuiTag);\n\n//# sourceMappingURL=index.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvY29tcG9zaXRlcy93dWktdGFnL2luZGV4LmpzIiwibWFwcGluZ3MiOiI7Ozs7Ozs7Ozs7QUFBQSxrQkFBa0IsU0FBSSxJQUFJLFNBQUk7QUFDOUI7QUFDQTtBQUNBLDZDQUE2QyxRQUFRO0FBQ3JEO0FBQ0E7QUFDdUM7QUFDTTtBQUNEO0FBQ1c7QUFDVTtBQUNoQztBQUNqQyxrQ0FBa0MsMkNBQVU7QUFDNUM7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0EsZUFBZSx5Q0FBSTtBQUNuQiwrQkFBK0IsY0FBYyxVQUFVLGFBQWE7QUFDcEU7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBLGlCQUFpQiw0REFBVyxFQUFFLGtEQUFNO0FBQ3BDO0FBQ0EsSUFBSSwyREFBUTtBQUNaO0FBQ0E7QUFDQSxJQUFJLDJEQUFRO0FBQ1o7QUFDQTtBQUNBLElBQUksMEVBQWE7QUFDakI7QUFDa0I7QUFDbEIiLCJzb3VyY2VzIjpbIndlYnBhY2s6Ly9fTl9FLy4vbm9kZV9tb2R1bGVzL0ByZW93bi9hcHBraXQtdWkvZGlzdC9lc20vc3JjL2NvbXBvc2l0ZXMvd3VpLXRhZy9pbmRleC5qcz9iZTE4Il0sInNvdXJjZXNDb250ZW50IjpbInZhciBfX2RlY29yYXRlID0gKHRoaXMgJiYgdGhpcy5fX2RlY29yYXRlKSB8fCBmdW5jdGlvbiAoZGVjb3JhdG9ycywgdGFyZ2V0LCBrZXksIGRlc2MpIHtcbiAgICB2YXIgYyA9IGFyZ3VtZW50cy5sZW5ndGgsIHIgPSBjIDwgMyA/IHRhcmdldCA6IGRlc2MgPT09IG51bGwgPyBkZXNjID0gT2JqZWN0LmdldE93blByb3BlcnR5RGVzY3JpcHRvcih0YXJnZXQsIGtleSkgOiBkZXNjLCBkO1xuICAgIGlmICh0eXBlb2YgUmVmbGVjdCA9PT0gXCJvYmplY3RcIiAmJiB0eXBlb2YgUmVmbGVjdC5kZWNvcmF0ZSA9PT0gXCJmdW5jdGlvblwiKSByID0gUmVmbGVjdC5kZWNvcmF0ZShkZWNvcmF0b3JzLCB0YXJnZXQsIGtleSwgZGVzYyk7XG4gICAgZWxzZSBmb3IgKHZhciBpID0gZGVjb3JhdG9ycy5sZW5ndGggLSAxOyBpID49IDA7IGktLSkgaWYgKGQgPSBkZWNvcmF0b3JzW2ldKSByID0gKGMgPCAzID8gZChyKSA6IGMgPiAzID8gZCh0YXJnZXQsIGtleSwgcikgOiBkKHRhcmdldCwga2V5KSkgfHwgcjtcbiAgICByZXR1cm4gYyA+IDMgJiYgciAmJiBPYmplY3QuZGVmaW5lUHJvcGVydHkodGFyZ2V0LCBrZXksIHIpLCByO1xufTtcbmltcG9ydCB7IExpdEVsZW1lbnQsIGh0bWwgfSBmcm9tICdsaXQnO1xuaW1wb3J0IHsgcHJvcGVydHkgfSBmcm9tICdsaXQvZGVjb3JhdG9ycy5qcyc7XG5pbXBvcnQgJy4uLy4uL2NvbXBvbmVudHMvd3VpLXRleHQvaW5kZXguanMnO1xuaW1wb3J0IHsgcmVzZXRTdHlsZXMgfSBmcm9tICcuLi8uLi91dGlscy9UaGVtZVV0aWwuanMnO1xuaW1wb3J0IHsgY3VzdG9tRWxlbWVudCB9IGZyb20gJy4uLy4uL3V0aWxzL1dlYkNvbXBvbmVudHNVdGlsLmpzJztcbmltcG9ydCBzdHlsZXMgZnJvbSAnLi9zdHlsZXMuanMnO1xubGV0IFd1aVRhZyA9IGNsYXNzIFd1aVRhZyBleHRlbmRzIExpdEVsZW1lbnQge1xuICAgIGNvbnN0cnVjdG9yKCkge1xuICAgICAgICBzdXBlciguLi5hcmd1bWVudHMpO1xuICAgICAgICB0aGlzLnZhcmlhbnQgPSAnbWFpbic7XG4gICAgICAgIHRoaXMuc2l6ZSA9ICdsZyc7XG4gICAgfVxuICAgIHJlbmRlcigpIHtcbiAgICAgICAgdGhpcy5kYXRhc2V0Wyd2YXJpYW50J10gPSB0aGlzLnZhcmlhbnQ7XG4gICAgICAgIHRoaXMuZGF0YXNldFsnc2l6ZSddID0gdGhpcy5zaXplO1xuICAgICAgICBjb25zdCB0ZXh0VmFyaWFudCA9IHRoaXMuc2l6ZSA9PT0gJ21kJyA/ICdtaW5pLTcwMCcgOiAnbWljcm8tNzAwJztcbiAgICAgICAgcmV0dXJuIGh0bWwgYFxuICAgICAgPHd1aS10ZXh0IGRhdGEtdmFyaWFudD0ke3RoaXMudmFyaWFudH0gdmFyaWFudD0ke3RleHRWYXJpYW50fSBjb2xvcj1cImluaGVyaXRcIj5cbiAgICAgICAgPHNsb3Q+PC9zbG90PlxuICAgICAgPC93dWktdGV4dD5cbiAgICBgO1xuICAgIH1cbn07XG5XdWlUYWcuc3R5bGVzID0gW3Jlc2V0U3R5bGVzLCBzdHlsZXNdO1xuX19kZWNvcmF0ZShbXG4gICAgcHJvcGVydHkoKVxuXSwgV3VpVGFnLnByb3RvdHlwZSwgXCJ2YXJpYW50XCIsIHZvaWQgMCk7XG5fX2RlY29yYXRlKFtcbiAgICBwcm9wZXJ0eSgpXG5dLCBXdWlUYWcucHJvdG90eXBlLCBcInNpemVcIiwgdm9pZCAwKTtcbld1aVRhZyA9IF9fZGVjb3JhdGUoW1xuICAgIGN1c3RvbUVsZW1lbnQoJ3d1aS10YWcnKVxuXSwgV3VpVGFnKTtcbmV4cG9ydCB7IFd1aVRhZyB9O1xuLy8jIHNvdXJjZU1hcHBpbmdVUkw9aW5kZXguanMubWFwIl0sIm5hbWVzIjpbXSwic291cmNlUm9vdCI6IiJ9\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-ui/dist/esm/src/composites/wui-tag/index.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@reown/appkit-ui/dist/esm/src/composites/wui-tag/styles.js":
-/*!*********************************************************************************!*\
-  !*** ./node_modules/@reown/appkit-ui/dist/esm/src/composites/wui-tag/styles.js ***!
-  \*********************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n\n/* harmony default export */ __webpack_exports__[\"default\"] = ((0,lit__WEBPACK_IMPORTED_MODULE_0__.css) `\n  :host {\n    display: flex;\n    justify-content: center;\n    align-items: center;\n    height: var(--wui-spacing-m);\n    padding: 0 var(--wui-spacing-3xs) !important;\n    border-radius: var(--wui-border-radius-5xs);\n    transition:\n      border-radius var(--wui-duration-lg) var(--wui-ease-out-power-1),\n      background-color var(--wui-duration-lg) var(--wui-ease-out-power-1);\n    will-change: border-radius, background-color;\n  }\n\n  :host > wui-text {\n    transform: translateY(5%);\n  }\n\n  :host([data-variant='main']) {\n    background-color: var(--wui-color-accent-glass-015);\n    color: var(--wui-color-accent-100);\n  }\n\n  :host([data-variant='shade']) {\n    background-color: var(--wui-color-gray-glass-010);\n    color: var(--wui-color-fg-200);\n  }\n\n  :host([data-variant='success']) {\n    background-color: var(--wui-icon-box-bg-success-100);\n    color: var(--wui-color-success-100);\n  }\n\n  :host([data-variant='error']) {\n    background-color: var(--wui-icon-box-bg-error-100);\n    color: var(--wui-color-error-100);\n  }\n\n  :host([data-size='lg']) {\n    padding: 11px 5px !important;\n  }\n\n  :host([data-size='lg']) > wui-text {\n    transform: translateY(2%);\n  }\n`);\n//# sourceMappingURL=styles.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvY29tcG9zaXRlcy93dWktdGFnL3N0eWxlcy5qcyIsIm1hcHBpbmdzIjoiOztBQUEwQjtBQUMxQiwrREFBZSx3Q0FBRztBQUNsQjtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7O0FBRUE7QUFDQTtBQUNBOztBQUVBO0FBQ0E7QUFDQTtBQUNBOztBQUVBO0FBQ0E7QUFDQTtBQUNBOztBQUVBO0FBQ0E7QUFDQTtBQUNBOztBQUVBO0FBQ0E7QUFDQTtBQUNBOztBQUVBO0FBQ0E7QUFDQTs7QUFFQTtBQUNBO0FBQ0E7QUFDQSxDQUFDLEVBQUM7QUFDRiIsInNvdXJjZXMiOlsid2VicGFjazovL19OX0UvLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvY29tcG9zaXRlcy93dWktdGFnL3N0eWxlcy5qcz85NjIyIl0sInNvdXJjZXNDb250ZW50IjpbImltcG9ydCB7IGNzcyB9IGZyb20gJ2xpdCc7XG5leHBvcnQgZGVmYXVsdCBjc3MgYFxuICA6aG9zdCB7XG4gICAgZGlzcGxheTogZmxleDtcbiAgICBqdXN0aWZ5LWNvbnRlbnQ6IGNlbnRlcjtcbiAgICBhbGlnbi1pdGVtczogY2VudGVyO1xuICAgIGhlaWdodDogdmFyKC0td3VpLXNwYWNpbmctbSk7XG4gICAgcGFkZGluZzogMCB2YXIoLS13dWktc3BhY2luZy0zeHMpICFpbXBvcnRhbnQ7XG4gICAgYm9yZGVyLXJhZGl1czogdmFyKC0td3VpLWJvcmRlci1yYWRpdXMtNXhzKTtcbiAgICB0cmFuc2l0aW9uOlxuICAgICAgYm9yZGVyLXJhZGl1cyB2YXIoLS13dWktZHVyYXRpb24tbGcpIHZhcigtLXd1aS1lYXNlLW91dC1wb3dlci0xKSxcbiAgICAgIGJhY2tncm91bmQtY29sb3IgdmFyKC0td3VpLWR1cmF0aW9uLWxnKSB2YXIoLS13dWktZWFzZS1vdXQtcG93ZXItMSk7XG4gICAgd2lsbC1jaGFuZ2U6IGJvcmRlci1yYWRpdXMsIGJhY2tncm91bmQtY29sb3I7XG4gIH1cblxuICA6aG9zdCA+IHd1aS10ZXh0IHtcbiAgICB0cmFuc2Zvcm06IHRyYW5zbGF0ZVkoNSUpO1xuICB9XG5cbiAgOmhvc3QoW2RhdGEtdmFyaWFudD0nbWFpbiddKSB7XG4gICAgYmFja2dyb3VuZC1jb2xvcjogdmFyKC0td3VpLWNvbG9yLWFjY2VudC1nbGFzcy0wMTUpO1xuICAgIGNvbG9yOiB2YXIoLS13dWktY29sb3ItYWNjZW50LTEwMCk7XG4gIH1cblxuICA6aG9zdChbZGF0YS12YXJpYW50PSdzaGFkZSddKSB7XG4gICAgYmFja2dyb3VuZC1jb2xvcjogdmFyKC0td3VpLWNvbG9yLWdyYXktZ2xhc3MtMDEwKTtcbiAgICBjb2xvcjogdmFyKC0td3VpLWNvbG9yLWZnLTIwMCk7XG4gIH1cblxuICA6aG9zdChbZGF0YS12YXJpYW50PSdzdWNjZXNzJ10pIHtcbiAgICBiYWNrZ3JvdW5kLWNvbG9yOiB2YXIoLS13dWktaWNvbi1ib3gtYmctc3VjY2Vzcy0xMDApO1xuICAgIGNvbG9yOiB2YXIoLS13dWktY29sb3Itc3VjY2Vzcy0xMDApO1xuICB9XG5cbiAgOmhvc3QoW2RhdGEtdmFyaWFudD0nZXJyb3InXSkge1xuICAgIGJhY2tncm91bmQtY29sb3I6IHZhcigtLXd1aS1pY29uLWJveC1iZy1lcnJvci0xMDApO1xuICAgIGNvbG9yOiB2YXIoLS13dWktY29sb3ItZXJyb3ItMTAwKTtcbiAgfVxuXG4gIDpob3N0KFtkYXRhLXNpemU9J2xnJ10pIHtcbiAgICBwYWRkaW5nOiAxMXB4IDVweCAhaW1wb3J0YW50O1xuICB9XG5cbiAgOmhvc3QoW2RhdGEtc2l6ZT0nbGcnXSkgPiB3dWktdGV4dCB7XG4gICAgdHJhbnNmb3JtOiB0cmFuc2xhdGVZKDIlKTtcbiAgfVxuYDtcbi8vIyBzb3VyY2VNYXBwaW5nVVJMPXN0eWxlcy5qcy5tYXAiXSwibmFtZXMiOltdLCJzb3VyY2VSb290IjoiIn0=\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-ui/dist/esm/src/composites/wui-tag/styles.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@reown/appkit-ui/dist/esm/src/layout/wui-flex/index.js":
-/*!*****************************************************************************!*\
-  !*** ./node_modules/@reown/appkit-ui/dist/esm/src/layout/wui-flex/index.js ***!
-  \*****************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   WuiFlex: function() { return /* binding */ WuiFlex; }\n/* harmony export */ });\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n/* harmony import */ var lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__ = __webpack_require__(/*! lit/decorators.js */ \"./node_modules/lit/decorators.js\");\n/* harmony import */ var _utils_ThemeUtil_js__WEBPACK_IMPORTED_MODULE_2__ = __webpack_require__(/*! ../../utils/ThemeUtil.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/utils/ThemeUtil.js\");\n/* harmony import */ var _utils_UiHelperUtil_js__WEBPACK_IMPORTED_MODULE_3__ = __webpack_require__(/*! ../../utils/UiHelperUtil.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/utils/UiHelperUtil.js\");\n/* harmony import */ var _utils_WebComponentsUtil_js__WEBPACK_IMPORTED_MODULE_4__ = __webpack_require__(/*! ../../utils/WebComponentsUtil.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/utils/WebComponentsUtil.js\");\n/* harmony import */ var _styles_js__WEBPACK_IMPORTED_MODULE_5__ = __webpack_require__(/*! ./styles.js */ \"./node_modules/@reown/appkit-ui/dist/esm/src/layout/wui-flex/styles.js\");\nvar __decorate = (undefined && undefined.__decorate) || function (decorators, target, key, desc) {\n    var c = arguments.length, r = c < 3 ? target : desc === null ? desc = Object.getOwnPropertyDescriptor(target, key) : desc, d;\n    if (typeof Reflect === \"object\" && typeof Reflect.decorate === \"function\") r = Reflect.decorate(decorators, target, key, desc);\n    else for (var i = decorators.length - 1; i >= 0; i--) if (d = decorators[i]) r = (c < 3 ? d(r) : c > 3 ? d(target, key, r) : d(target, key)) || r;\n    return c > 3 && r && Object.defineProperty(target, key, r), r;\n};\n\n\n\n\n\n\nlet WuiFlex = class WuiFlex extends lit__WEBPACK_IMPORTED_MODULE_0__.LitElement {\n    render() {\n        this.style.cssText = `\n      flex-direction: ${this.flexDirection};\n      flex-wrap: ${this.flexWrap};\n      flex-basis: ${this.flexBasis};\n      flex-grow: ${this.flexGrow};\n      flex-shrink: ${this.flexShrink};\n      align-items: ${this.alignItems};\n      justify-content: ${this.justifyContent};\n      column-gap: ${this.columnGap && `var(--wui-spacing-${this.columnGap})`};\n      row-gap: ${this.rowGap && `var(--wui-spacing-${this.rowGap})`};\n      gap: ${this.gap && `var(--wui-spacing-${this.gap})`};\n      padding-top: ${this.padding && _utils_UiHelperUtil_js__WEBPACK_IMPORTED_MODULE_3__.UiHelperUtil.getSpacingStyles(this.padding, 0)};\n      padding-right: ${this.padding && _utils_UiHelperUtil_js__WEBPACK_IMPORTED_MODULE_3__.UiHelperUtil.getSpacingStyles(this.padding, 1)};\n      padding-bottom: ${this.padding && _utils_UiHelperUtil_js__WEBPACK_IMPORTED_MODULE_3__.UiHelperUtil.getSpacingStyles(this.padding, 2)};\n      padding-left: ${this.padding && _utils_UiHelperUtil_js__WEBPACK_IMPORTED_MODULE_3__.UiHelperUtil.getSpacingStyles(this.padding, 3)};\n      margin-top: ${this.margin && _utils_UiHelperUtil_js__WEBPACK_IMPORTED_MODULE_3__.UiHelperUtil.getSpacingStyles(this.margin, 0)};\n      margin-right: ${this.margin && _utils_UiHelperUtil_js__WEBPACK_IMPORTED_MODULE_3__.UiHelperUtil.getSpacingStyles(this.margin, 1)};\n      margin-bottom: ${this.margin && _utils_UiHelperUtil_js__WEBPACK_IMPORTED_MODULE_3__.UiHelperUtil.getSpacingStyles(this.margin, 2)};\n      margin-left: ${this.margin && _utils_UiHelperUtil_js__WEBPACK_IMPORTED_MODULE_3__.UiHelperUtil.getSpacingStyles(this.margin, 3)};\n    `;\n        return (0,lit__WEBPACK_IMPORTED_MODULE_0__.html) `<slot></slot>`;\n    }\n};\nWuiFlex.styles = [_utils_ThemeUtil_js__WEBPACK_IMPORTED_MODULE_2__.resetStyles, _styles_js__WEBPACK_IMPORTED_MODULE_5__[\"default\"]];\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.property)()\n], WuiFlex.prototype, \"flexDirection\", void 0);\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.property)()\n], WuiFlex.prototype, \"flexWrap\", void 0);\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.property)()\n], WuiFlex.prototype, \"flexBasis\", void 0);\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.property)()\n], WuiFlex.prototype, \"flexGrow\", void 0);\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.property)()\n], WuiFlex.prototype, \"flexShrink\", void 0);\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.property)()\n], WuiFlex.prototype, \"alignItems\", void 0);\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.property)()\n], WuiFlex.prototype, \"justifyContent\", void 0);\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.property)()\n], WuiFlex.prototype, \"columnGap\", void 0);\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.property)()\n], WuiFlex.prototype, \"rowGap\", void 0);\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.property)()\n], WuiFlex.prototype, \"gap\", void 0);\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.property)()\n], WuiFlex.prototype, \"padding\", void 0);\n__decorate([\n    (0,lit_decorators_js__WEBPACK_IMPORTED_MODULE_1__.property)()\n], WuiFlex.prototype, \"margin\", void 0);\nWuiFlex = __decorate([\n    (0,_utils_WebComponentsUtil_js__WEBPACK_IMPORTED_MODULE_4__.customElement)('wui-flex')\n], WuiFlex);\n\n//# sourceMappingURL=index.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvbGF5b3V0L3d1aS1mbGV4L2luZGV4LmpzIiwibWFwcGluZ3MiOiI7Ozs7Ozs7Ozs7QUFBQSxrQkFBa0IsU0FBSSxJQUFJLFNBQUk7QUFDOUI7QUFDQTtBQUNBLDZDQUE2QyxRQUFRO0FBQ3JEO0FBQ0E7QUFDdUM7QUFDTTtBQUNVO0FBQ0k7QUFDTTtBQUNoQztBQUNqQyxvQ0FBb0MsMkNBQVU7QUFDOUM7QUFDQTtBQUNBLHdCQUF3QjtBQUN4QixtQkFBbUI7QUFDbkIsb0JBQW9CO0FBQ3BCLG1CQUFtQjtBQUNuQixxQkFBcUI7QUFDckIscUJBQXFCO0FBQ3JCLHlCQUF5QjtBQUN6QixvQkFBb0IsdUNBQXVDLGVBQWU7QUFDMUUsaUJBQWlCLG9DQUFvQyxZQUFZO0FBQ2pFLGFBQWEsaUNBQWlDLFNBQVM7QUFDdkQscUJBQXFCLGdCQUFnQixnRUFBWTtBQUNqRCx1QkFBdUIsZ0JBQWdCLGdFQUFZO0FBQ25ELHdCQUF3QixnQkFBZ0IsZ0VBQVk7QUFDcEQsc0JBQXNCLGdCQUFnQixnRUFBWTtBQUNsRCxvQkFBb0IsZUFBZSxnRUFBWTtBQUMvQyxzQkFBc0IsZUFBZSxnRUFBWTtBQUNqRCx1QkFBdUIsZUFBZSxnRUFBWTtBQUNsRCxxQkFBcUIsZUFBZSxnRUFBWTtBQUNoRDtBQUNBLGVBQWUseUNBQUk7QUFDbkI7QUFDQTtBQUNBLGtCQUFrQiw0REFBVyxFQUFFLGtEQUFNO0FBQ3JDO0FBQ0EsSUFBSSwyREFBUTtBQUNaO0FBQ0E7QUFDQSxJQUFJLDJEQUFRO0FBQ1o7QUFDQTtBQUNBLElBQUksMkRBQVE7QUFDWjtBQUNBO0FBQ0EsSUFBSSwyREFBUTtBQUNaO0FBQ0E7QUFDQSxJQUFJLDJEQUFRO0FBQ1o7QUFDQTtBQUNBLElBQUksMkRBQVE7QUFDWjtBQUNBO0FBQ0EsSUFBSSwyREFBUTtBQUNaO0FBQ0E7QUFDQSxJQUFJLDJEQUFRO0FBQ1o7QUFDQTtBQUNBLElBQUksMkRBQVE7QUFDWjtBQUNBO0FBQ0EsSUFBSSwyREFBUTtBQUNaO0FBQ0E7QUFDQSxJQUFJLDJEQUFRO0FBQ1o7QUFDQTtBQUNBLElBQUksMkRBQVE7QUFDWjtBQUNBO0FBQ0EsSUFBSSwwRUFBYTtBQUNqQjtBQUNtQjtBQUNuQiIsInNvdXJjZXMiOlsid2VicGFjazovL19OX0UvLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvbGF5b3V0L3d1aS1mbGV4L2luZGV4LmpzP2U3MTYiXSwic291cmNlc0NvbnRlbnQiOlsidmFyIF9fZGVjb3JhdGUgPSAodGhpcyAmJiB0aGlzLl9fZGVjb3JhdGUpIHx8IGZ1bmN0aW9uIChkZWNvcmF0b3JzLCB0YXJnZXQsIGtleSwgZGVzYykge1xuICAgIHZhciBjID0gYXJndW1lbnRzLmxlbmd0aCwgciA9IGMgPCAzID8gdGFyZ2V0IDogZGVzYyA9PT0gbnVsbCA/IGRlc2MgPSBPYmplY3QuZ2V0T3duUHJvcGVydHlEZXNjcmlwdG9yKHRhcmdldCwga2V5KSA6IGRlc2MsIGQ7XG4gICAgaWYgKHR5cGVvZiBSZWZsZWN0ID09PSBcIm9iamVjdFwiICYmIHR5cGVvZiBSZWZsZWN0LmRlY29yYXRlID09PSBcImZ1bmN0aW9uXCIpIHIgPSBSZWZsZWN0LmRlY29yYXRlKGRlY29yYXRvcnMsIHRhcmdldCwga2V5LCBkZXNjKTtcbiAgICBlbHNlIGZvciAodmFyIGkgPSBkZWNvcmF0b3JzLmxlbmd0aCAtIDE7IGkgPj0gMDsgaS0tKSBpZiAoZCA9IGRlY29yYXRvcnNbaV0pIHIgPSAoYyA8IDMgPyBkKHIpIDogYyA+IDMgPyBkKHRhcmdldCwga2V5LCByKSA6IGQodGFyZ2V0LCBrZXkpKSB8fCByO1xuICAgIHJldHVybiBjID4gMyAmJiByICYmIE9iamVjdC5kZWZpbmVQcm9wZXJ0eSh0YXJnZXQsIGtleSwgciksIHI7XG59O1xuaW1wb3J0IHsgTGl0RWxlbWVudCwgaHRtbCB9IGZyb20gJ2xpdCc7XG5pbXBvcnQgeyBwcm9wZXJ0eSB9IGZyb20gJ2xpdC9kZWNvcmF0b3JzLmpzJztcbmltcG9ydCB7IHJlc2V0U3R5bGVzIH0gZnJvbSAnLi4vLi4vdXRpbHMvVGhlbWVVdGlsLmpzJztcbmltcG9ydCB7IFVpSGVscGVyVXRpbCB9IGZyb20gJy4uLy4uL3V0aWxzL1VpSGVscGVyVXRpbC5qcyc7XG5pbXBvcnQgeyBjdXN0b21FbGVtZW50IH0gZnJvbSAnLi4vLi4vdXRpbHMvV2ViQ29tcG9uZW50c1V0aWwuanMnO1xuaW1wb3J0IHN0eWxlcyBmcm9tICcuL3N0eWxlcy5qcyc7XG5sZXQgV3VpRmxleCA9IGNsYXNzIFd1aUZsZXggZXh0ZW5kcyBMaXRFbGVtZW50IHtcbiAgICByZW5kZXIoKSB7XG4gICAgICAgIHRoaXMuc3R5bGUuY3NzVGV4dCA9IGBcbiAgICAgIGZsZXgtZGlyZWN0aW9uOiAke3RoaXMuZmxleERpcmVjdGlvbn07XG4gICAgICBmbGV4LXdyYXA6ICR7dGhpcy5mbGV4V3JhcH07XG4gICAgICBmbGV4LWJhc2lzOiAke3RoaXMuZmxleEJhc2lzfTtcbiAgICAgIGZsZXgtZ3JvdzogJHt0aGlzLmZsZXhHcm93fTtcbiAgICAgIGZsZXgtc2hyaW5rOiAke3RoaXMuZmxleFNocmlua307XG4gICAgICBhbGlnbi1pdGVtczogJHt0aGlzLmFsaWduSXRlbXN9O1xuICAgICAganVzdGlmeS1jb250ZW50OiAke3RoaXMuanVzdGlmeUNvbnRlbnR9O1xuICAgICAgY29sdW1uLWdhcDogJHt0aGlzLmNvbHVtbkdhcCAmJiBgdmFyKC0td3VpLXNwYWNpbmctJHt0aGlzLmNvbHVtbkdhcH0pYH07XG4gICAgICByb3ctZ2FwOiAke3RoaXMucm93R2FwICYmIGB2YXIoLS13dWktc3BhY2luZy0ke3RoaXMucm93R2FwfSlgfTtcbiAgICAgIGdhcDogJHt0aGlzLmdhcCAmJiBgdmFyKC0td3VpLXNwYWNpbmctJHt0aGlzLmdhcH0pYH07XG4gICAgICBwYWRkaW5nLXRvcDogJHt0aGlzLnBhZGRpbmcgJiYgVWlIZWxwZXJVdGlsLmdldFNwYWNpbmdTdHlsZXModGhpcy5wYWRkaW5nLCAwKX07XG4gICAgICBwYWRkaW5nLXJpZ2h0OiAke3RoaXMucGFkZGluZyAmJiBVaUhlbHBlclV0aWwuZ2V0U3BhY2luZ1N0eWxlcyh0aGlzLnBhZGRpbmcsIDEpfTtcbiAgICAgIHBhZGRpbmctYm90dG9tOiAke3RoaXMucGFkZGluZyAmJiBVaUhlbHBlclV0aWwuZ2V0U3BhY2luZ1N0eWxlcyh0aGlzLnBhZGRpbmcsIDIpfTtcbiAgICAgIHBhZGRpbmctbGVmdDogJHt0aGlzLnBhZGRpbmcgJiYgVWlIZWxwZXJVdGlsLmdldFNwYWNpbmdTdHlsZXModGhpcy5wYWRkaW5nLCAzKX07XG4gICAgICBtYXJnaW4tdG9wOiAke3RoaXMubWFyZ2luICYmIFVpSGVscGVyVXRpbC5nZXRTcGFjaW5nU3R5bGVzKHRoaXMubWFyZ2luLCAwKX07XG4gICAgICBtYXJnaW4tcmlnaHQ6ICR7dGhpcy5tYXJnaW4gJiYgVWlIZWxwZXJVdGlsLmdldFNwYWNpbmdTdHlsZXModGhpcy5tYXJnaW4sIDEpfTtcbiAgICAgIG1hcmdpbi1ib3R0b206ICR7dGhpcy5tYXJnaW4gJiYgVWlIZWxwZXJVdGlsLmdldFNwYWNpbmdTdHlsZXModGhpcy5tYXJnaW4sIDIpfTtcbiAgICAgIG1hcmdpbi1sZWZ0OiAke3RoaXMubWFyZ2luICYmIFVpSGVscGVyVXRpbC5nZXRTcGFjaW5nU3R5bGVzKHRoaXMubWFyZ2luLCAzKX07XG4gICAgYDtcbiAgICAgICAgcmV0dXJuIGh0bWwgYDxzbG90Pjwvc2xvdD5gO1xuICAgIH1cbn07XG5XdWlGbGV4LnN0eWxlcyA9IFtyZXNldFN0eWxlcywgc3R5bGVzXTtcbl9fZGVjb3JhdGUoW1xuICAgIHByb3BlcnR5KClcbl0sIFd1aUZsZXgucHJvdG90eXBlLCBcImZsZXhEaXJlY3Rpb25cIiwgdm9pZCAwKTtcbl9fZGVjb3JhdGUoW1xuICAgIHByb3BlcnR5KClcbl0sIFd1aUZsZXgucHJvdG90eXBlLCBcImZsZXhXcmFwXCIsIHZvaWQgMCk7XG5fX2RlY29yYXRlKFtcbiAgICBwcm9wZXJ0eSgpXG5dLCBXdWlGbGV4LnByb3RvdHlwZSwgXCJmbGV4QmFzaXNcIiwgdm9pZCAwKTtcbl9fZGVjb3JhdGUoW1xuICAgIHByb3BlcnR5KClcbl0sIFd1aUZsZXgucHJvdG90eXBlLCBcImZsZXhHcm93XCIsIHZvaWQgMCk7XG5fX2RlY29yYXRlKFtcbiAgICBwcm9wZXJ0eSgpXG5dLCBXdWlGbGV4LnByb3RvdHlwZSwgXCJmbGV4U2hyaW5rXCIsIHZvaWQgMCk7XG5fX2RlY29yYXRlKFtcbiAgICBwcm9wZXJ0eSgpXG5dLCBXdWlGbGV4LnByb3RvdHlwZSwgXCJhbGlnbkl0ZW1zXCIsIHZvaWQgMCk7XG5fX2RlY29yYXRlKFtcbiAgICBwcm9wZXJ0eSgpXG5dLCBXdWlGbGV4LnByb3RvdHlwZSwgXCJqdXN0aWZ5Q29udGVudFwiLCB2b2lkIDApO1xuX19kZWNvcmF0ZShbXG4gICAgcHJvcGVydHkoKVxuXSwgV3VpRmxleC5wcm90b3R5cGUsIFwiY29sdW1uR2FwXCIsIHZvaWQgMCk7XG5fX2RlY29yYXRlKFtcbiAgICBwcm9wZXJ0eSgpXG5dLCBXdWlGbGV4LnByb3RvdHlwZSwgXCJyb3dHYXBcIiwgdm9pZCAwKTtcbl9fZGVjb3JhdGUoW1xuICAgIHByb3BlcnR5KClcbl0sIFd1aUZsZXgucHJvdG90eXBlLCBcImdhcFwiLCB2b2lkIDApO1xuX19kZWNvcmF0ZShbXG4gICAgcHJvcGVydHkoKVxuXSwgV3VpRmxleC5wcm90b3R5cGUsIFwicGFkZGluZ1wiLCB2b2lkIDApO1xuX19kZWNvcmF0ZShbXG4gICAgcHJvcGVydHkoKVxuXSwgV3VpRmxleC5wcm90b3R5cGUsIFwibWFyZ2luXCIsIHZvaWQgMCk7XG5XdWlGbGV4ID0gX19kZWNvcmF0ZShbXG4gICAgY3VzdG9tRWxlbWVudCgnd3VpLWZsZXgnKVxuXSwgV3VpRmxleCk7XG5leHBvcnQgeyBXdWlGbGV4IH07XG4vLyMgc291cmNlTWFwcGluZ1VSTD1pbmRleC5qcy5tYXAiXSwibmFtZXMiOltdLCJzb3VyY2VSb290IjoiIn0=\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-ui/dist/esm/src/layout/wui-flex/index.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@reown/appkit-ui/dist/esm/src/layout/wui-flex/styles.js":
-/*!******************************************************************************!*\
-  !*** ./node_modules/@reown/appkit-ui/dist/esm/src/layout/wui-flex/styles.js ***!
-  \******************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n\n/* harmony default export */ __webpack_exports__[\"default\"] = ((0,lit__WEBPACK_IMPORTED_MODULE_0__.css) `\n  :host {\n    display: flex;\n    width: inherit;\n    height: inherit;\n  }\n`);\n//# sourceMappingURL=styles.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvbGF5b3V0L3d1aS1mbGV4L3N0eWxlcy5qcyIsIm1hcHBpbmdzIjoiOztBQUEwQjtBQUMxQiwrREFBZSx3Q0FBRztBQUNsQjtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0EsQ0FBQyxFQUFDO0FBQ0YiLCJzb3VyY2VzIjpbIndlYnBhY2s6Ly9fTl9FLy4vbm9kZV9tb2R1bGVzL0ByZW93bi9hcHBraXQtdWkvZGlzdC9lc20vc3JjL2xheW91dC93dWktZmxleC9zdHlsZXMuanM/ZGFhZCJdLCJzb3VyY2VzQ29udGVudCI6WyJpbXBvcnQgeyBjc3MgfSBmcm9tICdsaXQnO1xuZXhwb3J0IGRlZmF1bHQgY3NzIGBcbiAgOmhvc3Qge1xuICAgIGRpc3BsYXk6IGZsZXg7XG4gICAgd2lkdGg6IGluaGVyaXQ7XG4gICAgaGVpZ2h0OiBpbmhlcml0O1xuICB9XG5gO1xuLy8jIHNvdXJjZU1hcHBpbmdVUkw9c3R5bGVzLmpzLm1hcCJdLCJuYW1lcyI6W10sInNvdXJjZVJvb3QiOiIifQ==\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-ui/dist/esm/src/layout/wui-flex/styles.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/@reown/appkit-ui/dist/esm/src/utils/CacheUtil.js":
-/*!***********************************************************************!*\
-  !*** ./node_modules/@reown/appkit-ui/dist/esm/src/utils/CacheUtil.js ***!
-  \***********************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   CacheUtil: function() { return /* binding */ CacheUtil; },\n/* harmony export */   globalSvgCache: function() { return /* binding */ globalSvgCache; }\n/* harmony export */ });\nclass CacheUtil {\n    constructor() {\n        this.cache = new Map();\n    }\n    set(key, value) {\n        this.cache.set(key, value);\n    }\n    get(key) {\n        return this.cache.get(key);\n    }\n    has(key) {\n        return this.cache.has(key);\n    }\n    delete(key) {\n        this.cache.delete(key);\n    }\n    clear() {\n        this.cache.clear();\n    }\n}\nconst globalSvgCache = new CacheUtil();\n//# sourceMappingURL=CacheUtil.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvdXRpbHMvQ2FjaGVVdGlsLmpzIiwibWFwcGluZ3MiOiI7Ozs7O0FBQU87QUFDUDtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNPO0FBQ1AiLCJzb3VyY2VzIjpbIndlYnBhY2s6Ly9fTl9FLy4vbm9kZV9tb2R1bGVzL0ByZW93bi9hcHBraXQtdWkvZGlzdC9lc20vc3JjL3V0aWxzL0NhY2hlVXRpbC5qcz80Mzk2Il0sInNvdXJjZXNDb250ZW50IjpbImV4cG9ydCBjbGFzcyBDYWNoZVV0aWwge1xuICAgIGNvbnN0cnVjdG9yKCkge1xuICAgICAgICB0aGlzLmNhY2hlID0gbmV3IE1hcCgpO1xuICAgIH1cbiAgICBzZXQoa2V5LCB2YWx1ZSkge1xuICAgICAgICB0aGlzLmNhY2hlLnNldChrZXksIHZhbHVlKTtcbiAgICB9XG4gICAgZ2V0KGtleSkge1xuICAgICAgICByZXR1cm4gdGhpcy5jYWNoZS5nZXQoa2V5KTtcbiAgICB9XG4gICAgaGFzKGtleSkge1xuICAgICAgICByZXR1cm4gdGhpcy5jYWNoZS5oYXMoa2V5KTtcbiAgICB9XG4gICAgZGVsZXRlKGtleSkge1xuICAgICAgICB0aGlzLmNhY2hlLmRlbGV0ZShrZXkpO1xuICAgIH1cbiAgICBjbGVhcigpIHtcbiAgICAgICAgdGhpcy5jYWNoZS5jbGVhcigpO1xuICAgIH1cbn1cbmV4cG9ydCBjb25zdCBnbG9iYWxTdmdDYWNoZSA9IG5ldyBDYWNoZVV0aWwoKTtcbi8vIyBzb3VyY2VNYXBwaW5nVVJMPUNhY2hlVXRpbC5qcy5tYXAiXSwibmFtZXMiOltdLCJzb3VyY2VSb290IjoiIn0=\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-ui/dist/esm/src/utils/CacheUtil.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/lit-html/development/async-directive.js":
-/*!**************************************************************!*\
-  !*** ./node_modules/lit-html/development/async-directive.js ***!
-  \**************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   AsyncDirective: function() { return /* binding */ AsyncDirective; }\n/* harmony export */ });\n/* harmony import */ var _directive_helpers_js__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! ./directive-helpers.js */ \"./node_modules/lit-html/development/directive-helpers.js\");\n/* harmony import */ var _directive_js__WEBPACK_IMPORTED_MODULE_1__ = __webpack_require__(/*! ./directive.js */ \"./node_modules/lit-html/development/directive.js\");\n/* harmony reexport (unknown) */ var __WEBPACK_REEXPORT_OBJECT__ = {};\n/* harmony reexport (unknown) */ for(var __WEBPACK_IMPORT_KEY__ in _directive_js__WEBPACK_IMPORTED_MODULE_1__) if([\"default\",\"AsyncDirective\"].indexOf(__WEBPACK_IMPORT_KEY__) < 0) __WEBPACK_REEXPORT_OBJECT__[__WEBPACK_IMPORT_KEY__] = function(key) { return _directive_js__WEBPACK_IMPORTED_MODULE_1__[key]; }.bind(0, __WEBPACK_IMPORT_KEY__)\n/* harmony reexport (unknown) */ __webpack_require__.d(__webpack_exports__, __WEBPACK_REEXPORT_OBJECT__);\n/**\n * @license\n * Copyright 2017 Google LLC\n * SPDX-License-Identifier: BSD-3-Clause\n */\n\n\n\nconst DEV_MODE = true;\n/**\n * Recursively walks down the tree of Parts/TemplateInstances/Directives to set\n * the connected state of directives and run `disconnected`/ `reconnected`\n * callbacks.\n *\n * @return True if there were children to disconnect; false otherwise\n */\nconst notifyChildrenConnectedChanged = (parent, isConnected) => {\n    const children = parent._$disconnectableChildren;\n    if (children === undefined) {\n        return false;\n    }\n    for (const obj of children) {\n        // The existence of `_$notifyDirectiveConnectionChanged` is used as a \"brand\" to\n        // disambiguate AsyncDirectives from other DisconnectableChildren\n        // (as opposed to using an instanceof check to know when to call it); the\n        // redundancy of \"Directive\" in the API name is to avoid conflicting with\n        // `_$notifyConnectionChanged`, which exists `ChildParts` which are also in\n        // this list\n        // Disconnect Directive (and any nested directives contained within)\n        // This property needs to remain unminified.\n        obj['_$notifyDirectiveConnectionChanged']?.(isConnected, false);\n        // Disconnect Part/TemplateInstance\n        notifyChildrenConnectedChanged(obj, isConnected);\n    }\n    return true;\n};\n/**\n * Removes the given child from its parent list of disconnectable children, and\n * if the parent list becomes empty as a result, removes the parent from its\n * parent, and so forth up the tree when that causes subsequent parent lists to\n * become empty.\n */\nconst removeDisconnectableFromParent = (obj) => {\n    let parent, children;\n    do {\n        if ((parent = obj._$parent) === undefined) {\n            break;\n        }\n        children = parent._$disconnectableChildren;\n        children.delete(obj);\n        obj = parent;\n    } while (children?.size === 0);\n};\nconst addDisconnectableToParent = (obj) => {\n    // Climb the parent tree, creating a sparse tree of children needing\n    // disconnection\n    for (let parent; (parent = obj._$parent); obj = parent) {\n        let children = parent._$disconnectableChildren;\n        if (children === undefined) {\n            parent._$disconnectableChildren = children = new Set();\n        }\n        else if (children.has(obj)) {\n            // Once we've reached a parent that already contains this child, we\n            // can short-circuit\n            break;\n        }\n        children.add(obj);\n        installDisconnectAPI(parent);\n    }\n};\n/**\n * Changes the parent reference of the ChildPart, and updates the sparse tree of\n * Disconnectable children accordingly.\n *\n * Note, this method will be patched onto ChildPart instances and called from\n * the core code when parts are moved between different parents.\n */\nfunction reparentDisconnectables(newParent) {\n    if (this._$disconnectableChildren !== undefined) {\n        removeDisconnectableFromParent(this);\n        this._$parent = newParent;\n        addDisconnectableToParent(this);\n    }\n    else {\n        this._$parent = newParent;\n    }\n}\n/**\n * Sets the connected state on any directives contained within the committed\n * value of this part (i.e. within a TemplateInstance or iterable of\n * ChildParts) and runs their `disconnected`/`reconnected`s, as well as within\n * any directives stored on the ChildPart (when `valueOnly` is false).\n *\n * `isClearingValue` should be passed as `true` on a top-level part that is\n * clearing itself, and not as a result of recursively disconnecting directives\n * as part of a `clear` operation higher up the tree. This both ensures that any\n * directive on this ChildPart that produced a value that caused the clear\n * operation is not disconnected, and also serves as a performance optimization\n * to avoid needless bookkeeping when a subtree is going away; when clearing a\n * subtree, only the top-most part need to remove itself from the parent.\n *\n * `fromPartIndex` is passed only in the case of a partial `_clear` running as a\n * result of truncating an iterable.\n *\n * Note, this method will be patched onto ChildPart instances and called from the\n * core code when parts are cleared or the connection state is changed by the\n * user.\n */\nfunction notifyChildPartConnectedChanged(isConnected, isClearingValue = false, fromPartIndex = 0) {\n    const value = this._$committedValue;\n    const children = this._$disconnectableChildren;\n    if (children === undefined || children.size === 0) {\n        return;\n    }\n    if (isClearingValue) {\n        if (Array.isArray(value)) {\n            // Iterable case: Any ChildParts created by the iterable should be\n            // disconnected and removed from this ChildPart's disconnectable\n            // children (starting at `fromPartIndex` in the case of truncation)\n            for (let i = fromPartIndex; i < value.length; i++) {\n                notifyChildrenConnectedChanged(value[i], false);\n                removeDisconnectableFromParent(value[i]);\n            }\n        }\n        else if (value != null) {\n            // TemplateInstance case: If the value has disconnectable children (will\n            // only be in the case that it is a TemplateInstance), we disconnect it\n            // and remove it from this ChildPart's disconnectable children\n            notifyChildrenConnectedChanged(value, false);\n            removeDisconnectableFromParent(value);\n        }\n    }\n    else {\n        notifyChildrenConnectedChanged(this, isConnected);\n    }\n}\n/**\n * Patches disconnection API onto ChildParts.\n */\nconst installDisconnectAPI = (obj) => {\n    if (obj.type == _directive_js__WEBPACK_IMPORTED_MODULE_1__.PartType.CHILD) {\n        obj._$notifyConnectionChanged ??=\n            notifyChildPartConnectedChanged;\n        obj._$reparentDisconnectables ??= reparentDisconnectables;\n    }\n};\n/**\n * An abstract `Directive` base class whose `disconnected` method will be\n * called when the part containing the directive is cleared as a result of\n * re-rendering, or when the user calls `part.setConnected(false)` on\n * a part that was previously rendered containing the directive (as happens\n * when e.g. a LitElement disconnects from the DOM).\n *\n * If `part.setConnected(true)` is subsequently called on a\n * containing part, the directive's `reconnected` method will be called prior\n * to its next `update`/`render` callbacks. When implementing `disconnected`,\n * `reconnected` should also be implemented to be compatible with reconnection.\n *\n * Note that updates may occur while the directive is disconnected. As such,\n * directives should generally check the `this.isConnected` flag during\n * render/update to determine whether it is safe to subscribe to resources\n * that may prevent garbage collection.\n */\nclass AsyncDirective extends _directive_js__WEBPACK_IMPORTED_MODULE_1__.Directive {\n    constructor() {\n        super(...arguments);\n        // @internal\n        this._$disconnectableChildren = undefined;\n    }\n    /**\n     * Initialize the part with internal fields\n     * @param part\n     * @param parent\n     * @param attributeIndex\n     */\n    _$initialize(part, parent, attributeIndex) {\n        super._$initialize(part, parent, attributeIndex);\n        addDisconnectableToParent(this);\n        this.isConnected = part._$isConnected;\n    }\n    // This property needs to remain unminified.\n    /**\n     * Called from the core code when a directive is going away from a part (in\n     * which case `shouldRemoveFromParent` should be true), and from the\n     * `setChildrenConnected` helper function when recursively changing the\n     * connection state of a tree (in which case `shouldRemoveFromParent` should\n     * be false).\n     *\n     * @param isConnected\n     * @param isClearingDirective - True when the directive itself is being\n     *     removed; false when the tree is being disconnected\n     * @internal\n     */\n    ['_$notifyDirectiveConnectionChanged'](isConnected, isClearingDirective = true) {\n        if (isConnected !== this.isConnected) {\n            this.isConnected = isConnected;\n            if (isConnected) {\n                this.reconnected?.();\n            }\n            else {\n                this.disconnected?.();\n            }\n        }\n        if (isClearingDirective) {\n            notifyChildrenConnectedChanged(this, isConnected);\n            removeDisconnectableFromParent(this);\n        }\n    }\n    /**\n     * Sets the value of the directive's Part outside the normal `update`/`render`\n     * lifecycle of a directive.\n     *\n     * This method should not be called synchronously from a directive's `update`\n     * or `render`.\n     *\n     * @param directive The directive to update\n     * @param value The value to set\n     */\n    setValue(value) {\n        if ((0,_directive_helpers_js__WEBPACK_IMPORTED_MODULE_0__.isSingleExpression)(this.__part)) {\n            this.__part._$setValue(value, this);\n        }\n        else {\n            // this.__attributeIndex will be defined in this case, but\n            // assert it in dev mode\n            if (DEV_MODE && this.__attributeIndex === undefined) {\n                throw new Error(`Expected this.__attributeIndex to be a number`);\n            }\n            const newValues = [...this.__part._$committedValue];\n            newValues[this.__attributeIndex] = value;\n            this.__part._$setValue(newValues, this, 0);\n        }\n    }\n    /**\n     * User callbacks for implementing logic to release any resources/subscriptions\n     * that may have been retained by this directive. Since directives may also be\n     * re-connected, `reconnected` should also be implemented to restore the\n     * working state of the directive prior to the next render.\n     */\n    disconnected() { }\n    reconnected() { }\n}\n//# sourceMappingURL=async-directive.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvbGl0LWh0bWwvZGV2ZWxvcG1lbnQvYXN5bmMtZGlyZWN0aXZlLmpzIiwibWFwcGluZ3MiOiI7Ozs7Ozs7OztBQUFBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDNEQ7QUFDUDtBQUN0QjtBQUMvQjtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQSxzREFBc0Q7QUFDdEQ7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0EsOEVBQThFO0FBQzlFO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQSxNQUFNO0FBQ047QUFDQTtBQUNBO0FBQ0E7QUFDQSxxQkFBcUIseUJBQXlCO0FBQzlDO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQSwrREFBK0Q7QUFDL0Q7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBLHdDQUF3QyxrQkFBa0I7QUFDMUQ7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBLG9CQUFvQixtREFBUTtBQUM1QjtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNPLDZCQUE2QixvREFBUztBQUM3QztBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0Esb0JBQW9CO0FBQ3BCO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0EsWUFBWSx5RUFBa0I7QUFDOUI7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQSIsInNvdXJjZXMiOlsid2VicGFjazovL19OX0UvLi9ub2RlX21vZHVsZXMvbGl0LWh0bWwvZGV2ZWxvcG1lbnQvYXN5bmMtZGlyZWN0aXZlLmpzPzdiN2IiXSwic291cmNlc0NvbnRlbnQiOlsiLyoqXG4gKiBAbGljZW5zZVxuICogQ29weXJpZ2h0IDIwMTcgR29vZ2xlIExMQ1xuICogU1BEWC1MaWNlbnNlLUlkZW50aWZpZXI6IEJTRC0zLUNsYXVzZVxuICovXG5pbXBvcnQgeyBpc1NpbmdsZUV4cHJlc3Npb24gfSBmcm9tICcuL2RpcmVjdGl2ZS1oZWxwZXJzLmpzJztcbmltcG9ydCB7IERpcmVjdGl2ZSwgUGFydFR5cGUgfSBmcm9tICcuL2RpcmVjdGl2ZS5qcyc7XG5leHBvcnQgKiBmcm9tICcuL2RpcmVjdGl2ZS5qcyc7XG5jb25zdCBERVZfTU9ERSA9IHRydWU7XG4vKipcbiAqIFJlY3Vyc2l2ZWx5IHdhbGtzIGRvd24gdGhlIHRyZWUgb2YgUGFydHMvVGVtcGxhdGVJbnN0YW5jZXMvRGlyZWN0aXZlcyB0byBzZXRcbiAqIHRoZSBjb25uZWN0ZWQgc3RhdGUgb2YgZGlyZWN0aXZlcyBhbmQgcnVuIGBkaXNjb25uZWN0ZWRgLyBgcmVjb25uZWN0ZWRgXG4gKiBjYWxsYmFja3MuXG4gKlxuICogQHJldHVybiBUcnVlIGlmIHRoZXJlIHdlcmUgY2hpbGRyZW4gdG8gZGlzY29ubmVjdDsgZmFsc2Ugb3RoZXJ3aXNlXG4gKi9cbmNvbnN0IG5vdGlmeUNoaWxkcmVuQ29ubmVjdGVkQ2hhbmdlZCA9IChwYXJlbnQsIGlzQ29ubmVjdGVkKSA9PiB7XG4gICAgY29uc3QgY2hpbGRyZW4gPSBwYXJlbnQuXyRkaXNjb25uZWN0YWJsZUNoaWxkcmVuO1xuICAgIGlmIChjaGlsZHJlbiA9PT0gdW5kZWZpbmVkKSB7XG4gICAgICAgIHJldHVybiBmYWxzZTtcbiAgICB9XG4gICAgZm9yIChjb25zdCBvYmogb2YgY2hpbGRyZW4pIHtcbiAgICAgICAgLy8gVGhlIGV4aXN0ZW5jZSBvZiBgXyRub3RpZnlEaXJlY3RpdmVDb25uZWN0aW9uQ2hhbmdlZGAgaXMgdXNlZCBhcyBhIFwiYnJhbmRcIiB0b1xuICAgICAgICAvLyBkaXNhbWJpZ3VhdGUgQXN5bmNEaXJlY3RpdmVzIGZyb20gb3RoZXIgRGlzY29ubmVjdGFibGVDaGlsZHJlblxuICAgICAgICAvLyAoYXMgb3Bwb3NlZCB0byB1c2luZyBhbiBpbnN0YW5jZW9mIGNoZWNrIHRvIGtub3cgd2hlbiB0byBjYWxsIGl0KTsgdGhlXG4gICAgICAgIC8vIHJlZHVuZGFuY3kgb2YgXCJEaXJlY3RpdmVcIiBpbiB0aGUgQVBJIG5hbWUgaXMgdG8gYXZvaWQgY29uZmxpY3Rpbmcgd2l0aFxuICAgICAgICAvLyBgXyRub3RpZnlDb25uZWN0aW9uQ2hhbmdlZGAsIHdoaWNoIGV4aXN0cyBgQ2hpbGRQYXJ0c2Agd2hpY2ggYXJlIGFsc28gaW5cbiAgICAgICAgLy8gdGhpcyBsaXN0XG4gICAgICAgIC8vIERpc2Nvbm5lY3QgRGlyZWN0aXZlIChhbmQgYW55IG5lc3RlZCBkaXJlY3RpdmVzIGNvbnRhaW5lZCB3aXRoaW4pXG4gICAgICAgIC8vIFRoaXMgcHJvcGVydHkgbmVlZHMgdG8gcmVtYWluIHVubWluaWZpZWQuXG4gICAgICAgIG9ialsnXyRub3RpZnlEaXJlY3RpdmVDb25uZWN0aW9uQ2hhbmdlZCddPy4oaXNDb25uZWN0ZWQsIGZhbHNlKTtcbiAgICAgICAgLy8gRGlzY29ubmVjdCBQYXJ0L1RlbXBsYXRlSW5zdGFuY2VcbiAgICAgICAgbm90aWZ5Q2hpbGRyZW5Db25uZWN0ZWRDaGFuZ2VkKG9iaiwgaXNDb25uZWN0ZWQpO1xuICAgIH1cbiAgICByZXR1cm4gdHJ1ZTtcbn07XG4vKipcbiAqIFJlbW92ZXMgdGhlIGdpdmVuIGNoaWxkIGZyb20gaXRzIHBhcmVudCBsaXN0IG9mIGRpc2Nvbm5lY3RhYmxlIGNoaWxkcmVuLCBhbmRcbiAqIGlmIHRoZSBwYXJlbnQgbGlzdCBiZWNvbWVzIGVtcHR5IGFzIGEgcmVzdWx0LCByZW1vdmVzIHRoZSBwYXJlbnQgZnJvbSBpdHNcbiAqIHBhcmVudCwgYW5kIHNvIGZvcnRoIHVwIHRoZSB0cmVlIHdoZW4gdGhhdCBjYXVzZXMgc3Vic2VxdWVudCBwYXJlbnQgbGlzdHMgdG9cbiAqIGJlY29tZSBlbXB0eS5cbiAqL1xuY29uc3QgcmVtb3ZlRGlzY29ubmVjdGFibGVGcm9tUGFyZW50ID0gKG9iaikgPT4ge1xuICAgIGxldCBwYXJlbnQsIGNoaWxkcmVuO1xuICAgIGRvIHtcbiAgICAgICAgaWYgKChwYXJlbnQgPSBvYmouXyRwYXJlbnQpID09PSB1bmRlZmluZWQpIHtcbiAgICAgICAgICAgIGJyZWFrO1xuICAgICAgICB9XG4gICAgICAgIGNoaWxkcmVuID0gcGFyZW50Ll8kZGlzY29ubmVjdGFibGVDaGlsZHJlbjtcbiAgICAgICAgY2hpbGRyZW4uZGVsZXRlKG9iaik7XG4gICAgICAgIG9iaiA9IHBhcmVudDtcbiAgICB9IHdoaWxlIChjaGlsZHJlbj8uc2l6ZSA9PT0gMCk7XG59O1xuY29uc3QgYWRkRGlzY29ubmVjdGFibGVUb1BhcmVudCA9IChvYmopID0+IHtcbiAgICAvLyBDbGltYiB0aGUgcGFyZW50IHRyZWUsIGNyZWF0aW5nIGEgc3BhcnNlIHRyZWUgb2YgY2hpbGRyZW4gbmVlZGluZ1xuICAgIC8vIGRpc2Nvbm5lY3Rpb25cbiAgICBmb3IgKGxldCBwYXJlbnQ7IChwYXJlbnQgPSBvYmouXyRwYXJlbnQpOyBvYmogPSBwYXJlbnQpIHtcbiAgICAgICAgbGV0IGNoaWxkcmVuID0gcGFyZW50Ll8kZGlzY29ubmVjdGFibGVDaGlsZHJlbjtcbiAgICAgICAgaWYgKGNoaWxkcmVuID09PSB1bmRlZmluZWQpIHtcbiAgICAgICAgICAgIHBhcmVudC5fJGRpc2Nvbm5lY3RhYmxlQ2hpbGRyZW4gPSBjaGlsZHJlbiA9IG5ldyBTZXQoKTtcbiAgICAgICAgfVxuICAgICAgICBlbHNlIGlmIChjaGlsZHJlbi5oYXMob2JqKSkge1xuICAgICAgICAgICAgLy8gT25jZSB3ZSd2ZSByZWFjaGVkIGEgcGFyZW50IHRoYXQgYWxyZWFkeSBjb250YWlucyB0aGlzIGNoaWxkLCB3ZVxuICAgICAgICAgICAgLy8gY2FuIHNob3J0LWNpcmN1aXRcbiAgICAgICAgICAgIGJyZWFrO1xuICAgICAgICB9XG4gICAgICAgIGNoaWxkcmVuLmFkZChvYmopO1xuICAgICAgICBpbnN0YWxsRGlzY29ubmVjdEFQSShwYXJlbnQpO1xuICAgIH1cbn07XG4vKipcbiAqIENoYW5nZXMgdGhlIHBhcmVudCByZWZlcmVuY2Ugb2YgdGhlIENoaWxkUGFydCwgYW5kIHVwZGF0ZXMgdGhlIHNwYXJzZSB0cmVlIG9mXG4gKiBEaXNjb25uZWN0YWJsZSBjaGlsZHJlbiBhY2NvcmRpbmdseS5cbiAqXG4gKiBOb3RlLCB0aGlzIG1ldGhvZCB3aWxsIGJlIHBhdGNoZWQgb250byBDaGlsZFBhcnQgaW5zdGFuY2VzIGFuZCBjYWxsZWQgZnJvbVxuICogdGhlIGNvcmUgY29kZSB3aGVuIHBhcnRzIGFyZSBtb3ZlZCBiZXR3ZWVuIGRpZmZlcmVudCBwYXJlbnRzLlxuICovXG5mdW5jdGlvbiByZXBhcmVudERpc2Nvbm5lY3RhYmxlcyhuZXdQYXJlbnQpIHtcbiAgICBpZiAodGhpcy5fJGRpc2Nvbm5lY3RhYmxlQ2hpbGRyZW4gIT09IHVuZGVmaW5lZCkge1xuICAgICAgICByZW1vdmVEaXNjb25uZWN0YWJsZUZyb21QYXJlbnQodGhpcyk7XG4gICAgICAgIHRoaXMuXyRwYXJlbnQgPSBuZXdQYXJlbnQ7XG4gICAgICAgIGFkZERpc2Nvbm5lY3RhYmxlVG9QYXJlbnQodGhpcyk7XG4gICAgfVxuICAgIGVsc2Uge1xuICAgICAgICB0aGlzLl8kcGFyZW50ID0gbmV3UGFyZW50O1xuICAgIH1cbn1cbi8qKlxuICogU2V0cyB0aGUgY29ubmVjdGVkIHN0YXRlIG9uIGFueSBkaXJlY3RpdmVzIGNvbnRhaW5lZCB3aXRoaW4gdGhlIGNvbW1pdHRlZFxuICogdmFsdWUgb2YgdGhpcyBwYXJ0IChpLmUuIHdpdGhpbiBhIFRlbXBsYXRlSW5zdGFuY2Ugb3IgaXRlcmFibGUgb2ZcbiAqIENoaWxkUGFydHMpIGFuZCBydW5zIHRoZWlyIGBkaXNjb25uZWN0ZWRgL2ByZWNvbm5lY3RlZGBzLCBhcyB3ZWxsIGFzIHdpdGhpblxuICogYW55IGRpcmVjdGl2ZXMgc3RvcmVkIG9uIHRoZSBDaGlsZFBhcnQgKHdoZW4gYHZhbHVlT25seWAgaXMgZmFsc2UpLlxuICpcbiAqIGBpc0NsZWFyaW5nVmFsdWVgIHNob3VsZCBiZSBwYXNzZWQgYXMgYHRydWVgIG9uIGEgdG9wLWxldmVsIHBhcnQgdGhhdCBpc1xuICogY2xlYXJpbmcgaXRzZWxmLCBhbmQgbm90IGFzIGEgcmVzdWx0IG9mIHJlY3Vyc2l2ZWx5IGRpc2Nvbm5lY3RpbmcgZGlyZWN0aXZlc1xuICogYXMgcGFydCBvZiBhIGBjbGVhcmAgb3BlcmF0aW9uIGhpZ2hlciB1cCB0aGUgdHJlZS4gVGhpcyBib3RoIGVuc3VyZXMgdGhhdCBhbnlcbiAqIGRpcmVjdGl2ZSBvbiB0aGlzIENoaWxkUGFydCB0aGF0IHByb2R1Y2VkIGEgdmFsdWUgdGhhdCBjYXVzZWQgdGhlIGNsZWFyXG4gKiBvcGVyYXRpb24gaXMgbm90IGRpc2Nvbm5lY3RlZCwgYW5kIGFsc28gc2VydmVzIGFzIGEgcGVyZm9ybWFuY2Ugb3B0aW1pemF0aW9uXG4gKiB0byBhdm9pZCBuZWVkbGVzcyBib29ra2VlcGluZyB3aGVuIGEgc3VidHJlZSBpcyBnb2luZyBhd2F5OyB3aGVuIGNsZWFyaW5nIGFcbiAqIHN1YnRyZWUsIG9ubHkgdGhlIHRvcC1tb3N0IHBhcnQgbmVlZCB0byByZW1vdmUgaXRzZWxmIGZyb20gdGhlIHBhcmVudC5cbiAqXG4gKiBgZnJvbVBhcnRJbmRleGAgaXMgcGFzc2VkIG9ubHkgaW4gdGhlIGNhc2Ugb2YgYSBwYXJ0aWFsIGBfY2xlYXJgIHJ1bm5pbmcgYXMgYVxuICogcmVzdWx0IG9mIHRydW5jYXRpbmcgYW4gaXRlcmFibGUuXG4gKlxuICogTm90ZSwgdGhpcyBtZXRob2Qgd2lsbCBiZSBwYXRjaGVkIG9udG8gQ2hpbGRQYXJ0IGluc3RhbmNlcyBhbmQgY2FsbGVkIGZyb20gdGhlXG4gKiBjb3JlIGNvZGUgd2hlbiBwYXJ0cyBhcmUgY2xlYXJlZCBvciB0aGUgY29ubmVjdGlvbiBzdGF0ZSBpcyBjaGFuZ2VkIGJ5IHRoZVxuICogdXNlci5cbiAqL1xuZnVuY3Rpb24gbm90aWZ5Q2hpbGRQYXJ0Q29ubmVjdGVkQ2hhbmdlZChpc0Nvbm5lY3RlZCwgaXNDbGVhcmluZ1ZhbHVlID0gZmFsc2UsIGZyb21QYXJ0SW5kZXggPSAwKSB7XG4gICAgY29uc3QgdmFsdWUgPSB0aGlzLl8kY29tbWl0dGVkVmFsdWU7XG4gICAgY29uc3QgY2hpbGRyZW4gPSB0aGlzLl8kZGlzY29ubmVjdGFibGVDaGlsZHJlbjtcbiAgICBpZiAoY2hpbGRyZW4gPT09IHVuZGVmaW5lZCB8fCBjaGlsZHJlbi5zaXplID09PSAwKSB7XG4gICAgICAgIHJldHVybjtcbiAgICB9XG4gICAgaWYgKGlzQ2xlYXJpbmdWYWx1ZSkge1xuICAgICAgICBpZiAoQXJyYXkuaXNBcnJheSh2YWx1ZSkpIHtcbiAgICAgICAgICAgIC8vIEl0ZXJhYmxlIGNhc2U6IEFueSBDaGlsZFBhcnRzIGNyZWF0ZWQgYnkgdGhlIGl0ZXJhYmxlIHNob3VsZCBiZVxuICAgICAgICAgICAgLy8gZGlzY29ubmVjdGVkIGFuZCByZW1vdmVkIGZyb20gdGhpcyBDaGlsZFBhcnQncyBkaXNjb25uZWN0YWJsZVxuICAgICAgICAgICAgLy8gY2hpbGRyZW4gKHN0YXJ0aW5nIGF0IGBmcm9tUGFydEluZGV4YCBpbiB0aGUgY2FzZSBvZiB0cnVuY2F0aW9uKVxuICAgICAgICAgICAgZm9yIChsZXQgaSA9IGZyb21QYXJ0SW5kZXg7IGkgPCB2YWx1ZS5sZW5ndGg7IGkrKykge1xuICAgICAgICAgICAgICAgIG5vdGlmeUNoaWxkcmVuQ29ubmVjdGVkQ2hhbmdlZCh2YWx1ZVtpXSwgZmFsc2UpO1xuICAgICAgICAgICAgICAgIHJlbW92ZURpc2Nvbm5lY3RhYmxlRnJvbVBhcmVudCh2YWx1ZVtpXSk7XG4gICAgICAgICAgICB9XG4gICAgICAgIH1cbiAgICAgICAgZWxzZSBpZiAodmFsdWUgIT0gbnVsbCkge1xuICAgICAgICAgICAgLy8gVGVtcGxhdGVJbnN0YW5jZSBjYXNlOiBJZiB0aGUgdmFsdWUgaGFzIGRpc2Nvbm5lY3RhYmxlIGNoaWxkcmVuICh3aWxsXG4gICAgICAgICAgICAvLyBvbmx5IGJlIGluIHRoZSBjYXNlIHRoYXQgaXQgaXMgYSBUZW1wbGF0ZUluc3RhbmNlKSwgd2UgZGlzY29ubmVjdCBpdFxuICAgICAgICAgICAgLy8gYW5kIHJlbW92ZSBpdCBmcm9tIHRoaXMgQ2hpbGRQYXJ0J3MgZGlzY29ubmVjdGFibGUgY2hpbGRyZW5cbiAgICAgICAgICAgIG5vdGlmeUNoaWxkcmVuQ29ubmVjdGVkQ2hhbmdlZCh2YWx1ZSwgZmFsc2UpO1xuICAgICAgICAgICAgcmVtb3ZlRGlzY29ubmVjdGFibGVGcm9tUGFyZW50KHZhbHVlKTtcbiAgICAgICAgfVxuICAgIH1cbiAgICBlbHNlIHtcbiAgICAgICAgbm90aWZ5Q2hpbGRyZW5Db25uZWN0ZWRDaGFuZ2VkKHRoaXMsIGlzQ29ubmVjdGVkKTtcbiAgICB9XG59XG4vKipcbiAqIFBhdGNoZXMgZGlzY29ubmVjdGlvbiBBUEkgb250byBDaGlsZFBhcnRzLlxuICovXG5jb25zdCBpbnN0YWxsRGlzY29ubmVjdEFQSSA9IChvYmopID0+IHtcbiAgICBpZiAob2JqLnR5cGUgPT0gUGFydFR5cGUuQ0hJTEQpIHtcbiAgICAgICAgb2JqLl8kbm90aWZ5Q29ubmVjdGlvbkNoYW5nZWQgPz89XG4gICAgICAgICAgICBub3RpZnlDaGlsZFBhcnRDb25uZWN0ZWRDaGFuZ2VkO1xuICAgICAgICBvYmouXyRyZXBhcmVudERpc2Nvbm5lY3RhYmxlcyA/Pz0gcmVwYXJlbnREaXNjb25uZWN0YWJsZXM7XG4gICAgfVxufTtcbi8qKlxuICogQW4gYWJzdHJhY3QgYERpcmVjdGl2ZWAgYmFzZSBjbGFzcyB3aG9zZSBgZGlzY29ubmVjdGVkYCBtZXRob2Qgd2lsbCBiZVxuICogY2FsbGVkIHdoZW4gdGhlIHBhcnQgY29udGFpbmluZyB0aGUgZGlyZWN0aXZlIGlzIGNsZWFyZWQgYXMgYSByZXN1bHQgb2ZcbiAqIHJlLXJlbmRlcmluZywgb3Igd2hlbiB0aGUgdXNlciBjYWxscyBgcGFydC5zZXRDb25uZWN0ZWQoZmFsc2UpYCBvblxuICogYSBwYXJ0IHRoYXQgd2FzIHByZXZpb3VzbHkgcmVuZGVyZWQgY29udGFpbmluZyB0aGUgZGlyZWN0aXZlIChhcyBoYXBwZW5zXG4gKiB3aGVuIGUuZy4gYSBMaXRFbGVtZW50IGRpc2Nvbm5lY3RzIGZyb20gdGhlIERPTSkuXG4gKlxuICogSWYgYHBhcnQuc2V0Q29ubmVjdGVkKHRydWUpYCBpcyBzdWJzZXF1ZW50bHkgY2FsbGVkIG9uIGFcbiAqIGNvbnRhaW5pbmcgcGFydCwgdGhlIGRpcmVjdGl2ZSdzIGByZWNvbm5lY3RlZGAgbWV0aG9kIHdpbGwgYmUgY2FsbGVkIHByaW9yXG4gKiB0byBpdHMgbmV4dCBgdXBkYXRlYC9gcmVuZGVyYCBjYWxsYmFja3MuIFdoZW4gaW1wbGVtZW50aW5nIGBkaXNjb25uZWN0ZWRgLFxuICogYHJlY29ubmVjdGVkYCBzaG91bGQgYWxzbyBiZSBpbXBsZW1lbnRlZCB0byBiZSBjb21wYXRpYmxlIHdpdGggcmVjb25uZWN0aW9uLlxuICpcbiAqIE5vdGUgdGhhdCB1cGRhdGVzIG1heSBvY2N1ciB3aGlsZSB0aGUgZGlyZWN0aXZlIGlzIGRpc2Nvbm5lY3RlZC4gQXMgc3VjaCxcbiAqIGRpcmVjdGl2ZXMgc2hvdWxkIGdlbmVyYWxseSBjaGVjayB0aGUgYHRoaXMuaXNDb25uZWN0ZWRgIGZsYWcgZHVyaW5nXG4gKiByZW5kZXIvdXBkYXRlIHRvIGRldGVybWluZSB3aGV0aGVyIGl0IGlzIHNhZmUgdG8gc3Vic2NyaWJlIHRvIHJlc291cmNlc1xuICogdGhhdCBtYXkgcHJldmVudCBnYXJiYWdlIGNvbGxlY3Rpb24uXG4gKi9cbmV4cG9ydCBjbGFzcyBBc3luY0RpcmVjdGl2ZSBleHRlbmRzIERpcmVjdGl2ZSB7XG4gICAgY29uc3RydWN0b3IoKSB7XG4gICAgICAgIHN1cGVyKC4uLmFyZ3VtZW50cyk7XG4gICAgICAgIC8vIEBpbnRlcm5hbFxuICAgICAgICB0aGlzLl8kZGlzY29ubmVjdGFibGVDaGlsZHJlbiA9IHVuZGVmaW5lZDtcbiAgICB9XG4gICAgLyoqXG4gICAgICogSW5pdGlhbGl6ZSB0aGUgcGFydCB3aXRoIGludGVybmFsIGZpZWxkc1xuICAgICAqIEBwYXJhbSBwYXJ0XG4gICAgICogQHBhcmFtIHBhcmVudFxuICAgICAqIEBwYXJhbSBhdHRyaWJ1dGVJbmRleFxuICAgICAqL1xuICAgIF8kaW5pdGlhbGl6ZShwYXJ0LCBwYXJlbnQsIGF0dHJpYnV0ZUluZGV4KSB7XG4gICAgICAgIHN1cGVyLl8kaW5pdGlhbGl6ZShwYXJ0LCBwYXJlbnQsIGF0dHJpYnV0ZUluZGV4KTtcbiAgICAgICAgYWRkRGlzY29ubmVjdGFibGVUb1BhcmVudCh0aGlzKTtcbiAgICAgICAgdGhpcy5pc0Nvbm5lY3RlZCA9IHBhcnQuXyRpc0Nvbm5lY3RlZDtcbiAgICB9XG4gICAgLy8gVGhpcyBwcm9wZXJ0eSBuZWVkcyB0byByZW1haW4gdW5taW5pZmllZC5cbiAgICAvKipcbiAgICAgKiBDYWxsZWQgZnJvbSB0aGUgY29yZSBjb2RlIHdoZW4gYSBkaXJlY3RpdmUgaXMgZ29pbmcgYXdheSBmcm9tIGEgcGFydCAoaW5cbiAgICAgKiB3aGljaCBjYXNlIGBzaG91bGRSZW1vdmVGcm9tUGFyZW50YCBzaG91bGQgYmUgdHJ1ZSksIGFuZCBmcm9tIHRoZVxuICAgICAqIGBzZXRDaGlsZHJlbkNvbm5lY3RlZGAgaGVscGVyIGZ1bmN0aW9uIHdoZW4gcmVjdXJzaXZlbHkgY2hhbmdpbmcgdGhlXG4gICAgICogY29ubmVjdGlvbiBzdGF0ZSBvZiBhIHRyZWUgKGluIHdoaWNoIGNhc2UgYHNob3VsZFJlbW92ZUZyb21QYXJlbnRgIHNob3VsZFxuICAgICAqIGJlIGZhbHNlKS5cbiAgICAgKlxuICAgICAqIEBwYXJhbSBpc0Nvbm5lY3RlZFxuICAgICAqIEBwYXJhbSBpc0NsZWFyaW5nRGlyZWN0aXZlIC0gVHJ1ZSB3aGVuIHRoZSBkaXJlY3RpdmUgaXRzZWxmIGlzIGJlaW5nXG4gICAgICogICAgIHJlbW92ZWQ7IGZhbHNlIHdoZW4gdGhlIHRyZWUgaXMgYmVpbmcgZGlzY29ubmVjdGVkXG4gICAgICogQGludGVybmFsXG4gICAgICovXG4gICAgWydfJG5vdGlmeURpcmVjdGl2ZUNvbm5lY3Rpb25DaGFuZ2VkJ10oaXNDb25uZWN0ZWQsIGlzQ2xlYXJpbmdEaXJlY3RpdmUgPSB0cnVlKSB7XG4gICAgICAgIGlmIChpc0Nvbm5lY3RlZCAhPT0gdGhpcy5pc0Nvbm5lY3RlZCkge1xuICAgICAgICAgICAgdGhpcy5pc0Nvbm5lY3RlZCA9IGlzQ29ubmVjdGVkO1xuICAgICAgICAgICAgaWYgKGlzQ29ubmVjdGVkKSB7XG4gICAgICAgICAgICAgICAgdGhpcy5yZWNvbm5lY3RlZD8uKCk7XG4gICAgICAgICAgICB9XG4gICAgICAgICAgICBlbHNlIHtcbiAgICAgICAgICAgICAgICB0aGlzLmRpc2Nvbm5lY3RlZD8uKCk7XG4gICAgICAgICAgICB9XG4gICAgICAgIH1cbiAgICAgICAgaWYgKGlzQ2xlYXJpbmdEaXJlY3RpdmUpIHtcbiAgICAgICAgICAgIG5vdGlmeUNoaWxkcmVuQ29ubmVjdGVkQ2hhbmdlZCh0aGlzLCBpc0Nvbm5lY3RlZCk7XG4gICAgICAgICAgICByZW1vdmVEaXNjb25uZWN0YWJsZUZyb21QYXJlbnQodGhpcyk7XG4gICAgICAgIH1cbiAgICB9XG4gICAgLyoqXG4gICAgICogU2V0cyB0aGUgdmFsdWUgb2YgdGhlIGRpcmVjdGl2ZSdzIFBhcnQgb3V0c2lkZSB0aGUgbm9ybWFsIGB1cGRhdGVgL2ByZW5kZXJgXG4gICAgICogbGlmZWN5Y2xlIG9mIGEgZGlyZWN0aXZlLlxuICAgICAqXG4gICAgICogVGhpcyBtZXRob2Qgc2hvdWxkIG5vdCBiZSBjYWxsZWQgc3luY2hyb25vdXNseSBmcm9tIGEgZGlyZWN0aXZlJ3MgYHVwZGF0ZWBcbiAgICAgKiBvciBgcmVuZGVyYC5cbiAgICAgKlxuICAgICAqIEBwYXJhbSBkaXJlY3RpdmUgVGhlIGRpcmVjdGl2ZSB0byB1cGRhdGVcbiAgICAgKiBAcGFyYW0gdmFsdWUgVGhlIHZhbHVlIHRvIHNldFxuICAgICAqL1xuICAgIHNldFZhbHVlKHZhbHVlKSB7XG4gICAgICAgIGlmIChpc1NpbmdsZUV4cHJlc3Npb24odGhpcy5fX3BhcnQpKSB7XG4gICAgICAgICAgICB0aGlzLl9fcGFydC5fJHNldFZhbHVlKHZhbHVlLCB0aGlzKTtcbiAgICAgICAgfVxuICAgICAgICBlbHNlIHtcbiAgICAgICAgICAgIC8vIHRoaXMuX19hdHRyaWJ1dGVJbmRleCB3aWxsIGJlIGRlZmluZWQgaW4gdGhpcyBjYXNlLCBidXRcbiAgICAgICAgICAgIC8vIGFzc2VydCBpdCBpbiBkZXYgbW9kZVxuICAgICAgICAgICAgaWYgKERFVl9NT0RFICYmIHRoaXMuX19hdHRyaWJ1dGVJbmRleCA9PT0gdW5kZWZpbmVkKSB7XG4gICAgICAgICAgICAgICAgdGhyb3cgbmV3IEVycm9yKGBFeHBlY3RlZCB0aGlzLl9fYXR0cmlidXRlSW5kZXggdG8gYmUgYSBudW1iZXJgKTtcbiAgICAgICAgICAgIH1cbiAgICAgICAgICAgIGNvbnN0IG5ld1ZhbHVlcyA9IFsuLi50aGlzLl9fcGFydC5fJGNvbW1pdHRlZFZhbHVlXTtcbiAgICAgICAgICAgIG5ld1ZhbHVlc1t0aGlzLl9fYXR0cmlidXRlSW5kZXhdID0gdmFsdWU7XG4gICAgICAgICAgICB0aGlzLl9fcGFydC5fJHNldFZhbHVlKG5ld1ZhbHVlcywgdGhpcywgMCk7XG4gICAgICAgIH1cbiAgICB9XG4gICAgLyoqXG4gICAgICogVXNlciBjYWxsYmFja3MgZm9yIGltcGxlbWVudGluZyBsb2dpYyB0byByZWxlYXNlIGFueSByZXNvdXJjZXMvc3Vic2NyaXB0aW9uc1xuICAgICAqIHRoYXQgbWF5IGhhdmUgYmVlbiByZXRhaW5lZCBieSB0aGlzIGRpcmVjdGl2ZS4gU2luY2UgZGlyZWN0aXZlcyBtYXkgYWxzbyBiZVxuICAgICAqIHJlLWNvbm5lY3RlZCwgYHJlY29ubmVjdGVkYCBzaG91bGQgYWxzbyBiZSBpbXBsZW1lbnRlZCB0byByZXN0b3JlIHRoZVxuICAgICAqIHdvcmtpbmcgc3RhdGUgb2YgdGhlIGRpcmVjdGl2ZSBwcmlvciB0byB0aGUgbmV4dCByZW5kZXIuXG4gICAgICovXG4gICAgZGlzY29ubmVjdGVkKCkgeyB9XG4gICAgcmVjb25uZWN0ZWQoKSB7IH1cbn1cbi8vIyBzb3VyY2VNYXBwaW5nVVJMPWFzeW5jLWRpcmVjdGl2ZS5qcy5tYXAiXSwibmFtZXMiOltdLCJzb3VyY2VSb290IjoiIn0=\n//# sourceURL=webpack-internal:///./node_modules/lit-html/development/async-directive.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/lit-html/development/directive-helpers.js":
-/*!****************************************************************!*\
-  !*** ./node_modules/lit-html/development/directive-helpers.js ***!
-  \****************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   TemplateResultType: function() { return /* binding */ TemplateResultType; },\n/* harmony export */   clearPart: function() { return /* binding */ clearPart; },\n/* harmony export */   getCommittedValue: function() { return /* binding */ getCommittedValue; },\n/* harmony export */   getDirectiveClass: function() { return /* binding */ getDirectiveClass; },\n/* harmony export */   insertPart: function() { return /* binding */ insertPart; },\n/* harmony export */   isCompiledTemplateResult: function() { return /* binding */ isCompiledTemplateResult; },\n/* harmony export */   isDirectiveResult: function() { return /* binding */ isDirectiveResult; },\n/* harmony export */   isPrimitive: function() { return /* binding */ isPrimitive; },\n/* harmony export */   isSingleExpression: function() { return /* binding */ isSingleExpression; },\n/* harmony export */   isTemplateResult: function() { return /* binding */ isTemplateResult; },\n/* harmony export */   removePart: function() { return /* binding */ removePart; },\n/* harmony export */   setChildPartValue: function() { return /* binding */ setChildPartValue; },\n/* harmony export */   setCommittedValue: function() { return /* binding */ setCommittedValue; }\n/* harmony export */ });\n/* harmony import */ var _lit_html_js__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! ./lit-html.js */ \"./node_modules/lit-html/development/lit-html.js\");\n/**\n * @license\n * Copyright 2020 Google LLC\n * SPDX-License-Identifier: BSD-3-Clause\n */\n\nconst { _ChildPart: ChildPart } = _lit_html_js__WEBPACK_IMPORTED_MODULE_0__._$LH;\nconst ENABLE_SHADYDOM_NOPATCH = true;\nconst wrap = ENABLE_SHADYDOM_NOPATCH &&\n    window.ShadyDOM?.inUse &&\n    window.ShadyDOM?.noPatch === true\n    ? window.ShadyDOM.wrap\n    : (node) => node;\n/**\n * Tests if a value is a primitive value.\n *\n * See https://tc39.github.io/ecma262/#sec-typeof-operator\n */\nconst isPrimitive = (value) => value === null || (typeof value != 'object' && typeof value != 'function');\nconst TemplateResultType = {\n    HTML: 1,\n    SVG: 2,\n    MATHML: 3,\n};\n/**\n * Tests if a value is a TemplateResult or a CompiledTemplateResult.\n */\nconst isTemplateResult = (value, type) => type === undefined\n    ? // This property needs to remain unminified.\n        value?.['_$litType$'] !== undefined\n    : value?.['_$litType$'] === type;\n/**\n * Tests if a value is a CompiledTemplateResult.\n */\nconst isCompiledTemplateResult = (value) => {\n    return value?.['_$litType$']?.h != null;\n};\n/**\n * Tests if a value is a DirectiveResult.\n */\nconst isDirectiveResult = (value) => \n// This property needs to remain unminified.\nvalue?.['_$litDirective$'] !== undefined;\n/**\n * Retrieves the Directive class for a DirectiveResult\n */\nconst getDirectiveClass = (value) => \n// This property needs to remain unminified.\nvalue?.['_$litDirective$'];\n/**\n * Tests whether a part has only a single-expression with no strings to\n * interpolate between.\n *\n * Only AttributePart and PropertyPart can have multiple expressions.\n * Multi-expression parts have a `strings` property and single-expression\n * parts do not.\n */\nconst isSingleExpression = (part) => part.strings === undefined;\nconst createMarker = () => document.createComment('');\n/**\n * Inserts a ChildPart into the given container ChildPart's DOM, either at the\n * end of the container ChildPart, or before the optional `refPart`.\n *\n * This does not add the part to the containerPart's committed value. That must\n * be done by callers.\n *\n * @param containerPart Part within which to add the new ChildPart\n * @param refPart Part before which to add the new ChildPart; when omitted the\n *     part added to the end of the `containerPart`\n * @param part Part to insert, or undefined to create a new part\n */\nconst insertPart = (containerPart, refPart, part) => {\n    const container = wrap(containerPart._$startNode).parentNode;\n    const refNode = refPart === undefined ? containerPart._$endNode : refPart._$startNode;\n    if (part === undefined) {\n        const startNode = wrap(container).insertBefore(createMarker(), refNode);\n        const endNode = wrap(container).insertBefore(createMarker(), refNode);\n        part = new ChildPart(startNode, endNode, containerPart, containerPart.options);\n    }\n    else {\n        const endNode = wrap(part._$endNode).nextSibling;\n        const oldParent = part._$parent;\n        const parentChanged = oldParent !== containerPart;\n        if (parentChanged) {\n            part._$reparentDisconnectables?.(containerPart);\n            // Note that although `_$reparentDisconnectables` updates the part's\n            // `_$parent` reference after unlinking from its current parent, that\n            // method only exists if Disconnectables are present, so we need to\n            // unconditionally set it here\n            part._$parent = containerPart;\n            // Since the _$isConnected getter is somewhat costly, only\n            // read it once we know the subtree has directives that need\n            // to be notified\n            let newConnectionState;\n            if (part._$notifyConnectionChanged !== undefined &&\n                (newConnectionState = containerPart._$isConnected) !==\n                    oldParent._$isConnected) {\n                part._$notifyConnectionChanged(newConnectionState);\n            }\n        }\n        if (endNode !== refNode || parentChanged) {\n            let start = part._$startNode;\n            while (start !== endNode) {\n                const n = wrap(start).nextSibling;\n                wrap(container).insertBefore(start, refNode);\n                start = n;\n            }\n        }\n    }\n    return part;\n};\n/**\n * Sets the value of a Part.\n *\n * Note that this should only be used to set/update the value of user-created\n * parts (i.e. those created using `insertPart`); it should not be used\n * by directives to set the value of the directive's container part. Directives\n * should return a value from `update`/`render` to update their part state.\n *\n * For directives that require setting their part value asynchronously, they\n * should extend `AsyncDirective` and call `this.setValue()`.\n *\n * @param part Part to set\n * @param value Value to set\n * @param index For `AttributePart`s, the index to set\n * @param directiveParent Used internally; should not be set by user\n */\nconst setChildPartValue = (part, value, directiveParent = part) => {\n    part._$setValue(value, directiveParent);\n    return part;\n};\n// A sentinel value that can never appear as a part value except when set by\n// live(). Used to force a dirty-check to fail and cause a re-render.\nconst RESET_VALUE = {};\n/**\n * Sets the committed value of a ChildPart directly without triggering the\n * commit stage of the part.\n *\n * This is useful in cases where a directive needs to update the part such\n * that the next update detects a value change or not. When value is omitted,\n * the next update will be guaranteed to be detected as a change.\n *\n * @param part\n * @param value\n */\nconst setCommittedValue = (part, value = RESET_VALUE) => (part._$committedValue = value);\n/**\n * Returns the committed value of a ChildPart.\n *\n * The committed value is used for change detection and efficient updates of\n * the part. It can differ from the value set by the template or directive in\n * cases where the template value is transformed before being committed.\n *\n * - `TemplateResult`s are committed as a `TemplateInstance`\n * - Iterables are committed as `Array<ChildPart>`\n * - All other types are committed as the template value or value returned or\n *   set by a directive.\n *\n * @param part\n */\nconst getCommittedValue = (part) => part._$committedValue;\n/**\n * Removes a ChildPart from the DOM, including any of its content and markers.\n *\n * Note: The only difference between this and clearPart() is that this also\n * removes the part's start node. This means that the ChildPart must own its\n * start node, ie it must be a marker node specifically for this part and not an\n * anchor from surrounding content.\n *\n * @param part The Part to remove\n */\nconst removePart = (part) => {\n    part._$clear();\n    part._$startNode.remove();\n};\nconst clearPart = (part) => {\n    part._$clear();\n};\n//# sourceMappingURL=directive-helpers.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvbGl0LWh0bWwvZGV2ZWxvcG1lbnQvZGlyZWN0aXZlLWhlbHBlcnMuanMiLCJtYXBwaW5ncyI6Ijs7Ozs7Ozs7Ozs7Ozs7Ozs7QUFBQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ3NDO0FBQ3RDLFFBQVEsd0JBQXdCLEVBQUUsOENBQUk7QUFDdEM7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNPO0FBQ0E7QUFDUDtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNPO0FBQ1A7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ087QUFDUDtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ087QUFDUDtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ087QUFDUDtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNPO0FBQ1A7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0EsOERBQThEO0FBQzlEO0FBQ0E7QUFDQTtBQUNPO0FBQ1A7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQSxrREFBa0Q7QUFDbEQ7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0EsMkNBQTJDO0FBQzNDO0FBQ087QUFDUDtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ087QUFDUDtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ087QUFDUDtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNPO0FBQ1A7QUFDQTtBQUNBO0FBQ087QUFDUDtBQUNBO0FBQ0EiLCJzb3VyY2VzIjpbIndlYnBhY2s6Ly9fTl9FLy4vbm9kZV9tb2R1bGVzL2xpdC1odG1sL2RldmVsb3BtZW50L2RpcmVjdGl2ZS1oZWxwZXJzLmpzPzcyNWYiXSwic291cmNlc0NvbnRlbnQiOlsiLyoqXG4gKiBAbGljZW5zZVxuICogQ29weXJpZ2h0IDIwMjAgR29vZ2xlIExMQ1xuICogU1BEWC1MaWNlbnNlLUlkZW50aWZpZXI6IEJTRC0zLUNsYXVzZVxuICovXG5pbXBvcnQgeyBfJExILCB9IGZyb20gJy4vbGl0LWh0bWwuanMnO1xuY29uc3QgeyBfQ2hpbGRQYXJ0OiBDaGlsZFBhcnQgfSA9IF8kTEg7XG5jb25zdCBFTkFCTEVfU0hBRFlET01fTk9QQVRDSCA9IHRydWU7XG5jb25zdCB3cmFwID0gRU5BQkxFX1NIQURZRE9NX05PUEFUQ0ggJiZcbiAgICB3aW5kb3cuU2hhZHlET00/LmluVXNlICYmXG4gICAgd2luZG93LlNoYWR5RE9NPy5ub1BhdGNoID09PSB0cnVlXG4gICAgPyB3aW5kb3cuU2hhZHlET00ud3JhcFxuICAgIDogKG5vZGUpID0+IG5vZGU7XG4vKipcbiAqIFRlc3RzIGlmIGEgdmFsdWUgaXMgYSBwcmltaXRpdmUgdmFsdWUuXG4gKlxuICogU2VlIGh0dHBzOi8vdGMzOS5naXRodWIuaW8vZWNtYTI2Mi8jc2VjLXR5cGVvZi1vcGVyYXRvclxuICovXG5leHBvcnQgY29uc3QgaXNQcmltaXRpdmUgPSAodmFsdWUpID0+IHZhbHVlID09PSBudWxsIHx8ICh0eXBlb2YgdmFsdWUgIT0gJ29iamVjdCcgJiYgdHlwZW9mIHZhbHVlICE9ICdmdW5jdGlvbicpO1xuZXhwb3J0IGNvbnN0IFRlbXBsYXRlUmVzdWx0VHlwZSA9IHtcbiAgICBIVE1MOiAxLFxuICAgIFNWRzogMixcbiAgICBNQVRITUw6IDMsXG59O1xuLyoqXG4gKiBUZXN0cyBpZiBhIHZhbHVlIGlzIGEgVGVtcGxhdGVSZXN1bHQgb3IgYSBDb21waWxlZFRlbXBsYXRlUmVzdWx0LlxuICovXG5leHBvcnQgY29uc3QgaXNUZW1wbGF0ZVJlc3VsdCA9ICh2YWx1ZSwgdHlwZSkgPT4gdHlwZSA9PT0gdW5kZWZpbmVkXG4gICAgPyAvLyBUaGlzIHByb3BlcnR5IG5lZWRzIHRvIHJlbWFpbiB1bm1pbmlmaWVkLlxuICAgICAgICB2YWx1ZT8uWydfJGxpdFR5cGUkJ10gIT09IHVuZGVmaW5lZFxuICAgIDogdmFsdWU/LlsnXyRsaXRUeXBlJCddID09PSB0eXBlO1xuLyoqXG4gKiBUZXN0cyBpZiBhIHZhbHVlIGlzIGEgQ29tcGlsZWRUZW1wbGF0ZVJlc3VsdC5cbiAqL1xuZXhwb3J0IGNvbnN0IGlzQ29tcGlsZWRUZW1wbGF0ZVJlc3VsdCA9ICh2YWx1ZSkgPT4ge1xuICAgIHJldHVybiB2YWx1ZT8uWydfJGxpdFR5cGUkJ10/LmggIT0gbnVsbDtcbn07XG4vKipcbiAqIFRlc3RzIGlmIGEgdmFsdWUgaXMgYSBEaXJlY3RpdmVSZXN1bHQuXG4gKi9cbmV4cG9ydCBjb25zdCBpc0RpcmVjdGl2ZVJlc3VsdCA9ICh2YWx1ZSkgPT4gXG4vLyBUaGlzIHByb3BlcnR5IG5lZWRzIHRvIHJlbWFpbiB1bm1pbmlmaWVkLlxudmFsdWU/LlsnXyRsaXREaXJlY3RpdmUkJ10gIT09IHVuZGVmaW5lZDtcbi8qKlxuICogUmV0cmlldmVzIHRoZSBEaXJlY3RpdmUgY2xhc3MgZm9yIGEgRGlyZWN0aXZlUmVzdWx0XG4gKi9cbmV4cG9ydCBjb25zdCBnZXREaXJlY3RpdmVDbGFzcyA9ICh2YWx1ZSkgPT4gXG4vLyBUaGlzIHByb3BlcnR5IG5lZWRzIHRvIHJlbWFpbiB1bm1pbmlmaWVkLlxudmFsdWU/LlsnXyRsaXREaXJlY3RpdmUkJ107XG4vKipcbiAqIFRlc3RzIHdoZXRoZXIgYSBwYXJ0IGhhcyBvbmx5IGEgc2luZ2xlLWV4cHJlc3Npb24gd2l0aCBubyBzdHJpbmdzIHRvXG4gKiBpbnRlcnBvbGF0ZSBiZXR3ZWVuLlxuICpcbiAqIE9ubHkgQXR0cmlidXRlUGFydCBhbmQgUHJvcGVydHlQYXJ0IGNhbiBoYXZlIG11bHRpcGxlIGV4cHJlc3Npb25zLlxuICogTXVsdGktZXhwcmVzc2lvbiBwYXJ0cyBoYXZlIGEgYHN0cmluZ3NgIHByb3BlcnR5IGFuZCBzaW5nbGUtZXhwcmVzc2lvblxuICogcGFydHMgZG8gbm90LlxuICovXG5leHBvcnQgY29uc3QgaXNTaW5nbGVFeHByZXNzaW9uID0gKHBhcnQpID0+IHBhcnQuc3RyaW5ncyA9PT0gdW5kZWZpbmVkO1xuY29uc3QgY3JlYXRlTWFya2VyID0gKCkgPT4gZG9jdW1lbnQuY3JlYXRlQ29tbWVudCgnJyk7XG4vKipcbiAqIEluc2VydHMgYSBDaGlsZFBhcnQgaW50byB0aGUgZ2l2ZW4gY29udGFpbmVyIENoaWxkUGFydCdzIERPTSwgZWl0aGVyIGF0IHRoZVxuICogZW5kIG9mIHRoZSBjb250YWluZXIgQ2hpbGRQYXJ0LCBvciBiZWZvcmUgdGhlIG9wdGlvbmFsIGByZWZQYXJ0YC5cbiAqXG4gKiBUaGlzIGRvZXMgbm90IGFkZCB0aGUgcGFydCB0byB0aGUgY29udGFpbmVyUGFydCdzIGNvbW1pdHRlZCB2YWx1ZS4gVGhhdCBtdXN0XG4gKiBiZSBkb25lIGJ5IGNhbGxlcnMuXG4gKlxuICogQHBhcmFtIGNvbnRhaW5lclBhcnQgUGFydCB3aXRoaW4gd2hpY2ggdG8gYWRkIHRoZSBuZXcgQ2hpbGRQYXJ0XG4gKiBAcGFyYW0gcmVmUGFydCBQYXJ0IGJlZm9yZSB3aGljaCB0byBhZGQgdGhlIG5ldyBDaGlsZFBhcnQ7IHdoZW4gb21pdHRlZCB0aGVcbiAqICAgICBwYXJ0IGFkZGVkIHRvIHRoZSBlbmQgb2YgdGhlIGBjb250YWluZXJQYXJ0YFxuICogQHBhcmFtIHBhcnQgUGFydCB0byBpbnNlcnQsIG9yIHVuZGVmaW5lZCB0byBjcmVhdGUgYSBuZXcgcGFydFxuICovXG5leHBvcnQgY29uc3QgaW5zZXJ0UGFydCA9IChjb250YWluZXJQYXJ0LCByZWZQYXJ0LCBwYXJ0KSA9PiB7XG4gICAgY29uc3QgY29udGFpbmVyID0gd3JhcChjb250YWluZXJQYXJ0Ll8kc3RhcnROb2RlKS5wYXJlbnROb2RlO1xuICAgIGNvbnN0IHJlZk5vZGUgPSByZWZQYXJ0ID09PSB1bmRlZmluZWQgPyBjb250YWluZXJQYXJ0Ll8kZW5kTm9kZSA6IHJlZlBhcnQuXyRzdGFydE5vZGU7XG4gICAgaWYgKHBhcnQgPT09IHVuZGVmaW5lZCkge1xuICAgICAgICBjb25zdCBzdGFydE5vZGUgPSB3cmFwKGNvbnRhaW5lcikuaW5zZXJ0QmVmb3JlKGNyZWF0ZU1hcmtlcigpLCByZWZOb2RlKTtcbiAgICAgICAgY29uc3QgZW5kTm9kZSA9IHdyYXAoY29udGFpbmVyKS5pbnNlcnRCZWZvcmUoY3JlYXRlTWFya2VyKCksIHJlZk5vZGUpO1xuICAgICAgICBwYXJ0ID0gbmV3IENoaWxkUGFydChzdGFydE5vZGUsIGVuZE5vZGUsIGNvbnRhaW5lclBhcnQsIGNvbnRhaW5lclBhcnQub3B0aW9ucyk7XG4gICAgfVxuICAgIGVsc2Uge1xuICAgICAgICBjb25zdCBlbmROb2RlID0gd3JhcChwYXJ0Ll8kZW5kTm9kZSkubmV4dFNpYmxpbmc7XG4gICAgICAgIGNvbnN0IG9sZFBhcmVudCA9IHBhcnQuXyRwYXJlbnQ7XG4gICAgICAgIGNvbnN0IHBhcmVudENoYW5nZWQgPSBvbGRQYXJlbnQgIT09IGNvbnRhaW5lclBhcnQ7XG4gICAgICAgIGlmIChwYXJlbnRDaGFuZ2VkKSB7XG4gICAgICAgICAgICBwYXJ0Ll8kcmVwYXJlbnREaXNjb25uZWN0YWJsZXM/Lihjb250YWluZXJQYXJ0KTtcbiAgICAgICAgICAgIC8vIE5vdGUgdGhhdCBhbHRob3VnaCBgXyRyZXBhcmVudERpc2Nvbm5lY3RhYmxlc2AgdXBkYXRlcyB0aGUgcGFydCdzXG4gICAgICAgICAgICAvLyBgXyRwYXJlbnRgIHJlZmVyZW5jZSBhZnRlciB1bmxpbmtpbmcgZnJvbSBpdHMgY3VycmVudCBwYXJlbnQsIHRoYXRcbiAgICAgICAgICAgIC8vIG1ldGhvZCBvbmx5IGV4aXN0cyBpZiBEaXNjb25uZWN0YWJsZXMgYXJlIHByZXNlbnQsIHNvIHdlIG5lZWQgdG9cbiAgICAgICAgICAgIC8vIHVuY29uZGl0aW9uYWxseSBzZXQgaXQgaGVyZVxuICAgICAgICAgICAgcGFydC5fJHBhcmVudCA9IGNvbnRhaW5lclBhcnQ7XG4gICAgICAgICAgICAvLyBTaW5jZSB0aGUgXyRpc0Nvbm5lY3RlZCBnZXR0ZXIgaXMgc29tZXdoYXQgY29zdGx5LCBvbmx5XG4gICAgICAgICAgICAvLyByZWFkIGl0IG9uY2Ugd2Uga25vdyB0aGUgc3VidHJlZSBoYXMgZGlyZWN0aXZlcyB0aGF0IG5lZWRcbiAgICAgICAgICAgIC8vIHRvIGJlIG5vdGlmaWVkXG4gICAgICAgICAgICBsZXQgbmV3Q29ubmVjdGlvblN0YXRlO1xuICAgICAgICAgICAgaWYgKHBhcnQuXyRub3RpZnlDb25uZWN0aW9uQ2hhbmdlZCAhPT0gdW5kZWZpbmVkICYmXG4gICAgICAgICAgICAgICAgKG5ld0Nvbm5lY3Rpb25TdGF0ZSA9IGNvbnRhaW5lclBhcnQuXyRpc0Nvbm5lY3RlZCkgIT09XG4gICAgICAgICAgICAgICAgICAgIG9sZFBhcmVudC5fJGlzQ29ubmVjdGVkKSB7XG4gICAgICAgICAgICAgICAgcGFydC5fJG5vdGlmeUNvbm5lY3Rpb25DaGFuZ2VkKG5ld0Nvbm5lY3Rpb25TdGF0ZSk7XG4gICAgICAgICAgICB9XG4gICAgICAgIH1cbiAgICAgICAgaWYgKGVuZE5vZGUgIT09IHJlZk5vZGUgfHwgcGFyZW50Q2hhbmdlZCkge1xuICAgICAgICAgICAgbGV0IHN0YXJ0ID0gcGFydC5fJHN0YXJ0Tm9kZTtcbiAgICAgICAgICAgIHdoaWxlIChzdGFydCAhPT0gZW5kTm9kZSkge1xuICAgICAgICAgICAgICAgIGNvbnN0IG4gPSB3cmFwKHN0YXJ0KS5uZXh0U2libGluZztcbiAgICAgICAgICAgICAgICB3cmFwKGNvbnRhaW5lcikuaW5zZXJ0QmVmb3JlKHN0YXJ0LCByZWZOb2RlKTtcbiAgICAgICAgICAgICAgICBzdGFydCA9IG47XG4gICAgICAgICAgICB9XG4gICAgICAgIH1cbiAgICB9XG4gICAgcmV0dXJuIHBhcnQ7XG59O1xuLyoqXG4gKiBTZXRzIHRoZSB2YWx1ZSBvZiBhIFBhcnQuXG4gKlxuICogTm90ZSB0aGF0IHRoaXMgc2hvdWxkIG9ubHkgYmUgdXNlZCB0byBzZXQvdXBkYXRlIHRoZSB2YWx1ZSBvZiB1c2VyLWNyZWF0ZWRcbiAqIHBhcnRzIChpLmUuIHRob3NlIGNyZWF0ZWQgdXNpbmcgYGluc2VydFBhcnRgKTsgaXQgc2hvdWxkIG5vdCBiZSB1c2VkXG4gKiBieSBkaXJlY3RpdmVzIHRvIHNldCB0aGUgdmFsdWUgb2YgdGhlIGRpcmVjdGl2ZSdzIGNvbnRhaW5lciBwYXJ0LiBEaXJlY3RpdmVzXG4gKiBzaG91bGQgcmV0dXJuIGEgdmFsdWUgZnJvbSBgdXBkYXRlYC9gcmVuZGVyYCB0byB1cGRhdGUgdGhlaXIgcGFydCBzdGF0ZS5cbiAqXG4gKiBGb3IgZGlyZWN0aXZlcyB0aGF0IHJlcXVpcmUgc2V0dGluZyB0aGVpciBwYXJ0IHZhbHVlIGFzeW5jaHJvbm91c2x5LCB0aGV5XG4gKiBzaG91bGQgZXh0ZW5kIGBBc3luY0RpcmVjdGl2ZWAgYW5kIGNhbGwgYHRoaXMuc2V0VmFsdWUoKWAuXG4gKlxuICogQHBhcmFtIHBhcnQgUGFydCB0byBzZXRcbiAqIEBwYXJhbSB2YWx1ZSBWYWx1ZSB0byBzZXRcbiAqIEBwYXJhbSBpbmRleCBGb3IgYEF0dHJpYnV0ZVBhcnRgcywgdGhlIGluZGV4IHRvIHNldFxuICogQHBhcmFtIGRpcmVjdGl2ZVBhcmVudCBVc2VkIGludGVybmFsbHk7IHNob3VsZCBub3QgYmUgc2V0IGJ5IHVzZXJcbiAqL1xuZXhwb3J0IGNvbnN0IHNldENoaWxkUGFydFZhbHVlID0gKHBhcnQsIHZhbHVlLCBkaXJlY3RpdmVQYXJlbnQgPSBwYXJ0KSA9PiB7XG4gICAgcGFydC5fJHNldFZhbHVlKHZhbHVlLCBkaXJlY3RpdmVQYXJlbnQpO1xuICAgIHJldHVybiBwYXJ0O1xufTtcbi8vIEEgc2VudGluZWwgdmFsdWUgdGhhdCBjYW4gbmV2ZXIgYXBwZWFyIGFzIGEgcGFydCB2YWx1ZSBleGNlcHQgd2hlbiBzZXQgYnlcbi8vIGxpdmUoKS4gVXNlZCB0byBmb3JjZSBhIGRpcnR5LWNoZWNrIHRvIGZhaWwgYW5kIGNhdXNlIGEgcmUtcmVuZGVyLlxuY29uc3QgUkVTRVRfVkFMVUUgPSB7fTtcbi8qKlxuICogU2V0cyB0aGUgY29tbWl0dGVkIHZhbHVlIG9mIGEgQ2hpbGRQYXJ0IGRpcmVjdGx5IHdpdGhvdXQgdHJpZ2dlcmluZyB0aGVcbiAqIGNvbW1pdCBzdGFnZSBvZiB0aGUgcGFydC5cbiAqXG4gKiBUaGlzIGlzIHVzZWZ1bCBpbiBjYXNlcyB3aGVyZSBhIGRpcmVjdGl2ZSBuZWVkcyB0byB1cGRhdGUgdGhlIHBhcnQgc3VjaFxuICogdGhhdCB0aGUgbmV4dCB1cGRhdGUgZGV0ZWN0cyBhIHZhbHVlIGNoYW5nZSBvciBub3QuIFdoZW4gdmFsdWUgaXMgb21pdHRlZCxcbiAqIHRoZSBuZXh0IHVwZGF0ZSB3aWxsIGJlIGd1YXJhbnRlZWQgdG8gYmUgZGV0ZWN0ZWQgYXMgYSBjaGFuZ2UuXG4gKlxuICogQHBhcmFtIHBhcnRcbiAqIEBwYXJhbSB2YWx1ZVxuICovXG5leHBvcnQgY29uc3Qgc2V0Q29tbWl0dGVkVmFsdWUgPSAocGFydCwgdmFsdWUgPSBSRVNFVF9WQUxVRSkgPT4gKHBhcnQuXyRjb21taXR0ZWRWYWx1ZSA9IHZhbHVlKTtcbi8qKlxuICogUmV0dXJucyB0aGUgY29tbWl0dGVkIHZhbHVlIG9mIGEgQ2hpbGRQYXJ0LlxuICpcbiAqIFRoZSBjb21taXR0ZWQgdmFsdWUgaXMgdXNlZCBmb3IgY2hhbmdlIGRldGVjdGlvbiBhbmQgZWZmaWNpZW50IHVwZGF0ZXMgb2ZcbiAqIHRoZSBwYXJ0LiBJdCBjYW4gZGlmZmVyIGZyb20gdGhlIHZhbHVlIHNldCBieSB0aGUgdGVtcGxhdGUgb3IgZGlyZWN0aXZlIGluXG4gKiBjYXNlcyB3aGVyZSB0aGUgdGVtcGxhdGUgdmFsdWUgaXMgdHJhbnNmb3JtZWQgYmVmb3JlIGJlaW5nIGNvbW1pdHRlZC5cbiAqXG4gKiAtIGBUZW1wbGF0ZVJlc3VsdGBzIGFyZSBjb21taXR0ZWQgYXMgYSBgVGVtcGxhdGVJbnN0YW5jZWBcbiAqIC0gSXRlcmFibGVzIGFyZSBjb21taXR0ZWQgYXMgYEFycmF5PENoaWxkUGFydD5gXG4gKiAtIEFsbCBvdGhlciB0eXBlcyBhcmUgY29tbWl0dGVkIGFzIHRoZSB0ZW1wbGF0ZSB2YWx1ZSBvciB2YWx1ZSByZXR1cm5lZCBvclxuICogICBzZXQgYnkgYSBkaXJlY3RpdmUuXG4gKlxuICogQHBhcmFtIHBhcnRcbiAqL1xuZXhwb3J0IGNvbnN0IGdldENvbW1pdHRlZFZhbHVlID0gKHBhcnQpID0+IHBhcnQuXyRjb21taXR0ZWRWYWx1ZTtcbi8qKlxuICogUmVtb3ZlcyBhIENoaWxkUGFydCBmcm9tIHRoZSBET00sIGluY2x1ZGluZyBhbnkgb2YgaXRzIGNvbnRlbnQgYW5kIG1hcmtlcnMuXG4gKlxuICogTm90ZTogVGhlIG9ubHkgZGlmZmVyZW5jZSBiZXR3ZWVuIHRoaXMgYW5kIGNsZWFyUGFydCgpIGlzIHRoYXQgdGhpcyBhbHNvXG4gKiByZW1vdmVzIHRoZSBwYXJ0J3Mgc3RhcnQgbm9kZS4gVGhpcyBtZWFucyB0aGF0IHRoZSBDaGlsZFBhcnQgbXVzdCBvd24gaXRzXG4gKiBzdGFydCBub2RlLCBpZSBpdCBtdXN0IGJlIGEgbWFya2VyIG5vZGUgc3BlY2lmaWNhbGx5IGZvciB0aGlzIHBhcnQgYW5kIG5vdCBhblxuICogYW5jaG9yIGZyb20gc3Vycm91bmRpbmcgY29udGVudC5cbiAqXG4gKiBAcGFyYW0gcGFydCBUaGUgUGFydCB0byByZW1vdmVcbiAqL1xuZXhwb3J0IGNvbnN0IHJlbW92ZVBhcnQgPSAocGFydCkgPT4ge1xuICAgIHBhcnQuXyRjbGVhcigpO1xuICAgIHBhcnQuXyRzdGFydE5vZGUucmVtb3ZlKCk7XG59O1xuZXhwb3J0IGNvbnN0IGNsZWFyUGFydCA9IChwYXJ0KSA9PiB7XG4gICAgcGFydC5fJGNsZWFyKCk7XG59O1xuLy8jIHNvdXJjZU1hcHBpbmdVUkw9ZGlyZWN0aXZlLWhlbHBlcnMuanMubWFwIl0sIm5hbWVzIjpbXSwic291cmNlUm9vdCI6IiJ9\n//# sourceURL=webpack-internal:///./node_modules/lit-html/development/directive-helpers.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/lit-html/development/directive.js":
-/*!********************************************************!*\
-  !*** ./node_modules/lit-html/development/directive.js ***!
-  \********************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   Directive: function() { return /* binding */ Directive; },\n/* harmony export */   PartType: function() { return /* binding */ PartType; },\n/* harmony export */   directive: function() { return /* binding */ directive; }\n/* harmony export */ });\n/**\n * @license\n * Copyright 2017 Google LLC\n * SPDX-License-Identifier: BSD-3-Clause\n */\nconst PartType = {\n    ATTRIBUTE: 1,\n    CHILD: 2,\n    PROPERTY: 3,\n    BOOLEAN_ATTRIBUTE: 4,\n    EVENT: 5,\n    ELEMENT: 6,\n};\n/**\n * Creates a user-facing directive function from a Directive class. This\n * function has the same parameters as the directive's render() method.\n */\nconst directive = (c) => (...values) => ({\n    // This property needs to remain unminified.\n    ['_$litDirective$']: c,\n    values,\n});\n/**\n * Base class for creating custom directives. Users should extend this class,\n * implement `render` and/or `update`, and then pass their subclass to\n * `directive`.\n */\nclass Directive {\n    constructor(_partInfo) { }\n    // See comment in Disconnectable interface for why this is a getter\n    get _$isConnected() {\n        return this._$parent._$isConnected;\n    }\n    /** @internal */\n    _$initialize(part, parent, attributeIndex) {\n        this.__part = part;\n        this._$parent = parent;\n        this.__attributeIndex = attributeIndex;\n    }\n    /** @internal */\n    _$resolve(part, props) {\n        return this.update(part, props);\n    }\n    update(_part, props) {\n        return this.render(...props);\n    }\n}\n//# sourceMappingURL=directive.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvbGl0LWh0bWwvZGV2ZWxvcG1lbnQvZGlyZWN0aXZlLmpzIiwibWFwcGluZ3MiOiI7Ozs7OztBQUFBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDTztBQUNQO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDTztBQUNQO0FBQ0E7QUFDQTtBQUNBLENBQUM7QUFDRDtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ087QUFDUDtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBIiwic291cmNlcyI6WyJ3ZWJwYWNrOi8vX05fRS8uL25vZGVfbW9kdWxlcy9saXQtaHRtbC9kZXZlbG9wbWVudC9kaXJlY3RpdmUuanM/YWY5NSJdLCJzb3VyY2VzQ29udGVudCI6WyIvKipcbiAqIEBsaWNlbnNlXG4gKiBDb3B5cmlnaHQgMjAxNyBHb29nbGUgTExDXG4gKiBTUERYLUxpY2Vuc2UtSWRlbnRpZmllcjogQlNELTMtQ2xhdXNlXG4gKi9cbmV4cG9ydCBjb25zdCBQYXJ0VHlwZSA9IHtcbiAgICBBVFRSSUJVVEU6IDEsXG4gICAgQ0hJTEQ6IDIsXG4gICAgUFJPUEVSVFk6IDMsXG4gICAgQk9PTEVBTl9BVFRSSUJVVEU6IDQsXG4gICAgRVZFTlQ6IDUsXG4gICAgRUxFTUVOVDogNixcbn07XG4vKipcbiAqIENyZWF0ZXMgYSB1c2VyLWZhY2luZyBkaXJlY3RpdmUgZnVuY3Rpb24gZnJvbSBhIERpcmVjdGl2ZSBjbGFzcy4gVGhpc1xuICogZnVuY3Rpb24gaGFzIHRoZSBzYW1lIHBhcmFtZXRlcnMgYXMgdGhlIGRpcmVjdGl2ZSdzIHJlbmRlcigpIG1ldGhvZC5cbiAqL1xuZXhwb3J0IGNvbnN0IGRpcmVjdGl2ZSA9IChjKSA9PiAoLi4udmFsdWVzKSA9PiAoe1xuICAgIC8vIFRoaXMgcHJvcGVydHkgbmVlZHMgdG8gcmVtYWluIHVubWluaWZpZWQuXG4gICAgWydfJGxpdERpcmVjdGl2ZSQnXTogYyxcbiAgICB2YWx1ZXMsXG59KTtcbi8qKlxuICogQmFzZSBjbGFzcyBmb3IgY3JlYXRpbmcgY3VzdG9tIGRpcmVjdGl2ZXMuIFVzZXJzIHNob3VsZCBleHRlbmQgdGhpcyBjbGFzcyxcbiAqIGltcGxlbWVudCBgcmVuZGVyYCBhbmQvb3IgYHVwZGF0ZWAsIGFuZCB0aGVuIHBhc3MgdGhlaXIgc3ViY2xhc3MgdG9cbiAqIGBkaXJlY3RpdmVgLlxuICovXG5leHBvcnQgY2xhc3MgRGlyZWN0aXZlIHtcbiAgICBjb25zdHJ1Y3RvcihfcGFydEluZm8pIHsgfVxuICAgIC8vIFNlZSBjb21tZW50IGluIERpc2Nvbm5lY3RhYmxlIGludGVyZmFjZSBmb3Igd2h5IHRoaXMgaXMgYSBnZXR0ZXJcbiAgICBnZXQgXyRpc0Nvbm5lY3RlZCgpIHtcbiAgICAgICAgcmV0dXJuIHRoaXMuXyRwYXJlbnQuXyRpc0Nvbm5lY3RlZDtcbiAgICB9XG4gICAgLyoqIEBpbnRlcm5hbCAqL1xuICAgIF8kaW5pdGlhbGl6ZShwYXJ0LCBwYXJlbnQsIGF0dHJpYnV0ZUluZGV4KSB7XG4gICAgICAgIHRoaXMuX19wYXJ0ID0gcGFydDtcbiAgICAgICAgdGhpcy5fJHBhcmVudCA9IHBhcmVudDtcbiAgICAgICAgdGhpcy5fX2F0dHJpYnV0ZUluZGV4ID0gYXR0cmlidXRlSW5kZXg7XG4gICAgfVxuICAgIC8qKiBAaW50ZXJuYWwgKi9cbiAgICBfJHJlc29sdmUocGFydCwgcHJvcHMpIHtcbiAgICAgICAgcmV0dXJuIHRoaXMudXBkYXRlKHBhcnQsIHByb3BzKTtcbiAgICB9XG4gICAgdXBkYXRlKF9wYXJ0LCBwcm9wcykge1xuICAgICAgICByZXR1cm4gdGhpcy5yZW5kZXIoLi4ucHJvcHMpO1xuICAgIH1cbn1cbi8vIyBzb3VyY2VNYXBwaW5nVVJMPWRpcmVjdGl2ZS5qcy5tYXAiXSwibmFtZXMiOltdLCJzb3VyY2VSb290IjoiIn0=\n//# sourceURL=webpack-internal:///./node_modules/lit-html/development/directive.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/lit-html/development/directives/class-map.js":
-/*!*******************************************************************!*\
-  !*** ./node_modules/lit-html/development/directives/class-map.js ***!
-  \*******************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   classMap: function() { return /* binding */ classMap; }\n/* harmony export */ });\n/* harmony import */ var _lit_html_js__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! ../lit-html.js */ \"./node_modules/lit-html/development/lit-html.js\");\n/* harmony import */ var _directive_js__WEBPACK_IMPORTED_MODULE_1__ = __webpack_require__(/*! ../directive.js */ \"./node_modules/lit-html/development/directive.js\");\n/**\n * @license\n * Copyright 2018 Google LLC\n * SPDX-License-Identifier: BSD-3-Clause\n */\n\n\nclass ClassMapDirective extends _directive_js__WEBPACK_IMPORTED_MODULE_1__.Directive {\n    constructor(partInfo) {\n        super(partInfo);\n        if (partInfo.type !== _directive_js__WEBPACK_IMPORTED_MODULE_1__.PartType.ATTRIBUTE ||\n            partInfo.name !== 'class' ||\n            partInfo.strings?.length > 2) {\n            throw new Error('`classMap()` can only be used in the `class` attribute ' +\n                'and must be the only part in the attribute.');\n        }\n    }\n    render(classInfo) {\n        // Add spaces to ensure separation from static classes\n        return (' ' +\n            Object.keys(classInfo)\n                .filter((key) => classInfo[key])\n                .join(' ') +\n            ' ');\n    }\n    update(part, [classInfo]) {\n        // Remember dynamic classes on the first render\n        if (this._previousClasses === undefined) {\n            this._previousClasses = new Set();\n            if (part.strings !== undefined) {\n                this._staticClasses = new Set(part.strings\n                    .join(' ')\n                    .split(/\\s/)\n                    .filter((s) => s !== ''));\n            }\n            for (const name in classInfo) {\n                if (classInfo[name] && !this._staticClasses?.has(name)) {\n                    this._previousClasses.add(name);\n                }\n            }\n            return this.render(classInfo);\n        }\n        const classList = part.element.classList;\n        // Remove old classes that no longer apply\n        for (const name of this._previousClasses) {\n            if (!(name in classInfo)) {\n                classList.remove(name);\n                this._previousClasses.delete(name);\n            }\n        }\n        // Add or remove classes based on their classMap value\n        for (const name in classInfo) {\n            // We explicitly want a loose truthy check of `value` because it seems\n            // more convenient that '' and 0 are skipped.\n            const value = !!classInfo[name];\n            if (value !== this._previousClasses.has(name) &&\n                !this._staticClasses?.has(name)) {\n                if (value) {\n                    classList.add(name);\n                    this._previousClasses.add(name);\n                }\n                else {\n                    classList.remove(name);\n                    this._previousClasses.delete(name);\n                }\n            }\n        }\n        return _lit_html_js__WEBPACK_IMPORTED_MODULE_0__.noChange;\n    }\n}\n/**\n * A directive that applies dynamic CSS classes.\n *\n * This must be used in the `class` attribute and must be the only part used in\n * the attribute. It takes each property in the `classInfo` argument and adds\n * the property name to the element's `classList` if the property value is\n * truthy; if the property value is falsy, the property name is removed from\n * the element's `class`.\n *\n * For example `{foo: bar}` applies the class `foo` if the value of `bar` is\n * truthy.\n *\n * @param classInfo\n */\nconst classMap = (0,_directive_js__WEBPACK_IMPORTED_MODULE_1__.directive)(ClassMapDirective);\n//# sourceMappingURL=class-map.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvbGl0LWh0bWwvZGV2ZWxvcG1lbnQvZGlyZWN0aXZlcy9jbGFzcy1tYXAuanMiLCJtYXBwaW5ncyI6Ijs7Ozs7O0FBQUE7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUMwQztBQUN3QjtBQUNsRSxnQ0FBZ0Msb0RBQVM7QUFDekM7QUFDQTtBQUNBLDhCQUE4QixtREFBUTtBQUN0QztBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0EsZUFBZSxrREFBUTtBQUN2QjtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0EsV0FBVztBQUNYO0FBQ0E7QUFDQSxpQkFBaUIsU0FBUztBQUMxQjtBQUNBO0FBQ0E7QUFDQTtBQUNPLGlCQUFpQix3REFBUztBQUNqQyIsInNvdXJjZXMiOlsid2VicGFjazovL19OX0UvLi9ub2RlX21vZHVsZXMvbGl0LWh0bWwvZGV2ZWxvcG1lbnQvZGlyZWN0aXZlcy9jbGFzcy1tYXAuanM/ZmE3OSJdLCJzb3VyY2VzQ29udGVudCI6WyIvKipcbiAqIEBsaWNlbnNlXG4gKiBDb3B5cmlnaHQgMjAxOCBHb29nbGUgTExDXG4gKiBTUERYLUxpY2Vuc2UtSWRlbnRpZmllcjogQlNELTMtQ2xhdXNlXG4gKi9cbmltcG9ydCB7IG5vQ2hhbmdlIH0gZnJvbSAnLi4vbGl0LWh0bWwuanMnO1xuaW1wb3J0IHsgZGlyZWN0aXZlLCBEaXJlY3RpdmUsIFBhcnRUeXBlLCB9IGZyb20gJy4uL2RpcmVjdGl2ZS5qcyc7XG5jbGFzcyBDbGFzc01hcERpcmVjdGl2ZSBleHRlbmRzIERpcmVjdGl2ZSB7XG4gICAgY29uc3RydWN0b3IocGFydEluZm8pIHtcbiAgICAgICAgc3VwZXIocGFydEluZm8pO1xuICAgICAgICBpZiAocGFydEluZm8udHlwZSAhPT0gUGFydFR5cGUuQVRUUklCVVRFIHx8XG4gICAgICAgICAgICBwYXJ0SW5mby5uYW1lICE9PSAnY2xhc3MnIHx8XG4gICAgICAgICAgICBwYXJ0SW5mby5zdHJpbmdzPy5sZW5ndGggPiAyKSB7XG4gICAgICAgICAgICB0aHJvdyBuZXcgRXJyb3IoJ2BjbGFzc01hcCgpYCBjYW4gb25seSBiZSB1c2VkIGluIHRoZSBgY2xhc3NgIGF0dHJpYnV0ZSAnICtcbiAgICAgICAgICAgICAgICAnYW5kIG11c3QgYmUgdGhlIG9ubHkgcGFydCBpbiB0aGUgYXR0cmlidXRlLicpO1xuICAgICAgICB9XG4gICAgfVxuICAgIHJlbmRlcihjbGFzc0luZm8pIHtcbiAgICAgICAgLy8gQWRkIHNwYWNlcyB0byBlbnN1cmUgc2VwYXJhdGlvbiBmcm9tIHN0YXRpYyBjbGFzc2VzXG4gICAgICAgIHJldHVybiAoJyAnICtcbiAgICAgICAgICAgIE9iamVjdC5rZXlzKGNsYXNzSW5mbylcbiAgICAgICAgICAgICAgICAuZmlsdGVyKChrZXkpID0+IGNsYXNzSW5mb1trZXldKVxuICAgICAgICAgICAgICAgIC5qb2luKCcgJykgK1xuICAgICAgICAgICAgJyAnKTtcbiAgICB9XG4gICAgdXBkYXRlKHBhcnQsIFtjbGFzc0luZm9dKSB7XG4gICAgICAgIC8vIFJlbWVtYmVyIGR5bmFtaWMgY2xhc3NlcyBvbiB0aGUgZmlyc3QgcmVuZGVyXG4gICAgICAgIGlmICh0aGlzLl9wcmV2aW91c0NsYXNzZXMgPT09IHVuZGVmaW5lZCkge1xuICAgICAgICAgICAgdGhpcy5fcHJldmlvdXNDbGFzc2VzID0gbmV3IFNldCgpO1xuICAgICAgICAgICAgaWYgKHBhcnQuc3RyaW5ncyAhPT0gdW5kZWZpbmVkKSB7XG4gICAgICAgICAgICAgICAgdGhpcy5fc3RhdGljQ2xhc3NlcyA9IG5ldyBTZXQocGFydC5zdHJpbmdzXG4gICAgICAgICAgICAgICAgICAgIC5qb2luKCcgJylcbiAgICAgICAgICAgICAgICAgICAgLnNwbGl0KC9cXHMvKVxuICAgICAgICAgICAgICAgICAgICAuZmlsdGVyKChzKSA9PiBzICE9PSAnJykpO1xuICAgICAgICAgICAgfVxuICAgICAgICAgICAgZm9yIChjb25zdCBuYW1lIGluIGNsYXNzSW5mbykge1xuICAgICAgICAgICAgICAgIGlmIChjbGFzc0luZm9bbmFtZV0gJiYgIXRoaXMuX3N0YXRpY0NsYXNzZXM/LmhhcyhuYW1lKSkge1xuICAgICAgICAgICAgICAgICAgICB0aGlzLl9wcmV2aW91c0NsYXNzZXMuYWRkKG5hbWUpO1xuICAgICAgICAgICAgICAgIH1cbiAgICAgICAgICAgIH1cbiAgICAgICAgICAgIHJldHVybiB0aGlzLnJlbmRlcihjbGFzc0luZm8pO1xuICAgICAgICB9XG4gICAgICAgIGNvbnN0IGNsYXNzTGlzdCA9IHBhcnQuZWxlbWVudC5jbGFzc0xpc3Q7XG4gICAgICAgIC8vIFJlbW92ZSBvbGQgY2xhc3NlcyB0aGF0IG5vIGxvbmdlciBhcHBseVxuICAgICAgICBmb3IgKGNvbnN0IG5hbWUgb2YgdGhpcy5fcHJldmlvdXNDbGFzc2VzKSB7XG4gICAgICAgICAgICBpZiAoIShuYW1lIGluIGNsYXNzSW5mbykpIHtcbiAgICAgICAgICAgICAgICBjbGFzc0xpc3QucmVtb3ZlKG5hbWUpO1xuICAgICAgICAgICAgICAgIHRoaXMuX3ByZXZpb3VzQ2xhc3Nlcy5kZWxldGUobmFtZSk7XG4gICAgICAgICAgICB9XG4gICAgICAgIH1cbiAgICAgICAgLy8gQWRkIG9yIHJlbW92ZSBjbGFzc2VzIGJhc2VkIG9uIHRoZWlyIGNsYXNzTWFwIHZhbHVlXG4gICAgICAgIGZvciAoY29uc3QgbmFtZSBpbiBjbGFzc0luZm8pIHtcbiAgICAgICAgICAgIC8vIFdlIGV4cGxpY2l0bHkgd2FudCBhIGxvb3NlIHRydXRoeSBjaGVjayBvZiBgdmFsdWVgIGJlY2F1c2UgaXQgc2VlbXNcbiAgICAgICAgICAgIC8vIG1vcmUgY29udmVuaWVudCB0aGF0ICcnIGFuZCAwIGFyZSBza2lwcGVkLlxuICAgICAgICAgICAgY29uc3QgdmFsdWUgPSAhIWNsYXNzSW5mb1tuYW1lXTtcbiAgICAgICAgICAgIGlmICh2YWx1ZSAhPT0gdGhpcy5fcHJldmlvdXNDbGFzc2VzLmhhcyhuYW1lKSAmJlxuICAgICAgICAgICAgICAgICF0aGlzLl9zdGF0aWNDbGFzc2VzPy5oYXMobmFtZSkpIHtcbiAgICAgICAgICAgICAgICBpZiAodmFsdWUpIHtcbiAgICAgICAgICAgICAgICAgICAgY2xhc3NMaXN0LmFkZChuYW1lKTtcbiAgICAgICAgICAgICAgICAgICAgdGhpcy5fcHJldmlvdXNDbGFzc2VzLmFkZChuYW1lKTtcbiAgICAgICAgICAgICAgICB9XG4gICAgICAgICAgICAgICAgZWxzZSB7XG4gICAgICAgICAgICAgICAgICAgIGNsYXNzTGlzdC5yZW1vdmUobmFtZSk7XG4gICAgICAgICAgICAgICAgICAgIHRoaXMuX3ByZXZpb3VzQ2xhc3Nlcy5kZWxldGUobmFtZSk7XG4gICAgICAgICAgICAgICAgfVxuICAgICAgICAgICAgfVxuICAgICAgICB9XG4gICAgICAgIHJldHVybiBub0NoYW5nZTtcbiAgICB9XG59XG4vKipcbiAqIEEgZGlyZWN0aXZlIHRoYXQgYXBwbGllcyBkeW5hbWljIENTUyBjbGFzc2VzLlxuICpcbiAqIFRoaXMgbXVzdCBiZSB1c2VkIGluIHRoZSBgY2xhc3NgIGF0dHJpYnV0ZSBhbmQgbXVzdCBiZSB0aGUgb25seSBwYXJ0IHVzZWQgaW5cbiAqIHRoZSBhdHRyaWJ1dGUuIEl0IHRha2VzIGVhY2ggcHJvcGVydHkgaW4gdGhlIGBjbGFzc0luZm9gIGFyZ3VtZW50IGFuZCBhZGRzXG4gKiB0aGUgcHJvcGVydHkgbmFtZSB0byB0aGUgZWxlbWVudCdzIGBjbGFzc0xpc3RgIGlmIHRoZSBwcm9wZXJ0eSB2YWx1ZSBpc1xuICogdHJ1dGh5OyBpZiB0aGUgcHJvcGVydHkgdmFsdWUgaXMgZmFsc3ksIHRoZSBwcm9wZXJ0eSBuYW1lIGlzIHJlbW92ZWQgZnJvbVxuICogdGhlIGVsZW1lbnQncyBgY2xhc3NgLlxuICpcbiAqIEZvciBleGFtcGxlIGB7Zm9vOiBiYXJ9YCBhcHBsaWVzIHRoZSBjbGFzcyBgZm9vYCBpZiB0aGUgdmFsdWUgb2YgYGJhcmAgaXNcbiAqIHRydXRoeS5cbiAqXG4gKiBAcGFyYW0gY2xhc3NJbmZvXG4gKi9cbmV4cG9ydCBjb25zdCBjbGFzc01hcCA9IGRpcmVjdGl2ZShDbGFzc01hcERpcmVjdGl2ZSk7XG4vLyMgc291cmNlTWFwcGluZ1VSTD1jbGFzcy1tYXAuanMubWFwIl0sIm5hbWVzIjpbXSwic291cmNlUm9vdCI6IiJ9\n//# sourceURL=webpack-internal:///./node_modules/lit-html/development/directives/class-map.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/lit-html/development/directives/if-defined.js":
-/*!********************************************************************!*\
-  !*** ./node_modules/lit-html/development/directives/if-defined.js ***!
-  \********************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   ifDefined: function() { return /* binding */ ifDefined; }\n/* harmony export */ });\n/* harmony import */ var _lit_html_js__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! ../lit-html.js */ \"./node_modules/lit-html/development/lit-html.js\");\n/**\n * @license\n * Copyright 2018 Google LLC\n * SPDX-License-Identifier: BSD-3-Clause\n */\n\n/**\n * For AttributeParts, sets the attribute if the value is defined and removes\n * the attribute if the value is undefined.\n *\n * For other part types, this directive is a no-op.\n */\nconst ifDefined = (value) => value ?? _lit_html_js__WEBPACK_IMPORTED_MODULE_0__.nothing;\n//# sourceMappingURL=if-defined.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvbGl0LWh0bWwvZGV2ZWxvcG1lbnQvZGlyZWN0aXZlcy9pZi1kZWZpbmVkLmpzIiwibWFwcGluZ3MiOiI7Ozs7O0FBQUE7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUN5QztBQUN6QztBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDTyxzQ0FBc0MsaURBQU87QUFDcEQiLCJzb3VyY2VzIjpbIndlYnBhY2s6Ly9fTl9FLy4vbm9kZV9tb2R1bGVzL2xpdC1odG1sL2RldmVsb3BtZW50L2RpcmVjdGl2ZXMvaWYtZGVmaW5lZC5qcz9jM2I3Il0sInNvdXJjZXNDb250ZW50IjpbIi8qKlxuICogQGxpY2Vuc2VcbiAqIENvcHlyaWdodCAyMDE4IEdvb2dsZSBMTENcbiAqIFNQRFgtTGljZW5zZS1JZGVudGlmaWVyOiBCU0QtMy1DbGF1c2VcbiAqL1xuaW1wb3J0IHsgbm90aGluZyB9IGZyb20gJy4uL2xpdC1odG1sLmpzJztcbi8qKlxuICogRm9yIEF0dHJpYnV0ZVBhcnRzLCBzZXRzIHRoZSBhdHRyaWJ1dGUgaWYgdGhlIHZhbHVlIGlzIGRlZmluZWQgYW5kIHJlbW92ZXNcbiAqIHRoZSBhdHRyaWJ1dGUgaWYgdGhlIHZhbHVlIGlzIHVuZGVmaW5lZC5cbiAqXG4gKiBGb3Igb3RoZXIgcGFydCB0eXBlcywgdGhpcyBkaXJlY3RpdmUgaXMgYSBuby1vcC5cbiAqL1xuZXhwb3J0IGNvbnN0IGlmRGVmaW5lZCA9ICh2YWx1ZSkgPT4gdmFsdWUgPz8gbm90aGluZztcbi8vIyBzb3VyY2VNYXBwaW5nVVJMPWlmLWRlZmluZWQuanMubWFwIl0sIm5hbWVzIjpbXSwic291cmNlUm9vdCI6IiJ9\n//# sourceURL=webpack-internal:///./node_modules/lit-html/development/directives/if-defined.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/lit-html/development/directives/private-async-helpers.js":
-/*!*******************************************************************************!*\
-  !*** ./node_modules/lit-html/development/directives/private-async-helpers.js ***!
-  \*******************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   Pauser: function() { return /* binding */ Pauser; },\n/* harmony export */   PseudoWeakRef: function() { return /* binding */ PseudoWeakRef; },\n/* harmony export */   forAwaitOf: function() { return /* binding */ forAwaitOf; }\n/* harmony export */ });\n/**\n * @license\n * Copyright 2021 Google LLC\n * SPDX-License-Identifier: BSD-3-Clause\n */\n// Note, this module is not included in package exports so that it's private to\n// our first-party directives. If it ends up being useful, we can open it up and\n// export it.\n/**\n * Helper to iterate an AsyncIterable in its own closure.\n * @param iterable The iterable to iterate\n * @param callback The callback to call for each value. If the callback returns\n * `false`, the loop will be broken.\n */\nconst forAwaitOf = async (iterable, callback) => {\n    for await (const v of iterable) {\n        if ((await callback(v)) === false) {\n            return;\n        }\n    }\n};\n/**\n * Holds a reference to an instance that can be disconnected and reconnected,\n * so that a closure over the ref (e.g. in a then function to a promise) does\n * not strongly hold a ref to the instance. Approximates a WeakRef but must\n * be manually connected & disconnected to the backing instance.\n */\nclass PseudoWeakRef {\n    constructor(ref) {\n        this._ref = ref;\n    }\n    /**\n     * Disassociates the ref with the backing instance.\n     */\n    disconnect() {\n        this._ref = undefined;\n    }\n    /**\n     * Reassociates the ref with the backing instance.\n     */\n    reconnect(ref) {\n        this._ref = ref;\n    }\n    /**\n     * Retrieves the backing instance (will be undefined when disconnected)\n     */\n    deref() {\n        return this._ref;\n    }\n}\n/**\n * A helper to pause and resume waiting on a condition in an async function\n */\nclass Pauser {\n    constructor() {\n        this._promise = undefined;\n        this._resolve = undefined;\n    }\n    /**\n     * When paused, returns a promise to be awaited; when unpaused, returns\n     * undefined. Note that in the microtask between the pauser being resumed\n     * an await of this promise resolving, the pauser could be paused again,\n     * hence callers should check the promise in a loop when awaiting.\n     * @returns A promise to be awaited when paused or undefined\n     */\n    get() {\n        return this._promise;\n    }\n    /**\n     * Creates a promise to be awaited\n     */\n    pause() {\n        this._promise ??= new Promise((resolve) => (this._resolve = resolve));\n    }\n    /**\n     * Resolves the promise which may be awaited\n     */\n    resume() {\n        this._resolve?.();\n        this._promise = this._resolve = undefined;\n    }\n}\n//# sourceMappingURL=private-async-helpers.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvbGl0LWh0bWwvZGV2ZWxvcG1lbnQvZGlyZWN0aXZlcy9wcml2YXRlLWFzeW5jLWhlbHBlcnMuanMiLCJtYXBwaW5ncyI6Ijs7Ozs7O0FBQUE7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNPO0FBQ1A7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ087QUFDUDtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNPO0FBQ1A7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBLHFEQUFxRDtBQUNyRDtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBIiwic291cmNlcyI6WyJ3ZWJwYWNrOi8vX05fRS8uL25vZGVfbW9kdWxlcy9saXQtaHRtbC9kZXZlbG9wbWVudC9kaXJlY3RpdmVzL3ByaXZhdGUtYXN5bmMtaGVscGVycy5qcz9jOWNjIl0sInNvdXJjZXNDb250ZW50IjpbIi8qKlxuICogQGxpY2Vuc2VcbiAqIENvcHlyaWdodCAyMDIxIEdvb2dsZSBMTENcbiAqIFNQRFgtTGljZW5zZS1JZGVudGlmaWVyOiBCU0QtMy1DbGF1c2VcbiAqL1xuLy8gTm90ZSwgdGhpcyBtb2R1bGUgaXMgbm90IGluY2x1ZGVkIGluIHBhY2thZ2UgZXhwb3J0cyBzbyB0aGF0IGl0J3MgcHJpdmF0ZSB0b1xuLy8gb3VyIGZpcnN0LXBhcnR5IGRpcmVjdGl2ZXMuIElmIGl0IGVuZHMgdXAgYmVpbmcgdXNlZnVsLCB3ZSBjYW4gb3BlbiBpdCB1cCBhbmRcbi8vIGV4cG9ydCBpdC5cbi8qKlxuICogSGVscGVyIHRvIGl0ZXJhdGUgYW4gQXN5bmNJdGVyYWJsZSBpbiBpdHMgb3duIGNsb3N1cmUuXG4gKiBAcGFyYW0gaXRlcmFibGUgVGhlIGl0ZXJhYmxlIHRvIGl0ZXJhdGVcbiAqIEBwYXJhbSBjYWxsYmFjayBUaGUgY2FsbGJhY2sgdG8gY2FsbCBmb3IgZWFjaCB2YWx1ZS4gSWYgdGhlIGNhbGxiYWNrIHJldHVybnNcbiAqIGBmYWxzZWAsIHRoZSBsb29wIHdpbGwgYmUgYnJva2VuLlxuICovXG5leHBvcnQgY29uc3QgZm9yQXdhaXRPZiA9IGFzeW5jIChpdGVyYWJsZSwgY2FsbGJhY2spID0+IHtcbiAgICBmb3IgYXdhaXQgKGNvbnN0IHYgb2YgaXRlcmFibGUpIHtcbiAgICAgICAgaWYgKChhd2FpdCBjYWxsYmFjayh2KSkgPT09IGZhbHNlKSB7XG4gICAgICAgICAgICByZXR1cm47XG4gICAgICAgIH1cbiAgICB9XG59O1xuLyoqXG4gKiBIb2xkcyBhIHJlZmVyZW5jZSB0byBhbiBpbnN0YW5jZSB0aGF0IGNhbiBiZSBkaXNjb25uZWN0ZWQgYW5kIHJlY29ubmVjdGVkLFxuICogc28gdGhhdCBhIGNsb3N1cmUgb3ZlciB0aGUgcmVmIChlLmcuIGluIGEgdGhlbiBmdW5jdGlvbiB0byBhIHByb21pc2UpIGRvZXNcbiAqIG5vdCBzdHJvbmdseSBob2xkIGEgcmVmIHRvIHRoZSBpbnN0YW5jZS4gQXBwcm94aW1hdGVzIGEgV2Vha1JlZiBidXQgbXVzdFxuICogYmUgbWFudWFsbHkgY29ubmVjdGVkICYgZGlzY29ubmVjdGVkIHRvIHRoZSBiYWNraW5nIGluc3RhbmNlLlxuICovXG5leHBvcnQgY2xhc3MgUHNldWRvV2Vha1JlZiB7XG4gICAgY29uc3RydWN0b3IocmVmKSB7XG4gICAgICAgIHRoaXMuX3JlZiA9IHJlZjtcbiAgICB9XG4gICAgLyoqXG4gICAgICogRGlzYXNzb2NpYXRlcyB0aGUgcmVmIHdpdGggdGhlIGJhY2tpbmcgaW5zdGFuY2UuXG4gICAgICovXG4gICAgZGlzY29ubmVjdCgpIHtcbiAgICAgICAgdGhpcy5fcmVmID0gdW5kZWZpbmVkO1xuICAgIH1cbiAgICAvKipcbiAgICAgKiBSZWFzc29jaWF0ZXMgdGhlIHJlZiB3aXRoIHRoZSBiYWNraW5nIGluc3RhbmNlLlxuICAgICAqL1xuICAgIHJlY29ubmVjdChyZWYpIHtcbiAgICAgICAgdGhpcy5fcmVmID0gcmVmO1xuICAgIH1cbiAgICAvKipcbiAgICAgKiBSZXRyaWV2ZXMgdGhlIGJhY2tpbmcgaW5zdGFuY2UgKHdpbGwgYmUgdW5kZWZpbmVkIHdoZW4gZGlzY29ubmVjdGVkKVxuICAgICAqL1xuICAgIGRlcmVmKCkge1xuICAgICAgICByZXR1cm4gdGhpcy5fcmVmO1xuICAgIH1cbn1cbi8qKlxuICogQSBoZWxwZXIgdG8gcGF1c2UgYW5kIHJlc3VtZSB3YWl0aW5nIG9uIGEgY29uZGl0aW9uIGluIGFuIGFzeW5jIGZ1bmN0aW9uXG4gKi9cbmV4cG9ydCBjbGFzcyBQYXVzZXIge1xuICAgIGNvbnN0cnVjdG9yKCkge1xuICAgICAgICB0aGlzLl9wcm9taXNlID0gdW5kZWZpbmVkO1xuICAgICAgICB0aGlzLl9yZXNvbHZlID0gdW5kZWZpbmVkO1xuICAgIH1cbiAgICAvKipcbiAgICAgKiBXaGVuIHBhdXNlZCwgcmV0dXJucyBhIHByb21pc2UgdG8gYmUgYXdhaXRlZDsgd2hlbiB1bnBhdXNlZCwgcmV0dXJuc1xuICAgICAqIHVuZGVmaW5lZC4gTm90ZSB0aGF0IGluIHRoZSBtaWNyb3Rhc2sgYmV0d2VlbiB0aGUgcGF1c2VyIGJlaW5nIHJlc3VtZWRcbiAgICAgKiBhbiBhd2FpdCBvZiB0aGlzIHByb21pc2UgcmVzb2x2aW5nLCB0aGUgcGF1c2VyIGNvdWxkIGJlIHBhdXNlZCBhZ2FpbixcbiAgICAgKiBoZW5jZSBjYWxsZXJzIHNob3VsZCBjaGVjayB0aGUgcHJvbWlzZSBpbiBhIGxvb3Agd2hlbiBhd2FpdGluZy5cbiAgICAgKiBAcmV0dXJucyBBIHByb21pc2UgdG8gYmUgYXdhaXRlZCB3aGVuIHBhdXNlZCBvciB1bmRlZmluZWRcbiAgICAgKi9cbiAgICBnZXQoKSB7XG4gICAgICAgIHJldHVybiB0aGlzLl9wcm9taXNlO1xuICAgIH1cbiAgICAvKipcbiAgICAgKiBDcmVhdGVzIGEgcHJvbWlzZSB0byBiZSBhd2FpdGVkXG4gICAgICovXG4gICAgcGF1c2UoKSB7XG4gICAgICAgIHRoaXMuX3Byb21pc2UgPz89IG5ldyBQcm9taXNlKChyZXNvbHZlKSA9PiAodGhpcy5fcmVzb2x2ZSA9IHJlc29sdmUpKTtcbiAgICB9XG4gICAgLyoqXG4gICAgICogUmVzb2x2ZXMgdGhlIHByb21pc2Ugd2hpY2ggbWF5IGJlIGF3YWl0ZWRcbiAgICAgKi9cbiAgICByZXN1bWUoKSB7XG4gICAgICAgIHRoaXMuX3Jlc29sdmU/LigpO1xuICAgICAgICB0aGlzLl9wcm9taXNlID0gdGhpcy5fcmVzb2x2ZSA9IHVuZGVmaW5lZDtcbiAgICB9XG59XG4vLyMgc291cmNlTWFwcGluZ1VSTD1wcml2YXRlLWFzeW5jLWhlbHBlcnMuanMubWFwIl0sIm5hbWVzIjpbXSwic291cmNlUm9vdCI6IiJ9\n//# sourceURL=webpack-internal:///./node_modules/lit-html/development/directives/private-async-helpers.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/lit-html/development/directives/until.js":
-/*!***************************************************************!*\
-  !*** ./node_modules/lit-html/development/directives/until.js ***!
-  \***************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   UntilDirective: function() { return /* binding */ UntilDirective; },\n/* harmony export */   until: function() { return /* binding */ until; }\n/* harmony export */ });\n/* harmony import */ var _lit_html_js__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! ../lit-html.js */ \"./node_modules/lit-html/development/lit-html.js\");\n/* harmony import */ var _directive_helpers_js__WEBPACK_IMPORTED_MODULE_1__ = __webpack_require__(/*! ../directive-helpers.js */ \"./node_modules/lit-html/development/directive-helpers.js\");\n/* harmony import */ var _async_directive_js__WEBPACK_IMPORTED_MODULE_2__ = __webpack_require__(/*! ../async-directive.js */ \"./node_modules/lit-html/development/async-directive.js\");\n/* harmony import */ var _private_async_helpers_js__WEBPACK_IMPORTED_MODULE_3__ = __webpack_require__(/*! ./private-async-helpers.js */ \"./node_modules/lit-html/development/directives/private-async-helpers.js\");\n/**\n * @license\n * Copyright 2017 Google LLC\n * SPDX-License-Identifier: BSD-3-Clause\n */\n\n\n\n\nconst isPromise = (x) => {\n    return !(0,_directive_helpers_js__WEBPACK_IMPORTED_MODULE_1__.isPrimitive)(x) && typeof x.then === 'function';\n};\n// Effectively infinity, but a SMI.\nconst _infinity = 0x3fffffff;\nclass UntilDirective extends _async_directive_js__WEBPACK_IMPORTED_MODULE_2__.AsyncDirective {\n    constructor() {\n        super(...arguments);\n        this.__lastRenderedIndex = _infinity;\n        this.__values = [];\n        this.__weakThis = new _private_async_helpers_js__WEBPACK_IMPORTED_MODULE_3__.PseudoWeakRef(this);\n        this.__pauser = new _private_async_helpers_js__WEBPACK_IMPORTED_MODULE_3__.Pauser();\n    }\n    render(...args) {\n        return args.find((x) => !isPromise(x)) ?? _lit_html_js__WEBPACK_IMPORTED_MODULE_0__.noChange;\n    }\n    update(_part, args) {\n        const previousValues = this.__values;\n        let previousLength = previousValues.length;\n        this.__values = args;\n        const weakThis = this.__weakThis;\n        const pauser = this.__pauser;\n        // If our initial render occurs while disconnected, ensure that the pauser\n        // and weakThis are in the disconnected state\n        if (!this.isConnected) {\n            this.disconnected();\n        }\n        for (let i = 0; i < args.length; i++) {\n            // If we've rendered a higher-priority value already, stop.\n            if (i > this.__lastRenderedIndex) {\n                break;\n            }\n            const value = args[i];\n            // Render non-Promise values immediately\n            if (!isPromise(value)) {\n                this.__lastRenderedIndex = i;\n                // Since a lower-priority value will never overwrite a higher-priority\n                // synchronous value, we can stop processing now.\n                return value;\n            }\n            // If this is a Promise we've already handled, skip it.\n            if (i < previousLength && value === previousValues[i]) {\n                continue;\n            }\n            // We have a Promise that we haven't seen before, so priorities may have\n            // changed. Forget what we rendered before.\n            this.__lastRenderedIndex = _infinity;\n            previousLength = 0;\n            // Note, the callback avoids closing over `this` so that the directive\n            // can be gc'ed before the promise resolves; instead `this` is retrieved\n            // from `weakThis`, which can break the hard reference in the closure when\n            // the directive disconnects\n            Promise.resolve(value).then(async (result) => {\n                // If we're disconnected, wait until we're (maybe) reconnected\n                // The while loop here handles the case that the connection state\n                // thrashes, causing the pauser to resume and then get re-paused\n                while (pauser.get()) {\n                    await pauser.get();\n                }\n                // If the callback gets here and there is no `this`, it means that the\n                // directive has been disconnected and garbage collected and we don't\n                // need to do anything else\n                const _this = weakThis.deref();\n                if (_this !== undefined) {\n                    const index = _this.__values.indexOf(value);\n                    // If state.values doesn't contain the value, we've re-rendered without\n                    // the value, so don't render it. Then, only render if the value is\n                    // higher-priority than what's already been rendered.\n                    if (index > -1 && index < _this.__lastRenderedIndex) {\n                        _this.__lastRenderedIndex = index;\n                        _this.setValue(result);\n                    }\n                }\n            });\n        }\n        return _lit_html_js__WEBPACK_IMPORTED_MODULE_0__.noChange;\n    }\n    disconnected() {\n        this.__weakThis.disconnect();\n        this.__pauser.pause();\n    }\n    reconnected() {\n        this.__weakThis.reconnect(this);\n        this.__pauser.resume();\n    }\n}\n/**\n * Renders one of a series of values, including Promises, to a Part.\n *\n * Values are rendered in priority order, with the first argument having the\n * highest priority and the last argument having the lowest priority. If a\n * value is a Promise, low-priority values will be rendered until it resolves.\n *\n * The priority of values can be used to create placeholder content for async\n * data. For example, a Promise with pending content can be the first,\n * highest-priority, argument, and a non_promise loading indicator template can\n * be used as the second, lower-priority, argument. The loading indicator will\n * render immediately, and the primary content will render when the Promise\n * resolves.\n *\n * Example:\n *\n * ```js\n * const content = fetch('./content.txt').then(r => r.text());\n * html`${until(content, html`<span>Loading...</span>`)}`\n * ```\n */\nconst until = (0,_async_directive_js__WEBPACK_IMPORTED_MODULE_2__.directive)(UntilDirective);\n/**\n * The type of the class that powers this directive. Necessary for naming the\n * directive's return type.\n */\n// export type {UntilDirective};\n//# sourceMappingURL=until.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvbGl0LWh0bWwvZGV2ZWxvcG1lbnQvZGlyZWN0aXZlcy91bnRpbC5qcyIsIm1hcHBpbmdzIjoiOzs7Ozs7Ozs7QUFBQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQzBDO0FBQ1k7QUFDWTtBQUNDO0FBQ25FO0FBQ0EsWUFBWSxrRUFBVztBQUN2QjtBQUNBO0FBQ0E7QUFDTyw2QkFBNkIsK0RBQWM7QUFDbEQ7QUFDQTtBQUNBO0FBQ0E7QUFDQSw4QkFBOEIsb0VBQWE7QUFDM0MsNEJBQTRCLDZEQUFNO0FBQ2xDO0FBQ0E7QUFDQSxrREFBa0Qsa0RBQVE7QUFDMUQ7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0Esd0JBQXdCLGlCQUFpQjtBQUN6QztBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQSx5REFBeUQ7QUFDekQ7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBLGFBQWE7QUFDYjtBQUNBLGVBQWUsa0RBQVE7QUFDdkI7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQSxVQUFVLDhDQUE4QztBQUN4RDtBQUNBO0FBQ08sY0FBYyw4REFBUztBQUM5QjtBQUNBO0FBQ0E7QUFDQTtBQUNBLGdCQUFnQjtBQUNoQiIsInNvdXJjZXMiOlsid2VicGFjazovL19OX0UvLi9ub2RlX21vZHVsZXMvbGl0LWh0bWwvZGV2ZWxvcG1lbnQvZGlyZWN0aXZlcy91bnRpbC5qcz81MTAwIl0sInNvdXJjZXNDb250ZW50IjpbIi8qKlxuICogQGxpY2Vuc2VcbiAqIENvcHlyaWdodCAyMDE3IEdvb2dsZSBMTENcbiAqIFNQRFgtTGljZW5zZS1JZGVudGlmaWVyOiBCU0QtMy1DbGF1c2VcbiAqL1xuaW1wb3J0IHsgbm9DaGFuZ2UgfSBmcm9tICcuLi9saXQtaHRtbC5qcyc7XG5pbXBvcnQgeyBpc1ByaW1pdGl2ZSB9IGZyb20gJy4uL2RpcmVjdGl2ZS1oZWxwZXJzLmpzJztcbmltcG9ydCB7IGRpcmVjdGl2ZSwgQXN5bmNEaXJlY3RpdmUgfSBmcm9tICcuLi9hc3luYy1kaXJlY3RpdmUuanMnO1xuaW1wb3J0IHsgUGF1c2VyLCBQc2V1ZG9XZWFrUmVmIH0gZnJvbSAnLi9wcml2YXRlLWFzeW5jLWhlbHBlcnMuanMnO1xuY29uc3QgaXNQcm9taXNlID0gKHgpID0+IHtcbiAgICByZXR1cm4gIWlzUHJpbWl0aXZlKHgpICYmIHR5cGVvZiB4LnRoZW4gPT09ICdmdW5jdGlvbic7XG59O1xuLy8gRWZmZWN0aXZlbHkgaW5maW5pdHksIGJ1dCBhIFNNSS5cbmNvbnN0IF9pbmZpbml0eSA9IDB4M2ZmZmZmZmY7XG5leHBvcnQgY2xhc3MgVW50aWxEaXJlY3RpdmUgZXh0ZW5kcyBBc3luY0RpcmVjdGl2ZSB7XG4gICAgY29uc3RydWN0b3IoKSB7XG4gICAgICAgIHN1cGVyKC4uLmFyZ3VtZW50cyk7XG4gICAgICAgIHRoaXMuX19sYXN0UmVuZGVyZWRJbmRleCA9IF9pbmZpbml0eTtcbiAgICAgICAgdGhpcy5fX3ZhbHVlcyA9IFtdO1xuICAgICAgICB0aGlzLl9fd2Vha1RoaXMgPSBuZXcgUHNldWRvV2Vha1JlZih0aGlzKTtcbiAgICAgICAgdGhpcy5fX3BhdXNlciA9IG5ldyBQYXVzZXIoKTtcbiAgICB9XG4gICAgcmVuZGVyKC4uLmFyZ3MpIHtcbiAgICAgICAgcmV0dXJuIGFyZ3MuZmluZCgoeCkgPT4gIWlzUHJvbWlzZSh4KSkgPz8gbm9DaGFuZ2U7XG4gICAgfVxuICAgIHVwZGF0ZShfcGFydCwgYXJncykge1xuICAgICAgICBjb25zdCBwcmV2aW91c1ZhbHVlcyA9IHRoaXMuX192YWx1ZXM7XG4gICAgICAgIGxldCBwcmV2aW91c0xlbmd0aCA9IHByZXZpb3VzVmFsdWVzLmxlbmd0aDtcbiAgICAgICAgdGhpcy5fX3ZhbHVlcyA9IGFyZ3M7XG4gICAgICAgIGNvbnN0IHdlYWtUaGlzID0gdGhpcy5fX3dlYWtUaGlzO1xuICAgICAgICBjb25zdCBwYXVzZXIgPSB0aGlzLl9fcGF1c2VyO1xuICAgICAgICAvLyBJZiBvdXIgaW5pdGlhbCByZW5kZXIgb2NjdXJzIHdoaWxlIGRpc2Nvbm5lY3RlZCwgZW5zdXJlIHRoYXQgdGhlIHBhdXNlclxuICAgICAgICAvLyBhbmQgd2Vha1RoaXMgYXJlIGluIHRoZSBkaXNjb25uZWN0ZWQgc3RhdGVcbiAgICAgICAgaWYgKCF0aGlzLmlzQ29ubmVjdGVkKSB7XG4gICAgICAgICAgICB0aGlzLmRpc2Nvbm5lY3RlZCgpO1xuICAgICAgICB9XG4gICAgICAgIGZvciAobGV0IGkgPSAwOyBpIDwgYXJncy5sZW5ndGg7IGkrKykge1xuICAgICAgICAgICAgLy8gSWYgd2UndmUgcmVuZGVyZWQgYSBoaWdoZXItcHJpb3JpdHkgdmFsdWUgYWxyZWFkeSwgc3RvcC5cbiAgICAgICAgICAgIGlmIChpID4gdGhpcy5fX2xhc3RSZW5kZXJlZEluZGV4KSB7XG4gICAgICAgICAgICAgICAgYnJlYWs7XG4gICAgICAgICAgICB9XG4gICAgICAgICAgICBjb25zdCB2YWx1ZSA9IGFyZ3NbaV07XG4gICAgICAgICAgICAvLyBSZW5kZXIgbm9uLVByb21pc2UgdmFsdWVzIGltbWVkaWF0ZWx5XG4gICAgICAgICAgICBpZiAoIWlzUHJvbWlzZSh2YWx1ZSkpIHtcbiAgICAgICAgICAgICAgICB0aGlzLl9fbGFzdFJlbmRlcmVkSW5kZXggPSBpO1xuICAgICAgICAgICAgICAgIC8vIFNpbmNlIGEgbG93ZXItcHJpb3JpdHkgdmFsdWUgd2lsbCBuZXZlciBvdmVyd3JpdGUgYSBoaWdoZXItcHJpb3JpdHlcbiAgICAgICAgICAgICAgICAvLyBzeW5jaHJvbm91cyB2YWx1ZSwgd2UgY2FuIHN0b3AgcHJvY2Vzc2luZyBub3cuXG4gICAgICAgICAgICAgICAgcmV0dXJuIHZhbHVlO1xuICAgICAgICAgICAgfVxuICAgICAgICAgICAgLy8gSWYgdGhpcyBpcyBhIFByb21pc2Ugd2UndmUgYWxyZWFkeSBoYW5kbGVkLCBza2lwIGl0LlxuICAgICAgICAgICAgaWYgKGkgPCBwcmV2aW91c0xlbmd0aCAmJiB2YWx1ZSA9PT0gcHJldmlvdXNWYWx1ZXNbaV0pIHtcbiAgICAgICAgICAgICAgICBjb250aW51ZTtcbiAgICAgICAgICAgIH1cbiAgICAgICAgICAgIC8vIFdlIGhhdmUgYSBQcm9taXNlIHRoYXQgd2UgaGF2ZW4ndCBzZWVuIGJlZm9yZSwgc28gcHJpb3JpdGllcyBtYXkgaGF2ZVxuICAgICAgICAgICAgLy8gY2hhbmdlZC4gRm9yZ2V0IHdoYXQgd2UgcmVuZGVyZWQgYmVmb3JlLlxuICAgICAgICAgICAgdGhpcy5fX2xhc3RSZW5kZXJlZEluZGV4ID0gX2luZmluaXR5O1xuICAgICAgICAgICAgcHJldmlvdXNMZW5ndGggPSAwO1xuICAgICAgICAgICAgLy8gTm90ZSwgdGhlIGNhbGxiYWNrIGF2b2lkcyBjbG9zaW5nIG92ZXIgYHRoaXNgIHNvIHRoYXQgdGhlIGRpcmVjdGl2ZVxuICAgICAgICAgICAgLy8gY2FuIGJlIGdjJ2VkIGJlZm9yZSB0aGUgcHJvbWlzZSByZXNvbHZlczsgaW5zdGVhZCBgdGhpc2AgaXMgcmV0cmlldmVkXG4gICAgICAgICAgICAvLyBmcm9tIGB3ZWFrVGhpc2AsIHdoaWNoIGNhbiBicmVhayB0aGUgaGFyZCByZWZlcmVuY2UgaW4gdGhlIGNsb3N1cmUgd2hlblxuICAgICAgICAgICAgLy8gdGhlIGRpcmVjdGl2ZSBkaXNjb25uZWN0c1xuICAgICAgICAgICAgUHJvbWlzZS5yZXNvbHZlKHZhbHVlKS50aGVuKGFzeW5jIChyZXN1bHQpID0+IHtcbiAgICAgICAgICAgICAgICAvLyBJZiB3ZSdyZSBkaXNjb25uZWN0ZWQsIHdhaXQgdW50aWwgd2UncmUgKG1heWJlKSByZWNvbm5lY3RlZFxuICAgICAgICAgICAgICAgIC8vIFRoZSB3aGlsZSBsb29wIGhlcmUgaGFuZGxlcyB0aGUgY2FzZSB0aGF0IHRoZSBjb25uZWN0aW9uIHN0YXRlXG4gICAgICAgICAgICAgICAgLy8gdGhyYXNoZXMsIGNhdXNpbmcgdGhlIHBhdXNlciB0byByZXN1bWUgYW5kIHRoZW4gZ2V0IHJlLXBhdXNlZFxuICAgICAgICAgICAgICAgIHdoaWxlIChwYXVzZXIuZ2V0KCkpIHtcbiAgICAgICAgICAgICAgICAgICAgYXdhaXQgcGF1c2VyLmdldCgpO1xuICAgICAgICAgICAgICAgIH1cbiAgICAgICAgICAgICAgICAvLyBJZiB0aGUgY2FsbGJhY2sgZ2V0cyBoZXJlIGFuZCB0aGVyZSBpcyBubyBgdGhpc2AsIGl0IG1lYW5zIHRoYXQgdGhlXG4gICAgICAgICAgICAgICAgLy8gZGlyZWN0aXZlIGhhcyBiZWVuIGRpc2Nvbm5lY3RlZCBhbmQgZ2FyYmFnZSBjb2xsZWN0ZWQgYW5kIHdlIGRvbid0XG4gICAgICAgICAgICAgICAgLy8gbmVlZCB0byBkbyBhbnl0aGluZyBlbHNlXG4gICAgICAgICAgICAgICAgY29uc3QgX3RoaXMgPSB3ZWFrVGhpcy5kZXJlZigpO1xuICAgICAgICAgICAgICAgIGlmIChfdGhpcyAhPT0gdW5kZWZpbmVkKSB7XG4gICAgICAgICAgICAgICAgICAgIGNvbnN0IGluZGV4ID0gX3RoaXMuX192YWx1ZXMuaW5kZXhPZih2YWx1ZSk7XG4gICAgICAgICAgICAgICAgICAgIC8vIElmIHN0YXRlLnZhbHVlcyBkb2Vzbid0IGNvbnRhaW4gdGhlIHZhbHVlLCB3ZSd2ZSByZS1yZW5kZXJlZCB3aXRob3V0XG4gICAgICAgICAgICAgICAgICAgIC8vIHRoZSB2YWx1ZSwgc28gZG9uJ3QgcmVuZGVyIGl0LiBUaGVuLCBvbmx5IHJlbmRlciBpZiB0aGUgdmFsdWUgaXNcbiAgICAgICAgICAgICAgICAgICAgLy8gaGlnaGVyLXByaW9yaXR5IHRoYW4gd2hhdCdzIGFscmVhZHkgYmVlbiByZW5kZXJlZC5cbiAgICAgICAgICAgICAgICAgICAgaWYgKGluZGV4ID4gLTEgJiYgaW5kZXggPCBfdGhpcy5fX2xhc3RSZW5kZXJlZEluZGV4KSB7XG4gICAgICAgICAgICAgICAgICAgICAgICBfdGhpcy5fX2xhc3RSZW5kZXJlZEluZGV4ID0gaW5kZXg7XG4gICAgICAgICAgICAgICAgICAgICAgICBfdGhpcy5zZXRWYWx1ZShyZXN1bHQpO1xuICAgICAgICAgICAgICAgICAgICB9XG4gICAgICAgICAgICAgICAgfVxuICAgICAgICAgICAgfSk7XG4gICAgICAgIH1cbiAgICAgICAgcmV0dXJuIG5vQ2hhbmdlO1xuICAgIH1cbiAgICBkaXNjb25uZWN0ZWQoKSB7XG4gICAgICAgIHRoaXMuX193ZWFrVGhpcy5kaXNjb25uZWN0KCk7XG4gICAgICAgIHRoaXMuX19wYXVzZXIucGF1c2UoKTtcbiAgICB9XG4gICAgcmVjb25uZWN0ZWQoKSB7XG4gICAgICAgIHRoaXMuX193ZWFrVGhpcy5yZWNvbm5lY3QodGhpcyk7XG4gICAgICAgIHRoaXMuX19wYXVzZXIucmVzdW1lKCk7XG4gICAgfVxufVxuLyoqXG4gKiBSZW5kZXJzIG9uZSBvZiBhIHNlcmllcyBvZiB2YWx1ZXMsIGluY2x1ZGluZyBQcm9taXNlcywgdG8gYSBQYXJ0LlxuICpcbiAqIFZhbHVlcyBhcmUgcmVuZGVyZWQgaW4gcHJpb3JpdHkgb3JkZXIsIHdpdGggdGhlIGZpcnN0IGFyZ3VtZW50IGhhdmluZyB0aGVcbiAqIGhpZ2hlc3QgcHJpb3JpdHkgYW5kIHRoZSBsYXN0IGFyZ3VtZW50IGhhdmluZyB0aGUgbG93ZXN0IHByaW9yaXR5LiBJZiBhXG4gKiB2YWx1ZSBpcyBhIFByb21pc2UsIGxvdy1wcmlvcml0eSB2YWx1ZXMgd2lsbCBiZSByZW5kZXJlZCB1bnRpbCBpdCByZXNvbHZlcy5cbiAqXG4gKiBUaGUgcHJpb3JpdHkgb2YgdmFsdWVzIGNhbiBiZSB1c2VkIHRvIGNyZWF0ZSBwbGFjZWhvbGRlciBjb250ZW50IGZvciBhc3luY1xuICogZGF0YS4gRm9yIGV4YW1wbGUsIGEgUHJvbWlzZSB3aXRoIHBlbmRpbmcgY29udGVudCBjYW4gYmUgdGhlIGZpcnN0LFxuICogaGlnaGVzdC1wcmlvcml0eSwgYXJndW1lbnQsIGFuZCBhIG5vbl9wcm9taXNlIGxvYWRpbmcgaW5kaWNhdG9yIHRlbXBsYXRlIGNhblxuICogYmUgdXNlZCBhcyB0aGUgc2Vjb25kLCBsb3dlci1wcmlvcml0eSwgYXJndW1lbnQuIFRoZSBsb2FkaW5nIGluZGljYXRvciB3aWxsXG4gKiByZW5kZXIgaW1tZWRpYXRlbHksIGFuZCB0aGUgcHJpbWFyeSBjb250ZW50IHdpbGwgcmVuZGVyIHdoZW4gdGhlIFByb21pc2VcbiAqIHJlc29sdmVzLlxuICpcbiAqIEV4YW1wbGU6XG4gKlxuICogYGBganNcbiAqIGNvbnN0IGNvbnRlbnQgPSBmZXRjaCgnLi9jb250ZW50LnR4dCcpLnRoZW4ociA9PiByLnRleHQoKSk7XG4gKiBodG1sYCR7dW50aWwoY29udGVudCwgaHRtbGA8c3Bhbj5Mb2FkaW5nLi4uPC9zcGFuPmApfWBcbiAqIGBgYFxuICovXG5leHBvcnQgY29uc3QgdW50aWwgPSBkaXJlY3RpdmUoVW50aWxEaXJlY3RpdmUpO1xuLyoqXG4gKiBUaGUgdHlwZSBvZiB0aGUgY2xhc3MgdGhhdCBwb3dlcnMgdGhpcyBkaXJlY3RpdmUuIE5lY2Vzc2FyeSBmb3IgbmFtaW5nIHRoZVxuICogZGlyZWN0aXZlJ3MgcmV0dXJuIHR5cGUuXG4gKi9cbi8vIGV4cG9ydCB0eXBlIHtVbnRpbERpcmVjdGl2ZX07XG4vLyMgc291cmNlTWFwcGluZ1VSTD11bnRpbC5qcy5tYXAiXSwibmFtZXMiOltdLCJzb3VyY2VSb290IjoiIn0=\n//# sourceURL=webpack-internal:///./node_modules/lit-html/development/directives/until.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/lit/decorators.js":
-/*!****************************************!*\
-  !*** ./node_modules/lit/decorators.js ***!
-  \****************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony import */ var _lit_reactive_element_decorators_custom_element_js__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! @lit/reactive-element/decorators/custom-element.js */ \"./node_modules/@lit/reactive-element/development/decorators/custom-element.js\");\n/* harmony reexport (unknown) */ var __WEBPACK_REEXPORT_OBJECT__ = {};\n/* harmony reexport (unknown) */ for(var __WEBPACK_IMPORT_KEY__ in _lit_reactive_element_decorators_custom_element_js__WEBPACK_IMPORTED_MODULE_0__) if(__WEBPACK_IMPORT_KEY__ !== \"default\") __WEBPACK_REEXPORT_OBJECT__[__WEBPACK_IMPORT_KEY__] = function(key) { return _lit_reactive_element_decorators_custom_element_js__WEBPACK_IMPORTED_MODULE_0__[key]; }.bind(0, __WEBPACK_IMPORT_KEY__)\n/* harmony reexport (unknown) */ __webpack_require__.d(__webpack_exports__, __WEBPACK_REEXPORT_OBJECT__);\n/* harmony import */ var _lit_reactive_element_decorators_property_js__WEBPACK_IMPORTED_MODULE_1__ = __webpack_require__(/*! @lit/reactive-element/decorators/property.js */ \"./node_modules/@lit/reactive-element/development/decorators/property.js\");\n/* harmony reexport (unknown) */ var __WEBPACK_REEXPORT_OBJECT__ = {};\n/* harmony reexport (unknown) */ for(var __WEBPACK_IMPORT_KEY__ in _lit_reactive_element_decorators_property_js__WEBPACK_IMPORTED_MODULE_1__) if(__WEBPACK_IMPORT_KEY__ !== \"default\") __WEBPACK_REEXPORT_OBJECT__[__WEBPACK_IMPORT_KEY__] = function(key) { return _lit_reactive_element_decorators_property_js__WEBPACK_IMPORTED_MODULE_1__[key]; }.bind(0, __WEBPACK_IMPORT_KEY__)\n/* harmony reexport (unknown) */ __webpack_require__.d(__webpack_exports__, __WEBPACK_REEXPORT_OBJECT__);\n/* harmony import */ var _lit_reactive_element_decorators_state_js__WEBPACK_IMPORTED_MODULE_2__ = __webpack_require__(/*! @lit/reactive-element/decorators/state.js */ \"./node_modules/@lit/reactive-element/development/decorators/state.js\");\n/* harmony reexport (unknown) */ var __WEBPACK_REEXPORT_OBJECT__ = {};\n/* harmony reexport (unknown) */ for(var __WEBPACK_IMPORT_KEY__ in _lit_reactive_element_decorators_state_js__WEBPACK_IMPORTED_MODULE_2__) if(__WEBPACK_IMPORT_KEY__ !== \"default\") __WEBPACK_REEXPORT_OBJECT__[__WEBPACK_IMPORT_KEY__] = function(key) { return _lit_reactive_element_decorators_state_js__WEBPACK_IMPORTED_MODULE_2__[key]; }.bind(0, __WEBPACK_IMPORT_KEY__)\n/* harmony reexport (unknown) */ __webpack_require__.d(__webpack_exports__, __WEBPACK_REEXPORT_OBJECT__);\n/* harmony import */ var _lit_reactive_element_decorators_event_options_js__WEBPACK_IMPORTED_MODULE_3__ = __webpack_require__(/*! @lit/reactive-element/decorators/event-options.js */ \"./node_modules/@lit/reactive-element/development/decorators/event-options.js\");\n/* harmony reexport (unknown) */ var __WEBPACK_REEXPORT_OBJECT__ = {};\n/* harmony reexport (unknown) */ for(var __WEBPACK_IMPORT_KEY__ in _lit_reactive_element_decorators_event_options_js__WEBPACK_IMPORTED_MODULE_3__) if(__WEBPACK_IMPORT_KEY__ !== \"default\") __WEBPACK_REEXPORT_OBJECT__[__WEBPACK_IMPORT_KEY__] = function(key) { return _lit_reactive_element_decorators_event_options_js__WEBPACK_IMPORTED_MODULE_3__[key]; }.bind(0, __WEBPACK_IMPORT_KEY__)\n/* harmony reexport (unknown) */ __webpack_require__.d(__webpack_exports__, __WEBPACK_REEXPORT_OBJECT__);\n/* harmony import */ var _lit_reactive_element_decorators_query_js__WEBPACK_IMPORTED_MODULE_4__ = __webpack_require__(/*! @lit/reactive-element/decorators/query.js */ \"./node_modules/@lit/reactive-element/development/decorators/query.js\");\n/* harmony reexport (unknown) */ var __WEBPACK_REEXPORT_OBJECT__ = {};\n/* harmony reexport (unknown) */ for(var __WEBPACK_IMPORT_KEY__ in _lit_reactive_element_decorators_query_js__WEBPACK_IMPORTED_MODULE_4__) if(__WEBPACK_IMPORT_KEY__ !== \"default\") __WEBPACK_REEXPORT_OBJECT__[__WEBPACK_IMPORT_KEY__] = function(key) { return _lit_reactive_element_decorators_query_js__WEBPACK_IMPORTED_MODULE_4__[key]; }.bind(0, __WEBPACK_IMPORT_KEY__)\n/* harmony reexport (unknown) */ __webpack_require__.d(__webpack_exports__, __WEBPACK_REEXPORT_OBJECT__);\n/* harmony import */ var _lit_reactive_element_decorators_query_all_js__WEBPACK_IMPORTED_MODULE_5__ = __webpack_require__(/*! @lit/reactive-element/decorators/query-all.js */ \"./node_modules/@lit/reactive-element/development/decorators/query-all.js\");\n/* harmony reexport (unknown) */ var __WEBPACK_REEXPORT_OBJECT__ = {};\n/* harmony reexport (unknown) */ for(var __WEBPACK_IMPORT_KEY__ in _lit_reactive_element_decorators_query_all_js__WEBPACK_IMPORTED_MODULE_5__) if(__WEBPACK_IMPORT_KEY__ !== \"default\") __WEBPACK_REEXPORT_OBJECT__[__WEBPACK_IMPORT_KEY__] = function(key) { return _lit_reactive_element_decorators_query_all_js__WEBPACK_IMPORTED_MODULE_5__[key]; }.bind(0, __WEBPACK_IMPORT_KEY__)\n/* harmony reexport (unknown) */ __webpack_require__.d(__webpack_exports__, __WEBPACK_REEXPORT_OBJECT__);\n/* harmony import */ var _lit_reactive_element_decorators_query_async_js__WEBPACK_IMPORTED_MODULE_6__ = __webpack_require__(/*! @lit/reactive-element/decorators/query-async.js */ \"./node_modules/@lit/reactive-element/development/decorators/query-async.js\");\n/* harmony reexport (unknown) */ var __WEBPACK_REEXPORT_OBJECT__ = {};\n/* harmony reexport (unknown) */ for(var __WEBPACK_IMPORT_KEY__ in _lit_reactive_element_decorators_query_async_js__WEBPACK_IMPORTED_MODULE_6__) if(__WEBPACK_IMPORT_KEY__ !== \"default\") __WEBPACK_REEXPORT_OBJECT__[__WEBPACK_IMPORT_KEY__] = function(key) { return _lit_reactive_element_decorators_query_async_js__WEBPACK_IMPORTED_MODULE_6__[key]; }.bind(0, __WEBPACK_IMPORT_KEY__)\n/* harmony reexport (unknown) */ __webpack_require__.d(__webpack_exports__, __WEBPACK_REEXPORT_OBJECT__);\n/* harmony import */ var _lit_reactive_element_decorators_query_assigned_elements_js__WEBPACK_IMPORTED_MODULE_7__ = __webpack_require__(/*! @lit/reactive-element/decorators/query-assigned-elements.js */ \"./node_modules/@lit/reactive-element/development/decorators/query-assigned-elements.js\");\n/* harmony reexport (unknown) */ var __WEBPACK_REEXPORT_OBJECT__ = {};\n/* harmony reexport (unknown) */ for(var __WEBPACK_IMPORT_KEY__ in _lit_reactive_element_decorators_query_assigned_elements_js__WEBPACK_IMPORTED_MODULE_7__) if(__WEBPACK_IMPORT_KEY__ !== \"default\") __WEBPACK_REEXPORT_OBJECT__[__WEBPACK_IMPORT_KEY__] = function(key) { return _lit_reactive_element_decorators_query_assigned_elements_js__WEBPACK_IMPORTED_MODULE_7__[key]; }.bind(0, __WEBPACK_IMPORT_KEY__)\n/* harmony reexport (unknown) */ __webpack_require__.d(__webpack_exports__, __WEBPACK_REEXPORT_OBJECT__);\n/* harmony import */ var _lit_reactive_element_decorators_query_assigned_nodes_js__WEBPACK_IMPORTED_MODULE_8__ = __webpack_require__(/*! @lit/reactive-element/decorators/query-assigned-nodes.js */ \"./node_modules/@lit/reactive-element/development/decorators/query-assigned-nodes.js\");\n/* harmony reexport (unknown) */ var __WEBPACK_REEXPORT_OBJECT__ = {};\n/* harmony reexport (unknown) */ for(var __WEBPACK_IMPORT_KEY__ in _lit_reactive_element_decorators_query_assigned_nodes_js__WEBPACK_IMPORTED_MODULE_8__) if(__WEBPACK_IMPORT_KEY__ !== \"default\") __WEBPACK_REEXPORT_OBJECT__[__WEBPACK_IMPORT_KEY__] = function(key) { return _lit_reactive_element_decorators_query_assigned_nodes_js__WEBPACK_IMPORTED_MODULE_8__[key]; }.bind(0, __WEBPACK_IMPORT_KEY__)\n/* harmony reexport (unknown) */ __webpack_require__.d(__webpack_exports__, __WEBPACK_REEXPORT_OBJECT__);\n\n//# sourceMappingURL=decorators.js.map\n//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvbGl0L2RlY29yYXRvcnMuanMiLCJtYXBwaW5ncyI6Ijs7Ozs7Ozs7Ozs7Ozs7Ozs7Ozs7Ozs7Ozs7Ozs7Ozs7Ozs7OztBQUE4aUI7QUFDOWlCIiwic291cmNlcyI6WyJ3ZWJwYWNrOi8vX05fRS8uL25vZGVfbW9kdWxlcy9saXQvZGVjb3JhdG9ycy5qcz9iYWMzIl0sInNvdXJjZXNDb250ZW50IjpbImV4cG9ydCpmcm9tXCJAbGl0L3JlYWN0aXZlLWVsZW1lbnQvZGVjb3JhdG9ycy9jdXN0b20tZWxlbWVudC5qc1wiO2V4cG9ydCpmcm9tXCJAbGl0L3JlYWN0aXZlLWVsZW1lbnQvZGVjb3JhdG9ycy9wcm9wZXJ0eS5qc1wiO2V4cG9ydCpmcm9tXCJAbGl0L3JlYWN0aXZlLWVsZW1lbnQvZGVjb3JhdG9ycy9zdGF0ZS5qc1wiO2V4cG9ydCpmcm9tXCJAbGl0L3JlYWN0aXZlLWVsZW1lbnQvZGVjb3JhdG9ycy9ldmVudC1vcHRpb25zLmpzXCI7ZXhwb3J0KmZyb21cIkBsaXQvcmVhY3RpdmUtZWxlbWVudC9kZWNvcmF0b3JzL3F1ZXJ5LmpzXCI7ZXhwb3J0KmZyb21cIkBsaXQvcmVhY3RpdmUtZWxlbWVudC9kZWNvcmF0b3JzL3F1ZXJ5LWFsbC5qc1wiO2V4cG9ydCpmcm9tXCJAbGl0L3JlYWN0aXZlLWVsZW1lbnQvZGVjb3JhdG9ycy9xdWVyeS1hc3luYy5qc1wiO2V4cG9ydCpmcm9tXCJAbGl0L3JlYWN0aXZlLWVsZW1lbnQvZGVjb3JhdG9ycy9xdWVyeS1hc3NpZ25lZC1lbGVtZW50cy5qc1wiO2V4cG9ydCpmcm9tXCJAbGl0L3JlYWN0aXZlLWVsZW1lbnQvZGVjb3JhdG9ycy9xdWVyeS1hc3NpZ25lZC1ub2Rlcy5qc1wiO1xuLy8jIHNvdXJjZU1hcHBpbmdVUkw9ZGVjb3JhdG9ycy5qcy5tYXBcbiJdLCJuYW1lcyI6W10sInNvdXJjZVJvb3QiOiIifQ==\n//# sourceURL=webpack-internal:///./node_modules/lit/decorators.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/lit/directives/class-map.js":
-/*!**************************************************!*\
-  !*** ./node_modules/lit/directives/class-map.js ***!
-  \**************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony import */ var lit_html_directives_class_map_js__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit-html/directives/class-map.js */ \"./node_modules/lit-html/development/directives/class-map.js\");\n/* harmony reexport (unknown) */ var __WEBPACK_REEXPORT_OBJECT__ = {};\n/* harmony reexport (unknown) */ for(var __WEBPACK_IMPORT_KEY__ in lit_html_directives_class_map_js__WEBPACK_IMPORTED_MODULE_0__) if(__WEBPACK_IMPORT_KEY__ !== \"default\") __WEBPACK_REEXPORT_OBJECT__[__WEBPACK_IMPORT_KEY__] = function(key) { return lit_html_directives_class_map_js__WEBPACK_IMPORTED_MODULE_0__[key]; }.bind(0, __WEBPACK_IMPORT_KEY__)\n/* harmony reexport (unknown) */ __webpack_require__.d(__webpack_exports__, __WEBPACK_REEXPORT_OBJECT__);\n\n//# sourceMappingURL=class-map.js.map\n//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvbGl0L2RpcmVjdGl2ZXMvY2xhc3MtbWFwLmpzIiwibWFwcGluZ3MiOiI7Ozs7O0FBQThDO0FBQzlDIiwic291cmNlcyI6WyJ3ZWJwYWNrOi8vX05fRS8uL25vZGVfbW9kdWxlcy9saXQvZGlyZWN0aXZlcy9jbGFzcy1tYXAuanM/OWU2YiJdLCJzb3VyY2VzQ29udGVudCI6WyJleHBvcnQqZnJvbVwibGl0LWh0bWwvZGlyZWN0aXZlcy9jbGFzcy1tYXAuanNcIjtcbi8vIyBzb3VyY2VNYXBwaW5nVVJMPWNsYXNzLW1hcC5qcy5tYXBcbiJdLCJuYW1lcyI6W10sInNvdXJjZVJvb3QiOiIifQ==\n//# sourceURL=webpack-internal:///./node_modules/lit/directives/class-map.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/lit/directives/if-defined.js":
-/*!***************************************************!*\
-  !*** ./node_modules/lit/directives/if-defined.js ***!
-  \***************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony import */ var lit_html_directives_if_defined_js__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit-html/directives/if-defined.js */ \"./node_modules/lit-html/development/directives/if-defined.js\");\n/* harmony reexport (unknown) */ var __WEBPACK_REEXPORT_OBJECT__ = {};\n/* harmony reexport (unknown) */ for(var __WEBPACK_IMPORT_KEY__ in lit_html_directives_if_defined_js__WEBPACK_IMPORTED_MODULE_0__) if(__WEBPACK_IMPORT_KEY__ !== \"default\") __WEBPACK_REEXPORT_OBJECT__[__WEBPACK_IMPORT_KEY__] = function(key) { return lit_html_directives_if_defined_js__WEBPACK_IMPORTED_MODULE_0__[key]; }.bind(0, __WEBPACK_IMPORT_KEY__)\n/* harmony reexport (unknown) */ __webpack_require__.d(__webpack_exports__, __WEBPACK_REEXPORT_OBJECT__);\n\n//# sourceMappingURL=if-defined.js.map\n//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvbGl0L2RpcmVjdGl2ZXMvaWYtZGVmaW5lZC5qcyIsIm1hcHBpbmdzIjoiOzs7OztBQUErQztBQUMvQyIsInNvdXJjZXMiOlsid2VicGFjazovL19OX0UvLi9ub2RlX21vZHVsZXMvbGl0L2RpcmVjdGl2ZXMvaWYtZGVmaW5lZC5qcz8wMDI0Il0sInNvdXJjZXNDb250ZW50IjpbImV4cG9ydCpmcm9tXCJsaXQtaHRtbC9kaXJlY3RpdmVzL2lmLWRlZmluZWQuanNcIjtcbi8vIyBzb3VyY2VNYXBwaW5nVVJMPWlmLWRlZmluZWQuanMubWFwXG4iXSwibmFtZXMiOltdLCJzb3VyY2VSb290IjoiIn0=\n//# sourceURL=webpack-internal:///./node_modules/lit/directives/if-defined.js\n"));
-
-/***/ }),
-
-/***/ "./node_modules/lit/directives/until.js":
-/*!**********************************************!*\
-  !*** ./node_modules/lit/directives/until.js ***!
-  \**********************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony import */ var lit_html_directives_until_js__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit-html/directives/until.js */ \"./node_modules/lit-html/development/directives/until.js\");\n/* harmony reexport (unknown) */ var __WEBPACK_REEXPORT_OBJECT__ = {};\n/* harmony reexport (unknown) */ for(var __WEBPACK_IMPORT_KEY__ in lit_html_directives_until_js__WEBPACK_IMPORTED_MODULE_0__) if(__WEBPACK_IMPORT_KEY__ !== \"default\") __WEBPACK_REEXPORT_OBJECT__[__WEBPACK_IMPORT_KEY__] = function(key) { return lit_html_directives_until_js__WEBPACK_IMPORTED_MODULE_0__[key]; }.bind(0, __WEBPACK_IMPORT_KEY__)\n/* harmony reexport (unknown) */ __webpack_require__.d(__webpack_exports__, __WEBPACK_REEXPORT_OBJECT__);\n\n//# sourceMappingURL=until.js.map\n//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvbGl0L2RpcmVjdGl2ZXMvdW50aWwuanMiLCJtYXBwaW5ncyI6Ijs7Ozs7QUFBMEM7QUFDMUMiLCJzb3VyY2VzIjpbIndlYnBhY2s6Ly9fTl9FLy4vbm9kZV9tb2R1bGVzL2xpdC9kaXJlY3RpdmVzL3VudGlsLmpzP2FkZWUiXSwic291cmNlc0NvbnRlbnQiOlsiZXhwb3J0KmZyb21cImxpdC1odG1sL2RpcmVjdGl2ZXMvdW50aWwuanNcIjtcbi8vIyBzb3VyY2VNYXBwaW5nVVJMPXVudGlsLmpzLm1hcFxuIl0sIm5hbWVzIjpbXSwic291cmNlUm9vdCI6IiJ9\n//# sourceURL=webpack-internal:///./node_modules/lit/directives/until.js\n"));
-
-/***/ })
-
-}]);
\ No newline at end of file
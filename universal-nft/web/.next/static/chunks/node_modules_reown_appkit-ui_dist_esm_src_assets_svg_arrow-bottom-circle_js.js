@@ -1,22 +0,0 @@
-"use strict";
-/*
- * ATTENTION: An "eval-source-map" devtool has been used.
- * This devtool is neither made for production nor for readable output files.
- * It uses "eval()" calls to create a separate source file with attached SourceMaps in the browser devtools.
- * If you are trying to read the output file, select a different devtool (https://webpack.js.org/configuration/devtool/)
- * or disable the default devtool with "devtool: false".
- * If you are looking for production-ready output files, see mode: "production" (https://webpack.js.org/configuration/mode/).
- */
-(self["webpackChunk_N_E"] = self["webpackChunk_N_E"] || []).push([["node_modules_reown_appkit-ui_dist_esm_src_assets_svg_arrow-bottom-circle_js"],{
-
-/***/ "./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/arrow-bottom-circle.js":
-/*!**************************************************************************************!*\
-  !*** ./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/arrow-bottom-circle.js ***!
-  \**************************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   arrowBottomCircleSvg: function() { return /* binding */ arrowBottomCircleSvg; }\n/* harmony export */ });\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n\nconst arrowBottomCircleSvg = (0,lit__WEBPACK_IMPORTED_MODULE_0__.svg) `<svg\n  fill=\"none\"\n  viewBox=\"0 0 21 20\"\n>\n  <path\n    fill=\"currentColor\"\n    fill-rule=\"evenodd\"\n    d=\"M10.5 2.42908C6.31875 2.42908 2.92859 5.81989 2.92859 10.0034C2.92859 14.1869 6.31875 17.5777 10.5 17.5777C14.6813 17.5777 18.0714 14.1869 18.0714 10.0034C18.0714 5.81989 14.6813 2.42908 10.5 2.42908ZM0.928589 10.0034C0.928589 4.71596 5.21355 0.429077 10.5 0.429077C15.7865 0.429077 20.0714 4.71596 20.0714 10.0034C20.0714 15.2908 15.7865 19.5777 10.5 19.5777C5.21355 19.5777 0.928589 15.2908 0.928589 10.0034ZM10.5 5.75003C11.0523 5.75003 11.5 6.19774 11.5 6.75003L11.5 10.8343L12.7929 9.54137C13.1834 9.15085 13.8166 9.15085 14.2071 9.54137C14.5976 9.9319 14.5976 10.5651 14.2071 10.9556L11.2071 13.9556C10.8166 14.3461 10.1834 14.3461 9.79291 13.9556L6.79291 10.9556C6.40239 10.5651 6.40239 9.9319 6.79291 9.54137C7.18343 9.15085 7.8166 9.15085 8.20712 9.54137L9.50002 10.8343L9.50002 6.75003C9.50002 6.19774 9.94773 5.75003 10.5 5.75003Z\"\n    clip-rule=\"evenodd\"\n  /></svg\n>`;\n//# sourceMappingURL=arrow-bottom-circle.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvYXNzZXRzL3N2Zy9hcnJvdy1ib3R0b20tY2lyY2xlLmpzIiwibWFwcGluZ3MiOiI7Ozs7O0FBQTBCO0FBQ25CLDZCQUE2Qix3Q0FBRztBQUN2QztBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBIiwic291cmNlcyI6WyJ3ZWJwYWNrOi8vX05fRS8uL25vZGVfbW9kdWxlcy9AcmVvd24vYXBwa2l0LXVpL2Rpc3QvZXNtL3NyYy9hc3NldHMvc3ZnL2Fycm93LWJvdHRvbS1jaXJjbGUuanM/NGQ4ZCJdLCJzb3VyY2VzQ29udGVudCI6WyJpbXBvcnQgeyBzdmcgfSBmcm9tICdsaXQnO1xuZXhwb3J0IGNvbnN0IGFycm93Qm90dG9tQ2lyY2xlU3ZnID0gc3ZnIGA8c3ZnXG4gIGZpbGw9XCJub25lXCJcbiAgdmlld0JveD1cIjAgMCAyMSAyMFwiXG4+XG4gIDxwYXRoXG4gICAgZmlsbD1cImN1cnJlbnRDb2xvclwiXG4gICAgZmlsbC1ydWxlPVwiZXZlbm9kZFwiXG4gICAgZD1cIk0xMC41IDIuNDI5MDhDNi4zMTg3NSAyLjQyOTA4IDIuOTI4NTkgNS44MTk4OSAyLjkyODU5IDEwLjAwMzRDMi45Mjg1OSAxNC4xODY5IDYuMzE4NzUgMTcuNTc3NyAxMC41IDE3LjU3NzdDMTQuNjgxMyAxNy41Nzc3IDE4LjA3MTQgMTQuMTg2OSAxOC4wNzE0IDEwLjAwMzRDMTguMDcxNCA1LjgxOTg5IDE0LjY4MTMgMi40MjkwOCAxMC41IDIuNDI5MDhaTTAuOTI4NTg5IDEwLjAwMzRDMC45Mjg1ODkgNC43MTU5NiA1LjIxMzU1IDAuNDI5MDc3IDEwLjUgMC40MjkwNzdDMTUuNzg2NSAwLjQyOTA3NyAyMC4wNzE0IDQuNzE1OTYgMjAuMDcxNCAxMC4wMDM0QzIwLjA3MTQgMTUuMjkwOCAxNS43ODY1IDE5LjU3NzcgMTAuNSAxOS41Nzc3QzUuMjEzNTUgMTkuNTc3NyAwLjkyODU4OSAxNS4yOTA4IDAuOTI4NTg5IDEwLjAwMzRaTTEwLjUgNS43NTAwM0MxMS4wNTIzIDUuNzUwMDMgMTEuNSA2LjE5Nzc0IDExLjUgNi43NTAwM0wxMS41IDEwLjgzNDNMMTIuNzkyOSA5LjU0MTM3QzEzLjE4MzQgOS4xNTA4NSAxMy44MTY2IDkuMTUwODUgMTQuMjA3MSA5LjU0MTM3QzE0LjU5NzYgOS45MzE5IDE0LjU5NzYgMTAuNTY1MSAxNC4yMDcxIDEwLjk1NTZMMTEuMjA3MSAxMy45NTU2QzEwLjgxNjYgMTQuMzQ2MSAxMC4xODM0IDE0LjM0NjEgOS43OTI5MSAxMy45NTU2TDYuNzkyOTEgMTAuOTU1NkM2LjQwMjM5IDEwLjU2NTEgNi40MDIzOSA5LjkzMTkgNi43OTI5MSA5LjU0MTM3QzcuMTgzNDMgOS4xNTA4NSA3LjgxNjYgOS4xNTA4NSA4LjIwNzEyIDkuNTQxMzdMOS41MDAwMiAxMC44MzQzTDkuNTAwMDIgNi43NTAwM0M5LjUwMDAyIDYuMTk3NzQgOS45NDc3MyA1Ljc1MDAzIDEwLjUgNS43NTAwM1pcIlxuICAgIGNsaXAtcnVsZT1cImV2ZW5vZGRcIlxuICAvPjwvc3ZnXG4+YDtcbi8vIyBzb3VyY2VNYXBwaW5nVVJMPWFycm93LWJvdHRvbS1jaXJjbGUuanMubWFwIl0sIm5hbWVzIjpbXSwic291cmNlUm9vdCI6IiJ9\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/arrow-bottom-circle.js\n"));
-
-/***/ })
-
-}]);
\ No newline at end of file
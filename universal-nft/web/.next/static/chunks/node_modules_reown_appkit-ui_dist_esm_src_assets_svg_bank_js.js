@@ -1,22 +0,0 @@
-"use strict";
-/*
- * ATTENTION: An "eval-source-map" devtool has been used.
- * This devtool is neither made for production nor for readable output files.
- * It uses "eval()" calls to create a separate source file with attached SourceMaps in the browser devtools.
- * If you are trying to read the output file, select a different devtool (https://webpack.js.org/configuration/devtool/)
- * or disable the default devtool with "devtool: false".
- * If you are looking for production-ready output files, see mode: "production" (https://webpack.js.org/configuration/mode/).
- */
-(self["webpackChunk_N_E"] = self["webpackChunk_N_E"] || []).push([["node_modules_reown_appkit-ui_dist_esm_src_assets_svg_bank_js"],{
-
-/***/ "./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/bank.js":
-/*!***********************************************************************!*\
-  !*** ./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/bank.js ***!
-  \***********************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   bankSvg: function() { return /* binding */ bankSvg; }\n/* harmony export */ });\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n\nconst bankSvg = (0,lit__WEBPACK_IMPORTED_MODULE_0__.svg) `<svg\n  xmlns=\"http://www.w3.org/2000/svg\"\n  width=\"12\"\n  height=\"13\"\n  viewBox=\"0 0 12 13\"\n  fill=\"none\"\n>\n  <path\n    fill-rule=\"evenodd\"\n    clip-rule=\"evenodd\"\n    d=\"M5.61391 1.57124C5.85142 1.42873 6.14813 1.42873 6.38564 1.57124L11.0793 4.38749C11.9179 4.89067 11.5612 6.17864 10.5832 6.17864H9.96398V10.0358H10.2854C10.6996 10.0358 11.0354 10.3716 11.0354 10.7858C11.0354 11.2 10.6996 11.5358 10.2854 11.5358H1.71416C1.29995 11.5358 0.964172 11.2 0.964172 10.7858C0.964172 10.3716 1.29995 10.0358 1.71416 10.0358H2.03558L2.03558 6.17864H1.41637C0.438389 6.17864 0.0816547 4.89066 0.920263 4.38749L5.61391 1.57124ZM3.53554 6.17864V10.0358H5.24979V6.17864H3.53554ZM6.74976 6.17864V10.0358H8.46401V6.17864H6.74976ZM8.64913 4.67864H3.35043L5.99978 3.089L8.64913 4.67864Z\"\n    fill=\"currentColor\"\n  /></svg\n>`;\n//# sourceMappingURL=bank.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvYXNzZXRzL3N2Zy9iYW5rLmpzIiwibWFwcGluZ3MiOiI7Ozs7O0FBQTBCO0FBQ25CLGdCQUFnQix3Q0FBRztBQUMxQjtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBIiwic291cmNlcyI6WyJ3ZWJwYWNrOi8vX05fRS8uL25vZGVfbW9kdWxlcy9AcmVvd24vYXBwa2l0LXVpL2Rpc3QvZXNtL3NyYy9hc3NldHMvc3ZnL2JhbmsuanM/M2QzZiJdLCJzb3VyY2VzQ29udGVudCI6WyJpbXBvcnQgeyBzdmcgfSBmcm9tICdsaXQnO1xuZXhwb3J0IGNvbnN0IGJhbmtTdmcgPSBzdmcgYDxzdmdcbiAgeG1sbnM9XCJodHRwOi8vd3d3LnczLm9yZy8yMDAwL3N2Z1wiXG4gIHdpZHRoPVwiMTJcIlxuICBoZWlnaHQ9XCIxM1wiXG4gIHZpZXdCb3g9XCIwIDAgMTIgMTNcIlxuICBmaWxsPVwibm9uZVwiXG4+XG4gIDxwYXRoXG4gICAgZmlsbC1ydWxlPVwiZXZlbm9kZFwiXG4gICAgY2xpcC1ydWxlPVwiZXZlbm9kZFwiXG4gICAgZD1cIk01LjYxMzkxIDEuNTcxMjRDNS44NTE0MiAxLjQyODczIDYuMTQ4MTMgMS40Mjg3MyA2LjM4NTY0IDEuNTcxMjRMMTEuMDc5MyA0LjM4NzQ5QzExLjkxNzkgNC44OTA2NyAxMS41NjEyIDYuMTc4NjQgMTAuNTgzMiA2LjE3ODY0SDkuOTYzOThWMTAuMDM1OEgxMC4yODU0QzEwLjY5OTYgMTAuMDM1OCAxMS4wMzU0IDEwLjM3MTYgMTEuMDM1NCAxMC43ODU4QzExLjAzNTQgMTEuMiAxMC42OTk2IDExLjUzNTggMTAuMjg1NCAxMS41MzU4SDEuNzE0MTZDMS4yOTk5NSAxMS41MzU4IDAuOTY0MTcyIDExLjIgMC45NjQxNzIgMTAuNzg1OEMwLjk2NDE3MiAxMC4zNzE2IDEuMjk5OTUgMTAuMDM1OCAxLjcxNDE2IDEwLjAzNThIMi4wMzU1OEwyLjAzNTU4IDYuMTc4NjRIMS40MTYzN0MwLjQzODM4OSA2LjE3ODY0IDAuMDgxNjU0NyA0Ljg5MDY2IDAuOTIwMjYzIDQuMzg3NDlMNS42MTM5MSAxLjU3MTI0Wk0zLjUzNTU0IDYuMTc4NjRWMTAuMDM1OEg1LjI0OTc5VjYuMTc4NjRIMy41MzU1NFpNNi43NDk3NiA2LjE3ODY0VjEwLjAzNThIOC40NjQwMVY2LjE3ODY0SDYuNzQ5NzZaTTguNjQ5MTMgNC42Nzg2NEgzLjM1MDQzTDUuOTk5NzggMy4wODlMOC42NDkxMyA0LjY3ODY0WlwiXG4gICAgZmlsbD1cImN1cnJlbnRDb2xvclwiXG4gIC8+PC9zdmdcbj5gO1xuLy8jIHNvdXJjZU1hcHBpbmdVUkw9YmFuay5qcy5tYXAiXSwibmFtZXMiOltdLCJzb3VyY2VSb290IjoiIn0=\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/bank.js\n"));
-
-/***/ })
-
-}]);
\ No newline at end of file
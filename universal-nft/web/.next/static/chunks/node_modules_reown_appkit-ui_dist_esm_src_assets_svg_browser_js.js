@@ -1,22 +0,0 @@
-"use strict";
-/*
- * ATTENTION: An "eval-source-map" devtool has been used.
- * This devtool is neither made for production nor for readable output files.
- * It uses "eval()" calls to create a separate source file with attached SourceMaps in the browser devtools.
- * If you are trying to read the output file, select a different devtool (https://webpack.js.org/configuration/devtool/)
- * or disable the default devtool with "devtool: false".
- * If you are looking for production-ready output files, see mode: "production" (https://webpack.js.org/configuration/mode/).
- */
-(self["webpackChunk_N_E"] = self["webpackChunk_N_E"] || []).push([["node_modules_reown_appkit-ui_dist_esm_src_assets_svg_browser_js"],{
-
-/***/ "./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/browser.js":
-/*!**************************************************************************!*\
-  !*** ./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/browser.js ***!
-  \**************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   browserSvg: function() { return /* binding */ browserSvg; }\n/* harmony export */ });\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n\nconst browserSvg = (0,lit__WEBPACK_IMPORTED_MODULE_0__.svg) `<svg fill=\"none\" viewBox=\"0 0 20 20\">\n  <path\n    fill=\"currentColor\"\n    fill-rule=\"evenodd\"\n    d=\"M4 6.4a1 1 0 0 1-.46.89 6.98 6.98 0 0 0 .38 6.18A7 7 0 0 0 16.46 7.3a1 1 0 0 1-.47-.92 7 7 0 0 0-12 .03Zm-2.02-.5a9 9 0 1 1 16.03 8.2A9 9 0 0 1 1.98 5.9Z\"\n    clip-rule=\"evenodd\"\n  />\n  <path\n    fill=\"currentColor\"\n    fill-rule=\"evenodd\"\n    d=\"M6.03 8.63c-1.46-.3-2.72-.75-3.6-1.35l-.02-.01-.14-.11a1 1 0 0 1 1.2-1.6l.1.08c.6.4 1.52.74 2.69 1 .16-.99.39-1.88.67-2.65.3-.79.68-1.5 1.15-2.02A2.58 2.58 0 0 1 9.99 1c.8 0 1.45.44 1.92.97.47.52.84 1.23 1.14 2.02.29.77.52 1.66.68 2.64a8 8 0 0 0 2.7-1l.26-.18h.48a1 1 0 0 1 .12 2c-.86.51-2.01.91-3.34 1.18a22.24 22.24 0 0 1-.03 3.19c1.45.29 2.7.73 3.58 1.31a1 1 0 0 1-1.1 1.68c-.6-.4-1.56-.76-2.75-1-.15.8-.36 1.55-.6 2.2-.3.79-.67 1.5-1.14 2.02-.47.53-1.12.97-1.92.97-.8 0-1.45-.44-1.91-.97a6.51 6.51 0 0 1-1.15-2.02c-.24-.65-.44-1.4-.6-2.2-1.18.24-2.13.6-2.73.99a1 1 0 1 1-1.1-1.67c.88-.58 2.12-1.03 3.57-1.31a22.03 22.03 0 0 1-.04-3.2Zm2.2-1.7c.15-.86.34-1.61.58-2.24.24-.65.51-1.12.76-1.4.25-.28.4-.29.42-.29.03 0 .17.01.42.3.25.27.52.74.77 1.4.23.62.43 1.37.57 2.22a19.96 19.96 0 0 1-3.52 0Zm-.18 4.6a20.1 20.1 0 0 1-.03-2.62 21.95 21.95 0 0 0 3.94 0 20.4 20.4 0 0 1-.03 2.63 21.97 21.97 0 0 0-3.88 0Zm.27 2c.13.66.3 1.26.49 1.78.24.65.51 1.12.76 1.4.25.28.4.29.42.29.03 0 .17-.01.42-.3.25-.27.52-.74.77-1.4.19-.5.36-1.1.49-1.78a20.03 20.03 0 0 0-3.35 0Z\"\n    clip-rule=\"evenodd\"\n  />\n</svg>`;\n//# sourceMappingURL=browser.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvYXNzZXRzL3N2Zy9icm93c2VyLmpzIiwibWFwcGluZ3MiOiI7Ozs7O0FBQTBCO0FBQ25CLG1CQUFtQix3Q0FBRztBQUM3QjtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBIiwic291cmNlcyI6WyJ3ZWJwYWNrOi8vX05fRS8uL25vZGVfbW9kdWxlcy9AcmVvd24vYXBwa2l0LXVpL2Rpc3QvZXNtL3NyYy9hc3NldHMvc3ZnL2Jyb3dzZXIuanM/ZGEzMCJdLCJzb3VyY2VzQ29udGVudCI6WyJpbXBvcnQgeyBzdmcgfSBmcm9tICdsaXQnO1xuZXhwb3J0IGNvbnN0IGJyb3dzZXJTdmcgPSBzdmcgYDxzdmcgZmlsbD1cIm5vbmVcIiB2aWV3Qm94PVwiMCAwIDIwIDIwXCI+XG4gIDxwYXRoXG4gICAgZmlsbD1cImN1cnJlbnRDb2xvclwiXG4gICAgZmlsbC1ydWxlPVwiZXZlbm9kZFwiXG4gICAgZD1cIk00IDYuNGExIDEgMCAwIDEtLjQ2Ljg5IDYuOTggNi45OCAwIDAgMCAuMzggNi4xOEE3IDcgMCAwIDAgMTYuNDYgNy4zYTEgMSAwIDAgMS0uNDctLjkyIDcgNyAwIDAgMC0xMiAuMDNabS0yLjAyLS41YTkgOSAwIDEgMSAxNi4wMyA4LjJBOSA5IDAgMCAxIDEuOTggNS45WlwiXG4gICAgY2xpcC1ydWxlPVwiZXZlbm9kZFwiXG4gIC8+XG4gIDxwYXRoXG4gICAgZmlsbD1cImN1cnJlbnRDb2xvclwiXG4gICAgZmlsbC1ydWxlPVwiZXZlbm9kZFwiXG4gICAgZD1cIk02LjAzIDguNjNjLTEuNDYtLjMtMi43Mi0uNzUtMy42LTEuMzVsLS4wMi0uMDEtLjE0LS4xMWExIDEgMCAwIDEgMS4yLTEuNmwuMS4wOGMuNi40IDEuNTIuNzQgMi42OSAxIC4xNi0uOTkuMzktMS44OC42Ny0yLjY1LjMtLjc5LjY4LTEuNSAxLjE1LTIuMDJBMi41OCAyLjU4IDAgMCAxIDkuOTkgMWMuOCAwIDEuNDUuNDQgMS45Mi45Ny40Ny41Mi44NCAxLjIzIDEuMTQgMi4wMi4yOS43Ny41MiAxLjY2LjY4IDIuNjRhOCA4IDAgMCAwIDIuNy0xbC4yNi0uMThoLjQ4YTEgMSAwIDAgMSAuMTIgMmMtLjg2LjUxLTIuMDEuOTEtMy4zNCAxLjE4YTIyLjI0IDIyLjI0IDAgMCAxLS4wMyAzLjE5YzEuNDUuMjkgMi43LjczIDMuNTggMS4zMWExIDEgMCAwIDEtMS4xIDEuNjhjLS42LS40LTEuNTYtLjc2LTIuNzUtMS0uMTUuOC0uMzYgMS41NS0uNiAyLjItLjMuNzktLjY3IDEuNS0xLjE0IDIuMDItLjQ3LjUzLTEuMTIuOTctMS45Mi45Ny0uOCAwLTEuNDUtLjQ0LTEuOTEtLjk3YTYuNTEgNi41MSAwIDAgMS0xLjE1LTIuMDJjLS4yNC0uNjUtLjQ0LTEuNC0uNi0yLjItMS4xOC4yNC0yLjEzLjYtMi43My45OWExIDEgMCAxIDEtMS4xLTEuNjdjLjg4LS41OCAyLjEyLTEuMDMgMy41Ny0xLjMxYTIyLjAzIDIyLjAzIDAgMCAxLS4wNC0zLjJabTIuMi0xLjdjLjE1LS44Ni4zNC0xLjYxLjU4LTIuMjQuMjQtLjY1LjUxLTEuMTIuNzYtMS40LjI1LS4yOC40LS4yOS40Mi0uMjkuMDMgMCAuMTcuMDEuNDIuMy4yNS4yNy41Mi43NC43NyAxLjQuMjMuNjIuNDMgMS4zNy41NyAyLjIyYTE5Ljk2IDE5Ljk2IDAgMCAxLTMuNTIgMFptLS4xOCA0LjZhMjAuMSAyMC4xIDAgMCAxLS4wMy0yLjYyIDIxLjk1IDIxLjk1IDAgMCAwIDMuOTQgMCAyMC40IDIwLjQgMCAwIDEtLjAzIDIuNjMgMjEuOTcgMjEuOTcgMCAwIDAtMy44OCAwWm0uMjcgMmMuMTMuNjYuMyAxLjI2LjQ5IDEuNzguMjQuNjUuNTEgMS4xMi43NiAxLjQuMjUuMjguNC4yOS40Mi4yOS4wMyAwIC4xNy0uMDEuNDItLjMuMjUtLjI3LjUyLS43NC43Ny0xLjQuMTktLjUuMzYtMS4xLjQ5LTEuNzhhMjAuMDMgMjAuMDMgMCAwIDAtMy4zNSAwWlwiXG4gICAgY2xpcC1ydWxlPVwiZXZlbm9kZFwiXG4gIC8+XG48L3N2Zz5gO1xuLy8jIHNvdXJjZU1hcHBpbmdVUkw9YnJvd3Nlci5qcy5tYXAiXSwibmFtZXMiOltdLCJzb3VyY2VSb290IjoiIn0=\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/browser.js\n"));
-
-/***/ })
-
-}]);
\ No newline at end of file
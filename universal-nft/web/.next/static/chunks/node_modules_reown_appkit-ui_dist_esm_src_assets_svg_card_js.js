@@ -1,22 +0,0 @@
-"use strict";
-/*
- * ATTENTION: An "eval-source-map" devtool has been used.
- * This devtool is neither made for production nor for readable output files.
- * It uses "eval()" calls to create a separate source file with attached SourceMaps in the browser devtools.
- * If you are trying to read the output file, select a different devtool (https://webpack.js.org/configuration/devtool/)
- * or disable the default devtool with "devtool: false".
- * If you are looking for production-ready output files, see mode: "production" (https://webpack.js.org/configuration/mode/).
- */
-(self["webpackChunk_N_E"] = self["webpackChunk_N_E"] || []).push([["node_modules_reown_appkit-ui_dist_esm_src_assets_svg_card_js"],{
-
-/***/ "./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/card.js":
-/*!***********************************************************************!*\
-  !*** ./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/card.js ***!
-  \***********************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   cardSvg: function() { return /* binding */ cardSvg; }\n/* harmony export */ });\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n\nconst cardSvg = (0,lit__WEBPACK_IMPORTED_MODULE_0__.svg) `<svg\n  xmlns=\"http://www.w3.org/2000/svg\"\n  width=\"12\"\n  height=\"13\"\n  viewBox=\"0 0 12 13\"\n  fill=\"none\"\n>\n  <path\n    fill-rule=\"evenodd\"\n    clip-rule=\"evenodd\"\n    d=\"M4.16072 2C4.17367 2 4.18665 2 4.19968 2L7.83857 2C8.36772 1.99998 8.82398 1.99996 9.19518 2.04018C9.5895 2.0829 9.97577 2.17811 10.3221 2.42971C10.5131 2.56849 10.6811 2.73647 10.8198 2.92749C11.0714 3.27379 11.1666 3.66007 11.2094 4.0544C11.2496 4.42561 11.2496 4.88188 11.2495 5.41105V7.58896C11.2496 8.11812 11.2496 8.57439 11.2094 8.94561C11.1666 9.33994 11.0714 9.72621 10.8198 10.0725C10.6811 10.2635 10.5131 10.4315 10.3221 10.5703C9.97577 10.8219 9.5895 10.9171 9.19518 10.9598C8.82398 11 8.36772 11 7.83856 11H4.16073C3.63157 11 3.17531 11 2.80411 10.9598C2.40979 10.9171 2.02352 10.8219 1.67722 10.5703C1.48621 10.4315 1.31824 10.2635 1.17946 10.0725C0.927858 9.72621 0.832652 9.33994 0.78993 8.94561C0.749713 8.5744 0.749733 8.11813 0.749757 7.58896L0.749758 5.45C0.749758 5.43697 0.749758 5.42399 0.749757 5.41104C0.749733 4.88188 0.749713 4.42561 0.78993 4.0544C0.832652 3.66007 0.927858 3.27379 1.17946 2.92749C1.31824 2.73647 1.48621 2.56849 1.67722 2.42971C2.02352 2.17811 2.40979 2.0829 2.80411 2.04018C3.17531 1.99996 3.63157 1.99998 4.16072 2ZM2.96567 3.53145C2.69897 3.56034 2.60687 3.60837 2.55888 3.64324C2.49521 3.6895 2.43922 3.74549 2.39296 3.80916C2.35809 3.85715 2.31007 3.94926 2.28117 4.21597C2.26629 4.35335 2.25844 4.51311 2.25431 4.70832H9.74498C9.74085 4.51311 9.733 4.35335 9.71812 4.21597C9.68922 3.94926 9.6412 3.85715 9.60633 3.80916C9.56007 3.74549 9.50408 3.6895 9.44041 3.64324C9.39242 3.60837 9.30031 3.56034 9.03362 3.53145C8.75288 3.50103 8.37876 3.5 7.79961 3.5H4.19968C3.62053 3.5 3.24641 3.50103 2.96567 3.53145ZM9.74956 6.20832H2.24973V7.55C2.24973 8.12917 2.25076 8.5033 2.28117 8.78404C2.31007 9.05074 2.35809 9.14285 2.39296 9.19084C2.43922 9.25451 2.49521 9.31051 2.55888 9.35677C2.60687 9.39163 2.69897 9.43966 2.96567 9.46856C3.24641 9.49897 3.62053 9.5 4.19968 9.5H7.79961C8.37876 9.5 8.75288 9.49897 9.03362 9.46856C9.30032 9.43966 9.39242 9.39163 9.44041 9.35677C9.50408 9.31051 9.56007 9.25451 9.60633 9.19084C9.6412 9.14285 9.68922 9.05075 9.71812 8.78404C9.74854 8.5033 9.74956 8.12917 9.74956 7.55V6.20832ZM6.74963 8C6.74963 7.58579 7.08541 7.25 7.49961 7.25H8.2496C8.6638 7.25 8.99958 7.58579 8.99958 8C8.99958 8.41422 8.6638 8.75 8.2496 8.75H7.49961C7.08541 8.75 6.74963 8.41422 6.74963 8Z\"\n    fill=\"currentColor\"\n  /></svg\n>`;\n//# sourceMappingURL=card.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvYXNzZXRzL3N2Zy9jYXJkLmpzIiwibWFwcGluZ3MiOiI7Ozs7O0FBQTBCO0FBQ25CLGdCQUFnQix3Q0FBRztBQUMxQjtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBIiwic291cmNlcyI6WyJ3ZWJwYWNrOi8vX05fRS8uL25vZGVfbW9kdWxlcy9AcmVvd24vYXBwa2l0LXVpL2Rpc3QvZXNtL3NyYy9hc3NldHMvc3ZnL2NhcmQuanM/YjMxZCJdLCJzb3VyY2VzQ29udGVudCI6WyJpbXBvcnQgeyBzdmcgfSBmcm9tICdsaXQnO1xuZXhwb3J0IGNvbnN0IGNhcmRTdmcgPSBzdmcgYDxzdmdcbiAgeG1sbnM9XCJodHRwOi8vd3d3LnczLm9yZy8yMDAwL3N2Z1wiXG4gIHdpZHRoPVwiMTJcIlxuICBoZWlnaHQ9XCIxM1wiXG4gIHZpZXdCb3g9XCIwIDAgMTIgMTNcIlxuICBmaWxsPVwibm9uZVwiXG4+XG4gIDxwYXRoXG4gICAgZmlsbC1ydWxlPVwiZXZlbm9kZFwiXG4gICAgY2xpcC1ydWxlPVwiZXZlbm9kZFwiXG4gICAgZD1cIk00LjE2MDcyIDJDNC4xNzM2NyAyIDQuMTg2NjUgMiA0LjE5OTY4IDJMNy44Mzg1NyAyQzguMzY3NzIgMS45OTk5OCA4LjgyMzk4IDEuOTk5OTYgOS4xOTUxOCAyLjA0MDE4QzkuNTg5NSAyLjA4MjkgOS45NzU3NyAyLjE3ODExIDEwLjMyMjEgMi40Mjk3MUMxMC41MTMxIDIuNTY4NDkgMTAuNjgxMSAyLjczNjQ3IDEwLjgxOTggMi45Mjc0OUMxMS4wNzE0IDMuMjczNzkgMTEuMTY2NiAzLjY2MDA3IDExLjIwOTQgNC4wNTQ0QzExLjI0OTYgNC40MjU2MSAxMS4yNDk2IDQuODgxODggMTEuMjQ5NSA1LjQxMTA1VjcuNTg4OTZDMTEuMjQ5NiA4LjExODEyIDExLjI0OTYgOC41NzQzOSAxMS4yMDk0IDguOTQ1NjFDMTEuMTY2NiA5LjMzOTk0IDExLjA3MTQgOS43MjYyMSAxMC44MTk4IDEwLjA3MjVDMTAuNjgxMSAxMC4yNjM1IDEwLjUxMzEgMTAuNDMxNSAxMC4zMjIxIDEwLjU3MDNDOS45NzU3NyAxMC44MjE5IDkuNTg5NSAxMC45MTcxIDkuMTk1MTggMTAuOTU5OEM4LjgyMzk4IDExIDguMzY3NzIgMTEgNy44Mzg1NiAxMUg0LjE2MDczQzMuNjMxNTcgMTEgMy4xNzUzMSAxMSAyLjgwNDExIDEwLjk1OThDMi40MDk3OSAxMC45MTcxIDIuMDIzNTIgMTAuODIxOSAxLjY3NzIyIDEwLjU3MDNDMS40ODYyMSAxMC40MzE1IDEuMzE4MjQgMTAuMjYzNSAxLjE3OTQ2IDEwLjA3MjVDMC45Mjc4NTggOS43MjYyMSAwLjgzMjY1MiA5LjMzOTk0IDAuNzg5OTMgOC45NDU2MUMwLjc0OTcxMyA4LjU3NDQgMC43NDk3MzMgOC4xMTgxMyAwLjc0OTc1NyA3LjU4ODk2TDAuNzQ5NzU4IDUuNDVDMC43NDk3NTggNS40MzY5NyAwLjc0OTc1OCA1LjQyMzk5IDAuNzQ5NzU3IDUuNDExMDRDMC43NDk3MzMgNC44ODE4OCAwLjc0OTcxMyA0LjQyNTYxIDAuNzg5OTMgNC4wNTQ0QzAuODMyNjUyIDMuNjYwMDcgMC45Mjc4NTggMy4yNzM3OSAxLjE3OTQ2IDIuOTI3NDlDMS4zMTgyNCAyLjczNjQ3IDEuNDg2MjEgMi41Njg0OSAxLjY3NzIyIDIuNDI5NzFDMi4wMjM1MiAyLjE3ODExIDIuNDA5NzkgMi4wODI5IDIuODA0MTEgMi4wNDAxOEMzLjE3NTMxIDEuOTk5OTYgMy42MzE1NyAxLjk5OTk4IDQuMTYwNzIgMlpNMi45NjU2NyAzLjUzMTQ1QzIuNjk4OTcgMy41NjAzNCAyLjYwNjg3IDMuNjA4MzcgMi41NTg4OCAzLjY0MzI0QzIuNDk1MjEgMy42ODk1IDIuNDM5MjIgMy43NDU0OSAyLjM5Mjk2IDMuODA5MTZDMi4zNTgwOSAzLjg1NzE1IDIuMzEwMDcgMy45NDkyNiAyLjI4MTE3IDQuMjE1OTdDMi4yNjYyOSA0LjM1MzM1IDIuMjU4NDQgNC41MTMxMSAyLjI1NDMxIDQuNzA4MzJIOS43NDQ5OEM5Ljc0MDg1IDQuNTEzMTEgOS43MzMgNC4zNTMzNSA5LjcxODEyIDQuMjE1OTdDOS42ODkyMiAzLjk0OTI2IDkuNjQxMiAzLjg1NzE1IDkuNjA2MzMgMy44MDkxNkM5LjU2MDA3IDMuNzQ1NDkgOS41MDQwOCAzLjY4OTUgOS40NDA0MSAzLjY0MzI0QzkuMzkyNDIgMy42MDgzNyA5LjMwMDMxIDMuNTYwMzQgOS4wMzM2MiAzLjUzMTQ1QzguNzUyODggMy41MDEwMyA4LjM3ODc2IDMuNSA3Ljc5OTYxIDMuNUg0LjE5OTY4QzMuNjIwNTMgMy41IDMuMjQ2NDEgMy41MDEwMyAyLjk2NTY3IDMuNTMxNDVaTTkuNzQ5NTYgNi4yMDgzMkgyLjI0OTczVjcuNTVDMi4yNDk3MyA4LjEyOTE3IDIuMjUwNzYgOC41MDMzIDIuMjgxMTcgOC43ODQwNEMyLjMxMDA3IDkuMDUwNzQgMi4zNTgwOSA5LjE0Mjg1IDIuMzkyOTYgOS4xOTA4NEMyLjQzOTIyIDkuMjU0NTEgMi40OTUyMSA5LjMxMDUxIDIuNTU4ODggOS4zNTY3N0MyLjYwNjg3IDkuMzkxNjMgMi42OTg5NyA5LjQzOTY2IDIuOTY1NjcgOS40Njg1NkMzLjI0NjQxIDkuNDk4OTcgMy42MjA1MyA5LjUgNC4xOTk2OCA5LjVINy43OTk2MUM4LjM3ODc2IDkuNSA4Ljc1Mjg4IDkuNDk4OTcgOS4wMzM2MiA5LjQ2ODU2QzkuMzAwMzIgOS40Mzk2NiA5LjM5MjQyIDkuMzkxNjMgOS40NDA0MSA5LjM1Njc3QzkuNTA0MDggOS4zMTA1MSA5LjU2MDA3IDkuMjU0NTEgOS42MDYzMyA5LjE5MDg0QzkuNjQxMiA5LjE0Mjg1IDkuNjg5MjIgOS4wNTA3NSA5LjcxODEyIDguNzg0MDRDOS43NDg1NCA4LjUwMzMgOS43NDk1NiA4LjEyOTE3IDkuNzQ5NTYgNy41NVY2LjIwODMyWk02Ljc0OTYzIDhDNi43NDk2MyA3LjU4NTc5IDcuMDg1NDEgNy4yNSA3LjQ5OTYxIDcuMjVIOC4yNDk2QzguNjYzOCA3LjI1IDguOTk5NTggNy41ODU3OSA4Ljk5OTU4IDhDOC45OTk1OCA4LjQxNDIyIDguNjYzOCA4Ljc1IDguMjQ5NiA4Ljc1SDcuNDk5NjFDNy4wODU0MSA4Ljc1IDYuNzQ5NjMgOC40MTQyMiA2Ljc0OTYzIDhaXCJcbiAgICBmaWxsPVwiY3VycmVudENvbG9yXCJcbiAgLz48L3N2Z1xuPmA7XG4vLyMgc291cmNlTWFwcGluZ1VSTD1jYXJkLmpzLm1hcCJdLCJuYW1lcyI6W10sInNvdXJjZVJvb3QiOiIifQ==\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/card.js\n"));
-
-/***/ })
-
-}]);
\ No newline at end of file
@@ -1,22 +0,0 @@
-"use strict";
-/*
- * ATTENTION: An "eval-source-map" devtool has been used.
- * This devtool is neither made for production nor for readable output files.
- * It uses "eval()" calls to create a separate source file with attached SourceMaps in the browser devtools.
- * If you are trying to read the output file, select a different devtool (https://webpack.js.org/configuration/devtool/)
- * or disable the default devtool with "devtool: false".
- * If you are looking for production-ready output files, see mode: "production" (https://webpack.js.org/configuration/mode/).
- */
-(self["webpackChunk_N_E"] = self["webpackChunk_N_E"] || []).push([["node_modules_reown_appkit-ui_dist_esm_src_assets_svg_checkmark_js"],{
-
-/***/ "./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/checkmark.js":
-/*!****************************************************************************!*\
-  !*** ./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/checkmark.js ***!
-  \****************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   checkmarkSvg: function() { return /* binding */ checkmarkSvg; }\n/* harmony export */ });\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n\nconst checkmarkSvg = (0,lit__WEBPACK_IMPORTED_MODULE_0__.svg) `<svg\n  width=\"28\"\n  height=\"28\"\n  viewBox=\"0 0 28 28\" fill=\"none\" xmlns=\"http://www.w3.org/2000/svg\">\n  <path\n    fill-rule=\"evenodd\"\n    clip-rule=\"evenodd\"\n    d=\"M25.5297 4.92733C26.1221 5.4242 26.1996 6.30724 25.7027 6.89966L12.2836 22.8997C12.0316 23.2001 11.6652 23.3811 11.2735 23.3986C10.8817 23.4161 10.5006 23.2686 10.2228 22.9919L2.38218 15.1815C1.83439 14.6358 1.83268 13.7494 2.37835 13.2016C2.92403 12.6538 3.81046 12.6521 4.35825 13.1978L11.1183 19.9317L23.5573 5.10036C24.0542 4.50794 24.9372 4.43047 25.5297 4.92733Z\"\n    fill=\"currentColor\"/>\n</svg>\n`;\n//# sourceMappingURL=checkmark.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvYXNzZXRzL3N2Zy9jaGVja21hcmsuanMiLCJtYXBwaW5ncyI6Ijs7Ozs7QUFBMEI7QUFDbkIscUJBQXFCLHdDQUFHO0FBQy9CO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0EiLCJzb3VyY2VzIjpbIndlYnBhY2s6Ly9fTl9FLy4vbm9kZV9tb2R1bGVzL0ByZW93bi9hcHBraXQtdWkvZGlzdC9lc20vc3JjL2Fzc2V0cy9zdmcvY2hlY2ttYXJrLmpzPzQxYWMiXSwic291cmNlc0NvbnRlbnQiOlsiaW1wb3J0IHsgc3ZnIH0gZnJvbSAnbGl0JztcbmV4cG9ydCBjb25zdCBjaGVja21hcmtTdmcgPSBzdmcgYDxzdmdcbiAgd2lkdGg9XCIyOFwiXG4gIGhlaWdodD1cIjI4XCJcbiAgdmlld0JveD1cIjAgMCAyOCAyOFwiIGZpbGw9XCJub25lXCIgeG1sbnM9XCJodHRwOi8vd3d3LnczLm9yZy8yMDAwL3N2Z1wiPlxuICA8cGF0aFxuICAgIGZpbGwtcnVsZT1cImV2ZW5vZGRcIlxuICAgIGNsaXAtcnVsZT1cImV2ZW5vZGRcIlxuICAgIGQ9XCJNMjUuNTI5NyA0LjkyNzMzQzI2LjEyMjEgNS40MjQyIDI2LjE5OTYgNi4zMDcyNCAyNS43MDI3IDYuODk5NjZMMTIuMjgzNiAyMi44OTk3QzEyLjAzMTYgMjMuMjAwMSAxMS42NjUyIDIzLjM4MTEgMTEuMjczNSAyMy4zOTg2QzEwLjg4MTcgMjMuNDE2MSAxMC41MDA2IDIzLjI2ODYgMTAuMjIyOCAyMi45OTE5TDIuMzgyMTggMTUuMTgxNUMxLjgzNDM5IDE0LjYzNTggMS44MzI2OCAxMy43NDk0IDIuMzc4MzUgMTMuMjAxNkMyLjkyNDAzIDEyLjY1MzggMy44MTA0NiAxMi42NTIxIDQuMzU4MjUgMTMuMTk3OEwxMS4xMTgzIDE5LjkzMTdMMjMuNTU3MyA1LjEwMDM2QzI0LjA1NDIgNC41MDc5NCAyNC45MzcyIDQuNDMwNDcgMjUuNTI5NyA0LjkyNzMzWlwiXG4gICAgZmlsbD1cImN1cnJlbnRDb2xvclwiLz5cbjwvc3ZnPlxuYDtcbi8vIyBzb3VyY2VNYXBwaW5nVVJMPWNoZWNrbWFyay5qcy5tYXAiXSwibmFtZXMiOltdLCJzb3VyY2VSb290IjoiIn0=\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/checkmark.js\n"));
-
-/***/ })
-
-}]);
\ No newline at end of file
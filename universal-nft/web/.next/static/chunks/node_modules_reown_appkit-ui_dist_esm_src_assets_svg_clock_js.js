@@ -1,22 +0,0 @@
-"use strict";
-/*
- * ATTENTION: An "eval-source-map" devtool has been used.
- * This devtool is neither made for production nor for readable output files.
- * It uses "eval()" calls to create a separate source file with attached SourceMaps in the browser devtools.
- * If you are trying to read the output file, select a different devtool (https://webpack.js.org/configuration/devtool/)
- * or disable the default devtool with "devtool: false".
- * If you are looking for production-ready output files, see mode: "production" (https://webpack.js.org/configuration/mode/).
- */
-(self["webpackChunk_N_E"] = self["webpackChunk_N_E"] || []).push([["node_modules_reown_appkit-ui_dist_esm_src_assets_svg_clock_js"],{
-
-/***/ "./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/clock.js":
-/*!************************************************************************!*\
-  !*** ./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/clock.js ***!
-  \************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   clockSvg: function() { return /* binding */ clockSvg; }\n/* harmony export */ });\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n\nconst clockSvg = (0,lit__WEBPACK_IMPORTED_MODULE_0__.svg) `<svg width=\"14\" height=\"14\" viewBox=\"0 0 14 14\" fill=\"none\">\n  <path \n    fill-rule=\"evenodd\" \n    clip-rule=\"evenodd\" \n    d=\"M7.00235 2C4.24 2 2.00067 4.23858 2.00067 7C2.00067 9.76142 4.24 12 7.00235 12C9.7647 12 12.004 9.76142 12.004 7C12.004 4.23858 9.7647 2 7.00235 2ZM0 7C0 3.13401 3.13506 0 7.00235 0C10.8696 0 14.0047 3.13401 14.0047 7C14.0047 10.866 10.8696 14 7.00235 14C3.13506 14 0 10.866 0 7ZM7.00235 3C7.55482 3 8.00269 3.44771 8.00269 4V6.58579L9.85327 8.43575C10.2439 8.82627 10.2439 9.45944 9.85327 9.84996C9.46262 10.2405 8.82924 10.2405 8.43858 9.84996L6.29501 7.70711C6.10741 7.51957 6.00201 7.26522 6.00201 7V4C6.00201 3.44771 6.44988 3 7.00235 3Z\" \n    fill=\"currentColor\"\n  />\n</svg>`;\n//# sourceMappingURL=clock.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvYXNzZXRzL3N2Zy9jbG9jay5qcyIsIm1hcHBpbmdzIjoiOzs7OztBQUEwQjtBQUNuQixpQkFBaUIsd0NBQUc7QUFDM0I7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQSIsInNvdXJjZXMiOlsid2VicGFjazovL19OX0UvLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvYXNzZXRzL3N2Zy9jbG9jay5qcz81ZWQ1Il0sInNvdXJjZXNDb250ZW50IjpbImltcG9ydCB7IHN2ZyB9IGZyb20gJ2xpdCc7XG5leHBvcnQgY29uc3QgY2xvY2tTdmcgPSBzdmcgYDxzdmcgd2lkdGg9XCIxNFwiIGhlaWdodD1cIjE0XCIgdmlld0JveD1cIjAgMCAxNCAxNFwiIGZpbGw9XCJub25lXCI+XG4gIDxwYXRoIFxuICAgIGZpbGwtcnVsZT1cImV2ZW5vZGRcIiBcbiAgICBjbGlwLXJ1bGU9XCJldmVub2RkXCIgXG4gICAgZD1cIk03LjAwMjM1IDJDNC4yNCAyIDIuMDAwNjcgNC4yMzg1OCAyLjAwMDY3IDdDMi4wMDA2NyA5Ljc2MTQyIDQuMjQgMTIgNy4wMDIzNSAxMkM5Ljc2NDcgMTIgMTIuMDA0IDkuNzYxNDIgMTIuMDA0IDdDMTIuMDA0IDQuMjM4NTggOS43NjQ3IDIgNy4wMDIzNSAyWk0wIDdDMCAzLjEzNDAxIDMuMTM1MDYgMCA3LjAwMjM1IDBDMTAuODY5NiAwIDE0LjAwNDcgMy4xMzQwMSAxNC4wMDQ3IDdDMTQuMDA0NyAxMC44NjYgMTAuODY5NiAxNCA3LjAwMjM1IDE0QzMuMTM1MDYgMTQgMCAxMC44NjYgMCA3Wk03LjAwMjM1IDNDNy41NTQ4MiAzIDguMDAyNjkgMy40NDc3MSA4LjAwMjY5IDRWNi41ODU3OUw5Ljg1MzI3IDguNDM1NzVDMTAuMjQzOSA4LjgyNjI3IDEwLjI0MzkgOS40NTk0NCA5Ljg1MzI3IDkuODQ5OTZDOS40NjI2MiAxMC4yNDA1IDguODI5MjQgMTAuMjQwNSA4LjQzODU4IDkuODQ5OTZMNi4yOTUwMSA3LjcwNzExQzYuMTA3NDEgNy41MTk1NyA2LjAwMjAxIDcuMjY1MjIgNi4wMDIwMSA3VjRDNi4wMDIwMSAzLjQ0NzcxIDYuNDQ5ODggMyA3LjAwMjM1IDNaXCIgXG4gICAgZmlsbD1cImN1cnJlbnRDb2xvclwiXG4gIC8+XG48L3N2Zz5gO1xuLy8jIHNvdXJjZU1hcHBpbmdVUkw9Y2xvY2suanMubWFwIl0sIm5hbWVzIjpbXSwic291cmNlUm9vdCI6IiJ9\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/clock.js\n"));
-
-/***/ })
-
-}]);
\ No newline at end of file
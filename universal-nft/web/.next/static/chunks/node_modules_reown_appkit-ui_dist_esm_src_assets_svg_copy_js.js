@@ -1,22 +0,0 @@
-"use strict";
-/*
- * ATTENTION: An "eval-source-map" devtool has been used.
- * This devtool is neither made for production nor for readable output files.
- * It uses "eval()" calls to create a separate source file with attached SourceMaps in the browser devtools.
- * If you are trying to read the output file, select a different devtool (https://webpack.js.org/configuration/devtool/)
- * or disable the default devtool with "devtool: false".
- * If you are looking for production-ready output files, see mode: "production" (https://webpack.js.org/configuration/mode/).
- */
-(self["webpackChunk_N_E"] = self["webpackChunk_N_E"] || []).push([["node_modules_reown_appkit-ui_dist_esm_src_assets_svg_copy_js"],{
-
-/***/ "./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/copy.js":
-/*!***********************************************************************!*\
-  !*** ./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/copy.js ***!
-  \***********************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   copySvg: function() { return /* binding */ copySvg; }\n/* harmony export */ });\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n\nconst copySvg = (0,lit__WEBPACK_IMPORTED_MODULE_0__.svg) `<svg\n  xmlns=\"http://www.w3.org/2000/svg\"\n  width=\"16\"\n  height=\"16\"\n  viewBox=\"0 0 16 16\"\n  fill=\"none\"\n>\n  <path\n    fill=\"currentColor\"\n    fill-rule=\"evenodd\"\n    clip-rule=\"evenodd\"\n    d=\"M9.21498 1.28565H10.5944C11.1458 1.28562 11.6246 1.2856 12.0182 1.32093C12.4353 1.35836 12.853 1.44155 13.2486 1.66724C13.7005 1.92498 14.0749 2.29935 14.3326 2.75122C14.5583 3.14689 14.6415 3.56456 14.6789 3.9817C14.7143 4.37531 14.7142 4.85403 14.7142 5.40545V6.78489C14.7142 7.33631 14.7143 7.81503 14.6789 8.20865C14.6415 8.62578 14.5583 9.04345 14.3326 9.43912C14.0749 9.89099 13.7005 10.2654 13.2486 10.5231C12.853 10.7488 12.4353 10.832 12.0182 10.8694C11.7003 10.8979 11.3269 10.9034 10.9045 10.9045C10.9034 11.3269 10.8979 11.7003 10.8694 12.0182C10.832 12.4353 10.7488 12.853 10.5231 13.2486C10.2654 13.7005 9.89099 14.0749 9.43912 14.3326C9.04345 14.5583 8.62578 14.6415 8.20865 14.6789C7.81503 14.7143 7.33631 14.7142 6.78489 14.7142H5.40545C4.85403 14.7142 4.37531 14.7143 3.9817 14.6789C3.56456 14.6415 3.14689 14.5583 2.75122 14.3326C2.29935 14.0749 1.92498 13.7005 1.66724 13.2486C1.44155 12.853 1.35836 12.4353 1.32093 12.0182C1.2856 11.6246 1.28562 11.1458 1.28565 10.5944V9.21498C1.28562 8.66356 1.2856 8.18484 1.32093 7.79122C1.35836 7.37409 1.44155 6.95642 1.66724 6.56074C1.92498 6.10887 2.29935 5.73451 2.75122 5.47677C3.14689 5.25108 3.56456 5.16789 3.9817 5.13045C4.2996 5.10192 4.67301 5.09645 5.09541 5.09541C5.09645 4.67302 5.10192 4.2996 5.13045 3.9817C5.16789 3.56456 5.25108 3.14689 5.47676 2.75122C5.73451 2.29935 6.10887 1.92498 6.56074 1.66724C6.95642 1.44155 7.37409 1.35836 7.79122 1.32093C8.18484 1.2856 8.66356 1.28562 9.21498 1.28565ZM5.09541 7.09552C4.68397 7.09667 4.39263 7.10161 4.16046 7.12245C3.88053 7.14757 3.78516 7.18949 3.74214 7.21403C3.60139 7.29431 3.48478 7.41091 3.4045 7.55166C3.37997 7.59468 3.33804 7.69005 3.31292 7.96999C3.28659 8.26345 3.28565 8.65147 3.28565 9.25708V10.5523C3.28565 11.1579 3.28659 11.5459 3.31292 11.8394C3.33804 12.1193 3.37997 12.2147 3.4045 12.2577C3.48478 12.3985 3.60139 12.5151 3.74214 12.5954C3.78516 12.6199 3.88053 12.6618 4.16046 12.6869C4.45393 12.7133 4.84195 12.7142 5.44755 12.7142H6.74279C7.3484 12.7142 7.73641 12.7133 8.02988 12.6869C8.30981 12.6618 8.40518 12.6199 8.44821 12.5954C8.58895 12.5151 8.70556 12.3985 8.78584 12.2577C8.81038 12.2147 8.8523 12.1193 8.87742 11.8394C8.89825 11.6072 8.90319 11.3159 8.90435 10.9045C8.48219 10.9034 8.10898 10.8979 7.79122 10.8694C7.37409 10.832 6.95641 10.7488 6.56074 10.5231C6.10887 10.2654 5.73451 9.89099 5.47676 9.43912C5.25108 9.04345 5.16789 8.62578 5.13045 8.20865C5.10194 7.89089 5.09645 7.51767 5.09541 7.09552ZM7.96999 3.31292C7.69005 3.33804 7.59468 3.37997 7.55166 3.4045C7.41091 3.48478 7.29431 3.60139 7.21403 3.74214C7.18949 3.78516 7.14757 3.88053 7.12245 4.16046C7.09611 4.45393 7.09517 4.84195 7.09517 5.44755V6.74279C7.09517 7.3484 7.09611 7.73641 7.12245 8.02988C7.14757 8.30981 7.18949 8.40518 7.21403 8.4482C7.29431 8.58895 7.41091 8.70556 7.55166 8.78584C7.59468 8.81038 7.69005 8.8523 7.96999 8.87742C8.26345 8.90376 8.65147 8.9047 9.25708 8.9047H10.5523C11.1579 8.9047 11.5459 8.90376 11.8394 8.87742C12.1193 8.8523 12.2147 8.81038 12.2577 8.78584C12.3985 8.70556 12.5151 8.58895 12.5954 8.4482C12.6199 8.40518 12.6618 8.30981 12.6869 8.02988C12.7133 7.73641 12.7142 7.3484 12.7142 6.74279V5.44755C12.7142 4.84195 12.7133 4.45393 12.6869 4.16046C12.6618 3.88053 12.6199 3.78516 12.5954 3.74214C12.5151 3.60139 12.3985 3.48478 12.2577 3.4045C12.2147 3.37997 12.1193 3.33804 11.8394 3.31292C11.5459 3.28659 11.1579 3.28565 10.5523 3.28565H9.25708C8.65147 3.28565 8.26345 3.28659 7.96999 3.31292Z\"\n    fill=\"#788181\"\n  /></svg\n>`;\n//# sourceMappingURL=copy.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvYXNzZXRzL3N2Zy9jb3B5LmpzIiwibWFwcGluZ3MiOiI7Ozs7O0FBQTBCO0FBQ25CLGdCQUFnQix3Q0FBRztBQUMxQjtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0EiLCJzb3VyY2VzIjpbIndlYnBhY2s6Ly9fTl9FLy4vbm9kZV9tb2R1bGVzL0ByZW93bi9hcHBraXQtdWkvZGlzdC9lc20vc3JjL2Fzc2V0cy9zdmcvY29weS5qcz85NGMzIl0sInNvdXJjZXNDb250ZW50IjpbImltcG9ydCB7IHN2ZyB9IGZyb20gJ2xpdCc7XG5leHBvcnQgY29uc3QgY29weVN2ZyA9IHN2ZyBgPHN2Z1xuICB4bWxucz1cImh0dHA6Ly93d3cudzMub3JnLzIwMDAvc3ZnXCJcbiAgd2lkdGg9XCIxNlwiXG4gIGhlaWdodD1cIjE2XCJcbiAgdmlld0JveD1cIjAgMCAxNiAxNlwiXG4gIGZpbGw9XCJub25lXCJcbj5cbiAgPHBhdGhcbiAgICBmaWxsPVwiY3VycmVudENvbG9yXCJcbiAgICBmaWxsLXJ1bGU9XCJldmVub2RkXCJcbiAgICBjbGlwLXJ1bGU9XCJldmVub2RkXCJcbiAgICBkPVwiTTkuMjE0OTggMS4yODU2NUgxMC41OTQ0QzExLjE0NTggMS4yODU2MiAxMS42MjQ2IDEuMjg1NiAxMi4wMTgyIDEuMzIwOTNDMTIuNDM1MyAxLjM1ODM2IDEyLjg1MyAxLjQ0MTU1IDEzLjI0ODYgMS42NjcyNEMxMy43MDA1IDEuOTI0OTggMTQuMDc0OSAyLjI5OTM1IDE0LjMzMjYgMi43NTEyMkMxNC41NTgzIDMuMTQ2ODkgMTQuNjQxNSAzLjU2NDU2IDE0LjY3ODkgMy45ODE3QzE0LjcxNDMgNC4zNzUzMSAxNC43MTQyIDQuODU0MDMgMTQuNzE0MiA1LjQwNTQ1VjYuNzg0ODlDMTQuNzE0MiA3LjMzNjMxIDE0LjcxNDMgNy44MTUwMyAxNC42Nzg5IDguMjA4NjVDMTQuNjQxNSA4LjYyNTc4IDE0LjU1ODMgOS4wNDM0NSAxNC4zMzI2IDkuNDM5MTJDMTQuMDc0OSA5Ljg5MDk5IDEzLjcwMDUgMTAuMjY1NCAxMy4yNDg2IDEwLjUyMzFDMTIuODUzIDEwLjc0ODggMTIuNDM1MyAxMC44MzIgMTIuMDE4MiAxMC44Njk0QzExLjcwMDMgMTAuODk3OSAxMS4zMjY5IDEwLjkwMzQgMTAuOTA0NSAxMC45MDQ1QzEwLjkwMzQgMTEuMzI2OSAxMC44OTc5IDExLjcwMDMgMTAuODY5NCAxMi4wMTgyQzEwLjgzMiAxMi40MzUzIDEwLjc0ODggMTIuODUzIDEwLjUyMzEgMTMuMjQ4NkMxMC4yNjU0IDEzLjcwMDUgOS44OTA5OSAxNC4wNzQ5IDkuNDM5MTIgMTQuMzMyNkM5LjA0MzQ1IDE0LjU1ODMgOC42MjU3OCAxNC42NDE1IDguMjA4NjUgMTQuNjc4OUM3LjgxNTAzIDE0LjcxNDMgNy4zMzYzMSAxNC43MTQyIDYuNzg0ODkgMTQuNzE0Mkg1LjQwNTQ1QzQuODU0MDMgMTQuNzE0MiA0LjM3NTMxIDE0LjcxNDMgMy45ODE3IDE0LjY3ODlDMy41NjQ1NiAxNC42NDE1IDMuMTQ2ODkgMTQuNTU4MyAyLjc1MTIyIDE0LjMzMjZDMi4yOTkzNSAxNC4wNzQ5IDEuOTI0OTggMTMuNzAwNSAxLjY2NzI0IDEzLjI0ODZDMS40NDE1NSAxMi44NTMgMS4zNTgzNiAxMi40MzUzIDEuMzIwOTMgMTIuMDE4MkMxLjI4NTYgMTEuNjI0NiAxLjI4NTYyIDExLjE0NTggMS4yODU2NSAxMC41OTQ0VjkuMjE0OThDMS4yODU2MiA4LjY2MzU2IDEuMjg1NiA4LjE4NDg0IDEuMzIwOTMgNy43OTEyMkMxLjM1ODM2IDcuMzc0MDkgMS40NDE1NSA2Ljk1NjQyIDEuNjY3MjQgNi41NjA3NEMxLjkyNDk4IDYuMTA4ODcgMi4yOTkzNSA1LjczNDUxIDIuNzUxMjIgNS40NzY3N0MzLjE0Njg5IDUuMjUxMDggMy41NjQ1NiA1LjE2Nzg5IDMuOTgxNyA1LjEzMDQ1QzQuMjk5NiA1LjEwMTkyIDQuNjczMDEgNS4wOTY0NSA1LjA5NTQxIDUuMDk1NDFDNS4wOTY0NSA0LjY3MzAyIDUuMTAxOTIgNC4yOTk2IDUuMTMwNDUgMy45ODE3QzUuMTY3ODkgMy41NjQ1NiA1LjI1MTA4IDMuMTQ2ODkgNS40NzY3NiAyLjc1MTIyQzUuNzM0NTEgMi4yOTkzNSA2LjEwODg3IDEuOTI0OTggNi41NjA3NCAxLjY2NzI0QzYuOTU2NDIgMS40NDE1NSA3LjM3NDA5IDEuMzU4MzYgNy43OTEyMiAxLjMyMDkzQzguMTg0ODQgMS4yODU2IDguNjYzNTYgMS4yODU2MiA5LjIxNDk4IDEuMjg1NjVaTTUuMDk1NDEgNy4wOTU1MkM0LjY4Mzk3IDcuMDk2NjcgNC4zOTI2MyA3LjEwMTYxIDQuMTYwNDYgNy4xMjI0NUMzLjg4MDUzIDcuMTQ3NTcgMy43ODUxNiA3LjE4OTQ5IDMuNzQyMTQgNy4yMTQwM0MzLjYwMTM5IDcuMjk0MzEgMy40ODQ3OCA3LjQxMDkxIDMuNDA0NSA3LjU1MTY2QzMuMzc5OTcgNy41OTQ2OCAzLjMzODA0IDcuNjkwMDUgMy4zMTI5MiA3Ljk2OTk5QzMuMjg2NTkgOC4yNjM0NSAzLjI4NTY1IDguNjUxNDcgMy4yODU2NSA5LjI1NzA4VjEwLjU1MjNDMy4yODU2NSAxMS4xNTc5IDMuMjg2NTkgMTEuNTQ1OSAzLjMxMjkyIDExLjgzOTRDMy4zMzgwNCAxMi4xMTkzIDMuMzc5OTcgMTIuMjE0NyAzLjQwNDUgMTIuMjU3N0MzLjQ4NDc4IDEyLjM5ODUgMy42MDEzOSAxMi41MTUxIDMuNzQyMTQgMTIuNTk1NEMzLjc4NTE2IDEyLjYxOTkgMy44ODA1MyAxMi42NjE4IDQuMTYwNDYgMTIuNjg2OUM0LjQ1MzkzIDEyLjcxMzMgNC44NDE5NSAxMi43MTQyIDUuNDQ3NTUgMTIuNzE0Mkg2Ljc0Mjc5QzcuMzQ4NCAxMi43MTQyIDcuNzM2NDEgMTIuNzEzMyA4LjAyOTg4IDEyLjY4NjlDOC4zMDk4MSAxMi42NjE4IDguNDA1MTggMTIuNjE5OSA4LjQ0ODIxIDEyLjU5NTRDOC41ODg5NSAxMi41MTUxIDguNzA1NTYgMTIuMzk4NSA4Ljc4NTg0IDEyLjI1NzdDOC44MTAzOCAxMi4yMTQ3IDguODUyMyAxMi4xMTkzIDguODc3NDIgMTEuODM5NEM4Ljg5ODI1IDExLjYwNzIgOC45MDMxOSAxMS4zMTU5IDguOTA0MzUgMTAuOTA0NUM4LjQ4MjE5IDEwLjkwMzQgOC4xMDg5OCAxMC44OTc5IDcuNzkxMjIgMTAuODY5NEM3LjM3NDA5IDEwLjgzMiA2Ljk1NjQxIDEwLjc0ODggNi41NjA3NCAxMC41MjMxQzYuMTA4ODcgMTAuMjY1NCA1LjczNDUxIDkuODkwOTkgNS40NzY3NiA5LjQzOTEyQzUuMjUxMDggOS4wNDM0NSA1LjE2Nzg5IDguNjI1NzggNS4xMzA0NSA4LjIwODY1QzUuMTAxOTQgNy44OTA4OSA1LjA5NjQ1IDcuNTE3NjcgNS4wOTU0MSA3LjA5NTUyWk03Ljk2OTk5IDMuMzEyOTJDNy42OTAwNSAzLjMzODA0IDcuNTk0NjggMy4zNzk5NyA3LjU1MTY2IDMuNDA0NUM3LjQxMDkxIDMuNDg0NzggNy4yOTQzMSAzLjYwMTM5IDcuMjE0MDMgMy43NDIxNEM3LjE4OTQ5IDMuNzg1MTYgNy4xNDc1NyAzLjg4MDUzIDcuMTIyNDUgNC4xNjA0NkM3LjA5NjExIDQuNDUzOTMgNy4wOTUxNyA0Ljg0MTk1IDcuMDk1MTcgNS40NDc1NVY2Ljc0Mjc5QzcuMDk1MTcgNy4zNDg0IDcuMDk2MTEgNy43MzY0MSA3LjEyMjQ1IDguMDI5ODhDNy4xNDc1NyA4LjMwOTgxIDcuMTg5NDkgOC40MDUxOCA3LjIxNDAzIDguNDQ4MkM3LjI5NDMxIDguNTg4OTUgNy40MTA5MSA4LjcwNTU2IDcuNTUxNjYgOC43ODU4NEM3LjU5NDY4IDguODEwMzggNy42OTAwNSA4Ljg1MjMgNy45Njk5OSA4Ljg3NzQyQzguMjYzNDUgOC45MDM3NiA4LjY1MTQ3IDguOTA0NyA5LjI1NzA4IDguOTA0N0gxMC41NTIzQzExLjE1NzkgOC45MDQ3IDExLjU0NTkgOC45MDM3NiAxMS44Mzk0IDguODc3NDJDMTIuMTE5MyA4Ljg1MjMgMTIuMjE0NyA4LjgxMDM4IDEyLjI1NzcgOC43ODU4NEMxMi4zOTg1IDguNzA1NTYgMTIuNTE1MSA4LjU4ODk1IDEyLjU5NTQgOC40NDgyQzEyLjYxOTkgOC40MDUxOCAxMi42NjE4IDguMzA5ODEgMTIuNjg2OSA4LjAyOTg4QzEyLjcxMzMgNy43MzY0MSAxMi43MTQyIDcuMzQ4NCAxMi43MTQyIDYuNzQyNzlWNS40NDc1NUMxMi43MTQyIDQuODQxOTUgMTIuNzEzMyA0LjQ1MzkzIDEyLjY4NjkgNC4xNjA0NkMxMi42NjE4IDMuODgwNTMgMTIuNjE5OSAzLjc4NTE2IDEyLjU5NTQgMy43NDIxNEMxMi41MTUxIDMuNjAxMzkgMTIuMzk4NSAzLjQ4NDc4IDEyLjI1NzcgMy40MDQ1QzEyLjIxNDcgMy4zNzk5NyAxMi4xMTkzIDMuMzM4MDQgMTEuODM5NCAzLjMxMjkyQzExLjU0NTkgMy4yODY1OSAxMS4xNTc5IDMuMjg1NjUgMTAuNTUyMyAzLjI4NTY1SDkuMjU3MDhDOC42NTE0NyAzLjI4NTY1IDguMjYzNDUgMy4yODY1OSA3Ljk2OTk5IDMuMzEyOTJaXCJcbiAgICBmaWxsPVwiIzc4ODE4MVwiXG4gIC8+PC9zdmdcbj5gO1xuLy8jIHNvdXJjZU1hcHBpbmdVUkw9Y29weS5qcy5tYXAiXSwibmFtZXMiOltdLCJzb3VyY2VSb290IjoiIn0=\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/copy.js\n"));
-
-/***/ })
-
-}]);
\ No newline at end of file
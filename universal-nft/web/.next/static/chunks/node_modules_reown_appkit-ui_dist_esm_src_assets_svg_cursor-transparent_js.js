@@ -1,22 +0,0 @@
-"use strict";
-/*
- * ATTENTION: An "eval-source-map" devtool has been used.
- * This devtool is neither made for production nor for readable output files.
- * It uses "eval()" calls to create a separate source file with attached SourceMaps in the browser devtools.
- * If you are trying to read the output file, select a different devtool (https://webpack.js.org/configuration/devtool/)
- * or disable the default devtool with "devtool: false".
- * If you are looking for production-ready output files, see mode: "production" (https://webpack.js.org/configuration/mode/).
- */
-(self["webpackChunk_N_E"] = self["webpackChunk_N_E"] || []).push([["node_modules_reown_appkit-ui_dist_esm_src_assets_svg_cursor-transparent_js"],{
-
-/***/ "./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/cursor-transparent.js":
-/*!*************************************************************************************!*\
-  !*** ./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/cursor-transparent.js ***!
-  \*************************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   cursorTransparentSvg: function() { return /* binding */ cursorTransparentSvg; }\n/* harmony export */ });\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n\nconst cursorTransparentSvg = (0,lit__WEBPACK_IMPORTED_MODULE_0__.svg) `<svg fill=\"none\" viewBox=\"0 0 14 6\">\n  <path style=\"fill: var(--wui-color-bg-150);\" d=\"M0 1h14L9.21 5.12a3.31 3.31 0 0 1-4.49 0L0 1Z\" />\n  <path\n    style=\"stroke: var(--wui-color-inverse-100);\"\n    stroke-opacity=\".05\"\n    d=\"M1.33 1.5h11.32L8.88 4.75l-.01.01a2.81 2.81 0 0 1-3.8 0l-.02-.01L1.33 1.5Z\"\n  />\n  <path\n    style=\"fill: var(--wui-color-bg-150);\"\n    d=\"M1.25.71h11.5L9.21 3.88a3.31 3.31 0 0 1-4.49 0L1.25.71Z\"\n  />\n</svg> `;\n//# sourceMappingURL=cursor-transparent.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvYXNzZXRzL3N2Zy9jdXJzb3ItdHJhbnNwYXJlbnQuanMiLCJtYXBwaW5ncyI6Ijs7Ozs7QUFBMEI7QUFDbkIsNkJBQTZCLHdDQUFHO0FBQ3ZDLDZDQUE2QztBQUM3QztBQUNBLGdEQUFnRDtBQUNoRDtBQUNBO0FBQ0E7QUFDQTtBQUNBLHlDQUF5QztBQUN6QztBQUNBO0FBQ0E7QUFDQSIsInNvdXJjZXMiOlsid2VicGFjazovL19OX0UvLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvYXNzZXRzL3N2Zy9jdXJzb3ItdHJhbnNwYXJlbnQuanM/ZmY5OSJdLCJzb3VyY2VzQ29udGVudCI6WyJpbXBvcnQgeyBzdmcgfSBmcm9tICdsaXQnO1xuZXhwb3J0IGNvbnN0IGN1cnNvclRyYW5zcGFyZW50U3ZnID0gc3ZnIGA8c3ZnIGZpbGw9XCJub25lXCIgdmlld0JveD1cIjAgMCAxNCA2XCI+XG4gIDxwYXRoIHN0eWxlPVwiZmlsbDogdmFyKC0td3VpLWNvbG9yLWJnLTE1MCk7XCIgZD1cIk0wIDFoMTRMOS4yMSA1LjEyYTMuMzEgMy4zMSAwIDAgMS00LjQ5IDBMMCAxWlwiIC8+XG4gIDxwYXRoXG4gICAgc3R5bGU9XCJzdHJva2U6IHZhcigtLXd1aS1jb2xvci1pbnZlcnNlLTEwMCk7XCJcbiAgICBzdHJva2Utb3BhY2l0eT1cIi4wNVwiXG4gICAgZD1cIk0xLjMzIDEuNWgxMS4zMkw4Ljg4IDQuNzVsLS4wMS4wMWEyLjgxIDIuODEgMCAwIDEtMy44IDBsLS4wMi0uMDFMMS4zMyAxLjVaXCJcbiAgLz5cbiAgPHBhdGhcbiAgICBzdHlsZT1cImZpbGw6IHZhcigtLXd1aS1jb2xvci1iZy0xNTApO1wiXG4gICAgZD1cIk0xLjI1LjcxaDExLjVMOS4yMSAzLjg4YTMuMzEgMy4zMSAwIDAgMS00LjQ5IDBMMS4yNS43MVpcIlxuICAvPlxuPC9zdmc+IGA7XG4vLyMgc291cmNlTWFwcGluZ1VSTD1jdXJzb3ItdHJhbnNwYXJlbnQuanMubWFwIl0sIm5hbWVzIjpbXSwic291cmNlUm9vdCI6IiJ9\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/cursor-transparent.js\n"));
-
-/***/ })
-
-}]);
\ No newline at end of file
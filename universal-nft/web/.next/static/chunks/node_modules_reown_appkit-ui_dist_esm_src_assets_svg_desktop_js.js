@@ -1,22 +0,0 @@
-"use strict";
-/*
- * ATTENTION: An "eval-source-map" devtool has been used.
- * This devtool is neither made for production nor for readable output files.
- * It uses "eval()" calls to create a separate source file with attached SourceMaps in the browser devtools.
- * If you are trying to read the output file, select a different devtool (https://webpack.js.org/configuration/devtool/)
- * or disable the default devtool with "devtool: false".
- * If you are looking for production-ready output files, see mode: "production" (https://webpack.js.org/configuration/mode/).
- */
-(self["webpackChunk_N_E"] = self["webpackChunk_N_E"] || []).push([["node_modules_reown_appkit-ui_dist_esm_src_assets_svg_desktop_js"],{
-
-/***/ "./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/desktop.js":
-/*!**************************************************************************!*\
-  !*** ./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/desktop.js ***!
-  \**************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   desktopSvg: function() { return /* binding */ desktopSvg; }\n/* harmony export */ });\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n\nconst desktopSvg = (0,lit__WEBPACK_IMPORTED_MODULE_0__.svg) `<svg fill=\"none\" viewBox=\"0 0 20 20\">\n  <path\n    fill=\"currentColor\"\n    fill-rule=\"evenodd\"\n    d=\"M13.66 2H6.34c-1.07 0-1.96 0-2.68.08-.74.08-1.42.25-2.01.68a4 4 0 0 0-.89.89c-.43.6-.6 1.27-.68 2.01C0 6.38 0 7.26 0 8.34v.89c0 1.07 0 1.96.08 2.68.08.74.25 1.42.68 2.01a4 4 0 0 0 .89.89c.6.43 1.27.6 2.01.68a27 27 0 0 0 2.68.08h7.32a27 27 0 0 0 2.68-.08 4.03 4.03 0 0 0 2.01-.68 4 4 0 0 0 .89-.89c.43-.6.6-1.27.68-2.01.08-.72.08-1.6.08-2.68v-.89c0-1.07 0-1.96-.08-2.68a4.04 4.04 0 0 0-.68-2.01 4 4 0 0 0-.89-.89c-.6-.43-1.27-.6-2.01-.68C15.62 2 14.74 2 13.66 2ZM2.82 4.38c.2-.14.48-.25 1.06-.31C4.48 4 5.25 4 6.4 4h7.2c1.15 0 1.93 0 2.52.07.58.06.86.17 1.06.31a2 2 0 0 1 .44.44c.14.2.25.48.31 1.06.07.6.07 1.37.07 2.52v.77c0 1.15 0 1.93-.07 2.52-.06.58-.17.86-.31 1.06a2 2 0 0 1-.44.44c-.2.14-.48.25-1.06.32-.6.06-1.37.06-2.52.06H6.4c-1.15 0-1.93 0-2.52-.06-.58-.07-.86-.18-1.06-.32a2 2 0 0 1-.44-.44c-.14-.2-.25-.48-.31-1.06C2 11.1 2 10.32 2 9.17V8.4c0-1.15 0-1.93.07-2.52.06-.58.17-.86.31-1.06a2 2 0 0 1 .44-.44Z\"\n    clip-rule=\"evenodd\"\n  />\n  <path fill=\"currentColor\" d=\"M6.14 17.57a1 1 0 1 0 0 2h7.72a1 1 0 1 0 0-2H6.14Z\" />\n</svg>`;\n//# sourceMappingURL=desktop.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvYXNzZXRzL3N2Zy9kZXNrdG9wLmpzIiwibWFwcGluZ3MiOiI7Ozs7O0FBQTBCO0FBQ25CLG1CQUFtQix3Q0FBRztBQUM3QjtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0EiLCJzb3VyY2VzIjpbIndlYnBhY2s6Ly9fTl9FLy4vbm9kZV9tb2R1bGVzL0ByZW93bi9hcHBraXQtdWkvZGlzdC9lc20vc3JjL2Fzc2V0cy9zdmcvZGVza3RvcC5qcz9mMTZmIl0sInNvdXJjZXNDb250ZW50IjpbImltcG9ydCB7IHN2ZyB9IGZyb20gJ2xpdCc7XG5leHBvcnQgY29uc3QgZGVza3RvcFN2ZyA9IHN2ZyBgPHN2ZyBmaWxsPVwibm9uZVwiIHZpZXdCb3g9XCIwIDAgMjAgMjBcIj5cbiAgPHBhdGhcbiAgICBmaWxsPVwiY3VycmVudENvbG9yXCJcbiAgICBmaWxsLXJ1bGU9XCJldmVub2RkXCJcbiAgICBkPVwiTTEzLjY2IDJINi4zNGMtMS4wNyAwLTEuOTYgMC0yLjY4LjA4LS43NC4wOC0xLjQyLjI1LTIuMDEuNjhhNCA0IDAgMCAwLS44OS44OWMtLjQzLjYtLjYgMS4yNy0uNjggMi4wMUMwIDYuMzggMCA3LjI2IDAgOC4zNHYuODljMCAxLjA3IDAgMS45Ni4wOCAyLjY4LjA4Ljc0LjI1IDEuNDIuNjggMi4wMWE0IDQgMCAwIDAgLjg5Ljg5Yy42LjQzIDEuMjcuNiAyLjAxLjY4YTI3IDI3IDAgMCAwIDIuNjguMDhoNy4zMmEyNyAyNyAwIDAgMCAyLjY4LS4wOCA0LjAzIDQuMDMgMCAwIDAgMi4wMS0uNjggNCA0IDAgMCAwIC44OS0uODljLjQzLS42LjYtMS4yNy42OC0yLjAxLjA4LS43Mi4wOC0xLjYuMDgtMi42OHYtLjg5YzAtMS4wNyAwLTEuOTYtLjA4LTIuNjhhNC4wNCA0LjA0IDAgMCAwLS42OC0yLjAxIDQgNCAwIDAgMC0uODktLjg5Yy0uNi0uNDMtMS4yNy0uNi0yLjAxLS42OEMxNS42MiAyIDE0Ljc0IDIgMTMuNjYgMlpNMi44MiA0LjM4Yy4yLS4xNC40OC0uMjUgMS4wNi0uMzFDNC40OCA0IDUuMjUgNCA2LjQgNGg3LjJjMS4xNSAwIDEuOTMgMCAyLjUyLjA3LjU4LjA2Ljg2LjE3IDEuMDYuMzFhMiAyIDAgMCAxIC40NC40NGMuMTQuMi4yNS40OC4zMSAxLjA2LjA3LjYuMDcgMS4zNy4wNyAyLjUydi43N2MwIDEuMTUgMCAxLjkzLS4wNyAyLjUyLS4wNi41OC0uMTcuODYtLjMxIDEuMDZhMiAyIDAgMCAxLS40NC40NGMtLjIuMTQtLjQ4LjI1LTEuMDYuMzItLjYuMDYtMS4zNy4wNi0yLjUyLjA2SDYuNGMtMS4xNSAwLTEuOTMgMC0yLjUyLS4wNi0uNTgtLjA3LS44Ni0uMTgtMS4wNi0uMzJhMiAyIDAgMCAxLS40NC0uNDRjLS4xNC0uMi0uMjUtLjQ4LS4zMS0xLjA2QzIgMTEuMSAyIDEwLjMyIDIgOS4xN1Y4LjRjMC0xLjE1IDAtMS45My4wNy0yLjUyLjA2LS41OC4xNy0uODYuMzEtMS4wNmEyIDIgMCAwIDEgLjQ0LS40NFpcIlxuICAgIGNsaXAtcnVsZT1cImV2ZW5vZGRcIlxuICAvPlxuICA8cGF0aCBmaWxsPVwiY3VycmVudENvbG9yXCIgZD1cIk02LjE0IDE3LjU3YTEgMSAwIDEgMCAwIDJoNy43MmExIDEgMCAxIDAgMC0ySDYuMTRaXCIgLz5cbjwvc3ZnPmA7XG4vLyMgc291cmNlTWFwcGluZ1VSTD1kZXNrdG9wLmpzLm1hcCJdLCJuYW1lcyI6W10sInNvdXJjZVJvb3QiOiIifQ==\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/desktop.js\n"));
-
-/***/ })
-
-}]);
\ No newline at end of file
@@ -1,22 +0,0 @@
-"use strict";
-/*
- * ATTENTION: An "eval-source-map" devtool has been used.
- * This devtool is neither made for production nor for readable output files.
- * It uses "eval()" calls to create a separate source file with attached SourceMaps in the browser devtools.
- * If you are trying to read the output file, select a different devtool (https://webpack.js.org/configuration/devtool/)
- * or disable the default devtool with "devtool: false".
- * If you are looking for production-ready output files, see mode: "production" (https://webpack.js.org/configuration/mode/).
- */
-(self["webpackChunk_N_E"] = self["webpackChunk_N_E"] || []).push([["node_modules_reown_appkit-ui_dist_esm_src_assets_svg_disconnect_js"],{
-
-/***/ "./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/disconnect.js":
-/*!*****************************************************************************!*\
-  !*** ./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/disconnect.js ***!
-  \*****************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   disconnectSvg: function() { return /* binding */ disconnectSvg; }\n/* harmony export */ });\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n\nconst disconnectSvg = (0,lit__WEBPACK_IMPORTED_MODULE_0__.svg) `<svg fill=\"none\" viewBox=\"0 0 16 16\">\n  <path\n    fill=\"currentColor\"\n    fill-rule=\"evenodd\"\n    d=\"M6.07 1h.57a1 1 0 0 1 0 2h-.52c-.98 0-1.64 0-2.14.06-.48.05-.7.14-.84.24-.13.1-.25.22-.34.35-.1.14-.2.35-.25.83-.05.5-.05 1.16-.05 2.15v2.74c0 .99 0 1.65.05 2.15.05.48.14.7.25.83.1.14.2.25.34.35.14.1.36.2.84.25.5.05 1.16.05 2.14.05h.52a1 1 0 0 1 0 2h-.57c-.92 0-1.69 0-2.3-.07a3.6 3.6 0 0 1-1.8-.61c-.3-.22-.57-.49-.8-.8a3.6 3.6 0 0 1-.6-1.79C.5 11.11.5 10.35.5 9.43V6.58c0-.92 0-1.7.06-2.31a3.6 3.6 0 0 1 .62-1.8c.22-.3.48-.57.79-.79a3.6 3.6 0 0 1 1.8-.61C4.37 1 5.14 1 6.06 1ZM9.5 3a1 1 0 0 1 1.42 0l4.28 4.3a1 1 0 0 1 0 1.4L10.93 13a1 1 0 0 1-1.42-1.42L12.1 9H6.8a1 1 0 1 1 0-2h5.3L9.51 4.42a1 1 0 0 1 0-1.41Z\"\n    clip-rule=\"evenodd\"\n  />\n</svg>`;\n//# sourceMappingURL=disconnect.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvYXNzZXRzL3N2Zy9kaXNjb25uZWN0LmpzIiwibWFwcGluZ3MiOiI7Ozs7O0FBQTBCO0FBQ25CLHNCQUFzQix3Q0FBRztBQUNoQztBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBIiwic291cmNlcyI6WyJ3ZWJwYWNrOi8vX05fRS8uL25vZGVfbW9kdWxlcy9AcmVvd24vYXBwa2l0LXVpL2Rpc3QvZXNtL3NyYy9hc3NldHMvc3ZnL2Rpc2Nvbm5lY3QuanM/MTg4ZCJdLCJzb3VyY2VzQ29udGVudCI6WyJpbXBvcnQgeyBzdmcgfSBmcm9tICdsaXQnO1xuZXhwb3J0IGNvbnN0IGRpc2Nvbm5lY3RTdmcgPSBzdmcgYDxzdmcgZmlsbD1cIm5vbmVcIiB2aWV3Qm94PVwiMCAwIDE2IDE2XCI+XG4gIDxwYXRoXG4gICAgZmlsbD1cImN1cnJlbnRDb2xvclwiXG4gICAgZmlsbC1ydWxlPVwiZXZlbm9kZFwiXG4gICAgZD1cIk02LjA3IDFoLjU3YTEgMSAwIDAgMSAwIDJoLS41MmMtLjk4IDAtMS42NCAwLTIuMTQuMDYtLjQ4LjA1LS43LjE0LS44NC4yNC0uMTMuMS0uMjUuMjItLjM0LjM1LS4xLjE0LS4yLjM1LS4yNS44My0uMDUuNS0uMDUgMS4xNi0uMDUgMi4xNXYyLjc0YzAgLjk5IDAgMS42NS4wNSAyLjE1LjA1LjQ4LjE0LjcuMjUuODMuMS4xNC4yLjI1LjM0LjM1LjE0LjEuMzYuMi44NC4yNS41LjA1IDEuMTYuMDUgMi4xNC4wNWguNTJhMSAxIDAgMCAxIDAgMmgtLjU3Yy0uOTIgMC0xLjY5IDAtMi4zLS4wN2EzLjYgMy42IDAgMCAxLTEuOC0uNjFjLS4zLS4yMi0uNTctLjQ5LS44LS44YTMuNiAzLjYgMCAwIDEtLjYtMS43OUMuNSAxMS4xMS41IDEwLjM1LjUgOS40M1Y2LjU4YzAtLjkyIDAtMS43LjA2LTIuMzFhMy42IDMuNiAwIDAgMSAuNjItMS44Yy4yMi0uMy40OC0uNTcuNzktLjc5YTMuNiAzLjYgMCAwIDEgMS44LS42MUM0LjM3IDEgNS4xNCAxIDYuMDYgMVpNOS41IDNhMSAxIDAgMCAxIDEuNDIgMGw0LjI4IDQuM2ExIDEgMCAwIDEgMCAxLjRMMTAuOTMgMTNhMSAxIDAgMCAxLTEuNDItMS40MkwxMi4xIDlINi44YTEgMSAwIDEgMSAwLTJoNS4zTDkuNTEgNC40MmExIDEgMCAwIDEgMC0xLjQxWlwiXG4gICAgY2xpcC1ydWxlPVwiZXZlbm9kZFwiXG4gIC8+XG48L3N2Zz5gO1xuLy8jIHNvdXJjZU1hcHBpbmdVUkw9ZGlzY29ubmVjdC5qcy5tYXAiXSwibmFtZXMiOltdLCJzb3VyY2VSb290IjoiIn0=\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/disconnect.js\n"));
-
-/***/ })
-
-}]);
\ No newline at end of file
@@ -1,22 +0,0 @@
-"use strict";
-/*
- * ATTENTION: An "eval-source-map" devtool has been used.
- * This devtool is neither made for production nor for readable output files.
- * It uses "eval()" calls to create a separate source file with attached SourceMaps in the browser devtools.
- * If you are trying to read the output file, select a different devtool (https://webpack.js.org/configuration/devtool/)
- * or disable the default devtool with "devtool: false".
- * If you are looking for production-ready output files, see mode: "production" (https://webpack.js.org/configuration/mode/).
- */
-(self["webpackChunk_N_E"] = self["webpackChunk_N_E"] || []).push([["node_modules_reown_appkit-ui_dist_esm_src_assets_svg_discord_js"],{
-
-/***/ "./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/discord.js":
-/*!**************************************************************************!*\
-  !*** ./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/discord.js ***!
-  \**************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   discordSvg: function() { return /* binding */ discordSvg; }\n/* harmony export */ });\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n\nconst discordSvg = (0,lit__WEBPACK_IMPORTED_MODULE_0__.svg) `<svg fill=\"none\" viewBox=\"0 0 40 40\">\n  <g clip-path=\"url(#a)\">\n    <g clip-path=\"url(#b)\">\n      <circle cx=\"20\" cy=\"19.89\" r=\"20\" fill=\"#5865F2\" />\n      <path\n        fill=\"#fff\"\n        fill-rule=\"evenodd\"\n        d=\"M25.71 28.15C30.25 28 32 25.02 32 25.02c0-6.61-2.96-11.98-2.96-11.98-2.96-2.22-5.77-2.15-5.77-2.15l-.29.32c3.5 1.07 5.12 2.61 5.12 2.61a16.75 16.75 0 0 0-10.34-1.93l-.35.04a15.43 15.43 0 0 0-5.88 1.9s1.71-1.63 5.4-2.7l-.2-.24s-2.81-.07-5.77 2.15c0 0-2.96 5.37-2.96 11.98 0 0 1.73 2.98 6.27 3.13l1.37-1.7c-2.6-.79-3.6-2.43-3.6-2.43l.58.35.09.06.08.04.02.01.08.05a17.25 17.25 0 0 0 4.52 1.58 14.4 14.4 0 0 0 8.3-.86c.72-.27 1.52-.66 2.37-1.21 0 0-1.03 1.68-3.72 2.44.61.78 1.35 1.67 1.35 1.67Zm-9.55-9.6c-1.17 0-2.1 1.03-2.1 2.28 0 1.25.95 2.28 2.1 2.28 1.17 0 2.1-1.03 2.1-2.28.01-1.25-.93-2.28-2.1-2.28Zm7.5 0c-1.17 0-2.1 1.03-2.1 2.28 0 1.25.95 2.28 2.1 2.28 1.17 0 2.1-1.03 2.1-2.28 0-1.25-.93-2.28-2.1-2.28Z\"\n        clip-rule=\"evenodd\"\n      />\n    </g>\n  </g>\n  <defs>\n    <clipPath id=\"a\"><rect width=\"40\" height=\"40\" fill=\"#fff\" rx=\"20\" /></clipPath>\n    <clipPath id=\"b\"><path fill=\"#fff\" d=\"M0 0h40v40H0z\" /></clipPath>\n  </defs>\n</svg>`;\n//# sourceMappingURL=discord.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvYXNzZXRzL3N2Zy9kaXNjb3JkLmpzIiwibWFwcGluZ3MiOiI7Ozs7O0FBQTBCO0FBQ25CLG1CQUFtQix3Q0FBRztBQUM3QjtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBIiwic291cmNlcyI6WyJ3ZWJwYWNrOi8vX05fRS8uL25vZGVfbW9kdWxlcy9AcmVvd24vYXBwa2l0LXVpL2Rpc3QvZXNtL3NyYy9hc3NldHMvc3ZnL2Rpc2NvcmQuanM/NGM0ZiJdLCJzb3VyY2VzQ29udGVudCI6WyJpbXBvcnQgeyBzdmcgfSBmcm9tICdsaXQnO1xuZXhwb3J0IGNvbnN0IGRpc2NvcmRTdmcgPSBzdmcgYDxzdmcgZmlsbD1cIm5vbmVcIiB2aWV3Qm94PVwiMCAwIDQwIDQwXCI+XG4gIDxnIGNsaXAtcGF0aD1cInVybCgjYSlcIj5cbiAgICA8ZyBjbGlwLXBhdGg9XCJ1cmwoI2IpXCI+XG4gICAgICA8Y2lyY2xlIGN4PVwiMjBcIiBjeT1cIjE5Ljg5XCIgcj1cIjIwXCIgZmlsbD1cIiM1ODY1RjJcIiAvPlxuICAgICAgPHBhdGhcbiAgICAgICAgZmlsbD1cIiNmZmZcIlxuICAgICAgICBmaWxsLXJ1bGU9XCJldmVub2RkXCJcbiAgICAgICAgZD1cIk0yNS43MSAyOC4xNUMzMC4yNSAyOCAzMiAyNS4wMiAzMiAyNS4wMmMwLTYuNjEtMi45Ni0xMS45OC0yLjk2LTExLjk4LTIuOTYtMi4yMi01Ljc3LTIuMTUtNS43Ny0yLjE1bC0uMjkuMzJjMy41IDEuMDcgNS4xMiAyLjYxIDUuMTIgMi42MWExNi43NSAxNi43NSAwIDAgMC0xMC4zNC0xLjkzbC0uMzUuMDRhMTUuNDMgMTUuNDMgMCAwIDAtNS44OCAxLjlzMS43MS0xLjYzIDUuNC0yLjdsLS4yLS4yNHMtMi44MS0uMDctNS43NyAyLjE1YzAgMC0yLjk2IDUuMzctMi45NiAxMS45OCAwIDAgMS43MyAyLjk4IDYuMjcgMy4xM2wxLjM3LTEuN2MtMi42LS43OS0zLjYtMi40My0zLjYtMi40M2wuNTguMzUuMDkuMDYuMDguMDQuMDIuMDEuMDguMDVhMTcuMjUgMTcuMjUgMCAwIDAgNC41MiAxLjU4IDE0LjQgMTQuNCAwIDAgMCA4LjMtLjg2Yy43Mi0uMjcgMS41Mi0uNjYgMi4zNy0xLjIxIDAgMC0xLjAzIDEuNjgtMy43MiAyLjQ0LjYxLjc4IDEuMzUgMS42NyAxLjM1IDEuNjdabS05LjU1LTkuNmMtMS4xNyAwLTIuMSAxLjAzLTIuMSAyLjI4IDAgMS4yNS45NSAyLjI4IDIuMSAyLjI4IDEuMTcgMCAyLjEtMS4wMyAyLjEtMi4yOC4wMS0xLjI1LS45My0yLjI4LTIuMS0yLjI4Wm03LjUgMGMtMS4xNyAwLTIuMSAxLjAzLTIuMSAyLjI4IDAgMS4yNS45NSAyLjI4IDIuMSAyLjI4IDEuMTcgMCAyLjEtMS4wMyAyLjEtMi4yOCAwLTEuMjUtLjkzLTIuMjgtMi4xLTIuMjhaXCJcbiAgICAgICAgY2xpcC1ydWxlPVwiZXZlbm9kZFwiXG4gICAgICAvPlxuICAgIDwvZz5cbiAgPC9nPlxuICA8ZGVmcz5cbiAgICA8Y2xpcFBhdGggaWQ9XCJhXCI+PHJlY3Qgd2lkdGg9XCI0MFwiIGhlaWdodD1cIjQwXCIgZmlsbD1cIiNmZmZcIiByeD1cIjIwXCIgLz48L2NsaXBQYXRoPlxuICAgIDxjbGlwUGF0aCBpZD1cImJcIj48cGF0aCBmaWxsPVwiI2ZmZlwiIGQ9XCJNMCAwaDQwdjQwSDB6XCIgLz48L2NsaXBQYXRoPlxuICA8L2RlZnM+XG48L3N2Zz5gO1xuLy8jIHNvdXJjZU1hcHBpbmdVUkw9ZGlzY29yZC5qcy5tYXAiXSwibmFtZXMiOltdLCJzb3VyY2VSb290IjoiIn0=\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/discord.js\n"));
-
-/***/ })
-
-}]);
\ No newline at end of file
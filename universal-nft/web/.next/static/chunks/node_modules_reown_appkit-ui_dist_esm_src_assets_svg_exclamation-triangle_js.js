@@ -1,22 +0,0 @@
-"use strict";
-/*
- * ATTENTION: An "eval-source-map" devtool has been used.
- * This devtool is neither made for production nor for readable output files.
- * It uses "eval()" calls to create a separate source file with attached SourceMaps in the browser devtools.
- * If you are trying to read the output file, select a different devtool (https://webpack.js.org/configuration/devtool/)
- * or disable the default devtool with "devtool: false".
- * If you are looking for production-ready output files, see mode: "production" (https://webpack.js.org/configuration/mode/).
- */
-(self["webpackChunk_N_E"] = self["webpackChunk_N_E"] || []).push([["node_modules_reown_appkit-ui_dist_esm_src_assets_svg_exclamation-triangle_js"],{
-
-/***/ "./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/exclamation-triangle.js":
-/*!***************************************************************************************!*\
-  !*** ./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/exclamation-triangle.js ***!
-  \***************************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   exclamationTriangleSvg: function() { return /* binding */ exclamationTriangleSvg; }\n/* harmony export */ });\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n\nconst exclamationTriangleSvg = (0,lit__WEBPACK_IMPORTED_MODULE_0__.svg) `<svg width=\"16\" height=\"16\" viewBox=\"0 0 16 16\" fill=\"none\" xmlns=\"http://www.w3.org/2000/svg\">\n<path d=\"M15.0162 11.6312L9.55059 2.13937C9.39228 1.86862 9.16584 1.64405 8.8938 1.48798C8.62176 1.33192 8.3136 1.2498 7.99997 1.2498C7.68634 1.2498 7.37817 1.33192 7.10613 1.48798C6.83409 1.64405 6.60765 1.86862 6.44934 2.13937L0.983716 11.6312C0.830104 11.894 0.749146 12.1928 0.749146 12.4972C0.749146 12.8015 0.830104 13.1004 0.983716 13.3631C1.14027 13.6352 1.3664 13.8608 1.63889 14.0166C1.91139 14.1725 2.22044 14.253 2.53434 14.25H13.4656C13.7793 14.2528 14.0881 14.1721 14.3603 14.0163C14.6326 13.8604 14.8585 13.635 15.015 13.3631C15.1688 13.1005 15.2499 12.8017 15.2502 12.4973C15.2504 12.193 15.1696 11.8941 15.0162 11.6312ZM13.7162 12.6125C13.6908 12.6558 13.6541 12.6914 13.6101 12.7157C13.5661 12.7399 13.5164 12.7517 13.4662 12.75H2.53434C2.48415 12.7517 2.43442 12.7399 2.39042 12.7157C2.34641 12.6914 2.30976 12.6558 2.28434 12.6125C2.26278 12.5774 2.25137 12.5371 2.25137 12.4959C2.25137 12.4548 2.26278 12.4144 2.28434 12.3794L7.74997 2.88749C7.77703 2.84583 7.81408 2.8116 7.85774 2.7879C7.9014 2.7642 7.95029 2.75178 7.99997 2.75178C8.04964 2.75178 8.09854 2.7642 8.1422 2.7879C8.18586 2.8116 8.2229 2.84583 8.24997 2.88749L13.715 12.3794C13.7367 12.4143 13.7483 12.4546 13.7486 12.4958C13.7488 12.5369 13.7376 12.5773 13.7162 12.6125ZM7.24997 8.49999V6.49999C7.24997 6.30108 7.32898 6.11031 7.46964 5.96966C7.61029 5.82901 7.80105 5.74999 7.99997 5.74999C8.19888 5.74999 8.38964 5.82901 8.5303 5.96966C8.67095 6.11031 8.74997 6.30108 8.74997 6.49999V8.49999C8.74997 8.6989 8.67095 8.88967 8.5303 9.03032C8.38964 9.17097 8.19888 9.24999 7.99997 9.24999C7.80105 9.24999 7.61029 9.17097 7.46964 9.03032C7.32898 8.88967 7.24997 8.6989 7.24997 8.49999ZM8.99997 11C8.99997 11.1978 8.94132 11.3911 8.83144 11.5556C8.72155 11.72 8.56538 11.8482 8.38265 11.9239C8.19992 11.9996 7.99886 12.0194 7.80488 11.9808C7.6109 11.9422 7.43271 11.847 7.29286 11.7071C7.15301 11.5672 7.05777 11.3891 7.01918 11.1951C6.9806 11.0011 7.0004 10.8 7.07609 10.6173C7.15177 10.4346 7.27995 10.2784 7.4444 10.1685C7.60885 10.0586 7.80219 9.99999 7.99997 9.99999C8.26518 9.99999 8.51954 10.1053 8.70707 10.2929C8.89461 10.4804 8.99997 10.7348 8.99997 11Z\" fill=\"currentColor\"/>\n</svg>\n`;\n//# sourceMappingURL=exclamation-triangle.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvYXNzZXRzL3N2Zy9leGNsYW1hdGlvbi10cmlhbmdsZS5qcyIsIm1hcHBpbmdzIjoiOzs7OztBQUEwQjtBQUNuQiwrQkFBK0Isd0NBQUc7QUFDekM7QUFDQTtBQUNBO0FBQ0EiLCJzb3VyY2VzIjpbIndlYnBhY2s6Ly9fTl9FLy4vbm9kZV9tb2R1bGVzL0ByZW93bi9hcHBraXQtdWkvZGlzdC9lc20vc3JjL2Fzc2V0cy9zdmcvZXhjbGFtYXRpb24tdHJpYW5nbGUuanM/MzRiZiJdLCJzb3VyY2VzQ29udGVudCI6WyJpbXBvcnQgeyBzdmcgfSBmcm9tICdsaXQnO1xuZXhwb3J0IGNvbnN0IGV4Y2xhbWF0aW9uVHJpYW5nbGVTdmcgPSBzdmcgYDxzdmcgd2lkdGg9XCIxNlwiIGhlaWdodD1cIjE2XCIgdmlld0JveD1cIjAgMCAxNiAxNlwiIGZpbGw9XCJub25lXCIgeG1sbnM9XCJodHRwOi8vd3d3LnczLm9yZy8yMDAwL3N2Z1wiPlxuPHBhdGggZD1cIk0xNS4wMTYyIDExLjYzMTJMOS41NTA1OSAyLjEzOTM3QzkuMzkyMjggMS44Njg2MiA5LjE2NTg0IDEuNjQ0MDUgOC44OTM4IDEuNDg3OThDOC42MjE3NiAxLjMzMTkyIDguMzEzNiAxLjI0OTggNy45OTk5NyAxLjI0OThDNy42ODYzNCAxLjI0OTggNy4zNzgxNyAxLjMzMTkyIDcuMTA2MTMgMS40ODc5OEM2LjgzNDA5IDEuNjQ0MDUgNi42MDc2NSAxLjg2ODYyIDYuNDQ5MzQgMi4xMzkzN0wwLjk4MzcxNiAxMS42MzEyQzAuODMwMTA0IDExLjg5NCAwLjc0OTE0NiAxMi4xOTI4IDAuNzQ5MTQ2IDEyLjQ5NzJDMC43NDkxNDYgMTIuODAxNSAwLjgzMDEwNCAxMy4xMDA0IDAuOTgzNzE2IDEzLjM2MzFDMS4xNDAyNyAxMy42MzUyIDEuMzY2NCAxMy44NjA4IDEuNjM4ODkgMTQuMDE2NkMxLjkxMTM5IDE0LjE3MjUgMi4yMjA0NCAxNC4yNTMgMi41MzQzNCAxNC4yNUgxMy40NjU2QzEzLjc3OTMgMTQuMjUyOCAxNC4wODgxIDE0LjE3MjEgMTQuMzYwMyAxNC4wMTYzQzE0LjYzMjYgMTMuODYwNCAxNC44NTg1IDEzLjYzNSAxNS4wMTUgMTMuMzYzMUMxNS4xNjg4IDEzLjEwMDUgMTUuMjQ5OSAxMi44MDE3IDE1LjI1MDIgMTIuNDk3M0MxNS4yNTA0IDEyLjE5MyAxNS4xNjk2IDExLjg5NDEgMTUuMDE2MiAxMS42MzEyWk0xMy43MTYyIDEyLjYxMjVDMTMuNjkwOCAxMi42NTU4IDEzLjY1NDEgMTIuNjkxNCAxMy42MTAxIDEyLjcxNTdDMTMuNTY2MSAxMi43Mzk5IDEzLjUxNjQgMTIuNzUxNyAxMy40NjYyIDEyLjc1SDIuNTM0MzRDMi40ODQxNSAxMi43NTE3IDIuNDM0NDIgMTIuNzM5OSAyLjM5MDQyIDEyLjcxNTdDMi4zNDY0MSAxMi42OTE0IDIuMzA5NzYgMTIuNjU1OCAyLjI4NDM0IDEyLjYxMjVDMi4yNjI3OCAxMi41Nzc0IDIuMjUxMzcgMTIuNTM3MSAyLjI1MTM3IDEyLjQ5NTlDMi4yNTEzNyAxMi40NTQ4IDIuMjYyNzggMTIuNDE0NCAyLjI4NDM0IDEyLjM3OTRMNy43NDk5NyAyLjg4NzQ5QzcuNzc3MDMgMi44NDU4MyA3LjgxNDA4IDIuODExNiA3Ljg1Nzc0IDIuNzg3OUM3LjkwMTQgMi43NjQyIDcuOTUwMjkgMi43NTE3OCA3Ljk5OTk3IDIuNzUxNzhDOC4wNDk2NCAyLjc1MTc4IDguMDk4NTQgMi43NjQyIDguMTQyMiAyLjc4NzlDOC4xODU4NiAyLjgxMTYgOC4yMjI5IDIuODQ1ODMgOC4yNDk5NyAyLjg4NzQ5TDEzLjcxNSAxMi4zNzk0QzEzLjczNjcgMTIuNDE0MyAxMy43NDgzIDEyLjQ1NDYgMTMuNzQ4NiAxMi40OTU4QzEzLjc0ODggMTIuNTM2OSAxMy43Mzc2IDEyLjU3NzMgMTMuNzE2MiAxMi42MTI1Wk03LjI0OTk3IDguNDk5OTlWNi40OTk5OUM3LjI0OTk3IDYuMzAxMDggNy4zMjg5OCA2LjExMDMxIDcuNDY5NjQgNS45Njk2NkM3LjYxMDI5IDUuODI5MDEgNy44MDEwNSA1Ljc0OTk5IDcuOTk5OTcgNS43NDk5OUM4LjE5ODg4IDUuNzQ5OTkgOC4zODk2NCA1LjgyOTAxIDguNTMwMyA1Ljk2OTY2QzguNjcwOTUgNi4xMTAzMSA4Ljc0OTk3IDYuMzAxMDggOC43NDk5NyA2LjQ5OTk5VjguNDk5OTlDOC43NDk5NyA4LjY5ODkgOC42NzA5NSA4Ljg4OTY3IDguNTMwMyA5LjAzMDMyQzguMzg5NjQgOS4xNzA5NyA4LjE5ODg4IDkuMjQ5OTkgNy45OTk5NyA5LjI0OTk5QzcuODAxMDUgOS4yNDk5OSA3LjYxMDI5IDkuMTcwOTcgNy40Njk2NCA5LjAzMDMyQzcuMzI4OTggOC44ODk2NyA3LjI0OTk3IDguNjk4OSA3LjI0OTk3IDguNDk5OTlaTTguOTk5OTcgMTFDOC45OTk5NyAxMS4xOTc4IDguOTQxMzIgMTEuMzkxMSA4LjgzMTQ0IDExLjU1NTZDOC43MjE1NSAxMS43MiA4LjU2NTM4IDExLjg0ODIgOC4zODI2NSAxMS45MjM5QzguMTk5OTIgMTEuOTk5NiA3Ljk5ODg2IDEyLjAxOTQgNy44MDQ4OCAxMS45ODA4QzcuNjEwOSAxMS45NDIyIDcuNDMyNzEgMTEuODQ3IDcuMjkyODYgMTEuNzA3MUM3LjE1MzAxIDExLjU2NzIgNy4wNTc3NyAxMS4zODkxIDcuMDE5MTggMTEuMTk1MUM2Ljk4MDYgMTEuMDAxMSA3LjAwMDQgMTAuOCA3LjA3NjA5IDEwLjYxNzNDNy4xNTE3NyAxMC40MzQ2IDcuMjc5OTUgMTAuMjc4NCA3LjQ0NDQgMTAuMTY4NUM3LjYwODg1IDEwLjA1ODYgNy44MDIxOSA5Ljk5OTk5IDcuOTk5OTcgOS45OTk5OUM4LjI2NTE4IDkuOTk5OTkgOC41MTk1NCAxMC4xMDUzIDguNzA3MDcgMTAuMjkyOUM4Ljg5NDYxIDEwLjQ4MDQgOC45OTk5NyAxMC43MzQ4IDguOTk5OTcgMTFaXCIgZmlsbD1cImN1cnJlbnRDb2xvclwiLz5cbjwvc3ZnPlxuYDtcbi8vIyBzb3VyY2VNYXBwaW5nVVJMPWV4Y2xhbWF0aW9uLXRyaWFuZ2xlLmpzLm1hcCJdLCJuYW1lcyI6W10sInNvdXJjZVJvb3QiOiIifQ==\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/exclamation-triangle.js\n"));
-
-/***/ })
-
-}]);
\ No newline at end of file
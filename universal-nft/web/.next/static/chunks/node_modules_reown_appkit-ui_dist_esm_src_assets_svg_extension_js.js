@@ -1,22 +0,0 @@
-"use strict";
-/*
- * ATTENTION: An "eval-source-map" devtool has been used.
- * This devtool is neither made for production nor for readable output files.
- * It uses "eval()" calls to create a separate source file with attached SourceMaps in the browser devtools.
- * If you are trying to read the output file, select a different devtool (https://webpack.js.org/configuration/devtool/)
- * or disable the default devtool with "devtool: false".
- * If you are looking for production-ready output files, see mode: "production" (https://webpack.js.org/configuration/mode/).
- */
-(self["webpackChunk_N_E"] = self["webpackChunk_N_E"] || []).push([["node_modules_reown_appkit-ui_dist_esm_src_assets_svg_extension_js"],{
-
-/***/ "./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/extension.js":
-/*!****************************************************************************!*\
-  !*** ./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/extension.js ***!
-  \****************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   extensionSvg: function() { return /* binding */ extensionSvg; }\n/* harmony export */ });\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n\nconst extensionSvg = (0,lit__WEBPACK_IMPORTED_MODULE_0__.svg) `<svg fill=\"none\" viewBox=\"0 0 14 15\">\n  <path\n    fill=\"currentColor\"\n    fill-rule=\"evenodd\"\n    d=\"M6.71 2.99a.57.57 0 0 0-.57.57 1 1 0 0 1-1 1c-.58 0-.96 0-1.24.03-.27.03-.37.07-.42.1a.97.97 0 0 0-.36.35c-.04.08-.09.21-.11.67a2.57 2.57 0 0 1 0 5.13c.02.45.07.6.11.66.09.15.21.28.36.36.07.04.21.1.67.12a2.57 2.57 0 0 1 5.12 0c.46-.03.6-.08.67-.12a.97.97 0 0 0 .36-.36c.03-.04.07-.14.1-.41.02-.29.03-.66.03-1.24a1 1 0 0 1 1-1 .57.57 0 0 0 0-1.15 1 1 0 0 1-1-1c0-.58 0-.95-.03-1.24a1.04 1.04 0 0 0-.1-.42.97.97 0 0 0-.36-.36 1.04 1.04 0 0 0-.42-.1c-.28-.02-.65-.02-1.24-.02a1 1 0 0 1-1-1 .57.57 0 0 0-.57-.57ZM5.15 13.98a1 1 0 0 0 .99-1v-.78a.57.57 0 0 1 1.14 0v.78a1 1 0 0 0 .99 1H8.36a66.26 66.26 0 0 0 .73 0 3.78 3.78 0 0 0 1.84-.38c.46-.26.85-.64 1.1-1.1.23-.4.32-.8.36-1.22.02-.2.03-.4.03-.63a2.57 2.57 0 0 0 0-4.75c0-.23-.01-.44-.03-.63a2.96 2.96 0 0 0-.35-1.22 2.97 2.97 0 0 0-1.1-1.1c-.4-.22-.8-.31-1.22-.35a8.7 8.7 0 0 0-.64-.04 2.57 2.57 0 0 0-4.74 0c-.23 0-.44.02-.63.04-.42.04-.83.13-1.22.35-.46.26-.84.64-1.1 1.1-.33.57-.37 1.2-.39 1.84a21.39 21.39 0 0 0 0 .72v.1a1 1 0 0 0 1 .99h.78a.57.57 0 0 1 0 1.15h-.77a1 1 0 0 0-1 .98v.1a63.87 63.87 0 0 0 0 .73c0 .64.05 1.27.38 1.83.26.47.64.85 1.1 1.11.56.32 1.2.37 1.84.38a20.93 20.93 0 0 0 .72 0h.1Z\"\n    clip-rule=\"evenodd\"\n  />\n</svg>`;\n//# sourceMappingURL=extension.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvYXNzZXRzL3N2Zy9leHRlbnNpb24uanMiLCJtYXBwaW5ncyI6Ijs7Ozs7QUFBMEI7QUFDbkIscUJBQXFCLHdDQUFHO0FBQy9CO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0EiLCJzb3VyY2VzIjpbIndlYnBhY2s6Ly9fTl9FLy4vbm9kZV9tb2R1bGVzL0ByZW93bi9hcHBraXQtdWkvZGlzdC9lc20vc3JjL2Fzc2V0cy9zdmcvZXh0ZW5zaW9uLmpzPzA4NTkiXSwic291cmNlc0NvbnRlbnQiOlsiaW1wb3J0IHsgc3ZnIH0gZnJvbSAnbGl0JztcbmV4cG9ydCBjb25zdCBleHRlbnNpb25TdmcgPSBzdmcgYDxzdmcgZmlsbD1cIm5vbmVcIiB2aWV3Qm94PVwiMCAwIDE0IDE1XCI+XG4gIDxwYXRoXG4gICAgZmlsbD1cImN1cnJlbnRDb2xvclwiXG4gICAgZmlsbC1ydWxlPVwiZXZlbm9kZFwiXG4gICAgZD1cIk02LjcxIDIuOTlhLjU3LjU3IDAgMCAwLS41Ny41NyAxIDEgMCAwIDEtMSAxYy0uNTggMC0uOTYgMC0xLjI0LjAzLS4yNy4wMy0uMzcuMDctLjQyLjFhLjk3Ljk3IDAgMCAwLS4zNi4zNWMtLjA0LjA4LS4wOS4yMS0uMTEuNjdhMi41NyAyLjU3IDAgMCAxIDAgNS4xM2MuMDIuNDUuMDcuNi4xMS42Ni4wOS4xNS4yMS4yOC4zNi4zNi4wNy4wNC4yMS4xLjY3LjEyYTIuNTcgMi41NyAwIDAgMSA1LjEyIDBjLjQ2LS4wMy42LS4wOC42Ny0uMTJhLjk3Ljk3IDAgMCAwIC4zNi0uMzZjLjAzLS4wNC4wNy0uMTQuMS0uNDEuMDItLjI5LjAzLS42Ni4wMy0xLjI0YTEgMSAwIDAgMSAxLTEgLjU3LjU3IDAgMCAwIDAtMS4xNSAxIDEgMCAwIDEtMS0xYzAtLjU4IDAtLjk1LS4wMy0xLjI0YTEuMDQgMS4wNCAwIDAgMC0uMS0uNDIuOTcuOTcgMCAwIDAtLjM2LS4zNiAxLjA0IDEuMDQgMCAwIDAtLjQyLS4xYy0uMjgtLjAyLS42NS0uMDItMS4yNC0uMDJhMSAxIDAgMCAxLTEtMSAuNTcuNTcgMCAwIDAtLjU3LS41N1pNNS4xNSAxMy45OGExIDEgMCAwIDAgLjk5LTF2LS43OGEuNTcuNTcgMCAwIDEgMS4xNCAwdi43OGExIDEgMCAwIDAgLjk5IDFIOC4zNmE2Ni4yNiA2Ni4yNiAwIDAgMCAuNzMgMCAzLjc4IDMuNzggMCAwIDAgMS44NC0uMzhjLjQ2LS4yNi44NS0uNjQgMS4xLTEuMS4yMy0uNC4zMi0uOC4zNi0xLjIyLjAyLS4yLjAzLS40LjAzLS42M2EyLjU3IDIuNTcgMCAwIDAgMC00Ljc1YzAtLjIzLS4wMS0uNDQtLjAzLS42M2EyLjk2IDIuOTYgMCAwIDAtLjM1LTEuMjIgMi45NyAyLjk3IDAgMCAwLTEuMS0xLjFjLS40LS4yMi0uOC0uMzEtMS4yMi0uMzVhOC43IDguNyAwIDAgMC0uNjQtLjA0IDIuNTcgMi41NyAwIDAgMC00Ljc0IDBjLS4yMyAwLS40NC4wMi0uNjMuMDQtLjQyLjA0LS44My4xMy0xLjIyLjM1LS40Ni4yNi0uODQuNjQtMS4xIDEuMS0uMzMuNTctLjM3IDEuMi0uMzkgMS44NGEyMS4zOSAyMS4zOSAwIDAgMCAwIC43MnYuMWExIDEgMCAwIDAgMSAuOTloLjc4YS41Ny41NyAwIDAgMSAwIDEuMTVoLS43N2ExIDEgMCAwIDAtMSAuOTh2LjFhNjMuODcgNjMuODcgMCAwIDAgMCAuNzNjMCAuNjQuMDUgMS4yNy4zOCAxLjgzLjI2LjQ3LjY0Ljg1IDEuMSAxLjExLjU2LjMyIDEuMi4zNyAxLjg0LjM4YTIwLjkzIDIwLjkzIDAgMCAwIC43MiAwaC4xWlwiXG4gICAgY2xpcC1ydWxlPVwiZXZlbm9kZFwiXG4gIC8+XG48L3N2Zz5gO1xuLy8jIHNvdXJjZU1hcHBpbmdVUkw9ZXh0ZW5zaW9uLmpzLm1hcCJdLCJuYW1lcyI6W10sInNvdXJjZVJvb3QiOiIifQ==\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/extension.js\n"));
-
-/***/ })
-
-}]);
\ No newline at end of file
@@ -1,22 +0,0 @@
-"use strict";
-/*
- * ATTENTION: An "eval-source-map" devtool has been used.
- * This devtool is neither made for production nor for readable output files.
- * It uses "eval()" calls to create a separate source file with attached SourceMaps in the browser devtools.
- * If you are trying to read the output file, select a different devtool (https://webpack.js.org/configuration/devtool/)
- * or disable the default devtool with "devtool: false".
- * If you are looking for production-ready output files, see mode: "production" (https://webpack.js.org/configuration/mode/).
- */
-(self["webpackChunk_N_E"] = self["webpackChunk_N_E"] || []).push([["node_modules_reown_appkit-ui_dist_esm_src_assets_svg_facebook_js"],{
-
-/***/ "./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/facebook.js":
-/*!***************************************************************************!*\
-  !*** ./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/facebook.js ***!
-  \***************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   facebookSvg: function() { return /* binding */ facebookSvg; }\n/* harmony export */ });\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n\nconst facebookSvg = (0,lit__WEBPACK_IMPORTED_MODULE_0__.svg) `<svg fill=\"none\" viewBox=\"0 0 40 40\">\n  <g clip-path=\"url(#a)\">\n    <g clip-path=\"url(#b)\">\n      <circle cx=\"20\" cy=\"19.89\" r=\"20\" fill=\"#1877F2\" />\n      <g clip-path=\"url(#c)\">\n        <path\n          fill=\"#fff\"\n          d=\"M26 12.38h-2.89c-.92 0-1.61.38-1.61 1.34v1.66H26l-.36 4.5H21.5v12H17v-12h-3v-4.5h3V12.5c0-3.03 1.6-4.62 5.2-4.62H26v4.5Z\"\n        />\n      </g>\n    </g>\n    <path\n      fill=\"#1877F2\"\n      d=\"M40 20a20 20 0 1 0-23.13 19.76V25.78H11.8V20h5.07v-4.4c0-5.02 3-7.79 7.56-7.79 2.19 0 4.48.4 4.48.4v4.91h-2.53c-2.48 0-3.25 1.55-3.25 3.13V20h5.54l-.88 5.78h-4.66v13.98A20 20 0 0 0 40 20Z\"\n    />\n    <path\n      fill=\"#fff\"\n      d=\"m27.79 25.78.88-5.78h-5.55v-3.75c0-1.58.78-3.13 3.26-3.13h2.53V8.2s-2.3-.39-4.48-.39c-4.57 0-7.55 2.77-7.55 7.78V20H11.8v5.78h5.07v13.98a20.15 20.15 0 0 0 6.25 0V25.78h4.67Z\"\n    />\n  </g>\n  <defs>\n    <clipPath id=\"a\"><rect width=\"40\" height=\"40\" fill=\"#fff\" rx=\"20\" /></clipPath>\n    <clipPath id=\"b\"><path fill=\"#fff\" d=\"M0 0h40v40H0z\" /></clipPath>\n    <clipPath id=\"c\"><path fill=\"#fff\" d=\"M8 7.89h24v24H8z\" /></clipPath>\n  </defs>\n</svg>`;\n//# sourceMappingURL=facebook.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvYXNzZXRzL3N2Zy9mYWNlYm9vay5qcyIsIm1hcHBpbmdzIjoiOzs7OztBQUEwQjtBQUNuQixvQkFBb0Isd0NBQUc7QUFDOUI7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQSIsInNvdXJjZXMiOlsid2VicGFjazovL19OX0UvLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvYXNzZXRzL3N2Zy9mYWNlYm9vay5qcz9jZDY2Il0sInNvdXJjZXNDb250ZW50IjpbImltcG9ydCB7IHN2ZyB9IGZyb20gJ2xpdCc7XG5leHBvcnQgY29uc3QgZmFjZWJvb2tTdmcgPSBzdmcgYDxzdmcgZmlsbD1cIm5vbmVcIiB2aWV3Qm94PVwiMCAwIDQwIDQwXCI+XG4gIDxnIGNsaXAtcGF0aD1cInVybCgjYSlcIj5cbiAgICA8ZyBjbGlwLXBhdGg9XCJ1cmwoI2IpXCI+XG4gICAgICA8Y2lyY2xlIGN4PVwiMjBcIiBjeT1cIjE5Ljg5XCIgcj1cIjIwXCIgZmlsbD1cIiMxODc3RjJcIiAvPlxuICAgICAgPGcgY2xpcC1wYXRoPVwidXJsKCNjKVwiPlxuICAgICAgICA8cGF0aFxuICAgICAgICAgIGZpbGw9XCIjZmZmXCJcbiAgICAgICAgICBkPVwiTTI2IDEyLjM4aC0yLjg5Yy0uOTIgMC0xLjYxLjM4LTEuNjEgMS4zNHYxLjY2SDI2bC0uMzYgNC41SDIxLjV2MTJIMTd2LTEyaC0zdi00LjVoM1YxMi41YzAtMy4wMyAxLjYtNC42MiA1LjItNC42MkgyNnY0LjVaXCJcbiAgICAgICAgLz5cbiAgICAgIDwvZz5cbiAgICA8L2c+XG4gICAgPHBhdGhcbiAgICAgIGZpbGw9XCIjMTg3N0YyXCJcbiAgICAgIGQ9XCJNNDAgMjBhMjAgMjAgMCAxIDAtMjMuMTMgMTkuNzZWMjUuNzhIMTEuOFYyMGg1LjA3di00LjRjMC01LjAyIDMtNy43OSA3LjU2LTcuNzkgMi4xOSAwIDQuNDguNCA0LjQ4LjR2NC45MWgtMi41M2MtMi40OCAwLTMuMjUgMS41NS0zLjI1IDMuMTNWMjBoNS41NGwtLjg4IDUuNzhoLTQuNjZ2MTMuOThBMjAgMjAgMCAwIDAgNDAgMjBaXCJcbiAgICAvPlxuICAgIDxwYXRoXG4gICAgICBmaWxsPVwiI2ZmZlwiXG4gICAgICBkPVwibTI3Ljc5IDI1Ljc4Ljg4LTUuNzhoLTUuNTV2LTMuNzVjMC0xLjU4Ljc4LTMuMTMgMy4yNi0zLjEzaDIuNTNWOC4ycy0yLjMtLjM5LTQuNDgtLjM5Yy00LjU3IDAtNy41NSAyLjc3LTcuNTUgNy43OFYyMEgxMS44djUuNzhoNS4wN3YxMy45OGEyMC4xNSAyMC4xNSAwIDAgMCA2LjI1IDBWMjUuNzhoNC42N1pcIlxuICAgIC8+XG4gIDwvZz5cbiAgPGRlZnM+XG4gICAgPGNsaXBQYXRoIGlkPVwiYVwiPjxyZWN0IHdpZHRoPVwiNDBcIiBoZWlnaHQ9XCI0MFwiIGZpbGw9XCIjZmZmXCIgcng9XCIyMFwiIC8+PC9jbGlwUGF0aD5cbiAgICA8Y2xpcFBhdGggaWQ9XCJiXCI+PHBhdGggZmlsbD1cIiNmZmZcIiBkPVwiTTAgMGg0MHY0MEgwelwiIC8+PC9jbGlwUGF0aD5cbiAgICA8Y2xpcFBhdGggaWQ9XCJjXCI+PHBhdGggZmlsbD1cIiNmZmZcIiBkPVwiTTggNy44OWgyNHYyNEg4elwiIC8+PC9jbGlwUGF0aD5cbiAgPC9kZWZzPlxuPC9zdmc+YDtcbi8vIyBzb3VyY2VNYXBwaW5nVVJMPWZhY2Vib29rLmpzLm1hcCJdLCJuYW1lcyI6W10sInNvdXJjZVJvb3QiOiIifQ==\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/facebook.js\n"));
-
-/***/ })
-
-}]);
\ No newline at end of file
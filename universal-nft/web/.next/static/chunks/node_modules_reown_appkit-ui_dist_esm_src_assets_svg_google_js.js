@@ -1,22 +0,0 @@
-"use strict";
-/*
- * ATTENTION: An "eval-source-map" devtool has been used.
- * This devtool is neither made for production nor for readable output files.
- * It uses "eval()" calls to create a separate source file with attached SourceMaps in the browser devtools.
- * If you are trying to read the output file, select a different devtool (https://webpack.js.org/configuration/devtool/)
- * or disable the default devtool with "devtool: false".
- * If you are looking for production-ready output files, see mode: "production" (https://webpack.js.org/configuration/mode/).
- */
-(self["webpackChunk_N_E"] = self["webpackChunk_N_E"] || []).push([["node_modules_reown_appkit-ui_dist_esm_src_assets_svg_google_js"],{
-
-/***/ "./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/google.js":
-/*!*************************************************************************!*\
-  !*** ./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/google.js ***!
-  \*************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   googleSvg: function() { return /* binding */ googleSvg; }\n/* harmony export */ });\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n\nconst googleSvg = (0,lit__WEBPACK_IMPORTED_MODULE_0__.svg) `<svg fill=\"none\" viewBox=\"0 0 40 40\">\n  <path\n    fill=\"#4285F4\"\n    d=\"M32.74 20.3c0-.93-.08-1.81-.24-2.66H20.26v5.03h7a6 6 0 0 1-2.62 3.91v3.28h4.22c2.46-2.27 3.88-5.6 3.88-9.56Z\"\n  />\n  <path\n    fill=\"#34A853\"\n    d=\"M20.26 33a12.4 12.4 0 0 0 8.6-3.14l-4.22-3.28a7.74 7.74 0 0 1-4.38 1.26 7.76 7.76 0 0 1-7.28-5.36H8.65v3.36A12.99 12.99 0 0 0 20.26 33Z\"\n  />\n  <path\n    fill=\"#FBBC05\"\n    d=\"M12.98 22.47a7.79 7.79 0 0 1 0-4.94v-3.36H8.65a12.84 12.84 0 0 0 0 11.66l3.37-2.63.96-.73Z\"\n  />\n  <path\n    fill=\"#EA4335\"\n    d=\"M20.26 12.18a7.1 7.1 0 0 1 4.98 1.93l3.72-3.72A12.47 12.47 0 0 0 20.26 7c-5.08 0-9.47 2.92-11.6 7.17l4.32 3.36a7.76 7.76 0 0 1 7.28-5.35Z\"\n  />\n</svg>`;\n//# sourceMappingURL=google.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvYXNzZXRzL3N2Zy9nb29nbGUuanMiLCJtYXBwaW5ncyI6Ijs7Ozs7QUFBMEI7QUFDbkIsa0JBQWtCLHdDQUFHO0FBQzVCO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQSIsInNvdXJjZXMiOlsid2VicGFjazovL19OX0UvLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvYXNzZXRzL3N2Zy9nb29nbGUuanM/MjUzZCJdLCJzb3VyY2VzQ29udGVudCI6WyJpbXBvcnQgeyBzdmcgfSBmcm9tICdsaXQnO1xuZXhwb3J0IGNvbnN0IGdvb2dsZVN2ZyA9IHN2ZyBgPHN2ZyBmaWxsPVwibm9uZVwiIHZpZXdCb3g9XCIwIDAgNDAgNDBcIj5cbiAgPHBhdGhcbiAgICBmaWxsPVwiIzQyODVGNFwiXG4gICAgZD1cIk0zMi43NCAyMC4zYzAtLjkzLS4wOC0xLjgxLS4yNC0yLjY2SDIwLjI2djUuMDNoN2E2IDYgMCAwIDEtMi42MiAzLjkxdjMuMjhoNC4yMmMyLjQ2LTIuMjcgMy44OC01LjYgMy44OC05LjU2WlwiXG4gIC8+XG4gIDxwYXRoXG4gICAgZmlsbD1cIiMzNEE4NTNcIlxuICAgIGQ9XCJNMjAuMjYgMzNhMTIuNCAxMi40IDAgMCAwIDguNi0zLjE0bC00LjIyLTMuMjhhNy43NCA3Ljc0IDAgMCAxLTQuMzggMS4yNiA3Ljc2IDcuNzYgMCAwIDEtNy4yOC01LjM2SDguNjV2My4zNkExMi45OSAxMi45OSAwIDAgMCAyMC4yNiAzM1pcIlxuICAvPlxuICA8cGF0aFxuICAgIGZpbGw9XCIjRkJCQzA1XCJcbiAgICBkPVwiTTEyLjk4IDIyLjQ3YTcuNzkgNy43OSAwIDAgMSAwLTQuOTR2LTMuMzZIOC42NWExMi44NCAxMi44NCAwIDAgMCAwIDExLjY2bDMuMzctMi42My45Ni0uNzNaXCJcbiAgLz5cbiAgPHBhdGhcbiAgICBmaWxsPVwiI0VBNDMzNVwiXG4gICAgZD1cIk0yMC4yNiAxMi4xOGE3LjEgNy4xIDAgMCAxIDQuOTggMS45M2wzLjcyLTMuNzJBMTIuNDcgMTIuNDcgMCAwIDAgMjAuMjYgN2MtNS4wOCAwLTkuNDcgMi45Mi0xMS42IDcuMTdsNC4zMiAzLjM2YTcuNzYgNy43NiAwIDAgMSA3LjI4LTUuMzVaXCJcbiAgLz5cbjwvc3ZnPmA7XG4vLyMgc291cmNlTWFwcGluZ1VSTD1nb29nbGUuanMubWFwIl0sIm5hbWVzIjpbXSwic291cmNlUm9vdCI6IiJ9\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/google.js\n"));
-
-/***/ })
-
-}]);
\ No newline at end of file
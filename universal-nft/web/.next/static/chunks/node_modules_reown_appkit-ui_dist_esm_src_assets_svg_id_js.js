@@ -1,22 +0,0 @@
-"use strict";
-/*
- * ATTENTION: An "eval-source-map" devtool has been used.
- * This devtool is neither made for production nor for readable output files.
- * It uses "eval()" calls to create a separate source file with attached SourceMaps in the browser devtools.
- * If you are trying to read the output file, select a different devtool (https://webpack.js.org/configuration/devtool/)
- * or disable the default devtool with "devtool: false".
- * If you are looking for production-ready output files, see mode: "production" (https://webpack.js.org/configuration/mode/).
- */
-(self["webpackChunk_N_E"] = self["webpackChunk_N_E"] || []).push([["node_modules_reown_appkit-ui_dist_esm_src_assets_svg_id_js"],{
-
-/***/ "./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/id.js":
-/*!*********************************************************************!*\
-  !*** ./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/id.js ***!
-  \*********************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   idSvg: function() { return /* binding */ idSvg; }\n/* harmony export */ });\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n\nconst idSvg = (0,lit__WEBPACK_IMPORTED_MODULE_0__.svg) `<svg\n xmlns=\"http://www.w3.org/2000/svg\"\n width=\"28\"\n height=\"28\"\n viewBox=\"0 0 28 28\"\n fill=\"none\">\n  <path\n    fill=\"#949E9E\"\n    fill-rule=\"evenodd\"\n    d=\"M7.974 2.975h12.052c1.248 0 2.296 0 3.143.092.89.096 1.723.307 2.461.844a4.9 4.9 0 0 1 1.084 1.084c.537.738.748 1.57.844 2.461.092.847.092 1.895.092 3.143v6.802c0 1.248 0 2.296-.092 3.143-.096.89-.307 1.723-.844 2.461a4.9 4.9 0 0 1-1.084 1.084c-.738.537-1.57.748-2.461.844-.847.092-1.895.092-3.143.092H7.974c-1.247 0-2.296 0-3.143-.092-.89-.096-1.723-.307-2.461-.844a4.901 4.901 0 0 1-1.084-1.084c-.537-.738-.748-1.571-.844-2.461C.35 19.697.35 18.649.35 17.4v-6.802c0-1.248 0-2.296.092-3.143.096-.89.307-1.723.844-2.461A4.9 4.9 0 0 1 2.37 3.91c.738-.537 1.571-.748 2.461-.844.847-.092 1.895-.092 3.143-.092ZM5.133 5.85c-.652.071-.936.194-1.117.326a2.1 2.1 0 0 0-.465.465c-.132.181-.255.465-.325 1.117-.074.678-.076 1.573-.076 2.917v6.65c0 1.344.002 2.239.076 2.917.07.652.193.936.325 1.117a2.1 2.1 0 0 0 .465.465c.181.132.465.255 1.117.326.678.073 1.574.075 2.917.075h11.9c1.344 0 2.239-.002 2.917-.075.652-.071.936-.194 1.117-.326.179-.13.335-.286.465-.465.132-.181.255-.465.326-1.117.073-.678.075-1.573.075-2.917v-6.65c0-1.344-.002-2.239-.075-2.917-.071-.652-.194-.936-.326-1.117a2.1 2.1 0 0 0-.465-.465c-.181-.132-.465-.255-1.117-.326-.678-.073-1.573-.075-2.917-.075H8.05c-1.343 0-2.239.002-2.917.075Zm.467 7.275a3.15 3.15 0 1 1 6.3 0 3.15 3.15 0 0 1-6.3 0Zm8.75-1.75a1.4 1.4 0 0 1 1.4-1.4h3.5a1.4 1.4 0 0 1 0 2.8h-3.5a1.4 1.4 0 0 1-1.4-1.4Zm0 5.25a1.4 1.4 0 0 1 1.4-1.4H21a1.4 1.4 0 1 1 0 2.8h-5.25a1.4 1.4 0 0 1-1.4-1.4Z\"\n    clip-rule=\"evenodd\"/>\n</svg>`;\n//# sourceMappingURL=id.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvYXNzZXRzL3N2Zy9pZC5qcyIsIm1hcHBpbmdzIjoiOzs7OztBQUEwQjtBQUNuQixjQUFjLHdDQUFHO0FBQ3hCO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQSIsInNvdXJjZXMiOlsid2VicGFjazovL19OX0UvLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvYXNzZXRzL3N2Zy9pZC5qcz9jYTE2Il0sInNvdXJjZXNDb250ZW50IjpbImltcG9ydCB7IHN2ZyB9IGZyb20gJ2xpdCc7XG5leHBvcnQgY29uc3QgaWRTdmcgPSBzdmcgYDxzdmdcbiB4bWxucz1cImh0dHA6Ly93d3cudzMub3JnLzIwMDAvc3ZnXCJcbiB3aWR0aD1cIjI4XCJcbiBoZWlnaHQ9XCIyOFwiXG4gdmlld0JveD1cIjAgMCAyOCAyOFwiXG4gZmlsbD1cIm5vbmVcIj5cbiAgPHBhdGhcbiAgICBmaWxsPVwiIzk0OUU5RVwiXG4gICAgZmlsbC1ydWxlPVwiZXZlbm9kZFwiXG4gICAgZD1cIk03Ljk3NCAyLjk3NWgxMi4wNTJjMS4yNDggMCAyLjI5NiAwIDMuMTQzLjA5Mi44OS4wOTYgMS43MjMuMzA3IDIuNDYxLjg0NGE0LjkgNC45IDAgMCAxIDEuMDg0IDEuMDg0Yy41MzcuNzM4Ljc0OCAxLjU3Ljg0NCAyLjQ2MS4wOTIuODQ3LjA5MiAxLjg5NS4wOTIgMy4xNDN2Ni44MDJjMCAxLjI0OCAwIDIuMjk2LS4wOTIgMy4xNDMtLjA5Ni44OS0uMzA3IDEuNzIzLS44NDQgMi40NjFhNC45IDQuOSAwIDAgMS0xLjA4NCAxLjA4NGMtLjczOC41MzctMS41Ny43NDgtMi40NjEuODQ0LS44NDcuMDkyLTEuODk1LjA5Mi0zLjE0My4wOTJINy45NzRjLTEuMjQ3IDAtMi4yOTYgMC0zLjE0My0uMDkyLS44OS0uMDk2LTEuNzIzLS4zMDctMi40NjEtLjg0NGE0LjkwMSA0LjkwMSAwIDAgMS0xLjA4NC0xLjA4NGMtLjUzNy0uNzM4LS43NDgtMS41NzEtLjg0NC0yLjQ2MUMuMzUgMTkuNjk3LjM1IDE4LjY0OS4zNSAxNy40di02LjgwMmMwLTEuMjQ4IDAtMi4yOTYuMDkyLTMuMTQzLjA5Ni0uODkuMzA3LTEuNzIzLjg0NC0yLjQ2MUE0LjkgNC45IDAgMCAxIDIuMzcgMy45MWMuNzM4LS41MzcgMS41NzEtLjc0OCAyLjQ2MS0uODQ0Ljg0Ny0uMDkyIDEuODk1LS4wOTIgMy4xNDMtLjA5MlpNNS4xMzMgNS44NWMtLjY1Mi4wNzEtLjkzNi4xOTQtMS4xMTcuMzI2YTIuMSAyLjEgMCAwIDAtLjQ2NS40NjVjLS4xMzIuMTgxLS4yNTUuNDY1LS4zMjUgMS4xMTctLjA3NC42NzgtLjA3NiAxLjU3My0uMDc2IDIuOTE3djYuNjVjMCAxLjM0NC4wMDIgMi4yMzkuMDc2IDIuOTE3LjA3LjY1Mi4xOTMuOTM2LjMyNSAxLjExN2EyLjEgMi4xIDAgMCAwIC40NjUuNDY1Yy4xODEuMTMyLjQ2NS4yNTUgMS4xMTcuMzI2LjY3OC4wNzMgMS41NzQuMDc1IDIuOTE3LjA3NWgxMS45YzEuMzQ0IDAgMi4yMzktLjAwMiAyLjkxNy0uMDc1LjY1Mi0uMDcxLjkzNi0uMTk0IDEuMTE3LS4zMjYuMTc5LS4xMy4zMzUtLjI4Ni40NjUtLjQ2NS4xMzItLjE4MS4yNTUtLjQ2NS4zMjYtMS4xMTcuMDczLS42NzguMDc1LTEuNTczLjA3NS0yLjkxN3YtNi42NWMwLTEuMzQ0LS4wMDItMi4yMzktLjA3NS0yLjkxNy0uMDcxLS42NTItLjE5NC0uOTM2LS4zMjYtMS4xMTdhMi4xIDIuMSAwIDAgMC0uNDY1LS40NjVjLS4xODEtLjEzMi0uNDY1LS4yNTUtMS4xMTctLjMyNi0uNjc4LS4wNzMtMS41NzMtLjA3NS0yLjkxNy0uMDc1SDguMDVjLTEuMzQzIDAtMi4yMzkuMDAyLTIuOTE3LjA3NVptLjQ2NyA3LjI3NWEzLjE1IDMuMTUgMCAxIDEgNi4zIDAgMy4xNSAzLjE1IDAgMCAxLTYuMyAwWm04Ljc1LTEuNzVhMS40IDEuNCAwIDAgMSAxLjQtMS40aDMuNWExLjQgMS40IDAgMCAxIDAgMi44aC0zLjVhMS40IDEuNCAwIDAgMS0xLjQtMS40Wm0wIDUuMjVhMS40IDEuNCAwIDAgMSAxLjQtMS40SDIxYTEuNCAxLjQgMCAxIDEgMCAyLjhoLTUuMjVhMS40IDEuNCAwIDAgMS0xLjQtMS40WlwiXG4gICAgY2xpcC1ydWxlPVwiZXZlbm9kZFwiLz5cbjwvc3ZnPmA7XG4vLyMgc291cmNlTWFwcGluZ1VSTD1pZC5qcy5tYXAiXSwibmFtZXMiOltdLCJzb3VyY2VSb290IjoiIn0=\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/id.js\n"));
-
-/***/ })
-
-}]);
\ No newline at end of file
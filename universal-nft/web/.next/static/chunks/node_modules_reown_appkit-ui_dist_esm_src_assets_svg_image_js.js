@@ -1,22 +0,0 @@
-"use strict";
-/*
- * ATTENTION: An "eval-source-map" devtool has been used.
- * This devtool is neither made for production nor for readable output files.
- * It uses "eval()" calls to create a separate source file with attached SourceMaps in the browser devtools.
- * If you are trying to read the output file, select a different devtool (https://webpack.js.org/configuration/devtool/)
- * or disable the default devtool with "devtool: false".
- * If you are looking for production-ready output files, see mode: "production" (https://webpack.js.org/configuration/mode/).
- */
-(self["webpackChunk_N_E"] = self["webpackChunk_N_E"] || []).push([["node_modules_reown_appkit-ui_dist_esm_src_assets_svg_image_js"],{
-
-/***/ "./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/image.js":
-/*!************************************************************************!*\
-  !*** ./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/image.js ***!
-  \************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   imageSvg: function() { return /* binding */ imageSvg; }\n/* harmony export */ });\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n\nconst imageSvg = (0,lit__WEBPACK_IMPORTED_MODULE_0__.svg) `<svg width=\"14\" height=\"14\" viewBox=\"0 0 14 14\" fill=\"none\">\n  <path d=\"M4.98926 3.73932C4.2989 3.73932 3.73926 4.29896 3.73926 4.98932C3.73926 5.67968 4.2989 6.23932 4.98926 6.23932C5.67962 6.23932 6.23926 5.67968 6.23926 4.98932C6.23926 4.29896 5.67962 3.73932 4.98926 3.73932Z\" fill=\"currentColor\"/>\n  <path fill-rule=\"evenodd\" clip-rule=\"evenodd\" d=\"M7.60497 0.500001H6.39504C5.41068 0.499977 4.59185 0.499958 3.93178 0.571471C3.24075 0.64634 2.60613 0.809093 2.04581 1.21619C1.72745 1.44749 1.44749 1.72745 1.21619 2.04581C0.809093 2.60613 0.64634 3.24075 0.571471 3.93178C0.499958 4.59185 0.499977 5.41065 0.500001 6.39501V7.57815C0.499998 8.37476 0.499995 9.05726 0.534869 9.62725C0.570123 10.2034 0.644114 10.7419 0.828442 11.2302C0.925651 11.4877 1.05235 11.7287 1.21619 11.9542C1.44749 12.2726 1.72745 12.5525 2.04581 12.7838C2.60613 13.1909 3.24075 13.3537 3.93178 13.4285C4.59185 13.5001 5.41066 13.5 6.39503 13.5H7.60496C8.58933 13.5 9.40815 13.5001 10.0682 13.4285C10.7593 13.3537 11.3939 13.1909 11.9542 12.7838C12.2726 12.5525 12.5525 12.2726 12.7838 11.9542C13.1909 11.3939 13.3537 10.7593 13.4285 10.0682C13.5 9.40816 13.5 8.58935 13.5 7.60497V6.39505C13.5 5.41068 13.5 4.59185 13.4285 3.93178C13.3537 3.24075 13.1909 2.60613 12.7838 2.04581C12.5525 1.72745 12.2726 1.44749 11.9542 1.21619C11.3939 0.809093 10.7593 0.64634 10.0682 0.571471C9.40816 0.499958 8.58933 0.499977 7.60497 0.500001ZM3.22138 2.83422C3.38394 2.71612 3.62634 2.61627 4.14721 2.55984C4.68679 2.50138 5.39655 2.5 6.45 2.5H7.55C8.60345 2.5 9.31322 2.50138 9.8528 2.55984C10.3737 2.61627 10.6161 2.71612 10.7786 2.83422C10.9272 2.94216 11.0578 3.07281 11.1658 3.22138C11.2839 3.38394 11.3837 3.62634 11.4402 4.14721C11.4986 4.68679 11.5 5.39655 11.5 6.45V6.49703C10.9674 6.11617 10.386 5.84936 9.74213 5.81948C8.40536 5.75745 7.3556 6.73051 6.40509 7.84229C6.33236 7.92737 6.27406 7.98735 6.22971 8.02911L6.1919 8.00514L6.17483 7.99427C6.09523 7.94353 5.98115 7.87083 5.85596 7.80302C5.56887 7.64752 5.18012 7.4921 4.68105 7.4921C4.66697 7.4921 4.6529 7.49239 4.63884 7.49299C3.79163 7.52878 3.09922 8.1106 2.62901 8.55472C2.58751 8.59392 2.54594 8.6339 2.50435 8.6745C2.50011 8.34653 2.5 7.97569 2.5 7.55V6.45C2.5 5.39655 2.50138 4.68679 2.55984 4.14721C2.61627 3.62634 2.71612 3.38394 2.83422 3.22138C2.94216 3.07281 3.07281 2.94216 3.22138 2.83422ZM10.3703 8.14825C10.6798 8.37526 11.043 8.71839 11.4832 9.20889C11.4744 9.44992 11.4608 9.662 11.4402 9.8528C11.3837 10.3737 11.2839 10.6161 11.1658 10.7786C11.0578 10.9272 10.9272 11.0578 10.7786 11.1658C10.6161 11.2839 10.3737 11.3837 9.8528 11.4402C9.31322 11.4986 8.60345 11.5 7.55 11.5H6.45C5.39655 11.5 4.68679 11.4986 4.14721 11.4402C3.62634 11.3837 3.38394 11.2839 3.22138 11.1658C3.15484 11.1174 3.0919 11.0645 3.03298 11.0075C3.10126 10.9356 3.16806 10.8649 3.23317 10.7959L3.29772 10.7276C3.55763 10.4525 3.78639 10.2126 4.00232 10.0087C4.22016 9.80294 4.39412 9.66364 4.53524 9.57742C4.63352 9.51738 4.69022 9.49897 4.71275 9.49345C4.76387 9.49804 4.81803 9.51537 4.90343 9.56162C4.96409 9.59447 5.02355 9.63225 5.11802 9.69238L5.12363 9.69595C5.20522 9.74789 5.32771 9.82587 5.46078 9.89278C5.76529 10.0459 6.21427 10.186 6.74977 10.0158C7.21485 9.86796 7.59367 9.52979 7.92525 9.14195C8.91377 7.98571 9.38267 7.80495 9.64941 7.81733C9.7858 7.82366 10.0101 7.884 10.3703 8.14825Z\" fill=\"currentColor\"/>\n</svg>`;\n//# sourceMappingURL=image.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvYXNzZXRzL3N2Zy9pbWFnZS5qcyIsIm1hcHBpbmdzIjoiOzs7OztBQUEwQjtBQUNuQixpQkFBaUIsd0NBQUc7QUFDM0I7QUFDQTtBQUNBO0FBQ0EiLCJzb3VyY2VzIjpbIndlYnBhY2s6Ly9fTl9FLy4vbm9kZV9tb2R1bGVzL0ByZW93bi9hcHBraXQtdWkvZGlzdC9lc20vc3JjL2Fzc2V0cy9zdmcvaW1hZ2UuanM/ZjAwZSJdLCJzb3VyY2VzQ29udGVudCI6WyJpbXBvcnQgeyBzdmcgfSBmcm9tICdsaXQnO1xuZXhwb3J0IGNvbnN0IGltYWdlU3ZnID0gc3ZnIGA8c3ZnIHdpZHRoPVwiMTRcIiBoZWlnaHQ9XCIxNFwiIHZpZXdCb3g9XCIwIDAgMTQgMTRcIiBmaWxsPVwibm9uZVwiPlxuICA8cGF0aCBkPVwiTTQuOTg5MjYgMy43MzkzMkM0LjI5ODkgMy43MzkzMiAzLjczOTI2IDQuMjk4OTYgMy43MzkyNiA0Ljk4OTMyQzMuNzM5MjYgNS42Nzk2OCA0LjI5ODkgNi4yMzkzMiA0Ljk4OTI2IDYuMjM5MzJDNS42Nzk2MiA2LjIzOTMyIDYuMjM5MjYgNS42Nzk2OCA2LjIzOTI2IDQuOTg5MzJDNi4yMzkyNiA0LjI5ODk2IDUuNjc5NjIgMy43MzkzMiA0Ljk4OTI2IDMuNzM5MzJaXCIgZmlsbD1cImN1cnJlbnRDb2xvclwiLz5cbiAgPHBhdGggZmlsbC1ydWxlPVwiZXZlbm9kZFwiIGNsaXAtcnVsZT1cImV2ZW5vZGRcIiBkPVwiTTcuNjA0OTcgMC41MDAwMDFINi4zOTUwNEM1LjQxMDY4IDAuNDk5OTc3IDQuNTkxODUgMC40OTk5NTggMy45MzE3OCAwLjU3MTQ3MUMzLjI0MDc1IDAuNjQ2MzQgMi42MDYxMyAwLjgwOTA5MyAyLjA0NTgxIDEuMjE2MTlDMS43Mjc0NSAxLjQ0NzQ5IDEuNDQ3NDkgMS43Mjc0NSAxLjIxNjE5IDIuMDQ1ODFDMC44MDkwOTMgMi42MDYxMyAwLjY0NjM0IDMuMjQwNzUgMC41NzE0NzEgMy45MzE3OEMwLjQ5OTk1OCA0LjU5MTg1IDAuNDk5OTc3IDUuNDEwNjUgMC41MDAwMDEgNi4zOTUwMVY3LjU3ODE1QzAuNDk5OTk4IDguMzc0NzYgMC40OTk5OTUgOS4wNTcyNiAwLjUzNDg2OSA5LjYyNzI1QzAuNTcwMTIzIDEwLjIwMzQgMC42NDQxMTQgMTAuNzQxOSAwLjgyODQ0MiAxMS4yMzAyQzAuOTI1NjUxIDExLjQ4NzcgMS4wNTIzNSAxMS43Mjg3IDEuMjE2MTkgMTEuOTU0MkMxLjQ0NzQ5IDEyLjI3MjYgMS43Mjc0NSAxMi41NTI1IDIuMDQ1ODEgMTIuNzgzOEMyLjYwNjEzIDEzLjE5MDkgMy4yNDA3NSAxMy4zNTM3IDMuOTMxNzggMTMuNDI4NUM0LjU5MTg1IDEzLjUwMDEgNS40MTA2NiAxMy41IDYuMzk1MDMgMTMuNUg3LjYwNDk2QzguNTg5MzMgMTMuNSA5LjQwODE1IDEzLjUwMDEgMTAuMDY4MiAxMy40Mjg1QzEwLjc1OTMgMTMuMzUzNyAxMS4zOTM5IDEzLjE5MDkgMTEuOTU0MiAxMi43ODM4QzEyLjI3MjYgMTIuNTUyNSAxMi41NTI1IDEyLjI3MjYgMTIuNzgzOCAxMS45NTQyQzEzLjE5MDkgMTEuMzkzOSAxMy4zNTM3IDEwLjc1OTMgMTMuNDI4NSAxMC4wNjgyQzEzLjUgOS40MDgxNiAxMy41IDguNTg5MzUgMTMuNSA3LjYwNDk3VjYuMzk1MDVDMTMuNSA1LjQxMDY4IDEzLjUgNC41OTE4NSAxMy40Mjg1IDMuOTMxNzhDMTMuMzUzNyAzLjI0MDc1IDEzLjE5MDkgMi42MDYxMyAxMi43ODM4IDIuMDQ1ODFDMTIuNTUyNSAxLjcyNzQ1IDEyLjI3MjYgMS40NDc0OSAxMS45NTQyIDEuMjE2MTlDMTEuMzkzOSAwLjgwOTA5MyAxMC43NTkzIDAuNjQ2MzQgMTAuMDY4MiAwLjU3MTQ3MUM5LjQwODE2IDAuNDk5OTU4IDguNTg5MzMgMC40OTk5NzcgNy42MDQ5NyAwLjUwMDAwMVpNMy4yMjEzOCAyLjgzNDIyQzMuMzgzOTQgMi43MTYxMiAzLjYyNjM0IDIuNjE2MjcgNC4xNDcyMSAyLjU1OTg0QzQuNjg2NzkgMi41MDEzOCA1LjM5NjU1IDIuNSA2LjQ1IDIuNUg3LjU1QzguNjAzNDUgMi41IDkuMzEzMjIgMi41MDEzOCA5Ljg1MjggMi41NTk4NEMxMC4zNzM3IDIuNjE2MjcgMTAuNjE2MSAyLjcxNjEyIDEwLjc3ODYgMi44MzQyMkMxMC45MjcyIDIuOTQyMTYgMTEuMDU3OCAzLjA3MjgxIDExLjE2NTggMy4yMjEzOEMxMS4yODM5IDMuMzgzOTQgMTEuMzgzNyAzLjYyNjM0IDExLjQ0MDIgNC4xNDcyMUMxMS40OTg2IDQuNjg2NzkgMTEuNSA1LjM5NjU1IDExLjUgNi40NVY2LjQ5NzAzQzEwLjk2NzQgNi4xMTYxNyAxMC4zODYgNS44NDkzNiA5Ljc0MjEzIDUuODE5NDhDOC40MDUzNiA1Ljc1NzQ1IDcuMzU1NiA2LjczMDUxIDYuNDA1MDkgNy44NDIyOUM2LjMzMjM2IDcuOTI3MzcgNi4yNzQwNiA3Ljk4NzM1IDYuMjI5NzEgOC4wMjkxMUw2LjE5MTkgOC4wMDUxNEw2LjE3NDgzIDcuOTk0MjdDNi4wOTUyMyA3Ljk0MzUzIDUuOTgxMTUgNy44NzA4MyA1Ljg1NTk2IDcuODAzMDJDNS41Njg4NyA3LjY0NzUyIDUuMTgwMTIgNy40OTIxIDQuNjgxMDUgNy40OTIxQzQuNjY2OTcgNy40OTIxIDQuNjUyOSA3LjQ5MjM5IDQuNjM4ODQgNy40OTI5OUMzLjc5MTYzIDcuNTI4NzggMy4wOTkyMiA4LjExMDYgMi42MjkwMSA4LjU1NDcyQzIuNTg3NTEgOC41OTM5MiAyLjU0NTk0IDguNjMzOSAyLjUwNDM1IDguNjc0NUMyLjUwMDExIDguMzQ2NTMgMi41IDcuOTc1NjkgMi41IDcuNTVWNi40NUMyLjUgNS4zOTY1NSAyLjUwMTM4IDQuNjg2NzkgMi41NTk4NCA0LjE0NzIxQzIuNjE2MjcgMy42MjYzNCAyLjcxNjEyIDMuMzgzOTQgMi44MzQyMiAzLjIyMTM4QzIuOTQyMTYgMy4wNzI4MSAzLjA3MjgxIDIuOTQyMTYgMy4yMjEzOCAyLjgzNDIyWk0xMC4zNzAzIDguMTQ4MjVDMTAuNjc5OCA4LjM3NTI2IDExLjA0MyA4LjcxODM5IDExLjQ4MzIgOS4yMDg4OUMxMS40NzQ0IDkuNDQ5OTIgMTEuNDYwOCA5LjY2MiAxMS40NDAyIDkuODUyOEMxMS4zODM3IDEwLjM3MzcgMTEuMjgzOSAxMC42MTYxIDExLjE2NTggMTAuNzc4NkMxMS4wNTc4IDEwLjkyNzIgMTAuOTI3MiAxMS4wNTc4IDEwLjc3ODYgMTEuMTY1OEMxMC42MTYxIDExLjI4MzkgMTAuMzczNyAxMS4zODM3IDkuODUyOCAxMS40NDAyQzkuMzEzMjIgMTEuNDk4NiA4LjYwMzQ1IDExLjUgNy41NSAxMS41SDYuNDVDNS4zOTY1NSAxMS41IDQuNjg2NzkgMTEuNDk4NiA0LjE0NzIxIDExLjQ0MDJDMy42MjYzNCAxMS4zODM3IDMuMzgzOTQgMTEuMjgzOSAzLjIyMTM4IDExLjE2NThDMy4xNTQ4NCAxMS4xMTc0IDMuMDkxOSAxMS4wNjQ1IDMuMDMyOTggMTEuMDA3NUMzLjEwMTI2IDEwLjkzNTYgMy4xNjgwNiAxMC44NjQ5IDMuMjMzMTcgMTAuNzk1OUwzLjI5NzcyIDEwLjcyNzZDMy41NTc2MyAxMC40NTI1IDMuNzg2MzkgMTAuMjEyNiA0LjAwMjMyIDEwLjAwODdDNC4yMjAxNiA5LjgwMjk0IDQuMzk0MTIgOS42NjM2NCA0LjUzNTI0IDkuNTc3NDJDNC42MzM1MiA5LjUxNzM4IDQuNjkwMjIgOS40OTg5NyA0LjcxMjc1IDkuNDkzNDVDNC43NjM4NyA5LjQ5ODA0IDQuODE4MDMgOS41MTUzNyA0LjkwMzQzIDkuNTYxNjJDNC45NjQwOSA5LjU5NDQ3IDUuMDIzNTUgOS42MzIyNSA1LjExODAyIDkuNjkyMzhMNS4xMjM2MyA5LjY5NTk1QzUuMjA1MjIgOS43NDc4OSA1LjMyNzcxIDkuODI1ODcgNS40NjA3OCA5Ljg5Mjc4QzUuNzY1MjkgMTAuMDQ1OSA2LjIxNDI3IDEwLjE4NiA2Ljc0OTc3IDEwLjAxNThDNy4yMTQ4NSA5Ljg2Nzk2IDcuNTkzNjcgOS41Mjk3OSA3LjkyNTI1IDkuMTQxOTVDOC45MTM3NyA3Ljk4NTcxIDkuMzgyNjcgNy44MDQ5NSA5LjY0OTQxIDcuODE3MzNDOS43ODU4IDcuODIzNjYgMTAuMDEwMSA3Ljg4NCAxMC4zNzAzIDguMTQ4MjVaXCIgZmlsbD1cImN1cnJlbnRDb2xvclwiLz5cbjwvc3ZnPmA7XG4vLyMgc291cmNlTWFwcGluZ1VSTD1pbWFnZS5qcy5tYXAiXSwibmFtZXMiOltdLCJzb3VyY2VSb290IjoiIn0=\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/image.js\n"));
-
-/***/ })
-
-}]);
\ No newline at end of file
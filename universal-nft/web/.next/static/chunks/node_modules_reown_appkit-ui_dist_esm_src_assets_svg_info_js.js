@@ -1,22 +0,0 @@
-"use strict";
-/*
- * ATTENTION: An "eval-source-map" devtool has been used.
- * This devtool is neither made for production nor for readable output files.
- * It uses "eval()" calls to create a separate source file with attached SourceMaps in the browser devtools.
- * If you are trying to read the output file, select a different devtool (https://webpack.js.org/configuration/devtool/)
- * or disable the default devtool with "devtool: false".
- * If you are looking for production-ready output files, see mode: "production" (https://webpack.js.org/configuration/mode/).
- */
-(self["webpackChunk_N_E"] = self["webpackChunk_N_E"] || []).push([["node_modules_reown_appkit-ui_dist_esm_src_assets_svg_info_js"],{
-
-/***/ "./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/info.js":
-/*!***********************************************************************!*\
-  !*** ./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/info.js ***!
-  \***********************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   infoSvg: function() { return /* binding */ infoSvg; }\n/* harmony export */ });\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n\nconst infoSvg = (0,lit__WEBPACK_IMPORTED_MODULE_0__.svg) `<svg width=\"22\" height=\"22\" viewBox=\"0 0 22 22\" fill=\"none\" xmlns=\"http://www.w3.org/2000/svg\">\n<path d=\"M9.125 6.875C9.125 6.57833 9.21298 6.28832 9.3778 6.04165C9.54262 5.79497 9.77689 5.60271 10.051 5.48918C10.3251 5.37565 10.6267 5.34594 10.9176 5.40382C11.2086 5.4617 11.4759 5.60456 11.6857 5.81434C11.8954 6.02412 12.0383 6.29139 12.0962 6.58236C12.1541 6.87334 12.1244 7.17494 12.0108 7.44903C11.8973 7.72311 11.705 7.95738 11.4584 8.1222C11.2117 8.28703 10.9217 8.375 10.625 8.375C10.2272 8.375 9.84565 8.21696 9.56434 7.93566C9.28304 7.65436 9.125 7.27282 9.125 6.875ZM21.125 11C21.125 13.0025 20.5312 14.9601 19.4186 16.6251C18.3061 18.2902 16.7248 19.5879 14.8747 20.3543C13.0246 21.1206 10.9888 21.3211 9.02471 20.9305C7.06066 20.5398 5.25656 19.5755 3.84055 18.1595C2.42454 16.7435 1.46023 14.9393 1.06955 12.9753C0.678878 11.0112 0.879387 8.97543 1.64572 7.12533C2.41206 5.27523 3.70981 3.69392 5.37486 2.58137C7.0399 1.46882 8.99747 0.875 11 0.875C13.6844 0.877978 16.258 1.94567 18.1562 3.84383C20.0543 5.74199 21.122 8.3156 21.125 11ZM18.875 11C18.875 9.44247 18.4131 7.91992 17.5478 6.62488C16.6825 5.32985 15.4526 4.32049 14.0136 3.72445C12.5747 3.12841 10.9913 2.97246 9.46367 3.27632C7.93607 3.58017 6.53288 4.3302 5.43154 5.43153C4.3302 6.53287 3.58018 7.93606 3.27632 9.46366C2.97246 10.9913 3.12841 12.5747 3.72445 14.0136C4.32049 15.4526 5.32985 16.6825 6.62489 17.5478C7.91993 18.4131 9.44248 18.875 11 18.875C13.0879 18.8728 15.0896 18.0424 16.566 16.566C18.0424 15.0896 18.8728 13.0879 18.875 11ZM12.125 14.4387V11.375C12.125 10.8777 11.9275 10.4008 11.5758 10.0492C11.2242 9.69754 10.7473 9.5 10.25 9.5C9.98433 9.4996 9.72708 9.59325 9.52383 9.76435C9.32058 9.93544 9.18444 10.173 9.13952 10.4348C9.09461 10.6967 9.14381 10.966 9.27843 11.195C9.41304 11.4241 9.62438 11.5981 9.875 11.6863V14.75C9.875 15.2473 10.0725 15.7242 10.4242 16.0758C10.7758 16.4275 11.2527 16.625 11.75 16.625C12.0157 16.6254 12.2729 16.5318 12.4762 16.3607C12.6794 16.1896 12.8156 15.952 12.8605 15.6902C12.9054 15.4283 12.8562 15.159 12.7216 14.93C12.587 14.7009 12.3756 14.5269 12.125 14.4387Z\" fill=\"currentColor\"/>\n</svg>`;\n//# sourceMappingURL=info.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvYXNzZXRzL3N2Zy9pbmZvLmpzIiwibWFwcGluZ3MiOiI7Ozs7O0FBQTBCO0FBQ25CLGdCQUFnQix3Q0FBRztBQUMxQjtBQUNBO0FBQ0EiLCJzb3VyY2VzIjpbIndlYnBhY2s6Ly9fTl9FLy4vbm9kZV9tb2R1bGVzL0ByZW93bi9hcHBraXQtdWkvZGlzdC9lc20vc3JjL2Fzc2V0cy9zdmcvaW5mby5qcz9mZDJhIl0sInNvdXJjZXNDb250ZW50IjpbImltcG9ydCB7IHN2ZyB9IGZyb20gJ2xpdCc7XG5leHBvcnQgY29uc3QgaW5mb1N2ZyA9IHN2ZyBgPHN2ZyB3aWR0aD1cIjIyXCIgaGVpZ2h0PVwiMjJcIiB2aWV3Qm94PVwiMCAwIDIyIDIyXCIgZmlsbD1cIm5vbmVcIiB4bWxucz1cImh0dHA6Ly93d3cudzMub3JnLzIwMDAvc3ZnXCI+XG48cGF0aCBkPVwiTTkuMTI1IDYuODc1QzkuMTI1IDYuNTc4MzMgOS4yMTI5OCA2LjI4ODMyIDkuMzc3OCA2LjA0MTY1QzkuNTQyNjIgNS43OTQ5NyA5Ljc3Njg5IDUuNjAyNzEgMTAuMDUxIDUuNDg5MThDMTAuMzI1MSA1LjM3NTY1IDEwLjYyNjcgNS4zNDU5NCAxMC45MTc2IDUuNDAzODJDMTEuMjA4NiA1LjQ2MTcgMTEuNDc1OSA1LjYwNDU2IDExLjY4NTcgNS44MTQzNEMxMS44OTU0IDYuMDI0MTIgMTIuMDM4MyA2LjI5MTM5IDEyLjA5NjIgNi41ODIzNkMxMi4xNTQxIDYuODczMzQgMTIuMTI0NCA3LjE3NDk0IDEyLjAxMDggNy40NDkwM0MxMS44OTczIDcuNzIzMTEgMTEuNzA1IDcuOTU3MzggMTEuNDU4NCA4LjEyMjJDMTEuMjExNyA4LjI4NzAzIDEwLjkyMTcgOC4zNzUgMTAuNjI1IDguMzc1QzEwLjIyNzIgOC4zNzUgOS44NDU2NSA4LjIxNjk2IDkuNTY0MzQgNy45MzU2NkM5LjI4MzA0IDcuNjU0MzYgOS4xMjUgNy4yNzI4MiA5LjEyNSA2Ljg3NVpNMjEuMTI1IDExQzIxLjEyNSAxMy4wMDI1IDIwLjUzMTIgMTQuOTYwMSAxOS40MTg2IDE2LjYyNTFDMTguMzA2MSAxOC4yOTAyIDE2LjcyNDggMTkuNTg3OSAxNC44NzQ3IDIwLjM1NDNDMTMuMDI0NiAyMS4xMjA2IDEwLjk4ODggMjEuMzIxMSA5LjAyNDcxIDIwLjkzMDVDNy4wNjA2NiAyMC41Mzk4IDUuMjU2NTYgMTkuNTc1NSAzLjg0MDU1IDE4LjE1OTVDMi40MjQ1NCAxNi43NDM1IDEuNDYwMjMgMTQuOTM5MyAxLjA2OTU1IDEyLjk3NTNDMC42Nzg4NzggMTEuMDExMiAwLjg3OTM4NyA4Ljk3NTQzIDEuNjQ1NzIgNy4xMjUzM0MyLjQxMjA2IDUuMjc1MjMgMy43MDk4MSAzLjY5MzkyIDUuMzc0ODYgMi41ODEzN0M3LjAzOTkgMS40Njg4MiA4Ljk5NzQ3IDAuODc1IDExIDAuODc1QzEzLjY4NDQgMC44Nzc5NzggMTYuMjU4IDEuOTQ1NjcgMTguMTU2MiAzLjg0MzgzQzIwLjA1NDMgNS43NDE5OSAyMS4xMjIgOC4zMTU2IDIxLjEyNSAxMVpNMTguODc1IDExQzE4Ljg3NSA5LjQ0MjQ3IDE4LjQxMzEgNy45MTk5MiAxNy41NDc4IDYuNjI0ODhDMTYuNjgyNSA1LjMyOTg1IDE1LjQ1MjYgNC4zMjA0OSAxNC4wMTM2IDMuNzI0NDVDMTIuNTc0NyAzLjEyODQxIDEwLjk5MTMgMi45NzI0NiA5LjQ2MzY3IDMuMjc2MzJDNy45MzYwNyAzLjU4MDE3IDYuNTMyODggNC4zMzAyIDUuNDMxNTQgNS40MzE1M0M0LjMzMDIgNi41MzI4NyAzLjU4MDE4IDcuOTM2MDYgMy4yNzYzMiA5LjQ2MzY2QzIuOTcyNDYgMTAuOTkxMyAzLjEyODQxIDEyLjU3NDcgMy43MjQ0NSAxNC4wMTM2QzQuMzIwNDkgMTUuNDUyNiA1LjMyOTg1IDE2LjY4MjUgNi42MjQ4OSAxNy41NDc4QzcuOTE5OTMgMTguNDEzMSA5LjQ0MjQ4IDE4Ljg3NSAxMSAxOC44NzVDMTMuMDg3OSAxOC44NzI4IDE1LjA4OTYgMTguMDQyNCAxNi41NjYgMTYuNTY2QzE4LjA0MjQgMTUuMDg5NiAxOC44NzI4IDEzLjA4NzkgMTguODc1IDExWk0xMi4xMjUgMTQuNDM4N1YxMS4zNzVDMTIuMTI1IDEwLjg3NzcgMTEuOTI3NSAxMC40MDA4IDExLjU3NTggMTAuMDQ5MkMxMS4yMjQyIDkuNjk3NTQgMTAuNzQ3MyA5LjUgMTAuMjUgOS41QzkuOTg0MzMgOS40OTk2IDkuNzI3MDggOS41OTMyNSA5LjUyMzgzIDkuNzY0MzVDOS4zMjA1OCA5LjkzNTQ0IDkuMTg0NDQgMTAuMTczIDkuMTM5NTIgMTAuNDM0OEM5LjA5NDYxIDEwLjY5NjcgOS4xNDM4MSAxMC45NjYgOS4yNzg0MyAxMS4xOTVDOS40MTMwNCAxMS40MjQxIDkuNjI0MzggMTEuNTk4MSA5Ljg3NSAxMS42ODYzVjE0Ljc1QzkuODc1IDE1LjI0NzMgMTAuMDcyNSAxNS43MjQyIDEwLjQyNDIgMTYuMDc1OEMxMC43NzU4IDE2LjQyNzUgMTEuMjUyNyAxNi42MjUgMTEuNzUgMTYuNjI1QzEyLjAxNTcgMTYuNjI1NCAxMi4yNzI5IDE2LjUzMTggMTIuNDc2MiAxNi4zNjA3QzEyLjY3OTQgMTYuMTg5NiAxMi44MTU2IDE1Ljk1MiAxMi44NjA1IDE1LjY5MDJDMTIuOTA1NCAxNS40MjgzIDEyLjg1NjIgMTUuMTU5IDEyLjcyMTYgMTQuOTNDMTIuNTg3IDE0LjcwMDkgMTIuMzc1NiAxNC41MjY5IDEyLjEyNSAxNC40Mzg3WlwiIGZpbGw9XCJjdXJyZW50Q29sb3JcIi8+XG48L3N2Zz5gO1xuLy8jIHNvdXJjZU1hcHBpbmdVUkw9aW5mby5qcy5tYXAiXSwibmFtZXMiOltdLCJzb3VyY2VSb290IjoiIn0=\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/info.js\n"));
-
-/***/ })
-
-}]);
\ No newline at end of file
@@ -1,22 +0,0 @@
-"use strict";
-/*
- * ATTENTION: An "eval-source-map" devtool has been used.
- * This devtool is neither made for production nor for readable output files.
- * It uses "eval()" calls to create a separate source file with attached SourceMaps in the browser devtools.
- * If you are trying to read the output file, select a different devtool (https://webpack.js.org/configuration/devtool/)
- * or disable the default devtool with "devtool: false".
- * If you are looking for production-ready output files, see mode: "production" (https://webpack.js.org/configuration/mode/).
- */
-(self["webpackChunk_N_E"] = self["webpackChunk_N_E"] || []).push([["node_modules_reown_appkit-ui_dist_esm_src_assets_svg_lightbulb_js"],{
-
-/***/ "./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/lightbulb.js":
-/*!****************************************************************************!*\
-  !*** ./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/lightbulb.js ***!
-  \****************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   lightbulbSvg: function() { return /* binding */ lightbulbSvg; }\n/* harmony export */ });\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n\nconst lightbulbSvg = (0,lit__WEBPACK_IMPORTED_MODULE_0__.svg) `<svg width=\"10\" height=\"10\" viewBox=\"0 0 10 10\" fill=\"none\" xmlns=\"http://www.w3.org/2000/svg\">\n<path fill-rule=\"evenodd\" clip-rule=\"evenodd\" d=\"M5.00177 1.78569C3.8179 1.78569 2.85819 2.74508 2.85819 3.92855C2.85819 4.52287 3.09928 5.05956 3.49077 5.4485L3.5005 5.45817C3.64381 5.60054 3.76515 5.72108 3.85631 5.81845C3.93747 5.90512 4.05255 6.03218 4.12889 6.1805C4.16999 6.26034 4.19 6.30843 4.21768 6.39385C4.22145 6.40546 4.22499 6.41703 4.22833 6.42855H5.77521C5.77854 6.41703 5.78208 6.40547 5.78585 6.39385C5.81353 6.30843 5.83354 6.26034 5.87464 6.1805C5.95098 6.03218 6.06606 5.90512 6.14722 5.81845C6.23839 5.72108 6.35973 5.60053 6.50304 5.45816L6.51276 5.4485C6.90425 5.05956 7.14534 4.52287 7.14534 3.92855C7.14534 2.74508 6.18563 1.78569 5.00177 1.78569ZM5.71629 7.85712H4.28724C4.28724 8.21403 4.28876 8.40985 4.30703 8.54571C4.30727 8.54748 4.30751 8.54921 4.30774 8.55091C4.30944 8.55115 4.31118 8.55138 4.31295 8.55162C4.44884 8.56989 4.64474 8.5714 5.00177 8.5714C5.3588 8.5714 5.55469 8.56989 5.69059 8.55162C5.69236 8.55138 5.69409 8.55115 5.69579 8.55091C5.69603 8.54921 5.69627 8.54748 5.6965 8.54571C5.71477 8.40985 5.71629 8.21403 5.71629 7.85712ZM2.85819 7.14283C2.85819 6.9948 2.85796 6.91114 2.8548 6.85032C2.85461 6.84656 2.85441 6.84309 2.85421 6.83988C2.84393 6.8282 2.83047 6.81334 2.81301 6.79469C2.74172 6.71856 2.63908 6.61643 2.48342 6.46178C1.83307 5.81566 1.42914 4.91859 1.42914 3.92855C1.42914 1.9561 3.02866 0.357117 5.00177 0.357117C6.97487 0.357117 8.57439 1.9561 8.57439 3.92855C8.57439 4.91859 8.17047 5.81566 7.52012 6.46178C7.36445 6.61643 7.26182 6.71856 7.19053 6.79469C7.17306 6.81334 7.1596 6.8282 7.14932 6.83988C7.14912 6.84309 7.14892 6.84656 7.14873 6.85032C7.14557 6.91114 7.14534 6.9948 7.14534 7.14283V7.85712C7.14534 7.87009 7.14535 7.88304 7.14535 7.89598C7.14541 8.19889 7.14547 8.49326 7.11281 8.73606C7.076 9.00978 6.98631 9.32212 6.72678 9.58156C6.46726 9.841 6.15481 9.93065 5.881 9.96745C5.63813 10.0001 5.34365 10 5.04064 9.99998C5.0277 9.99998 5.01474 9.99998 5.00177 9.99998C4.98879 9.99998 4.97583 9.99998 4.96289 9.99998C4.65988 10 4.36541 10.0001 4.12253 9.96745C3.84872 9.93065 3.53628 9.841 3.27675 9.58156C3.01722 9.32212 2.92753 9.00978 2.89072 8.73606C2.85807 8.49326 2.85812 8.19889 2.85818 7.89598C2.85819 7.88304 2.85819 7.87008 2.85819 7.85712V7.14283ZM7.1243 6.86977C7.12366 6.87069 7.1233 6.87116 7.12327 6.87119C7.12323 6.87123 7.12356 6.87076 7.1243 6.86977ZM2.88027 6.8712C2.88025 6.87119 2.87988 6.8707 2.87921 6.86975C2.87995 6.87072 2.88028 6.8712 2.88027 6.8712Z\" fill=\"#949E9E\"/>\n</svg>`;\n//# sourceMappingURL=lightbulb.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvYXNzZXRzL3N2Zy9saWdodGJ1bGIuanMiLCJtYXBwaW5ncyI6Ijs7Ozs7QUFBMEI7QUFDbkIscUJBQXFCLHdDQUFHO0FBQy9CO0FBQ0E7QUFDQSIsInNvdXJjZXMiOlsid2VicGFjazovL19OX0UvLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvYXNzZXRzL3N2Zy9saWdodGJ1bGIuanM/Mzg1ZSJdLCJzb3VyY2VzQ29udGVudCI6WyJpbXBvcnQgeyBzdmcgfSBmcm9tICdsaXQnO1xuZXhwb3J0IGNvbnN0IGxpZ2h0YnVsYlN2ZyA9IHN2ZyBgPHN2ZyB3aWR0aD1cIjEwXCIgaGVpZ2h0PVwiMTBcIiB2aWV3Qm94PVwiMCAwIDEwIDEwXCIgZmlsbD1cIm5vbmVcIiB4bWxucz1cImh0dHA6Ly93d3cudzMub3JnLzIwMDAvc3ZnXCI+XG48cGF0aCBmaWxsLXJ1bGU9XCJldmVub2RkXCIgY2xpcC1ydWxlPVwiZXZlbm9kZFwiIGQ9XCJNNS4wMDE3NyAxLjc4NTY5QzMuODE3OSAxLjc4NTY5IDIuODU4MTkgMi43NDUwOCAyLjg1ODE5IDMuOTI4NTVDMi44NTgxOSA0LjUyMjg3IDMuMDk5MjggNS4wNTk1NiAzLjQ5MDc3IDUuNDQ4NUwzLjUwMDUgNS40NTgxN0MzLjY0MzgxIDUuNjAwNTQgMy43NjUxNSA1LjcyMTA4IDMuODU2MzEgNS44MTg0NUMzLjkzNzQ3IDUuOTA1MTIgNC4wNTI1NSA2LjAzMjE4IDQuMTI4ODkgNi4xODA1QzQuMTY5OTkgNi4yNjAzNCA0LjE5IDYuMzA4NDMgNC4yMTc2OCA2LjM5Mzg1QzQuMjIxNDUgNi40MDU0NiA0LjIyNDk5IDYuNDE3MDMgNC4yMjgzMyA2LjQyODU1SDUuNzc1MjFDNS43Nzg1NCA2LjQxNzAzIDUuNzgyMDggNi40MDU0NyA1Ljc4NTg1IDYuMzkzODVDNS44MTM1MyA2LjMwODQzIDUuODMzNTQgNi4yNjAzNCA1Ljg3NDY0IDYuMTgwNUM1Ljk1MDk4IDYuMDMyMTggNi4wNjYwNiA1LjkwNTEyIDYuMTQ3MjIgNS44MTg0NUM2LjIzODM5IDUuNzIxMDggNi4zNTk3MyA1LjYwMDUzIDYuNTAzMDQgNS40NTgxNkw2LjUxMjc2IDUuNDQ4NUM2LjkwNDI1IDUuMDU5NTYgNy4xNDUzNCA0LjUyMjg3IDcuMTQ1MzQgMy45Mjg1NUM3LjE0NTM0IDIuNzQ1MDggNi4xODU2MyAxLjc4NTY5IDUuMDAxNzcgMS43ODU2OVpNNS43MTYyOSA3Ljg1NzEySDQuMjg3MjRDNC4yODcyNCA4LjIxNDAzIDQuMjg4NzYgOC40MDk4NSA0LjMwNzAzIDguNTQ1NzFDNC4zMDcyNyA4LjU0NzQ4IDQuMzA3NTEgOC41NDkyMSA0LjMwNzc0IDguNTUwOTFDNC4zMDk0NCA4LjU1MTE1IDQuMzExMTggOC41NTEzOCA0LjMxMjk1IDguNTUxNjJDNC40NDg4NCA4LjU2OTg5IDQuNjQ0NzQgOC41NzE0IDUuMDAxNzcgOC41NzE0QzUuMzU4OCA4LjU3MTQgNS41NTQ2OSA4LjU2OTg5IDUuNjkwNTkgOC41NTE2MkM1LjY5MjM2IDguNTUxMzggNS42OTQwOSA4LjU1MTE1IDUuNjk1NzkgOC41NTA5MUM1LjY5NjAzIDguNTQ5MjEgNS42OTYyNyA4LjU0NzQ4IDUuNjk2NSA4LjU0NTcxQzUuNzE0NzcgOC40MDk4NSA1LjcxNjI5IDguMjE0MDMgNS43MTYyOSA3Ljg1NzEyWk0yLjg1ODE5IDcuMTQyODNDMi44NTgxOSA2Ljk5NDggMi44NTc5NiA2LjkxMTE0IDIuODU0OCA2Ljg1MDMyQzIuODU0NjEgNi44NDY1NiAyLjg1NDQxIDYuODQzMDkgMi44NTQyMSA2LjgzOTg4QzIuODQzOTMgNi44MjgyIDIuODMwNDcgNi44MTMzNCAyLjgxMzAxIDYuNzk0NjlDMi43NDE3MiA2LjcxODU2IDIuNjM5MDggNi42MTY0MyAyLjQ4MzQyIDYuNDYxNzhDMS44MzMwNyA1LjgxNTY2IDEuNDI5MTQgNC45MTg1OSAxLjQyOTE0IDMuOTI4NTVDMS40MjkxNCAxLjk1NjEgMy4wMjg2NiAwLjM1NzExNyA1LjAwMTc3IDAuMzU3MTE3QzYuOTc0ODcgMC4zNTcxMTcgOC41NzQzOSAxLjk1NjEgOC41NzQzOSAzLjkyODU1QzguNTc0MzkgNC45MTg1OSA4LjE3MDQ3IDUuODE1NjYgNy41MjAxMiA2LjQ2MTc4QzcuMzY0NDUgNi42MTY0MyA3LjI2MTgyIDYuNzE4NTYgNy4xOTA1MyA2Ljc5NDY5QzcuMTczMDYgNi44MTMzNCA3LjE1OTYgNi44MjgyIDcuMTQ5MzIgNi44Mzk4OEM3LjE0OTEyIDYuODQzMDkgNy4xNDg5MiA2Ljg0NjU2IDcuMTQ4NzMgNi44NTAzMkM3LjE0NTU3IDYuOTExMTQgNy4xNDUzNCA2Ljk5NDggNy4xNDUzNCA3LjE0MjgzVjcuODU3MTJDNy4xNDUzNCA3Ljg3MDA5IDcuMTQ1MzUgNy44ODMwNCA3LjE0NTM1IDcuODk1OThDNy4xNDU0MSA4LjE5ODg5IDcuMTQ1NDcgOC40OTMyNiA3LjExMjgxIDguNzM2MDZDNy4wNzYgOS4wMDk3OCA2Ljk4NjMxIDkuMzIyMTIgNi43MjY3OCA5LjU4MTU2QzYuNDY3MjYgOS44NDEgNi4xNTQ4MSA5LjkzMDY1IDUuODgxIDkuOTY3NDVDNS42MzgxMyAxMC4wMDAxIDUuMzQzNjUgMTAgNS4wNDA2NCA5Ljk5OTk4QzUuMDI3NyA5Ljk5OTk4IDUuMDE0NzQgOS45OTk5OCA1LjAwMTc3IDkuOTk5OThDNC45ODg3OSA5Ljk5OTk4IDQuOTc1ODMgOS45OTk5OCA0Ljk2Mjg5IDkuOTk5OThDNC42NTk4OCAxMCA0LjM2NTQxIDEwLjAwMDEgNC4xMjI1MyA5Ljk2NzQ1QzMuODQ4NzIgOS45MzA2NSAzLjUzNjI4IDkuODQxIDMuMjc2NzUgOS41ODE1NkMzLjAxNzIyIDkuMzIyMTIgMi45Mjc1MyA5LjAwOTc4IDIuODkwNzIgOC43MzYwNkMyLjg1ODA3IDguNDkzMjYgMi44NTgxMiA4LjE5ODg5IDIuODU4MTggNy44OTU5OEMyLjg1ODE5IDcuODgzMDQgMi44NTgxOSA3Ljg3MDA4IDIuODU4MTkgNy44NTcxMlY3LjE0MjgzWk03LjEyNDMgNi44Njk3N0M3LjEyMzY2IDYuODcwNjkgNy4xMjMzIDYuODcxMTYgNy4xMjMyNyA2Ljg3MTE5QzcuMTIzMjMgNi44NzEyMyA3LjEyMzU2IDYuODcwNzYgNy4xMjQzIDYuODY5NzdaTTIuODgwMjcgNi44NzEyQzIuODgwMjUgNi44NzExOSAyLjg3OTg4IDYuODcwNyAyLjg3OTIxIDYuODY5NzVDMi44Nzk5NSA2Ljg3MDcyIDIuODgwMjggNi44NzEyIDIuODgwMjcgNi44NzEyWlwiIGZpbGw9XCIjOTQ5RTlFXCIvPlxuPC9zdmc+YDtcbi8vIyBzb3VyY2VNYXBwaW5nVVJMPWxpZ2h0YnVsYi5qcy5tYXAiXSwibmFtZXMiOltdLCJzb3VyY2VSb290IjoiIn0=\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/lightbulb.js\n"));
-
-/***/ })
-
-}]);
\ No newline at end of file
@@ -1,22 +0,0 @@
-"use strict";
-/*
- * ATTENTION: An "eval-source-map" devtool has been used.
- * This devtool is neither made for production nor for readable output files.
- * It uses "eval()" calls to create a separate source file with attached SourceMaps in the browser devtools.
- * If you are trying to read the output file, select a different devtool (https://webpack.js.org/configuration/devtool/)
- * or disable the default devtool with "devtool: false".
- * If you are looking for production-ready output files, see mode: "production" (https://webpack.js.org/configuration/mode/).
- */
-(self["webpackChunk_N_E"] = self["webpackChunk_N_E"] || []).push([["node_modules_reown_appkit-ui_dist_esm_src_assets_svg_mail_js"],{
-
-/***/ "./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/mail.js":
-/*!***********************************************************************!*\
-  !*** ./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/mail.js ***!
-  \***********************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   mailSvg: function() { return /* binding */ mailSvg; }\n/* harmony export */ });\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n\nconst mailSvg = (0,lit__WEBPACK_IMPORTED_MODULE_0__.svg) `<svg fill=\"none\" viewBox=\"0 0 16 16\">\n  <path\n    fill=\"currentColor\"\n    fill-rule=\"evenodd\"\n    d=\"M4.83 1.34h6.34c.68 0 1.26 0 1.73.04.5.05.97.15 1.42.4.52.3.95.72 1.24 1.24.26.45.35.92.4 1.42.04.47.04 1.05.04 1.73v3.71c0 .69 0 1.26-.04 1.74-.05.5-.14.97-.4 1.41-.3.52-.72.95-1.24 1.25-.45.25-.92.35-1.42.4-.47.03-1.05.03-1.73.03H4.83c-.68 0-1.26 0-1.73-.04-.5-.04-.97-.14-1.42-.4-.52-.29-.95-.72-1.24-1.24a3.39 3.39 0 0 1-.4-1.41A20.9 20.9 0 0 1 0 9.88v-3.7c0-.7 0-1.27.04-1.74.05-.5.14-.97.4-1.42.3-.52.72-.95 1.24-1.24.45-.25.92-.35 1.42-.4.47-.04 1.05-.04 1.73-.04ZM3.28 3.38c-.36.03-.51.08-.6.14-.21.11-.39.29-.5.5a.8.8 0 0 0-.08.19l5.16 3.44c.45.3 1.03.3 1.48 0L13.9 4.2a.79.79 0 0 0-.08-.2c-.11-.2-.29-.38-.5-.5-.09-.05-.24-.1-.6-.13-.37-.04-.86-.04-1.6-.04H4.88c-.73 0-1.22 0-1.6.04ZM14 6.54 9.85 9.31a3.33 3.33 0 0 1-3.7 0L2 6.54v3.3c0 .74 0 1.22.03 1.6.04.36.1.5.15.6.11.2.29.38.5.5.09.05.24.1.6.14.37.03.86.03 1.6.03h6.25c.73 0 1.22 0 1.6-.03.35-.03.5-.09.6-.14.2-.12.38-.3.5-.5.05-.1.1-.24.14-.6.03-.38.03-.86.03-1.6v-3.3Z\"\n    clip-rule=\"evenodd\"\n  />\n</svg>`;\n//# sourceMappingURL=mail.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvYXNzZXRzL3N2Zy9tYWlsLmpzIiwibWFwcGluZ3MiOiI7Ozs7O0FBQTBCO0FBQ25CLGdCQUFnQix3Q0FBRztBQUMxQjtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBIiwic291cmNlcyI6WyJ3ZWJwYWNrOi8vX05fRS8uL25vZGVfbW9kdWxlcy9AcmVvd24vYXBwa2l0LXVpL2Rpc3QvZXNtL3NyYy9hc3NldHMvc3ZnL21haWwuanM/ODA1YiJdLCJzb3VyY2VzQ29udGVudCI6WyJpbXBvcnQgeyBzdmcgfSBmcm9tICdsaXQnO1xuZXhwb3J0IGNvbnN0IG1haWxTdmcgPSBzdmcgYDxzdmcgZmlsbD1cIm5vbmVcIiB2aWV3Qm94PVwiMCAwIDE2IDE2XCI+XG4gIDxwYXRoXG4gICAgZmlsbD1cImN1cnJlbnRDb2xvclwiXG4gICAgZmlsbC1ydWxlPVwiZXZlbm9kZFwiXG4gICAgZD1cIk00LjgzIDEuMzRoNi4zNGMuNjggMCAxLjI2IDAgMS43My4wNC41LjA1Ljk3LjE1IDEuNDIuNC41Mi4zLjk1LjcyIDEuMjQgMS4yNC4yNi40NS4zNS45Mi40IDEuNDIuMDQuNDcuMDQgMS4wNS4wNCAxLjczdjMuNzFjMCAuNjkgMCAxLjI2LS4wNCAxLjc0LS4wNS41LS4xNC45Ny0uNCAxLjQxLS4zLjUyLS43Mi45NS0xLjI0IDEuMjUtLjQ1LjI1LS45Mi4zNS0xLjQyLjQtLjQ3LjAzLTEuMDUuMDMtMS43My4wM0g0LjgzYy0uNjggMC0xLjI2IDAtMS43My0uMDQtLjUtLjA0LS45Ny0uMTQtMS40Mi0uNC0uNTItLjI5LS45NS0uNzItMS4yNC0xLjI0YTMuMzkgMy4zOSAwIDAgMS0uNC0xLjQxQTIwLjkgMjAuOSAwIDAgMSAwIDkuODh2LTMuN2MwLS43IDAtMS4yNy4wNC0xLjc0LjA1LS41LjE0LS45Ny40LTEuNDIuMy0uNTIuNzItLjk1IDEuMjQtMS4yNC40NS0uMjUuOTItLjM1IDEuNDItLjQuNDctLjA0IDEuMDUtLjA0IDEuNzMtLjA0Wk0zLjI4IDMuMzhjLS4zNi4wMy0uNTEuMDgtLjYuMTQtLjIxLjExLS4zOS4yOS0uNS41YS44LjggMCAwIDAtLjA4LjE5bDUuMTYgMy40NGMuNDUuMyAxLjAzLjMgMS40OCAwTDEzLjkgNC4yYS43OS43OSAwIDAgMC0uMDgtLjJjLS4xMS0uMi0uMjktLjM4LS41LS41LS4wOS0uMDUtLjI0LS4xLS42LS4xMy0uMzctLjA0LS44Ni0uMDQtMS42LS4wNEg0Ljg4Yy0uNzMgMC0xLjIyIDAtMS42LjA0Wk0xNCA2LjU0IDkuODUgOS4zMWEzLjMzIDMuMzMgMCAwIDEtMy43IDBMMiA2LjU0djMuM2MwIC43NCAwIDEuMjIuMDMgMS42LjA0LjM2LjEuNS4xNS42LjExLjIuMjkuMzguNS41LjA5LjA1LjI0LjEuNi4xNC4zNy4wMy44Ni4wMyAxLjYuMDNoNi4yNWMuNzMgMCAxLjIyIDAgMS42LS4wMy4zNS0uMDMuNS0uMDkuNi0uMTQuMi0uMTIuMzgtLjMuNS0uNS4wNS0uMS4xLS4yNC4xNC0uNi4wMy0uMzguMDMtLjg2LjAzLTEuNnYtMy4zWlwiXG4gICAgY2xpcC1ydWxlPVwiZXZlbm9kZFwiXG4gIC8+XG48L3N2Zz5gO1xuLy8jIHNvdXJjZU1hcHBpbmdVUkw9bWFpbC5qcy5tYXAiXSwibmFtZXMiOltdLCJzb3VyY2VSb290IjoiIn0=\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/mail.js\n"));
-
-/***/ })
-
-}]);
\ No newline at end of file
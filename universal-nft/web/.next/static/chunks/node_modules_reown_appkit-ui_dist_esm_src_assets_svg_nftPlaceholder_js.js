@@ -1,22 +0,0 @@
-"use strict";
-/*
- * ATTENTION: An "eval-source-map" devtool has been used.
- * This devtool is neither made for production nor for readable output files.
- * It uses "eval()" calls to create a separate source file with attached SourceMaps in the browser devtools.
- * If you are trying to read the output file, select a different devtool (https://webpack.js.org/configuration/devtool/)
- * or disable the default devtool with "devtool: false".
- * If you are looking for production-ready output files, see mode: "production" (https://webpack.js.org/configuration/mode/).
- */
-(self["webpackChunk_N_E"] = self["webpackChunk_N_E"] || []).push([["node_modules_reown_appkit-ui_dist_esm_src_assets_svg_nftPlaceholder_js"],{
-
-/***/ "./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/nftPlaceholder.js":
-/*!*********************************************************************************!*\
-  !*** ./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/nftPlaceholder.js ***!
-  \*********************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   nftPlaceholderSvg: function() { return /* binding */ nftPlaceholderSvg; }\n/* harmony export */ });\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n\nconst nftPlaceholderSvg = (0,lit__WEBPACK_IMPORTED_MODULE_0__.svg) `<svg fill=\"none\" viewBox=\"0 0 20 20\">\n  <path\n    fill=\"currentColor\"\n    fill-rule=\"evenodd\"\n    d=\"M9.13 1h1.71c1.46 0 2.63 0 3.56.1.97.1 1.8.33 2.53.85a5 5 0 0 1 1.1 1.11c.53.73.75 1.56.86 2.53.1.93.1 2.1.1 3.55v1.72c0 1.45 0 2.62-.1 3.55-.1.97-.33 1.8-.86 2.53a5 5 0 0 1-1.1 1.1c-.73.53-1.56.75-2.53.86-.93.1-2.1.1-3.55.1H9.13c-1.45 0-2.62 0-3.56-.1-.96-.1-1.8-.33-2.52-.85a5 5 0 0 1-1.1-1.11 5.05 5.05 0 0 1-.86-2.53c-.1-.93-.1-2.1-.1-3.55V9.14c0-1.45 0-2.62.1-3.55.1-.97.33-1.8.85-2.53a5 5 0 0 1 1.1-1.1 5.05 5.05 0 0 1 2.53-.86C6.51 1 7.67 1 9.13 1ZM5.79 3.09a3.1 3.1 0 0 0-1.57.48 3 3 0 0 0-.66.67c-.24.32-.4.77-.48 1.56-.1.82-.1 1.88-.1 3.4v1.6c0 1.15 0 2.04.05 2.76l.41-.42c.5-.5.93-.92 1.32-1.24.41-.33.86-.6 1.43-.7a3 3 0 0 1 .94 0c.35.06.66.2.95.37a17.11 17.11 0 0 0 .8.45c.1-.08.2-.2.41-.4l.04-.03a27 27 0 0 1 1.95-1.84 4.03 4.03 0 0 1 1.91-.94 4 4 0 0 1 1.25 0c.73.11 1.33.46 1.91.94l.64.55V9.2c0-1.52 0-2.58-.1-3.4a3.1 3.1 0 0 0-.48-1.56 3 3 0 0 0-.66-.67 3.1 3.1 0 0 0-1.56-.48C13.37 3 12.3 3 10.79 3h-1.6c-1.52 0-2.59 0-3.4.09Zm11.18 10-.04-.05a26.24 26.24 0 0 0-1.83-1.74c-.45-.36-.73-.48-.97-.52a2 2 0 0 0-.63 0c-.24.04-.51.16-.97.52-.46.38-1.01.93-1.83 1.74l-.02.02c-.17.18-.34.34-.49.47a2.04 2.04 0 0 1-1.08.5 1.97 1.97 0 0 1-1.25-.27l-.79-.46-.02-.02a.65.65 0 0 0-.24-.1 1 1 0 0 0-.31 0c-.08.02-.21.06-.49.28-.3.24-.65.59-1.2 1.14l-.56.56-.65.66a3 3 0 0 0 .62.6c.33.24.77.4 1.57.49.81.09 1.88.09 3.4.09h1.6c1.52 0 2.58 0 3.4-.09a3.1 3.1 0 0 0 1.56-.48 3 3 0 0 0 .66-.67c.24-.32.4-.77.49-1.56l.07-1.12Zm-8.02-1.03ZM4.99 7a2 2 0 1 1 4 0 2 2 0 0 1-4 0Z\"\n    clip-rule=\"evenodd\"\n  />\n</svg>`;\n//# sourceMappingURL=nftPlaceholder.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvYXNzZXRzL3N2Zy9uZnRQbGFjZWhvbGRlci5qcyIsIm1hcHBpbmdzIjoiOzs7OztBQUEwQjtBQUNuQiwwQkFBMEIsd0NBQUc7QUFDcEM7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQSIsInNvdXJjZXMiOlsid2VicGFjazovL19OX0UvLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvYXNzZXRzL3N2Zy9uZnRQbGFjZWhvbGRlci5qcz8yNjg2Il0sInNvdXJjZXNDb250ZW50IjpbImltcG9ydCB7IHN2ZyB9IGZyb20gJ2xpdCc7XG5leHBvcnQgY29uc3QgbmZ0UGxhY2Vob2xkZXJTdmcgPSBzdmcgYDxzdmcgZmlsbD1cIm5vbmVcIiB2aWV3Qm94PVwiMCAwIDIwIDIwXCI+XG4gIDxwYXRoXG4gICAgZmlsbD1cImN1cnJlbnRDb2xvclwiXG4gICAgZmlsbC1ydWxlPVwiZXZlbm9kZFwiXG4gICAgZD1cIk05LjEzIDFoMS43MWMxLjQ2IDAgMi42MyAwIDMuNTYuMS45Ny4xIDEuOC4zMyAyLjUzLjg1YTUgNSAwIDAgMSAxLjEgMS4xMWMuNTMuNzMuNzUgMS41Ni44NiAyLjUzLjEuOTMuMSAyLjEuMSAzLjU1djEuNzJjMCAxLjQ1IDAgMi42Mi0uMSAzLjU1LS4xLjk3LS4zMyAxLjgtLjg2IDIuNTNhNSA1IDAgMCAxLTEuMSAxLjFjLS43My41My0xLjU2Ljc1LTIuNTMuODYtLjkzLjEtMi4xLjEtMy41NS4xSDkuMTNjLTEuNDUgMC0yLjYyIDAtMy41Ni0uMS0uOTYtLjEtMS44LS4zMy0yLjUyLS44NWE1IDUgMCAwIDEtMS4xLTEuMTEgNS4wNSA1LjA1IDAgMCAxLS44Ni0yLjUzYy0uMS0uOTMtLjEtMi4xLS4xLTMuNTVWOS4xNGMwLTEuNDUgMC0yLjYyLjEtMy41NS4xLS45Ny4zMy0xLjguODUtMi41M2E1IDUgMCAwIDEgMS4xLTEuMSA1LjA1IDUuMDUgMCAwIDEgMi41My0uODZDNi41MSAxIDcuNjcgMSA5LjEzIDFaTTUuNzkgMy4wOWEzLjEgMy4xIDAgMCAwLTEuNTcuNDggMyAzIDAgMCAwLS42Ni42N2MtLjI0LjMyLS40Ljc3LS40OCAxLjU2LS4xLjgyLS4xIDEuODgtLjEgMy40djEuNmMwIDEuMTUgMCAyLjA0LjA1IDIuNzZsLjQxLS40MmMuNS0uNS45My0uOTIgMS4zMi0xLjI0LjQxLS4zMy44Ni0uNiAxLjQzLS43YTMgMyAwIDAgMSAuOTQgMGMuMzUuMDYuNjYuMi45NS4zN2ExNy4xMSAxNy4xMSAwIDAgMCAuOC40NWMuMS0uMDguMi0uMi40MS0uNGwuMDQtLjAzYTI3IDI3IDAgMCAxIDEuOTUtMS44NCA0LjAzIDQuMDMgMCAwIDEgMS45MS0uOTQgNCA0IDAgMCAxIDEuMjUgMGMuNzMuMTEgMS4zMy40NiAxLjkxLjk0bC42NC41NVY5LjJjMC0xLjUyIDAtMi41OC0uMS0zLjRhMy4xIDMuMSAwIDAgMC0uNDgtMS41NiAzIDMgMCAwIDAtLjY2LS42NyAzLjEgMy4xIDAgMCAwLTEuNTYtLjQ4QzEzLjM3IDMgMTIuMyAzIDEwLjc5IDNoLTEuNmMtMS41MiAwLTIuNTkgMC0zLjQuMDlabTExLjE4IDEwLS4wNC0uMDVhMjYuMjQgMjYuMjQgMCAwIDAtMS44My0xLjc0Yy0uNDUtLjM2LS43My0uNDgtLjk3LS41MmEyIDIgMCAwIDAtLjYzIDBjLS4yNC4wNC0uNTEuMTYtLjk3LjUyLS40Ni4zOC0xLjAxLjkzLTEuODMgMS43NGwtLjAyLjAyYy0uMTcuMTgtLjM0LjM0LS40OS40N2EyLjA0IDIuMDQgMCAwIDEtMS4wOC41IDEuOTcgMS45NyAwIDAgMS0xLjI1LS4yN2wtLjc5LS40Ni0uMDItLjAyYS42NS42NSAwIDAgMC0uMjQtLjEgMSAxIDAgMCAwLS4zMSAwYy0uMDguMDItLjIxLjA2LS40OS4yOC0uMy4yNC0uNjUuNTktMS4yIDEuMTRsLS41Ni41Ni0uNjUuNjZhMyAzIDAgMCAwIC42Mi42Yy4zMy4yNC43Ny40IDEuNTcuNDkuODEuMDkgMS44OC4wOSAzLjQuMDloMS42YzEuNTIgMCAyLjU4IDAgMy40LS4wOWEzLjEgMy4xIDAgMCAwIDEuNTYtLjQ4IDMgMyAwIDAgMCAuNjYtLjY3Yy4yNC0uMzIuNC0uNzcuNDktMS41NmwuMDctMS4xMlptLTguMDItMS4wM1pNNC45OSA3YTIgMiAwIDEgMSA0IDAgMiAyIDAgMCAxLTQgMFpcIlxuICAgIGNsaXAtcnVsZT1cImV2ZW5vZGRcIlxuICAvPlxuPC9zdmc+YDtcbi8vIyBzb3VyY2VNYXBwaW5nVVJMPW5mdFBsYWNlaG9sZGVyLmpzLm1hcCJdLCJuYW1lcyI6W10sInNvdXJjZVJvb3QiOiIifQ==\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/nftPlaceholder.js\n"));
-
-/***/ })
-
-}]);
\ No newline at end of file
@@ -1,22 +0,0 @@
-"use strict";
-/*
- * ATTENTION: An "eval-source-map" devtool has been used.
- * This devtool is neither made for production nor for readable output files.
- * It uses "eval()" calls to create a separate source file with attached SourceMaps in the browser devtools.
- * If you are trying to read the output file, select a different devtool (https://webpack.js.org/configuration/devtool/)
- * or disable the default devtool with "devtool: false".
- * If you are looking for production-ready output files, see mode: "production" (https://webpack.js.org/configuration/mode/).
- */
-(self["webpackChunk_N_E"] = self["webpackChunk_N_E"] || []).push([["node_modules_reown_appkit-ui_dist_esm_src_assets_svg_recycle-horizontal_js"],{
-
-/***/ "./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/recycle-horizontal.js":
-/*!*************************************************************************************!*\
-  !*** ./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/recycle-horizontal.js ***!
-  \*************************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   recycleHorizontalSvg: function() { return /* binding */ recycleHorizontalSvg; }\n/* harmony export */ });\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n\nconst recycleHorizontalSvg = (0,lit__WEBPACK_IMPORTED_MODULE_0__.svg) `<svg\n  fill=\"none\"\n  viewBox=\"0 0 21 20\"\n>\n  <path\n    fill=\"currentColor\"\n    d=\"M8.8071 0.292893C9.19763 0.683417 9.19763 1.31658 8.8071 1.70711L6.91421 3.6H11.8404C14.3368 3.6 16.5533 5.1975 17.3427 7.56588L17.4487 7.88377C17.6233 8.40772 17.3402 8.97404 16.8162 9.14868C16.2923 9.32333 15.726 9.04017 15.5513 8.51623L15.4453 8.19834C14.9281 6.64664 13.476 5.6 11.8404 5.6H6.91421L8.8071 7.49289C9.19763 7.88342 9.19763 8.51658 8.8071 8.90711C8.41658 9.29763 7.78341 9.29763 7.39289 8.90711L3.79289 5.30711C3.40236 4.91658 3.40236 4.28342 3.79289 3.89289L7.39289 0.292893C7.78341 -0.0976311 8.41658 -0.0976311 8.8071 0.292893ZM4.18377 10.8513C4.70771 10.6767 5.27403 10.9598 5.44868 11.4838L5.55464 11.8017C6.07188 13.3534 7.52401 14.4 9.15964 14.4L14.0858 14.4L12.1929 12.5071C11.8024 12.1166 11.8024 11.4834 12.1929 11.0929C12.5834 10.7024 13.2166 10.7024 13.6071 11.0929L17.2071 14.6929C17.5976 15.0834 17.5976 15.7166 17.2071 16.1071L13.6071 19.7071C13.2166 20.0976 12.5834 20.0976 12.1929 19.7071C11.8024 19.3166 11.8024 18.6834 12.1929 18.2929L14.0858 16.4L9.15964 16.4C6.66314 16.4 4.44674 14.8025 3.65728 12.4341L3.55131 12.1162C3.37667 11.5923 3.65983 11.026 4.18377 10.8513Z\"\n  /></svg\n>`;\n//# sourceMappingURL=recycle-horizontal.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvYXNzZXRzL3N2Zy9yZWN5Y2xlLWhvcml6b250YWwuanMiLCJtYXBwaW5ncyI6Ijs7Ozs7QUFBMEI7QUFDbkIsNkJBQTZCLHdDQUFHO0FBQ3ZDO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQSIsInNvdXJjZXMiOlsid2VicGFjazovL19OX0UvLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvYXNzZXRzL3N2Zy9yZWN5Y2xlLWhvcml6b250YWwuanM/ZDRjZiJdLCJzb3VyY2VzQ29udGVudCI6WyJpbXBvcnQgeyBzdmcgfSBmcm9tICdsaXQnO1xuZXhwb3J0IGNvbnN0IHJlY3ljbGVIb3Jpem9udGFsU3ZnID0gc3ZnIGA8c3ZnXG4gIGZpbGw9XCJub25lXCJcbiAgdmlld0JveD1cIjAgMCAyMSAyMFwiXG4+XG4gIDxwYXRoXG4gICAgZmlsbD1cImN1cnJlbnRDb2xvclwiXG4gICAgZD1cIk04LjgwNzEgMC4yOTI4OTNDOS4xOTc2MyAwLjY4MzQxNyA5LjE5NzYzIDEuMzE2NTggOC44MDcxIDEuNzA3MTFMNi45MTQyMSAzLjZIMTEuODQwNEMxNC4zMzY4IDMuNiAxNi41NTMzIDUuMTk3NSAxNy4zNDI3IDcuNTY1ODhMMTcuNDQ4NyA3Ljg4Mzc3QzE3LjYyMzMgOC40MDc3MiAxNy4zNDAyIDguOTc0MDQgMTYuODE2MiA5LjE0ODY4QzE2LjI5MjMgOS4zMjMzMyAxNS43MjYgOS4wNDAxNyAxNS41NTEzIDguNTE2MjNMMTUuNDQ1MyA4LjE5ODM0QzE0LjkyODEgNi42NDY2NCAxMy40NzYgNS42IDExLjg0MDQgNS42SDYuOTE0MjFMOC44MDcxIDcuNDkyODlDOS4xOTc2MyA3Ljg4MzQyIDkuMTk3NjMgOC41MTY1OCA4LjgwNzEgOC45MDcxMUM4LjQxNjU4IDkuMjk3NjMgNy43ODM0MSA5LjI5NzYzIDcuMzkyODkgOC45MDcxMUwzLjc5Mjg5IDUuMzA3MTFDMy40MDIzNiA0LjkxNjU4IDMuNDAyMzYgNC4yODM0MiAzLjc5Mjg5IDMuODkyODlMNy4zOTI4OSAwLjI5Mjg5M0M3Ljc4MzQxIC0wLjA5NzYzMTEgOC40MTY1OCAtMC4wOTc2MzExIDguODA3MSAwLjI5Mjg5M1pNNC4xODM3NyAxMC44NTEzQzQuNzA3NzEgMTAuNjc2NyA1LjI3NDAzIDEwLjk1OTggNS40NDg2OCAxMS40ODM4TDUuNTU0NjQgMTEuODAxN0M2LjA3MTg4IDEzLjM1MzQgNy41MjQwMSAxNC40IDkuMTU5NjQgMTQuNEwxNC4wODU4IDE0LjRMMTIuMTkyOSAxMi41MDcxQzExLjgwMjQgMTIuMTE2NiAxMS44MDI0IDExLjQ4MzQgMTIuMTkyOSAxMS4wOTI5QzEyLjU4MzQgMTAuNzAyNCAxMy4yMTY2IDEwLjcwMjQgMTMuNjA3MSAxMS4wOTI5TDE3LjIwNzEgMTQuNjkyOUMxNy41OTc2IDE1LjA4MzQgMTcuNTk3NiAxNS43MTY2IDE3LjIwNzEgMTYuMTA3MUwxMy42MDcxIDE5LjcwNzFDMTMuMjE2NiAyMC4wOTc2IDEyLjU4MzQgMjAuMDk3NiAxMi4xOTI5IDE5LjcwNzFDMTEuODAyNCAxOS4zMTY2IDExLjgwMjQgMTguNjgzNCAxMi4xOTI5IDE4LjI5MjlMMTQuMDg1OCAxNi40TDkuMTU5NjQgMTYuNEM2LjY2MzE0IDE2LjQgNC40NDY3NCAxNC44MDI1IDMuNjU3MjggMTIuNDM0MUwzLjU1MTMxIDEyLjExNjJDMy4zNzY2NyAxMS41OTIzIDMuNjU5ODMgMTEuMDI2IDQuMTgzNzcgMTAuODUxM1pcIlxuICAvPjwvc3ZnXG4+YDtcbi8vIyBzb3VyY2VNYXBwaW5nVVJMPXJlY3ljbGUtaG9yaXpvbnRhbC5qcy5tYXAiXSwibmFtZXMiOltdLCJzb3VyY2VSb290IjoiIn0=\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/recycle-horizontal.js\n"));
-
-/***/ })
-
-}]);
\ No newline at end of file
@@ -1,22 +0,0 @@
-"use strict";
-/*
- * ATTENTION: An "eval-source-map" devtool has been used.
- * This devtool is neither made for production nor for readable output files.
- * It uses "eval()" calls to create a separate source file with attached SourceMaps in the browser devtools.
- * If you are trying to read the output file, select a different devtool (https://webpack.js.org/configuration/devtool/)
- * or disable the default devtool with "devtool: false".
- * If you are looking for production-ready output files, see mode: "production" (https://webpack.js.org/configuration/mode/).
- */
-(self["webpackChunk_N_E"] = self["webpackChunk_N_E"] || []).push([["node_modules_reown_appkit-ui_dist_esm_src_assets_svg_send_js"],{
-
-/***/ "./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/send.js":
-/*!***********************************************************************!*\
-  !*** ./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/send.js ***!
-  \***********************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   sendSvg: function() { return /* binding */ sendSvg; }\n/* harmony export */ });\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n\nconst sendSvg = (0,lit__WEBPACK_IMPORTED_MODULE_0__.svg) `<svg fill=\"none\" viewBox=\"0 0 21 20\">\n  <path\n    fill=\"currentColor\"\n    fill-rule=\"evenodd\"\n    d=\"M14.3808 4.34812C13.72 4.47798 12.8501 4.7587 11.5748 5.17296L9.00869 6.00646C6.90631 6.68935 5.40679 7.17779 4.38121 7.63178C3.87166 7.85734 3.5351 8.05091 3.32022 8.22035C3.11183 8.38466 3.07011 8.48486 3.05969 8.51817C2.98058 8.77103 2.98009 9.04195 3.05831 9.29509C3.06861 9.32844 3.10998 9.42878 3.31777 9.59384C3.53205 9.76404 3.86792 9.95881 4.37667 10.1862C5.29287 10.5957 6.58844 11.0341 8.35529 11.6164L10.8876 8.59854C11.2426 8.17547 11.8733 8.12028 12.2964 8.47528C12.7195 8.83029 12.7746 9.46104 12.4196 9.88412L9.88738 12.9019C10.7676 14.5408 11.4244 15.7406 11.9867 16.5718C12.299 17.0333 12.5491 17.3303 12.7539 17.5117C12.9526 17.6877 13.0586 17.711 13.0932 17.7154C13.3561 17.7484 13.6228 17.7009 13.8581 17.5791C13.8891 17.563 13.9805 17.5046 14.1061 17.2708C14.2357 17.0298 14.3679 16.6647 14.5015 16.1237C14.7705 15.0349 14.9912 13.4733 15.2986 11.2843L15.6738 8.61249C15.8603 7.28456 15.9857 6.37917 15.9989 5.7059C16.012 5.03702 15.9047 4.8056 15.8145 4.69183C15.7044 4.55297 15.5673 4.43792 15.4114 4.35365C15.2837 4.28459 15.0372 4.2191 14.3808 4.34812ZM7.99373 13.603C6.11919 12.9864 4.6304 12.4902 3.5606 12.0121C2.98683 11.7557 2.4778 11.4808 2.07383 11.1599C1.66337 10.8339 1.31312 10.4217 1.14744 9.88551C0.949667 9.24541 0.950886 8.56035 1.15094 7.92096C1.31852 7.38534 1.67024 6.97442 2.08185 6.64985C2.48697 6.33041 2.99697 6.05734 3.57166 5.80295C4.70309 5.3021 6.30179 4.78283 8.32903 4.12437L11.0196 3.25042C12.2166 2.86159 13.2017 2.54158 13.9951 2.38566C14.8065 2.22618 15.6202 2.19289 16.3627 2.59437C16.7568 2.80747 17.1035 3.09839 17.3818 3.4495C17.9062 4.111 18.0147 4.91815 17.9985 5.74496C17.9827 6.55332 17.8386 7.57903 17.6636 8.82534L17.2701 11.6268C16.9737 13.7376 16.7399 15.4022 16.4432 16.6034C16.2924 17.2135 16.1121 17.7632 15.8678 18.2176C15.6197 18.6794 15.2761 19.0971 14.7777 19.3551C14.1827 19.6632 13.5083 19.7833 12.8436 19.6997C12.2867 19.6297 11.82 19.3563 11.4277 19.0087C11.0415 18.6666 10.6824 18.213 10.3302 17.6925C9.67361 16.722 8.92648 15.342 7.99373 13.603Z\"\n    clip-rule=\"evenodd\"\n  />\n  <svg\n    xmlns=\"http://www.w3.org/2000/svg\"\n    width=\"21\"\n    height=\"20\"\n    viewBox=\"0 0 21 20\"\n    fill=\"none\"\n  ></svg></svg\n>`;\n//# sourceMappingURL=send.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvYXNzZXRzL3N2Zy9zZW5kLmpzIiwibWFwcGluZ3MiOiI7Ozs7O0FBQTBCO0FBQ25CLGdCQUFnQix3Q0FBRztBQUMxQjtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0EiLCJzb3VyY2VzIjpbIndlYnBhY2s6Ly9fTl9FLy4vbm9kZV9tb2R1bGVzL0ByZW93bi9hcHBraXQtdWkvZGlzdC9lc20vc3JjL2Fzc2V0cy9zdmcvc2VuZC5qcz80YzgyIl0sInNvdXJjZXNDb250ZW50IjpbImltcG9ydCB7IHN2ZyB9IGZyb20gJ2xpdCc7XG5leHBvcnQgY29uc3Qgc2VuZFN2ZyA9IHN2ZyBgPHN2ZyBmaWxsPVwibm9uZVwiIHZpZXdCb3g9XCIwIDAgMjEgMjBcIj5cbiAgPHBhdGhcbiAgICBmaWxsPVwiY3VycmVudENvbG9yXCJcbiAgICBmaWxsLXJ1bGU9XCJldmVub2RkXCJcbiAgICBkPVwiTTE0LjM4MDggNC4zNDgxMkMxMy43MiA0LjQ3Nzk4IDEyLjg1MDEgNC43NTg3IDExLjU3NDggNS4xNzI5Nkw5LjAwODY5IDYuMDA2NDZDNi45MDYzMSA2LjY4OTM1IDUuNDA2NzkgNy4xNzc3OSA0LjM4MTIxIDcuNjMxNzhDMy44NzE2NiA3Ljg1NzM0IDMuNTM1MSA4LjA1MDkxIDMuMzIwMjIgOC4yMjAzNUMzLjExMTgzIDguMzg0NjYgMy4wNzAxMSA4LjQ4NDg2IDMuMDU5NjkgOC41MTgxN0MyLjk4MDU4IDguNzcxMDMgMi45ODAwOSA5LjA0MTk1IDMuMDU4MzEgOS4yOTUwOUMzLjA2ODYxIDkuMzI4NDQgMy4xMDk5OCA5LjQyODc4IDMuMzE3NzcgOS41OTM4NEMzLjUzMjA1IDkuNzY0MDQgMy44Njc5MiA5Ljk1ODgxIDQuMzc2NjcgMTAuMTg2MkM1LjI5Mjg3IDEwLjU5NTcgNi41ODg0NCAxMS4wMzQxIDguMzU1MjkgMTEuNjE2NEwxMC44ODc2IDguNTk4NTRDMTEuMjQyNiA4LjE3NTQ3IDExLjg3MzMgOC4xMjAyOCAxMi4yOTY0IDguNDc1MjhDMTIuNzE5NSA4LjgzMDI5IDEyLjc3NDYgOS40NjEwNCAxMi40MTk2IDkuODg0MTJMOS44ODczOCAxMi45MDE5QzEwLjc2NzYgMTQuNTQwOCAxMS40MjQ0IDE1Ljc0MDYgMTEuOTg2NyAxNi41NzE4QzEyLjI5OSAxNy4wMzMzIDEyLjU0OTEgMTcuMzMwMyAxMi43NTM5IDE3LjUxMTdDMTIuOTUyNiAxNy42ODc3IDEzLjA1ODYgMTcuNzExIDEzLjA5MzIgMTcuNzE1NEMxMy4zNTYxIDE3Ljc0ODQgMTMuNjIyOCAxNy43MDA5IDEzLjg1ODEgMTcuNTc5MUMxMy44ODkxIDE3LjU2MyAxMy45ODA1IDE3LjUwNDYgMTQuMTA2MSAxNy4yNzA4QzE0LjIzNTcgMTcuMDI5OCAxNC4zNjc5IDE2LjY2NDcgMTQuNTAxNSAxNi4xMjM3QzE0Ljc3MDUgMTUuMDM0OSAxNC45OTEyIDEzLjQ3MzMgMTUuMjk4NiAxMS4yODQzTDE1LjY3MzggOC42MTI0OUMxNS44NjAzIDcuMjg0NTYgMTUuOTg1NyA2LjM3OTE3IDE1Ljk5ODkgNS43MDU5QzE2LjAxMiA1LjAzNzAyIDE1LjkwNDcgNC44MDU2IDE1LjgxNDUgNC42OTE4M0MxNS43MDQ0IDQuNTUyOTcgMTUuNTY3MyA0LjQzNzkyIDE1LjQxMTQgNC4zNTM2NUMxNS4yODM3IDQuMjg0NTkgMTUuMDM3MiA0LjIxOTEgMTQuMzgwOCA0LjM0ODEyWk03Ljk5MzczIDEzLjYwM0M2LjExOTE5IDEyLjk4NjQgNC42MzA0IDEyLjQ5MDIgMy41NjA2IDEyLjAxMjFDMi45ODY4MyAxMS43NTU3IDIuNDc3OCAxMS40ODA4IDIuMDczODMgMTEuMTU5OUMxLjY2MzM3IDEwLjgzMzkgMS4zMTMxMiAxMC40MjE3IDEuMTQ3NDQgOS44ODU1MUMwLjk0OTY2NyA5LjI0NTQxIDAuOTUwODg2IDguNTYwMzUgMS4xNTA5NCA3LjkyMDk2QzEuMzE4NTIgNy4zODUzNCAxLjY3MDI0IDYuOTc0NDIgMi4wODE4NSA2LjY0OTg1QzIuNDg2OTcgNi4zMzA0MSAyLjk5Njk3IDYuMDU3MzQgMy41NzE2NiA1LjgwMjk1QzQuNzAzMDkgNS4zMDIxIDYuMzAxNzkgNC43ODI4MyA4LjMyOTAzIDQuMTI0MzdMMTEuMDE5NiAzLjI1MDQyQzEyLjIxNjYgMi44NjE1OSAxMy4yMDE3IDIuNTQxNTggMTMuOTk1MSAyLjM4NTY2QzE0LjgwNjUgMi4yMjYxOCAxNS42MjAyIDIuMTkyODkgMTYuMzYyNyAyLjU5NDM3QzE2Ljc1NjggMi44MDc0NyAxNy4xMDM1IDMuMDk4MzkgMTcuMzgxOCAzLjQ0OTVDMTcuOTA2MiA0LjExMSAxOC4wMTQ3IDQuOTE4MTUgMTcuOTk4NSA1Ljc0NDk2QzE3Ljk4MjcgNi41NTMzMiAxNy44Mzg2IDcuNTc5MDMgMTcuNjYzNiA4LjgyNTM0TDE3LjI3MDEgMTEuNjI2OEMxNi45NzM3IDEzLjczNzYgMTYuNzM5OSAxNS40MDIyIDE2LjQ0MzIgMTYuNjAzNEMxNi4yOTI0IDE3LjIxMzUgMTYuMTEyMSAxNy43NjMyIDE1Ljg2NzggMTguMjE3NkMxNS42MTk3IDE4LjY3OTQgMTUuMjc2MSAxOS4wOTcxIDE0Ljc3NzcgMTkuMzU1MUMxNC4xODI3IDE5LjY2MzIgMTMuNTA4MyAxOS43ODMzIDEyLjg0MzYgMTkuNjk5N0MxMi4yODY3IDE5LjYyOTcgMTEuODIgMTkuMzU2MyAxMS40Mjc3IDE5LjAwODdDMTEuMDQxNSAxOC42NjY2IDEwLjY4MjQgMTguMjEzIDEwLjMzMDIgMTcuNjkyNUM5LjY3MzYxIDE2LjcyMiA4LjkyNjQ4IDE1LjM0MiA3Ljk5MzczIDEzLjYwM1pcIlxuICAgIGNsaXAtcnVsZT1cImV2ZW5vZGRcIlxuICAvPlxuICA8c3ZnXG4gICAgeG1sbnM9XCJodHRwOi8vd3d3LnczLm9yZy8yMDAwL3N2Z1wiXG4gICAgd2lkdGg9XCIyMVwiXG4gICAgaGVpZ2h0PVwiMjBcIlxuICAgIHZpZXdCb3g9XCIwIDAgMjEgMjBcIlxuICAgIGZpbGw9XCJub25lXCJcbiAgPjwvc3ZnPjwvc3ZnXG4+YDtcbi8vIyBzb3VyY2VNYXBwaW5nVVJMPXNlbmQuanMubWFwIl0sIm5hbWVzIjpbXSwic291cmNlUm9vdCI6IiJ9\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/send.js\n"));
-
-/***/ })
-
-}]);
\ No newline at end of file
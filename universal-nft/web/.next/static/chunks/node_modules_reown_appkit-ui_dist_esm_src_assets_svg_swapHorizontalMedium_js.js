@@ -1,22 +0,0 @@
-"use strict";
-/*
- * ATTENTION: An "eval-source-map" devtool has been used.
- * This devtool is neither made for production nor for readable output files.
- * It uses "eval()" calls to create a separate source file with attached SourceMaps in the browser devtools.
- * If you are trying to read the output file, select a different devtool (https://webpack.js.org/configuration/devtool/)
- * or disable the default devtool with "devtool: false".
- * If you are looking for production-ready output files, see mode: "production" (https://webpack.js.org/configuration/mode/).
- */
-(self["webpackChunk_N_E"] = self["webpackChunk_N_E"] || []).push([["node_modules_reown_appkit-ui_dist_esm_src_assets_svg_swapHorizontalMedium_js"],{
-
-/***/ "./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/swapHorizontalMedium.js":
-/*!***************************************************************************************!*\
-  !*** ./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/swapHorizontalMedium.js ***!
-  \***************************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   swapHorizontalMediumSvg: function() { return /* binding */ swapHorizontalMediumSvg; }\n/* harmony export */ });\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n\nconst swapHorizontalMediumSvg = (0,lit__WEBPACK_IMPORTED_MODULE_0__.svg) `<svg\n  width=\"14\"\n  height=\"14\"\n  viewBox=\"0 0 14 14\"\n  fill=\"none\"\n  xmlns=\"http://www.w3.org/2000/svg\"\n>\n  <path\n    fill-rule=\"evenodd\"\n    clip-rule=\"evenodd\"\n    d=\"M13.7306 3.24213C14.0725 3.58384 14.0725 4.13786 13.7306 4.47957L10.7418 7.46737C10.4 7.80908 9.84581 7.80908 9.50399 7.46737C9.16216 7.12567 9.16216 6.57165 9.50399 6.22994L10.9986 4.73585H5.34082C4.85741 4.73585 4.46553 4.3441 4.46553 3.86085C4.46553 3.3776 4.85741 2.98585 5.34082 2.98585L10.9986 2.98585L9.50399 1.49177C9.16216 1.15006 9.16216 0.596037 9.50399 0.254328C9.84581 -0.0873803 10.4 -0.0873803 10.7418 0.254328L13.7306 3.24213ZM9.52515 10.1352C9.52515 10.6185 9.13327 11.0102 8.64986 11.0102L2.9921 11.0102L4.48669 12.5043C4.82852 12.846 4.82852 13.4001 4.48669 13.7418C4.14487 14.0835 3.59066 14.0835 3.24884 13.7418L0.26003 10.754C0.0958806 10.5899 0.0036621 10.3673 0.00366211 10.1352C0.00366212 9.90318 0.0958806 9.68062 0.26003 9.51652L3.24884 6.52872C3.59066 6.18701 4.14487 6.18701 4.48669 6.52872C4.82851 6.87043 4.82851 7.42445 4.48669 7.76616L2.9921 9.26024L8.64986 9.26024C9.13327 9.26024 9.52515 9.65199 9.52515 10.1352Z\"\n    fill=\"currentColor\"\n  />\n</svg>\n\n`;\n//# sourceMappingURL=swapHorizontalMedium.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvYXNzZXRzL3N2Zy9zd2FwSG9yaXpvbnRhbE1lZGl1bS5qcyIsIm1hcHBpbmdzIjoiOzs7OztBQUEwQjtBQUNuQixnQ0FBZ0Msd0NBQUc7QUFDMUM7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7O0FBRUE7QUFDQSIsInNvdXJjZXMiOlsid2VicGFjazovL19OX0UvLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvYXNzZXRzL3N2Zy9zd2FwSG9yaXpvbnRhbE1lZGl1bS5qcz80ZDlmIl0sInNvdXJjZXNDb250ZW50IjpbImltcG9ydCB7IHN2ZyB9IGZyb20gJ2xpdCc7XG5leHBvcnQgY29uc3Qgc3dhcEhvcml6b250YWxNZWRpdW1TdmcgPSBzdmcgYDxzdmdcbiAgd2lkdGg9XCIxNFwiXG4gIGhlaWdodD1cIjE0XCJcbiAgdmlld0JveD1cIjAgMCAxNCAxNFwiXG4gIGZpbGw9XCJub25lXCJcbiAgeG1sbnM9XCJodHRwOi8vd3d3LnczLm9yZy8yMDAwL3N2Z1wiXG4+XG4gIDxwYXRoXG4gICAgZmlsbC1ydWxlPVwiZXZlbm9kZFwiXG4gICAgY2xpcC1ydWxlPVwiZXZlbm9kZFwiXG4gICAgZD1cIk0xMy43MzA2IDMuMjQyMTNDMTQuMDcyNSAzLjU4Mzg0IDE0LjA3MjUgNC4xMzc4NiAxMy43MzA2IDQuNDc5NTdMMTAuNzQxOCA3LjQ2NzM3QzEwLjQgNy44MDkwOCA5Ljg0NTgxIDcuODA5MDggOS41MDM5OSA3LjQ2NzM3QzkuMTYyMTYgNy4xMjU2NyA5LjE2MjE2IDYuNTcxNjUgOS41MDM5OSA2LjIyOTk0TDEwLjk5ODYgNC43MzU4NUg1LjM0MDgyQzQuODU3NDEgNC43MzU4NSA0LjQ2NTUzIDQuMzQ0MSA0LjQ2NTUzIDMuODYwODVDNC40NjU1MyAzLjM3NzYgNC44NTc0MSAyLjk4NTg1IDUuMzQwODIgMi45ODU4NUwxMC45OTg2IDIuOTg1ODVMOS41MDM5OSAxLjQ5MTc3QzkuMTYyMTYgMS4xNTAwNiA5LjE2MjE2IDAuNTk2MDM3IDkuNTAzOTkgMC4yNTQzMjhDOS44NDU4MSAtMC4wODczODAzIDEwLjQgLTAuMDg3MzgwMyAxMC43NDE4IDAuMjU0MzI4TDEzLjczMDYgMy4yNDIxM1pNOS41MjUxNSAxMC4xMzUyQzkuNTI1MTUgMTAuNjE4NSA5LjEzMzI3IDExLjAxMDIgOC42NDk4NiAxMS4wMTAyTDIuOTkyMSAxMS4wMTAyTDQuNDg2NjkgMTIuNTA0M0M0LjgyODUyIDEyLjg0NiA0LjgyODUyIDEzLjQwMDEgNC40ODY2OSAxMy43NDE4QzQuMTQ0ODcgMTQuMDgzNSAzLjU5MDY2IDE0LjA4MzUgMy4yNDg4NCAxMy43NDE4TDAuMjYwMDMgMTAuNzU0QzAuMDk1ODgwNiAxMC41ODk5IDAuMDAzNjYyMSAxMC4zNjczIDAuMDAzNjYyMTEgMTAuMTM1MkMwLjAwMzY2MjEyIDkuOTAzMTggMC4wOTU4ODA2IDkuNjgwNjIgMC4yNjAwMyA5LjUxNjUyTDMuMjQ4ODQgNi41Mjg3MkMzLjU5MDY2IDYuMTg3MDEgNC4xNDQ4NyA2LjE4NzAxIDQuNDg2NjkgNi41Mjg3MkM0LjgyODUxIDYuODcwNDMgNC44Mjg1MSA3LjQyNDQ1IDQuNDg2NjkgNy43NjYxNkwyLjk5MjEgOS4yNjAyNEw4LjY0OTg2IDkuMjYwMjRDOS4xMzMyNyA5LjI2MDI0IDkuNTI1MTUgOS42NTE5OSA5LjUyNTE1IDEwLjEzNTJaXCJcbiAgICBmaWxsPVwiY3VycmVudENvbG9yXCJcbiAgLz5cbjwvc3ZnPlxuXG5gO1xuLy8jIHNvdXJjZU1hcHBpbmdVUkw9c3dhcEhvcml6b250YWxNZWRpdW0uanMubWFwIl0sIm5hbWVzIjpbXSwic291cmNlUm9vdCI6IiJ9\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/swapHorizontalMedium.js\n"));
-
-/***/ })
-
-}]);
\ No newline at end of file
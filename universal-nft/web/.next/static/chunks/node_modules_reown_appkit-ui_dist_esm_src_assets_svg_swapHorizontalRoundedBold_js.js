@@ -1,22 +0,0 @@
-"use strict";
-/*
- * ATTENTION: An "eval-source-map" devtool has been used.
- * This devtool is neither made for production nor for readable output files.
- * It uses "eval()" calls to create a separate source file with attached SourceMaps in the browser devtools.
- * If you are trying to read the output file, select a different devtool (https://webpack.js.org/configuration/devtool/)
- * or disable the default devtool with "devtool: false".
- * If you are looking for production-ready output files, see mode: "production" (https://webpack.js.org/configuration/mode/).
- */
-(self["webpackChunk_N_E"] = self["webpackChunk_N_E"] || []).push([["node_modules_reown_appkit-ui_dist_esm_src_assets_svg_swapHorizontalRoundedBold_js"],{
-
-/***/ "./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/swapHorizontalRoundedBold.js":
-/*!********************************************************************************************!*\
-  !*** ./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/swapHorizontalRoundedBold.js ***!
-  \********************************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   swapHorizontalRoundedBoldSvg: function() { return /* binding */ swapHorizontalRoundedBoldSvg; }\n/* harmony export */ });\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n\nconst swapHorizontalRoundedBoldSvg = (0,lit__WEBPACK_IMPORTED_MODULE_0__.svg) `<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"20\" height=\"20\" viewBox=\"0 0 20 20\" fill=\"none\">\n  <path \n    fill=\"currentColor\"\n    fill-rule=\"evenodd\" \n    clip-rule=\"evenodd\" \n    d=\"M8.3071 0.292893C8.69763 0.683417 8.69763 1.31658 8.3071 1.70711L6.41421 3.6H11.3404C13.8368 3.6 16.0533 5.1975 16.8427 7.56588L16.9487 7.88377C17.1233 8.40772 16.8402 8.97404 16.3162 9.14868C15.7923 9.32333 15.226 9.04017 15.0513 8.51623L14.9453 8.19834C14.4281 6.64664 12.976 5.6 11.3404 5.6H6.41421L8.3071 7.49289C8.69763 7.88342 8.69763 8.51658 8.3071 8.90711C7.91658 9.29763 7.28341 9.29763 6.89289 8.90711L3.29289 5.30711C2.90236 4.91658 2.90236 4.28342 3.29289 3.89289L6.89289 0.292893C7.28341 -0.0976311 7.91658 -0.0976311 8.3071 0.292893ZM3.68377 10.8513C4.20771 10.6767 4.77403 10.9598 4.94868 11.4838L5.05464 11.8017C5.57188 13.3534 7.024 14.4 8.65964 14.4L13.5858 14.4L11.6929 12.5071C11.3024 12.1166 11.3024 11.4834 11.6929 11.0929C12.0834 10.7024 12.7166 10.7024 13.1071 11.0929L16.7071 14.6929C17.0976 15.0834 17.0976 15.7166 16.7071 16.1071L13.1071 19.7071C12.7166 20.0976 12.0834 20.0976 11.6929 19.7071C11.3024 19.3166 11.3024 18.6834 11.6929 18.2929L13.5858 16.4L8.65964 16.4C6.16314 16.4 3.94674 14.8025 3.15728 12.4341L3.05131 12.1162C2.87667 11.5923 3.15983 11.026 3.68377 10.8513Z\" \n  />\n</svg>`;\n//# sourceMappingURL=swapHorizontalRoundedBold.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvYXNzZXRzL3N2Zy9zd2FwSG9yaXpvbnRhbFJvdW5kZWRCb2xkLmpzIiwibWFwcGluZ3MiOiI7Ozs7O0FBQTBCO0FBQ25CLHFDQUFxQyx3Q0FBRztBQUMvQztBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBIiwic291cmNlcyI6WyJ3ZWJwYWNrOi8vX05fRS8uL25vZGVfbW9kdWxlcy9AcmVvd24vYXBwa2l0LXVpL2Rpc3QvZXNtL3NyYy9hc3NldHMvc3ZnL3N3YXBIb3Jpem9udGFsUm91bmRlZEJvbGQuanM/ODg0YSJdLCJzb3VyY2VzQ29udGVudCI6WyJpbXBvcnQgeyBzdmcgfSBmcm9tICdsaXQnO1xuZXhwb3J0IGNvbnN0IHN3YXBIb3Jpem9udGFsUm91bmRlZEJvbGRTdmcgPSBzdmcgYDxzdmcgeG1sbnM9XCJodHRwOi8vd3d3LnczLm9yZy8yMDAwL3N2Z1wiIHdpZHRoPVwiMjBcIiBoZWlnaHQ9XCIyMFwiIHZpZXdCb3g9XCIwIDAgMjAgMjBcIiBmaWxsPVwibm9uZVwiPlxuICA8cGF0aCBcbiAgICBmaWxsPVwiY3VycmVudENvbG9yXCJcbiAgICBmaWxsLXJ1bGU9XCJldmVub2RkXCIgXG4gICAgY2xpcC1ydWxlPVwiZXZlbm9kZFwiIFxuICAgIGQ9XCJNOC4zMDcxIDAuMjkyODkzQzguNjk3NjMgMC42ODM0MTcgOC42OTc2MyAxLjMxNjU4IDguMzA3MSAxLjcwNzExTDYuNDE0MjEgMy42SDExLjM0MDRDMTMuODM2OCAzLjYgMTYuMDUzMyA1LjE5NzUgMTYuODQyNyA3LjU2NTg4TDE2Ljk0ODcgNy44ODM3N0MxNy4xMjMzIDguNDA3NzIgMTYuODQwMiA4Ljk3NDA0IDE2LjMxNjIgOS4xNDg2OEMxNS43OTIzIDkuMzIzMzMgMTUuMjI2IDkuMDQwMTcgMTUuMDUxMyA4LjUxNjIzTDE0Ljk0NTMgOC4xOTgzNEMxNC40MjgxIDYuNjQ2NjQgMTIuOTc2IDUuNiAxMS4zNDA0IDUuNkg2LjQxNDIxTDguMzA3MSA3LjQ5Mjg5QzguNjk3NjMgNy44ODM0MiA4LjY5NzYzIDguNTE2NTggOC4zMDcxIDguOTA3MTFDNy45MTY1OCA5LjI5NzYzIDcuMjgzNDEgOS4yOTc2MyA2Ljg5Mjg5IDguOTA3MTFMMy4yOTI4OSA1LjMwNzExQzIuOTAyMzYgNC45MTY1OCAyLjkwMjM2IDQuMjgzNDIgMy4yOTI4OSAzLjg5Mjg5TDYuODkyODkgMC4yOTI4OTNDNy4yODM0MSAtMC4wOTc2MzExIDcuOTE2NTggLTAuMDk3NjMxMSA4LjMwNzEgMC4yOTI4OTNaTTMuNjgzNzcgMTAuODUxM0M0LjIwNzcxIDEwLjY3NjcgNC43NzQwMyAxMC45NTk4IDQuOTQ4NjggMTEuNDgzOEw1LjA1NDY0IDExLjgwMTdDNS41NzE4OCAxMy4zNTM0IDcuMDI0IDE0LjQgOC42NTk2NCAxNC40TDEzLjU4NTggMTQuNEwxMS42OTI5IDEyLjUwNzFDMTEuMzAyNCAxMi4xMTY2IDExLjMwMjQgMTEuNDgzNCAxMS42OTI5IDExLjA5MjlDMTIuMDgzNCAxMC43MDI0IDEyLjcxNjYgMTAuNzAyNCAxMy4xMDcxIDExLjA5MjlMMTYuNzA3MSAxNC42OTI5QzE3LjA5NzYgMTUuMDgzNCAxNy4wOTc2IDE1LjcxNjYgMTYuNzA3MSAxNi4xMDcxTDEzLjEwNzEgMTkuNzA3MUMxMi43MTY2IDIwLjA5NzYgMTIuMDgzNCAyMC4wOTc2IDExLjY5MjkgMTkuNzA3MUMxMS4zMDI0IDE5LjMxNjYgMTEuMzAyNCAxOC42ODM0IDExLjY5MjkgMTguMjkyOUwxMy41ODU4IDE2LjRMOC42NTk2NCAxNi40QzYuMTYzMTQgMTYuNCAzLjk0Njc0IDE0LjgwMjUgMy4xNTcyOCAxMi40MzQxTDMuMDUxMzEgMTIuMTE2MkMyLjg3NjY3IDExLjU5MjMgMy4xNTk4MyAxMS4wMjYgMy42ODM3NyAxMC44NTEzWlwiIFxuICAvPlxuPC9zdmc+YDtcbi8vIyBzb3VyY2VNYXBwaW5nVVJMPXN3YXBIb3Jpem9udGFsUm91bmRlZEJvbGQuanMubWFwIl0sIm5hbWVzIjpbXSwic291cmNlUm9vdCI6IiJ9\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/swapHorizontalRoundedBold.js\n"));
-
-/***/ })
-
-}]);
\ No newline at end of file
@@ -1,22 +0,0 @@
-"use strict";
-/*
- * ATTENTION: An "eval-source-map" devtool has been used.
- * This devtool is neither made for production nor for readable output files.
- * It uses "eval()" calls to create a separate source file with attached SourceMaps in the browser devtools.
- * If you are trying to read the output file, select a different devtool (https://webpack.js.org/configuration/devtool/)
- * or disable the default devtool with "devtool: false".
- * If you are looking for production-ready output files, see mode: "production" (https://webpack.js.org/configuration/mode/).
- */
-(self["webpackChunk_N_E"] = self["webpackChunk_N_E"] || []).push([["node_modules_reown_appkit-ui_dist_esm_src_assets_svg_telegram_js"],{
-
-/***/ "./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/telegram.js":
-/*!***************************************************************************!*\
-  !*** ./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/telegram.js ***!
-  \***************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   telegramSvg: function() { return /* binding */ telegramSvg; }\n/* harmony export */ });\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n\nconst telegramSvg = (0,lit__WEBPACK_IMPORTED_MODULE_0__.svg) `<svg width=\"32\" height=\"32\" fill=\"none\" xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 32 32\">\n  <g clip-path=\"url(#a)\">\n    <path fill=\"url(#b)\" d=\"M0 0h32v32H0z\"/>\n    <path fill-rule=\"evenodd\" clip-rule=\"evenodd\" d=\"M7.034 15.252c4.975-2.167 8.293-3.596 9.953-4.287 4.74-1.971 5.725-2.314 6.366-2.325.142-.002.457.033.662.198.172.14.22.33.243.463.022.132.05.435.028.671-.257 2.7-1.368 9.248-1.933 12.27-.24 1.28-.71 1.708-1.167 1.75-.99.091-1.743-.655-2.703-1.284-1.502-.985-2.351-1.598-3.81-2.558-1.684-1.11-.592-1.721.368-2.718.252-.261 4.619-4.233 4.703-4.594.01-.045.02-.213-.08-.301-.1-.09-.246-.059-.353-.035-.15.034-2.55 1.62-7.198 4.758-.682.468-1.298.696-1.851.684-.61-.013-1.782-.344-2.653-.628-1.069-.347-1.918-.53-1.845-1.12.039-.308.462-.623 1.27-.944Z\" fill=\"#fff\"/>\n  </g>\n  <path d=\"M.5 16C.5 7.44 7.44.5 16 .5 24.56.5 31.5 7.44 31.5 16c0 8.56-6.94 15.5-15.5 15.5C7.44 31.5.5 24.56.5 16Z\" stroke=\"#141414\" stroke-opacity=\".05\"/>\n  <defs>\n    <linearGradient id=\"b\" x1=\"1600\" y1=\"0\" x2=\"1600\" y2=\"3176.27\" gradientUnits=\"userSpaceOnUse\">\n      <stop stop-color=\"#2AABEE\"/>\n      <stop offset=\"1\" stop-color=\"#229ED9\"/>\n    </linearGradient>\n    <clipPath id=\"a\">\n      <path d=\"M0 16C0 7.163 7.163 0 16 0s16 7.163 16 16-7.163 16-16 16S0 24.837 0 16Z\" fill=\"#fff\"/>\n    </clipPath>\n  </defs>\n</svg>`;\n//# sourceMappingURL=telegram.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvYXNzZXRzL3N2Zy90ZWxlZ3JhbS5qcyIsIm1hcHBpbmdzIjoiOzs7OztBQUEwQjtBQUNuQixvQkFBb0Isd0NBQUc7QUFDOUI7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0EiLCJzb3VyY2VzIjpbIndlYnBhY2s6Ly9fTl9FLy4vbm9kZV9tb2R1bGVzL0ByZW93bi9hcHBraXQtdWkvZGlzdC9lc20vc3JjL2Fzc2V0cy9zdmcvdGVsZWdyYW0uanM/MmQ0YyJdLCJzb3VyY2VzQ29udGVudCI6WyJpbXBvcnQgeyBzdmcgfSBmcm9tICdsaXQnO1xuZXhwb3J0IGNvbnN0IHRlbGVncmFtU3ZnID0gc3ZnIGA8c3ZnIHdpZHRoPVwiMzJcIiBoZWlnaHQ9XCIzMlwiIGZpbGw9XCJub25lXCIgeG1sbnM9XCJodHRwOi8vd3d3LnczLm9yZy8yMDAwL3N2Z1wiIHZpZXdCb3g9XCIwIDAgMzIgMzJcIj5cbiAgPGcgY2xpcC1wYXRoPVwidXJsKCNhKVwiPlxuICAgIDxwYXRoIGZpbGw9XCJ1cmwoI2IpXCIgZD1cIk0wIDBoMzJ2MzJIMHpcIi8+XG4gICAgPHBhdGggZmlsbC1ydWxlPVwiZXZlbm9kZFwiIGNsaXAtcnVsZT1cImV2ZW5vZGRcIiBkPVwiTTcuMDM0IDE1LjI1MmM0Ljk3NS0yLjE2NyA4LjI5My0zLjU5NiA5Ljk1My00LjI4NyA0Ljc0LTEuOTcxIDUuNzI1LTIuMzE0IDYuMzY2LTIuMzI1LjE0Mi0uMDAyLjQ1Ny4wMzMuNjYyLjE5OC4xNzIuMTQuMjIuMzMuMjQzLjQ2My4wMjIuMTMyLjA1LjQzNS4wMjguNjcxLS4yNTcgMi43LTEuMzY4IDkuMjQ4LTEuOTMzIDEyLjI3LS4yNCAxLjI4LS43MSAxLjcwOC0xLjE2NyAxLjc1LS45OS4wOTEtMS43NDMtLjY1NS0yLjcwMy0xLjI4NC0xLjUwMi0uOTg1LTIuMzUxLTEuNTk4LTMuODEtMi41NTgtMS42ODQtMS4xMS0uNTkyLTEuNzIxLjM2OC0yLjcxOC4yNTItLjI2MSA0LjYxOS00LjIzMyA0LjcwMy00LjU5NC4wMS0uMDQ1LjAyLS4yMTMtLjA4LS4zMDEtLjEtLjA5LS4yNDYtLjA1OS0uMzUzLS4wMzUtLjE1LjAzNC0yLjU1IDEuNjItNy4xOTggNC43NTgtLjY4Mi40NjgtMS4yOTguNjk2LTEuODUxLjY4NC0uNjEtLjAxMy0xLjc4Mi0uMzQ0LTIuNjUzLS42MjgtMS4wNjktLjM0Ny0xLjkxOC0uNTMtMS44NDUtMS4xMi4wMzktLjMwOC40NjItLjYyMyAxLjI3LS45NDRaXCIgZmlsbD1cIiNmZmZcIi8+XG4gIDwvZz5cbiAgPHBhdGggZD1cIk0uNSAxNkMuNSA3LjQ0IDcuNDQuNSAxNiAuNSAyNC41Ni41IDMxLjUgNy40NCAzMS41IDE2YzAgOC41Ni02Ljk0IDE1LjUtMTUuNSAxNS41QzcuNDQgMzEuNS41IDI0LjU2LjUgMTZaXCIgc3Ryb2tlPVwiIzE0MTQxNFwiIHN0cm9rZS1vcGFjaXR5PVwiLjA1XCIvPlxuICA8ZGVmcz5cbiAgICA8bGluZWFyR3JhZGllbnQgaWQ9XCJiXCIgeDE9XCIxNjAwXCIgeTE9XCIwXCIgeDI9XCIxNjAwXCIgeTI9XCIzMTc2LjI3XCIgZ3JhZGllbnRVbml0cz1cInVzZXJTcGFjZU9uVXNlXCI+XG4gICAgICA8c3RvcCBzdG9wLWNvbG9yPVwiIzJBQUJFRVwiLz5cbiAgICAgIDxzdG9wIG9mZnNldD1cIjFcIiBzdG9wLWNvbG9yPVwiIzIyOUVEOVwiLz5cbiAgICA8L2xpbmVhckdyYWRpZW50PlxuICAgIDxjbGlwUGF0aCBpZD1cImFcIj5cbiAgICAgIDxwYXRoIGQ9XCJNMCAxNkMwIDcuMTYzIDcuMTYzIDAgMTYgMHMxNiA3LjE2MyAxNiAxNi03LjE2MyAxNi0xNiAxNlMwIDI0LjgzNyAwIDE2WlwiIGZpbGw9XCIjZmZmXCIvPlxuICAgIDwvY2xpcFBhdGg+XG4gIDwvZGVmcz5cbjwvc3ZnPmA7XG4vLyMgc291cmNlTWFwcGluZ1VSTD10ZWxlZ3JhbS5qcy5tYXAiXSwibmFtZXMiOltdLCJzb3VyY2VSb290IjoiIn0=\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/telegram.js\n"));
-
-/***/ })
-
-}]);
\ No newline at end of file
@@ -1,22 +0,0 @@
-"use strict";
-/*
- * ATTENTION: An "eval-source-map" devtool has been used.
- * This devtool is neither made for production nor for readable output files.
- * It uses "eval()" calls to create a separate source file with attached SourceMaps in the browser devtools.
- * If you are trying to read the output file, select a different devtool (https://webpack.js.org/configuration/devtool/)
- * or disable the default devtool with "devtool: false".
- * If you are looking for production-ready output files, see mode: "production" (https://webpack.js.org/configuration/mode/).
- */
-(self["webpackChunk_N_E"] = self["webpackChunk_N_E"] || []).push([["node_modules_reown_appkit-ui_dist_esm_src_assets_svg_verify_js"],{
-
-/***/ "./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/verify.js":
-/*!*************************************************************************!*\
-  !*** ./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/verify.js ***!
-  \*************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   verifySvg: function() { return /* binding */ verifySvg; }\n/* harmony export */ });\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n\nconst verifySvg = (0,lit__WEBPACK_IMPORTED_MODULE_0__.svg) `<svg fill=\"none\" viewBox=\"0 0 28 28\">\n  <path\n    fill=\"currentColor\"\n    fill-rule=\"evenodd\"\n    d=\"M18.1 4.76c-.42-.73-1.33-1.01-2.09-.66l-1.42.66c-.37.18-.8.18-1.18 0l-1.4-.65a1.63 1.63 0 0 0-2.1.66l-.84 1.45c-.2.34-.53.59-.92.67l-1.7.35c-.83.17-1.39.94-1.3 1.78l.19 1.56c.04.39-.08.78-.33 1.07l-1.12 1.3c-.52.6-.52 1.5 0 2.11L5 16.38c.25.3.37.68.33 1.06l-.18 1.57c-.1.83.46 1.6 1.28 1.78l1.7.35c.4.08.73.32.93.66l.84 1.43a1.63 1.63 0 0 0 2.09.66l1.41-.66c.37-.17.8-.17 1.18 0l1.43.67c.76.35 1.66.07 2.08-.65l.86-1.45c.2-.34.54-.58.92-.66l1.68-.35A1.63 1.63 0 0 0 22.84 19l-.18-1.57a1.4 1.4 0 0 1 .33-1.06l1.12-1.32c.52-.6.52-1.5 0-2.11l-1.12-1.3a1.4 1.4 0 0 1-.33-1.07l.18-1.57c.1-.83-.46-1.6-1.28-1.77l-1.68-.35a1.4 1.4 0 0 1-.92-.66l-.86-1.47Zm-3.27-3.2a4.43 4.43 0 0 1 5.69 1.78l.54.93 1.07.22a4.43 4.43 0 0 1 3.5 4.84l-.11.96.7.83a4.43 4.43 0 0 1 .02 5.76l-.72.85.1.96a4.43 4.43 0 0 1-3.5 4.84l-1.06.22-.54.92a4.43 4.43 0 0 1-5.68 1.77l-.84-.4-.82.39a4.43 4.43 0 0 1-5.7-1.79l-.51-.89-1.09-.22a4.43 4.43 0 0 1-3.5-4.84l.1-.96-.72-.85a4.43 4.43 0 0 1 .01-5.76l.71-.83-.1-.95a4.43 4.43 0 0 1 3.5-4.84l1.08-.23.53-.9a4.43 4.43 0 0 1 5.7-1.8l.81.38.83-.39ZM18.2 9.4c.65.42.84 1.28.42 1.93l-4.4 6.87a1.4 1.4 0 0 1-2.26.14L9.5 15.39a1.4 1.4 0 0 1 2.15-1.8l1.23 1.48 3.38-5.26a1.4 1.4 0 0 1 1.93-.42Z\"\n    clip-rule=\"evenodd\"\n  />\n</svg>`;\n//# sourceMappingURL=verify.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvYXNzZXRzL3N2Zy92ZXJpZnkuanMiLCJtYXBwaW5ncyI6Ijs7Ozs7QUFBMEI7QUFDbkIsa0JBQWtCLHdDQUFHO0FBQzVCO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBO0FBQ0EiLCJzb3VyY2VzIjpbIndlYnBhY2s6Ly9fTl9FLy4vbm9kZV9tb2R1bGVzL0ByZW93bi9hcHBraXQtdWkvZGlzdC9lc20vc3JjL2Fzc2V0cy9zdmcvdmVyaWZ5LmpzPzZjZGQiXSwic291cmNlc0NvbnRlbnQiOlsiaW1wb3J0IHsgc3ZnIH0gZnJvbSAnbGl0JztcbmV4cG9ydCBjb25zdCB2ZXJpZnlTdmcgPSBzdmcgYDxzdmcgZmlsbD1cIm5vbmVcIiB2aWV3Qm94PVwiMCAwIDI4IDI4XCI+XG4gIDxwYXRoXG4gICAgZmlsbD1cImN1cnJlbnRDb2xvclwiXG4gICAgZmlsbC1ydWxlPVwiZXZlbm9kZFwiXG4gICAgZD1cIk0xOC4xIDQuNzZjLS40Mi0uNzMtMS4zMy0xLjAxLTIuMDktLjY2bC0xLjQyLjY2Yy0uMzcuMTgtLjguMTgtMS4xOCAwbC0xLjQtLjY1YTEuNjMgMS42MyAwIDAgMC0yLjEuNjZsLS44NCAxLjQ1Yy0uMi4zNC0uNTMuNTktLjkyLjY3bC0xLjcuMzVjLS44My4xNy0xLjM5Ljk0LTEuMyAxLjc4bC4xOSAxLjU2Yy4wNC4zOS0uMDguNzgtLjMzIDEuMDdsLTEuMTIgMS4zYy0uNTIuNi0uNTIgMS41IDAgMi4xMUw1IDE2LjM4Yy4yNS4zLjM3LjY4LjMzIDEuMDZsLS4xOCAxLjU3Yy0uMS44My40NiAxLjYgMS4yOCAxLjc4bDEuNy4zNWMuNC4wOC43My4zMi45My42NmwuODQgMS40M2ExLjYzIDEuNjMgMCAwIDAgMi4wOS42NmwxLjQxLS42NmMuMzctLjE3LjgtLjE3IDEuMTggMGwxLjQzLjY3Yy43Ni4zNSAxLjY2LjA3IDIuMDgtLjY1bC44Ni0xLjQ1Yy4yLS4zNC41NC0uNTguOTItLjY2bDEuNjgtLjM1QTEuNjMgMS42MyAwIDAgMCAyMi44NCAxOWwtLjE4LTEuNTdhMS40IDEuNCAwIDAgMSAuMzMtMS4wNmwxLjEyLTEuMzJjLjUyLS42LjUyLTEuNSAwLTIuMTFsLTEuMTItMS4zYTEuNCAxLjQgMCAwIDEtLjMzLTEuMDdsLjE4LTEuNTdjLjEtLjgzLS40Ni0xLjYtMS4yOC0xLjc3bC0xLjY4LS4zNWExLjQgMS40IDAgMCAxLS45Mi0uNjZsLS44Ni0xLjQ3Wm0tMy4yNy0zLjJhNC40MyA0LjQzIDAgMCAxIDUuNjkgMS43OGwuNTQuOTMgMS4wNy4yMmE0LjQzIDQuNDMgMCAwIDEgMy41IDQuODRsLS4xMS45Ni43LjgzYTQuNDMgNC40MyAwIDAgMSAuMDIgNS43NmwtLjcyLjg1LjEuOTZhNC40MyA0LjQzIDAgMCAxLTMuNSA0Ljg0bC0xLjA2LjIyLS41NC45MmE0LjQzIDQuNDMgMCAwIDEtNS42OCAxLjc3bC0uODQtLjQtLjgyLjM5YTQuNDMgNC40MyAwIDAgMS01LjctMS43OWwtLjUxLS44OS0xLjA5LS4yMmE0LjQzIDQuNDMgMCAwIDEtMy41LTQuODRsLjEtLjk2LS43Mi0uODVhNC40MyA0LjQzIDAgMCAxIC4wMS01Ljc2bC43MS0uODMtLjEtLjk1YTQuNDMgNC40MyAwIDAgMSAzLjUtNC44NGwxLjA4LS4yMy41My0uOWE0LjQzIDQuNDMgMCAwIDEgNS43LTEuOGwuODEuMzguODMtLjM5Wk0xOC4yIDkuNGMuNjUuNDIuODQgMS4yOC40MiAxLjkzbC00LjQgNi44N2ExLjQgMS40IDAgMCAxLTIuMjYuMTRMOS41IDE1LjM5YTEuNCAxLjQgMCAwIDEgMi4xNS0xLjhsMS4yMyAxLjQ4IDMuMzgtNS4yNmExLjQgMS40IDAgMCAxIDEuOTMtLjQyWlwiXG4gICAgY2xpcC1ydWxlPVwiZXZlbm9kZFwiXG4gIC8+XG48L3N2Zz5gO1xuLy8jIHNvdXJjZU1hcHBpbmdVUkw9dmVyaWZ5LmpzLm1hcCJdLCJuYW1lcyI6W10sInNvdXJjZVJvb3QiOiIifQ==\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/verify.js\n"));
-
-/***/ })
-
-}]);
\ No newline at end of file
@@ -1,22 +0,0 @@
-"use strict";
-/*
- * ATTENTION: An "eval-source-map" devtool has been used.
- * This devtool is neither made for production nor for readable output files.
- * It uses "eval()" calls to create a separate source file with attached SourceMaps in the browser devtools.
- * If you are trying to read the output file, select a different devtool (https://webpack.js.org/configuration/devtool/)
- * or disable the default devtool with "devtool: false".
- * If you are looking for production-ready output files, see mode: "production" (https://webpack.js.org/configuration/mode/).
- */
-(self["webpackChunk_N_E"] = self["webpackChunk_N_E"] || []).push([["node_modules_reown_appkit-ui_dist_esm_src_assets_svg_wallet-placeholder_js"],{
-
-/***/ "./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/wallet-placeholder.js":
-/*!*************************************************************************************!*\
-  !*** ./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/wallet-placeholder.js ***!
-  \*************************************************************************************/
-/***/ (function(__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) {
-
-eval(__webpack_require__.ts("__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   walletPlaceholderSvg: function() { return /* binding */ walletPlaceholderSvg; }\n/* harmony export */ });\n/* harmony import */ var lit__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lit */ \"./node_modules/lit/index.js\");\n\nconst walletPlaceholderSvg = (0,lit__WEBPACK_IMPORTED_MODULE_0__.svg) `\n  <svg fill=\"none\" viewBox=\"0 0 48 44\">\n    <path\n      style=\"fill: var(--wui-color-bg-300);\"\n      d=\"M4.56 8.64c-1.23 1.68-1.23 4.08-1.23 8.88v8.96c0 4.8 0 7.2 1.23 8.88.39.55.87 1.02 1.41 1.42C7.65 38 10.05 38 14.85 38h14.3c4.8 0 7.2 0 8.88-1.22a6.4 6.4 0 0 0 1.41-1.42c.83-1.14 1.1-2.6 1.19-4.92a6.4 6.4 0 0 0 5.16-4.65c.21-.81.21-1.8.21-3.79 0-1.98 0-2.98-.22-3.79a6.4 6.4 0 0 0-5.15-4.65c-.1-2.32-.36-3.78-1.19-4.92a6.4 6.4 0 0 0-1.41-1.42C36.35 6 33.95 6 29.15 6h-14.3c-4.8 0-7.2 0-8.88 1.22a6.4 6.4 0 0 0-1.41 1.42Z\"\n    />\n    <path\n      style=\"fill: var(--wui-color-fg-200);\"\n      fill-rule=\"evenodd\"\n      d=\"M2.27 11.33a6.4 6.4 0 0 1 6.4-6.4h26.66a6.4 6.4 0 0 1 6.4 6.4v1.7a6.4 6.4 0 0 1 5.34 6.3v5.34a6.4 6.4 0 0 1-5.34 6.3v1.7a6.4 6.4 0 0 1-6.4 6.4H8.67a6.4 6.4 0 0 1-6.4-6.4V11.33ZM39.6 31.07h-6.93a9.07 9.07 0 1 1 0-18.14h6.93v-1.6a4.27 4.27 0 0 0-4.27-4.26H8.67a4.27 4.27 0 0 0-4.27 4.26v21.34a4.27 4.27 0 0 0 4.27 4.26h26.66a4.27 4.27 0 0 0 4.27-4.26v-1.6Zm-6.93-16a6.93 6.93 0 0 0 0 13.86h8a4.27 4.27 0 0 0 4.26-4.26v-5.34a4.27 4.27 0 0 0-4.26-4.26h-8Z\"\n      clip-rule=\"evenodd\"\n    />\n  </svg>\n`;\n//# sourceMappingURL=wallet-placeholder.js.map//# sourceURL=[module]\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiLi9ub2RlX21vZHVsZXMvQHJlb3duL2FwcGtpdC11aS9kaXN0L2VzbS9zcmMvYXNzZXRzL3N2Zy93YWxsZXQtcGxhY2Vob2xkZXIuanMiLCJtYXBwaW5ncyI6Ijs7Ozs7QUFBMEI7QUFDbkIsNkJBQTZCLHdDQUFHO0FBQ3ZDO0FBQ0E7QUFDQSwyQ0FBMkM7QUFDM0M7QUFDQTtBQUNBO0FBQ0EsMkNBQTJDO0FBQzNDO0FBQ0E7QUFDQTtBQUNBO0FBQ0E7QUFDQTtBQUNBIiwic291cmNlcyI6WyJ3ZWJwYWNrOi8vX05fRS8uL25vZGVfbW9kdWxlcy9AcmVvd24vYXBwa2l0LXVpL2Rpc3QvZXNtL3NyYy9hc3NldHMvc3ZnL3dhbGxldC1wbGFjZWhvbGRlci5qcz9iZjVkIl0sInNvdXJjZXNDb250ZW50IjpbImltcG9ydCB7IHN2ZyB9IGZyb20gJ2xpdCc7XG5leHBvcnQgY29uc3Qgd2FsbGV0UGxhY2Vob2xkZXJTdmcgPSBzdmcgYFxuICA8c3ZnIGZpbGw9XCJub25lXCIgdmlld0JveD1cIjAgMCA0OCA0NFwiPlxuICAgIDxwYXRoXG4gICAgICBzdHlsZT1cImZpbGw6IHZhcigtLXd1aS1jb2xvci1iZy0zMDApO1wiXG4gICAgICBkPVwiTTQuNTYgOC42NGMtMS4yMyAxLjY4LTEuMjMgNC4wOC0xLjIzIDguODh2OC45NmMwIDQuOCAwIDcuMiAxLjIzIDguODguMzkuNTUuODcgMS4wMiAxLjQxIDEuNDJDNy42NSAzOCAxMC4wNSAzOCAxNC44NSAzOGgxNC4zYzQuOCAwIDcuMiAwIDguODgtMS4yMmE2LjQgNi40IDAgMCAwIDEuNDEtMS40MmMuODMtMS4xNCAxLjEtMi42IDEuMTktNC45MmE2LjQgNi40IDAgMCAwIDUuMTYtNC42NWMuMjEtLjgxLjIxLTEuOC4yMS0zLjc5IDAtMS45OCAwLTIuOTgtLjIyLTMuNzlhNi40IDYuNCAwIDAgMC01LjE1LTQuNjVjLS4xLTIuMzItLjM2LTMuNzgtMS4xOS00LjkyYTYuNCA2LjQgMCAwIDAtMS40MS0xLjQyQzM2LjM1IDYgMzMuOTUgNiAyOS4xNSA2aC0xNC4zYy00LjggMC03LjIgMC04Ljg4IDEuMjJhNi40IDYuNCAwIDAgMC0xLjQxIDEuNDJaXCJcbiAgICAvPlxuICAgIDxwYXRoXG4gICAgICBzdHlsZT1cImZpbGw6IHZhcigtLXd1aS1jb2xvci1mZy0yMDApO1wiXG4gICAgICBmaWxsLXJ1bGU9XCJldmVub2RkXCJcbiAgICAgIGQ9XCJNMi4yNyAxMS4zM2E2LjQgNi40IDAgMCAxIDYuNC02LjRoMjYuNjZhNi40IDYuNCAwIDAgMSA2LjQgNi40djEuN2E2LjQgNi40IDAgMCAxIDUuMzQgNi4zdjUuMzRhNi40IDYuNCAwIDAgMS01LjM0IDYuM3YxLjdhNi40IDYuNCAwIDAgMS02LjQgNi40SDguNjdhNi40IDYuNCAwIDAgMS02LjQtNi40VjExLjMzWk0zOS42IDMxLjA3aC02LjkzYTkuMDcgOS4wNyAwIDEgMSAwLTE4LjE0aDYuOTN2LTEuNmE0LjI3IDQuMjcgMCAwIDAtNC4yNy00LjI2SDguNjdhNC4yNyA0LjI3IDAgMCAwLTQuMjcgNC4yNnYyMS4zNGE0LjI3IDQuMjcgMCAwIDAgNC4yNyA0LjI2aDI2LjY2YTQuMjcgNC4yNyAwIDAgMCA0LjI3LTQuMjZ2LTEuNlptLTYuOTMtMTZhNi45MyA2LjkzIDAgMCAwIDAgMTMuODZoOGE0LjI3IDQuMjcgMCAwIDAgNC4yNi00LjI2di01LjM0YTQuMjcgNC4yNyAwIDAgMC00LjI2LTQuMjZoLThaXCJcbiAgICAgIGNsaXAtcnVsZT1cImV2ZW5vZGRcIlxuICAgIC8+XG4gIDwvc3ZnPlxuYDtcbi8vIyBzb3VyY2VNYXBwaW5nVVJMPXdhbGxldC1wbGFjZWhvbGRlci5qcy5tYXAiXSwibmFtZXMiOltdLCJzb3VyY2VSb290IjoiIn0=\n//# sourceURL=webpack-internal:///./node_modules/@reown/appkit-ui/dist/esm/src/assets/svg/wallet-placeholder.js\n"));
-
-/***/ })
-
-}]);
\ No newline at end of file
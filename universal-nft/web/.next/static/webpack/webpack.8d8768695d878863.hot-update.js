@@ -1,656 +0,0 @@
-"use strict";
-/*
- * ATTENTION: An "eval-source-map" devtool has been used.
- * This devtool is neither made for production nor for readable output files.
- * It uses "eval()" calls to create a separate source file with attached SourceMaps in the browser devtools.
- * If you are trying to read the output file, select a different devtool (https://webpack.js.org/configuration/devtool/)
- * or disable the default devtool with "devtool: false".
- * If you are looking for production-ready output files, see mode: "production" (https://webpack.js.org/configuration/mode/).
- */
-self["webpackHotUpdate_N_E"]("webpack",{},
-/******/ function(__webpack_require__) { // webpackRuntimeModules
-/******/ /* webpack/runtime/amd options */
-/******/ !function() {
-/******/ 	__webpack_require__.amdO = {};
-/******/ }();
-/******/ 
-/******/ /* webpack/runtime/create fake namespace object */
-/******/ !function() {
-/******/ 	var getProto = Object.getPrototypeOf ? function(obj) { return Object.getPrototypeOf(obj); } : function(obj) { return obj.__proto__; };
-/******/ 	var leafPrototypes;
-/******/ 	// create a fake namespace object
-/******/ 	// mode & 1: value is a module id, require it
-/******/ 	// mode & 2: merge all properties of value into the ns
-/******/ 	// mode & 4: return value when already ns object
-/******/ 	// mode & 16: return value when it's Promise-like
-/******/ 	// mode & 8|1: behave like require
-/******/ 	__webpack_require__.t = function(value, mode) {
-/******/ 		if(mode & 1) value = this(value);
-/******/ 		if(mode & 8) return value;
-/******/ 		if(typeof value === 'object' && value) {
-/******/ 			if((mode & 4) && value.__esModule) return value;
-/******/ 			if((mode & 16) && typeof value.then === 'function') return value;
-/******/ 		}
-/******/ 		var ns = Object.create(null);
-/******/ 		__webpack_require__.r(ns);
-/******/ 		var def = {};
-/******/ 		leafPrototypes = leafPrototypes || [null, getProto({}), getProto([]), getProto(getProto)];
-/******/ 		for(var current = mode & 2 && value; typeof current == 'object' && !~leafPrototypes.indexOf(current); current = getProto(current)) {
-/******/ 			Object.getOwnPropertyNames(current).forEach(function(key) { def[key] = function() { return value[key]; }; });
-/******/ 		}
-/******/ 		def['default'] = function() { return value; };
-/******/ 		__webpack_require__.d(ns, def);
-/******/ 		return ns;
-/******/ 	};
-/******/ }();
-/******/ 
-/******/ /* webpack/runtime/ensure chunk */
-/******/ !function() {
-/******/ 	__webpack_require__.f = {};
-/******/ 	// This file contains only the entry chunk.
-/******/ 	// The chunk loading function for additional chunks
-/******/ 	__webpack_require__.e = function(chunkId) {
-/******/ 		return Promise.all(Object.keys(__webpack_require__.f).reduce(function(promises, key) {
-/******/ 			__webpack_require__.f[key](chunkId, promises);
-/******/ 			return promises;
-/******/ 		}, []));
-/******/ 	};
-/******/ }();
-/******/ 
-/******/ /* webpack/runtime/get javascript chunk filename */
-/******/ !function() {
-/******/ 	// This function allow to reference async chunks
-/******/ 	__webpack_require__.u = function(chunkId) {
-/******/ 		// return url for filenames based on template
-/******/ 		return "static/chunks/" + chunkId + ".js";
-/******/ 	};
-/******/ }();
-/******/ 
-/******/ /* webpack/runtime/getFullHash */
-/******/ !function() {
-/******/ 	__webpack_require__.h = function() { return "dd8d1b54870cabd3"; }
-/******/ }();
-/******/ 
-/******/ /* webpack/runtime/jsonp chunk loading */
-/******/ !function() {
-/******/ 	// no baseURI
-/******/ 	
-/******/ 	// object to store loaded and loading chunks
-/******/ 	// undefined = chunk not loaded, null = chunk preloaded/prefetched
-/******/ 	// [resolve, reject, Promise] = chunk loading, 0 = chunk loaded
-/******/ 	var installedChunks = __webpack_require__.hmrS_jsonp = __webpack_require__.hmrS_jsonp || {
-/******/ 		"webpack": 0
-/******/ 	};
-/******/ 	
-/******/ 	__webpack_require__.f.j = function(chunkId, promises) {
-/******/ 			// JSONP chunk loading for javascript
-/******/ 			var installedChunkData = __webpack_require__.o(installedChunks, chunkId) ? installedChunks[chunkId] : undefined;
-/******/ 			if(installedChunkData !== 0) { // 0 means "already installed".
-/******/ 	
-/******/ 				// a Promise means "currently loading".
-/******/ 				if(installedChunkData) {
-/******/ 					promises.push(installedChunkData[2]);
-/******/ 				} else {
-/******/ 					if("webpack" != chunkId) {
-/******/ 						// setup Promise in chunk cache
-/******/ 						var promise = new Promise(function(resolve, reject) { installedChunkData = installedChunks[chunkId] = [resolve, reject]; });
-/******/ 						promises.push(installedChunkData[2] = promise);
-/******/ 	
-/******/ 						// start chunk loading
-/******/ 						var url = __webpack_require__.p + __webpack_require__.u(chunkId);
-/******/ 						// create error before stack unwound to get useful stacktrace later
-/******/ 						var error = new Error();
-/******/ 						var loadingEnded = function(event) {
-/******/ 							if(__webpack_require__.o(installedChunks, chunkId)) {
-/******/ 								installedChunkData = installedChunks[chunkId];
-/******/ 								if(installedChunkData !== 0) installedChunks[chunkId] = undefined;
-/******/ 								if(installedChunkData) {
-/******/ 									var errorType = event && (event.type === 'load' ? 'missing' : event.type);
-/******/ 									var realSrc = event && event.target && event.target.src;
-/******/ 									error.message = 'Loading chunk ' + chunkId + ' failed.\n(' + errorType + ': ' + realSrc + ')';
-/******/ 									error.name = 'ChunkLoadError';
-/******/ 									error.type = errorType;
-/******/ 									error.request = realSrc;
-/******/ 									installedChunkData[1](error);
-/******/ 								}
-/******/ 							}
-/******/ 						};
-/******/ 						__webpack_require__.l(url, loadingEnded, "chunk-" + chunkId, chunkId);
-/******/ 					} else installedChunks[chunkId] = 0;
-/******/ 				}
-/******/ 			}
-/******/ 	};
-/******/ 	
-/******/ 	// no prefetching
-/******/ 	
-/******/ 	// no preloaded
-/******/ 	
-/******/ 	var currentUpdatedModulesList;
-/******/ 	var waitingUpdateResolves = {};
-/******/ 	function loadUpdateChunk(chunkId, updatedModulesList) {
-/******/ 		currentUpdatedModulesList = updatedModulesList;
-/******/ 		return new Promise(function(resolve, reject) {
-/******/ 			waitingUpdateResolves[chunkId] = resolve;
-/******/ 			// start update chunk loading
-/******/ 			var url = __webpack_require__.p + __webpack_require__.hu(chunkId);
-/******/ 			// create error before stack unwound to get useful stacktrace later
-/******/ 			var error = new Error();
-/******/ 			var loadingEnded = function(event) {
-/******/ 				if(waitingUpdateResolves[chunkId]) {
-/******/ 					waitingUpdateResolves[chunkId] = undefined
-/******/ 					var errorType = event && (event.type === 'load' ? 'missing' : event.type);
-/******/ 					var realSrc = event && event.target && event.target.src;
-/******/ 					error.message = 'Loading hot update chunk ' + chunkId + ' failed.\n(' + errorType + ': ' + realSrc + ')';
-/******/ 					error.name = 'ChunkLoadError';
-/******/ 					error.type = errorType;
-/******/ 					error.request = realSrc;
-/******/ 					reject(error);
-/******/ 				}
-/******/ 			};
-/******/ 			__webpack_require__.l(url, loadingEnded);
-/******/ 		});
-/******/ 	}
-/******/ 	
-/******/ 	self["webpackHotUpdate_N_E"] = function(chunkId, moreModules, runtime) {
-/******/ 		for(var moduleId in moreModules) {
-/******/ 			if(__webpack_require__.o(moreModules, moduleId)) {
-/******/ 				currentUpdate[moduleId] = moreModules[moduleId];
-/******/ 				if(currentUpdatedModulesList) currentUpdatedModulesList.push(moduleId);
-/******/ 			}
-/******/ 		}
-/******/ 		if(runtime) currentUpdateRuntime.push(runtime);
-/******/ 		if(waitingUpdateResolves[chunkId]) {
-/******/ 			waitingUpdateResolves[chunkId]();
-/******/ 			waitingUpdateResolves[chunkId] = undefined;
-/******/ 		}
-/******/ 	};
-/******/ 	
-/******/ 	var currentUpdateChunks;
-/******/ 	var currentUpdate;
-/******/ 	var currentUpdateRemovedChunks;
-/******/ 	var currentUpdateRuntime;
-/******/ 	function applyHandler(options) {
-/******/ 		if (__webpack_require__.f) delete __webpack_require__.f.jsonpHmr;
-/******/ 		currentUpdateChunks = undefined;
-/******/ 		function getAffectedModuleEffects(updateModuleId) {
-/******/ 			var outdatedModules = [updateModuleId];
-/******/ 			var outdatedDependencies = {};
-/******/ 	
-/******/ 			var queue = outdatedModules.map(function (id) {
-/******/ 				return {
-/******/ 					chain: [id],
-/******/ 					id: id
-/******/ 				};
-/******/ 			});
-/******/ 			while (queue.length > 0) {
-/******/ 				var queueItem = queue.pop();
-/******/ 				var moduleId = queueItem.id;
-/******/ 				var chain = queueItem.chain;
-/******/ 				var module = __webpack_require__.c[moduleId];
-/******/ 				if (
-/******/ 					!module ||
-/******/ 					(module.hot._selfAccepted && !module.hot._selfInvalidated)
-/******/ 				)
-/******/ 					continue;
-/******/ 				if (module.hot._selfDeclined) {
-/******/ 					return {
-/******/ 						type: "self-declined",
-/******/ 						chain: chain,
-/******/ 						moduleId: moduleId
-/******/ 					};
-/******/ 				}
-/******/ 				if (module.hot._main) {
-/******/ 					return {
-/******/ 						type: "unaccepted",
-/******/ 						chain: chain,
-/******/ 						moduleId: moduleId
-/******/ 					};
-/******/ 				}
-/******/ 				for (var i = 0; i < module.parents.length; i++) {
-/******/ 					var parentId = module.parents[i];
-/******/ 					var parent = __webpack_require__.c[parentId];
-/******/ 					if (!parent) continue;
-/******/ 					if (parent.hot._declinedDependencies[moduleId]) {
-/******/ 						return {
-/******/ 							type: "declined",
-/******/ 							chain: chain.concat([parentId]),
-/******/ 							moduleId: moduleId,
-/******/ 							parentId: parentId
-/******/ 						};
-/******/ 					}
-/******/ 					if (outdatedModules.indexOf(parentId) !== -1) continue;
-/******/ 					if (parent.hot._acceptedDependencies[moduleId]) {
-/******/ 						if (!outdatedDependencies[parentId])
-/******/ 							outdatedDependencies[parentId] = [];
-/******/ 						addAllToSet(outdatedDependencies[parentId], [moduleId]);
-/******/ 						continue;
-/******/ 					}
-/******/ 					delete outdatedDependencies[parentId];
-/******/ 					outdatedModules.push(parentId);
-/******/ 					queue.push({
-/******/ 						chain: chain.concat([parentId]),
-/******/ 						id: parentId
-/******/ 					});
-/******/ 				}
-/******/ 			}
-/******/ 	
-/******/ 			return {
-/******/ 				type: "accepted",
-/******/ 				moduleId: updateModuleId,
-/******/ 				outdatedModules: outdatedModules,
-/******/ 				outdatedDependencies: outdatedDependencies
-/******/ 			};
-/******/ 		}
-/******/ 	
-/******/ 		function addAllToSet(a, b) {
-/******/ 			for (var i = 0; i < b.length; i++) {
-/******/ 				var item = b[i];
-/******/ 				if (a.indexOf(item) === -1) a.push(item);
-/******/ 			}
-/******/ 		}
-/******/ 	
-/******/ 		// at begin all updates modules are outdated
-/******/ 		// the "outdated" status can propagate to parents if they don't accept the children
-/******/ 		var outdatedDependencies = {};
-/******/ 		var outdatedModules = [];
-/******/ 		var appliedUpdate = {};
-/******/ 	
-/******/ 		var warnUnexpectedRequire = function warnUnexpectedRequire(module) {
-/******/ 			console.warn(
-/******/ 				"[HMR] unexpected require(" + module.id + ") to disposed module"
-/******/ 			);
-/******/ 		};
-/******/ 	
-/******/ 		for (var moduleId in currentUpdate) {
-/******/ 			if (__webpack_require__.o(currentUpdate, moduleId)) {
-/******/ 				var newModuleFactory = currentUpdate[moduleId];
-/******/ 				/** @type {TODO} */
-/******/ 				var result;
-/******/ 				if (newModuleFactory) {
-/******/ 					result = getAffectedModuleEffects(moduleId);
-/******/ 				} else {
-/******/ 					result = {
-/******/ 						type: "disposed",
-/******/ 						moduleId: moduleId
-/******/ 					};
-/******/ 				}
-/******/ 				/** @type {Error|false} */
-/******/ 				var abortError = false;
-/******/ 				var doApply = false;
-/******/ 				var doDispose = false;
-/******/ 				var chainInfo = "";
-/******/ 				if (result.chain) {
-/******/ 					chainInfo = "\nUpdate propagation: " + result.chain.join(" -> ");
-/******/ 				}
-/******/ 				switch (result.type) {
-/******/ 					case "self-declined":
-/******/ 						if (options.onDeclined) options.onDeclined(result);
-/******/ 						if (!options.ignoreDeclined)
-/******/ 							abortError = new Error(
-/******/ 								"Aborted because of self decline: " +
-/******/ 									result.moduleId +
-/******/ 									chainInfo
-/******/ 							);
-/******/ 						break;
-/******/ 					case "declined":
-/******/ 						if (options.onDeclined) options.onDeclined(result);
-/******/ 						if (!options.ignoreDeclined)
-/******/ 							abortError = new Error(
-/******/ 								"Aborted because of declined dependency: " +
-/******/ 									result.moduleId +
-/******/ 									" in " +
-/******/ 									result.parentId +
-/******/ 									chainInfo
-/******/ 							);
-/******/ 						break;
-/******/ 					case "unaccepted":
-/******/ 						if (options.onUnaccepted) options.onUnaccepted(result);
-/******/ 						if (!options.ignoreUnaccepted)
-/******/ 							abortError = new Error(
-/******/ 								"Aborted because " + moduleId + " is not accepted" + chainInfo
-/******/ 							);
-/******/ 						break;
-/******/ 					case "accepted":
-/******/ 						if (options.onAccepted) options.onAccepted(result);
-/******/ 						doApply = true;
-/******/ 						break;
-/******/ 					case "disposed":
-/******/ 						if (options.onDisposed) options.onDisposed(result);
-/******/ 						doDispose = true;
-/******/ 						break;
-/******/ 					default:
-/******/ 						throw new Error("Unexception type " + result.type);
-/******/ 				}
-/******/ 				if (abortError) {
-/******/ 					return {
-/******/ 						error: abortError
-/******/ 					};
-/******/ 				}
-/******/ 				if (doApply) {
-/******/ 					appliedUpdate[moduleId] = newModuleFactory;
-/******/ 					addAllToSet(outdatedModules, result.outdatedModules);
-/******/ 					for (moduleId in result.outdatedDependencies) {
-/******/ 						if (__webpack_require__.o(result.outdatedDependencies, moduleId)) {
-/******/ 							if (!outdatedDependencies[moduleId])
-/******/ 								outdatedDependencies[moduleId] = [];
-/******/ 							addAllToSet(
-/******/ 								outdatedDependencies[moduleId],
-/******/ 								result.outdatedDependencies[moduleId]
-/******/ 							);
-/******/ 						}
-/******/ 					}
-/******/ 				}
-/******/ 				if (doDispose) {
-/******/ 					addAllToSet(outdatedModules, [result.moduleId]);
-/******/ 					appliedUpdate[moduleId] = warnUnexpectedRequire;
-/******/ 				}
-/******/ 			}
-/******/ 		}
-/******/ 		currentUpdate = undefined;
-/******/ 	
-/******/ 		// Store self accepted outdated modules to require them later by the module system
-/******/ 		var outdatedSelfAcceptedModules = [];
-/******/ 		for (var j = 0; j < outdatedModules.length; j++) {
-/******/ 			var outdatedModuleId = outdatedModules[j];
-/******/ 			var module = __webpack_require__.c[outdatedModuleId];
-/******/ 			if (
-/******/ 				module &&
-/******/ 				(module.hot._selfAccepted || module.hot._main) &&
-/******/ 				// removed self-accepted modules should not be required
-/******/ 				appliedUpdate[outdatedModuleId] !== warnUnexpectedRequire &&
-/******/ 				// when called invalidate self-accepting is not possible
-/******/ 				!module.hot._selfInvalidated
-/******/ 			) {
-/******/ 				outdatedSelfAcceptedModules.push({
-/******/ 					module: outdatedModuleId,
-/******/ 					require: module.hot._requireSelf,
-/******/ 					errorHandler: module.hot._selfAccepted
-/******/ 				});
-/******/ 			}
-/******/ 		}
-/******/ 	
-/******/ 		var moduleOutdatedDependencies;
-/******/ 	
-/******/ 		return {
-/******/ 			dispose: function () {
-/******/ 				currentUpdateRemovedChunks.forEach(function (chunkId) {
-/******/ 					delete installedChunks[chunkId];
-/******/ 				});
-/******/ 				currentUpdateRemovedChunks = undefined;
-/******/ 	
-/******/ 				var idx;
-/******/ 				var queue = outdatedModules.slice();
-/******/ 				while (queue.length > 0) {
-/******/ 					var moduleId = queue.pop();
-/******/ 					var module = __webpack_require__.c[moduleId];
-/******/ 					if (!module) continue;
-/******/ 	
-/******/ 					var data = {};
-/******/ 	
-/******/ 					// Call dispose handlers
-/******/ 					var disposeHandlers = module.hot._disposeHandlers;
-/******/ 					for (j = 0; j < disposeHandlers.length; j++) {
-/******/ 						disposeHandlers[j].call(null, data);
-/******/ 					}
-/******/ 					__webpack_require__.hmrD[moduleId] = data;
-/******/ 	
-/******/ 					// disable module (this disables requires from this module)
-/******/ 					module.hot.active = false;
-/******/ 	
-/******/ 					// remove module from cache
-/******/ 					delete __webpack_require__.c[moduleId];
-/******/ 	
-/******/ 					// when disposing there is no need to call dispose handler
-/******/ 					delete outdatedDependencies[moduleId];
-/******/ 	
-/******/ 					// remove "parents" references from all children
-/******/ 					for (j = 0; j < module.children.length; j++) {
-/******/ 						var child = __webpack_require__.c[module.children[j]];
-/******/ 						if (!child) continue;
-/******/ 						idx = child.parents.indexOf(moduleId);
-/******/ 						if (idx >= 0) {
-/******/ 							child.parents.splice(idx, 1);
-/******/ 						}
-/******/ 					}
-/******/ 				}
-/******/ 	
-/******/ 				// remove outdated dependency from module children
-/******/ 				var dependency;
-/******/ 				for (var outdatedModuleId in outdatedDependencies) {
-/******/ 					if (__webpack_require__.o(outdatedDependencies, outdatedModuleId)) {
-/******/ 						module = __webpack_require__.c[outdatedModuleId];
-/******/ 						if (module) {
-/******/ 							moduleOutdatedDependencies =
-/******/ 								outdatedDependencies[outdatedModuleId];
-/******/ 							for (j = 0; j < moduleOutdatedDependencies.length; j++) {
-/******/ 								dependency = moduleOutdatedDependencies[j];
-/******/ 								idx = module.children.indexOf(dependency);
-/******/ 								if (idx >= 0) module.children.splice(idx, 1);
-/******/ 							}
-/******/ 						}
-/******/ 					}
-/******/ 				}
-/******/ 			},
-/******/ 			apply: function (reportError) {
-/******/ 				// insert new code
-/******/ 				for (var updateModuleId in appliedUpdate) {
-/******/ 					if (__webpack_require__.o(appliedUpdate, updateModuleId)) {
-/******/ 						__webpack_require__.m[updateModuleId] = appliedUpdate[updateModuleId];
-/******/ 					}
-/******/ 				}
-/******/ 	
-/******/ 				// run new runtime modules
-/******/ 				for (var i = 0; i < currentUpdateRuntime.length; i++) {
-/******/ 					currentUpdateRuntime[i](__webpack_require__);
-/******/ 				}
-/******/ 	
-/******/ 				// call accept handlers
-/******/ 				for (var outdatedModuleId in outdatedDependencies) {
-/******/ 					if (__webpack_require__.o(outdatedDependencies, outdatedModuleId)) {
-/******/ 						var module = __webpack_require__.c[outdatedModuleId];
-/******/ 						if (module) {
-/******/ 							moduleOutdatedDependencies =
-/******/ 								outdatedDependencies[outdatedModuleId];
-/******/ 							var callbacks = [];
-/******/ 							var errorHandlers = [];
-/******/ 							var dependenciesForCallbacks = [];
-/******/ 							for (var j = 0; j < moduleOutdatedDependencies.length; j++) {
-/******/ 								var dependency = moduleOutdatedDependencies[j];
-/******/ 								var acceptCallback =
-/******/ 									module.hot._acceptedDependencies[dependency];
-/******/ 								var errorHandler =
-/******/ 									module.hot._acceptedErrorHandlers[dependency];
-/******/ 								if (acceptCallback) {
-/******/ 									if (callbacks.indexOf(acceptCallback) !== -1) continue;
-/******/ 									callbacks.push(acceptCallback);
-/******/ 									errorHandlers.push(errorHandler);
-/******/ 									dependenciesForCallbacks.push(dependency);
-/******/ 								}
-/******/ 							}
-/******/ 							for (var k = 0; k < callbacks.length; k++) {
-/******/ 								try {
-/******/ 									callbacks[k].call(null, moduleOutdatedDependencies);
-/******/ 								} catch (err) {
-/******/ 									if (typeof errorHandlers[k] === "function") {
-/******/ 										try {
-/******/ 											errorHandlers[k](err, {
-/******/ 												moduleId: outdatedModuleId,
-/******/ 												dependencyId: dependenciesForCallbacks[k]
-/******/ 											});
-/******/ 										} catch (err2) {
-/******/ 											if (options.onErrored) {
-/******/ 												options.onErrored({
-/******/ 													type: "accept-error-handler-errored",
-/******/ 													moduleId: outdatedModuleId,
-/******/ 													dependencyId: dependenciesForCallbacks[k],
-/******/ 													error: err2,
-/******/ 													originalError: err
-/******/ 												});
-/******/ 											}
-/******/ 											if (!options.ignoreErrored) {
-/******/ 												reportError(err2);
-/******/ 												reportError(err);
-/******/ 											}
-/******/ 										}
-/******/ 									} else {
-/******/ 										if (options.onErrored) {
-/******/ 											options.onErrored({
-/******/ 												type: "accept-errored",
-/******/ 												moduleId: outdatedModuleId,
-/******/ 												dependencyId: dependenciesForCallbacks[k],
-/******/ 												error: err
-/******/ 											});
-/******/ 										}
-/******/ 										if (!options.ignoreErrored) {
-/******/ 											reportError(err);
-/******/ 										}
-/******/ 									}
-/******/ 								}
-/******/ 							}
-/******/ 						}
-/******/ 					}
-/******/ 				}
-/******/ 	
-/******/ 				// Load self accepted modules
-/******/ 				for (var o = 0; o < outdatedSelfAcceptedModules.length; o++) {
-/******/ 					var item = outdatedSelfAcceptedModules[o];
-/******/ 					var moduleId = item.module;
-/******/ 					try {
-/******/ 						item.require(moduleId);
-/******/ 					} catch (err) {
-/******/ 						if (typeof item.errorHandler === "function") {
-/******/ 							try {
-/******/ 								item.errorHandler(err, {
-/******/ 									moduleId: moduleId,
-/******/ 									module: __webpack_require__.c[moduleId]
-/******/ 								});
-/******/ 							} catch (err2) {
-/******/ 								if (options.onErrored) {
-/******/ 									options.onErrored({
-/******/ 										type: "self-accept-error-handler-errored",
-/******/ 										moduleId: moduleId,
-/******/ 										error: err2,
-/******/ 										originalError: err
-/******/ 									});
-/******/ 								}
-/******/ 								if (!options.ignoreErrored) {
-/******/ 									reportError(err2);
-/******/ 									reportError(err);
-/******/ 								}
-/******/ 							}
-/******/ 						} else {
-/******/ 							if (options.onErrored) {
-/******/ 								options.onErrored({
-/******/ 									type: "self-accept-errored",
-/******/ 									moduleId: moduleId,
-/******/ 									error: err
-/******/ 								});
-/******/ 							}
-/******/ 							if (!options.ignoreErrored) {
-/******/ 								reportError(err);
-/******/ 							}
-/******/ 						}
-/******/ 					}
-/******/ 				}
-/******/ 	
-/******/ 				return outdatedModules;
-/******/ 			}
-/******/ 		};
-/******/ 	}
-/******/ 	__webpack_require__.hmrI.jsonp = function (moduleId, applyHandlers) {
-/******/ 		if (!currentUpdate) {
-/******/ 			currentUpdate = {};
-/******/ 			currentUpdateRuntime = [];
-/******/ 			currentUpdateRemovedChunks = [];
-/******/ 			applyHandlers.push(applyHandler);
-/******/ 		}
-/******/ 		if (!__webpack_require__.o(currentUpdate, moduleId)) {
-/******/ 			currentUpdate[moduleId] = __webpack_require__.m[moduleId];
-/******/ 		}
-/******/ 	};
-/******/ 	__webpack_require__.hmrC.jsonp = function (
-/******/ 		chunkIds,
-/******/ 		removedChunks,
-/******/ 		removedModules,
-/******/ 		promises,
-/******/ 		applyHandlers,
-/******/ 		updatedModulesList
-/******/ 	) {
-/******/ 		applyHandlers.push(applyHandler);
-/******/ 		currentUpdateChunks = {};
-/******/ 		currentUpdateRemovedChunks = removedChunks;
-/******/ 		currentUpdate = removedModules.reduce(function (obj, key) {
-/******/ 			obj[key] = false;
-/******/ 			return obj;
-/******/ 		}, {});
-/******/ 		currentUpdateRuntime = [];
-/******/ 		chunkIds.forEach(function (chunkId) {
-/******/ 			if (
-/******/ 				__webpack_require__.o(installedChunks, chunkId) &&
-/******/ 				installedChunks[chunkId] !== undefined
-/******/ 			) {
-/******/ 				promises.push(loadUpdateChunk(chunkId, updatedModulesList));
-/******/ 				currentUpdateChunks[chunkId] = true;
-/******/ 			} else {
-/******/ 				currentUpdateChunks[chunkId] = false;
-/******/ 			}
-/******/ 		});
-/******/ 		if (__webpack_require__.f) {
-/******/ 			__webpack_require__.f.jsonpHmr = function (chunkId, promises) {
-/******/ 				if (
-/******/ 					currentUpdateChunks &&
-/******/ 					__webpack_require__.o(currentUpdateChunks, chunkId) &&
-/******/ 					!currentUpdateChunks[chunkId]
-/******/ 				) {
-/******/ 					promises.push(loadUpdateChunk(chunkId));
-/******/ 					currentUpdateChunks[chunkId] = true;
-/******/ 				}
-/******/ 			};
-/******/ 		}
-/******/ 	};
-/******/ 	
-/******/ 	__webpack_require__.hmrM = function() {
-/******/ 		if (typeof fetch === "undefined") throw new Error("No browser support: need fetch API");
-/******/ 		return fetch(__webpack_require__.p + __webpack_require__.hmrF()).then(function(response) {
-/******/ 			if(response.status === 404) return; // no update available
-/******/ 			if(!response.ok) throw new Error("Failed to fetch update manifest " + response.statusText);
-/******/ 			return response.json();
-/******/ 		});
-/******/ 	};
-/******/ 	
-/******/ 	__webpack_require__.O.j = function(chunkId) { return installedChunks[chunkId] === 0; };
-/******/ 	
-/******/ 	// install a JSONP callback for chunk loading
-/******/ 	var webpackJsonpCallback = function(parentChunkLoadingFunction, data) {
-/******/ 		var chunkIds = data[0];
-/******/ 		var moreModules = data[1];
-/******/ 		var runtime = data[2];
-/******/ 		// add "moreModules" to the modules object,
-/******/ 		// then flag all "chunkIds" as loaded and fire callback
-/******/ 		var moduleId, chunkId, i = 0;
-/******/ 		if(chunkIds.some(function(id) { return installedChunks[id] !== 0; })) {
-/******/ 			for(moduleId in moreModules) {
-/******/ 				if(__webpack_require__.o(moreModules, moduleId)) {
-/******/ 					__webpack_require__.m[moduleId] = moreModules[moduleId];
-/******/ 				}
-/******/ 			}
-/******/ 			if(runtime) var result = runtime(__webpack_require__);
-/******/ 		}
-/******/ 		if(parentChunkLoadingFunction) parentChunkLoadingFunction(data);
-/******/ 		for(;i < chunkIds.length; i++) {
-/******/ 			chunkId = chunkIds[i];
-/******/ 			if(__webpack_require__.o(installedChunks, chunkId) && installedChunks[chunkId]) {
-/******/ 				installedChunks[chunkId][0]();
-/******/ 			}
-/******/ 			installedChunks[chunkId] = 0;
-/******/ 		}
-/******/ 		return __webpack_require__.O(result);
-/******/ 	}
-/******/ 	
-/******/ 	var chunkLoadingGlobal = self["webpackChunk_N_E"] = self["webpackChunk_N_E"] || [];
-/******/ 	chunkLoadingGlobal.forEach(webpackJsonpCallback.bind(null, 0));
-/******/ 	chunkLoadingGlobal.push = webpackJsonpCallback.bind(null, chunkLoadingGlobal.push.bind(chunkLoadingGlobal));
-/******/ }();
-/******/ 
-/******/ }
-);
\ No newline at end of file
@@ -1,7 +0,0 @@
-import type { AppProps } from 'next/app'
-import '@solana/wallet-adapter-react-ui/styles.css'
-
-export default function App({ Component, pageProps }: AppProps) {
-  return <Component {...pageProps} />
-}
-
@@ -1,14 +1,37 @@
 import { useCallback, useMemo, useState } from 'react'
-import { clusterApiUrl, Connection, Keypair, PublicKey, SystemProgram } from '@solana/web3.js'
+import { clusterApiUrl, Connection, Keypair, PublicKey, SystemProgram, SYSVAR_RENT_PUBKEY } from '@solana/web3.js'
 import idl from '../idl/universal_nft.json'
 import * as anchor from '@coral-xyz/anchor'
 import { WalletAdapterNetwork } from '@solana/wallet-adapter-base'
 import { ConnectionProvider, WalletProvider, useAnchorWallet } from '@solana/wallet-adapter-react'
 import { WalletModalProvider, WalletMultiButton } from '@solana/wallet-adapter-react-ui'
 import { PhantomWalletAdapter, SolflareWalletAdapter } from '@solana/wallet-adapter-wallets'
-import { getAssociatedTokenAddress, ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_PROGRAM_ID } from '@solana/spl-token'
 
 const PROGRAM_ID = new PublicKey((idl as any).address)
+const TOKEN_PROGRAM_ID = new PublicKey('TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA')
+const ASSOCIATED_TOKEN_PROGRAM_ID = new PublicKey('ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL')
+const TOKEN_METADATA_PROGRAM_ID = new PublicKey('metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s')
+
+const pda = (seeds: Buffer[], programId = PROGRAM_ID) => PublicKey.findProgramAddressSync(seeds, programId)
+const globalConfigPda = () => pda([Buffer.from('global_config')])[0]
+const associatedTokenAddress = (mint: PublicKey, owner: PublicKey) =>
+  pda([owner.toBuffer(), TOKEN_PROGRAM_ID.toBuffer(), mint.toBuffer()], ASSOCIATED_TOKEN_PROGRAM_ID)[0]
+const metadataPda = (mint: PublicKey) =>
+  pda([Buffer.from('metadata'), TOKEN_METADATA_PROGRAM_ID.toBuffer(), mint.toBuffer()], TOKEN_METADATA_PROGRAM_ID)[0]
+const masterEditionPda = (mint: PublicKey) =>
+  pda([Buffer.from('metadata'), TOKEN_METADATA_PROGRAM_ID.toBuffer(), mint.toBuffer(), Buffer.from('edition')], TOKEN_METADATA_PROGRAM_ID)[0]
+const sha256 = async (data: Buffer) => Buffer.from(await crypto.subtle.digest('SHA-256', data))
+
+// Mirrors OriginIndex::origin_hash: length-prefixed (u32 LE) chain, contract, token id
+const originIndexPda = async (chain: string, contract: string, tokenId: string) => {
+  const parts = [chain, contract, tokenId].flatMap(part => {
+    const bytes = Buffer.from(part)
+    const len = Buffer.alloc(4)
+    len.writeUInt32LE(bytes.length)
+    return [len, bytes]
+  })
+  return pda([Buffer.from('origin_index'), await sha256(Buffer.concat(parts))])[0]
+}
 
 function AppInner() {
   const wallet = useAnchorWallet()
@@ -17,7 +40,7 @@ function AppInner() {
   const connection = useMemo(() => new Connection(clusterApiUrl(WalletAdapterNetwork.Devnet), 'confirmed'), [])
 
   const provider = useMemo(() => (wallet ? new anchor.AnchorProvider(connection, wallet, { commitment: 'confirmed' }) : null), [connection, wallet])
-  const program = useMemo(() => (provider ? new anchor.Program(idl as any, PROGRAM_ID, provider) : null), [provider])
+  const program = useMemo(() => (provider ? new anchor.Program(idl as any, provider) : null), [provider])
 
   const [gateway, setGateway] = useState<string>('11111111111111111111111111111111')
   const [fee, setFee] = useState<string>('10000')
@@ -29,17 +52,20 @@ function AppInner() {
   const [originalContract, setOriginalContract] = useState('native')
   const [originalTokenId, setOriginalTokenId] = useState('1')
 
+  const [destinationChainId, setDestinationChainId] = useState('137')
+  const [destinationRecipient, setDestinationRecipient] = useState('0x0000000000000000000000000000000000000abc')
+
   const [lastMint, setLastMint] = useState<PublicKey | null>(null)
 
   const handleInitialize = useCallback(async () => {
     if (!program || !provider || !wallet?.publicKey) return
     setStatus('Initializing...')
     try {
-      const [globalConfigPda, bump] = PublicKey.findProgramAddressSync([Buffer.from('global_config')], PROGRAM_ID)
+      const [globalConfig, bump] = pda([Buffer.from('global_config')])
       const tx = await program.methods
         .initialize(bump, new anchor.BN(parseInt(fee)))
         .accounts({
-          globalConfig: globalConfigPda,
+          globalConfig,
           authority: wallet.publicKey,
           zetachainGateway: new PublicKey(gateway),
           collectionAuthority: wallet.publicKey,
@@ -60,22 +86,32 @@ function AppInner() {
     setStatus('Minting...')
     try {
       const mint = Keypair.generate()
-      const [universalNftPda, bump] = PublicKey.findProgramAddressSync([Buffer.from('universal_nft'), mint.publicKey.toBuffer()], PROGRAM_ID)
-      const tokenAccount = await getAssociatedTokenAddress(mint.publicKey, wallet.publicKey)
+      const [universalNftPda, bump] = pda([Buffer.from('universal_nft'), mint.publicKey.toBuffer()])
+      const config = await (program.account as any).globalConfig.fetch(globalConfigPda())
+      const collectionMint: PublicKey = config.collectionMint
 
       const tx = await program.methods
         .mintNft(bump, mintName, mintSymbol, mintUri, originalChain, originalContract, originalTokenId)
         .accounts({
-          globalConfig: PublicKey.findProgramAddressSync([Buffer.from('global_config')], PROGRAM_ID)[0],
+          globalConfig: globalConfigPda(),
           universalNft: universalNftPda,
+          originIndex: await originIndexPda(originalChain, originalContract, originalTokenId),
           mint: mint.publicKey,
-          tokenAccount,
+          tokenAccount: associatedTokenAddress(mint.publicKey, wallet.publicKey),
+          mintAuthority: pda([Buffer.from('mint_authority')])[0],
+          metadata: metadataPda(mint.publicKey),
+          masterEdition: masterEditionPda(mint.publicKey),
+          collectionMint,
+          collectionMetadata: metadataPda(collectionMint),
+          collectionMasterEdition: masterEditionPda(collectionMint),
           payer: wallet.publicKey,
           recipient: wallet.publicKey,
           collectionAuthority: wallet.publicKey,
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-          systemProgram: SystemProgram.programId
+          tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          rent: SYSVAR_RENT_PUBKEY
         })
         .signers([mint])
         .rpc()
@@ -93,34 +129,44 @@ function AppInner() {
     if (!program || !provider || !wallet?.publicKey || !lastMint) return
     setStatus('Initiating cross-chain transfer...')
     try {
-      const transferId = `transfer_${Date.now()}`
-      const [universalNftPda] = PublicKey.findProgramAddressSync([Buffer.from('universal_nft'), lastMint.toBuffer()], PROGRAM_ID)
-      const [crossChainTransferPda, crossBump] = PublicKey.findProgramAddressSync([Buffer.from('cross_chain_transfer'), Buffer.from(transferId)], PROGRAM_ID)
-      const ownerTokenAccount = await getAssociatedTokenAddress(lastMint, wallet.publicKey)
+      const chainId = new anchor.BN(destinationChainId)
+      const config = await (program.account as any).globalConfig.fetch(globalConfigPda())
+
+      // Mirrors CrossChainTransfer::transfer_id: sha256(mint || owner || nonce LE)
+      const [transferNonce] = pda([Buffer.from('transfer_nonce'), wallet.publicKey.toBuffer()])
+      const counter = await (program.account as any).transferNonce.fetchNullable(transferNonce)
+      const nonce: anchor.BN = counter ? counter.nonce : new anchor.BN(0)
+      const transferId = await sha256(Buffer.concat([lastMint.toBuffer(), wallet.publicKey.toBuffer(), nonce.toArrayLike(Buffer, 'le', 8)]))
+      const [crossChainTransferPda] = pda([Buffer.from('cross_chain_transfer'), transferId])
 
       const tx = await program.methods
-        .initiateCrossChainTransfer(transferId, 'polygon', '0xabc', crossBump)
+        .initiateCrossChainTransfer(chainId, destinationRecipient)
         .accounts({
-          globalConfig: PublicKey.findProgramAddressSync([Buffer.from('global_config')], PROGRAM_ID)[0],
-          universalNft: universalNftPda,
+          globalConfig: globalConfigPda(),
+          universalNft: pda([Buffer.from('universal_nft'), lastMint.toBuffer()])[0],
+          transferNonce,
           crossChainTransfer: crossChainTransferPda,
+          chainConfig: pda([Buffer.from('chain_config'), chainId.toArrayLike(Buffer, 'le', 8)])[0],
           nftMint: lastMint,
-          ownerTokenAccount,
+          ownerTokenAccount: associatedTokenAddress(lastMint, wallet.publicKey),
+          escrowTokenAccount: associatedTokenAddress(lastMint, globalConfigPda()),
           owner: wallet.publicKey,
           payer: wallet.publicKey,
-          zetachainGateway: PublicKey.default,
+          zetachainGateway: config.zetachainGateway,
+          feeRecipient: config.feeRecipient,
           tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId
         })
         .rpc()
 
       setStatus('Transfer initiated ✅')
-      setLogs(`Initiate tx: ${tx}\nTransfer ID: ${transferId}`)
+      setLogs(`Initiate tx: ${tx}\nTransfer ID: ${transferId.toString('hex')}`)
     } catch (e: any) {
       setStatus('Initiate failed')
       setLogs(e.message || String(e))
     }
-  }, [program, provider, wallet, lastMint])
+  }, [program, provider, wallet, lastMint, destinationChainId, destinationRecipient])
 
   return (
     <div style={{ fontFamily: 'sans-serif', maxWidth: 900, margin: '40px auto' }}>
@@ -156,7 +202,11 @@ function AppInner() {
 
       <section style={{ border: '1px solid #ddd', padding: 16, borderRadius: 8, marginBottom: 16 }}>
         <h3>3) Initiate Cross-Chain Transfer (Demo)</h3>
-        <button onClick={handleInitiate} disabled={!wallet || !lastMint}>Initiate</button>
+        <div style={{ display: 'flex', gap: 8, flexWrap: 'wrap' }}>
+          <input style={{ width: 180 }} value={destinationChainId} onChange={e => setDestinationChainId(e.target.value)} placeholder="Destination Chain ID" />
+          <input style={{ flex: 1 }} value={destinationRecipient} onChange={e => setDestinationRecipient(e.target.value)} placeholder="Destination Recipient" />
+          <button onClick={handleInitiate} disabled={!wallet || !lastMint}>Initiate</button>
+        </div>
       </section>
 
       <section style={{ border: '1px solid #ddd', padding: 16, borderRadius: 8 }}>